@@ -4,7 +4,10 @@ use anyhow::Context as _;
 use clap::Parser;
 use tokio::sync::watch;
 use zksync_config::configs::{ContractVerifierSecrets, DatabaseSecrets, PrometheusConfig};
-use zksync_contract_verifier_lib::{etherscan::EtherscanVerifier, ContractVerifier};
+use zksync_contract_verifier_lib::{
+    etherscan::EtherscanVerifier, similar_match::SimilarMatchFinder, sourcify::SourcifyBridge,
+    ContractVerifier,
+};
 use zksync_core_leftovers::temp_config_store::{load_general_config, read_yaml_repr};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_env_config::FromEnv;
@@ -125,6 +128,34 @@ async fn main() -> anyhow::Result<()> {
                 .run(stop_receiver.clone()),
         ),
     ];
+    if verifier_config.similar_match_discovery_enabled {
+        tracing::info!("Similar match discovery is enabled");
+        let similar_match_finder = SimilarMatchFinder::new(pool.clone());
+        tasks.push(tokio::spawn(
+            similar_match_finder.run(stop_receiver.clone()),
+        ));
+    } else {
+        tracing::info!("Similar match discovery is disabled");
+    }
+    let sourcify_enabled = verifier_config.sourcify_api_url.is_some()
+        && (verifier_config.sourcify_import_enabled || verifier_config.sourcify_export_enabled);
+    if sourcify_enabled {
+        tracing::info!("Sourcify bridge is enabled");
+        let chain_id = verifier_config
+            .sourcify_chain_id
+            .context("sourcify_chain_id must be set if Sourcify sync is enabled")?;
+        let sourcify_bridge = SourcifyBridge::new(
+            verifier_config.sourcify_api_url.clone().unwrap(),
+            chain_id,
+            pool.clone(),
+            verifier_config.sourcify_sync_interval(),
+        )
+        .with_import(verifier_config.sourcify_import_enabled)
+        .with_export(verifier_config.sourcify_export_enabled);
+        tasks.push(tokio::spawn(sourcify_bridge.run(stop_receiver.clone())));
+    } else {
+        tracing::info!("Sourcify bridge is disabled");
+    }
     if etherscan_verifier_enabled {
         tracing::info!("Etherscan verifier is enabled");
         let etherscan_verifier = EtherscanVerifier::new(
@@ -302,12 +302,22 @@ pub(crate) struct OptionalENConfig {
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     #[serde(default = "OptionalENConfig::default_max_batch_request_size")]
     pub max_batch_request_size: usize,
+    /// Maximum number of requests in a single batch JSON RPC request sent over WebSocket. If not set,
+    /// `max_batch_request_size` is used. Setting this to 0 disables batch requests for the WebSocket server.
+    pub websocket_max_batch_request_size: Option<usize>,
+    /// Rejects quantity params with leading zero digits (e.g., `0x01`), like geth does.
+    #[serde(default)]
+    pub strict_quantity_validation: bool,
     /// Maximum response body size in MiBs. Default is 10 MiB.
     #[serde(default = "OptionalENConfig::default_max_response_body_size_mb")]
     pub max_response_body_size_mb: usize,
     /// Method-specific overrides in MiBs for the maximum response body size.
     #[serde(default = "MaxResponseSizeOverrides::empty")]
     max_response_body_size_overrides_mb: MaxResponseSizeOverrides,
+    /// Maximum size of call traces processed by a single `debug_trace*` call, in bytes of the JSON response. Stored
+    /// traces with the estimated response size exceeding this limit are not loaded; otherwise, traces are truncated
+    /// to fit. If not set, traces are not limited.
+    pub max_trace_size_bytes: Option<usize>,
 
     // Other API config settings
     /// Interval between polling DB for Web3 subscriptions.
@@ -335,6 +345,9 @@ pub(crate) struct OptionalENConfig {
     latest_values_cache_size_mb: usize,
     /// Enabled JSON RPC API namespaces.
     api_namespaces: Option<Vec<Namespace>>,
+    /// JSON RPC API namespaces disabled regardless of `api_namespaces`.
+    #[serde(default)]
+    disabled_api_namespaces: Vec<Namespace>,
     /// Whether to support HTTP methods that install filters and query filter changes.
     /// WS methods are unaffected.
     ///
@@ -448,6 +461,20 @@ pub(crate) struct OptionalENConfig {
     /// (presumably, to participate in L1 batch proving).
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
+    /// Number of latest L2 blocks for which transaction receipts are cached in a dedicated DB table
+    /// when L1 batches are persisted. Set to 0 (the default) to disable the cache.
+    #[serde(default)]
+    pub receipts_cache_l2_blocks: u32,
+    /// Whether to persist call traces only for failed transactions. Only has an effect if the `debug` namespace is enabled.
+    #[serde(default)]
+    pub save_failed_call_traces_only: bool,
+    /// Number of days for which call traces are retained. If not set (the default), call traces are retained indefinitely.
+    #[serde(default)]
+    pub call_traces_retention_days: Option<u32>,
+    /// Whether to compress persisted call traces. Compressed traces cannot be read by node versions predating
+    /// compression support.
+    #[serde(default)]
+    pub compress_call_traces: bool,
     /// Address of the L1 diamond proxy contract used by the consistency checker to match with the origin of logs emitted
     /// by commit transactions. If not set, it will not be verified.
     // This is intentionally not a part of `RemoteENConfig` because fetching this info from the main node would defeat
@@ -493,16 +520,37 @@ pub(crate) struct OptionalENConfig {
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 7 days.
     #[serde(default = "OptionalENConfig::default_pruning_data_retention_sec")]
     pruning_data_retention_sec: u64,
+    /// Runs the node as a full-history (archive) node. In this mode, pruning must be disabled and the node must be
+    /// synced from genesis rather than recovered from a snapshot; additional indexes for historical queries are created
+    /// in Postgres, and API limits are relaxed to at least the `ARCHIVE_MODE_*` values. The consistency
+    /// of the node storage with these requirements is validated on node start.
+    #[serde(default)]
+    pub archive_mode: bool,
     /// Gateway RPC URL, needed for operating during migration.
     pub gateway_url: Option<SensitiveUrl>,
     /// Interval for bridge addresses refreshing in seconds.
     bridge_addresses_refresh_interval_sec: Option<NonZeroU64>,
+    /// Time-to-live (in seconds) for transactions queued for resubmission to the main node if the main node
+    /// is unavailable. If not set, transactions are rejected if they cannot be proxied to the main node.
+    tx_proxy_queue_ttl_sec: Option<NonZeroU64>,
+    /// Maximum number of transactions queued for resubmission to the main node. If the queue is full,
+    /// transactions that cannot be proxied to the main node are rejected. Only used if the queue is enabled
+    /// via `tx_proxy_queue_ttl_sec`.
+    #[serde(default = "OptionalENConfig::default_tx_proxy_queue_capacity")]
+    pub tx_proxy_queue_capacity: NonZeroUsize,
     /// Minimum time between current block.timestamp and the end of the asserted range for TimestampAsserter
     #[serde(default = "OptionalENConfig::default_timestamp_asserter_min_time_till_end_sec")]
     pub timestamp_asserter_min_time_till_end_sec: u32,
 }
 
 impl OptionalENConfig {
+    /// Minimum number of entities (e.g., logs) returned by a single API request in the archive mode.
+    const ARCHIVE_MODE_REQ_ENTITIES_LIMIT: usize = 100_000;
+    /// Minimum number of blocks for `eth_feeHistory` in the archive mode.
+    const ARCHIVE_MODE_FEE_HISTORY_LIMIT: u64 = 10_000;
+    /// Minimum global response body size limit in the archive mode.
+    const ARCHIVE_MODE_MAX_RESPONSE_BODY_SIZE_MB: usize = 100;
+
     fn from_configs(
         general_config: &GeneralConfig,
         enconfig: &ENConfig,
@@ -511,8 +559,15 @@ impl OptionalENConfig {
         let api_namespaces = load_config!(general_config.api_config, web3_json_rpc.api_namespaces)
             .map(|a: Vec<String>| a.iter().map(|a| a.parse()).collect::<Result<_, _>>())
             .transpose()?;
+        let disabled_api_namespaces = load_config!(
+            general_config.api_config,
+            web3_json_rpc.disabled_api_namespaces
+        )
+        .map(|a: Vec<String>| a.iter().map(|a| a.parse()).collect::<Result<_, _>>())
+        .transpose()?
+        .unwrap_or_default();
 
-        Ok(OptionalENConfig {
+        OptionalENConfig {
             filters_limit: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.filters_limit,
@@ -547,6 +602,15 @@ impl OptionalENConfig {
                 web3_json_rpc.max_batch_request_size,
                 default_max_batch_request_size
             ),
+            websocket_max_batch_request_size: load_config!(
+                general_config.api_config,
+                web3_json_rpc.websocket_max_batch_request_size
+            ),
+            strict_quantity_validation: general_config
+                .api_config
+                .as_ref()
+                .map(|a| a.web3_json_rpc.strict_quantity_validation)
+                .unwrap_or_default(),
             max_response_body_size_mb: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.max_response_body_size_mb,
@@ -557,6 +621,10 @@ impl OptionalENConfig {
                 web3_json_rpc.max_response_body_size_overrides_mb,
                 default_max_response_body_size_overrides_mb
             ),
+            max_trace_size_bytes: load_config!(
+                general_config.api_config,
+                web3_json_rpc.max_trace_size_bytes
+            ),
             pubsub_polling_interval_ms: load_optional_config_or_default!(
                 general_config.api_config,
                 web3_json_rpc.pubsub_polling_interval,
@@ -719,6 +787,25 @@ impl OptionalENConfig {
                 .as_ref()
                 .map(|a| a.experimental.protective_reads_persistence_enabled)
                 .unwrap_or_default(),
+            receipts_cache_l2_blocks: general_config
+                .state_keeper_config
+                .as_ref()
+                .map(|config| config.receipts_cache_l2_blocks)
+                .unwrap_or_default(),
+            save_failed_call_traces_only: general_config
+                .state_keeper_config
+                .as_ref()
+                .map(|config| config.save_failed_call_traces_only)
+                .unwrap_or_default(),
+            call_traces_retention_days: general_config
+                .state_keeper_config
+                .as_ref()
+                .and_then(|config| config.call_traces_retention_days),
+            compress_call_traces: general_config
+                .state_keeper_config
+                .as_ref()
+                .map(|config| config.compress_call_traces)
+                .unwrap_or_default(),
             merkle_tree_processing_delay_ms: load_config_or_default!(
                 general_config.db_config,
                 experimental.processing_delay_ms,
@@ -738,18 +825,25 @@ impl OptionalENConfig {
                 .main_node_rate_limit_rps
                 .unwrap_or_else(Self::default_main_node_rate_limit_rps),
             api_namespaces,
+            disabled_api_namespaces,
             contracts_diamond_proxy_addr: None,
             gateway_url: secrets
                 .l1
                 .as_ref()
                 .and_then(|l1| l1.gateway_rpc_url.clone()),
             bridge_addresses_refresh_interval_sec: enconfig.bridge_addresses_refresh_interval_sec,
+            tx_proxy_queue_ttl_sec: enconfig.tx_proxy_queue_ttl_sec,
+            tx_proxy_queue_capacity: enconfig
+                .tx_proxy_queue_capacity
+                .unwrap_or_else(Self::default_tx_proxy_queue_capacity),
             timestamp_asserter_min_time_till_end_sec: general_config
                 .timestamp_asserter_config
                 .as_ref()
                 .map(|x| x.min_time_till_end_sec)
                 .unwrap_or_else(Self::default_timestamp_asserter_min_time_till_end_sec),
-        })
+            archive_mode: enconfig.archive_mode,
+        }
+        .with_archive_profile()
     }
 
     const fn default_filters_limit() -> usize {
@@ -887,12 +981,44 @@ impl OptionalENConfig {
         60
     }
 
+    fn default_tx_proxy_queue_capacity() -> NonZeroUsize {
+        NonZeroUsize::new(10_000).unwrap()
+    }
+
     fn from_env() -> anyhow::Result<Self> {
         let mut result: OptionalENConfig = envy::prefixed("EN_")
             .from_env()
             .context("could not load external node config")?;
         result.snapshots_recovery_object_store = snapshot_recovery_object_store_config().ok();
-        Ok(result)
+        result.with_archive_profile()
+    }
+
+    /// Checks that the archive profile is consistent and relaxes API limits if the archive mode is enabled.
+    /// No-op if the archive mode is disabled.
+    fn with_archive_profile(mut self) -> anyhow::Result<Self> {
+        if !self.archive_mode {
+            return Ok(self);
+        }
+
+        anyhow::ensure!(
+            !self.pruning_enabled,
+            "archive mode is incompatible with pruning; disable pruning or archive mode"
+        );
+        anyhow::ensure!(
+            !self.snapshots_recovery_enabled,
+            "archive mode is incompatible with snapshot recovery; an archive node must be synced from genesis"
+        );
+
+        self.req_entities_limit = self
+            .req_entities_limit
+            .max(Self::ARCHIVE_MODE_REQ_ENTITIES_LIMIT);
+        self.fee_history_limit = self
+            .fee_history_limit
+            .max(Self::ARCHIVE_MODE_FEE_HISTORY_LIMIT);
+        self.max_response_body_size_mb = self
+            .max_response_body_size_mb
+            .max(Self::ARCHIVE_MODE_MAX_RESPONSE_BODY_SIZE_MB);
+        Ok(self)
     }
 
     pub fn polling_interval(&self) -> Duration {
@@ -943,10 +1069,23 @@ impl OptionalENConfig {
             .map(Duration::from_millis)
     }
 
+    pub fn call_traces_retention(&self) -> Option<Duration> {
+        self.call_traces_retention_days
+            .map(|days| Duration::from_secs(u64::from(days) * 86_400))
+    }
+
     pub fn api_namespaces(&self) -> Vec<Namespace> {
-        self.api_namespaces
+        let mut namespaces = self
+            .api_namespaces
             .clone()
-            .unwrap_or_else(|| Namespace::DEFAULT.to_vec())
+            .unwrap_or_else(|| Namespace::DEFAULT.to_vec());
+        namespaces.retain(|namespace| !self.disabled_api_namespaces.contains(namespace));
+        namespaces
+    }
+
+    pub fn websocket_max_batch_request_size(&self) -> usize {
+        self.websocket_max_batch_request_size
+            .unwrap_or(self.max_batch_request_size)
     }
 
     pub fn max_response_body_size(&self) -> MaxResponseSize {
@@ -984,6 +1123,11 @@ impl OptionalENConfig {
             .map(|n| Duration::from_secs(n.get()))
     }
 
+    pub fn tx_proxy_queue_ttl(&self) -> Option<Duration> {
+        self.tx_proxy_queue_ttl_sec
+            .map(|n| Duration::from_secs(n.get()))
+    }
+
     #[cfg(test)]
     fn mock() -> Self {
         // Set all values to their defaults
@@ -1150,6 +1294,17 @@ pub(crate) struct ExperimentalENConfig {
     /// If not set, parallel persistence will be disabled.
     #[serde(default)] // Temporarily use a conservative option (sequential recovery) as default
     pub snapshots_recovery_tree_parallel_persistence_buffer: Option<NonZeroUsize>,
+    /// Maximum number of storage log chunks concurrently inserted into Postgres during snapshot recovery.
+    /// If not set, equals `snapshots_recovery_postgres_max_concurrency`.
+    pub snapshots_recovery_postgres_max_concurrent_applications: Option<NonZeroUsize>,
+    /// Maximum number of storage logs inserted into Postgres in a single query during snapshot recovery.
+    /// If not set, each storage logs chunk is inserted using a single query.
+    pub snapshots_recovery_postgres_insertion_batch_size: Option<NonZeroUsize>,
+    /// Maximum bandwidth (in bytes per second) used to download snapshot storage log chunks. If not set, downloads are not throttled.
+    pub snapshots_recovery_max_download_bandwidth: Option<NonZeroU64>,
+    /// If set, snapshot recovery will pause applying storage log chunks while the replay lag of Postgres replicas
+    /// exceeds this value.
+    pub snapshots_recovery_postgres_max_replication_lag_sec: Option<u64>,
 
     // Commitment generator
     /// Maximum degree of parallelism during commitment generation, i.e., the maximum number of L1 batches being processed in parallel.
@@ -1176,6 +1331,10 @@ impl ExperimentalENConfig {
             snapshots_recovery_drop_storage_key_preimages: false,
             snapshots_recovery_tree_chunk_size: Self::default_snapshots_recovery_tree_chunk_size(),
             snapshots_recovery_tree_parallel_persistence_buffer: None,
+            snapshots_recovery_postgres_max_concurrent_applications: None,
+            snapshots_recovery_postgres_insertion_batch_size: None,
+            snapshots_recovery_max_download_bandwidth: None,
+            snapshots_recovery_postgres_max_replication_lag_sec: None,
             commitment_generator_max_parallelism: None,
         }
     }
@@ -1206,6 +1365,22 @@ impl ExperimentalENConfig {
                 general_config.snapshot_recovery,
                 tree.parallel_persistence_buffer
             ),
+            snapshots_recovery_postgres_max_concurrent_applications: load_config!(
+                general_config.snapshot_recovery,
+                postgres.max_concurrent_applications
+            ),
+            snapshots_recovery_postgres_insertion_batch_size: load_config!(
+                general_config.snapshot_recovery,
+                postgres.insertion_batch_size
+            ),
+            snapshots_recovery_max_download_bandwidth: load_config!(
+                general_config.snapshot_recovery,
+                postgres.max_download_bandwidth
+            ),
+            snapshots_recovery_postgres_max_replication_lag_sec: load_config!(
+                general_config.snapshot_recovery,
+                postgres.max_replication_lag_sec
+            ),
             snapshots_recovery_drop_storage_key_preimages: general_config
                 .snapshot_recovery
                 .as_ref()
@@ -1511,6 +1686,7 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
             // We do not fetch it from remote to not introduce a dependency on the unstable endpoint.
             // At the same time, this variable should only be used from the main node during v26 upgrade.
             l1_to_l2_txs_paused: true,
+            max_trace_size_bytes: config.optional.max_trace_size_bytes,
         }
     }
 }
@@ -1544,6 +1720,8 @@ impl From<&ExternalNodeConfig> for TxSenderConfig {
                     ),
                 }
             }),
+            // Address policies are enforced by the main node.
+            address_policies_enabled: false,
         }
     }
 }
@@ -131,6 +131,10 @@ fn parsing_optional_config_from_env() {
         ),
         ("EN_L1_BATCH_COMMIT_DATA_GENERATOR_MODE", "Validium"),
         ("EN_TIMESTAMP_ASSERTER_MIN_TIME_TILL_END_SEC", "2"),
+        ("EN_API_NAMESPACES", "eth,net,zks,debug"),
+        ("EN_DISABLED_API_NAMESPACES", "net,debug"),
+        ("EN_TX_PROXY_QUEUE_TTL_SEC", "300"),
+        ("EN_TX_PROXY_QUEUE_CAPACITY", "1000"),
     ];
     let env_vars = env_vars
         .into_iter()
@@ -176,6 +180,44 @@ fn parsing_optional_config_from_env() {
         config.l1_batch_commit_data_generator_mode,
         L1BatchCommitmentMode::Validium
     );
+    assert_eq!(config.api_namespaces(), [Namespace::Eth, Namespace::Zks]);
+    assert_eq!(config.tx_proxy_queue_ttl(), Some(Duration::from_secs(300)));
+    assert_eq!(config.tx_proxy_queue_capacity.get(), 1_000);
+}
+
+#[test]
+fn applying_archive_profile() {
+    let env_vars = [
+        ("EN_ARCHIVE_MODE", "true"),
+        ("EN_REQ_ENTITIES_LIMIT", "200000"),
+    ];
+    let env_vars = env_vars
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value.to_owned()));
+    let config: OptionalENConfig = envy::prefixed("EN_").from_iter(env_vars).unwrap();
+    assert!(config.archive_mode);
+
+    let config = config.with_archive_profile().unwrap();
+    // Limits greater than archive ones must be retained.
+    assert_eq!(config.req_entities_limit, 200_000);
+    assert_eq!(
+        config.fee_history_limit,
+        OptionalENConfig::ARCHIVE_MODE_FEE_HISTORY_LIMIT
+    );
+    assert_eq!(
+        config.max_response_body_size().global,
+        OptionalENConfig::ARCHIVE_MODE_MAX_RESPONSE_BODY_SIZE_MB * BYTES_IN_MEGABYTE
+    );
+
+    for inconsistent_var in ["EN_PRUNING_ENABLED", "EN_SNAPSHOTS_RECOVERY_ENABLED"] {
+        let env_vars = [("EN_ARCHIVE_MODE", "true"), (inconsistent_var, "true")];
+        let env_vars = env_vars
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()));
+        let config: OptionalENConfig = envy::prefixed("EN_").from_iter(env_vars).unwrap();
+        let err = config.with_archive_profile().unwrap_err().to_string();
+        assert!(err.contains("archive mode is incompatible"), "{err}");
+    }
 }
 
 #[test]
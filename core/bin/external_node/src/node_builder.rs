@@ -1,6 +1,8 @@
 //! This module provides a "builder" for the external node,
 //! as well as an interface to run the node with the specified components.
 
+use std::time::Duration;
+
 use anyhow::{bail, Context as _};
 use zksync_block_reverter::NodeRole;
 use zksync_config::{
@@ -17,6 +19,7 @@ use zksync_metadata_calculator::{
 use zksync_node_api_server::web3::Namespace;
 use zksync_node_framework::{
     implementations::layers::{
+        archive_mode::ArchiveModeLayer,
         batch_status_updater::BatchStatusUpdaterLayer,
         block_reverter::BlockReverterLayer,
         commitment_generator::CommitmentGeneratorLayer,
@@ -37,12 +40,14 @@ use zksync_node_framework::{
             external_node_strategy::{ExternalNodeInitStrategyLayer, SnapshotRecoveryConfig},
             NodeStorageInitializerLayer,
         },
+        node_version_check::NodeVersionCheckLayer,
         pools_layer::PoolsLayerBuilder,
         postgres::PostgresLayer,
         prometheus_exporter::PrometheusExporterLayer,
-        pruning::PruningLayer,
+        pruning::{CallTracesPruningLayer, PruningLayer},
         query_eth_client::QueryEthClientLayer,
         reorg_detector::ReorgDetectorLayer,
+        settlement_layer_data::SettlementLayerDataLayer,
         sigint::SigintHandlerLayer,
         state_keeper::{
             external_io::ExternalIOLayer, main_batch_executor::MainBatchExecutorLayer,
@@ -52,7 +57,7 @@ use zksync_node_framework::{
         tree_data_fetcher::TreeDataFetcherLayer,
         validate_chain_ids::ValidateChainIdsLayer,
         web3_api::{
-            caches::MempoolCacheLayer,
+            caches::{GasPerPubdataOracleLayer, MempoolCacheLayer},
             server::{Web3ServerLayer, Web3ServerOptionalConfig},
             tree_api_client::TreeApiClientLayer,
             tx_sender::{PostgresStorageCachesConfig, TxSenderLayer},
@@ -62,7 +67,7 @@ use zksync_node_framework::{
     service::{ZkStackService, ZkStackServiceBuilder},
 };
 use zksync_state::RocksdbStorageOptions;
-use zksync_types::L2_ASSET_ROUTER_ADDRESS;
+use zksync_types::{settlement::SettlementMode, L2_ASSET_ROUTER_ADDRESS};
 
 use crate::{config::ExternalNodeConfig, metrics::framework::ExternalNodeMetricsLayer, Component};
 
@@ -149,6 +154,22 @@ impl ExternalNodeBuilder {
         Ok(self)
     }
 
+    fn add_settlement_layer_data_layer(mut self) -> anyhow::Result<Self> {
+        let settlement_mode = if self.config.required.gateway_chain_id.is_some() {
+            SettlementMode::Gateway
+        } else {
+            SettlementMode::SettlesToL1
+        };
+        let sl_chain_id = self
+            .config
+            .required
+            .gateway_chain_id
+            .unwrap_or(self.config.required.l1_chain_id.into());
+        self.node
+            .add_layer(SettlementLayerDataLayer::new(settlement_mode, sl_chain_id));
+        Ok(self)
+    }
+
     fn add_main_node_client_layer(mut self) -> anyhow::Result<Self> {
         let layer = MainNodeClientLayer::new(
             self.config.required.main_node_url.clone(),
@@ -224,7 +245,10 @@ impl ExternalNodeBuilder {
         .with_pre_insert_txs(true) // EN requires txs to be pre-inserted.
         .with_protective_reads_persistence_enabled(
             self.config.optional.protective_reads_persistence_enabled,
-        );
+        )
+        .with_receipts_cache_l2_blocks(self.config.optional.receipts_cache_l2_blocks)
+        .with_failed_call_traces_only(self.config.optional.save_failed_call_traces_only)
+        .with_call_traces_compression(self.config.optional.compress_call_traces);
 
         let io_layer = ExternalIOLayer::new(self.config.required.l2_chain_id);
 
@@ -281,6 +305,16 @@ impl ExternalNodeBuilder {
         } else {
             tracing::info!("Pruning is disabled");
         }
+        if let Some(retention) = self.config.optional.call_traces_retention() {
+            self.node.add_layer(CallTracesPruningLayer::new(retention));
+        }
+        Ok(self)
+    }
+
+    fn add_archive_mode_layer(mut self) -> anyhow::Result<Self> {
+        if self.config.optional.archive_mode {
+            self.node.add_layer(ArchiveModeLayer);
+        }
         Ok(self)
     }
 
@@ -303,6 +337,11 @@ impl ExternalNodeBuilder {
         Ok(self)
     }
 
+    fn add_node_version_check_layer(mut self) -> anyhow::Result<Self> {
+        self.node.add_layer(NodeVersionCheckLayer);
+        Ok(self)
+    }
+
     fn add_consistency_checker_layer(mut self) -> anyhow::Result<Self> {
         let max_batches_to_recheck = 10; // TODO (BFT-97): Make it a part of a proper EN config
         let layer = ConsistencyCheckerLayer::new(
@@ -484,7 +523,12 @@ impl ExternalNodeBuilder {
         )
         .with_whitelisted_tokens_for_aa_cache(true);
 
-        self.node.add_layer(ProxySinkLayer);
+        let mut proxy_sink_layer = ProxySinkLayer::default();
+        if let Some(ttl) = self.config.optional.tx_proxy_queue_ttl() {
+            proxy_sink_layer = proxy_sink_layer
+                .with_retry_queue(ttl, self.config.optional.tx_proxy_queue_capacity);
+        }
+        self.node.add_layer(proxy_sink_layer);
         self.node.add_layer(tx_sender_layer);
         Ok(self)
     }
@@ -494,6 +538,9 @@ impl ExternalNodeBuilder {
             self.config.optional.mempool_cache_size,
             self.config.optional.mempool_cache_update_interval(),
         ));
+        self.node.add_layer(GasPerPubdataOracleLayer::new(
+            self.config.optional.gas_price_scale_factor,
+        ));
         Ok(self)
     }
 
@@ -526,6 +573,7 @@ impl ExternalNodeBuilder {
             batch_request_size_limit: Some(self.config.optional.max_batch_request_size),
             response_body_size_limit: Some(self.config.optional.max_response_body_size()),
             with_extended_tracing: self.config.optional.extended_rpc_tracing,
+            with_strict_quantity_validation: self.config.optional.strict_quantity_validation,
             pruning_info_refresh_interval: Some(pruning_info_refresh_interval),
             bridge_addresses_refresh_interval: self
                 .config
@@ -534,6 +582,10 @@ impl ExternalNodeBuilder {
             polling_interval: Some(self.config.optional.polling_interval()),
             websocket_requests_per_minute_limit: None, // To be set by WS server layer method if required.
             replication_lag_limit: None,               // TODO: Support replication lag limit
+            custom_namespaces: vec![],
+            snapshots_download_url_ttl: None,
+            snapshots_requests_per_minute_limit: None,
+            snapshots_rate_limit_proxy_hops: None,
         }
     }
 
@@ -550,7 +602,10 @@ impl ExternalNodeBuilder {
 
     fn add_ws_web3_api_layer(mut self) -> anyhow::Result<Self> {
         // TODO: Support websocket requests per minute limit
-        let optional_config = self.web3_api_optional_config();
+        let optional_config = Web3ServerOptionalConfig {
+            batch_request_size_limit: Some(self.config.optional.websocket_max_batch_request_size()),
+            ..self.web3_api_optional_config()
+        };
         self.node.add_layer(Web3ServerLayer::ws(
             self.config.required.ws_port,
             (&self.config).into(),
@@ -602,6 +657,19 @@ impl ExternalNodeBuilder {
                         .experimental
                         .snapshots_recovery_drop_storage_key_preimages,
                     object_store_config: config.optional.snapshots_recovery_object_store.clone(),
+                    max_concurrent_applications: config
+                        .experimental
+                        .snapshots_recovery_postgres_max_concurrent_applications,
+                    insertion_batch_size: config
+                        .experimental
+                        .snapshots_recovery_postgres_insertion_batch_size,
+                    max_download_bandwidth: config
+                        .experimental
+                        .snapshots_recovery_max_download_bandwidth,
+                    max_replication_lag: config
+                        .experimental
+                        .snapshots_recovery_postgres_max_replication_lag_sec
+                        .map(Duration::from_secs),
                 });
         self.node.add_layer(ExternalNodeInitStrategyLayer {
             l2_chain_id: self.config.required.l2_chain_id,
@@ -649,6 +717,7 @@ impl ExternalNodeBuilder {
         self = self
             .add_l1_batch_commitment_mode_validation_layer()?
             .add_validate_chain_ids_layer()?
+            .add_node_version_check_layer()?
             .add_storage_initialization_layer(LayerKind::Precondition)?;
 
         // Sort the components, so that the components they may depend on each other are added in the correct order.
@@ -668,6 +737,7 @@ impl ExternalNodeBuilder {
                         .add_tree_api_client_layer()?
                         .add_main_node_fee_params_fetcher_layer()?
                         .add_tx_sender_layer()?
+                        .add_settlement_layer_data_layer()?
                         .add_http_web3_api_layer()?;
                 }
                 Component::WsApi => {
@@ -677,6 +747,7 @@ impl ExternalNodeBuilder {
                         .add_tree_api_client_layer()?
                         .add_main_node_fee_params_fetcher_layer()?
                         .add_tx_sender_layer()?
+                        .add_settlement_layer_data_layer()?
                         .add_ws_web3_api_layer()?;
                 }
                 Component::Tree => {
@@ -711,6 +782,7 @@ impl ExternalNodeBuilder {
                         .add_state_keeper_layer()?
                         .add_consensus_layer()?
                         .add_pruning_layer()?
+                        .add_archive_mode_layer()?
                         .add_consistency_checker_layer()?
                         .add_commitment_generator_layer()?
                         .add_batch_status_updater_layer()?
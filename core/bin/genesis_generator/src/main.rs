@@ -2,13 +2,14 @@
 /// This tool generates the new correct genesis file that could be used for the new chain
 /// Please note, this tool update only yaml file, if you still use env based configuration,
 /// update env values correspondingly
-use std::fs;
+use std::{fmt, fs, path::PathBuf};
 
 use anyhow::Context as _;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
 use serde_yaml::Serializer;
 use zksync_config::{configs::DatabaseSecrets, GenesisConfig};
-use zksync_contracts::BaseSystemContracts;
+use zksync_contracts::{BaseSystemContracts, SystemContractsRepo};
 use zksync_core_leftovers::temp_config_store::read_yaml_repr;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_env_config::FromEnv;
@@ -23,14 +24,35 @@ use zksync_types::{
 };
 
 const DEFAULT_GENESIS_FILE_PATH: &str = "../etc/env/file_based/genesis.yaml";
+const DEFAULT_GENESIS_ENV_FILE_PATH: &str = "../etc/env/file_based/genesis.env";
 
 #[derive(Debug, Parser)]
 #[command(author = "Matter Labs", version, about = "Genesis config generator", long_about = None)]
 struct Cli {
     #[arg(long)]
-    config_path: Option<std::path::PathBuf>,
+    config_path: Option<PathBuf>,
+    /// Path to the system contracts repository (e.g., `contracts/system-contracts`) to load base system contracts
+    /// and other system contracts from. If not specified, contracts are loaded from the default location.
+    #[arg(long)]
+    contracts_path: Option<PathBuf>,
+    /// Additionally writes genesis values as an env fragment for env-based configuration. If the path is not
+    /// specified, the fragment is written next to the genesis YAML file.
+    #[arg(long, num_args = 0..=1, default_missing_value = DEFAULT_GENESIS_ENV_FILE_PATH, conflicts_with_all = ["check", "diff"])]
+    emit_env: Option<PathBuf>,
     #[arg(long, default_value = "false")]
     check: bool,
+    /// Prints the diff between the existing and the newly generated genesis config without updating the file.
+    #[arg(long, default_value = "false", conflicts_with = "check")]
+    diff: bool,
+    /// Output format of the diff.
+    #[arg(long, value_enum, default_value_t = DiffFormat::Human, requires = "diff")]
+    diff_format: DiffFormat,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DiffFormat {
+    Human,
+    Json,
 }
 
 #[tokio::main]
@@ -48,14 +70,28 @@ async fn main() -> anyhow::Result<()> {
 
     let original_genesis = read_yaml_repr::<Genesis>(&DEFAULT_GENESIS_FILE_PATH.into())?;
     let db_url = database_secrets.master_url()?;
-    let new_genesis = generate_new_config(db_url, original_genesis.clone()).await?;
+    let new_genesis =
+        generate_new_config(db_url, original_genesis.clone(), opt.contracts_path).await?;
     if opt.check {
         assert_eq!(&original_genesis, &new_genesis);
         println!("Genesis config is up to date");
         return Ok(());
     }
+    if opt.diff {
+        let diff = GenesisDiff::new(&original_genesis, &new_genesis);
+        match opt.diff_format {
+            DiffFormat::Human => print!("{diff}"),
+            DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+        }
+        return Ok(());
+    }
     let data = encode_yaml(&Genesis::build(&new_genesis))?;
     fs::write(DEFAULT_GENESIS_FILE_PATH, data)?;
+    if let Some(env_path) = &opt.emit_env {
+        fs::write(env_path, encode_env(&new_genesis))
+            .with_context(|| format!("failed writing env fragment to {env_path:?}"))?;
+        println!("Genesis env fragment written to {env_path:?}");
+    }
     println!("Genesis successfully generated");
     Ok(())
 }
@@ -63,6 +99,7 @@ async fn main() -> anyhow::Result<()> {
 async fn generate_new_config(
     db_url: SensitiveUrl,
     genesis_config: GenesisConfig,
+    contracts_path: Option<PathBuf>,
 ) -> anyhow::Result<GenesisConfig> {
     let pool = ConnectionPool::<Core>::singleton(db_url)
         .build()
@@ -76,7 +113,13 @@ async fn generate_new_config(
         anyhow::bail!("Please cleanup database for regenerating genesis")
     }
 
-    let base_system_contracts = BaseSystemContracts::load_from_disk().hashes();
+    let base_system_contracts = match &contracts_path {
+        Some(path) => {
+            BaseSystemContracts::load_from_repo(&SystemContractsRepo { root: path.clone() })
+        }
+        None => BaseSystemContracts::load_from_disk(),
+    }
+    .hashes();
     let mut updated_genesis = GenesisConfig {
         protocol_version: Some(ProtocolSemanticVersion {
             minor: ProtocolVersionId::latest(),
@@ -93,7 +136,10 @@ async fn generate_new_config(
 
     // This tool doesn't really insert the batch. It doesn't commit the transaction,
     // so the database is clean after using the tool
-    let params = GenesisParams::load_genesis_params(updated_genesis.clone())?;
+    let params = match contracts_path {
+        Some(path) => GenesisParams::load_genesis_params_from_dir(updated_genesis.clone(), path)?,
+        None => GenesisParams::load_genesis_params(updated_genesis.clone())?,
+    };
     let batch_params = insert_genesis_batch(&mut transaction, &params).await?;
 
     updated_genesis.genesis_commitment = Some(batch_params.commitment);
@@ -103,6 +149,141 @@ async fn generate_new_config(
     Ok(updated_genesis)
 }
 
+/// Change of a single genesis config field.
+#[derive(Debug, Serialize)]
+struct FieldDiff {
+    field: &'static str,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+/// Field-by-field diff between two genesis configs. Only changed fields are included.
+#[derive(Debug, Serialize)]
+struct GenesisDiff {
+    changes: Vec<FieldDiff>,
+}
+
+impl GenesisDiff {
+    fn new(old: &GenesisConfig, new: &GenesisConfig) -> Self {
+        let fields = [
+            (
+                "protocol_version",
+                old.protocol_version.map(|v| v.to_string()),
+                new.protocol_version.map(|v| v.to_string()),
+            ),
+            (
+                "genesis_root_hash",
+                old.genesis_root_hash.map(|h| format!("{h:?}")),
+                new.genesis_root_hash.map(|h| format!("{h:?}")),
+            ),
+            (
+                "rollup_last_leaf_index",
+                old.rollup_last_leaf_index.map(|i| i.to_string()),
+                new.rollup_last_leaf_index.map(|i| i.to_string()),
+            ),
+            (
+                "genesis_commitment",
+                old.genesis_commitment.map(|h| format!("{h:?}")),
+                new.genesis_commitment.map(|h| format!("{h:?}")),
+            ),
+            (
+                "bootloader_hash",
+                old.bootloader_hash.map(|h| format!("{h:?}")),
+                new.bootloader_hash.map(|h| format!("{h:?}")),
+            ),
+            (
+                "default_aa_hash",
+                old.default_aa_hash.map(|h| format!("{h:?}")),
+                new.default_aa_hash.map(|h| format!("{h:?}")),
+            ),
+            (
+                "evm_emulator_hash",
+                old.evm_emulator_hash.map(|h| format!("{h:?}")),
+                new.evm_emulator_hash.map(|h| format!("{h:?}")),
+            ),
+        ];
+        let changes = fields
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| FieldDiff { field, old, new })
+            .collect();
+        Self { changes }
+    }
+}
+
+impl fmt::Display for GenesisDiff {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(formatter, "Genesis config is up to date");
+        }
+        for change in &self.changes {
+            let old = change.old.as_deref().unwrap_or("<unset>");
+            let new = change.new.as_deref().unwrap_or("<unset>");
+            writeln!(formatter, "{}:\n  - {old}\n  + {new}", change.field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Encodes genesis config as env variables read by `GenesisConfig::from_env()`.
+fn encode_env(genesis: &GenesisConfig) -> String {
+    let mut vars = vec![];
+    if let Some(version) = genesis.protocol_version {
+        vars.push((
+            "CONTRACTS_GENESIS_PROTOCOL_VERSION",
+            (version.minor as u16).to_string(),
+        ));
+        vars.push((
+            "CONTRACTS_GENESIS_PROTOCOL_SEMANTIC_VERSION",
+            version.to_string(),
+        ));
+    }
+    if let Some(hash) = genesis.genesis_root_hash {
+        vars.push(("CONTRACTS_GENESIS_ROOT", format!("{hash:?}")));
+    }
+    if let Some(hash) = genesis.genesis_commitment {
+        vars.push(("CONTRACTS_GENESIS_BATCH_COMMITMENT", format!("{hash:?}")));
+    }
+    if let Some(index) = genesis.rollup_last_leaf_index {
+        vars.push(("CONTRACTS_GENESIS_ROLLUP_LEAF_INDEX", index.to_string()));
+    }
+    if let Some(hash) = genesis.bootloader_hash {
+        vars.push(("CHAIN_STATE_KEEPER_BOOTLOADER_HASH", format!("{hash:?}")));
+    }
+    if let Some(hash) = genesis.default_aa_hash {
+        vars.push(("CHAIN_STATE_KEEPER_DEFAULT_AA_HASH", format!("{hash:?}")));
+    }
+    if let Some(hash) = genesis.evm_emulator_hash {
+        vars.push(("CHAIN_STATE_KEEPER_EVM_EMULATOR_HASH", format!("{hash:?}")));
+    }
+    vars.push((
+        "CHAIN_STATE_KEEPER_FEE_ACCOUNT_ADDR",
+        format!("{:?}", genesis.fee_account),
+    ));
+    vars.push((
+        "CHAIN_STATE_KEEPER_L1_BATCH_COMMIT_DATA_GENERATOR_MODE",
+        genesis.l1_batch_commit_data_generator_mode.to_string(),
+    ));
+    vars.push((
+        "CHAIN_ETH_ZKSYNC_NETWORK_ID",
+        genesis.l2_chain_id.as_u64().to_string(),
+    ));
+    vars.push((
+        "CONTRACTS_SNARK_WRAPPER_VK_HASH",
+        format!("{:?}", genesis.snark_wrapper_vk_hash),
+    ));
+    if let Some(hash) = genesis.fflonk_snark_wrapper_vk_hash {
+        vars.push((
+            "CONTRACTS_FFLONK_SNARK_WRAPPER_VK_HASH",
+            format!("{hash:?}"),
+        ));
+    }
+
+    vars.into_iter()
+        .map(|(name, value)| format!("{name}={value}\n"))
+        .collect()
+}
+
 /// Encodes a generated proto message to json for arbitrary `ProtoFmt`.
 pub(crate) fn encode_yaml<T: ReflectMessage>(x: &T) -> anyhow::Result<String> {
     let mut serializer = Serializer::new(vec![]);
@@ -16,9 +16,12 @@ use zksync_types::{
     L1BatchNumber, L2BlockNumber,
 };
 
-use crate::metrics::{FactoryDepsStage, StorageChunkStage, METRICS};
 #[cfg(test)]
 use crate::tests::HandleEvent;
+use crate::{
+    metrics::{FactoryDepsStage, StorageChunkStage, METRICS},
+    parquet_export,
+};
 
 /// Encapsulates progress of creating a particular storage snapshot.
 #[derive(Debug)]
@@ -83,6 +86,7 @@ impl SnapshotCreator {
         progress: &SnapshotProgress,
         l2_block_number: L2BlockNumber,
         chunk_id: u64,
+        parquet_export: bool,
     ) -> anyhow::Result<()> {
         let chunk_count = progress.chunk_count;
         let l1_batch_number = progress.l1_batch_number;
@@ -117,6 +121,10 @@ impl SnapshotCreator {
                     "Loaded chunk {chunk_id} ({} logs) from Postgres in {latency:?}",
                     logs.len()
                 );
+                if parquet_export {
+                    self.export_storage_logs_chunk_to_parquet(l1_batch_number, chunk_id, &logs)
+                        .await?;
+                }
                 self.store_storage_logs_chunk(l1_batch_number, chunk_id, logs)
                     .await?
             }
@@ -133,6 +141,10 @@ impl SnapshotCreator {
                     "Loaded chunk {chunk_id} ({} logs) from Postgres in {latency:?}",
                     logs.len()
                 );
+                if parquet_export {
+                    self.export_storage_logs_chunk_to_parquet(l1_batch_number, chunk_id, &logs)
+                        .await?;
+                }
                 self.store_storage_logs_chunk(l1_batch_number, chunk_id, logs)
                     .await?
             }
@@ -157,6 +169,26 @@ impl SnapshotCreator {
         Ok(())
     }
 
+    async fn export_storage_logs_chunk_to_parquet<K: parquet_export::ExportedStorageKey>(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        chunk_id: u64,
+        logs: &[SnapshotStorageLog<K>],
+    ) -> anyhow::Result<()> {
+        let latency =
+            METRICS.storage_logs_processing_duration[&StorageChunkStage::SaveParquet].start();
+        parquet_export::export_storage_logs_chunk(
+            &*self.blob_store,
+            l1_batch_number,
+            chunk_id,
+            logs,
+        )
+        .await?;
+        let latency = latency.observe();
+        tracing::info!("Exported chunk {chunk_id} in Parquet format in {latency:?}");
+        Ok(())
+    }
+
     async fn store_storage_logs_chunk<K>(
         &self,
         l1_batch_number: L1BatchNumber,
@@ -190,6 +222,7 @@ impl SnapshotCreator {
         &self,
         l2_block_number: L2BlockNumber,
         l1_batch_number: L1BatchNumber,
+        parquet_export: bool,
     ) -> anyhow::Result<String> {
         let mut conn = self.connect_to_replica().await?;
 
@@ -204,6 +237,15 @@ impl SnapshotCreator {
         let latency = latency.observe();
         tracing::info!("Loaded {} factory deps in {latency:?}", factory_deps.len());
 
+        if parquet_export {
+            let latency =
+                METRICS.factory_deps_processing_duration[&FactoryDepsStage::SaveParquet].start();
+            parquet_export::export_factory_deps(&*self.blob_store, l1_batch_number, &factory_deps)
+                .await?;
+            let latency = latency.observe();
+            tracing::info!("Exported factory deps in Parquet format in {latency:?}");
+        }
+
         tracing::info!("Saving factory deps to GCS...");
         let latency =
             METRICS.factory_deps_processing_duration[&FactoryDepsStage::SaveToGcs].start();
@@ -379,7 +421,11 @@ impl SnapshotCreator {
 
         if progress.is_new_snapshot {
             let factory_deps_output_file = self
-                .process_factory_deps(last_l2_block_number_in_batch, progress.l1_batch_number)
+                .process_factory_deps(
+                    last_l2_block_number_in_batch,
+                    progress.l1_batch_number,
+                    config.parquet_export,
+                )
                 .await?;
 
             let mut master_conn = self
@@ -411,6 +457,7 @@ impl SnapshotCreator {
                     &progress,
                     last_l2_block_number_in_batch,
                     chunk_id,
+                    config.parquet_export,
                 )
             });
         futures::future::try_join_all(tasks).await?;
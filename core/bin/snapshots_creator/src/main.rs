@@ -22,6 +22,7 @@ use crate::creator::SnapshotCreator;
 
 mod creator;
 mod metrics;
+mod parquet_export;
 #[cfg(test)]
 mod tests;
 
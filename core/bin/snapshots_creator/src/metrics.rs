@@ -9,6 +9,7 @@ use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram,
 pub(crate) enum FactoryDepsStage {
     LoadFromPostgres,
     SaveToGcs,
+    SaveParquet,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
@@ -16,6 +17,7 @@ pub(crate) enum FactoryDepsStage {
 pub(crate) enum StorageChunkStage {
     LoadFromPostgres,
     SaveToGcs,
+    SaveParquet,
 }
 
 #[derive(Debug, Metrics)]
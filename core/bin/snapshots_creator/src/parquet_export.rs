@@ -0,0 +1,266 @@
+//! Export of snapshot data in the Apache Parquet format, intended for analytics consumers.
+//!
+//! Parquet files are chunked in the same way as Protobuf storage logs chunks and are stored in the same bucket,
+//! so that a consumer can process them in parallel without any custom conversions. Unlike Protobuf objects, Parquet files
+//! are not referenced from Postgres, so their keys have a distinct prefix; otherwise, they would be detected as orphaned
+//! snapshot blobs and removed by the house keeper.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::Context as _;
+use parquet::{
+    basic::Compression,
+    data_type::{ByteArray, ByteArrayType, Int64Type},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+use zksync_object_store::{Bucket, ObjectStore};
+use zksync_types::{snapshots::SnapshotStorageLog, Address, L1BatchNumber, StorageKey, H256};
+
+/// Prefix of all Parquet file keys. Must not start with the prefix of snapshot Protobuf objects.
+const KEY_PREFIX: &str = "parquet_snapshot_l1_batch_";
+
+const STORAGE_LOGS_SCHEMA: &str = "
+    message storage_logs {
+        required binary hashed_key;
+        optional binary address;
+        optional binary key;
+        required binary value;
+        required int64 l1_batch_number_of_initial_write;
+        required int64 enumeration_index;
+    }
+";
+
+const ACCOUNTS_SCHEMA: &str = "
+    message accounts {
+        required binary address;
+        required int64 storage_slots_count;
+    }
+";
+
+const FACTORY_DEPS_SCHEMA: &str = "
+    message factory_deps {
+        required binary bytecode_hash;
+        required binary bytecode;
+    }
+";
+
+pub(crate) fn storage_logs_key(l1_batch_number: L1BatchNumber, chunk_id: u64) -> String {
+    format!("{KEY_PREFIX}{l1_batch_number}_storage_logs_part_{chunk_id:0>4}.parquet")
+}
+
+pub(crate) fn accounts_key(l1_batch_number: L1BatchNumber, chunk_id: u64) -> String {
+    format!("{KEY_PREFIX}{l1_batch_number}_accounts_part_{chunk_id:0>4}.parquet")
+}
+
+pub(crate) fn factory_deps_key(l1_batch_number: L1BatchNumber) -> String {
+    format!("{KEY_PREFIX}{l1_batch_number}_factory_deps.parquet")
+}
+
+/// Storage log key that can be exported to Parquet.
+pub(crate) trait ExportedStorageKey {
+    /// Whether key preimages are available for this key type.
+    const HAS_PREIMAGE: bool;
+
+    fn hashed_key(&self) -> H256;
+
+    /// Returns the key preimage (address and contract slot), if it's known.
+    fn preimage(&self) -> Option<(Address, H256)>;
+}
+
+impl ExportedStorageKey for H256 {
+    const HAS_PREIMAGE: bool = false;
+
+    fn hashed_key(&self) -> H256 {
+        *self
+    }
+
+    fn preimage(&self) -> Option<(Address, H256)> {
+        None
+    }
+}
+
+impl ExportedStorageKey for StorageKey {
+    const HAS_PREIMAGE: bool = true;
+
+    fn hashed_key(&self) -> H256 {
+        StorageKey::hashed_key(self)
+    }
+
+    fn preimage(&self) -> Option<(Address, H256)> {
+        Some((*self.address(), *self.key()))
+    }
+}
+
+#[derive(Debug)]
+enum Column {
+    Bytes(Vec<ByteArray>),
+    OptionalBytes(Vec<Option<ByteArray>>),
+    Int64(Vec<i64>),
+}
+
+fn bytes(bytes: &[u8]) -> ByteArray {
+    ByteArray::from(bytes.to_vec())
+}
+
+/// Serializes columns as a single row group. Columns must be provided in the order of the schema.
+fn serialize(schema: &str, columns: Vec<Column>) -> anyhow::Result<Vec<u8>> {
+    let schema = Arc::new(parse_message_type(schema).context("invalid Parquet schema")?);
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut buffer = vec![];
+    let mut writer = SerializedFileWriter::new(&mut buffer, schema, Arc::new(properties))?;
+    let mut row_group = writer.next_row_group()?;
+    for column in columns {
+        let mut column_writer = row_group
+            .next_column()?
+            .context("more columns than declared in the schema")?;
+        match column {
+            Column::Bytes(values) => {
+                column_writer
+                    .typed::<ByteArrayType>()
+                    .write_batch(&values, None, None)?;
+            }
+            Column::OptionalBytes(values) => {
+                let definition_levels: Vec<_> = values
+                    .iter()
+                    .map(|value| i16::from(value.is_some()))
+                    .collect();
+                let values: Vec<_> = values.into_iter().flatten().collect();
+                column_writer.typed::<ByteArrayType>().write_batch(
+                    &values,
+                    Some(&definition_levels),
+                    None,
+                )?;
+            }
+            Column::Int64(values) => {
+                column_writer
+                    .typed::<Int64Type>()
+                    .write_batch(&values, None, None)?;
+            }
+        }
+        column_writer.close()?;
+    }
+    row_group.close()?;
+    writer.close()?;
+    Ok(buffer)
+}
+
+fn serialize_storage_logs<K: ExportedStorageKey>(
+    logs: &[SnapshotStorageLog<K>],
+) -> anyhow::Result<Vec<u8>> {
+    let preimages: Vec<_> = logs.iter().map(|log| log.key.preimage()).collect();
+    let columns = vec![
+        Column::Bytes(
+            logs.iter()
+                .map(|log| bytes(log.key.hashed_key().as_bytes()))
+                .collect(),
+        ),
+        Column::OptionalBytes(
+            preimages
+                .iter()
+                .map(|preimage| preimage.map(|(address, _)| bytes(address.as_bytes())))
+                .collect(),
+        ),
+        Column::OptionalBytes(
+            preimages
+                .iter()
+                .map(|preimage| preimage.map(|(_, key)| bytes(key.as_bytes())))
+                .collect(),
+        ),
+        Column::Bytes(logs.iter().map(|log| bytes(log.value.as_bytes())).collect()),
+        Column::Int64(
+            logs.iter()
+                .map(|log| log.l1_batch_number_of_initial_write.0.into())
+                .collect(),
+        ),
+        Column::Int64(
+            logs.iter()
+                .map(|log| log.enumeration_index as i64)
+                .collect(),
+        ),
+    ];
+    serialize(STORAGE_LOGS_SCHEMA, columns)
+}
+
+/// Returns `Ok(None)` if key preimages are not available for the logs.
+fn serialize_accounts<K: ExportedStorageKey>(
+    logs: &[SnapshotStorageLog<K>],
+) -> anyhow::Result<Option<Vec<u8>>> {
+    if !K::HAS_PREIMAGE {
+        return Ok(None);
+    }
+
+    let mut slots_by_account = BTreeMap::<Address, i64>::new();
+    for (address, _) in logs.iter().filter_map(|log| log.key.preimage()) {
+        *slots_by_account.entry(address).or_default() += 1;
+    }
+
+    let columns = vec![
+        Column::Bytes(
+            slots_by_account
+                .keys()
+                .map(|address| bytes(address.as_bytes()))
+                .collect(),
+        ),
+        Column::Int64(slots_by_account.into_values().collect()),
+    ];
+    serialize(ACCOUNTS_SCHEMA, columns).map(Some)
+}
+
+/// Exports a storage logs chunk and, if key preimages are available, accounts touched by the chunk.
+pub(crate) async fn export_storage_logs_chunk<K: ExportedStorageKey>(
+    blob_store: &dyn ObjectStore,
+    l1_batch_number: L1BatchNumber,
+    chunk_id: u64,
+    logs: &[SnapshotStorageLog<K>],
+) -> anyhow::Result<()> {
+    let serialized_logs =
+        serialize_storage_logs(logs).context("failed serializing storage logs")?;
+    let key = storage_logs_key(l1_batch_number, chunk_id);
+    blob_store
+        .put_raw(Bucket::StorageSnapshot, &key, serialized_logs)
+        .await
+        .context("failed storing storage logs in Parquet format")?;
+
+    if let Some(serialized_accounts) =
+        serialize_accounts(logs).context("failed serializing accounts")?
+    {
+        let key = accounts_key(l1_batch_number, chunk_id);
+        blob_store
+            .put_raw(Bucket::StorageSnapshot, &key, serialized_accounts)
+            .await
+            .context("failed storing accounts in Parquet format")?;
+    }
+    Ok(())
+}
+
+pub(crate) async fn export_factory_deps(
+    blob_store: &dyn ObjectStore,
+    l1_batch_number: L1BatchNumber,
+    factory_deps: &[(H256, Vec<u8>)],
+) -> anyhow::Result<()> {
+    let columns = vec![
+        Column::Bytes(
+            factory_deps
+                .iter()
+                .map(|(hash, _)| bytes(hash.as_bytes()))
+                .collect(),
+        ),
+        Column::Bytes(
+            factory_deps
+                .iter()
+                .map(|(_, bytecode)| bytes(bytecode))
+                .collect(),
+        ),
+    ];
+    let serialized =
+        serialize(FACTORY_DEPS_SCHEMA, columns).context("failed serializing factory deps")?;
+    let key = factory_deps_key(l1_batch_number);
+    blob_store
+        .put_raw(Bucket::StorageSnapshot, &key, serialized)
+        .await
+        .context("failed storing factory deps in Parquet format")?;
+    Ok(())
+}
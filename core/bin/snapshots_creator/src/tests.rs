@@ -7,13 +7,21 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    time::Duration,
 };
 
+use parquet::{
+    file::reader::{FileReader, SerializedFileReader},
+    record::{Row, RowAccessor},
+};
 use rand::{thread_rng, Rng};
 use test_casing::test_casing;
 use zksync_config::SnapshotsCreatorConfig;
 use zksync_dal::{Connection, CoreDal};
-use zksync_object_store::{MockObjectStore, ObjectStore};
+use zksync_house_keeper::{
+    orphaned_blobs_detector::OrphanedBlobsDetector, periodic_job::PeriodicJob,
+};
+use zksync_object_store::{Bucket, MockObjectStore, ObjectStore};
 use zksync_types::{
     block::{L1BatchHeader, L1BatchTreeData, L2BlockHeader},
     snapshots::{
@@ -31,6 +39,7 @@ const TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     l1_batch_number: None,
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 10,
+    parquet_export: false,
     object_store: None,
 };
 const SEQUENTIAL_TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
@@ -449,6 +458,131 @@ async fn persisting_snapshot_logs_for_v0_snapshot() {
     assert_eq!(actual_logs, expected_outputs.storage_logs);
 }
 
+fn read_parquet_rows(bytes: Vec<u8>) -> Vec<Row> {
+    let reader = SerializedFileReader::new(bytes::Bytes::from(bytes)).unwrap();
+    reader
+        .get_row_iter(None)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[test_casing(2, [0, 1])]
+#[tokio::test]
+async fn exporting_snapshot_to_parquet(version: u16) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    let expected_outputs = prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let config = SnapshotsCreatorConfig {
+        version,
+        parquet_export: true,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    // Protobuf outputs must be unaffected.
+    assert_storage_logs(&*object_store, snapshot_l1_batch_number, &expected_outputs).await;
+
+    let mut actual_logs = HashSet::new();
+    let mut account_slots_count = 0;
+    for chunk_id in 0..MIN_CHUNK_COUNT {
+        let key = parquet_export::storage_logs_key(snapshot_l1_batch_number, chunk_id);
+        let logs = object_store
+            .get_raw(Bucket::StorageSnapshot, &key)
+            .await
+            .unwrap();
+        for row in read_parquet_rows(logs) {
+            // Key preimages are only present in version 0 snapshots
+            assert_eq!(row.get_bytes(1).is_ok(), version == 0);
+            assert_eq!(row.get_bytes(2).is_ok(), version == 0);
+            actual_logs.insert(SnapshotStorageLog {
+                key: H256::from_slice(row.get_bytes(0).unwrap().data()),
+                value: H256::from_slice(row.get_bytes(3).unwrap().data()),
+                l1_batch_number_of_initial_write: L1BatchNumber(row.get_long(4).unwrap() as u32),
+                enumeration_index: row.get_long(5).unwrap() as u64,
+            });
+        }
+
+        let key = parquet_export::accounts_key(snapshot_l1_batch_number, chunk_id);
+        let accounts = object_store.get_raw(Bucket::StorageSnapshot, &key).await;
+        if version == 0 {
+            for row in read_parquet_rows(accounts.unwrap()) {
+                account_slots_count += row.get_long(1).unwrap();
+            }
+        } else {
+            accounts.unwrap_err();
+        }
+    }
+    assert_eq!(actual_logs, expected_outputs.storage_logs);
+    if version == 0 {
+        assert_eq!(
+            account_slots_count as usize,
+            expected_outputs.storage_logs.len()
+        );
+    }
+
+    let key = parquet_export::factory_deps_key(snapshot_l1_batch_number);
+    let factory_deps = object_store
+        .get_raw(Bucket::StorageSnapshot, &key)
+        .await
+        .unwrap();
+    let actual_deps: HashSet<_> = read_parquet_rows(factory_deps)
+        .into_iter()
+        .map(|row| SnapshotFactoryDependency {
+            bytecode: row.get_bytes(1).unwrap().data().to_vec().into(),
+        })
+        .collect();
+    assert_eq!(actual_deps, expected_outputs.deps);
+}
+
+#[tokio::test]
+async fn parquet_export_is_not_removed_as_orphaned() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    prepare_postgres(&mut rng, &mut conn, 10).await;
+
+    let config = SnapshotsCreatorConfig {
+        version: 0,
+        parquet_export: true,
+        ..TEST_CONFIG
+    };
+    SnapshotCreator::for_tests(object_store.clone(), pool.clone())
+        .run(config, MIN_CHUNK_COUNT)
+        .await
+        .unwrap();
+
+    let mut detector =
+        OrphanedBlobsDetector::new(1_000, Some(Duration::ZERO), pool, object_store.clone());
+    detector.run_routine_task().await.unwrap();
+
+    let snapshot_l1_batch_number = L1BatchNumber(8);
+    let mut keys = vec![parquet_export::factory_deps_key(snapshot_l1_batch_number)];
+    for chunk_id in 0..MIN_CHUNK_COUNT {
+        keys.push(parquet_export::storage_logs_key(
+            snapshot_l1_batch_number,
+            chunk_id,
+        ));
+        keys.push(parquet_export::accounts_key(
+            snapshot_l1_batch_number,
+            chunk_id,
+        ));
+    }
+    for key in keys {
+        object_store
+            .get_raw(Bucket::StorageSnapshot, &key)
+            .await
+            .unwrap_or_else(|err| panic!("Parquet file `{key}` was removed: {err}"));
+    }
+}
+
 #[test_casing(2, [false, true])]
 #[tokio::test]
 async fn recovery_workflow(specify_batch_after_recovery: bool) {
@@ -0,0 +1,310 @@
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::Context as _;
+use clap::Parser;
+use serde_json::json;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_merkle_tree::{MerkleTree, PatchSet, TreeEntry};
+use zksync_system_constants::ACCOUNT_CODE_STORAGE_ADDRESS;
+use zksync_types::{
+    get_code_key, get_nonce_key, h256_to_address, h256_to_u256,
+    snapshots::{uniform_hashed_keys_chunk, SnapshotStorageLog},
+    url::SensitiveUrl,
+    utils::{decompose_full_nonce, storage_key_for_eth_balance},
+    Address, L1BatchNumber, L2BlockNumber, StorageKey, H256, U256,
+};
+
+const STORAGE_FILE: &str = "storage.csv";
+const ACCOUNTS_FILE: &str = "accounts.csv";
+const METADATA_FILE: &str = "metadata.json";
+/// Number of accounts for which storage values are queried at once.
+const ACCOUNTS_BATCH_SIZE: usize = 1_000;
+
+#[derive(Debug, Parser)]
+#[command(name = "State export tool", author = "Matter Labs")]
+struct Args {
+    /// PostgreSQL connection string for the database to export.
+    #[arg(short, long)]
+    database_url: Option<String>,
+
+    /// L1 batch to export the state at. The batch must be processed by the Merkle tree.
+    #[arg(short, long)]
+    l1_batch: u32,
+
+    /// Directory to write the exported files to.
+    #[arg(short, long, default_value = "state_export")]
+    output_dir: PathBuf,
+
+    /// Number of chunks to split the storage into when reading it from Postgres.
+    #[arg(long, default_value_t = 256)]
+    chunk_count: u64,
+
+    /// Do not export storage key preimages (addresses and slots). Required for nodes recovered from snapshots,
+    /// which do not store preimages.
+    #[arg(long)]
+    hashed_keys_only: bool,
+
+    /// Recompute the Merkle tree root hash from the exported storage and compare it with the one stored in Postgres.
+    #[arg(long)]
+    verify_root_hash: bool,
+}
+
+/// Storage log exported to `storage.csv`, with an optional key preimage.
+#[derive(Debug)]
+struct ExportedLog {
+    hashed_key: H256,
+    preimage: Option<StorageKey>,
+    value: H256,
+    enumeration_index: u64,
+    l1_batch_number_of_initial_write: L1BatchNumber,
+}
+
+impl From<SnapshotStorageLog> for ExportedLog {
+    fn from(log: SnapshotStorageLog) -> Self {
+        Self {
+            hashed_key: log.key,
+            preimage: None,
+            value: log.value,
+            enumeration_index: log.enumeration_index,
+            l1_batch_number_of_initial_write: log.l1_batch_number_of_initial_write,
+        }
+    }
+}
+
+impl From<SnapshotStorageLog<StorageKey>> for ExportedLog {
+    fn from(log: SnapshotStorageLog<StorageKey>) -> Self {
+        Self {
+            hashed_key: log.key.hashed_key(),
+            preimage: Some(log.key),
+            value: log.value,
+            enumeration_index: log.enumeration_index,
+            l1_batch_number_of_initial_write: log.l1_batch_number_of_initial_write,
+        }
+    }
+}
+
+impl ExportedLog {
+    const CSV_HEADER: &'static str =
+        "hashed_key,address,key,value,enumeration_index,l1_batch_number_of_initial_write";
+
+    fn write_csv_row(&self, out: &mut impl Write) -> anyhow::Result<()> {
+        let (address, key) = match &self.preimage {
+            Some(key) => (format!("{:?}", key.address()), format!("{:?}", key.key())),
+            None => (String::new(), String::new()),
+        };
+        writeln!(
+            out,
+            "{:?},{address},{key},{:?},{},{}",
+            self.hashed_key,
+            self.value,
+            self.enumeration_index,
+            self.l1_batch_number_of_initial_write.0
+        )?;
+        Ok(())
+    }
+
+    fn tree_entry(&self) -> TreeEntry {
+        let key = U256::from_little_endian(self.hashed_key.as_bytes());
+        TreeEntry::new(key, self.enumeration_index, self.value)
+    }
+}
+
+/// Exports storage logs at the specified L2 block into `storage.csv`. Returns the number of exported logs.
+async fn export_storage(
+    storage: &mut Connection<'_, Core>,
+    args: &Args,
+    l2_block: L2BlockNumber,
+    contracts: &mut BTreeSet<Address>,
+    mut tree: Option<&mut MerkleTree<PatchSet>>,
+) -> anyhow::Result<u64> {
+    let l1_batch = L1BatchNumber(args.l1_batch);
+    let mut out = BufWriter::new(File::create(args.output_dir.join(STORAGE_FILE))?);
+    writeln!(out, "{}", ExportedLog::CSV_HEADER)?;
+
+    let mut exported_count = 0;
+    for chunk_id in 0..args.chunk_count {
+        let hashed_keys_range = uniform_hashed_keys_chunk(chunk_id, args.chunk_count);
+        let logs: Vec<ExportedLog> = if args.hashed_keys_only {
+            storage
+                .snapshots_creator_dal()
+                .get_storage_logs_chunk(l2_block, l1_batch, hashed_keys_range)
+                .await?
+                .into_iter()
+                .map(ExportedLog::from)
+                .collect()
+        } else {
+            // Fails on nodes recovered from snapshots; `--hashed-keys-only` should be used for them
+            #[allow(deprecated)]
+            let logs = storage
+                .snapshots_creator_dal()
+                .get_storage_logs_chunk_with_key_preimages(l2_block, l1_batch, hashed_keys_range)
+                .await?;
+            logs.into_iter().map(ExportedLog::from).collect()
+        };
+
+        for log in &logs {
+            log.write_csv_row(&mut out)?;
+            if let Some(key) = &log.preimage {
+                if *key.address() == ACCOUNT_CODE_STORAGE_ADDRESS && !log.value.is_zero() {
+                    contracts.insert(h256_to_address(key.key()));
+                }
+            }
+        }
+        if let Some(tree) = tree.as_deref_mut() {
+            tree.extend(logs.iter().map(ExportedLog::tree_entry).collect())?;
+        }
+
+        exported_count += logs.len() as u64;
+        println!(
+            "Exported storage chunk {}/{} ({exported_count} logs in total)",
+            chunk_id + 1,
+            args.chunk_count
+        );
+    }
+    out.flush()?;
+    Ok(exported_count)
+}
+
+/// Exports nonces, balances and bytecode hashes of `accounts` at the specified L2 block into `accounts.csv`.
+async fn export_accounts(
+    storage: &mut Connection<'_, Core>,
+    output_dir: &Path,
+    l2_block: L2BlockNumber,
+    accounts: &BTreeSet<Address>,
+) -> anyhow::Result<()> {
+    let mut out = BufWriter::new(File::create(output_dir.join(ACCOUNTS_FILE))?);
+    writeln!(out, "address,nonce,deployment_nonce,balance,bytecode_hash")?;
+
+    let accounts: Vec<_> = accounts.iter().copied().collect();
+    for chunk in accounts.chunks(ACCOUNTS_BATCH_SIZE) {
+        let hashed_keys: Vec<_> = chunk
+            .iter()
+            .flat_map(|address| {
+                [
+                    get_nonce_key(address).hashed_key(),
+                    storage_key_for_eth_balance(address).hashed_key(),
+                    get_code_key(address).hashed_key(),
+                ]
+            })
+            .collect();
+        let values = storage
+            .storage_logs_dal()
+            .get_storage_values(&hashed_keys, l2_block)
+            .await?;
+        let value = |key: H256| values.get(&key).copied().flatten().unwrap_or_default();
+
+        for (address, keys) in chunk.iter().zip(hashed_keys.chunks(3)) {
+            let (nonce, deployment_nonce) = decompose_full_nonce(h256_to_u256(value(keys[0])));
+            let balance = h256_to_u256(value(keys[1]));
+            let bytecode_hash = value(keys[2]);
+            writeln!(
+                out,
+                "{address:?},{nonce},{deployment_nonce},{balance},{bytecode_hash:?}"
+            )?;
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// The `state_exporter` tool exports the full account and storage state at the specified L1 batch
+/// from the ZKsync PostgreSQL database into CSV files, so that the state can be audited or analyzed
+/// without direct access to the database.
+///
+/// Outputs (in `output_dir`):
+///     * `storage.csv` - all storage slots with their hashed keys, preimages (unless `--hashed-keys-only` is specified),
+///       values, enumeration indices and initial write batches, ordered by hashed key.
+///     * `accounts.csv` - nonces, base token balances and bytecode hashes of deployed contracts and transaction
+///       initiators, ordered by address. Deployed contracts are only discovered if key preimages are exported.
+///     * `metadata.json` - the exported L1 batch and L2 block, the state root hash and row counts.
+///
+/// The state root hash can be used to verify the exported storage: the Merkle tree built from `storage.csv`
+/// must have the same root hash. With `--verify-root-hash`, the tool performs this check itself.
+///
+/// Note: Accounts that never initiated a transaction and have no deployed code (e.g., addresses that only
+/// received base tokens) are not present in `accounts.csv`, since they cannot be discovered from hashed storage keys.
+/// Their balances are still exported in `storage.csv`. Root hash verification requires keeping the entire tree
+/// in RAM.
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let l1_batch = L1BatchNumber(args.l1_batch);
+
+    fs::create_dir_all(&args.output_dir)?;
+    println!(
+        "Export directory: {}",
+        args.output_dir.canonicalize()?.display()
+    );
+
+    println!("Connecting to source database...");
+    let db_url = args.database_url.clone().or_else(|| std::env::var("DATABASE_URL").ok()).expect("Specify the database connection string in either a CLI argument or in the DATABASE_URL environment variable.");
+    // we need only 1 DB connection at most for data export
+    let connection_pool = ConnectionPool::<Core>::builder(SensitiveUrl::from_str(&db_url)?, 1)
+        .build()
+        .await?;
+    let mut storage = connection_pool.connection().await?;
+    println!("Connected to source database.");
+
+    let root_hash = storage
+        .blocks_dal()
+        .get_l1_batch_state_root(l1_batch)
+        .await?
+        .with_context(|| format!("L1 batch #{l1_batch} is not processed by the Merkle tree"))?;
+    let (_, l2_block) = storage
+        .blocks_dal()
+        .get_l2_block_range_of_l1_batch(l1_batch)
+        .await?
+        .with_context(|| format!("L1 batch #{l1_batch} has no L2 blocks"))?;
+    println!(
+        "Exporting state at L1 batch #{l1_batch} (L2 block #{l2_block}), root hash {root_hash:?}"
+    );
+
+    let mut tree = if args.verify_root_hash {
+        Some(MerkleTree::new(PatchSet::default())?)
+    } else {
+        None
+    };
+    let mut accounts = BTreeSet::new();
+    let storage_count =
+        export_storage(&mut storage, &args, l2_block, &mut accounts, tree.as_mut()).await?;
+
+    if let Some(tree) = &tree {
+        let computed_root_hash = tree.latest_root_hash();
+        anyhow::ensure!(
+            computed_root_hash == root_hash,
+            "Root hash mismatch: computed {computed_root_hash:?}, expected {root_hash:?}"
+        );
+        println!("Root hash verified.");
+    }
+
+    let initiators = storage
+        .transactions_dal()
+        .get_initiator_addresses(l2_block)
+        .await?;
+    accounts.extend(initiators);
+    export_accounts(&mut storage, &args.output_dir, l2_block, &accounts).await?;
+    println!("Exported {} accounts", accounts.len());
+
+    let metadata = json!({
+        "l1_batch_number": l1_batch.0,
+        "l2_block_number": l2_block.0,
+        "root_hash": root_hash,
+        "root_hash_verified": tree.is_some(),
+        "includes_key_preimages": !args.hashed_keys_only,
+        "storage_logs_count": storage_count,
+        "accounts_count": accounts.len(),
+    });
+    fs::write(
+        args.output_dir.join(METADATA_FILE),
+        serde_json::to_string_pretty(&metadata)?,
+    )?;
+
+    println!("Done.");
+    Ok(())
+}
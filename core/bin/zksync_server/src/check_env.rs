@@ -0,0 +1,296 @@
+//! Preflight checks of the node environment run by the `check-env` command.
+
+use std::{
+    fmt,
+    future::Future,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use clap::Args;
+use zksync_config::{
+    configs::{GatewayChainConfig, GeneralConfig, Secrets},
+    ContractsConfig, GenesisConfig,
+};
+use zksync_contracts::hyperchain_contract;
+use zksync_dal::{system_dal::latest_embedded_migration, ConnectionPool, Core, CoreDal};
+use zksync_eth_client::{
+    clients::{Client, L1},
+    CallFunctionArgs, EthInterface,
+};
+use zksync_object_store::{Bucket, ObjectStoreError, ObjectStoreFactory};
+use zksync_types::{url::SensitiveUrl, Address, SLChainId, U256};
+
+/// Timeout for each individual check.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+/// Object store key read by the object store check. The object is not expected to exist.
+const PROBE_KEY: &str = "check_env_probe";
+
+/// Arguments of the `check-env` command.
+#[derive(Debug, Args)]
+pub(crate) struct CheckEnvArgs {
+    /// Minimum free disk space (in GiB) required on volumes hosting RocksDB caches.
+    #[arg(long, default_value_t = 10)]
+    min_free_disk_space_gb: u64,
+}
+
+#[derive(Debug)]
+enum CheckOutcome {
+    Passed(String),
+    Skipped(String),
+    Failed(anyhow::Error),
+}
+
+impl fmt::Display for CheckOutcome {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed(details) => write!(formatter, "OK      {details}"),
+            Self::Skipped(reason) => write!(formatter, "SKIPPED {reason}"),
+            Self::Failed(err) => write!(formatter, "FAILED  {err:#}"),
+        }
+    }
+}
+
+/// Outcome of a single check. `Ok(None)` means that the check was skipped because the corresponding
+/// component is not configured.
+type CheckResult = anyhow::Result<Option<String>>;
+
+/// Validates the node environment without starting any components.
+#[derive(Debug)]
+pub(crate) struct EnvChecker {
+    pub general: GeneralConfig,
+    pub secrets: Secrets,
+    pub genesis: GenesisConfig,
+    pub contracts: ContractsConfig,
+    pub gateway_contracts: Option<GatewayChainConfig>,
+}
+
+impl EnvChecker {
+    /// Runs all checks, prints the report to stdout and returns an error if any of the checks failed.
+    pub fn run(self, args: CheckEnvArgs) -> anyhow::Result<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .context("failed creating Tokio runtime")?;
+        let report = runtime.block_on(self.run_checks(&args));
+
+        let name_width = report.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, outcome) in &report {
+            println!("{name:name_width$}  {outcome}");
+        }
+        let failed_count = report
+            .iter()
+            .filter(|(_, outcome)| matches!(outcome, CheckOutcome::Failed(_)))
+            .count();
+        anyhow::ensure!(
+            failed_count == 0,
+            "{failed_count} out of {} environment checks failed",
+            report.len()
+        );
+        Ok(())
+    }
+
+    async fn run_checks(&self, args: &CheckEnvArgs) -> Vec<(&'static str, CheckOutcome)> {
+        let l1_chain_id = SLChainId::from(self.genesis.l1_chain_id);
+        let l1_diamond_proxy = Some(self.contracts.diamond_proxy_addr);
+        let gateway = self.gateway_contracts.as_ref();
+        let gateway_rpc_url = self
+            .secrets
+            .l1
+            .as_ref()
+            .and_then(|l1| l1.gateway_rpc_url.clone());
+
+        vec![
+            ("database", Self::check(self.check_database()).await),
+            ("object store", Self::check(self.check_object_store()).await),
+            (
+                "L1 RPC",
+                Self::check(Self::check_settlement_layer(
+                    self.secrets.l1.as_ref().map(|l1| l1.l1_rpc_url.clone()),
+                    Some(l1_chain_id),
+                    l1_diamond_proxy,
+                ))
+                .await,
+            ),
+            (
+                "Gateway RPC",
+                Self::check(Self::check_settlement_layer(
+                    gateway_rpc_url,
+                    gateway.map(|config| config.gateway_chain_id),
+                    gateway.map(|config| config.diamond_proxy_addr),
+                ))
+                .await,
+            ),
+            (
+                "contract addresses",
+                Self::check(self.check_contract_addresses()).await,
+            ),
+            (
+                "disk space",
+                Self::check(self.check_disk_space(args.min_free_disk_space_gb)).await,
+            ),
+        ]
+    }
+
+    async fn check(check: impl Future<Output = CheckResult>) -> CheckOutcome {
+        match tokio::time::timeout(CHECK_TIMEOUT, check).await {
+            Ok(Ok(Some(details))) => CheckOutcome::Passed(details),
+            Ok(Ok(None)) => CheckOutcome::Skipped("not configured".to_owned()),
+            Ok(Err(err)) => CheckOutcome::Failed(err),
+            Err(_) => CheckOutcome::Failed(anyhow::anyhow!("timed out after {CHECK_TIMEOUT:?}")),
+        }
+    }
+
+    async fn check_database(&self) -> CheckResult {
+        let Some(database) = &self.secrets.database else {
+            return Ok(None);
+        };
+        let pool = ConnectionPool::<Core>::singleton(database.master_url()?)
+            .build()
+            .await
+            .context("failed building connection pool")?;
+        let mut conn = pool.connection().await?;
+        let migration = conn.system_dal().get_last_migration().await?;
+        anyhow::ensure!(
+            migration.success,
+            "last migration {} ({}) has not completed successfully",
+            migration.version,
+            migration.description
+        );
+        let (expected_version, expected_description) =
+            latest_embedded_migration().context("no migrations are embedded into the binary")?;
+        anyhow::ensure!(
+            migration.version == expected_version,
+            "database is at migration {} ({}), but the server expects migration {expected_version} ({expected_description})",
+            migration.version,
+            migration.description
+        );
+        Ok(Some(format!(
+            "last migration: {} ({})",
+            migration.version, migration.description
+        )))
+    }
+
+    async fn check_object_store(&self) -> CheckResult {
+        let Some(config) = &self.general.core_object_store else {
+            return Ok(None);
+        };
+        let store = ObjectStoreFactory::new(config.clone())
+            .create_store()
+            .await?;
+        // The check is read-only so that it doesn't modify buckets used in production. Reading a non-existing key
+        // checks that the store is reachable and that credentials allow reading objects.
+        match store.get_raw(Bucket::WitnessInput, PROBE_KEY).await {
+            Ok(_) | Err(ObjectStoreError::KeyNotFound(_)) => {}
+            Err(err) => return Err(anyhow::Error::new(err).context("failed reading probe object")),
+        }
+        Ok(Some("probe object read".to_owned()))
+    }
+
+    /// Checks that the settlement layer RPC is reachable, has the expected chain ID and that the diamond proxy
+    /// is deployed on it.
+    async fn check_settlement_layer(
+        rpc_url: Option<SensitiveUrl>,
+        expected_chain_id: Option<SLChainId>,
+        diamond_proxy_addr: Option<Address>,
+    ) -> CheckResult {
+        let Some(rpc_url) = rpc_url else {
+            return Ok(None);
+        };
+        let mut builder = Client::<L1>::http(rpc_url).context("Client::new()")?;
+        if let Some(chain_id) = expected_chain_id {
+            builder = builder.for_network(chain_id.into());
+        }
+        let client = builder.report_config(false).build();
+
+        let chain_id = client.fetch_chain_id().await?;
+        if let Some(expected_chain_id) = expected_chain_id {
+            anyhow::ensure!(
+                chain_id == expected_chain_id,
+                "chain ID mismatch: configured {expected_chain_id}, RPC returned {chain_id}"
+            );
+        }
+        let block_number = client.block_number().await?;
+        let mut details = format!("chain ID {chain_id}, latest block #{block_number}");
+
+        if let Some(address) = diamond_proxy_addr {
+            let version: U256 = CallFunctionArgs::new("getProtocolVersion", ())
+                .for_contract(address, &hyperchain_contract())
+                .call(&client)
+                .await
+                .with_context(|| format!("failed querying diamond proxy at {address:?}"))?;
+            details += &format!(", diamond proxy protocol version {version}");
+        }
+        Ok(Some(details))
+    }
+
+    async fn check_contract_addresses(&self) -> CheckResult {
+        let mut addresses = vec![
+            ("diamond_proxy_addr", self.contracts.diamond_proxy_addr),
+            (
+                "validator_timelock_addr",
+                self.contracts.validator_timelock_addr,
+            ),
+            ("verifier_addr", self.contracts.verifier_addr),
+            ("governance_addr", self.contracts.governance_addr),
+        ];
+        if let Some(ecosystem) = &self.contracts.ecosystem_contracts {
+            addresses.push(("bridgehub_proxy_addr", ecosystem.bridgehub_proxy_addr));
+            addresses.push((
+                "state_transition_proxy_addr",
+                ecosystem.state_transition_proxy_addr,
+            ));
+        }
+
+        let zero_addresses: Vec<_> = addresses
+            .iter()
+            .filter(|(_, address)| address.is_zero())
+            .map(|(name, _)| *name)
+            .collect();
+        anyhow::ensure!(
+            zero_addresses.is_empty(),
+            "zero contract addresses: {}",
+            zero_addresses.join(", ")
+        );
+        Ok(Some(format!("{} addresses are set", addresses.len())))
+    }
+
+    async fn check_disk_space(&self, min_free_space_gb: u64) -> CheckResult {
+        let Some(db_config) = &self.general.db_config else {
+            return Ok(None);
+        };
+        let paths = [
+            ("state keeper cache", &db_config.state_keeper_db_path),
+            ("Merkle tree", &db_config.merkle_tree.path),
+        ];
+
+        let mut details = vec![];
+        for (name, path) in paths {
+            let free_space_gb = available_space(Path::new(path))
+                .with_context(|| format!("failed getting free space for {name} at {path}"))?
+                >> 30;
+            anyhow::ensure!(
+                free_space_gb >= min_free_space_gb,
+                "{name} at {path} has {free_space_gb} GiB free, at least {min_free_space_gb} GiB required"
+            );
+            details.push(format!("{name}: {free_space_gb} GiB free"));
+        }
+        Ok(Some(details.join(", ")))
+    }
+}
+
+/// Returns available space on the volume containing `path`. The path may not exist yet, in which case
+/// its closest existing ancestor is used.
+fn available_space(path: &Path) -> anyhow::Result<u64> {
+    let path = if path.is_relative() {
+        std::env::current_dir()?.join(path)
+    } else {
+        PathBuf::from(path)
+    };
+    let existing_path = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .context("no ancestor of the path exists")?;
+    Ok(fs4::available_space(existing_path)?)
+}
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use anyhow::Context as _;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use zksync_config::{
     configs::{
         api::{HealthCheckConfig, MerkleTreeApiConfig, Web3JsonRpcConfig},
@@ -16,7 +16,7 @@ use zksync_config::{
         ExternalPriceApiClientConfig, FriProofCompressorConfig, FriProverConfig,
         FriProverGatewayConfig, FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig,
         L1Secrets, ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig,
-        ProtectiveReadsWriterConfig, Secrets,
+        ProofDataHandlerSecrets, ProtectiveReadsWriterConfig, Secrets,
     },
     ApiConfig, BaseTokenAdjusterConfig, ContractVerifierConfig, DAClientConfig, DADispatcherConfig,
     DBConfig, EthConfig, EthWatchConfig, ExternalProofIntegrationApiConfig, GasAdjusterConfig,
@@ -28,8 +28,12 @@ use zksync_core_leftovers::{
 };
 use zksync_env_config::FromEnv;
 
-use crate::node_builder::MainNodeBuilder;
+use crate::{
+    check_env::{CheckEnvArgs, EnvChecker},
+    node_builder::MainNodeBuilder,
+};
 
+mod check_env;
 mod config;
 mod node_builder;
 
@@ -39,6 +43,8 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 #[derive(Debug, Parser)]
 #[command(author = "Matter Labs", version, about = "ZKsync operator node", long_about = None)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Generate genesis block for the first contract deployment using temporary DB.
     #[arg(long)]
     genesis: bool,
@@ -78,6 +84,14 @@ struct Cli {
     no_run: bool,
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validates the node environment (database, object store, L1 / Gateway RPCs, contract addresses,
+    /// free disk space) without starting the node, prints a report and exits with a non-zero code
+    /// if any of the checks fail.
+    CheckEnv(CheckEnvArgs),
+}
+
 #[derive(Debug, Clone)]
 struct ComponentsToRun(Vec<Component>);
 
@@ -128,6 +142,7 @@ fn main() -> anyhow::Result<()> {
             l1: L1Secrets::from_env().ok(),
             data_availability: DataAvailabilitySecrets::from_env().ok(),
             contract_verifier: ContractVerifierSecrets::from_env().ok(),
+            proof_data_handler: ProofDataHandlerSecrets::from_env().ok(),
         },
     };
 
@@ -156,6 +171,18 @@ fn main() -> anyhow::Result<()> {
         Some(path) => read_yaml_repr::<zksync_protobuf_config::proto::genesis::Genesis>(&path)
             .context("failed decoding genesis YAML config")?,
     };
+
+    if let Some(Command::CheckEnv(args)) = opt.command {
+        let checker = EnvChecker {
+            general: configs,
+            secrets,
+            genesis,
+            contracts: contracts_config,
+            gateway_contracts: gateway_contracts_config,
+        };
+        return checker.run(args);
+    }
+
     let observability_config = configs
         .observability
         .clone()
@@ -6,8 +6,8 @@ use std::time::Duration;
 use anyhow::{bail, Context};
 use zksync_config::{
     configs::{
-        da_client::DAClientConfig, gateway::GatewayChainConfig, secrets::DataAvailabilitySecrets,
-        wallets::Wallets, GeneralConfig, Secrets,
+        api::Web3JsonRpcConfig, da_client::DAClientConfig, gateway::GatewayChainConfig,
+        secrets::DataAvailabilitySecrets, wallets::Wallets, GeneralConfig, Secrets,
     },
     ContractsConfig, GenesisConfig,
 };
@@ -51,7 +51,9 @@ use zksync_node_framework::{
         postgres::PostgresLayer,
         prometheus_exporter::PrometheusExporterLayer,
         proof_data_handler::ProofDataHandlerLayer,
+        pruning::CallTracesPruningLayer,
         query_eth_client::QueryEthClientLayer,
+        settlement_layer_data::SettlementLayerDataLayer,
         sigint::SigintHandlerLayer,
         state_keeper::{
             main_batch_executor::MainBatchExecutorLayer, mempool_io::MempoolIOLayer,
@@ -62,7 +64,7 @@ use zksync_node_framework::{
             protective_reads::ProtectiveReadsWriterLayer,
         },
         web3_api::{
-            caches::MempoolCacheLayer,
+            caches::{GasPerPubdataOracleLayer, MempoolCacheLayer},
             server::{Web3ServerLayer, Web3ServerOptionalConfig},
             tree_api_client::TreeApiClientLayer,
             tx_sender::{PostgresStorageCachesConfig, TxSenderLayer},
@@ -75,7 +77,7 @@ use zksync_types::{
     commitment::{L1BatchCommitmentMode, PubdataType},
     pubdata_da::PubdataSendingMode,
     settlement::SettlementMode,
-    SHARED_BRIDGE_ETHER_TOKEN_ADDRESS,
+    SLChainId, SHARED_BRIDGE_ETHER_TOKEN_ADDRESS,
 };
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
@@ -266,7 +268,11 @@ impl MainNodeBuilder {
             self.contracts_config.l2_legacy_shared_bridge_addr,
             sk_config.l2_block_seal_queue_capacity,
         )
-        .with_protective_reads_persistence_enabled(sk_config.protective_reads_persistence_enabled);
+        .with_protective_reads_persistence_enabled(sk_config.protective_reads_persistence_enabled)
+        .with_storage_accesses_persistence_enabled(sk_config.save_storage_accesses)
+        .with_receipts_cache_l2_blocks(sk_config.receipts_cache_l2_blocks)
+        .with_failed_call_traces_only(sk_config.save_failed_call_traces_only)
+        .with_call_traces_compression(sk_config.compress_call_traces);
         let mempool_io_layer = MempoolIOLayer::new(
             self.genesis_config.l2_chain_id,
             sk_config.clone(),
@@ -283,7 +289,10 @@ impl MainNodeBuilder {
             .unwrap_or_default();
         let main_node_batch_executor_builder_layer =
             MainBatchExecutorLayer::new(sk_config.save_call_traces, OPTIONAL_BYTECODE_COMPRESSION)
-                .with_fast_vm_mode(experimental_vm_config.state_keeper_fast_vm_mode);
+                .with_fast_vm_mode(experimental_vm_config.state_keeper_fast_vm_mode)
+                .with_shadow_parallel_execution(
+                    experimental_vm_config.state_keeper_shadow_parallel_execution,
+                );
 
         let rocksdb_options = RocksdbStorageOptions {
             block_cache_capacity: db_config
@@ -298,6 +307,9 @@ impl MainNodeBuilder {
             .add_layer(mempool_io_layer)
             .add_layer(main_node_batch_executor_builder_layer)
             .add_layer(state_keeper_layer);
+        if let Some(retention) = sk_config.call_traces_retention() {
+            self.node.add_layer(CallTracesPruningLayer::new(retention));
+        }
         Ok(self)
     }
 
@@ -318,11 +330,20 @@ impl MainNodeBuilder {
     }
 
     fn add_proof_data_handler_layer(mut self) -> anyhow::Result<Self> {
-        self.node.add_layer(ProofDataHandlerLayer::new(
+        let mut layer = ProofDataHandlerLayer::new(
             try_load_config!(self.configs.proof_data_handler_config),
             self.genesis_config.l1_batch_commit_data_generator_mode,
             self.genesis_config.l2_chain_id,
-        ));
+        );
+        let admin_api_key = self
+            .secrets
+            .proof_data_handler
+            .as_ref()
+            .and_then(|secrets| secrets.admin_api_key.clone());
+        if let Some(admin_api_key) = admin_api_key {
+            layer = layer.with_admin_api_key(admin_api_key);
+        }
+        self.node.add_layer(layer);
         Ok(self)
     }
 
@@ -361,23 +382,38 @@ impl MainNodeBuilder {
             .clone()
             .unwrap_or_default();
 
+        // Address policies are only mirrored by `eth_watch` if the policy registry is configured.
+        let address_policies_enabled = self
+            .configs
+            .eth
+            .as_ref()
+            .and_then(|eth| eth.watcher.as_ref())
+            .is_some_and(|watcher| watcher.address_policy_registry_addr.is_some());
+
         // On main node we always use master pool sink.
         self.node.add_layer(MasterPoolSinkLayer);
 
         let layer = TxSenderLayer::new(
-            TxSenderConfig::new(
-                &sk_config,
-                &rpc_config,
-                try_load_config!(self.wallets.state_keeper)
-                    .fee_account
-                    .address(),
-                self.genesis_config.l2_chain_id,
-                timestamp_asserter_params,
-            ),
+            TxSenderConfig {
+                address_policies_enabled,
+                ..TxSenderConfig::new(
+                    &sk_config,
+                    &rpc_config,
+                    try_load_config!(self.wallets.state_keeper)
+                        .fee_account
+                        .address(),
+                    self.genesis_config.l2_chain_id,
+                    timestamp_asserter_params,
+                )
+            },
             postgres_storage_caches_config,
             rpc_config.vm_concurrency_limit(),
         );
-        let layer = layer.with_vm_mode(vm_config.api_fast_vm_mode);
+        let layer = layer
+            .with_vm_mode(vm_config.api_fast_vm_mode)
+            .with_pruned_calls_snapshot_chunks_budget(
+                rpc_config.pruned_calls_snapshot_chunks_budget,
+            );
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -388,6 +424,9 @@ impl MainNodeBuilder {
             rpc_config.mempool_cache_size(),
             rpc_config.mempool_cache_update_interval(),
         ));
+        self.node.add_layer(GasPerPubdataOracleLayer::new(
+            rpc_config.gas_price_scale_factor,
+        ));
         Ok(self)
     }
 
@@ -398,11 +437,10 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
-    fn add_http_web3_api_layer(mut self) -> anyhow::Result<Self> {
-        let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
-        let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
-        let with_debug_namespace = state_keeper_config.save_call_traces;
-
+    fn web3_api_namespaces(
+        rpc_config: &Web3JsonRpcConfig,
+        with_debug_namespace: bool,
+    ) -> anyhow::Result<Vec<Namespace>> {
         let mut namespaces = if let Some(namespaces) = &rpc_config.api_namespaces {
             namespaces
                 .iter()
@@ -416,6 +454,23 @@ impl MainNodeBuilder {
         }
         namespaces.push(Namespace::Snapshots);
 
+        if let Some(disabled_namespaces) = &rpc_config.disabled_api_namespaces {
+            let disabled_namespaces: Vec<Namespace> = disabled_namespaces
+                .iter()
+                .map(|a| a.parse())
+                .collect::<Result<_, _>>()?;
+            namespaces.retain(|namespace| !disabled_namespaces.contains(namespace));
+        }
+        Ok(namespaces)
+    }
+
+    fn add_http_web3_api_layer(mut self) -> anyhow::Result<Self> {
+        let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
+        let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
+        let with_debug_namespace = state_keeper_config.save_call_traces;
+
+        let namespaces = Self::web3_api_namespaces(&rpc_config, with_debug_namespace)?;
+
         let optional_config = Web3ServerOptionalConfig {
             namespaces: Some(namespaces),
             filters_limit: Some(rpc_config.filters_limit()),
@@ -423,6 +478,10 @@ impl MainNodeBuilder {
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
             with_extended_tracing: rpc_config.extended_api_tracing,
+            with_strict_quantity_validation: rpc_config.strict_quantity_validation,
+            snapshots_download_url_ttl: rpc_config.snapshots_download_url_ttl(),
+            snapshots_requests_per_minute_limit: rpc_config.snapshots_requests_per_minute_limit,
+            snapshots_rate_limit_proxy_hops: rpc_config.snapshots_rate_limit_proxy_hops,
             ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::http(
@@ -449,30 +508,23 @@ impl MainNodeBuilder {
         let circuit_breaker_config = try_load_config!(self.configs.circuit_breaker_config);
         let with_debug_namespace = state_keeper_config.save_call_traces;
 
-        let mut namespaces = if let Some(namespaces) = &rpc_config.api_namespaces {
-            namespaces
-                .iter()
-                .map(|a| a.parse())
-                .collect::<Result<_, _>>()?
-        } else {
-            Namespace::DEFAULT.to_vec()
-        };
-        if with_debug_namespace {
-            namespaces.push(Namespace::Debug)
-        }
-        namespaces.push(Namespace::Snapshots);
+        let namespaces = Self::web3_api_namespaces(&rpc_config, with_debug_namespace)?;
 
         let optional_config = Web3ServerOptionalConfig {
             namespaces: Some(namespaces),
             filters_limit: Some(rpc_config.filters_limit()),
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
-            batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
+            batch_request_size_limit: Some(rpc_config.websocket_max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
             websocket_requests_per_minute_limit: Some(
                 rpc_config.websocket_requests_per_minute_limit(),
             ),
             replication_lag_limit: circuit_breaker_config.replication_lag_limit(),
             with_extended_tracing: rpc_config.extended_api_tracing,
+            with_strict_quantity_validation: rpc_config.strict_quantity_validation,
+            snapshots_download_url_ttl: rpc_config.snapshots_download_url_ttl(),
+            snapshots_requests_per_minute_limit: rpc_config.snapshots_requests_per_minute_limit,
+            snapshots_rate_limit_proxy_hops: rpc_config.snapshots_rate_limit_proxy_hops,
             ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::ws(
@@ -520,6 +572,27 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_settlement_layer_data_layer(mut self) -> anyhow::Result<Self> {
+        let settlement_mode = self
+            .configs
+            .eth
+            .as_ref()
+            .and_then(|x| Some(x.gas_adjuster?.settlement_mode))
+            .unwrap_or(SettlementMode::SettlesToL1);
+        let sl_chain_id = if settlement_mode.is_gateway() {
+            self.gateway_chain_config
+                .as_ref()
+                .context("Gateway chain config is required when settling to Gateway")?
+                .gateway_chain_id
+        } else {
+            SLChainId::from(self.genesis_config.l1_chain_id)
+        };
+        self.node
+            .add_layer(SettlementLayerDataLayer::new(settlement_mode, sl_chain_id));
+
+        Ok(self)
+    }
+
     fn add_house_keeper_layer(mut self) -> anyhow::Result<Self> {
         let house_keeper_config = try_load_config!(self.configs.house_keeper_config);
 
@@ -790,6 +863,7 @@ impl MainNodeBuilder {
                         .add_tx_sender_layer()?
                         .add_tree_api_client_layer()?
                         .add_api_caches_layer()?
+                        .add_settlement_layer_data_layer()?
                         .add_http_web3_api_layer()?;
                 }
                 Component::WsApi => {
@@ -798,6 +872,7 @@ impl MainNodeBuilder {
                         .add_tx_sender_layer()?
                         .add_tree_api_client_layer()?
                         .add_api_caches_layer()?
+                        .add_settlement_layer_data_layer()?
                         .add_ws_web3_api_layer()?;
                 }
                 Component::ContractVerificationApi => {
@@ -820,7 +895,8 @@ impl MainNodeBuilder {
                 Component::EthTxAggregator => {
                     self = self
                         .add_pk_signing_client_layer()?
-                        .add_eth_tx_aggregator_layer()?;
+                        .add_eth_tx_aggregator_layer()?
+                        .add_settlement_layer_data_layer()?;
                 }
                 Component::EthTxManager => {
                     self = self.add_eth_tx_manager_layer()?;
@@ -303,6 +303,30 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn checked_number_arithmetic() {
+        assert_eq!(L1BatchNumber(1).checked_prev(), Some(L1BatchNumber(0)));
+        assert_eq!(L1BatchNumber(0).checked_prev(), None);
+        assert_eq!(L1BatchNumber(0).saturating_prev(), L1BatchNumber(0));
+        assert_eq!(L2BlockNumber(1).checked_next(), Some(L2BlockNumber(2)));
+        assert_eq!(L2BlockNumber(u32::MAX).checked_next(), None);
+    }
+
+    #[test]
+    fn converting_numbers_from_postgres() {
+        assert_eq!(L1BatchNumber::try_from(42_i64).unwrap(), L1BatchNumber(42));
+        assert_eq!(
+            L2BlockNumber::try_from(i64::from(u32::MAX)).unwrap(),
+            L2BlockNumber(u32::MAX)
+        );
+        L1BatchNumber::try_from(-1_i64).unwrap_err();
+        L1BatchNumber::try_from(i64::from(u32::MAX) + 1).unwrap_err();
+        assert_eq!(
+            PriorityOpId::try_from(1_i64 << 40).unwrap(),
+            PriorityOpId(1 << 40)
+        );
+    }
+
     #[test]
     fn test_from_str_valid_decimal() {
         let input = "42";
@@ -10,6 +10,21 @@ macro_rules! basic_type {
             pub fn next(self) -> $name {
                 $name(self.0 + 1)
             }
+
+            /// Returns the next value, or `None` if it overflows.
+            pub fn checked_next(self) -> Option<$name> {
+                self.0.checked_add(1).map($name)
+            }
+
+            /// Returns the previous value, or `None` if `self` is zero.
+            pub fn checked_prev(self) -> Option<$name> {
+                self.0.checked_sub(1).map($name)
+            }
+
+            /// Returns the previous value, or zero if `self` is zero.
+            pub fn saturating_prev(self) -> $name {
+                $name(self.0.saturating_sub(1))
+            }
         }
 
         impl Deref for $name {
@@ -74,5 +89,15 @@ macro_rules! basic_type {
                 Self(value)
             }
         }
+
+        /// Conversion from signed integers as stored in Postgres. Fails on negative or out-of-range values
+        /// instead of silently truncating them.
+        impl TryFrom<i64> for $name {
+            type Error = std::num::TryFromIntError;
+
+            fn try_from(value: i64) -> Result<Self, Self::Error> {
+                <$type>::try_from(value).map(Self)
+            }
+        }
     };
 }
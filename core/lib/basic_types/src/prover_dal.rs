@@ -1,12 +1,19 @@
 //! Types exposed by the prover DAL for general-purpose use.
-use std::{net::IpAddr, ops::Add, str::FromStr, time::Instant};
+use std::{
+    net::IpAddr,
+    ops::{Add, RangeInclusive},
+    str::FromStr,
+    time::Instant,
+};
 
 use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
 use crate::{
-    basic_fri_types::AggregationRound, protocol_version::ProtocolVersionId, L1BatchNumber,
+    basic_fri_types::AggregationRound,
+    protocol_version::{ProtocolSemanticVersion, ProtocolVersionId},
+    L1BatchNumber,
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -81,6 +88,33 @@ pub struct StuckJobs {
     pub error: Option<String>,
 }
 
+/// Selects stuck jobs to be re-queued in bulk. Jobs must match all specified conditions; the default filter
+/// matches all jobs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StuckJobsFilter {
+    /// Inclusive range of L1 batch numbers of the jobs.
+    pub l1_batch_range: Option<RangeInclusive<L1BatchNumber>>,
+    /// Protocol version of the jobs.
+    pub protocol_version: Option<ProtocolSemanticVersion>,
+}
+
+impl StuckJobsFilter {
+    /// Creates a filter matching jobs for a single L1 batch.
+    pub fn for_batch(l1_batch_number: L1BatchNumber) -> Self {
+        Self {
+            l1_batch_range: Some(l1_batch_number..=l1_batch_number),
+            protocol_version: None,
+        }
+    }
+}
+
+/// Numbers of jobs moved to archive tables for batches which proofs were sent to the server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchivedBatchJobs {
+    pub compression_jobs: usize,
+    pub witness_jobs: usize,
+}
+
 // TODO (PLA-774): Redundant structure, should be replaced with `std::net::SocketAddr`.
 #[derive(Debug, Clone)]
 pub struct SocketAddress {
@@ -207,6 +241,8 @@ pub enum WitnessJobStatus {
     InProgress,
     #[strum(serialize = "queued")]
     Queued,
+    #[strum(serialize = "cancelled")]
+    Cancelled,
 }
 
 #[derive(Debug)]
@@ -287,6 +323,19 @@ pub struct ProverJobFriInfo {
     pub picked_by: Option<String>,
 }
 
+/// Aggregated state of prover jobs for a single aggregation round of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProverJobsRoundStats {
+    pub l1_batch_number: L1BatchNumber,
+    pub aggregation_round: AggregationRound,
+    pub jobs_count: u32,
+    pub successful_jobs_count: u32,
+    /// IDs of circuits having jobs in the `failed` status.
+    pub failed_circuit_ids: Vec<u8>,
+    /// Proof blob URL of one of the round jobs. Only meaningful if the round has a single job.
+    pub proof_blob_url: Option<String>,
+}
+
 pub trait Stallable {
     fn get_status(&self) -> WitnessJobStatus;
     fn get_attempts(&self) -> u32;
@@ -424,6 +473,21 @@ impl Stallable for SchedulerWitnessGeneratorJobInfo {
     }
 }
 
+/// Resources consumed by a single witness generator job.
+#[derive(Debug, Clone)]
+pub struct WitnessGeneratorJobStats {
+    pub job_id: u32,
+    pub l1_batch_number: L1BatchNumber,
+    pub aggregation_round: AggregationRound,
+    /// Set for rounds processing jobs per circuit (i.e., leaf and node aggregation).
+    pub circuit_id: Option<u8>,
+    pub time_taken: NaiveTime,
+    /// Peak resident set size of the witness generator process observed while processing the job.
+    /// May include memory used by other jobs processed concurrently.
+    pub peak_rss_bytes: Option<u64>,
+    pub created_at: NaiveDateTime,
+}
+
 #[derive(Debug, EnumString, Display, Clone)]
 pub enum ProofCompressionJobStatus {
     #[strum(serialize = "queued")]
@@ -463,3 +527,10 @@ pub struct ProofGenerationTime {
     pub time_taken: NaiveTime,
     pub created_at: NaiveDateTime,
 }
+
+/// Pause of proving job dispensing.
+#[derive(Debug, Clone)]
+pub struct ProvingPause {
+    pub reason: Option<String>,
+    pub paused_at: NaiveDateTime,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::SLChainId;
+
 /// An enum which is used to describe whether a zkSync network settles to L1 or to the gateway.
 /// Gateway is an Ethereum-compatible L2 and so it requires different treatment with regards to DA handling.
 #[derive(Default, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -14,3 +16,12 @@ impl SettlementMode {
         matches!(self, Self::Gateway)
     }
 }
+
+/// Settlement layer used by a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettlementLayerData {
+    pub settlement_mode: SettlementMode,
+    pub sl_chain_id: SLChainId,
+    /// Whether the chain is in the process of migrating to `settlement_mode`.
+    pub migration_pending: bool,
+}
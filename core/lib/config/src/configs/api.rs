@@ -220,10 +220,50 @@ pub struct Web3JsonRpcConfig {
     /// Enabled JSON RPC API namespaces. If not set, all namespaces will be available
     #[serde(default)]
     pub api_namespaces: Option<Vec<String>>,
+    /// JSON RPC API namespaces that are disabled regardless of `api_namespaces`. Can be used to switch off namespaces
+    /// that are enabled by default, such as `debug` or `snapshots` on the main node.
+    #[serde(default)]
+    pub disabled_api_namespaces: Option<Vec<String>>,
     /// Enables extended tracing of RPC calls. This may negatively impact performance for nodes under high load
     /// (hundreds or thousands RPS).
     #[serde(default)]
     pub extended_api_tracing: bool,
+    /// If set, `eth_call` requests against pruned blocks are served by reconstructing the block state from storage snapshots
+    /// (only possible for the last L2 blocks of L1 batches with a complete snapshot). The value is the maximum number
+    /// of snapshot storage log chunks that a single call may load.
+    #[serde(default)]
+    pub pruned_calls_snapshot_chunks_budget: Option<usize>,
+    /// Maximum size of call traces processed by a single `debug_trace*` call, in bytes of the JSON response. Requests
+    /// for stored traces with the estimated response size (derived from their uncompressed stored size) exceeding
+    /// this limit are rejected before the traces are loaded. Otherwise, nested calls are truncated once
+    /// the estimated size of the response exceeds the limit; calls with omitted nested calls are marked
+    /// with the `truncated` flag. If not set, traces are neither limited nor truncated.
+    #[serde(default)]
+    pub max_trace_size_bytes: Option<usize>,
+    /// Maximum number of requests in a single batch JSON RPC request sent over WebSocket. If not set,
+    /// `max_batch_request_size` is used. Setting this to 0 disables batch requests for the WebSocket server.
+    #[serde(default)]
+    pub websocket_max_batch_request_size: Option<usize>,
+    /// If set, snapshot headers returned by the `snapshots` namespace include signed object store URLs to download
+    /// snapshot files from, which expire after the specified number of seconds. This allows distributing snapshots
+    /// to third-party nodes without making the snapshot bucket public. Requires a GCS or S3 object store
+    /// with credentials allowing to sign URLs.
+    #[serde(default)]
+    pub snapshots_download_url_ttl_sec: Option<u64>,
+    /// Maximum number of requests per minute to the `snapshots` namespace for a single consumer. Consumers are identified
+    /// as specified by `snapshots_rate_limit_proxy_hops`. If not set, requests are not limited.
+    #[serde(default)]
+    pub snapshots_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Number of trusted reverse proxies in front of the server, each of which appends the address of its peer
+    /// to the `X-Forwarded-For` header. Consumers of the `snapshots` namespace are identified by the entry at this
+    /// position from the right; entries to the left of it are client-controlled and ignored. If not set,
+    /// the header is ignored and all consumers share a single quota.
+    #[serde(default)]
+    pub snapshots_rate_limit_proxy_hops: Option<NonZeroU32>,
+    /// Rejects quantity params with leading zero digits (e.g., `0x01`), like geth does. Off by default since
+    /// such params are accepted by the server otherwise.
+    #[serde(default)]
+    pub strict_quantity_validation: bool,
 }
 
 impl Web3JsonRpcConfig {
@@ -263,7 +303,15 @@ impl Web3JsonRpcConfig {
             tree_api_url: None,
             whitelisted_tokens_for_aa: vec![],
             api_namespaces: None,
+            disabled_api_namespaces: None,
             extended_api_tracing: false,
+            pruned_calls_snapshot_chunks_budget: None,
+            max_trace_size_bytes: None,
+            websocket_max_batch_request_size: None,
+            snapshots_download_url_ttl_sec: None,
+            snapshots_requests_per_minute_limit: None,
+            snapshots_rate_limit_proxy_hops: None,
+            strict_quantity_validation: false,
         }
     }
 
@@ -327,6 +375,11 @@ impl Web3JsonRpcConfig {
         self.max_batch_request_size.unwrap_or(500)
     }
 
+    pub fn websocket_max_batch_request_size(&self) -> usize {
+        self.websocket_max_batch_request_size
+            .unwrap_or_else(|| self.max_batch_request_size())
+    }
+
     pub fn max_response_body_size(&self) -> MaxResponseSize {
         let scale = NonZeroUsize::new(super::BYTES_IN_MEGABYTE).unwrap();
         MaxResponseSize {
@@ -352,6 +405,10 @@ impl Web3JsonRpcConfig {
     pub fn mempool_cache_size(&self) -> usize {
         self.mempool_cache_size.unwrap_or(10_000)
     }
+
+    pub fn snapshots_download_url_ttl(&self) -> Option<Duration> {
+        self.snapshots_download_url_ttl_sec.map(Duration::from_secs)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -132,6 +132,32 @@ pub struct StateKeeperConfig {
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
 
+    /// Whether to persist storage slots read and written by each executed transaction, so that they can be
+    /// queried via `debug_getTransactionStorageAccesses`. Disabled by default since it noticeably increases
+    /// the DB size.
+    #[serde(default)]
+    pub save_storage_accesses: bool,
+
+    /// Number of latest L2 blocks for which transaction receipts are materialized in a dedicated cache table
+    /// when an L1 batch is sealed, so that `eth_getTransactionReceipt` and `eth_getBlockReceipts` don't need
+    /// to assemble them from several tables. Set to 0 (the default) to disable the cache.
+    #[serde(default)]
+    pub receipts_cache_l2_blocks: u32,
+
+    /// If set, only call traces of failed transactions are persisted (provided that `save_call_traces` is enabled).
+    /// Traces of successful transactions are discarded, and `debug_traceTransaction` returns no trace for them.
+    #[serde(default)]
+    pub save_failed_call_traces_only: bool,
+    /// Number of days for which call traces are retained. Older traces are periodically removed by a background
+    /// pruner. If not set (the default), call traces are retained indefinitely (or until the DB is pruned).
+    #[serde(default)]
+    pub call_traces_retention_days: Option<u32>,
+    /// If set, call traces are compressed with zstd when persisted. Compressed traces cannot be read by
+    /// server versions predating compression support, so this should only be enabled once a rollback
+    /// to such a version is no longer expected.
+    #[serde(default)]
+    pub compress_call_traces: bool,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -178,12 +204,23 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            save_storage_accesses: false,
+            receipts_cache_l2_blocks: 0,
+            save_failed_call_traces_only: false,
+            call_traces_retention_days: None,
+            compress_call_traces: false,
             bootloader_hash: None,
             default_aa_hash: None,
             evm_emulator_hash: None,
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
         }
     }
+
+    /// Returns the retention period for call traces, if any.
+    pub fn call_traces_retention(&self) -> Option<Duration> {
+        self.call_traces_retention_days
+            .map(|days| Duration::from_secs(u64::from(days) * 86_400))
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
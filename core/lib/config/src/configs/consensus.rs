@@ -4,6 +4,8 @@ use secrecy::{ExposeSecret as _, SecretString};
 use zksync_basic_types::{ethabi, L2ChainId};
 use zksync_concurrency::{limiter, time};
 
+use crate::ObjectStoreConfig;
+
 /// `zksync_consensus_crypto::TextFmt` representation of `zksync_consensus_roles::validator::PublicKey`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ValidatorPublicKey(pub String);
@@ -111,6 +113,24 @@ impl RpcConfig {
     }
 }
 
+/// Configuration of archiving old certified blocks to the object store.
+/// Archived blocks are removed from Postgres, but are still served to peers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockArchiveConfig {
+    /// Number of the most recent certified blocks to keep in Postgres.
+    pub retention_blocks: u64,
+    /// Interval between archiving iterations. Defaults to 1 minute.
+    pub interval: Option<time::Duration>,
+    /// Object store to archive blocks to.
+    pub object_store: ObjectStoreConfig,
+}
+
+impl BlockArchiveConfig {
+    pub fn interval(&self) -> time::Duration {
+        self.interval.unwrap_or(time::Duration::minutes(1))
+    }
+}
+
 /// Config (shared between main node and external node).
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConsensusConfig {
@@ -154,6 +174,9 @@ pub struct ConsensusConfig {
 
     /// Local socket address to expose the node debug page.
     pub debug_page_addr: Option<std::net::SocketAddr>,
+
+    /// Archiving of old certified blocks to the object store. If not set, blocks are kept in Postgres.
+    pub block_archive: Option<BlockArchiveConfig>,
 }
 
 impl ConsensusConfig {
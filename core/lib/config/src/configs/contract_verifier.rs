@@ -15,14 +15,52 @@ pub struct ContractVerifierConfig {
     /// Etherscan API URL that is used for contract verification in Etherscan.
     /// If not set, the Etherscan verification is disabled.
     pub etherscan_api_url: Option<String>,
+    /// Whether to run the background discovery of similar matches, i.e. unverified contracts with bytecode
+    /// matching a verified contract (possibly up to the metadata hash).
+    #[serde(default)]
+    pub similar_match_discovery_enabled: bool,
+    /// Sourcify server API URL. If not set, syncing with Sourcify is disabled.
+    pub sourcify_api_url: Option<String>,
+    /// L2 chain ID used to identify contracts on Sourcify. Required if `sourcify_api_url` is set.
+    pub sourcify_chain_id: Option<u64>,
+    /// Whether to import sources of contracts verified on Sourcify as local verification requests.
+    #[serde(default)]
+    pub sourcify_import_enabled: bool,
+    /// Whether to export sources of locally verified contracts to Sourcify.
+    #[serde(default)]
+    pub sourcify_export_enabled: bool,
+    /// Interval between syncs with Sourcify (in s).
+    #[serde(default = "ContractVerifierConfig::default_sourcify_sync_interval")]
+    pub sourcify_sync_interval: u64,
+    /// Port of the admin API over the verification request queue. The admin API is bound to the loopback
+    /// interface only. If not set, the admin API is disabled.
+    pub admin_port: Option<u16>,
+    /// Requesters (as specified in the `X-Requester` header of verification requests submitted via the admin API)
+    /// whose requests are processed in the priority lane, e.g. explorer backfill. The header is ignored
+    /// by the public API.
+    #[serde(default)]
+    pub priority_requesters: Vec<String>,
 }
 
 impl ContractVerifierConfig {
+    const fn default_sourcify_sync_interval() -> u64 {
+        300
+    }
+
     pub fn compilation_timeout(&self) -> Duration {
         Duration::from_secs(self.compilation_timeout)
     }
 
+    pub fn sourcify_sync_interval(&self) -> Duration {
+        Duration::from_secs(self.sourcify_sync_interval)
+    }
+
     pub fn bind_addr(&self) -> SocketAddr {
         SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), self.port)
     }
+
+    pub fn admin_bind_addr(&self) -> Option<SocketAddr> {
+        let port = self.admin_port?;
+        Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+    }
 }
@@ -20,4 +20,14 @@ pub struct ENConfig {
     pub bridge_addresses_refresh_interval_sec: Option<NonZeroU64>,
 
     pub gateway_chain_id: Option<SLChainId>,
+
+    /// Runs the node as a full-history (archive) node.
+    #[serde(default)]
+    pub archive_mode: bool,
+    /// Time-to-live for transactions queued for resubmission to the main node when it is unavailable.
+    /// If not set, the queue is disabled, and transactions are rejected if the main node is unavailable.
+    pub tx_proxy_queue_ttl_sec: Option<NonZeroU64>,
+    /// Maximum number of transactions queued for resubmission to the main node. Once the queue is full,
+    /// new transactions are rejected until the main node becomes available.
+    pub tx_proxy_queue_capacity: Option<NonZeroUsize>,
 }
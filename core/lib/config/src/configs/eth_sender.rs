@@ -32,6 +32,7 @@ impl EthConfig {
                 max_eth_tx_data_size: 6000000,
                 max_aggregated_blocks_to_commit: 10,
                 max_aggregated_blocks_to_execute: 10,
+                max_aggregated_skipped_proofs: 1,
                 aggregated_block_commit_deadline: 1,
                 aggregated_block_prove_deadline: 10,
                 aggregated_block_execute_deadline: 10,
@@ -43,6 +44,8 @@ impl EthConfig {
                 tx_aggregation_only_prove_and_execute: false,
                 time_in_mempool_in_l1_blocks_cap: 1800,
                 is_verifier_pre_fflonk: true,
+                max_commit_base_fee_per_gas: None,
+                max_commit_postponement_sec: SenderConfig::default_max_commit_postponement_sec(),
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -58,10 +61,12 @@ impl EthConfig {
                 internal_pubdata_pricing_multiplier: 1.0,
                 max_blob_base_fee: None,
                 settlement_mode: Default::default(),
+                gateway_settlement_supported: false,
             }),
             watcher: Some(EthWatchConfig {
                 confirmations_for_eth_event: None,
                 eth_node_poll_interval: 0,
+                address_policy_registry_addr: None,
             }),
         }
     }
@@ -98,6 +103,11 @@ pub struct SenderConfig {
     pub max_eth_tx_data_size: usize,
     pub max_aggregated_blocks_to_commit: u32,
     pub max_aggregated_blocks_to_execute: u32,
+    /// Max number of L1 batches with skipped proofs (i.e., proven without proof verification) sent in a single
+    /// transaction. Real proofs are never aggregated: the L1 verifier accepts a single proof for a single L1 batch,
+    /// so transactions with real proofs always contain one L1 batch regardless of this value.
+    #[serde(default = "SenderConfig::default_max_aggregated_skipped_proofs")]
+    pub max_aggregated_skipped_proofs: u32,
     pub aggregated_block_commit_deadline: u64,
     pub aggregated_block_prove_deadline: u64,
     pub aggregated_block_execute_deadline: u64,
@@ -122,6 +132,13 @@ pub struct SenderConfig {
     #[serde(default = "SenderConfig::default_time_in_mempool_in_l1_blocks_cap")]
     pub time_in_mempool_in_l1_blocks_cap: u32,
     pub is_verifier_pre_fflonk: bool,
+    /// L1 base fee (in wei) above which commit transactions are postponed until the fee drops, unless
+    /// the commit is urgent. If not specified, commit transactions are never postponed.
+    pub max_commit_base_fee_per_gas: Option<u64>,
+    /// Maximum age (in seconds) of the oldest uncommitted L1 batch after which its commit is considered urgent
+    /// and is not postponed regardless of the L1 base fee.
+    #[serde(default = "SenderConfig::default_max_commit_postponement_sec")]
+    pub max_commit_postponement_sec: u64,
 }
 
 impl SenderConfig {
@@ -135,6 +152,11 @@ impl SenderConfig {
         Duration::from_secs(self.aggregate_tx_poll_period)
     }
 
+    /// Converts `self.max_commit_postponement_sec` into `Duration`.
+    pub fn max_commit_postponement(&self) -> Duration {
+        Duration::from_secs(self.max_commit_postponement_sec)
+    }
+
     // Don't load private key, if it's not required.
     #[deprecated]
     pub fn private_key(&self) -> anyhow::Result<Option<K256PrivateKey>> {
@@ -157,6 +179,10 @@ impl SenderConfig {
             .map(|pk| pk.parse().unwrap())
     }
 
+    pub const fn default_max_aggregated_skipped_proofs() -> u32 {
+        1
+    }
+
     const fn default_tx_aggregation_paused() -> bool {
         false
     }
@@ -170,6 +196,10 @@ impl SenderConfig {
         // 1,001 ^ 1800 ~= 6, so by default we cap exponential price formula at roughly median * 6
         blocks_per_hour * 6
     }
+
+    pub const fn default_max_commit_postponement_sec() -> u64 {
+        3_600
+    }
 }
 
 #[derive(Debug, Deserialize, Copy, Clone, PartialEq, Default)]
@@ -210,6 +240,10 @@ pub struct GasAdjusterConfig {
     /// It offers a runtime check for correctly provided values.
     #[serde(default)]
     pub settlement_mode: SettlementMode,
+    /// Whether this deployment supports settling to Gateway. If set, a Gateway RPC client must be configured;
+    /// if not set, [`Self::settlement_mode`] must not be set to Gateway.
+    #[serde(default)]
+    pub gateway_settlement_supported: bool,
 }
 
 impl GasAdjusterConfig {
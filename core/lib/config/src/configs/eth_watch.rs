@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
+use zksync_basic_types::Address;
 
 /// Configuration for the Ethereum watch crate.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -11,6 +12,10 @@ pub struct EthWatchConfig {
     /// How often we want to poll the Ethereum node.
     /// Value in milliseconds.
     pub eth_node_poll_interval: u64,
+    /// Address of the on-chain address policy registry. If set, address policies (greylisting / blacklisting)
+    /// emitted by the registry are mirrored to the database and enforced during transaction admission.
+    #[serde(default)]
+    pub address_policy_registry_addr: Option<Address>,
 }
 
 impl EthWatchConfig {
@@ -115,4 +115,9 @@ pub struct ExperimentalVmConfig {
     /// or transaction validation), so the legacy VM will always be used for them.
     #[serde(default)]
     pub api_fast_vm_mode: FastVmMode,
+
+    /// Whether to speculatively re-execute transactions in each L2 block in parallel in the state keeper, and compare
+    /// the results with sequential execution. Only affects metrics and logs; should not be enabled in production.
+    #[serde(default)]
+    pub state_keeper_shadow_parallel_execution: bool,
 }
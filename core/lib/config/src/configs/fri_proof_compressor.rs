@@ -17,6 +17,12 @@ pub struct FriProofCompressorConfig {
     pub generation_timeout_in_secs: u16,
     /// Max attempts for proof compression to be performed
     pub max_attempts: u32,
+    /// Max attempts after which stuck compression jobs are no longer requeued by the job monitor.
+    /// If not set, `max_attempts` is used.
+    pub requeue_max_attempts: Option<u32>,
+    /// Time after which an in-progress compression job is considered stuck and is requeued by the job monitor.
+    /// If not set, `generation_timeout_in_secs` is used.
+    pub processing_timeout_in_secs: Option<u16>,
 
     /// Path to universal setup key file
     pub universal_setup_path: String,
@@ -25,10 +31,57 @@ pub struct FriProofCompressorConfig {
 
     // Whether to verify wrapper proof or not.
     pub verify_wrapper_proof: bool,
+    /// Whether to checkpoint the compressed proof in the object store, so that a job retried after a failure
+    /// that happened *after* compression doesn't need to compress the proof again. Compression itself cannot be resumed;
+    /// a job interrupted during compression starts over. Can be disabled on hardware where compression is fast.
+    #[serde(default = "FriProofCompressorConfig::default_checkpointing_enabled")]
+    pub checkpointing_enabled: bool,
 }
 
 impl FriProofCompressorConfig {
+    const fn default_checkpointing_enabled() -> bool {
+        true
+    }
+
     pub fn generation_timeout(&self) -> Duration {
         Duration::from_secs(self.generation_timeout_in_secs as u64)
     }
+
+    pub fn requeue_max_attempts(&self) -> u32 {
+        self.requeue_max_attempts.unwrap_or(self.max_attempts)
+    }
+
+    pub fn processing_timeout(&self) -> Duration {
+        self.processing_timeout_in_secs.map_or_else(
+            || self.generation_timeout(),
+            |secs| Duration::from_secs(secs.into()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requeue_params_fall_back_to_generation_params() {
+        let mut config: FriProofCompressorConfig = serde_json::from_value(serde_json::json!({
+            "compression_mode": 1,
+            "prometheus_listener_port": 3321,
+            "prometheus_pushgateway_url": "http://127.0.0.1:9091",
+            "generation_timeout_in_secs": 3600,
+            "max_attempts": 5,
+            "universal_setup_path": "keys/setup/setup_compact.key",
+            "universal_setup_download_url": "https://example.com/setup_compact.key",
+            "verify_wrapper_proof": true,
+        }))
+        .unwrap();
+        assert_eq!(config.requeue_max_attempts(), 5);
+        assert_eq!(config.processing_timeout(), Duration::from_secs(3600));
+
+        config.requeue_max_attempts = Some(10);
+        config.processing_timeout_in_secs = Some(600);
+        assert_eq!(config.requeue_max_attempts(), 10);
+        assert_eq!(config.processing_timeout(), Duration::from_secs(600));
+    }
 }
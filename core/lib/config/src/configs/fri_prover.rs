@@ -37,6 +37,9 @@ pub struct FriProverConfig {
     pub witness_vector_receiver_port: u16,
     pub zone_read_url: String,
     pub availability_check_interval_in_secs: Option<u32>,
+    /// Base URL to download setup keys from if they are missing in `setup_data_path`. Keys for a specific protocol version
+    /// are expected at `{setup_keys_download_url}/{protocol_version}/`, together with a manifest listing key hashes.
+    pub setup_keys_download_url: Option<String>,
 
     pub prover_object_store: Option<ObjectStoreConfig>,
     #[serde(default)]
@@ -3,6 +3,7 @@ use std::time::Duration;
 // Built-in uses
 // External uses
 use serde::Deserialize;
+use zksync_basic_types::basic_fri_types::AggregationRound;
 
 /// Configuration for the fri witness generation
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -16,6 +17,12 @@ pub struct FriWitnessGeneratorConfig {
     pub recursion_tip_generation_timeout_in_secs: Option<u16>,
     /// Max attempts for generating witness
     pub max_attempts: u32,
+    /// Max attempts for generating witness in the specific round. If not set, `max_attempts` is used.
+    pub basic_max_attempts: Option<u32>,
+    pub leaf_max_attempts: Option<u32>,
+    pub node_max_attempts: Option<u32>,
+    pub recursion_tip_max_attempts: Option<u32>,
+    pub scheduler_max_attempts: Option<u32>,
     // Optional l1 batch number to process block until(inclusive).
     // This parameter is used in case of performing circuit upgrades(VK/Setup keys),
     // to not let witness-generator pick new job and finish all the existing jobs with old circuit.
@@ -39,6 +46,35 @@ pub struct FriWitnessGeneratorConfig {
     /// It affects the performance and resource usage of WGs.
     #[serde(default = "FriWitnessGeneratorConfig::default_max_circuits_in_flight")]
     pub max_circuits_in_flight: usize,
+    /// Number of jobs picked and prepared (i.e., with input artifacts loaded from the object store) ahead of time,
+    /// while the current job is being processed. Each prefetched job is kept in memory until it's processed.
+    /// 0 disables prefetching.
+    #[serde(default)]
+    pub prefetch_depth: usize,
+    /// Maximum number of proofs loaded into memory at the same time by a leaf or node aggregation job.
+    /// Each proof takes roughly 1 MB, so this caps the memory used for proofs regardless of `max_circuits_in_flight`.
+    /// A single queue always gets its proofs loaded in full, even if it holds more proofs than the budget.
+    /// If not set, the number of loaded proofs is only limited by `max_circuits_in_flight`.
+    #[serde(default)]
+    pub max_proofs_in_memory: Option<usize>,
+    /// Compression applied to artifacts passed between witness generator rounds (aggregations and scheduler inputs).
+    /// Compressed artifacts are detected when read, so compression can be changed without migrating existing artifacts.
+    /// Circuits and proofs are never compressed since they are read by other components.
+    #[serde(default)]
+    pub artifacts_compression: ArtifactsCompressionAlgorithm,
+    /// Compression level for zstd. If not set, the zstd default level is used. Ignored for other algorithms.
+    #[serde(default)]
+    pub artifacts_compression_level: Option<i32>,
+}
+
+/// Compression algorithm for witness generator artifacts.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArtifactsCompressionAlgorithm {
+    /// Artifacts are stored uncompressed.
+    #[default]
+    None,
+    Zstd,
+    Lz4,
 }
 
 #[derive(Debug)]
@@ -82,7 +118,58 @@ impl WitnessGenerationTimeouts {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct WitnessGenerationMaxAttempts {
+    basic: u32,
+    leaf: u32,
+    node: u32,
+    recursion_tip: u32,
+    scheduler: u32,
+}
+
+impl WitnessGenerationMaxAttempts {
+    pub fn basic(&self) -> u32 {
+        self.basic
+    }
+
+    pub fn leaf(&self) -> u32 {
+        self.leaf
+    }
+
+    pub fn node(&self) -> u32 {
+        self.node
+    }
+
+    pub fn recursion_tip(&self) -> u32 {
+        self.recursion_tip
+    }
+
+    pub fn scheduler(&self) -> u32 {
+        self.scheduler
+    }
+
+    pub fn for_round(&self, round: AggregationRound) -> u32 {
+        match round {
+            AggregationRound::BasicCircuits => self.basic,
+            AggregationRound::LeafAggregation => self.leaf,
+            AggregationRound::NodeAggregation => self.node,
+            AggregationRound::RecursionTip => self.recursion_tip,
+            AggregationRound::Scheduler => self.scheduler,
+        }
+    }
+}
+
 impl FriWitnessGeneratorConfig {
+    pub fn witness_generation_max_attempts(&self) -> WitnessGenerationMaxAttempts {
+        WitnessGenerationMaxAttempts {
+            basic: self.basic_max_attempts.unwrap_or(self.max_attempts),
+            leaf: self.leaf_max_attempts.unwrap_or(self.max_attempts),
+            node: self.node_max_attempts.unwrap_or(self.max_attempts),
+            recursion_tip: self.recursion_tip_max_attempts.unwrap_or(self.max_attempts),
+            scheduler: self.scheduler_max_attempts.unwrap_or(self.max_attempts),
+        }
+    }
+
     pub fn witness_generation_timeouts(&self) -> WitnessGenerationTimeouts {
         WitnessGenerationTimeouts::new(
             self.basic_generation_timeout_in_secs
@@ -111,3 +198,43 @@ impl FriWitnessGeneratorConfig {
         500
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_max_attempts(
+        basic_max_attempts: Option<u32>,
+        scheduler_max_attempts: Option<u32>,
+    ) -> FriWitnessGeneratorConfig {
+        let mut config: FriWitnessGeneratorConfig = serde_json::from_value(serde_json::json!({
+            "generation_timeout_in_secs": 900,
+            "max_attempts": 5,
+        }))
+        .unwrap();
+        config.basic_max_attempts = basic_max_attempts;
+        config.scheduler_max_attempts = scheduler_max_attempts;
+        config
+    }
+
+    #[test]
+    fn max_attempts_fall_back_to_global_value() {
+        let max_attempts = config_with_max_attempts(None, None).witness_generation_max_attempts();
+        for round in AggregationRound::ALL_ROUNDS {
+            assert_eq!(max_attempts.for_round(round), 5, "{round:?}");
+        }
+    }
+
+    #[test]
+    fn max_attempts_are_overridden_per_round() {
+        let max_attempts =
+            config_with_max_attempts(Some(2), Some(10)).witness_generation_max_attempts();
+        assert_eq!(max_attempts.basic(), 2);
+        assert_eq!(max_attempts.leaf(), 5);
+        assert_eq!(max_attempts.node(), 5);
+        assert_eq!(max_attempts.recursion_tip(), 5);
+        assert_eq!(max_attempts.scheduler(), 10);
+        assert_eq!(max_attempts.for_round(AggregationRound::BasicCircuits), 2);
+        assert_eq!(max_attempts.for_round(AggregationRound::Scheduler), 10);
+    }
+}
@@ -20,6 +20,23 @@ pub struct FriWitnessVectorGeneratorConfig {
     // specialized group id for this witness vector generator.
     // witness vector generator running the same (circuit id, round) shall have same group id.
     pub specialized_group_id: u8,
+
+    /// Format used to send witness vectors to provers over the network.
+    #[serde(default)]
+    pub transfer_format: WitnessVectorTransferFormat,
+}
+
+/// Format of witness vectors sent to provers over the network. Provers detect the format of each transfer
+/// automatically, so non-raw formats can be enabled once all provers are updated to support them.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WitnessVectorTransferFormat {
+    /// `bincode`-serialized witness vector delimited by the end of the stream. Supported by all provers.
+    #[default]
+    Raw,
+    /// Witness vector framed with its length and checksum.
+    Framed,
+    /// Witness vector compressed with zstd and framed with its length and checksum.
+    FramedZstd,
 }
 
 impl FriWitnessVectorGeneratorConfig {
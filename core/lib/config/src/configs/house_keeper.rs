@@ -4,4 +4,12 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct HouseKeeperConfig {
     pub l1_batch_metrics_reporting_interval_ms: u64,
+    /// Interval between checks for orphaned object store blobs, i.e. blobs not referenced from Postgres.
+    /// If not set, orphaned blobs are not checked.
+    #[serde(default)]
+    pub orphaned_blobs_check_interval_ms: Option<u64>,
+    /// Period after which detected orphaned blobs are removed from the object store. If not set,
+    /// orphaned blobs are only reported.
+    #[serde(default)]
+    pub orphaned_blobs_removal_grace_period_secs: Option<u64>,
 }
@@ -27,7 +27,8 @@ pub use self::{
     prover_job_monitor::ProverJobMonitorConfig,
     pruning::PruningConfig,
     secrets::{
-        ContractVerifierSecrets, DataAvailabilitySecrets, DatabaseSecrets, L1Secrets, Secrets,
+        ContractVerifierSecrets, DataAvailabilitySecrets, DatabaseSecrets, L1Secrets,
+        ProofDataHandlerSecrets, Secrets,
     },
     snapshot_recovery::SnapshotRecoveryConfig,
     snapshots_creator::SnapshotsCreatorConfig,
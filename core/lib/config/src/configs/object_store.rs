@@ -16,6 +16,11 @@ pub struct ObjectStoreConfig {
     /// **Important.** Mirroring logic assumes that objects in the underlying store are immutable. If this is not the case,
     /// the mirrored objects may become stale.
     pub local_mirror_path: Option<String>,
+    /// Whether to write objects supporting versioned envelopes (e.g., witness inputs and final proofs) in the versioned
+    /// format. Objects in both formats are always readable, so this should only be enabled once all components
+    /// reading the objects are upgraded.
+    #[serde(default)]
+    pub write_versioned_envelopes: bool,
 }
 
 impl ObjectStoreConfig {
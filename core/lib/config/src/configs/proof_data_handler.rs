@@ -59,7 +59,12 @@ impl TeeConfig {
 #[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct ProofDataHandlerConfig {
     pub http_port: u16,
+    /// WebSocket URL of the prover gateway.
     pub api_url: String,
+    /// URLs of prover gateways to fail over to if the gateway at `api_url` is unavailable, in the order of preference.
+    /// Proofs are received from all configured gateways.
+    #[serde(default)]
+    pub fallback_api_urls: Vec<String>,
     pub batch_readiness_check_interval_in_secs: u16,
     pub proof_generation_timeout_in_secs: u16,
     pub retry_connection_interval_in_secs: u16,
@@ -70,6 +75,13 @@ pub struct ProofDataHandlerConfig {
 }
 
 impl ProofDataHandlerConfig {
+    /// Returns URLs of all configured prover gateways, starting from the primary one.
+    pub fn gateway_urls(&self) -> Vec<String> {
+        let mut urls = vec![self.api_url.clone()];
+        urls.extend(self.fallback_api_urls.iter().cloned());
+        urls
+    }
+
     pub fn proof_generation_timeout(&self) -> Duration {
         Duration::from_secs(self.proof_generation_timeout_in_secs as u64)
     }
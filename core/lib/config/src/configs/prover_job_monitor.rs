@@ -32,6 +32,15 @@ pub struct ProverJobMonitorConfig {
         default = "ProverJobMonitorConfig::default_prover_jobs_archiver_archive_jobs_after_ms"
     )]
     pub prover_jobs_archiver_archive_jobs_after_ms: u64,
+    /// The interval between runs for Compression Jobs Archiver.
+    #[serde(default = "ProverJobMonitorConfig::default_compression_jobs_archiver_run_interval_ms")]
+    pub compression_jobs_archiver_run_interval_ms: u64,
+    /// The amount of time after a proof was sent to the server, after which its compression and witness generator jobs
+    /// can be archived.
+    #[serde(
+        default = "ProverJobMonitorConfig::default_compression_jobs_archiver_archive_jobs_after_ms"
+    )]
+    pub compression_jobs_archiver_archive_jobs_after_ms: u64,
     /// The interval between runs for Proof Compressor Job Requeuer.
     #[serde(
         default = "ProverJobMonitorConfig::default_proof_compressor_job_requeuer_run_interval_ms"
@@ -115,6 +124,27 @@ impl ProverJobMonitorConfig {
         172_800_000
     }
 
+    /// The interval between runs for Compression Jobs Archiver.
+    pub fn compression_jobs_archiver_run_interval(&self) -> Duration {
+        Duration::from_millis(self.compression_jobs_archiver_run_interval_ms)
+    }
+
+    /// Default compression_jobs_archiver_run_interval_ms -- 30 minutes
+    pub fn default_compression_jobs_archiver_run_interval_ms() -> u64 {
+        1_800_000
+    }
+
+    /// The amount of time after a proof was sent to the server, after which its compression and witness generator jobs
+    /// can be archived.
+    pub fn archive_compression_jobs_duration(&self) -> Duration {
+        Duration::from_millis(self.compression_jobs_archiver_archive_jobs_after_ms)
+    }
+
+    /// Default compression_jobs_archiver_archive_jobs_after_ms -- 2 days
+    pub fn default_compression_jobs_archiver_archive_jobs_after_ms() -> u64 {
+        172_800_000
+    }
+
     /// The interval between runs for Proof Compressor Job Requeuer.
     pub fn proof_compressor_job_requeuer_run_interval(&self) -> Duration {
         Duration::from_millis(self.proof_compressor_job_requeuer_run_interval_ms)
@@ -33,6 +33,13 @@ pub struct ContractVerifierSecrets {
     pub etherscan_api_key: Option<APIKey>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofDataHandlerSecrets {
+    /// API key that authenticates requests to the admin endpoints of the proof data handler.
+    /// If not set, the admin endpoints are disabled.
+    pub admin_api_key: Option<APIKey>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Secrets {
     pub consensus: Option<ConsensusSecrets>,
@@ -40,6 +47,7 @@ pub struct Secrets {
     pub l1: Option<L1Secrets>,
     pub data_availability: Option<DataAvailabilitySecrets>,
     pub contract_verifier: Option<ContractVerifierSecrets>,
+    pub proof_data_handler: Option<ProofDataHandlerSecrets>,
 }
 
 impl DatabaseSecrets {
@@ -1,4 +1,7 @@
-use std::num::NonZeroUsize;
+use std::{
+    num::{NonZeroU64, NonZeroUsize},
+    time::Duration,
+};
 
 use serde::Deserialize;
 use zksync_basic_types::L1BatchNumber;
@@ -26,6 +29,23 @@ pub struct PostgresRecoveryConfig {
     /// reduce this factor to about 5 if snapshot recovery overloads I/O capacity of the node. Conversely,
     /// if I/O capacity of your infra is high, you may increase concurrency to speed up Postgres recovery.
     pub max_concurrency: Option<NonZeroUsize>,
+    /// Maximum number of storage log chunks concurrently inserted into Postgres. Can be used to reduce DB load
+    /// while still downloading chunks with `max_concurrency`. If not set, equals `max_concurrency`.
+    pub max_concurrent_applications: Option<NonZeroUsize>,
+    /// Maximum number of storage logs inserted into Postgres in a single query. If not set, each storage logs chunk
+    /// is inserted using a single query.
+    pub insertion_batch_size: Option<NonZeroUsize>,
+    /// Maximum bandwidth (in bytes per second) used to download snapshot storage log chunks from the object store.
+    /// If not set, downloads are not throttled.
+    pub max_download_bandwidth: Option<NonZeroU64>,
+    /// If set, applying storage log chunks will be paused while the replay lag of Postgres replicas exceeds this value.
+    pub max_replication_lag_sec: Option<u64>,
+}
+
+impl PostgresRecoveryConfig {
+    pub fn max_replication_lag(&self) -> Option<Duration> {
+        self.max_replication_lag_sec.map(Duration::from_secs)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
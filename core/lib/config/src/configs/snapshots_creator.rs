@@ -20,6 +20,11 @@ pub struct SnapshotsCreatorConfig {
     pub storage_logs_chunk_size: u64,
     #[serde(default = "SnapshotsCreatorConfig::concurrent_queries_count")]
     pub concurrent_queries_count: u32,
+    /// Whether to additionally export storage logs, accounts and factory deps in the Parquet format.
+    /// Parquet files are chunked in the same way as storage logs used for recovery and are placed next to them
+    /// in the object store. Accounts are only exported for version 0 snapshots, since they require key preimages.
+    #[serde(default)]
+    pub parquet_export: bool,
     pub object_store: Option<ObjectStoreConfig>,
 }
 
@@ -62,6 +62,11 @@ impl Wallet {
 pub struct EthSender {
     pub operator: Wallet,
     pub blob_operator: Option<Wallet>,
+    /// Operator key that is being rotated out. If set, `eth_sender` keeps tracking (and, if necessary, resending)
+    /// in-flight transactions signed by this key, while all new transactions are signed by `operator`.
+    pub previous_operator: Option<Wallet>,
+    /// Same as `previous_operator`, but for the blob operator key.
+    pub previous_blob_operator: Option<Wallet>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -89,6 +94,8 @@ impl Wallets {
                 blob_operator: Some(
                     Wallet::from_private_key_bytes(H256::repeat_byte(0x2), None).unwrap(),
                 ),
+                previous_operator: None,
+                previous_blob_operator: None,
             }),
             state_keeper: Some(StateKeeper {
                 fee_account: AddressWallet::from_address(H160::repeat_byte(0x3)),
@@ -114,7 +114,16 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             whitelisted_tokens_for_aa: self.sample_range(rng).map(|_| rng.gen()).collect(),
             api_namespaces: self
                 .sample_opt(|| self.sample_range(rng).map(|_| self.sample(rng)).collect()),
+            disabled_api_namespaces: self
+                .sample_opt(|| self.sample_range(rng).map(|_| self.sample(rng)).collect()),
             extended_api_tracing: self.sample(rng),
+            pruned_calls_snapshot_chunks_budget: self.sample(rng),
+            max_trace_size_bytes: self.sample(rng),
+            websocket_max_batch_request_size: self.sample(rng),
+            snapshots_download_url_ttl_sec: self.sample(rng),
+            snapshots_requests_per_minute_limit: self.sample(rng),
+            snapshots_rate_limit_proxy_hops: self.sample(rng),
+            strict_quantity_validation: self.sample(rng),
         }
     }
 }
@@ -194,6 +203,11 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            save_storage_accesses: self.sample(rng),
+            receipts_cache_l2_blocks: self.sample(rng),
+            save_failed_call_traces_only: self.sample(rng),
+            call_traces_retention_days: self.sample(rng),
+            compress_call_traces: self.sample(rng),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -245,6 +259,14 @@ impl Distribution<configs::ContractVerifierConfig> for EncodeDist {
             prometheus_port: self.sample(rng),
             port: self.sample(rng),
             etherscan_api_url: self.sample(rng),
+            similar_match_discovery_enabled: self.sample(rng),
+            sourcify_api_url: self.sample(rng),
+            sourcify_chain_id: self.sample(rng),
+            sourcify_import_enabled: self.sample(rng),
+            sourcify_export_enabled: self.sample(rng),
+            sourcify_sync_interval: self.sample(rng),
+            admin_port: self.sample(rng),
+            priority_requesters: self.sample_collect(rng),
         }
     }
 }
@@ -340,6 +362,7 @@ impl Distribution<configs::ExperimentalVmConfig> for EncodeDist {
             playground: self.sample(rng),
             state_keeper_fast_vm_mode: gen_fast_vm_mode(rng),
             api_fast_vm_mode: gen_fast_vm_mode(rng),
+            state_keeper_shadow_parallel_execution: self.sample(rng),
         }
     }
 }
@@ -412,6 +435,7 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             max_eth_tx_data_size: self.sample(rng),
             max_aggregated_blocks_to_commit: self.sample(rng),
             max_aggregated_blocks_to_execute: self.sample(rng),
+            max_aggregated_skipped_proofs: self.sample(rng),
             aggregated_block_commit_deadline: self.sample(rng),
             aggregated_block_prove_deadline: self.sample(rng),
             aggregated_block_execute_deadline: self.sample(rng),
@@ -423,6 +447,8 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             tx_aggregation_only_prove_and_execute: false,
             time_in_mempool_in_l1_blocks_cap: self.sample(rng),
             is_verifier_pre_fflonk: self.sample(rng),
+            max_commit_base_fee_per_gas: self.sample(rng),
+            max_commit_postponement_sec: self.sample(rng),
         }
     }
 }
@@ -444,6 +470,7 @@ impl Distribution<configs::eth_sender::GasAdjusterConfig> for EncodeDist {
             max_blob_base_fee: self.sample(rng),
             // TODO(EVM-676): generate it randomly once this value is used
             settlement_mode: Default::default(),
+            gateway_settlement_supported: self.sample(rng),
         }
     }
 }
@@ -453,6 +480,7 @@ impl Distribution<configs::EthWatchConfig> for EncodeDist {
         configs::EthWatchConfig {
             confirmations_for_eth_event: self.sample(rng),
             eth_node_poll_interval: self.sample(rng),
+            address_policy_registry_addr: self.sample_opt(|| rng.gen()),
         }
     }
 }
@@ -466,9 +494,12 @@ impl Distribution<configs::FriProofCompressorConfig> for EncodeDist {
             prometheus_push_interval_ms: self.sample(rng),
             generation_timeout_in_secs: self.sample(rng),
             max_attempts: self.sample(rng),
+            requeue_max_attempts: self.sample(rng),
+            processing_timeout_in_secs: self.sample(rng),
             universal_setup_path: self.sample(rng),
             universal_setup_download_url: self.sample(rng),
             verify_wrapper_proof: self.sample(rng),
+            checkpointing_enabled: self.sample(rng),
         }
     }
 }
@@ -506,6 +537,7 @@ impl Distribution<configs::FriProverConfig> for EncodeDist {
             witness_vector_receiver_port: self.sample(rng),
             zone_read_url: self.sample(rng),
             availability_check_interval_in_secs: self.sample(rng),
+            setup_keys_download_url: self.sample(rng),
             prover_object_store: self.sample(rng),
             cloud_type: self.sample(rng),
         }
@@ -614,9 +646,18 @@ impl Distribution<configs::FriWitnessGeneratorConfig> for EncodeDist {
             recursion_tip_generation_timeout_in_secs: self.sample(rng),
             scheduler_generation_timeout_in_secs: self.sample(rng),
             max_attempts: self.sample(rng),
+            basic_max_attempts: self.sample(rng),
+            leaf_max_attempts: self.sample(rng),
+            node_max_attempts: self.sample(rng),
+            recursion_tip_max_attempts: self.sample(rng),
+            scheduler_max_attempts: self.sample(rng),
             last_l1_batch_to_process: self.sample(rng),
             prometheus_listener_port: self.sample(rng),
             max_circuits_in_flight: self.sample(rng),
+            prefetch_depth: self.sample(rng),
+            max_proofs_in_memory: self.sample(rng),
+            artifacts_compression: self.sample(rng),
+            artifacts_compression_level: self.sample(rng),
         }
     }
 }
@@ -631,6 +672,37 @@ impl Distribution<configs::FriWitnessVectorGeneratorConfig> for EncodeDist {
             prometheus_pushgateway_url: self.sample(rng),
             prometheus_push_interval_ms: self.sample(rng),
             specialized_group_id: self.sample(rng),
+            transfer_format: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::fri_witness_vector_generator::WitnessVectorTransferFormat>
+    for EncodeDist
+{
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::fri_witness_vector_generator::WitnessVectorTransferFormat {
+        type T = configs::fri_witness_vector_generator::WitnessVectorTransferFormat;
+        match rng.gen_range(0..3) {
+            0 => T::Raw,
+            1 => T::Framed,
+            _ => T::FramedZstd,
+        }
+    }
+}
+
+impl Distribution<configs::fri_witness_generator::ArtifactsCompressionAlgorithm> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::fri_witness_generator::ArtifactsCompressionAlgorithm {
+        type T = configs::fri_witness_generator::ArtifactsCompressionAlgorithm;
+        match rng.gen_range(0..3) {
+            0 => T::None,
+            1 => T::Zstd,
+            _ => T::Lz4,
         }
     }
 }
@@ -639,6 +711,8 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::house_keeper::HouseKeeperConfig {
         configs::house_keeper::HouseKeeperConfig {
             l1_batch_metrics_reporting_interval_ms: self.sample(rng),
+            orphaned_blobs_check_interval_ms: self.sample(rng),
+            orphaned_blobs_removal_grace_period_secs: self.sample(rng),
         }
     }
 }
@@ -670,6 +744,7 @@ impl Distribution<configs::ObjectStoreConfig> for EncodeDist {
             mode: self.sample(rng),
             max_retries: self.sample(rng),
             local_mirror_path: self.sample(rng),
+            write_versioned_envelopes: self.sample(rng),
         }
     }
 }
@@ -679,6 +754,7 @@ impl Distribution<configs::ProofDataHandlerConfig> for EncodeDist {
         configs::ProofDataHandlerConfig {
             http_port: self.sample(rng),
             api_url: self.sample(rng),
+            fallback_api_urls: self.sample_collect(rng),
             batch_readiness_check_interval_in_secs: self.sample(rng),
             proof_generation_timeout_in_secs: self.sample(rng),
             retry_connection_interval_in_secs: self.sample(rng),
@@ -699,6 +775,7 @@ impl Distribution<configs::SnapshotsCreatorConfig> for EncodeDist {
             version: if rng.gen() { 0 } else { 1 },
             storage_logs_chunk_size: self.sample(rng),
             concurrent_queries_count: self.sample(rng),
+            parquet_export: self.sample(rng),
             object_store: self.sample(rng),
         }
     }
@@ -831,6 +908,17 @@ impl Distribution<configs::consensus::ConsensusConfig> for EncodeDist {
             genesis_spec: self.sample(rng),
             rpc: self.sample(rng),
             debug_page_addr: self.sample(rng),
+            block_archive: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::consensus::BlockArchiveConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::consensus::BlockArchiveConfig {
+        configs::consensus::BlockArchiveConfig {
+            retention_blocks: self.sample(rng),
+            interval: self.sample(rng),
+            object_store: self.sample(rng),
         }
     }
 }
@@ -877,6 +965,14 @@ impl Distribution<configs::secrets::DatabaseSecrets> for EncodeDist {
     }
 }
 
+impl Distribution<configs::secrets::ProofDataHandlerSecrets> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::secrets::ProofDataHandlerSecrets {
+        configs::secrets::ProofDataHandlerSecrets {
+            admin_api_key: Some(<APIKey as From<String>>::from(self.sample(rng))),
+        }
+    }
+}
+
 impl Distribution<configs::secrets::Secrets> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::secrets::Secrets {
         use configs::secrets::Secrets;
@@ -886,6 +982,7 @@ impl Distribution<configs::secrets::Secrets> for EncodeDist {
             l1: self.sample_opt(|| self.sample(rng)),
             data_availability: self.sample_opt(|| self.sample(rng)),
             contract_verifier: self.sample_opt(|| self.sample(rng)),
+            proof_data_handler: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -915,6 +1012,8 @@ impl Distribution<configs::wallets::EthSender> for EncodeDist {
         configs::wallets::EthSender {
             operator: self.sample(rng),
             blob_operator: self.sample_opt(|| self.sample(rng)),
+            previous_operator: self.sample_opt(|| self.sample(rng)),
+            previous_blob_operator: self.sample_opt(|| self.sample(rng)),
         }
     }
 }
@@ -950,6 +1049,9 @@ impl Distribution<configs::en_config::ENConfig> for EncodeDist {
             main_node_rate_limit_rps: self.sample_opt(|| rng.gen()),
             bridge_addresses_refresh_interval_sec: self.sample_opt(|| rng.gen()),
             gateway_chain_id: self.sample_opt(|| SLChainId(rng.gen())),
+            archive_mode: rng.gen(),
+            tx_proxy_queue_ttl_sec: self.sample_opt(|| rng.gen()),
+            tx_proxy_queue_capacity: self.sample_opt(|| rng.gen()),
         }
     }
 }
@@ -1042,6 +1144,10 @@ impl Distribution<configs::snapshot_recovery::PostgresRecoveryConfig> for Encode
     ) -> configs::snapshot_recovery::PostgresRecoveryConfig {
         configs::snapshot_recovery::PostgresRecoveryConfig {
             max_concurrency: self.sample_opt(|| rng.gen()),
+            max_concurrent_applications: self.sample_opt(|| rng.gen()),
+            insertion_batch_size: self.sample_opt(|| rng.gen()),
+            max_download_bandwidth: self.sample_opt(|| rng.gen()),
+            max_replication_lag_sec: self.sample(rng),
         }
     }
 }
@@ -1144,6 +1250,8 @@ impl Distribution<configs::prover_job_monitor::ProverJobMonitorConfig> for Encod
             gpu_prover_archiver_archive_prover_after_ms: self.sample(rng),
             prover_jobs_archiver_run_interval_ms: self.sample(rng),
             prover_jobs_archiver_archive_jobs_after_ms: self.sample(rng),
+            compression_jobs_archiver_run_interval_ms: self.sample(rng),
+            compression_jobs_archiver_archive_jobs_after_ms: self.sample(rng),
             proof_compressor_job_requeuer_run_interval_ms: self.sample(rng),
             prover_job_requeuer_run_interval_ms: self.sample(rng),
             witness_generator_job_requeuer_run_interval_ms: self.sample(rng),
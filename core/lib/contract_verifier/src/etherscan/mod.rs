@@ -19,7 +19,7 @@ use crate::metrics::API_CONTRACT_VERIFIER_METRICS;
 
 pub mod client;
 pub mod errors;
-mod solc_versions_fetcher;
+pub(crate) mod solc_versions_fetcher;
 pub mod types;
 pub mod utils;
 
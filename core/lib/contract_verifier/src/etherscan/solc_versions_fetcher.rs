@@ -112,7 +112,7 @@ const SOLC_VERSIONS: &[(&str, &str)] = &[
 /// Fetches solc long versions from the official solc-bin repository. Long build versions are required for verifying
 /// contracts on Etherscan.
 #[derive(Debug, Clone)]
-pub(super) struct SolcVersionsFetcher {
+pub(crate) struct SolcVersionsFetcher {
     http_client: Client,
     solc_long_versions: HashMap<String, String>,
     last_update: Option<Instant>,
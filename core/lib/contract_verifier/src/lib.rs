@@ -2,7 +2,10 @@
 
 use std::{
     fmt,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::{Duration, Instant},
 };
 
@@ -37,6 +40,8 @@ pub mod error;
 pub mod etherscan;
 mod metrics;
 mod resolver;
+pub mod similar_match;
+pub mod sourcify;
 #[cfg(test)]
 mod tests;
 
@@ -119,6 +124,9 @@ pub struct ContractVerifier {
     connection_pool: ConnectionPool<Core>,
     compiler_resolver: Arc<dyn CompilerResolver>,
     etherscan_verifier_enabled: bool,
+    /// Whether the next picked job should preferably come from the priority lane. Toggled on each pick,
+    /// so that neither user-facing requests nor requests from trusted requesters are starved.
+    prefer_priority_lane: Arc<AtomicBool>,
 }
 
 impl ContractVerifier {
@@ -163,6 +171,7 @@ impl ContractVerifier {
             connection_pool,
             compiler_resolver,
             etherscan_verifier_enabled,
+            prefer_priority_lane: Arc::default(),
         })
     }
 
@@ -702,9 +711,13 @@ impl JobProcessor for ContractVerifier {
         // Considering that jobs that reach compilation timeout will be executed in
         // `compilation_timeout` + `non_compilation_time_overhead` (which is significantly less than `compilation_timeout`),
         // we re-pick up jobs that are being executed for a bit more than `compilation_timeout`.
+        let prefer_priority_lane = !self.prefer_priority_lane.fetch_xor(true, Ordering::Relaxed);
         let job = connection
             .contract_verification_dal()
-            .get_next_queued_verification_request(self.compilation_timeout + TIME_OVERHEAD)
+            .get_next_queued_verification_request(
+                self.compilation_timeout + TIME_OVERHEAD,
+                prefer_priority_lane,
+            )
             .await?;
         Ok(job.map(|job| (job.id, job)))
     }
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, Counter, Histogram, LabeledFamily, Metrics};
+use vise::{Buckets, Counter, Gauge, Histogram, LabeledFamily, Metrics};
 
 // Starting bucket from 5 sec as there is a 5 second pause between
 // the verification request and the time verification status is checked for the first time.
@@ -19,6 +19,14 @@ pub(crate) struct ApiContractVerifierMetrics {
     pub failed_verifications: LabeledFamily<&'static str, Counter, 1>,
     #[metrics(labels = ["service_name"])]
     pub successful_verifications: LabeledFamily<&'static str, Counter, 1>,
+    /// Number of similar matches discovered for unverified contracts, labeled by the match status.
+    #[metrics(labels = ["status"])]
+    pub similar_matches: LabeledFamily<&'static str, Counter, 1>,
+    /// Last L2 block processed by the similar match discovery.
+    pub similar_match_last_processed_l2_block: Gauge<u64>,
+    /// Number of contracts synced with Sourcify, labeled by the sync direction and outcome.
+    #[metrics(labels = ["direction", "status"])]
+    pub sourcify_sync_records: LabeledFamily<(&'static str, &'static str), Counter, 2>,
 }
 
 #[vise::register]
@@ -0,0 +1,164 @@
+//! Background discovery of "similar matches", i.e. unverified contracts which bytecode matches a verified contract
+//! exactly or up to the metadata hash. Discovered matches are exposed via the contract verification API.
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use zksync_dal::{
+    contract_verification_dal::{SimilarMatchRecord, UnverifiedDeployedContract},
+    Connection, ConnectionPool, Core, CoreDal,
+};
+use zksync_types::{
+    bytecode::{trim_bytecode, BytecodeHash},
+    contract_verification::{api::SimilarMatchStatus, contract_identifier::ContractIdentifier},
+    Address, L2BlockNumber,
+};
+
+use crate::metrics::API_CONTRACT_VERIFIER_METRICS;
+
+/// Scans deployed contracts in the ascending L2 block order and matches them against verified contracts.
+#[derive(Debug)]
+pub struct SimilarMatchFinder {
+    connection_pool: ConnectionPool<Core>,
+    poll_interval: Duration,
+    max_l2_blocks_per_iteration: u32,
+}
+
+impl SimilarMatchFinder {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+    const DEFAULT_MAX_L2_BLOCKS_PER_ITERATION: u32 = 100;
+
+    pub fn new(connection_pool: ConnectionPool<Core>) -> Self {
+        Self {
+            connection_pool,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            max_l2_blocks_per_iteration: Self::DEFAULT_MAX_L2_BLOCKS_PER_ITERATION,
+        }
+    }
+
+    /// Processes the next range of L2 blocks starting from `next_l2_block`. Returns the next L2 block to process,
+    /// or `None` if there are no new sealed L2 blocks.
+    pub(crate) async fn process_next_l2_blocks(
+        &self,
+        next_l2_block: L2BlockNumber,
+    ) -> anyhow::Result<Option<L2BlockNumber>> {
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("contract_verifier")
+            .await?;
+        let Some(sealed_l2_block) = storage.blocks_dal().get_sealed_l2_block_number().await? else {
+            return Ok(None);
+        };
+        if sealed_l2_block < next_l2_block {
+            return Ok(None);
+        }
+        let last_l2_block =
+            sealed_l2_block.min(next_l2_block + (self.max_l2_blocks_per_iteration - 1));
+
+        let contracts = storage
+            .contract_verification_dal()
+            .get_unverified_deployed_contracts(next_l2_block..=last_l2_block)
+            .await?;
+        let mut records = Vec::with_capacity(contracts.len());
+        for contract in contracts {
+            let matched = Self::find_match(&mut storage, &contract).await?;
+            if let Some((_, status)) = matched {
+                let status = match status {
+                    SimilarMatchStatus::Perfect => "perfect",
+                    SimilarMatchStatus::Partial => "partial",
+                };
+                API_CONTRACT_VERIFIER_METRICS.similar_matches[&status].inc();
+            }
+            records.push(SimilarMatchRecord {
+                contract_address: contract.address,
+                l2_block_number: contract.l2_block_number,
+                matched,
+            });
+        }
+        storage
+            .contract_verification_dal()
+            .insert_similar_matches(&records)
+            .await?;
+
+        tracing::debug!(
+            "Processed L2 blocks {next_l2_block}..={last_l2_block} with {} unverified contracts",
+            records.len()
+        );
+        API_CONTRACT_VERIFIER_METRICS
+            .similar_match_last_processed_l2_block
+            .set(last_l2_block.0.into());
+        Ok(Some(last_l2_block + 1))
+    }
+
+    async fn find_match(
+        storage: &mut Connection<'_, Core>,
+        contract: &UnverifiedDeployedContract,
+    ) -> anyhow::Result<Option<(Address, SimilarMatchStatus)>> {
+        // Bogus bytecodes cannot match any verified contract, so they are logged and skipped.
+        let bytecode_hash = match BytecodeHash::try_from(contract.bytecode_hash) {
+            Ok(hash) => hash,
+            Err(err) => {
+                tracing::warn!(
+                    "Contract {:?} has invalid bytecode hash: {err:#}",
+                    contract.address
+                );
+                return Ok(None);
+            }
+        };
+        let deployed_bytecode = match trim_bytecode(bytecode_hash, &contract.bytecode) {
+            Ok(bytecode) => bytecode,
+            Err(err) => {
+                tracing::warn!(
+                    "Contract {:?} has invalid bytecode: {err:#}",
+                    contract.address
+                );
+                return Ok(None);
+            }
+        };
+        let identifier =
+            ContractIdentifier::from_bytecode(bytecode_hash.marker(), deployed_bytecode);
+
+        let matched = storage
+            .contract_verification_dal()
+            .find_verified_contract_by_bytecode(
+                identifier.bytecode_keccak256,
+                identifier.bytecode_without_metadata_keccak256,
+            )
+            .await?;
+        // Partial matches are only meaningful if metadata was detected in the deployed bytecode; otherwise,
+        // the contract bytecode is a prefix of the verified one, which is not a match.
+        Ok(matched.filter(|(_, status)| {
+            *status == SimilarMatchStatus::Perfect || identifier.detected_metadata.is_some()
+        }))
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("contract_verifier")
+            .await?;
+        let mut next_l2_block = storage
+            .contract_verification_dal()
+            .get_last_similar_match_l2_block()
+            .await?
+            .map_or(L2BlockNumber(0), |number| number + 1);
+        drop(storage);
+        tracing::info!("Starting similar match discovery from L2 block #{next_l2_block}");
+
+        while !*stop_receiver.borrow_and_update() {
+            if let Some(next) = self.process_next_l2_blocks(next_l2_block).await? {
+                next_l2_block = next;
+                continue;
+            }
+            // No new L2 blocks; wait until they appear.
+            if tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, similar match discovery is shutting down");
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use zksync_types::Address;
+
+use super::types::{
+    SourcifyCheckResponse, SourcifyFilesResponse, SourcifyMatch, SourcifySolcJsonRequest,
+    SourcifyVerifyResponse,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub(super) enum SourcifyError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded,
+    #[error("Sourcify server error ({status}): {message}")]
+    ServerError { status: StatusCode, message: String },
+    #[error("Sourcify rejected the request ({status}): {message}")]
+    Rejected { status: StatusCode, message: String },
+    #[error("Sourcify verification failed: {0}")]
+    VerificationFailed(String),
+}
+
+impl SourcifyError {
+    /// Returns whether the error is caused by the network or Sourcify availability, so that the request should be retried.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Reqwest(err) => !err.is_decode(),
+            Self::RateLimitExceeded | Self::ServerError { .. } => true,
+            Self::Rejected { .. } | Self::VerificationFailed(_) => false,
+        }
+    }
+}
+
+async fn extract_result<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, SourcifyError> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(SourcifyError::RateLimitExceeded);
+    }
+    if status.is_server_error() {
+        let message = response.text().await.unwrap_or_default();
+        return Err(SourcifyError::ServerError { status, message });
+    }
+    if !status.is_success() {
+        let message = response.text().await.unwrap_or_default();
+        return Err(SourcifyError::Rejected { status, message });
+    }
+    Ok(response.json().await?)
+}
+
+/// Client for the Sourcify server API.
+#[derive(Debug, Clone)]
+pub(super) struct SourcifyClient {
+    api_url: String,
+    chain_id: u64,
+    http_client: Client,
+}
+
+impl SourcifyClient {
+    /// Maximum number of addresses that can be checked in a single request.
+    pub const MAX_ADDRESSES_PER_CHECK: usize = 50;
+
+    pub fn new(api_url: String, chain_id: u64) -> Self {
+        let http_client = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(60))
+            .build()
+            .expect("Failed to create a reqwest client for SourcifyClient");
+        Self {
+            api_url: api_url.trim_end_matches('/').to_owned(),
+            chain_id,
+            http_client,
+        }
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Checks which of the provided contracts are verified on Sourcify. Returns the match for each address
+    /// in the same order as `addresses`.
+    pub async fn check_by_addresses(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<Option<SourcifyMatch>>, SourcifyError> {
+        let addresses_param = addresses
+            .iter()
+            .map(|address| format!("{address:?}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let response = self
+            .http_client
+            .get(format!("{}/check-by-addresses", self.api_url))
+            .query(&[
+                ("addresses", addresses_param),
+                ("chainIds", self.chain_id.to_string()),
+            ])
+            .send()
+            .await?;
+        let response: Vec<SourcifyCheckResponse> = extract_result(response).await?;
+
+        Ok(addresses
+            .iter()
+            .map(|address| {
+                response
+                    .iter()
+                    .find(|entry| entry.address == *address)
+                    .and_then(|entry| entry.match_for_chain(self.chain_id))
+            })
+            .collect())
+    }
+
+    /// Fetches source files for a verified contract (with either a perfect or partial match).
+    pub async fn get_files(
+        &self,
+        address: Address,
+    ) -> Result<SourcifyFilesResponse, SourcifyError> {
+        let response = self
+            .http_client
+            .get(format!(
+                "{}/files/any/{}/{address:?}",
+                self.api_url, self.chain_id
+            ))
+            .send()
+            .await?;
+        extract_result(response).await
+    }
+
+    /// Submits a contract for verification using the standard JSON input.
+    pub async fn verify_solc_json(
+        &self,
+        request: &SourcifySolcJsonRequest,
+    ) -> Result<SourcifyVerifyResponse, SourcifyError> {
+        let response = self
+            .http_client
+            .post(format!("{}/verify/solc-json", self.api_url))
+            .json(request)
+            .send()
+            .await?;
+        extract_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Response;
+
+    use super::*;
+
+    fn create_response(status: StatusCode, body: &str) -> reqwest::Response {
+        Response::builder()
+            .status(status)
+            .body(body.to_owned())
+            .unwrap()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn extracting_result_errors() {
+        let response = create_response(StatusCode::TOO_MANY_REQUESTS, "");
+        let err = extract_result::<serde_json::Value>(response)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SourcifyError::RateLimitExceeded));
+        assert!(err.is_retryable());
+
+        let response = create_response(StatusCode::BAD_GATEWAY, "oops");
+        let err = extract_result::<serde_json::Value>(response)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SourcifyError::ServerError { .. }));
+        assert!(err.is_retryable());
+
+        let response = create_response(StatusCode::BAD_REQUEST, "bytecode mismatch");
+        let err = extract_result::<serde_json::Value>(response)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, SourcifyError::Rejected { message, .. } if message == "bytecode mismatch"),
+            "{err:?}"
+        );
+        assert!(!err.is_retryable());
+    }
+}
@@ -0,0 +1,375 @@
+//! Bridge between the contract verifier and [Sourcify](https://sourcify.dev/). Imports sources of contracts verified
+//! on Sourcify as local verification requests, and exports sources of locally verified contracts to Sourcify.
+//!
+//! The local verifier remains the source of truth: imported sources are verified locally like any other request,
+//! and contracts which are already verified (or are being verified) locally are not overwritten.
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_types::{
+    bytecode::{BytecodeHash, BytecodeMarker},
+    contract_verification::{
+        api::{CompilerVersions, SourceCodeData, VerificationInfo},
+        sourcify::{SourcifySyncDirection, SourcifySyncRecord, SourcifySyncStatus},
+    },
+    Address, L2BlockNumber,
+};
+
+use self::{
+    client::{SourcifyClient, SourcifyError},
+    types::{build_verification_request, SourcifyMatch, SourcifySolcJsonRequest},
+};
+use crate::{
+    compilers::Solc, etherscan::solc_versions_fetcher::SolcVersionsFetcher,
+    metrics::API_CONTRACT_VERIFIER_METRICS,
+};
+
+mod client;
+mod types;
+
+/// Periodically synchronizes verified sources between the contract verifier and Sourcify.
+#[derive(Debug)]
+pub struct SourcifyBridge {
+    client: SourcifyClient,
+    connection_pool: ConnectionPool<Core>,
+    solc_versions_fetcher: SolcVersionsFetcher,
+    import_enabled: bool,
+    export_enabled: bool,
+    sync_interval: Duration,
+    max_l2_blocks_per_import: u32,
+    max_contracts_per_export: usize,
+}
+
+impl SourcifyBridge {
+    const DEFAULT_MAX_L2_BLOCKS_PER_IMPORT: u32 = 100;
+    const DEFAULT_MAX_CONTRACTS_PER_EXPORT: usize = 20;
+
+    pub fn new(
+        api_url: String,
+        chain_id: u64,
+        connection_pool: ConnectionPool<Core>,
+        sync_interval: Duration,
+    ) -> Self {
+        Self {
+            client: SourcifyClient::new(api_url, chain_id),
+            connection_pool,
+            solc_versions_fetcher: SolcVersionsFetcher::new(),
+            import_enabled: false,
+            export_enabled: false,
+            sync_interval,
+            max_l2_blocks_per_import: Self::DEFAULT_MAX_L2_BLOCKS_PER_IMPORT,
+            max_contracts_per_export: Self::DEFAULT_MAX_CONTRACTS_PER_EXPORT,
+        }
+    }
+
+    /// Enables importing sources of contracts verified on Sourcify.
+    pub fn with_import(mut self, enabled: bool) -> Self {
+        self.import_enabled = enabled;
+        self
+    }
+
+    /// Enables exporting sources of locally verified contracts to Sourcify.
+    pub fn with_export(mut self, enabled: bool) -> Self {
+        self.export_enabled = enabled;
+        self
+    }
+
+    async fn connection(&self) -> anyhow::Result<Connection<'_, Core>> {
+        Ok(self
+            .connection_pool
+            .connection_tagged("sourcify_bridge")
+            .await?)
+    }
+
+    async fn save_records(&self, records: &[SourcifySyncRecord]) -> anyhow::Result<()> {
+        self.connection()
+            .await?
+            .sourcify_sync_dal()
+            .save_sync_records(records)
+            .await?;
+        for record in records {
+            let labels = (record.direction.as_str(), record.status.as_str());
+            API_CONTRACT_VERIFIER_METRICS.sourcify_sync_records[&labels].inc();
+        }
+        Ok(())
+    }
+
+    /// Imports contracts deployed in the next range of L2 blocks starting from `next_l2_block`. Returns the next
+    /// L2 block to import, or `None` if there are no new sealed L2 blocks.
+    async fn import_next_l2_blocks(
+        &self,
+        next_l2_block: L2BlockNumber,
+    ) -> anyhow::Result<Option<L2BlockNumber>> {
+        let mut storage = self.connection().await?;
+        let Some(sealed_l2_block) = storage.blocks_dal().get_sealed_l2_block_number().await? else {
+            return Ok(None);
+        };
+        if sealed_l2_block < next_l2_block {
+            return Ok(None);
+        }
+        let last_l2_block =
+            sealed_l2_block.min(next_l2_block + (self.max_l2_blocks_per_import - 1));
+        let contracts = storage
+            .contract_verification_dal()
+            .get_unverified_deployed_contracts(next_l2_block..=last_l2_block)
+            .await?;
+        drop(storage);
+
+        // Only EVM contracts can be verified on Sourcify.
+        let (evm_contracts, other_contracts): (Vec<_>, Vec<_>) =
+            contracts.into_iter().partition(|contract| {
+                BytecodeHash::try_from(contract.bytecode_hash)
+                    .is_ok_and(|hash| hash.marker() == BytecodeMarker::Evm)
+            });
+        let mut records: Vec<_> = other_contracts
+            .into_iter()
+            .map(|contract| SourcifySyncRecord {
+                contract_address: contract.address,
+                direction: SourcifySyncDirection::Import,
+                l2_block_number: Some(contract.l2_block_number),
+                status: SourcifySyncStatus::Unsupported,
+                verification_request_id: None,
+                error: None,
+            })
+            .collect();
+
+        for chunk in evm_contracts.chunks(SourcifyClient::MAX_ADDRESSES_PER_CHECK) {
+            let addresses: Vec<_> = chunk.iter().map(|contract| contract.address).collect();
+            // Network errors abort the import; the same L2 blocks will be retried on the next sync.
+            let matches = self.client.check_by_addresses(&addresses).await?;
+            for (contract, sourcify_match) in chunk.iter().zip(matches) {
+                let (status, verification_request_id, error) = if sourcify_match.is_some() {
+                    self.import_contract(contract.address).await?
+                } else {
+                    (SourcifySyncStatus::NotFound, None, None)
+                };
+                records.push(SourcifySyncRecord {
+                    contract_address: contract.address,
+                    direction: SourcifySyncDirection::Import,
+                    l2_block_number: Some(contract.l2_block_number),
+                    status,
+                    verification_request_id,
+                    error,
+                });
+            }
+        }
+
+        self.save_records(&records).await?;
+        tracing::debug!(
+            "Imported L2 blocks {next_l2_block}..={last_l2_block} with {} unverified contracts",
+            records.len()
+        );
+        Ok(Some(last_l2_block + 1))
+    }
+
+    async fn import_contract(
+        &self,
+        address: Address,
+    ) -> anyhow::Result<(SourcifySyncStatus, Option<usize>, Option<String>)> {
+        let files = match self.client.get_files(address).await {
+            Ok(files) => files,
+            Err(err) if err.is_retryable() => return Err(err.into()),
+            Err(err) => {
+                tracing::warn!("Failed fetching sources for {address:?} from Sourcify: {err}");
+                return Ok((SourcifySyncStatus::Failed, None, Some(err.to_string())));
+            }
+        };
+        let request = match build_verification_request(address, &files) {
+            Ok(request) => request,
+            Err(err) => {
+                tracing::info!("Sources for {address:?} on Sourcify are not supported: {err:#}");
+                return Ok((
+                    SourcifySyncStatus::Unsupported,
+                    None,
+                    Some(format!("{err:#}")),
+                ));
+            }
+        };
+
+        let mut storage = self.connection().await?;
+        let mut dal = storage.contract_verification_dal();
+        // Do not interfere with verification requests submitted by users.
+        if dal
+            .get_active_verification_request(address)
+            .await?
+            .is_some()
+        {
+            return Ok((SourcifySyncStatus::AlreadyVerified, None, None));
+        }
+        let request_id = dal.add_contract_verification_request(&request).await?;
+        tracing::info!(
+            "Imported sources for {address:?} from Sourcify ({:?} match) as verification request #{request_id}",
+            files.status
+        );
+        Ok((SourcifySyncStatus::Synced, Some(request_id), None))
+    }
+
+    /// Exports the next batch of locally verified contracts. Returns the number of processed contracts.
+    async fn export_next_contracts(&self) -> anyhow::Result<usize> {
+        let contracts = self
+            .connection()
+            .await?
+            .sourcify_sync_dal()
+            .get_contracts_for_export(self.max_contracts_per_export)
+            .await?;
+
+        let mut records = Vec::with_capacity(contracts.len());
+        for info in &contracts {
+            let (status, error) = match self.export_contract(info).await {
+                Ok(status) => (status, None),
+                Err(err) if err.is_retryable() => {
+                    // Save the processed contracts so that they are not exported again.
+                    tracing::warn!("Sourcify export was interrupted: {err}");
+                    break;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed exporting {:?} to Sourcify: {err}",
+                        info.request.req.contract_address
+                    );
+                    (SourcifySyncStatus::Failed, Some(err.to_string()))
+                }
+            };
+            records.push(SourcifySyncRecord {
+                contract_address: info.request.req.contract_address,
+                direction: SourcifySyncDirection::Export,
+                l2_block_number: None,
+                status,
+                verification_request_id: Some(info.request.id),
+                error,
+            });
+        }
+
+        self.save_records(&records).await?;
+        Ok(records.len())
+    }
+
+    async fn export_contract(
+        &self,
+        info: &VerificationInfo,
+    ) -> Result<SourcifySyncStatus, SourcifyError> {
+        let req = &info.request.req;
+        let is_supported = info.bytecode_marker() == BytecodeMarker::Evm
+            && matches!(
+                req.compiler_versions,
+                CompilerVersions::Solc {
+                    compiler_zksolc_version: None,
+                    ..
+                }
+            )
+            && matches!(
+                req.source_code_data,
+                SourceCodeData::SolSingleFile(_) | SourceCodeData::StandardJsonInput(_)
+            );
+        if !is_supported {
+            return Ok(SourcifySyncStatus::Unsupported);
+        }
+        let solc_version = req.compiler_versions.compiler_version();
+        let Some(compiler_version) = self
+            .solc_versions_fetcher
+            .get_solc_long_version(solc_version)
+        else {
+            tracing::info!("Unknown solc version {solc_version}, skipping Sourcify export");
+            return Ok(SourcifySyncStatus::Unsupported);
+        };
+
+        // A local partial match must not replace a perfect match on Sourcify, and there's no point in exporting
+        // a contract with any match if our match is not better.
+        let existing_match = self
+            .client
+            .check_by_addresses(&[req.contract_address])
+            .await?
+            .pop()
+            .flatten();
+        match existing_match {
+            Some(SourcifyMatch::Perfect) => return Ok(SourcifySyncStatus::AlreadyVerified),
+            Some(SourcifyMatch::Partial) if !info.is_perfect_match() => {
+                return Ok(SourcifySyncStatus::AlreadyVerified);
+            }
+            _ => { /* continue exporting */ }
+        }
+
+        let Ok(input) = Solc::build_input(req.clone()) else {
+            return Ok(SourcifySyncStatus::Unsupported);
+        };
+        let standard_json =
+            serde_json::to_string(&input.standard_json).expect("failed serializing input");
+        let request = SourcifySolcJsonRequest {
+            address: req.contract_address,
+            chain: self.client.chain_id().to_string(),
+            files: [("SolcJsonInput.json".to_owned(), standard_json)].into(),
+            compiler_version,
+            contract_name: input.contract_name,
+        };
+        let response = self.client.verify_solc_json(&request).await?;
+        if response.match_status().is_none() {
+            let message = response.message().unwrap_or("no match").to_owned();
+            return Err(SourcifyError::VerificationFailed(message));
+        }
+        tracing::info!(
+            "Exported {:?} to Sourcify ({:?} match)",
+            req.contract_address,
+            response.match_status()
+        );
+        Ok(SourcifySyncStatus::Synced)
+    }
+
+    async fn sync(
+        &mut self,
+        next_l2_block: &mut L2BlockNumber,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        if self.import_enabled {
+            while !*stop_receiver.borrow() {
+                let Some(next) = self.import_next_l2_blocks(*next_l2_block).await? else {
+                    break;
+                };
+                *next_l2_block = next;
+            }
+        }
+        if self.export_enabled {
+            // Long solc versions are required by Sourcify. Internally cached, so it's fine to call it on every sync.
+            if let Err(err) = self.solc_versions_fetcher.update_versions().await {
+                tracing::error!("Failed to update solc versions: {err}");
+            }
+            while !*stop_receiver.borrow() {
+                if self.export_next_contracts().await? < self.max_contracts_per_export {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut next_l2_block = self
+            .connection()
+            .await?
+            .sourcify_sync_dal()
+            .get_last_imported_l2_block()
+            .await?
+            .map_or(L2BlockNumber(0), |number| number + 1);
+        tracing::info!(
+            import = self.import_enabled,
+            export = self.export_enabled,
+            "Starting Sourcify sync; next imported L2 block is #{next_l2_block}"
+        );
+
+        while !*stop_receiver.borrow_and_update() {
+            // Sourcify unavailability must not stop the verifier, so errors are logged and the sync is retried later.
+            if let Err(err) = self.sync(&mut next_l2_block, &stop_receiver).await {
+                tracing::warn!("Sourcify sync failed: {err:#}");
+            }
+            if tokio::time::timeout(self.sync_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, Sourcify bridge is shutting down");
+        Ok(())
+    }
+}
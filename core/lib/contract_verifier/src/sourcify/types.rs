@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use zksync_types::{
+    contract_verification::api::{
+        CompilerVersions, SourceCodeData, VerificationEvmSettings, VerificationIncomingRequest,
+    },
+    web3::Bytes,
+    Address,
+};
+
+/// Match status of a contract verified on Sourcify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum SourcifyMatch {
+    #[serde(alias = "full")]
+    Perfect,
+    Partial,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SourcifyChainStatus {
+    chain_id: String,
+    status: String,
+}
+
+/// Single entry in the `check-by-addresses` response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SourcifyCheckResponse {
+    pub address: Address,
+    /// Chains the contract is verified on. Absent for unverified contracts.
+    #[serde(default)]
+    chain_ids: Vec<SourcifyChainStatus>,
+}
+
+impl SourcifyCheckResponse {
+    pub fn match_for_chain(&self, chain_id: u64) -> Option<SourcifyMatch> {
+        let chain_id = chain_id.to_string();
+        let status = self
+            .chain_ids
+            .iter()
+            .find(|entry| entry.chain_id == chain_id)?;
+        match status.status.as_str() {
+            "perfect" => Some(SourcifyMatch::Perfect),
+            "partial" => Some(SourcifyMatch::Partial),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct SourcifyFile {
+    pub name: String,
+    pub path: String,
+    pub content: String,
+}
+
+/// Response of the `files/any` endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct SourcifyFilesResponse {
+    pub status: SourcifyMatch,
+    pub files: Vec<SourcifyFile>,
+}
+
+impl SourcifyFilesResponse {
+    fn find_file(&self, name: &str) -> Option<&SourcifyFile> {
+        self.files.iter().find(|file| file.name == name)
+    }
+
+    fn find_source(&self, source_name: &str) -> Option<&SourcifyFile> {
+        let path_suffix = format!("/sources/{source_name}");
+        self.files
+            .iter()
+            .find(|file| file.path.ends_with(&path_suffix))
+    }
+}
+
+/// Subset of the Solidity metadata JSON used to reconstruct the compiler input.
+#[derive(Debug, Deserialize)]
+struct SolidityMetadata {
+    compiler: MetadataCompiler,
+    language: String,
+    settings: serde_json::Map<String, serde_json::Value>,
+    sources: HashMap<String, MetadataSource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataCompiler {
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataSource {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Converts a full Solidity compiler version (e.g., `0.8.28+commit.7893614a`) to the short format
+/// used by the contract verifier.
+fn short_solc_version(version: &str) -> &str {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    version.split_once('+').map_or(version, |(short, _)| short)
+}
+
+/// Builds a local verification request from the source files of a contract verified on Sourcify.
+pub(super) fn build_verification_request(
+    contract_address: Address,
+    files: &SourcifyFilesResponse,
+) -> anyhow::Result<VerificationIncomingRequest> {
+    let metadata = files
+        .find_file("metadata.json")
+        .context("metadata.json is missing")?;
+    let metadata: SolidityMetadata =
+        serde_json::from_str(&metadata.content).context("failed parsing metadata.json")?;
+    anyhow::ensure!(
+        metadata.language == "Solidity",
+        "unsupported language: {}",
+        metadata.language
+    );
+
+    let mut settings = metadata.settings;
+    let compilation_target = settings
+        .remove("compilationTarget")
+        .context("compilation target is missing")?;
+    let compilation_target = compilation_target
+        .as_object()
+        .filter(|target| target.len() == 1)
+        .context("compilation target must contain exactly one contract")?;
+    let (file_name, contract_name) = compilation_target.iter().next().unwrap();
+    let contract_name = contract_name
+        .as_str()
+        .context("contract name in compilation target is not a string")?;
+    let contract_name = format!("{file_name}:{contract_name}");
+
+    // Metadata lists libraries as `file:Library => address`, while the standard JSON input
+    // expects them to be grouped by file.
+    if let Some(libraries) = settings.remove("libraries") {
+        let libraries = libraries
+            .as_object()
+            .context("libraries is not an object")?;
+        let mut grouped = serde_json::Map::new();
+        for (name, address) in libraries {
+            let (file, library) = name.rsplit_once(':').unwrap_or(("", name));
+            let file_entry = grouped.entry(file).or_insert_with(|| serde_json::json!({}));
+            file_entry[library] = address.clone();
+        }
+        settings.insert("libraries".to_owned(), grouped.into());
+    }
+    let optimization_used = settings
+        .get("optimizer")
+        .and_then(|optimizer| optimizer.get("enabled"))
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+
+    let mut sources = serde_json::Map::new();
+    for (source_name, source) in metadata.sources {
+        let content = match source.content {
+            Some(content) => content,
+            None => files
+                .find_source(&source_name)
+                .with_context(|| format!("source `{source_name}` is missing"))?
+                .content
+                .clone(),
+        };
+        sources.insert(source_name, serde_json::json!({ "content": content }));
+    }
+
+    let mut standard_json = serde_json::Map::new();
+    standard_json.insert("language".to_owned(), metadata.language.into());
+    standard_json.insert("sources".to_owned(), sources.into());
+    standard_json.insert("settings".to_owned(), settings.into());
+
+    let constructor_arguments = match files.find_file("constructor-args.txt") {
+        Some(file) => {
+            let args = file.content.trim();
+            let args = args.strip_prefix("0x").unwrap_or(args);
+            Bytes(hex::decode(args).context("invalid constructor arguments")?)
+        }
+        None => Bytes::default(),
+    };
+
+    Ok(VerificationIncomingRequest {
+        contract_address,
+        source_code_data: SourceCodeData::StandardJsonInput(standard_json),
+        contract_name,
+        compiler_versions: CompilerVersions::Solc {
+            compiler_zksolc_version: None,
+            compiler_solc_version: short_solc_version(&metadata.compiler.version).to_owned(),
+        },
+        optimization_used,
+        optimizer_mode: None,
+        constructor_arguments,
+        is_system: false,
+        force_evmla: false,
+        evm_specific: VerificationEvmSettings::default(),
+    })
+}
+
+/// Request for the `verify/solc-json` endpoint.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SourcifySolcJsonRequest {
+    pub address: Address,
+    pub chain: String,
+    /// Maps file names to their content; must contain a single standard JSON input file.
+    pub files: HashMap<String, String>,
+    pub compiler_version: String,
+    pub contract_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SourcifyVerifyResult {
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Response of the `verify/solc-json` endpoint.
+#[derive(Debug, Deserialize)]
+pub(super) struct SourcifyVerifyResponse {
+    pub result: Vec<SourcifyVerifyResult>,
+}
+
+impl SourcifyVerifyResponse {
+    pub fn match_status(&self) -> Option<SourcifyMatch> {
+        self.result
+            .iter()
+            .find_map(|result| match result.status.as_deref()? {
+                "perfect" => Some(SourcifyMatch::Perfect),
+                "partial" => Some(SourcifyMatch::Partial),
+                _ => None,
+            })
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.result
+            .iter()
+            .find_map(|result| result.message.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sourcify_file(name: &str, path: &str, content: &str) -> SourcifyFile {
+        SourcifyFile {
+            name: name.to_owned(),
+            path: path.to_owned(),
+            content: content.to_owned(),
+        }
+    }
+
+    #[test]
+    fn parsing_check_response() {
+        let response = r#"[
+            {
+                "address": "0x0101010101010101010101010101010101010101",
+                "chainIds": [{ "chainId": "1", "status": "partial" }, { "chainId": "270", "status": "perfect" }]
+            },
+            { "address": "0x0202020202020202020202020202020202020202", "status": "false" }
+        ]"#;
+        let response: Vec<SourcifyCheckResponse> = serde_json::from_str(response).unwrap();
+        assert_eq!(response.len(), 2);
+        assert_eq!(
+            response[0].match_for_chain(270),
+            Some(SourcifyMatch::Perfect)
+        );
+        assert_eq!(response[0].match_for_chain(1), Some(SourcifyMatch::Partial));
+        assert_eq!(response[0].match_for_chain(324), None);
+        assert_eq!(response[1].match_for_chain(270), None);
+    }
+
+    #[test]
+    fn building_verification_request() {
+        let metadata = serde_json::json!({
+            "compiler": { "version": "0.8.28+commit.7893614a" },
+            "language": "Solidity",
+            "settings": {
+                "compilationTarget": { "contracts/Counter.sol": "Counter" },
+                "evmVersion": "cancun",
+                "libraries": { "contracts/Math.sol:Math": "0x0303030303030303030303030303030303030303" },
+                "optimizer": { "enabled": true, "runs": 200 },
+            },
+            "sources": {
+                "contracts/Counter.sol": { "keccak256": "0x00" },
+                "contracts/Math.sol": { "keccak256": "0x00", "content": "library Math {}" },
+            },
+        });
+        let files = SourcifyFilesResponse {
+            status: SourcifyMatch::Perfect,
+            files: vec![
+                sourcify_file(
+                    "metadata.json",
+                    "/data/full_match/270/0x01/metadata.json",
+                    &metadata.to_string(),
+                ),
+                sourcify_file(
+                    "Counter.sol",
+                    "/data/full_match/270/0x01/sources/contracts/Counter.sol",
+                    "contract Counter {}",
+                ),
+                sourcify_file(
+                    "constructor-args.txt",
+                    "/data/full_match/270/0x01/constructor-args.txt",
+                    "0x0102",
+                ),
+            ],
+        };
+
+        let address = Address::repeat_byte(1);
+        let req = build_verification_request(address, &files).unwrap();
+        assert_eq!(req.contract_address, address);
+        assert_eq!(req.contract_name, "contracts/Counter.sol:Counter");
+        assert_eq!(
+            req.compiler_versions,
+            CompilerVersions::Solc {
+                compiler_zksolc_version: None,
+                compiler_solc_version: "0.8.28".to_owned(),
+            }
+        );
+        assert!(req.optimization_used);
+        assert_eq!(req.constructor_arguments.0, [1, 2]);
+
+        let SourceCodeData::StandardJsonInput(input) = req.source_code_data else {
+            panic!("Unexpected source code: {:?}", req.source_code_data);
+        };
+        assert_eq!(
+            input["sources"]["contracts/Counter.sol"]["content"],
+            "contract Counter {}"
+        );
+        assert_eq!(
+            input["sources"]["contracts/Math.sol"]["content"],
+            "library Math {}"
+        );
+        let settings = input["settings"].as_object().unwrap();
+        assert!(!settings.contains_key("compilationTarget"));
+        assert_eq!(
+            settings["libraries"]["contracts/Math.sol"]["Math"],
+            "0x0303030303030303030303030303030303030303"
+        );
+        assert_eq!(settings["evmVersion"], "cancun");
+    }
+
+    #[test]
+    fn building_verification_request_with_missing_source() {
+        let metadata = serde_json::json!({
+            "compiler": { "version": "0.8.28+commit.7893614a" },
+            "language": "Solidity",
+            "settings": { "compilationTarget": { "Counter.sol": "Counter" } },
+            "sources": { "Counter.sol": { "keccak256": "0x00" } },
+        });
+        let files = SourcifyFilesResponse {
+            status: SourcifyMatch::Partial,
+            files: vec![sourcify_file(
+                "metadata.json",
+                "/data/partial_match/270/0x01/metadata.json",
+                &metadata.to_string(),
+            )],
+        };
+        let err = build_verification_request(Address::repeat_byte(1), &files).unwrap_err();
+        assert!(err.to_string().contains("Counter.sol"), "{err:#}");
+    }
+
+    #[test]
+    fn parsing_verify_response() {
+        let response =
+            r#"{ "result": [{ "address": "0x01", "chainId": "270", "status": "partial" }] }"#;
+        let response: SourcifyVerifyResponse = serde_json::from_str(response).unwrap();
+        assert_eq!(response.match_status(), Some(SourcifyMatch::Partial));
+        assert_eq!(response.message(), None);
+    }
+}
@@ -22,6 +22,7 @@ use super::*;
 use crate::{
     compilers::{SolcInput, VyperInput, ZkSolcInput},
     resolver::{Compiler, SupportedCompilerVersions},
+    similar_match::SimilarMatchFinder,
 };
 
 mod real;
@@ -798,3 +799,92 @@ async fn no_compiler_version() {
     let error = status.error.unwrap();
     assert!(error.contains("solc version"), "{error}");
 }
+
+#[tokio::test]
+async fn discovering_similar_matches() {
+    let pool = ConnectionPool::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let bytecode = vec![0_u8; 32];
+    let verified_address = Address::repeat_byte(1);
+    mock_deployment(&mut storage, verified_address, bytecode.clone(), &[]).await;
+    let req = test_request(verified_address, COUNTER_CONTRACT);
+    let id = storage
+        .contract_verification_dal()
+        .add_contract_verification_request(&req)
+        .await
+        .unwrap();
+    let identifier = ContractIdentifier::from_bytecode(BytecodeMarker::EraVm, &bytecode);
+    let verification_info = VerificationInfo {
+        request: VerificationRequest { id, req },
+        artifacts: CompilationArtifacts {
+            bytecode: bytecode.clone(),
+            deployed_bytecode: None,
+            abi: counter_contract_abi(),
+        },
+        verified_at: Utc::now(),
+        verification_problems: vec![],
+    };
+    storage
+        .contract_verification_dal()
+        .save_verification_info(
+            verification_info,
+            identifier.bytecode_keccak256,
+            identifier.bytecode_without_metadata_keccak256,
+        )
+        .await
+        .unwrap();
+
+    // Deploy the same bytecode to another address in the next L2 block.
+    storage
+        .blocks_dal()
+        .insert_l2_block(&create_l2_block(1))
+        .await
+        .unwrap();
+    let similar_address = Address::repeat_byte(2);
+    let deploy_event = VmEvent {
+        location: (L1BatchNumber(1), 0),
+        address: CONTRACT_DEPLOYER_ADDRESS,
+        indexed_topics: vec![
+            VmEvent::DEPLOY_EVENT_SIGNATURE,
+            address_to_h256(&Address::repeat_byte(0xff)),
+            BytecodeHash::for_bytecode(&bytecode).value(),
+            address_to_h256(&similar_address),
+        ],
+        value: vec![],
+    };
+    let location = IncludedTxLocation {
+        tx_hash: H256::repeat_byte(0x24),
+        tx_index_in_l2_block: 0,
+    };
+    storage
+        .events_dal()
+        .save_events(L2BlockNumber(1), &[(location, vec![&deploy_event])])
+        .await
+        .unwrap();
+
+    let finder = SimilarMatchFinder::new(pool.clone());
+    let next_l2_block = finder
+        .process_next_l2_blocks(L2BlockNumber(0))
+        .await
+        .unwrap();
+    assert_eq!(next_l2_block, Some(L2BlockNumber(2)));
+    let next_l2_block = finder
+        .process_next_l2_blocks(L2BlockNumber(2))
+        .await
+        .unwrap();
+    assert_eq!(next_l2_block, None);
+
+    let mut dal = storage.contract_verification_dal();
+    assert_eq!(
+        dal.get_similar_match(similar_address).await.unwrap(),
+        Some((verified_address, api::SimilarMatchStatus::Perfect))
+    );
+    // The verified contract itself must not be processed.
+    assert_eq!(dal.get_similar_match(verified_address).await.unwrap(), None);
+    assert_eq!(
+        dal.get_last_similar_match_l2_block().await.unwrap(),
+        Some(L2BlockNumber(1))
+    );
+}
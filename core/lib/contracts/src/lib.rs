@@ -401,15 +401,19 @@ impl SystemContractsRepo {
             }
         }
     }
+
+    pub fn read_bootloader_code(&self, bootloader_type: &str) -> Vec<u8> {
+        self.read_sys_contract_bytecode(
+            "bootloader",
+            bootloader_type,
+            Some("Bootloader"),
+            ContractLanguage::Yul,
+        )
+    }
 }
 
 pub fn read_bootloader_code(bootloader_type: &str) -> Vec<u8> {
-    DEFAULT_SYSTEM_CONTRACTS_REPO.read_sys_contract_bytecode(
-        "bootloader",
-        bootloader_type,
-        Some("Bootloader"),
-        ContractLanguage::Yul,
-    )
+    DEFAULT_SYSTEM_CONTRACTS_REPO.read_bootloader_code(bootloader_type)
 }
 
 /// Reads zbin bytecode from a given path, relative to workspace location.
@@ -466,6 +470,18 @@ impl PartialEq for BaseSystemContracts {
 
 impl BaseSystemContracts {
     fn load_with_bootloader(bootloader_bytecode: Vec<u8>, load_evm_emulator: bool) -> Self {
+        Self::load_with_bootloader_from_repo(
+            &DEFAULT_SYSTEM_CONTRACTS_REPO,
+            bootloader_bytecode,
+            load_evm_emulator,
+        )
+    }
+
+    fn load_with_bootloader_from_repo(
+        repo: &SystemContractsRepo,
+        bootloader_bytecode: Vec<u8>,
+        load_evm_emulator: bool,
+    ) -> Self {
         let hash = BytecodeHash::for_bytecode(&bootloader_bytecode).value();
         let bootloader = SystemContractCode {
             code: bootloader_bytecode,
@@ -473,7 +489,8 @@ impl BaseSystemContracts {
         };
 
         // `DefaultAccount` is not versioned.
-        let bytecode = read_sys_contract_bytecode("", "DefaultAccount", ContractLanguage::Sol);
+        let bytecode =
+            repo.read_sys_contract_bytecode("", "DefaultAccount", None, ContractLanguage::Sol);
         let hash = BytecodeHash::for_bytecode(&bytecode).value();
         let default_aa = SystemContractCode {
             code: bytecode,
@@ -482,7 +499,8 @@ impl BaseSystemContracts {
 
         // EVM emulator is not versioned either. It is only accessed for protocol versions >=27.
         let evm_emulator = load_evm_emulator.then(|| {
-            let bytecode = read_sys_contract_bytecode("", "EvmEmulator", ContractLanguage::Yul);
+            let bytecode =
+                repo.read_sys_contract_bytecode("", "EvmEmulator", None, ContractLanguage::Yul);
             let hash = BytecodeHash::for_bytecode(&bytecode).value();
             SystemContractCode {
                 code: bytecode,
@@ -503,6 +521,12 @@ impl BaseSystemContracts {
         BaseSystemContracts::load_with_bootloader(bootloader_bytecode, true)
     }
 
+    /// Same as [`Self::load_from_disk()`], but loads contracts from the specified system contracts repository.
+    pub fn load_from_repo(repo: &SystemContractsRepo) -> Self {
+        let bootloader_bytecode = repo.read_bootloader_code("proved_batch");
+        BaseSystemContracts::load_with_bootloader_from_repo(repo, bootloader_bytecode, true)
+    }
+
     /// BaseSystemContracts with playground bootloader - used for handling eth_calls.
     pub fn playground() -> Self {
         let bootloader_bytecode = read_bootloader_code("playground_batch");
@@ -1,5 +1,7 @@
 //! Generates rust code from protobufs.
 fn main() {
+    // Migrations are embedded into the crate by `sqlx::migrate!`.
+    println!("cargo:rerun-if-changed=migrations");
     zksync_protobuf_build::Config {
         input_root: "src/consensus/proto".into(),
         proto_root: "zksync/dal".into(),
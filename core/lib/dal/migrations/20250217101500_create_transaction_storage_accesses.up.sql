@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS transaction_storage_accesses (
+    tx_hash BYTEA PRIMARY KEY,
+    -- Accessed slots are encoded as concatenated 52-byte entries (20-byte contract address followed by 32-byte key).
+    reads BYTEA NOT NULL,
+    writes BYTEA NOT NULL,
+    FOREIGN KEY (tx_hash) REFERENCES transactions (hash) ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS contract_verification_similar_matches;
@@ -0,0 +1,13 @@
+-- Deployed contracts processed by the similar match discovery job of the contract verifier.
+-- `matched_contract_addr` is NULL if no verified contract with matching bytecode was found.
+CREATE TABLE IF NOT EXISTS contract_verification_similar_matches (
+    contract_addr BYTEA NOT NULL PRIMARY KEY,
+    miniblock_number BIGINT NOT NULL,
+    matched_contract_addr BYTEA,
+    is_perfect_match BOOLEAN,
+
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS contract_verification_similar_matches_miniblock_number_idx
+    ON contract_verification_similar_matches (miniblock_number);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS sourcify_sync_records;
@@ -0,0 +1,17 @@
+-- Contracts synced with Sourcify by the contract verifier. For imports, rows are created for each processed
+-- EVM contract deployment (including contracts not verified on Sourcify) and are used to track sync progress.
+CREATE TABLE IF NOT EXISTS sourcify_sync_records (
+    contract_addr BYTEA NOT NULL,
+    direction TEXT NOT NULL,
+    miniblock_number BIGINT,
+    status TEXT NOT NULL,
+    verification_request_id BIGINT,
+    error TEXT,
+
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (contract_addr, direction)
+);
+
+CREATE INDEX IF NOT EXISTS sourcify_sync_records_direction_miniblock_number_idx
+    ON sourcify_sync_records (direction, miniblock_number);
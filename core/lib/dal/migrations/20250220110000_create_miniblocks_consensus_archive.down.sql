@@ -0,0 +1 @@
+DROP TABLE IF EXISTS miniblocks_consensus_archive;
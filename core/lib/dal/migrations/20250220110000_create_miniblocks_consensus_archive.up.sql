@@ -0,0 +1,6 @@
+-- Index of L2 blocks whose consensus certificates were moved from `miniblocks_consensus` to the object store.
+-- There is deliberately no foreign key to `miniblocks`: archived blocks remain in the object store after pruning.
+CREATE TABLE IF NOT EXISTS miniblocks_consensus_archive (
+    number BIGINT PRIMARY KEY,
+    archived_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
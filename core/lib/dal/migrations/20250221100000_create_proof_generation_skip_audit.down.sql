@@ -0,0 +1 @@
+DROP TABLE IF EXISTS proof_generation_skip_audit;
@@ -0,0 +1,11 @@
+-- Audit trail of L1 batches whose proof generation was skipped manually via the proof data handler admin API.
+CREATE TABLE IF NOT EXISTS proof_generation_skip_audit (
+    id BIGSERIAL PRIMARY KEY,
+    l1_batch_number BIGINT NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    requested_by TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS proof_generation_skip_audit_l1_batch_number_idx
+    ON proof_generation_skip_audit (l1_batch_number);
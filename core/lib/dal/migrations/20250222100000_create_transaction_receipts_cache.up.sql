@@ -0,0 +1,12 @@
+-- Materialized receipts for transactions in the most recent L2 blocks. Maintained by the state keeper when sealing L1 batches.
+CREATE TABLE IF NOT EXISTS transaction_receipts_cache (
+    tx_hash BYTEA PRIMARY KEY,
+    miniblock_number BIGINT NOT NULL,
+    index_in_block INT NOT NULL,
+    receipt JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (miniblock_number) REFERENCES miniblocks (number) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS transaction_receipts_cache_miniblock_number_idx
+    ON transaction_receipts_cache (miniblock_number, index_in_block);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS address_policies;
+
+-- postgres doesn't allow dropping enum variant, so `AddressPolicies` is kept in `event_type`
@@ -0,0 +1,13 @@
+-- Address policies mirrored from the on-chain policy registry by `eth_watch`.
+-- Addresses without a policy are not stored.
+CREATE TABLE IF NOT EXISTS address_policies (
+    address BYTEA PRIMARY KEY,
+    policy TEXT NOT NULL,
+    l1_block_number BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS address_policies_policy_idx ON address_policies (policy);
+
+ALTER TYPE event_type ADD VALUE 'AddressPolicies';
@@ -0,0 +1,12 @@
+-- Intermediate proving progress reported by the prover subsystem for each aggregation round of a batch.
+CREATE TABLE IF NOT EXISTS proof_generation_rounds (
+    l1_batch_number BIGINT NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    aggregation_round SMALLINT NOT NULL,
+    status TEXT NOT NULL,
+    jobs_count INT NOT NULL,
+    failed_circuit_ids SMALLINT[] NOT NULL,
+    artifact_url TEXT,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, aggregation_round)
+);
@@ -0,0 +1,15 @@
+-- Transactions submitted to an external node that couldn't be proxied to the main node because it was unavailable.
+-- Such transactions are resubmitted by the external node until they are accepted or expire.
+CREATE TABLE IF NOT EXISTS proxied_transactions (
+    tx_hash BYTEA PRIMARY KEY,
+    initiator_address BYTEA NOT NULL,
+    nonce BIGINT NOT NULL,
+    raw_tx BYTEA NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS proxied_transactions_initiator_address_nonce_idx
+ON proxied_transactions (initiator_address, nonce);
+CREATE INDEX IF NOT EXISTS proxied_transactions_created_at_idx ON proxied_transactions (created_at);
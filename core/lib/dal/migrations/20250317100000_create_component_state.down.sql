@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS component_state_history;
+DROP TABLE IF EXISTS component_state;
@@ -0,0 +1,20 @@
+-- Durable key-value state of node components, such as state machines, feature flags or admin switches.
+-- Values are versioned to support optimistic concurrency; each change is recorded in `component_state_history`.
+CREATE TABLE IF NOT EXISTS component_state (
+    component TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value JSONB NOT NULL,
+    version BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (component, key)
+);
+
+CREATE TABLE IF NOT EXISTS component_state_history (
+    component TEXT NOT NULL,
+    key TEXT NOT NULL,
+    version BIGINT NOT NULL,
+    value JSONB NOT NULL,
+    changed_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (component, key, version)
+);
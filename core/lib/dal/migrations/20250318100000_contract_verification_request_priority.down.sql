@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS contract_verification_requests_queued_priority_idx;
+
+ALTER TABLE contract_verification_requests
+    DROP COLUMN IF EXISTS priority_lane,
+    DROP COLUMN IF EXISTS priority,
+    DROP COLUMN IF EXISTS requester;
@@ -0,0 +1,7 @@
+ALTER TABLE contract_verification_requests
+    ADD COLUMN IF NOT EXISTS requester TEXT,
+    ADD COLUMN IF NOT EXISTS priority INT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS priority_lane BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX IF NOT EXISTS contract_verification_requests_queued_priority_idx
+    ON contract_verification_requests (priority DESC, created_at) WHERE status = 'queued';
@@ -0,0 +1 @@
+ALTER TABLE protocol_patches DROP COLUMN IF EXISTS verifier_address;
@@ -0,0 +1 @@
+ALTER TABLE protocol_patches ADD COLUMN IF NOT EXISTS verifier_address BYTEA;
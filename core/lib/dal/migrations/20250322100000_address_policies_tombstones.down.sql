@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS address_policies_l1_block_number_idx;
+
+DELETE FROM address_policies WHERE policy IS NULL;
+ALTER TABLE address_policies ALTER COLUMN policy SET NOT NULL;
@@ -0,0 +1,5 @@
+-- Removed policies are kept as rows with a `NULL` policy, so that consumers can load policy changes incrementally
+-- by `l1_block_number`.
+ALTER TABLE address_policies ALTER COLUMN policy DROP NOT NULL;
+
+CREATE INDEX IF NOT EXISTS address_policies_l1_block_number_idx ON address_policies (l1_block_number);
@@ -0,0 +1 @@
+ALTER TABLE proof_generation_skip_audit DROP COLUMN IF EXISTS propagated_to_prover_at;
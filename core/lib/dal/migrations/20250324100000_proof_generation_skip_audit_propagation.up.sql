@@ -0,0 +1,2 @@
+-- Time when the manual skip was propagated to the prover subsystem, so that prover jobs for the batch are skipped as well.
+ALTER TABLE proof_generation_skip_audit ADD COLUMN IF NOT EXISTS propagated_to_prover_at TIMESTAMP;
@@ -0,0 +1,3 @@
+-- Size of the serialized call trace before compression. `NULL` for traces persisted before the column was added;
+-- all such traces are uncompressed, so their size is equal to `OCTET_LENGTH(call_trace)`.
+ALTER TABLE call_traces ADD COLUMN IF NOT EXISTS uncompressed_size BIGINT;
@@ -0,0 +1,324 @@
+//! Storage for address policies mirrored from the on-chain policy registry.
+
+use std::{fmt, str::FromStr};
+
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+use zksync_types::{Address, L1BlockNumber};
+
+use crate::Core;
+
+/// Policy applied to transactions initiated by a certain address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressPolicy {
+    /// Transactions are accepted, but are deprioritized in the mempool.
+    Greylisted,
+    /// Transactions are rejected on submission.
+    Blacklisted,
+}
+
+impl AddressPolicy {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Greylisted => "greylisted",
+            Self::Blacklisted => "blacklisted",
+        }
+    }
+}
+
+impl fmt::Display for AddressPolicy {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AddressPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "greylisted" => Self::Greylisted,
+            "blacklisted" => Self::Blacklisted,
+            _ => anyhow::bail!("unknown address policy: {s}"),
+        })
+    }
+}
+
+/// Change of the policy for a certain address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressPolicyChange {
+    pub address: Address,
+    /// New policy; `None` if the policy was removed.
+    pub policy: Option<AddressPolicy>,
+    /// L1 block in which the policy was changed.
+    pub l1_block_number: L1BlockNumber,
+}
+
+#[derive(Debug)]
+pub struct AddressPoliciesDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl AddressPoliciesDal<'_, '_> {
+    /// Sets the policy for the specified address. `None` policy removes any previously set policy.
+    ///
+    /// Removed policies are persisted as tombstones, so that they are returned by [`Self::get_address_policy_changes()`].
+    pub async fn set_address_policy(
+        &mut self,
+        address: Address,
+        policy: Option<AddressPolicy>,
+        l1_block_number: L1BlockNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            address_policies (address, policy, l1_block_number, created_at, updated_at)
+            VALUES
+            ($1, $2, $3, NOW(), NOW())
+            ON CONFLICT (address) DO
+            UPDATE
+            SET
+            policy = excluded.policy,
+            l1_block_number = excluded.l1_block_number,
+            updated_at = NOW()
+            "#,
+            address.as_bytes(),
+            policy.map(AddressPolicy::as_str),
+            i64::from(l1_block_number.0)
+        )
+        .instrument("set_address_policy")
+        .with_arg("address", &address)
+        .with_arg("policy", &policy)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the policy set for the specified address, if any.
+    pub async fn get_address_policy(
+        &mut self,
+        address: Address,
+    ) -> DalResult<Option<AddressPolicy>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                policy
+            FROM
+                address_policies
+            WHERE
+                address = $1
+            "#,
+            address.as_bytes()
+        )
+        .instrument("get_address_policy")
+        .with_arg("address", &address)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row
+            .and_then(|row| row.policy)
+            .map(|policy| policy.parse().expect("invalid address policy stored in DB")))
+    }
+
+    /// Returns all addresses with the specified policy.
+    pub async fn get_addresses_with_policy(
+        &mut self,
+        policy: AddressPolicy,
+    ) -> DalResult<Vec<Address>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                address
+            FROM
+                address_policies
+            WHERE
+                policy = $1
+            "#,
+            policy.as_str()
+        )
+        .instrument("get_addresses_with_policy")
+        .with_arg("policy", &policy)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Address::from_slice(&row.address))
+            .collect())
+    }
+
+    /// Returns policy changes mirrored from L1 blocks after `after_l1_block` (or all stored policies
+    /// if `after_l1_block` is `None`), ordered by the L1 block number.
+    ///
+    /// Since `eth_watch` persists policy changes atomically and in the increasing L1 block order, the greatest
+    /// returned L1 block number can be used as a cursor for the following call.
+    pub async fn get_address_policy_changes(
+        &mut self,
+        after_l1_block: Option<L1BlockNumber>,
+    ) -> DalResult<Vec<AddressPolicyChange>> {
+        let after_l1_block = after_l1_block.map_or(-1, |number| i64::from(number.0));
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                address,
+                policy,
+                l1_block_number
+            FROM
+                address_policies
+            WHERE
+                l1_block_number > $1
+            ORDER BY
+                l1_block_number
+            "#,
+            after_l1_block
+        )
+        .instrument("get_address_policy_changes")
+        .with_arg("after_l1_block", &after_l1_block)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AddressPolicyChange {
+                address: Address::from_slice(&row.address),
+                policy: row
+                    .policy
+                    .map(|policy| policy.parse().expect("invalid address policy stored in DB")),
+                l1_block_number: L1BlockNumber(row.l1_block_number as u32),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn setting_and_removing_address_policies() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let greylisted = Address::repeat_byte(1);
+        let blacklisted = Address::repeat_byte(2);
+
+        let mut dal = conn.address_policies_dal();
+        assert_eq!(dal.get_address_policy(greylisted).await.unwrap(), None);
+        dal.set_address_policy(
+            greylisted,
+            Some(AddressPolicy::Greylisted),
+            L1BlockNumber(1),
+        )
+        .await
+        .unwrap();
+        dal.set_address_policy(
+            blacklisted,
+            Some(AddressPolicy::Greylisted),
+            L1BlockNumber(1),
+        )
+        .await
+        .unwrap();
+        dal.set_address_policy(
+            blacklisted,
+            Some(AddressPolicy::Blacklisted),
+            L1BlockNumber(2),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            dal.get_address_policy(greylisted).await.unwrap(),
+            Some(AddressPolicy::Greylisted)
+        );
+        assert_eq!(
+            dal.get_address_policy(blacklisted).await.unwrap(),
+            Some(AddressPolicy::Blacklisted)
+        );
+        assert_eq!(
+            dal.get_addresses_with_policy(AddressPolicy::Greylisted)
+                .await
+                .unwrap(),
+            [greylisted]
+        );
+
+        dal.set_address_policy(blacklisted, None, L1BlockNumber(3))
+            .await
+            .unwrap();
+        assert_eq!(dal.get_address_policy(blacklisted).await.unwrap(), None);
+        assert_eq!(
+            dal.get_addresses_with_policy(AddressPolicy::Blacklisted)
+                .await
+                .unwrap(),
+            []
+        );
+    }
+
+    #[tokio::test]
+    async fn loading_address_policy_changes() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let first = Address::repeat_byte(1);
+        let second = Address::repeat_byte(2);
+
+        let mut dal = conn.address_policies_dal();
+        assert_eq!(dal.get_address_policy_changes(None).await.unwrap(), []);
+        dal.set_address_policy(first, Some(AddressPolicy::Greylisted), L1BlockNumber(1))
+            .await
+            .unwrap();
+        dal.set_address_policy(second, Some(AddressPolicy::Blacklisted), L1BlockNumber(2))
+            .await
+            .unwrap();
+
+        let changes = dal.get_address_policy_changes(None).await.unwrap();
+        assert_eq!(
+            changes,
+            [
+                AddressPolicyChange {
+                    address: first,
+                    policy: Some(AddressPolicy::Greylisted),
+                    l1_block_number: L1BlockNumber(1),
+                },
+                AddressPolicyChange {
+                    address: second,
+                    policy: Some(AddressPolicy::Blacklisted),
+                    l1_block_number: L1BlockNumber(2),
+                },
+            ]
+        );
+        let changes = dal
+            .get_address_policy_changes(Some(L1BlockNumber(1)))
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].address, second);
+        assert_eq!(
+            dal.get_address_policy_changes(Some(L1BlockNumber(2)))
+                .await
+                .unwrap(),
+            []
+        );
+
+        // Removed policies must be returned as well.
+        dal.set_address_policy(first, None, L1BlockNumber(3))
+            .await
+            .unwrap();
+        let changes = dal
+            .get_address_policy_changes(Some(L1BlockNumber(2)))
+            .await
+            .unwrap();
+        assert_eq!(
+            changes,
+            [AddressPolicyChange {
+                address: first,
+                policy: None,
+                l1_block_number: L1BlockNumber(3),
+            }]
+        );
+        assert_eq!(dal.get_address_policy(first).await.unwrap(), None);
+        assert_eq!(
+            dal.get_addresses_with_policy(AddressPolicy::Greylisted)
+                .await
+                .unwrap(),
+            []
+        );
+    }
+}
@@ -343,6 +343,28 @@ impl BlocksDal<'_, '_> {
         .collect())
     }
 
+    /// Returns the number of L1 batches that are committed, proven or executed by the specified `eth_tx`.
+    pub async fn get_l1_batch_count_for_eth_tx_id(&mut self, eth_tx_id: u32) -> DalResult<usize> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                l1_batches
+            WHERE
+                eth_commit_tx_id = $1
+                OR eth_prove_tx_id = $1
+                OR eth_execute_tx_id = $1
+            "#,
+            eth_tx_id as i32
+        )
+        .instrument("get_l1_batch_count_for_eth_tx_id")
+        .with_arg("eth_tx_id", &eth_tx_id)
+        .fetch_one(self.storage)
+        .await?;
+        Ok(count as usize)
+    }
+
     async fn get_storage_l1_batch(
         &mut self,
         number: L1BatchNumber,
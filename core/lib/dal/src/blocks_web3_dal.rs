@@ -527,6 +527,33 @@ impl BlocksWeb3Dal<'_, '_> {
         Ok(result)
     }
 
+    /// Returns the total size of call traces stored for the specified L2 block, in bytes. Compressed traces
+    /// are accounted with their size after decompression. Allows to estimate the cost
+    /// of [`Self::get_traces_for_l2_block()`] without loading the traces.
+    pub async fn get_traces_size_for_l2_block(
+        &mut self,
+        block_number: L2BlockNumber,
+    ) -> DalResult<u64> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                SUM(COALESCE(uncompressed_size, OCTET_LENGTH(call_trace)))::BIGINT AS traces_size
+            FROM
+                call_traces
+            INNER JOIN transactions ON tx_hash = transactions.hash
+            WHERE
+                transactions.miniblock_number = $1
+            "#,
+            i64::from(block_number.0)
+        )
+        .instrument("get_traces_size_for_l2_block")
+        .with_arg("block_number", &block_number)
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(row.traces_size.unwrap_or(0) as u64)
+    }
+
     /// Returns call traces for all transactions in the specified L2 block in the order of their execution.
     pub async fn get_traces_for_l2_block(
         &mut self,
@@ -1139,6 +1166,8 @@ mod tests {
                 from: Address::from_low_u64_be(i as u64),
                 to: Address::from_low_u64_be(i as u64 + 1),
                 value: i.into(),
+                // Highly compressible input, so that compressed traces are much smaller than uncompressed ones
+                input: vec![0; 10_000],
                 ..Call::default()
             });
             tx_results.push(tx_result);
@@ -1150,6 +1179,7 @@ mod tests {
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                true,
             )
             .await
             .unwrap();
@@ -1165,5 +1195,31 @@ mod tests {
             assert_eq!(tx_result.hash, meta.tx_hash);
             assert_eq!(*trace, expected_trace);
         }
+
+        let traces_size = conn
+            .blocks_web3_dal()
+            .get_traces_size_for_l2_block(L2BlockNumber(1))
+            .await
+            .unwrap();
+        let mut expected_traces_size = 0;
+        for tx_result in &tx_results {
+            let trace_size = conn
+                .transactions_dal()
+                .get_call_trace_size(tx_result.hash)
+                .await
+                .unwrap()
+                .expect("no trace size");
+            // Trace size must be reported before compression.
+            let serialized_trace = bincode::serialize(&tx_result.call_trace().unwrap()).unwrap();
+            assert_eq!(trace_size, serialized_trace.len() as u64);
+            expected_traces_size += trace_size;
+        }
+        assert_eq!(traces_size, expected_traces_size);
+        let traces_size = conn
+            .blocks_web3_dal()
+            .get_traces_size_for_l2_block(L2BlockNumber(2))
+            .await
+            .unwrap();
+        assert_eq!(traces_size, 0);
     }
 }
@@ -0,0 +1,277 @@
+//! Durable key-value state of node components, such as state machines, feature flags or admin switches.
+
+use chrono::NaiveDateTime;
+use serde::{de::DeserializeOwned, Serialize};
+use zksync_db_connection::{
+    connection::Connection,
+    error::{DalResult, SqlxContext},
+    instrument::InstrumentExt,
+};
+
+use crate::Core;
+
+/// Value stored for a component key together with its version.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentStateEntry<T> {
+    pub value: T,
+    /// Version of the value. The first stored value has version 1; each update increments the version.
+    pub version: u64,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Recorded change of a component key. The value is untyped since its format may have changed over time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentStateChange {
+    pub version: u64,
+    pub value: serde_json::Value,
+    pub changed_at: NaiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct ComponentStateDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl ComponentStateDal<'_, '_> {
+    /// Returns the current value for the specified component key, or `None` if the key was never set.
+    pub async fn get_state<T: DeserializeOwned>(
+        &mut self,
+        component: &str,
+        key: &str,
+    ) -> DalResult<Option<ComponentStateEntry<T>>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                value,
+                version,
+                updated_at
+            FROM
+                component_state
+            WHERE
+                component = $1
+                AND key = $2
+            "#,
+            component,
+            key
+        )
+        .try_map(|row| {
+            Ok(ComponentStateEntry {
+                value: serde_json::from_value(row.value).decode_column("value")?,
+                version: row.version as u64,
+                updated_at: row.updated_at,
+            })
+        })
+        .instrument("get_component_state")
+        .with_arg("component", &component)
+        .with_arg("key", &key)
+        .fetch_optional(self.storage)
+        .await
+    }
+
+    /// Sets the value for the specified component key if its current version matches `expected_version`
+    /// (`None` means that the key must not be set yet). Returns the new version of the value, or `None`
+    /// if the key was concurrently modified, in which case the caller should re-read the value and retry.
+    pub async fn set_state<T: Serialize>(
+        &mut self,
+        component: &str,
+        key: &str,
+        value: &T,
+        expected_version: Option<u64>,
+    ) -> DalResult<Option<u64>> {
+        // Serialization should always succeed.
+        let value =
+            serde_json::to_value(value).expect("failed serializing component state into JSON");
+        let mut transaction = self.storage.start_transaction().await?;
+
+        let new_version = if let Some(expected_version) = expected_version {
+            sqlx::query_scalar!(
+                r#"
+                UPDATE component_state
+                SET
+                    value = $3,
+                    version = version + 1,
+                    updated_at = NOW()
+                WHERE
+                    component = $1
+                    AND key = $2
+                    AND version = $4
+                RETURNING
+                version
+                "#,
+                component,
+                key,
+                &value,
+                expected_version as i64
+            )
+            .instrument("set_component_state#update")
+            .with_arg("component", &component)
+            .with_arg("key", &key)
+            .with_arg("expected_version", &expected_version)
+            .fetch_optional(&mut transaction)
+            .await?
+        } else {
+            sqlx::query_scalar!(
+                r#"
+                INSERT INTO
+                component_state (component, key, value, version, created_at, updated_at)
+                VALUES
+                ($1, $2, $3, 1, NOW(), NOW())
+                ON CONFLICT (component, key) DO NOTHING
+                RETURNING
+                version
+                "#,
+                component,
+                key,
+                &value
+            )
+            .instrument("set_component_state#insert")
+            .with_arg("component", &component)
+            .with_arg("key", &key)
+            .fetch_optional(&mut transaction)
+            .await?
+        };
+        let Some(new_version) = new_version else {
+            return Ok(None);
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            component_state_history (component, key, version, value, changed_at)
+            VALUES
+            ($1, $2, $3, $4, NOW())
+            "#,
+            component,
+            key,
+            new_version,
+            &value
+        )
+        .instrument("set_component_state#insert_history")
+        .with_arg("component", &component)
+        .with_arg("key", &key)
+        .with_arg("version", &new_version)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(Some(new_version as u64))
+    }
+
+    /// Returns up to `limit` most recent changes of the specified component key, newest first.
+    pub async fn get_state_history(
+        &mut self,
+        component: &str,
+        key: &str,
+        limit: usize,
+    ) -> DalResult<Vec<ComponentStateChange>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                version,
+                value,
+                changed_at
+            FROM
+                component_state_history
+            WHERE
+                component = $1
+                AND key = $2
+            ORDER BY
+                version DESC
+            LIMIT
+                $3
+            "#,
+            component,
+            key,
+            limit as i64
+        )
+        .instrument("get_component_state_history")
+        .with_arg("component", &component)
+        .with_arg("key", &key)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ComponentStateChange {
+                version: row.version as u64,
+                value: row.value,
+                changed_at: row.changed_at,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum MigrationState {
+        Idle,
+        InProgress { step: u32 },
+    }
+
+    #[tokio::test]
+    async fn setting_component_state() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.component_state_dal();
+
+        let state = dal
+            .get_state::<MigrationState>("migrator", "state")
+            .await
+            .unwrap();
+        assert!(state.is_none());
+
+        let version = dal
+            .set_state("migrator", "state", &MigrationState::Idle, None)
+            .await
+            .unwrap();
+        assert_eq!(version, Some(1));
+        // The key is already set, so it cannot be created again.
+        let version = dal
+            .set_state("migrator", "state", &MigrationState::Idle, None)
+            .await
+            .unwrap();
+        assert_eq!(version, None);
+
+        let new_state = MigrationState::InProgress { step: 3 };
+        let version = dal
+            .set_state("migrator", "state", &new_state, Some(1))
+            .await
+            .unwrap();
+        assert_eq!(version, Some(2));
+        // Stale version must be rejected.
+        let version = dal
+            .set_state("migrator", "state", &MigrationState::Idle, Some(1))
+            .await
+            .unwrap();
+        assert_eq!(version, None);
+
+        let state = dal
+            .get_state::<MigrationState>("migrator", "state")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(state.value, new_state);
+        assert_eq!(state.version, 2);
+        // Keys are scoped by the component.
+        let state = dal
+            .get_state::<MigrationState>("other", "state")
+            .await
+            .unwrap();
+        assert!(state.is_none());
+
+        let history = dal
+            .get_state_history("migrator", "state", 10)
+            .await
+            .unwrap();
+        let versions: Vec<_> = history.iter().map(|change| change.version).collect();
+        assert_eq!(versions, [2, 1]);
+        assert_eq!(history[0].value, serde_json::to_value(&new_state).unwrap());
+    }
+}
@@ -179,6 +179,14 @@ impl ConsensusDal<'_, '_> {
         .instrument("try_update_genesis#DELETE FROM miniblock_consensus")
         .execute(&mut txn)
         .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM miniblocks_consensus_archive
+            "#
+        )
+        .instrument("try_update_genesis#DELETE FROM miniblocks_consensus_archive")
+        .execute(&mut txn)
+        .await?;
         sqlx::query!(
             r#"
             DELETE FROM consensus_replica_state
@@ -548,6 +556,137 @@ impl ConsensusDal<'_, '_> {
         Ok(())
     }
 
+    /// Gets a number of the last L2 block with a consensus certificate.
+    pub async fn last_block_certificate_number(
+        &mut self,
+    ) -> anyhow::Result<Option<validator::BlockNumber>> {
+        let Some(row) = sqlx::query!(
+            r#"
+            SELECT
+                number
+            FROM
+                miniblocks_consensus
+            ORDER BY
+                number DESC
+            LIMIT
+                1
+            "#
+        )
+        .instrument("last_block_certificate_number")
+        .report_latency()
+        .fetch_optional(self.storage)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(validator::BlockNumber(
+            row.number.try_into().context("overflow")?,
+        )))
+    }
+
+    /// Fetches numbers of (at most `limit`) L2 blocks before `before` which have a certificate
+    /// in `miniblocks_consensus` and thus can be archived, in ascending order.
+    pub async fn blocks_to_archive(
+        &mut self,
+        before: validator::BlockNumber,
+        limit: usize,
+    ) -> anyhow::Result<Vec<validator::BlockNumber>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                number
+            FROM
+                miniblocks_consensus
+            WHERE
+                number < $1
+            ORDER BY
+                number
+            LIMIT
+                $2
+            "#,
+            i64::try_from(before.0).context("overflow")?,
+            i64::try_from(limit).context("overflow")?,
+        )
+        .instrument("blocks_to_archive")
+        .with_arg("before", &before.0)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(validator::BlockNumber(
+                    row.number.try_into().context("overflow")?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Marks the L2 block as archived and removes its certificate from `miniblocks_consensus`.
+    /// Should be called only after the block has been persisted in the archive.
+    pub async fn mark_block_archived(
+        &mut self,
+        number: validator::BlockNumber,
+    ) -> anyhow::Result<()> {
+        let number = i64::try_from(number.0).context("overflow")?;
+        let mut txn = self.storage.start_transaction().await?;
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            miniblocks_consensus_archive (number)
+            VALUES
+            ($1)
+            ON CONFLICT (number) DO NOTHING
+            "#,
+            number,
+        )
+        .instrument("mark_block_archived#INSERT INTO miniblocks_consensus_archive")
+        .with_arg("number", &number)
+        .execute(&mut txn)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM miniblocks_consensus
+            WHERE
+                number = $1
+            "#,
+            number,
+        )
+        .instrument("mark_block_archived#DELETE FROM miniblocks_consensus")
+        .with_arg("number", &number)
+        .execute(&mut txn)
+        .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    /// Checks whether the L2 block has been moved to the archive.
+    pub async fn is_block_archived(
+        &mut self,
+        number: validator::BlockNumber,
+    ) -> anyhow::Result<bool> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        miniblocks_consensus_archive
+                    WHERE
+                        number = $1
+                ) AS "exists!"
+            "#,
+            i64::try_from(number.0).context("overflow")?,
+        )
+        .instrument("is_block_archived")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?
+        .exists)
+    }
+
     /// Persist the attester committee for the given batch.
     pub async fn upsert_attester_committee(
         &mut self,
@@ -2,6 +2,7 @@
 
 use std::{
     fmt::{Display, Formatter},
+    ops,
     time::Duration,
 };
 
@@ -15,12 +16,12 @@ use zksync_types::{
     address_to_h256,
     contract_verification::{
         api::{
-            VerificationIncomingRequest, VerificationInfo, VerificationRequest,
-            VerificationRequestStatus,
+            SimilarMatchStatus, VerificationIncomingRequest, VerificationInfo, VerificationRequest,
+            VerificationRequestDetails, VerificationRequestStatus, VerificationRequestSummary,
         },
         contract_identifier::ContractIdentifier,
     },
-    web3, Address, CONTRACT_DEPLOYER_ADDRESS, H256,
+    web3, Address, L2BlockNumber, CONTRACT_DEPLOYER_ADDRESS, H256,
 };
 use zksync_vm_interface::VmEvent;
 
@@ -58,6 +59,33 @@ pub struct DeployedContractData {
     pub calldata: Option<Vec<u8>>,
 }
 
+/// Contract without verification info deployed in a certain L2 block.
+#[derive(Debug)]
+pub struct UnverifiedDeployedContract {
+    pub address: Address,
+    pub l2_block_number: L2BlockNumber,
+    pub bytecode_hash: H256,
+    /// Bytecode as persisted in Postgres (i.e., with additional padding for EVM bytecodes).
+    pub bytecode: Vec<u8>,
+}
+
+/// Outcome of the similar match discovery for a deployed contract.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarMatchRecord {
+    pub contract_address: Address,
+    pub l2_block_number: L2BlockNumber,
+    /// Verified contract with the matching bytecode, if any.
+    pub matched: Option<(Address, SimilarMatchStatus)>,
+}
+
+fn similar_match_status(is_perfect_match: bool) -> SimilarMatchStatus {
+    if is_perfect_match {
+        SimilarMatchStatus::Perfect
+    } else {
+        SimilarMatchStatus::Partial
+    }
+}
+
 #[derive(Debug)]
 pub struct ContractVerificationDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -108,6 +136,19 @@ impl ContractVerificationDal<'_, '_> {
     pub async fn add_contract_verification_request(
         &mut self,
         query: &VerificationIncomingRequest,
+    ) -> DalResult<usize> {
+        self.add_contract_verification_request_from(query, None, false)
+            .await
+    }
+
+    /// Adds a verification request submitted by the specified `requester`. Requests from trusted requesters
+    /// should be added with `priority_lane` set, so that they are processed in a separate lane and do not starve
+    /// other requests (and vice versa).
+    pub async fn add_contract_verification_request_from(
+        &mut self,
+        query: &VerificationIncomingRequest,
+        requester: Option<&str>,
+        priority_lane: bool,
     ) -> DalResult<usize> {
         sqlx::query!(
             r#"
@@ -124,12 +165,14 @@ impl ContractVerificationDal<'_, '_> {
                 is_system,
                 force_evmla,
                 evm_specific,
+                requester,
+                priority_lane,
                 status,
                 created_at,
                 updated_at
             )
             VALUES
-            ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 'queued', NOW(), NOW())
+            ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, 'queued', NOW(), NOW())
             RETURNING
             id
             "#,
@@ -145,9 +188,13 @@ impl ContractVerificationDal<'_, '_> {
             query.is_system,
             query.force_evmla,
             serde_json::to_value(&query.evm_specific).unwrap(),
+            requester,
+            priority_lane
         )
         .instrument("add_contract_verification_request")
         .with_arg("address", &query.contract_address)
+        .with_arg("requester", &requester)
+        .with_arg("priority_lane", &priority_lane)
         .fetch_one(self.storage)
         .await
         .map(|row| row.id as usize)
@@ -157,9 +204,13 @@ impl ContractVerificationDal<'_, '_> {
     /// Considering the situation where processing of some request
     /// can be interrupted (panic, pod restart, etc..),
     /// `processing_timeout` parameter is added to avoid stuck requests.
+    ///
+    /// Requests with higher priority are returned first. Among requests with the same priority,
+    /// requests from the priority lane are preferred iff `prefer_priority_lane` is set.
     pub async fn get_next_queued_verification_request(
         &mut self,
         processing_timeout: Duration,
+        prefer_priority_lane: bool,
     ) -> DalResult<Option<VerificationRequest>> {
         let processing_timeout = PgInterval {
             months: 0,
@@ -188,6 +239,8 @@ impl ContractVerificationDal<'_, '_> {
                             AND processing_started_at < NOW() - $1::INTERVAL
                         )
                     ORDER BY
+                        priority DESC,
+                        (priority_lane = $2) DESC,
                         created_at
                     LIMIT
                         1
@@ -208,10 +261,12 @@ impl ContractVerificationDal<'_, '_> {
             force_evmla,
             evm_specific
             "#,
-            &processing_timeout
+            &processing_timeout,
+            prefer_priority_lane
         )
         .instrument("get_next_queued_verification_request")
         .with_arg("processing_timeout", &processing_timeout)
+        .with_arg("prefer_priority_lane", &prefer_priority_lane)
         .fetch_optional(self.storage)
         .await?
         .map(Into::into);
@@ -358,6 +413,205 @@ impl ContractVerificationDal<'_, '_> {
         .await
     }
 
+    /// Returns up to `limit` verification requests with the specified status (or all requests if `status`
+    /// is not specified) in the order they would be picked up by the verifier.
+    pub async fn get_verification_requests(
+        &mut self,
+        status: Option<&str>,
+        limit: usize,
+    ) -> DalResult<Vec<VerificationRequestSummary>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                contract_address,
+                status,
+                requester,
+                priority,
+                priority_lane,
+                attempts,
+                created_at,
+                updated_at
+            FROM
+                contract_verification_requests
+            WHERE
+                $1::TEXT IS NULL
+                OR status = $1
+            ORDER BY
+                priority DESC,
+                created_at
+            LIMIT
+                $2
+            "#,
+            status,
+            limit as i64
+        )
+        .instrument("get_verification_requests")
+        .with_arg("status", &status)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| VerificationRequestSummary {
+                id: row.id as usize,
+                contract_address: Address::from_slice(&row.contract_address),
+                status: row.status,
+                requester: row.requester,
+                priority: row.priority,
+                priority_lane: row.priority_lane,
+                attempts: row.attempts as u32,
+                created_at: row.created_at.and_utc(),
+                updated_at: row.updated_at.and_utc(),
+            })
+            .collect())
+    }
+
+    /// Returns details of the specified verification request, including compilation errors if it has failed.
+    pub async fn get_verification_request_details(
+        &mut self,
+        id: usize,
+    ) -> DalResult<Option<VerificationRequestDetails>> {
+        let Some(row) = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                contract_address,
+                source_code,
+                contract_name,
+                zk_compiler_version,
+                compiler_version,
+                optimization_used,
+                optimizer_mode,
+                constructor_arguments,
+                is_system,
+                force_evmla,
+                evm_specific,
+                status,
+                requester,
+                priority,
+                priority_lane,
+                attempts,
+                created_at,
+                updated_at,
+                error,
+                compilation_errors,
+                panic_message
+            FROM
+                contract_verification_requests
+            WHERE
+                id = $1
+            "#,
+            id as i64
+        )
+        .instrument("get_verification_request_details")
+        .with_arg("id", &id)
+        .fetch_optional(self.storage)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let summary = VerificationRequestSummary {
+            id: row.id as usize,
+            contract_address: Address::from_slice(&row.contract_address),
+            status: row.status,
+            requester: row.requester,
+            priority: row.priority,
+            priority_lane: row.priority_lane,
+            attempts: row.attempts as u32,
+            created_at: row.created_at.and_utc(),
+            updated_at: row.updated_at.and_utc(),
+        };
+        let request = VerificationRequest::from(StorageVerificationRequest {
+            id: row.id,
+            contract_address: row.contract_address,
+            source_code: row.source_code,
+            contract_name: row.contract_name,
+            zk_compiler_version: row.zk_compiler_version,
+            compiler_version: row.compiler_version,
+            optimization_used: row.optimization_used,
+            optimizer_mode: row.optimizer_mode,
+            constructor_arguments: row.constructor_arguments,
+            is_system: row.is_system,
+            force_evmla: row.force_evmla,
+            evm_specific: row.evm_specific,
+        });
+        Ok(Some(VerificationRequestDetails {
+            summary,
+            contract_name: request.req.contract_name,
+            compiler_versions: request.req.compiler_versions,
+            error: row.error,
+            compilation_errors: row.compilation_errors,
+            panic_message: row.panic_message,
+        }))
+    }
+
+    /// Puts a failed or stuck verification request back into the queue. Returns `false` if the request
+    /// doesn't exist or cannot be re-queued (e.g., it is already queued or has succeeded).
+    pub async fn requeue_verification_request(&mut self, id: usize) -> DalResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE contract_verification_requests
+            SET
+                status = 'queued',
+                error = NULL,
+                compilation_errors = NULL,
+                panic_message = NULL,
+                processing_started_at = NULL,
+                updated_at = NOW()
+            WHERE
+                id = $1
+                AND status IN ('failed', 'in_progress')
+            "#,
+            id as i64
+        )
+        .instrument("requeue_verification_request")
+        .with_arg("id", &id)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Sets `priority` for all queued verification requests matching the specified contract address and / or
+    /// requester. Returns the number of updated requests.
+    pub async fn set_queued_verification_requests_priority(
+        &mut self,
+        address: Option<Address>,
+        requester: Option<&str>,
+        priority: i32,
+    ) -> DalResult<usize> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE contract_verification_requests
+            SET
+                priority = $3,
+                updated_at = NOW()
+            WHERE
+                status = 'queued'
+                AND (
+                    $1::BYTEA IS NULL
+                    OR contract_address = $1
+                )
+                AND (
+                    $2::TEXT IS NULL
+                    OR requester = $2
+                )
+            "#,
+            address.as_ref().map(Address::as_bytes),
+            requester,
+            priority
+        )
+        .instrument("set_queued_verification_requests_priority")
+        .with_arg("address", &address)
+        .with_arg("requester", &requester)
+        .with_arg("priority", &priority)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() as usize)
+    }
+
     /// Returns bytecode and calldata from the contract and the transaction that created it.
     pub async fn get_contract_info_for_verification(
         &mut self,
@@ -680,6 +934,207 @@ impl ContractVerificationDal<'_, '_> {
         .await
     }
 
+    /// Returns contracts deployed in the specified L2 blocks that have no verification info, ordered by deployment.
+    pub async fn get_unverified_deployed_contracts(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<UnverifiedDeployedContract>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                deploy_event.miniblock_number,
+                deploy_event.topic4 AS contract_address,
+                factory_deps.bytecode_hash,
+                factory_deps.bytecode
+            FROM
+                events deploy_event
+            JOIN factory_deps ON factory_deps.bytecode_hash = deploy_event.topic3
+            WHERE
+                deploy_event.address = $1
+                AND deploy_event.topic1 = $2
+                AND deploy_event.miniblock_number BETWEEN $3 AND $4
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        contract_verification_info_v2
+                    WHERE
+                        initial_contract_addr = SUBSTRING(deploy_event.topic4 FROM 13)
+                )
+            ORDER BY
+                deploy_event.miniblock_number,
+                deploy_event.event_index_in_block
+            "#,
+            CONTRACT_DEPLOYER_ADDRESS.as_bytes(),
+            VmEvent::DEPLOY_EVENT_SIGNATURE.as_bytes(),
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0),
+        )
+        .instrument("get_unverified_deployed_contracts")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| UnverifiedDeployedContract {
+                    address: Address::from_slice(&row.contract_address[12..]),
+                    l2_block_number: L2BlockNumber(row.miniblock_number as u32),
+                    bytecode_hash: H256::from_slice(&row.bytecode_hash),
+                    bytecode: row.bytecode,
+                })
+                .collect()
+        })
+    }
+
+    /// Finds a verified contract with the specified bytecode hashes. Perfect matches (i.e., matching
+    /// `bytecode_keccak256`) take precedence over partial ones; among matches of the same kind,
+    /// the earliest verified contract is returned.
+    pub async fn find_verified_contract_by_bytecode(
+        &mut self,
+        bytecode_keccak256: H256,
+        bytecode_without_metadata_keccak256: H256,
+    ) -> DalResult<Option<(Address, SimilarMatchStatus)>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                initial_contract_addr,
+                bytecode_keccak256 = $1 AS "is_perfect_match!"
+            FROM
+                contract_verification_info_v2
+            WHERE
+                bytecode_keccak256 = $1
+                OR bytecode_without_metadata_keccak256 = $2
+            ORDER BY
+                (bytecode_keccak256 = $1) DESC,
+                created_at
+            LIMIT
+                1
+            "#,
+            bytecode_keccak256.as_bytes(),
+            bytecode_without_metadata_keccak256.as_bytes()
+        )
+        .instrument("find_verified_contract_by_bytecode")
+        .with_arg("bytecode_keccak256", &bytecode_keccak256)
+        .with_arg(
+            "bytecode_without_metadata_keccak256",
+            &bytecode_without_metadata_keccak256,
+        )
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| {
+            (
+                Address::from_slice(&row.initial_contract_addr),
+                similar_match_status(row.is_perfect_match),
+            )
+        }))
+    }
+
+    /// Returns the last L2 block processed by the similar match discovery, or `None` if no blocks were processed yet.
+    pub async fn get_last_similar_match_l2_block(&mut self) -> DalResult<Option<L2BlockNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(miniblock_number) AS "number?"
+            FROM
+                contract_verification_similar_matches
+            "#
+        )
+        .instrument("get_last_similar_match_l2_block")
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.number.map(|number| L2BlockNumber(number as u32)))
+    }
+
+    /// Inserts similar match records. Records for contracts that were previously processed without finding a match
+    /// are updated if a match is found now; existing matches are never overwritten.
+    pub async fn insert_similar_matches(
+        &mut self,
+        records: &[SimilarMatchRecord],
+    ) -> DalResult<()> {
+        let mut contract_addresses = Vec::with_capacity(records.len());
+        let mut l2_block_numbers = Vec::with_capacity(records.len());
+        let mut matched_addresses = Vec::with_capacity(records.len());
+        let mut perfect_match_flags = Vec::with_capacity(records.len());
+        for record in records {
+            let (matched_address, is_perfect_match) = match record.matched {
+                Some((address, status)) => (
+                    Some(address.as_bytes().to_vec()),
+                    Some(status == SimilarMatchStatus::Perfect),
+                ),
+                None => (None, None),
+            };
+            contract_addresses.push(record.contract_address.as_bytes());
+            l2_block_numbers.push(i64::from(record.l2_block_number.0));
+            matched_addresses.push(matched_address);
+            perfect_match_flags.push(is_perfect_match);
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            contract_verification_similar_matches (
+                contract_addr,
+                miniblock_number,
+                matched_contract_addr,
+                is_perfect_match
+            )
+            SELECT
+                *
+            FROM
+                UNNEST($1::bytea [], $2::bigint [], $3::bytea [], $4::bool [])
+            ON CONFLICT (contract_addr) DO
+            UPDATE
+            SET
+            matched_contract_addr = excluded.matched_contract_addr,
+            is_perfect_match = excluded.is_perfect_match
+            WHERE
+                contract_verification_similar_matches.matched_contract_addr IS NULL
+            "#,
+            &contract_addresses as &[&[u8]],
+            &l2_block_numbers,
+            &matched_addresses as &[Option<Vec<u8>>],
+            &perfect_match_flags as &[Option<bool>],
+        )
+        .instrument("insert_similar_matches")
+        .with_arg("records.len", &records.len())
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the verified contract with the bytecode matching the specified contract, as discovered
+    /// by the similar match discovery.
+    pub async fn get_similar_match(
+        &mut self,
+        address: Address,
+    ) -> DalResult<Option<(Address, SimilarMatchStatus)>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                matched_contract_addr AS "matched_contract_addr!",
+                is_perfect_match AS "is_perfect_match!"
+            FROM
+                contract_verification_similar_matches
+            WHERE
+                contract_addr = $1
+                AND matched_contract_addr IS NOT NULL
+            "#,
+            address.as_bytes()
+        )
+        .instrument("get_similar_match")
+        .with_arg("address", &address)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| {
+            (
+                Address::from_slice(&row.matched_contract_addr),
+                similar_match_status(row.is_perfect_match),
+            )
+        }))
+    }
+
     /// Checks if migration from `contracts_verification_info` to `contract_verification_info_v2` is performed
     /// by checking if the latter has more or equal number of rows.
     pub async fn is_verification_info_migration_performed(&mut self) -> DalResult<bool> {
@@ -924,6 +1379,79 @@ mod tests {
         assert_eq!(contract.bytecode, bytecode);
         assert_eq!(contract.contract_address, Some(CONTRACT_DEPLOYER_ADDRESS));
         assert_eq!(contract.calldata.unwrap(), tx.execute.calldata);
+
+        let unverified_contracts = conn
+            .contract_verification_dal()
+            .get_unverified_deployed_contracts(L2BlockNumber(0)..=L2BlockNumber(0))
+            .await
+            .unwrap();
+        assert_eq!(unverified_contracts.len(), 1);
+        assert_eq!(unverified_contracts[0].address, deployed_address);
+        assert_eq!(unverified_contracts[0].l2_block_number, L2BlockNumber(0));
+        assert_eq!(unverified_contracts[0].bytecode_hash, bytecode_hash);
+        assert_eq!(unverified_contracts[0].bytecode, bytecode);
+    }
+
+    #[tokio::test]
+    async fn persisting_similar_matches() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.contract_verification_dal();
+        assert_eq!(dal.get_last_similar_match_l2_block().await.unwrap(), None);
+
+        let matched_address = Address::repeat_byte(1);
+        let records = [
+            SimilarMatchRecord {
+                contract_address: Address::repeat_byte(2),
+                l2_block_number: L2BlockNumber(1),
+                matched: Some((matched_address, SimilarMatchStatus::Partial)),
+            },
+            SimilarMatchRecord {
+                contract_address: Address::repeat_byte(3),
+                l2_block_number: L2BlockNumber(3),
+                matched: None,
+            },
+        ];
+        dal.insert_similar_matches(&records).await.unwrap();
+        // Insertion must be idempotent.
+        dal.insert_similar_matches(&records).await.unwrap();
+        // A match found later must update the record without a match, but must not overwrite an existing match.
+        let updated_records = [
+            SimilarMatchRecord {
+                contract_address: Address::repeat_byte(2),
+                l2_block_number: L2BlockNumber(1),
+                matched: Some((Address::repeat_byte(5), SimilarMatchStatus::Perfect)),
+            },
+            SimilarMatchRecord {
+                contract_address: Address::repeat_byte(3),
+                l2_block_number: L2BlockNumber(3),
+                matched: Some((matched_address, SimilarMatchStatus::Perfect)),
+            },
+        ];
+        dal.insert_similar_matches(&updated_records).await.unwrap();
+
+        assert_eq!(
+            dal.get_last_similar_match_l2_block().await.unwrap(),
+            Some(L2BlockNumber(3))
+        );
+        assert_eq!(
+            dal.get_similar_match(Address::repeat_byte(2))
+                .await
+                .unwrap(),
+            Some((matched_address, SimilarMatchStatus::Partial))
+        );
+        assert_eq!(
+            dal.get_similar_match(Address::repeat_byte(3))
+                .await
+                .unwrap(),
+            Some((matched_address, SimilarMatchStatus::Perfect))
+        );
+        assert_eq!(
+            dal.get_similar_match(Address::repeat_byte(4))
+                .await
+                .unwrap(),
+            None
+        );
     }
 
     async fn test_working_with_verification_requests(zksolc: Option<&str>) {
@@ -961,7 +1489,7 @@ mod tests {
 
         let req = conn
             .contract_verification_dal()
-            .get_next_queued_verification_request(Duration::from_secs(600))
+            .get_next_queued_verification_request(Duration::from_secs(600), false)
             .await
             .unwrap()
             .expect("request not queued");
@@ -977,7 +1505,7 @@ mod tests {
 
         let maybe_req = conn
             .contract_verification_dal()
-            .get_next_queued_verification_request(Duration::from_secs(600))
+            .get_next_queued_verification_request(Duration::from_secs(600), false)
             .await
             .unwrap();
         assert!(maybe_req.is_none());
@@ -988,4 +1516,101 @@ mod tests {
         test_working_with_verification_requests(None).await;
         test_working_with_verification_requests(Some("1.5.7")).await;
     }
+
+    #[tokio::test]
+    async fn prioritizing_verification_requests() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.contract_verification_dal();
+
+        let mut ids = vec![];
+        for (i, (requester, priority_lane)) in
+            [(None, false), (Some("explorer"), true), (None, false)]
+                .into_iter()
+                .enumerate()
+        {
+            let request = VerificationIncomingRequest {
+                contract_address: Address::repeat_byte(i as u8 + 1),
+                source_code_data: SourceCodeData::SolSingleFile("contract Test {}".to_owned()),
+                contract_name: "Test".to_owned(),
+                compiler_versions: CompilerVersions::Solc {
+                    compiler_zksolc_version: None,
+                    compiler_solc_version: "0.8.27".to_owned(),
+                },
+                optimization_used: true,
+                optimizer_mode: None,
+                constructor_arguments: web3::Bytes::default(),
+                is_system: false,
+                force_evmla: false,
+                evm_specific: Default::default(),
+            };
+            let id = dal
+                .add_contract_verification_request_from(&request, requester, priority_lane)
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+
+        let queued = dal
+            .get_verification_requests(Some("queued"), 10)
+            .await
+            .unwrap();
+        let queued_ids: Vec<_> = queued.iter().map(|req| req.id).collect();
+        assert_eq!(queued_ids, ids);
+        assert_eq!(queued[1].requester.as_deref(), Some("explorer"));
+        assert!(queued[1].priority_lane);
+
+        // Bump the priority of the last request; it should be picked up first regardless of the lane.
+        let updated = dal
+            .set_queued_verification_requests_priority(Some(Address::repeat_byte(3)), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(updated, 1);
+        let timeout = Duration::from_secs(600);
+        let req = dal
+            .get_next_queued_verification_request(timeout, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.id, ids[2]);
+
+        let req = dal
+            .get_next_queued_verification_request(timeout, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.id, ids[1]);
+
+        dal.save_verification_error(ids[1], "oops", &serde_json::json!(["error"]), None)
+            .await
+            .unwrap();
+        let details = dal
+            .get_verification_request_details(ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(details.summary.status, "failed");
+        assert_eq!(details.summary.attempts, 1);
+        assert_eq!(details.error.as_deref(), Some("oops"));
+        assert_eq!(
+            details.compilation_errors,
+            Some(serde_json::json!(["error"]))
+        );
+
+        assert!(dal.requeue_verification_request(ids[1]).await.unwrap());
+        // Queued requests cannot be re-queued.
+        assert!(!dal.requeue_verification_request(ids[0]).await.unwrap());
+        let req = dal
+            .get_next_queued_verification_request(timeout, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.id, ids[0]);
+        let req = dal
+            .get_next_queued_verification_request(timeout, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(req.id, ids[1]);
+    }
 }
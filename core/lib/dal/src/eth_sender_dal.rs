@@ -9,6 +9,7 @@ use zksync_db_connection::{
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
     eth_sender::{EthTx, EthTxBlobSidecar, TxHistory, TxHistoryToSend},
+    settlement::SettlementMode,
     Address, L1BatchNumber, SLChainId, H256, U256,
 };
 
@@ -25,10 +26,12 @@ pub struct EthSenderDal<'a, 'c> {
 }
 
 impl EthSenderDal<'_, '_> {
+    /// Returns in-flight transactions (i.e., sent but not yet confirmed ones) of the specified operator
+    /// for the specified settlement layer.
     pub async fn get_inflight_txs(
         &mut self,
         operator_address: Option<Address>,
-        is_gateway: bool,
+        settlement_mode: SettlementMode,
     ) -> sqlx::Result<Vec<EthTx>> {
         let txs = sqlx::query_as!(
             StorageEthTx,
@@ -58,16 +61,63 @@ impl EthSenderDal<'_, '_> {
                 id
             "#,
             operator_address.as_ref().map(|h160| h160.as_bytes()),
-            is_gateway
+            settlement_mode.is_gateway()
         )
         .fetch_all(self.storage.conn())
         .await?;
         Ok(txs.into_iter().map(|tx| tx.into()).collect())
     }
 
-    pub async fn get_non_gateway_inflight_txs_count_for_gateway_migration(
+    /// Returns the number of transactions that would be returned by [`Self::get_inflight_txs()`]
+    /// without loading them.
+    pub async fn get_inflight_txs_count(
+        &mut self,
+        operator_address: Option<Address>,
+        settlement_mode: SettlementMode,
+    ) -> DalResult<usize> {
+        let count = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*)
+            FROM
+                eth_txs
+            WHERE
+                from_addr IS NOT DISTINCT FROM $1 -- can't just use equality as NULL != NULL
+                AND confirmed_eth_tx_history_id IS NULL
+                AND is_gateway = $2
+                AND id <= COALESCE(
+                    (SELECT
+                        eth_tx_id
+                    FROM
+                        eth_txs_history
+                    JOIN eth_txs ON eth_txs.id = eth_txs_history.eth_tx_id
+                    WHERE
+                        eth_txs_history.sent_at_block IS NOT NULL
+                        AND eth_txs.from_addr IS NOT DISTINCT FROM $1
+                        AND is_gateway = $2
+                    ORDER BY eth_tx_id DESC LIMIT 1),
+                    0
+                )
+            "#,
+            operator_address.as_ref().map(|h160| h160.as_bytes()),
+            settlement_mode.is_gateway()
+        )
+        .instrument("get_inflight_txs_count")
+        .with_arg("operator_address", &operator_address)
+        .with_arg("settlement_mode", &settlement_mode)
+        .fetch_one(self.storage)
+        .await?
+        .count
+        .unwrap();
+        Ok(count.try_into().unwrap())
+    }
+
+    /// Returns the number of unconfirmed transactions (both sent and not yet sent ones) of all operators
+    /// for the specified settlement layer. Used to check that the settlement layer can be switched.
+    pub async fn get_unconfirmed_txs_count_for_settlement_mode(
         &mut self,
-    ) -> sqlx::Result<usize> {
+        settlement_mode: SettlementMode,
+    ) -> DalResult<usize> {
         let count = sqlx::query!(
             r#"
             SELECT
@@ -76,10 +126,13 @@ impl EthSenderDal<'_, '_> {
                 eth_txs
             WHERE
                 confirmed_eth_tx_history_id IS NULL
-                AND is_gateway = FALSE
-            "#
+                AND is_gateway = $1
+            "#,
+            settlement_mode.is_gateway()
         )
-        .fetch_one(self.storage.conn())
+        .instrument("get_unconfirmed_txs_count_for_settlement_mode")
+        .with_arg("settlement_mode", &settlement_mode)
+        .fetch_one(self.storage)
         .await?
         .count
         .unwrap();
@@ -181,7 +234,7 @@ impl EthSenderDal<'_, '_> {
         &mut self,
         limit: u64,
         operator_address: &Option<Address>,
-        is_gateway: bool,
+        settlement_mode: SettlementMode,
     ) -> sqlx::Result<Vec<EthTx>> {
         let txs = sqlx::query_as!(
             StorageEthTx,
@@ -213,7 +266,7 @@ impl EthSenderDal<'_, '_> {
             "#,
             limit as i64,
             operator_address.as_ref().map(|h160| h160.as_bytes()),
-            is_gateway
+            settlement_mode.is_gateway()
         )
         .fetch_all(self.storage.conn())
         .await?;
@@ -489,6 +542,32 @@ impl EthSenderDal<'_, '_> {
         Ok(row.and_then(|r| r.chain_id).map(|id| SLChainId(id as u64)))
     }
 
+    /// Returns the settlement mode of the most recently created transaction, or `None` if there are no transactions.
+    pub async fn get_latest_settlement_mode(&mut self) -> DalResult<Option<SettlementMode>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                is_gateway
+            FROM
+                eth_txs
+            ORDER BY
+                id DESC
+            LIMIT
+                1
+            "#
+        )
+        .instrument("get_latest_settlement_mode")
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| {
+            if row.is_gateway {
+                SettlementMode::Gateway
+            } else {
+                SettlementMode::SettlesToL1
+            }
+        }))
+    }
+
     pub async fn get_confirmed_tx_hash_by_eth_tx_id(
         &mut self,
         eth_tx_id: u32,
@@ -697,7 +776,7 @@ impl EthSenderDal<'_, '_> {
     pub async fn get_next_nonce(
         &mut self,
         from_address: Option<Address>,
-        is_gateway: bool,
+        settlement_mode: SettlementMode,
     ) -> sqlx::Result<Option<u64>> {
         let nonce = sqlx::query!(
             r#"
@@ -715,7 +794,7 @@ impl EthSenderDal<'_, '_> {
                 1
             "#,
             from_address.as_ref().map(|h160| h160.as_bytes()),
-            is_gateway
+            settlement_mode.is_gateway()
         )
         .fetch_optional(self.storage.conn())
         .await?;
@@ -723,6 +802,73 @@ impl EthSenderDal<'_, '_> {
         Ok(nonce.map(|row| row.nonce as u64 + 1))
     }
 
+    /// Returns the maximum nonce used by the operator for the specified settlement layer, or `None`
+    /// if the operator has no transactions for it. See [`Self::get_next_nonce()`] for the meaning of `from_address`.
+    pub async fn get_max_used_nonce(
+        &mut self,
+        from_address: Option<Address>,
+        settlement_mode: SettlementMode,
+    ) -> DalResult<Option<u64>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(nonce) AS "max_nonce"
+            FROM
+                eth_txs
+            WHERE
+                -- can't just use equality as NULL != NULL
+                from_addr IS NOT DISTINCT FROM $1
+                AND is_gateway = $2
+            "#,
+            from_address.as_ref().map(|h160| h160.as_bytes()),
+            settlement_mode.is_gateway()
+        )
+        .instrument("get_max_used_nonce")
+        .with_arg("from_address", &from_address)
+        .with_arg("settlement_mode", &settlement_mode)
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(row.max_nonce.map(|nonce| nonce as u64))
+    }
+
+    /// Assigns L1 transactions of the main operator (i.e., ones with the `NULL` sender address) to the specified
+    /// previous operator address. This is used during operator key rotation, so that transactions signed
+    /// by the previous key are tracked separately from transactions signed by the new key. Returns the number
+    /// of reassigned transactions.
+    ///
+    /// Transactions are only reassigned if there are no transactions for `previous_operator` yet, so that
+    /// transactions sent by the new key are not reassigned on node restarts.
+    pub async fn assign_main_operator_txs_to_previous_operator(
+        &mut self,
+        previous_operator: Address,
+    ) -> DalResult<u64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE eth_txs
+            SET
+                from_addr = $1
+            WHERE
+                from_addr IS NULL
+                AND is_gateway = FALSE
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        eth_txs
+                    WHERE
+                        from_addr = $1
+                )
+            "#,
+            previous_operator.as_bytes()
+        )
+        .instrument("assign_main_operator_txs_to_previous_operator")
+        .with_arg("previous_operator", &previous_operator)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     pub async fn mark_failed_transaction(&mut self, eth_tx_id: u32) -> sqlx::Result<()> {
         sqlx::query!(
             r#"
@@ -7,30 +7,36 @@ pub use sqlx::{types::BigDecimal, Error as SqlxError};
 use zksync_db_connection::connection::DbMarker;
 pub use zksync_db_connection::{
     connection::{Connection, IsolationLevel},
-    connection_pool::{ConnectionPool, ConnectionPoolBuilder},
+    connection_pool::{ConnectionPool, ConnectionPoolBuilder, SeededTestTemplate},
     error::{DalError, DalResult},
+    seeded_test_template,
 };
 
 use crate::{
-    base_token_dal::BaseTokenDal, blocks_dal::BlocksDal, blocks_web3_dal::BlocksWeb3Dal,
+    address_policies_dal::AddressPoliciesDal, base_token_dal::BaseTokenDal, blocks_dal::BlocksDal,
+    blocks_web3_dal::BlocksWeb3Dal, component_state_dal::ComponentStateDal,
     consensus_dal::ConsensusDal, contract_verification_dal::ContractVerificationDal,
     custom_genesis_export_dal::CustomGenesisExportDal, data_availability_dal::DataAvailabilityDal,
     eth_sender_dal::EthSenderDal, eth_watcher_dal::EthWatcherDal,
     etherscan_verification_dal::EtherscanVerificationDal, events_dal::EventsDal,
     events_web3_dal::EventsWeb3Dal, factory_deps_dal::FactoryDepsDal,
     proof_generation_dal::ProofGenerationDal, protocol_versions_dal::ProtocolVersionsDal,
-    protocol_versions_web3_dal::ProtocolVersionsWeb3Dal, pruning_dal::PruningDal,
+    protocol_versions_web3_dal::ProtocolVersionsWeb3Dal,
+    proxied_transactions_dal::ProxiedTransactionsDal, pruning_dal::PruningDal,
     snapshot_recovery_dal::SnapshotRecoveryDal, snapshots_creator_dal::SnapshotsCreatorDal,
-    snapshots_dal::SnapshotsDal, storage_logs_dal::StorageLogsDal,
-    storage_logs_dedup_dal::StorageLogsDedupDal, storage_web3_dal::StorageWeb3Dal,
-    sync_dal::SyncDal, system_dal::SystemDal, tee_proof_generation_dal::TeeProofGenerationDal,
-    tokens_dal::TokensDal, tokens_web3_dal::TokensWeb3Dal, transactions_dal::TransactionsDal,
+    snapshots_dal::SnapshotsDal, sourcify_sync_dal::SourcifySyncDal,
+    storage_logs_dal::StorageLogsDal, storage_logs_dedup_dal::StorageLogsDedupDal,
+    storage_web3_dal::StorageWeb3Dal, sync_dal::SyncDal, system_dal::SystemDal,
+    tee_proof_generation_dal::TeeProofGenerationDal, tokens_dal::TokensDal,
+    tokens_web3_dal::TokensWeb3Dal, transactions_dal::TransactionsDal,
     transactions_web3_dal::TransactionsWeb3Dal, vm_runner_dal::VmRunnerDal,
 };
 
+pub mod address_policies_dal;
 pub mod base_token_dal;
 pub mod blocks_dal;
 pub mod blocks_web3_dal;
+pub mod component_state_dal;
 pub mod consensus;
 pub mod consensus_dal;
 pub mod contract_verification_dal;
@@ -48,10 +54,12 @@ mod models;
 pub mod proof_generation_dal;
 pub mod protocol_versions_dal;
 pub mod protocol_versions_web3_dal;
+pub mod proxied_transactions_dal;
 pub mod pruning_dal;
 pub mod snapshot_recovery_dal;
 pub mod snapshots_creator_dal;
 pub mod snapshots_dal;
+pub mod sourcify_sync_dal;
 pub mod storage_logs_dal;
 pub mod storage_logs_dedup_dal;
 pub mod storage_web3_dal;
@@ -110,6 +118,8 @@ where
 
     fn etherscan_verification_dal(&mut self) -> EtherscanVerificationDal<'_, 'a>;
 
+    fn sourcify_sync_dal(&mut self) -> SourcifySyncDal<'_, 'a>;
+
     fn protocol_versions_dal(&mut self) -> ProtocolVersionsDal<'_, 'a>;
 
     fn protocol_versions_web3_dal(&mut self) -> ProtocolVersionsWeb3Dal<'_, 'a>;
@@ -139,6 +149,12 @@ where
     fn eth_watcher_dal(&mut self) -> EthWatcherDal<'_, 'a>;
 
     fn custom_genesis_export_dal(&mut self) -> CustomGenesisExportDal<'_, 'a>;
+
+    fn address_policies_dal(&mut self) -> AddressPoliciesDal<'_, 'a>;
+
+    fn proxied_transactions_dal(&mut self) -> ProxiedTransactionsDal<'_, 'a>;
+
+    fn component_state_dal(&mut self) -> ComponentStateDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -214,6 +230,10 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
         EtherscanVerificationDal { storage: self }
     }
 
+    fn sourcify_sync_dal(&mut self) -> SourcifySyncDal<'_, 'a> {
+        SourcifySyncDal { storage: self }
+    }
+
     fn protocol_versions_dal(&mut self) -> ProtocolVersionsDal<'_, 'a> {
         ProtocolVersionsDal { storage: self }
     }
@@ -273,4 +293,16 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
     fn custom_genesis_export_dal(&mut self) -> CustomGenesisExportDal<'_, 'a> {
         CustomGenesisExportDal { storage: self }
     }
+
+    fn address_policies_dal(&mut self) -> AddressPoliciesDal<'_, 'a> {
+        AddressPoliciesDal { storage: self }
+    }
+
+    fn proxied_transactions_dal(&mut self) -> ProxiedTransactionsDal<'_, 'a> {
+        ProxiedTransactionsDal { storage: self }
+    }
+
+    fn component_state_dal(&mut self) -> ComponentStateDal<'_, 'a> {
+        ComponentStateDal { storage: self }
+    }
 }
@@ -19,6 +19,17 @@ pub struct DbStorageLog {
     pub l2_block_number: L2BlockNumber,
 }
 
+/// Latest value of a contract storage slot returned by [`StorageLogsDal::get_storage_range()`].
+///
+/// [`StorageLogsDal::get_storage_range()`]: crate::storage_logs_dal::StorageLogsDal::get_storage_range()
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StorageRangeEntry {
+    pub hashed_key: H256,
+    /// Unhashed slot key. May be missing for logs restored from a snapshot.
+    pub key: Option<H256>,
+    pub value: H256,
+}
+
 // We don't want to rely on the Merkle tree crate to import a single type, so we duplicate `TreeEntry` here.
 #[derive(Debug, Clone, Copy)]
 pub struct StorageRecoveryLogEntry {
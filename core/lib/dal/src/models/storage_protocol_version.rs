@@ -5,7 +5,7 @@ use zksync_types::{
     api,
     protocol_upgrade::{self, ProtocolUpgradeTx},
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion, VersionPatch},
-    H256,
+    Address, H256,
 };
 
 #[derive(sqlx::FromRow)]
@@ -18,6 +18,7 @@ pub struct StorageProtocolVersion {
     pub bootloader_code_hash: Vec<u8>,
     pub default_account_code_hash: Vec<u8>,
     pub evm_emulator_code_hash: Option<Vec<u8>>,
+    pub verifier_address: Option<Vec<u8>>,
 }
 
 pub(crate) fn protocol_version_from_storage(
@@ -37,6 +38,10 @@ pub(crate) fn protocol_version_from_storage(
                 .as_ref()
                 .map(|x| H256::from_slice(x)),
         },
+        verifier_address: storage_version
+            .verifier_address
+            .as_deref()
+            .map(Address::from_slice),
         base_system_contracts_hashes: BaseSystemContractsHashes {
             bootloader: H256::from_slice(&storage_version.bootloader_code_hash),
             default_aa: H256::from_slice(&storage_version.default_account_code_hash),
@@ -79,3 +84,39 @@ impl From<StorageApiProtocolVersion> for api::ProtocolVersion {
         )
     }
 }
+
+#[derive(sqlx::FromRow)]
+pub struct StorageApiProtocolVersionDetails {
+    pub minor: i32,
+    pub patch: i32,
+    pub timestamp: i64,
+    pub bootloader_code_hash: Vec<u8>,
+    pub default_account_code_hash: Vec<u8>,
+    pub evm_emulator_code_hash: Option<Vec<u8>>,
+    pub upgrade_tx_hash: Option<Vec<u8>>,
+    pub snark_wrapper_vk_hash: Vec<u8>,
+    pub fflonk_snark_wrapper_vk_hash: Option<Vec<u8>>,
+    pub verifier_address: Option<Vec<u8>>,
+}
+
+impl From<StorageApiProtocolVersionDetails> for api::ProtocolVersionDetails {
+    fn from(row: StorageApiProtocolVersionDetails) -> Self {
+        Self {
+            version: ProtocolSemanticVersion {
+                minor: (row.minor as u16).try_into().unwrap(),
+                patch: VersionPatch(row.patch as u32),
+            },
+            activation_timestamp: row.timestamp as u64,
+            bootloader_code_hash: H256::from_slice(&row.bootloader_code_hash),
+            default_account_code_hash: H256::from_slice(&row.default_account_code_hash),
+            evm_emulator_code_hash: row.evm_emulator_code_hash.as_deref().map(H256::from_slice),
+            snark_wrapper_vk_hash: H256::from_slice(&row.snark_wrapper_vk_hash),
+            fflonk_snark_wrapper_vk_hash: row
+                .fflonk_snark_wrapper_vk_hash
+                .as_deref()
+                .map(H256::from_slice),
+            verifier_address: row.verifier_address.as_deref().map(Address::from_slice),
+            l2_system_upgrade_tx_hash: row.upgrade_tx_hash.as_deref().map(H256::from_slice),
+        }
+    }
+}
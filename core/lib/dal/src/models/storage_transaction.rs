@@ -1,4 +1,4 @@
-use std::{convert::TryInto, str::FromStr};
+use std::{collections::BTreeSet, convert::TryInto, str::FromStr};
 
 use bigdecimal::Zero;
 use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
@@ -10,10 +10,11 @@ use zksync_types::{
     protocol_upgrade::ProtocolUpgradeTxCommonData,
     transaction_request::PaymasterParams,
     web3::Bytes,
-    Address, Execute, ExecuteTransactionCommon, L1TxCommonData, L2ChainId, L2TxCommonData, Nonce,
-    PackedEthSignature, PriorityOpId, ProtocolVersionId, Transaction,
-    TransactionTimeRangeConstraint, EIP_1559_TX_TYPE, EIP_2930_TX_TYPE, EIP_712_TX_TYPE, H160,
-    H256, PRIORITY_OPERATION_L2_TX_TYPE, PROTOCOL_UPGRADE_TX_TYPE, U256, U64,
+    AccountTreeId, Address, Execute, ExecuteTransactionCommon, L1TxCommonData, L2ChainId,
+    L2TxCommonData, Nonce, PackedEthSignature, PriorityOpId, ProtocolVersionId, StorageKey,
+    Transaction, TransactionTimeRangeConstraint, EIP_1559_TX_TYPE, EIP_2930_TX_TYPE,
+    EIP_712_TX_TYPE, H160, H256, PRIORITY_OPERATION_L2_TX_TYPE, PROTOCOL_UPGRADE_TX_TYPE, U256,
+    U64,
 };
 use zksync_vm_interface::Call;
 
@@ -604,7 +605,21 @@ impl CallTrace {
     }
 }
 
+/// Magic number starting each zstd frame. Uncompressed call traces cannot start with it since `bincode` serializes them
+/// starting from a small `u32` call type discriminant.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const CALL_TRACE_COMPRESSION_LEVEL: i32 = 3;
+
 pub(crate) fn parse_call_trace(call_trace: &[u8], protocol_version: ProtocolVersionId) -> Call {
+    // Call traces may be stored either compressed or uncompressed (the latter for traces persisted by older server versions).
+    let decompressed;
+    let call_trace = if call_trace.starts_with(&ZSTD_MAGIC) {
+        decompressed = zstd::decode_all(call_trace).expect("Failed to decompress call trace");
+        decompressed.as_slice()
+    } else {
+        call_trace
+    };
+
     if protocol_version.is_pre_1_5_0() {
         if let Ok(legacy_call_trace) = bincode::deserialize::<LegacyCall>(call_trace) {
             legacy_call_trace.into()
@@ -618,6 +633,7 @@ pub(crate) fn parse_call_trace(call_trace: &[u8], protocol_version: ProtocolVers
     }
 }
 
+/// Serializes a call trace without compression; the output can be compressed with [`compress_call_trace()`].
 pub(crate) fn serialize_call_into_bytes(
     call: Call,
     protocol_version: ProtocolVersionId,
@@ -629,3 +645,38 @@ pub(crate) fn serialize_call_into_bytes(
     }
     .unwrap()
 }
+
+pub(crate) fn compress_call_trace(serialized: &[u8]) -> Vec<u8> {
+    zstd::encode_all(serialized, CALL_TRACE_COMPRESSION_LEVEL)
+        .expect("Failed to compress call trace")
+}
+
+/// Size of a serialized storage slot: 20-byte contract address followed by 32-byte key.
+const STORAGE_SLOT_SIZE: usize = 52;
+
+pub(crate) fn serialize_storage_slots(slots: &BTreeSet<StorageKey>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(slots.len() * STORAGE_SLOT_SIZE);
+    for slot in slots {
+        bytes.extend_from_slice(slot.address().as_bytes());
+        bytes.extend_from_slice(slot.key().as_bytes());
+    }
+    bytes
+}
+
+pub(crate) fn parse_storage_slots(bytes: &[u8]) -> BTreeSet<StorageKey> {
+    assert_eq!(
+        bytes.len() % STORAGE_SLOT_SIZE,
+        0,
+        "Invalid serialized storage slots length"
+    );
+    bytes
+        .chunks_exact(STORAGE_SLOT_SIZE)
+        .map(|chunk| {
+            let (address, key) = chunk.split_at(20);
+            StorageKey::new(
+                AccountTreeId::new(Address::from_slice(address)),
+                H256::from_slice(key),
+            )
+        })
+        .collect()
+}
@@ -352,3 +352,31 @@ fn storage_tx_to_l2_tx_unsupported_tx_type() {
     let stx = l2_storage_tx(1984);
     _ = Transaction::from(stx.clone());
 }
+
+#[test]
+fn call_trace_compression() {
+    use zksync_types::ProtocolVersionId;
+    use zksync_vm_interface::Call;
+
+    use crate::models::storage_transaction::{
+        compress_call_trace, parse_call_trace, serialize_call_into_bytes,
+    };
+
+    let call = Call {
+        from: Address::repeat_byte(1),
+        to: Address::repeat_byte(2),
+        gas: 1_000_000,
+        input: vec![0; 1_024],
+        calls: vec![Call::default(); 10],
+        ..Call::default()
+    };
+    let protocol_version = ProtocolVersionId::latest();
+    let uncompressed = serialize_call_into_bytes(call.clone(), protocol_version);
+    assert_eq!(uncompressed, bincode::serialize(&call).unwrap());
+    let compressed = compress_call_trace(&uncompressed);
+    assert!(compressed.len() < uncompressed.len());
+
+    assert_eq!(parse_call_trace(&compressed, protocol_version), call);
+    // Traces persisted without compression must be readable as well.
+    assert_eq!(parse_call_trace(&uncompressed, protocol_version), call);
+}
@@ -1,6 +1,7 @@
 #![doc = include_str!("../doc/ProofGenerationDal.md")]
-use std::time::Duration;
+use std::{collections::HashSet, time::Duration};
 
+use chrono::NaiveDateTime;
 use strum::{Display, EnumString};
 use zksync_db_connection::{
     connection::Connection,
@@ -8,7 +9,11 @@ use zksync_db_connection::{
     instrument::{InstrumentExt, Instrumented},
     utils::pg_interval_from_duration,
 };
-use zksync_types::L1BatchNumber;
+use zksync_types::{
+    api::{ProvingRoundDetails, ProvingRoundStatus},
+    basic_fri_types::AggregationRound,
+    L1BatchNumber,
+};
 
 use crate::Core;
 
@@ -17,6 +22,15 @@ pub struct ProofGenerationDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
 }
 
+/// Audit record of a batch whose proof generation was skipped manually.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofGenerationSkipRecord {
+    pub l1_batch_number: L1BatchNumber,
+    pub requested_by: String,
+    pub reason: String,
+    pub created_at: NaiveDateTime,
+}
+
 #[derive(Debug, EnumString, Display)]
 enum ProofGenerationJobStatus {
     #[strum(serialize = "unpicked")]
@@ -29,6 +43,21 @@ enum ProofGenerationJobStatus {
     Skipped,
 }
 
+fn proving_round_status_to_str(status: ProvingRoundStatus) -> &'static str {
+    match status {
+        ProvingRoundStatus::Completed => "completed",
+        ProvingRoundStatus::Failing => "failing",
+    }
+}
+
+fn proving_round_status_from_str(status: &str) -> ProvingRoundStatus {
+    match status {
+        "completed" => ProvingRoundStatus::Completed,
+        "failing" => ProvingRoundStatus::Failing,
+        _ => panic!("invalid proving round status stored in DB: {status}"),
+    }
+}
+
 impl ProofGenerationDal<'_, '_> {
     /// Chooses the batch number so that it has all the necessary data to generate the proof
     /// and is not already picked.
@@ -313,6 +342,255 @@ impl ProofGenerationDal<'_, '_> {
         Ok(())
     }
 
+    /// Marks proof generation for the batch as skipped on the operator's request, recording who
+    /// requested it and why.
+    ///
+    /// Returns `false` (and doesn't record anything) if the batch has no proof generation details
+    /// or its proof is already generated.
+    pub async fn mark_proof_generation_job_as_skipped_manually(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        requested_by: &str,
+        reason: &str,
+    ) -> DalResult<bool> {
+        let mut transaction = self.storage.start_transaction().await?;
+        let status = ProofGenerationJobStatus::Skipped.to_string();
+        let generated_status = ProofGenerationJobStatus::Generated.to_string();
+        let batch_number = i64::from(l1_batch_number.0);
+        let query = sqlx::query!(
+            r#"
+            UPDATE proof_generation_details
+            SET
+                status = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND status != $3
+            "#,
+            status,
+            batch_number,
+            generated_status
+        );
+        let result = query
+            .instrument("mark_proof_generation_job_as_skipped_manually")
+            .with_arg("l1_batch_number", &l1_batch_number)
+            .with_arg("requested_by", &requested_by)
+            .execute(&mut transaction)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            proof_generation_skip_audit (l1_batch_number, requested_by, reason, created_at)
+            VALUES
+            ($1, $2, $3, NOW())
+            "#,
+            batch_number,
+            requested_by,
+            reason
+        )
+        .instrument("mark_proof_generation_job_as_skipped_manually#insert_audit")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(true)
+    }
+
+    /// Returns audit records of manual proof generation skips for the batch, oldest first.
+    pub async fn get_proof_generation_skip_records(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<ProofGenerationSkipRecord>> {
+        let records = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                requested_by,
+                reason,
+                created_at
+            FROM
+                proof_generation_skip_audit
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                id
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_proof_generation_skip_records")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|row| ProofGenerationSkipRecord {
+                l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+                requested_by: row.requested_by,
+                reason: row.reason,
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+
+    /// Returns up to `limit` batches (in the ascending order) whose proof generation was skipped manually,
+    /// but the skip wasn't propagated to the prover subsystem yet.
+    pub async fn get_unpropagated_proof_generation_skips(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<L1BatchNumber>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT
+                l1_batch_number
+            FROM
+                proof_generation_skip_audit
+            WHERE
+                propagated_to_prover_at IS NULL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                $1
+            "#,
+            limit as i64
+        )
+        .instrument("get_unpropagated_proof_generation_skips")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+            .collect())
+    }
+
+    /// Marks manual proof generation skips for the batch as propagated to the prover subsystem.
+    pub async fn mark_proof_generation_skip_as_propagated(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE proof_generation_skip_audit
+            SET
+                propagated_to_prover_at = NOW()
+            WHERE
+                l1_batch_number = $1
+                AND propagated_to_prover_at IS NULL
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("mark_proof_generation_skip_as_propagated")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Saves intermediate proving progress for an aggregation round of the batch, overwriting
+    /// the previously saved progress for the same round.
+    pub async fn save_proving_round(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        aggregation_round: AggregationRound,
+        status: ProvingRoundStatus,
+        jobs_count: u32,
+        failed_circuit_ids: &[u8],
+        artifact_url: Option<&str>,
+    ) -> DalResult<()> {
+        let failed_circuit_ids: Vec<_> =
+            failed_circuit_ids.iter().copied().map(i16::from).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            proof_generation_rounds (
+                l1_batch_number,
+                aggregation_round,
+                status,
+                jobs_count,
+                failed_circuit_ids,
+                artifact_url,
+                created_at,
+                updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+            ON CONFLICT (l1_batch_number, aggregation_round) DO
+            UPDATE
+            SET
+            status = excluded.status,
+            jobs_count = excluded.jobs_count,
+            failed_circuit_ids = excluded.failed_circuit_ids,
+            artifact_url = excluded.artifact_url,
+            updated_at = NOW()
+            "#,
+            i64::from(l1_batch_number.0),
+            aggregation_round as i16,
+            proving_round_status_to_str(status),
+            jobs_count as i32,
+            &failed_circuit_ids,
+            artifact_url
+        )
+        .instrument("save_proving_round")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("aggregation_round", &aggregation_round)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns intermediate proving progress for the batch ordered by the aggregation round.
+    pub async fn get_proving_rounds(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<ProvingRoundDetails>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                aggregation_round,
+                status,
+                jobs_count,
+                failed_circuit_ids,
+                artifact_url,
+                updated_at
+            FROM
+                proof_generation_rounds
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                aggregation_round
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .instrument("get_proving_rounds")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ProvingRoundDetails {
+                aggregation_round: AggregationRound::from(row.aggregation_round as u8),
+                status: proving_round_status_from_str(&row.status),
+                jobs_count: row.jobs_count as u32,
+                failed_circuit_ids: row
+                    .failed_circuit_ids
+                    .into_iter()
+                    .map(|id| id as u8)
+                    .collect(),
+                artifact_url: row.artifact_url,
+                updated_at: row.updated_at.and_utc(),
+            })
+            .collect())
+    }
+
     pub async fn get_oldest_unpicked_batch(&mut self) -> DalResult<Option<L1BatchNumber>> {
         let result: Option<L1BatchNumber> = sqlx::query!(
             r#"
@@ -358,6 +636,48 @@ impl ProofGenerationDal<'_, '_> {
 
         Ok(result)
     }
+
+    /// Returns the subset of `blob_urls` referenced by proof generation details, i.e. Merkle paths,
+    /// VM run data or proofs for L1 batches.
+    pub async fn filter_referenced_blob_urls(
+        &mut self,
+        blob_urls: &[String],
+    ) -> DalResult<HashSet<String>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                proof_gen_data_blob_url,
+                vm_run_data_blob_url,
+                proof_blob_url
+            FROM
+                proof_generation_details
+            WHERE
+                proof_gen_data_blob_url = ANY($1)
+                OR vm_run_data_blob_url = ANY($1)
+                OR proof_blob_url = ANY($1)
+            "#,
+            blob_urls
+        )
+        .instrument("filter_referenced_blob_urls")
+        .with_arg("blob_urls.len", &blob_urls.len())
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        let queried_urls: HashSet<_> = blob_urls.iter().collect();
+        Ok(rows
+            .into_iter()
+            .flat_map(|row| {
+                [
+                    row.proof_gen_data_blob_url,
+                    row.vm_run_data_blob_url,
+                    row.proof_blob_url,
+                ]
+            })
+            .flatten()
+            .filter(|url| queried_urls.contains(url))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -490,5 +810,191 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(unpicked_l1_batch, None);
+
+        let blob_urls = ["vm_run", "data", "proof", "orphan"].map(str::to_owned);
+        let referenced_urls = conn
+            .proof_generation_dal()
+            .filter_referenced_blob_urls(&blob_urls)
+            .await
+            .unwrap();
+        assert_eq!(
+            referenced_urls,
+            HashSet::from(["vm_run", "data", "proof"].map(str::to_owned))
+        );
+
+        // Batches with a generated proof cannot be skipped.
+        let skipped = conn
+            .proof_generation_dal()
+            .mark_proof_generation_job_as_skipped_manually(L1BatchNumber(1), "operator", "test")
+            .await
+            .unwrap();
+        assert!(!skipped);
+        let records = conn
+            .proof_generation_dal()
+            .get_proof_generation_skip_records(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[tokio::test]
+    async fn skipping_proof_generation_manually() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        // Proof generation details for the batch don't exist yet.
+        let skipped = conn
+            .proof_generation_dal()
+            .mark_proof_generation_job_as_skipped_manually(L1BatchNumber(1), "operator", "test")
+            .await
+            .unwrap();
+        assert!(!skipped);
+
+        conn.proof_generation_dal()
+            .insert_proof_generation_details(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let skipped = conn
+            .proof_generation_dal()
+            .mark_proof_generation_job_as_skipped_manually(
+                L1BatchNumber(1),
+                "operator",
+                "prover outage",
+            )
+            .await
+            .unwrap();
+        assert!(skipped);
+
+        let unpicked_l1_batch = conn
+            .proof_generation_dal()
+            .get_oldest_unpicked_batch()
+            .await
+            .unwrap();
+        assert_eq!(unpicked_l1_batch, None);
+        let picked_l1_batch = conn
+            .proof_generation_dal()
+            .lock_batch_for_proving(Duration::MAX)
+            .await
+            .unwrap();
+        assert_eq!(picked_l1_batch, None);
+
+        let records = conn
+            .proof_generation_dal()
+            .get_proof_generation_skip_records(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].l1_batch_number, L1BatchNumber(1));
+        assert_eq!(records[0].requested_by, "operator");
+        assert_eq!(records[0].reason, "prover outage");
+
+        let unpropagated = conn
+            .proof_generation_dal()
+            .get_unpropagated_proof_generation_skips(10)
+            .await
+            .unwrap();
+        assert_eq!(unpropagated, [L1BatchNumber(1)]);
+        conn.proof_generation_dal()
+            .mark_proof_generation_skip_as_propagated(L1BatchNumber(1))
+            .await
+            .unwrap();
+        let unpropagated = conn
+            .proof_generation_dal()
+            .get_unpropagated_proof_generation_skips(10)
+            .await
+            .unwrap();
+        assert!(unpropagated.is_empty());
+    }
+
+    #[tokio::test]
+    async fn saving_proving_rounds() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        let rounds = conn
+            .proof_generation_dal()
+            .get_proving_rounds(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(rounds.is_empty());
+
+        conn.proof_generation_dal()
+            .save_proving_round(
+                L1BatchNumber(1),
+                AggregationRound::LeafAggregation,
+                ProvingRoundStatus::Failing,
+                16,
+                &[3, 7],
+                None,
+            )
+            .await
+            .unwrap();
+        conn.proof_generation_dal()
+            .save_proving_round(
+                L1BatchNumber(1),
+                AggregationRound::BasicCircuits,
+                ProvingRoundStatus::Completed,
+                100,
+                &[],
+                None,
+            )
+            .await
+            .unwrap();
+
+        let rounds = conn
+            .proof_generation_dal()
+            .get_proving_rounds(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].aggregation_round, AggregationRound::BasicCircuits);
+        assert_eq!(rounds[0].status, ProvingRoundStatus::Completed);
+        assert_eq!(rounds[0].jobs_count, 100);
+        assert_eq!(
+            rounds[1].aggregation_round,
+            AggregationRound::LeafAggregation
+        );
+        assert_eq!(rounds[1].status, ProvingRoundStatus::Failing);
+        assert_eq!(rounds[1].failed_circuit_ids, [3, 7]);
+
+        // Progress for the same round is overwritten.
+        conn.proof_generation_dal()
+            .save_proving_round(
+                L1BatchNumber(1),
+                AggregationRound::LeafAggregation,
+                ProvingRoundStatus::Completed,
+                16,
+                &[],
+                Some("leaf_artifact"),
+            )
+            .await
+            .unwrap();
+        let rounds = conn
+            .proof_generation_dal()
+            .get_proving_rounds(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[1].status, ProvingRoundStatus::Completed);
+        assert!(rounds[1].failed_circuit_ids.is_empty());
+        assert_eq!(rounds[1].artifact_url.as_deref(), Some("leaf_artifact"));
     }
 }
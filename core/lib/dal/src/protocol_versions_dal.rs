@@ -10,7 +10,7 @@ use zksync_db_connection::{
 use zksync_types::{
     protocol_upgrade::{ProtocolUpgradeTx, ProtocolVersion},
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion, VersionPatch},
-    ProtocolVersionId, H256,
+    Address, ProtocolVersionId, H256,
 };
 
 use crate::{
@@ -32,6 +32,7 @@ impl ProtocolVersionsDal<'_, '_> {
         version: ProtocolSemanticVersion,
         timestamp: u64,
         l1_verifier_config: L1VerifierConfig,
+        verifier_address: Option<Address>,
         base_system_contracts_hashes: BaseSystemContractsHashes,
         tx_hash: Option<H256>,
     ) -> DalResult<()> {
@@ -81,10 +82,11 @@ impl ProtocolVersionsDal<'_, '_> {
                 patch,
                 snark_wrapper_vk_hash,
                 fflonk_snark_wrapper_vk_hash,
+                verifier_address,
                 created_at
             )
             VALUES
-            ($1, $2, $3, $4, NOW())
+            ($1, $2, $3, $4, $5, NOW())
             ON CONFLICT DO NOTHING
             "#,
             version.minor as i32,
@@ -94,6 +96,7 @@ impl ProtocolVersionsDal<'_, '_> {
                 .fflonk_snark_wrapper_vk_hash
                 .as_ref()
                 .map(|x| x.as_bytes()),
+            verifier_address.as_ref().map(Address::as_bytes),
         )
         .instrument("save_protocol_version#patch")
         .with_arg("version", &version)
@@ -124,6 +127,7 @@ impl ProtocolVersionsDal<'_, '_> {
                 version.version,
                 version.timestamp,
                 version.l1_verifier_config,
+                version.verifier_address,
                 version.base_system_contracts_hashes,
                 tx_hash,
             )
@@ -250,7 +254,8 @@ impl ProtocolVersionsDal<'_, '_> {
                 protocol_versions.evm_emulator_code_hash,
                 protocol_patches.patch,
                 protocol_patches.snark_wrapper_vk_hash,
-                protocol_patches.fflonk_snark_wrapper_vk_hash
+                protocol_patches.fflonk_snark_wrapper_vk_hash,
+                protocol_patches.verifier_address
             FROM
                 protocol_versions
             JOIN protocol_patches ON protocol_patches.minor = protocol_versions.id
@@ -411,6 +416,39 @@ impl ProtocolVersionsDal<'_, '_> {
         Some((id as u16).try_into().unwrap())
     }
 
+    /// Returns IDs and activation timestamps of protocol versions newer than `version_id`, in the ascending order.
+    pub async fn versions_after(
+        &mut self,
+        version_id: ProtocolVersionId,
+    ) -> DalResult<Vec<(ProtocolVersionId, u64)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                timestamp
+            FROM
+                protocol_versions
+            WHERE
+                id > $1
+            ORDER BY
+                id
+            "#,
+            i32::from(version_id as u16)
+        )
+        .instrument("versions_after")
+        .with_arg("version_id", &version_id)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let id = ProtocolVersionId::try_from(row.id as u16).ok()?;
+                Some((id, row.timestamp as u64))
+            })
+            .collect())
+    }
+
     pub async fn all_versions(&mut self) -> Vec<ProtocolSemanticVersion> {
         let rows = sqlx::query!(
             r#"
@@ -1,7 +1,15 @@
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
-use zksync_types::api::ProtocolVersion;
+use zksync_types::{
+    api::{ProtocolVersion, ProtocolVersionDetails},
+    ProtocolVersionId,
+};
 
-use crate::{models::storage_protocol_version::StorageApiProtocolVersion, Core, CoreDal};
+use crate::{
+    models::storage_protocol_version::{
+        StorageApiProtocolVersion, StorageApiProtocolVersionDetails,
+    },
+    Core, CoreDal,
+};
 
 #[derive(Debug)]
 pub struct ProtocolVersionsWeb3Dal<'a, 'c> {
@@ -49,4 +57,43 @@ impl ProtocolVersionsWeb3Dal<'_, '_> {
             .await
             .map(|v| v.unwrap())
     }
+
+    /// Returns details for all persisted patches of protocol versions starting from `min_version_id`,
+    /// in the ascending order.
+    pub async fn get_protocol_version_details(
+        &mut self,
+        min_version_id: ProtocolVersionId,
+    ) -> DalResult<Vec<ProtocolVersionDetails>> {
+        let rows = sqlx::query_as!(
+            StorageApiProtocolVersionDetails,
+            r#"
+            SELECT
+                protocol_versions.id AS "minor!",
+                protocol_patches.patch,
+                protocol_versions.timestamp,
+                protocol_versions.bootloader_code_hash,
+                protocol_versions.default_account_code_hash,
+                protocol_versions.evm_emulator_code_hash,
+                protocol_versions.upgrade_tx_hash,
+                protocol_patches.snark_wrapper_vk_hash,
+                protocol_patches.fflonk_snark_wrapper_vk_hash,
+                protocol_patches.verifier_address
+            FROM
+                protocol_versions
+            JOIN protocol_patches ON protocol_patches.minor = protocol_versions.id
+            WHERE
+                protocol_versions.id >= $1
+            ORDER BY
+                protocol_versions.id,
+                protocol_patches.patch
+            "#,
+            min_version_id as i32
+        )
+        .instrument("get_protocol_version_details")
+        .with_arg("min_version_id", &min_version_id)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().map(ProtocolVersionDetails::from).collect())
+    }
 }
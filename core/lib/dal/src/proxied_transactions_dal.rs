@@ -0,0 +1,223 @@
+//! Durable queue of transactions that an external node failed to proxy to the main node.
+
+use std::time::Duration;
+
+use zksync_db_connection::{
+    connection::Connection, error::DalResult, instrument::InstrumentExt,
+    utils::pg_interval_from_duration,
+};
+use zksync_types::{Address, Nonce, H256};
+
+use crate::Core;
+
+/// Transaction queued for resubmission to the main node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedProxiedTransaction {
+    pub tx_hash: H256,
+    pub initiator_address: Address,
+    pub nonce: Nonce,
+    pub raw_tx: Vec<u8>,
+    pub attempts: u32,
+}
+
+#[derive(Debug)]
+pub struct ProxiedTransactionsDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl ProxiedTransactionsDal<'_, '_> {
+    /// Adds a transaction to the queue. Returns `false` if a transaction with the same hash is already queued.
+    pub async fn insert_transaction(
+        &mut self,
+        tx_hash: H256,
+        initiator_address: Address,
+        nonce: Nonce,
+        raw_tx: &[u8],
+    ) -> DalResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO
+            proxied_transactions (
+                tx_hash, initiator_address, nonce, raw_tx, attempts, created_at, updated_at
+            )
+            VALUES
+            ($1, $2, $3, $4, 0, NOW(), NOW())
+            ON CONFLICT (tx_hash) DO NOTHING
+            "#,
+            tx_hash.as_bytes(),
+            initiator_address.as_bytes(),
+            i64::from(nonce.0),
+            raw_tx
+        )
+        .instrument("insert_proxied_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns up to `limit` queued transactions ordered by the initiator address and nonce, so that transactions
+    /// from the same account are resubmitted in the nonce order.
+    pub async fn get_queued_transactions(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<QueuedProxiedTransaction>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                tx_hash,
+                initiator_address,
+                nonce,
+                raw_tx,
+                attempts
+            FROM
+                proxied_transactions
+            ORDER BY
+                initiator_address,
+                nonce,
+                created_at
+            LIMIT
+                $1
+            "#,
+            limit as i64
+        )
+        .instrument("get_queued_proxied_transactions")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| QueuedProxiedTransaction {
+                tx_hash: H256::from_slice(&row.tx_hash),
+                initiator_address: Address::from_slice(&row.initiator_address),
+                nonce: Nonce(row.nonce as u32),
+                raw_tx: row.raw_tx,
+                attempts: row.attempts as u32,
+            })
+            .collect())
+    }
+
+    /// Returns the number of queued transactions.
+    pub async fn get_queued_transactions_count(&mut self) -> DalResult<usize> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                proxied_transactions
+            "#
+        )
+        .instrument("get_queued_proxied_transactions_count")
+        .fetch_one(self.storage)
+        .await?;
+        Ok(count as usize)
+    }
+
+    /// Increments the number of resubmission attempts for a queued transaction.
+    pub async fn record_failed_attempt(&mut self, tx_hash: H256) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE proxied_transactions
+            SET
+                attempts = attempts + 1,
+                updated_at = NOW()
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("record_failed_proxied_transaction_attempt")
+        .with_arg("tx_hash", &tx_hash)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes a transaction from the queue, e.g. after it was accepted or rejected by the main node.
+    pub async fn remove_transaction(&mut self, tx_hash: H256) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM proxied_transactions
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("remove_proxied_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes transactions queued more than `ttl` ago. Returns hashes of the removed transactions.
+    pub async fn remove_expired_transactions(&mut self, ttl: Duration) -> DalResult<Vec<H256>> {
+        let rows = sqlx::query!(
+            r#"
+            DELETE FROM proxied_transactions
+            WHERE
+                created_at < NOW() - $1::INTERVAL
+            RETURNING
+            tx_hash
+            "#,
+            &pg_interval_from_duration(ttl)
+        )
+        .instrument("remove_expired_proxied_transactions")
+        .with_arg("ttl", &ttl)
+        .fetch_all(self.storage)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| H256::from_slice(&row.tx_hash))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn queueing_proxied_transactions() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.proxied_transactions_dal();
+        let account = Address::repeat_byte(1);
+
+        for nonce in [1, 0] {
+            let tx_hash = H256::repeat_byte(nonce + 1);
+            let inserted = dal
+                .insert_transaction(tx_hash, account, Nonce(nonce.into()), &[nonce])
+                .await
+                .unwrap();
+            assert!(inserted);
+        }
+        // Duplicate transactions must be ignored.
+        let inserted = dal
+            .insert_transaction(H256::repeat_byte(1), account, Nonce(0), &[0])
+            .await
+            .unwrap();
+        assert!(!inserted);
+        assert_eq!(dal.get_queued_transactions_count().await.unwrap(), 2);
+
+        dal.record_failed_attempt(H256::repeat_byte(1))
+            .await
+            .unwrap();
+        let queued = dal.get_queued_transactions(10).await.unwrap();
+        let nonces: Vec<_> = queued.iter().map(|tx| tx.nonce).collect();
+        assert_eq!(nonces, [Nonce(0), Nonce(1)]);
+        assert_eq!(queued[0].attempts, 1);
+        assert_eq!(queued[1].attempts, 0);
+
+        dal.remove_transaction(H256::repeat_byte(1)).await.unwrap();
+        assert_eq!(dal.get_queued_transactions_count().await.unwrap(), 1);
+        let removed = dal
+            .remove_expired_transactions(Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(removed, [H256::repeat_byte(2)]);
+        assert_eq!(dal.get_queued_transactions_count().await.unwrap(), 0);
+    }
+}
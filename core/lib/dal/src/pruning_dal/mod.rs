@@ -48,6 +48,7 @@ pub struct HardPruningStats {
     pub deleted_storage_logs: u64,
     pub deleted_events: u64,
     pub deleted_call_traces: u64,
+    pub deleted_storage_accesses: u64,
     pub deleted_l2_to_l1_logs: u64,
 }
 
@@ -226,6 +227,9 @@ impl PruningDal<'_, '_> {
         let deleted_call_traces = self
             .delete_call_traces(first_l2_block_to_prune..=last_l2_block_to_prune)
             .await?;
+        let deleted_storage_accesses = self
+            .delete_storage_accesses(first_l2_block_to_prune..=last_l2_block_to_prune)
+            .await?;
         self.clear_transaction_fields(first_l2_block_to_prune..=last_l2_block_to_prune)
             .await?;
 
@@ -241,6 +245,7 @@ impl PruningDal<'_, '_> {
             deleted_events,
             deleted_l2_to_l1_logs,
             deleted_call_traces,
+            deleted_storage_accesses,
             deleted_storage_logs,
         };
         Ok(stats)
@@ -319,6 +324,34 @@ impl PruningDal<'_, '_> {
         Ok(execution_result.rows_affected())
     }
 
+    async fn delete_storage_accesses(
+        &mut self,
+        l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<u64> {
+        let execution_result = sqlx::query!(
+            r#"
+            DELETE FROM transaction_storage_accesses
+            WHERE
+                tx_hash IN (
+                    SELECT
+                        hash
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number BETWEEN $1 AND $2
+                )
+            "#,
+            i64::from(l2_blocks_to_prune.start().0),
+            i64::from(l2_blocks_to_prune.end().0)
+        )
+        .instrument("hard_prune_batches_range#delete_storage_accesses")
+        .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(execution_result.rows_affected())
+    }
+
     // The pruned fields are accessed as follows:
     //
     // - `input`: is a part of `StorageTransaction`, read via `TransactionsDal` (`get_l2_blocks_to_reexecute`,
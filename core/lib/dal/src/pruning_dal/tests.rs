@@ -464,6 +464,7 @@ async fn transactions_are_handled_correctly_after_pruning() {
             1.into(),
             ProtocolVersionId::latest(),
             false,
+            false,
         )
         .await
         .unwrap();
@@ -1,3 +1,5 @@
+use std::iter;
+
 use zksync_db_connection::{
     connection::Connection,
     error::{DalResult, SqlxContext},
@@ -186,6 +188,34 @@ impl SnapshotsDal<'_, '_> {
         .await
     }
 
+    /// Returns object store paths of all files referenced by snapshots, including incomplete ones.
+    pub async fn get_all_snapshot_filepaths(&mut self) -> DalResult<Vec<String>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                factory_deps_filepath,
+                storage_logs_filepaths
+            FROM
+                snapshots
+            "#
+        )
+        .instrument("get_all_snapshot_filepaths")
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .flat_map(|row| {
+                let storage_logs_filepaths = row
+                    .storage_logs_filepaths
+                    .into_iter()
+                    .filter(|path| !path.is_empty());
+                iter::once(row.factory_deps_filepath).chain(storage_logs_filepaths)
+            })
+            .collect())
+    }
+
     /// Deletes all snapshots after the specified L1 batch number and returns their metadata.
     pub async fn delete_snapshots_after(
         &mut self,
@@ -265,6 +295,16 @@ mod tests {
             .unwrap()
             .expect("snapshot is not persisted");
         assert_eq!(snapshot_metadata.l1_batch_number, l1_batch_number);
+
+        let filepaths = dal.get_all_snapshot_filepaths().await.unwrap();
+        assert_eq!(
+            filepaths,
+            [
+                "gs:///bucket/factory_deps.bin",
+                "gs:///bucket/chunk.bin",
+                "gs:///bucket/chunk.bin"
+            ]
+        );
     }
 
     #[tokio::test]
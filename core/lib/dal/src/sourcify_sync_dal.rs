@@ -0,0 +1,222 @@
+use zksync_db_connection::{error::SqlxContext, instrument::InstrumentExt};
+use zksync_types::{
+    contract_verification::{
+        api::VerificationInfo,
+        sourcify::{SourcifySyncDirection, SourcifySyncRecord, SourcifySyncStatus},
+    },
+    Address, L2BlockNumber,
+};
+
+use crate::{Connection, Core, DalResult};
+
+#[derive(Debug)]
+pub struct SourcifySyncDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+impl SourcifySyncDal<'_, '_> {
+    /// Returns the last L2 block processed by the Sourcify import, or `None` if no blocks were processed yet.
+    pub async fn get_last_imported_l2_block(&mut self) -> DalResult<Option<L2BlockNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(miniblock_number) AS "number?"
+            FROM
+                sourcify_sync_records
+            WHERE
+                direction = 'import'
+            "#
+        )
+        .instrument("get_last_imported_l2_block")
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.number.map(|number| L2BlockNumber(number as u32)))
+    }
+
+    /// Returns up to `limit` locally verified contracts that were not exported to Sourcify yet,
+    /// ordered by the verification time.
+    pub async fn get_contracts_for_export(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<VerificationInfo>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                verification_info
+            FROM
+                contract_verification_info_v2
+            WHERE
+                NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        sourcify_sync_records
+                    WHERE
+                        contract_addr = contract_verification_info_v2.initial_contract_addr
+                        AND direction = 'export'
+                )
+            ORDER BY
+                created_at
+            LIMIT
+                $1
+            "#,
+            limit as i64
+        )
+        .try_map(|row| {
+            serde_json::from_value(row.verification_info).decode_column("verification_info")
+        })
+        .instrument("get_contracts_for_export")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await
+    }
+
+    /// Saves outcomes of syncing contracts with Sourcify, overwriting existing records.
+    pub async fn save_sync_records(&mut self, records: &[SourcifySyncRecord]) -> DalResult<()> {
+        let mut transaction = self.storage.start_transaction().await?;
+        for record in records {
+            sqlx::query!(
+                r#"
+                INSERT INTO
+                sourcify_sync_records (
+                    contract_addr,
+                    direction,
+                    miniblock_number,
+                    status,
+                    verification_request_id,
+                    error,
+                    created_at,
+                    updated_at
+                )
+                VALUES
+                ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+                ON CONFLICT (contract_addr, direction) DO
+                UPDATE
+                SET
+                miniblock_number = COALESCE(excluded.miniblock_number, sourcify_sync_records.miniblock_number),
+                status = excluded.status,
+                verification_request_id = excluded.verification_request_id,
+                error = excluded.error,
+                updated_at = NOW()
+                "#,
+                record.contract_address.as_bytes(),
+                record.direction.as_str(),
+                record.l2_block_number.map(|number| i64::from(number.0)),
+                record.status.as_str(),
+                record.verification_request_id.map(|id| id as i64),
+                record.error.as_deref()
+            )
+            .instrument("save_sync_records")
+            .with_arg("contract_address", &record.contract_address)
+            .with_arg("direction", &record.direction)
+            .execute(&mut transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn get_sync_record(
+        &mut self,
+        address: Address,
+        direction: SourcifySyncDirection,
+    ) -> DalResult<Option<SourcifySyncRecord>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                miniblock_number,
+                status,
+                verification_request_id,
+                error
+            FROM
+                sourcify_sync_records
+            WHERE
+                contract_addr = $1
+                AND direction = $2
+            "#,
+            address.as_bytes(),
+            direction.as_str()
+        )
+        .try_map(|row| {
+            Ok(SourcifySyncRecord {
+                contract_address: address,
+                direction,
+                l2_block_number: row
+                    .miniblock_number
+                    .map(|number| L2BlockNumber(number as u32)),
+                status: row
+                    .status
+                    .parse::<SourcifySyncStatus>()
+                    .decode_column("status")?,
+                verification_request_id: row.verification_request_id.map(|id| id as usize),
+                error: row.error,
+            })
+        })
+        .instrument("get_sync_record")
+        .with_arg("address", &address)
+        .with_arg("direction", &direction)
+        .fetch_optional(self.storage)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn saving_sync_records() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.sourcify_sync_dal();
+        assert_eq!(dal.get_last_imported_l2_block().await.unwrap(), None);
+
+        let address = Address::repeat_byte(1);
+        let mut import_record = SourcifySyncRecord {
+            contract_address: address,
+            direction: SourcifySyncDirection::Import,
+            l2_block_number: Some(L2BlockNumber(5)),
+            status: SourcifySyncStatus::Synced,
+            verification_request_id: Some(1),
+            error: None,
+        };
+        let export_record = SourcifySyncRecord {
+            contract_address: address,
+            direction: SourcifySyncDirection::Export,
+            l2_block_number: None,
+            status: SourcifySyncStatus::Failed,
+            verification_request_id: None,
+            error: Some("bytecode mismatch".to_owned()),
+        };
+        dal.save_sync_records(&[import_record.clone(), export_record.clone()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            dal.get_last_imported_l2_block().await.unwrap(),
+            Some(L2BlockNumber(5))
+        );
+        let record = dal
+            .get_sync_record(address, SourcifySyncDirection::Import)
+            .await
+            .unwrap();
+        assert_eq!(record.as_ref(), Some(&import_record));
+        let record = dal
+            .get_sync_record(address, SourcifySyncDirection::Export)
+            .await
+            .unwrap();
+        assert_eq!(record.as_ref(), Some(&export_record));
+
+        // Records must be overwritten.
+        import_record.status = SourcifySyncStatus::AlreadyVerified;
+        import_record.verification_request_id = None;
+        dal.save_sync_records(&[import_record.clone()])
+            .await
+            .unwrap();
+        let record = dal
+            .get_sync_record(address, SourcifySyncDirection::Import)
+            .await
+            .unwrap();
+        assert_eq!(record.as_ref(), Some(&import_record));
+    }
+}
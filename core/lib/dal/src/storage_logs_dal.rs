@@ -12,7 +12,7 @@ use zksync_types::{
     L2BlockNumber, StorageKey, StorageLog, FAILED_CONTRACT_DEPLOYMENT_BYTECODE_HASH, H160, H256,
 };
 
-pub use crate::models::storage_log::{DbStorageLog, StorageRecoveryLogEntry};
+pub use crate::models::storage_log::{DbStorageLog, StorageRangeEntry, StorageRecoveryLogEntry};
 use crate::{Core, CoreDal};
 
 #[derive(Debug)]
@@ -598,6 +598,73 @@ impl StorageLogsDal<'_, '_> {
             .collect())
     }
 
+    /// Returns non-zero storage slots of the specified contract at the end of `l2_block_number`, ordered by hashed key
+    /// and starting from `start_hashed_key` (inclusive). Returns at most `limit` entries.
+    pub async fn get_storage_range(
+        &mut self,
+        address: Address,
+        l2_block_number: L2BlockNumber,
+        start_hashed_key: H256,
+        limit: usize,
+    ) -> DalResult<Vec<StorageRangeEntry>> {
+        let rows = sqlx::query!(
+            r#"
+            WITH
+            latest_storage_logs AS (
+                SELECT DISTINCT ON (hashed_key)
+                    hashed_key,
+                    key,
+                    value
+                FROM
+                    storage_logs
+                WHERE
+                    address = $1
+                    AND hashed_key >= $2
+                    AND miniblock_number <= $3
+                ORDER BY
+                    hashed_key,
+                    miniblock_number DESC,
+                    operation_number DESC
+            )
+            
+            SELECT
+                hashed_key AS "hashed_key!",
+                key AS "key?",
+                value AS "value!"
+            FROM
+                latest_storage_logs
+            WHERE
+                value
+                <> '\x0000000000000000000000000000000000000000000000000000000000000000'::bytea
+            ORDER BY
+                hashed_key
+            LIMIT
+                $4
+            "#,
+            address.as_bytes(),
+            start_hashed_key.as_bytes(),
+            i64::from(l2_block_number.0),
+            limit as i64
+        )
+        .instrument("get_storage_range")
+        .with_arg("address", &address)
+        .with_arg("l2_block_number", &l2_block_number)
+        .with_arg("start_hashed_key", &start_hashed_key)
+        .with_arg("limit", &limit)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| StorageRangeEntry {
+                hashed_key: H256::from_slice(&row.hashed_key),
+                key: row.key.as_deref().map(H256::from_slice),
+                value: H256::from_slice(&row.value),
+            })
+            .collect())
+    }
+
     /// Retrieves all storage log entries for testing purposes.
     pub async fn dump_all_storage_logs_for_tests(&mut self) -> Vec<DbStorageLog> {
         let rows = sqlx::query!(
@@ -1118,6 +1185,74 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn getting_storage_range() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        prepare_tree_entries(&mut conn, 10).await;
+
+        let address = Address::repeat_byte(1);
+        let account = AccountTreeId::new(address);
+        let other_account = AccountTreeId::new(Address::repeat_byte(2));
+        let logs = vec![
+            StorageLog::new_write_log(StorageKey::new(account, H256::repeat_byte(1)), H256::zero()),
+            StorageLog::new_write_log(
+                StorageKey::new(account, H256::repeat_byte(2)),
+                H256::repeat_byte(0xff),
+            ),
+            StorageLog::new_write_log(
+                StorageKey::new(other_account, H256::repeat_byte(1)),
+                H256::repeat_byte(1),
+            ),
+        ];
+        insert_l2_block(&mut conn, 2, logs).await;
+
+        // The slot with the zero key has a zero value, so it's skipped.
+        let range = conn
+            .storage_logs_dal()
+            .get_storage_range(address, L2BlockNumber(1), H256::zero(), 100)
+            .await
+            .unwrap();
+        assert_eq!(range.len(), 9);
+        assert!(range
+            .windows(2)
+            .all(|window| window[0].hashed_key < window[1].hashed_key));
+        for entry in &range {
+            let key = entry.key.unwrap();
+            assert_eq!(entry.hashed_key, StorageKey::new(account, key).hashed_key());
+            assert_eq!(entry.value, key);
+        }
+
+        let range_at_block2 = conn
+            .storage_logs_dal()
+            .get_storage_range(address, L2BlockNumber(2), H256::zero(), 100)
+            .await
+            .unwrap();
+        assert_eq!(range_at_block2.len(), 8);
+        let updated_entry = range_at_block2
+            .iter()
+            .find(|entry| entry.key == Some(H256::repeat_byte(2)))
+            .unwrap();
+        assert_eq!(updated_entry.value, H256::repeat_byte(0xff));
+        assert!(!range_at_block2
+            .iter()
+            .any(|entry| entry.key == Some(H256::repeat_byte(1))));
+
+        // Check paging.
+        let first_page = conn
+            .storage_logs_dal()
+            .get_storage_range(address, L2BlockNumber(1), H256::zero(), 5)
+            .await
+            .unwrap();
+        assert_eq!(first_page, range[..5]);
+        let second_page = conn
+            .storage_logs_dal()
+            .get_storage_range(address, L2BlockNumber(1), range[5].hashed_key, 5)
+            .await
+            .unwrap();
+        assert_eq!(second_page, range[5..]);
+    }
+
     #[tokio::test]
     async fn filtering_deployed_contracts() {
         let contract_address = Address::repeat_byte(1);
@@ -310,7 +310,10 @@ impl StorageWeb3Dal<'_, '_> {
 
 #[cfg(test)]
 mod tests {
-    use zksync_types::{block::L1BatchHeader, ProtocolVersion, ProtocolVersionId};
+    use zksync_types::{
+        block::L1BatchHeader, utils::storage_key_for_eth_balance, ProtocolVersion,
+        ProtocolVersionId, StorageLog,
+    };
 
     use super::*;
     use crate::{
@@ -459,4 +462,71 @@ mod tests {
             .unwrap();
         assert_eq!(timestamp, Some(first_l2_block.timestamp));
     }
+
+    #[tokio::test]
+    async fn getting_historical_account_state() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let address = Address::repeat_byte(1);
+        let nonce_key = get_nonce_key(&address);
+        let balance_key = storage_key_for_eth_balance(&address);
+        let logs_by_block = [
+            vec![StorageLog::new_write_log(
+                balance_key,
+                H256::from_low_u64_be(1_000),
+            )],
+            vec![
+                StorageLog::new_write_log(nonce_key, H256::from_low_u64_be(1)),
+                StorageLog::new_write_log(balance_key, H256::from_low_u64_be(900)),
+            ],
+            // Overwrite the nonce twice in the same block to check ordering by operation number.
+            vec![
+                StorageLog::new_write_log(nonce_key, H256::from_low_u64_be(2)),
+                StorageLog::new_write_log(nonce_key, H256::from_low_u64_be(3)),
+            ],
+        ];
+        for (number, logs) in (1..).zip(logs_by_block) {
+            conn.blocks_dal()
+                .insert_l2_block(&create_l2_block_header(number))
+                .await
+                .unwrap();
+            conn.storage_logs_dal()
+                .insert_storage_logs(L2BlockNumber(number), &logs)
+                .await
+                .unwrap();
+        }
+
+        let expected_states = [(0, 0), (0, 1_000), (1, 900), (3, 900), (3, 900)];
+        // Check lookups both with and without archive indexes, which change query plans.
+        for with_archive_indexes in [false, true] {
+            if with_archive_indexes {
+                conn.system_dal().create_archive_indexes().await.unwrap();
+            }
+
+            for (number, (nonce, balance)) in (0..).zip(expected_states) {
+                let block_number = L2BlockNumber(number);
+                let historical_nonce = conn
+                    .storage_web3_dal()
+                    .get_address_historical_nonce(address, block_number)
+                    .await
+                    .unwrap();
+                assert_eq!(historical_nonce, nonce.into(), "{block_number}");
+                let historical_balance = conn
+                    .storage_web3_dal()
+                    .get_historical_value_unchecked(balance_key.hashed_key(), block_number)
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    h256_to_u256(historical_balance),
+                    balance.into(),
+                    "{block_number}"
+                );
+            }
+        }
+    }
 }
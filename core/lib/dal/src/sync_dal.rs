@@ -186,6 +186,7 @@ mod tests {
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
@@ -24,6 +24,58 @@ pub struct DatabaseMigration {
     pub execution_time: Duration,
 }
 
+/// Migrations embedded into the binary from the `migrations` directory of this crate.
+static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// Returns the version and description of the latest migration embedded into the binary, i.e., the migration
+/// the database is expected to be at.
+pub fn latest_embedded_migration() -> Option<(i64, &'static str)> {
+    MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .max_by_key(|migration| migration.version)
+        .map(|migration| (migration.version, &*migration.description))
+}
+
+/// Index used by `debug_storageRangeAt` to iterate over the storage of a specific contract. It's an archive index
+/// because of its size; the method is unavailable until the index is built.
+pub const STORAGE_RANGE_INDEX: &str = "archive_storage_logs_address_hashed_key_idx";
+
+/// Indexes created for archive (full-history) nodes on top of the indexes created by migrations.
+/// Each entry is a pair of the index name and the indexed table with columns.
+pub const ARCHIVE_INDEXES: &[(&str, &str)] = &[
+    // Address activity (transactions sent by an address over time) and historical nonces.
+    (
+        "archive_transactions_initiator_address_block_idx",
+        "transactions (initiator_address, miniblock_number DESC) INCLUDE (nonce, hash)",
+    ),
+    // Address activity (transactions sent to an address over time).
+    (
+        "archive_transactions_contract_address_block_idx",
+        "transactions (contract_address, miniblock_number DESC) INCLUDE (hash)",
+    ),
+    // Address activity (events emitted in transactions initiated by an address over time).
+    (
+        "archive_events_tx_initiator_address_block_idx",
+        "events (tx_initiator_address, miniblock_number DESC)",
+    ),
+    // Index-only scans over L2 block log blooms for log queries spanning long block ranges.
+    (
+        "archive_miniblocks_logs_bloom_idx",
+        "miniblocks (number) INCLUDE (logs_bloom)",
+    ),
+    // Index-only scans for point-in-time storage lookups (`eth_getBalance`, `eth_getTransactionCount`,
+    // `eth_getStorageAt` etc. at historical blocks), for which heap pages are unlikely to be cached.
+    (
+        "archive_storage_logs_hashed_key_block_value_idx",
+        "storage_logs (hashed_key, miniblock_number DESC, operation_number DESC) INCLUDE (value)",
+    ),
+    (
+        STORAGE_RANGE_INDEX,
+        "storage_logs (address, hashed_key, miniblock_number DESC, operation_number DESC)",
+    ),
+];
+
 #[derive(Debug)]
 pub struct SystemDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -56,6 +108,24 @@ impl SystemDal<'_, '_> {
         })
     }
 
+    /// Returns the maximum replay lag among streaming replicas connected to this (primary) Postgres instance.
+    /// If there are no connected replicas, returns zero lag.
+    pub async fn get_max_replica_lag(&mut self) -> DalResult<Duration> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                MAX(EXTRACT(EPOCH FROM replay_lag))::INT AS lag
+            FROM
+                pg_stat_replication
+            "#
+        )
+        .instrument("get_max_replica_lag")
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(Duration::from_secs(row.lag.unwrap_or(0).max(0) as u64))
+    }
+
     pub(crate) async fn get_table_sizes(&mut self) -> DalResult<HashMap<String, TableSize>> {
         let rows = sqlx::query!(
             r#"
@@ -121,4 +191,62 @@ impl SystemDal<'_, '_> {
             execution_time: Duration::from_nanos(u64::try_from(row.execution_time).unwrap_or(0)),
         })
     }
+
+    /// Returns `Some(true)` if the specified index exists and is invalid (e.g., left behind by an interrupted
+    /// concurrent build), `Some(false)` if it exists and is valid, and `None` if it doesn't exist.
+    async fn is_index_invalid(&mut self, name: &str) -> DalResult<Option<bool>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                NOT pg_index.indisvalid AS "is_invalid!"
+            FROM
+                pg_index
+            JOIN pg_class ON pg_class.oid = pg_index.indexrelid
+            WHERE
+                pg_class.relname = $1::TEXT
+            "#,
+            name
+        )
+        .instrument("is_index_invalid")
+        .with_arg("name", &name)
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| row.is_invalid))
+    }
+
+    /// Checks whether the specified index exists and can be used by queries.
+    pub async fn is_index_ready(&mut self, name: &str) -> DalResult<bool> {
+        Ok(self.is_index_invalid(name).await? == Some(false))
+    }
+
+    /// Creates [`ARCHIVE_INDEXES`] that don't exist yet. Indexes are built concurrently, so this doesn't block writes
+    /// to the indexed tables, but may take a long time for large databases.
+    pub async fn create_archive_indexes(&mut self) -> DalResult<()> {
+        for &(name, definition) in ARCHIVE_INDEXES {
+            match self.is_index_invalid(name).await? {
+                Some(false) => continue,
+                Some(true) => {
+                    // An interrupted concurrent build leaves an invalid index behind, which would be skipped
+                    // by `CREATE INDEX IF NOT EXISTS`.
+                    tracing::info!("Dropping invalid archive index `{name}`");
+                    sqlx::query(&format!("DROP INDEX CONCURRENTLY IF EXISTS {name}"))
+                        .instrument("create_archive_indexes#drop")
+                        .with_arg("name", &name)
+                        .execute(self.storage)
+                        .await?;
+                }
+                None => { /* The index doesn't exist */ }
+            }
+
+            tracing::info!("Creating archive index `{name}` on {definition}");
+            sqlx::query(&format!(
+                "CREATE INDEX CONCURRENTLY IF NOT EXISTS {name} ON {definition}"
+            ))
+            .instrument("create_archive_indexes#create")
+            .with_arg("name", &name)
+            .execute(self.storage)
+            .await?;
+        }
+        Ok(())
+    }
 }
@@ -165,6 +165,7 @@ pub(crate) fn mock_execution_result(transaction: L2Tx) -> TransactionExecutionRe
         refunded_gas: 0,
         call_traces: vec![],
         revert_reason: None,
+        storage_accesses: None,
     }
 }
 
@@ -371,6 +372,7 @@ async fn remove_stuck_txs() {
             U256::from(1),
             ProtocolVersionId::latest(),
             false,
+            false,
         )
         .await
         .unwrap();
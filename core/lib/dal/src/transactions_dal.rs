@@ -1,4 +1,4 @@
-use std::{cmp::min, collections::HashMap, fmt, time::Duration};
+use std::{cmp::min, collections::HashMap, fmt, ops, time::Duration};
 
 use bigdecimal::BigDecimal;
 use itertools::Itertools;
@@ -16,13 +16,16 @@ use zksync_types::{
     TransactionTimeRangeConstraint, H256, PROTOCOL_UPGRADE_TX_TYPE, U256,
 };
 use zksync_vm_interface::{
-    tracer::ValidationTraces, Call, TransactionExecutionMetrics, TransactionExecutionResult,
-    TxExecutionStatus,
+    tracer::ValidationTraces, Call, StorageAccessSet, TransactionExecutionMetrics,
+    TransactionExecutionResult, TxExecutionStatus,
 };
 
 use crate::{
     models::{
-        storage_transaction::{parse_call_trace, serialize_call_into_bytes, StorageTransaction},
+        storage_transaction::{
+            compress_call_trace, parse_call_trace, parse_storage_slots, serialize_call_into_bytes,
+            serialize_storage_slots, StorageTransaction,
+        },
         u256_to_big_decimal,
     },
     Core, CoreDal,
@@ -188,6 +191,35 @@ impl TransactionsDal<'_, '_> {
             .collect())
     }
 
+    /// Returns distinct initiators of transactions included in L2 blocks up to and including `l2_block_number`,
+    /// ordered by address.
+    pub async fn get_initiator_addresses(
+        &mut self,
+        l2_block_number: L2BlockNumber,
+    ) -> DalResult<Vec<Address>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT
+                initiator_address
+            FROM
+                transactions
+            WHERE
+                miniblock_number <= $1
+            ORDER BY
+                initiator_address
+            "#,
+            i64::from(l2_block_number.0)
+        )
+        .instrument("get_initiator_addresses")
+        .with_arg("l2_block_number", &l2_block_number)
+        .fetch_all(self.storage)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| Address::from_slice(&row.initiator_address))
+            .collect())
+    }
+
     pub async fn insert_system_transaction(&mut self, tx: &ProtocolUpgradeTx) -> DalResult<()> {
         let contract_address = tx.execute.contract_address;
         let contract_address_as_bytes = contract_address.map(|addr| addr.as_bytes().to_vec());
@@ -565,14 +597,23 @@ impl TransactionsDal<'_, '_> {
         // On the main node, transactions are inserted into the DB by API servers.
         // However on the EN, they need to be inserted after they are executed by the state keeper.
         insert_txs: bool,
+        // Compressed call traces cannot be read by server versions predating compression support.
+        compress_call_traces: bool,
     ) -> DalResult<()> {
         let mut transaction = self.storage.start_transaction().await?;
 
         let mut call_traces_tx_hashes = Vec::with_capacity(transactions.len());
         let mut bytea_call_traces = Vec::with_capacity(transactions.len());
+        let mut call_trace_sizes = Vec::with_capacity(transactions.len());
         for tx_res in transactions {
             if let Some(call_trace) = tx_res.call_trace() {
-                bytea_call_traces.push(serialize_call_into_bytes(call_trace, protocol_version));
+                let serialized = serialize_call_into_bytes(call_trace, protocol_version);
+                call_trace_sizes.push(serialized.len() as i64);
+                bytea_call_traces.push(if compress_call_traces {
+                    compress_call_trace(&serialized)
+                } else {
+                    serialized
+                });
                 call_traces_tx_hashes.push(tx_res.hash.as_bytes());
             }
         }
@@ -653,15 +694,18 @@ impl TransactionsDal<'_, '_> {
             sqlx::query!(
                 r#"
                 INSERT INTO
-                call_traces (tx_hash, call_trace)
+                call_traces (tx_hash, call_trace, uncompressed_size)
                 SELECT
                     u.tx_hash,
-                    u.call_trace
+                    u.call_trace,
+                    u.uncompressed_size
                 FROM
-                    UNNEST($1::bytea [], $2::bytea []) AS u (tx_hash, call_trace)
+                    UNNEST($1::bytea [], $2::bytea [], $3::BIGINT [])
+                    AS u (tx_hash, call_trace, uncompressed_size)
                 "#,
                 &call_traces_tx_hashes as &[&[u8]],
-                &bytea_call_traces
+                &bytea_call_traces,
+                &call_trace_sizes
             )
             .instrument("insert_call_tracer")
             .report_latency()
@@ -1726,6 +1770,20 @@ impl TransactionsDal<'_, '_> {
         .with_arg("tx_hashes.len", &tx_hashes.len())
         .execute(self.storage)
         .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM transaction_storage_accesses
+            WHERE
+                tx_hash = ANY($1)
+            "#,
+            &tx_hashes as &[&[u8]]
+        )
+        .instrument("reset_transactions_state#delete_storage_accesses")
+        .with_arg("l2_block_number", &l2_block_number)
+        .with_arg("tx_hashes.len", &tx_hashes.len())
+        .execute(self.storage)
+        .await?;
         Ok(())
     }
 
@@ -1752,6 +1810,31 @@ impl TransactionsDal<'_, '_> {
         Ok(rows.len())
     }
 
+    /// Removes all pending (i.e., not included into an L2 block) L2 transactions initiated by the specified accounts.
+    /// Returns the number of removed transactions.
+    pub async fn remove_pending_txs_for_initiators(
+        &mut self,
+        initiators: &[Address],
+    ) -> DalResult<u64> {
+        let initiators: Vec<_> = initiators.iter().map(Address::as_bytes).collect();
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM transactions
+            WHERE
+                miniblock_number IS NULL
+                AND is_priority = FALSE
+                AND initiator_address = ANY($1)
+            "#,
+            &initiators as &[&[u8]]
+        )
+        .instrument("remove_pending_txs_for_initiators")
+        .with_arg("initiators.len", &initiators.len())
+        .execute(self.storage)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn get_priority_txs_in_mempool(&mut self) -> DalResult<usize> {
         let result = sqlx::query!(
             r#"
@@ -2237,6 +2320,28 @@ impl TransactionsDal<'_, '_> {
         Ok(data)
     }
 
+    /// Returns the size of the call trace stored for the specified transaction, in bytes. If the trace is stored
+    /// compressed, returns its size after decompression.
+    pub async fn get_call_trace_size(&mut self, tx_hash: H256) -> DalResult<Option<u64>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COALESCE(uncompressed_size, OCTET_LENGTH(call_trace)) AS "trace_size!"
+            FROM
+                call_traces
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("get_call_trace_size")
+        .with_arg("tx_hash", &tx_hash)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| row.trace_size as u64))
+    }
+
     pub async fn get_call_trace(
         &mut self,
         tx_hash: H256,
@@ -2301,6 +2406,108 @@ impl TransactionsDal<'_, '_> {
         }))
     }
 
+    /// Removes call traces for transactions included in the specified range of L2 blocks.
+    /// Returns the number of removed traces.
+    pub async fn delete_call_traces_for_l2_blocks(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<u64> {
+        let execution_result = sqlx::query!(
+            r#"
+            DELETE FROM call_traces
+            WHERE
+                tx_hash IN (
+                    SELECT
+                        hash
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number BETWEEN $1 AND $2
+                )
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .instrument("delete_call_traces_for_l2_blocks")
+        .with_arg("l2_blocks", &l2_blocks)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(execution_result.rows_affected())
+    }
+
+    /// Persists storage slots accessed by the provided transactions. Transactions without collected
+    /// storage accesses are skipped.
+    pub async fn insert_storage_accesses(
+        &mut self,
+        transactions: &[TransactionExecutionResult],
+    ) -> DalResult<()> {
+        let mut tx_hashes = Vec::with_capacity(transactions.len());
+        let mut reads = Vec::with_capacity(transactions.len());
+        let mut writes = Vec::with_capacity(transactions.len());
+        for tx_res in transactions {
+            if let Some(accesses) = &tx_res.storage_accesses {
+                tx_hashes.push(tx_res.hash.as_bytes());
+                reads.push(serialize_storage_slots(&accesses.reads));
+                writes.push(serialize_storage_slots(&accesses.writes));
+            }
+        }
+        if tx_hashes.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            transaction_storage_accesses (tx_hash, reads, writes)
+            SELECT
+                u.tx_hash,
+                u.reads,
+                u.writes
+            FROM
+                UNNEST($1::bytea [], $2::bytea [], $3::bytea []) AS u (tx_hash, reads, writes)
+            ON CONFLICT (tx_hash) DO NOTHING
+            "#,
+            &tx_hashes as &[&[u8]],
+            &reads,
+            &writes
+        )
+        .instrument("insert_storage_accesses")
+        .with_arg("tx_hashes.len", &tx_hashes.len())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns storage slots accessed by the specified transaction, or `None` if they were not persisted.
+    pub async fn get_storage_accesses(
+        &mut self,
+        tx_hash: H256,
+    ) -> DalResult<Option<StorageAccessSet>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                reads,
+                writes
+            FROM
+                transaction_storage_accesses
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("get_storage_accesses")
+        .with_arg("tx_hash", &tx_hash)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| StorageAccessSet {
+            reads: parse_storage_slots(&row.reads),
+            writes: parse_storage_slots(&row.writes),
+        }))
+    }
+
     pub(crate) async fn get_tx_by_hash(&mut self, hash: H256) -> DalResult<Option<Transaction>> {
         sqlx::query_as!(
             StorageTransaction,
@@ -2347,7 +2554,7 @@ impl TransactionsDal<'_, '_> {
 
 #[cfg(test)]
 mod tests {
-    use zksync_types::ProtocolVersion;
+    use zksync_types::{AccountTreeId, ProtocolVersion, StorageKey};
 
     use super::*;
     use crate::{
@@ -2393,6 +2600,7 @@ mod tests {
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
@@ -2404,6 +2612,85 @@ mod tests {
             .unwrap()
             .expect("no call trace");
         assert_eq!(call_trace, expected_call_trace);
+
+        let deleted_count = conn
+            .transactions_dal()
+            .delete_call_traces_for_l2_blocks(L2BlockNumber(2)..=L2BlockNumber(3))
+            .await
+            .unwrap();
+        assert_eq!(deleted_count, 0);
+        let deleted_count = conn
+            .transactions_dal()
+            .delete_call_traces_for_l2_blocks(L2BlockNumber(0)..=L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(deleted_count, 1);
+        let call_trace = conn
+            .transactions_dal()
+            .get_call_trace(tx_hash)
+            .await
+            .unwrap();
+        assert!(call_trace.is_none());
+    }
+
+    #[tokio::test]
+    async fn persisting_storage_accesses() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        let mut tx_result = mock_execution_result(tx);
+        let slot = |address: u64, key: u64| {
+            StorageKey::new(
+                AccountTreeId::new(Address::from_low_u64_be(address)),
+                H256::from_low_u64_be(key),
+            )
+        };
+        let storage_accesses = StorageAccessSet {
+            reads: [slot(1, 1), slot(1, 2), slot(2, 1)].into(),
+            writes: [slot(1, 2), slot(3, 0)].into(),
+        };
+        tx_result.storage_accesses = Some(storage_accesses.clone());
+        let tx_results = [tx_result];
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &tx_results,
+                1.into(),
+                ProtocolVersionId::latest(),
+                true,
+                false,
+            )
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .insert_storage_accesses(&tx_results)
+            .await
+            .unwrap();
+
+        let accesses_from_db = conn
+            .transactions_dal()
+            .get_storage_accesses(tx_hash)
+            .await
+            .unwrap()
+            .expect("no storage accesses");
+        assert_eq!(accesses_from_db, storage_accesses);
+
+        conn.transactions_dal()
+            .reset_transactions_state(L2BlockNumber(0))
+            .await
+            .unwrap();
+        let accesses_from_db = conn
+            .transactions_dal()
+            .get_storage_accesses(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(accesses_from_db, None);
     }
 
     #[tokio::test]
@@ -2425,6 +2712,7 @@ mod tests {
                 1.into(),
                 ProtocolVersionId::latest(),
                 true,
+                false,
             )
             .await
             .unwrap();
@@ -2436,4 +2724,42 @@ mod tests {
             .unwrap();
         assert_eq!(tx_from_db[0].hash, tx_hash);
     }
+
+    #[tokio::test]
+    async fn getting_initiator_addresses() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let initiator = tx.initiator_account();
+        let tx_result = mock_execution_result(tx);
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[tx_result],
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let initiators = conn
+            .transactions_dal()
+            .get_initiator_addresses(L2BlockNumber(0))
+            .await
+            .unwrap();
+        assert_eq!(initiators, []);
+        let initiators = conn
+            .transactions_dal()
+            .get_initiator_addresses(L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(initiators, [initiator]);
+    }
 }
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, iter::once};
 
 use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
 use sqlx::types::chrono::NaiveDateTime;
 use zksync_db_connection::{
     connection::Connection,
@@ -9,8 +10,8 @@ use zksync_db_connection::{
     interpolate_query, match_query_as,
 };
 use zksync_types::{
-    api, api::TransactionReceipt, block::build_bloom, web3, Address, BloomInput, L2BlockNumber,
-    L2ChainId, Transaction, H256, U256,
+    api, api::TransactionReceipt, block::build_bloom, web3, Address, BloomInput, L1BatchNumber,
+    L2BlockNumber, L2ChainId, Transaction, H256, U256,
 };
 
 use crate::{
@@ -35,6 +36,34 @@ pub struct ExtendedTransactionReceipt {
     pub calldata: web3::Bytes,
 }
 
+/// Receipt stored in the `transaction_receipts_cache` table.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedTransactionReceipt {
+    receipt: TransactionReceipt,
+    nonce: U256,
+    calldata: web3::Bytes,
+}
+
+impl From<ExtendedTransactionReceipt> for CachedTransactionReceipt {
+    fn from(receipt: ExtendedTransactionReceipt) -> Self {
+        Self {
+            receipt: receipt.inner,
+            nonce: receipt.nonce,
+            calldata: receipt.calldata,
+        }
+    }
+}
+
+impl From<CachedTransactionReceipt> for ExtendedTransactionReceipt {
+    fn from(receipt: CachedTransactionReceipt) -> Self {
+        Self {
+            inner: receipt.receipt,
+            nonce: receipt.nonce,
+            calldata: receipt.calldata,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TransactionsWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -50,6 +79,19 @@ impl TransactionsWeb3Dal<'_, '_> {
         &mut self,
         hashes: &[H256],
     ) -> DalResult<Vec<ExtendedTransactionReceipt>> {
+        let mut receipts = self.get_cached_transaction_receipts(hashes).await?;
+        let hashes: Vec<_> = hashes
+            .iter()
+            .copied()
+            .filter(|hash| {
+                !receipts
+                    .iter()
+                    .any(|receipt| receipt.inner.transaction_hash == *hash)
+            })
+            .collect();
+        if hashes.is_empty() {
+            return Ok(receipts);
+        }
         let hash_bytes: Vec<_> = hashes.iter().map(H256::as_bytes).collect();
 
         let st_receipts: Vec<StorageTransactionReceipt> = sqlx::query_as!(
@@ -89,6 +131,89 @@ impl TransactionsWeb3Dal<'_, '_> {
         .fetch_all(self.storage)
         .await?;
 
+        let uncached_receipts = self
+            .complete_transaction_receipts(st_receipts, &hashes)
+            .await?;
+        receipts.extend(uncached_receipts);
+        Ok(receipts)
+    }
+
+    /// Returns receipts for all transactions in the specified L2 block, ordered by their index in the block.
+    /// Unlike [`Self::get_transaction_receipts()`], doesn't require to know transaction hashes in advance.
+    ///
+    /// # Important!
+    ///
+    /// The returned receipts do not have `contract_address` set; this field needs to be filled separately.
+    pub async fn get_l2_block_transaction_receipts(
+        &mut self,
+        block_number: L2BlockNumber,
+    ) -> DalResult<Vec<ExtendedTransactionReceipt>> {
+        if let Some(receipts) = self
+            .get_cached_l2_block_transaction_receipts(block_number)
+            .await?
+        {
+            return Ok(receipts);
+        }
+        self.load_l2_block_transaction_receipts(block_number).await
+    }
+
+    async fn load_l2_block_transaction_receipts(
+        &mut self,
+        block_number: L2BlockNumber,
+    ) -> DalResult<Vec<ExtendedTransactionReceipt>> {
+        let st_receipts: Vec<StorageTransactionReceipt> = sqlx::query_as!(
+            StorageTransactionReceipt,
+            r#"
+            SELECT
+                transactions.hash AS tx_hash,
+                transactions.index_in_block,
+                transactions.l1_batch_tx_index,
+                transactions.miniblock_number AS "block_number!",
+                transactions.error,
+                transactions.effective_gas_price,
+                transactions.initiator_address,
+                transactions.data -> 'to' AS "transfer_to?",
+                transactions.data -> 'contractAddress' AS "execute_contract_address?",
+                transactions.data -> 'calldata' AS "calldata",
+                transactions.tx_format AS "tx_format?",
+                transactions.refunded_gas,
+                transactions.gas_limit,
+                transactions.nonce,
+                miniblocks.hash AS "block_hash",
+                miniblocks.l1_batch_number AS "l1_batch_number?",
+                miniblocks.timestamp AS "block_timestamp?"
+            FROM
+                transactions
+            JOIN miniblocks ON miniblocks.number = transactions.miniblock_number
+            WHERE
+                transactions.miniblock_number = $1
+                AND transactions.data != '{}'::jsonb
+            ORDER BY
+                transactions.index_in_block
+            "#,
+            // ^ Filter out transactions with pruned data, which would lead to potentially incomplete / bogus
+            // transaction info.
+            i64::from(block_number.0)
+        )
+        .instrument("get_l2_block_transaction_receipts")
+        .with_arg("block_number", &block_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        let hashes: Vec<_> = st_receipts
+            .iter()
+            .map(|receipt| H256::from_slice(&receipt.tx_hash))
+            .collect();
+        self.complete_transaction_receipts(st_receipts, &hashes)
+            .await
+    }
+
+    /// Converts storage receipts and fills in their logs and L2-to-L1 logs.
+    async fn complete_transaction_receipts(
+        &mut self,
+        st_receipts: Vec<StorageTransactionReceipt>,
+        hashes: &[H256],
+    ) -> DalResult<Vec<ExtendedTransactionReceipt>> {
         let block_timestamps: Vec<Option<i64>> =
             st_receipts.iter().map(|x| x.block_timestamp).collect();
 
@@ -146,6 +271,166 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(receipts)
     }
 
+    /// Materializes receipts for transactions in the specified L1 batch in the receipts cache, so that
+    /// they are served without assembling them from several tables. Only the last `retained_l2_blocks` L2 blocks
+    /// are cached; receipts for older blocks are evicted.
+    ///
+    /// Must be called after the L1 batch is sealed since receipts contain L1 batch info.
+    pub async fn cache_l1_batch_receipts(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+        retained_l2_blocks: u32,
+    ) -> DalResult<()> {
+        if retained_l2_blocks == 0 {
+            return Ok(());
+        }
+        let Some((first_l2_block, last_l2_block)) = self
+            .storage
+            .blocks_web3_dal()
+            .get_l2_block_range_of_l1_batch(l1_batch_number)
+            .await?
+        else {
+            return Ok(());
+        };
+        let first_retained_l2_block =
+            L2BlockNumber((last_l2_block.0 + 1).saturating_sub(retained_l2_blocks));
+
+        let mut tx_hashes = vec![];
+        let mut l2_block_numbers = vec![];
+        let mut indices_in_block = vec![];
+        let mut cached_receipts = vec![];
+        let first_l2_block = first_l2_block.max(first_retained_l2_block);
+        for l2_block_number in first_l2_block.0..=last_l2_block.0 {
+            let receipts = self
+                .load_l2_block_transaction_receipts(L2BlockNumber(l2_block_number))
+                .await?;
+            for receipt in receipts {
+                tx_hashes.push(receipt.inner.transaction_hash.as_bytes().to_vec());
+                l2_block_numbers.push(i64::from(l2_block_number));
+                indices_in_block.push(receipt.inner.transaction_index.as_u32() as i32);
+                let receipt = CachedTransactionReceipt::from(receipt);
+                cached_receipts
+                    .push(serde_json::to_value(&receipt).expect("failed serializing receipt"));
+            }
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            transaction_receipts_cache (
+                tx_hash, miniblock_number, index_in_block, receipt, created_at
+            )
+            SELECT
+                u.tx_hash,
+                u.miniblock_number,
+                u.index_in_block,
+                u.receipt,
+                NOW()
+            FROM
+                UNNEST($1::bytea [], $2::bigint [], $3::int [], $4::jsonb [])
+                AS u (tx_hash, miniblock_number, index_in_block, receipt)
+            ON CONFLICT (tx_hash) DO NOTHING
+            "#,
+            &tx_hashes,
+            &l2_block_numbers,
+            &indices_in_block,
+            &cached_receipts
+        )
+        .instrument("cache_l1_batch_receipts#insert")
+        .with_arg("l1_batch_number", &l1_batch_number)
+        .with_arg("tx_hashes.len", &tx_hashes.len())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM transaction_receipts_cache
+            WHERE
+                miniblock_number < $1
+            "#,
+            i64::from(first_retained_l2_block.0)
+        )
+        .instrument("cache_l1_batch_receipts#evict")
+        .with_arg("first_retained_l2_block", &first_retained_l2_block)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_cached_transaction_receipts(
+        &mut self,
+        hashes: &[H256],
+    ) -> DalResult<Vec<ExtendedTransactionReceipt>> {
+        let hash_bytes: Vec<_> = hashes.iter().map(H256::as_bytes).collect();
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                receipt
+            FROM
+                transaction_receipts_cache
+            WHERE
+                tx_hash = ANY($1)
+            "#,
+            &hash_bytes as &[&[u8]]
+        )
+        .instrument("get_cached_transaction_receipts")
+        .with_arg("hashes.len", &hashes.len())
+        .fetch_all(self.storage)
+        .await?;
+
+        // Receipts that cannot be parsed (e.g., cached by a node with a different receipt format)
+        // are treated as missing from the cache.
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| serde_json::from_value::<CachedTransactionReceipt>(row.receipt).ok())
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Returns `None` if receipts for the block are not cached (or are cached partially).
+    async fn get_cached_l2_block_transaction_receipts(
+        &mut self,
+        block_number: L2BlockNumber,
+    ) -> DalResult<Option<Vec<ExtendedTransactionReceipt>>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                transaction_receipts_cache.receipt,
+                miniblocks.l1_tx_count + miniblocks.l2_tx_count AS "tx_count!"
+            FROM
+                transaction_receipts_cache
+            JOIN miniblocks ON miniblocks.number = transaction_receipts_cache.miniblock_number
+            WHERE
+                transaction_receipts_cache.miniblock_number = $1
+            ORDER BY
+                transaction_receipts_cache.index_in_block
+            "#,
+            i64::from(block_number.0)
+        )
+        .instrument("get_cached_l2_block_transaction_receipts")
+        .with_arg("block_number", &block_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        let Some(first_row) = rows.first() else {
+            return Ok(None);
+        };
+        if first_row.tx_count as usize != rows.len() {
+            return Ok(None);
+        }
+        let receipts: Option<Vec<_>> = rows
+            .into_iter()
+            .map(|row| {
+                serde_json::from_value::<CachedTransactionReceipt>(row.receipt)
+                    .ok()
+                    .map(Into::into)
+            })
+            .collect();
+        Ok(receipts)
+    }
+
     /// Obtains transactions with the specified hashes. Transactions are returned in no particular order; if some hashes
     /// don't correspond to transactions, the output will contain less elements than `hashes`.
     pub async fn get_transactions(
@@ -327,6 +612,33 @@ impl TransactionsWeb3Dal<'_, '_> {
         Ok(row.map(Into::into))
     }
 
+    /// Returns raw signed bytes of an L2 transaction as it was submitted to the node. Returns `None` for L1
+    /// and upgrade transactions (they are not signed by a user), and for transactions with pruned data.
+    pub async fn get_raw_transaction_bytes(&mut self, hash: H256) -> DalResult<Option<Vec<u8>>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                input
+            FROM
+                transactions
+            WHERE
+                hash = $1
+                AND is_priority = FALSE
+                AND upgrade_id IS NULL
+            "#,
+            hash.as_bytes()
+        )
+        .instrument("get_raw_transaction_bytes")
+        .with_arg("hash", &hash)
+        .fetch_optional(self.storage)
+        .await?;
+
+        // Transactions without input data are stored with empty input.
+        Ok(row
+            .and_then(|row| row.input)
+            .filter(|input| !input.is_empty()))
+    }
+
     /// Returns hashes of txs which were received after `from_timestamp` and the time of receiving the last tx.
     pub async fn get_pending_txs_hashes_after(
         &mut self,
@@ -488,7 +800,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        tests::{create_l2_block_header, mock_execution_result, mock_l2_transaction},
+        tests::{
+            create_l1_batch_header, create_l2_block_header, mock_execution_result,
+            mock_l2_transaction,
+        },
         ConnectionPool, Core, CoreDal,
     };
 
@@ -531,6 +846,7 @@ mod tests {
                 U256::from(1),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
@@ -602,6 +918,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn getting_raw_transaction_bytes() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        let raw_bytes = tx.common_data.input_data().unwrap().to_vec();
+        prepare_transactions(&mut conn, vec![tx]).await;
+
+        let fetched_bytes = conn
+            .transactions_web3_dal()
+            .get_raw_transaction_bytes(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(fetched_bytes, Some(raw_bytes));
+
+        let fetched_bytes = conn
+            .transactions_web3_dal()
+            .get_raw_transaction_bytes(H256::zero())
+            .await
+            .unwrap();
+        assert_eq!(fetched_bytes, None);
+    }
+
     #[tokio::test]
     async fn getting_evm_deployment_tx() {
         let connection_pool = ConnectionPool::<Core>::test_pool().await;
@@ -663,6 +1007,159 @@ mod tests {
         assert_eq!(receipts.len(), 2);
         assert_eq!(receipts[0].inner.transaction_hash, tx1_hash);
         assert_eq!(receipts[1].inner.transaction_hash, tx2_hash);
+
+        let block_receipts = conn
+            .transactions_web3_dal()
+            .get_l2_block_transaction_receipts(L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(block_receipts.len(), 2);
+        for (block_receipt, receipt) in block_receipts.iter().zip(&receipts) {
+            assert_eq!(block_receipt.inner, receipt.inner);
+        }
+
+        let block_receipts = conn
+            .transactions_web3_dal()
+            .get_l2_block_transaction_receipts(L2BlockNumber(2))
+            .await
+            .unwrap();
+        assert!(block_receipts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn caching_receipts() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let tx1 = mock_l2_transaction();
+        let tx1_hash = tx1.hash();
+        let tx2 = mock_l2_transaction();
+        let tx2_hash = tx2.hash();
+        prepare_transactions(&mut conn, vec![tx1, tx2]).await;
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .mark_l2_blocks_as_executed_in_l1_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+
+        let loaded_receipts = conn
+            .transactions_web3_dal()
+            .load_l2_block_transaction_receipts(L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(loaded_receipts.len(), 2);
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_l2_block_transaction_receipts(L2BlockNumber(1))
+            .await
+            .unwrap();
+        assert!(cached_receipts.is_none());
+
+        conn.transactions_web3_dal()
+            .cache_l1_batch_receipts(L1BatchNumber(1), 10)
+            .await
+            .unwrap();
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_l2_block_transaction_receipts(L2BlockNumber(1))
+            .await
+            .unwrap()
+            .expect("receipts are not cached");
+        assert_eq!(cached_receipts.len(), loaded_receipts.len());
+        for (cached, loaded) in cached_receipts.iter().zip(&loaded_receipts) {
+            assert_eq!(cached.inner, loaded.inner);
+            assert_eq!(cached.nonce, loaded.nonce);
+            assert_eq!(cached.calldata, loaded.calldata);
+        }
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_transaction_receipts(&[tx1_hash, tx2_hash, H256::zero()])
+            .await
+            .unwrap();
+        assert_eq!(cached_receipts.len(), 2);
+
+        // Receipts must be removed from the cache together with the L2 block.
+        conn.blocks_dal()
+            .delete_l2_blocks(L2BlockNumber(0))
+            .await
+            .unwrap();
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_transaction_receipts(&[tx1_hash, tx2_hash])
+            .await
+            .unwrap();
+        assert!(cached_receipts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn evicting_cached_receipts() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        prepare_transactions(&mut conn, vec![tx]).await;
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .mark_l2_blocks_as_executed_in_l1_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+        conn.transactions_web3_dal()
+            .cache_l1_batch_receipts(L1BatchNumber(1), 1)
+            .await
+            .unwrap();
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_transaction_receipts(&[tx_hash])
+            .await
+            .unwrap();
+        assert_eq!(cached_receipts.len(), 1);
+
+        // Seal another L1 batch with an empty L2 block; the block with the transaction should be evicted.
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(2))
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(2))
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .mark_l2_blocks_as_executed_in_l1_batch(L1BatchNumber(2))
+            .await
+            .unwrap();
+        conn.transactions_web3_dal()
+            .cache_l1_batch_receipts(L1BatchNumber(2), 1)
+            .await
+            .unwrap();
+        let cached_receipts = conn
+            .transactions_web3_dal()
+            .get_cached_transaction_receipts(&[tx_hash])
+            .await
+            .unwrap();
+        assert!(cached_receipts.is_empty());
+
+        // Receipts are still served from the main tables.
+        let receipts = conn
+            .transactions_web3_dal()
+            .get_transaction_receipts(&[tx_hash])
+            .await
+            .unwrap();
+        assert_eq!(receipts.len(), 1);
     }
 
     #[tokio::test]
@@ -786,6 +1283,7 @@ mod tests {
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
@@ -3,11 +3,12 @@ use std::{
     future::Future,
     marker::PhantomData,
     panic::Location,
+    pin::Pin,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use anyhow::Context as _;
@@ -122,6 +123,9 @@ impl<DB: DbMarker> ConnectionPoolBuilder<DB> {
 pub struct TestTemplate(SensitiveUrl);
 
 impl TestTemplate {
+    /// Postgres error code for an attempt to clone a template database that has active connections.
+    const OBJECT_IN_USE_CODE: &'static str = "55006";
+
     fn db_name(&self) -> &str {
         self.0.expose_url().path().strip_prefix('/').unwrap()
     }
@@ -169,16 +173,19 @@ impl TestTemplate {
     /// Closes the connection pool, disallows connecting to the underlying db,
     /// so that the db can be used as a template.
     pub async fn freeze<DB: DbMarker>(pool: ConnectionPool<DB>) -> anyhow::Result<Self> {
-        use sqlx::Executor as _;
-        let mut conn = pool.acquire_connection_retried(None).await?;
-        conn.execute(
-            "UPDATE pg_database SET datallowconn = false WHERE datname = current_database()",
-        )
-        .await
-        .context("SET dataallowconn = false")?;
-        drop(conn);
+        // The pool is closed before the db is marked as frozen, so that other processes don't try
+        // to clone the template while it still has connections.
         pool.inner.close().await;
-        Ok(Self(pool.database_url))
+        let template = Self(pool.database_url);
+        let mut conn = Self::connect_to(&template.url(""))
+            .await
+            .context("connect_to()")?;
+        sqlx::query("UPDATE pg_database SET datallowconn = false WHERE datname = $1")
+            .bind(template.db_name())
+            .execute(&mut conn)
+            .await
+            .context("SET datallowconn = false")?;
+        Ok(template)
     }
 
     /// Constructs a new temporary database (with a randomized name)
@@ -200,9 +207,22 @@ impl TestTemplate {
             .context("connect_to()")?;
         let db_old = self.db_name();
         let db_new = format!("test-{}", rand::thread_rng().gen::<u64>());
-        conn.execute(format!("CREATE DATABASE \"{db_new}\" WITH TEMPLATE \"{db_old}\"").as_str())
-            .await
-            .context("CREATE DATABASE")?;
+        let query = format!("CREATE DATABASE \"{db_new}\" WITH TEMPLATE \"{db_old}\"");
+        // Backends of connections to the template may take some time to terminate after the connections
+        // are closed, during which cloning the template fails.
+        let mut attempts = 20;
+        loop {
+            match conn.execute(query.as_str()).await {
+                Ok(_) => break,
+                Err(sqlx::Error::Database(err))
+                    if err.code().as_deref() == Some(Self::OBJECT_IN_USE_CODE) && attempts > 1 =>
+                {
+                    attempts -= 1;
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                }
+                Err(err) => return Err(anyhow::Error::new(err).context("CREATE DATABASE")),
+            }
+        }
 
         Ok(ConnectionPool::<DB>::builder(
             self.url(&db_new),
@@ -211,6 +231,198 @@ impl TestTemplate {
     }
 }
 
+/// Function seeding a [`SeededTestTemplate`]. The provided pool is connected to the template database.
+pub type TemplateSeedFn<DB> =
+    fn(ConnectionPool<DB>) -> Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send>>;
+
+/// Test database template with data seeded by a user-defined function on top of the migrated database
+/// (i.e., the database pointed by `TEST_DATABASE_URL` / `TEST_DATABASE_PROVER_URL`).
+///
+/// The template database is created and seeded lazily, once per Postgres instance, so that tests
+/// requiring the same preconditions (e.g., genesis) don't need to recreate them in every test; cloning
+/// a template is much cheaper. Seeding is coordinated via Postgres, so it's safe to use the template
+/// from multiple test processes (e.g., when running tests with `nextest`). As with other test databases,
+/// the template is never cleaned up, and is expected to be recreated together with the Postgres container;
+/// thus, the template name should be changed if the seeding logic changes in an incompatible way.
+///
+/// Usually, templates should be declared using the [`seeded_test_template!`](crate::seeded_test_template) macro.
+pub struct SeededTestTemplate<DB: DbMarker> {
+    name: &'static str,
+    base: fn() -> anyhow::Result<TestTemplate>,
+    seed: TemplateSeedFn<DB>,
+}
+
+impl<DB: DbMarker> fmt::Debug for SeededTestTemplate<DB> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SeededTestTemplate")
+            .field("name", &self.name)
+            .field("db", &any::type_name::<DB>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<DB: DbMarker> SeededTestTemplate<DB> {
+    /// Postgres error code for an attempt to create a database that already exists.
+    const DUPLICATE_DATABASE_CODE: &'static str = "42P04";
+    /// Maximum time to wait for the template to be seeded by another test.
+    const SEEDING_TIMEOUT: Duration = Duration::from_secs(120);
+
+    /// Creates a template based on the core test database.
+    pub const fn new(name: &'static str, seed: TemplateSeedFn<DB>) -> Self {
+        Self {
+            name,
+            base: TestTemplate::empty,
+            seed,
+        }
+    }
+
+    /// Creates a template based on the prover test database.
+    pub const fn prover(name: &'static str, seed: TemplateSeedFn<DB>) -> Self {
+        Self {
+            name,
+            base: TestTemplate::prover_empty,
+            seed,
+        }
+    }
+
+    /// Returns the seeded template, creating and seeding it if necessary.
+    pub async fn template(&self) -> anyhow::Result<TestTemplate> {
+        use sqlx::Executor as _;
+
+        let base = (self.base)()?;
+        let template_name = format!("{}-{}", base.db_name(), self.name.to_lowercase());
+        let template = TestTemplate(base.url(&template_name));
+        let mut conn = TestTemplate::connect_to(&base.url(""))
+            .await
+            .context("connect_to()")?;
+
+        let started_at = Instant::now();
+        loop {
+            let allows_connections: Option<bool> =
+                sqlx::query_scalar("SELECT datallowconn FROM pg_database WHERE datname = $1")
+                    .bind(&template_name)
+                    .fetch_optional(&mut conn)
+                    .await
+                    .context("failed querying template database")?;
+            match allows_connections {
+                // The template is seeded and frozen.
+                Some(false) => return Ok(template),
+                // The template is being seeded, presumably by another test process.
+                Some(true) => {
+                    anyhow::ensure!(
+                        started_at.elapsed() < Self::SEEDING_TIMEOUT,
+                        "timed out waiting for template `{template_name}` to be seeded; it might have been left \
+                         in an inconsistent state by a crashed test"
+                    );
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+                None => {
+                    let create_result = conn
+                        .execute(
+                            format!(
+                                "CREATE DATABASE \"{template_name}\" WITH TEMPLATE \"{}\"",
+                                base.db_name()
+                            )
+                            .as_str(),
+                        )
+                        .await;
+                    match create_result {
+                        Ok(_) => break,
+                        Err(sqlx::Error::Database(err))
+                            if err.code().as_deref() == Some(Self::DUPLICATE_DATABASE_CODE) =>
+                        {
+                            // Another test process has created the template concurrently.
+                            continue;
+                        }
+                        Err(err) => return Err(anyhow::Error::new(err).context("CREATE DATABASE")),
+                    }
+                }
+            }
+        }
+
+        tracing::info!("Seeding test template `{template_name}`");
+        let pool = ConnectionPool::<DB>::builder(template.0.clone(), 10)
+            .build()
+            .await
+            .context("failed connecting to template")?;
+        if let Err(err) = (self.seed)(pool.clone()).await {
+            // Drop the template so that it's re-seeded by the next test instead of other tests waiting for it
+            // until the timeout.
+            pool.inner.close().await;
+            let drop_query = format!("DROP DATABASE IF EXISTS \"{template_name}\" WITH (FORCE)");
+            if let Err(drop_err) = conn.execute(drop_query.as_str()).await {
+                tracing::warn!(
+                    "Failed dropping template `{template_name}` after seeding error: {drop_err}"
+                );
+            }
+            return Err(err.context(format!("failed seeding template `{template_name}`")));
+        }
+        drop(conn);
+        TestTemplate::freeze(pool).await
+    }
+
+    /// Creates a test pool cloned from this template. Analogous to [`ConnectionPool::test_pool()`].
+    pub async fn test_pool(&self) -> ConnectionPool<DB> {
+        self.constrained_test_pool(ConnectionPool::<DB>::TEST_CONNECTIONS)
+            .await
+    }
+
+    /// Creates a test pool with the specified number of connections cloned from this template.
+    /// Analogous to [`ConnectionPool::constrained_test_pool()`].
+    pub async fn constrained_test_pool(&self, connections: u32) -> ConnectionPool<DB> {
+        let template = self
+            .template()
+            .await
+            .expect("failed creating seeded test template");
+        ConnectionPool::test_pool_from_template(template, connections).await
+    }
+}
+
+/// Declares a static [`SeededTestTemplate`] seeded by the specified async function.
+/// The function must have `async fn(ConnectionPool<DB>) -> anyhow::Result<()>` signature.
+/// By default, the template is based on the core test database; use `prover(_)` to base it on the prover database.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use zksync_db_connection::{connection::DbMarker, connection_pool::ConnectionPool, seeded_test_template};
+/// # #[derive(Debug, Clone)]
+/// # struct Core;
+/// # impl DbMarker for Core {}
+/// async fn seed_genesis(pool: ConnectionPool<Core>) -> anyhow::Result<()> {
+///     let _conn = pool.connection().await?;
+///     // Insert genesis data...
+///     Ok(())
+/// }
+///
+/// seeded_test_template!(static GENESIS: Core = seed_genesis;);
+///
+/// # async fn test() {
+/// // In a test:
+/// let pool = GENESIS.test_pool().await;
+/// # }
+/// ```
+#[macro_export]
+macro_rules! seeded_test_template {
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $db:ty = prover($seed:path);) => {
+        $(#[$attr])*
+        $vis static $name: $crate::connection_pool::SeededTestTemplate<$db> =
+            $crate::connection_pool::SeededTestTemplate::prover(
+                stringify!($name),
+                |pool| ::std::boxed::Box::pin($seed(pool)),
+            );
+    };
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $db:ty = $seed:path;) => {
+        $(#[$attr])*
+        $vis static $name: $crate::connection_pool::SeededTestTemplate<$db> =
+            $crate::connection_pool::SeededTestTemplate::new(
+                stringify!($name),
+                |pool| ::std::boxed::Box::pin($seed(pool)),
+            );
+    };
+}
+
 /// Global DB connection parameters applied to all [`ConnectionPool`] instances.
 #[derive(Debug)]
 pub struct GlobalConnectionPoolConfig {
@@ -282,6 +494,8 @@ impl<DB: DbMarker> fmt::Debug for ConnectionPool<DB> {
 
 impl<DB: DbMarker> ConnectionPool<DB> {
     const TEST_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(10);
+    /// Number of connections in test pools; expected to be enough for any unit test.
+    const TEST_CONNECTIONS: u32 = 100;
 
     /// Returns a reference to the global configuration parameters applied for all DB pools. For consistency, these parameters
     /// should be changed early in the app life cycle.
@@ -295,37 +509,31 @@ impl<DB: DbMarker> ConnectionPool<DB> {
     /// Test pools trace their active connections. If acquiring a connection fails (e.g., with a timeout),
     /// the returned error will contain information on all active connections.
     pub async fn test_pool() -> ConnectionPool<DB> {
-        const DEFAULT_CONNECTIONS: u32 = 100; // Expected to be enough for any unit test.
-        Self::constrained_test_pool(DEFAULT_CONNECTIONS).await
+        Self::constrained_test_pool(Self::TEST_CONNECTIONS).await
     }
 
     pub async fn prover_test_pool() -> ConnectionPool<DB> {
-        const DEFAULT_CONNECTIONS: u32 = 100; // Expected to be enough for any unit test.
-        Self::constrained_prover_test_pool(DEFAULT_CONNECTIONS).await
+        Self::constrained_prover_test_pool(Self::TEST_CONNECTIONS).await
     }
 
     /// Same as [`Self::test_pool()`], but with a configurable number of connections. This is useful to test
     /// behavior of components that rely on singleton / constrained pools in production.
     pub async fn constrained_test_pool(connections: u32) -> ConnectionPool<DB> {
-        assert!(connections > 0, "Number of connections must be positive");
-        let mut builder = TestTemplate::empty()
-            .expect("failed creating test template")
-            .create_db(connections)
-            .await
-            .expect("failed creating database for tests");
-        let mut pool = builder
-            .set_acquire_timeout(Some(Self::TEST_ACQUIRE_TIMEOUT))
-            .build()
-            .await
-            .expect("cannot build connection pool");
-        pool.traced_connections = Some(Arc::default());
-        pool
+        let template = TestTemplate::empty().expect("failed creating test template");
+        Self::test_pool_from_template(template, connections).await
     }
 
     pub async fn constrained_prover_test_pool(connections: u32) -> ConnectionPool<DB> {
+        let template = TestTemplate::prover_empty().expect("failed creating test template");
+        Self::test_pool_from_template(template, connections).await
+    }
+
+    async fn test_pool_from_template(
+        template: TestTemplate,
+        connections: u32,
+    ) -> ConnectionPool<DB> {
         assert!(connections > 0, "Number of connections must be positive");
-        let mut builder = TestTemplate::prover_empty()
-            .expect("failed creating test template")
+        let mut builder = template
             .create_db(connections)
             .await
             .expect("failed creating database for tests");
@@ -468,6 +676,51 @@ mod tests {
     use super::*;
     use crate::utils::InternalMarker;
 
+    async fn seed_test_table(pool: ConnectionPool<InternalMarker>) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        sqlx::query("CREATE TABLE seeded_test_table (value BIGINT NOT NULL)")
+            .execute(storage.conn())
+            .await?;
+        sqlx::query("INSERT INTO seeded_test_table VALUES (42)")
+            .execute(storage.conn())
+            .await?;
+        Ok(())
+    }
+
+    seeded_test_template!(
+        static TEST_TABLE_TEMPLATE: InternalMarker = seed_test_table;
+    );
+
+    async fn seeded_values(pool: &ConnectionPool<InternalMarker>) -> Vec<i64> {
+        let mut storage = pool.connection().await.unwrap();
+        sqlx::query_scalar("SELECT value FROM seeded_test_table ORDER BY value")
+            .fetch_all(storage.conn())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn using_seeded_test_template() {
+        let (pool, other_pool) = tokio::join!(
+            TEST_TABLE_TEMPLATE.test_pool(),
+            TEST_TABLE_TEMPLATE.constrained_test_pool(1)
+        );
+        assert_eq!(seeded_values(&pool).await, [42]);
+        assert_eq!(seeded_values(&other_pool).await, [42]);
+
+        // Databases cloned from the template must be independent.
+        let mut storage = pool.connection().await.unwrap();
+        sqlx::query("INSERT INTO seeded_test_table VALUES (23)")
+            .execute(storage.conn())
+            .await
+            .unwrap();
+        drop(storage);
+        assert_eq!(seeded_values(&pool).await, [23, 42]);
+        assert_eq!(seeded_values(&other_pool).await, [42]);
+        let new_pool = TEST_TABLE_TEMPLATE.test_pool().await;
+        assert_eq!(seeded_values(&new_pool).await, [42]);
+    }
+
     #[tokio::test]
     async fn setting_statement_timeout() {
         let db_url = TestTemplate::empty()
@@ -96,7 +96,18 @@ mod tests {
                     addr("0x0000000000000000000000000000000000000002"),
                 ],
                 api_namespaces: Some(vec!["debug".to_string()]),
+                disabled_api_namespaces: Some(vec![
+                    "snapshots".to_string(),
+                    "unstable".to_string(),
+                ]),
                 extended_api_tracing: true,
+                pruned_calls_snapshot_chunks_budget: Some(4),
+                max_trace_size_bytes: Some(10000),
+                websocket_max_batch_request_size: Some(50),
+                snapshots_download_url_ttl_sec: Some(3600),
+                snapshots_requests_per_minute_limit: Some(NonZeroU32::new(60).unwrap()),
+                snapshots_rate_limit_proxy_hops: Some(NonZeroU32::new(2).unwrap()),
+                strict_quantity_validation: true,
             },
             prometheus: PrometheusConfig {
                 listener_port: 3312,
@@ -128,6 +139,7 @@ mod tests {
             API_WEB3_JSON_RPC_MAX_NONCE_AHEAD=5
             API_WEB3_JSON_RPC_GAS_PRICE_SCALE_FACTOR=1.2
             API_WEB3_JSON_RPC_API_NAMESPACES=debug
+            API_WEB3_JSON_RPC_DISABLED_API_NAMESPACES=snapshots,unstable
             API_WEB3_JSON_RPC_EXTENDED_API_TRACING=true
             API_WEB3_JSON_RPC_WHITELISTED_TOKENS_FOR_AA="0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002"
             API_WEB3_JSON_RPC_ESTIMATE_GAS_SCALE_FACTOR=1.0
@@ -143,6 +155,13 @@ mod tests {
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_SIZE=10000
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_UPDATE_INTERVAL=50
+            API_WEB3_JSON_RPC_PRUNED_CALLS_SNAPSHOT_CHUNKS_BUDGET=4
+            API_WEB3_JSON_RPC_MAX_TRACE_SIZE_BYTES=10000
+            API_WEB3_JSON_RPC_WEBSOCKET_MAX_BATCH_REQUEST_SIZE=50
+            API_WEB3_JSON_RPC_SNAPSHOTS_DOWNLOAD_URL_TTL_SEC=3600
+            API_WEB3_JSON_RPC_SNAPSHOTS_REQUESTS_PER_MINUTE_LIMIT=60
+            API_WEB3_JSON_RPC_SNAPSHOTS_RATE_LIMIT_PROXY_HOPS=2
+            API_WEB3_JSON_RPC_STRICT_QUANTITY_VALIDATION=true
             API_CONTRACT_VERIFICATION_PORT="3070"
             API_CONTRACT_VERIFICATION_URL="http://127.0.0.1:3070"
             API_WEB3_JSON_RPC_MAX_RESPONSE_BODY_SIZE_MB=10
@@ -106,6 +106,11 @@ mod tests {
             l1_batch_commit_data_generator_mode,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            save_storage_accesses: true,
+            receipts_cache_l2_blocks: 128,
+            save_failed_call_traces_only: true,
+            call_traces_retention_days: Some(30),
+            compress_call_traces: true,
         }
     }
 
@@ -139,6 +144,11 @@ mod tests {
             CHAIN_STATE_KEEPER_BOOTLOADER_HASH=0x010007ede999d096c84553fb514d3d6ca76fbf39789dda76bfeda9f3ae06236e
             CHAIN_STATE_KEEPER_DEFAULT_AA_HASH=0x0100055b041eb28aff6e3a6e0f37c31fd053fc9ef142683b05e5f0aee6934066
             CHAIN_STATE_KEEPER_PROTECTIVE_READS_PERSISTENCE_ENABLED=true
+            CHAIN_STATE_KEEPER_SAVE_STORAGE_ACCESSES=true
+            CHAIN_STATE_KEEPER_RECEIPTS_CACHE_L2_BLOCKS=128
+            CHAIN_STATE_KEEPER_SAVE_FAILED_CALL_TRACES_ONLY=true
+            CHAIN_STATE_KEEPER_CALL_TRACES_RETENTION_DAYS=30
+            CHAIN_STATE_KEEPER_COMPRESS_CALL_TRACES=true
             CHAIN_STATE_KEEPER_L1_BATCH_COMMIT_DATA_GENERATOR_MODE="{l1_batch_commit_data_generator_mode}"
         "#
         )
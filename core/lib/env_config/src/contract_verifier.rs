@@ -45,6 +45,14 @@ mod tests {
                 prometheus_port: 3314,
                 port: 3070,
                 etherscan_api_url: None,
+                similar_match_discovery_enabled: false,
+                sourcify_api_url: None,
+                sourcify_chain_id: None,
+                sourcify_import_enabled: false,
+                sourcify_export_enabled: false,
+                sourcify_sync_interval: 300,
+                admin_port: None,
+                priority_requesters: vec![],
             }
         );
     }
@@ -57,6 +65,14 @@ mod tests {
             CONTRACT_VERIFIER_PROMETHEUS_PORT=3314
             CONTRACT_VERIFIER_PORT=3070
             CONTRACT_VERIFIER_ETHERSCAN_API_URL=http://localhost:8080/api
+            CONTRACT_VERIFIER_SIMILAR_MATCH_DISCOVERY_ENABLED=true
+            CONTRACT_VERIFIER_SOURCIFY_API_URL=https://sourcify.dev/server
+            CONTRACT_VERIFIER_SOURCIFY_CHAIN_ID=270
+            CONTRACT_VERIFIER_SOURCIFY_IMPORT_ENABLED=true
+            CONTRACT_VERIFIER_SOURCIFY_EXPORT_ENABLED=true
+            CONTRACT_VERIFIER_SOURCIFY_SYNC_INTERVAL=60
+            CONTRACT_VERIFIER_ADMIN_PORT=3071
+            CONTRACT_VERIFIER_PRIORITY_REQUESTERS=explorer,backfill
         "#;
         lock.set_env(config);
 
@@ -68,6 +84,14 @@ mod tests {
                 prometheus_port: 3314,
                 port: 3070,
                 etherscan_api_url: Some("http://localhost:8080/api".to_string()),
+                similar_match_discovery_enabled: true,
+                sourcify_api_url: Some("https://sourcify.dev/server".to_string()),
+                sourcify_chain_id: Some(270),
+                sourcify_import_enabled: true,
+                sourcify_export_enabled: true,
+                sourcify_sync_interval: 60,
+                admin_port: Some(3071),
+                priority_requesters: vec!["explorer".to_owned(), "backfill".to_owned()],
             }
         );
     }
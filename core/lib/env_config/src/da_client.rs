@@ -162,6 +162,7 @@ mod tests {
             },
             max_retries,
             local_mirror_path: None,
+            write_versioned_envelopes: false,
         })
     }
 
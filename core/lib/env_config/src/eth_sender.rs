@@ -64,6 +64,7 @@ mod tests {
                     timestamp_criteria_max_allowed_lag: 30,
                     max_aggregated_blocks_to_commit: 3,
                     max_aggregated_blocks_to_execute: 4,
+                    max_aggregated_skipped_proofs: 5,
                     wait_confirmations: Some(1),
                     tx_poll_period: 3,
                     aggregate_tx_poll_period: 3,
@@ -76,6 +77,8 @@ mod tests {
                     tx_aggregation_paused: false,
                     time_in_mempool_in_l1_blocks_cap: 2000,
                     is_verifier_pre_fflonk: true,
+                    max_commit_base_fee_per_gas: Some(100_000_000_000),
+                    max_commit_postponement_sec: 7_200,
                 }),
                 gas_adjuster: Some(GasAdjusterConfig {
                     default_priority_fee_per_gas: 20000000000,
@@ -91,10 +94,12 @@ mod tests {
                     internal_pubdata_pricing_multiplier: 1.0,
                     max_blob_base_fee: None,
                     settlement_mode: Default::default(),
+                    gateway_settlement_supported: true,
                 }),
                 watcher: Some(EthWatchConfig {
                     confirmations_for_eth_event: Some(0),
                     eth_node_poll_interval: 300,
+                    address_policy_registry_addr: None,
                 }),
             },
             L1Secrets {
@@ -126,9 +131,11 @@ mod tests {
             ETH_SENDER_GAS_ADJUSTER_MAX_L1_GAS_PRICE="100000000"
             ETH_SENDER_GAS_ADJUSTER_MAX_BLOB_BASE_FEE_SAMPLES="10"
             ETH_SENDER_GAS_ADJUSTER_INTERNAL_PUBDATA_PRICING_MULTIPLIER="1.0"
+            ETH_SENDER_GAS_ADJUSTER_GATEWAY_SETTLEMENT_SUPPORTED="true"
             ETH_SENDER_WAIT_FOR_PROOFS="false"
             ETH_SENDER_SENDER_MAX_AGGREGATED_BLOCKS_TO_COMMIT="3"
             ETH_SENDER_SENDER_MAX_AGGREGATED_BLOCKS_TO_EXECUTE="4"
+            ETH_SENDER_SENDER_MAX_AGGREGATED_SKIPPED_PROOFS="5"
             ETH_SENDER_SENDER_AGGREGATED_BLOCK_COMMIT_DEADLINE="30"
             ETH_SENDER_SENDER_AGGREGATED_BLOCK_PROVE_DEADLINE="3000"
             ETH_SENDER_SENDER_AGGREGATED_BLOCK_EXECUTE_DEADLINE="4000"
@@ -140,6 +147,8 @@ mod tests {
             ETH_SENDER_SENDER_MAX_ACCEPTABLE_PRIORITY_FEE_IN_GWEI="100000000000"
             ETH_SENDER_SENDER_PUBDATA_SENDING_MODE="Calldata"
             ETH_SENDER_SENDER_is_verifier_pre_fflonk="true"
+            ETH_SENDER_SENDER_MAX_COMMIT_BASE_FEE_PER_GAS="100000000000"
+            ETH_SENDER_SENDER_MAX_COMMIT_POSTPONEMENT_SEC="7200"
             ETH_WATCH_CONFIRMATIONS_FOR_ETH_EVENT="0"
             ETH_WATCH_ETH_NODE_POLL_INTERVAL="300"
             ETH_CLIENT_WEB3_URL="http://127.0.0.1:8545"
@@ -11,7 +11,7 @@ impl FromEnv for EthWatchConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::EnvMutex;
+    use crate::test_utils::{addr, EnvMutex};
 
     static MUTEX: EnvMutex = EnvMutex::new();
 
@@ -19,6 +19,7 @@ mod tests {
         EthWatchConfig {
             confirmations_for_eth_event: Some(0),
             eth_node_poll_interval: 300,
+            address_policy_registry_addr: Some(addr("0x1000000000000000000000000000000000000001")),
         }
     }
 
@@ -28,6 +29,7 @@ mod tests {
         let config = r#"
             ETH_WATCH_CONFIRMATIONS_FOR_ETH_EVENT="0"
             ETH_WATCH_ETH_NODE_POLL_INTERVAL="300"
+            ETH_WATCH_ADDRESS_POLICY_REGISTRY_ADDR="0x1000000000000000000000000000000000000001"
         "#;
         lock.set_env(config);
 
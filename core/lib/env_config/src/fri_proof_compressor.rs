@@ -23,11 +23,14 @@ mod tests {
             prometheus_push_interval_ms: Some(100),
             generation_timeout_in_secs: 3000,
             max_attempts: 5,
+            requeue_max_attempts: Some(10),
+            processing_timeout_in_secs: None,
             universal_setup_path: "keys/setup/setup_2^24.key".to_string(),
             universal_setup_download_url:
                 "https://storage.googleapis.com/matterlabs-setup-keys-us/setup-keys/setup_2^24.key"
                     .to_string(),
             verify_wrapper_proof: false,
+            checkpointing_enabled: false,
         }
     }
 
@@ -41,9 +44,11 @@ mod tests {
             FRI_PROOF_COMPRESSOR_PROMETHEUS_PUSH_INTERVAL_MS=100
             FRI_PROOF_COMPRESSOR_GENERATION_TIMEOUT_IN_SECS=3000
             FRI_PROOF_COMPRESSOR_MAX_ATTEMPTS=5
+            FRI_PROOF_COMPRESSOR_REQUEUE_MAX_ATTEMPTS=10
             FRI_PROOF_COMPRESSOR_UNIVERSAL_SETUP_PATH="keys/setup/setup_2^24.key"
             FRI_PROOF_COMPRESSOR_UNIVERSAL_SETUP_DOWNLOAD_URL="https://storage.googleapis.com/matterlabs-setup-keys-us/setup-keys/setup_2^24.key"
             FRI_PROOF_COMPRESSOR_VERIFY_WRAPPER_PROOF=false
+            FRI_PROOF_COMPRESSOR_CHECKPOINTING_ENABLED=false
             FRI_PROOF_COMPRESSOR_UNIVERSAL_FFLONK_SETUP_PATH="keys/setup/setup_fflonk_compact.key"
             FRI_PROOF_COMPRESSOR_UNIVERSAL_FFLONK_SETUP_DOWNLOAD_URL="https://storage.googleapis.com/matterlabs-setup-keys-us/setup-keys/setup_fflonk_compact.key"
         "#;
@@ -51,5 +56,7 @@ mod tests {
 
         let actual = FriProofCompressorConfig::from_env().unwrap();
         assert_eq!(actual, expected_config());
+        assert_eq!(actual.requeue_max_attempts(), 10);
+        assert_eq!(actual.processing_timeout().as_secs(), 3000);
     }
 }
@@ -44,8 +44,10 @@ mod tests {
                 },
                 max_retries: 5,
                 local_mirror_path: None,
+                write_versioned_envelopes: false,
             }),
             availability_check_interval_in_secs: Some(1_800),
+            setup_keys_download_url: Some("https://storage.googleapis.com/setup-keys".to_owned()),
             cloud_type: CloudConnectionMode::GCP,
         }
     }
@@ -64,6 +66,7 @@ mod tests {
             FRI_PROVER_WITNESS_VECTOR_RECEIVER_PORT="3316"
             FRI_PROVER_ZONE_READ_URL="http://metadata.google.internal/computeMetadata/v1/instance/zone"
             FRI_PROVER_AVAILABILITY_CHECK_INTERVAL_IN_SECS="1800"
+            FRI_PROVER_SETUP_KEYS_DOWNLOAD_URL="https://storage.googleapis.com/setup-keys"
             PROVER_OBJECT_STORE_BUCKET_BASE_URL="/base/url"
             PROVER_OBJECT_STORE_MODE="GCSWithCredentialFile"
             PROVER_OBJECT_STORE_GCS_CREDENTIAL_FILE_PATH="/path/to/credentials1.json"
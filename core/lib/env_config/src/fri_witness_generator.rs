@@ -10,6 +10,8 @@ impl FromEnv for FriWitnessGeneratorConfig {
 
 #[cfg(test)]
 mod tests {
+    use zksync_config::configs::fri_witness_generator::ArtifactsCompressionAlgorithm;
+
     use super::*;
     use crate::test_utils::EnvMutex;
 
@@ -24,9 +26,18 @@ mod tests {
             recursion_tip_generation_timeout_in_secs: Some(700u16),
             scheduler_generation_timeout_in_secs: Some(900u16),
             max_attempts: 4,
+            basic_max_attempts: None,
+            leaf_max_attempts: Some(10),
+            node_max_attempts: Some(10),
+            recursion_tip_max_attempts: None,
+            scheduler_max_attempts: Some(2),
             last_l1_batch_to_process: None,
             prometheus_listener_port: Some(3333u16),
             max_circuits_in_flight: 500,
+            prefetch_depth: 2,
+            max_proofs_in_memory: Some(256),
+            artifacts_compression: ArtifactsCompressionAlgorithm::Zstd,
+            artifacts_compression_level: Some(5),
         }
     }
 
@@ -41,8 +52,15 @@ mod tests {
             FRI_WITNESS_RECURSION_TIP_GENERATION_TIMEOUT_IN_SECS=700
             FRI_WITNESS_SCHEDULER_GENERATION_TIMEOUT_IN_SECS=900
             FRI_WITNESS_MAX_ATTEMPTS=4
+            FRI_WITNESS_LEAF_MAX_ATTEMPTS=10
+            FRI_WITNESS_NODE_MAX_ATTEMPTS=10
+            FRI_WITNESS_SCHEDULER_MAX_ATTEMPTS=2
             FRI_WITNESS_PROMETHEUS_LISTENER_PORT=3333
             FRI_WITNESS_MAX_CIRCUITS_IN_FLIGHT=500
+            FRI_WITNESS_PREFETCH_DEPTH=2
+            FRI_WITNESS_MAX_PROOFS_IN_MEMORY=256
+            FRI_WITNESS_ARTIFACTS_COMPRESSION="Zstd"
+            FRI_WITNESS_ARTIFACTS_COMPRESSION_LEVEL=5
         "#;
         lock.set_env(config);
 
@@ -75,4 +93,29 @@ mod tests {
         assert_eq!(timeouts.recursion_tip().as_secs(), 800);
         assert_eq!(timeouts.scheduler().as_secs(), 200);
     }
+
+    #[test]
+    fn from_env_default_max_attempts() {
+        let mut lock = MUTEX.lock();
+        lock.remove_env(&[
+            "FRI_WITNESS_LEAF_MAX_ATTEMPTS",
+            "FRI_WITNESS_NODE_MAX_ATTEMPTS",
+        ]);
+        let config = r#"
+            FRI_WITNESS_GENERATION_TIMEOUT_IN_SECS=800
+            FRI_WITNESS_MAX_ATTEMPTS=4
+            FRI_WITNESS_BASIC_MAX_ATTEMPTS=2
+            FRI_WITNESS_SCHEDULER_MAX_ATTEMPTS=10
+        "#;
+        lock.set_env(config);
+
+        let actual = FriWitnessGeneratorConfig::from_env().unwrap();
+        let max_attempts = actual.witness_generation_max_attempts();
+
+        assert_eq!(max_attempts.basic(), 2);
+        assert_eq!(max_attempts.leaf(), 4);
+        assert_eq!(max_attempts.node(), 4);
+        assert_eq!(max_attempts.recursion_tip(), 4);
+        assert_eq!(max_attempts.scheduler(), 10);
+    }
 }
@@ -13,6 +13,8 @@ impl FromEnv for FriWitnessVectorGeneratorConfig {
 
 #[cfg(test)]
 mod tests {
+    use zksync_config::configs::fri_witness_vector_generator::WitnessVectorTransferFormat;
+
     use super::*;
     use crate::test_utils::EnvMutex;
 
@@ -27,6 +29,7 @@ mod tests {
             prometheus_pushgateway_url: "http://127.0.0.1:9091".to_string(),
             prometheus_push_interval_ms: Some(100),
             specialized_group_id: 1,
+            transfer_format: WitnessVectorTransferFormat::FramedZstd,
         }
     }
 
@@ -41,6 +44,7 @@ mod tests {
             FRI_WITNESS_VECTOR_GENERATOR_PROMETHEUS_PUSHGATEWAY_URL="http://127.0.0.1:9091"
             FRI_WITNESS_VECTOR_GENERATOR_PROMETHEUS_PUSH_INTERVAL_MS=100
             FRI_WITNESS_VECTOR_GENERATOR_SPECIALIZED_GROUP_ID=1
+            FRI_WITNESS_VECTOR_GENERATOR_TRANSFER_FORMAT="FramedZstd"
         "#;
         lock.set_env(config);
 
@@ -18,6 +18,8 @@ mod tests {
     fn expected_config() -> HouseKeeperConfig {
         HouseKeeperConfig {
             l1_batch_metrics_reporting_interval_ms: 10_000,
+            orphaned_blobs_check_interval_ms: Some(3_600_000),
+            orphaned_blobs_removal_grace_period_secs: Some(86_400),
         }
     }
 
@@ -26,6 +28,8 @@ mod tests {
         let mut lock = MUTEX.lock();
         let config = r#"
             HOUSE_KEEPER_L1_BATCH_METRICS_REPORTING_INTERVAL_MS="10000"
+            HOUSE_KEEPER_ORPHANED_BLOBS_CHECK_INTERVAL_MS="3600000"
+            HOUSE_KEEPER_ORPHANED_BLOBS_REMOVAL_GRACE_PERIOD_SECS="86400"
         "#;
         lock.set_env(config);
 
@@ -46,6 +46,7 @@ mod tests {
             },
             max_retries: 5,
             local_mirror_path: Some("/var/cache".to_owned()),
+            write_versioned_envelopes: false,
         }
     }
 
@@ -70,6 +71,7 @@ mod tests {
         let config = r#"
             OBJECT_STORE_MODE="FileBacked"
             OBJECT_STORE_FILE_BACKED_BASE_PATH="artifacts"
+            OBJECT_STORE_WRITE_VERSIONED_ENVELOPES="true"
         "#;
         lock.set_env(config);
         let actual = ObjectStoreConfig::from_env().unwrap();
@@ -79,6 +81,7 @@ mod tests {
                 file_backed_base_path: "artifacts".to_owned(),
             }
         );
+        assert!(actual.write_versioned_envelopes);
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use zksync_config::configs::ProofDataHandlerConfig;
+use zksync_config::configs::{ProofDataHandlerConfig, ProofDataHandlerSecrets};
 
 use crate::{envy_load, FromEnv};
 
@@ -11,9 +11,19 @@ impl FromEnv for ProofDataHandlerConfig {
     }
 }
 
+impl FromEnv for ProofDataHandlerSecrets {
+    fn from_env() -> anyhow::Result<Self> {
+        Ok(Self {
+            admin_api_key: std::env::var("PROOF_DATA_HANDLER_ADMIN_API_KEY")
+                .ok()
+                .map(Into::into),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use zksync_basic_types::L1BatchNumber;
+    use zksync_basic_types::{secrets::APIKey, L1BatchNumber};
     use zksync_config::configs::TeeConfig;
 
     use super::*;
@@ -25,6 +35,7 @@ mod tests {
         ProofDataHandlerConfig {
             http_port: 3320,
             api_url: "2342".to_string(),
+            fallback_api_urls: vec!["ws://gateway-1".to_string(), "ws://gateway-2".to_string()],
             batch_readiness_check_interval_in_secs: 123,
             proof_generation_timeout_in_secs: 18000,
             retry_connection_interval_in_secs: 123,
@@ -45,6 +56,7 @@ mod tests {
             PROOF_DATA_HANDLER_BATCH_READINESS_CHECK_INTERVAL_IN_SECS="123"
             PROOF_DATA_HANDLER_RETRY_CONNECTION_INTERVAL_IN_SECS="123"
             PROOF_DATA_HANDLER_API_URL="2342"
+            PROOF_DATA_HANDLER_FALLBACK_API_URLS="ws://gateway-1,ws://gateway-2"
             PROOF_DATA_HANDLER_TEE_SUPPORT="true"
             PROOF_DATA_HANDLER_FIRST_TEE_PROCESSED_BATCH="1337"
             PROOF_DATA_HANDLER_TEE_PROOF_GENERATION_TIMEOUT_IN_SECS="600"
@@ -55,4 +67,20 @@ mod tests {
         let actual = ProofDataHandlerConfig::from_env().unwrap();
         assert_eq!(actual, expected_config());
     }
+
+    #[test]
+    fn secrets_from_env() {
+        let config = r#"
+            PROOF_DATA_HANDLER_ADMIN_API_KEY=admin-api-key
+        "#;
+        let mut lock = MUTEX.lock();
+        lock.set_env(config);
+        let actual = ProofDataHandlerSecrets::from_env().unwrap();
+        assert_eq!(
+            actual,
+            ProofDataHandlerSecrets {
+                admin_api_key: Some(APIKey::from("admin-api-key")),
+            }
+        );
+    }
 }
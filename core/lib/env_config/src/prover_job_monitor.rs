@@ -24,6 +24,8 @@ mod tests {
             gpu_prover_archiver_archive_prover_after_ms: 172800000,
             prover_jobs_archiver_run_interval_ms: 1800000,
             prover_jobs_archiver_archive_jobs_after_ms: 172800000,
+            compression_jobs_archiver_run_interval_ms: 1800000,
+            compression_jobs_archiver_archive_jobs_after_ms: 172800000,
             proof_compressor_job_requeuer_run_interval_ms: 10000,
             prover_job_requeuer_run_interval_ms: 10000,
             witness_generator_job_requeuer_run_interval_ms: 10000,
@@ -42,6 +44,8 @@ mod tests {
         config.gpu_prover_archiver_archive_prover_after_ms += 1;
         config.prover_jobs_archiver_run_interval_ms += 1;
         config.prover_jobs_archiver_archive_jobs_after_ms += 1;
+        config.compression_jobs_archiver_run_interval_ms += 1;
+        config.compression_jobs_archiver_archive_jobs_after_ms += 1;
         config.proof_compressor_job_requeuer_run_interval_ms += 1;
         config.prover_job_requeuer_run_interval_ms += 1;
         config.witness_generator_job_requeuer_run_interval_ms += 1;
@@ -75,6 +79,8 @@ mod tests {
             PROVER_JOB_MONITOR_GPU_PROVER_ARCHIVER_ARCHIVE_PROVER_AFTER_MS=172800001
             PROVER_JOB_MONITOR_PROVER_JOBS_ARCHIVER_RUN_INTERVAL_MS=1800001
             PROVER_JOB_MONITOR_PROVER_JOBS_ARCHIVER_ARCHIVE_JOBS_AFTER_MS=172800001
+            PROVER_JOB_MONITOR_COMPRESSION_JOBS_ARCHIVER_RUN_INTERVAL_MS=1800001
+            PROVER_JOB_MONITOR_COMPRESSION_JOBS_ARCHIVER_ARCHIVE_JOBS_AFTER_MS=172800001
             PROVER_JOB_MONITOR_PROOF_COMPRESSOR_JOB_REQUEUER_RUN_INTERVAL_MS=10001
             PROVER_JOB_MONITOR_PROVER_JOB_REQUEUER_RUN_INTERVAL_MS=10001
             PROVER_JOB_MONITOR_WITNESS_GENERATOR_JOB_REQUEUER_RUN_INTERVAL_MS=10001
@@ -56,6 +56,7 @@ mod tests {
         let config = r#"
             EXPERIMENTAL_VM_STATE_KEEPER_FAST_VM_MODE=new
             EXPERIMENTAL_VM_API_FAST_VM_MODE=shadow
+            EXPERIMENTAL_VM_STATE_KEEPER_SHADOW_PARALLEL_EXECUTION=true
             EXPERIMENTAL_VM_PLAYGROUND_FAST_VM_MODE=shadow
             EXPERIMENTAL_VM_PLAYGROUND_DB_PATH=/db/vm_playground
             EXPERIMENTAL_VM_PLAYGROUND_FIRST_PROCESSED_BATCH=123
@@ -66,6 +67,7 @@ mod tests {
         let config = ExperimentalVmConfig::from_env().unwrap();
         assert_eq!(config.state_keeper_fast_vm_mode, FastVmMode::New);
         assert_eq!(config.api_fast_vm_mode, FastVmMode::Shadow);
+        assert!(config.state_keeper_shadow_parallel_execution);
         assert_eq!(config.playground.fast_vm_mode, FastVmMode::Shadow);
         assert_eq!(config.playground.db_path.unwrap(), "/db/vm_playground");
         assert_eq!(config.playground.first_processed_batch, L1BatchNumber(123));
@@ -26,6 +26,15 @@ impl FromEnv for Wallets {
             "Malformed blob operator pk",
         )?;
 
+        let previous_operator = pk_from_env(
+            "ETH_SENDER_SENDER_PREVIOUS_OPERATOR_PRIVATE_KEY",
+            "Malformed previous operator pk",
+        )?;
+        let previous_blob_operator = pk_from_env(
+            "ETH_SENDER_SENDER_PREVIOUS_OPERATOR_BLOBS_PRIVATE_KEY",
+            "Malformed previous blob operator pk",
+        )?;
+
         let eth_sender = if let Some(operator) = operator {
             let operator = Wallet::from_private_key_bytes(operator, None)?;
             let blob_operator = if let Some(blob_operator) = blob_operator {
@@ -33,10 +42,18 @@ impl FromEnv for Wallets {
             } else {
                 None
             };
+            let previous_operator = previous_operator
+                .map(|pk| Wallet::from_private_key_bytes(pk, None))
+                .transpose()?;
+            let previous_blob_operator = previous_blob_operator
+                .map(|pk| Wallet::from_private_key_bytes(pk, None))
+                .transpose()?;
 
             Some(EthSender {
                 operator,
                 blob_operator,
+                previous_operator,
+                previous_blob_operator,
             })
         } else {
             None
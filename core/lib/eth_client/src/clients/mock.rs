@@ -238,6 +238,7 @@ pub struct MockSettlementLayerBuilder<Net: SupportedMockSLNetwork = L1> {
     inner: Arc<RwLock<MockSettlementLayerInner>>,
     call_handler: Box<CallHandler>,
     chain_id: u64,
+    sender_account: Address,
     _network: PhantomData<Net>,
 }
 
@@ -253,6 +254,7 @@ impl<Net: SupportedMockSLNetwork> fmt::Debug for MockSettlementLayerBuilder<Net>
                 &self.non_ordering_confirmations,
             )
             .field("inner", &self.inner)
+            .field("sender_account", &self.sender_account)
             .finish_non_exhaustive()
     }
 }
@@ -269,6 +271,7 @@ impl<Net: SupportedMockSLNetwork> Default for MockSettlementLayerBuilder<Net> {
                 panic!("Unexpected eth_call: {call:?}, {block_id:?}");
             }),
             chain_id: 9,
+            sender_account: MOCK_SENDER_ACCOUNT,
             _network: PhantomData,
         }
     }
@@ -321,6 +324,14 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayerBuilder<Net> {
         Self { chain_id, ..self }
     }
 
+    /// Sets the account returned by [`BoundEthInterface::sender_account()`]. Useful to emulate several operators.
+    pub fn with_sender_account(self, sender_account: Address) -> Self {
+        Self {
+            sender_account,
+            ..self
+        }
+    }
+
     fn get_block_by_number(
         fee_history: &[BaseFees],
         block: web3::BlockNumber,
@@ -405,6 +416,7 @@ impl<Net: SupportedMockSLNetwork> MockSettlementLayerBuilder<Net> {
             max_priority_fee_per_gas: self.max_priority_fee_per_gas,
             non_ordering_confirmations: self.non_ordering_confirmations,
             inner: self.inner.clone(),
+            sender_account: self.sender_account,
             client: Net::build_client(self),
         }
     }
@@ -500,6 +512,7 @@ pub struct MockSettlementLayer<Net: Network = L1> {
     max_priority_fee_per_gas: U256,
     non_ordering_confirmations: bool,
     inner: Arc<RwLock<MockSettlementLayerInner>>,
+    sender_account: Address,
     client: MockClient<Net>,
 }
 
@@ -634,7 +647,7 @@ impl<Net: SupportedMockSLNetwork + SupportedMockSLNetwork> BoundEthInterface
     }
 
     fn sender_account(&self) -> Address {
-        MOCK_SENDER_ACCOUNT
+        self.sender_account
     }
 
     async fn sign_prepared_tx_for_addr(
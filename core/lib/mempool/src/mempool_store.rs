@@ -1,4 +1,4 @@
-use std::collections::{hash_map, BTreeSet, HashMap};
+use std::collections::{hash_map, BTreeSet, HashMap, HashSet};
 
 use zksync_types::{
     l1::L1Tx, l2::L2Tx, Address, ExecuteTransactionCommon, Nonce, PriorityOpId, Transaction,
@@ -31,6 +31,8 @@ pub struct MempoolStore {
     /// Next priority operation
     next_priority_id: PriorityOpId,
     stashed_accounts: Vec<Address>,
+    /// Accounts which transactions are picked only if there are no other suitable transactions
+    deprioritized_accounts: HashSet<Address>,
     /// Number of L2 transactions in the mempool.
     size: u64,
     capacity: u64,
@@ -44,6 +46,7 @@ impl MempoolStore {
             l2_priority_queue: BTreeSet::new(),
             next_priority_id,
             stashed_accounts: vec![],
+            deprioritized_accounts: HashSet::new(),
             size: 0,
             capacity,
         }
@@ -124,8 +127,9 @@ impl MempoolStore {
             hash_map::Entry::Occupied(mut txs) => txs.get_mut().insert(transaction, constraint),
             hash_map::Entry::Vacant(entry) => {
                 let account_nonce = initial_nonces.get(&account).cloned().unwrap_or(Nonce(0));
+                let deprioritized = self.deprioritized_accounts.contains(&account);
                 entry
-                    .insert(AccountTransactions::new(account_nonce))
+                    .insert(AccountTransactions::new(account_nonce, deprioritized))
                     .insert(transaction, constraint)
             }
         };
@@ -140,6 +144,47 @@ impl MempoolStore {
         }
     }
 
+    /// Sets whether transactions of the account should be deprioritized, i.e. only returned if there are
+    /// no other transactions satisfying the filter.
+    pub fn set_account_deprioritized(&mut self, account: Address, deprioritized: bool) {
+        let changed = if deprioritized {
+            self.deprioritized_accounts.insert(account)
+        } else {
+            self.deprioritized_accounts.remove(&account)
+        };
+        if !changed {
+            return;
+        }
+        let Some(txs) = self.l2_transactions_per_account.get_mut(&account) else {
+            return;
+        };
+        if let Some((previous_score, new_score)) = txs.set_deprioritized(deprioritized) {
+            // The score may be missing from the queue, e.g. if the next transaction is being processed.
+            if self.l2_priority_queue.remove(&previous_score) {
+                self.l2_priority_queue.insert(new_score);
+            }
+        }
+    }
+
+    /// Removes all L2 transactions initiated by the specified accounts from the mempool.
+    pub fn remove_accounts(&mut self, accounts: &HashSet<Address>) {
+        let mut removed = 0;
+        for account in accounts {
+            if let Some(txs) = self.l2_transactions_per_account.remove(account) {
+                removed += txs.len() as u64;
+            }
+        }
+        if removed == 0 {
+            return;
+        }
+        self.l2_priority_queue
+            .retain(|score| !accounts.contains(&score.account));
+        self.size = self
+            .size
+            .checked_sub(removed)
+            .expect("mempool size can't be negative");
+    }
+
     /// Returns `true` if there is a transaction in the mempool satisfying the filter.
     pub fn has_next(&self, filter: &L2TxFilter) -> bool {
         self.l1_transactions.contains_key(&self.next_priority_id)
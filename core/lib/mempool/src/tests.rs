@@ -321,6 +321,87 @@ fn stashed_accounts() {
     assert!(mempool.next_transaction(&filter_zero).is_none());
 }
 
+#[test]
+fn deprioritized_accounts() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    mempool.set_account_deprioritized(account0, true);
+
+    let now = unix_timestamp_ms();
+    mempool.insert_without_constraints(
+        vec![
+            gen_l2_tx_with_timestamp(account0, Nonce(0), now),
+            gen_l2_tx_with_timestamp(account0, Nonce(1), now),
+            gen_l2_tx_with_timestamp(account1, Nonce(0), now + 10),
+            gen_l2_tx_with_timestamp(account2, Nonce(0), now + 20),
+        ],
+        HashMap::new(),
+    );
+    // `account0` transactions are older, but they go after transactions of other accounts.
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+
+    // Changing the deprioritized accounts should reorder pending transactions.
+    mempool.set_account_deprioritized(account0, false);
+    mempool.set_account_deprioritized(account2, true);
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 0)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 1)
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account2, 0)
+    );
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+}
+
+#[test]
+fn removing_accounts() {
+    let mut mempool = MempoolStore::new(PriorityOpId(0), 100);
+    let account0 = Address::random();
+    let account1 = Address::random();
+    let account2 = Address::random();
+    let now = unix_timestamp_ms();
+    mempool.insert_without_constraints(
+        vec![
+            gen_l2_tx_with_timestamp(account0, Nonce(0), now),
+            gen_l2_tx_with_timestamp(account0, Nonce(1), now),
+            gen_l2_tx_with_timestamp(account1, Nonce(0), now + 10),
+            gen_l2_tx_with_timestamp(account2, Nonce(0), now + 20),
+        ],
+        HashMap::new(),
+    );
+    assert_eq!(mempool.stats().l2_transaction_count, 4);
+
+    mempool.remove_accounts(&HashSet::from([account0, account2, Address::random()]));
+    let stats = mempool.stats();
+    assert_eq!(stats.l2_transaction_count, 1);
+    assert_eq!(stats.l2_priority_queue_size, 1);
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account1, 0)
+    );
+    assert_eq!(mempool.next_transaction(&L2TxFilter::default()), None);
+
+    // Transactions of removed accounts can be inserted again.
+    mempool.insert_without_constraints(
+        vec![gen_l2_tx(account0, Nonce(1))],
+        HashMap::from([(account0, Nonce(1))]),
+    );
+    assert_eq!(
+        view(mempool.next_transaction(&L2TxFilter::default())),
+        (account0, 1)
+    );
+}
+
 #[test]
 fn mempool_capacity() {
     let mut mempool = MempoolStore::new(PriorityOpId(0), 4);
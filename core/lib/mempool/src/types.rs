@@ -13,13 +13,16 @@ pub(crate) struct AccountTransactions {
     /// account nonce in mempool
     /// equals to committed nonce in db + number of transactions sent to state keeper
     nonce: Nonce,
+    /// whether transactions of the account are deprioritized compared to other accounts
+    deprioritized: bool,
 }
 
 impl AccountTransactions {
-    pub fn new(nonce: Nonce) -> Self {
+    pub fn new(nonce: Nonce, deprioritized: bool) -> Self {
         Self {
             transactions: HashMap::new(),
             nonce,
+            deprioritized,
         }
     }
 
@@ -35,11 +38,11 @@ impl AccountTransactions {
         if nonce < self.nonce {
             return metadata;
         }
-        let new_score = Self::score_for_transaction(&transaction);
+        let new_score = self.score_for_transaction(&transaction);
         let previous_score = self
             .transactions
             .insert(nonce, (transaction, constraint))
-            .map(|x| Self::score_for_transaction(&x.0));
+            .map(|x| self.score_for_transaction(&x.0));
         metadata.is_new = previous_score.is_none();
         if nonce == self.nonce {
             metadata.new_score = Some(new_score);
@@ -59,7 +62,7 @@ impl AccountTransactions {
         let score = self
             .transactions
             .get(&self.nonce)
-            .map(|(tx, _c)| Self::score_for_transaction(tx));
+            .map(|(tx, _c)| self.score_for_transaction(tx));
         (transaction.0, transaction.1, score)
     }
 
@@ -76,24 +79,47 @@ impl AccountTransactions {
         self.nonce = self.nonce.min(tx_nonce);
         self.transactions
             .get(&(tx_nonce + 1))
-            .map(|(tx, c)| (Self::score_for_transaction(tx), c.clone()))
+            .map(|(tx, c)| (self.score_for_transaction(tx), c.clone()))
     }
 
     pub fn len(&self) -> usize {
         self.transactions.len()
     }
 
-    fn score_for_transaction(transaction: &L2Tx) -> MempoolScore {
+    /// Changes the deprioritization status of the account. Returns scores of the next transaction
+    /// before and after the change if the status has changed.
+    pub fn set_deprioritized(
+        &mut self,
+        deprioritized: bool,
+    ) -> Option<(MempoolScore, MempoolScore)> {
+        if self.deprioritized == deprioritized {
+            return None;
+        }
+        let previous_score = self
+            .transactions
+            .get(&self.nonce)
+            .map(|(tx, _c)| self.score_for_transaction(tx));
+        self.deprioritized = deprioritized;
+        let new_score = self
+            .transactions
+            .get(&self.nonce)
+            .map(|(tx, _c)| self.score_for_transaction(tx));
+        previous_score.zip(new_score)
+    }
+
+    fn score_for_transaction(&self, transaction: &L2Tx) -> MempoolScore {
         MempoolScore {
             account: transaction.initiator_account(),
             received_at_ms: transaction.received_timestamp_ms,
             fee_data: transaction.common_data.fee.clone(),
+            deprioritized: self.deprioritized,
         }
     }
 }
 
 /// Mempool score of transaction. Used to prioritize L2 transactions in mempool
-/// Currently trivial ordering is used based on received at timestamp
+/// Currently trivial ordering is used based on received at timestamp; transactions
+/// from deprioritized accounts go after all other transactions
 #[derive(Eq, PartialEq, Clone, Debug, Hash)]
 pub struct MempoolScore {
     pub account: Address,
@@ -102,6 +128,7 @@ pub struct MempoolScore {
     // transactions that have acceptable fee values (so transactions
     // with fee too low would be ignored until prices go down).
     pub fee_data: Fee,
+    pub deprioritized: bool,
 }
 
 impl MempoolScore {
@@ -114,6 +141,10 @@ impl MempoolScore {
 
 impl Ord for MempoolScore {
     fn cmp(&self, other: &MempoolScore) -> Ordering {
+        match self.deprioritized.cmp(&other.deprioritized).reverse() {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
         match self.received_at_ms.cmp(&other.received_at_ms).reverse() {
             Ordering::Equal => {}
             ordering => return ordering,
@@ -175,6 +206,7 @@ mod tests {
                 max_priority_fee_per_gas: U256::from(MAX_PRIORITY_FEE_PER_GAS),
                 gas_per_pubdata_limit: U256::from(GAS_PER_PUBDATA_LIMIT),
             },
+            deprioritized: false,
         };
 
         let noop_filter = filter(0, 0);
@@ -1,3 +1,4 @@
+pub(crate) use self::validator::ValidationGasAttribution;
 pub use self::{
     call_tracer::CallTracer,
     multivm_dispatcher::TracerDispatcher,
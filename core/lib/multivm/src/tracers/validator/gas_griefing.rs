@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use zksync_types::Address;
+
+use crate::interface::tracer::ViolatedValidationRule;
+
+/// Attribution of the computational gas spent during account validation to the contracts executing it.
+///
+/// Used to distinguish an account running out of validation gas on its own from gas griefing, i.e. a contract
+/// called by the account (e.g., a token or an oracle it depends on) burning most of the validation gas.
+/// Gas spent in kernel space (i.e., by system contracts and precompiles) isn't attributed, since these contracts
+/// are trusted and only execute work requested by their callers.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ValidationGasAttribution {
+    gas_by_contract: HashMap<Address, u32>,
+}
+
+impl ValidationGasAttribution {
+    /// Share of the validation gas limit (as a fraction `1 / GRIEFING_GAS_LIMIT_DIVISOR`) that a single called
+    /// contract must consume for an out-of-gas condition to be reported as gas griefing.
+    const GRIEFING_GAS_LIMIT_DIVISOR: u32 = 2;
+
+    /// Checks whether the address belongs to kernel space, i.e. is below `2^16`.
+    fn is_kernel_space(address: Address) -> bool {
+        address.as_bytes()[..Address::len_bytes() - 2]
+            .iter()
+            .all(|&byte| byte == 0)
+    }
+
+    pub fn charge(&mut self, contract: Address, gas: u32) {
+        if gas == 0 || Self::is_kernel_space(contract) {
+            return;
+        }
+        let entry = self.gas_by_contract.entry(contract).or_default();
+        *entry = entry.saturating_add(gas);
+    }
+
+    /// Returns the violated rule for validation running out of gas. If a contract other than the validated account
+    /// consumed more than half of the validation gas limit, it is reported as a gas griefer; otherwise,
+    /// a generic [`ViolatedValidationRule::TookTooManyComputationalGas`] is returned.
+    pub fn out_of_gas_violation(
+        &self,
+        user_address: Address,
+        gas_limit: u32,
+    ) -> ViolatedValidationRule {
+        let top_consumer = self
+            .gas_by_contract
+            .iter()
+            .filter(|(&contract, _)| contract != user_address)
+            // Break ties by the address so that the result is deterministic.
+            .max_by_key(|(&contract, &gas_used)| (gas_used, contract));
+        match top_consumer {
+            Some((&contract, &gas_used))
+                if gas_used > gas_limit / Self::GRIEFING_GAS_LIMIT_DIVISOR =>
+            {
+                ViolatedValidationRule::GasGriefing {
+                    contract,
+                    gas_used,
+                    gas_limit,
+                }
+            }
+            _ => ViolatedValidationRule::TookTooManyComputationalGas(gas_limit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_system_constants::{KECCAK256_PRECOMPILE_ADDRESS, NONCE_HOLDER_ADDRESS};
+
+    use super::*;
+
+    #[test]
+    fn out_of_gas_in_account_is_not_griefing() {
+        let account = Address::repeat_byte(0x10);
+        let mut attribution = ValidationGasAttribution::default();
+        attribution.charge(account, 900);
+        attribution.charge(Address::repeat_byte(0x20), 100);
+
+        assert_eq!(
+            attribution.out_of_gas_violation(account, 1_000),
+            ViolatedValidationRule::TookTooManyComputationalGas(1_000)
+        );
+    }
+
+    #[test]
+    fn called_contract_consuming_most_gas_is_reported() {
+        let account = Address::repeat_byte(0x10);
+        let griefer = Address::repeat_byte(0x20);
+        let mut attribution = ValidationGasAttribution::default();
+        attribution.charge(account, 100);
+        attribution.charge(Address::repeat_byte(0x30), 200);
+        for _ in 0..7 {
+            attribution.charge(griefer, 100);
+        }
+
+        assert_eq!(
+            attribution.out_of_gas_violation(account, 1_000),
+            ViolatedValidationRule::GasGriefing {
+                contract: griefer,
+                gas_used: 700,
+                gas_limit: 1_000,
+            }
+        );
+    }
+
+    #[test]
+    fn gas_spread_across_contracts_is_not_griefing() {
+        let account = Address::repeat_byte(0x10);
+        let mut attribution = ValidationGasAttribution::default();
+        attribution.charge(Address::repeat_byte(0x20), 500);
+        attribution.charge(Address::repeat_byte(0x30), 500);
+
+        // Consuming exactly half of the limit is not considered griefing.
+        assert_eq!(
+            attribution.out_of_gas_violation(account, 1_000),
+            ViolatedValidationRule::TookTooManyComputationalGas(1_000)
+        );
+    }
+
+    #[test]
+    fn kernel_space_contracts_are_not_attributed() {
+        let account = Address::repeat_byte(0x10);
+        let mut attribution = ValidationGasAttribution::default();
+        attribution.charge(KECCAK256_PRECOMPILE_ADDRESS, 600);
+        attribution.charge(NONCE_HOLDER_ADDRESS, 300);
+        attribution.charge(account, 100);
+
+        assert_eq!(
+            attribution.out_of_gas_violation(account, 1_000),
+            ViolatedValidationRule::TookTooManyComputationalGas(1_000)
+        );
+
+        // The first contract outside kernel space is attributed.
+        let create2_factory = Address::from_low_u64_be(0x1_0000);
+        attribution.charge(create2_factory, 600);
+        assert_eq!(
+            attribution.out_of_gas_violation(account, 1_000),
+            ViolatedValidationRule::GasGriefing {
+                contract: create2_factory,
+                gas_used: 600,
+                gas_limit: 1_000,
+            }
+        );
+    }
+
+    #[test]
+    fn charging_saturates() {
+        let contract = Address::repeat_byte(0x20);
+        let mut attribution = ValidationGasAttribution::default();
+        attribution.charge(contract, u32::MAX);
+        attribution.charge(contract, 1);
+
+        assert_eq!(
+            attribution.out_of_gas_violation(Address::zero(), u32::MAX),
+            ViolatedValidationRule::GasGriefing {
+                contract,
+                gas_used: u32::MAX,
+                gas_limit: u32::MAX,
+            }
+        );
+    }
+}
@@ -16,6 +16,7 @@ use zksync_types::{
 };
 use zksync_vm_interface::tracer::{TimestampAsserterParams, ValidationTraces};
 
+pub(crate) use self::gas_griefing::ValidationGasAttribution;
 use self::types::{NewTrustedValidationItems, ValidationTracerMode};
 use crate::{
     glue::tracers::IntoOldVmTracer,
@@ -26,6 +27,7 @@ use crate::{
     utils::bytecode::be_bytes_to_safe_address,
 };
 
+mod gas_griefing;
 mod types;
 mod vm_1_4_1;
 mod vm_1_4_2;
@@ -50,6 +52,8 @@ pub struct ValidationTracer<H> {
     trusted_address_slots: HashSet<(Address, U256)>,
     computational_gas_used: u32,
     computational_gas_limit: u32,
+    /// Only populated by the tracer for the latest VM version.
+    gas_attribution: ValidationGasAttribution,
     timestamp_asserter_params: Option<TimestampAsserterParams>,
     vm_version: VmVersion,
     l1_batch_timestamp: u64,
@@ -76,6 +80,7 @@ impl<H> ValidationTracer<H> {
             trusted_address_slots: params.trusted_address_slots,
             computational_gas_used: 0,
             computational_gas_limit: params.computational_gas_limit,
+            gas_attribution: ValidationGasAttribution::default(),
             timestamp_asserter_params: params.timestamp_asserter_params.clone(),
             vm_version,
             result: Arc::new(OnceCell::new()),
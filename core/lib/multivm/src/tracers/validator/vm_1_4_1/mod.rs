@@ -89,7 +89,9 @@ impl<H: HistoryMode> ValidationTracer<H> {
             Opcode::Context(context) => {
                 match context {
                     ContextOpcode::Meta => {
-                        return Err(ViolatedValidationRule::TouchedDisallowedContext);
+                        return Err(ViolatedValidationRule::TouchedDisallowedContext(
+                            state.vm_local_state.callstack.current.this_address,
+                        ));
                     }
                     ContextOpcode::ErgsLeft => {
                         // TODO (SMA-1168): implement the correct restrictions for the gas left opcode.
@@ -194,7 +196,7 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H::Vm1_4_1> for ValidationTrac
         }
         if let Some(result) = self.result.get() {
             return TracerExecutionStatus::Stop(TracerExecutionStopReason::Abort(
-                Halt::TracerCustom(format!("Validation error: {:#?}", result)),
+                Halt::TracerCustom(format!("Validation error: {result}")),
             ));
         }
         TracerExecutionStatus::Continue
@@ -39,9 +39,9 @@ impl<H: HistoryMode> ValidationTracer<H> {
         storage: StoragePtr<S>,
     ) -> ValidationRoundResult {
         if self.computational_gas_used > self.computational_gas_limit {
-            return Err(ViolatedValidationRule::TookTooManyComputationalGas(
-                self.computational_gas_limit,
-            ));
+            return Err(self
+                .gas_attribution
+                .out_of_gas_violation(self.user_address, self.computational_gas_limit));
         }
 
         let opcode_variant = data.opcode.variant;
@@ -135,7 +135,9 @@ impl<H: HistoryMode> ValidationTracer<H> {
             Opcode::Context(context) => {
                 match context {
                     ContextOpcode::Meta => {
-                        return Err(ViolatedValidationRule::TouchedDisallowedContext);
+                        return Err(ViolatedValidationRule::TouchedDisallowedContext(
+                            state.vm_local_state.callstack.current.this_address,
+                        ));
                     }
                     ContextOpcode::ErgsLeft => {
                         // TODO (SMA-1168): implement the correct restrictions for the gas left opcode.
@@ -193,9 +195,12 @@ impl<S: WriteStorage, H: HistoryMode> DynTracer<S, SimpleMemory<H::Vm1_5_0>>
     ) {
         // For now, we support only validations for users.
         if let ValidationTracerMode::UserTxValidation = self.validation_mode {
-            self.computational_gas_used = self
-                .computational_gas_used
-                .saturating_add(computational_gas_price(state, &data));
+            let gas_price = computational_gas_price(state, &data);
+            self.computational_gas_used = self.computational_gas_used.saturating_add(gas_price);
+            self.gas_attribution.charge(
+                state.vm_local_state.callstack.current.this_address,
+                gas_price,
+            );
 
             let validation_round_result =
                 self.check_user_restrictions_vm_latest(state, data, memory, storage);
@@ -245,7 +250,7 @@ impl<S: WriteStorage, H: HistoryMode> VmTracer<S, H::Vm1_5_0> for ValidationTrac
         }
         if let Some(result) = self.result.get() {
             return TracerExecutionStatus::Stop(TracerExecutionStopReason::Abort(
-                Halt::TracerCustom(format!("Validation error: {:#?}", result)),
+                Halt::TracerCustom(format!("Validation error: {result}")),
             ));
         }
         TracerExecutionStatus::Continue
@@ -610,6 +610,11 @@ mod rollbacks {
     fn rollback_in_call_mode() {
         test_rollback_in_call_mode::<super::ShadowedFastVm>();
     }
+
+    #[test]
+    fn speculative_execution() {
+        test_speculative_execution::<super::ShadowedFastVm>();
+    }
 }
 
 mod secp256r1 {
@@ -1,6 +1,8 @@
 use assert_matches::assert_matches;
 use zksync_test_contracts::{Account, TestContract};
-use zksync_types::{address_to_h256, fee::Fee, AccountTreeId, Address, StorageKey, H256};
+use zksync_types::{
+    address_to_h256, fee::Fee, AccountTreeId, Address, StorageKey, H256, L2_BASE_TOKEN_ADDRESS,
+};
 
 use super::{
     default_system_env, get_empty_storage, require_eip712::make_aa_transaction,
@@ -40,7 +42,8 @@ pub(crate) fn test_account_validation_rules<VM: TestedVm + TestedVmForValidation
     );
     assert_matches!(
         violated_rule,
-        Some(ViolatedValidationRule::TouchedDisallowedStorageSlots(_, _))
+        Some(ViolatedValidationRule::TouchedDisallowedStorageSlots(address, _))
+            if address == L2_BASE_TOKEN_ADDRESS
     );
 
     let (result, violated_rule) = test_rule::<VM>(u32::MAX, TestCase::CallEoa);
@@ -52,7 +55,8 @@ pub(crate) fn test_account_validation_rules<VM: TestedVm + TestedVmForValidation
     );
     assert_matches!(
         violated_rule,
-        Some(ViolatedValidationRule::CalledContractWithNoCode(_))
+        Some(ViolatedValidationRule::CalledContractWithNoCode(address))
+            if address == Address::from_low_u64_be(1_234_567_890)
     );
 
     let (result, violated_rule) = test_rule::<VM>(u32::MAX, TestCase::ReadFromTrustedAddressSlot);
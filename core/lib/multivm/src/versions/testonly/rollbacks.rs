@@ -11,7 +11,10 @@ use super::{
     tester::{TransactionTestInfo, TxModifier, VmTesterBuilder},
     ContractToDeploy, TestedVm,
 };
-use crate::interface::{storage::ReadStorage, ExecutionResult, TxExecutionMode, VmInterfaceExt};
+use crate::interface::{
+    storage::ReadStorage, ExecutionResult, SpeculationOutcome, TxExecutionMode,
+    VmExecutionResultAndLogs, VmInterfaceExt, VmInterfaceHistoryEnabledExt,
+};
 
 pub(crate) fn test_vm_rollbacks<VM: TestedVm>() {
     let mut vm = VmTesterBuilder::new()
@@ -209,3 +212,41 @@ pub(crate) fn test_rollback_in_call_mode<VM: TestedVm>() {
         assert_eq!(storage.inner_mut().read_value(&key), value);
     }
 }
+
+pub(crate) fn test_speculative_execution<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .build::<VM>();
+
+    let mut account = vm.rich_accounts[0].clone();
+    let counter = TestContract::counter().bytecode;
+    let tx_0 = account.get_deploy_tx(counter, None, TxType::L2).tx;
+    let tx_1 = account.get_deploy_tx(counter, None, TxType::L2).tx;
+    let is_successful = |result: &VmExecutionResultAndLogs| !result.result.is_failed();
+
+    let result = vm
+        .vm
+        .execute_transaction_speculatively(tx_0.clone(), |_| false);
+    result.compression_result.unwrap();
+    assert!(!result.execution_result.result.is_failed());
+    assert_eq!(result.outcome, SpeculationOutcome::Rollback);
+
+    // The transaction was rolled back, so it can be executed again with the same nonce.
+    let result = vm
+        .vm
+        .execute_transaction_speculatively(tx_0.clone(), is_successful);
+    result.compression_result.unwrap();
+    assert_eq!(result.outcome, SpeculationOutcome::Commit);
+
+    // Now, the nonce is used, so the transaction fails and is rolled back.
+    let result = vm.vm.execute_transaction_speculatively(tx_0, is_successful);
+    assert!(result.execution_result.result.is_failed());
+    assert_eq!(result.outcome, SpeculationOutcome::Rollback);
+
+    let result = vm.vm.execute_transaction_speculatively(tx_1, is_successful);
+    assert_eq!(result.outcome, SpeculationOutcome::Commit);
+
+    let batch_result = vm.vm.finish_batch_without_pubdata();
+    assert!(!batch_result.result.is_failed(), "{batch_result:#?}");
+}
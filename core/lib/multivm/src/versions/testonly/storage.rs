@@ -1,14 +1,16 @@
 use assert_matches::assert_matches;
 use ethabi::Token;
+use proptest::prelude::*;
 use zksync_test_contracts::{LoadnextContractExecutionParams, TestContract, TxType};
-use zksync_types::{AccountTreeId, Address, Execute, StorageKey, H256, U256};
+use zksync_types::{u256_to_h256, AccountTreeId, Address, Execute, Nonce, StorageKey, H256, U256};
 
 use super::{
     get_empty_storage, tester::VmTesterBuilder, ContractToDeploy, TestedVm,
     TestedVmWithStorageLimit, VmTester,
 };
 use crate::interface::{
-    ExecutionResult, Halt, InspectExecutionMode, TxExecutionMode, VmInterfaceExt,
+    ExecutionResult, Halt, InspectExecutionMode, TxExecutionMode, VmExecutionResultAndLogs,
+    VmInterfaceExt,
 };
 
 fn test_storage<VM: TestedVm>(first_tx_calldata: Vec<u8>, second_tx_calldata: Vec<u8>) -> u32 {
@@ -201,3 +203,222 @@ pub(crate) fn test_limiting_storage_reads<VM: TestedVmWithStorageLimit>(should_s
         assert!(!result.result.is_failed(), "{:?}", result.result);
     }
 }
+
+/// Number of distinct storage slots touched by generated storage operations.
+const PROPTEST_SLOT_COUNT: u8 = 4;
+/// Offset of generated slots so that they don't intersect with the slots used by other `StorageTester` methods.
+const PROPTEST_SLOT_OFFSET: u64 = 0x100;
+/// Fees are paid via balance updates; pubdata for these updates depends on the paid fee and may differ
+/// by a byte per balance between transactions doing different amount of work.
+const FEE_PUBDATA_TOLERANCE: u32 = 2;
+
+/// Kind of storage operation performed by `StorageTester.applyOps()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StorageOpKind {
+    Write = 0,
+    /// Write in a nested call that is reverted afterward.
+    RevertedWrite = 1,
+    TransientWrite = 2,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StorageOp {
+    kind: StorageOpKind,
+    slot: u8,
+    value: U256,
+}
+
+/// Sequence of storage operations executed in a single transaction together with the initial slot values.
+/// `None` initial value means that the slot was never written to, i.e., the first write to it is an initial write.
+#[derive(Debug, Clone)]
+pub(crate) struct StorageScenario {
+    initial_values: Vec<Option<U256>>,
+    ops: Vec<StorageOp>,
+}
+
+impl StorageScenario {
+    fn slot_key(slot: u8) -> H256 {
+        H256::from_low_u64_be(PROPTEST_SLOT_OFFSET + u64::from(slot))
+    }
+
+    /// Returns the minimal sequence of operations with the same effect on the persistent storage:
+    /// a single write for each slot with the changed value.
+    fn canonical_ops(&self) -> Vec<StorageOp> {
+        let initial_values: Vec<_> = self
+            .initial_values
+            .iter()
+            .map(|value| value.unwrap_or_default())
+            .collect();
+        let mut final_values = initial_values.clone();
+        for op in &self.ops {
+            if op.kind == StorageOpKind::Write {
+                final_values[usize::from(op.slot)] = op.value;
+            }
+        }
+
+        let changed_slots = initial_values.into_iter().zip(final_values).enumerate();
+        changed_slots
+            .filter(|(_, (initial, last))| initial != last)
+            .map(|(slot, (_, value))| StorageOp {
+                kind: StorageOpKind::Write,
+                slot: slot as u8,
+                value,
+            })
+            .collect()
+    }
+
+    fn calldata(ops: &[StorageOp]) -> Vec<u8> {
+        let kinds = ops
+            .iter()
+            .map(|op| Token::Uint((op.kind as u8).into()))
+            .collect();
+        let slots = ops
+            .iter()
+            .map(|op| Token::Uint(U256::from_big_endian(Self::slot_key(op.slot).as_bytes())))
+            .collect();
+        let values = ops.iter().map(|op| Token::Uint(op.value)).collect();
+        TestContract::storage_test()
+            .function("applyOps")
+            .encode_input(&[
+                Token::Array(kinds),
+                Token::Array(slots),
+                Token::Array(values),
+            ])
+            .unwrap()
+    }
+}
+
+fn storage_value_strategy() -> impl Strategy<Value = U256> {
+    prop_oneof![
+        Just(U256::zero()),
+        Just(U256::one()),
+        Just(U256::MAX >> 4),
+        any::<u64>().prop_map(U256::from),
+        any::<[u8; 32]>().prop_map(|bytes| U256::from_big_endian(&bytes)),
+    ]
+}
+
+pub(crate) fn storage_scenario_strategy() -> impl Strategy<Value = StorageScenario> {
+    let kind = prop_oneof![
+        Just(StorageOpKind::Write),
+        Just(StorageOpKind::RevertedWrite),
+        Just(StorageOpKind::TransientWrite),
+    ];
+    let op = (kind, 0..PROPTEST_SLOT_COUNT, storage_value_strategy())
+        .prop_map(|(kind, slot, value)| StorageOp { kind, slot, value });
+    let initial_values = proptest::collection::vec(
+        proptest::option::of(storage_value_strategy()),
+        usize::from(PROPTEST_SLOT_COUNT),
+    );
+    let ops = proptest::collection::vec(op, 0..12);
+    (initial_values, ops).prop_map(|(initial_values, ops)| StorageScenario {
+        initial_values,
+        ops,
+    })
+}
+
+fn execute_storage_ops<VM: TestedVm>(
+    vm: &mut VmTester<VM>,
+    contract_address: Address,
+    nonce: Nonce,
+    ops: &[StorageOp],
+) -> (u64, VmExecutionResultAndLogs) {
+    let execute = Execute {
+        contract_address: Some(contract_address),
+        calldata: StorageScenario::calldata(ops),
+        value: 0.into(),
+        factory_deps: vec![],
+    };
+    let tx = vm.rich_accounts[0].get_l2_tx_for_execute_with_nonce(execute, None, nonce);
+    let gas_limit = tx.gas_limit().as_u64();
+
+    // All transactions are executed on top of the same state.
+    vm.vm.make_snapshot();
+    vm.vm.push_transaction(tx);
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    vm.vm.rollback_to_the_latest_snapshot();
+    assert!(!result.result.is_failed(), "{:?}", result.result);
+    (gas_limit, result)
+}
+
+/// Checks invariants of pubdata accounting and gas refunds for a generated [`StorageScenario`].
+/// Unlike [`test_storage_behavior()`], doesn't depend on specific pubdata costs.
+pub(crate) fn test_storage_refund_accounting<VM: TestedVm>(scenario: &StorageScenario) {
+    let test_contract_address = Address::repeat_byte(1);
+    let mut storage = get_empty_storage();
+    for (slot, value) in scenario.initial_values.iter().enumerate() {
+        if let Some(value) = value {
+            let key = StorageKey::new(
+                AccountTreeId::new(test_contract_address),
+                StorageScenario::slot_key(slot as u8),
+            );
+            storage.set_value(key, u256_to_h256(*value));
+        }
+    }
+
+    let mut vm = VmTesterBuilder::new()
+        .with_storage(storage)
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .with_custom_contracts(vec![ContractToDeploy::new(
+            TestContract::storage_test().bytecode.to_vec(),
+            test_contract_address,
+        )])
+        .build::<VM>();
+
+    // Execute a transaction first so that fee-related writes in the tested transactions are repeated.
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_l2_tx_for_execute(
+        Execute {
+            contract_address: Some(test_contract_address),
+            calldata: vec![],
+            value: 0.into(),
+            factory_deps: vec![],
+        },
+        None,
+    );
+    let nonce = account.nonce;
+    vm.vm.push_transaction(tx);
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!result.result.is_failed(), "First tx failed");
+
+    let canonical_ops = scenario.canonical_ops();
+    let results = [&[][..], scenario.ops.as_slice(), canonical_ops.as_slice()]
+        .map(|ops| execute_storage_ops(&mut vm, test_contract_address, nonce, ops));
+    let [(_, baseline), (_, result), (_, canonical)] = &results;
+
+    // Reverted and transient writes, as well as intermediate values of repeated writes, are not published.
+    let pubdata = result.statistics.pubdata_published;
+    let canonical_pubdata = canonical.statistics.pubdata_published;
+    assert!(
+        canonical_pubdata <= pubdata && pubdata <= canonical_pubdata + FEE_PUBDATA_TOLERANCE,
+        "pubdata: {pubdata}, canonical pubdata: {canonical_pubdata}"
+    );
+    let baseline_pubdata = baseline.statistics.pubdata_published;
+    if canonical_ops.is_empty() {
+        assert!(
+            baseline_pubdata <= pubdata && pubdata <= baseline_pubdata + FEE_PUBDATA_TOLERANCE,
+            "pubdata: {pubdata}, baseline pubdata: {baseline_pubdata}"
+        );
+    } else {
+        assert!(
+            canonical_pubdata > baseline_pubdata,
+            "canonical pubdata: {canonical_pubdata}, baseline pubdata: {baseline_pubdata}"
+        );
+    }
+
+    for (gas_limit, result) in &results {
+        let refunds = &result.refunds;
+        assert!(refunds.gas_refunded <= *gas_limit, "{refunds:?}");
+        // The bootloader can only increase the refund suggested by the operator.
+        assert!(
+            refunds.gas_refunded >= refunds.operator_suggested_refund,
+            "{refunds:?}"
+        );
+    }
+    let charged_gas = |(gas_limit, result): &(u64, VmExecutionResultAndLogs)| {
+        gas_limit - result.refunds.gas_refunded
+    };
+    // Storage operations can only increase the amount of gas charged.
+    assert!(charged_gas(&results[0]) <= charged_gas(&results[1]));
+}
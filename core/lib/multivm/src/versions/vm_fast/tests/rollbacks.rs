@@ -1,6 +1,7 @@
 use crate::{
     versions::testonly::rollbacks::{
-        test_rollback_in_call_mode, test_vm_loadnext_rollbacks, test_vm_rollbacks,
+        test_rollback_in_call_mode, test_speculative_execution, test_vm_loadnext_rollbacks,
+        test_vm_rollbacks,
     },
     vm_fast::Vm,
 };
@@ -19,3 +20,8 @@ fn vm_loadnext_rollbacks() {
 fn rollback_in_call_mode() {
     test_rollback_in_call_mode::<Vm<_>>();
 }
+
+#[test]
+fn speculative_execution() {
+    test_speculative_execution::<Vm<_>>();
+}
@@ -18,7 +18,7 @@ use crate::{
         },
         Halt,
     },
-    tracers::TIMESTAMP_ASSERTER_FUNCTION_SELECTOR,
+    tracers::{ValidationGasAttribution, TIMESTAMP_ASSERTER_FUNCTION_SELECTOR},
     vm_fast::utils::read_raw_fat_pointer,
 };
 
@@ -110,6 +110,10 @@ pub struct FullValidationTracer {
     timestamp_asserter_params: Option<TimestampAsserterParams>,
     l1_batch_timestamp: u64,
 
+    /// Gas remaining in the current frame before the currently executed instruction.
+    gas_before_instruction: u32,
+    gas_attribution: ValidationGasAttribution,
+
     validation_error: Option<ViolatedValidationRule>,
     traces: ValidationTraces,
 }
@@ -125,9 +129,10 @@ impl ValidationTracer for FullValidationTracer {
     fn validation_exited(&mut self) -> Option<Halt> {
         self.in_validation = false;
         match self.validation_error {
-            Some(ViolatedValidationRule::TookTooManyComputationalGas(_)) => {
-                Some(Halt::ValidationOutOfGas)
-            }
+            Some(
+                ViolatedValidationRule::TookTooManyComputationalGas(_)
+                | ViolatedValidationRule::GasGriefing { .. },
+            ) => Some(Halt::ValidationOutOfGas),
             _ => None,
         }
     }
@@ -139,16 +144,21 @@ impl Tracer for FullValidationTracer {
             return;
         }
 
+        self.gas_before_instruction = state.current_frame().gas();
         match OP::VALUE {
             // FIXME: should this use the same filtering as the fast tracer?
             // Out of gas once means out of gas for the whole validation, as the EIP forbids handling out of gas errors
             Ret(Panic) if state.current_frame().gas() == 0 => {
-                let err =
-                    ViolatedValidationRule::TookTooManyComputationalGas(self.validation_gas_limit);
+                let err = self
+                    .gas_attribution
+                    .out_of_gas_violation(self.user_address, self.validation_gas_limit);
                 self.set_error(err);
             }
 
-            ContextMeta => self.set_error(ViolatedValidationRule::TouchedDisallowedContext),
+            ContextMeta => {
+                let address = state.current_frame().address();
+                self.set_error(ViolatedValidationRule::TouchedDisallowedContext(address));
+            }
 
             StorageRead => {
                 let address = state.current_frame().address();
@@ -192,6 +202,16 @@ impl Tracer for FullValidationTracer {
             return ShouldStop::Continue;
         }
 
+        // Calls and returns switch the current frame, so the gas difference for them isn't meaningful. This also
+        // excludes gas passed to the callees, which is attributed to the instructions executed by them.
+        if !matches!(OP::VALUE, FarCall(_) | NearCall | Ret(_)) {
+            let gas_used = self
+                .gas_before_instruction
+                .saturating_sub(state.current_frame().gas());
+            self.gas_attribution
+                .charge(state.current_frame().address(), gas_used);
+        }
+
         if self.validation_error.is_some() {
             return ShouldStop::Stop;
         }
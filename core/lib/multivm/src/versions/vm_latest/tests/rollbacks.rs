@@ -14,7 +14,10 @@ use crate::{
     },
     tracers::dynamic::vm_1_5_0::DynTracer,
     versions::testonly::{
-        rollbacks::{test_rollback_in_call_mode, test_vm_loadnext_rollbacks, test_vm_rollbacks},
+        rollbacks::{
+            test_rollback_in_call_mode, test_speculative_execution, test_vm_loadnext_rollbacks,
+            test_vm_rollbacks,
+        },
         VmTesterBuilder,
     },
     vm_latest::{
@@ -33,6 +36,11 @@ fn vm_loadnext_rollbacks() {
     test_vm_loadnext_rollbacks::<Vm<_, HistoryEnabled>>();
 }
 
+#[test]
+fn speculative_execution() {
+    test_speculative_execution::<Vm<_, HistoryEnabled>>();
+}
+
 // Testing tracer that does not allow the recursion to go deeper than a certain limit
 struct MaxRecursionTracer {
     max_recursion_depth: usize,
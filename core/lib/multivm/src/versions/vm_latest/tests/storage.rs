@@ -1,7 +1,9 @@
+use proptest::prelude::*;
+
 use crate::{
     versions::testonly::storage::{
-        test_limiting_storage_reads, test_limiting_storage_writes, test_storage_behavior,
-        test_transient_storage_behavior,
+        storage_scenario_strategy, test_limiting_storage_reads, test_limiting_storage_writes,
+        test_storage_behavior, test_storage_refund_accounting, test_transient_storage_behavior,
     },
     vm_latest::{HistoryEnabled, Vm},
 };
@@ -31,3 +33,13 @@ fn limiting_storage_reads() {
     println!("Storage limiter check");
     test_limiting_storage_reads::<Vm<_, HistoryEnabled>>(true);
 }
+
+proptest! {
+    // Each case spins up a VM, so the number of cases is kept low.
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn storage_refund_accounting(scenario in storage_scenario_strategy()) {
+        test_storage_refund_accounting::<Vm<_, HistoryEnabled>>(&scenario);
+    }
+}
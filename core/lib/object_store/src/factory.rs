@@ -11,6 +11,7 @@ use crate::{
     raw::{ObjectStore, ObjectStoreError},
     retries::StoreWithRetries,
     s3::{S3Store, S3StoreAuthMode},
+    versioned::{WithWriteFormat, WriteFormat},
 };
 
 /// Factory of [`ObjectStore`]s that caches the store instance once it's created. Used mainly for legacy reasons.
@@ -53,13 +54,25 @@ impl ObjectStoreFactory {
             .cloned()
     }
 
-    /// Creates an [`ObjectStore`] based on the provided `config`.
+    /// Creates an [`ObjectStore`] based on the provided `config`. If the config enables writing
+    /// [versioned envelopes](crate::versioned), the store writes versioned objects in envelopes.
     ///
     /// # Errors
     ///
     /// Returns an error if store initialization fails (e.g., because of incorrect configuration).
     async fn create_from_config(
         config: &ObjectStoreConfig,
+    ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
+        let store = Self::create_raw_from_config(config).await?;
+        Ok(if config.write_versioned_envelopes {
+            Arc::new(WithWriteFormat::new(store, WriteFormat::Envelope))
+        } else {
+            store
+        })
+    }
+
+    async fn create_raw_from_config(
+        config: &ObjectStoreConfig,
     ) -> Result<Arc<dyn ObjectStore>, ObjectStoreError> {
         tracing::trace!("Initializing object store with configuration {config:?}");
         match &config.mode {
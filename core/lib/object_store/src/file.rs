@@ -43,6 +43,7 @@ impl FileBackedObjectStore {
             Bucket::ProofsFri,
             Bucket::StorageSnapshot,
             Bucket::VmDumps,
+            Bucket::ConsensusBlocks,
         ] {
             let bucket_path = format!("{base_dir}/{bucket}");
             fs::create_dir_all(&bucket_path).await?;
@@ -77,6 +78,31 @@ impl ObjectStore for FileBackedObjectStore {
         fs::remove_file(filename).await.map_err(From::from)
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        let mut entries = match fs::read_dir(format!("{}/{bucket}", self.base_dir)).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err.into()),
+        };
+        let mut keys = vec![];
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            if let Some(key) = entry.file_name().to_str() {
+                if key.starts_with(prefix) {
+                    keys.push(key.to_owned());
+                }
+            }
+        }
+        keys.sort_unstable();
+        Ok(keys)
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!("{}/{}", self.base_dir, bucket)
     }
@@ -131,4 +157,28 @@ mod test {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_list() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.into_path().into_os_string().into_string().unwrap();
+        let object_store = FileBackedObjectStore::new(path).await.unwrap();
+        for key in ["test-key-1.bin", "test-key-0.bin", "other-key.bin"] {
+            object_store
+                .put_raw(Bucket::ProverJobs, key, vec![0, 1])
+                .await
+                .unwrap();
+        }
+
+        let keys = object_store
+            .list_raw(Bucket::ProverJobs, "test-key")
+            .await
+            .unwrap();
+        assert_eq!(keys, ["test-key-0.bin", "test-key-1.bin"]);
+        let keys = object_store
+            .list_raw(Bucket::WitnessInput, "test-key")
+            .await
+            .unwrap();
+        assert!(keys.is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 //! GCS-based [`ObjectStore`] implementation.
 
-use std::{error::Error as StdError, fmt, io};
+use std::{error::Error as StdError, fmt, io, time::Duration};
 
 use async_trait::async_trait;
 use google_cloud_auth::{credentials::CredentialsFile, error::Error as AuthError};
@@ -11,10 +11,12 @@ use google_cloud_storage::{
             delete::DeleteObjectRequest,
             download::Range,
             get::GetObjectRequest,
+            list::ListObjectsRequest,
             upload::{Media, UploadObjectRequest, UploadType},
         },
         Error as HttpError,
     },
+    sign::{SignedURLMethod, SignedURLOptions},
 };
 use http::StatusCode;
 use tokio::sync::{AcquireError, Semaphore};
@@ -248,6 +250,72 @@ impl ObjectStore for GoogleCloudStore {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        let bucket_dir = Self::filename(bucket.as_str(), "");
+        let full_prefix = Self::filename(bucket.as_str(), prefix);
+        tracing::trace!(
+            "Listing GCS keys with prefix {full_prefix} from bucket {}",
+            self.bucket_prefix
+        );
+
+        let mut keys = vec![];
+        let mut page_token = None;
+        loop {
+            let _permit = self.semaphore.acquire().await?;
+            let request = ListObjectsRequest {
+                bucket: self.bucket_prefix.clone(),
+                prefix: Some(full_prefix.clone()),
+                page_token: page_token.take(),
+                ..ListObjectsRequest::default()
+            };
+            let response = self.client.list_objects(&request).await?;
+            let objects = response.items.unwrap_or_default();
+            keys.extend(
+                objects
+                    .into_iter()
+                    .filter_map(|object| object.name.strip_prefix(&bucket_dir).map(str::to_owned)),
+            );
+
+            page_token = response.next_page_token;
+            if page_token.is_none() {
+                break;
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        let filename = Self::filename(bucket.as_str(), key);
+        tracing::trace!(
+            "Signing GCS URL for key {filename} from bucket {} expiring in {expires_in:?}",
+            self.bucket_prefix
+        );
+
+        let options = SignedURLOptions {
+            method: SignedURLMethod::GET,
+            expires: expires_in,
+            ..SignedURLOptions::default()
+        };
+        // Signing is performed locally (or via the IAM API for ambient credentials) and does not count
+        // towards the request limit for GCS.
+        self.client
+            .signed_url(&self.bucket_prefix, &filename, None, None, options)
+            .await
+            .map_err(|err| ObjectStoreError::Other {
+                source: err.into(),
+                is_retriable: false,
+            })
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!(
             "https://storage.googleapis.com/{}/{}",
@@ -33,11 +33,12 @@ mod objects;
 mod raw;
 mod retries;
 mod s3;
+pub mod versioned;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
 pub use bincode;
 
-#[doc(hidden)] // used by the `serialize_using_bincode!` macro
+#[doc(hidden)] // used by the `serialize_using_bincode!` and `serialize_using_versioned_envelope!` macros
 pub mod _reexports {
     pub use crate::raw::BoxedError;
 }
@@ -1,8 +1,12 @@
 //! Mirroring object store.
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 
-use crate::{file::FileBackedObjectStore, raw::ObjectStore, Bucket, ObjectStoreError};
+use crate::{
+    file::FileBackedObjectStore, raw::ObjectStore, versioned::WriteFormat, Bucket, ObjectStoreError,
+};
 
 #[derive(Debug)]
 pub(crate) struct MirroringObjectStore<S> {
@@ -85,9 +89,32 @@ impl<S: ObjectStore> ObjectStore for MirroringObjectStore<S> {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        // The mirror may be incomplete, so the underlying store is the only source of truth
+        self.inner.list_raw(bucket, prefix).await
+    }
+
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        // URLs are handed out to third parties, so they must point to the underlying store rather than the local mirror
+        self.inner.signed_url_raw(bucket, key, expires_in).await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
+
+    fn write_format(&self) -> WriteFormat {
+        self.inner.write_format()
+    }
 }
 
 #[cfg(test)]
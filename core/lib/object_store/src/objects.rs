@@ -1,6 +1,9 @@
 //! Stored objects.
 
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Write},
+    time::Duration,
+};
 
 use anyhow::Context;
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
@@ -13,7 +16,10 @@ use zksync_types::{
     L1BatchNumber,
 };
 
-use crate::raw::{BoxedError, Bucket, ObjectStore, ObjectStoreError};
+use crate::{
+    raw::{BoxedError, Bucket, ObjectStore, ObjectStoreError},
+    versioned::WriteFormat,
+};
 
 /// Object that can be stored in an [`ObjectStore`].
 pub trait StoredObject: Sized {
@@ -33,6 +39,16 @@ pub trait StoredObject: Sized {
     /// Returns an error if serialization fails.
     fn serialize(&self) -> Result<Vec<u8>, BoxedError>;
 
+    /// Serializes a value to a blob in the specified format. Only objects with a [versioned wire format](crate::versioned)
+    /// distinguish between formats; the default implementation delegates to [`Self::serialize()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    fn serialize_in_format(&self, _format: WriteFormat) -> Result<Vec<u8>, BoxedError> {
+        self.serialize()
+    }
+
     /// Deserializes a value from the blob.
     ///
     /// # Errors
@@ -178,7 +194,9 @@ impl dyn ObjectStore + '_ {
         let key = V::encode_key(key);
         // Record the key for tracing.
         tracing::Span::current().record("key", key.as_str());
-        let bytes = value.serialize().map_err(ObjectStoreError::Serialization)?;
+        let bytes = value
+            .serialize_in_format(self.write_format())
+            .map_err(ObjectStoreError::Serialization)?;
         self.put_raw(V::BUCKET, &key, bytes).await?;
         Ok(key)
     }
@@ -200,6 +218,21 @@ impl dyn ObjectStore + '_ {
         self.remove_raw(V::BUCKET, &key).await
     }
 
+    /// Generates a URL allowing to download the value for the given key without credentials.
+    /// The URL expires after `expires_in`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails or is not supported by the store.
+    pub async fn signed_url<V: StoredObject>(
+        &self,
+        key: V::Key<'_>,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        let key = V::encode_key(key);
+        self.signed_url_raw(V::BUCKET, &key, expires_in).await
+    }
+
     pub fn get_storage_prefix<V: StoredObject>(&self) -> String {
         self.storage_prefix_raw(V::BUCKET)
     }
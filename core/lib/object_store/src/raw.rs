@@ -1,7 +1,9 @@
-use std::{error, fmt};
+use std::{error, fmt, time::Duration};
 
 use async_trait::async_trait;
 
+use crate::versioned::WriteFormat;
+
 /// Bucket for [`ObjectStore`] in which objects can be placed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -20,6 +22,7 @@ pub enum Bucket {
     StorageSnapshot,
     DataAvailability,
     VmDumps,
+    ConsensusBlocks,
 }
 
 impl Bucket {
@@ -39,6 +42,7 @@ impl Bucket {
             Self::StorageSnapshot => "storage_logs_snapshots",
             Self::DataAvailability => "data_availability",
             Self::VmDumps => "vm_dumps",
+            Self::ConsensusBlocks => "consensus_blocks",
         }
     }
 }
@@ -157,5 +161,53 @@ pub trait ObjectStore: 'static + fmt::Debug + Send + Sync {
     /// Returns an error if removal fails.
     async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError>;
 
+    /// Lists keys in the given bucket that start with the specified prefix. Keys are returned in the same form
+    /// as accepted by other methods (i.e., without the bucket part).
+    ///
+    /// The default implementation returns an error; stores that support listing should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing fails or is not supported by the store.
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        Err(ObjectStoreError::Other {
+            is_retriable: false,
+            source: format!("listing keys with prefix `{prefix}` in bucket `{bucket}` is not supported by {self:?}").into(),
+        })
+    }
+
+    /// Generates a URL allowing to download the object with the given key from the given bucket without credentials.
+    /// The URL expires after `expires_in`. The object is not required to exist when the URL is generated.
+    ///
+    /// The default implementation returns an error; stores that support signed URLs (e.g., GCS or S3) should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if signing fails (e.g., the store lacks credentials to sign URLs) or is not supported by the store.
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        _expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        Err(ObjectStoreError::Other {
+            is_retriable: false,
+            source: format!(
+                "signing URL for key `{key}` in bucket `{bucket}` is not supported by {self:?}"
+            )
+            .into(),
+        })
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String;
+
+    /// Returns the format used to write objects with a [versioned wire format](crate::versioned).
+    /// The default implementation returns the unversioned format.
+    fn write_format(&self) -> WriteFormat {
+        WriteFormat::default()
+    }
 }
@@ -6,6 +6,7 @@ use rand::Rng;
 use crate::{
     metrics::OBJECT_STORE_METRICS,
     raw::{Bucket, ObjectStore, ObjectStoreError},
+    versioned::WriteFormat,
 };
 
 /// Information about request added to logs.
@@ -16,6 +17,8 @@ enum Request<'a> {
     Get(Bucket, &'a str),
     Put(Bucket, &'a str),
     Remove(Bucket, &'a str),
+    List(Bucket, &'a str),
+    SignUrl(Bucket, &'a str),
 }
 
 impl Request<'_> {
@@ -126,9 +129,38 @@ impl<S: ObjectStore> ObjectStore for StoreWithRetries<S> {
             .await
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        Request::List(bucket, prefix)
+            .retry(&self.inner, self.max_retries, || {
+                self.inner.list_raw(bucket, prefix)
+            })
+            .await
+    }
+
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        Request::SignUrl(bucket, key)
+            .retry(&self.inner, self.max_retries, || {
+                self.inner.signed_url_raw(bucket, key, expires_in)
+            })
+            .await
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         self.inner.storage_prefix_raw(bucket)
     }
+
+    fn write_format(&self) -> WriteFormat {
+        self.inner.write_format()
+    }
 }
 
 #[cfg(test)]
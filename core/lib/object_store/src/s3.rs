@@ -1,12 +1,14 @@
 //! S3-based [`ObjectStore`] implementation.
 
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use anyhow::Context;
 use async_trait::async_trait;
 use aws_config::{meta::region::RegionProviderChain, BehaviorVersion, ConfigLoader, Region};
 use aws_runtime::env_config::file::{EnvConfigFileKind, EnvConfigFiles};
-use aws_sdk_s3::{error::SdkError, primitives::ByteStreamError, Client};
+use aws_sdk_s3::{
+    error::SdkError, presigning::PresigningConfig, primitives::ByteStreamError, Client,
+};
 use http::StatusCode;
 
 use crate::raw::{Bucket, ObjectStore, ObjectStoreError};
@@ -215,6 +217,69 @@ impl ObjectStore for S3Store {
         Ok(())
     }
 
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        let bucket_dir = Self::filename(bucket.as_str(), "");
+        let full_prefix = Self::filename(bucket.as_str(), prefix);
+        tracing::trace!(
+            "Listing S3 keys with prefix {full_prefix} from bucket {}",
+            self.bucket_prefix
+        );
+
+        let mut keys = vec![];
+        let mut continuation_token = None;
+        loop {
+            let output = self
+                .client
+                .list_objects_v2()
+                .bucket(self.bucket_prefix.clone())
+                .prefix(full_prefix.clone())
+                .set_continuation_token(continuation_token.take())
+                .send()
+                .await?;
+            keys.extend(output.contents().iter().filter_map(|object| {
+                let key = object.key()?.strip_prefix(&bucket_dir)?;
+                Some(key.to_owned())
+            }));
+
+            continuation_token = output.next_continuation_token().map(str::to_owned);
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        let filename = Self::filename(bucket.as_str(), key);
+        tracing::trace!(
+            "Presigning S3 URL for key {filename} from bucket {} expiring in {expires_in:?}",
+            self.bucket_prefix
+        );
+
+        let presigning_config =
+            PresigningConfig::expires_in(expires_in).map_err(|err| ObjectStoreError::Other {
+                source: err.into(),
+                is_retriable: false,
+            })?;
+        let request = self
+            .client
+            .get_object()
+            .bucket(self.bucket_prefix.clone())
+            .key(filename)
+            .presigned(presigning_config)
+            .await?;
+        Ok(request.uri().to_owned())
+    }
+
     fn storage_prefix_raw(&self, bucket: Bucket) -> String {
         format!(
             "{}/{}/{}",
@@ -0,0 +1,392 @@
+//! Versioned wire format for stored objects.
+//!
+//! Objects exchanged between independently deployed components (e.g., the core and provers) cannot rely
+//! on a bare `bincode` encoding: any change to the object fields makes it undecodable by the other side.
+//! Instead, such objects can be wrapped into an envelope consisting of [`ENVELOPE_MAGIC`], a little-endian
+//! `u16` version tag and the payload for this version. Readers dispatch on the version tag to the corresponding
+//! decoder, so a field change amounts to introducing a new version while keeping decoders for the older ones.
+//!
+//! Objects written before envelopes were introduced don't have the magic prefix; they are decoded
+//! with [`VersionedObject::decode_unversioned()`].
+//!
+//! Readers support both formats, but objects are written in the [`WriteFormat`] of the object store, which is
+//! unversioned unless envelope writes are enabled in the object store config. This allows to upgrade all readers
+//! before any envelopes are written, so that components not supporting envelopes can still read new objects.
+
+use std::{error, fmt, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+use crate::raw::{BoxedError, Bucket, ObjectStore, ObjectStoreError};
+
+/// Magic bytes starting each versioned envelope.
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"zkVO";
+
+const HEADER_LEN: usize = ENVELOPE_MAGIC.len() + 2;
+
+/// Format used to write objects with a versioned wire format. Returned by [`ObjectStore::write_format()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WriteFormat {
+    /// Format used before versioned envelopes were introduced.
+    #[default]
+    Unversioned,
+    /// Versioned envelope.
+    Envelope,
+}
+
+/// Error returned by [`VersionedObject::decode()`] for unsupported versions, e.g. if the object was written
+/// by a newer component.
+#[derive(Debug)]
+pub struct UnsupportedVersion {
+    pub object: &'static str,
+    pub version: u16,
+    pub max_supported_version: u16,
+}
+
+impl fmt::Display for UnsupportedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "unsupported {} version {} (max supported version is {}); is this component outdated?",
+            self.object, self.version, self.max_supported_version
+        )
+    }
+}
+
+impl error::Error for UnsupportedVersion {}
+
+/// Object with an explicitly versioned wire format.
+pub trait VersionedObject: Sized {
+    /// Version of the payload produced by [`Self::encode()`].
+    const CURRENT_VERSION: u16;
+
+    /// Encodes the payload of [`Self::CURRENT_VERSION`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    fn encode(&self) -> Result<Vec<u8>, BoxedError>;
+
+    /// Decodes the payload of the specified version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed, or [`UnsupportedVersion`] if the version is not supported.
+    fn decode(version: u16, payload: &[u8]) -> Result<Self, BoxedError>;
+
+    /// Encodes an object in the format used before versioned envelopes were introduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    fn encode_unversioned(&self) -> Result<Vec<u8>, BoxedError>;
+
+    /// Decodes an object serialized before versioned envelopes were introduced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are malformed.
+    fn decode_unversioned(bytes: &[u8]) -> Result<Self, BoxedError>;
+
+    /// Returns an [`UnsupportedVersion`] error for the specified version.
+    fn unsupported_version(version: u16) -> BoxedError {
+        Box::new(UnsupportedVersion {
+            object: std::any::type_name::<Self>(),
+            version,
+            max_supported_version: Self::CURRENT_VERSION,
+        })
+    }
+}
+
+/// Serializes an object into a versioned envelope.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_versioned<T: VersionedObject>(object: &T) -> Result<Vec<u8>, BoxedError> {
+    let payload = object.encode()?;
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&ENVELOPE_MAGIC);
+    bytes.extend_from_slice(&T::CURRENT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&payload);
+    Ok(bytes)
+}
+
+/// Serializes an object in the specified format.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn serialize_in_format<T: VersionedObject>(
+    object: &T,
+    format: WriteFormat,
+) -> Result<Vec<u8>, BoxedError> {
+    match format {
+        WriteFormat::Unversioned => object.encode_unversioned(),
+        WriteFormat::Envelope => serialize_versioned(object),
+    }
+}
+
+/// Deserializes an object from a versioned envelope, falling back to the unversioned format if the envelope is absent.
+///
+/// # Errors
+///
+/// Returns an error if deserialization fails.
+pub fn deserialize_versioned<T: VersionedObject>(bytes: &[u8]) -> Result<T, BoxedError> {
+    let Some(header) = bytes.get(..HEADER_LEN) else {
+        return T::decode_unversioned(bytes);
+    };
+    if header[..ENVELOPE_MAGIC.len()] != ENVELOPE_MAGIC {
+        return T::decode_unversioned(bytes);
+    }
+
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    T::decode(version, &bytes[HEADER_LEN..]).or_else(|err| {
+        // An unversioned object may start with the magic bytes by coincidence.
+        T::decode_unversioned(bytes).map_err(|_| err)
+    })
+}
+
+/// Derives [`StoredObject::serialize()`], [`StoredObject::serialize_in_format()`] and [`StoredObject::deserialize()`]
+/// using the [versioned envelope](crate::versioned) defined by the [`VersionedObject`] implementation.
+/// [`StoredObject::serialize()`] uses the unversioned format. Should be used in `impl StoredObject` blocks.
+///
+/// [`StoredObject::serialize()`]: crate::StoredObject::serialize()
+/// [`StoredObject::serialize_in_format()`]: crate::StoredObject::serialize_in_format()
+/// [`StoredObject::deserialize()`]: crate::StoredObject::deserialize()
+#[macro_export]
+macro_rules! serialize_using_versioned_envelope {
+    () => {
+        fn serialize(
+            &self,
+        ) -> std::result::Result<std::vec::Vec<u8>, $crate::_reexports::BoxedError> {
+            $crate::versioned::serialize_in_format(
+                self,
+                $crate::versioned::WriteFormat::Unversioned,
+            )
+        }
+
+        fn serialize_in_format(
+            &self,
+            format: $crate::versioned::WriteFormat,
+        ) -> std::result::Result<std::vec::Vec<u8>, $crate::_reexports::BoxedError> {
+            $crate::versioned::serialize_in_format(self, format)
+        }
+
+        fn deserialize(
+            bytes: std::vec::Vec<u8>,
+        ) -> std::result::Result<Self, $crate::_reexports::BoxedError> {
+            $crate::versioned::deserialize_versioned(&bytes)
+        }
+    };
+}
+
+/// Object store decorator overriding the [`WriteFormat`] of the wrapped store.
+#[derive(Debug)]
+pub(crate) struct WithWriteFormat {
+    inner: Arc<dyn ObjectStore>,
+    format: WriteFormat,
+}
+
+impl WithWriteFormat {
+    pub fn new(inner: Arc<dyn ObjectStore>, format: WriteFormat) -> Self {
+        Self { inner, format }
+    }
+}
+
+#[async_trait]
+impl ObjectStore for WithWriteFormat {
+    async fn get_raw(&self, bucket: Bucket, key: &str) -> Result<Vec<u8>, ObjectStoreError> {
+        self.inner.get_raw(bucket, key).await
+    }
+
+    async fn put_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        value: Vec<u8>,
+    ) -> Result<(), ObjectStoreError> {
+        self.inner.put_raw(bucket, key, value).await
+    }
+
+    async fn remove_raw(&self, bucket: Bucket, key: &str) -> Result<(), ObjectStoreError> {
+        self.inner.remove_raw(bucket, key).await
+    }
+
+    async fn list_raw(
+        &self,
+        bucket: Bucket,
+        prefix: &str,
+    ) -> Result<Vec<String>, ObjectStoreError> {
+        self.inner.list_raw(bucket, prefix).await
+    }
+
+    async fn signed_url_raw(
+        &self,
+        bucket: Bucket,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<String, ObjectStoreError> {
+        self.inner.signed_url_raw(bucket, key, expires_in).await
+    }
+
+    fn storage_prefix_raw(&self, bucket: Bucket) -> String {
+        self.inner.storage_prefix_raw(bucket)
+    }
+
+    fn write_format(&self) -> WriteFormat {
+        self.format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{MockObjectStore, StoredObject};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TestObjectV0 {
+        value: u32,
+        name: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct TestObject {
+        value: u32,
+        name: String,
+        note: Option<String>,
+    }
+
+    impl VersionedObject for TestObject {
+        const CURRENT_VERSION: u16 = 1;
+
+        fn encode(&self) -> Result<Vec<u8>, BoxedError> {
+            bincode::serialize(self).map_err(Into::into)
+        }
+
+        fn decode(version: u16, payload: &[u8]) -> Result<Self, BoxedError> {
+            match version {
+                1 => bincode::deserialize(payload).map_err(Into::into),
+                _ => Err(Self::unsupported_version(version)),
+            }
+        }
+
+        fn encode_unversioned(&self) -> Result<Vec<u8>, BoxedError> {
+            let legacy_object = TestObjectV0 {
+                value: self.value,
+                name: self.name.clone(),
+            };
+            bincode::serialize(&legacy_object).map_err(Into::into)
+        }
+
+        fn decode_unversioned(bytes: &[u8]) -> Result<Self, BoxedError> {
+            let TestObjectV0 { value, name } = bincode::deserialize(bytes)?;
+            Ok(Self {
+                value,
+                name,
+                note: None,
+            })
+        }
+    }
+
+    #[test]
+    fn versioned_roundtrip() {
+        let object = TestObject {
+            value: 42,
+            name: "object".into(),
+            note: Some("test".into()),
+        };
+        let bytes = serialize_versioned(&object).unwrap();
+        assert_eq!(bytes[..4], ENVELOPE_MAGIC);
+        assert_eq!(bytes[4..6], [1, 0]);
+        let restored: TestObject = deserialize_versioned(&bytes).unwrap();
+        assert_eq!(restored, object);
+    }
+
+    #[test]
+    fn deserializing_unversioned_object() {
+        let legacy_object = TestObjectV0 {
+            value: 23,
+            name: "legacy".into(),
+        };
+        let bytes = bincode::serialize(&legacy_object).unwrap();
+        let restored: TestObject = deserialize_versioned(&bytes).unwrap();
+        assert_eq!(
+            restored,
+            TestObject {
+                value: 23,
+                name: "legacy".into(),
+                note: None
+            }
+        );
+    }
+
+    #[test]
+    fn unversioned_format_is_used_by_default() {
+        let object = TestObject {
+            value: 42,
+            name: "object".into(),
+            note: None,
+        };
+        let bytes = serialize_in_format(&object, WriteFormat::Unversioned).unwrap();
+        assert_ne!(bytes[..4], ENVELOPE_MAGIC);
+        let legacy_object: TestObjectV0 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(legacy_object.value, 42);
+        let restored: TestObject = deserialize_versioned(&bytes).unwrap();
+        assert_eq!(restored, object);
+
+        let bytes = serialize_in_format(&object, WriteFormat::Envelope).unwrap();
+        assert_eq!(bytes[..4], ENVELOPE_MAGIC);
+        let restored: TestObject = deserialize_versioned(&bytes).unwrap();
+        assert_eq!(restored, object);
+    }
+
+    impl StoredObject for TestObject {
+        const BUCKET: Bucket = Bucket::ProofsFri;
+        type Key<'a> = u32;
+
+        fn encode_key(key: Self::Key<'_>) -> String {
+            format!("test_object_{key}.bin")
+        }
+
+        crate::serialize_using_versioned_envelope!();
+    }
+
+    #[tokio::test]
+    async fn store_write_format_is_respected() {
+        let object = TestObject {
+            value: 42,
+            name: "object".into(),
+            note: None,
+        };
+
+        let store = MockObjectStore::arc();
+        assert_eq!(store.write_format(), WriteFormat::Unversioned);
+        let key = store.put(1, &object).await.unwrap();
+        let bytes = store.get_raw(TestObject::BUCKET, &key).await.unwrap();
+        assert_ne!(bytes[..4], ENVELOPE_MAGIC);
+        assert_eq!(store.get::<TestObject>(1).await.unwrap(), object);
+
+        let store: Arc<dyn ObjectStore> = Arc::new(WithWriteFormat::new(
+            MockObjectStore::arc(),
+            WriteFormat::Envelope,
+        ));
+        let key = store.put(1, &object).await.unwrap();
+        let bytes = store.get_raw(TestObject::BUCKET, &key).await.unwrap();
+        assert_eq!(bytes[..4], ENVELOPE_MAGIC);
+        assert_eq!(store.get::<TestObject>(1).await.unwrap(), object);
+    }
+
+    #[test]
+    fn unsupported_version_error() {
+        let mut bytes = ENVELOPE_MAGIC.to_vec();
+        bytes.extend_from_slice(&2_u16.to_le_bytes());
+
+        let err = deserialize_versioned::<TestObject>(&bytes).unwrap_err();
+        let err = err.downcast_ref::<UnsupportedVersion>().unwrap();
+        assert_eq!(err.version, 2);
+        assert_eq!(err.max_supported_version, 1);
+    }
+}
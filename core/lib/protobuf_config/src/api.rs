@@ -61,6 +61,11 @@ impl ProtoRepr for proto::Web3JsonRpc {
         } else {
             Some(self.api_namespaces.clone())
         };
+        let disabled_api_namespaces = if self.disabled_api_namespaces.is_empty() {
+            None
+        } else {
+            Some(self.disabled_api_namespaces.clone())
+        };
         Ok(Self::Type {
             http_port: required(&self.http_port)
                 .and_then(|p| Ok((*p).try_into()?))
@@ -151,6 +156,34 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .context("whitelisted_tokens_for_aa")?,
             extended_api_tracing: self.extended_api_tracing.unwrap_or_default(),
             api_namespaces,
+            disabled_api_namespaces,
+            pruned_calls_snapshot_chunks_budget: self
+                .pruned_calls_snapshot_chunks_budget
+                .map(|x| x.try_into())
+                .transpose()
+                .context("pruned_calls_snapshot_chunks_budget")?,
+            max_trace_size_bytes: self
+                .max_trace_size_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_trace_size_bytes")?,
+            websocket_max_batch_request_size: self
+                .websocket_max_batch_request_size
+                .map(|x| x.try_into())
+                .transpose()
+                .context("websocket_max_batch_request_size")?,
+            snapshots_download_url_ttl_sec: self.snapshots_download_url_ttl_sec,
+            snapshots_requests_per_minute_limit: self
+                .snapshots_requests_per_minute_limit
+                .map(|x| x.try_into())
+                .transpose()
+                .context("snapshots_requests_per_minute_limit")?,
+            snapshots_rate_limit_proxy_hops: self
+                .snapshots_rate_limit_proxy_hops
+                .map(|x| x.try_into())
+                .transpose()
+                .context("snapshots_rate_limit_proxy_hops")?,
+            strict_quantity_validation: self.strict_quantity_validation.unwrap_or_default(),
         })
     }
 
@@ -217,6 +250,20 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .collect(),
             extended_api_tracing: Some(this.extended_api_tracing),
             api_namespaces: this.api_namespaces.clone().unwrap_or_default(),
+            disabled_api_namespaces: this.disabled_api_namespaces.clone().unwrap_or_default(),
+            pruned_calls_snapshot_chunks_budget: this
+                .pruned_calls_snapshot_chunks_budget
+                .map(|x| x.try_into().unwrap()),
+            max_trace_size_bytes: this.max_trace_size_bytes.map(|x| x.try_into().unwrap()),
+            websocket_max_batch_request_size: this
+                .websocket_max_batch_request_size
+                .map(|x| x.try_into().unwrap()),
+            snapshots_download_url_ttl_sec: this.snapshots_download_url_ttl_sec,
+            snapshots_requests_per_minute_limit: this
+                .snapshots_requests_per_minute_limit
+                .map(|x| x.into()),
+            snapshots_rate_limit_proxy_hops: this.snapshots_rate_limit_proxy_hops.map(|x| x.into()),
+            strict_quantity_validation: Some(this.strict_quantity_validation),
         }
     }
 }
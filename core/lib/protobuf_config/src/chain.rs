@@ -81,6 +81,11 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            save_storage_accesses: self.save_storage_accesses.unwrap_or_default(),
+            receipts_cache_l2_blocks: self.receipts_cache_l2_blocks.unwrap_or_default(),
+            save_failed_call_traces_only: self.save_failed_call_traces_only.unwrap_or_default(),
+            call_traces_retention_days: self.call_traces_retention_days,
+            compress_call_traces: self.compress_call_traces.unwrap_or_default(),
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -120,6 +125,11 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            save_storage_accesses: Some(this.save_storage_accesses),
+            receipts_cache_l2_blocks: Some(this.receipts_cache_l2_blocks),
+            save_failed_call_traces_only: Some(this.save_failed_call_traces_only),
+            call_traces_retention_days: this.call_traces_retention_days,
+            compress_call_traces: Some(this.compress_call_traces),
         }
     }
 }
@@ -2,10 +2,14 @@ use anyhow::Context as _;
 use zksync_basic_types::L2ChainId;
 use zksync_concurrency::time;
 use zksync_config::configs::consensus::{
-    AttesterPublicKey, ConsensusConfig, GenesisSpec, Host, NodePublicKey, ProtocolVersion,
-    RpcConfig, ValidatorPublicKey, WeightedAttester, WeightedValidator,
+    AttesterPublicKey, BlockArchiveConfig, ConsensusConfig, GenesisSpec, Host, NodePublicKey,
+    ProtocolVersion, RpcConfig, ValidatorPublicKey, WeightedAttester, WeightedValidator,
+};
+use zksync_protobuf::{
+    kB, read_optional,
+    repr::{read_required_repr, ProtoRepr},
+    required, ProtoFmt,
 };
-use zksync_protobuf::{kB, read_optional, repr::ProtoRepr, required, ProtoFmt};
 
 use crate::{parse_h160, proto::consensus as proto, read_optional_repr};
 
@@ -112,6 +116,24 @@ impl ProtoRepr for proto::RpcConfig {
     }
 }
 
+impl ProtoRepr for proto::BlockArchive {
+    type Type = BlockArchiveConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            retention_blocks: *required(&self.retention_blocks).context("retention_blocks")?,
+            interval: read_optional(&self.interval).context("interval")?,
+            object_store: read_required_repr(&self.object_store).context("object_store")?,
+        })
+    }
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            retention_blocks: Some(this.retention_blocks),
+            interval: this.interval.as_ref().map(ProtoFmt::build),
+            object_store: Some(ProtoRepr::build(&this.object_store)),
+        }
+    }
+}
+
 impl ProtoRepr for proto::NodeAddr {
     type Type = (NodePublicKey, Host);
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -184,6 +206,11 @@ impl ProtoRepr for proto::Config {
                 .map(|x| Ok::<_, anyhow::Error>(x.parse()?))
                 .transpose()
                 .context("debug_page_addr")?,
+            block_archive: self
+                .block_archive
+                .as_ref()
+                .map(|x| x.read().context("block_archive"))
+                .transpose()?,
         })
     }
 
@@ -211,6 +238,7 @@ impl ProtoRepr for proto::Config {
             genesis_spec: this.genesis_spec.as_ref().map(ProtoRepr::build),
             rpc_config: this.rpc.as_ref().map(ProtoRepr::build),
             debug_page_addr: this.debug_page_addr.as_ref().map(|x| x.to_string()),
+            block_archive: this.block_archive.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -17,6 +17,18 @@ impl ProtoRepr for proto::ContractVerifier {
                 .and_then(|x| (*x).try_into().context("overflow"))
                 .context("port")?,
             etherscan_api_url: self.etherscan_api_url.clone(),
+            similar_match_discovery_enabled: self.similar_match_discovery_enabled.unwrap_or(false),
+            sourcify_api_url: self.sourcify_api_url.clone(),
+            sourcify_chain_id: self.sourcify_chain_id,
+            sourcify_import_enabled: self.sourcify_import_enabled.unwrap_or(false),
+            sourcify_export_enabled: self.sourcify_export_enabled.unwrap_or(false),
+            sourcify_sync_interval: self.sourcify_sync_interval.unwrap_or(300),
+            admin_port: self
+                .admin_port
+                .map(|x| x.try_into().context("overflow"))
+                .transpose()
+                .context("admin_port")?,
+            priority_requesters: self.priority_requesters.clone(),
         })
     }
 
@@ -26,6 +38,14 @@ impl ProtoRepr for proto::ContractVerifier {
             compilation_timeout: Some(this.compilation_timeout),
             prometheus_port: Some(this.prometheus_port.into()),
             etherscan_api_url: this.etherscan_api_url.clone(),
+            similar_match_discovery_enabled: Some(this.similar_match_discovery_enabled),
+            sourcify_api_url: this.sourcify_api_url.clone(),
+            sourcify_chain_id: this.sourcify_chain_id,
+            sourcify_import_enabled: Some(this.sourcify_import_enabled),
+            sourcify_export_enabled: Some(this.sourcify_export_enabled),
+            sourcify_sync_interval: Some(this.sourcify_sync_interval),
+            admin_port: this.admin_port.map(Into::into),
+            priority_requesters: this.priority_requesters.clone(),
         }
     }
 }
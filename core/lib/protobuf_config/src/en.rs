@@ -37,6 +37,11 @@ impl ProtoRepr for proto::ExternalNode {
                 .bridge_addresses_refresh_interval_sec
                 .and_then(NonZeroU64::new),
             gateway_chain_id: self.gateway_chain_id.map(SLChainId),
+            archive_mode: self.archive_mode.unwrap_or_default(),
+            tx_proxy_queue_ttl_sec: self.tx_proxy_queue_ttl_sec.and_then(NonZeroU64::new),
+            tx_proxy_queue_capacity: self
+                .tx_proxy_queue_capacity
+                .and_then(|a| NonZeroUsize::new(a as usize)),
         })
     }
 
@@ -56,6 +61,9 @@ impl ProtoRepr for proto::ExternalNode {
                 .bridge_addresses_refresh_interval_sec
                 .map(|a| a.get()),
             gateway_chain_id: this.gateway_chain_id.map(|c| c.0),
+            archive_mode: Some(this.archive_mode),
+            tx_proxy_queue_ttl_sec: this.tx_proxy_queue_ttl_sec.map(|a| a.get()),
+            tx_proxy_queue_capacity: this.tx_proxy_queue_capacity.map(|a| a.get() as u64),
         }
     }
 }
@@ -3,7 +3,7 @@ use zksync_config::configs::{self};
 use zksync_protobuf::{required, ProtoRepr};
 use zksync_types::{pubdata_da::PubdataSendingMode, settlement::SettlementMode};
 
-use crate::{proto::eth as proto, read_optional_repr};
+use crate::{parse_h160, proto::eth as proto, read_optional_repr};
 
 impl proto::ProofSendingMode {
     fn new(x: &configs::eth_sender::ProofSendingMode) -> Self {
@@ -105,6 +105,9 @@ impl ProtoRepr for proto::Sender {
                 .context("max_aggregated_blocks_to_commit")?,
             max_aggregated_blocks_to_execute: *required(&self.max_aggregated_blocks_to_execute)
                 .context("max_aggregated_blocks_to_execute")?,
+            max_aggregated_skipped_proofs: self
+                .max_aggregated_skipped_proofs
+                .unwrap_or(Self::Type::default_max_aggregated_skipped_proofs()),
             aggregated_block_commit_deadline: *required(&self.aggregated_block_commit_deadline)
                 .context("aggregated_block_commit_deadline")?,
             aggregated_block_prove_deadline: *required(&self.aggregated_block_prove_deadline)
@@ -129,6 +132,10 @@ impl ProtoRepr for proto::Sender {
                 .time_in_mempool_in_l1_blocks_cap
                 .unwrap_or(Self::Type::default_time_in_mempool_in_l1_blocks_cap()),
             is_verifier_pre_fflonk: self.is_verifier_pre_fflonk.unwrap_or(true),
+            max_commit_base_fee_per_gas: self.max_commit_base_fee_per_gas,
+            max_commit_postponement_sec: self
+                .max_commit_postponement_sec
+                .unwrap_or(Self::Type::default_max_commit_postponement_sec()),
         })
     }
 
@@ -143,6 +150,7 @@ impl ProtoRepr for proto::Sender {
             max_eth_tx_data_size: Some(this.max_eth_tx_data_size.try_into().unwrap()),
             max_aggregated_blocks_to_commit: Some(this.max_aggregated_blocks_to_commit),
             max_aggregated_blocks_to_execute: Some(this.max_aggregated_blocks_to_execute),
+            max_aggregated_skipped_proofs: Some(this.max_aggregated_skipped_proofs),
             aggregated_block_commit_deadline: Some(this.aggregated_block_commit_deadline),
             aggregated_block_prove_deadline: Some(this.aggregated_block_prove_deadline),
             aggregated_block_execute_deadline: Some(this.aggregated_block_execute_deadline),
@@ -158,6 +166,8 @@ impl ProtoRepr for proto::Sender {
             tx_aggregation_paused: Some(this.tx_aggregation_paused),
             time_in_mempool_in_l1_blocks_cap: Some(this.time_in_mempool_in_l1_blocks_cap),
             is_verifier_pre_fflonk: Some(this.is_verifier_pre_fflonk),
+            max_commit_base_fee_per_gas: this.max_commit_base_fee_per_gas,
+            max_commit_postponement_sec: Some(this.max_commit_postponement_sec),
         }
     }
 }
@@ -197,6 +207,7 @@ impl ProtoRepr for proto::GasAdjuster {
                 .transpose()?
                 .map(|x| x.parse())
                 .unwrap_or_default(),
+            gateway_settlement_supported: self.gateway_settlement_supported.unwrap_or(false),
         })
     }
 
@@ -219,6 +230,7 @@ impl ProtoRepr for proto::GasAdjuster {
             internal_pubdata_pricing_multiplier: Some(this.internal_pubdata_pricing_multiplier),
             max_blob_base_fee: this.max_blob_base_fee,
             settlement_mode: Some(proto::SettlementMode::new(&this.settlement_mode).into()),
+            gateway_settlement_supported: Some(this.gateway_settlement_supported),
         }
     }
 }
@@ -231,6 +243,12 @@ impl ProtoRepr for proto::EthWatch {
             confirmations_for_eth_event: self.confirmations_for_eth_event,
             eth_node_poll_interval: *required(&self.eth_node_poll_interval)
                 .context("eth_node_poll_interval")?,
+            address_policy_registry_addr: self
+                .address_policy_registry_addr
+                .as_ref()
+                .map(|addr| parse_h160(addr))
+                .transpose()
+                .context("address_policy_registry_addr")?,
         })
     }
 
@@ -238,6 +256,9 @@ impl ProtoRepr for proto::EthWatch {
         Self {
             confirmations_for_eth_event: this.confirmations_for_eth_event,
             eth_node_poll_interval: Some(this.eth_node_poll_interval),
+            address_policy_registry_addr: this
+                .address_policy_registry_addr
+                .map(|addr| format!("{addr:?}")),
         }
     }
 }
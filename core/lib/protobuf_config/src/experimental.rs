@@ -115,6 +115,9 @@ impl ProtoRepr for proto::Vm {
             playground: read_optional_repr(&self.playground).unwrap_or_default(),
             state_keeper_fast_vm_mode: parse_vm_mode(self.state_keeper_fast_vm_mode)?,
             api_fast_vm_mode: parse_vm_mode(self.api_fast_vm_mode)?,
+            state_keeper_shadow_parallel_execution: self
+                .state_keeper_shadow_parallel_execution
+                .unwrap_or(false),
         })
     }
 
@@ -125,6 +128,9 @@ impl ProtoRepr for proto::Vm {
                 proto::FastVmMode::new(this.state_keeper_fast_vm_mode).into(),
             ),
             api_fast_vm_mode: Some(proto::FastVmMode::new(this.api_fast_vm_mode).into()),
+            state_keeper_shadow_parallel_execution: Some(
+                this.state_keeper_shadow_parallel_execution,
+            ),
         }
     }
 }
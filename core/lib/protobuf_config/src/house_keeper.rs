@@ -12,6 +12,8 @@ impl ProtoRepr for proto::HouseKeeper {
                 &self.l1_batch_metrics_reporting_interval_ms,
             )
             .context("l1_batch_metrics_reporting_interval_ms")?,
+            orphaned_blobs_check_interval_ms: self.orphaned_blobs_check_interval_ms,
+            orphaned_blobs_removal_grace_period_secs: self.orphaned_blobs_removal_grace_period_secs,
         })
     }
 
@@ -20,6 +22,8 @@ impl ProtoRepr for proto::HouseKeeper {
             l1_batch_metrics_reporting_interval_ms: Some(
                 this.l1_batch_metrics_reporting_interval_ms,
             ),
+            orphaned_blobs_check_interval_ms: this.orphaned_blobs_check_interval_ms,
+            orphaned_blobs_removal_grace_period_secs: this.orphaned_blobs_removal_grace_period_secs,
         }
     }
 }
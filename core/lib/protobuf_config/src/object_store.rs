@@ -66,6 +66,7 @@ impl ProtoRepr for proto::ObjectStore {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_retries")?,
             local_mirror_path: self.local_mirror_path.clone(),
+            write_versioned_envelopes: self.write_versioned_envelopes.unwrap_or(false),
         })
     }
 
@@ -127,6 +128,7 @@ impl ProtoRepr for proto::ObjectStore {
             mode: Some(mode),
             max_retries: Some(this.max_retries.into()),
             local_mirror_path: this.local_mirror_path.clone(),
+            write_versioned_envelopes: Some(this.write_versioned_envelopes),
         }
     }
 }
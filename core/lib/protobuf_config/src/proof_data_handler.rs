@@ -19,6 +19,7 @@ impl ProtoRepr for proto::ProofDataHandler {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("retry_connection_interval_in_secs")?,
             api_url: required(&self.api_url).context("api_url")?.clone(),
+            fallback_api_urls: self.fallback_api_urls.clone(),
             batch_readiness_check_interval_in_secs: required(
                 &self.batch_readiness_check_interval_in_secs,
             )
@@ -52,6 +53,7 @@ impl ProtoRepr for proto::ProofDataHandler {
         Self {
             http_port: Some(this.http_port.into()),
             api_url: Some(this.api_url.clone()),
+            fallback_api_urls: this.fallback_api_urls.clone(),
             batch_readiness_check_interval_in_secs: Some(
                 this.batch_readiness_check_interval_in_secs.into(),
             ),
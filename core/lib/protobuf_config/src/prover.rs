@@ -25,6 +25,12 @@ impl ProtoRepr for proto::ProofCompressor {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("generation_timeout_in_secs")?,
             max_attempts: *required(&self.max_attempts).context("max_attempts")?,
+            requeue_max_attempts: self.requeue_max_attempts,
+            processing_timeout_in_secs: self
+                .processing_timeout_in_secs
+                .map(|x| x.try_into())
+                .transpose()
+                .context("processing_timeout_in_secs")?,
             universal_setup_path: required(&self.universal_setup_path)
                 .context("universal_setup_path")?
                 .clone(),
@@ -33,6 +39,7 @@ impl ProtoRepr for proto::ProofCompressor {
                 .clone(),
             verify_wrapper_proof: *required(&self.verify_wrapper_proof)
                 .context("verify_wrapper_proof")?,
+            checkpointing_enabled: self.checkpointing_enabled.unwrap_or(true),
         })
     }
 
@@ -44,9 +51,12 @@ impl ProtoRepr for proto::ProofCompressor {
             prometheus_push_interval_ms: this.prometheus_push_interval_ms,
             generation_timeout_in_secs: Some(this.generation_timeout_in_secs.into()),
             max_attempts: Some(this.max_attempts),
+            requeue_max_attempts: this.requeue_max_attempts,
+            processing_timeout_in_secs: this.processing_timeout_in_secs.map(Into::into),
             universal_setup_path: Some(this.universal_setup_path.clone()),
             universal_setup_download_url: Some(this.universal_setup_download_url.clone()),
             verify_wrapper_proof: Some(this.verify_wrapper_proof),
+            checkpointing_enabled: Some(this.checkpointing_enabled),
         }
     }
 }
@@ -169,6 +179,11 @@ impl ProtoRepr for proto::WitnessGenerator {
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("generation_timeout_in_secs")?,
             max_attempts: *required(&self.max_attempts).context("max_attempts")?,
+            basic_max_attempts: self.basic_max_attempts,
+            leaf_max_attempts: self.leaf_max_attempts,
+            node_max_attempts: self.node_max_attempts,
+            recursion_tip_max_attempts: self.recursion_tip_max_attempts,
+            scheduler_max_attempts: self.scheduler_max_attempts,
             last_l1_batch_to_process: self.last_l1_batch_to_process,
             basic_generation_timeout_in_secs: self
                 .basic_generation_timeout_in_secs
@@ -203,6 +218,25 @@ impl ProtoRepr for proto::WitnessGenerator {
             max_circuits_in_flight: required(&self.max_circuits_in_flight)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_circuits_in_flight")?,
+            prefetch_depth: self
+                .prefetch_depth
+                .map(|x| x.try_into())
+                .transpose()
+                .context("prefetch_depth")?
+                .unwrap_or_default(),
+            max_proofs_in_memory: self
+                .max_proofs_in_memory
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_proofs_in_memory")?,
+            artifacts_compression: self
+                .artifacts_compression
+                .map(proto::ArtifactsCompressionAlgorithm::try_from)
+                .transpose()
+                .context("artifacts_compression")?
+                .map(|x| x.parse())
+                .unwrap_or_default(),
+            artifacts_compression_level: self.artifacts_compression_level,
         })
     }
 
@@ -210,6 +244,11 @@ impl ProtoRepr for proto::WitnessGenerator {
         Self {
             generation_timeout_in_secs: Some(this.generation_timeout_in_secs.into()),
             max_attempts: Some(this.max_attempts),
+            basic_max_attempts: this.basic_max_attempts,
+            leaf_max_attempts: this.leaf_max_attempts,
+            node_max_attempts: this.node_max_attempts,
+            recursion_tip_max_attempts: this.recursion_tip_max_attempts,
+            scheduler_max_attempts: this.scheduler_max_attempts,
             last_l1_batch_to_process: this.last_l1_batch_to_process,
             basic_generation_timeout_in_secs: this
                 .basic_generation_timeout_in_secs
@@ -224,6 +263,12 @@ impl ProtoRepr for proto::WitnessGenerator {
                 .map(|x| x.into()),
             prometheus_listener_port: this.prometheus_listener_port.map(|x| x.into()),
             max_circuits_in_flight: Some(this.max_circuits_in_flight as u64),
+            prefetch_depth: Some(this.prefetch_depth as u64),
+            max_proofs_in_memory: this.max_proofs_in_memory.map(|x| x as u64),
+            artifacts_compression: Some(
+                proto::ArtifactsCompressionAlgorithm::new(&this.artifacts_compression).into(),
+            ),
+            artifacts_compression_level: this.artifacts_compression_level,
         }
     }
 }
@@ -257,6 +302,13 @@ impl ProtoRepr for proto::WitnessVectorGenerator {
             specialized_group_id: required(&self.specialized_group_id)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("specialized_group_id")?,
+            transfer_format: self
+                .transfer_format
+                .map(proto::WitnessVectorTransferFormat::try_from)
+                .transpose()
+                .context("transfer_format")?
+                .map(|x| x.parse())
+                .unwrap_or_default(),
         })
     }
 
@@ -275,6 +327,49 @@ impl ProtoRepr for proto::WitnessVectorGenerator {
             prometheus_pushgateway_url: Some(this.prometheus_pushgateway_url.clone()),
             prometheus_push_interval_ms: this.prometheus_push_interval_ms,
             specialized_group_id: Some(this.specialized_group_id.into()),
+            transfer_format: Some(
+                proto::WitnessVectorTransferFormat::new(&this.transfer_format).into(),
+            ),
+        }
+    }
+}
+
+impl proto::ArtifactsCompressionAlgorithm {
+    fn new(x: &configs::fri_witness_generator::ArtifactsCompressionAlgorithm) -> Self {
+        use configs::fri_witness_generator::ArtifactsCompressionAlgorithm as From;
+        match x {
+            From::None => Self::None,
+            From::Zstd => Self::Zstd,
+            From::Lz4 => Self::Lz4,
+        }
+    }
+
+    fn parse(&self) -> configs::fri_witness_generator::ArtifactsCompressionAlgorithm {
+        use configs::fri_witness_generator::ArtifactsCompressionAlgorithm as To;
+        match self {
+            Self::None => To::None,
+            Self::Zstd => To::Zstd,
+            Self::Lz4 => To::Lz4,
+        }
+    }
+}
+
+impl proto::WitnessVectorTransferFormat {
+    fn new(x: &configs::fri_witness_vector_generator::WitnessVectorTransferFormat) -> Self {
+        use configs::fri_witness_vector_generator::WitnessVectorTransferFormat as From;
+        match x {
+            From::Raw => Self::Raw,
+            From::Framed => Self::Framed,
+            From::FramedZstd => Self::FramedZstd,
+        }
+    }
+
+    fn parse(&self) -> configs::fri_witness_vector_generator::WitnessVectorTransferFormat {
+        use configs::fri_witness_vector_generator::WitnessVectorTransferFormat as To;
+        match self {
+            Self::Raw => To::Raw,
+            Self::Framed => To::Framed,
+            Self::FramedZstd => To::FramedZstd,
         }
     }
 }
@@ -352,6 +447,7 @@ impl ProtoRepr for proto::Prover {
                 .context("zone_read_url")?
                 .clone(),
             availability_check_interval_in_secs: self.availability_check_interval_in_secs,
+            setup_keys_download_url: self.setup_keys_download_url.clone(),
             prover_object_store,
             cloud_type: self
                 .cloud_type
@@ -375,6 +471,7 @@ impl ProtoRepr for proto::Prover {
             witness_vector_receiver_port: Some(this.witness_vector_receiver_port.into()),
             zone_read_url: Some(this.zone_read_url.clone()),
             availability_check_interval_in_secs: this.availability_check_interval_in_secs,
+            setup_keys_download_url: this.setup_keys_download_url.clone(),
             prover_object_store: this.prover_object_store.as_ref().map(ProtoRepr::build),
             cloud_type: Some(proto::CloudType::new(&this.cloud_type).into()),
         }
@@ -45,6 +45,20 @@ impl ProtoRepr for proto::ProverJobMonitor {
                 }),
             )
             .context("prover_jobs_archiver_archive_jobs_after_ms")?,
+            compression_jobs_archiver_run_interval_ms: *required(
+                &self.compression_jobs_archiver_run_interval_ms.or_else(|| {
+                    Some(Self::Type::default_compression_jobs_archiver_run_interval_ms())
+                }),
+            )
+            .context("compression_jobs_archiver_run_interval_ms")?,
+            compression_jobs_archiver_archive_jobs_after_ms: *required(
+                &self
+                    .compression_jobs_archiver_archive_jobs_after_ms
+                    .or_else(|| {
+                        Some(Self::Type::default_compression_jobs_archiver_archive_jobs_after_ms())
+                    }),
+            )
+            .context("compression_jobs_archiver_archive_jobs_after_ms")?,
             proof_compressor_job_requeuer_run_interval_ms: *required(
                 &self
                     .proof_compressor_job_requeuer_run_interval_ms
@@ -114,6 +128,12 @@ impl ProtoRepr for proto::ProverJobMonitor {
             prover_jobs_archiver_archive_jobs_after_ms: Some(
                 this.prover_jobs_archiver_archive_jobs_after_ms,
             ),
+            compression_jobs_archiver_run_interval_ms: Some(
+                this.compression_jobs_archiver_run_interval_ms,
+            ),
+            compression_jobs_archiver_archive_jobs_after_ms: Some(
+                this.compression_jobs_archiver_archive_jobs_after_ms,
+            ),
             proof_compressor_job_requeuer_run_interval_ms: Some(
                 this.proof_compressor_job_requeuer_run_interval_ms,
             ),
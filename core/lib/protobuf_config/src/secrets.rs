@@ -10,7 +10,7 @@ use zksync_config::configs::{
     consensus::{AttesterSecretKey, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
     da_client::{avail::AvailSecrets, celestia::CelestiaSecrets, eigen::EigenSecrets},
     secrets::{DataAvailabilitySecrets, Secrets},
-    ContractVerifierSecrets, DatabaseSecrets, L1Secrets,
+    ContractVerifierSecrets, DatabaseSecrets, L1Secrets, ProofDataHandlerSecrets,
 };
 use zksync_protobuf::{required, ProtoRepr};
 
@@ -29,6 +29,7 @@ impl ProtoRepr for proto::Secrets {
             l1: read_optional_repr(&self.l1),
             data_availability: read_optional_repr(&self.da),
             contract_verifier: read_optional_repr(&self.contract_verifier),
+            proof_data_handler: read_optional_repr(&self.proof_data_handler),
         })
     }
 
@@ -39,6 +40,7 @@ impl ProtoRepr for proto::Secrets {
             consensus: this.consensus.as_ref().map(ProtoRepr::build),
             da: this.data_availability.as_ref().map(ProtoRepr::build),
             contract_verifier: this.contract_verifier.as_ref().map(ProtoRepr::build),
+            proof_data_handler: this.proof_data_handler.as_ref().map(ProtoRepr::build),
         }
     }
 }
@@ -271,3 +273,25 @@ impl ProtoRepr for proto::ContractVerifierSecrets {
         Self { etherscan_api_key }
     }
 }
+
+impl ProtoRepr for proto::ProofDataHandlerSecrets {
+    type Type = ProofDataHandlerSecrets;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(ProofDataHandlerSecrets {
+            admin_api_key: self
+                .admin_api_key
+                .as_ref()
+                .map(|s| APIKey::from(s.as_str())),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            admin_api_key: this
+                .admin_api_key
+                .as_ref()
+                .map(|key| key.0.expose_secret().to_string()),
+        }
+    }
+}
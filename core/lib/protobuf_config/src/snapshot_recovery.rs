@@ -1,4 +1,4 @@
-use std::num::NonZeroUsize;
+use std::num::{NonZeroU64, NonZeroUsize};
 
 use zksync_basic_types::L1BatchNumber;
 use zksync_config::configs::{
@@ -17,12 +17,24 @@ impl ProtoRepr for proto::Postgres {
             max_concurrency: self
                 .max_concurrency
                 .and_then(|a| NonZeroUsize::new(a as usize)),
+            max_concurrent_applications: self
+                .max_concurrent_applications
+                .and_then(|a| NonZeroUsize::new(a as usize)),
+            insertion_batch_size: self
+                .insertion_batch_size
+                .and_then(|a| NonZeroUsize::new(a as usize)),
+            max_download_bandwidth: self.max_download_bandwidth.and_then(NonZeroU64::new),
+            max_replication_lag_sec: self.max_replication_lag_sec,
         })
     }
 
     fn build(this: &Self::Type) -> Self {
         Self {
             max_concurrency: this.max_concurrency.map(|a| a.get() as u64),
+            max_concurrent_applications: this.max_concurrent_applications.map(|a| a.get() as u64),
+            insertion_batch_size: this.insertion_batch_size.map(|a| a.get() as u64),
+            max_download_bandwidth: this.max_download_bandwidth.map(NonZeroU64::get),
+            max_replication_lag_sec: this.max_replication_lag_sec,
         }
     }
 }
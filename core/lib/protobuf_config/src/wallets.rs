@@ -8,6 +8,20 @@ use zksync_types::{Address, K256PrivateKey};
 
 use crate::{parse_h160, parse_h256, proto::wallets as proto};
 
+fn read_optional_pk_wallet(
+    wallet: Option<&proto::PrivateKeyWallet>,
+    name: &str,
+) -> anyhow::Result<Option<Wallet>> {
+    wallet
+        .map(|wallet| {
+            Wallet::from_private_key_bytes(
+                parse_h256(required(&wallet.private_key).context(name.to_owned())?)?,
+                wallet.address.as_ref().and_then(|a| parse_h160(a).ok()),
+            )
+        })
+        .transpose()
+}
+
 impl ProtoRepr for proto::Wallets {
     type Type = configs::wallets::Wallets;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -37,6 +51,14 @@ impl ProtoRepr for proto::Wallets {
             Some(EthSender {
                 operator,
                 blob_operator,
+                previous_operator: read_optional_pk_wallet(
+                    self.previous_operator.as_ref(),
+                    "previous operator",
+                )?,
+                previous_blob_operator: read_optional_pk_wallet(
+                    self.previous_blob_operator.as_ref(),
+                    "previous blob operator",
+                )?,
             })
         } else {
             None
@@ -86,22 +108,25 @@ impl ProtoRepr for proto::Wallets {
             }
         };
 
-        let (operator, blob_operator) = if let Some(eth_sender) = &this.eth_sender {
-            let blob = eth_sender
-                .blob_operator
-                .as_ref()
-                .map(|blob| create_pk_wallet(blob.address(), blob.private_key()));
-            (
-                Some(create_pk_wallet(
-                    eth_sender.operator.address(),
-                    eth_sender.operator.private_key(),
-                )),
-                blob,
-            )
-        } else {
-            (None, None)
+        let create_opt_pk_wallet = |wallet: Option<&Wallet>| {
+            wallet.map(|wallet| create_pk_wallet(wallet.address(), wallet.private_key()))
         };
 
+        let (operator, blob_operator, previous_operator, previous_blob_operator) =
+            if let Some(eth_sender) = &this.eth_sender {
+                (
+                    Some(create_pk_wallet(
+                        eth_sender.operator.address(),
+                        eth_sender.operator.private_key(),
+                    )),
+                    create_opt_pk_wallet(eth_sender.blob_operator.as_ref()),
+                    create_opt_pk_wallet(eth_sender.previous_operator.as_ref()),
+                    create_opt_pk_wallet(eth_sender.previous_blob_operator.as_ref()),
+                )
+            } else {
+                (None, None, None, None)
+            };
+
         let fee_account = this
             .state_keeper
             .as_ref()
@@ -124,6 +149,8 @@ impl ProtoRepr for proto::Wallets {
             operator,
             fee_account,
             token_multiplier_setter,
+            previous_operator,
+            previous_blob_operator,
         }
     }
 }
@@ -4,6 +4,8 @@
 use serde::{Deserialize, Serialize};
 use serde_with::{hex::Hex, serde_as};
 use zksync_types::{
+    api::ProvingRoundStatus,
+    basic_fri_types::AggregationRound,
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
     tee_types::TeeType,
     L1BatchNumber,
@@ -63,6 +65,21 @@ pub enum SubmitProofRequest {
     SkippedProofGeneration(L1BatchNumber),
 }
 
+/// Notice about a finished (or failing) aggregation round of the batch proof. Sent by the prover subsystem
+/// before the final proof is available, so that intermediate progress can be observed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvingRoundNotice {
+    pub l1_batch_number: L1BatchNumber,
+    pub aggregation_round: AggregationRound,
+    pub status: ProvingRoundStatus,
+    /// Total number of prover jobs in the round.
+    pub jobs_count: u32,
+    /// IDs of circuits with failed proofs.
+    pub failed_circuit_ids: Vec<u8>,
+    /// Object store reference to the round artifact, if the round produces a single artifact.
+    pub artifact_url: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VerifyProofRequest(pub Box<L1BatchProofForL1>);
 
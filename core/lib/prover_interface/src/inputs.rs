@@ -2,7 +2,10 @@ use std::{collections::HashMap, convert::TryInto, fmt::Debug};
 
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, Bytes};
-use zksync_object_store::{_reexports::BoxedError, serialize_using_bincode, Bucket, StoredObject};
+use zksync_object_store::{
+    _reexports::BoxedError, serialize_using_bincode, serialize_using_versioned_envelope,
+    versioned::VersionedObject, Bucket, StoredObject,
+};
 use zksync_types::{
     basic_fri_types::Eip4844Blobs, block::L2BlockExecutionData, commitment::PubdataParams,
     witness_block_state::WitnessStorageState, L1BatchNumber, ProtocolVersionId, H256, U256,
@@ -183,28 +186,109 @@ impl From<VMRunWitnessInputDataLegacy> for VMRunWitnessInputData {
     }
 }
 
-impl StoredObject for VMRunWitnessInputData {
-    const BUCKET: Bucket = Bucket::WitnessInput;
+/// Fields of [`VMRunWitnessInputData`] in the order of the version 1 wire format. Unlike the struct itself,
+/// the wire format always encodes `evm_emulator_code_hash`; `skip_serializing_if` is not supported by `bincode`.
+type VMRunWitnessInputDataV1 = (
+    L1BatchNumber,
+    HashMap<U256, Vec<[u8; 32]>>,
+    Vec<(usize, U256)>,
+    ProtocolVersionId,
+    Vec<[u8; 32]>,
+    U256,
+    Option<U256>,
+    Vec<u32>,
+    Vec<i32>,
+    WitnessStorageState,
+);
+
+impl VMRunWitnessInputData {
+    fn v1_fields(&self) -> impl Serialize + '_ {
+        (
+            self.l1_batch_number,
+            &self.used_bytecodes,
+            &self.initial_heap_content,
+            self.protocol_version,
+            &self.bootloader_code,
+            self.default_account_code_hash,
+            self.evm_emulator_code_hash,
+            &self.storage_refunds,
+            &self.pubdata_costs,
+            &self.witness_block_state,
+        )
+    }
 
-    type Key<'a> = L1BatchNumber;
+    fn from_v1_fields(fields: VMRunWitnessInputDataV1) -> Self {
+        let (
+            l1_batch_number,
+            used_bytecodes,
+            initial_heap_content,
+            protocol_version,
+            bootloader_code,
+            default_account_code_hash,
+            evm_emulator_code_hash,
+            storage_refunds,
+            pubdata_costs,
+            witness_block_state,
+        ) = fields;
+        Self {
+            l1_batch_number,
+            used_bytecodes,
+            initial_heap_content,
+            protocol_version,
+            bootloader_code,
+            default_account_code_hash,
+            evm_emulator_code_hash,
+            storage_refunds,
+            pubdata_costs,
+            witness_block_state,
+        }
+    }
+}
 
-    fn encode_key(key: Self::Key<'_>) -> String {
-        format!("vm_run_data_{key}.bin")
+/// Version history:
+///
+/// - Version 1: `bincode` encoding of `VMRunWitnessInputDataV1` fields.
+impl VersionedObject for VMRunWitnessInputData {
+    const CURRENT_VERSION: u16 = 1;
+
+    fn encode(&self) -> Result<Vec<u8>, BoxedError> {
+        zksync_object_store::bincode::serialize(&self.v1_fields()).map_err(Into::into)
     }
 
-    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
+    fn decode(version: u16, payload: &[u8]) -> Result<Self, BoxedError> {
+        match version {
+            1 => Ok(Self::from_v1_fields(
+                zksync_object_store::bincode::deserialize(payload)?,
+            )),
+            _ => Err(Self::unsupported_version(version)),
+        }
+    }
+
+    fn encode_unversioned(&self) -> Result<Vec<u8>, BoxedError> {
         zksync_object_store::bincode::serialize(self).map_err(Into::into)
     }
 
-    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
-        zksync_object_store::bincode::deserialize::<VMRunWitnessInputData>(&bytes).or_else(|_| {
-            zksync_object_store::bincode::deserialize::<VMRunWitnessInputDataLegacy>(&bytes)
+    fn decode_unversioned(bytes: &[u8]) -> Result<Self, BoxedError> {
+        zksync_object_store::bincode::deserialize::<VMRunWitnessInputData>(bytes).or_else(|_| {
+            zksync_object_store::bincode::deserialize::<VMRunWitnessInputDataLegacy>(bytes)
                 .map(Into::into)
                 .map_err(Into::into)
         })
     }
 }
 
+impl StoredObject for VMRunWitnessInputData {
+    const BUCKET: Bucket = Bucket::WitnessInput;
+
+    type Key<'a> = L1BatchNumber;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        format!("vm_run_data_{key}.bin")
+    }
+
+    serialize_using_versioned_envelope!();
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WitnessInputData {
     pub vm_run_data: VMRunWitnessInputData,
@@ -232,28 +316,63 @@ impl From<WitnessInputDataLegacy> for WitnessInputData {
     }
 }
 
-impl StoredObject for WitnessInputData {
-    const BUCKET: Bucket = Bucket::WitnessInput;
-
-    type Key<'a> = L1BatchNumber;
+/// Version history:
+///
+/// - Version 1: `bincode` encoding of [`VMRunWitnessInputData`] version 1 fields, followed by the remaining fields.
+impl VersionedObject for WitnessInputData {
+    const CURRENT_VERSION: u16 = 1;
+
+    fn encode(&self) -> Result<Vec<u8>, BoxedError> {
+        let fields = (
+            self.vm_run_data.v1_fields(),
+            &self.merkle_paths,
+            &self.previous_batch_metadata,
+            &self.eip_4844_blobs,
+        );
+        zksync_object_store::bincode::serialize(&fields).map_err(Into::into)
+    }
 
-    fn encode_key(key: Self::Key<'_>) -> String {
-        format!("witness_inputs_{key}.bin")
+    fn decode(version: u16, payload: &[u8]) -> Result<Self, BoxedError> {
+        match version {
+            1 => {
+                let (vm_run_data, merkle_paths, previous_batch_metadata, eip_4844_blobs) =
+                    zksync_object_store::bincode::deserialize(payload)?;
+                Ok(Self {
+                    vm_run_data: VMRunWitnessInputData::from_v1_fields(vm_run_data),
+                    merkle_paths,
+                    previous_batch_metadata,
+                    eip_4844_blobs,
+                })
+            }
+            _ => Err(Self::unsupported_version(version)),
+        }
     }
 
-    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
+    fn encode_unversioned(&self) -> Result<Vec<u8>, BoxedError> {
         zksync_object_store::bincode::serialize(self).map_err(Into::into)
     }
 
-    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
-        zksync_object_store::bincode::deserialize::<WitnessInputData>(&bytes).or_else(|_| {
-            zksync_object_store::bincode::deserialize::<WitnessInputDataLegacy>(&bytes)
+    fn decode_unversioned(bytes: &[u8]) -> Result<Self, BoxedError> {
+        zksync_object_store::bincode::deserialize::<WitnessInputData>(bytes).or_else(|_| {
+            zksync_object_store::bincode::deserialize::<WitnessInputDataLegacy>(bytes)
                 .map(Into::into)
                 .map_err(Into::into)
         })
     }
 }
 
+impl StoredObject for WitnessInputData {
+    const BUCKET: Bucket = Bucket::WitnessInput;
+
+    type Key<'a> = L1BatchNumber;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        format!("witness_inputs_{key}.bin")
+    }
+
+    serialize_using_versioned_envelope!();
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct L1BatchMetadataHashes {
     pub root_hash: H256,
@@ -310,8 +429,95 @@ impl TeeVerifierInput {
 
 #[cfg(test)]
 mod tests {
+    use zksync_object_store::versioned::{serialize_versioned, ENVELOPE_MAGIC};
+
     use super::*;
 
+    fn mock_vm_run_data(evm_emulator_code_hash: Option<U256>) -> VMRunWitnessInputData {
+        VMRunWitnessInputData {
+            l1_batch_number: L1BatchNumber(1),
+            used_bytecodes: HashMap::from([(U256::from(1), vec![[1; 32]])]),
+            initial_heap_content: vec![(0, U256::from(2))],
+            protocol_version: ProtocolVersionId::latest(),
+            bootloader_code: vec![[2; 32]],
+            default_account_code_hash: U256::from(3),
+            evm_emulator_code_hash,
+            storage_refunds: vec![1, 2, 3],
+            pubdata_costs: vec![-1, 2],
+            witness_block_state: WitnessStorageState::default(),
+        }
+    }
+
+    #[test]
+    fn vm_run_data_versioned_roundtrip() {
+        for evm_emulator_code_hash in [None, Some(U256::from(4))] {
+            let data = mock_vm_run_data(evm_emulator_code_hash);
+            let bytes = serialize_versioned(&data).unwrap();
+            assert_eq!(bytes[..4], ENVELOPE_MAGIC);
+            let restored: VMRunWitnessInputData = StoredObject::deserialize(bytes).unwrap();
+            assert_eq!(restored, data);
+
+            // Envelope writes are disabled by default.
+            let bytes = StoredObject::serialize(&data).unwrap();
+            assert_eq!(
+                bytes,
+                zksync_object_store::bincode::serialize(&data).unwrap()
+            );
+            let restored: VMRunWitnessInputData = StoredObject::deserialize(bytes).unwrap();
+            assert_eq!(restored, data);
+        }
+    }
+
+    #[test]
+    fn vm_run_data_unversioned_compatibility() {
+        let data = mock_vm_run_data(Some(U256::from(4)));
+        let bytes = zksync_object_store::bincode::serialize(&data).unwrap();
+        let restored: VMRunWitnessInputData = StoredObject::deserialize(bytes).unwrap();
+        assert_eq!(restored, data);
+
+        let data = mock_vm_run_data(None);
+        let legacy_data = VMRunWitnessInputDataLegacy {
+            l1_batch_number: data.l1_batch_number,
+            used_bytecodes: data.used_bytecodes.clone(),
+            initial_heap_content: data.initial_heap_content.clone(),
+            protocol_version: data.protocol_version,
+            bootloader_code: data.bootloader_code.clone(),
+            default_account_code_hash: data.default_account_code_hash,
+            storage_refunds: data.storage_refunds.clone(),
+            pubdata_costs: data.pubdata_costs.clone(),
+            witness_block_state: data.witness_block_state.clone(),
+        };
+        let bytes = zksync_object_store::bincode::serialize(&legacy_data).unwrap();
+        let restored: VMRunWitnessInputData = StoredObject::deserialize(bytes).unwrap();
+        assert_eq!(restored, data);
+    }
+
+    #[test]
+    fn witness_input_data_versioned_roundtrip() {
+        let data = WitnessInputData {
+            vm_run_data: mock_vm_run_data(None),
+            merkle_paths: WitnessInputMerklePaths::new(1),
+            previous_batch_metadata: L1BatchMetadataHashes {
+                root_hash: H256::repeat_byte(1),
+                meta_hash: H256::repeat_byte(2),
+                aux_hash: H256::repeat_byte(3),
+            },
+            eip_4844_blobs: Eip4844Blobs::empty(),
+        };
+        let bytes = serialize_versioned(&data).unwrap();
+        assert_eq!(bytes[..4], ENVELOPE_MAGIC);
+        let restored: WitnessInputData = StoredObject::deserialize(bytes).unwrap();
+        assert_eq!(restored, data);
+
+        let bytes = StoredObject::serialize(&data).unwrap();
+        assert_eq!(
+            bytes,
+            zksync_object_store::bincode::serialize(&data).unwrap()
+        );
+        let restored: WitnessInputData = StoredObject::deserialize(bytes).unwrap();
+        assert_eq!(restored, data);
+    }
+
     #[test]
     fn prepare_basic_circuits_job_roundtrip() {
         let zero_hash = [0_u8; 32];
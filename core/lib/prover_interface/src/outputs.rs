@@ -10,7 +10,10 @@ use circuit_definitions::{
 use fflonk::FflonkProof;
 use serde::{Deserialize, Serialize};
 use serde_with::{hex::Hex, serde_as};
-use zksync_object_store::{serialize_using_bincode, Bucket, StoredObject, _reexports::BoxedError};
+use zksync_object_store::{
+    _reexports::BoxedError, serialize_using_bincode, serialize_using_versioned_envelope,
+    versioned::VersionedObject, Bucket, StoredObject,
+};
 use zksync_types::{protocol_version::ProtocolSemanticVersion, tee_types::TeeType, L1BatchNumber};
 
 /// A "final" ZK proof that can be sent to the L1 contract.
@@ -68,6 +71,15 @@ impl From<PlonkL1BatchProofForL1> for L1BatchProofForL1 {
     }
 }
 
+/// SNARK wrapper proof checkpointed by the proof compressor before it's combined with aggregation result coordinates
+/// into [`L1BatchProofForL1`]. Allows to skip recompressing the scheduler proof if the compression job is retried.
+#[derive(Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum L1BatchCompressionCheckpoint {
+    Fflonk(FflonkProof<Bn256, ZkSyncSnarkWrapperCircuitNoLookupCustomGate>),
+    Plonk(PlonkProof<Bn256, ZkSyncSnarkWrapperCircuit>),
+}
+
 /// A "final" TEE proof that can be sent to the L1 contract.
 #[serde_as]
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
@@ -112,6 +124,19 @@ impl fmt::Debug for FflonkL1BatchProofForL1 {
     }
 }
 
+impl fmt::Debug for L1BatchCompressionCheckpoint {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Fflonk(_) => "Fflonk",
+            Self::Plonk(_) => "Plonk",
+        };
+        formatter
+            .debug_tuple("L1BatchCompressionCheckpoint")
+            .field(&kind)
+            .finish()
+    }
+}
+
 impl fmt::Debug for L1BatchTeeProofForL1 {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter
@@ -120,6 +145,60 @@ impl fmt::Debug for L1BatchTeeProofForL1 {
     }
 }
 
+/// Wire format of [`L1BatchProofForL1`] version 1. Unlike [`L1BatchProofForL1`], which is untagged,
+/// explicitly encodes the proof kind.
+#[derive(Serialize)]
+enum L1BatchProofForL1V1Ref<'a> {
+    Fflonk(&'a FflonkL1BatchProofForL1),
+    Plonk(&'a PlonkL1BatchProofForL1),
+}
+
+/// Owned counterpart of [`L1BatchProofForL1V1Ref`]; variants must be kept in the same order.
+#[derive(Deserialize)]
+#[allow(clippy::large_enum_variant)]
+enum L1BatchProofForL1V1 {
+    Fflonk(FflonkL1BatchProofForL1),
+    Plonk(PlonkL1BatchProofForL1),
+}
+
+/// Version history:
+///
+/// - Version 1: `bincode` encoding of the proof kind followed by the proof.
+impl VersionedObject for L1BatchProofForL1 {
+    const CURRENT_VERSION: u16 = 1;
+
+    fn encode(&self) -> Result<Vec<u8>, BoxedError> {
+        let wire = match self {
+            Self::Fflonk(proof) => L1BatchProofForL1V1Ref::Fflonk(proof),
+            Self::Plonk(proof) => L1BatchProofForL1V1Ref::Plonk(proof),
+        };
+        zksync_object_store::bincode::serialize(&wire).map_err(From::from)
+    }
+
+    fn decode(version: u16, payload: &[u8]) -> Result<Self, BoxedError> {
+        match version {
+            1 => Ok(match zksync_object_store::bincode::deserialize(payload)? {
+                L1BatchProofForL1V1::Fflonk(proof) => Self::Fflonk(proof),
+                L1BatchProofForL1V1::Plonk(proof) => Self::Plonk(proof),
+            }),
+            _ => Err(Self::unsupported_version(version)),
+        }
+    }
+
+    fn encode_unversioned(&self) -> Result<Vec<u8>, BoxedError> {
+        zksync_object_store::bincode::serialize(self).map_err(From::from)
+    }
+
+    fn decode_unversioned(bytes: &[u8]) -> Result<Self, BoxedError> {
+        match zksync_object_store::bincode::deserialize::<PlonkL1BatchProofForL1>(bytes) {
+            Ok(proof) => Ok(proof.into()),
+            Err(_) => zksync_object_store::bincode::deserialize::<FflonkL1BatchProofForL1>(bytes)
+                .map(Into::into)
+                .map_err(Into::into),
+        }
+    }
+}
+
 impl StoredObject for L1BatchProofForL1 {
     const BUCKET: Bucket = Bucket::ProofsFri;
     type Key<'a> = (L1BatchNumber, ProtocolSemanticVersion);
@@ -130,18 +209,20 @@ impl StoredObject for L1BatchProofForL1 {
         format!("l1_batch_proof_{l1_batch_number}_{semver_suffix}.bin")
     }
 
-    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
-        zksync_object_store::bincode::serialize(self).map_err(From::from)
-    }
+    serialize_using_versioned_envelope!();
+}
 
-    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
-        match zksync_object_store::bincode::deserialize::<PlonkL1BatchProofForL1>(&bytes) {
-            Ok(proof) => Ok(proof.into()),
-            Err(_) => zksync_object_store::bincode::deserialize::<FflonkL1BatchProofForL1>(&bytes)
-                .map(Into::into)
-                .map_err(Into::into),
-        }
+impl StoredObject for L1BatchCompressionCheckpoint {
+    const BUCKET: Bucket = Bucket::ProofsFri;
+    type Key<'a> = (L1BatchNumber, ProtocolSemanticVersion);
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        let (l1_batch_number, protocol_version) = key;
+        let semver_suffix = protocol_version.to_string().replace('.', "_");
+        format!("l1_batch_compression_checkpoint_{l1_batch_number}_{semver_suffix}.bin")
     }
+
+    serialize_using_bincode!();
 }
 
 impl StoredObject for L1BatchTeeProofForL1 {
@@ -4,7 +4,7 @@ use jsonrpsee::{
 };
 use zksync_types::L1BatchNumber;
 
-use crate::api::{ProofGenerationData, SubmitProofRequest};
+use crate::api::{ProofGenerationData, ProvingRoundNotice, SubmitProofRequest};
 #[rpc(server, client)]
 pub trait GatewayRpc {
     /// Submits proof generation data from client to server
@@ -15,7 +15,17 @@ pub trait GatewayRpc {
     #[method(name = "received_final_proof")]
     async fn received_final_proof(&self, batch: L1BatchNumber) -> RpcResult<()>;
 
+    /// Notifies server that proof generation for the batch was skipped by the operator, so that the unfinished
+    /// prover jobs for the batch are skipped as well
+    #[method(name = "skip_proving")]
+    async fn skip_proving(&self, batch: L1BatchNumber) -> RpcResult<()>;
+
     /// Subscription method
     #[subscription(name = "subscribe_for_proofs" => "subscription", unsubscribe = "unsubscribe_from_proofs", item = SubmitProofRequest)]
     async fn subscribe_for_proofs(&self) -> SubscriptionResult;
+
+    /// Subscribes to intermediate proving progress, i.e. notices about finished or failing aggregation rounds
+    /// of batches that don't have the final proof yet
+    #[subscription(name = "subscribe_for_proving_progress" => "proving_progress", unsubscribe = "unsubscribe_from_proving_progress", item = ProvingRoundNotice)]
+    async fn subscribe_for_proving_progress(&self) -> SubscriptionResult;
 }
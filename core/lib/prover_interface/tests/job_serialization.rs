@@ -2,14 +2,22 @@
 
 use fflonk::FflonkProof;
 use tokio::fs;
-use zksync_object_store::{Bucket, MockObjectStore, StoredObject};
+use zksync_object_store::{
+    versioned::{serialize_versioned, ENVELOPE_MAGIC},
+    Bucket, MockObjectStore, StoredObject,
+};
 use zksync_prover_interface::{
-    api::{SubmitProofRequest, SubmitTeeProofRequest},
+    api::{ProvingRoundNotice, SubmitProofRequest, SubmitTeeProofRequest},
     inputs::{StorageLogMetadata, WitnessInputMerklePaths},
-    outputs::{FflonkL1BatchProofForL1, L1BatchProofForL1, L1BatchTeeProofForL1},
+    outputs::{
+        FflonkL1BatchProofForL1, L1BatchCompressionCheckpoint, L1BatchProofForL1,
+        L1BatchTeeProofForL1,
+    },
 };
 use zksync_types::{
-    protocol_version::ProtocolSemanticVersion, tee_types::TeeType, L1BatchNumber, ProtocolVersionId,
+    api::ProvingRoundStatus, basic_fri_types::AggregationRound,
+    protocol_version::ProtocolSemanticVersion, tee_types::TeeType, L1BatchNumber,
+    ProtocolVersionId,
 };
 
 /// Tests compatibility of the `PrepareBasicCircuitsJob` serialization to the previously used
@@ -79,12 +87,54 @@ async fn test_final_proof_deserialization() {
 
     let results: L1BatchProofForL1 = StoredObject::deserialize(proof).unwrap();
 
-    let coords = match results {
+    let coords = match &results {
         L1BatchProofForL1::Fflonk(proof) => proof.aggregation_result_coords,
         L1BatchProofForL1::Plonk(proof) => proof.aggregation_result_coords,
     };
 
     assert_eq!(coords[0][0], 0);
+
+    // Envelope writes are disabled by default, so the proof is written in the legacy format.
+    let serialized = StoredObject::serialize(&results).unwrap();
+    assert_ne!(serialized[..4], ENVELOPE_MAGIC);
+    let restored: L1BatchProofForL1 = StoredObject::deserialize(serialized).unwrap();
+    assert!(matches!(restored, L1BatchProofForL1::Plonk(_)));
+    assert_eq!(restored.aggregation_result_coords(), coords);
+
+    // Check that the legacy proof survives a roundtrip via the versioned format.
+    let serialized = serialize_versioned(&results).unwrap();
+    assert_eq!(serialized[..4], ENVELOPE_MAGIC);
+    let restored: L1BatchProofForL1 = StoredObject::deserialize(serialized).unwrap();
+    assert!(matches!(restored, L1BatchProofForL1::Plonk(_)));
+    assert_eq!(restored.aggregation_result_coords(), coords);
+    assert_eq!(restored.protocol_version(), results.protocol_version());
+}
+
+#[tokio::test]
+async fn compression_checkpoint_roundtrip() {
+    let proof = fs::read("./tests/l1_batch_proof_1_0_24_0.bin")
+        .await
+        .unwrap();
+    let L1BatchProofForL1::Plonk(proof) = StoredObject::deserialize(proof).unwrap() else {
+        panic!("unexpected proof kind");
+    };
+    let protocol_version = proof.protocol_version;
+    let checkpoint = L1BatchCompressionCheckpoint::Plonk(proof.scheduler_proof);
+
+    let store = MockObjectStore::arc();
+    let key = (L1BatchNumber(1), protocol_version);
+    store.put(key, &checkpoint).await.unwrap();
+    let restored: L1BatchCompressionCheckpoint = store.get(key).await.unwrap();
+    assert!(matches!(restored, L1BatchCompressionCheckpoint::Plonk(_)));
+
+    store
+        .remove::<L1BatchCompressionCheckpoint>(key)
+        .await
+        .unwrap();
+    store
+        .get::<L1BatchCompressionCheckpoint>(key)
+        .await
+        .unwrap_err();
 }
 
 #[test]
@@ -199,3 +249,31 @@ fn test_tee_proof_request_serialization() {
     }));
     assert_eq!(tee_proof_result, tee_proof_expected);
 }
+
+#[test]
+fn test_proving_round_notice_serialization() {
+    let notice_str = r#"{
+        "l1_batch_number": 42,
+        "aggregation_round": "LeafAggregation",
+        "status": "failing",
+        "jobs_count": 16,
+        "failed_circuit_ids": [3, 7],
+        "artifact_url": null
+    }"#;
+    let notice = serde_json::from_str::<ProvingRoundNotice>(notice_str).unwrap();
+    let expected_notice = ProvingRoundNotice {
+        l1_batch_number: L1BatchNumber(42),
+        aggregation_round: AggregationRound::LeafAggregation,
+        status: ProvingRoundStatus::Failing,
+        jobs_count: 16,
+        failed_circuit_ids: vec![3, 7],
+        artifact_url: None,
+    };
+    assert_eq!(notice, expected_notice);
+
+    let serialized = serde_json::to_string(&notice).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ProvingRoundNotice>(&serialized).unwrap(),
+        expected_notice
+    );
+}
@@ -1,7 +1,12 @@
 //! Logic for applying application-level snapshots to Postgres storage.
 
 use std::{
-    cmp::Ordering, collections::HashMap, fmt, mem, num::NonZeroUsize, sync::Arc, time::Duration,
+    cmp::Ordering,
+    collections::HashMap,
+    fmt, mem,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
 };
 
 use anyhow::Context as _;
@@ -10,7 +15,7 @@ use serde::Serialize;
 use tokio::sync::{watch, Semaphore};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError, SqlxError};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
-use zksync_object_store::{ObjectStore, ObjectStoreError};
+use zksync_object_store::{ObjectStore, ObjectStoreError, StoredObject};
 use zksync_types::{
     api,
     bytecode::BytecodeHash,
@@ -28,11 +33,15 @@ use zksync_web3_decl::{
     namespaces::{EnNamespaceClient, SnapshotsNamespaceClient, ZksNamespaceClient},
 };
 
-use self::metrics::{InitialStage, StorageLogsChunksStage, METRICS};
+use self::{
+    metrics::{InitialStage, StorageLogsChunksStage, METRICS},
+    throttle::BandwidthThrottler,
+};
 
 mod metrics;
 #[cfg(test)]
 mod tests;
+mod throttle;
 
 #[derive(Debug, Serialize)]
 struct SnapshotsApplierHealthDetails {
@@ -219,6 +228,19 @@ pub struct SnapshotsApplierConfig {
     /// Maximum concurrency factor when performing concurrent operations (for now, the only such operation
     /// is recovering chunks of storage logs).
     pub max_concurrency: NonZeroUsize,
+    /// Maximum number of storage log chunks concurrently inserted into Postgres. Values greater than
+    /// [`Self.max_concurrency`] have no effect. If not set, equals [`Self.max_concurrency`].
+    pub max_concurrent_applications: Option<NonZeroUsize>,
+    /// Maximum number of storage logs inserted into Postgres in a single query. If not set, each storage logs chunk
+    /// is inserted using a single query.
+    pub insertion_batch_size: Option<NonZeroUsize>,
+    /// Maximum bandwidth (in bytes per second) used to download storage log chunks from the object store.
+    /// If not set, downloads are not throttled.
+    pub max_download_bandwidth: Option<NonZeroU64>,
+    /// If set, applying storage log chunks will be paused while the replay lag of Postgres replicas exceeds this value.
+    pub max_replication_lag: Option<Duration>,
+    /// Interval between replication lag checks while applying storage log chunks is paused.
+    pub replication_lag_poll_interval: Duration,
 }
 
 impl Default for SnapshotsApplierConfig {
@@ -228,6 +250,11 @@ impl Default for SnapshotsApplierConfig {
             initial_retry_backoff: Duration::from_secs(2),
             retry_backoff_multiplier: 2.0,
             max_concurrency: NonZeroUsize::new(10).unwrap(),
+            max_concurrent_applications: None,
+            insertion_batch_size: None,
+            max_download_bandwidth: None,
+            max_replication_lag: None,
+            replication_lag_poll_interval: Duration::from_secs(5),
         }
     }
 }
@@ -237,6 +264,7 @@ impl SnapshotsApplierConfig {
     fn for_tests() -> Self {
         Self {
             initial_retry_backoff: Duration::from_millis(5),
+            replication_lag_poll_interval: Duration::from_millis(10),
             ..Self::default()
         }
     }
@@ -569,23 +597,40 @@ enum StorageLogs {
 }
 
 impl StorageLogs {
+    /// Loads a storage logs chunk, returning it together with its serialized size in bytes.
     async fn load(
         blob_store: &dyn ObjectStore,
         key: SnapshotStorageLogsStorageKey,
         version: SnapshotVersion,
-    ) -> Result<Self, ObjectStoreError> {
+    ) -> Result<(Self, usize), ObjectStoreError> {
         match version {
             SnapshotVersion::Version0 => {
-                let logs: SnapshotStorageLogsChunk<StorageKey> = blob_store.get(key).await?;
-                Ok(Self::V0(logs.storage_logs))
+                let (logs, byte_count) =
+                    Self::load_chunk::<SnapshotStorageLogsChunk<StorageKey>>(blob_store, key)
+                        .await?;
+                Ok((Self::V0(logs.storage_logs), byte_count))
             }
             SnapshotVersion::Version1 => {
-                let logs: SnapshotStorageLogsChunk = blob_store.get(key).await?;
-                Ok(Self::V1(logs.storage_logs))
+                let (logs, byte_count) =
+                    Self::load_chunk::<SnapshotStorageLogsChunk>(blob_store, key).await?;
+                Ok((Self::V1(logs.storage_logs), byte_count))
             }
         }
     }
 
+    async fn load_chunk<V>(
+        blob_store: &dyn ObjectStore,
+        key: SnapshotStorageLogsStorageKey,
+    ) -> Result<(V, usize), ObjectStoreError>
+    where
+        V: for<'k> StoredObject<Key<'k> = SnapshotStorageLogsStorageKey>,
+    {
+        let bytes = blob_store.get_raw(V::BUCKET, &V::encode_key(key)).await?;
+        let byte_count = bytes.len();
+        let chunk = V::deserialize(bytes).map_err(ObjectStoreError::Serialization)?;
+        Ok((chunk, byte_count))
+    }
+
     fn len(&self) -> usize {
         match self {
             Self::V0(logs) => logs.len(),
@@ -652,7 +697,8 @@ struct SnapshotsApplier<'a> {
     applied_snapshot_status: SnapshotRecoveryStatus,
     health_updater: &'a HealthUpdater,
     snapshot_version: SnapshotVersion,
-    max_concurrency: usize,
+    config: &'a SnapshotsApplierConfig,
+    download_throttler: Option<BandwidthThrottler>,
     drop_storage_key_preimages: bool,
     factory_deps_recovered: bool,
     tokens_recovered: bool,
@@ -697,7 +743,11 @@ impl<'a> SnapshotsApplier<'a> {
             applied_snapshot_status,
             health_updater,
             snapshot_version,
-            max_concurrency: task.config.max_concurrency.get(),
+            config: &task.config,
+            download_throttler: task
+                .config
+                .max_download_bandwidth
+                .map(BandwidthThrottler::new),
             drop_storage_key_preimages: task.drop_storage_key_preimages,
             factory_deps_recovered: !created_from_scratch,
             tokens_recovered: false,
@@ -826,15 +876,23 @@ impl<'a> SnapshotsApplier<'a> {
         Ok(())
     }
 
+    fn insertion_batch_size(&self) -> usize {
+        self.config
+            .insertion_batch_size
+            .map_or(usize::MAX, NonZeroUsize::get)
+    }
+
     async fn insert_initial_writes_chunk(
         &self,
         storage_logs: &[SnapshotStorageLog],
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), SnapshotsApplierError> {
-        storage
-            .storage_logs_dedup_dal()
-            .insert_initial_writes_from_snapshot(storage_logs)
-            .await?;
+        for batch in storage_logs.chunks(self.insertion_batch_size()) {
+            storage
+                .storage_logs_dedup_dal()
+                .insert_initial_writes_from_snapshot(batch)
+                .await?;
+        }
         Ok(())
     }
 
@@ -843,34 +901,65 @@ impl<'a> SnapshotsApplier<'a> {
         storage_logs: &StorageLogs,
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), SnapshotsApplierError> {
+        let batch_size = self.insertion_batch_size();
         match storage_logs {
             StorageLogs::V0(logs) => {
-                #[allow(deprecated)]
-                storage
-                    .storage_logs_dal()
-                    .insert_storage_logs_with_preimages_from_snapshot(
-                        self.applied_snapshot_status.l2_block_number,
-                        logs,
-                    )
-                    .await?;
+                for batch in logs.chunks(batch_size) {
+                    #[allow(deprecated)]
+                    storage
+                        .storage_logs_dal()
+                        .insert_storage_logs_with_preimages_from_snapshot(
+                            self.applied_snapshot_status.l2_block_number,
+                            batch,
+                        )
+                        .await?;
+                }
             }
             StorageLogs::V1(logs) => {
-                storage
-                    .storage_logs_dal()
-                    .insert_storage_logs_from_snapshot(
-                        self.applied_snapshot_status.l2_block_number,
-                        logs,
-                    )
-                    .await?;
+                for batch in logs.chunks(batch_size) {
+                    storage
+                        .storage_logs_dal()
+                        .insert_storage_logs_from_snapshot(
+                            self.applied_snapshot_status.l2_block_number,
+                            batch,
+                        )
+                        .await?;
+                }
             }
         }
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", err, skip(self, semaphore))]
+    /// Waits until the replay lag of Postgres replicas drops to an acceptable level (if the max lag is configured).
+    async fn wait_for_replication_lag(&self) -> Result<(), SnapshotsApplierError> {
+        let Some(max_lag) = self.config.max_replication_lag else {
+            return Ok(());
+        };
+
+        loop {
+            let mut storage = self
+                .connection_pool
+                .connection_tagged("snapshots_applier")
+                .await?;
+            let lag = storage.system_dal().get_max_replica_lag().await?;
+            drop(storage);
+
+            METRICS.replication_lag.set(lag);
+            if lag <= max_lag {
+                return Ok(());
+            }
+            tracing::info!(
+                "Postgres replication lag {lag:?} exceeds configured maximum {max_lag:?}; pausing storage logs application"
+            );
+            tokio::time::sleep(self.config.replication_lag_poll_interval).await;
+        }
+    }
+
+    #[tracing::instrument(level = "debug", err, skip(self, semaphore, application_semaphore))]
     async fn recover_storage_logs_single_chunk(
         &self,
         semaphore: &Semaphore,
+        application_semaphore: &Semaphore,
         chunk_id: u64,
     ) -> Result<(), SnapshotsApplierError> {
         // `unwrap()` is safe: the semaphore is never closed
@@ -884,7 +973,7 @@ impl<'a> SnapshotsApplier<'a> {
             chunk_id,
             l1_batch_number: self.applied_snapshot_status.l1_batch_number,
         };
-        let mut storage_logs =
+        let (mut storage_logs, byte_count) =
             StorageLogs::load(self.blob_store, storage_key, self.snapshot_version)
                 .await
                 .map_err(|err| {
@@ -892,6 +981,14 @@ impl<'a> SnapshotsApplier<'a> {
                         format!("cannot fetch storage logs {storage_key:?} from object store");
                     SnapshotsApplierError::object_store(err, context)
                 })?;
+        METRICS.downloaded_bytes.inc_by(byte_count as u64);
+        if let Some(throttler) = &self.download_throttler {
+            let delay = throttler.throttle(byte_count as u64).await;
+            if !delay.is_zero() {
+                METRICS.download_throttling_duration.observe(delay);
+                tracing::debug!("Throttled download of storage logs chunk {chunk_id} by {delay:?}");
+            }
+        }
 
         storage_logs.validate(&self.applied_snapshot_status)?;
         if self.drop_storage_key_preimages {
@@ -903,6 +1000,10 @@ impl<'a> SnapshotsApplier<'a> {
             storage_logs.len()
         );
 
+        // `unwrap()` is safe: the semaphore is never closed
+        let _application_permit = application_semaphore.acquire().await.unwrap();
+        self.wait_for_replication_lag().await?;
+
         let latency =
             METRICS.storage_logs_chunks_duration[&StorageLogsChunksStage::SaveToPostgres].start();
 
@@ -938,11 +1039,19 @@ impl<'a> SnapshotsApplier<'a> {
         stop_receiver: &mut watch::Receiver<bool>,
     ) -> Result<(), SnapshotsApplierError> {
         let effective_concurrency =
-            (self.connection_pool.max_size() as usize).min(self.max_concurrency);
+            (self.connection_pool.max_size() as usize).min(self.config.max_concurrency.get());
+        let application_concurrency = self
+            .config
+            .max_concurrent_applications
+            .map_or(effective_concurrency, |limit| {
+                limit.get().min(effective_concurrency)
+            });
         tracing::info!(
-            "Recovering storage log chunks with {effective_concurrency} max concurrency"
+            "Recovering storage log chunks with {effective_concurrency} max concurrency \
+             ({application_concurrency} max concurrent applications)"
         );
         let semaphore = Semaphore::new(effective_concurrency);
+        let application_semaphore = Semaphore::new(application_concurrency);
 
         let tasks = self
             .applied_snapshot_status
@@ -951,7 +1060,11 @@ impl<'a> SnapshotsApplier<'a> {
             .enumerate()
             .filter(|(_, is_processed)| !**is_processed)
             .map(|(chunk_id, _)| {
-                self.recover_storage_logs_single_chunk(&semaphore, chunk_id as u64)
+                self.recover_storage_logs_single_chunk(
+                    &semaphore,
+                    &application_semaphore,
+                    chunk_id as u64,
+                )
             });
         let job_completion = futures::future::try_join_all(tasks);
 
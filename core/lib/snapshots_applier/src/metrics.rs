@@ -2,7 +2,9 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit};
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -38,6 +40,16 @@ pub(crate) struct SnapshotsApplierMetrics {
     /// Latency of storage log chunk processing split by stage.
     #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
     pub storage_logs_chunks_duration: Family<StorageLogsChunksStage, Histogram<Duration>>,
+
+    /// Total number of bytes downloaded from the object store for storage log chunks.
+    #[metrics(unit = Unit::Bytes)]
+    pub downloaded_bytes: Counter,
+    /// Delays introduced by download bandwidth throttling.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub download_throttling_duration: Histogram<Duration>,
+    /// Last observed replay lag of Postgres replicas. Only updated if the max replication lag is configured.
+    #[metrics(unit = Unit::Seconds)]
+    pub replication_lag: Gauge<Duration>,
 }
 
 #[vise::register]
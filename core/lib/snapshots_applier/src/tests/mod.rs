@@ -243,6 +243,50 @@ async fn applier_recovers_explicitly_specified_snapshot() {
     assert_eq!(all_storage_logs.len(), storage_logs.len());
 }
 
+#[tokio::test]
+async fn applier_recovers_snapshot_with_throttling_options() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let expected_status = mock_recovery_status();
+    let storage_logs = random_storage_logs::<H256>(expected_status.l1_batch_number, 200);
+    let (object_store, client) = prepare_clients(&expected_status, &storage_logs).await;
+
+    let config = SnapshotsApplierConfig {
+        max_concurrent_applications: Some(NonZeroUsize::new(1).unwrap()),
+        insertion_batch_size: Some(NonZeroUsize::new(7).unwrap()),
+        max_download_bandwidth: Some(NonZeroU64::new(u64::MAX).unwrap()),
+        max_replication_lag: Some(Duration::from_secs(60)),
+        ..SnapshotsApplierConfig::for_tests()
+    };
+    let task = SnapshotsApplierTask::new(config, pool.clone(), Box::new(client), object_store);
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let stats = task.run(stop_receiver).await.unwrap();
+    assert!(stats.done_work);
+
+    let mut storage = pool.connection().await.unwrap();
+    let all_storage_logs = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await;
+    assert_eq!(all_storage_logs.len(), storage_logs.len());
+    let all_initial_writes = storage
+        .storage_logs_dedup_dal()
+        .dump_all_initial_writes_for_tests()
+        .await;
+    assert_eq!(all_initial_writes.len(), storage_logs.len());
+}
+
+#[tokio::test(start_paused = true)]
+async fn bandwidth_throttler_delays_downloads() {
+    let throttler = BandwidthThrottler::new(NonZeroU64::new(1_000).unwrap());
+    assert_eq!(throttler.record_download(500), Duration::from_millis(500));
+    assert_eq!(throttler.record_download(1_500), Duration::from_secs(2));
+
+    let delay = throttler.throttle(0).await;
+    assert_eq!(delay, Duration::from_secs(2));
+    // The expected download time has elapsed, so no further delay is necessary.
+    assert_eq!(throttler.record_download(0), Duration::ZERO);
+}
+
 #[tokio::test]
 async fn applier_error_for_missing_explicitly_specified_snapshot() {
     let pool = ConnectionPool::<Core>::test_pool().await;
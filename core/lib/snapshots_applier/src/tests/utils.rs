@@ -227,6 +227,7 @@ fn l1_batch_details(number: L1BatchNumber, root_hash: H256) -> api::L1BatchDetai
     api::L1BatchDetails {
         number,
         base: block_details_base(root_hash),
+        proving_rounds: vec![],
     }
 }
 
@@ -292,9 +293,11 @@ pub(super) fn mock_snapshot_header(
             .map(|chunk_id| SnapshotStorageLogsChunkMetadata {
                 chunk_id,
                 filepath: format!("file{chunk_id}"),
+                download_url: None,
             })
             .collect(),
         factory_deps_filepath: "some_filepath".to_string(),
+        factory_deps_download_url: None,
     }
 }
 
@@ -0,0 +1,50 @@
+//! Download bandwidth throttling for the snapshot applier.
+
+use std::{num::NonZeroU64, sync::Mutex, time::Duration};
+
+use tokio::time::Instant;
+
+#[derive(Debug)]
+struct ThrottlerState {
+    started_at: Instant,
+    downloaded_bytes: u64,
+}
+
+/// Throttler limiting the average bandwidth of object store downloads shared among all concurrent chunk tasks.
+#[derive(Debug)]
+pub(crate) struct BandwidthThrottler {
+    max_bytes_per_second: NonZeroU64,
+    state: Mutex<ThrottlerState>,
+}
+
+impl BandwidthThrottler {
+    pub fn new(max_bytes_per_second: NonZeroU64) -> Self {
+        Self {
+            max_bytes_per_second,
+            state: Mutex::new(ThrottlerState {
+                started_at: Instant::now(),
+                downloaded_bytes: 0,
+            }),
+        }
+    }
+
+    /// Records `byte_count` downloaded bytes and returns the delay that should be awaited by the caller
+    /// so that the average download bandwidth doesn't exceed the configured limit.
+    pub fn record_download(&self, byte_count: u64) -> Duration {
+        let mut state = self.state.lock().expect("throttler state is poisoned");
+        state.downloaded_bytes += byte_count;
+        let expected_elapsed = Duration::from_secs_f64(
+            state.downloaded_bytes as f64 / self.max_bytes_per_second.get() as f64,
+        );
+        expected_elapsed.saturating_sub(state.started_at.elapsed())
+    }
+
+    /// Same as [`Self::record_download()`], but also sleeps for the returned delay.
+    pub async fn throttle(&self, byte_count: u64) -> Duration {
+        let delay = self.record_download(byte_count);
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        delay
+    }
+}
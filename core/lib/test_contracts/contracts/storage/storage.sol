@@ -99,5 +99,39 @@ contract StorageTester {
         this.testTstoreRollback();
     }
 
+    // Operation kinds for `applyOps()`.
+    uint8 constant OP_WRITE = 0;
+    uint8 constant OP_REVERTED_WRITE = 1;
+    uint8 constant OP_TRANSIENT_WRITE = 2;
+
+    // Applies a sequence of storage operations; used in property-based tests of pubdata accounting.
+    function applyOps(uint8[] calldata kinds, uint256[] calldata slots, uint256[] calldata values) external {
+        for (uint256 i = 0; i < kinds.length; i++) {
+            uint256 slot = slots[i];
+            uint256 newValue = values[i];
+            if (kinds[i] == OP_WRITE) {
+                assembly {
+                    sstore(slot, newValue)
+                }
+            } else if (kinds[i] == OP_REVERTED_WRITE) {
+                (bool success, ) = (address(this)).call(abi.encodeWithSignature("writeSlotAndRevert(uint256,uint256)", slot, newValue));
+                require(!success, "The call should have failed");
+            } else if (kinds[i] == OP_TRANSIENT_WRITE) {
+                assembly {
+                    tstore(slot, newValue)
+                }
+            } else {
+                revert("Unknown operation");
+            }
+        }
+    }
+
+    function writeSlotAndRevert(uint256 slot, uint256 newValue) external {
+        assembly {
+            sstore(slot, newValue)
+        }
+        revert("This method always reverts");
+    }
+
     fallback() external {}
 }
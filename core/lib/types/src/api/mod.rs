@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use serde_with::{hex::Hex, serde_as};
 use zksync_basic_types::{
+    basic_fri_types::AggregationRound,
     commitment::PubdataType,
+    settlement::SettlementMode,
     web3::{AccessList, Bytes, Index},
     Bloom, L1BatchNumber, SLChainId, H160, H256, H64, U256, U64,
 };
@@ -15,7 +19,8 @@ pub use crate::transaction_request::{
 };
 use crate::{
     debug_flat_call::{DebugCallFlat, ResultDebugCallFlat},
-    protocol_version::L1VerifierConfig,
+    fee::Fee,
+    protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
     tee_types::TeeType,
     Address, L2BlockNumber, ProtocolVersionId,
 };
@@ -677,6 +682,9 @@ pub struct DebugCall {
     pub error: Option<String>,
     pub revert_reason: Option<String>,
     pub calls: Vec<DebugCall>,
+    /// Set if some of the nested `calls` were omitted because the trace exceeded the server-side size limit.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
 }
 
 // TODO (PLA-965): remove deprecated fields from the struct. It is currently in a "migration" phase
@@ -898,6 +906,34 @@ pub struct L1BatchDetails {
     pub number: L1BatchNumber,
     #[serde(flatten)]
     pub base: BlockDetailsBase,
+    /// Progress of proof generation reported by provers before the final proof is available.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proving_rounds: Vec<ProvingRoundDetails>,
+}
+
+/// Status of a single aggregation round of the batch proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProvingRoundStatus {
+    /// All proofs of the round are generated.
+    Completed,
+    /// Some proofs of the round have failed; the failed circuits are listed in the round details.
+    Failing,
+}
+
+/// Intermediate proof generation progress for an aggregation round of an L1 batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvingRoundDetails {
+    pub aggregation_round: AggregationRound,
+    pub status: ProvingRoundStatus,
+    /// Total number of prover jobs in the round.
+    pub jobs_count: u32,
+    /// IDs of circuits with failed proofs. Empty for completed rounds.
+    pub failed_circuit_ids: Vec<u8>,
+    /// Reference to the round artifact in the prover object store, if the round produces a single artifact.
+    pub artifact_url: Option<String>,
+    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -942,6 +978,79 @@ pub struct TransactionDetailedResult {
     pub events: Vec<Log>,
 }
 
+/// Result of simulating a single L1->L2 transaction returned by `zks_simulateL1ToL2Transactions`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L1ToL2TransactionSimulation {
+    /// Whether the transaction would succeed if submitted on L1 with `gas_limit`.
+    pub success: bool,
+    /// L2 gas limit the transaction was executed with: either the limit provided by the caller, or the estimated one.
+    /// `None` if the transaction could not be executed (e.g., if gas estimation has failed).
+    pub gas_limit: Option<U256>,
+    /// Gas used by the transaction. `None` if the transaction could not be executed.
+    pub gas_used: Option<U256>,
+    /// Gas per pubdata byte used for the simulation.
+    pub gas_per_pubdata: U256,
+    /// Human-readable reason of the failure.
+    pub error: Option<String>,
+    /// Revert data of the failed transaction, if any.
+    pub revert_data: Option<Bytes>,
+}
+
+/// Fee estimate returned by `zks_estimateFee`.
+///
+/// Fee fields are flattened, so the response stays compatible with clients expecting a plain [`Fee`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeeEstimate {
+    #[serde(flatten)]
+    pub fee: Fee,
+    /// Components the gas limit is composed of. May be absent if returned by older servers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<FeeBreakdown>,
+}
+
+/// Breakdown of an estimated gas limit. Field naming follows [`Fee`].
+///
+/// The estimated gas limit is `(computation_gas + pubdata_gas) * scale_factor + overhead_gas`
+/// (up to rounding).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    /// Gas spent on execution, excluding gas spent on publishing pubdata.
+    pub computation_gas: U256,
+    /// Number of pubdata bytes published by the transaction.
+    pub pubdata_bytes: U256,
+    /// Gas per pubdata byte used for the estimation.
+    pub gas_per_pubdata: U256,
+    /// Gas spent on publishing pubdata, i.e. `pubdata_bytes * gas_per_pubdata`.
+    pub pubdata_gas: U256,
+    /// Transaction overhead charged by the bootloader.
+    pub overhead_gas: U256,
+    /// Scale factor applied to the sum of computation and pubdata gas.
+    pub scale_factor: f64,
+}
+
+/// `gas_per_pubdata` value at a certain point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPerPubdataSample {
+    /// UNIX timestamp (in seconds) of the sample.
+    pub timestamp: u64,
+    /// Fair pubdata price (in the base token) used to derive the gas per pubdata.
+    pub fair_pubdata_price: u64,
+    pub gas_per_pubdata: u64,
+}
+
+/// Current, recent and forecasted `gas_per_pubdata` values returned by `zks_getGasPerPubdata`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasPerPubdataInfo {
+    pub current: GasPerPubdataSample,
+    /// Recent samples ordered by ascending timestamp. Includes the current sample.
+    pub history: Vec<GasPerPubdataSample>,
+    /// Forecast extrapolating the trend of the fair pubdata price; `None` if there are not enough samples.
+    pub forecast: Option<GasPerPubdataSample>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiStorageLog {
@@ -950,6 +1059,159 @@ pub struct ApiStorageLog {
     pub written_value: U256,
 }
 
+/// Storage slot accessed during transaction execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageSlot {
+    pub address: Address,
+    pub key: H256,
+}
+
+/// Storage slots read and written during transaction execution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAccessSet {
+    /// Slots read by the transaction. May intersect with `writes`.
+    pub reads: Vec<StorageSlot>,
+    /// Slots written by the transaction.
+    pub writes: Vec<StorageSlot>,
+}
+
+/// Storage slot returned by `debug_storageRangeAt`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRangeEntry {
+    /// Unhashed slot key. May be `null` for slots restored from a snapshot.
+    pub key: Option<H256>,
+    pub value: H256,
+}
+
+/// Result of `debug_storageRangeAt`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRangeResult {
+    /// Non-zero storage slots keyed by the hashed slot key.
+    pub storage: BTreeMap<H256, StorageRangeEntry>,
+    /// Hashed key to start the next page from, or `null` if there are no more slots.
+    pub next_key: Option<H256>,
+}
+
+/// Known deviation of an EVM opcode from its Ethereum semantics when executed by the EVM emulator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmOpcodeDeviation {
+    /// Opcode mnemonic, e.g. `SELFDESTRUCT`.
+    pub opcode: String,
+    pub description: String,
+}
+
+/// EVM compatibility information returned by `zks_getEvmCompatibilityInfo`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmCompatibilityInfo {
+    /// Protocol version used to execute the pending L2 block.
+    pub protocol_version: ProtocolVersionId,
+    /// Chain ID as returned by `eth_chainId`.
+    pub chain_id: U64,
+    /// Network ID as returned by `net_version`. Always equal to `chain_id` in decimal form.
+    pub net_version: String,
+    /// Whether the protocol version supports the EVM emulator.
+    pub evm_emulator_supported: bool,
+    /// Whether EVM bytecode deployment is enabled on the chain.
+    pub evm_emulator_enabled: bool,
+    /// Ethereum hard fork which semantics the EVM emulator follows; `None` if the emulator is not supported.
+    pub evm_version: Option<String>,
+    /// Opcodes which semantics differ from Ethereum. Empty if the emulator is not supported.
+    pub opcode_deviations: Vec<EvmOpcodeDeviation>,
+}
+
+/// System contract deployed on L2, as returned by `zks_getSystemContracts`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemContractInfo {
+    /// Contract name, e.g. `ContractDeployer` or `L2NativeTokenVault`.
+    pub name: String,
+    pub address: Address,
+    /// Bytecode hash recorded in `AccountCodeStorage` as of the latest sealed L2 block; `None` if the contract
+    /// is not deployed on the chain (e.g., it was introduced in a later protocol version).
+    pub code_hash: Option<H256>,
+}
+
+/// System contracts information returned by `zks_getSystemContracts`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemContracts {
+    /// Protocol version used to execute the pending L2 block.
+    pub protocol_version: ProtocolVersionId,
+    pub bootloader_code_hash: H256,
+    pub default_aa_code_hash: H256,
+    /// EVM emulator code hash; `None` if the EVM emulator is not enabled on the chain.
+    pub evm_emulator_code_hash: Option<H256>,
+    /// Contracts deployed to system addresses.
+    pub contracts: Vec<SystemContractInfo>,
+}
+
+/// Parameters required to register a chain in an ecosystem, returned by `zks_getChainCreationParams`.
+/// All values are taken from the genesis L1 batch of the chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainCreationParams {
+    pub genesis_root: H256,
+    pub genesis_rollup_leaf_index: u64,
+    pub genesis_batch_commitment: H256,
+    pub protocol_version: ProtocolSemanticVersion,
+    /// Protocol version packed in the format used by L1 contracts.
+    pub packed_protocol_version: U256,
+    pub bootloader_hash: H256,
+    pub default_aa_hash: H256,
+    pub evm_emulator_hash: Option<H256>,
+}
+
+/// Details of a protocol version (including its patch), as returned by `zks_getProtocolVersionInfo`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolVersionDetails {
+    pub version: ProtocolSemanticVersion,
+    /// Timestamp starting from which L1 batches may use the version.
+    pub activation_timestamp: u64,
+    pub bootloader_code_hash: H256,
+    pub default_account_code_hash: H256,
+    pub evm_emulator_code_hash: Option<H256>,
+    pub snark_wrapper_vk_hash: H256,
+    pub fflonk_snark_wrapper_vk_hash: Option<H256>,
+    /// Address of the verifier contract on the settlement layer; `None` if unknown to the node
+    /// (e.g., for external nodes or for versions persisted before the address was tracked).
+    pub verifier_address: Option<Address>,
+    /// Hash of the L2 system upgrade transaction executed in the first L1 batch using the version.
+    pub l2_system_upgrade_tx_hash: Option<H256>,
+}
+
+/// Current and scheduled protocol versions returned by `zks_getProtocolVersionInfo`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolVersionInfo {
+    /// Protocol version used to execute the pending L2 block.
+    pub current: ProtocolVersionDetails,
+    /// Newer protocol versions observed on the settlement layer, in the ascending order. A version is
+    /// applied once its activation timestamp passes and a new L1 batch is started.
+    pub scheduled: Vec<ProtocolVersionDetails>,
+}
+
+/// Version and build information returned by `zks_getNodeVersion`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeVersion {
+    /// Semantic version of the node.
+    pub version: String,
+    /// Git revision the node was built from, if known.
+    pub git_commit: Option<String>,
+    /// Minor protocol versions the node can execute. Represented as raw numbers so that versions
+    /// unknown to the client can be deserialized.
+    pub supported_protocol_versions: Vec<u16>,
+    /// Optional API features enabled on the node, e.g. `filters` or `proofs`.
+    pub api_features: Vec<String>,
+}
+
 /// Raw transaction execution data.
 /// Data is taken from `TransactionExecutionMetrics`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -987,6 +1249,22 @@ pub struct L1ToL2TxsStatus {
     pub l1_to_l2_txs_paused: bool,
 }
 
+/// Status of the chain migration between L1 and Gateway.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayMigrationStatus {
+    /// Settlement mode the node is configured to use, as reported by the settlement layer data task.
+    /// `None` if the node doesn't track settlement layer data, or it wasn't loaded yet.
+    pub settlement_mode: Option<SettlementMode>,
+    /// Number of unconfirmed settlement layer transactions for the current settlement mode.
+    /// The settlement layer cannot be switched until all of them are confirmed.
+    pub inflight_txs_count: usize,
+    /// Chain ID of the settlement layer, as reported by the settlement layer data task.
+    pub settlement_layer: Option<SLChainId>,
+    /// Whether a settlement layer migration was requested on L1 but hasn't finished yet.
+    pub migration_pending: Option<bool>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,6 +279,26 @@ impl VerificationInfo {
     }
 }
 
+/// Status of a match between an unverified contract and a verified contract with the same bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SimilarMatchStatus {
+    /// Bytecodes are identical, including metadata.
+    Perfect,
+    /// Bytecodes are identical except for metadata.
+    Partial,
+}
+
+/// Verified contract with the same bytecode as the requested unverified contract.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarMatch {
+    pub matched_contract_address: Address,
+    pub status: SimilarMatchStatus,
+    /// Verification info of the matched contract.
+    pub verification_info: VerificationInfo,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationRequestStatus {
@@ -289,6 +309,52 @@ pub struct VerificationRequestStatus {
     pub compilation_errors: Option<Vec<String>>,
 }
 
+/// Verification request in the queue, as returned by the verifier admin API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationRequestSummary {
+    pub id: usize,
+    pub contract_address: Address,
+    pub status: String,
+    /// Requester specified in the verification request, if any.
+    pub requester: Option<String>,
+    /// Priority assigned by the admin API; requests with higher priority are processed first.
+    pub priority: i32,
+    /// Whether the request was submitted by a trusted requester and is processed in the priority lane.
+    pub priority_lane: bool,
+    pub attempts: u32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Detailed information about a verification request, including the compiler output for failed requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationRequestDetails {
+    #[serde(flatten)]
+    pub summary: VerificationRequestSummary,
+    pub contract_name: String,
+    pub compiler_versions: CompilerVersions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compilation_errors: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic_message: Option<String>,
+}
+
+/// Admin API request to change the priority of queued verification requests. At least one of the filters
+/// (`address` or `requester`) must be specified.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritizeVerificationRequests {
+    #[serde(default)]
+    pub address: Option<Address>,
+    #[serde(default)]
+    pub requester: Option<String>,
+    pub priority: i32,
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
@@ -0,0 +1,83 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Address, L2BlockNumber};
+
+/// Direction of syncing verified sources with Sourcify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourcifySyncDirection {
+    /// Sources verified on Sourcify are imported as local verification requests.
+    Import,
+    /// Sources verified locally are pushed to Sourcify.
+    Export,
+}
+
+impl SourcifySyncDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Import => "import",
+            Self::Export => "export",
+        }
+    }
+}
+
+/// Outcome of syncing a single contract with Sourcify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourcifySyncStatus {
+    /// Sources were imported from Sourcify, or exported to Sourcify.
+    Synced,
+    /// The contract is not verified on Sourcify (for imports).
+    NotFound,
+    /// The contract is already verified on the other side with the same or better match, so nothing was synced.
+    AlreadyVerified,
+    /// The contract cannot be synced, e.g. because it's an EraVM contract or the verification request
+    /// format isn't supported by Sourcify.
+    Unsupported,
+    /// Syncing failed with a non-retryable error.
+    Failed,
+}
+
+impl SourcifySyncStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Synced => "synced",
+            Self::NotFound => "not_found",
+            Self::AlreadyVerified => "already_verified",
+            Self::Unsupported => "unsupported",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for SourcifySyncStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SourcifySyncStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "synced" => Self::Synced,
+            "not_found" => Self::NotFound,
+            "already_verified" => Self::AlreadyVerified,
+            "unsupported" => Self::Unsupported,
+            "failed" => Self::Failed,
+            _ => anyhow::bail!("unknown Sourcify sync status: {s}"),
+        })
+    }
+}
+
+/// Record about syncing a contract with Sourcify.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourcifySyncRecord {
+    pub contract_address: Address,
+    pub direction: SourcifySyncDirection,
+    /// L2 block in which the contract was deployed. Only set for imports.
+    pub l2_block_number: Option<L2BlockNumber>,
+    pub status: SourcifySyncStatus,
+    /// ID of the local verification request created on import.
+    pub verification_request_id: Option<usize>,
+    pub error: Option<String>,
+}
@@ -23,6 +23,9 @@ pub struct DebugCallFlat {
     pub block_number: u32,
     pub block_hash: H256,
     pub r#type: DebugCallType,
+    /// Set if some of the subtraces of this call were omitted because the trace exceeded the server-side size limit.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
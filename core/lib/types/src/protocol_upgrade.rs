@@ -355,6 +355,8 @@ pub struct ProtocolVersion {
     pub timestamp: u64,
     /// Verifier configuration
     pub l1_verifier_config: L1VerifierConfig,
+    /// Address of the verifier contract on the settlement layer, if known
+    pub verifier_address: Option<Address>,
     /// Hashes of base system contracts (bootloader and default account)
     pub base_system_contracts_hashes: BaseSystemContractsHashes,
     /// L2 Upgrade transaction.
@@ -378,6 +380,7 @@ impl ProtocolVersion {
                 fflonk_snark_wrapper_vk_hash: new_fflonk_snark_wrapper_vk_hash
                     .or(self.l1_verifier_config.fflonk_snark_wrapper_vk_hash),
             },
+            verifier_address: upgrade.verifier_address.or(self.verifier_address),
             base_system_contracts_hashes: BaseSystemContractsHashes {
                 bootloader: upgrade
                     .bootloader_code_hash
@@ -61,6 +61,10 @@ pub struct SnapshotHeader {
     /// Ordered by chunk IDs.
     pub storage_logs_chunks: Vec<SnapshotStorageLogsChunkMetadata>,
     pub factory_deps_filepath: String,
+    /// Expiring URL to download factory dependencies from without object store credentials.
+    /// Only provided if the API server is configured to sign snapshot URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_deps_download_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -69,6 +73,10 @@ pub struct SnapshotStorageLogsChunkMetadata {
     pub chunk_id: u64,
     // can be either be a file available under HTTP(s) or local filesystem path
     pub filepath: String,
+    /// Expiring URL to download the chunk from without object store credentials.
+    /// Only provided if the API server is configured to sign snapshot URLs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
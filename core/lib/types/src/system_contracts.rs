@@ -261,3 +261,12 @@ pub fn get_system_smart_contracts_from_dir(path: PathBuf) -> Vec<DeployedContrac
         })
         .collect::<Vec<_>>()
 }
+
+/// Returns names and addresses of system contracts deployed at genesis. Empty contracts deployed
+/// to the zero and bootloader addresses are skipped.
+pub fn system_contract_addresses() -> impl Iterator<Item = (&'static str, Address)> {
+    SYSTEM_CONTRACT_LIST
+        .iter()
+        .filter(|(_, name, ..)| *name != "EmptyContract")
+        .map(|(_, name, address, _)| (*name, *address))
+}
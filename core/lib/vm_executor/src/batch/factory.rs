@@ -25,6 +25,7 @@ use zksync_types::{commitment::PubdataParams, vm::FastVmMode, Transaction};
 use super::{
     executor::{Command, MainBatchExecutor},
     metrics::{TxExecutionStage, BATCH_TIP_METRICS, EXECUTOR_METRICS, KEEPER_METRICS},
+    shadow_parallel::ShadowParallelExecution,
 };
 use crate::shared::{InteractionType, Sealed, STORAGE_METRICS};
 
@@ -91,6 +92,7 @@ pub struct MainBatchExecutorFactory<Tr> {
     observe_storage_metrics: bool,
     skip_signature_verification: bool,
     divergence_handler: Option<DivergenceHandler>,
+    shadow_parallel_execution: bool,
     _tracer: PhantomData<Tr>,
 }
 
@@ -102,6 +104,7 @@ impl<Tr: BatchTracer> MainBatchExecutorFactory<Tr> {
             observe_storage_metrics: false,
             skip_signature_verification: false,
             divergence_handler: None,
+            shadow_parallel_execution: false,
             _tracer: PhantomData,
         }
     }
@@ -136,6 +139,16 @@ impl<Tr: BatchTracer> MainBatchExecutorFactory<Tr> {
     pub fn skip_signature_verification(&mut self) {
         self.skip_signature_verification = true;
     }
+
+    /// Enables experimental parallel execution of transactions in shadow mode. After each L2 block, its transactions
+    /// are speculatively re-executed in parallel, and the results are compared with the results of sequential execution.
+    /// Results of the parallel execution are never used otherwise.
+    ///
+    /// This significantly increases the executor load and delays the start of each L2 block, so it should not be used in production.
+    pub fn enable_shadow_parallel_execution(&mut self) {
+        tracing::warn!("Enabled shadow parallel execution; this will slow down the batch executor");
+        self.shadow_parallel_execution = true;
+    }
 }
 
 impl<S: ReadStorage + Send + 'static, Tr: BatchTracer> BatchExecutorFactory<S>
@@ -157,6 +170,7 @@ impl<S: ReadStorage + Send + 'static, Tr: BatchTracer> BatchExecutorFactory<S>
             observe_storage_metrics: self.observe_storage_metrics,
             skip_signature_verification: self.skip_signature_verification,
             divergence_handler: self.divergence_handler.clone(),
+            shadow_parallel_execution: self.shadow_parallel_execution,
             commands: commands_receiver,
             _storage: PhantomData,
             _tracer: PhantomData::<Tr>,
@@ -309,6 +323,7 @@ struct CommandReceiver<S, Tr> {
     observe_storage_metrics: bool,
     skip_signature_verification: bool,
     divergence_handler: Option<DivergenceHandler>,
+    shadow_parallel_execution: bool,
     commands: mpsc::Receiver<Command>,
     _storage: PhantomData<S>,
     _tracer: PhantomData<Tr>,
@@ -324,6 +339,9 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
     ) -> anyhow::Result<StorageView<S>> {
         tracing::info!("Starting executing L1 batch #{}", &l1_batch_params.number);
 
+        let mut shadow_parallel = self
+            .shadow_parallel_execution
+            .then(|| ShadowParallelExecution::new(l1_batch_params.clone(), system_env.clone()));
         let storage_view = StorageView::new(storage).to_rc_ptr();
         let mut vm = BatchVm::<S, Tr>::new(
             l1_batch_params,
@@ -350,9 +368,13 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
             match cmd {
                 Command::ExecuteTx(tx, resp) => {
                     let tx_hash = tx.hash();
+                    let tx_copy = shadow_parallel.as_ref().map(|_| (*tx).clone());
                     let (result, latency) = self.execute_tx(*tx, &mut vm).with_context(|| {
                         format!("fatal error executing transaction {tx_hash:?}")
                     })?;
+                    if let (Some(shadow), Some(tx)) = (&mut shadow_parallel, tx_copy) {
+                        shadow.push_tx(tx, &result.tx_result);
+                    }
 
                     if self.observe_storage_metrics {
                         let storage_stats = storage_view.borrow().stats();
@@ -366,17 +388,26 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
                 }
                 Command::RollbackLastTx(resp) => {
                     self.rollback_last_tx(&mut vm);
+                    if let Some(shadow) = &mut shadow_parallel {
+                        shadow.pop_tx();
+                    }
                     if resp.send(()).is_err() {
                         break;
                     }
                 }
                 Command::StartNextL2Block(l2_block_env, resp) => {
+                    if let Some(shadow) = &mut shadow_parallel {
+                        shadow.finish_l2_block(&storage_view, Some(l2_block_env));
+                    }
                     vm.start_new_l2_block(l2_block_env);
                     if resp.send(()).is_err() {
                         break;
                     }
                 }
                 Command::FinishBatch(resp) => {
+                    if let Some(shadow) = &mut shadow_parallel {
+                        shadow.finish_l2_block(&storage_view, None);
+                    }
                     let vm_block_result = self.finish_batch(&mut vm, pubdata_builder)?;
                     if resp.send(vm_block_result).is_err() {
                         break;
@@ -2,7 +2,7 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics};
+use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics};
 use zksync_multivm::interface::VmExecutionResultAndLogs;
 
 use crate::shared::InteractionType;
@@ -98,3 +98,31 @@ impl BatchTipMetrics {
 
 #[vise::register]
 pub(super) static BATCH_TIP_METRICS: vise::Global<BatchTipMetrics> = vise::Global::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "outcome", rename_all = "snake_case")]
+pub(super) enum SpeculationOutcome {
+    /// Speculative result matches the sequential one.
+    Matched,
+    /// Speculative result doesn't conflict with preceding transactions, but differs from the sequential one.
+    Diverged,
+    /// Transaction has read slots written by preceding transactions; its speculative result is discarded.
+    Conflicted,
+    /// Speculative execution has failed (e.g., because of failed bytecode compression or a VM panic).
+    Failed,
+}
+
+/// Metrics for shadow parallel execution of transactions.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "state_keeper_shadow_parallel")]
+pub(super) struct ShadowParallelMetrics {
+    /// Number of speculatively executed transactions grouped by the outcome.
+    pub transactions: Family<SpeculationOutcome, Counter>,
+    /// Latency of speculatively executing all transactions in an L2 block.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub l2_block_latency: Histogram<Duration>,
+}
+
+#[vise::register]
+pub(super) static SHADOW_PARALLEL_METRICS: vise::Global<ShadowParallelMetrics> =
+    vise::Global::new();
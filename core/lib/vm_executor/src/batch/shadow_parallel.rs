@@ -0,0 +1,488 @@
+//! Experimental optimistic parallel execution of transactions, run in shadow mode alongside the batch VM.
+//!
+//! After each L2 block, its transactions are re-executed speculatively in parallel, each in a fresh VM
+//! on top of the state as of the L1 batch start. A speculative result is only valid if the transaction
+//! didn't read any slots written by the preceding transactions in the batch; this is checked using
+//! [storage access sets](StorageAccessSet). Conflicting transactions fall back to sequential execution
+//! (i.e., the result of the batch VM is used). Valid speculative results are cross-checked against
+//! the results of sequential execution, and the outcomes are reported as metrics.
+//!
+//! The batch VM is not affected in any way; committing speculative results to the batch is not supported,
+//! since the bootloader executes the entire batch as a single program.
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use zksync_multivm::{
+    interface::{
+        storage::{ReadStorage, StorageView, WriteStorage},
+        ExecutionResult, L1BatchEnv, L2BlockEnv, StorageAccessSet, SystemEnv,
+        VmExecutionResultAndLogs, VmFactory, VmInterface,
+    },
+    vm_latest::HistoryDisabled,
+    LegacyVmInstance,
+};
+use zksync_types::{
+    utils::storage_key_for_eth_balance, Address, StorageKey, StorageValue, Transaction,
+    BOOTLOADER_ADDRESS, H256, L1_MESSENGER_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
+};
+
+use super::metrics::{SpeculationOutcome, SHADOW_PARALLEL_METRICS};
+
+/// Request from a speculative VM to the storage owned by the batch executor thread.
+#[derive(Debug)]
+enum StorageRequest {
+    ReadValue(StorageKey, mpsc::SyncSender<StorageValue>),
+    IsWriteInitial(StorageKey, mpsc::SyncSender<bool>),
+    LoadFactoryDep(H256, mpsc::SyncSender<Option<Vec<u8>>>),
+    GetEnumerationIndex(StorageKey, mpsc::SyncSender<Option<u64>>),
+}
+
+/// Storage used by speculative VMs. Proxies all requests to the batch executor thread, which returns values
+/// as of the L1 batch start.
+#[derive(Debug)]
+struct ProxyStorage {
+    requests: mpsc::Sender<StorageRequest>,
+}
+
+impl ProxyStorage {
+    fn request<T>(&self, create: impl FnOnce(mpsc::SyncSender<T>) -> StorageRequest) -> T {
+        let (response_sender, response_receiver) = mpsc::sync_channel(1);
+        self.requests
+            .send(create(response_sender))
+            .expect("storage server unexpectedly stopped");
+        response_receiver
+            .recv()
+            .expect("storage server unexpectedly stopped")
+    }
+}
+
+impl ReadStorage for ProxyStorage {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        self.request(|response| StorageRequest::ReadValue(*key, response))
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.request(|response| StorageRequest::IsWriteInitial(*key, response))
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.request(|response| StorageRequest::LoadFactoryDep(hash, response))
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.request(|response| StorageRequest::GetEnumerationIndex(*key, response))
+    }
+}
+
+/// Observable effects of a transaction compared between sequential and speculative execution.
+#[derive(Debug, PartialEq)]
+struct TxEffects {
+    result: ExecutionResult,
+    /// Final values of written slots, excluding bookkeeping slots.
+    writes: BTreeMap<StorageKey, StorageValue>,
+    /// Emitted events as (address, topics, data) tuples; event locations are ignored since they depend
+    /// on the transaction position in the batch.
+    events: Vec<(Address, Vec<H256>, Vec<u8>)>,
+}
+
+#[derive(Debug)]
+struct ExecutedTx {
+    tx: Transaction,
+    effects: TxEffects,
+}
+
+#[derive(Debug)]
+struct SpeculativeOutput {
+    effects: TxEffects,
+    /// Slots read by the transaction, excluding bookkeeping slots.
+    reads: BTreeSet<StorageKey>,
+}
+
+/// Shadow parallel execution state for a single L1 batch.
+#[derive(Debug)]
+pub(super) struct ShadowParallelExecution {
+    l1_batch_env: L1BatchEnv,
+    system_env: SystemEnv,
+    /// Slots modified by the bootloader for every transaction (e.g., the operator balance). Accesses to these slots
+    /// are not considered conflicts.
+    bookkeeping_slots: BTreeSet<StorageKey>,
+    current_l2_block: L2BlockEnv,
+    executed_txs: Vec<ExecutedTx>,
+    /// Slots written by transactions in the previous L2 blocks of the batch.
+    batch_writes: BTreeSet<StorageKey>,
+}
+
+impl ShadowParallelExecution {
+    pub fn new(l1_batch_env: L1BatchEnv, system_env: SystemEnv) -> Self {
+        let bookkeeping_slots = [
+            storage_key_for_eth_balance(&l1_batch_env.fee_account),
+            storage_key_for_eth_balance(&BOOTLOADER_ADDRESS),
+        ];
+        Self {
+            current_l2_block: l1_batch_env.first_l2_block,
+            bookkeeping_slots: bookkeeping_slots.into(),
+            l1_batch_env,
+            system_env,
+            executed_txs: vec![],
+            batch_writes: BTreeSet::new(),
+        }
+    }
+
+    fn is_bookkeeping(&self, key: &StorageKey) -> bool {
+        // The bootloader updates the system context and sends L2-to-L1 logs for every transaction.
+        [SYSTEM_CONTEXT_ADDRESS, L1_MESSENGER_ADDRESS].contains(key.address())
+            || self.bookkeeping_slots.contains(key)
+    }
+
+    fn effects(&self, result: &VmExecutionResultAndLogs) -> TxEffects {
+        let mut writes = BTreeMap::new();
+        for log in &result.logs.storage_logs {
+            if log.log.is_write() && !self.is_bookkeeping(&log.log.key) {
+                writes.insert(log.log.key, log.log.value);
+            }
+        }
+        let events = result
+            .logs
+            .events
+            .iter()
+            .map(|event| {
+                (
+                    event.address,
+                    event.indexed_topics.clone(),
+                    event.value.clone(),
+                )
+            })
+            .collect();
+        TxEffects {
+            result: result.result.clone(),
+            writes,
+            events,
+        }
+    }
+
+    /// Records a transaction executed by the batch VM.
+    pub fn push_tx(&mut self, tx: Transaction, result: &VmExecutionResultAndLogs) {
+        let effects = self.effects(result);
+        self.executed_txs.push(ExecutedTx { tx, effects });
+    }
+
+    /// Handles the last executed transaction being rolled back in the batch VM.
+    pub fn pop_tx(&mut self) {
+        self.executed_txs.pop();
+    }
+
+    /// Speculatively re-executes transactions in the finished L2 block and compares results with the sequential ones.
+    /// `next_l2_block` is `None` if the batch is being finished.
+    pub fn finish_l2_block<S: ReadStorage>(
+        &mut self,
+        storage: &RefCell<StorageView<S>>,
+        next_l2_block: Option<L2BlockEnv>,
+    ) {
+        if !self.executed_txs.is_empty() {
+            let latency = SHADOW_PARALLEL_METRICS.l2_block_latency.start();
+            let outputs = self.speculate(storage);
+            latency.observe();
+            self.check_outputs(outputs);
+        }
+        if let Some(env) = next_l2_block {
+            self.current_l2_block = env;
+        }
+    }
+
+    fn speculate<S: ReadStorage>(
+        &self,
+        storage: &RefCell<StorageView<S>>,
+    ) -> Vec<Option<SpeculativeOutput>> {
+        let tx_count = self.executed_txs.len();
+        let worker_count = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(tx_count);
+        let next_tx_index = AtomicUsize::new(0);
+        let (request_sender, request_receiver) = mpsc::channel();
+
+        let mut outputs: Vec<_> = (0..tx_count).map(|_| None).collect();
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..worker_count)
+                .map(|_| {
+                    let requests = request_sender.clone();
+                    let next_tx_index = &next_tx_index;
+                    scope.spawn(move || {
+                        let mut worker_outputs = vec![];
+                        loop {
+                            let idx = next_tx_index.fetch_add(1, Ordering::Relaxed);
+                            let Some(executed_tx) = self.executed_txs.get(idx) else {
+                                break;
+                            };
+                            let tx = executed_tx.tx.clone();
+                            let requests = requests.clone();
+                            // The VM may panic on unexpected storage state; this shouldn't affect other workers.
+                            let output = panic::catch_unwind(AssertUnwindSafe(|| {
+                                self.speculate_tx(tx, requests)
+                            }));
+                            worker_outputs.push((idx, output.ok().flatten()));
+                        }
+                        worker_outputs
+                    })
+                })
+                .collect();
+            drop(request_sender);
+
+            // Serve storage requests until all workers are finished.
+            for request in request_receiver {
+                Self::serve_request(storage, request);
+            }
+            for worker in workers {
+                let worker_outputs = worker
+                    .join()
+                    .expect("speculative execution worker panicked");
+                for (idx, output) in worker_outputs {
+                    outputs[idx] = output;
+                }
+            }
+        });
+        outputs
+    }
+
+    fn speculate_tx(
+        &self,
+        tx: Transaction,
+        requests: mpsc::Sender<StorageRequest>,
+    ) -> Option<SpeculativeOutput> {
+        let storage = StorageView::new(ProxyStorage { requests }).to_rc_ptr();
+        // The speculative VM always starts from the first L2 block in the batch, since block continuity is checked
+        // by the bootloader. Hence, only the L2 block timestamp is matched with the sequential execution.
+        let l1_batch_env = L1BatchEnv {
+            first_l2_block: L2BlockEnv {
+                timestamp: self.current_l2_block.timestamp,
+                ..self.l1_batch_env.first_l2_block
+            },
+            ..self.l1_batch_env.clone()
+        };
+        let mut vm = LegacyVmInstance::<_, HistoryDisabled>::new(
+            l1_batch_env,
+            self.system_env.clone(),
+            storage,
+        );
+        let (compression_result, result) =
+            vm.inspect_transaction_with_bytecode_compression(&mut Default::default(), tx, true);
+        if compression_result.is_err() {
+            return None;
+        }
+
+        let access_set = StorageAccessSet::from_storage_logs(&result.logs.storage_logs);
+        let reads = access_set
+            .reads
+            .into_iter()
+            .filter(|key| !self.is_bookkeeping(key))
+            .collect();
+        Some(SpeculativeOutput {
+            effects: self.effects(&result),
+            reads,
+        })
+    }
+
+    fn serve_request<S: ReadStorage>(storage: &RefCell<StorageView<S>>, request: StorageRequest) {
+        let mut storage = storage.borrow_mut();
+        // Send errors are ignored; they can only occur if the requesting worker has panicked.
+        match request {
+            StorageRequest::ReadValue(key, response) => {
+                // The read cache contains values from the underlying storage, i.e. as of the batch start.
+                let cached_value = storage.read_storage_keys().get(&key).copied();
+                let value = cached_value.unwrap_or_else(|| storage.inner_mut().read_value(&key));
+                response.send(value).ok();
+            }
+            StorageRequest::IsWriteInitial(key, response) => {
+                response.send(storage.is_write_initial(&key)).ok();
+            }
+            StorageRequest::LoadFactoryDep(hash, response) => {
+                response.send(storage.load_factory_dep(hash)).ok();
+            }
+            StorageRequest::GetEnumerationIndex(key, response) => {
+                response.send(storage.get_enumeration_index(&key)).ok();
+            }
+        }
+    }
+
+    fn check_outputs(&mut self, outputs: Vec<Option<SpeculativeOutput>>) {
+        let mut writes = std::mem::take(&mut self.batch_writes);
+        for (executed_tx, output) in self.executed_txs.drain(..).zip(outputs) {
+            let outcome = match output {
+                None => SpeculationOutcome::Failed,
+                Some(output) if !output.reads.is_disjoint(&writes) => {
+                    SpeculationOutcome::Conflicted
+                }
+                Some(output) if output.effects == executed_tx.effects => {
+                    SpeculationOutcome::Matched
+                }
+                Some(output) => {
+                    tracing::warn!(
+                        tx_hash = ?executed_tx.tx.hash(),
+                        "Speculative execution result diverged from sequential execution: {:?}, expected {:?}",
+                        output.effects,
+                        executed_tx.effects
+                    );
+                    SpeculationOutcome::Diverged
+                }
+            };
+            SHADOW_PARALLEL_METRICS.transactions[&outcome].inc();
+            writes.extend(executed_tx.effects.writes.into_keys());
+        }
+        self.batch_writes = writes;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_multivm::interface::{storage::InMemoryStorage, TxExecutionMode, VmEvent};
+    use zksync_types::{u256_to_h256, AccountTreeId, L1BatchNumber, Nonce};
+
+    use super::*;
+    use crate::testonly::{create_l2_transaction, default_l1_batch_env, default_system_env};
+
+    fn slot(address: u64, key: u64) -> StorageKey {
+        StorageKey::new(
+            AccountTreeId::new(Address::from_low_u64_be(address)),
+            H256::from_low_u64_be(key),
+        )
+    }
+
+    fn effects(writes: &[StorageKey]) -> TxEffects {
+        TxEffects {
+            result: ExecutionResult::Success { output: vec![] },
+            writes: writes.iter().map(|&key| (key, H256::zero())).collect(),
+            events: vec![],
+        }
+    }
+
+    fn shadow_with_txs(txs: &[&[StorageKey]]) -> ShadowParallelExecution {
+        let mut shadow = ShadowParallelExecution::new(
+            default_l1_batch_env(1),
+            default_system_env(TxExecutionMode::VerifyExecute),
+        );
+        for &writes in txs {
+            let tx = create_l2_transaction(0.into(), Nonce(0)).into();
+            shadow.executed_txs.push(ExecutedTx {
+                tx,
+                effects: effects(writes),
+            });
+        }
+        shadow
+    }
+
+    #[test]
+    fn classifying_speculative_outputs() {
+        let before = SHADOW_PARALLEL_METRICS.transactions[&SpeculationOutcome::Conflicted].get();
+        let mut shadow = shadow_with_txs(&[&[slot(1, 1)], &[slot(2, 2)], &[slot(3, 3)]]);
+        let outputs = vec![
+            Some(SpeculativeOutput {
+                effects: effects(&[slot(1, 1)]),
+                reads: [slot(1, 0)].into(),
+            }),
+            // Reads a slot written by the first transaction.
+            Some(SpeculativeOutput {
+                effects: effects(&[slot(2, 2)]),
+                reads: [slot(1, 1)].into(),
+            }),
+            Some(SpeculativeOutput {
+                effects: effects(&[slot(3, 3)]),
+                reads: [slot(3, 0)].into(),
+            }),
+        ];
+        shadow.check_outputs(outputs);
+
+        let after = SHADOW_PARALLEL_METRICS.transactions[&SpeculationOutcome::Conflicted].get();
+        assert!(after > before);
+        assert!(shadow.executed_txs.is_empty());
+        let expected_writes = [slot(1, 1), slot(2, 2), slot(3, 3)].into();
+        assert_eq!(shadow.batch_writes, expected_writes);
+    }
+
+    #[test]
+    fn bookkeeping_slots_are_ignored() {
+        let shadow = shadow_with_txs(&[]);
+        let fee_account = shadow.l1_batch_env.fee_account;
+        assert!(shadow.is_bookkeeping(&storage_key_for_eth_balance(&fee_account)));
+        assert!(shadow.is_bookkeeping(&StorageKey::new(
+            AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+            H256::zero()
+        )));
+        assert!(!shadow.is_bookkeeping(&slot(1, 1)));
+
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.logs.events.push(VmEvent {
+            location: (L1BatchNumber(1), 5),
+            address: Address::repeat_byte(1),
+            ..VmEvent::default()
+        });
+        let effects = shadow.effects(&result);
+        assert_eq!(effects.events.len(), 1);
+        assert_eq!(effects.events[0].0, Address::repeat_byte(1));
+    }
+
+    #[test]
+    fn speculatively_executing_transfers() {
+        let txs: Vec<_> = (0..4)
+            .map(|_| create_l2_transaction(1_000_000_000.into(), Nonce(0)))
+            .collect();
+        let mut storage = InMemoryStorage::with_system_contracts();
+        for tx in &txs {
+            storage.set_value(
+                storage_key_for_eth_balance(&tx.initiator_account()),
+                u256_to_h256(u64::MAX.into()),
+            );
+        }
+        let storage = RefCell::new(StorageView::new(storage));
+
+        let shadow = ShadowParallelExecution::new(
+            default_l1_batch_env(1),
+            default_system_env(TxExecutionMode::VerifyExecute),
+        );
+        let mut shadow = ShadowParallelExecution {
+            executed_txs: txs
+                .into_iter()
+                .map(|tx| ExecutedTx {
+                    tx: tx.into(),
+                    effects: effects(&[]),
+                })
+                .collect(),
+            ..shadow
+        };
+        let outputs = shadow.speculate(&storage);
+        assert_eq!(outputs.len(), 4);
+        for output in &outputs {
+            let output = output.as_ref().expect("speculative execution failed");
+            assert!(
+                !output.effects.result.is_failed(),
+                "{:?}",
+                output.effects.result
+            );
+            assert!(!output.effects.writes.is_empty());
+        }
+        // Independent transfers don't conflict with each other.
+        shadow.batch_writes = outputs[0]
+            .as_ref()
+            .unwrap()
+            .effects
+            .writes
+            .keys()
+            .copied()
+            .collect();
+        for output in &outputs[1..] {
+            assert!(output
+                .as_ref()
+                .unwrap()
+                .reads
+                .is_disjoint(&shadow.batch_writes));
+        }
+    }
+}
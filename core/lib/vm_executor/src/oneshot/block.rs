@@ -10,9 +10,11 @@ use zksync_types::{
     api,
     block::{unpack_block_info, L2BlockHasher},
     fee_model::BatchFeeInput,
-    get_deployer_key, h256_to_u256, AccountTreeId, L1BatchNumber, L2BlockNumber, ProtocolVersionId,
-    StorageKey, H256, SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
-    SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION, ZKPORTER_IS_AVAILABLE,
+    get_deployer_key, h256_to_u256, u256_to_h256, AccountTreeId, L1BatchNumber, L2BlockNumber,
+    ProtocolVersionId, StorageKey, H256, SYSTEM_CONTEXT_ADDRESS,
+    SYSTEM_CONTEXT_BLOCK_INFO_POSITION, SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION,
+    SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION, SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION,
+    SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES, U256, ZKPORTER_IS_AVAILABLE,
 };
 
 use super::{env::OneshotEnvParameters, ContractsKind};
@@ -149,6 +151,7 @@ impl BlockInfo {
             protocol_version,
             use_evm_emulator,
             is_pending: self.is_pending_l2_block(),
+            detached_env: None,
         })
     }
 
@@ -157,8 +160,7 @@ impl BlockInfo {
         connection: &mut Connection<'_, Core>,
         at_block: L2BlockNumber,
     ) -> anyhow::Result<bool> {
-        let allowed_contract_types_hashed_key =
-            get_deployer_key(H256::from_low_u64_be(1)).hashed_key();
+        let allowed_contract_types_hashed_key = Self::allowed_contract_types_key().hashed_key();
         let storage_values = connection
             .storage_logs_dal()
             .get_storage_values(&[allowed_contract_types_hashed_key], at_block)
@@ -168,17 +170,89 @@ impl BlockInfo {
             .copied()
             .flatten()
             .unwrap_or_default();
-        Ok(match allowed_contract_types {
+        Ok(Self::parse_allowed_contract_types(
+            allowed_contract_types,
+            at_block,
+        ))
+    }
+
+    fn allowed_contract_types_key() -> StorageKey {
+        get_deployer_key(H256::from_low_u64_be(1))
+    }
+
+    fn parse_allowed_contract_types(allowed_contract_types: H256, at_block: L2BlockNumber) -> bool {
+        match allowed_contract_types {
             val if val.is_zero() => false,
             val if val == H256::from_low_u64_be(1) => true,
             _ => {
                 tracing::warn!(?allowed_contract_types, %at_block, "Unknown allowed contract types in ContractDeployer storage");
                 false
             }
-        })
+        }
     }
 }
 
+/// Storage values necessary to execute a transaction / call in the context of an L2 block which state is not stored in Postgres
+/// (e.g., a pruned block which state is reconstructed from a snapshot). All values must correspond to the state
+/// at the end of the block; use [`Self::storage_keys()`] to get the keys to read.
+#[derive(Debug, Clone)]
+pub struct DetachedBlockState {
+    /// Packed number and timestamp of the L1 batch containing the block.
+    pub l1_batch_info: H256,
+    /// Packed number and timestamp of the block.
+    pub l2_block_info: H256,
+    /// Rolling hash of transactions in the block.
+    pub txs_rolling_hash: H256,
+    /// Hash of the previous L2 block.
+    pub prev_l2_block_hash: H256,
+    /// Hash of the L2 block preceding the previous block.
+    pub prev_prev_l2_block_hash: H256,
+    /// Allowed contract types in `ContractDeployer`, which determine whether EVM emulation is enabled.
+    pub allowed_contract_types: H256,
+}
+
+impl DetachedBlockState {
+    /// Returns storage keys for the fields of this struct (in the order of their declaration) for the specified L2 block.
+    /// Returns `None` if the block is too old to be executed in a detached state (i.e., one of the first 2 blocks).
+    pub fn storage_keys(l2_block_number: L2BlockNumber) -> Option<[StorageKey; 6]> {
+        let prev_prev_block_number = l2_block_number.0.checked_sub(2)?;
+        let system_context_key =
+            |position| StorageKey::new(AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS), position);
+        let l2_block_hash_key = |number: u32| {
+            let position = h256_to_u256(SYSTEM_CONTEXT_CURRENT_L2_BLOCK_HASHES_POSITION)
+                + U256::from(number % SYSTEM_CONTEXT_STORED_L2_BLOCK_HASHES);
+            system_context_key(u256_to_h256(position))
+        };
+        Some([
+            system_context_key(SYSTEM_CONTEXT_BLOCK_INFO_POSITION),
+            system_context_key(SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION),
+            system_context_key(SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION),
+            l2_block_hash_key(prev_prev_block_number + 1),
+            l2_block_hash_key(prev_prev_block_number),
+            BlockInfo::allowed_contract_types_key(),
+        ])
+    }
+
+    /// Creates the state from values of the keys returned by [`Self::storage_keys()`].
+    pub fn from_values(values: [H256; 6]) -> Self {
+        Self {
+            l1_batch_info: values[0],
+            l2_block_info: values[1],
+            txs_rolling_hash: values[2],
+            prev_l2_block_hash: values[3],
+            prev_prev_l2_block_hash: values[4],
+            allowed_contract_types: values[5],
+        }
+    }
+}
+
+/// L2 block environment restored from [`DetachedBlockState`].
+#[derive(Debug, Clone)]
+struct DetachedL2BlockEnv {
+    next_block: L2BlockEnv,
+    current_block: StoredL2BlockEnv,
+}
+
 /// Resolved [`BlockInfo`] containing additional data from VM state.
 #[derive(Debug, Clone)]
 pub struct ResolvedBlockInfo {
@@ -189,9 +263,81 @@ pub struct ResolvedBlockInfo {
     protocol_version: ProtocolVersionId,
     use_evm_emulator: bool,
     is_pending: bool,
+    detached_env: Option<DetachedL2BlockEnv>,
 }
 
 impl ResolvedBlockInfo {
+    /// Resolves information for an L2 block which state is not stored in Postgres (e.g., a pruned block which state is reconstructed
+    /// from a snapshot). The L1 batch and L2 block environments are restored from the provided `state`. Postgres storage
+    /// is only expected to be used to load factory deps; it's created for `postgres_l2_block_number`
+    /// (see [`Self::state_l2_block_number()`]).
+    ///
+    /// The previous L2 block is not restored exactly since its timestamp and transactions are not a part of the state.
+    /// Instead, it's replaced with a block having the same number and a consistent hash; thus, `blockhash()` of the previous block
+    /// will differ from the original one.
+    pub fn detached(
+        l2_block_number: L2BlockNumber,
+        postgres_l2_block_number: L2BlockNumber,
+        state: &DetachedBlockState,
+        protocol_version: ProtocolVersionId,
+    ) -> anyhow::Result<Self> {
+        let (l1_batch_number, l1_batch_timestamp) =
+            unpack_block_info(h256_to_u256(state.l1_batch_info));
+        let l1_batch_number =
+            u32::try_from(l1_batch_number).context("L1 batch number in state overflows u32")?;
+        let (number_from_state, timestamp) = unpack_block_info(h256_to_u256(state.l2_block_info));
+        anyhow::ensure!(
+            number_from_state == u64::from(l2_block_number.0),
+            "L2 block number in state ({number_from_state}) differs from the expected one ({l2_block_number})"
+        );
+        anyhow::ensure!(
+            l2_block_number.0 >= 2 && timestamp > 0,
+            "L2 block #{l2_block_number} cannot be executed in a detached state"
+        );
+
+        let current_block = StoredL2BlockEnv {
+            number: l2_block_number.0 - 1,
+            timestamp: timestamp - 1,
+            txs_rolling_hash: H256::zero(),
+        };
+        let next_block = L2BlockEnv {
+            number: l2_block_number.0,
+            timestamp,
+            prev_block_hash: L2BlockHasher::hash(
+                l2_block_number - 1,
+                current_block.timestamp,
+                state.prev_prev_l2_block_hash,
+                current_block.txs_rolling_hash,
+                protocol_version,
+            ),
+            max_virtual_blocks_to_create: 1,
+        };
+        let state_l2_block_hash = L2BlockHasher::hash(
+            l2_block_number,
+            timestamp,
+            state.prev_l2_block_hash,
+            state.txs_rolling_hash,
+            protocol_version,
+        );
+
+        Ok(Self {
+            state_l2_block_number: postgres_l2_block_number,
+            state_l2_block_hash,
+            vm_l1_batch_number: L1BatchNumber(l1_batch_number),
+            l1_batch_timestamp,
+            protocol_version,
+            use_evm_emulator: BlockInfo::parse_allowed_contract_types(
+                state.allowed_contract_types,
+                l2_block_number,
+            ),
+            is_pending: false,
+            detached_env: Some(DetachedL2BlockEnv {
+                next_block,
+                current_block,
+            }),
+        })
+    }
+
     /// L2 block number (as stored in Postgres). This number may differ from `block.number` provided to the VM.
     pub fn state_l2_block_number(&self) -> L2BlockNumber {
         self.state_l2_block_number
@@ -215,12 +361,16 @@ impl<C: ContractsKind> OneshotEnvParameters<C> {
         fee_input: BatchFeeInput,
         enforced_base_fee: Option<u64>,
     ) -> anyhow::Result<OneshotEnv> {
-        let (next_block, current_block) = load_l2_block_info(
-            connection,
-            resolved_block_info.is_pending,
-            resolved_block_info,
-        )
-        .await?;
+        let (next_block, current_block) = if let Some(env) = &resolved_block_info.detached_env {
+            (env.next_block, Some(env.current_block))
+        } else {
+            load_l2_block_info(
+                connection,
+                resolved_block_info.is_pending,
+                resolved_block_info,
+            )
+            .await?
+        };
 
         let (system, l1_batch) = self
             .prepare_env(
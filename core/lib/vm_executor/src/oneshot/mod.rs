@@ -44,7 +44,7 @@ use zksync_types::{
 };
 
 pub use self::{
-    block::{BlockInfo, ResolvedBlockInfo},
+    block::{BlockInfo, DetachedBlockState, ResolvedBlockInfo},
     contracts::{
         BaseSystemContractsProvider, CallOrExecute, ContractsKind, EstimateGas,
         MultiVmBaseSystemContracts,
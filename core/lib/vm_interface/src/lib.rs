@@ -31,13 +31,16 @@ pub use crate::{
             BatchTransactionExecutionResult, BootloaderMemory, Call, CallType, CircuitStatistic,
             CompressedBytecodeInfo, CurrentExecutionState, DeduplicatedWritesMetrics,
             ExecutionResult, FinishedL1Batch, L2Block, OneshotTransactionExecutionResult,
-            PushTransactionResult, Refunds, TransactionExecutionMetrics,
+            PushTransactionResult, Refunds, StorageAccessSet, TransactionExecutionMetrics,
             TransactionExecutionResult, TxExecutionStatus, VmEvent, VmExecutionLogs,
             VmExecutionMetrics, VmExecutionResultAndLogs, VmExecutionStatistics, VmMemoryMetrics,
         },
         tracer,
     },
-    vm::{VmFactory, VmInterface, VmInterfaceExt, VmInterfaceHistoryEnabled, VmTrackingContracts},
+    vm::{
+        SpeculationOutcome, SpeculativeExecutionResult, VmFactory, VmInterface, VmInterfaceExt,
+        VmInterfaceHistoryEnabled, VmInterfaceHistoryEnabledExt, VmTrackingContracts,
+    },
 };
 
 pub mod executor;
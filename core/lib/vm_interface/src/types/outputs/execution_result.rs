@@ -14,7 +14,8 @@ use zksync_types::{
 };
 
 use crate::{
-    BytecodeCompressionError, Halt, VmExecutionMetrics, VmExecutionStatistics, VmRevertReason,
+    BytecodeCompressionError, Halt, StorageAccessSet, VmExecutionMetrics, VmExecutionStatistics,
+    VmRevertReason,
 };
 
 /// Event generated by the VM.
@@ -170,6 +171,14 @@ impl VmExecutionResultAndLogs {
         Self::mock(ExecutionResult::Success { output: vec![] })
     }
 
+    /// Returns storage slots accessed by the transaction, based on the storage logs produced by the VM.
+    ///
+    /// Writes are always reported. Reads are reported only by VMs emitting read logs (i.e., the legacy VM);
+    /// the fast VM only reports writes.
+    pub fn storage_access_set(&self) -> StorageAccessSet {
+        StorageAccessSet::from_storage_logs(&self.logs.storage_logs)
+    }
+
     pub fn get_execution_metrics(&self) -> VmExecutionMetrics {
         // We published the data as ABI-encoded `bytes`, so the total length is:
         // - message length in bytes, rounded up to a multiple of 32
@@ -359,6 +368,8 @@ pub struct TransactionExecutionResult {
     pub refunded_gas: u64,
     pub call_traces: Vec<Call>,
     pub revert_reason: Option<String>,
+    /// Storage slots accessed by the transaction, if collected.
+    pub storage_accesses: Option<StorageAccessSet>,
 }
 
 impl TransactionExecutionResult {
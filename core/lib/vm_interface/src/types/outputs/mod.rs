@@ -14,6 +14,7 @@ pub use self::{
         CircuitStatistic, DeduplicatedWritesMetrics, TransactionExecutionMetrics,
         VmExecutionMetrics, VmExecutionStatistics, VmMemoryMetrics,
     },
+    storage_access::StorageAccessSet,
 };
 
 mod bytecode;
@@ -22,6 +23,7 @@ mod execution_state;
 mod finished_l1batch;
 mod l2_block;
 mod statistic;
+mod storage_access;
 
 /// Result of pushing a transaction to the VM state without executing it.
 #[derive(Debug)]
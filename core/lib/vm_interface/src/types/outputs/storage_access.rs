@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+
+use zksync_types::{api, StorageKey, StorageLogWithPreviousValue};
+
+/// Storage slots accessed by a transaction. Can be used to analyze conflicts among transactions,
+/// e.g. to estimate potential for their parallel execution.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageAccessSet {
+    /// Slots read by the transaction. May intersect with `writes`.
+    pub reads: BTreeSet<StorageKey>,
+    /// Slots written by the transaction.
+    pub writes: BTreeSet<StorageKey>,
+}
+
+impl StorageAccessSet {
+    /// Collects accessed slots from the storage logs produced by the VM.
+    pub fn from_storage_logs(logs: &[StorageLogWithPreviousValue]) -> Self {
+        let mut this = Self::default();
+        for log in logs {
+            if log.log.is_write() {
+                this.writes.insert(log.log.key);
+            } else {
+                this.reads.insert(log.log.key);
+            }
+        }
+        this
+    }
+
+    /// Checks whether this set conflicts with another one, i.e., either of the transactions writes a slot
+    /// accessed by the other transaction.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        !self.writes.is_disjoint(&other.writes)
+            || !self.writes.is_disjoint(&other.reads)
+            || !self.reads.is_disjoint(&other.writes)
+    }
+}
+
+impl From<StorageAccessSet> for api::StorageAccessSet {
+    fn from(set: StorageAccessSet) -> Self {
+        let to_api = |key: StorageKey| api::StorageSlot {
+            address: *key.address(),
+            key: *key.key(),
+        };
+        Self {
+            reads: set.reads.into_iter().map(to_api).collect(),
+            writes: set.writes.into_iter().map(to_api).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{AccountTreeId, Address, StorageLog, H256};
+
+    use super::*;
+
+    fn key(byte: u8) -> StorageKey {
+        StorageKey::new(
+            AccountTreeId::new(Address::repeat_byte(1)),
+            H256::repeat_byte(byte),
+        )
+    }
+
+    fn log(log: StorageLog) -> StorageLogWithPreviousValue {
+        StorageLogWithPreviousValue {
+            log,
+            previous_value: H256::zero(),
+        }
+    }
+
+    #[test]
+    fn collecting_storage_access_set() {
+        let logs = [
+            log(StorageLog::new_read_log(key(1), H256::zero())),
+            log(StorageLog::new_write_log(key(2), H256::repeat_byte(0xff))),
+            log(StorageLog::new_read_log(key(2), H256::zero())),
+            log(StorageLog::new_read_log(key(1), H256::zero())),
+        ];
+        let set = StorageAccessSet::from_storage_logs(&logs);
+        assert_eq!(set.reads, BTreeSet::from([key(1), key(2)]));
+        assert_eq!(set.writes, BTreeSet::from([key(2)]));
+
+        let api_set = api::StorageAccessSet::from(set);
+        assert_eq!(api_set.reads.len(), 2);
+        assert_eq!(api_set.writes[0].key, H256::repeat_byte(2));
+    }
+
+    #[test]
+    fn detecting_conflicts() {
+        let set = |reads: &[u8], writes: &[u8]| StorageAccessSet {
+            reads: reads.iter().copied().map(key).collect(),
+            writes: writes.iter().copied().map(key).collect(),
+        };
+
+        assert!(!set(&[1], &[2]).conflicts_with(&set(&[1], &[3])));
+        assert!(set(&[1], &[2]).conflicts_with(&set(&[2], &[])));
+        assert!(set(&[], &[2]).conflicts_with(&set(&[], &[2])));
+        assert!(set(&[3], &[]).conflicts_with(&set(&[], &[3])));
+    }
+}
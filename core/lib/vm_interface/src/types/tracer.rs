@@ -77,10 +77,21 @@ pub enum ViolatedValidationRule {
     TouchedDisallowedStorageSlots(Address, U256),
     /// The transaction called a contract without attached bytecode.
     CalledContractWithNoCode(Address),
-    /// The transaction touched disallowed context.
-    TouchedDisallowedContext,
+    /// The transaction touched disallowed context (e.g., `block.timestamp` or `block.number`).
+    /// Holds the address of the contract that executed the context opcode.
+    TouchedDisallowedContext(Address),
     /// The transaction used too much gas during validation.
     TookTooManyComputationalGas(u32),
+    /// The transaction used too much gas during validation, most of which was consumed by a single contract
+    /// called by the account.
+    GasGriefing {
+        /// Contract that consumed most of the validation gas.
+        contract: Address,
+        /// Computational gas consumed by the contract.
+        gas_used: u32,
+        /// Validation computational gas limit.
+        gas_limit: u32,
+    },
     /// The transaction failed block.timestamp assertion because the block.timestamp is too close to the range end
     TimestampAssertionCloseToRangeEnd,
 }
@@ -95,8 +106,8 @@ impl fmt::Display for ViolatedValidationRule {
             ViolatedValidationRule::CalledContractWithNoCode(contract) => {
                 write!(f, "Called contract with no code: {contract:x}")
             }
-            ViolatedValidationRule::TouchedDisallowedContext => {
-                write!(f, "Touched disallowed context")
+            ViolatedValidationRule::TouchedDisallowedContext(contract) => {
+                write!(f, "Touched disallowed context in contract {contract:x}")
             }
             ViolatedValidationRule::TookTooManyComputationalGas(gas_limit) => {
                 write!(
@@ -104,6 +115,16 @@ impl fmt::Display for ViolatedValidationRule {
                     "Took too many computational gas, allowed limit: {gas_limit}"
                 )
             }
+            ViolatedValidationRule::GasGriefing {
+                contract,
+                gas_used,
+                gas_limit,
+            } => {
+                write!(
+                    f,
+                    "Contract {contract:x} took {gas_used} computational gas during validation, allowed limit: {gas_limit}"
+                )
+            }
             ViolatedValidationRule::TimestampAssertionCloseToRangeEnd => {
                 write!(f, "block.timestamp is too close to the range end")
             }
@@ -161,6 +182,35 @@ impl fmt::Display for ValidationError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn violated_rule_descriptions() {
+        let rule = ViolatedValidationRule::TouchedDisallowedContext(Address::repeat_byte(0x10));
+        assert_eq!(
+            rule.to_string(),
+            "Touched disallowed context in contract 1010101010101010101010101010101010101010"
+        );
+        let rule = ViolatedValidationRule::GasGriefing {
+            contract: Address::repeat_byte(0x20),
+            gas_used: 700,
+            gas_limit: 1_000,
+        };
+        assert_eq!(
+            rule.to_string(),
+            "Contract 2020202020202020202020202020202020202020 took 700 computational gas \
+             during validation, allowed limit: 1000"
+        );
+        let err =
+            ValidationError::ViolatedRule(ViolatedValidationRule::TouchedDisallowedStorageSlots(
+                Address::repeat_byte(1),
+                2.into(),
+            ));
+        assert_eq!(
+            err.to_string(),
+            "Violated validation rules: Touched disallowed storage slots: \
+             address 0101010101010101010101010101010101010101, key: 2"
+        );
+    }
+
     #[test]
     fn test_apply_range_when_none() {
         let mut validation_traces = ValidationTraces {
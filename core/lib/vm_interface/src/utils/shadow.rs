@@ -261,19 +261,19 @@ impl CheckDivergence for Result<ValidationTraces, ValidationError> {
     fn check_divergence(&self, other: &Self) -> DivergenceErrors {
         let mut errors = DivergenceErrors::new();
 
-        if matches!(
-            (self, other),
-            (
-                Err(ValidationError::ViolatedRule(
-                    ViolatedValidationRule::TookTooManyComputationalGas(_)
-                )),
+        let is_out_of_gas = |res: &Self| {
+            matches!(
+                res,
                 Err(ValidationError::ViolatedRule(
                     ViolatedValidationRule::TookTooManyComputationalGas(_)
-                )),
+                        | ViolatedValidationRule::GasGriefing { .. }
+                ))
             )
-        ) {
+        };
+        if is_out_of_gas(self) && is_out_of_gas(other) {
             // Because of differences in how validation is implemented in the legacy and fast VMs, we don't require
-            // the exact match in the computational gas limit reported; it only influences error messages.
+            // the exact match in the computational gas limit reported or in gas attribution to contracts;
+            // it only influences error messages.
             return errors;
         }
 
@@ -16,9 +16,9 @@ use std::rc::Rc;
 use zksync_types::{Transaction, H256};
 
 use crate::{
-    pubdata::PubdataBuilder, storage::StoragePtr, BytecodeCompressionResult, FinishedL1Batch,
-    InspectExecutionMode, L1BatchEnv, L2BlockEnv, PushTransactionResult, SystemEnv,
-    VmExecutionResultAndLogs,
+    pubdata::PubdataBuilder, storage::StoragePtr, BytecodeCompressionError,
+    BytecodeCompressionResult, FinishedL1Batch, InspectExecutionMode, L1BatchEnv, L2BlockEnv,
+    PushTransactionResult, SystemEnv, VmExecutionResultAndLogs,
 };
 
 pub trait VmInterface {
@@ -109,6 +109,74 @@ pub trait VmInterfaceHistoryEnabled: VmInterface {
     fn pop_snapshot_no_rollback(&mut self);
 }
 
+/// Decision on the VM state changes made by a speculative action, see [`VmInterfaceHistoryEnabledExt::speculate()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeculationOutcome {
+    /// Keep the changes made by the action.
+    Commit,
+    /// Discard the changes made by the action, restoring the VM state from before the action.
+    Rollback,
+}
+
+/// Result of [`VmInterfaceHistoryEnabledExt::execute_transaction_speculatively()`].
+#[derive(Debug)]
+pub struct SpeculativeExecutionResult {
+    /// Result of bytecode compression. If compression has failed, the transaction is always rolled back.
+    pub compression_result: Result<(), BytecodeCompressionError>,
+    /// Result of transaction execution.
+    pub execution_result: VmExecutionResultAndLogs,
+    /// Whether the transaction was kept in the VM state.
+    pub outcome: SpeculationOutcome,
+}
+
+/// Extension trait for [`VmInterfaceHistoryEnabled`] providing a stable API for speculative execution. It is intended
+/// to be used by block-building software that needs to tentatively insert transactions into a batch and revert them
+/// (e.g., if they turn out to be unprofitable or conflict with other transactions).
+///
+/// Methods of this trait encapsulate the [snapshot workflow](VmInterfaceHistoryEnabled#snapshot-workflow), so that
+/// a snapshot is always either popped or rolled back. Consequently, speculative actions must not be nested, and the action
+/// passed to [`Self::speculate()`] must not manipulate snapshots directly.
+pub trait VmInterfaceHistoryEnabledExt: VmInterfaceHistoryEnabled {
+    /// Performs an action on the VM and then either keeps or discards the changes made by it, depending on
+    /// the outcome returned by the action.
+    fn speculate<R>(&mut self, action: impl FnOnce(&mut Self) -> (R, SpeculationOutcome)) -> R {
+        self.make_snapshot();
+        let (output, outcome) = action(self);
+        match outcome {
+            SpeculationOutcome::Commit => self.pop_snapshot_no_rollback(),
+            SpeculationOutcome::Rollback => self.rollback_to_the_latest_snapshot(),
+        }
+        output
+    }
+
+    /// Executes a transaction with bytecode compression and keeps it in the VM state iff `should_commit` returns `true`
+    /// for the execution result. Otherwise, the VM state is rolled back to the state before the transaction.
+    fn execute_transaction_speculatively(
+        &mut self,
+        tx: Transaction,
+        should_commit: impl FnOnce(&VmExecutionResultAndLogs) -> bool,
+    ) -> SpeculativeExecutionResult {
+        self.speculate(|vm| {
+            let (compression_result, execution_result) =
+                vm.execute_transaction_with_bytecode_compression(tx, true);
+            let compression_result = compression_result.map(drop);
+            let outcome = if compression_result.is_ok() && should_commit(&execution_result) {
+                SpeculationOutcome::Commit
+            } else {
+                SpeculationOutcome::Rollback
+            };
+            let result = SpeculativeExecutionResult {
+                compression_result,
+                execution_result,
+                outcome,
+            };
+            (result, outcome)
+        })
+    }
+}
+
+impl<T: VmInterfaceHistoryEnabled> VmInterfaceHistoryEnabledExt for T {}
+
 /// VM that tracks decommitment of bytecodes during execution. This is required to create a [`VmDump`].
 pub trait VmTrackingContracts: VmInterface {
     /// Returns hashes of all decommitted bytecodes.
@@ -39,6 +39,12 @@ pub enum Web3Error {
     LogsLimitExceeded(usize, u32, u32),
     #[error("invalid filter: if blockHash is supplied fromBlock and toBlock must not be")]
     InvalidFilterBlockHash,
+    #[error("Request contains more than {0} transactions")]
+    TooManyTransactions(usize),
+    #[error("Storage state before transaction #{0} in the middle of a block is not available")]
+    IntermediateStateUnavailable(usize),
+    #[error("Call traces exceed the size limit of {0} bytes")]
+    TraceSizeLimitExceeded(usize),
     /// Weaker form of a "method not found" error; the method implementation is technically present,
     /// but the node configuration prevents the method from functioning.
     #[error("Method not implemented")]
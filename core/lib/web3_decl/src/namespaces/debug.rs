@@ -2,13 +2,16 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
-    api::{BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, TracerConfig},
+    api::{
+        BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, StorageAccessSet,
+        StorageRangeResult, TracerConfig,
+    },
     transaction_request::CallRequest,
 };
 
 use crate::{
     client::{ForWeb3Network, L2},
-    types::H256,
+    types::{Address, H256},
 };
 
 #[cfg_attr(
@@ -48,4 +51,27 @@ pub trait DebugNamespace {
         tx_hash: H256,
         options: Option<TracerConfig>,
     ) -> RpcResult<Option<CallTracerResult>>;
+
+    /// Returns storage slots read and written by the specified transaction. Returns `null` if the transaction
+    /// is unknown or storage accesses were not persisted for it (see `save_storage_accesses` state keeper config).
+    #[method(name = "getTransactionStorageAccesses")]
+    async fn get_transaction_storage_accesses(
+        &self,
+        tx_hash: H256,
+    ) -> RpcResult<Option<StorageAccessSet>>;
+
+    /// Returns non-zero storage slots of `address` ordered by hashed slot key, starting from `start_key`.
+    /// The state is taken before executing the transaction with `tx_index` in the block; since storage logs
+    /// are not persisted per transaction, only `0` (state at the start of the block) and indices greater than
+    /// or equal to the number of transactions in the block (state at the end of the block) are supported.
+    /// The method is only available on archive nodes, after the corresponding Postgres index is built.
+    #[method(name = "storageRangeAt")]
+    async fn storage_range_at(
+        &self,
+        block: BlockId,
+        tx_index: usize,
+        address: Address,
+        start_key: H256,
+        max_result: usize,
+    ) -> RpcResult<StorageRangeResult>;
 }
@@ -5,10 +5,11 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockDetails, BridgeAddresses, L1BatchDetails,
-        L2ToL1LogProof, Proof, ProtocolVersion, TransactionDetailedResult, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BridgeAddresses, ChainCreationParams,
+        EvmCompatibilityInfo, FeeEstimate, GasPerPubdataInfo, L1BatchDetails,
+        L1ToL2TransactionSimulation, L2ToL1LogProof, NodeVersion, Proof, ProtocolVersion,
+        ProtocolVersionInfo, SystemContracts, TransactionDetailedResult, TransactionDetails,
     },
-    fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
     transaction_request::CallRequest,
     Address, L1BatchNumber, L2BlockNumber, H256, U256, U64,
@@ -33,7 +34,7 @@ pub trait ZksNamespace {
         &self,
         req: CallRequest,
         state_override: Option<StateOverride>,
-    ) -> RpcResult<Fee>;
+    ) -> RpcResult<FeeEstimate>;
 
     #[method(name = "estimateGasL1ToL2")]
     async fn estimate_gas_l1_to_l2(
@@ -42,6 +43,13 @@ pub trait ZksNamespace {
         state_override: Option<StateOverride>,
     ) -> RpcResult<U256>;
 
+    #[method(name = "simulateL1ToL2Transactions")]
+    async fn simulate_l1_to_l2_transactions(
+        &self,
+        reqs: Vec<CallRequest>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<Vec<L1ToL2TransactionSimulation>>;
+
     #[method(name = "getBridgehubContract")]
     async fn get_bridgehub_contract(&self) -> RpcResult<Option<Address>>;
 
@@ -101,6 +109,9 @@ pub trait ZksNamespace {
     #[method(name = "getTransactionDetails")]
     async fn get_transaction_details(&self, hash: H256) -> RpcResult<Option<TransactionDetails>>;
 
+    #[method(name = "getRawTransaction")]
+    async fn get_raw_transaction(&self, hash: H256) -> RpcResult<Option<Bytes>>;
+
     #[method(name = "getRawBlockTransactions")]
     async fn get_raw_block_transactions(
         &self,
@@ -126,6 +137,9 @@ pub trait ZksNamespace {
         version_id: Option<u16>,
     ) -> RpcResult<Option<ProtocolVersion>>;
 
+    #[method(name = "getProtocolVersionInfo")]
+    async fn get_protocol_version_info(&self) -> RpcResult<ProtocolVersionInfo>;
+
     #[method(name = "getProof")]
     async fn get_proof(
         &self,
@@ -137,9 +151,28 @@ pub trait ZksNamespace {
     #[method(name = "getBatchFeeInput")]
     async fn get_batch_fee_input(&self) -> RpcResult<PubdataIndependentBatchFeeModelInput>;
 
+    #[method(name = "getGasPerPubdata")]
+    async fn get_gas_per_pubdata(&self) -> RpcResult<GasPerPubdataInfo>;
+
+    #[method(name = "getEvmCompatibilityInfo")]
+    async fn get_evm_compatibility_info(&self) -> RpcResult<EvmCompatibilityInfo>;
+
+    #[method(name = "getChainCreationParams")]
+    async fn get_chain_creation_params(&self) -> RpcResult<ChainCreationParams>;
+
+    #[method(name = "getSystemContracts")]
+    async fn get_system_contracts(&self) -> RpcResult<SystemContracts>;
+
+    #[method(name = "getNodeVersion")]
+    async fn get_node_version(&self) -> RpcResult<NodeVersion>;
+
+    /// Sends a transaction and returns its execution output. If `validation_only` is set, the transaction
+    /// is only simulated (including account validation) and is not added to the mempool; account validation
+    /// rule violations are returned as errors with a detailed description.
     #[method(name = "sendRawTransactionWithDetailedOutput")]
     async fn send_raw_transaction_with_detailed_output(
         &self,
         tx_bytes: Bytes,
+        validation_only: Option<bool>,
     ) -> RpcResult<TransactionDetailedResult>;
 }
@@ -139,6 +139,8 @@ impl TempConfigStore {
             Some(EthSender {
                 operator,
                 blob_operator,
+                previous_operator: None,
+                previous_blob_operator: None,
             })
         });
         let state_keeper = self
@@ -2,6 +2,7 @@
 
 use std::{
     fmt,
+    sync::{atomic::Ordering, Arc},
     time::{Duration, Instant},
 };
 
@@ -12,7 +13,7 @@ use zksync_dal::{Connection, Core};
 use zksync_multivm::{
     interface::{
         executor::{OneshotExecutor, TransactionValidator},
-        storage::StorageWithOverrides,
+        storage::{ReadStorage, StorageWithOverrides},
         tracer::TimestampAsserterParams,
         Call, ExecutionResult, OneshotEnv, OneshotTracingParams, TransactionExecutionMetrics,
         TxExecutionArgs, VmEvent,
@@ -21,11 +22,16 @@ use zksync_multivm::{
 };
 use zksync_state::{PostgresStorage, PostgresStorageCaches};
 use zksync_types::{
-    api::state_override::StateOverride, fee_model::BatchFeeInput, l2::L2Tx, StorageLog, Transaction,
+    api::state_override::StateOverride, fee_model::BatchFeeInput, l2::L2Tx, StorageKey, StorageLog,
+    StorageValue, Transaction, H256,
 };
 use zksync_vm_executor::oneshot::{MainOneshotExecutor, MockOneshotExecutor};
 
-use super::{vm_metrics::SandboxStage, BlockArgs, VmPermit, SANDBOX_METRICS};
+use super::{
+    snapshot::{SnapshotBudgetExceeded, SnapshotStateReader, SnapshotStorage},
+    vm_metrics::SandboxStage,
+    BlockArgs, VmPermit, SANDBOX_METRICS,
+};
 use crate::{execution_sandbox::storage::apply_state_override, tx_sender::SandboxExecutorOptions};
 
 /// Action that can be executed by [`SandboxExecutor`].
@@ -85,7 +91,44 @@ pub(crate) struct SandboxExecutionOutput {
     pub are_published_bytecodes_ok: bool,
 }
 
-type SandboxStorage = StorageWithOverrides<PostgresStorage<'static>>;
+/// Storage used by the sandbox. Calls against pruned blocks can be served from a snapshot.
+#[derive(Debug)]
+pub(crate) enum SandboxReadStorage {
+    Postgres(PostgresStorage<'static>),
+    Snapshot(SnapshotStorage),
+}
+
+impl ReadStorage for SandboxReadStorage {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        match self {
+            Self::Postgres(storage) => storage.read_value(key),
+            Self::Snapshot(storage) => storage.read_value(key),
+        }
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        match self {
+            Self::Postgres(storage) => storage.is_write_initial(key),
+            Self::Snapshot(storage) => storage.is_write_initial(key),
+        }
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        match self {
+            Self::Postgres(storage) => storage.load_factory_dep(hash),
+            Self::Snapshot(storage) => storage.load_factory_dep(hash),
+        }
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        match self {
+            Self::Postgres(storage) => storage.get_enumeration_index(key),
+            Self::Snapshot(storage) => storage.get_enumeration_index(key),
+        }
+    }
+}
+
+type SandboxStorage = StorageWithOverrides<SandboxReadStorage>;
 
 /// Higher-level wrapper around a oneshot VM executor used in the API server.
 #[async_trait]
@@ -150,6 +193,7 @@ pub(crate) struct SandboxExecutor {
     pub(super) options: SandboxExecutorOptions,
     storage_caches: Option<PostgresStorageCaches>,
     pub(super) timestamp_asserter_params: Option<TimestampAsserterParams>,
+    snapshot_reader: Option<Arc<SnapshotStateReader>>,
 }
 
 impl SandboxExecutor {
@@ -171,6 +215,7 @@ impl SandboxExecutor {
             options,
             storage_caches: Some(caches),
             timestamp_asserter_params,
+            snapshot_reader: None,
         }
     }
 
@@ -187,9 +232,19 @@ impl SandboxExecutor {
             options,
             storage_caches: None,
             timestamp_asserter_params: None,
+            snapshot_reader: None,
         }
     }
 
+    /// Enables serving calls against pruned blocks from snapshots.
+    pub(crate) fn set_snapshot_reader(&mut self, reader: SnapshotStateReader) {
+        self.snapshot_reader = Some(Arc::new(reader));
+    }
+
+    pub(crate) fn snapshot_reader(&self) -> Option<&SnapshotStateReader> {
+        self.snapshot_reader.as_deref()
+    }
+
     /// This method assumes that (block with number `resolved_block_number` is present in DB)
     /// or (`block_id` is `pending` and block with number `resolved_block_number - 1` is present in DB)
     pub async fn execute_in_sandbox(
@@ -204,12 +259,28 @@ impl SandboxExecutor {
             .prepare_env_and_storage(connection, block_args, &action)
             .await?;
 
+        let budget_exceeded = match &storage {
+            SandboxReadStorage::Snapshot(storage) => Some(storage.budget_exceeded_flag()),
+            SandboxReadStorage::Postgres(_) => None,
+        };
+
         let state_override = state_override.unwrap_or_default();
         let storage = apply_state_override(storage, &state_override);
         let (execution_args, tracing_params) = action.into_parts();
-        self.engine
+        let output = self
+            .engine
             .execute_in_sandbox(storage, env, execution_args, tracing_params)
-            .await
+            .await?;
+
+        if budget_exceeded.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            // The execution result is meaningless since the VM observed default values for some storage slots.
+            let budget = self
+                .snapshot_reader
+                .as_ref()
+                .map_or(0, |reader| reader.chunks_budget());
+            return Err(SnapshotBudgetExceeded(budget).into());
+        }
+        Ok(output)
     }
 
     pub(super) async fn prepare_env_and_storage(
@@ -217,7 +288,7 @@ impl SandboxExecutor {
         mut connection: Connection<'static, Core>,
         block_args: &BlockArgs,
         action: &SandboxAction,
-    ) -> anyhow::Result<(OneshotEnv, PostgresStorage<'static>)> {
+    ) -> anyhow::Result<(OneshotEnv, SandboxReadStorage)> {
         let initialization_stage = SANDBOX_METRICS.sandbox[&SandboxStage::Initialization].start();
         let resolve_started_at = Instant::now();
         let resolve_time = resolve_started_at.elapsed();
@@ -279,6 +350,16 @@ impl SandboxExecutor {
         if let Some(caches) = &self.storage_caches {
             storage = storage.with_caches(caches.clone());
         }
+
+        let storage = if let Some(snapshot) = block_args.snapshot {
+            let reader = self
+                .snapshot_reader
+                .clone()
+                .context("block args refer to a snapshot, but snapshot reader is not configured")?;
+            SandboxReadStorage::Snapshot(SnapshotStorage::new(storage, reader, snapshot))
+        } else {
+            SandboxReadStorage::Postgres(storage)
+        };
         initialization_stage.observe();
         Ok((env, storage))
     }
@@ -12,17 +12,19 @@ use zksync_types::{
 };
 use zksync_vm_executor::oneshot::{BlockInfo, ResolvedBlockInfo};
 
-use self::vm_metrics::SandboxStage;
 pub(super) use self::{
     error::SandboxExecutionError,
     execute::{SandboxAction, SandboxExecutionOutput, SandboxExecutor},
+    snapshot::{SnapshotBudgetExceeded, SnapshotStateReader},
     validate::ValidationError,
     vm_metrics::{SubmitTxStage, SANDBOX_METRICS},
 };
+use self::{snapshot::SnapshotBlock, vm_metrics::SandboxStage};
 
 // Note: keep the modules private, and instead re-export functions that make public interface.
 mod error;
 mod execute;
+mod snapshot;
 mod storage;
 #[cfg(test)]
 mod tests;
@@ -288,6 +290,8 @@ pub(crate) struct BlockArgs {
     inner: BlockInfo,
     resolved: ResolvedBlockInfo,
     block_id: api::BlockId,
+    /// Set for pruned blocks which state is reconstructed from a snapshot.
+    snapshot: Option<SnapshotBlock>,
 }
 
 impl BlockArgs {
@@ -298,6 +302,7 @@ impl BlockArgs {
             inner,
             resolved,
             block_id: api::BlockId::Number(api::BlockNumber::Pending),
+            snapshot: None,
         })
     }
 
@@ -340,10 +345,75 @@ impl BlockArgs {
             inner,
             resolved: inner.resolve(connection).await?,
             block_id,
+            snapshot: None,
+        })
+    }
+
+    /// Same as [`Self::new()`], but if the block is pruned, attempts to reconstruct its state from a snapshot.
+    /// This is only possible for the last L2 block of an L1 batch with a complete snapshot. The L1 batch and L2 block
+    /// context is restored from the snapshot state, except for the historical fee input, which is taken
+    /// from the first retained block.
+    pub async fn with_snapshot_fallback(
+        connection: &mut Connection<'_, Core>,
+        block_id: api::BlockId,
+        start_info: &BlockStartInfo,
+        snapshot_reader: &SnapshotStateReader,
+    ) -> Result<Self, BlockArgsError> {
+        let first_l2_block = match start_info
+            .ensure_not_pruned_block(block_id, connection)
+            .await
+        {
+            Ok(()) => return Self::new(connection, block_id, start_info).await,
+            Err(BlockArgsError::Pruned(first_l2_block)) => first_l2_block,
+            Err(err) => return Err(err),
+        };
+        let block_number = match block_id {
+            api::BlockId::Number(api::BlockNumber::Number(number)) => {
+                u32::try_from(number).ok().map(L2BlockNumber)
+            }
+            api::BlockId::Number(api::BlockNumber::Earliest) => Some(L2BlockNumber(0)),
+            _ => None,
+        };
+        let Some(block_number) = block_number else {
+            return Err(BlockArgsError::Pruned(first_l2_block));
+        };
+
+        let first_l1_batch = start_info.first_l1_batch(connection).await?;
+        let snapshot = snapshot_reader
+            .resolve_block(connection, block_number, first_l1_batch)
+            .await?;
+        let Some(snapshot) = snapshot else {
+            return Err(BlockArgsError::Pruned(first_l2_block));
+        };
+        // The first retained block may be absent if the node was recovered from a snapshot recently.
+        let has_first_l2_block = connection
+            .blocks_dal()
+            .get_l2_block_header(first_l2_block)
+            .await
+            .map_err(DalError::generalize)?
+            .is_some();
+        if !has_first_l2_block {
+            return Err(BlockArgsError::Pruned(first_l2_block));
+        }
+
+        let resolved = snapshot_reader
+            .resolve_block_info(connection, &snapshot, first_l2_block)
+            .await?;
+        let Some(resolved) = resolved else {
+            return Err(BlockArgsError::Pruned(first_l2_block));
+        };
+        Ok(Self {
+            inner: BlockInfo::for_existing_block(connection, first_l2_block).await?,
+            resolved,
+            block_id,
+            snapshot: Some(snapshot),
         })
     }
 
     pub fn resolved_block_number(&self) -> L2BlockNumber {
+        if let Some(snapshot) = &self.snapshot {
+            return snapshot.l2_block_number;
+        }
         self.inner.block_number()
     }
 
@@ -0,0 +1,337 @@
+//! Reconstruction of storage state for pruned blocks from storage snapshots.
+//!
+//! A node with enabled pruning removes historical storage logs, so calls against pruned blocks cannot be served from Postgres.
+//! If an L2 block is the last block of an L1 batch for which a complete snapshot exists, the storage state at this block
+//! is fully contained in the snapshot storage logs. [`SnapshotStateReader`] resolves such blocks and loads storage log chunks
+//! from the object store on demand, bounding the number of chunks that a single call can touch.
+
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use anyhow::Context as _;
+use lru::LruCache;
+use tokio::runtime::Handle;
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_multivm::interface::storage::ReadStorage;
+use zksync_object_store::ObjectStore;
+use zksync_state::PostgresStorage;
+use zksync_types::{
+    block::unpack_block_info,
+    h256_to_u256,
+    snapshots::{
+        uniform_hashed_keys_chunk, SnapshotStorageLog, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsStorageKey,
+    },
+    AccountTreeId, L1BatchNumber, L2BlockNumber, StorageKey, StorageValue, H256,
+    SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
+};
+use zksync_vm_executor::oneshot::{DetachedBlockState, ResolvedBlockInfo};
+
+/// Maximum number of storage log chunks cached in memory across calls.
+const MAX_CACHED_CHUNKS: usize = 16;
+
+/// Storage logs from a single snapshot chunk keyed by the hashed storage key.
+type StorageLogsChunk = HashMap<H256, SnapshotStorageLog>;
+
+/// Returned (wrapped in `anyhow::Error`) by the sandbox if executing a call would require loading more snapshot chunks
+/// than allowed by the per-call budget.
+#[derive(Debug, thiserror::Error)]
+#[error("reconstructing state for a pruned block requires more than {0} snapshot chunks")]
+pub(crate) struct SnapshotBudgetExceeded(pub usize);
+
+/// Pruned L2 block which state can be reconstructed from a snapshot.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SnapshotBlock {
+    pub l1_batch_number: L1BatchNumber,
+    pub l2_block_number: L2BlockNumber,
+    storage_logs_chunk_count: u64,
+}
+
+impl SnapshotBlock {
+    fn chunk_id(&self, hashed_key: H256) -> u64 {
+        // Chunks cover the entire hashed key space in order, so we can use binary search.
+        let (mut start, mut end) = (0, self.storage_logs_chunk_count);
+        while end - start > 1 {
+            let mid = (start + end) / 2;
+            if hashed_key < *uniform_hashed_keys_chunk(mid, self.storage_logs_chunk_count).start() {
+                end = mid;
+            } else {
+                start = mid;
+            }
+        }
+        start
+    }
+}
+
+/// Reader of snapshot storage logs shared among all API calls.
+#[derive(Debug)]
+pub(crate) struct SnapshotStateReader {
+    blob_store: Arc<dyn ObjectStore>,
+    chunks_budget: usize,
+    chunks_cache: Mutex<LruCache<(L1BatchNumber, u64), Arc<StorageLogsChunk>>>,
+    /// Numbers of the last L2 blocks in snapshot L1 batches; lazily populated.
+    snapshot_l2_blocks: Mutex<HashMap<L1BatchNumber, L2BlockNumber>>,
+}
+
+impl SnapshotStateReader {
+    /// Creates a reader allowing each call to touch at most `chunks_budget` storage log chunks.
+    pub(crate) fn new(blob_store: Arc<dyn ObjectStore>, chunks_budget: usize) -> Self {
+        Self {
+            blob_store,
+            chunks_budget,
+            chunks_cache: Mutex::new(LruCache::new(NonZeroUsize::new(MAX_CACHED_CHUNKS).unwrap())),
+            snapshot_l2_blocks: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn chunks_budget(&self) -> usize {
+        self.chunks_budget
+    }
+
+    async fn load_chunk(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        chunk_id: u64,
+    ) -> anyhow::Result<Arc<StorageLogsChunk>> {
+        let cache_key = (l1_batch_number, chunk_id);
+        if let Some(chunk) = self.chunks_cache.lock().unwrap().get(&cache_key) {
+            return Ok(chunk.clone());
+        }
+
+        let key = SnapshotStorageLogsStorageKey {
+            l1_batch_number,
+            chunk_id,
+        };
+        let chunk: SnapshotStorageLogsChunk = self.blob_store.get(key).await.with_context(|| {
+            format!("failed loading storage logs chunk {chunk_id} for snapshot L1 batch #{l1_batch_number}")
+        })?;
+        let chunk: Arc<StorageLogsChunk> = Arc::new(
+            chunk
+                .storage_logs
+                .into_iter()
+                .map(|log| (log.key, log))
+                .collect(),
+        );
+        self.chunks_cache
+            .lock()
+            .unwrap()
+            .put(cache_key, chunk.clone());
+        Ok(chunk)
+    }
+
+    async fn read_snapshot_value(
+        &self,
+        snapshot: &SnapshotBlock,
+        key: &StorageKey,
+    ) -> anyhow::Result<StorageValue> {
+        let hashed_key = key.hashed_key();
+        let chunk = self
+            .load_chunk(snapshot.l1_batch_number, snapshot.chunk_id(hashed_key))
+            .await?;
+        Ok(chunk
+            .get(&hashed_key)
+            .map_or_else(StorageValue::zero, |log| log.value))
+    }
+
+    /// Resolves a pruned L2 block to a snapshot. Returns `Ok(None)` if the block is not the last block
+    /// of a completely snapshotted L1 batch older than `first_retained_l1_batch`.
+    pub(crate) async fn resolve_block(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        block_number: L2BlockNumber,
+        first_retained_l1_batch: L1BatchNumber,
+    ) -> anyhow::Result<Option<SnapshotBlock>> {
+        // Snapshots are ordered by descending L1 batch number.
+        let snapshots = connection
+            .snapshots_dal()
+            .get_all_complete_snapshots()
+            .await?
+            .snapshots_l1_batch_numbers;
+        let snapshots = snapshots
+            .into_iter()
+            .filter(|&l1_batch_number| l1_batch_number < first_retained_l1_batch);
+
+        let mut loaded_chunks = 0;
+        for l1_batch_number in snapshots {
+            let Some(metadata) = connection
+                .snapshots_dal()
+                .get_snapshot_metadata(l1_batch_number)
+                .await?
+            else {
+                continue; // The snapshot was removed in the meantime
+            };
+            if metadata.storage_logs_filepaths.is_empty() {
+                continue;
+            }
+            let mut snapshot = SnapshotBlock {
+                l1_batch_number,
+                l2_block_number: L2BlockNumber(0),
+                storage_logs_chunk_count: metadata.storage_logs_filepaths.len() as u64,
+            };
+
+            let cached_l2_block = self
+                .snapshot_l2_blocks
+                .lock()
+                .unwrap()
+                .get(&l1_batch_number)
+                .copied();
+            snapshot.l2_block_number = if let Some(l2_block_number) = cached_l2_block {
+                l2_block_number
+            } else {
+                // Resolving L2 blocks for snapshots isn't free either, so it's subject to the same budget.
+                if loaded_chunks >= self.chunks_budget {
+                    tracing::debug!(
+                        "Snapshot chunks budget exceeded when resolving pruned L2 block #{block_number}"
+                    );
+                    break;
+                }
+                loaded_chunks += 1;
+                let l2_block_number = self.read_snapshot_l2_block(&snapshot).await?;
+                self.snapshot_l2_blocks
+                    .lock()
+                    .unwrap()
+                    .insert(l1_batch_number, l2_block_number);
+                l2_block_number
+            };
+
+            if snapshot.l2_block_number == block_number {
+                return Ok(Some(snapshot));
+            } else if snapshot.l2_block_number < block_number {
+                // All remaining snapshots are older.
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolves execution environment for the snapshot block from the snapshot state. Postgres is only used to determine
+    /// the protocol version of the block, and `postgres_l2_block_number` is the block for which Postgres storage
+    /// (used to load factory deps) will be created. Returns `Ok(None)` if the block cannot be executed in a detached state.
+    pub(crate) async fn resolve_block_info(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        snapshot: &SnapshotBlock,
+        postgres_l2_block_number: L2BlockNumber,
+    ) -> anyhow::Result<Option<ResolvedBlockInfo>> {
+        let Some(keys) = DetachedBlockState::storage_keys(snapshot.l2_block_number) else {
+            return Ok(None);
+        };
+        let mut values = [H256::zero(); 6];
+        for (value, key) in values.iter_mut().zip(&keys) {
+            *value = self.read_snapshot_value(snapshot, key).await?;
+        }
+        let state = DetachedBlockState::from_values(values);
+
+        let (_, l1_batch_timestamp) = unpack_block_info(h256_to_u256(state.l1_batch_info));
+        let protocol_version = connection
+            .protocol_versions_dal()
+            .protocol_version_id_by_timestamp(l1_batch_timestamp)
+            .await
+            .context("failed resolving protocol version for snapshot L1 batch")?;
+        ResolvedBlockInfo::detached(
+            snapshot.l2_block_number,
+            postgres_l2_block_number,
+            &state,
+            protocol_version,
+        )
+        .map(Some)
+    }
+
+    /// Reads the number of the last L2 block in the snapshot L1 batch from the system context state.
+    async fn read_snapshot_l2_block(
+        &self,
+        snapshot: &SnapshotBlock,
+    ) -> anyhow::Result<L2BlockNumber> {
+        let l2_block_info_key = StorageKey::new(
+            AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+            SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
+        );
+        let l2_block_info = self
+            .read_snapshot_value(snapshot, &l2_block_info_key)
+            .await?;
+        let (l2_block_number, _) = unpack_block_info(h256_to_u256(l2_block_info));
+        let l2_block_number = u32::try_from(l2_block_number)
+            .context("L2 block number in snapshot state overflows u32")?;
+        Ok(L2BlockNumber(l2_block_number))
+    }
+}
+
+/// VM storage serving values from a snapshot. Factory dependencies are not pruned and are loaded from Postgres.
+///
+/// If the call exceeds the chunks budget, the storage returns default values for all subsequent reads and raises
+/// the `budget_exceeded` flag, which must be checked after execution.
+#[derive(Debug)]
+pub(crate) struct SnapshotStorage {
+    inner: PostgresStorage<'static>,
+    reader: Arc<SnapshotStateReader>,
+    snapshot: SnapshotBlock,
+    rt_handle: Handle,
+    touched_chunks: HashMap<u64, Arc<StorageLogsChunk>>,
+    budget_exceeded: Arc<AtomicBool>,
+}
+
+impl SnapshotStorage {
+    pub fn new(
+        inner: PostgresStorage<'static>,
+        reader: Arc<SnapshotStateReader>,
+        snapshot: SnapshotBlock,
+    ) -> Self {
+        Self {
+            inner,
+            reader,
+            snapshot,
+            rt_handle: Handle::current(),
+            touched_chunks: HashMap::new(),
+            budget_exceeded: Arc::default(),
+        }
+    }
+
+    pub fn budget_exceeded_flag(&self) -> Arc<AtomicBool> {
+        self.budget_exceeded.clone()
+    }
+
+    fn read_log(&mut self, key: &StorageKey) -> Option<&SnapshotStorageLog> {
+        let hashed_key = key.hashed_key();
+        let chunk_id = self.snapshot.chunk_id(hashed_key);
+        if !self.touched_chunks.contains_key(&chunk_id) {
+            if self.touched_chunks.len() >= self.reader.chunks_budget {
+                self.budget_exceeded.store(true, Ordering::Relaxed);
+                return None;
+            }
+            let chunk = self
+                .rt_handle
+                .block_on(
+                    self.reader
+                        .load_chunk(self.snapshot.l1_batch_number, chunk_id),
+                )
+                .expect("failed loading snapshot storage logs");
+            self.touched_chunks.insert(chunk_id, chunk);
+        }
+        self.touched_chunks[&chunk_id].get(&hashed_key)
+    }
+}
+
+impl ReadStorage for SnapshotStorage {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        self.read_log(key)
+            .map_or_else(StorageValue::zero, |log| log.value)
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.read_log(key).is_none()
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.inner.load_factory_dep(hash)
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.read_log(key).map(|log| log.enumeration_index)
+    }
+}
@@ -5,21 +5,38 @@ use std::collections::HashMap;
 use assert_matches::assert_matches;
 use test_casing::test_casing;
 use zksync_dal::ConnectionPool;
-use zksync_multivm::{interface::ExecutionResult, utils::derive_base_fee_and_gas_per_pubdata};
+use zksync_multivm::interface::storage::ReadStorage;
+use zksync_multivm::{
+    interface::{ExecutionResult, OneshotTracingParams},
+    utils::derive_base_fee_and_gas_per_pubdata,
+};
 use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
 use zksync_node_test_utils::{create_l1_batch, create_l2_block, prepare_recovery_snapshot};
-use zksync_state::PostgresStorageCaches;
+use zksync_object_store::MockObjectStore;
+use zksync_state::{PostgresStorage, PostgresStorageCaches};
 use zksync_test_contracts::Account;
 use zksync_types::{
     api::state_override::{OverrideAccount, StateOverride},
+    block::pack_block_info,
     fee::Fee,
     fee_model::BatchFeeInput,
-    Address, ProtocolVersionId, Transaction, U256,
+    l2::L2Tx,
+    snapshots::{
+        SnapshotStorageLog, SnapshotStorageLogsChunk, SnapshotStorageLogsStorageKey,
+        SnapshotVersion,
+    },
+    u256_to_h256,
+    utils::storage_key_for_eth_balance,
+    AccountTreeId, Address, ProtocolVersionId, StorageKey, Transaction,
+    CURRENT_VIRTUAL_BLOCK_INFO_POSITION, H256, SYSTEM_CONTEXT_ADDRESS,
+    SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION, U256,
 };
+use zksync_vm_executor::oneshot::DetachedBlockState;
 
 use super::*;
 use crate::{
-    execution_sandbox::execute::SandboxExecutor, testonly::TestAccount,
+    execution_sandbox::{execute::SandboxExecutor, snapshot::SnapshotStorage},
+    testonly::TestAccount,
     tx_sender::SandboxExecutorOptions,
 };
 
@@ -192,6 +209,307 @@ async fn creating_block_args_after_snapshot_recovery() {
     }
 }
 
+#[tokio::test]
+async fn creating_block_args_for_pruned_block_from_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let snapshot_recovery =
+        prepare_recovery_snapshot(&mut storage, L1BatchNumber(23), L2BlockNumber(42), &[]).await;
+    let l2_block = create_l2_block(snapshot_recovery.l2_block_number.0 + 1);
+    storage
+        .blocks_dal()
+        .insert_l2_block(&l2_block)
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .insert_mock_l1_batch(&create_l1_batch(snapshot_recovery.l1_batch_number.0 + 1))
+        .await
+        .unwrap();
+    storage
+        .blocks_dal()
+        .mark_l2_blocks_as_executed_in_l1_batch(snapshot_recovery.l1_batch_number + 1)
+        .await
+        .unwrap();
+
+    let l2_block_info_key = StorageKey::new(
+        AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+        SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
+    );
+    let slot_key = StorageKey::new(AccountTreeId::new(Address::repeat_byte(1)), H256::zero());
+    let storage_logs = [
+        (
+            l2_block_info_key,
+            u256_to_h256(pack_block_info(
+                snapshot_recovery.l2_block_number.0.into(),
+                snapshot_recovery.l2_block_timestamp,
+            )),
+        ),
+        (slot_key, H256::repeat_byte(0xff)),
+    ];
+    let storage_logs = storage_logs
+        .into_iter()
+        .enumerate()
+        .map(|(i, (key, value))| SnapshotStorageLog {
+            key: key.hashed_key(),
+            value,
+            l1_batch_number_of_initial_write: L1BatchNumber(1),
+            enumeration_index: i as u64 + 1,
+        })
+        .collect();
+    let blob_store = MockObjectStore::arc();
+    let chunk_key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot_recovery.l1_batch_number,
+        chunk_id: 0,
+    };
+    blob_store
+        .put(chunk_key, &SnapshotStorageLogsChunk { storage_logs })
+        .await
+        .unwrap();
+    storage
+        .snapshots_dal()
+        .add_snapshot(
+            SnapshotVersion::Version1,
+            snapshot_recovery.l1_batch_number,
+            1,
+            "factory_deps",
+        )
+        .await
+        .unwrap();
+    storage
+        .snapshots_dal()
+        .add_storage_logs_filepath_for_snapshot(snapshot_recovery.l1_batch_number, 0, "chunk")
+        .await
+        .unwrap();
+
+    let start_info = BlockStartInfo::new(&mut storage, Duration::MAX)
+        .await
+        .unwrap();
+    let reader = Arc::new(SnapshotStateReader::new(blob_store.clone(), 1));
+    let snapshot_block = api::BlockId::Number(snapshot_recovery.l2_block_number.0.into());
+    let block_args =
+        BlockArgs::with_snapshot_fallback(&mut storage, snapshot_block, &start_info, &reader)
+            .await
+            .unwrap();
+    assert_eq!(
+        block_args.resolved_block_number(),
+        snapshot_recovery.l2_block_number
+    );
+    let snapshot = block_args.snapshot.unwrap();
+    assert_eq!(snapshot.l1_batch_number, snapshot_recovery.l1_batch_number);
+
+    // Only the last block in the snapshot L1 batch can be reconstructed.
+    let other_block = api::BlockId::Number((snapshot_recovery.l2_block_number.0 - 1).into());
+    let err = BlockArgs::with_snapshot_fallback(&mut storage, other_block, &start_info, &reader)
+        .await
+        .unwrap_err();
+    assert_matches!(err, BlockArgsError::Pruned(_));
+    // Resolving an uncached snapshot is subject to the chunks budget.
+    let no_budget_reader = SnapshotStateReader::new(blob_store, 0);
+    let err = BlockArgs::with_snapshot_fallback(
+        &mut storage,
+        snapshot_block,
+        &start_info,
+        &no_budget_reader,
+    )
+    .await
+    .unwrap_err();
+    assert_matches!(err, BlockArgsError::Pruned(_));
+
+    let postgres_storage = PostgresStorage::new_async(
+        tokio::runtime::Handle::current(),
+        storage,
+        l2_block.number,
+        false,
+    )
+    .await
+    .unwrap();
+    let mut snapshot_storage = SnapshotStorage::new(postgres_storage, reader, snapshot);
+    let budget_exceeded = snapshot_storage.budget_exceeded_flag();
+    tokio::task::spawn_blocking(move || {
+        assert_eq!(
+            snapshot_storage.read_value(&slot_key),
+            H256::repeat_byte(0xff)
+        );
+        assert!(!snapshot_storage.is_write_initial(&slot_key));
+        assert_eq!(snapshot_storage.get_enumeration_index(&slot_key), Some(2));
+
+        let missing_key =
+            StorageKey::new(AccountTreeId::new(Address::repeat_byte(2)), H256::zero());
+        assert_eq!(snapshot_storage.read_value(&missing_key), H256::zero());
+        assert!(snapshot_storage.is_write_initial(&missing_key));
+    })
+    .await
+    .unwrap();
+    assert!(!budget_exceeded.load(std::sync::atomic::Ordering::Relaxed));
+}
+
+#[tokio::test]
+async fn eth_call_for_pruned_block_from_snapshot() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let genesis_params = GenesisParams::mock();
+    insert_genesis_batch(&mut storage, &genesis_params)
+        .await
+        .unwrap();
+    // L2 blocks #1 and #2 are in L1 batch #1, L2 block #3 is in L1 batch #2.
+    for number in 1..=3 {
+        storage
+            .blocks_dal()
+            .insert_l2_block(&create_l2_block(number))
+            .await
+            .unwrap();
+        if number > 1 {
+            let l1_batch_number = L1BatchNumber(number - 1);
+            storage
+                .blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch(l1_batch_number.0))
+                .await
+                .unwrap();
+            storage
+                .blocks_dal()
+                .mark_l2_blocks_as_executed_in_l1_batch(l1_batch_number)
+                .await
+                .unwrap();
+        }
+    }
+
+    // Snapshot the state at the end of L1 batch #1 based on the genesis state.
+    let snapshot_l1_batch = L1BatchNumber(1);
+    let snapshot_l2_block = L2BlockNumber(2);
+    let alice = Account::random();
+    let alice_balance = U256::from(123_456_789);
+    let mut snapshot_logs: HashMap<_, _> = storage
+        .storage_logs_dal()
+        .dump_all_storage_logs_for_tests()
+        .await
+        .into_iter()
+        .map(|log| (log.hashed_key, log.value))
+        .collect();
+    let state_keys = DetachedBlockState::storage_keys(snapshot_l2_block).unwrap();
+    let virtual_block_info_key = StorageKey::new(
+        AccountTreeId::new(SYSTEM_CONTEXT_ADDRESS),
+        CURRENT_VIRTUAL_BLOCK_INFO_POSITION,
+    );
+    let overridden_values = [
+        // L1 batch info, L2 block info, txs rolling hash, previous and pre-previous L2 block hashes
+        (state_keys[0], u256_to_h256(pack_block_info(1, 2))),
+        (state_keys[1], u256_to_h256(pack_block_info(2, 2))),
+        (state_keys[2], H256::repeat_byte(1)),
+        (state_keys[3], H256::repeat_byte(2)),
+        (state_keys[4], H256::repeat_byte(3)),
+        (virtual_block_info_key, u256_to_h256(pack_block_info(2, 2))),
+        (
+            storage_key_for_eth_balance(&alice.address()),
+            u256_to_h256(alice_balance),
+        ),
+    ];
+    for (key, value) in overridden_values {
+        snapshot_logs.insert(key.hashed_key(), value);
+    }
+    let storage_logs = snapshot_logs
+        .into_iter()
+        .enumerate()
+        .map(|(i, (key, value))| SnapshotStorageLog {
+            key,
+            value,
+            l1_batch_number_of_initial_write: L1BatchNumber(0),
+            enumeration_index: i as u64 + 1,
+        })
+        .collect();
+    let blob_store = MockObjectStore::arc();
+    let chunk_key = SnapshotStorageLogsStorageKey {
+        l1_batch_number: snapshot_l1_batch,
+        chunk_id: 0,
+    };
+    blob_store
+        .put(chunk_key, &SnapshotStorageLogsChunk { storage_logs })
+        .await
+        .unwrap();
+    storage
+        .snapshots_dal()
+        .add_snapshot(
+            SnapshotVersion::Version1,
+            snapshot_l1_batch,
+            1,
+            "factory_deps",
+        )
+        .await
+        .unwrap();
+    storage
+        .snapshots_dal()
+        .add_storage_logs_filepath_for_snapshot(snapshot_l1_batch, 0, "chunk")
+        .await
+        .unwrap();
+
+    // Prune L1 batch #1. Factory deps are retained by pruning.
+    storage
+        .pruning_dal()
+        .insert_soft_pruning_log(snapshot_l1_batch, snapshot_l2_block)
+        .await
+        .unwrap();
+    storage
+        .pruning_dal()
+        .hard_prune_batches_range(snapshot_l1_batch, snapshot_l2_block)
+        .await
+        .unwrap();
+    storage
+        .pruning_dal()
+        .insert_hard_pruning_log(snapshot_l1_batch, snapshot_l2_block, H256::zero())
+        .await
+        .unwrap();
+
+    let start_info = BlockStartInfo::new(&mut storage, Duration::MAX)
+        .await
+        .unwrap();
+    let reader = SnapshotStateReader::new(blob_store, 1);
+    let block_args = BlockArgs::with_snapshot_fallback(
+        &mut storage,
+        api::BlockId::Number(snapshot_l2_block.0.into()),
+        &start_info,
+        &reader,
+    )
+    .await
+    .unwrap();
+    assert_eq!(block_args.resolved_block_number(), snapshot_l2_block);
+    drop(storage);
+
+    let mut executor = SandboxExecutor::real(
+        SandboxExecutorOptions::mock().await,
+        PostgresStorageCaches::new(1, 1),
+        usize::MAX,
+        None,
+    );
+    executor.set_snapshot_reader(reader);
+
+    let mut call = alice.query_base_token_balance();
+    call.gas = Some(10_000_000.into());
+    let call = L2Tx::from_request(call.into(), usize::MAX, true).unwrap();
+    let (limiter, _) = VmConcurrencyLimiter::new(1);
+    let vm_permit = limiter.acquire().await.unwrap();
+    let action = SandboxAction::Call {
+        call,
+        fee_input: BatchFeeInput::l1_pegged(55, 555),
+        enforced_base_fee: None,
+        tracing_params: OneshotTracingParams::default(),
+    };
+    let output = executor
+        .execute_in_sandbox(
+            vm_permit,
+            pool.connection().await.unwrap(),
+            action,
+            &block_args,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let ExecutionResult::Success { output } = output.result else {
+        panic!("Unexpected call result: {:?}", output.result);
+    };
+    assert_eq!(U256::from_big_endian(&output), alice_balance);
+}
+
 #[tokio::test]
 async fn estimating_gas() {
     let pool = ConnectionPool::<Core>::test_pool().await;
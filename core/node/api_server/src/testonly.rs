@@ -556,6 +556,7 @@ pub(crate) fn mock_execute_transaction(transaction: Transaction) -> TransactionE
         refunded_gas: 0,
         call_traces: vec![],
         revert_reason: None,
+        storage_accesses: None,
     }
 }
 
@@ -600,6 +601,7 @@ pub(crate) async fn store_custom_l2_block(
             1.into(),
             ProtocolVersionId::latest(),
             false,
+            false,
         )
         .await?;
     Ok(())
@@ -11,8 +11,11 @@ use zksync_multivm::{
 };
 use zksync_system_constants::MAX_L2_TX_GAS_LIMIT;
 use zksync_types::{
-    api::state_override::StateOverride, fee::Fee, fee_model::BatchFeeInput, get_code_key,
-    ExecuteTransactionCommon, PackedEthSignature, ProtocolVersionId, Transaction, H256,
+    api::{state_override::StateOverride, FeeBreakdown, FeeEstimate},
+    fee::Fee,
+    fee_model::BatchFeeInput,
+    get_code_key, ExecuteTransactionCommon, PackedEthSignature, ProtocolVersionId, Transaction,
+    H256,
 };
 
 use super::{result::ApiCallResult, SubmitTxError, TxSender};
@@ -49,7 +52,7 @@ impl TxSender {
         acceptable_overestimation: u64,
         state_override: Option<StateOverride>,
         kind: BinarySearchKind,
-    ) -> Result<Fee, SubmitTxError> {
+    ) -> Result<FeeEstimate, SubmitTxError> {
         let estimation_started_at = Instant::now();
         let mut estimator = GasEstimator::new(self, tx, block_args, state_override).await?;
         estimator.adjust_transaction_fee();
@@ -113,12 +116,30 @@ impl TxSender {
             "Finished estimating gas limit for transaction"
         );
 
-        let suggested_gas_limit = (unscaled_gas_limit as f64 * estimated_fee_scale_factor) as u64;
         estimator
-            .finalize(suggested_gas_limit, estimated_fee_scale_factor)
+            .finalize(unscaled_gas_limit, estimated_fee_scale_factor)
             .await
     }
 
+    /// Executes a transaction with the specified gas limit (including the operator overhead) and the base fee
+    /// derived from the current fee input, like the state keeper would. Returns the execution result and the gas used.
+    pub(crate) async fn execute_with_gas_limit(
+        &self,
+        tx: Transaction,
+        gas_limit: u64,
+        block_args: BlockArgs,
+        state_override: Option<StateOverride>,
+    ) -> Result<(ExecutionResult, u64), SubmitTxError> {
+        let mut estimator = GasEstimator::new(self, tx, block_args, state_override).await?;
+        estimator.adjust_transaction_fee();
+        let (result, tx_metrics) = estimator.unadjusted_step(gas_limit).await?;
+        if !result.is_failed() {
+            self.ensure_tx_executable(&estimator.transaction, tx_metrics, false)?;
+        }
+        let gas_used = gas_limit.saturating_sub(tx_metrics.gas_refunded);
+        Ok((result, gas_used))
+    }
+
     async fn binary_search(
         estimator: &GasEstimator<'_>,
         bounds: ops::RangeInclusive<u64>,
@@ -481,9 +502,10 @@ impl<'a> GasEstimator<'a> {
 
     async fn finalize(
         self,
-        suggested_gas_limit: u64,
+        unscaled_gas_limit: u64,
         estimated_fee_scale_factor: f64,
-    ) -> Result<Fee, SubmitTxError> {
+    ) -> Result<FeeEstimate, SubmitTxError> {
+        let suggested_gas_limit = (unscaled_gas_limit as f64 * estimated_fee_scale_factor) as u64;
         let (result, tx_metrics) = self.step(suggested_gas_limit).await?;
         result.into_api_call_result()?;
         self.sender
@@ -538,11 +560,23 @@ impl<'a> GasEstimator<'a> {
             self.transaction.gas_per_pubdata_byte_limit(),
         );
 
-        Ok(Fee {
+        let fee = Fee {
             max_fee_per_gas: self.base_fee.into(),
             max_priority_fee_per_gas: 0u32.into(),
             gas_limit: full_gas_limit.into(),
             gas_per_pubdata_limit,
+        };
+        let breakdown = FeeBreakdown {
+            computation_gas: unscaled_gas_limit.saturating_sub(gas_for_pubdata).into(),
+            pubdata_bytes: tx_metrics.vm.pubdata_published.into(),
+            gas_per_pubdata: self.gas_per_pubdata_byte.into(),
+            pubdata_gas: gas_for_pubdata.into(),
+            overhead_gas: overhead.into(),
+            scale_factor: estimated_fee_scale_factor,
+        };
+        Ok(FeeEstimate {
+            fee,
+            breakdown: Some(breakdown),
         })
     }
 }
@@ -6,7 +6,8 @@ use anyhow::Context as _;
 use tokio::sync::RwLock;
 use zksync_config::configs::{api::Web3JsonRpcConfig, chain::StateKeeperConfig};
 use zksync_dal::{
-    transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool, Core, CoreDal,
+    address_policies_dal::AddressPolicy, transactions_dal::L2TxSubmissionResult, Connection,
+    ConnectionPool, Core, CoreDal,
 };
 use zksync_multivm::{
     interface::{
@@ -18,6 +19,7 @@ use zksync_multivm::{
     },
 };
 use zksync_node_fee_model::{ApiFeeInputProvider, BatchFeeModelInputProvider};
+use zksync_object_store::ObjectStore;
 use zksync_state::PostgresStorageCaches;
 use zksync_state_keeper::{
     seal_criteria::{ConditionalSealer, NoopSealer, SealData},
@@ -40,8 +42,9 @@ use zksync_vm_executor::oneshot::{
 pub(super) use self::{gas_estimation::BinarySearchKind, result::SubmitTxError};
 use self::{master_pool_sink::MasterPoolSink, result::ApiCallResult, tx_sink::TxSink};
 use crate::execution_sandbox::{
-    BlockArgs, SandboxAction, SandboxExecutionOutput, SandboxExecutor, SubmitTxStage,
-    VmConcurrencyBarrier, VmConcurrencyLimiter, SANDBOX_METRICS,
+    BlockArgs, SandboxAction, SandboxExecutionOutput, SandboxExecutor, SnapshotBudgetExceeded,
+    SnapshotStateReader, SubmitTxStage, VmConcurrencyBarrier, VmConcurrencyLimiter,
+    SANDBOX_METRICS,
 };
 
 mod gas_estimation;
@@ -160,6 +163,8 @@ pub struct TxSenderBuilder {
     sealer: Option<Arc<dyn ConditionalSealer>>,
     /// Cache for tokens that are white-listed for AA.
     whitelisted_tokens_for_aa_cache: Option<Arc<RwLock<Vec<Address>>>>,
+    /// Reader used to serve calls against pruned blocks from snapshots.
+    snapshot_reader: Option<SnapshotStateReader>,
 }
 
 impl TxSenderBuilder {
@@ -174,6 +179,7 @@ impl TxSenderBuilder {
             tx_sink,
             sealer: None,
             whitelisted_tokens_for_aa_cache: None,
+            snapshot_reader: None,
         }
     }
 
@@ -187,6 +193,17 @@ impl TxSenderBuilder {
         self
     }
 
+    /// Enables `eth_call` against pruned blocks for which state can be reconstructed from snapshots in `blob_store`.
+    /// Each call may load at most `chunks_budget` snapshot storage log chunks.
+    pub fn with_pruned_calls_from_snapshots(
+        mut self,
+        blob_store: Arc<dyn ObjectStore>,
+        chunks_budget: usize,
+    ) -> Self {
+        self.snapshot_reader = Some(SnapshotStateReader::new(blob_store, chunks_budget));
+        self
+    }
+
     pub fn build(
         self,
         batch_fee_input_provider: Arc<dyn BatchFeeModelInputProvider>,
@@ -204,7 +221,7 @@ impl TxSenderBuilder {
             .config
             .vm_execution_cache_misses_limit
             .unwrap_or(usize::MAX);
-        let executor = SandboxExecutor::real(
+        let mut executor = SandboxExecutor::real(
             executor_options,
             storage_caches,
             missed_storage_invocation_limit,
@@ -215,6 +232,9 @@ impl TxSenderBuilder {
                 }
             }),
         );
+        if let Some(reader) = self.snapshot_reader {
+            executor.set_snapshot_reader(reader);
+        }
 
         TxSender(Arc::new(TxSenderInner {
             sender_config: self.config,
@@ -244,6 +264,9 @@ pub struct TxSenderConfig {
     pub chain_id: L2ChainId,
     pub whitelisted_tokens_for_aa: Vec<Address>,
     pub timestamp_asserter_params: Option<TimestampAsserterParams>,
+    /// Whether address policies are mirrored from the on-chain policy registry. If not set, initiators
+    /// are not checked against address policies.
+    pub address_policies_enabled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -271,6 +294,7 @@ impl TxSenderConfig {
             chain_id,
             whitelisted_tokens_for_aa: web3_json_config.whitelisted_tokens_for_aa.clone(),
             timestamp_asserter_params,
+            address_policies_enabled: false,
         }
     }
 }
@@ -305,6 +329,10 @@ impl TxSender {
         Arc::clone(&self.0.vm_concurrency_limiter)
     }
 
+    pub(crate) fn snapshot_reader(&self) -> Option<&SnapshotStateReader> {
+        self.0.executor.snapshot_reader()
+    }
+
     pub(crate) async fn read_whitelisted_tokens_for_aa_cache(&self) -> Vec<Address> {
         self.0.whitelisted_tokens_for_aa_cache.read().await.clone()
     }
@@ -322,6 +350,26 @@ impl TxSender {
         &self,
         tx: L2Tx,
         block_args: BlockArgs,
+    ) -> Result<SandboxExecutionOutput, SubmitTxError> {
+        self.submit_tx_inner(tx, block_args, false).await
+    }
+
+    /// Performs all checks done by [`Self::submit_tx()`], including the dry run and account validation,
+    /// without submitting the transaction to the mempool.
+    #[tracing::instrument(level = "debug", name = "simulate_tx_validation", skip_all, fields(tx.hash = ?tx.hash()))]
+    pub(crate) async fn simulate_tx_validation(
+        &self,
+        tx: L2Tx,
+        block_args: BlockArgs,
+    ) -> Result<SandboxExecutionOutput, SubmitTxError> {
+        self.submit_tx_inner(tx, block_args, true).await
+    }
+
+    async fn submit_tx_inner(
+        &self,
+        tx: L2Tx,
+        block_args: BlockArgs,
+        validation_only: bool,
     ) -> Result<SandboxExecutionOutput, SubmitTxError> {
         let tx_hash = tx.hash();
         let stage_latency = SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::Validate);
@@ -379,11 +427,14 @@ impl TxSender {
         if !execution_output.are_published_bytecodes_ok {
             return Err(SubmitTxError::FailedToPublishCompressedBytecodes);
         }
-        let mut stage_latency =
-            SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::DbInsert);
         self.ensure_tx_executable(&tx.clone().into(), execution_output.metrics, true)?;
-
         let validation_traces = validation_result?;
+        if validation_only {
+            return Ok(execution_output);
+        }
+
+        let mut stage_latency =
+            SANDBOX_METRICS.start_tx_submit_stage(tx_hash, SubmitTxStage::DbInsert);
         let submission_res_handle = self
             .0
             .tx_sink
@@ -494,6 +545,7 @@ impl TxSender {
             return Err(SubmitTxError::IntrinsicGas);
         }
 
+        self.validate_address_policy(tx).await?;
         // We still double-check the nonce manually
         // to make sure that only the correct nonce is submitted and the transaction's hashes never repeat
         self.validate_account_nonce(tx).await?;
@@ -503,6 +555,30 @@ impl TxSender {
         Ok(())
     }
 
+    /// Checks the initiator against policies mirrored from the on-chain address policy registry.
+    /// Greylisted initiators are accepted; their transactions are deprioritized by the mempool.
+    async fn validate_address_policy(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
+        if !self.0.sender_config.address_policies_enabled {
+            return Ok(());
+        }
+        let initiator_account = tx.initiator_account();
+        let policy = self
+            .acquire_replica_connection()
+            .await?
+            .address_policies_dal()
+            .get_address_policy(initiator_account)
+            .await
+            .with_context(|| format!("failed getting policy for {initiator_account:?}"))?;
+        if policy == Some(AddressPolicy::Blacklisted) {
+            tracing::info!(
+                "Submitted Tx {:?} is rejected because its initiator {initiator_account:?} is blacklisted",
+                tx.hash()
+            );
+            return Err(SubmitTxError::SenderBlacklisted(initiator_account));
+        }
+        Ok(())
+    }
+
     async fn validate_account_nonce(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
         let Nonce(expected_nonce) = self
             .get_expected_nonce(tx.initiator_account())
@@ -635,7 +711,11 @@ impl TxSender {
             .0
             .executor
             .execute_in_sandbox(vm_permit, connection, action, &block_args, state_override)
-            .await?;
+            .await
+            .map_err(|err| match err.downcast::<SnapshotBudgetExceeded>() {
+                Ok(err) => SubmitTxError::Unexecutable(err.to_string()),
+                Err(err) => SubmitTxError::Internal(err),
+            })?;
         result.result.into_api_call_result()
     }
 
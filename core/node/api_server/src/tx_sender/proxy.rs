@@ -7,6 +7,7 @@ use std::{
 use anyhow::Context;
 use chrono::{TimeZone, Utc};
 use tokio::sync::{watch, RwLock};
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Metrics};
 use zksync_dal::{
     helpers::wait_for_l1_batch, transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool,
     Core, CoreDal, DalError,
@@ -22,6 +23,30 @@ use zksync_web3_decl::{
 
 use super::{tx_sink::TxSink, SubmitTxError};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "outcome", rename_all = "snake_case")]
+enum QueuedTxOutcome {
+    Resubmitted,
+    Rejected,
+    Expired,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_tx_proxy_queue")]
+struct TxProxyQueueMetrics {
+    /// Number of transactions queued because the main node was unavailable.
+    queued: Counter,
+    /// Current number of transactions in the queue.
+    size: Gauge<usize>,
+    /// Number of transactions rejected because the queue is full.
+    overflowed: Counter,
+    /// Number of transactions removed from the queue, grouped by the outcome.
+    processed: Family<QueuedTxOutcome, Counter>,
+}
+
+#[vise::register]
+static QUEUE_METRICS: vise::Global<TxProxyQueueMetrics> = vise::Global::new();
+
 /// In-memory transaction cache for a full node. Works like an ad-hoc mempool replacement, with the important limitation that
 /// it's not synchronized across the network.
 ///
@@ -205,12 +230,32 @@ impl TxCache {
     }
 }
 
+/// Sends a raw transaction to the main node.
+async fn send_raw_tx(
+    client: &DynClient<L2>,
+    tx_hash: H256,
+    raw_tx: &[u8],
+) -> EnrichedClientResult<H256> {
+    let raw_tx = zksync_types::web3::Bytes(raw_tx.to_vec());
+    client
+        .send_raw_transaction(raw_tx)
+        .rpc_context("send_raw_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .await
+}
+
 /// Used by external node to proxy transaction to the main node
 /// and store them while they're not synced back yet
+///
+/// If the retry queue is enabled (see [`Self::with_retry_queue()`]), transactions that cannot be proxied because
+/// the main node is unavailable are persisted in Postgres and are resubmitted by [`TxProxyQueueTask`].
+/// The queue is bounded; once it's full, new transactions are rejected until the main node becomes available again.
 #[derive(Debug)]
 pub struct TxProxy {
     tx_cache: TxCache,
     client: Box<DynClient<L2>>,
+    retry_queue_pool: Option<ConnectionPool<Core>>,
+    retry_queue_capacity: usize,
 }
 
 impl TxProxy {
@@ -218,19 +263,56 @@ impl TxProxy {
         Self {
             tx_cache: TxCache::default(),
             client: client.for_component("tx_proxy"),
+            retry_queue_pool: None,
+            retry_queue_capacity: 0,
         }
     }
 
+    /// Enables the durable retry queue for transactions that failed to be proxied because of transient errors.
+    /// The queue holds at most `capacity` transactions.
+    pub fn with_retry_queue(mut self, pool: ConnectionPool<Core>, capacity: usize) -> Self {
+        self.retry_queue_pool = Some(pool);
+        self.retry_queue_capacity = capacity;
+        self
+    }
+
     async fn submit_tx_impl(&self, tx: &L2Tx) -> EnrichedClientResult<H256> {
         let input_data = tx.common_data.input_data().expect("raw tx is absent");
-        let raw_tx = zksync_types::web3::Bytes(input_data.to_vec());
         let tx_hash = tx.hash();
         tracing::info!("Proxying tx {tx_hash:?}");
-        self.client
-            .send_raw_transaction(raw_tx)
-            .rpc_context("send_raw_transaction")
-            .with_arg("tx_hash", &tx_hash)
+        send_raw_tx(self.client.as_ref(), tx_hash, input_data).await
+    }
+
+    /// Persists a transaction in the retry queue. Returns `false` if the queue is disabled, or an error
+    /// if the queue is full.
+    async fn enqueue_tx(&self, tx: &L2Tx) -> Result<bool, SubmitTxError> {
+        let Some(pool) = &self.retry_queue_pool else {
+            return Ok(false);
+        };
+        let input_data = tx.common_data.input_data().expect("raw tx is absent");
+        let mut storage = pool
+            .connection_tagged("api")
+            .await
+            .map_err(DalError::generalize)?;
+        let queue_size = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions_count()
             .await
+            .map_err(DalError::generalize)?;
+        if queue_size >= self.retry_queue_capacity {
+            QUEUE_METRICS.overflowed.inc();
+            return Err(SubmitTxError::ProxyQueueFull(self.retry_queue_capacity));
+        }
+
+        let inserted = storage
+            .proxied_transactions_dal()
+            .insert_transaction(tx.hash(), tx.initiator_account(), tx.nonce(), input_data)
+            .await
+            .map_err(DalError::generalize)?;
+        if inserted {
+            QUEUE_METRICS.queued.inc();
+        }
+        Ok(true)
     }
 
     async fn find_tx(
@@ -289,6 +371,18 @@ impl TxProxy {
         let cache = self.tx_cache.clone();
         AccountNonceSweeperTask { cache, pool }
     }
+
+    /// Returns a task resubmitting queued transactions, or `None` if the retry queue is disabled.
+    /// Transactions not accepted by the main node within `ttl` are dropped.
+    pub fn retry_queue_task(&self, ttl: Duration) -> Option<TxProxyQueueTask> {
+        Some(TxProxyQueueTask {
+            cache: self.tx_cache.clone(),
+            client: self.client.clone(),
+            pool: self.retry_queue_pool.clone()?,
+            ttl,
+            poll_interval: TxProxyQueueTask::DEFAULT_POLL_INTERVAL,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -303,6 +397,96 @@ impl AccountNonceSweeperTask {
     }
 }
 
+/// Resubmits transactions from the retry queue to the main node.
+///
+/// Transactions are resubmitted in the `(initiator, nonce)` order. Resubmission stops on the first transient error
+/// (i.e., if the main node is still unavailable) so that the order is preserved, and is retried after a delay.
+#[derive(Debug)]
+pub struct TxProxyQueueTask {
+    cache: TxCache,
+    client: Box<DynClient<L2>>,
+    pool: ConnectionPool<Core>,
+    ttl: Duration,
+    poll_interval: Duration,
+}
+
+impl TxProxyQueueTask {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+    const BATCH_SIZE: usize = 100;
+
+    async fn step(&self) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("api").await?;
+        let expired_tx_hashes = storage
+            .proxied_transactions_dal()
+            .remove_expired_transactions(self.ttl)
+            .await?;
+        for &tx_hash in &expired_tx_hashes {
+            tracing::warn!(
+                "Transaction {tx_hash:?} wasn't accepted by the main node in {:?}; dropping it",
+                self.ttl
+            );
+            self.cache.remove(tx_hash).await;
+        }
+        QUEUE_METRICS.processed[&QueuedTxOutcome::Expired].inc_by(expired_tx_hashes.len() as u64);
+
+        let queued_txs = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions(Self::BATCH_SIZE)
+            .await?;
+        for tx in queued_txs {
+            match send_raw_tx(self.client.as_ref(), tx.tx_hash, &tx.raw_tx).await {
+                Ok(_) => {
+                    tracing::info!(
+                        "Resubmitted queued tx {:?} after {} failed attempt(s)",
+                        tx.tx_hash,
+                        tx.attempts + 1
+                    );
+                    QUEUE_METRICS.processed[&QueuedTxOutcome::Resubmitted].inc();
+                    APP_METRICS.processed_txs[&TxStage::Proxied].inc();
+                }
+                Err(err) if err.is_retriable() => {
+                    tracing::debug!("Main node is still unavailable: {err}");
+                    storage
+                        .proxied_transactions_dal()
+                        .record_failed_attempt(tx.tx_hash)
+                        .await?;
+                    break;
+                }
+                Err(err) => {
+                    tracing::warn!("Main node rejected queued tx {:?}: {err}", tx.tx_hash);
+                    QUEUE_METRICS.processed[&QueuedTxOutcome::Rejected].inc();
+                    self.cache.remove(tx.tx_hash).await;
+                }
+            }
+            storage
+                .proxied_transactions_dal()
+                .remove_transaction(tx.tx_hash)
+                .await?;
+        }
+
+        let queue_size = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions_count()
+            .await?;
+        QUEUE_METRICS.size.set(queue_size);
+        Ok(())
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow() {
+            self.step().await?;
+            if tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, tx proxy queue is shutting down");
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl TxSink for TxProxy {
     async fn submit_tx(
@@ -316,6 +500,31 @@ impl TxSink for TxProxy {
         // Before it reaches the main node.
         self.tx_cache.push(tx.clone()).await;
         if let Err(err) = self.submit_tx_impl(tx).await {
+            // If the main node is temporarily unavailable, the transaction is queued to be resubmitted later
+            // and is retained in the cache.
+            let enqueue_result = if err.is_retriable() {
+                self.enqueue_tx(tx).await
+            } else {
+                Ok(false)
+            };
+            match enqueue_result {
+                Ok(true) => {
+                    tracing::info!(
+                        "Main node is unavailable ({err}); queued tx {:?} for resubmission",
+                        tx.hash()
+                    );
+                    return Ok(L2TxSubmissionResult::Proxied);
+                }
+                Ok(false) => { /* the queue is disabled, or the error is not transient */ }
+                Err(queue_err) => {
+                    tracing::warn!(
+                        "Main node is unavailable ({err}), and tx {:?} cannot be queued: {queue_err}",
+                        tx.hash()
+                    );
+                    self.tx_cache.remove(tx.hash()).await;
+                    return Err(queue_err);
+                }
+            }
             // Remove the transaction from the cache on failure so that it doesn't occupy space in the cache indefinitely.
             self.tx_cache.remove(tx.hash()).await;
             return Err(err.into());
@@ -380,12 +589,22 @@ impl TxSink for TxProxy {
         }
         Ok(None)
     }
+
+    async fn lookup_raw_tx(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        hash: H256,
+    ) -> Result<Option<Vec<u8>>, Web3Error> {
+        let tx = self.find_tx(storage, hash).await?;
+        Ok(tx.and_then(|tx| tx.common_data.input.map(|input| input.data)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicBool, Ordering};
 
+    use assert_matches::assert_matches;
     use test_casing::test_casing;
     use zksync_node_genesis::{insert_genesis_batch, mock_genesis_config, GenesisParams};
     use zksync_node_test_utils::{create_l2_block, create_l2_transaction};
@@ -542,6 +761,125 @@ mod tests {
         assert!(found_tx.is_none(), "{found_tx:?}");
     }
 
+    #[tokio::test]
+    async fn transaction_is_queued_on_main_node_failure() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let params = GenesisParams::load_genesis_params(mock_genesis_config()).unwrap();
+        insert_genesis_batch(&mut storage, &params).await.unwrap();
+
+        let tx = create_l2_transaction(10, 100);
+        let main_node_available = Arc::new(AtomicBool::new(false));
+        let main_node_client = MockClient::builder(L2::default())
+            .method("eth_sendRawTransaction", {
+                let main_node_available = main_node_available.clone();
+                move |_bytes: Bytes| {
+                    if main_node_available.load(Ordering::Relaxed) {
+                        Ok(H256::zero())
+                    } else {
+                        Err(ClientError::RequestTimeout)
+                    }
+                }
+            })
+            .build();
+
+        let proxy = TxProxy::new(Box::new(main_node_client)).with_retry_queue(pool.clone(), 10);
+        let submission_result = proxy
+            .submit_tx(
+                &tx,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap();
+        assert_matches!(submission_result, L2TxSubmissionResult::Proxied);
+        let found_tx = proxy.find_tx(&mut storage, tx.hash()).await.unwrap();
+        assert_eq!(found_tx.unwrap().hash(), tx.hash());
+        let queued_txs = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions(10)
+            .await
+            .unwrap();
+        assert_eq!(queued_txs.len(), 1);
+        assert_eq!(queued_txs[0].tx_hash, tx.hash());
+
+        let task = proxy.retry_queue_task(Duration::from_secs(60)).unwrap();
+        // The main node is still unavailable; the transaction must be retained.
+        task.step().await.unwrap();
+        let queued_txs = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions(10)
+            .await
+            .unwrap();
+        assert_eq!(queued_txs[0].attempts, 1);
+
+        main_node_available.store(true, Ordering::Relaxed);
+        task.step().await.unwrap();
+        let queued_count = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions_count()
+            .await
+            .unwrap();
+        assert_eq!(queued_count, 0);
+    }
+
+    #[tokio::test]
+    async fn transaction_is_rejected_if_queue_is_full() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let params = GenesisParams::load_genesis_params(mock_genesis_config()).unwrap();
+        insert_genesis_batch(&mut storage, &params).await.unwrap();
+
+        let main_node_client = MockClient::builder(L2::default())
+            .method("eth_sendRawTransaction", |_bytes: Bytes| {
+                Err::<H256, _>(ClientError::RequestTimeout)
+            })
+            .build();
+        let proxy = TxProxy::new(Box::new(main_node_client)).with_retry_queue(pool.clone(), 2);
+
+        let queued_txs: Vec<_> = (0..2).map(|_| create_l2_transaction(10, 100)).collect();
+        for tx in &queued_txs {
+            let submission_result = proxy
+                .submit_tx(
+                    tx,
+                    TransactionExecutionMetrics::default(),
+                    ValidationTraces::default(),
+                )
+                .await
+                .unwrap();
+            assert_matches!(submission_result, L2TxSubmissionResult::Proxied);
+        }
+
+        let tx = create_l2_transaction(10, 100);
+        let err = proxy
+            .submit_tx(
+                &tx,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap_err();
+        assert_matches!(err, SubmitTxError::ProxyQueueFull(2));
+        assert!(err.to_string().contains("queue is full"), "{err}");
+
+        // The rejected transaction must not be retained in either the cache or the queue.
+        let found_tx = proxy.find_tx(&mut storage, tx.hash()).await.unwrap();
+        assert!(found_tx.is_none(), "{found_tx:?}");
+        let queued_tx_hashes: Vec<_> = storage
+            .proxied_transactions_dal()
+            .get_queued_transactions(10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|tx| tx.tx_hash)
+            .collect();
+        assert_eq!(queued_tx_hashes.len(), 2);
+        assert!(!queued_tx_hashes.contains(&tx.hash()));
+        for tx in &queued_txs {
+            assert!(queued_tx_hashes.contains(&tx.hash()));
+        }
+    }
+
     #[derive(Debug, Clone, Copy)]
     enum CacheUpdateMethod {
         BackgroundTask,
@@ -1,6 +1,6 @@
 use thiserror::Error;
 use zksync_multivm::interface::ExecutionResult;
-use zksync_types::{l2::error::TxCheckError, U256};
+use zksync_types::{l2::error::TxCheckError, Address, U256};
 use zksync_web3_decl::error::EnrichedClientError;
 
 use crate::execution_sandbox::{SandboxExecutionError, ValidationError};
@@ -69,6 +69,14 @@ pub enum SubmitTxError {
     Internal(#[from] anyhow::Error),
     #[error("transaction failed block.timestamp assertion")]
     FailedBlockTimestampAssertion,
+    /// The transaction initiator is blacklisted by the on-chain address policy registry.
+    #[error("sender {0:?} is not allowed to submit transactions")]
+    SenderBlacklisted(Address),
+    /// The main node is unavailable, and the queue of transactions awaiting resubmission to it is full.
+    #[error(
+        "main node is unavailable, and the transaction resubmission queue is full (capacity: {0})"
+    )]
+    ProxyQueueFull(usize),
 }
 
 impl SubmitTxError {
@@ -99,6 +107,8 @@ impl SubmitTxError {
             Self::ProxyError(_) => "proxy-error",
             Self::Internal(_) => "internal",
             Self::FailedBlockTimestampAssertion => "failed-block-timestamp-assertion",
+            Self::SenderBlacklisted(_) => "sender-blacklisted",
+            Self::ProxyQueueFull(_) => "proxy-queue-full",
         }
     }
 
@@ -7,7 +7,10 @@ use test_casing::{test_casing, Product};
 use zksync_system_constants::CODE_ORACLE_ADDRESS;
 use zksync_test_contracts::Account;
 use zksync_types::{
-    api::state_override::{OverrideAccount, OverrideState},
+    api::{
+        state_override::{OverrideAccount, OverrideState},
+        FeeEstimate,
+    },
     bytecode::BytecodeHash,
     u256_to_h256,
     web3::keccak256,
@@ -346,6 +349,24 @@ async fn insufficient_funds_error_for_transfer() {
     );
 }
 
+fn assert_fee_breakdown_consistent(estimate: &FeeEstimate) {
+    let breakdown = estimate.breakdown.as_ref().expect("no fee breakdown");
+    assert_eq!(
+        breakdown.pubdata_gas,
+        breakdown.pubdata_bytes * breakdown.gas_per_pubdata,
+        "{breakdown:?}"
+    );
+    assert!(!breakdown.overhead_gas.is_zero(), "{breakdown:?}");
+
+    let unscaled_gas_limit = breakdown.computation_gas + breakdown.pubdata_gas;
+    let scaled_gas_limit = (unscaled_gas_limit.as_u64() as f64 * breakdown.scale_factor) as u64;
+    assert_eq!(
+        estimate.fee.gas_limit,
+        U256::from(scaled_gas_limit) + breakdown.overhead_gas,
+        "{estimate:?}"
+    );
+}
+
 async fn test_estimating_gas(
     state_override: StateOverride,
     tx: impl Into<Transaction>,
@@ -357,7 +378,7 @@ async fn test_estimating_gas(
     let block_args = pending_block_args(&tx_sender).await;
 
     let fee_scale_factor = 1.0;
-    let fee = tx_sender
+    let estimate = tx_sender
         .get_txs_fee_in_wei(
             tx.clone(),
             block_args.clone(),
@@ -369,13 +390,14 @@ async fn test_estimating_gas(
         .await
         .unwrap();
     // Sanity-check gas limit
-    let gas_limit_after_full_search = u64::try_from(fee.gas_limit).unwrap();
+    let gas_limit_after_full_search = u64::try_from(estimate.fee.gas_limit).unwrap();
     assert!(
         (10_000..10_000_000).contains(&gas_limit_after_full_search),
-        "{fee:?}"
+        "{estimate:?}"
     );
+    assert_fee_breakdown_consistent(&estimate);
 
-    let fee = tx_sender
+    let estimate = tx_sender
         .get_txs_fee_in_wei(
             tx,
             block_args,
@@ -386,7 +408,8 @@ async fn test_estimating_gas(
         )
         .await
         .unwrap();
-    let gas_limit_after_optimized_search = u64::try_from(fee.gas_limit).unwrap();
+    let gas_limit_after_optimized_search = u64::try_from(estimate.fee.gas_limit).unwrap();
+    assert_fee_breakdown_consistent(&estimate);
 
     let diff = gas_limit_after_full_search.abs_diff(gas_limit_after_optimized_search);
     assert!(
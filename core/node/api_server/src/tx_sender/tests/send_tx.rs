@@ -9,6 +9,7 @@ use zksync_multivm::interface::{tracer::ValidationTraces, ExecutionResult};
 use zksync_node_fee_model::{BatchFeeModelInputProvider, MockBatchFeeParamsProvider};
 use zksync_node_test_utils::create_l2_transaction;
 use zksync_test_contracts::Account;
+use zksync_types::L1BlockNumber;
 
 use super::*;
 use crate::testonly::{StateBuilder, TestAccount};
@@ -222,6 +223,83 @@ async fn sending_transfer_with_insufficient_balance() {
     );
 }
 
+#[tokio::test]
+async fn sending_transfer_with_address_policies() {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut tx_sender = create_real_tx_sender(pool).await;
+    Arc::get_mut(&mut tx_sender.0)
+        .unwrap()
+        .sender_config
+        .address_policies_enabled = true;
+    let block_args = pending_block_args(&tx_sender).await;
+    let mut alice = Account::random();
+    let mut bob = Account::random();
+
+    let mut storage = tx_sender.acquire_replica_connection().await.unwrap();
+    StateBuilder::default()
+        .with_balance(alice.address(), u64::MAX.into())
+        .with_balance(bob.address(), u64::MAX.into())
+        .apply(&mut storage)
+        .await;
+    let mut dal = storage.address_policies_dal();
+    dal.set_address_policy(
+        alice.address(),
+        Some(AddressPolicy::Blacklisted),
+        L1BlockNumber(1),
+    )
+    .await
+    .unwrap();
+    dal.set_address_policy(
+        bob.address(),
+        Some(AddressPolicy::Greylisted),
+        L1BlockNumber(1),
+    )
+    .await
+    .unwrap();
+    drop(storage);
+
+    let transfer = alice.create_transfer(1_000_000_000.into());
+    let err = tx_sender
+        .submit_tx(transfer, block_args.clone())
+        .await
+        .unwrap_err();
+    assert_matches!(err, SubmitTxError::SenderBlacklisted(address) if address == alice.address());
+
+    // Greylisted senders are still allowed to submit transactions.
+    let transfer = bob.create_transfer(1_000_000_000.into());
+    let vm_result = tx_sender.submit_tx(transfer, block_args).await.unwrap();
+    assert!(!vm_result.result.is_failed(), "{vm_result:?}");
+}
+
+#[tokio::test]
+async fn address_policies_are_ignored_if_disabled() {
+    let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let tx_sender = create_real_tx_sender(pool).await;
+    assert!(!tx_sender.0.sender_config.address_policies_enabled);
+    let block_args = pending_block_args(&tx_sender).await;
+    let mut alice = Account::random();
+
+    let mut storage = tx_sender.acquire_replica_connection().await.unwrap();
+    StateBuilder::default()
+        .with_balance(alice.address(), u64::MAX.into())
+        .apply(&mut storage)
+        .await;
+    storage
+        .address_policies_dal()
+        .set_address_policy(
+            alice.address(),
+            Some(AddressPolicy::Blacklisted),
+            L1BlockNumber(1),
+        )
+        .await
+        .unwrap();
+    drop(storage);
+
+    let transfer = alice.create_transfer(1_000_000_000.into());
+    let vm_result = tx_sender.submit_tx(transfer, block_args).await.unwrap();
+    assert!(!vm_result.result.is_failed(), "{vm_result:?}");
+}
+
 #[tokio::test]
 async fn sending_transfer_with_incorrect_signature() {
     let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
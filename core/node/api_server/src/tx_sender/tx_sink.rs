@@ -60,4 +60,14 @@ pub trait TxSink: std::fmt::Debug + Send + Sync + 'static {
     ) -> Result<Option<TransactionDetails>, Web3Error> {
         Ok(None)
     }
+
+    /// Attempts to look up raw signed bytes of the transaction by its hash in the sink-specific storage.
+    /// By default, returns `Ok(None)`.
+    async fn lookup_raw_tx(
+        &self,
+        _storage: &mut Connection<'_, Core>,
+        _hash: H256,
+    ) -> Result<Option<Vec<u8>>, Web3Error> {
+        Ok(None)
+    }
 }
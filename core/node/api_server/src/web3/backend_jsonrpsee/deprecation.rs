@@ -0,0 +1,353 @@
+//! Deprecation framework for RPC methods.
+//!
+//! Deprecated methods are listed in [`DeprecatedMethods::current()`]. Responses to deprecated methods are marked
+//! with a [`DeprecationNotice`] extension by [`DeprecationMiddleware`]; for the HTTP transport,
+//! [`DeprecationHeadersLayer`] converts this extension to `Deprecation`, `Sunset` and `Warning` HTTP headers
+//! and logs distinct callers of deprecated methods.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use http::{header, HeaderMap, HeaderValue};
+use lru::LruCache;
+use pin_project_lite::pin_project;
+use vise::{Counter, LabeledFamily, Metrics};
+use zksync_web3_decl::jsonrpsee::{
+    server::middleware::rpc::RpcServiceT, types::Request, MethodResponse,
+};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_jsonrpc_backend_deprecation")]
+struct DeprecationMetrics {
+    /// Number of calls to deprecated methods.
+    #[metrics(labels = ["method"])]
+    calls: LabeledFamily<&'static str, Counter>,
+    /// Number of distinct callers of deprecated methods. Only reported for the HTTP transport; callers are identified
+    /// by the forwarded IP address and user agent.
+    #[metrics(labels = ["method"])]
+    distinct_callers: LabeledFamily<&'static str, Counter>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<DeprecationMetrics> = vise::Global::new();
+
+/// Information about a deprecated RPC method.
+#[derive(Debug, Clone)]
+pub struct MethodDeprecation {
+    /// Timestamp since which the method is deprecated.
+    pub since: DateTime<Utc>,
+    /// Timestamp after which the method may be removed or changed in a breaking way.
+    pub sunset: Option<DateTime<Utc>>,
+    /// Method or another API that should be used instead.
+    pub replacement: Option<&'static str>,
+}
+
+/// Notice attached to responses of deprecated methods as a `jsonrpsee` response extension.
+#[derive(Debug, Clone)]
+pub(crate) struct DeprecationNotice {
+    method: &'static str,
+    deprecation: Arc<MethodDeprecation>,
+}
+
+impl fmt::Display for DeprecationNotice {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "method `{}` is deprecated", self.method)?;
+        if let Some(sunset) = &self.deprecation.sunset {
+            write!(
+                formatter,
+                " and may be removed after {}",
+                sunset.to_rfc3339()
+            )?;
+        }
+        if let Some(replacement) = self.deprecation.replacement {
+            write!(formatter, "; use `{replacement}` instead")?;
+        }
+        Ok(())
+    }
+}
+
+impl DeprecationNotice {
+    /// Formats a timestamp as an HTTP date, as required by the `Sunset` header (RFC 8594).
+    fn http_date(timestamp: &DateTime<Utc>) -> String {
+        timestamp.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+    }
+
+    fn add_headers(&self, headers: &mut HeaderMap) {
+        // Structured date format defined in RFC 9745.
+        let deprecation = format!("@{}", self.deprecation.since.timestamp());
+        if let Ok(value) = HeaderValue::from_str(&deprecation) {
+            headers.insert("deprecation", value);
+        }
+        if let Some(sunset) = &self.deprecation.sunset {
+            if let Ok(value) = HeaderValue::from_str(&Self::http_date(sunset)) {
+                headers.insert("sunset", value);
+            }
+        }
+        let warning = format!("299 - \"{}\"", self.to_string().replace('"', "'"));
+        if let Ok(value) = HeaderValue::from_str(&warning) {
+            headers.append(header::WARNING, value);
+        }
+    }
+}
+
+/// Registry of deprecated RPC methods.
+#[derive(Debug, Clone, Default)]
+pub struct DeprecatedMethods(Arc<HashMap<&'static str, Arc<MethodDeprecation>>>);
+
+impl DeprecatedMethods {
+    /// Returns methods deprecated in this version of the server. Methods should be added here
+    /// well in advance of being removed or changed in a breaking way, so that integrators have time to migrate.
+    pub fn current() -> Self {
+        Self::new([])
+    }
+
+    pub fn new(methods: impl IntoIterator<Item = (&'static str, MethodDeprecation)>) -> Self {
+        let methods = methods
+            .into_iter()
+            .map(|(name, deprecation)| (name, Arc::new(deprecation)))
+            .collect();
+        Self(Arc::new(methods))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn notice(&self, method_name: &str) -> Option<DeprecationNotice> {
+        let (&method, deprecation) = self.0.get_key_value(method_name)?;
+        Some(DeprecationNotice {
+            method,
+            deprecation: deprecation.clone(),
+        })
+    }
+}
+
+/// RPC-level middleware marking responses of deprecated methods with [`DeprecationNotice`] and reporting
+/// deprecated method calls as metrics.
+#[derive(Debug)]
+pub(crate) struct DeprecationMiddleware<S> {
+    inner: S,
+    deprecated_methods: DeprecatedMethods,
+}
+
+impl<S> DeprecationMiddleware<S> {
+    pub fn new(inner: S, deprecated_methods: DeprecatedMethods) -> Self {
+        Self {
+            inner,
+            deprecated_methods,
+        }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for DeprecationMiddleware<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = WithDeprecationNotice<S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        let notice = self.deprecated_methods.notice(request.method_name());
+        if let Some(notice) = &notice {
+            METRICS.calls[&notice.method].inc();
+            tracing::debug!("Called deprecated method `{}`", notice.method);
+        }
+        WithDeprecationNotice {
+            inner: self.inner.call(request),
+            notice,
+        }
+    }
+}
+
+pin_project! {
+    #[derive(Debug)]
+    pub(crate) struct WithDeprecationNotice<F> {
+        #[pin]
+        inner: F,
+        notice: Option<DeprecationNotice>,
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for WithDeprecationNotice<F> {
+    type Output = MethodResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let projection = self.project();
+        let mut response = futures::ready!(projection.inner.poll(cx));
+        if let Some(notice) = projection.notice.take() {
+            response.extensions_mut().insert(notice);
+        }
+        Poll::Ready(response)
+    }
+}
+
+type DistinctCallers = Arc<Mutex<LruCache<(&'static str, String), ()>>>;
+
+/// HTTP-level [`tower`] layer converting [`DeprecationNotice`]s to HTTP response headers and logging distinct callers
+/// of deprecated methods.
+#[derive(Debug, Clone)]
+pub(crate) struct DeprecationHeadersLayer {
+    distinct_callers: DistinctCallers,
+}
+
+impl DeprecationHeadersLayer {
+    /// Maximum number of tracked distinct callers. If exceeded, the least recent callers will be logged again.
+    const MAX_TRACKED_CALLERS: NonZeroUsize = match NonZeroUsize::new(10_000) {
+        Some(value) => value,
+        None => unreachable!(),
+    };
+}
+
+impl Default for DeprecationHeadersLayer {
+    fn default() -> Self {
+        Self {
+            distinct_callers: Arc::new(Mutex::new(LruCache::new(Self::MAX_TRACKED_CALLERS))),
+        }
+    }
+}
+
+impl<Svc> tower::Layer<Svc> for DeprecationHeadersLayer {
+    type Service = DeprecationHeaders<Svc>;
+
+    fn layer(&self, inner: Svc) -> Self::Service {
+        DeprecationHeaders {
+            inner,
+            distinct_callers: self.distinct_callers.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DeprecationHeaders<S> {
+    inner: S,
+    distinct_callers: DistinctCallers,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for DeprecationHeaders<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = DeprecationHeadersFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Cloning `HeaderValue`s is cheap, so we don't bother formatting the caller ID unless it's necessary.
+        let headers = request.headers();
+        let forwarded_for = headers.get("x-forwarded-for").cloned();
+        let user_agent = headers.get(header::USER_AGENT).cloned();
+        DeprecationHeadersFuture {
+            inner: self.inner.call(request),
+            forwarded_for,
+            user_agent,
+            distinct_callers: self.distinct_callers.clone(),
+        }
+    }
+}
+
+pin_project! {
+    #[derive(Debug)]
+    pub(crate) struct DeprecationHeadersFuture<F> {
+        #[pin]
+        inner: F,
+        forwarded_for: Option<HeaderValue>,
+        user_agent: Option<HeaderValue>,
+        distinct_callers: DistinctCallers,
+    }
+}
+
+impl<F, ResBody, E> Future for DeprecationHeadersFuture<F>
+where
+    F: Future<Output = Result<http::Response<ResBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let projection = self.project();
+        let mut response = futures::ready!(projection.inner.poll(cx))?;
+        let Some(notice) = response.extensions().get::<DeprecationNotice>().cloned() else {
+            return Poll::Ready(Ok(response));
+        };
+        notice.add_headers(response.headers_mut());
+
+        let header_str = |value: &Option<HeaderValue>| {
+            value
+                .as_ref()
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or("unknown")
+                .to_owned()
+        };
+        let caller = format!(
+            "{} ({})",
+            header_str(projection.forwarded_for),
+            header_str(projection.user_agent)
+        );
+        let is_new_caller = projection
+            .distinct_callers
+            .lock()
+            .expect("distinct callers are poisoned")
+            .put((notice.method, caller.clone()), ())
+            .is_none();
+        if is_new_caller {
+            METRICS.distinct_callers[&notice.method].inc();
+            tracing::warn!("Deprecated method `{}` called by {caller}", notice.method);
+        }
+        Poll::Ready(Ok(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn deprecation_headers() {
+        let notice = DeprecationNotice {
+            method: "eth_test",
+            deprecation: Arc::new(MethodDeprecation {
+                since: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+                sunset: Some(Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap()),
+                replacement: Some("eth_newTest"),
+            }),
+        };
+        let mut headers = HeaderMap::new();
+        notice.add_headers(&mut headers);
+
+        assert_eq!(headers["deprecation"], "@1735689600");
+        assert_eq!(headers["sunset"], "Sun, 01 Jun 2025 12:00:00 GMT");
+        let warning = headers[header::WARNING].to_str().unwrap();
+        assert!(warning.starts_with("299 - \""), "{warning}");
+        assert!(warning.contains("`eth_test` is deprecated"), "{warning}");
+        assert!(warning.contains("use `eth_newTest` instead"), "{warning}");
+    }
+
+    #[test]
+    fn looking_up_deprecated_methods() {
+        let deprecated_methods = DeprecatedMethods::new([(
+            "eth_test",
+            MethodDeprecation {
+                since: Utc::now(),
+                sunset: None,
+                replacement: None,
+            },
+        )]);
+
+        let notice = deprecated_methods.notice("eth_test").unwrap();
+        assert_eq!(notice.method, "eth_test");
+        assert_eq!(notice.to_string(), "method `eth_test` is deprecated");
+        assert!(deprecated_methods.notice("eth_other").is_none());
+    }
+}
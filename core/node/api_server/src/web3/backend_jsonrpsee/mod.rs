@@ -7,19 +7,26 @@ use zksync_web3_decl::{
     jsonrpsee::types::{error::ErrorCode, ErrorObjectOwned},
 };
 
+pub use self::deprecation::{DeprecatedMethods, MethodDeprecation};
 pub(crate) use self::{
+    deprecation::{DeprecationHeadersLayer, DeprecationMiddleware},
     metadata::{MethodMetadata, MethodTracer},
     middleware::{
         CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware, TrafficTracker,
     },
+    snapshots_limit::{ConsumerIdLayer, SnapshotsLimitMiddleware, SnapshotsRateLimiter},
+    validation::ValidationMiddleware,
 };
 use crate::tx_sender::SubmitTxError;
 
+mod deprecation;
 mod metadata;
 mod middleware;
 pub mod namespaces;
+mod snapshots_limit;
 #[cfg(test)]
 pub(crate) mod testonly;
+mod validation;
 
 impl MethodTracer {
     pub(crate) fn map_err(&self, err: Web3Error) -> ErrorObjectOwned {
@@ -39,6 +46,9 @@ impl MethodTracer {
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
+            | Web3Error::TooManyTransactions(_)
+            | Web3Error::IntermediateStateUnavailable(_)
+            | Web3Error::TraceSizeLimitExceeded(_)
             | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
             Web3Error::SubmitTransactionError(_, _)
             | Web3Error::SerializationError(_)
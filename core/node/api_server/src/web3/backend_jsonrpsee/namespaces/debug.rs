@@ -1,7 +1,10 @@
 use zksync_types::{
-    api::{BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, TracerConfig},
+    api::{
+        BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, StorageAccessSet,
+        StorageRangeResult, TracerConfig,
+    },
     transaction_request::CallRequest,
-    H256,
+    Address, H256,
 };
 use zksync_web3_decl::{
     jsonrpsee::core::{async_trait, RpcResult},
@@ -52,4 +55,26 @@ impl DebugNamespaceServer for DebugNamespace {
             .await
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn get_transaction_storage_accesses(
+        &self,
+        tx_hash: H256,
+    ) -> RpcResult<Option<StorageAccessSet>> {
+        self.get_transaction_storage_accesses_impl(tx_hash)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn storage_range_at(
+        &self,
+        block: BlockId,
+        tx_index: usize,
+        address: Address,
+        start_key: H256,
+        max_result: usize,
+    ) -> RpcResult<StorageRangeResult> {
+        self.storage_range_at_impl(block, tx_index, address, start_key, max_result)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
@@ -1,6 +1,7 @@
 use zksync_types::{
     api::{
-        ChainAggProof, DataAvailabilityDetails, L1ToL2TxsStatus, TeeProof, TransactionExecutionInfo,
+        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, TeeProof,
+        TransactionExecutionInfo,
     },
     tee_types::TeeType,
     L1BatchNumber, L2ChainId, H256,
@@ -67,4 +68,10 @@ impl UnstableNamespaceServer for UnstableNamespace {
             .await
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn gateway_migration_status(&self) -> RpcResult<GatewayMigrationStatus> {
+        self.gateway_migration_status_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
@@ -2,10 +2,11 @@ use std::collections::HashMap;
 
 use zksync_types::{
     api::{
-        state_override::StateOverride, BlockDetails, BridgeAddresses, L1BatchDetails,
-        L2ToL1LogProof, Proof, ProtocolVersion, TransactionDetailedResult, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BridgeAddresses, ChainCreationParams,
+        EvmCompatibilityInfo, FeeEstimate, GasPerPubdataInfo, L1BatchDetails,
+        L1ToL2TransactionSimulation, L2ToL1LogProof, NodeVersion, Proof, ProtocolVersion,
+        ProtocolVersionInfo, SystemContracts, TransactionDetailedResult, TransactionDetails,
     },
-    fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
     transaction_request::CallRequest,
     web3, Address, L1BatchNumber, L2BlockNumber, H256, U256, U64,
@@ -24,7 +25,7 @@ impl ZksNamespaceServer for ZksNamespace {
         &self,
         req: CallRequest,
         state_override: Option<StateOverride>,
-    ) -> RpcResult<Fee> {
+    ) -> RpcResult<FeeEstimate> {
         self.estimate_fee_impl(req, state_override)
             .await
             .map_err(|err| self.current_method().map_err(err))
@@ -40,6 +41,16 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn simulate_l1_to_l2_transactions(
+        &self,
+        reqs: Vec<CallRequest>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<Vec<L1ToL2TransactionSimulation>> {
+        self.simulate_l1_to_l2_transactions_impl(reqs, state_override)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_bridgehub_contract(&self) -> RpcResult<Option<Address>> {
         Ok(self.get_bridgehub_contract_impl())
     }
@@ -128,6 +139,12 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_raw_transaction(&self, hash: H256) -> RpcResult<Option<web3::Bytes>> {
+        self.get_raw_transaction_impl(hash)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_raw_block_transactions(
         &self,
         block_number: L2BlockNumber,
@@ -170,6 +187,33 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_gas_per_pubdata(&self) -> RpcResult<GasPerPubdataInfo> {
+        self.get_gas_per_pubdata_impl()
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_evm_compatibility_info(&self) -> RpcResult<EvmCompatibilityInfo> {
+        self.get_evm_compatibility_info_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_chain_creation_params(&self) -> RpcResult<ChainCreationParams> {
+        self.get_chain_creation_params_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_system_contracts(&self) -> RpcResult<SystemContracts> {
+        self.get_system_contracts_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_node_version(&self) -> RpcResult<NodeVersion> {
+        Ok(self.get_node_version_impl())
+    }
+
     async fn get_protocol_version(
         &self,
         version_id: Option<u16>,
@@ -179,6 +223,12 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_protocol_version_info(&self) -> RpcResult<ProtocolVersionInfo> {
+        self.get_protocol_version_info_impl()
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_proof(
         &self,
         address: Address,
@@ -198,9 +248,13 @@ impl ZksNamespaceServer for ZksNamespace {
     async fn send_raw_transaction_with_detailed_output(
         &self,
         tx_bytes: web3::Bytes,
+        validation_only: Option<bool>,
     ) -> RpcResult<TransactionDetailedResult> {
-        self.send_raw_transaction_with_detailed_output_impl(tx_bytes)
-            .await
-            .map_err(|err| self.current_method().map_err(err))
+        self.send_raw_transaction_with_detailed_output_impl(
+            tx_bytes,
+            validation_only.unwrap_or(false),
+        )
+        .await
+        .map_err(|err| self.current_method().map_err(err))
     }
 }
@@ -0,0 +1,295 @@
+//! Per-consumer rate limiting for the `snapshots` namespace.
+//!
+//! Snapshot consumers (e.g., third-party external nodes) are identified by [`ConsumerIdLayer`] on the HTTP level
+//! and rate-limited by [`SnapshotsLimitMiddleware`] on the RPC level, so that a single consumer cannot monopolize
+//! signing snapshot URLs. Methods from other namespaces are not limited.
+//!
+//! Consumers are identified by the `X-Forwarded-For` entry appended by the outermost trusted reverse proxy,
+//! i.e. the entry at the configured number of proxy hops from the right. Entries to the left of it are supplied
+//! by the client and cannot be trusted. If the number of hops is not configured, the header is ignored
+//! and all consumers share a single quota.
+
+use std::{
+    fmt,
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use governor::{clock::DefaultClock, state::keyed::DefaultKeyedStateStore, Quota, RateLimiter};
+use vise::{Counter, Metrics};
+use zksync_web3_decl::jsonrpsee::{
+    server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
+    types::{error::ErrorCode, ErrorObject, Request},
+    MethodResponse,
+};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_jsonrpc_backend_snapshots")]
+struct SnapshotsLimitMetrics {
+    /// Number of rate-limited requests to the `snapshots` namespace.
+    rate_limited: Counter,
+}
+
+#[vise::register]
+static METRICS: vise::Global<SnapshotsLimitMetrics> = vise::Global::new();
+
+/// Identifier of an API consumer attached to requests as an extension.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ConsumerId(Arc<str>);
+
+impl ConsumerId {
+    /// Used if the consumer cannot be identified, e.g. if the server is not behind a trusted proxy.
+    fn unknown() -> Self {
+        Self("unknown".into())
+    }
+
+    /// Extracts the consumer ID from the `X-Forwarded-For` header populated by `proxy_hops` trusted proxies.
+    /// Each proxy appends the address of its peer, so the entry at `proxy_hops` from the right is the address
+    /// of the consumer as seen by the outermost trusted proxy.
+    fn from_headers(headers: &http::HeaderMap, proxy_hops: Option<NonZeroU32>) -> Self {
+        let Some(proxy_hops) = proxy_hops else {
+            return Self::unknown();
+        };
+        // Multiple `X-Forwarded-For` headers are equivalent to a single header with comma-separated values.
+        let mut entries = vec![];
+        for value in headers.get_all("x-forwarded-for") {
+            let Ok(value) = value.to_str() else {
+                return Self::unknown();
+            };
+            entries.extend(value.split(',').map(str::trim));
+        }
+
+        let idx_from_right = proxy_hops.get() as usize - 1;
+        let consumer_addr = entries
+            .iter()
+            .rev()
+            .nth(idx_from_right)
+            .and_then(|entry| entry.parse::<IpAddr>().ok());
+        consumer_addr.map_or_else(Self::unknown, |addr| Self(addr.to_string().into()))
+    }
+}
+
+/// HTTP-level [`tower`] layer attaching [`ConsumerId`] to requests based on the `X-Forwarded-For` header.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConsumerIdLayer {
+    proxy_hops: Option<NonZeroU32>,
+}
+
+impl ConsumerIdLayer {
+    pub fn new(proxy_hops: Option<NonZeroU32>) -> Self {
+        Self { proxy_hops }
+    }
+}
+
+impl<Svc> tower::Layer<Svc> for ConsumerIdLayer {
+    type Service = WithConsumerId<Svc>;
+
+    fn layer(&self, inner: Svc) -> Self::Service {
+        WithConsumerId {
+            inner,
+            proxy_hops: self.proxy_hops,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct WithConsumerId<S> {
+    inner: S,
+    proxy_hops: Option<NonZeroU32>,
+}
+
+impl<S, ReqBody> tower::Service<http::Request<ReqBody>> for WithConsumerId<S>
+where
+    S: tower::Service<http::Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let consumer_id = ConsumerId::from_headers(request.headers(), self.proxy_hops);
+        request.extensions_mut().insert(consumer_id);
+        self.inner.call(request)
+    }
+}
+
+type KeyedRateLimiter = RateLimiter<ConsumerId, DefaultKeyedStateStore<ConsumerId>, DefaultClock>;
+
+/// Rate limiter for the `snapshots` namespace shared among all server sessions.
+#[derive(Clone)]
+pub(crate) struct SnapshotsRateLimiter(Arc<KeyedRateLimiter>);
+
+impl fmt::Debug for SnapshotsRateLimiter {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SnapshotsRateLimiter")
+            .field("tracked_consumers", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SnapshotsRateLimiter {
+    /// Number of tracked consumers after which the state of consumers with replenished quotas is discarded.
+    const MAX_TRACKED_CONSUMERS: usize = 10_000;
+
+    pub fn new(requests_per_minute_limit: NonZeroU32) -> Self {
+        Self(Arc::new(RateLimiter::keyed(Quota::per_minute(
+            requests_per_minute_limit,
+        ))))
+    }
+
+    fn check(&self, consumer_id: &ConsumerId) -> bool {
+        let is_allowed = self.0.check_key(consumer_id).is_ok();
+        if self.0.len() > Self::MAX_TRACKED_CONSUMERS {
+            self.0.retain_recent();
+        }
+        is_allowed
+    }
+}
+
+/// RPC-level middleware limiting requests to the `snapshots` namespace per [`ConsumerId`].
+#[derive(Debug)]
+pub(crate) struct SnapshotsLimitMiddleware<S> {
+    inner: S,
+    rate_limiter: SnapshotsRateLimiter,
+}
+
+impl<S> SnapshotsLimitMiddleware<S> {
+    pub fn new(inner: S, rate_limiter: SnapshotsRateLimiter) -> Self {
+        Self {
+            inner,
+            rate_limiter,
+        }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for SnapshotsLimitMiddleware<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = ResponseFuture<S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        if request.method_name().starts_with("snapshots_") {
+            let consumer_id = request
+                .extensions()
+                .get::<ConsumerId>()
+                .cloned()
+                .unwrap_or_else(ConsumerId::unknown);
+            if !self.rate_limiter.check(&consumer_id) {
+                METRICS.rate_limited.inc();
+                tracing::debug!(
+                    "Rate-limited `{}` call from consumer {consumer_id:?}",
+                    request.method_name()
+                );
+                let rp = MethodResponse::error(
+                    request.id,
+                    ErrorObject::borrowed(
+                        ErrorCode::ServerError(http::StatusCode::TOO_MANY_REQUESTS.as_u16().into())
+                            .code(),
+                        "Too many requests",
+                        None,
+                    ),
+                );
+                return ResponseFuture::ready(rp);
+            }
+        }
+        ResponseFuture::future(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracting_consumer_id() {
+        let one_hop = NonZeroU32::new(1);
+        let two_hops = NonZeroU32::new(2);
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(
+            ConsumerId::from_headers(&headers, one_hop),
+            ConsumerId::unknown()
+        );
+
+        headers.insert(
+            "x-forwarded-for",
+            http::HeaderValue::from_static("203.0.113.1, 10.0.0.1"),
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, one_hop),
+            ConsumerId("10.0.0.1".into())
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, two_hops),
+            ConsumerId("203.0.113.1".into())
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, NonZeroU32::new(3)),
+            ConsumerId::unknown()
+        );
+        // The header is ignored if the server is not configured to be behind a proxy.
+        assert_eq!(
+            ConsumerId::from_headers(&headers, None),
+            ConsumerId::unknown()
+        );
+    }
+
+    #[test]
+    fn consumer_id_cannot_be_spoofed() {
+        let one_hop = NonZeroU32::new(1);
+        let mut headers = http::HeaderMap::new();
+        // The client supplies a fake entry, and the proxy appends the real client address.
+        headers.insert(
+            "x-forwarded-for",
+            http::HeaderValue::from_static("198.51.100.1, 203.0.113.1"),
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, one_hop),
+            ConsumerId("203.0.113.1".into())
+        );
+
+        // Multiple headers are treated as a single comma-separated list.
+        headers.insert(
+            "x-forwarded-for",
+            http::HeaderValue::from_static("198.51.100.1"),
+        );
+        headers.append(
+            "x-forwarded-for",
+            http::HeaderValue::from_static("203.0.113.1"),
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, one_hop),
+            ConsumerId("203.0.113.1".into())
+        );
+
+        // Malformed entries are not used as keys.
+        headers.insert(
+            "x-forwarded-for",
+            http::HeaderValue::from_static("203.0.113.1, not-an-ip"),
+        );
+        assert_eq!(
+            ConsumerId::from_headers(&headers, one_hop),
+            ConsumerId::unknown()
+        );
+    }
+
+    #[test]
+    fn limiting_consumers() {
+        let rate_limiter = SnapshotsRateLimiter::new(NonZeroU32::new(2).unwrap());
+        let consumer = ConsumerId("203.0.113.1".into());
+        let other_consumer = ConsumerId("203.0.113.2".into());
+
+        assert!(rate_limiter.check(&consumer));
+        assert!(rate_limiter.check(&consumer));
+        assert!(!rate_limiter.check(&consumer));
+        // Quotas are tracked per consumer.
+        assert!(rate_limiter.check(&other_consumer));
+    }
+}
@@ -0,0 +1,906 @@
+//! Strict validation of RPC method params.
+//!
+//! `jsonrpsee` reports malformed params using `serde` error messages, which differ across param types and from messages
+//! returned by other Ethereum nodes. [`ValidationMiddleware`] checks params of the common `eth_` methods before they
+//! are deserialized, and rejects malformed params with the "Invalid params" (`-32602`) error code and geth-compatible
+//! messages, such as `invalid argument 0: hex string without 0x prefix`.
+//!
+//! By default, params are only validated if the method handler rejects them, so that params of well-formed calls
+//! are parsed once. Quantities with leading zero digits (e.g., `0x01`) are accepted in this mode. If strict quantity
+//! validation is enabled, params are validated before the call, and such quantities are rejected, as in geth.
+
+use std::{
+    borrow::Cow,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+use serde_json::{value::RawValue, Map, Value};
+use thiserror::Error;
+use vise::{Counter, LabeledFamily, Metrics};
+use zksync_web3_decl::jsonrpsee::{
+    server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
+    types::{error::ErrorCode, ErrorObject, Id, Request},
+    MethodResponse,
+};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_jsonrpc_backend_validation")]
+struct ValidationMetrics {
+    /// Number of calls rejected because of malformed params.
+    #[metrics(labels = ["method"])]
+    rejected_calls: LabeledFamily<&'static str, Counter>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<ValidationMetrics> = vise::Global::new();
+
+/// Block tags supported by the server.
+const BLOCK_TAGS: &[&str] = &[
+    "committed",
+    "finalized",
+    "latest",
+    "l1_committed",
+    "earliest",
+    "pending",
+];
+
+/// Error validating a single param. Messages match the ones produced by geth.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+enum ParamError {
+    #[error("empty hex string")]
+    EmptyString,
+    #[error("hex string without 0x prefix")]
+    MissingPrefix,
+    #[error("invalid hex string")]
+    Syntax,
+    #[error("hex string of odd length")]
+    OddLength,
+    #[error("hex string \"0x\"")]
+    EmptyNumber,
+    #[error("hex number with leading zero digits")]
+    LeadingZero,
+    #[error("hex number > {bits} bits")]
+    NumberOutOfRange { bits: usize },
+    #[error("hex string too long, want at most 32 bytes")]
+    SlotTooLong,
+    #[error("hex string has length {len}, want {expected} for {type_name}")]
+    InvalidLength {
+        len: usize,
+        expected: usize,
+        type_name: &'static str,
+    },
+    #[error("json: cannot unmarshal {found} into Go value of type {type_name}")]
+    UnexpectedType {
+        found: &'static str,
+        type_name: &'static str,
+    },
+    #[error("block number larger than int64")]
+    BlockNumberOutOfRange,
+    #[error("cannot specify both BlockHash and BlockNumber, choose one or the other")]
+    BothBlockHashAndNumber,
+    #[error("at least one of BlockNumber or BlockHash is needed if a dictionary is provided")]
+    NoBlockHashOrNumber,
+    #[error("cannot specify both BlockHash and FromBlock/ToBlock, choose one or the other")]
+    BothBlockHashAndRange,
+    #[error("invalid address: {0}")]
+    InvalidAddress(Box<ParamError>),
+    #[error("invalid address at index {0}: {1}")]
+    InvalidAddressAt(usize, Box<ParamError>),
+    #[error("non-string address at index {0}")]
+    NonStringAddressAt(usize),
+    #[error("invalid addresses in query")]
+    InvalidAddresses,
+    #[error("invalid topic(s)")]
+    InvalidTopics,
+    #[error("hex has invalid length {len} after decoding; expected {expected} for {what}")]
+    InvalidDecodedLength {
+        len: usize,
+        expected: usize,
+        what: &'static str,
+    },
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn expect_str(value: &Value, type_name: &'static str) -> Result<&str, ParamError> {
+    value.as_str().ok_or(ParamError::UnexpectedType {
+        found: "non-string",
+        type_name,
+    })
+}
+
+/// Unlike geth, the `0X` prefix is not accepted since it's rejected during deserialization.
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x")
+}
+
+fn check_hex_digits(digits: &str) -> Result<(), ParamError> {
+    if digits.bytes().all(|ch| ch.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(ParamError::Syntax)
+    }
+}
+
+/// Validates a fixed-length hex value, such as an address or a hash.
+fn parse_fixed_hex(s: &str, byte_len: usize, type_name: &'static str) -> Result<(), ParamError> {
+    let digits = if s.is_empty() {
+        s
+    } else {
+        strip_hex_prefix(s).ok_or(ParamError::MissingPrefix)?
+    };
+    if digits.len() % 2 != 0 {
+        return Err(ParamError::OddLength);
+    }
+    if digits.len() != byte_len * 2 {
+        return Err(ParamError::InvalidLength {
+            len: digits.len(),
+            expected: byte_len * 2,
+            type_name,
+        });
+    }
+    check_hex_digits(digits)
+}
+
+fn parse_address(s: &str) -> Result<(), ParamError> {
+    parse_fixed_hex(s, 20, "common.Address")
+}
+
+fn parse_hash(s: &str) -> Result<(), ParamError> {
+    parse_fixed_hex(s, 32, "common.Hash")
+}
+
+/// Validates variable-length hex-encoded bytes.
+fn parse_bytes(s: &str) -> Result<(), ParamError> {
+    if s.is_empty() {
+        return Err(ParamError::EmptyString);
+    }
+    let digits = strip_hex_prefix(s).ok_or(ParamError::MissingPrefix)?;
+    if digits.len() % 2 != 0 {
+        return Err(ParamError::OddLength);
+    }
+    check_hex_digits(digits)
+}
+
+/// Validates a hex-encoded quantity with the specified maximum bit width. Leading zero digits are only rejected
+/// if `strict` is set.
+fn parse_quantity(s: &str, bits: usize, strict: bool) -> Result<(), ParamError> {
+    if s.is_empty() {
+        return Err(ParamError::EmptyString);
+    }
+    let digits = strip_hex_prefix(s).ok_or(ParamError::MissingPrefix)?;
+    if digits.is_empty() {
+        return Err(ParamError::EmptyNumber);
+    }
+    if strict && digits.len() > 1 && digits.starts_with('0') {
+        return Err(ParamError::LeadingZero);
+    }
+    check_hex_digits(digits)?;
+    if digits.len() > bits / 4 {
+        return Err(ParamError::NumberOutOfRange { bits });
+    }
+    Ok(())
+}
+
+/// Validates a storage slot. Unlike quantities, slots may have leading zeros.
+fn parse_storage_slot(s: &str) -> Result<(), ParamError> {
+    let digits = strip_hex_prefix(s).ok_or(ParamError::MissingPrefix)?;
+    if digits.is_empty() {
+        return Err(ParamError::EmptyNumber);
+    }
+    check_hex_digits(digits)?;
+    if digits.len() > 64 {
+        return Err(ParamError::SlotTooLong);
+    }
+    Ok(())
+}
+
+/// Validates a block number, which is either a supported tag or a quantity.
+fn parse_block_number(value: &Value, strict: bool) -> Result<(), ParamError> {
+    // geth treats non-string values as unquoted strings, which fail the prefix check.
+    let Some(s) = value.as_str() else {
+        return Err(ParamError::MissingPrefix);
+    };
+    if BLOCK_TAGS.contains(&s) {
+        return Ok(());
+    }
+    parse_quantity(s, 64, strict)?;
+    let digits = strip_hex_prefix(s).unwrap_or(s);
+    if digits.len() == 16 && digits.as_bytes()[0] > b'7' {
+        return Err(ParamError::BlockNumberOutOfRange);
+    }
+    Ok(())
+}
+
+/// Validates a block identifier, which is either a block hash or a block number.
+fn parse_block_id(value: &Value, strict: bool) -> Result<(), ParamError> {
+    match value.as_str() {
+        Some(s) if s.len() == 66 => parse_hash(s),
+        _ => parse_block_number(value, strict),
+    }
+}
+
+/// Validates an EIP-1898 block identifier: a block number or an object with either `blockNumber` or `blockHash`.
+fn parse_block_id_variant(value: &Value, strict: bool) -> Result<(), ParamError> {
+    let Value::Object(object) = value else {
+        return parse_block_number(value, strict);
+    };
+    let block_number = object.get("blockNumber").filter(|val| !val.is_null());
+    let block_hash = object.get("blockHash").filter(|val| !val.is_null());
+    match (block_number, block_hash) {
+        (Some(_), Some(_)) => Err(ParamError::BothBlockHashAndNumber),
+        (None, None) => Err(ParamError::NoBlockHashOrNumber),
+        (Some(number), None) => parse_block_number(number, strict),
+        (None, Some(hash)) => parse_hash(expect_str(hash, "common.Hash")?),
+    }
+}
+
+fn parse_filter_address(value: &Value) -> Result<(), ParamError> {
+    let decode_address = |s: &str| {
+        parse_bytes(s)?;
+        let len = strip_hex_prefix(s).unwrap_or(s).len() / 2;
+        if len != 20 {
+            return Err(ParamError::InvalidDecodedLength {
+                len,
+                expected: 20,
+                what: "address",
+            });
+        }
+        Ok(())
+    };
+
+    match value {
+        Value::Null => Ok(()),
+        Value::String(s) => decode_address(s).map_err(|err| ParamError::InvalidAddress(err.into())),
+        Value::Array(addresses) => {
+            for (i, address) in addresses.iter().enumerate() {
+                let address = address.as_str().ok_or(ParamError::NonStringAddressAt(i))?;
+                decode_address(address)
+                    .map_err(|err| ParamError::InvalidAddressAt(i, err.into()))?;
+            }
+            Ok(())
+        }
+        _ => Err(ParamError::InvalidAddresses),
+    }
+}
+
+fn parse_filter_topics(value: &Value) -> Result<(), ParamError> {
+    let decode_topic = |topic: &Value| match topic {
+        Value::Null => Ok(()),
+        Value::String(s) => {
+            parse_bytes(s)?;
+            let len = strip_hex_prefix(s).unwrap_or(s).len() / 2;
+            if len != 32 {
+                return Err(ParamError::InvalidDecodedLength {
+                    len,
+                    expected: 32,
+                    what: "topic",
+                });
+            }
+            Ok(())
+        }
+        _ => Err(ParamError::InvalidTopics),
+    };
+
+    let topics = match value {
+        Value::Null => return Ok(()),
+        Value::Array(topics) => topics,
+        _ => return Err(ParamError::InvalidTopics),
+    };
+    for topic in topics {
+        if let Value::Array(alternatives) = topic {
+            // Unlike geth, `null` alternatives are not supported by the server.
+            if alternatives.iter().any(Value::is_null) {
+                return Err(ParamError::InvalidTopics);
+            }
+            alternatives.iter().try_for_each(decode_topic)?;
+        } else {
+            decode_topic(topic)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validates a logs filter.
+fn parse_filter(value: &Value, strict: bool) -> Result<(), ParamError> {
+    let Value::Object(filter) = value else {
+        return Err(ParamError::UnexpectedType {
+            found: json_type(value),
+            type_name: "filters.FilterCriteria",
+        });
+    };
+    let get = |name: &str| filter.get(name).filter(|val| !val.is_null());
+
+    if let Some(block_hash) = get("blockHash") {
+        if get("fromBlock").is_some() || get("toBlock").is_some() {
+            return Err(ParamError::BothBlockHashAndRange);
+        }
+        parse_hash(expect_str(block_hash, "common.Hash")?)?;
+    }
+    for name in ["fromBlock", "toBlock"] {
+        if let Some(block_number) = get(name) {
+            parse_block_number(block_number, strict)?;
+        }
+    }
+    parse_filter_address(filter.get("address").unwrap_or(&Value::Null))?;
+    parse_filter_topics(filter.get("topics").unwrap_or(&Value::Null))
+}
+
+/// Kind of a validated param.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParamKind {
+    Address,
+    Hash,
+    Bytes,
+    Bool,
+    /// 64-bit quantity, e.g. a transaction index.
+    Quantity64,
+    /// 256-bit quantity, e.g. a filter ID.
+    Quantity256,
+    StorageSlot,
+    BlockNumber,
+    /// Block hash or number.
+    BlockId,
+    /// EIP-1898 block identifier.
+    BlockIdVariant,
+    Filter,
+    /// Param that is not validated (e.g., a call request), leaving it to `serde`.
+    Any,
+}
+
+impl ParamKind {
+    fn validate(self, value: &Value, strict: bool) -> Result<(), ParamError> {
+        match self {
+            Self::Address => parse_address(expect_str(value, "common.Address")?),
+            Self::Hash => parse_hash(expect_str(value, "common.Hash")?),
+            Self::Bytes => parse_bytes(expect_str(value, "hexutil.Bytes")?),
+            Self::Bool => {
+                if value.is_boolean() {
+                    Ok(())
+                } else {
+                    Err(ParamError::UnexpectedType {
+                        found: json_type(value),
+                        type_name: "bool",
+                    })
+                }
+            }
+            Self::Quantity64 => parse_quantity(expect_str(value, "hexutil.Uint64")?, 64, strict),
+            Self::Quantity256 => parse_quantity(expect_str(value, "hexutil.Big")?, 256, strict),
+            Self::StorageSlot => parse_storage_slot(expect_str(value, "string")?),
+            Self::BlockNumber => parse_block_number(value, strict),
+            Self::BlockId => parse_block_id(value, strict),
+            Self::BlockIdVariant => parse_block_id_variant(value, strict),
+            Self::Filter => parse_filter(value, strict),
+            Self::Any => Ok(()),
+        }
+    }
+}
+
+/// Error validating method params.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+enum ParamsError {
+    #[error("too many arguments, want at most {0}")]
+    TooManyArguments(usize),
+    #[error("missing value for required argument {0}")]
+    MissingArgument(usize),
+    #[error("invalid argument {0}: {1}")]
+    InvalidArgument(usize, ParamError),
+}
+
+/// Param kinds for a method, the first `required` of which are mandatory.
+#[derive(Debug, Clone, Copy)]
+struct MethodParams {
+    kinds: &'static [ParamKind],
+    required: usize,
+}
+
+impl MethodParams {
+    const fn new(kinds: &'static [ParamKind], required: usize) -> Self {
+        Self { kinds, required }
+    }
+
+    fn for_method(method: &str) -> Option<(&'static str, Self)> {
+        use self::ParamKind::*;
+
+        Some(match method {
+            "eth_getBalance" => ("eth_getBalance", Self::new(&[Address, BlockIdVariant], 1)),
+            "eth_getCode" => ("eth_getCode", Self::new(&[Address, BlockIdVariant], 1)),
+            "eth_getTransactionCount" => (
+                "eth_getTransactionCount",
+                Self::new(&[Address, BlockIdVariant], 1),
+            ),
+            "eth_getStorageAt" => (
+                "eth_getStorageAt",
+                Self::new(&[Address, StorageSlot, BlockIdVariant], 2),
+            ),
+            "eth_call" => ("eth_call", Self::new(&[Any, BlockIdVariant, Any], 1)),
+            "eth_estimateGas" => ("eth_estimateGas", Self::new(&[Any, BlockNumber, Any], 1)),
+            "eth_getBlockByNumber" => ("eth_getBlockByNumber", Self::new(&[BlockNumber, Bool], 2)),
+            "eth_getBlockByHash" => ("eth_getBlockByHash", Self::new(&[Hash, Bool], 2)),
+            "eth_getBlockTransactionCountByNumber" => (
+                "eth_getBlockTransactionCountByNumber",
+                Self::new(&[BlockNumber], 1),
+            ),
+            "eth_getBlockTransactionCountByHash" => {
+                ("eth_getBlockTransactionCountByHash", Self::new(&[Hash], 1))
+            }
+            "eth_getBlockReceipts" => ("eth_getBlockReceipts", Self::new(&[BlockId], 1)),
+            "eth_getUncleCountByBlockNumber" => (
+                "eth_getUncleCountByBlockNumber",
+                Self::new(&[BlockNumber], 1),
+            ),
+            "eth_getUncleCountByBlockHash" => {
+                ("eth_getUncleCountByBlockHash", Self::new(&[Hash], 1))
+            }
+            "eth_getTransactionByHash" => ("eth_getTransactionByHash", Self::new(&[Hash], 1)),
+            "eth_getTransactionReceipt" => ("eth_getTransactionReceipt", Self::new(&[Hash], 1)),
+            "eth_getTransactionByBlockHashAndIndex" => (
+                "eth_getTransactionByBlockHashAndIndex",
+                Self::new(&[Hash, Quantity64], 2),
+            ),
+            "eth_getTransactionByBlockNumberAndIndex" => (
+                "eth_getTransactionByBlockNumberAndIndex",
+                Self::new(&[BlockNumber, Quantity64], 2),
+            ),
+            "eth_sendRawTransaction" => ("eth_sendRawTransaction", Self::new(&[Bytes], 1)),
+            "eth_getLogs" => ("eth_getLogs", Self::new(&[Filter], 1)),
+            "eth_newFilter" => ("eth_newFilter", Self::new(&[Filter], 1)),
+            "eth_getFilterLogs" => ("eth_getFilterLogs", Self::new(&[Quantity256], 1)),
+            "eth_getFilterChanges" => ("eth_getFilterChanges", Self::new(&[Quantity256], 1)),
+            "eth_uninstallFilter" => ("eth_uninstallFilter", Self::new(&[Quantity256], 1)),
+            _ => return None,
+        })
+    }
+
+    fn validate(&self, params: &[Value], strict: bool) -> Result<(), ParamsError> {
+        if params.len() > self.kinds.len() {
+            return Err(ParamsError::TooManyArguments(self.kinds.len()));
+        }
+        for (i, &kind) in self.kinds.iter().enumerate() {
+            match params.get(i) {
+                None | Some(Value::Null) if i < self.required => {
+                    return Err(ParamsError::MissingArgument(i));
+                }
+                None | Some(Value::Null) => { /* optional param is not provided */ }
+                Some(value) => kind
+                    .validate(value, strict)
+                    .map_err(|err| ParamsError::InvalidArgument(i, err))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validates params of the specified method. Returns `Ok(())` for methods without validated params
+/// or if params are not provided as an array.
+fn validate_params(
+    method: &str,
+    raw_params: Option<&str>,
+    strict: bool,
+) -> Result<(), (&'static str, ParamsError)> {
+    let Some((method, method_params)) = MethodParams::for_method(method) else {
+        return Ok(());
+    };
+    let params = match raw_params {
+        None => vec![],
+        Some(raw) => match serde_json::from_str::<Value>(raw) {
+            Ok(Value::Array(params)) => params,
+            // Leave named params and malformed JSON to `jsonrpsee`.
+            _ => return Ok(()),
+        },
+    };
+    method_params
+        .validate(&params, strict)
+        .map_err(|err| (method, err))
+}
+
+fn reject_call(id: Id<'_>, method: &'static str, err: ParamsError) -> MethodResponse {
+    METRICS.rejected_calls[&method].inc();
+    let err = ErrorObject::owned(ErrorCode::InvalidParams.code(), err.to_string(), None::<()>);
+    MethodResponse::error(id, err)
+}
+
+/// RPC-level middleware rejecting calls with malformed params; see the [module docs](self) for details.
+#[derive(Debug)]
+pub(crate) struct ValidationMiddleware<S> {
+    inner: S,
+    strict_quantities: bool,
+}
+
+impl<S> ValidationMiddleware<S> {
+    pub fn new(inner: S, strict_quantities: bool) -> Self {
+        Self {
+            inner,
+            strict_quantities,
+        }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for ValidationMiddleware<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = WithValidatedParams<'a, S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        if MethodParams::for_method(request.method_name()).is_none() {
+            return WithValidatedParams {
+                inner: ResponseFuture::future(self.inner.call(request)),
+                deferred: None,
+            };
+        }
+
+        let deferred = if self.strict_quantities {
+            // Leading zeros are accepted by method handlers, so params must be validated before the call.
+            let raw_params = request.params().as_str();
+            if let Err((method, err)) = validate_params(request.method_name(), raw_params, true) {
+                return WithValidatedParams {
+                    inner: ResponseFuture::ready(reject_call(request.id, method, err)),
+                    deferred: None,
+                };
+            }
+            None
+        } else {
+            // Params are borrowed from the request body, so deferring validation doesn't copy them.
+            Some(DeferredValidation {
+                id: request.id.clone(),
+                method: request.method.clone(),
+                params: request.params.clone(),
+            })
+        };
+        WithValidatedParams {
+            inner: ResponseFuture::future(self.inner.call(request)),
+            deferred,
+        }
+    }
+}
+
+/// Call data necessary to validate params after the method handler has rejected them.
+#[derive(Debug)]
+struct DeferredValidation<'a> {
+    id: Id<'a>,
+    method: Cow<'a, str>,
+    params: Option<Cow<'a, RawValue>>,
+}
+
+pin_project! {
+    pub(crate) struct WithValidatedParams<'a, F> {
+        #[pin]
+        inner: ResponseFuture<F>,
+        deferred: Option<DeferredValidation<'a>>,
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for WithValidatedParams<'_, F> {
+    type Output = MethodResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let projection = self.project();
+        let response = futures::ready!(projection.inner.poll(cx));
+        let Some(deferred) = projection.deferred.take() else {
+            return Poll::Ready(response);
+        };
+        if response.as_error_code() != Some(ErrorCode::InvalidParams.code()) {
+            return Poll::Ready(response);
+        }
+
+        // Replace the `serde` error with a geth-compatible one. If the validator accepts params,
+        // the original error is more informative.
+        let raw_params = deferred.params.as_deref().map(RawValue::get);
+        match validate_params(&deferred.method, raw_params, false) {
+            Ok(()) => Poll::Ready(response),
+            Err((method, err)) => Poll::Ready(reject_call(deferred.id, method, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+    use zksync_types::{
+        api::{BlockId, BlockIdVariant, BlockNumber},
+        Address, H256, U256, U64,
+    };
+    use zksync_web3_decl::types::{Bytes, Filter};
+
+    use super::*;
+
+    #[test]
+    fn validating_hex_params() {
+        assert_eq!(
+            parse_address("0x0000000000000000000000000000000000000001"),
+            Ok(())
+        );
+        assert_eq!(
+            parse_address("0000000000000000000000000000000000000001"),
+            Err(ParamError::MissingPrefix)
+        );
+        let err = parse_address("0x01").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "hex string has length 2, want 40 for common.Address"
+        );
+        assert_eq!(
+            parse_hash(&format!("0x{}", "g".repeat(64))),
+            Err(ParamError::Syntax)
+        );
+
+        assert_eq!(parse_bytes("0x"), Ok(()));
+        assert_eq!(parse_bytes("0x123"), Err(ParamError::OddLength));
+
+        assert_eq!(parse_quantity("0x0", 64, true), Ok(()));
+        assert_eq!(parse_quantity("0x", 64, true), Err(ParamError::EmptyNumber));
+        assert_eq!(
+            parse_quantity("0x01", 64, true),
+            Err(ParamError::LeadingZero)
+        );
+        assert_eq!(
+            parse_quantity("123", 64, true),
+            Err(ParamError::MissingPrefix)
+        );
+        let err = parse_quantity(&format!("0x1{}", "0".repeat(16)), 64, true).unwrap_err();
+        assert_eq!(err.to_string(), "hex number > 64 bits");
+        // Leading zeros are only rejected by strict validation.
+        assert_eq!(parse_quantity("0x01", 64, false), Ok(()));
+        assert_eq!(parse_quantity("0x00", 64, false), Ok(()));
+        assert_eq!(
+            parse_quantity(&format!("0x0{}", "f".repeat(16)), 64, false),
+            Err(ParamError::NumberOutOfRange { bits: 64 })
+        );
+
+        assert_eq!(parse_storage_slot("0x0001"), Ok(()));
+        assert_eq!(
+            parse_storage_slot(&format!("0x{}", "0".repeat(65))),
+            Err(ParamError::SlotTooLong)
+        );
+    }
+
+    #[test]
+    fn validating_block_ids() {
+        for tag in BLOCK_TAGS {
+            parse_block_number(&json!(tag), true).unwrap();
+        }
+        parse_block_number(&json!("0x7fffffffffffffff"), true).unwrap();
+        assert_eq!(
+            parse_block_number(&json!("0x8000000000000000"), true),
+            Err(ParamError::BlockNumberOutOfRange)
+        );
+        assert_eq!(
+            parse_block_number(&json!(1), true),
+            Err(ParamError::MissingPrefix)
+        );
+        assert_eq!(
+            parse_block_number(&json!("safe"), true),
+            Err(ParamError::MissingPrefix)
+        );
+
+        parse_block_id(&json!(H256::repeat_byte(1)), true).unwrap();
+        parse_block_id(&json!("latest"), true).unwrap();
+
+        parse_block_id_variant(&json!({ "blockNumber": "0x1" }), true).unwrap();
+        parse_block_id_variant(&json!({ "blockHash": H256::zero() }), true).unwrap();
+        assert_eq!(
+            parse_block_id_variant(
+                &json!({ "blockNumber": "0x1", "blockHash": H256::zero() }),
+                true
+            ),
+            Err(ParamError::BothBlockHashAndNumber)
+        );
+        assert_eq!(
+            parse_block_id_variant(&json!({}), true),
+            Err(ParamError::NoBlockHashOrNumber)
+        );
+    }
+
+    #[test]
+    fn validating_filters() {
+        let address = Address::repeat_byte(1);
+        let topic = H256::repeat_byte(2);
+        parse_filter(
+            &json!({
+                "fromBlock": "0x1",
+                "toBlock": "latest",
+                "address": [address],
+                "topics": [topic, null, [topic]],
+            }),
+            true,
+        )
+        .unwrap();
+
+        let err =
+            parse_filter(&json!({ "blockHash": topic, "fromBlock": "0x1" }), true).unwrap_err();
+        assert_eq!(err, ParamError::BothBlockHashAndRange);
+        let err = parse_filter(&json!({ "address": [address, "0x01"] }), true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid address at index 1: hex has invalid length 1 after decoding; expected 20 for address"
+        );
+        let err = parse_filter(&json!({ "address": 1 }), true).unwrap_err();
+        assert_eq!(err, ParamError::InvalidAddresses);
+        let err = parse_filter(&json!({ "topics": [1] }), true).unwrap_err();
+        assert_eq!(err, ParamError::InvalidTopics);
+        let err = parse_filter(&json!({ "topics": [[topic, null]] }), true).unwrap_err();
+        assert_eq!(err, ParamError::InvalidTopics);
+        let err = parse_filter(&json!("latest"), true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "json: cannot unmarshal string into Go value of type filters.FilterCriteria"
+        );
+    }
+
+    #[test]
+    fn validating_method_params() {
+        let address = Address::repeat_byte(1);
+        validate_params("eth_getBalance", Some(&json!([address]).to_string()), false).unwrap();
+        validate_params(
+            "eth_getBalance",
+            Some(&json!([address, "latest"]).to_string()),
+            false,
+        )
+        .unwrap();
+        validate_params(
+            "eth_getBalance",
+            Some(&json!([address, null]).to_string()),
+            false,
+        )
+        .unwrap();
+        // Methods without validated params and named params are skipped.
+        validate_params("eth_unknown", Some("[1, 2, 3]"), false).unwrap();
+        validate_params("eth_getBalance", Some(r#"{"address": "0x"}"#), false).unwrap();
+
+        let (method, err) = validate_params("eth_getBalance", None, false).unwrap_err();
+        assert_eq!(method, "eth_getBalance");
+        assert_eq!(err.to_string(), "missing value for required argument 0");
+        let (_, err) = validate_params(
+            "eth_getBalance",
+            Some(&json!([address, "latest", 1]).to_string()),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "too many arguments, want at most 2");
+        let (_, err) = validate_params("eth_getBalance", Some(r#"["0x01"]"#), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid argument 0: hex string has length 2, want 40 for common.Address"
+        );
+        let (_, err) =
+            validate_params("eth_getBlockByNumber", Some(r#"["latest", 1]"#), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid argument 1: json: cannot unmarshal number into Go value of type bool"
+        );
+
+        validate_params("eth_getBlockByNumber", Some(r#"["0x01", false]"#), false).unwrap();
+        let (_, err) =
+            validate_params("eth_getBlockByNumber", Some(r#"["0x01", false]"#), true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid argument 0: hex number with leading zero digits"
+        );
+    }
+
+    const FUZZ_ITERATIONS: usize = 10_000;
+
+    /// Generates a random string biased towards (almost) valid hex values.
+    fn gen_hex_like_string(rng: &mut impl Rng) -> String {
+        const CHARS: &[u8] = b"0123456789abcdefABCDEFxXg -";
+        let mut s = match rng.gen_range(0..4) {
+            0 => String::new(),
+            1 => "0X".to_owned(),
+            _ => "0x".to_owned(),
+        };
+        let len = match rng.gen_range(0..4) {
+            0 => rng.gen_range(0..8),
+            1 => 40,
+            2 => 64,
+            _ => rng.gen_range(0..80),
+        };
+        for _ in 0..len {
+            let ch = if rng.gen_bool(0.95) {
+                CHARS[rng.gen_range(0..16)]
+            } else {
+                CHARS[rng.gen_range(0..CHARS.len())]
+            };
+            s.push(char::from(ch));
+        }
+        s
+    }
+
+    fn gen_value(rng: &mut impl Rng) -> Value {
+        match rng.gen_range(0..10) {
+            0 => Value::Null,
+            1 => json!(rng.gen::<u32>()),
+            2 => json!(BLOCK_TAGS[rng.gen_range(0..BLOCK_TAGS.len())]),
+            3 => json!({ "blockNumber": gen_hex_like_string(rng) }),
+            4 => json!({ "blockHash": gen_hex_like_string(rng) }),
+            _ => json!(gen_hex_like_string(rng)),
+        }
+    }
+
+    /// Checks that the validator never panics, and that all values it accepts can be deserialized.
+    fn fuzz_parser<T: DeserializeOwned>(parse: impl Fn(&Value, bool) -> Result<(), ParamError>) {
+        let mut rng = StdRng::seed_from_u64(123);
+        for _ in 0..FUZZ_ITERATIONS {
+            let value = gen_value(&mut rng);
+            if parse(&value, rng.gen()).is_ok() {
+                if let Err(err) = serde_json::from_value::<T>(value.clone()) {
+                    panic!("Accepted value {value} cannot be deserialized: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fuzzing_parsers() {
+        fuzz_parser::<Address>(|value, strict| ParamKind::Address.validate(value, strict));
+        fuzz_parser::<H256>(|value, strict| ParamKind::Hash.validate(value, strict));
+        fuzz_parser::<Bytes>(|value, strict| ParamKind::Bytes.validate(value, strict));
+        fuzz_parser::<U64>(|value, strict| ParamKind::Quantity64.validate(value, strict));
+        fuzz_parser::<U256>(|value, strict| ParamKind::Quantity256.validate(value, strict));
+        fuzz_parser::<U256>(|value, strict| ParamKind::StorageSlot.validate(value, strict));
+        fuzz_parser::<BlockNumber>(parse_block_number);
+        fuzz_parser::<BlockId>(parse_block_id);
+        fuzz_parser::<BlockIdVariant>(parse_block_id_variant);
+    }
+
+    #[test]
+    fn fuzzing_filter_parser() {
+        let mut rng = StdRng::seed_from_u64(321);
+        for _ in 0..FUZZ_ITERATIONS {
+            let mut filter = Map::new();
+            for name in ["fromBlock", "toBlock", "blockHash", "address"] {
+                if rng.gen_bool(0.3) {
+                    filter.insert(name.to_owned(), gen_value(&mut rng));
+                }
+            }
+            if rng.gen_bool(0.5) {
+                let topics: Vec<_> = (0..rng.gen_range(0..4))
+                    .map(|_| match rng.gen_range(0..3) {
+                        0 => Value::Array(vec![gen_value(&mut rng), gen_value(&mut rng)]),
+                        _ => gen_value(&mut rng),
+                    })
+                    .collect();
+                filter.insert("topics".to_owned(), topics.into());
+            }
+
+            let filter = Value::Object(filter);
+            if parse_filter(&filter, rng.gen()).is_ok() {
+                if let Err(err) = serde_json::from_value::<Filter>(filter.clone()) {
+                    panic!("Accepted filter {filter} cannot be deserialized: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_values_are_accepted() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..FUZZ_ITERATIONS {
+            let address = json!(Address::from(rng.gen::<[u8; 20]>()));
+            ParamKind::Address.validate(&address, true).unwrap();
+            let hash = json!(H256::from(rng.gen::<[u8; 32]>()));
+            ParamKind::Hash.validate(&hash, true).unwrap();
+            ParamKind::BlockId.validate(&hash, true).unwrap();
+            let number = json!(U64::from(rng.gen::<u64>() >> rng.gen_range(1..64)));
+            ParamKind::Quantity64.validate(&number, true).unwrap();
+            ParamKind::BlockNumber.validate(&number, true).unwrap();
+            let number = json!(U256::from_big_endian(&rng.gen::<[u8; 32]>()));
+            ParamKind::Quantity256.validate(&number, true).unwrap();
+            ParamKind::StorageSlot.validate(&number, true).unwrap();
+        }
+    }
+}
@@ -170,6 +170,9 @@ enum Web3ErrorKind {
     FilterNotFound,
     LogsLimitExceeded,
     InvalidFilterBlockHash,
+    TooManyTransactions,
+    IntermediateStateUnavailable,
+    TraceSizeLimitExceeded,
     TreeApiUnavailable,
     Internal,
 }
@@ -186,6 +189,9 @@ impl Web3ErrorKind {
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::TooManyTransactions(_) => Self::TooManyTransactions,
+            Web3Error::IntermediateStateUnavailable(_) => Self::IntermediateStateUnavailable,
+            Web3Error::TraceSizeLimitExceeded(_) => Self::TraceSizeLimitExceeded,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
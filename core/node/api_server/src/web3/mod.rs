@@ -1,4 +1,4 @@
-use std::{collections::HashSet, net::SocketAddr, num::NonZeroU32, sync::Arc, time::Duration};
+use std::{collections::HashSet, fmt, net::SocketAddr, num::NonZeroU32, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use chrono::NaiveDateTime;
@@ -13,8 +13,10 @@ use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
+use zksync_node_fee_model::gas_per_pubdata::GasPerPubdataOracle;
 use zksync_node_sync::SyncState;
-use zksync_types::L2BlockNumber;
+use zksync_object_store::ObjectStore;
+use zksync_types::{settlement::SettlementLayerData, L2BlockNumber};
 use zksync_web3_decl::{
     client::{DynClient, L2},
     jsonrpsee::{
@@ -33,14 +35,15 @@ use zksync_web3_decl::{
 
 use self::{
     backend_jsonrpsee::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
-        TrafficTracker,
+        ConsumerIdLayer, CorrelationMiddleware, DeprecatedMethods, DeprecationHeadersLayer,
+        DeprecationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
+        SnapshotsLimitMiddleware, SnapshotsRateLimiter, TrafficTracker, ValidationMiddleware,
     },
     mempool_cache::MempoolCache,
     metrics::API_METRICS,
     namespaces::{
-        DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotsNamespace,
-        UnstableNamespace, Web3Namespace, ZksNamespace,
+        DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotUrlSigner,
+        SnapshotsNamespace, UnstableNamespace, Web3Namespace, ZksNamespace,
     },
     pubsub::{EthSubscribe, EthSubscriptionIdProvider, PubSubEvent},
     state::{Filters, InternalApiConfig, RpcState, SealedL2BlockNumber},
@@ -115,6 +118,24 @@ impl Namespace {
     ];
 }
 
+/// Data available to [`CustomNamespace`]s when creating their RPC modules.
+#[derive(Debug, Clone)]
+pub struct CustomNamespaceContext {
+    pub pool: ConnectionPool<Core>,
+    pub tx_sender: TxSender,
+    pub api_config: InternalApiConfig,
+}
+
+/// Additional API namespace not known to the server, e.g. one defined by a fork of the node.
+/// Custom namespaces are registered via [`ApiBuilder::with_custom_namespace()`] and are always enabled.
+pub trait CustomNamespace: fmt::Debug + Send + Sync + 'static {
+    /// Name of the namespace used in logs and errors.
+    fn name(&self) -> &'static str;
+
+    /// Creates an RPC module with all namespace methods.
+    fn create_rpc(&self, context: &CustomNamespaceContext) -> anyhow::Result<RpcModule<()>>;
+}
+
 /// Handles to the initialized API server.
 #[derive(Debug)]
 pub struct ApiServerHandles {
@@ -136,9 +157,17 @@ struct OptionalApiParams {
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
+    gas_per_pubdata_oracle: Option<GasPerPubdataOracle>,
     extended_tracing: bool,
+    strict_quantity_validation: bool,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
     l2_l1_log_proof_handler: Option<Box<DynClient<L2>>>,
+    deprecated_methods: DeprecatedMethods,
+    custom_namespaces: Vec<Arc<dyn CustomNamespace>>,
+    snapshot_url_signer: Option<SnapshotUrlSigner>,
+    snapshots_rate_limiter: Option<SnapshotsRateLimiter>,
+    snapshots_rate_limit_proxy_hops: Option<NonZeroU32>,
+    settlement_layer_data: Option<watch::Receiver<Option<SettlementLayerData>>>,
 }
 
 /// Structure capable of spawning a configured Web3 API server along with all the required
@@ -193,7 +222,10 @@ impl ApiBuilder {
             sealed_l2_block_handle: None,
             namespaces: None,
             method_tracer: Arc::new(MethodTracer::default()),
-            optional: OptionalApiParams::default(),
+            optional: OptionalApiParams {
+                deprecated_methods: DeprecatedMethods::current(),
+                ..OptionalApiParams::default()
+            },
         }
     }
 
@@ -246,11 +278,50 @@ impl ApiBuilder {
         self
     }
 
+    /// Enables signing object store URLs of snapshot files returned by the `snapshots` namespace.
+    /// Signed URLs expire after `ttl`.
+    pub fn with_snapshot_download_urls(
+        mut self,
+        blob_store: Arc<dyn ObjectStore>,
+        ttl: Duration,
+    ) -> Self {
+        tracing::info!("Signing snapshot download URLs for {blob_store:?} with TTL {ttl:?}");
+        self.optional.snapshot_url_signer = Some(SnapshotUrlSigner::new(blob_store, ttl));
+        self
+    }
+
+    /// Limits the number of requests to the `snapshots` namespace per consumer.
+    pub fn with_snapshots_requests_per_minute_limit(
+        mut self,
+        snapshots_requests_per_minute_limit: NonZeroU32,
+    ) -> Self {
+        self.optional.snapshots_rate_limiter = Some(SnapshotsRateLimiter::new(
+            snapshots_requests_per_minute_limit,
+        ));
+        self
+    }
+
+    /// Sets the number of trusted reverse proxies in front of the server used to identify consumers
+    /// of the `snapshots` namespace by the `X-Forwarded-For` header.
+    pub fn with_snapshots_rate_limit_proxy_hops(mut self, proxy_hops: NonZeroU32) -> Self {
+        self.optional.snapshots_rate_limit_proxy_hops = Some(proxy_hops);
+        self
+    }
+
     pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
         self.optional.sync_state = Some(sync_state);
         self
     }
 
+    /// Sets the source of settlement layer data reported by `unstable_gatewayMigrationStatus`.
+    pub fn with_settlement_layer_data(
+        mut self,
+        settlement_layer_data: watch::Receiver<Option<SettlementLayerData>>,
+    ) -> Self {
+        self.optional.settlement_layer_data = Some(settlement_layer_data);
+        self
+    }
+
     pub fn with_polling_interval(mut self, polling_interval: Duration) -> Self {
         self.polling_interval = polling_interval;
         self
@@ -277,11 +348,29 @@ impl ApiBuilder {
         self
     }
 
+    pub fn with_gas_per_pubdata_oracle(mut self, oracle: GasPerPubdataOracle) -> Self {
+        self.optional.gas_per_pubdata_oracle = Some(oracle);
+        self
+    }
+
     pub fn with_extended_tracing(mut self, extended_tracing: bool) -> Self {
         self.optional.extended_tracing = extended_tracing;
         self
     }
 
+    /// Rejects quantity params with leading zero digits, like geth does. Off by default since such params
+    /// are accepted by the server otherwise.
+    pub fn with_strict_quantity_validation(mut self, enabled: bool) -> Self {
+        self.optional.strict_quantity_validation = enabled;
+        self
+    }
+
+    /// Overrides deprecated RPC methods, which are [`DeprecatedMethods::current()`] by default.
+    pub fn with_deprecated_methods(mut self, deprecated_methods: DeprecatedMethods) -> Self {
+        self.optional.deprecated_methods = deprecated_methods;
+        self
+    }
+
     pub fn with_sealed_l2_block_handle(
         mut self,
         sealed_l2_block_handle: SealedL2BlockNumber,
@@ -306,6 +395,14 @@ impl ApiBuilder {
         self
     }
 
+    /// Registers an additional API namespace. Method names of the namespace must not clash with methods
+    /// of other enabled namespaces.
+    pub fn with_custom_namespace(mut self, namespace: Arc<dyn CustomNamespace>) -> Self {
+        tracing::info!("Registering custom API namespace `{}`", namespace.name());
+        self.optional.custom_namespaces.push(namespace);
+        self
+    }
+
     // Intended for tests only.
     #[doc(hidden)]
     fn with_pub_sub_events(mut self, sender: mpsc::UnboundedSender<PubSubEvent>) -> Self {
@@ -386,10 +483,13 @@ impl ApiServer {
             api_config: self.config,
             start_info,
             mempool_cache: self.optional.mempool_cache,
+            gas_per_pubdata_oracle: self.optional.gas_per_pubdata_oracle,
             last_sealed_l2_block: self.sealed_l2_block_handle,
             bridge_addresses_handle: self.bridge_addresses_handle,
             tree_api: self.optional.tree_api,
             l2_l1_log_proof_handler: self.optional.l2_l1_log_proof_handler,
+            snapshot_url_signer: self.optional.snapshot_url_signer,
+            settlement_layer_data: self.optional.settlement_layer_data,
         })
     }
 
@@ -398,8 +498,14 @@ impl ApiServer {
         pub_sub: Option<EthSubscribe>,
     ) -> anyhow::Result<RpcModule<()>> {
         let namespaces = self.namespaces.clone();
+        let custom_namespaces = self.optional.custom_namespaces.clone();
         let zksync_network_id = self.config.l2_chain_id;
         let rpc_state = self.build_rpc_state().await?;
+        let custom_namespace_context = CustomNamespaceContext {
+            pool: rpc_state.connection_pool.clone(),
+            tx_sender: rpc_state.tx_sender.clone(),
+            api_config: rpc_state.api_config.clone(),
+        };
 
         // Collect all the methods into a single RPC module.
         let mut rpc = RpcModule::new(());
@@ -440,6 +546,14 @@ impl ApiServer {
             rpc.merge(UnstableNamespace::new(rpc_state).into_rpc())
                 .context("cannot merge unstable namespace")?;
         }
+        for namespace in custom_namespaces {
+            let name = namespace.name();
+            let module = namespace
+                .create_rpc(&custom_namespace_context)
+                .with_context(|| format!("cannot create custom {name} namespace"))?;
+            rpc.merge(module)
+                .with_context(|| format!("cannot merge custom {name} namespace"))?;
+        }
         Ok(rpc)
     }
 
@@ -624,12 +738,12 @@ impl ApiServer {
             return Ok(());
         }
 
-        let batch_request_config = self
-            .optional
-            .batch_request_size_limit
-            .map_or(BatchRequestConfig::Unlimited, |limit| {
-                BatchRequestConfig::Limit(limit as u32)
-            });
+        let batch_request_config = match self.optional.batch_request_size_limit {
+            None => BatchRequestConfig::Unlimited,
+            // A zero limit disables batch requests only for the WS server, so that the HTTP server semantics is unchanged.
+            Some(0) if !is_http => BatchRequestConfig::Disabled,
+            Some(limit) => BatchRequestConfig::Limit(limit as u32),
+        };
         let (response_body_size_limit, max_response_size_overrides) =
             if let Some(limit) = &self.optional.response_body_size_limit {
                 (limit.global as u32, limit.overrides.clone())
@@ -637,10 +751,14 @@ impl ApiServer {
                 (u32::MAX, MaxResponseSizeOverrides::empty())
             };
         let websocket_requests_per_minute_limit = self.optional.websocket_requests_per_minute_limit;
+        let snapshots_rate_limiter = self.optional.snapshots_rate_limiter.clone();
+        let snapshots_rate_limit_proxy_hops = self.optional.snapshots_rate_limit_proxy_hops;
         let subscriptions_limit = self.optional.subscriptions_limit;
         let vm_barrier = self.optional.vm_barrier.clone();
         let health_updater = self.health_updater.clone();
         let method_tracer = self.method_tracer.clone();
+        let deprecated_methods = self.optional.deprecated_methods.clone();
+        let strict_quantity_validation = self.optional.strict_quantity_validation;
 
         let extended_tracing = self.optional.extended_tracing;
         if extended_tracing {
@@ -675,7 +793,15 @@ impl ApiServer {
         // Assemble server middleware.
         let middleware = tower::ServiceBuilder::new()
             .layer(in_flight_requests)
-            .option_layer(cors);
+            .option_layer(cors)
+            .option_layer(
+                (is_http && !deprecated_methods.is_empty()).then(DeprecationHeadersLayer::default),
+            )
+            .option_layer(
+                snapshots_rate_limiter
+                    .is_some()
+                    .then(|| ConsumerIdLayer::new(snapshots_rate_limit_proxy_hops)),
+            );
 
         // Settings shared by HTTP and WS servers.
         let max_connections = !is_http
@@ -703,12 +829,25 @@ impl ApiServer {
                 extended_tracing.then(|| tower::layer::layer_fn(CorrelationMiddleware::new)),
             )
             .layer(metadata_layer)
+            .option_layer((!deprecated_methods.is_empty()).then(|| {
+                tower::layer::layer_fn(move |svc| {
+                    DeprecationMiddleware::new(svc, deprecated_methods.clone())
+                })
+            }))
             // We want to capture limit middleware errors with `metadata_layer`; hence, `LimitMiddleware` is placed after it.
             .option_layer((!is_http).then(|| {
                 tower::layer::layer_fn(move |svc| {
                     LimitMiddleware::new(svc, websocket_requests_per_minute_limit)
                 })
-            }));
+            }))
+            // Similarly, per-consumer limits for the `snapshots` namespace are captured by `metadata_layer`.
+            .option_layer(snapshots_rate_limiter.map(|rate_limiter| {
+                tower::layer::layer_fn(move |svc| {
+                    SnapshotsLimitMiddleware::new(svc, rate_limiter.clone())
+                })
+            }))
+            // Params are validated after rate limiting; validation errors are captured by `metadata_layer` as well.
+            .layer_fn(move |svc| ValidationMiddleware::new(svc, strict_quantity_validation));
 
         let server_builder = ServerBuilder::default()
             .max_connections(max_connections as u32)
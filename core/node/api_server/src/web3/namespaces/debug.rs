@@ -1,18 +1,19 @@
 use anyhow::Context as _;
-use zksync_dal::{CoreDal, DalError};
+use zksync_dal::{system_dal::STORAGE_RANGE_INDEX, CoreDal, DalError};
 use zksync_multivm::interface::{Call, CallType, ExecutionResult, OneshotTracingParams};
 use zksync_system_constants::MAX_ENCODED_TX_SIZE;
 use zksync_types::{
     api::{
         BlockId, BlockNumber, CallTracerBlockResult, CallTracerResult, DebugCall, DebugCallType,
-        ResultDebugCall, SupportedTracers, TracerConfig,
+        ResultDebugCall, StorageAccessSet, StorageRangeEntry, StorageRangeResult, SupportedTracers,
+        TracerConfig,
     },
     debug_flat_call::{Action, CallResult, CallTraceMeta, DebugCallFlat, ResultDebugCallFlat},
     l2::L2Tx,
     transaction_request::CallRequest,
     web3,
     zk_evm_types::FarCallOpcode,
-    H256, U256,
+    Address, L2BlockNumber, H256, U256,
 };
 use zksync_web3_decl::error::Web3Error;
 
@@ -21,6 +22,70 @@ use crate::{
     web3::{backend_jsonrpsee::MethodTracer, state::RpcState},
 };
 
+/// Limits the size of call traces processed in a single tracing request.
+///
+/// The limit is checked against the estimated response size of traces before they are loaded (based on their stored
+/// uncompressed size), and against the estimated size of nested calls in the response while traces are mapped; nested calls not fitting into the budget are omitted.
+/// Top-level calls (i.e., calls corresponding to transactions) are always returned and do not count towards the limit.
+#[derive(Debug)]
+pub(crate) struct TraceBudget {
+    max_bytes: Option<usize>,
+    remaining_bytes: Option<usize>,
+}
+
+impl TraceBudget {
+    /// Estimated size of a serialized call excluding its input and output, in bytes.
+    const CALL_SIZE_OVERHEAD: usize = 256;
+    /// Ratio between the JSON size of a call and its stored (`bincode`) size. Byte fields (addresses, input, output)
+    /// are hex-encoded in the response, so they take twice as much space as in the stored trace.
+    const STORED_SIZE_MULTIPLIER: u64 = 2;
+
+    pub(crate) fn new(max_bytes: Option<usize>) -> Self {
+        Self {
+            max_bytes,
+            remaining_bytes: max_bytes,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn unlimited() -> Self {
+        Self::new(None)
+    }
+
+    /// Estimates the serialized size of a call excluding its nested calls. Input and output are hex-encoded,
+    /// so they take twice as much space as their byte length.
+    pub(crate) fn estimate_call_size(call: &Call) -> usize {
+        Self::CALL_SIZE_OVERHEAD + 2 * (call.input.len() + call.output.len())
+    }
+
+    /// Checks that traces with the specified stored uncompressed size can be loaded.
+    pub(crate) fn check_stored_size(&self, stored_size: u64) -> Result<(), Web3Error> {
+        let estimated_size = stored_size.saturating_mul(Self::STORED_SIZE_MULTIPLIER);
+        match self.max_bytes {
+            Some(max_bytes) if estimated_size > max_bytes as u64 => {
+                Err(Web3Error::TraceSizeLimitExceeded(max_bytes))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Tries to consume the budget for a single nested call. Returns `false` if the call doesn't fit into the budget.
+    fn try_consume(&mut self, call: &Call) -> bool {
+        let Some(remaining) = &mut self.remaining_bytes else {
+            return true;
+        };
+        let call_size = Self::estimate_call_size(call);
+        if call_size > *remaining {
+            // Do not return further calls even if they are smaller, so that the truncated trace is a prefix
+            // of the full one.
+            *remaining = 0;
+            return false;
+        }
+        *remaining -= call_size;
+        true
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct DebugNamespace {
     state: RpcState,
@@ -31,16 +96,22 @@ impl DebugNamespace {
         Ok(Self { state })
     }
 
+    fn trace_budget(&self) -> TraceBudget {
+        TraceBudget::new(self.state.api_config.max_trace_size_bytes)
+    }
+
     pub(crate) fn map_call(
         call: Call,
         mut meta: CallTraceMeta,
         tracer_option: TracerConfig,
+        budget: &mut TraceBudget,
     ) -> CallTracerResult {
         match tracer_option.tracer {
             SupportedTracers::CallTracer => CallTracerResult::CallTrace(Self::map_default_call(
                 call,
                 tracer_option.tracer_config.only_top_call,
                 meta.internal_error,
+                budget,
             )),
             SupportedTracers::FlatCallTracer => {
                 let mut calls = vec![];
@@ -51,6 +122,7 @@ impl DebugNamespace {
                     &mut traces,
                     tracer_option.tracer_config.only_top_call,
                     &mut meta,
+                    budget,
                 );
                 CallTracerResult::FlatCallTrace(calls)
             }
@@ -61,16 +133,20 @@ impl DebugNamespace {
         call: Call,
         only_top_call: bool,
         internal_error: Option<String>,
+        budget: &mut TraceBudget,
     ) -> DebugCall {
-        let calls = if only_top_call {
-            vec![]
-        } else {
-            // We don't need to propagate the internal error to the nested calls.
-            call.calls
-                .into_iter()
-                .map(|call| Self::map_default_call(call, false, None))
-                .collect()
-        };
+        let mut calls = vec![];
+        let mut truncated = false;
+        if !only_top_call {
+            for call in call.calls {
+                if !budget.try_consume(&call) {
+                    truncated = true;
+                    break;
+                }
+                // We don't need to propagate the internal error to the nested calls.
+                calls.push(Self::map_default_call(call, false, None, budget));
+            }
+        }
         let debug_type = match call.r#type {
             CallType::Call(FarCallOpcode::Normal) => DebugCallType::Call,
             CallType::Call(FarCallOpcode::Mimic) => DebugCallType::Call,
@@ -90,6 +166,7 @@ impl DebugNamespace {
             error: call.error.or(internal_error),
             revert_reason: call.revert_reason,
             calls,
+            truncated,
         }
     }
 
@@ -99,6 +176,7 @@ impl DebugNamespace {
         trace_address: &mut Vec<usize>,
         only_top_call: bool,
         meta: &mut CallTraceMeta,
+        budget: &mut TraceBudget,
     ) {
         let subtraces = call.calls.len();
         let debug_type = match call.r#type {
@@ -135,6 +213,7 @@ impl DebugNamespace {
             ),
         };
 
+        let call_index = calls.len();
         calls.push(DebugCallFlat {
             action: Action {
                 call_type: debug_type,
@@ -153,12 +232,19 @@ impl DebugNamespace {
             block_number: meta.block_number,
             block_hash: meta.block_hash,
             r#type: DebugCallType::Call,
+            truncated: false,
         });
 
         if !only_top_call {
             for (number, call) in call.calls.into_iter().enumerate() {
+                if !budget.try_consume(&call) {
+                    // Keep `subtraces` consistent with the nested calls actually returned.
+                    calls[call_index].subtraces = number;
+                    calls[call_index].truncated = true;
+                    break;
+                }
                 trace_address.push(number);
-                Self::flatten_call(call, calls, trace_address, false, meta);
+                Self::flatten_call(call, calls, trace_address, false, meta, budget);
                 trace_address.pop();
             }
         }
@@ -190,6 +276,14 @@ impl DebugNamespace {
         self.current_method()
             .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
 
+        let mut budget = self.trace_budget();
+        let traces_size = connection
+            .blocks_web3_dal()
+            .get_traces_size_for_l2_block(block_number)
+            .await
+            .map_err(DalError::generalize)?;
+        budget.check_stored_size(traces_size)?;
+
         let call_traces = connection
             .blocks_web3_dal()
             .get_traces_for_l2_block(block_number)
@@ -206,6 +300,7 @@ impl DebugNamespace {
                             call,
                             options.tracer_config.only_top_call,
                             meta.internal_error,
+                            &mut budget,
                         ),
                     })
                     .collect(),
@@ -222,6 +317,7 @@ impl DebugNamespace {
                             &mut traces,
                             options.tracer_config.only_top_call,
                             &mut meta,
+                            &mut budget,
                         );
                         ResultDebugCallFlat {
                             tx_hash: meta.tx_hash,
@@ -241,16 +337,111 @@ impl DebugNamespace {
         options: Option<TracerConfig>,
     ) -> Result<Option<CallTracerResult>, Web3Error> {
         let mut connection = self.state.acquire_connection().await?;
+        let mut budget = self.trace_budget();
+        let trace_size = connection
+            .transactions_dal()
+            .get_call_trace_size(tx_hash)
+            .await
+            .map_err(DalError::generalize)?;
+        let Some(trace_size) = trace_size else {
+            return Ok(None);
+        };
+        budget.check_stored_size(trace_size)?;
+
         let call_trace = connection
             .transactions_dal()
             .get_call_trace(tx_hash)
             .await
             .map_err(DalError::generalize)?;
         Ok(call_trace.map(|(call_trace, meta)| {
-            Self::map_call(call_trace, meta, options.unwrap_or_default())
+            Self::map_call(call_trace, meta, options.unwrap_or_default(), &mut budget)
         }))
     }
 
+    pub async fn get_transaction_storage_accesses_impl(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<StorageAccessSet>, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let storage_accesses = connection
+            .transactions_dal()
+            .get_storage_accesses(tx_hash)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(storage_accesses.map(StorageAccessSet::from))
+    }
+
+    pub async fn storage_range_at_impl(
+        &self,
+        block_id: BlockId,
+        tx_index: usize,
+        address: Address,
+        start_key: H256,
+        max_result: usize,
+    ) -> Result<StorageRangeResult, Web3Error> {
+        self.current_method().set_block_id(block_id);
+        let mut connection = self.state.acquire_connection().await?;
+        // Without the index, the storage range query would scan the entire `storage_logs` table.
+        let is_index_ready = connection
+            .system_dal()
+            .is_index_ready(STORAGE_RANGE_INDEX)
+            .await
+            .map_err(DalError::generalize)?;
+        if !is_index_ready {
+            return Err(Web3Error::MethodNotImplemented);
+        }
+
+        let block_number = self.state.resolve_block(&mut connection, block_id).await?;
+        self.current_method()
+            .set_block_diff(self.state.last_sealed_l2_block.diff(block_number));
+
+        // Storage logs are not linked to transactions, so the only states we can provide are the ones
+        // at the start and at the end of the block.
+        let state_block_number = if tx_index == 0 {
+            match block_number.0.checked_sub(1) {
+                Some(number) => L2BlockNumber(number),
+                None => return Ok(StorageRangeResult::default()), // State before the genesis block is empty
+            }
+        } else {
+            let header = connection
+                .blocks_dal()
+                .get_l2_block_header(block_number)
+                .await
+                .map_err(DalError::generalize)?
+                .ok_or(Web3Error::NoBlock)?;
+            let tx_count = usize::from(header.l1_tx_count) + usize::from(header.l2_tx_count);
+            if tx_index < tx_count {
+                return Err(Web3Error::IntermediateStateUnavailable(tx_index));
+            }
+            block_number
+        };
+
+        let limit = max_result.min(self.state.api_config.req_entities_limit);
+        // Request an extra entry to determine the next key.
+        let mut entries = connection
+            .storage_logs_dal()
+            .get_storage_range(address, state_block_number, start_key, limit + 1)
+            .await
+            .map_err(DalError::generalize)?;
+        let next_key = if entries.len() > limit {
+            entries.pop().map(|entry| entry.hashed_key)
+        } else {
+            None
+        };
+
+        let storage = entries
+            .into_iter()
+            .map(|entry| {
+                let entry_value = StorageRangeEntry {
+                    key: entry.key,
+                    value: entry.value,
+                };
+                (entry.hashed_key, entry_value)
+            })
+            .collect();
+        Ok(StorageRangeResult { storage, next_key })
+    }
+
     pub async fn debug_trace_call_impl(
         &self,
         mut request: CallRequest,
@@ -354,6 +545,11 @@ impl DebugNamespace {
             // It's a call request, it's safe to everything as default
             ..Default::default()
         };
-        Ok(Self::map_call(call, meta, options))
+        Ok(Self::map_call(
+            call,
+            meta,
+            options,
+            &mut self.trace_budget(),
+        ))
     }
 }
@@ -66,7 +66,7 @@ impl EthNamespace {
         let mut connection = self.state.acquire_connection().await?;
         let block_args = self
             .state
-            .resolve_block_args(&mut connection, block_id)
+            .resolve_call_block_args(&mut connection, block_id)
             .await?;
         self.current_method().set_block_diff(
             self.state
@@ -153,7 +153,7 @@ impl EthNamespace {
                 search_kind,
             )
             .await?;
-        Ok(fee.gas_limit)
+        Ok(fee.fee.gas_limit)
     }
 
     pub async fn gas_price_impl(&self) -> Result<U256, Web3Error> {
@@ -362,21 +362,23 @@ impl EthNamespace {
         else {
             return Ok(None);
         };
-        let Some(block) = storage
+        let block_hash = storage
             .blocks_web3_dal()
-            .get_api_block(block_number)
+            .get_l2_block_hash(block_number)
             .await
-            .map_err(DalError::generalize)?
-        else {
+            .map_err(DalError::generalize)?;
+        if block_hash.is_none() {
             return Ok(None);
-        };
+        }
         self.set_block_diff(block_number); // only report block diff for existing L2 blocks
 
+        // Receipts for the entire block are loaded with a single query, which is important since this method
+        // is heavily used by indexers instead of requesting receipts for each transaction separately.
         let receipts = storage
             .transactions_web3_dal()
-            .get_transaction_receipts(&block.transactions)
+            .get_l2_block_transaction_receipts(block_number)
             .await
-            .with_context(|| format!("get_transaction_receipts({block_number})"))?;
+            .with_context(|| format!("get_l2_block_transaction_receipts({block_number})"))?;
         let receipts = fill_transaction_receipts(&mut storage, receipts).await?;
         Ok(Some(receipts))
     }
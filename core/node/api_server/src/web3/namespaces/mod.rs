@@ -10,8 +10,15 @@ mod unstable;
 mod web3;
 mod zks;
 
+#[cfg(test)]
+pub(super) use self::debug::TraceBudget;
 pub(super) use self::{
-    debug::DebugNamespace, en::EnNamespace, eth::EthNamespace, net::NetNamespace,
-    snapshots::SnapshotsNamespace, unstable::UnstableNamespace, web3::Web3Namespace,
+    debug::DebugNamespace,
+    en::EnNamespace,
+    eth::EthNamespace,
+    net::NetNamespace,
+    snapshots::{SnapshotUrlSigner, SnapshotsNamespace},
+    unstable::UnstableNamespace,
+    web3::Web3Namespace,
     zks::ZksNamespace,
 };
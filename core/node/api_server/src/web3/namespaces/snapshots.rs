@@ -1,13 +1,63 @@
+use std::{sync::Arc, time::Duration};
+
 use anyhow::Context as _;
+use futures::future;
 use zksync_dal::{CoreDal, DalError};
+use zksync_object_store::ObjectStore;
 use zksync_types::{
-    snapshots::{AllSnapshots, SnapshotHeader, SnapshotStorageLogsChunkMetadata},
+    snapshots::{
+        AllSnapshots, SnapshotFactoryDependencies, SnapshotHeader, SnapshotStorageLogsChunk,
+        SnapshotStorageLogsChunkMetadata, SnapshotStorageLogsStorageKey,
+    },
     L1BatchNumber,
 };
 use zksync_web3_decl::error::Web3Error;
 
 use crate::web3::{backend_jsonrpsee::MethodTracer, state::RpcState};
 
+/// Signs object store URLs of snapshot files, so that snapshots can be downloaded without object store credentials.
+#[derive(Debug, Clone)]
+pub(crate) struct SnapshotUrlSigner {
+    blob_store: Arc<dyn ObjectStore>,
+    ttl: Duration,
+}
+
+impl SnapshotUrlSigner {
+    pub fn new(blob_store: Arc<dyn ObjectStore>, ttl: Duration) -> Self {
+        Self { blob_store, ttl }
+    }
+
+    async fn sign_header(&self, header: &mut SnapshotHeader) -> anyhow::Result<()> {
+        let l1_batch_number = header.l1_batch_number;
+        let factory_deps_url = self
+            .blob_store
+            .signed_url::<SnapshotFactoryDependencies>(l1_batch_number, self.ttl)
+            .await
+            .with_context(|| {
+                format!("failed signing URL for factory deps of snapshot for L1 batch #{l1_batch_number}")
+            })?;
+        header.factory_deps_download_url = Some(factory_deps_url);
+
+        let chunk_urls = header.storage_logs_chunks.iter().map(|chunk| {
+            let key = SnapshotStorageLogsStorageKey {
+                l1_batch_number,
+                chunk_id: chunk.chunk_id,
+            };
+            async move {
+                self.blob_store
+                    .signed_url::<SnapshotStorageLogsChunk>(key, self.ttl)
+                    .await
+                    .with_context(|| format!("failed signing URL for snapshot chunk {key:?}"))
+            }
+        });
+        let chunk_urls = future::try_join_all(chunk_urls).await?;
+        for (chunk, url) in header.storage_logs_chunks.iter_mut().zip(chunk_urls) {
+            chunk.download_url = Some(url);
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct SnapshotsNamespace {
     state: RpcState,
@@ -60,6 +110,7 @@ impl SnapshotsNamespace {
                 Some(SnapshotStorageLogsChunkMetadata {
                     chunk_id: chunk_id as u64,
                     filepath: filepath?,
+                    download_url: None,
                 })
             })
             .collect();
@@ -70,12 +121,19 @@ impl SnapshotsNamespace {
             .map_err(DalError::generalize)?
             .with_context(|| format!("missing L2 blocks for L1 batch #{l1_batch_number}"))?;
 
-        Ok(Some(SnapshotHeader {
+        drop(storage_processor);
+
+        let mut header = SnapshotHeader {
             version: snapshot_metadata.version.into(),
             l1_batch_number: snapshot_metadata.l1_batch_number,
             l2_block_number,
             storage_logs_chunks: chunks,
             factory_deps_filepath: snapshot_metadata.factory_deps_filepath,
-        }))
+            factory_deps_download_url: None,
+        };
+        if let Some(signer) = &self.state.snapshot_url_signer {
+            signer.sign_header(&mut header).await?;
+        }
+        Ok(Some(header))
     }
 }
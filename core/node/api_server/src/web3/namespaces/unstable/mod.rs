@@ -8,7 +8,8 @@ use zksync_dal::{CoreDal, DalError};
 use zksync_mini_merkle_tree::MiniMerkleTree;
 use zksync_types::{
     api::{
-        ChainAggProof, DataAvailabilityDetails, L1ToL2TxsStatus, TeeProof, TransactionExecutionInfo,
+        ChainAggProof, DataAvailabilityDetails, GatewayMigrationStatus, L1ToL2TxsStatus, TeeProof,
+        TransactionExecutionInfo,
     },
     tee_types::TeeType,
     L1BatchNumber, L2ChainId,
@@ -194,4 +195,30 @@ impl UnstableNamespace {
             l1_to_l2_txs_in_mempool,
         })
     }
+
+    pub async fn gateway_migration_status_impl(&self) -> Result<GatewayMigrationStatus, Web3Error> {
+        let settlement_layer_data = self
+            .state
+            .settlement_layer_data
+            .as_ref()
+            .and_then(|data| *data.borrow());
+        let settlement_mode = settlement_layer_data.map(|data| data.settlement_mode);
+        let inflight_txs_count = if let Some(settlement_mode) = settlement_mode {
+            let mut connection = self.state.acquire_connection().await?;
+            connection
+                .eth_sender_dal()
+                .get_unconfirmed_txs_count_for_settlement_mode(settlement_mode)
+                .await
+                .map_err(DalError::generalize)?
+        } else {
+            0
+        };
+
+        Ok(GatewayMigrationStatus {
+            settlement_mode,
+            inflight_txs_count,
+            settlement_layer: settlement_layer_data.map(|data| data.sl_chain_id),
+            migration_pending: settlement_layer_data.map(|data| data.migration_pending),
+        })
+    }
 }
@@ -5,7 +5,8 @@ use zksync_crypto_primitives::hasher::{keccak::KeccakHasher, Hasher};
 use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_metadata_calculator::api_server::TreeApiError;
 use zksync_mini_merkle_tree::MiniMerkleTree;
-use zksync_multivm::interface::VmEvent;
+use zksync_multivm::interface::{ExecutionResult, VmEvent};
+use zksync_shared_metrics::metadata::GIT_METRICS;
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     address_to_h256,
@@ -14,14 +15,16 @@ use zksync_types::{
         L1BatchDetails, L2ToL1LogProof, Proof, ProtocolVersion, StorageProof,
         TransactionDetailedResult, TransactionDetails,
     },
-    fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
     h256_to_u256,
     l1::L1Tx,
     l2::L2Tx,
     l2_to_l1_log::{l2_to_l1_logs_tree_size, L2ToL1Log, LOG_PROOF_SUPPORTED_METADATA_VERSION},
+    protocol_version::ProtocolSemanticVersion,
+    storage::get_code_key,
+    system_contracts::system_contract_addresses,
     tokens::ETHEREUM_ADDRESS,
-    transaction_request::CallRequest,
+    transaction_request::{CallRequest, SerializationTransactionError},
     utils::storage_key_for_standard_token_balance,
     web3,
     web3::Bytes,
@@ -36,11 +39,52 @@ use zksync_web3_decl::{
 
 use crate::{
     execution_sandbox::BlockArgs,
-    tx_sender::BinarySearchKind,
+    tx_sender::{BinarySearchKind, SubmitTxError},
     utils::open_readonly_transaction,
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, RpcState},
 };
 
+/// Maximum number of transactions accepted by `zks_simulateL1ToL2Transactions`. Each transaction without a gas limit
+/// requires a full gas estimation, i.e., dozens of VM runs.
+const MAX_L1_TO_L2_SIMULATIONS: usize = 16;
+
+/// Ethereum hard fork followed by the EVM emulator.
+const EVM_EMULATOR_EVM_VERSION: &str = "cancun";
+
+/// Known deviations of the EVM emulator from Ethereum semantics, returned by `zks_getEvmCompatibilityInfo`.
+const EVM_EMULATOR_OPCODE_DEVIATIONS: &[(&str, &str)] = &[
+    ("SELFDESTRUCT", "Not supported; execution reverts."),
+    ("CALLCODE", "Not supported; execution reverts."),
+    (
+        "PREVRANDAO",
+        "Returns a constant value instead of the beacon chain randomness.",
+    ),
+    (
+        "COINBASE",
+        "Returns the bootloader address instead of the block proposer.",
+    ),
+    (
+        "BLOBHASH",
+        "Always returns zero since blob transactions are not supported on L2.",
+    ),
+    (
+        "BLOBBASEFEE",
+        "Always returns zero since blob transactions are not supported on L2.",
+    ),
+    (
+        "GAS",
+        "Returns emulated EVM gas, which is derived from native ergs and may differ from Ethereum.",
+    ),
+    (
+        "GASLIMIT",
+        "Returns the L2 block gas limit, which is not related to Ethereum gas.",
+    ),
+    (
+        "DELEGATECALL",
+        "Delegate calls from EVM contracts into native EraVM contracts are not supported.",
+    ),
+];
+
 #[derive(Debug)]
 pub(crate) struct ZksNamespace {
     state: RpcState,
@@ -59,7 +103,7 @@ impl ZksNamespace {
         &self,
         request: CallRequest,
         state_override: Option<StateOverride>,
-    ) -> Result<Fee, Web3Error> {
+    ) -> Result<api::FeeEstimate, Web3Error> {
         let mut request_with_gas_per_pubdata_overridden = request;
         self.state
             .set_nonce_for_call_request(&mut request_with_gas_per_pubdata_overridden)
@@ -91,28 +135,137 @@ impl ZksNamespace {
         request: CallRequest,
         state_override: Option<StateOverride>,
     ) -> Result<U256, Web3Error> {
-        let mut request_with_gas_per_pubdata_overridden = request;
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        drop(connection);
+        let tx = Self::l1_tx_from_request(request, &block_args)
+            .map_err(Web3Error::SerializationError)?;
+
+        let fee = self
+            .estimate_fee(tx.into(), block_args, state_override)
+            .await?;
+        Ok(fee.fee.gas_limit)
+    }
+
+    fn l1_tx_from_request(
+        mut request: CallRequest,
+        block_args: &BlockArgs,
+    ) -> Result<L1Tx, SerializationTransactionError> {
         // When we're estimating fee, we are trying to deduce values related to fee, so we should
         // not consider provided ones.
-        if let Some(ref mut eip712_meta) = request_with_gas_per_pubdata_overridden.eip712_meta {
+        if let Some(ref mut eip712_meta) = request.eip712_meta {
             if eip712_meta.gas_per_pubdata == U256::zero() {
                 eip712_meta.gas_per_pubdata = REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into();
             }
         }
+        L1Tx::from_request(request, block_args.use_evm_emulator())
+    }
+
+    /// Simulates L1->L2 transactions one by one on top of the pending block. Transactions are simulated independently,
+    /// i.e., state changes made by a transaction are not visible to subsequent transactions. Each transaction is executed
+    /// with the gas limit provided by the caller, or with the estimated gas limit if it's not provided. Failures
+    /// of individual transactions are reported in their results; only internal errors fail the entire request.
+    pub async fn simulate_l1_to_l2_transactions_impl(
+        &self,
+        requests: Vec<CallRequest>,
+        state_override: Option<StateOverride>,
+    ) -> Result<Vec<api::L1ToL2TransactionSimulation>, Web3Error> {
+        if requests.len() > MAX_L1_TO_L2_SIMULATIONS {
+            return Err(Web3Error::TooManyTransactions(MAX_L1_TO_L2_SIMULATIONS));
+        }
 
         let mut connection = self.state.acquire_connection().await?;
         let block_args = BlockArgs::pending(&mut connection).await?;
         drop(connection);
-        let tx = L1Tx::from_request(
-            request_with_gas_per_pubdata_overridden,
-            block_args.use_evm_emulator(),
-        )
-        .map_err(Web3Error::SerializationError)?;
 
-        let fee = self
-            .estimate_fee(tx.into(), block_args, state_override)
+        let mut results = Vec::with_capacity(requests.len());
+        for request in requests {
+            let gas_per_pubdata = Self::l1_tx_from_request(request.clone(), &block_args)
+                .map_or(REQUIRED_L1_TO_L2_GAS_PER_PUBDATA_BYTE.into(), |tx| {
+                    tx.common_data.gas_per_pubdata_limit
+                });
+            let simulation = self
+                .simulate_l1_to_l2_transaction(request, &block_args, state_override.clone())
+                .await;
+            let result = match simulation {
+                Ok(result) => result,
+                Err(
+                    err @ (SubmitTxError::Internal(_)
+                    | SubmitTxError::ProxyError(_)
+                    | SubmitTxError::ServerShuttingDown),
+                ) => return Err(err.into()),
+                Err(err) => {
+                    let revert_data = err.data();
+                    api::L1ToL2TransactionSimulation {
+                        success: false,
+                        gas_limit: None,
+                        gas_used: None,
+                        gas_per_pubdata,
+                        error: Some(err.to_string()),
+                        revert_data: (!revert_data.is_empty()).then(|| revert_data.into()),
+                    }
+                }
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    /// Executes a single L1->L2 transaction with the gas limit provided by the caller, or with the estimated
+    /// gas limit if the caller hasn't provided one.
+    async fn simulate_l1_to_l2_transaction(
+        &self,
+        request: CallRequest,
+        block_args: &BlockArgs,
+        state_override: Option<StateOverride>,
+    ) -> Result<api::L1ToL2TransactionSimulation, SubmitTxError> {
+        let provided_gas_limit = request.gas;
+        let tx = Self::l1_tx_from_request(request, block_args)
+            .map_err(|err| SubmitTxError::Unexecutable(err.to_string()))?;
+        let gas_per_pubdata = tx.common_data.gas_per_pubdata_limit;
+        let gas_limit = match provided_gas_limit {
+            Some(gas_limit) => gas_limit,
+            None => {
+                let estimate = self
+                    .estimate_fee_inner(
+                        tx.clone().into(),
+                        block_args.clone(),
+                        state_override.clone(),
+                    )
+                    .await?;
+                estimate.fee.gas_limit
+            }
+        };
+        if gas_limit > U256::from(u64::MAX) {
+            return Err(SubmitTxError::GasLimitIsTooBig);
+        }
+
+        let (result, gas_used) = self
+            .state
+            .tx_sender
+            .execute_with_gas_limit(
+                tx.into(),
+                gas_limit.as_u64(),
+                block_args.clone(),
+                state_override,
+            )
             .await?;
-        Ok(fee.gas_limit)
+        let (error, revert_data) = match result {
+            ExecutionResult::Success { .. } => (None, None),
+            ExecutionResult::Revert { output } => (
+                Some(output.to_user_friendly_string()),
+                Some(output.encoded_data().into()),
+            ),
+            ExecutionResult::Halt { reason } => (Some(reason.to_string()), None),
+        };
+        Ok(api::L1ToL2TransactionSimulation {
+            success: error.is_none(),
+            gas_limit: Some(gas_limit),
+            gas_used: Some(gas_used.into()),
+            gas_per_pubdata,
+            error,
+            revert_data,
+        })
     }
 
     async fn estimate_fee(
@@ -120,14 +273,24 @@ impl ZksNamespace {
         tx: Transaction,
         block_args: BlockArgs,
         state_override: Option<StateOverride>,
-    ) -> Result<Fee, Web3Error> {
+    ) -> Result<api::FeeEstimate, Web3Error> {
+        Ok(self
+            .estimate_fee_inner(tx, block_args, state_override)
+            .await?)
+    }
+
+    async fn estimate_fee_inner(
+        &self,
+        tx: Transaction,
+        block_args: BlockArgs,
+        state_override: Option<StateOverride>,
+    ) -> Result<api::FeeEstimate, SubmitTxError> {
         let scale_factor = self.state.api_config.estimate_gas_scale_factor;
         let acceptable_overestimation =
             self.state.api_config.estimate_gas_acceptable_overestimation;
         let search_kind = BinarySearchKind::new(self.state.api_config.estimate_gas_optimize_search);
 
-        Ok(self
-            .state
+        self.state
             .tx_sender
             .get_txs_fee_in_wei(
                 tx,
@@ -137,7 +300,7 @@ impl ZksNamespace {
                 state_override,
                 search_kind,
             )
-            .await?)
+            .await
     }
 
     pub fn get_bridgehub_contract_impl(&self) -> Option<Address> {
@@ -545,6 +708,25 @@ impl ZksNamespace {
         Ok(tx_details)
     }
 
+    pub async fn get_raw_transaction_impl(&self, hash: H256) -> Result<Option<Bytes>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let raw_bytes = storage
+            .transactions_web3_dal()
+            .get_raw_transaction_bytes(hash)
+            .await
+            .map_err(DalError::generalize)?;
+        let raw_bytes = match raw_bytes {
+            Some(raw_bytes) => Some(raw_bytes),
+            None => {
+                self.state
+                    .tx_sink()
+                    .lookup_raw_tx(&mut storage, hash)
+                    .await?
+            }
+        };
+        Ok(raw_bytes.map(Bytes))
+    }
+
     pub async fn get_l1_batch_details_impl(
         &self,
         batch_number: L1BatchNumber,
@@ -555,11 +737,20 @@ impl ZksNamespace {
             .ensure_not_pruned(batch_number, &mut storage)
             .await?;
 
-        Ok(storage
+        let Some(mut details) = storage
             .blocks_web3_dal()
             .get_l1_batch_details(batch_number)
             .await
-            .map_err(DalError::generalize)?)
+            .map_err(DalError::generalize)?
+        else {
+            return Ok(None);
+        };
+        details.proving_rounds = storage
+            .proof_generation_dal()
+            .get_proving_rounds(batch_number)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(Some(details))
     }
 
     pub async fn get_bytecode_by_hash_impl(
@@ -606,6 +797,32 @@ impl ZksNamespace {
         Ok(protocol_version)
     }
 
+    pub async fn get_protocol_version_info_impl(
+        &self,
+    ) -> Result<api::ProtocolVersionInfo, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let current_version = BlockArgs::pending(&mut connection)
+            .await?
+            .protocol_version();
+        // Newer versions are persisted by `eth_watch` as soon as upgrades are observed on the settlement layer.
+        let versions = connection
+            .protocol_versions_web3_dal()
+            .get_protocol_version_details(current_version)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(connection);
+
+        let (current, scheduled): (Vec<_>, _) = versions
+            .into_iter()
+            .partition(|details| details.version.minor == current_version);
+        // Use the latest patch for the current version; patches only change verification keys.
+        let current = current
+            .into_iter()
+            .last()
+            .with_context(|| format!("protocol version {current_version:?} is not persisted"))?;
+        Ok(api::ProtocolVersionInfo { current, scheduled })
+    }
+
     pub async fn get_proofs_impl(
         &self,
         address: Address,
@@ -684,10 +901,165 @@ impl ZksNamespace {
             .into_pubdata_independent())
     }
 
+    pub fn get_gas_per_pubdata_impl(&self) -> Result<api::GasPerPubdataInfo, Web3Error> {
+        let oracle = self
+            .state
+            .gas_per_pubdata_oracle
+            .as_ref()
+            .ok_or(Web3Error::MethodNotImplemented)?;
+        oracle
+            .info()
+            .context("gas per pubdata oracle hasn't sampled fee input yet")
+            .map_err(Web3Error::InternalError)
+    }
+
+    pub async fn get_evm_compatibility_info_impl(
+        &self,
+    ) -> Result<api::EvmCompatibilityInfo, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        drop(connection);
+
+        let protocol_version = block_args.protocol_version();
+        // The EVM emulator was introduced together with `VmVersion::VmEvmEmulator`.
+        let evm_emulator_supported = protocol_version >= ProtocolVersionId::Version27;
+        let opcode_deviations = if evm_emulator_supported {
+            EVM_EMULATOR_OPCODE_DEVIATIONS
+                .iter()
+                .map(|&(opcode, description)| api::EvmOpcodeDeviation {
+                    opcode: opcode.to_owned(),
+                    description: description.to_owned(),
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+        // Must be consistent with `eth_chainId` and `net_version`.
+        let chain_id = self.state.api_config.l2_chain_id;
+        Ok(api::EvmCompatibilityInfo {
+            protocol_version,
+            chain_id: chain_id.as_u64().into(),
+            net_version: chain_id.as_u64().to_string(),
+            evm_emulator_supported,
+            evm_emulator_enabled: block_args.use_evm_emulator(),
+            evm_version: evm_emulator_supported.then(|| EVM_EMULATOR_EVM_VERSION.to_owned()),
+            opcode_deviations,
+        })
+    }
+
+    pub async fn get_chain_creation_params_impl(
+        &self,
+    ) -> Result<api::ChainCreationParams, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        // Nodes recovered from a snapshot don't have the genesis batch.
+        self.state
+            .start_info
+            .ensure_not_pruned(L1BatchNumber(0), &mut storage)
+            .await?;
+        let genesis_batch = storage
+            .blocks_dal()
+            .get_l1_batch_metadata(L1BatchNumber(0))
+            .await
+            .map_err(DalError::generalize)?
+            .context("genesis L1 batch doesn't exist")?;
+        let minor = genesis_batch
+            .header
+            .protocol_version
+            .context("genesis L1 batch has no protocol version")?;
+        let patch = storage
+            .protocol_versions_dal()
+            .first_patch_for_version(minor)
+            .await
+            .map_err(DalError::generalize)?
+            .context("genesis protocol version is not persisted")?;
+        let protocol_version = ProtocolSemanticVersion { minor, patch };
+
+        let base_system_contracts = genesis_batch.header.base_system_contracts_hashes;
+        Ok(api::ChainCreationParams {
+            genesis_root: genesis_batch.metadata.root_hash,
+            genesis_rollup_leaf_index: genesis_batch.metadata.rollup_last_leaf_index,
+            genesis_batch_commitment: genesis_batch.metadata.commitment,
+            protocol_version,
+            packed_protocol_version: protocol_version.pack(),
+            bootloader_hash: base_system_contracts.bootloader,
+            default_aa_hash: base_system_contracts.default_aa,
+            evm_emulator_hash: base_system_contracts.evm_emulator,
+        })
+    }
+
+    pub async fn get_system_contracts_impl(&self) -> Result<api::SystemContracts, Web3Error> {
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = BlockArgs::pending(&mut connection).await?;
+        let protocol_version = block_args.protocol_version();
+        let base_system_contracts = connection
+            .protocol_versions_dal()
+            .get_base_system_contract_hashes_by_version_id(protocol_version)
+            .await?
+            .with_context(|| format!("protocol version {protocol_version:?} is not persisted"))?;
+
+        // Deployed code hashes are taken from the chain state rather than from the contracts bundled with the node,
+        // since the latter may differ from the contracts deployed during genesis or upgrades.
+        let contracts: Vec<_> = system_contract_addresses().collect();
+        let code_keys: Vec<_> = contracts
+            .iter()
+            .map(|(_, address)| get_code_key(address).hashed_key())
+            .collect();
+        let code_hashes = connection
+            .storage_web3_dal()
+            .get_values(&code_keys)
+            .await
+            .map_err(DalError::generalize)?;
+        let contracts = contracts
+            .into_iter()
+            .zip(&code_keys)
+            .map(|((name, address), code_key)| api::SystemContractInfo {
+                name: name.to_owned(),
+                address,
+                code_hash: code_hashes
+                    .get(code_key)
+                    .copied()
+                    .filter(|hash| !hash.is_zero()),
+            })
+            .collect();
+
+        Ok(api::SystemContracts {
+            protocol_version,
+            bootloader_code_hash: base_system_contracts.bootloader,
+            default_aa_code_hash: base_system_contracts.default_aa,
+            evm_emulator_code_hash: base_system_contracts.evm_emulator,
+            contracts,
+        })
+    }
+
+    pub fn get_node_version_impl(&self) -> api::NodeVersion {
+        let mut api_features = vec![];
+        if !self.state.api_config.filters_disabled {
+            api_features.push("filters");
+        }
+        if self.state.tree_api.is_some() {
+            api_features.push("proofs");
+        }
+        if self.state.mempool_cache.is_some() {
+            api_features.push("mempool_cache");
+        }
+        if self.state.l2_l1_log_proof_handler.is_some() {
+            api_features.push("l2_l1_log_proof_proxy");
+        }
+
+        api::NodeVersion {
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            git_commit: GIT_METRICS.initialize().revision.map(str::to_owned),
+            // The VM is able to execute all known protocol versions, including the speculative next one.
+            supported_protocol_versions: (0..=ProtocolVersionId::next() as u16).collect(),
+            api_features: api_features.into_iter().map(str::to_owned).collect(),
+        }
+    }
+
     #[tracing::instrument(skip(self, tx_bytes))]
     pub async fn send_raw_transaction_with_detailed_output_impl(
         &self,
         tx_bytes: Bytes,
+        validation_only: bool,
     ) -> Result<TransactionDetailedResult, Web3Error> {
         let mut connection = self.state.acquire_connection().await?;
         let block_args = BlockArgs::pending(&mut connection).await?;
@@ -697,16 +1069,17 @@ impl ZksNamespace {
             .parse_transaction_bytes(&tx_bytes.0, &block_args)?;
         tx.set_input(tx_bytes.0, tx_hash);
 
-        let submit_output = self
-            .state
-            .tx_sender
-            .submit_tx(tx, block_args)
-            .await
-            .map_err(|err| {
-                tracing::debug!("Send raw transaction error: {err}");
-                API_METRICS.submit_tx_error[&err.prom_error_code()].inc();
-                err
-            })?;
+        let tx_sender = &self.state.tx_sender;
+        let submit_output = if validation_only {
+            tx_sender.simulate_tx_validation(tx, block_args).await
+        } else {
+            tx_sender.submit_tx(tx, block_args).await
+        };
+        let submit_output = submit_output.map_err(|err| {
+            tracing::debug!("Send raw transaction error: {err}");
+            API_METRICS.submit_tx_error[&err.prom_error_code()].inc();
+            err
+        })?;
         Ok(TransactionDetailedResult {
             transaction_hash: tx_hash,
             storage_logs: submit_output
@@ -10,7 +10,7 @@ use std::{
 use anyhow::Context as _;
 use futures::TryFutureExt;
 use lru::LruCache;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{watch, Mutex, RwLock};
 use vise::GaugeGuard;
 use zksync_config::{
     configs::{api::Web3JsonRpcConfig, ContractsConfig},
@@ -18,10 +18,12 @@ use zksync_config::{
 };
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
 use zksync_metadata_calculator::api_server::TreeApiClient;
+use zksync_node_fee_model::gas_per_pubdata::GasPerPubdataOracle;
 use zksync_node_sync::SyncState;
 use zksync_types::{
-    api, commitment::L1BatchCommitmentMode, l2::L2Tx, transaction_request::CallRequest, Address,
-    L1BatchNumber, L1ChainId, L2BlockNumber, L2ChainId, H256, U256, U64,
+    api, commitment::L1BatchCommitmentMode, l2::L2Tx, settlement::SettlementLayerData,
+    transaction_request::CallRequest, Address, L1BatchNumber, L1ChainId, L2BlockNumber, L2ChainId,
+    H256, U256, U64,
 };
 use zksync_web3_decl::{
     client::{DynClient, L2},
@@ -33,6 +35,7 @@ use super::{
     backend_jsonrpsee::MethodTracer,
     mempool_cache::MempoolCache,
     metrics::{FilterType, FILTER_METRICS},
+    namespaces::SnapshotUrlSigner,
     TypedFilter,
 };
 use crate::{
@@ -122,6 +125,7 @@ pub struct InternalApiConfig {
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
     pub timestamp_asserter_address: Option<Address>,
     pub l1_to_l2_txs_paused: bool,
+    pub max_trace_size_bytes: Option<usize>,
 }
 
 impl InternalApiConfig {
@@ -186,6 +190,7 @@ impl InternalApiConfig {
             l1_batch_commit_data_generator_mode: genesis_config.l1_batch_commit_data_generator_mode,
             timestamp_asserter_address: contracts_config.l2_timestamp_asserter_addr,
             l1_to_l2_txs_paused,
+            max_trace_size_bytes: web3_config.max_trace_size_bytes,
         }
     }
 }
@@ -268,9 +273,12 @@ pub(crate) struct RpcState {
     /// from a snapshot.
     pub(super) start_info: BlockStartInfo,
     pub(super) mempool_cache: Option<MempoolCache>,
+    pub(super) gas_per_pubdata_oracle: Option<GasPerPubdataOracle>,
     pub(super) last_sealed_l2_block: SealedL2BlockNumber,
     pub(super) bridge_addresses_handle: BridgeAddressesHandle,
     pub(super) l2_l1_log_proof_handler: Option<Box<DynClient<L2>>>,
+    pub(super) snapshot_url_signer: Option<SnapshotUrlSigner>,
+    pub(super) settlement_layer_data: Option<watch::Receiver<Option<SettlementLayerData>>>,
 }
 
 impl RpcState {
@@ -370,6 +378,22 @@ impl RpcState {
             })
     }
 
+    /// Resolves block args for `eth_call`. Unlike [`Self::resolve_block_args()`], calls against pruned blocks may be served
+    /// from snapshots if this is enabled for the server.
+    pub(crate) async fn resolve_call_block_args(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        block: api::BlockId,
+    ) -> Result<BlockArgs, Web3Error> {
+        let Some(snapshot_reader) = self.tx_sender.snapshot_reader() else {
+            return self.resolve_block_args(connection, block).await;
+        };
+        Ok(
+            BlockArgs::with_snapshot_fallback(connection, block, &self.start_info, snapshot_reader)
+                .await?,
+        )
+    }
+
     pub async fn resolve_filter_block_number(
         &self,
         block_number: Option<api::BlockNumber>,
@@ -9,7 +9,7 @@ use zksync_health_check::CheckHealth;
 use zksync_node_fee_model::MockBatchFeeParamsProvider;
 use zksync_state::PostgresStorageCaches;
 use zksync_state_keeper::seal_criteria::NoopSealer;
-use zksync_types::L2ChainId;
+use zksync_types::{settlement::SettlementLayerData, L2ChainId};
 use zksync_vm_executor::oneshot::MockOneshotExecutor;
 
 use super::{metrics::ApiTransportLabel, *};
@@ -109,6 +109,12 @@ pub struct TestServerBuilder {
     tx_executor: MockOneshotExecutor,
     executor_options: Option<SandboxExecutorOptions>,
     method_tracer: Arc<MethodTracer>,
+    snapshot_download_urls: Option<(Arc<dyn ObjectStore>, Duration)>,
+    snapshots_requests_per_minute_limit: Option<NonZeroU32>,
+    batch_request_size_limit: Option<usize>,
+    namespaces: Option<Vec<Namespace>>,
+    custom_namespaces: Vec<Arc<dyn CustomNamespace>>,
+    settlement_layer_data: Option<watch::Receiver<Option<SettlementLayerData>>>,
 }
 
 impl TestServerBuilder {
@@ -120,6 +126,12 @@ impl TestServerBuilder {
             tx_executor: MockOneshotExecutor::default(),
             executor_options: None,
             method_tracer: Arc::default(),
+            snapshot_download_urls: None,
+            snapshots_requests_per_minute_limit: None,
+            batch_request_size_limit: None,
+            namespaces: None,
+            custom_namespaces: vec![],
+            settlement_layer_data: None,
         }
     }
 
@@ -143,6 +155,51 @@ impl TestServerBuilder {
         self
     }
 
+    /// Enables signing snapshot download URLs using the provided object store.
+    #[must_use]
+    pub fn with_snapshot_download_urls(
+        mut self,
+        blob_store: Arc<dyn ObjectStore>,
+        ttl: Duration,
+    ) -> Self {
+        self.snapshot_download_urls = Some((blob_store, ttl));
+        self
+    }
+
+    #[must_use]
+    pub fn with_snapshots_requests_per_minute_limit(mut self, limit: NonZeroU32) -> Self {
+        self.snapshots_requests_per_minute_limit = Some(limit);
+        self
+    }
+
+    #[must_use]
+    pub fn with_batch_request_size_limit(mut self, limit: usize) -> Self {
+        self.batch_request_size_limit = Some(limit);
+        self
+    }
+
+    /// Overrides enabled API namespaces. By default, all namespaces are enabled.
+    #[must_use]
+    pub fn with_namespaces(mut self, namespaces: Vec<Namespace>) -> Self {
+        self.namespaces = Some(namespaces);
+        self
+    }
+
+    #[must_use]
+    pub fn with_custom_namespace(mut self, namespace: Arc<dyn CustomNamespace>) -> Self {
+        self.custom_namespaces.push(namespace);
+        self
+    }
+
+    #[must_use]
+    pub fn with_settlement_layer_data(
+        mut self,
+        data: watch::Receiver<Option<SettlementLayerData>>,
+    ) -> Self {
+        self.settlement_layer_data = Some(data);
+        self
+    }
+
     /// Builds an HTTP server.
     pub async fn build_http(self, stop_receiver: watch::Receiver<bool>) -> ApiServerHandles {
         self.spawn_server(ApiTransportLabel::Http, None, stop_receiver)
@@ -176,6 +233,12 @@ impl TestServerBuilder {
             pool,
             api_config,
             method_tracer,
+            snapshot_download_urls,
+            snapshots_requests_per_minute_limit,
+            batch_request_size_limit,
+            namespaces,
+            custom_namespaces,
+            settlement_layer_data,
         } = self;
 
         let tx_executor = if let Some(options) = executor_options {
@@ -187,13 +250,16 @@ impl TestServerBuilder {
             create_test_tx_sender(pool.clone(), api_config.l2_chain_id, tx_executor).await;
         let (pub_sub_events_sender, pub_sub_events_receiver) = mpsc::unbounded_channel();
 
-        let mut namespaces = Namespace::DEFAULT.to_vec();
-        namespaces.extend([Namespace::Debug, Namespace::Snapshots, Namespace::Unstable]);
+        let namespaces = namespaces.unwrap_or_else(|| {
+            let mut namespaces = Namespace::DEFAULT.to_vec();
+            namespaces.extend([Namespace::Debug, Namespace::Snapshots, Namespace::Unstable]);
+            namespaces
+        });
         let sealed_l2_block_handle = SealedL2BlockNumber::default();
         let bridge_addresses_handle =
             BridgeAddressesHandle::new(api_config.bridge_addresses.clone());
 
-        let server_builder = match transport {
+        let mut server_builder = match transport {
             ApiTransportLabel::Http => ApiBuilder::jsonrpsee_backend(api_config, pool).http(0),
             ApiTransportLabel::Ws => {
                 let mut builder = ApiBuilder::jsonrpsee_backend(api_config, pool)
@@ -209,6 +275,21 @@ impl TestServerBuilder {
                 builder
             }
         };
+        if let Some((blob_store, ttl)) = snapshot_download_urls {
+            server_builder = server_builder.with_snapshot_download_urls(blob_store, ttl);
+        }
+        if let Some(limit) = snapshots_requests_per_minute_limit {
+            server_builder = server_builder.with_snapshots_requests_per_minute_limit(limit);
+        }
+        if let Some(limit) = batch_request_size_limit {
+            server_builder = server_builder.with_batch_request_size_limit(limit);
+        }
+        for namespace in custom_namespaces {
+            server_builder = server_builder.with_custom_namespace(namespace);
+        }
+        if let Some(data) = settlement_layer_data {
+            server_builder = server_builder.with_settlement_layer_data(data);
+        }
         let server_handles = server_builder
             .with_polling_interval(POLL_INTERVAL)
             .with_tx_sender(tx_sender)
@@ -3,10 +3,12 @@
 use zksync_multivm::interface::{Call, TransactionExecutionResult};
 use zksync_types::{
     api::{CallTracerConfig, SupportedTracers, TracerConfig},
+    debug_flat_call::CallTraceMeta,
     BOOTLOADER_ADDRESS,
 };
 use zksync_web3_decl::{
     client::{DynClient, L2},
+    error::Web3Error,
     namespaces::DebugNamespaceClient,
 };
 
@@ -73,7 +75,14 @@ impl HttpTest for TraceBlockTest {
                 let expected_calls: Vec<_> = tx_result
                     .call_traces
                     .iter()
-                    .map(|call| DebugNamespace::map_default_call(call.clone(), false, None))
+                    .map(|call| {
+                        DebugNamespace::map_default_call(
+                            call.clone(),
+                            false,
+                            None,
+                            &mut TraceBudget::unlimited(),
+                        )
+                    })
                     .collect();
                 assert_eq!(result.calls, expected_calls);
             }
@@ -198,6 +207,132 @@ async fn tracing_block_flat() {
     test_http_server(TraceBlockFlatTest(L2BlockNumber(1))).await;
 }
 
+fn trace_with_nested_calls(index_in_block: u8) -> Call {
+    Call {
+        calls: execute_l2_transaction_with_traces(index_in_block).call_traces,
+        ..Call::default()
+    }
+}
+
+#[test]
+fn truncating_traces() {
+    let traces = [0, 1, 2].map(trace_with_nested_calls);
+    let nested_call_sizes: Vec<_> = traces[0]
+        .calls
+        .iter()
+        .map(TraceBudget::estimate_call_size)
+        .collect();
+    // The budget fits both nested calls of the first trace and the first nested call of the second one.
+    let max_bytes = nested_call_sizes[0] + nested_call_sizes[1] + nested_call_sizes[0];
+
+    let mut budget = TraceBudget::new(Some(max_bytes));
+    let debug_calls: Vec<_> = traces
+        .iter()
+        .map(|call| DebugNamespace::map_default_call(call.clone(), false, None, &mut budget))
+        .collect();
+    // All top-level calls are returned, but nested calls are truncated.
+    let nested_call_counts: Vec<_> = debug_calls.iter().map(|call| call.calls.len()).collect();
+    assert_eq!(nested_call_counts, [2, 1, 0]);
+    let truncated_flags: Vec<_> = debug_calls.iter().map(|call| call.truncated).collect();
+    assert_eq!(truncated_flags, [false, true, true]);
+
+    let flat_config = TracerConfig {
+        tracer: SupportedTracers::FlatCallTracer,
+        tracer_config: CallTracerConfig {
+            only_top_call: false,
+        },
+    };
+    let mut budget = TraceBudget::new(Some(max_bytes));
+    let flat_traces: Vec<_> = traces
+        .iter()
+        .map(|call| {
+            DebugNamespace::map_call(
+                call.clone(),
+                CallTraceMeta::default(),
+                flat_config,
+                &mut budget,
+            )
+            .unwrap_flat()
+        })
+        .collect();
+    let flat_call_counts: Vec<_> = flat_traces.iter().map(Vec::len).collect();
+    assert_eq!(flat_call_counts, [3, 2, 1]);
+    let truncated_flags: Vec<_> = flat_traces.iter().map(|calls| calls[0].truncated).collect();
+    assert_eq!(truncated_flags, [false, true, true]);
+    // `subtraces` must correspond to the returned nested calls.
+    let subtraces: Vec<_> = flat_traces.iter().map(|calls| calls[0].subtraces).collect();
+    assert_eq!(subtraces, [2, 1, 0]);
+}
+
+#[test]
+fn checking_stored_trace_size() {
+    let budget = TraceBudget::new(Some(1_000));
+    // Stored size is converted to the estimated JSON size of the response.
+    budget.check_stored_size(500).unwrap();
+    let err = budget.check_stored_size(501).unwrap_err();
+    assert_matches!(err, Web3Error::TraceSizeLimitExceeded(1_000));
+
+    TraceBudget::unlimited()
+        .check_stored_size(u64::MAX)
+        .unwrap();
+}
+
+#[derive(Debug)]
+struct TraceSizeLimitTest;
+
+#[async_trait]
+impl HttpTest for TraceSizeLimitTest {
+    fn max_trace_size_bytes(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let tx_results = [0, 1, 2].map(execute_l2_transaction_with_traces);
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &tx_results).await?;
+        drop(storage);
+
+        // Stored traces exceed the limit, so they must be rejected without loading.
+        let error = client
+            .trace_block_by_number(api::BlockNumber::from(1_u32), None)
+            .await
+            .unwrap_err();
+        assert_trace_size_error(&error);
+        let error = client
+            .trace_transaction(tx_results[0].hash, None)
+            .await
+            .unwrap_err();
+        assert_trace_size_error(&error);
+
+        // The limit doesn't affect blocks and transactions without traces.
+        let block_traces = client
+            .trace_block_by_number(api::BlockNumber::from(0_u32), None)
+            .await?;
+        assert!(block_traces.unwrap_default().is_empty());
+        let tx_trace = client.trace_transaction(H256::repeat_byte(1), None).await?;
+        assert!(tx_trace.is_none(), "{tx_trace:?}");
+        Ok(())
+    }
+}
+
+fn assert_trace_size_error(error: &ClientError) {
+    if let ClientError::Call(error) = error {
+        assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        assert!(error.message().contains("size limit"), "{error:?}");
+    } else {
+        panic!("Unexpected error: {error:?}");
+    }
+}
+
+#[tokio::test]
+async fn tracing_with_size_limit() {
+    test_http_server(TraceSizeLimitTest).await;
+}
+
 #[derive(Debug)]
 struct TraceTransactionTest;
 
@@ -216,7 +351,14 @@ impl HttpTest for TraceTransactionTest {
         let expected_calls: Vec<_> = tx_results[0]
             .call_traces
             .iter()
-            .map(|call| DebugNamespace::map_default_call(call.clone(), false, None))
+            .map(|call| {
+                DebugNamespace::map_default_call(
+                    call.clone(),
+                    false,
+                    None,
+                    &mut TraceBudget::unlimited(),
+                )
+            })
             .collect();
 
         let result = client
@@ -278,3 +420,94 @@ impl HttpTest for TraceBlockTestWithSnapshotRecovery {
 async fn tracing_block_after_snapshot_recovery() {
     test_http_server(TraceBlockTestWithSnapshotRecovery).await;
 }
+
+#[derive(Debug)]
+struct StorageRangeTest;
+
+#[async_trait]
+impl HttpTest for StorageRangeTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let address = Address::repeat_byte(0x42);
+        let account = AccountTreeId::new(address);
+        let logs: Vec<_> = (1_u8..=3)
+            .map(|i| {
+                StorageLog::new_write_log(
+                    StorageKey::new(account, H256::repeat_byte(i)),
+                    H256::repeat_byte(i),
+                )
+            })
+            .collect();
+        let tx_results = [0, 1].map(execute_l2_transaction_with_traces);
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &tx_results).await?;
+        storage
+            .storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(1), &logs)
+            .await?;
+
+        let block_id = api::BlockId::Number(1_u32.into());
+        // The method is unavailable until the archive index is built.
+        let error = client
+            .storage_range_at(block_id, 0, address, H256::zero(), 10)
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::MethodNotFound.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+        storage.system_dal().create_archive_indexes().await?;
+        drop(storage);
+
+        let range = client
+            .storage_range_at(block_id, 0, address, H256::zero(), 10)
+            .await?;
+        assert_eq!(range, api::StorageRangeResult::default());
+
+        let mut sorted_logs = logs.clone();
+        sorted_logs.sort_unstable_by_key(|log| log.key.hashed_key());
+        let first_page = client
+            .storage_range_at(block_id, tx_results.len(), address, H256::zero(), 2)
+            .await?;
+        assert_eq!(first_page.storage.len(), 2);
+        assert_eq!(first_page.next_key, Some(sorted_logs[2].key.hashed_key()));
+        for log in &sorted_logs[..2] {
+            let entry = &first_page.storage[&log.key.hashed_key()];
+            assert_eq!(entry.key, Some(*log.key.key()));
+            assert_eq!(entry.value, log.value);
+        }
+
+        let second_page = client
+            .storage_range_at(
+                block_id,
+                tx_results.len(),
+                address,
+                first_page.next_key.unwrap(),
+                2,
+            )
+            .await?;
+        assert_eq!(second_page.storage.len(), 1);
+        assert_eq!(second_page.next_key, None);
+
+        let error = client
+            .storage_range_at(block_id, 1, address, H256::zero(), 10)
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_storage_range() {
+    test_http_server(StorageRangeTest).await;
+}
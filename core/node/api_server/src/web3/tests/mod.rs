@@ -27,9 +27,12 @@ use zksync_node_test_utils::{
     l1_batch_metadata_to_commitment_artifacts, prepare_recovery_snapshot,
 };
 use zksync_system_constants::{
+    BOOTLOADER_ADDRESS, L2_BRIDGEHUB_ADDRESS, L2_NATIVE_TOKEN_VAULT_ADDRESS,
     SYSTEM_CONTEXT_ADDRESS, SYSTEM_CONTEXT_CURRENT_L2_BLOCK_INFO_POSITION,
 };
 use zksync_types::{
+    address_to_h256,
+    aggregated_operations::AggregatedActionType,
     api,
     block::{pack_block_info, L2BlockHasher, L2BlockHeader, UnsealedL1BatchHeader},
     bytecode::{
@@ -38,13 +41,20 @@ use zksync_types::{
     },
     fee_model::{BatchFeeInput, FeeParams},
     get_deployer_key, get_nonce_key,
+    l2_to_l1_log::{
+        BatchAndChainMerklePath, L2ToL1Log, UserL2ToL1Log, LOG_PROOF_SUPPORTED_METADATA_VERSION,
+    },
+    protocol_upgrade::ProtocolVersion,
+    protocol_version::ProtocolSemanticVersion,
+    settlement::SettlementLayerData,
     storage::get_code_key,
     system_contracts::get_system_smart_contracts,
     tokens::{TokenInfo, TokenMetadata},
     tx::IncludedTxLocation,
     u256_to_h256,
     utils::{storage_key_for_eth_balance, storage_key_for_standard_token_balance},
-    AccountTreeId, Address, L1BatchNumber, Nonce, StorageKey, StorageLog, H256, U256, U64,
+    AccountTreeId, Address, L1BatchNumber, Nonce, ProtocolVersionId, SLChainId, StorageKey,
+    StorageLog, H256, L1_MESSENGER_ADDRESS, U256, U64,
 };
 use zksync_vm_executor::oneshot::MockOneshotExecutor;
 use zksync_web3_decl::{
@@ -58,7 +68,7 @@ use zksync_web3_decl::{
             ErrorObjectOwned,
         },
     },
-    namespaces::{EnNamespaceClient, EthNamespaceClient, ZksNamespaceClient},
+    namespaces::{EnNamespaceClient, EthNamespaceClient, NetNamespaceClient, ZksNamespaceClient},
 };
 
 use super::*;
@@ -165,6 +175,25 @@ trait HttpTest: Send + Sync {
     fn filters_disabled(&self) -> bool {
         false
     }
+
+    /// Overrides the `max_trace_size_bytes` configuration parameter for HTTP server startup
+    fn max_trace_size_bytes(&self) -> Option<usize> {
+        None
+    }
+
+    /// Enables signing snapshot download URLs using the returned object store.
+    fn snapshot_download_urls(&self) -> Option<(Arc<dyn ObjectStore>, Duration)> {
+        None
+    }
+
+    fn snapshots_requests_per_minute_limit(&self) -> Option<NonZeroU32> {
+        None
+    }
+
+    /// Provides settlement layer data to the server.
+    fn settlement_layer_data(&self) -> Option<watch::Receiver<Option<SettlementLayerData>>> {
+        None
+    }
 }
 
 /// Storage initialization strategy.
@@ -291,12 +320,22 @@ async fn test_http_server(test: impl HttpTest) {
     let genesis = GenesisConfig::for_tests();
     let mut api_config = InternalApiConfig::new(&web3_config, &contracts_config, &genesis, false);
     api_config.filters_disabled = test.filters_disabled();
+    api_config.max_trace_size_bytes = test.max_trace_size_bytes();
     let mut server_builder = TestServerBuilder::new(pool.clone(), api_config)
         .with_tx_executor(test.transaction_executor())
         .with_method_tracer(test.method_tracer());
     if let Some(executor_options) = test.executor_options() {
         server_builder = server_builder.with_executor_options(executor_options);
     }
+    if let Some((blob_store, ttl)) = test.snapshot_download_urls() {
+        server_builder = server_builder.with_snapshot_download_urls(blob_store, ttl);
+    }
+    if let Some(limit) = test.snapshots_requests_per_minute_limit() {
+        server_builder = server_builder.with_snapshots_requests_per_minute_limit(limit);
+    }
+    if let Some(data) = test.settlement_layer_data() {
+        server_builder = server_builder.with_settlement_layer_data(data);
+    }
     let mut server_handles = server_builder.build_http(stop_receiver).await;
 
     let local_addr = server_handles.wait_until_ready().await;
@@ -309,6 +348,83 @@ async fn test_http_server(test: impl HttpTest) {
     server_handles.shutdown().await;
 }
 
+/// Custom namespace returning the L2 chain ID from the server context.
+#[derive(Debug)]
+struct ChainIdNamespace;
+
+impl CustomNamespace for ChainIdNamespace {
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+
+    fn create_rpc(&self, context: &CustomNamespaceContext) -> anyhow::Result<RpcModule<()>> {
+        let chain_id = context.api_config.l2_chain_id;
+        let mut rpc = RpcModule::new(());
+        rpc.register_method("custom_chainId", move |_params, _ctx, _ext| {
+            Ok::<_, ErrorObjectOwned>(U64::from(chain_id.as_u64()))
+        })?;
+        Ok(rpc)
+    }
+}
+
+#[tokio::test]
+async fn disabled_and_custom_namespaces() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    StorageInitialization::genesis()
+        .prepare_storage(&NetworkConfig::for_tests(), &mut storage)
+        .await
+        .unwrap();
+    drop(storage);
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let api_config = InternalApiConfig::new(
+        &Web3JsonRpcConfig::for_tests(),
+        &ContractsConfig::for_tests(),
+        &GenesisConfig::for_tests(),
+        false,
+    );
+    let l2_chain_id = api_config.l2_chain_id;
+    let mut namespaces = Namespace::DEFAULT.to_vec();
+    namespaces.retain(|namespace| *namespace != Namespace::Zks);
+    let mut server_handles = TestServerBuilder::new(pool, api_config)
+        .with_namespaces(namespaces)
+        .with_custom_namespace(Arc::new(ChainIdNamespace))
+        .build_http(stop_receiver)
+        .await;
+    let local_addr = server_handles.wait_until_ready().await;
+    let client = Client::<L2>::http(format!("http://{local_addr}/").parse().unwrap())
+        .unwrap()
+        .build();
+
+    let chain_id = client.chain_id().await.unwrap();
+    assert_eq!(chain_id, U64::from(l2_chain_id.as_u64()));
+    // Methods of disabled namespaces must not be registered.
+    for method in [
+        "zks_L1ChainId",
+        "debug_traceBlockByNumber",
+        "unstable_getChainLogProof",
+    ] {
+        let err = client
+            .request::<serde_json::Value, _>(method, rpc_params![])
+            .await
+            .unwrap_err();
+        assert_matches!(
+            err,
+            ClientError::Call(err) if err.code() == ErrorCode::MethodNotFound.code(),
+            "{method}"
+        );
+    }
+    let custom_chain_id: U64 = client
+        .request("custom_chainId", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(custom_chain_id, chain_id);
+
+    stop_sender.send_replace(true);
+    server_handles.shutdown().await;
+}
+
 fn assert_logs_match(actual_logs: &[api::Log], expected_logs: &[&VmEvent]) {
     assert_eq!(
         actual_logs.len(),
@@ -855,6 +971,203 @@ async fn getting_transaction_count_for_account_after_snapshot_recovery() {
     test_http_server(TransactionCountAfterSnapshotRecoveryTest).await;
 }
 
+#[derive(Debug)]
+struct RawTransactionTest;
+
+#[async_trait]
+impl HttpTest for RawTransactionTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let pending_tx = create_l2_transaction(10, 200);
+        let mut storage = pool.connection().await?;
+        storage
+            .transactions_dal()
+            .insert_transaction_l2(
+                &pending_tx,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap();
+
+        let raw_tx = client
+            .get_raw_transaction(pending_tx.hash())
+            .await?
+            .context("no raw transaction")?;
+        assert_eq!(raw_tx.0, pending_tx.common_data.input_data().unwrap());
+
+        let raw_tx = client.get_raw_transaction(H256::repeat_byte(1)).await?;
+        assert!(raw_tx.is_none());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_raw_transaction() {
+    test_http_server(RawTransactionTest).await;
+}
+
+#[derive(Debug)]
+struct BytecodeByHashTest;
+
+#[async_trait]
+impl HttpTest for BytecodeByHashTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let bytecode = vec![1; 96];
+        let bytecode_hash = BytecodeHash::for_bytecode(&bytecode).value();
+        let mut storage = pool.connection().await?;
+        storage
+            .factory_deps_dal()
+            .insert_factory_deps(
+                L2BlockNumber(1),
+                &HashMap::from([(bytecode_hash, bytecode.clone())]),
+            )
+            .await?;
+
+        // Factory deps must not be returned until the L2 block they belong to is sealed.
+        let fetched_bytecode = client.get_bytecode_by_hash(bytecode_hash).await?;
+        assert_eq!(fetched_bytecode, None);
+
+        store_l2_block(&mut storage, L2BlockNumber(1), &[]).await?;
+        let fetched_bytecode = client.get_bytecode_by_hash(bytecode_hash).await?;
+        assert_eq!(fetched_bytecode, Some(bytecode));
+
+        let fetched_bytecode = client.get_bytecode_by_hash(H256::repeat_byte(1)).await?;
+        assert_eq!(fetched_bytecode, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_bytecode_by_hash() {
+    test_http_server(BytecodeByHashTest).await;
+}
+
+/// Checks log proofs for L1 batches executed on Gateway, which include the batch-and-chain Merkle path
+/// persisted by the `eth_watch` batch root processor.
+#[derive(Debug)]
+struct GatewayLogProofTest;
+
+impl GatewayLogProofTest {
+    const GATEWAY_CHAIN_ID: SLChainId = SLChainId(505);
+}
+
+#[async_trait]
+impl HttpTest for GatewayLogProofTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let l1_chain_id = client.l1_chain_id().await?;
+        assert_ne!(l1_chain_id.as_u64(), Self::GATEWAY_CHAIN_ID.0);
+
+        let sender = Address::repeat_byte(0x11);
+        let msg = H256::repeat_byte(0x22);
+        let mut storage = pool.connection().await?;
+        store_l2_block(&mut storage, L2BlockNumber(1), &[]).await?;
+        let log = UserL2ToL1Log(L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block: 0,
+            sender: L1_MESSENGER_ADDRESS,
+            key: address_to_h256(&sender),
+            value: msg,
+        });
+        let location = IncludedTxLocation {
+            tx_hash: H256::repeat_byte(1),
+            tx_index_in_l2_block: 0,
+        };
+        storage
+            .events_dal()
+            .save_user_l2_to_l1_logs(L2BlockNumber(1), &[(location, vec![&log])])
+            .await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+
+        // The batch is not executed yet.
+        let proof = client
+            .get_l2_to_l1_msg_proof(L2BlockNumber(1), sender, msg, None)
+            .await?;
+        assert!(proof.is_none(), "{proof:?}");
+
+        let eth_tx_id = storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                0,
+                vec![],
+                AggregatedActionType::Execute,
+                Address::zero(),
+                None,
+                None,
+                None,
+                true,
+            )
+            .await?
+            .id;
+        storage
+            .eth_sender_dal()
+            .set_chain_id(eth_tx_id, Self::GATEWAY_CHAIN_ID.0)
+            .await?;
+        storage
+            .blocks_dal()
+            .set_eth_tx_id(
+                L1BatchNumber(1)..=L1BatchNumber(1),
+                eth_tx_id,
+                AggregatedActionType::Execute,
+            )
+            .await?;
+
+        // The batch is executed on Gateway, but its batch root wasn't processed yet.
+        let proof = client
+            .get_l2_to_l1_msg_proof(L2BlockNumber(1), sender, msg, None)
+            .await?;
+        assert!(proof.is_none(), "{proof:?}");
+
+        let batch_chain_path = BatchAndChainMerklePath {
+            batch_proof_len: 2,
+            proof: (0..7).map(H256::from_low_u64_be).collect(),
+        };
+        storage
+            .blocks_dal()
+            .set_batch_chain_merkle_path(L1BatchNumber(1), batch_chain_path.clone())
+            .await?;
+
+        let proof = client
+            .get_l2_to_l1_msg_proof(L2BlockNumber(1), sender, msg, None)
+            .await?
+            .context("no log proof")?;
+        assert_eq!(proof.id, 0);
+        let metadata = proof.proof[0];
+        assert_eq!(metadata[0], LOG_PROOF_SUPPORTED_METADATA_VERSION);
+        assert_eq!(metadata[2], 2);
+        assert_eq!(metadata[3], 0, "Gateway proofs must not be final");
+        let log_leaf_proof_len = usize::from(metadata[1]);
+        assert_eq!(
+            proof.proof.len(),
+            1 + log_leaf_proof_len + batch_chain_path.proof.len()
+        );
+        // The log leaf proof ends with the aggregation root of the batch.
+        assert_eq!(proof.proof[log_leaf_proof_len], H256::zero());
+        assert_eq!(
+            proof.proof[1 + log_leaf_proof_len..],
+            batch_chain_path.proof
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_log_proof_for_batch_executed_on_gateway() {
+    test_http_server(GatewayLogProofTest).await;
+}
+
 #[derive(Debug)]
 struct TransactionReceiptsTest;
 
@@ -1047,9 +1360,21 @@ impl HttpTest for RpcCallsTracingTest {
         assert_eq!(calls[0].error_code, Some(ErrorCode::MethodNotFound.code()));
         assert!(!calls[0].metadata.has_app_error);
 
-        ClientT::request::<serde_json::Value, _>(&client, "eth_getBlockByNumber", rpc_params![0])
-            .await
-            .unwrap_err();
+        let err = ClientT::request::<serde_json::Value, _>(
+            &client,
+            "eth_getBlockByNumber",
+            rpc_params![0, false],
+        )
+        .await
+        .unwrap_err();
+        let ClientError::Call(err) = err else {
+            panic!("Unexpected error: {err:?}");
+        };
+        assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+        assert_eq!(
+            err.message(),
+            "invalid argument 0: hex string without 0x prefix"
+        );
 
         let calls = self.tracer.recorded_calls().take();
         assert_eq!(calls.len(), 1);
@@ -1118,6 +1443,278 @@ async fn tracing_genesis_config() {
     test_http_server(GenesisConfigTest).await;
 }
 
+#[derive(Debug)]
+struct ChainCreationParamsTest {
+    snapshot_recovery: bool,
+}
+
+#[async_trait]
+impl HttpTest for ChainCreationParamsTest {
+    fn storage_initialization(&self) -> StorageInitialization {
+        if self.snapshot_recovery {
+            StorageInitialization::empty_recovery()
+        } else {
+            StorageInitialization::genesis()
+        }
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        if self.snapshot_recovery {
+            let error = client.get_chain_creation_params().await.unwrap_err();
+            assert_pruned_l1_batch_error(
+                &error,
+                StorageInitialization::SNAPSHOT_RECOVERY_BATCH + 1,
+            );
+            return Ok(());
+        }
+
+        let params = client.get_chain_creation_params().await?;
+        let genesis_config = client.genesis_config().await?;
+        assert_eq!(Some(params.genesis_root), genesis_config.genesis_root_hash);
+        assert_eq!(
+            Some(params.genesis_rollup_leaf_index),
+            genesis_config.rollup_last_leaf_index
+        );
+        assert_eq!(
+            Some(params.genesis_batch_commitment),
+            genesis_config.genesis_commitment
+        );
+        assert_eq!(
+            Some(params.protocol_version),
+            genesis_config.protocol_version
+        );
+        assert_eq!(
+            params.packed_protocol_version,
+            params.protocol_version.pack()
+        );
+        assert_eq!(Some(params.bootloader_hash), genesis_config.bootloader_hash);
+        assert_eq!(Some(params.default_aa_hash), genesis_config.default_aa_hash);
+        assert_eq!(params.evm_emulator_hash, genesis_config.evm_emulator_hash);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_chain_creation_params() {
+    test_http_server(ChainCreationParamsTest {
+        snapshot_recovery: false,
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn getting_chain_creation_params_after_snapshot_recovery() {
+    test_http_server(ChainCreationParamsTest {
+        snapshot_recovery: true,
+    })
+    .await;
+}
+
+#[derive(Debug)]
+struct EvmCompatibilityInfoTest {
+    evm_emulator: bool,
+}
+
+#[async_trait]
+impl HttpTest for EvmCompatibilityInfoTest {
+    fn storage_initialization(&self) -> StorageInitialization {
+        if self.evm_emulator {
+            StorageInitialization::genesis_with_evm()
+        } else {
+            StorageInitialization::genesis()
+        }
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let chain_id = client.chain_id().await?;
+        let net_version = NetNamespaceClient::version(client).await?;
+        assert_eq!(net_version, chain_id.as_u64().to_string());
+
+        let info = client.get_evm_compatibility_info().await?;
+        assert_eq!(info.chain_id, chain_id);
+        assert_eq!(info.net_version, net_version);
+        assert_eq!(info.protocol_version, ProtocolVersionId::latest());
+        assert!(info.evm_emulator_supported);
+        assert_eq!(info.evm_emulator_enabled, self.evm_emulator);
+        assert_eq!(info.evm_version.as_deref(), Some("cancun"));
+        let opcodes: Vec<_> = info
+            .opcode_deviations
+            .iter()
+            .map(|deviation| deviation.opcode.as_str())
+            .collect();
+        assert!(opcodes.contains(&"SELFDESTRUCT"), "{opcodes:?}");
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_evm_compatibility_info() {
+    test_http_server(EvmCompatibilityInfoTest {
+        evm_emulator: false,
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn getting_evm_compatibility_info_with_evm_emulator() {
+    test_http_server(EvmCompatibilityInfoTest { evm_emulator: true }).await;
+}
+
+#[derive(Debug)]
+struct SystemContractsTest;
+
+#[async_trait]
+impl HttpTest for SystemContractsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let info = client.get_system_contracts().await?;
+        assert_eq!(info.protocol_version, ProtocolVersionId::latest());
+        let base_system_contracts = pool
+            .connection()
+            .await?
+            .protocol_versions_dal()
+            .get_base_system_contract_hashes_by_version_id(info.protocol_version)
+            .await?
+            .context("no base system contracts")?;
+        assert_eq!(info.bootloader_code_hash, base_system_contracts.bootloader);
+        assert_eq!(info.default_aa_code_hash, base_system_contracts.default_aa);
+        assert_eq!(
+            info.evm_emulator_code_hash,
+            base_system_contracts.evm_emulator
+        );
+
+        let contracts: HashMap<_, _> = info
+            .contracts
+            .iter()
+            .map(|contract| (contract.address, contract))
+            .collect();
+        assert_eq!(contracts.len(), info.contracts.len());
+        assert!(!contracts.contains_key(&Address::zero()));
+        // All contracts are deployed at genesis.
+        for deployed in get_system_smart_contracts() {
+            let address = *deployed.account_id.address();
+            if address == Address::zero() || address == BOOTLOADER_ADDRESS {
+                continue;
+            }
+            let contract = contracts[&address];
+            let expected_hash = BytecodeHash::for_bytecode(&deployed.bytecode).value();
+            assert_eq!(contract.code_hash, Some(expected_hash), "{contract:?}");
+        }
+        assert_eq!(contracts[&L2_BRIDGEHUB_ADDRESS].name, "Bridgehub");
+        assert_eq!(
+            contracts[&L2_NATIVE_TOKEN_VAULT_ADDRESS].name,
+            "L2NativeTokenVault"
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_system_contracts() {
+    test_http_server(SystemContractsTest).await;
+}
+
+#[derive(Debug)]
+struct ProtocolVersionInfoTest;
+
+#[async_trait]
+impl HttpTest for ProtocolVersionInfoTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let info = client.get_protocol_version_info().await?;
+        assert_eq!(info.current.version.minor, ProtocolVersionId::latest());
+        assert!(info.scheduled.is_empty(), "{info:?}");
+        let base_system_contracts = pool
+            .connection()
+            .await?
+            .protocol_versions_dal()
+            .get_base_system_contract_hashes_by_version_id(ProtocolVersionId::latest())
+            .await?
+            .context("no base system contracts")?;
+        assert_eq!(
+            info.current.bootloader_code_hash,
+            base_system_contracts.bootloader
+        );
+        assert_eq!(
+            info.current.default_account_code_hash,
+            base_system_contracts.default_aa
+        );
+
+        let verifier_address = Address::repeat_byte(0x42);
+        let next_version = ProtocolVersion {
+            version: ProtocolSemanticVersion {
+                minor: ProtocolVersionId::next(),
+                patch: 0.into(),
+            },
+            timestamp: 1_000_000,
+            verifier_address: Some(verifier_address),
+            base_system_contracts_hashes: base_system_contracts,
+            ..ProtocolVersion::default()
+        };
+        pool.connection()
+            .await?
+            .protocol_versions_dal()
+            .save_protocol_version_with_tx(&next_version)
+            .await?;
+
+        let info = client.get_protocol_version_info().await?;
+        // The new version is not used until a new L1 batch is started.
+        assert_eq!(info.current.version.minor, ProtocolVersionId::latest());
+        assert_eq!(info.scheduled.len(), 1, "{info:?}");
+        let scheduled = &info.scheduled[0];
+        assert_eq!(scheduled.version, next_version.version);
+        assert_eq!(scheduled.activation_timestamp, 1_000_000);
+        assert_eq!(scheduled.verifier_address, Some(verifier_address));
+        assert_eq!(scheduled.l2_system_upgrade_tx_hash, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_protocol_version_info() {
+    test_http_server(ProtocolVersionInfoTest).await;
+}
+
+#[derive(Debug)]
+struct NodeVersionTest;
+
+#[async_trait]
+impl HttpTest for NodeVersionTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let version = client.get_node_version().await?;
+        assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+        assert!(version
+            .supported_protocol_versions
+            .contains(&(ProtocolVersionId::latest() as u16)));
+        // The test server doesn't have a Merkle tree API or mempool cache.
+        assert_eq!(version.api_features, ["filters"]);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_node_version() {
+    test_http_server(NodeVersionTest).await;
+}
+
 #[derive(Debug)]
 struct GetBytecodeTest;
 
@@ -2,6 +2,8 @@
 
 use std::collections::HashSet;
 
+use http::StatusCode;
+use zksync_object_store::MockObjectStore;
 use zksync_types::snapshots::SnapshotVersion;
 use zksync_web3_decl::namespaces::SnapshotsNamespaceClient;
 
@@ -109,3 +111,116 @@ async fn snapshot_with_some_chunks() {
 async fn snapshot_with_all_chunks() {
     test_http_server(SnapshotBasicsTest::new(0..SnapshotBasicsTest::CHUNK_COUNT)).await;
 }
+
+#[derive(Debug)]
+struct SnapshotDownloadUrlsTest;
+
+impl SnapshotDownloadUrlsTest {
+    const URL_TTL: Duration = Duration::from_secs(600);
+}
+
+#[async_trait]
+impl HttpTest for SnapshotDownloadUrlsTest {
+    fn snapshot_download_urls(&self) -> Option<(Arc<dyn ObjectStore>, Duration)> {
+        Some((MockObjectStore::arc(), Self::URL_TTL))
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await.unwrap();
+        store_l2_block(
+            &mut storage,
+            L2BlockNumber(1),
+            &[mock_execute_transaction(create_l2_transaction(1, 2).into())],
+        )
+        .await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        storage
+            .snapshots_dal()
+            .add_snapshot(
+                SnapshotVersion::Version0,
+                L1BatchNumber(1),
+                2,
+                "file:///factory_deps",
+            )
+            .await?;
+        for chunk_id in 0..2 {
+            let path = format!("file:///storage_logs/chunk{chunk_id}");
+            storage
+                .snapshots_dal()
+                .add_storage_logs_filepath_for_snapshot(L1BatchNumber(1), chunk_id, &path)
+                .await?;
+        }
+
+        let snapshot_header = client
+            .get_snapshot_by_l1_batch_number(L1BatchNumber(1))
+            .await?
+            .context("no snapshot for L1 batch #1")?;
+        // File paths must be retained for consumers not aware of signed URLs.
+        assert_eq!(
+            snapshot_header.factory_deps_filepath,
+            "file:///factory_deps"
+        );
+        assert_eq!(
+            snapshot_header.factory_deps_download_url.as_deref(),
+            Some("mock://storage_logs_snapshots/snapshot_l1_batch_1_factory_deps.proto.gzip?expires_in=600")
+        );
+
+        let chunk_urls: Vec<_> = snapshot_header
+            .storage_logs_chunks
+            .iter()
+            .map(|chunk| chunk.download_url.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            chunk_urls,
+            [
+                "mock://storage_logs_snapshots/snapshot_l1_batch_1_storage_logs_part_0000.proto.gzip?expires_in=600",
+                "mock://storage_logs_snapshots/snapshot_l1_batch_1_storage_logs_part_0001.proto.gzip?expires_in=600",
+            ]
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn snapshot_with_download_urls() {
+    test_http_server(SnapshotDownloadUrlsTest).await;
+}
+
+#[derive(Debug)]
+struct SnapshotsRateLimitingTest;
+
+#[async_trait]
+impl HttpTest for SnapshotsRateLimitingTest {
+    fn snapshots_requests_per_minute_limit(&self) -> Option<NonZeroU32> {
+        Some(NonZeroU32::new(2).unwrap())
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        client.get_all_snapshots().await?;
+        client.get_all_snapshots().await?;
+        let err = client.get_all_snapshots().await.unwrap_err();
+        if let ClientError::Call(error) = err {
+            assert_eq!(error.code() as u16, StatusCode::TOO_MANY_REQUESTS.as_u16());
+            assert_eq!(error.message(), "Too many requests");
+        } else {
+            panic!("Unexpected error returned: {err}");
+        }
+
+        // Methods from other namespaces are not limited.
+        client.get_block_number().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn snapshots_rate_limiting() {
+    test_http_server(SnapshotsRateLimitingTest).await;
+}
@@ -1,6 +1,6 @@
 //! Tests for the `unstable` Web3 namespace.
 
-use zksync_types::tee_types::TeeType;
+use zksync_types::{settlement::SettlementMode, tee_types::TeeType};
 use zksync_web3_decl::namespaces::UnstableNamespaceClient;
 
 use super::*;
@@ -62,3 +62,84 @@ impl HttpTest for GetTeeProofsTest {
 async fn get_tee_proofs() {
     test_http_server(GetTeeProofsTest::new()).await;
 }
+
+#[derive(Debug)]
+struct GatewayMigrationStatusTest {
+    settlement_layer_data: watch::Sender<Option<SettlementLayerData>>,
+}
+
+impl GatewayMigrationStatusTest {
+    fn new() -> Self {
+        Self {
+            settlement_layer_data: watch::channel(None).0,
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTest for GatewayMigrationStatusTest {
+    fn settlement_layer_data(&self) -> Option<watch::Receiver<Option<SettlementLayerData>>> {
+        Some(self.settlement_layer_data.subscribe())
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let status = client.gateway_migration_status().await?;
+        assert_eq!(status.settlement_mode, None);
+        assert_eq!(status.inflight_txs_count, 0);
+        assert_eq!(status.settlement_layer, None);
+        assert_eq!(status.migration_pending, None);
+
+        let l1_chain_id = SLChainId(9);
+        self.settlement_layer_data
+            .send_replace(Some(SettlementLayerData {
+                settlement_mode: SettlementMode::SettlesToL1,
+                sl_chain_id: l1_chain_id,
+                migration_pending: false,
+            }));
+
+        let status = client.gateway_migration_status().await?;
+        assert_eq!(status.settlement_mode, Some(SettlementMode::SettlesToL1));
+        assert_eq!(status.inflight_txs_count, 0);
+        assert_eq!(status.settlement_layer, Some(l1_chain_id));
+        assert_eq!(status.migration_pending, Some(false));
+
+        // Start settling to Gateway.
+        let mut storage = pool.connection().await?;
+        storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                0,
+                vec![],
+                AggregatedActionType::Execute,
+                Address::zero(),
+                None,
+                None,
+                None,
+                true,
+            )
+            .await?;
+        let gateway_chain_id = SLChainId(505);
+        self.settlement_layer_data
+            .send_replace(Some(SettlementLayerData {
+                settlement_mode: SettlementMode::Gateway,
+                sl_chain_id: gateway_chain_id,
+                migration_pending: true,
+            }));
+
+        let status = client.gateway_migration_status().await?;
+        assert_eq!(status.settlement_mode, Some(SettlementMode::Gateway));
+        assert_eq!(status.inflight_txs_count, 1);
+        assert_eq!(status.settlement_layer, Some(gateway_chain_id));
+        assert_eq!(status.migration_pending, Some(true));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_gateway_migration_status() {
+    test_http_server(GatewayMigrationStatusTest::new()).await;
+}
@@ -600,7 +600,9 @@ async fn send_raw_transaction_with_evm_emulator() {
 }
 
 #[derive(Debug)]
-struct SendTransactionWithDetailedOutputTest;
+struct SendTransactionWithDetailedOutputTest {
+    validation_only: bool,
+}
 
 impl SendTransactionWithDetailedOutputTest {
     fn storage_logs(&self) -> Vec<StorageLogWithPreviousValue> {
@@ -685,9 +687,14 @@ impl HttpTest for SendTransactionWithDetailedOutputTest {
 
         let (tx_bytes, tx_hash) = SendRawTransactionTest::transaction_bytes_and_hash(true);
         let send_result = client
-            .send_raw_transaction_with_detailed_output(tx_bytes.into())
+            .send_raw_transaction_with_detailed_output(tx_bytes.into(), Some(self.validation_only))
             .await?;
         assert_eq!(send_result.transaction_hash, tx_hash);
+        let stored_tx = storage
+            .transactions_dal()
+            .get_storage_tx_by_hash(tx_hash)
+            .await?;
+        assert_eq!(stored_tx.is_none(), self.validation_only);
 
         let expected_events = self.vm_events();
         assert_eq!(send_result.events.len(), expected_events.len());
@@ -711,9 +718,10 @@ impl HttpTest for SendTransactionWithDetailedOutputTest {
     }
 }
 
+#[test_casing(2, [false, true])]
 #[tokio::test]
-async fn send_raw_transaction_with_detailed_output() {
-    test_http_server(SendTransactionWithDetailedOutputTest).await;
+async fn send_raw_transaction_with_detailed_output(validation_only: bool) {
+    test_http_server(SendTransactionWithDetailedOutputTest { validation_only }).await;
 }
 
 #[derive(Debug, Default)]
@@ -998,7 +1006,7 @@ impl EstimateMethod {
             Self::ZksEstimateFee => client
                 .estimate_fee(req, None)
                 .await
-                .map(|fee| fee.gas_limit),
+                .map(|estimate| estimate.fee.gas_limit),
             Self::ZksEstimateGasL1ToL2 => client.estimate_gas_l1_to_l2(req, None).await,
         }
     }
@@ -1123,6 +1131,121 @@ async fn estimate_gas_after_snapshot_recovery(method: EstimateMethod) {
     test_http_server(EstimateGasTest::new(method, true)).await;
 }
 
+#[derive(Debug)]
+struct SimulateL1ToL2TransactionsTest;
+
+impl SimulateL1ToL2TransactionsTest {
+    const GAS_LIMIT_THRESHOLD: u64 = 50_000;
+}
+
+#[async_trait]
+impl HttpTest for SimulateL1ToL2TransactionsTest {
+    fn transaction_executor(&self) -> MockOneshotExecutor {
+        let mut tx_executor = MockOneshotExecutor::default();
+        tx_executor.set_tx_responses(|tx, _| {
+            if tx.execute.calldata() == b"revert" {
+                ExecutionResult::Revert {
+                    output: VmRevertReason::General {
+                        msg: "oops".to_owned(),
+                        data: vec![1, 2, 3],
+                    },
+                }
+            } else if tx.gas_limit() >= U256::from(Self::GAS_LIMIT_THRESHOLD) {
+                ExecutionResult::Success { output: vec![] }
+            } else {
+                ExecutionResult::Revert {
+                    output: VmRevertReason::VmError,
+                }
+            }
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let call_request = CallRequest {
+            gas: None,
+            ..create_l2_transaction(10, 100).into()
+        };
+        let low_gas_request = CallRequest {
+            gas: Some(1_000.into()),
+            ..call_request.clone()
+        };
+        let reverting_request = CallRequest {
+            data: Some(b"revert".to_vec().into()),
+            ..call_request.clone()
+        };
+        let reverting_request_with_gas = CallRequest {
+            gas: Some(Self::GAS_LIMIT_THRESHOLD.into()),
+            ..reverting_request.clone()
+        };
+        let results = client
+            .simulate_l1_to_l2_transactions(
+                vec![
+                    call_request.clone(),
+                    low_gas_request,
+                    reverting_request,
+                    reverting_request_with_gas,
+                ],
+                None,
+            )
+            .await?;
+        assert_eq!(results.len(), 4);
+
+        let threshold = U256::from(Self::GAS_LIMIT_THRESHOLD);
+        assert!(results[0].success, "{results:?}");
+        let gas_limit = results[0].gas_limit.unwrap();
+        assert!(
+            gas_limit >= threshold && gas_limit < threshold * 2,
+            "{gas_limit}"
+        );
+        let gas_used = results[0].gas_used.unwrap();
+        assert!(gas_used <= gas_limit, "{gas_used}");
+        assert_eq!(results[0].gas_per_pubdata, 100.into());
+
+        // The transaction is executed with the provided gas limit rather than compared to the estimate.
+        assert!(!results[1].success, "{results:?}");
+        assert_eq!(results[1].gas_limit, Some(1_000.into()));
+        assert!(results[1].gas_used.is_some());
+        let error = results[1].error.as_ref().unwrap();
+        assert!(error.contains("VM Error"), "{error}");
+
+        assert!(!results[2].success, "{results:?}");
+        assert_eq!(results[2].gas_limit, None);
+        assert_eq!(results[2].gas_used, None);
+        let error = results[2].error.as_ref().unwrap();
+        assert!(error.contains("oops"), "{error}");
+        assert!(results[2].revert_data.is_some());
+
+        assert!(!results[3].success, "{results:?}");
+        assert_eq!(results[3].gas_limit, Some(threshold));
+        assert!(results[3].gas_used.is_some());
+        let error = results[3].error.as_ref().unwrap();
+        assert!(error.contains("oops"), "{error}");
+        assert!(results[3].revert_data.is_some());
+
+        let too_many_requests = vec![call_request; 100];
+        let err = client
+            .simulate_l1_to_l2_transactions(too_many_requests, None)
+            .await
+            .unwrap_err();
+        if let ClientError::Call(err) = err {
+            assert_eq!(err.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {err:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn simulating_l1_to_l2_transactions() {
+    test_http_server(SimulateL1ToL2TransactionsTest).await;
+}
+
 #[derive(Debug)]
 struct EstimateGasWithStateOverrideTest {
     inner: EstimateGasTest,
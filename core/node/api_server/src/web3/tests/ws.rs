@@ -160,6 +160,10 @@ trait WsTest: Send + Sync {
     fn websocket_requests_per_minute_limit(&self) -> Option<NonZeroU32> {
         None
     }
+
+    fn batch_request_size_limit(&self) -> Option<usize> {
+        None
+    }
 }
 
 async fn test_ws_server(test: impl WsTest) {
@@ -178,7 +182,11 @@ async fn test_ws_server(test: impl WsTest) {
     drop(storage);
 
     let (stop_sender, stop_receiver) = watch::channel(false);
-    let (mut server_handles, pub_sub_events) = TestServerBuilder::new(pool.clone(), api_config)
+    let mut server_builder = TestServerBuilder::new(pool.clone(), api_config);
+    if let Some(limit) = test.batch_request_size_limit() {
+        server_builder = server_builder.with_batch_request_size_limit(limit);
+    }
+    let (mut server_handles, pub_sub_events) = server_builder
         .build_ws(test.websocket_requests_per_minute_limit(), stop_receiver)
         .await;
 
@@ -736,3 +744,54 @@ impl WsTest for BatchGetsRateLimitedTest {
 async fn batch_rate_limiting() {
     test_ws_server(BatchGetsRateLimitedTest).await;
 }
+
+/// Checks that the batch request is rejected by the server.
+async fn assert_batch_rejected(client: &WsClient<L2>, batch_size: usize) {
+    let mut batch = BatchRequestBuilder::new();
+    for _ in 0..batch_size {
+        batch.insert("eth_chainId", rpc_params![]).unwrap();
+    }
+    // Depending on the error, the client either fails the entire batch, or returns an error for each request.
+    if let Ok(response) = client.batch_request::<U64>(batch).await {
+        assert_eq!(response.num_successful_calls(), 0, "{response:?}");
+    }
+}
+
+#[derive(Debug)]
+struct BatchRequestSizeLimitTest(usize);
+
+#[async_trait]
+impl WsTest for BatchRequestSizeLimitTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        _pool: &ConnectionPool<Core>,
+        _pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        let limit = self.0;
+        if limit > 0 {
+            let mut batch = BatchRequestBuilder::new();
+            for _ in 0..limit {
+                batch.insert("eth_chainId", rpc_params![]).unwrap();
+            }
+            let response = client.batch_request::<U64>(batch).await?;
+            assert_eq!(response.num_successful_calls(), limit);
+        }
+        assert_batch_rejected(client, limit + 1).await;
+        Ok(())
+    }
+
+    fn batch_request_size_limit(&self) -> Option<usize> {
+        Some(self.0)
+    }
+}
+
+#[tokio::test]
+async fn batch_request_size_limit() {
+    test_ws_server(BatchRequestSizeLimitTest(2)).await;
+}
+
+#[tokio::test]
+async fn batch_requests_can_be_disabled() {
+    test_ws_server(BatchRequestSizeLimitTest(0)).await;
+}
@@ -15,7 +15,11 @@ use zksync_web3_decl::{
     namespaces::{EnNamespaceClient as _, EthNamespaceClient as _},
 };
 
-use super::{config, storage::Store, ConsensusConfig, ConsensusSecrets};
+use super::{
+    config,
+    storage::{BlockArchive, Store},
+    ConsensusConfig, ConsensusSecrets,
+};
 use crate::{
     metrics::METRICS,
     registry,
@@ -98,6 +102,15 @@ impl EN {
                 }
             });
 
+            let archive = match &cfg.block_archive {
+                Some(cfg) => Some(Arc::new(
+                    BlockArchive::new(cfg.clone())
+                        .await
+                        .context("BlockArchive::new()")?,
+                )),
+                None => None,
+            };
+
             // Run consensus component.
             // External nodes have a payload queue which they use to fetch data from the main node.
             let (store, runner) = Store::new(
@@ -105,6 +118,7 @@ impl EN {
                 self.pool.clone(),
                 Some(payload_queue),
                 Some(self.client.clone()),
+                archive,
             )
             .await
             .wrap("Store::new()")?;
@@ -7,6 +7,10 @@ pub(crate) struct Metrics {
     /// It is used only as a fallback when the p2p syncing is disabled or falling behind.
     /// so it shouldn't be increasing under normal circumstances if p2p syncing is enabled.
     pub fetch_block: vise::Counter,
+    /// Number of blocks that has been moved to the object store archive.
+    pub archived_blocks: vise::Counter,
+    /// Number of blocks that has been fetched from the object store archive.
+    pub archive_fetch_block: vise::Counter,
 }
 
 #[vise::register]
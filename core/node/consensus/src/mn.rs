@@ -10,7 +10,7 @@ use zksync_dal::consensus_dal;
 
 use crate::{
     config, registry,
-    storage::{ConnectionPool, Store},
+    storage::{BlockArchive, ConnectionPool, Store},
 };
 
 /// Task running a consensus validator for the main node.
@@ -42,8 +42,17 @@ pub async fn run_main_node(
                 .wrap("adjust_global_config()")?;
         }
 
+        let archive = match &cfg.block_archive {
+            Some(cfg) => Some(Arc::new(
+                BlockArchive::new(cfg.clone())
+                    .await
+                    .context("BlockArchive::new()")?,
+            )),
+            None => None,
+        };
+
         // The main node doesn't have a payload queue as it produces all the L2 blocks itself.
-        let (store, runner) = Store::new(ctx, pool.clone(), None, None)
+        let (store, runner) = Store::new(ctx, pool.clone(), None, None, archive)
             .await
             .wrap("Store::new()")?;
         s.spawn_bg(async { Ok(runner.run(ctx).await.context("Store::runner()")?) });
@@ -0,0 +1,136 @@
+//! Archival of old certified blocks to the object store.
+
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_concurrency::{ctx, error::Wrap as _};
+use zksync_config::configs::consensus::BlockArchiveConfig;
+use zksync_consensus_roles::validator;
+use zksync_object_store::{
+    Bucket, ObjectStore, ObjectStoreError, ObjectStoreFactory, StoredObject, _reexports::BoxedError,
+};
+
+use super::ConnectionPool;
+use crate::metrics::METRICS;
+
+/// Number of blocks archived in a single iteration.
+const ARCHIVE_BATCH_SIZE: usize = 100;
+
+/// Certified block as stored in the archive.
+#[derive(Debug)]
+struct ArchivedBlock(validator::FinalBlock);
+
+impl StoredObject for ArchivedBlock {
+    const BUCKET: Bucket = Bucket::ConsensusBlocks;
+    type Key<'a> = validator::BlockNumber;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        format!("block_{}.pb", key.0)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
+        Ok(zksync_protobuf::encode(&self.0))
+    }
+
+    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
+        zksync_protobuf::decode(&bytes)
+            .map(Self)
+            .context("decode()")
+            .map_err(From::from)
+    }
+}
+
+/// Object store archive of certified blocks.
+///
+/// Blocks older than the configured retention are moved from Postgres to the object store
+/// by [`BlockArchive::run()`]; Postgres keeps only an index of the archived blocks.
+/// Archived blocks are fetched from the object store on demand.
+#[derive(Debug)]
+pub(crate) struct BlockArchive {
+    store: Arc<dyn ObjectStore>,
+    cfg: BlockArchiveConfig,
+}
+
+impl BlockArchive {
+    /// Creates an archive backed by the object store from the config.
+    pub(crate) async fn new(cfg: BlockArchiveConfig) -> anyhow::Result<Self> {
+        let store = ObjectStoreFactory::new(cfg.object_store.clone())
+            .create_store()
+            .await
+            .context("create_store()")?;
+        Ok(Self { store, cfg })
+    }
+
+    /// Creates an archive backed by the provided object store.
+    #[cfg(test)]
+    pub(crate) fn with_store(store: Arc<dyn ObjectStore>, cfg: BlockArchiveConfig) -> Self {
+        Self { store, cfg }
+    }
+
+    /// Fetches an archived block. Returns `None` if the block is not in the archive.
+    pub(crate) async fn fetch(
+        &self,
+        ctx: &ctx::Ctx,
+        number: validator::BlockNumber,
+    ) -> ctx::Result<Option<validator::FinalBlock>> {
+        match ctx.wait(self.store.get::<ArchivedBlock>(number)).await? {
+            Ok(block) => {
+                METRICS.archive_fetch_block.inc();
+                Ok(Some(block.0))
+            }
+            Err(ObjectStoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(anyhow::Error::from(err).context("get()").into()),
+        }
+    }
+
+    /// Archives at most `ARCHIVE_BATCH_SIZE` blocks outside of the retention window.
+    /// Returns the number of archived blocks.
+    async fn archive_batch(&self, ctx: &ctx::Ctx, pool: &ConnectionPool) -> ctx::Result<usize> {
+        let mut conn = pool.connection(ctx).await.wrap("connection()")?;
+        let Some(last) = conn
+            .last_block_certificate_number(ctx)
+            .await
+            .wrap("last_block_certificate_number()")?
+        else {
+            return Ok(0);
+        };
+        let before = validator::BlockNumber(last.0.saturating_sub(self.cfg.retention_blocks));
+        let numbers = conn
+            .blocks_to_archive(ctx, before, ARCHIVE_BATCH_SIZE)
+            .await
+            .wrap("blocks_to_archive()")?;
+
+        let mut archived = 0;
+        for number in numbers {
+            // The block might have been pruned in the meantime.
+            let Some(validator::Block::Final(block)) =
+                conn.block(ctx, number).await.wrap("block()")?
+            else {
+                continue;
+            };
+            ctx.wait(self.store.put(number, &ArchivedBlock(block)))
+                .await?
+                .context("put()")?;
+            // Certificate is removed from Postgres only after the block is safely archived.
+            conn.mark_block_archived(ctx, number)
+                .await
+                .wrap("mark_block_archived()")?;
+            METRICS.archived_blocks.inc();
+            archived += 1;
+        }
+        Ok(archived)
+    }
+
+    /// Periodically moves certified blocks outside of the retention window to the archive.
+    pub(crate) async fn run(&self, ctx: &ctx::Ctx, pool: &ConnectionPool) -> ctx::Result<()> {
+        loop {
+            let archived = self.archive_batch(ctx, pool).await?;
+            if archived > 0 {
+                tracing::debug!("archived {archived} consensus blocks");
+            }
+            if archived < ARCHIVE_BATCH_SIZE {
+                ctx.sleep(self.cfg.interval()).await?;
+            }
+        }
+    }
+}
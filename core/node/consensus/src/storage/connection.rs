@@ -151,6 +151,50 @@ impl<'a> Connection<'a> {
             .await??)
     }
 
+    /// Wrapper for `consensus_dal().last_block_certificate_number()`.
+    pub async fn last_block_certificate_number(
+        &mut self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<validator::BlockNumber>> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().last_block_certificate_number())
+            .await??)
+    }
+
+    /// Wrapper for `consensus_dal().blocks_to_archive()`.
+    pub async fn blocks_to_archive(
+        &mut self,
+        ctx: &ctx::Ctx,
+        before: validator::BlockNumber,
+        limit: usize,
+    ) -> ctx::Result<Vec<validator::BlockNumber>> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().blocks_to_archive(before, limit))
+            .await??)
+    }
+
+    /// Wrapper for `consensus_dal().mark_block_archived()`.
+    pub async fn mark_block_archived(
+        &mut self,
+        ctx: &ctx::Ctx,
+        number: validator::BlockNumber,
+    ) -> ctx::Result<()> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().mark_block_archived(number))
+            .await??)
+    }
+
+    /// Wrapper for `consensus_dal().is_block_archived()`.
+    pub async fn is_block_archived(
+        &mut self,
+        ctx: &ctx::Ctx,
+        number: validator::BlockNumber,
+    ) -> ctx::Result<bool> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().is_block_archived(number))
+            .await??)
+    }
+
     /// Wrapper for `consensus_dal().insert_batch_certificate()`,
     /// which additionally verifies that the batch hash matches the stored batch.
     #[tracing::instrument(skip_all, fields(l1_batch = %cert.message.number))]
@@ -315,6 +359,15 @@ impl<'a> Connection<'a> {
             ));
         }
 
+        // Certificates of archived blocks are only available in the archive.
+        if self
+            .is_block_archived(ctx, number)
+            .await
+            .wrap("is_block_archived()")?
+        {
+            return Ok(None);
+        }
+
         Ok(Some(
             validator::PreGenesisBlock {
                 number,
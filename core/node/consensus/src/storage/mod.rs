@@ -10,9 +10,11 @@ use zksync_node_sync::{
 };
 use zksync_state_keeper::io::common::IoCursor;
 
+mod archive;
 mod connection;
 mod store;
 
+pub(crate) use archive::*;
 pub(crate) use connection::*;
 pub(crate) use store::*;
 
@@ -14,7 +14,7 @@ use zksync_web3_decl::{
     namespaces::EnNamespaceClient as _,
 };
 
-use super::{Connection, PayloadQueue};
+use super::{BlockArchive, Connection, PayloadQueue};
 use crate::storage::{ConnectionPool, InsertCertificateError};
 
 fn to_fetched_block(
@@ -65,6 +65,8 @@ pub(crate) struct Store {
     blocks_persisted: sync::watch::Receiver<storage::BlockStoreState>,
     /// Main node client. None if this node is the main node.
     client: Option<Box<DynClient<L2>>>,
+    /// Object store archive of old certified blocks. None if archival is disabled.
+    archive: Option<Arc<BlockArchive>>,
 }
 
 struct PersistedBlockState(sync::watch::Sender<storage::BlockStoreState>);
@@ -74,6 +76,7 @@ pub struct StoreRunner {
     pool: ConnectionPool,
     blocks_persisted: PersistedBlockState,
     block_certificates: ctx::channel::UnboundedReceiver<validator::CommitQC>,
+    archive: Option<Arc<BlockArchive>>,
 }
 
 impl Store {
@@ -82,6 +85,7 @@ impl Store {
         pool: ConnectionPool,
         payload_queue: Option<PayloadQueue>,
         client: Option<Box<DynClient<L2>>>,
+        archive: Option<Arc<BlockArchive>>,
     ) -> ctx::Result<(Store, StoreRunner)> {
         let mut conn = pool.connection(ctx).await.wrap("connection()")?;
 
@@ -99,11 +103,13 @@ impl Store {
                 block_payloads: Arc::new(sync::Mutex::new(payload_queue)),
                 blocks_persisted: blocks_persisted.subscribe(),
                 client,
+                archive: archive.clone(),
             },
             StoreRunner {
                 pool,
                 blocks_persisted: PersistedBlockState(blocks_persisted),
                 block_certificates: block_certs_recv,
+                archive,
             },
         ))
     }
@@ -182,6 +188,7 @@ impl StoreRunner {
             pool,
             blocks_persisted,
             mut block_certificates,
+            archive,
         } = self;
 
         let res = scope::run!(ctx, |ctx, s| async {
@@ -212,6 +219,14 @@ impl StoreRunner {
                 }
             });
 
+            if let Some(archive) = &archive {
+                // Loop moving old certified blocks to the archive.
+                s.spawn::<()>(async {
+                    archive.run(ctx, &pool).await?;
+                    Ok(())
+                });
+            }
+
             #[tracing::instrument(skip_all)]
             async fn insert_block_certificates_iteration(
                 ctx: &ctx::Ctx,
@@ -301,12 +316,18 @@ impl storage::PersistentBlockStore for Store {
         ctx: &ctx::Ctx,
         number: validator::BlockNumber,
     ) -> ctx::Result<validator::Block> {
-        Ok(self
-            .conn(ctx)
-            .await?
-            .block(ctx, number)
-            .await?
-            .context("not found")?)
+        if let Some(block) = self.conn(ctx).await?.block(ctx, number).await? {
+            return Ok(block);
+        }
+        let Some(archive) = &self.archive else {
+            return Err(anyhow::format_err!("not found").into());
+        };
+        Ok(archive
+            .fetch(ctx, number)
+            .await
+            .wrap("archive.fetch()")?
+            .context("not found")?
+            .into())
     }
 
     async fn verify_pregenesis_block(
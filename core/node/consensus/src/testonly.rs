@@ -171,6 +171,7 @@ fn make_config(
         genesis_spec,
         rpc: None,
         debug_page_addr: None,
+        block_archive: None,
     }
 }
 
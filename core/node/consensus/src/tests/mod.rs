@@ -1,9 +1,14 @@
+use std::sync::Arc;
+
 use anyhow::Context as _;
 use rand::Rng as _;
 use test_casing::{test_casing, Product};
 use tracing::Instrument as _;
 use zksync_concurrency::{ctx, error::Wrap as _, scope, time};
-use zksync_config::configs::consensus as config;
+use zksync_config::{
+    configs::{consensus as config, object_store::ObjectStoreMode},
+    ObjectStoreConfig,
+};
 use zksync_consensus_crypto::TextFmt as _;
 use zksync_consensus_roles::{
     node, validator,
@@ -11,6 +16,7 @@ use zksync_consensus_roles::{
 };
 use zksync_consensus_storage::{BlockStore, PersistentBlockStore};
 use zksync_dal::consensus_dal;
+use zksync_object_store::MockObjectStore;
 use zksync_test_contracts::Account;
 use zksync_types::ProtocolVersionId;
 use zksync_web3_decl::namespaces::EnNamespaceClient as _;
@@ -18,7 +24,7 @@ use zksync_web3_decl::namespaces::EnNamespaceClient as _;
 use crate::{
     en::FALLBACK_FETCHER_THRESHOLD,
     mn::run_main_node,
-    storage::{ConnectionPool, Store},
+    storage::{BlockArchive, ConnectionPool, Store},
     testonly,
 };
 
@@ -87,6 +93,7 @@ async fn test_verify_pregenesis_block(version: ProtocolVersionId) {
             pool.clone(),
             None,
             Some(sk.connect(ctx).await.unwrap()),
+            None,
         )
         .await
         .unwrap();
@@ -170,7 +177,9 @@ async fn test_validator_block_store(version: ProtocolVersionId) {
     // Insert blocks one by one and check the storage state.
     for (i, block) in want.iter().enumerate() {
         scope::run!(ctx, |ctx, s| async {
-            let (store, runner) = Store::new(ctx, pool.clone(), None, None).await.unwrap();
+            let (store, runner) = Store::new(ctx, pool.clone(), None, None, None)
+                .await
+                .unwrap();
             s.spawn_bg(runner.run(ctx));
             let (block_store, runner) =
                 BlockStore::new(ctx, Box::new(store.clone())).await.unwrap();
@@ -189,6 +198,117 @@ async fn test_validator_block_store(version: ProtocolVersionId) {
     }
 }
 
+#[test_casing(2, VERSIONS)]
+#[tokio::test]
+async fn test_validator_block_store_archive(version: ProtocolVersionId) {
+    const RETENTION_BLOCKS: u64 = 3;
+
+    zksync_concurrency::testonly::abort_on_panic();
+    let ctx = &ctx::test_root(&ctx::RealClock);
+    let rng = &mut ctx.rng();
+    let pool = ConnectionPool::test(false, version).await;
+    let account = &mut Account::random();
+    let archive = Arc::new(BlockArchive::with_store(
+        MockObjectStore::arc(),
+        config::BlockArchiveConfig {
+            retention_blocks: RETENTION_BLOCKS,
+            interval: Some(time::Duration::milliseconds(50)),
+            object_store: ObjectStoreConfig {
+                mode: ObjectStoreMode::FileBacked {
+                    file_backed_base_path: "artifacts".to_owned(),
+                },
+                max_retries: 1,
+                local_mirror_path: None,
+                write_versioned_envelopes: false,
+            },
+        },
+    ));
+
+    let want = scope::run!(ctx, |ctx, s| async {
+        let (mut sk, runner) = testonly::StateKeeper::new(ctx, pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx));
+        sk.push_random_blocks(rng, account, 10).await;
+        pool.wait_for_payload(ctx, sk.last_block()).await?;
+        let mut setup = SetupSpec::new(rng, 3);
+        setup.first_block = validator::BlockNumber(0);
+        setup.first_pregenesis_block = setup.first_block;
+        let mut setup = Setup::from_spec(rng, setup);
+        let mut conn = pool.connection(ctx).await.wrap("connection()")?;
+        conn.try_update_global_config(
+            ctx,
+            &consensus_dal::GlobalConfig {
+                genesis: setup.genesis.clone(),
+                registry_address: None,
+                seed_peers: [].into(),
+            },
+        )
+        .await
+        .wrap("try_update_global_config()")?;
+        for i in setup.genesis.first_block.0..sk.last_block().next().0 {
+            let i = validator::BlockNumber(i);
+            let payload = conn
+                .payload(ctx, i)
+                .await
+                .wrap(i)?
+                .with_context(|| format!("payload for {i:?} not found"))?
+                .encode();
+            setup.push_block(payload);
+        }
+        Ok(setup.blocks.clone())
+    })
+    .await
+    .unwrap();
+    let last = want.last().unwrap().number();
+    let archived = validator::BlockNumber(last.0 - RETENTION_BLOCKS);
+
+    scope::run!(ctx, |ctx, s| async {
+        let (store, runner) = Store::new(ctx, pool.clone(), None, None, Some(archive.clone()))
+            .await
+            .unwrap();
+        s.spawn_bg(runner.run(ctx));
+        let (block_store, runner) = BlockStore::new(ctx, Box::new(store.clone())).await.unwrap();
+        s.spawn_bg(runner.run(ctx));
+        for block in &want {
+            block_store.queue_block(ctx, block.clone()).await.unwrap();
+        }
+        block_store.wait_until_persisted(ctx, last).await.unwrap();
+
+        tracing::info!("Wait for the old blocks to be archived.");
+        loop {
+            let mut conn = pool.connection(ctx).await.wrap("connection()")?;
+            if conn
+                .is_block_archived(ctx, archived.prev().unwrap())
+                .await
+                .wrap("is_block_archived()")?
+            {
+                break;
+            }
+            ctx.sleep(POLL_INTERVAL).await?;
+        }
+
+        tracing::info!("Archived blocks should be removed from Postgres, but still be served.");
+        let mut conn = pool.connection(ctx).await.wrap("connection()")?;
+        for block in &want {
+            let number = block.number();
+            let cert = conn
+                .block_certificate(ctx, number)
+                .await
+                .wrap("block_certificate()")?;
+            assert_eq!(cert.is_none(), number < archived, "{number:?}");
+            assert_eq!(*block, store.block(ctx, number).await.unwrap());
+        }
+        assert_eq!(
+            Some(last),
+            conn.last_block_certificate_number(ctx)
+                .await
+                .wrap("last_block_certificate_number()")?
+        );
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
 // In the current implementation, consensus certificates are created asynchronously
 // for the L2 blocks constructed by the StateKeeper. This means that consensus actor
 // is effectively just back filling the consensus certificates for the L2 blocks in storage.
@@ -0,0 +1,199 @@
+//! Admin API over the verification request queue. Since it allows modifying the queue, it must not be exposed publicly.
+
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    Json,
+};
+use serde::Deserialize;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::contract_verification::api::{
+    PrioritizeVerificationRequests, VerificationIncomingRequest, VerificationRequestDetails,
+    VerificationRequestSummary,
+};
+
+use crate::{
+    api_decl::RestApi,
+    api_impl::{ApiError, ApiResult},
+    metrics::METRICS,
+};
+
+/// Header identifying the source of a verification request, e.g. an explorer backfill job.
+/// Only honored by the admin API, since the public API cannot authenticate requesters.
+const REQUESTER_HEADER: &str = "x-requester";
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RequestsQuery {
+    /// Status of the returned requests, e.g. `queued` or `failed`. If not specified, requests with any status are returned.
+    status: Option<String>,
+    limit: Option<usize>,
+}
+
+impl RequestsQuery {
+    const DEFAULT_LIMIT: usize = 100;
+    const MAX_LIMIT: usize = 1_000;
+
+    fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .min(Self::MAX_LIMIT)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AdminApi {
+    connection_pool: ConnectionPool<Core>,
+    api: RestApi,
+    /// Requesters whose verification requests are processed in the priority lane.
+    priority_requesters: HashSet<String>,
+}
+
+impl AdminApi {
+    pub fn new(api: RestApi) -> Self {
+        Self {
+            connection_pool: api.master_connection_pool.clone(),
+            api,
+            priority_requesters: HashSet::new(),
+        }
+    }
+
+    pub fn with_priority_requesters(
+        mut self,
+        requesters: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.priority_requesters = requesters.into_iter().collect();
+        self
+    }
+
+    pub fn into_router(self) -> axum::Router<()> {
+        axum::Router::new()
+            .route(
+                "/contract_verification",
+                axum::routing::post(Self::verification),
+            )
+            .route("/verification_requests", axum::routing::get(Self::requests))
+            .route(
+                "/verification_requests/prioritize",
+                axum::routing::post(Self::prioritize_requests),
+            )
+            .route(
+                "/verification_requests/:id",
+                axum::routing::get(Self::request_details),
+            )
+            .route(
+                "/verification_requests/:id/requeue",
+                axum::routing::post(Self::requeue_request),
+            )
+            .with_state(Arc::new(self))
+    }
+
+    /// Same as the public verification endpoint, but records the requester (as identified by the `X-Requester` header).
+    /// Requests from trusted requesters are put into the priority lane.
+    #[tracing::instrument(skip(self_, headers, request))]
+    async fn verification(
+        State(self_): State<Arc<Self>>,
+        headers: HeaderMap,
+        Json(request): Json<VerificationIncomingRequest>,
+    ) -> ApiResult<usize> {
+        let method_latency = METRICS.call[&"contract_verification_admin"].start();
+        let requester = headers
+            .get(REQUESTER_HEADER)
+            .and_then(|value| value.to_str().ok());
+        let priority_lane = requester.is_some_and(|name| self_.priority_requesters.contains(name));
+        let request_id = self_
+            .api
+            .add_verification_request(&request, requester, priority_lane)
+            .await?;
+        method_latency.observe();
+        Ok(Json(request_id))
+    }
+
+    /// Lists verification requests in the order they are picked up by the verifier.
+    #[tracing::instrument(skip(self_))]
+    async fn requests(
+        State(self_): State<Arc<Self>>,
+        Query(query): Query<RequestsQuery>,
+    ) -> ApiResult<Vec<VerificationRequestSummary>> {
+        let requests = self_
+            .connection_pool
+            .connection_tagged("contract_verification_admin")
+            .await?
+            .contract_verification_dal()
+            .get_verification_requests(query.status.as_deref(), query.limit())
+            .await?;
+        Ok(Json(requests))
+    }
+
+    #[tracing::instrument(skip(self_))]
+    async fn request_details(
+        State(self_): State<Arc<Self>>,
+        id: Path<usize>,
+    ) -> ApiResult<VerificationRequestDetails> {
+        let details = self_
+            .connection_pool
+            .connection_tagged("contract_verification_admin")
+            .await?
+            .contract_verification_dal()
+            .get_verification_request_details(*id)
+            .await?
+            .ok_or(ApiError::RequestNotFound)?;
+        Ok(Json(details))
+    }
+
+    /// Puts a failed or stuck request back into the queue.
+    #[tracing::instrument(skip(self_))]
+    async fn requeue_request(
+        State(self_): State<Arc<Self>>,
+        id: Path<usize>,
+    ) -> ApiResult<VerificationRequestDetails> {
+        let mut storage = self_
+            .connection_pool
+            .connection_tagged("contract_verification_admin")
+            .await?;
+        let mut dal = storage.contract_verification_dal();
+        if !dal.requeue_verification_request(*id).await? {
+            return Err(match dal.get_verification_request_details(*id).await? {
+                Some(_) => ApiError::RequestNotRequeueable,
+                None => ApiError::RequestNotFound,
+            });
+        }
+        tracing::info!("Re-queued verification request #{}", *id);
+
+        let details = dal
+            .get_verification_request_details(*id)
+            .await?
+            .ok_or(ApiError::RequestNotFound)?;
+        Ok(Json(details))
+    }
+
+    /// Changes the priority of queued requests for the specified contract and / or requester.
+    /// Returns the number of affected requests.
+    #[tracing::instrument(skip(self_))]
+    async fn prioritize_requests(
+        State(self_): State<Arc<Self>>,
+        Json(request): Json<PrioritizeVerificationRequests>,
+    ) -> ApiResult<usize> {
+        if request.address.is_none() && request.requester.is_none() {
+            return Err(ApiError::MissingPriorityFilter);
+        }
+
+        let updated_count = self_
+            .connection_pool
+            .connection_tagged("contract_verification_admin")
+            .await?
+            .contract_verification_dal()
+            .set_queued_verification_requests_priority(
+                request.address,
+                request.requester.as_deref(),
+                request.priority,
+            )
+            .await?;
+        tracing::info!(
+            "Set priority {} for {updated_count} queued verification request(s) matching {request:?}",
+            request.priority
+        );
+        Ok(Json(updated_count))
+    }
+}
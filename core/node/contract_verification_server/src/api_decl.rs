@@ -55,6 +55,10 @@ impl RestApi {
                 "/contract_verification/info/:address",
                 axum::routing::get(Self::verification_info),
             )
+            .route(
+                "/contract_verification/similar_match/:address",
+                axum::routing::get(Self::similar_match),
+            )
             .layer(CorsLayer::permissive())
             .with_state(Arc::new(self))
     }
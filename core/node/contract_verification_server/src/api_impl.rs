@@ -12,8 +12,9 @@ use zksync_types::{
     bytecode::{trim_bytecode, BytecodeHash, BytecodeMarker},
     contract_verification::{
         api::{
-            CompilerVersions, SourceCodeData, VerificationIncomingRequest, VerificationInfo,
-            VerificationProblem, VerificationRequestStatus,
+            CompilerVersions, SimilarMatch, SimilarMatchStatus, SourceCodeData,
+            VerificationIncomingRequest, VerificationInfo, VerificationProblem,
+            VerificationRequestStatus,
         },
         contract_identifier::ContractIdentifier,
     },
@@ -33,6 +34,8 @@ pub(crate) enum ApiError {
     VerificationInfoNotFound,
     AlreadyVerified,
     ActiveRequestExists(usize),
+    RequestNotRequeueable,
+    MissingPriorityFilter,
     Internal(anyhow::Error),
 }
 
@@ -64,6 +67,12 @@ impl ApiError {
             Self::ActiveRequestExists(id) => {
                 format!("active request for this contract already exists, ID: {id}")
             }
+            Self::RequestNotRequeueable => {
+                "only failed or in-progress requests can be re-queued".into()
+            }
+            Self::MissingPriorityFilter => {
+                "either contract address or requester must be specified".into()
+            }
             Self::Internal(_) => "internal server error".into(),
         }
     }
@@ -78,7 +87,9 @@ impl IntoResponse for ApiError {
             | Self::BogusZkCompilerVersion
             | Self::NoDeployedContract
             | Self::AlreadyVerified
-            | Self::ActiveRequestExists(_) => StatusCode::BAD_REQUEST,
+            | Self::ActiveRequestExists(_)
+            | Self::RequestNotRequeueable
+            | Self::MissingPriorityFilter => StatusCode::BAD_REQUEST,
 
             Self::RequestNotFound | Self::VerificationInfoNotFound => StatusCode::NOT_FOUND,
 
@@ -92,7 +103,7 @@ impl IntoResponse for ApiError {
     }
 }
 
-type ApiResult<T> = Result<Json<T>, ApiError>;
+pub(crate) type ApiResult<T> = Result<Json<T>, ApiError>;
 
 impl RestApi {
     #[tracing::instrument(skip(query))]
@@ -127,9 +138,24 @@ impl RestApi {
         Json(request): Json<VerificationIncomingRequest>,
     ) -> ApiResult<usize> {
         let method_latency = METRICS.call[&"contract_verification"].start();
-        Self::validate_contract_verification_query(&request)?;
+        let request_id = self_
+            .add_verification_request(&request, None, false)
+            .await?;
+        method_latency.observe();
+        Ok(Json(request_id))
+    }
 
-        let is_compilation_supported = self_
+    /// Validates the request and adds it to the queue. `requester` is only recorded for requests
+    /// received via the admin API, since it cannot be authenticated otherwise.
+    pub(crate) async fn add_verification_request(
+        &self,
+        request: &VerificationIncomingRequest,
+        requester: Option<&str>,
+        priority_lane: bool,
+    ) -> Result<usize, ApiError> {
+        Self::validate_contract_verification_query(request)?;
+
+        let is_compilation_supported = self
             .supported_compilers
             .get(|supported| supported.contain(&request.compiler_versions))
             .await?;
@@ -137,10 +163,7 @@ impl RestApi {
             return Err(ApiError::UnsupportedCompilerVersions);
         }
 
-        let mut storage = self_
-            .master_connection_pool
-            .connection_tagged("api")
-            .await?;
+        let mut storage = self.master_connection_pool.connection_tagged("api").await?;
 
         // Verification is only allowed if the contract is either wasn't verified yet
         // or the verification is partial.
@@ -192,10 +215,9 @@ impl RestApi {
 
         let request_id = storage
             .contract_verification_dal()
-            .add_contract_verification_request(&request)
+            .add_contract_verification_request_from(request, requester, priority_lane)
             .await?;
-        method_latency.observe();
-        Ok(Json(request_id))
+        Ok(request_id)
     }
 
     #[tracing::instrument(skip(self_))]
@@ -285,6 +307,39 @@ impl RestApi {
         method_latency.observe();
         Ok(Json(info))
     }
+
+    /// Returns a verified contract with bytecode matching the specified (unverified) contract, as discovered
+    /// by the similar match discovery in the contract verifier.
+    #[tracing::instrument(skip(self_))]
+    pub async fn similar_match(
+        State(self_): State<Arc<Self>>,
+        address: Path<Address>,
+    ) -> ApiResult<SimilarMatch> {
+        let method_latency = METRICS.call[&"contract_verification_similar_match"].start();
+        let mut conn = self_
+            .replica_connection_pool
+            .connection_tagged("api")
+            .await?;
+        let mut dal = conn.contract_verification_dal();
+
+        let Some((matched_contract_address, status)) = dal.get_similar_match(*address).await?
+        else {
+            return Err(ApiError::VerificationInfoNotFound);
+        };
+        let mut verification_info = dal
+            .get_contract_verification_info(matched_contract_address)
+            .await?
+            .context("verification info for the matched contract is missing")?;
+        if status == SimilarMatchStatus::Partial {
+            verification_info.verification_problems = vec![VerificationProblem::IncorrectMetadata];
+        }
+        method_latency.observe();
+        Ok(Json(SimilarMatch {
+            matched_contract_address,
+            status,
+            verification_info,
+        }))
+    }
 }
 
 /// Tries to do a lookup for partial match verification info.
@@ -2,10 +2,12 @@ use std::net::SocketAddr;
 
 use anyhow::Context as _;
 use tokio::sync::watch;
+use zksync_config::ContractVerifierConfig;
 use zksync_dal::ConnectionPool;
 
-use self::api_decl::RestApi;
+use self::{admin::AdminApi, api_decl::RestApi};
 
+mod admin;
 mod api_decl;
 mod api_impl;
 mod cache;
@@ -16,23 +18,55 @@ mod tests;
 pub async fn start_server(
     master_connection_pool: ConnectionPool<zksync_dal::Core>,
     replica_connection_pool: ConnectionPool<zksync_dal::Core>,
+    config: &ContractVerifierConfig,
+    stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let api = RestApi::new(master_connection_pool, replica_connection_pool);
+    let server = serve(
+        api.clone().into_router(),
+        config.bind_addr(),
+        "contract verification server",
+        stop_receiver.clone(),
+    );
+
+    if let Some(admin_bind_address) = config.admin_bind_addr() {
+        tracing::info!("Starting contract verification admin API on {admin_bind_address}");
+        let admin_api = AdminApi::new(api)
+            .with_priority_requesters(config.priority_requesters.iter().cloned())
+            .into_router();
+        let admin_server = serve(
+            admin_api,
+            admin_bind_address,
+            "contract verification admin server",
+            stop_receiver,
+        );
+        tokio::try_join!(server, admin_server)?;
+    } else {
+        server.await?;
+    }
+    Ok(())
+}
+
+async fn serve(
+    router: axum::Router<()>,
     bind_address: SocketAddr,
+    name: &'static str,
     mut stop_receiver: watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
-    let api = RestApi::new(master_connection_pool, replica_connection_pool).into_router();
-
     let listener = tokio::net::TcpListener::bind(bind_address)
         .await
-        .context("Cannot bind to the specified address")?;
-    axum::serve(listener, api)
+        .with_context(|| format!("Cannot bind {name} to the specified address"))?;
+    axum::serve(listener, router)
         .with_graceful_shutdown(async move {
             if stop_receiver.changed().await.is_err() {
-                tracing::warn!("Stop signal sender for contract verification server was dropped without sending a signal");
+                tracing::warn!(
+                    "Stop signal sender for {name} was dropped without sending a signal"
+                );
             }
-            tracing::info!("Stop signal received, contract verification server is shutting down");
+            tracing::info!("Stop signal received, {name} is shutting down");
         })
         .await
-        .context("Contract verification handler server failed")?;
-    tracing::info!("Contract verification handler server shut down");
+        .with_context(|| format!("{name} failed"))?;
+    tracing::info!("{name} shut down");
     Ok(())
 }
@@ -4,8 +4,11 @@ use std::{str, vec};
 
 use test_casing::test_casing;
 use utils::{mock_verification_info, MockApiClient, MockContractVerifier};
+use zksync_dal::contract_verification_dal::SimilarMatchRecord;
 use zksync_types::{
-    bytecode::BytecodeMarker, contract_verification::api::VerificationProblem, Address,
+    bytecode::BytecodeMarker,
+    contract_verification::api::{SimilarMatchStatus, VerificationProblem},
+    Address, L2BlockNumber,
 };
 
 use super::*;
@@ -278,6 +281,64 @@ async fn querying_missing_verification_info() {
         .await;
 }
 
+#[test_casing(2, [SimilarMatchStatus::Perfect, SimilarMatchStatus::Partial])]
+#[tokio::test]
+async fn querying_similar_match(status: SimilarMatchStatus) {
+    let pool = ConnectionPool::test_pool().await;
+    let contract_verifier = MockContractVerifier::new(pool.clone());
+    let client = MockApiClient::new(pool.clone());
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let address = Address::repeat_byte(0x23);
+    let verification_request = serde_json::json!({
+        "contractAddress": address,
+        "sourceCode": "contract Test {}",
+        "contractName": "Test",
+        "compilerZksolcVersion": ZKSOLC_VERSION,
+        "compilerSolcVersion": SOLC_VERSION,
+        "optimizationUsed": true,
+    });
+    mock_deploy_contract(&mut storage, address, BytecodeMarker::EraVm).await;
+    let id = client
+        .send_verification_request(&verification_request)
+        .await;
+    contract_verifier
+        .pick_up_next_request(id, &verification_request, BytecodeMarker::EraVm)
+        .await;
+    contract_verifier
+        .verify_contract(mock_verification_info(id, &verification_request))
+        .await;
+
+    let unverified_address = Address::repeat_byte(0x24);
+    client
+        .assert_similar_match_error(unverified_address, ApiError::VerificationInfoNotFound)
+        .await;
+
+    storage
+        .contract_verification_dal()
+        .insert_similar_matches(&[SimilarMatchRecord {
+            contract_address: unverified_address,
+            l2_block_number: L2BlockNumber(1),
+            matched: Some((address, status)),
+        }])
+        .await
+        .unwrap();
+
+    let similar_match = client.similar_match(unverified_address).await;
+    assert_eq!(similar_match.matched_contract_address, address);
+    assert_eq!(similar_match.status, status);
+    assert_eq!(similar_match.verification_info.request.id, id);
+    let expected_problems = match status {
+        SimilarMatchStatus::Perfect => vec![],
+        SimilarMatchStatus::Partial => vec![VerificationProblem::IncorrectMetadata],
+    };
+    assert_eq!(
+        similar_match.verification_info.verification_problems,
+        expected_problems
+    );
+}
+
 #[tokio::test]
 async fn mismatched_compiler_type() {
     let pool = ConnectionPool::test_pool().await;
@@ -301,3 +362,126 @@ async fn mismatched_compiler_type() {
         )
         .await;
 }
+
+#[tokio::test]
+async fn managing_verification_queue_via_admin_api() {
+    let pool = ConnectionPool::test_pool().await;
+    let client = MockApiClient::new(pool.clone());
+    let admin_client = MockApiClient::admin(pool.clone(), &["explorer"]);
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let mut ids = vec![];
+    for (i, requester) in [None, Some("explorer"), Some("untrusted")]
+        .into_iter()
+        .enumerate()
+    {
+        let address = Address::repeat_byte(i as u8 + 1);
+        mock_deploy_contract(&mut storage, address, BytecodeMarker::EraVm).await;
+        let verification_request = serde_json::json!({
+            "contractAddress": address,
+            "sourceCode": "contract Test {}",
+            "contractName": "Test",
+            "compilerZksolcVersion": ZKSOLC_VERSION,
+            "compilerSolcVersion": SOLC_VERSION,
+            "optimizationUsed": true,
+        });
+        let id = match requester {
+            Some(requester) => {
+                admin_client
+                    .send_verification_request_from(&verification_request, requester)
+                    .await
+            }
+            None => {
+                client
+                    .send_verification_request(&verification_request)
+                    .await
+            }
+        };
+        ids.push(id);
+    }
+
+    let queued = admin_client.verification_requests("queued").await;
+    let queued_ids: Vec<_> = queued.iter().map(|req| req.id).collect();
+    assert_eq!(queued_ids, ids);
+    let lanes: Vec<_> = queued.iter().map(|req| req.priority_lane).collect();
+    assert_eq!(lanes, [false, true, false]);
+    assert_eq!(queued[2].requester.as_deref(), Some("untrusted"));
+
+    let updated = admin_client
+        .prioritize_verification_requests(&serde_json::json!({
+            "requester": "untrusted",
+            "priority": 5,
+        }))
+        .await;
+    assert_eq!(updated, 1);
+    admin_client
+        .assert_prioritize_error(
+            &serde_json::json!({ "priority": 5 }),
+            ApiError::MissingPriorityFilter,
+        )
+        .await;
+    let queued = admin_client.verification_requests("queued").await;
+    assert_eq!(queued[0].id, ids[2]);
+    assert_eq!(queued[0].priority, 5);
+
+    // Queued requests cannot be re-queued.
+    admin_client
+        .assert_requeue_error(ids[0], ApiError::RequestNotRequeueable)
+        .await;
+    admin_client
+        .assert_requeue_error(100, ApiError::RequestNotFound)
+        .await;
+
+    storage
+        .contract_verification_dal()
+        .save_verification_error(
+            ids[0],
+            "compilation failed",
+            &serde_json::json!(["oops"]),
+            None,
+        )
+        .await
+        .unwrap();
+    let details = admin_client.verification_request_details(ids[0]).await;
+    assert_eq!(details.summary.status, "failed");
+    assert_eq!(details.error.as_deref(), Some("compilation failed"));
+    assert_eq!(
+        details.compilation_errors,
+        Some(serde_json::json!(["oops"]))
+    );
+
+    let details = admin_client.requeue_verification_request(ids[0]).await;
+    assert_eq!(details.summary.status, "queued");
+    assert_eq!(details.error, None);
+    assert_eq!(details.compilation_errors, None);
+}
+
+#[tokio::test]
+async fn requester_header_is_ignored_by_public_api() {
+    let pool = ConnectionPool::test_pool().await;
+    let client = MockApiClient::new(pool.clone());
+    let admin_client = MockApiClient::admin(pool.clone(), &["explorer"]);
+    let mut storage = pool.connection().await.unwrap();
+    prepare_storage(&mut storage).await;
+
+    let address = Address::repeat_byte(1);
+    mock_deploy_contract(&mut storage, address, BytecodeMarker::EraVm).await;
+    let verification_request = serde_json::json!({
+        "contractAddress": address,
+        "sourceCode": "contract Test {}",
+        "contractName": "Test",
+        "compilerZksolcVersion": ZKSOLC_VERSION,
+        "compilerSolcVersion": SOLC_VERSION,
+        "optimizationUsed": true,
+    });
+    let id = client
+        .send_verification_request_from(&verification_request, "explorer")
+        .await;
+
+    let queued = admin_client.verification_requests("queued").await;
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued[0].id, id);
+    assert!(!queued[0].priority_lane);
+    assert_eq!(queued[0].requester, None);
+}
@@ -13,13 +13,14 @@ use zksync_node_test_utils::create_l2_block;
 use zksync_types::{
     bytecode::{BytecodeHash, BytecodeMarker},
     contract_verification::api::{
-        CompilationArtifacts, CompilerVersions, VerificationIncomingRequest, VerificationInfo,
-        VerificationRequest, VerificationRequestStatus,
+        CompilationArtifacts, CompilerVersions, SimilarMatch, VerificationIncomingRequest,
+        VerificationInfo, VerificationRequest, VerificationRequestDetails,
+        VerificationRequestStatus, VerificationRequestSummary,
     },
     get_code_key, Address, L2BlockNumber, ProtocolVersion, StorageLog, H256,
 };
 
-use crate::{api_impl::ApiError, RestApi};
+use crate::{admin::AdminApi, api_impl::ApiError, RestApi};
 
 pub(super) const SOLC_VERSION: &str = "0.8.27";
 pub(super) const ZKSOLC_VERSION: &str = "1.5.6";
@@ -105,7 +106,7 @@ impl MockContractVerifier {
         let mut storage = self.pool.connection().await.unwrap();
         let request = storage
             .contract_verification_dal()
-            .get_next_queued_verification_request(Duration::from_secs(600))
+            .get_next_queued_verification_request(Duration::from_secs(600), false)
             .await
             .unwrap()
             .expect("request not persisted");
@@ -163,6 +164,16 @@ impl MockApiClient {
         }
     }
 
+    /// Creates a client for the admin API.
+    pub fn admin(pool: ConnectionPool<Core>, priority_requesters: &[&str]) -> Self {
+        let requesters = priority_requesters.iter().map(|&name| name.to_owned());
+        Self {
+            router: AdminApi::new(RestApi::new(pool.clone(), pool))
+                .with_priority_requesters(requesters)
+                .into_router(),
+        }
+    }
+
     pub async fn send_verification_request(&self, request: &serde_json::Value) -> usize {
         let response = self
             .send_request("/contract_verification", Some(request))
@@ -170,6 +181,17 @@ impl MockApiClient {
         Self::json_response::<usize>(response).await
     }
 
+    pub async fn send_verification_request_from(
+        &self,
+        request: &serde_json::Value,
+        requester: &str,
+    ) -> usize {
+        let response = self
+            .send_request_from("/contract_verification", Some(request), Some(requester))
+            .await;
+        Self::json_response::<usize>(response).await
+    }
+
     pub async fn assert_verification_request_error(
         &self,
         request: &serde_json::Value,
@@ -213,6 +235,26 @@ impl MockApiClient {
         Self::assert_response_error(response, expected_err).await;
     }
 
+    pub async fn similar_match(&self, address: Address) -> SimilarMatch {
+        let response = self
+            .send_request(
+                &format!("/contract_verification/similar_match/{address:?}"),
+                None,
+            )
+            .await;
+        Self::json_response::<SimilarMatch>(response).await
+    }
+
+    pub async fn assert_similar_match_error(&self, address: Address, expected_err: ApiError) {
+        let response = self
+            .send_request(
+                &format!("/contract_verification/similar_match/{address:?}"),
+                None,
+            )
+            .await;
+        Self::assert_response_error(response, expected_err).await;
+    }
+
     pub async fn zksolc_versions(&self) -> Vec<String> {
         let response = self
             .send_request("/contract_verification/zksolc_versions", None)
@@ -227,18 +269,73 @@ impl MockApiClient {
         Self::json_response::<Vec<String>>(response).await
     }
 
+    pub async fn verification_requests(&self, status: &str) -> Vec<VerificationRequestSummary> {
+        let response = self
+            .send_request(&format!("/verification_requests?status={status}"), None)
+            .await;
+        Self::json_response(response).await
+    }
+
+    pub async fn verification_request_details(&self, id: usize) -> VerificationRequestDetails {
+        let response = self
+            .send_request(&format!("/verification_requests/{id}"), None)
+            .await;
+        Self::json_response(response).await
+    }
+
+    pub async fn requeue_verification_request(&self, id: usize) -> VerificationRequestDetails {
+        let url = format!("/verification_requests/{id}/requeue");
+        let response = self.send_request(&url, Some(&serde_json::json!({}))).await;
+        Self::json_response(response).await
+    }
+
+    pub async fn assert_requeue_error(&self, id: usize, expected_err: ApiError) {
+        let url = format!("/verification_requests/{id}/requeue");
+        let response = self.send_request(&url, Some(&serde_json::json!({}))).await;
+        Self::assert_response_error(response, expected_err).await;
+    }
+
+    pub async fn prioritize_verification_requests(&self, request: &serde_json::Value) -> usize {
+        let response = self
+            .send_request("/verification_requests/prioritize", Some(request))
+            .await;
+        Self::json_response(response).await
+    }
+
+    pub async fn assert_prioritize_error(
+        &self,
+        request: &serde_json::Value,
+        expected_err: ApiError,
+    ) {
+        let response = self
+            .send_request("/verification_requests/prioritize", Some(request))
+            .await;
+        Self::assert_response_error(response, expected_err).await;
+    }
+
     async fn send_request(&self, url: &str, body: Option<&serde_json::Value>) -> Response<Body> {
+        self.send_request_from(url, body, None).await
+    }
+
+    async fn send_request_from(
+        &self,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        requester: Option<&str>,
+    ) -> Response<Body> {
         let (method, body) = match body {
             Some(body) => (Method::POST, Body::from(serde_json::to_vec(body).unwrap())),
             None => (Method::GET, Body::empty()),
         };
 
-        let req = Request::builder()
+        let mut req = Request::builder()
             .method(method)
             .uri(url)
-            .header(header::CONTENT_TYPE, "application/json")
-            .body(body)
-            .unwrap();
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(requester) = requester {
+            req = req.header("X-Requester", requester);
+        }
+        let req = req.body(body).unwrap();
 
         self.router.clone().oneshot(req).await.unwrap()
     }
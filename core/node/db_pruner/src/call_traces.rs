@@ -0,0 +1,243 @@
+//! Pruning of call traces according to the configured retention period.
+
+use std::time::Duration;
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{helpers::unix_timestamp_ms, L2BlockNumber};
+
+use crate::metrics::CALL_TRACES_METRICS;
+
+/// Configuration of [`CallTracesPruner`].
+#[derive(Debug)]
+pub struct CallTracesPrunerConfig {
+    /// Call traces for L2 blocks older than this period are removed.
+    pub retention: Duration,
+    /// Number of L2 blocks for which call traces are removed in a single DB query.
+    pub pruned_l2_block_chunk_size: u32,
+    /// Interval between pruning iterations.
+    pub pruning_interval: Duration,
+}
+
+impl CallTracesPrunerConfig {
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            retention,
+            pruned_l2_block_chunk_size: 1_000,
+            pruning_interval: Duration::from_secs(3_600),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CallTracesPrunerHealth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_pruned_l2_block: Option<L2BlockNumber>,
+}
+
+/// Component removing call traces for L2 blocks older than the configured retention period.
+///
+/// Unlike [`DbPruner`](crate::DbPruner), this component doesn't remove any other data. Call traces are removed
+/// in chunks of L2 blocks so that a single DB query doesn't hold locks for long. The last L2 block for which
+/// traces were removed is persisted as the component state, so that the pruner doesn't rescan already pruned
+/// L2 blocks after a restart.
+#[derive(Debug)]
+pub struct CallTracesPruner {
+    config: CallTracesPrunerConfig,
+    connection_pool: ConnectionPool<Core>,
+    health_updater: HealthUpdater,
+}
+
+impl CallTracesPruner {
+    pub const COMPONENT: &'static str = "call_traces_pruner";
+    pub const LAST_PRUNED_L2_BLOCK_KEY: &'static str = "last_pruned_l2_block";
+
+    pub fn new(config: CallTracesPrunerConfig, connection_pool: ConnectionPool<Core>) -> Self {
+        Self {
+            config,
+            connection_pool,
+            health_updater: ReactiveHealthCheck::new("call_traces_pruner").1,
+        }
+    }
+
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    fn update_health(&self, last_pruned_l2_block: Option<L2BlockNumber>) {
+        let health = Health::from(HealthStatus::Ready).with_details(CallTracesPrunerHealth {
+            last_pruned_l2_block,
+        });
+        self.health_updater.update(health);
+    }
+
+    pub(crate) async fn last_pruned_l2_block(
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<Option<(L2BlockNumber, u64)>> {
+        let entry = storage
+            .component_state_dal()
+            .get_state::<L2BlockNumber>(Self::COMPONENT, Self::LAST_PRUNED_L2_BLOCK_KEY)
+            .await?;
+        Ok(entry.map(|entry| (entry.value, entry.version)))
+    }
+
+    /// Returns the last L2 block in `range` with timestamp less than `cutoff_timestamp`, or `None` if there is no such block.
+    /// Relies on L2 block timestamps being monotonic; only uses primary key lookups.
+    async fn last_l2_block_before(
+        storage: &mut Connection<'_, Core>,
+        (mut start, mut end): (L2BlockNumber, L2BlockNumber),
+        cutoff_timestamp: u64,
+    ) -> anyhow::Result<Option<L2BlockNumber>> {
+        let mut result = None;
+        while start <= end {
+            let mid = start + (end.0 - start.0) / 2;
+            let header = storage
+                .blocks_dal()
+                .get_l2_block_header(mid)
+                .await?
+                .with_context(|| format!("L2 block #{mid} disappeared from storage"))?;
+            if header.timestamp < cutoff_timestamp {
+                result = Some(mid);
+                start = mid + 1;
+            } else if mid.0 == 0 {
+                break;
+            } else {
+                end = mid - 1;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the last L2 block for which call traces were removed, or `None` if nothing was pruned.
+    pub(crate) async fn run_single_iteration(
+        &self,
+        stop_receiver: &watch::Receiver<bool>,
+    ) -> anyhow::Result<Option<L2BlockNumber>> {
+        let mut storage = self
+            .connection_pool
+            .connection_tagged("call_traces_pruner")
+            .await?;
+        let last_pruned = Self::last_pruned_l2_block(&mut storage).await?;
+        let Some(earliest_l2_block) = storage.blocks_dal().get_earliest_l2_block_number().await?
+        else {
+            return Ok(None);
+        };
+        let sealed_l2_block = storage
+            .blocks_dal()
+            .get_sealed_l2_block_number()
+            .await?
+            .context("unreachable DB state: there's an earliest L2 block, but no latest one")?;
+        // L2 blocks may have been removed by the DB pruner since the last iteration.
+        let first_l2_block = match last_pruned {
+            Some((l2_block, _)) => earliest_l2_block.max(l2_block + 1),
+            None => earliest_l2_block,
+        };
+        if first_l2_block > sealed_l2_block {
+            return Ok(None);
+        }
+
+        let now = unix_timestamp_ms() / 1_000;
+        let cutoff_timestamp = now.saturating_sub(self.config.retention.as_secs());
+        let Some(last_l2_block) = Self::last_l2_block_before(
+            &mut storage,
+            (first_l2_block, sealed_l2_block),
+            cutoff_timestamp,
+        )
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let chunk_size = self.config.pruned_l2_block_chunk_size.max(1);
+        let mut pruned_until = None;
+        let mut chunk_start = first_l2_block;
+        while chunk_start <= last_l2_block && !*stop_receiver.borrow() {
+            let chunk_end = L2BlockNumber(
+                chunk_start
+                    .0
+                    .saturating_add(chunk_size - 1)
+                    .min(last_l2_block.0),
+            );
+            let latency = CALL_TRACES_METRICS.chunk_duration.start();
+            let deleted_count = storage
+                .transactions_dal()
+                .delete_call_traces_for_l2_blocks(chunk_start..=chunk_end)
+                .await?;
+            let latency = latency.observe();
+            CALL_TRACES_METRICS
+                .deleted_call_traces
+                .inc_by(deleted_count);
+            tracing::debug!(
+                "Removed {deleted_count} call traces for L2 blocks {chunk_start}..={chunk_end}, operation took {latency:?}"
+            );
+
+            pruned_until = Some(chunk_end);
+            chunk_start = chunk_end + 1;
+        }
+
+        let Some(pruned_until) = pruned_until else {
+            return Ok(None);
+        };
+        let expected_version = last_pruned.map(|(_, version)| version);
+        let updated = storage
+            .component_state_dal()
+            .set_state(
+                Self::COMPONENT,
+                Self::LAST_PRUNED_L2_BLOCK_KEY,
+                &pruned_until,
+                expected_version,
+            )
+            .await?;
+        anyhow::ensure!(
+            updated.is_some(),
+            "last pruned L2 block was concurrently modified; is there another call traces pruner running?"
+        );
+        CALL_TRACES_METRICS
+            .last_pruned_l2_block
+            .set(pruned_until.0.into());
+        tracing::info!(
+            "Removed call traces for L2 blocks {first_l2_block}..={pruned_until} (cutoff timestamp: {cutoff_timestamp})"
+        );
+        Ok(Some(pruned_until))
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        tracing::info!(
+            "Starting call traces pruning with configuration {:?}",
+            self.config
+        );
+        let mut last_pruned_l2_block = None;
+        while !*stop_receiver.borrow_and_update() {
+            match self.run_single_iteration(&stop_receiver).await {
+                Ok(pruned_until) => {
+                    last_pruned_l2_block = pruned_until.or(last_pruned_l2_block);
+                    self.update_health(last_pruned_l2_block);
+                }
+                Err(err) => {
+                    // As this component is not really mission-critical, all errors are generally ignored
+                    tracing::warn!(
+                        "Call traces pruning error, retrying in {:?}, error was: {err:?}",
+                        self.config.pruning_interval
+                    );
+                    let health =
+                        Health::from(HealthStatus::Affected).with_details(serde_json::json!({
+                            "error": err.to_string(),
+                        }));
+                    self.health_updater.update(health);
+                }
+            }
+
+            if tokio::time::timeout(self.config.pruning_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, shutting down call traces pruning");
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use zksync_dal::{
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_types::{L1BatchNumber, L2BlockNumber};
 
+pub use self::call_traces::{CallTracesPruner, CallTracesPrunerConfig};
 use self::{
     metrics::{ConditionOutcome, PruneType, METRICS},
     prune_conditions::{
@@ -23,6 +24,7 @@ use self::{
     },
 };
 
+mod call_traces;
 mod metrics;
 mod prune_conditions;
 #[cfg(test)]
@@ -24,6 +24,7 @@ enum PrunedEntityType {
     Event,
     L2ToL1Log,
     CallTrace,
+    StorageAccessSet,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
@@ -68,12 +69,14 @@ impl DbPrunerMetrics {
             deleted_storage_logs,
             deleted_events,
             deleted_call_traces,
+            deleted_storage_accesses,
             deleted_l2_to_l1_logs,
         } = stats;
         tracing::info!(
             "Performed pruning of database, deleted {deleted_l1_batches} L1 batches, {deleted_l2_blocks} L2 blocks, \
              {deleted_storage_logs} storage logs, \
-             {deleted_events} events, {deleted_call_traces} call traces, {deleted_l2_to_l1_logs} L2-to-L1 logs"
+             {deleted_events} events, {deleted_call_traces} call traces, {deleted_storage_accesses} tx storage access sets, \
+             {deleted_l2_to_l1_logs} L2-to-L1 logs"
         );
 
         self.deleted_entities[&PrunedEntityType::L1Batch].observe(deleted_l1_batches);
@@ -82,6 +85,8 @@ impl DbPrunerMetrics {
         self.deleted_entities[&PrunedEntityType::Event].observe(deleted_events);
         self.deleted_entities[&PrunedEntityType::L2ToL1Log].observe(deleted_l2_to_l1_logs);
         self.deleted_entities[&PrunedEntityType::CallTrace].observe(deleted_call_traces);
+        self.deleted_entities[&PrunedEntityType::StorageAccessSet]
+            .observe(deleted_storage_accesses);
     }
 
     pub fn observe_condition(&self, condition: &dyn PruneCondition, outcome: ConditionOutcome) {
@@ -95,3 +100,18 @@ impl DbPrunerMetrics {
 
 #[vise::register]
 pub(super) static METRICS: vise::Global<DbPrunerMetrics> = vise::Global::new();
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "call_traces_pruner")]
+pub(super) struct CallTracesPrunerMetrics {
+    /// Latency of removing call traces for a chunk of L2 blocks.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub chunk_duration: Histogram<Duration>,
+    /// Total number of removed call traces.
+    pub deleted_call_traces: Counter,
+    /// Last L2 block for which call traces were removed.
+    pub last_pruned_l2_block: Gauge<u64>,
+}
+
+#[vise::register]
+pub(super) static CALL_TRACES_METRICS: vise::Global<CallTracesPrunerMetrics> = vise::Global::new();
@@ -8,12 +8,14 @@ use zksync_db_connection::connection::Connection;
 use zksync_health_check::CheckHealth;
 use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
 use zksync_node_test_utils::{
-    create_l1_batch, create_l1_batch_metadata, create_l2_block,
-    l1_batch_metadata_to_commitment_artifacts,
+    create_l1_batch, create_l1_batch_metadata, create_l2_block, create_l2_transaction,
+    execute_l2_transaction, l1_batch_metadata_to_commitment_artifacts,
 };
 use zksync_types::{
-    aggregated_operations::AggregatedActionType, L2BlockNumber, ProtocolVersion, H256,
+    aggregated_operations::AggregatedActionType, block::L2BlockHeader, helpers::unix_timestamp_ms,
+    Address, L2BlockNumber, ProtocolVersion, ProtocolVersionId, H256,
 };
+use zksync_vm_interface::{tracer::ValidationTraces, Call, TransactionExecutionMetrics};
 
 use super::*;
 
@@ -585,3 +587,118 @@ async fn pruner_timely_shuts_down() {
     stop_sender.send_replace(true);
     pruner_handle.await.unwrap().unwrap();
 }
+
+async fn insert_l2_block_with_call_trace(
+    storage: &mut Connection<'_, Core>,
+    number: u32,
+    timestamp: u64,
+) -> H256 {
+    let l2_block = L2BlockHeader {
+        timestamp,
+        ..create_l2_block(number)
+    };
+    storage
+        .blocks_dal()
+        .insert_l2_block(&l2_block)
+        .await
+        .unwrap();
+
+    let tx = create_l2_transaction(10, 100);
+    storage
+        .transactions_dal()
+        .insert_transaction_l2(
+            &tx,
+            TransactionExecutionMetrics::default(),
+            ValidationTraces::default(),
+        )
+        .await
+        .unwrap();
+    let mut tx_result = execute_l2_transaction(tx);
+    tx_result.call_traces.push(Call {
+        from: Address::repeat_byte(1),
+        to: Address::repeat_byte(2),
+        ..Call::default()
+    });
+    let tx_hash = tx_result.hash;
+    storage
+        .transactions_dal()
+        .mark_txs_as_executed_in_l2_block(
+            L2BlockNumber(number),
+            &[tx_result],
+            1.into(),
+            ProtocolVersionId::latest(),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    tx_hash
+}
+
+#[tokio::test]
+async fn pruning_call_traces_in_chunks() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let retention = Duration::from_secs(3_600);
+    let now = unix_timestamp_ms() / 1_000;
+    let mut old_tx_hashes = vec![];
+    for number in 1..=5 {
+        let tx_hash = insert_l2_block_with_call_trace(&mut storage, number, now - 7_200).await;
+        old_tx_hashes.push(tx_hash);
+    }
+    let mut new_tx_hashes = vec![];
+    for number in 6..=7 {
+        let tx_hash = insert_l2_block_with_call_trace(&mut storage, number, now).await;
+        new_tx_hashes.push(tx_hash);
+    }
+
+    let pruner = CallTracesPruner::new(
+        CallTracesPrunerConfig {
+            pruned_l2_block_chunk_size: 2,
+            ..CallTracesPrunerConfig::new(retention)
+        },
+        pool.clone(),
+    );
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    let pruned_until = pruner.run_single_iteration(&stop_receiver).await.unwrap();
+    assert_eq!(pruned_until, Some(L2BlockNumber(5)));
+
+    for tx_hash in old_tx_hashes {
+        let call_trace = storage
+            .transactions_dal()
+            .get_call_trace(tx_hash)
+            .await
+            .unwrap();
+        assert!(call_trace.is_none());
+    }
+    for &tx_hash in &new_tx_hashes {
+        let call_trace = storage
+            .transactions_dal()
+            .get_call_trace(tx_hash)
+            .await
+            .unwrap();
+        assert!(call_trace.is_some());
+    }
+
+    let (last_pruned_l2_block, _) = CallTracesPruner::last_pruned_l2_block(&mut storage)
+        .await
+        .unwrap()
+        .expect("last pruned L2 block is not persisted");
+    assert_eq!(last_pruned_l2_block, L2BlockNumber(5));
+
+    // No more L2 blocks are eligible for pruning.
+    let pruned_until = pruner.run_single_iteration(&stop_receiver).await.unwrap();
+    assert_eq!(pruned_until, None);
+    for tx_hash in new_tx_hashes {
+        let call_trace = storage
+            .transactions_dal()
+            .get_call_trace(tx_hash)
+            .await
+            .unwrap();
+        assert!(call_trace.is_some());
+    }
+}
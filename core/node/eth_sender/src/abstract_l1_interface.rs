@@ -10,6 +10,7 @@ use zksync_eth_client::{
 use zksync_types::web3;
 use zksync_types::{
     eth_sender::{EthTx, EthTxBlobSidecar},
+    settlement::SettlementMode,
     web3::{BlockId, BlockNumber},
     Address, L1BlockNumber, Nonce, EIP_1559_TX_TYPE, EIP_4844_TX_TYPE, H256, U256,
 };
@@ -37,6 +38,27 @@ pub(crate) enum OperatorType {
     NonBlob,
     Blob,
     Gateway,
+    /// Non-blob operator key that is being rotated out. Only used to track and resend transactions
+    /// that were signed with this key before the rotation.
+    PreviousNonBlob,
+    /// Blob operator key that is being rotated out.
+    PreviousBlob,
+}
+
+impl OperatorType {
+    /// Returns the settlement layer that transactions of this operator are sent to.
+    pub(crate) fn settlement_mode(self) -> SettlementMode {
+        match self {
+            Self::NonBlob | Self::Blob | Self::PreviousNonBlob | Self::PreviousBlob => {
+                SettlementMode::SettlesToL1
+            }
+            Self::Gateway => SettlementMode::Gateway,
+        }
+    }
+
+    pub(crate) fn is_blob(self) -> bool {
+        matches!(self, Self::Blob | Self::PreviousBlob)
+    }
 }
 
 #[async_trait]
@@ -70,6 +92,9 @@ pub(super) trait AbstractL1Interface: 'static + Sync + Send + fmt::Debug {
 
     fn get_blobs_operator_account(&self) -> Option<Address>;
 
+    /// Returns the address of the operator key that is being rotated out for the specified operator type.
+    fn get_previous_operator_account(&self, operator_type: OperatorType) -> Option<Address>;
+
     async fn get_operator_nonce(
         &self,
         block_numbers: L1BlockNumbers,
@@ -97,16 +122,14 @@ pub(super) struct RealL1Interface {
     pub ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
     pub ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
     pub l2_gateway: Option<Box<dyn BoundEthInterface>>,
+    pub previous_ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
+    pub previous_ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
     pub wait_confirmations: Option<u64>,
 }
 
 impl RealL1Interface {
     fn query_client(&self, operator_type: OperatorType) -> &dyn EthInterface {
-        match operator_type {
-            OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap().as_ref(),
-            OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap().as_ref(),
-            OperatorType::Gateway => self.l2_gateway.as_deref().unwrap().as_ref(),
-        }
+        self.bound_query_client(operator_type).as_ref()
     }
 
     fn bound_query_client(&self, operator_type: OperatorType) -> &dyn BoundEthInterface {
@@ -114,6 +137,8 @@ impl RealL1Interface {
             OperatorType::NonBlob => self.ethereum_gateway.as_deref().unwrap(),
             OperatorType::Blob => self.ethereum_gateway_blobs.as_deref().unwrap(),
             OperatorType::Gateway => self.l2_gateway.as_deref().unwrap(),
+            OperatorType::PreviousNonBlob => self.previous_ethereum_gateway.as_deref().unwrap(),
+            OperatorType::PreviousBlob => self.previous_ethereum_gateway_blobs.as_deref().unwrap(),
         }
     }
 }
@@ -131,6 +156,12 @@ impl AbstractL1Interface for RealL1Interface {
         if self.ethereum_gateway.is_some() {
             result.push(OperatorType::NonBlob);
         }
+        if self.previous_ethereum_gateway_blobs.is_some() {
+            result.push(OperatorType::PreviousBlob);
+        }
+        if self.previous_ethereum_gateway.is_some() {
+            result.push(OperatorType::PreviousNonBlob);
+        }
         result
     }
 
@@ -182,6 +213,15 @@ impl AbstractL1Interface for RealL1Interface {
             .map(|s| s.sender_account())
     }
 
+    fn get_previous_operator_account(&self, operator_type: OperatorType) -> Option<Address> {
+        let client = match operator_type {
+            OperatorType::PreviousNonBlob => self.previous_ethereum_gateway.as_deref(),
+            OperatorType::PreviousBlob => self.previous_ethereum_gateway_blobs.as_deref(),
+            _ => None,
+        };
+        client.map(|s| s.sender_account())
+    }
+
     async fn get_operator_nonce(
         &self,
         block_numbers: L1BlockNumbers,
@@ -188,12 +188,33 @@ impl Aggregator {
             })]
         };
 
+        // Only skipped proofs can be aggregated; the verifier accepts a single proof for a single L1 batch,
+        // so these criteria aren't used for real proofs (see `load_real_proof_operation()`).
+        if config.max_aggregated_skipped_proofs > 1
+            && config.proof_sending_mode == ProofSendingMode::OnlyRealProofs
+        {
+            tracing::warn!(
+                "config.max_aggregated_skipped_proofs is set to {} but \
+                proofs are never skipped in the {:?} mode",
+                config.max_aggregated_skipped_proofs,
+                config.proof_sending_mode
+            );
+        }
+        let proof_criteria: Vec<Box<dyn L1BatchPublishCriterion>> = vec![
+            Box::from(NumberCriterion {
+                op: AggregatedActionType::PublishProofOnchain,
+                limit: config.max_aggregated_skipped_proofs,
+            }),
+            Box::from(TimestampDeadlineCriterion {
+                op: AggregatedActionType::PublishProofOnchain,
+                deadline_seconds: config.aggregated_block_prove_deadline,
+                max_allowed_lag: Some(config.timestamp_criteria_max_allowed_lag),
+            }),
+        ];
+
         Ok(Self {
             commit_criteria,
-            proof_criteria: vec![Box::from(NumberCriterion {
-                op: AggregatedActionType::PublishProofOnchain,
-                limit: 1,
-            })],
+            proof_criteria,
             execute_criteria,
             config,
             blob_store,
@@ -491,11 +512,12 @@ impl Aggregator {
 
     async fn load_dummy_proof_operations(
         storage: &mut Connection<'_, Core>,
+        limit: usize,
         is_4844_mode: bool,
     ) -> Vec<L1BatchWithMetadata> {
         let mut ready_for_proof_l1_batches = storage
             .blocks_dal()
-            .get_ready_for_dummy_proof_l1_batches(1)
+            .get_ready_for_dummy_proof_l1_batches(limit)
             .await
             .unwrap();
 
@@ -532,6 +554,8 @@ impl Aggregator {
         ready_for_proof_l1_batches
     }
 
+    /// Loads the operation proving the next L1 batch with a real proof. Such operations always contain
+    /// a single L1 batch since the L1 verifier doesn't support verifying proofs for multiple batches.
     async fn load_real_proof_operation(
         storage: &mut Connection<'_, Core>,
         l1_verifier_config: L1VerifierConfig,
@@ -679,8 +703,12 @@ impl Aggregator {
             }
 
             ProofSendingMode::SkipEveryProof => {
-                let ready_for_proof_l1_batches =
-                    Self::load_dummy_proof_operations(storage, self.operate_4844_mode).await;
+                let ready_for_proof_l1_batches = Self::load_dummy_proof_operations(
+                    storage,
+                    self.config.max_aggregated_skipped_proofs as usize,
+                    self.operate_4844_mode,
+                )
+                .await;
                 self.prepare_dummy_proof_operation(
                     storage,
                     ready_for_proof_l1_batches,
@@ -703,7 +731,9 @@ impl Aggregator {
                 } else {
                     let ready_for_proof_batches = storage
                         .blocks_dal()
-                        .get_skipped_for_proof_l1_batches(1)
+                        .get_skipped_for_proof_l1_batches(
+                            self.config.max_aggregated_skipped_proofs as usize,
+                        )
                         .await
                         .unwrap();
                     self.prepare_dummy_proof_operation(
@@ -0,0 +1,207 @@
+//! Postponement of commit transactions during L1 gas price spikes.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use zksync_config::configs::eth_sender::SenderConfig;
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
+use zksync_types::{helpers::unix_timestamp_ms, L1BatchNumber};
+
+use crate::{metrics::METRICS, publish_criterion::L1GasCriterion};
+
+/// Postponement started because of a high L1 base fee.
+#[derive(Debug, Clone, Copy)]
+struct Postponement {
+    started_at: Instant,
+    base_fee_per_gas: u64,
+}
+
+/// Policy postponing commit transactions while the L1 base fee exceeds the configured ceiling.
+///
+/// A commit is not postponed if it's urgent, i.e. the oldest uncommitted L1 batch is older than the configured
+/// maximum postponement, or if postponement is overridden by the operator. The override is stored as a boolean
+/// under the [`Self::OVERRIDE_KEY`] key of the [`Self::COMPONENT`] component state, so that it can be toggled
+/// without restarting the server.
+#[derive(Debug)]
+pub struct CommitPostponementPolicy {
+    gas_adjuster: Arc<dyn TxParamsProvider>,
+    max_base_fee_per_gas: u64,
+    max_postponement: Duration,
+    postponement: Option<Postponement>,
+}
+
+impl CommitPostponementPolicy {
+    pub const COMPONENT: &'static str = "eth_tx_aggregator";
+    pub const OVERRIDE_KEY: &'static str = "commit_postponement_override";
+
+    /// Creates a policy based on the config. Returns `None` if the L1 base fee ceiling is not configured.
+    pub fn new(gas_adjuster: Arc<dyn TxParamsProvider>, config: &SenderConfig) -> Option<Self> {
+        Some(Self {
+            gas_adjuster,
+            max_base_fee_per_gas: config.max_commit_base_fee_per_gas?,
+            max_postponement: config.max_commit_postponement(),
+            postponement: None,
+        })
+    }
+
+    /// Returns the reason to postpone commit operations, or `None` if they can be sent.
+    pub(crate) async fn commit_restriction(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+    ) -> Option<&'static str> {
+        // This is the lower bound for the next block base fee, i.e., it's close to the current L1 base fee.
+        let base_fee_per_gas = self.gas_adjuster.get_next_block_minimal_base_fee();
+        let should_postpone = base_fee_per_gas > self.max_base_fee_per_gas
+            && !Self::is_overridden(storage).await
+            && Self::oldest_uncommitted_l1_batch_age(storage)
+                .await
+                .is_some_and(|age| age < self.max_postponement);
+        self.update(base_fee_per_gas, should_postpone)
+            .then_some("L1 base fee exceeds the configured ceiling")
+    }
+
+    async fn is_overridden(storage: &mut Connection<'_, Core>) -> bool {
+        storage
+            .component_state_dal()
+            .get_state::<bool>(Self::COMPONENT, Self::OVERRIDE_KEY)
+            .await
+            .unwrap()
+            .is_some_and(|entry| entry.value)
+    }
+
+    /// Returns `None` if there are no uncommitted L1 batches.
+    async fn oldest_uncommitted_l1_batch_age(
+        storage: &mut Connection<'_, Core>,
+    ) -> Option<Duration> {
+        let mut blocks_dal = storage.blocks_dal();
+        let last_committed_l1_batch = blocks_dal
+            .get_last_committed_to_eth_l1_batch()
+            .await
+            .unwrap()?;
+        let oldest_uncommitted_l1_batch = blocks_dal
+            .get_l1_batch_header(last_committed_l1_batch.header.number + 1)
+            .await
+            .unwrap()?;
+        let now = unix_timestamp_ms() / 1_000;
+        Some(Duration::from_secs(
+            now.saturating_sub(oldest_uncommitted_l1_batch.timestamp),
+        ))
+    }
+
+    /// Updates the postponement state and reports metrics. Returns `should_postpone` for convenience.
+    fn update(&mut self, base_fee_per_gas: u64, should_postpone: bool) -> bool {
+        if should_postpone {
+            if self.postponement.is_none() {
+                tracing::info!(
+                    "Postponing commit transactions since L1 base fee {base_fee_per_gas} exceeds the ceiling {}",
+                    self.max_base_fee_per_gas
+                );
+                self.postponement = Some(Postponement {
+                    started_at: Instant::now(),
+                    base_fee_per_gas,
+                });
+            }
+        } else if let Some(postponement) = self.postponement.take() {
+            let postponed_for = postponement.started_at.elapsed();
+            // Commit gas excluding pubdata, so this is a lower-bound estimate.
+            let commit_gas = L1GasCriterion::total_validium_commit_gas_amount(
+                L1BatchNumber(0)..=L1BatchNumber(0),
+            );
+            let savings_gwei = postponement
+                .base_fee_per_gas
+                .saturating_sub(base_fee_per_gas)
+                .saturating_mul(commit_gas.into())
+                / 1_000_000_000;
+            tracing::info!(
+                "Resuming commit transactions after postponing them for {postponed_for:?}; L1 base fee is {base_fee_per_gas}, \
+                 estimated savings: {savings_gwei} gwei"
+            );
+            METRICS.commit_postponement_time.observe(postponed_for);
+            METRICS
+                .commit_postponement_savings_gwei
+                .inc_by(savings_gwei);
+        }
+        METRICS
+            .commit_postponement_active
+            .set(self.postponement.is_some().into());
+        should_postpone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::eth_sender::EthConfig;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockTxParams(u64);
+
+    impl TxParamsProvider for MockTxParams {
+        fn get_base_fee(&self, _time_in_mempool_in_l1_blocks: u32) -> u64 {
+            self.0
+        }
+
+        fn get_priority_fee(&self) -> u64 {
+            1
+        }
+
+        fn get_next_block_minimal_base_fee(&self) -> u64 {
+            self.0
+        }
+
+        fn get_blob_tx_base_fee(&self) -> u64 {
+            self.0
+        }
+
+        fn get_blob_tx_blob_base_fee(&self) -> u64 {
+            1
+        }
+
+        fn get_blob_tx_priority_fee(&self) -> u64 {
+            1
+        }
+
+        fn get_gateway_tx_base_fee(&self) -> u64 {
+            self.0
+        }
+
+        fn get_gateway_tx_pubdata_price(&self) -> u64 {
+            1
+        }
+    }
+
+    #[test]
+    fn policy_is_disabled_without_ceiling() {
+        let config = SenderConfig {
+            max_commit_base_fee_per_gas: None,
+            ..EthConfig::for_tests().sender.unwrap()
+        };
+        let policy = CommitPostponementPolicy::new(Arc::new(MockTxParams(1)), &config);
+        assert!(policy.is_none());
+    }
+
+    #[test]
+    fn tracking_postponement() {
+        let config = SenderConfig {
+            max_commit_base_fee_per_gas: Some(100),
+            ..EthConfig::for_tests().sender.unwrap()
+        };
+        let mut policy = CommitPostponementPolicy::new(Arc::new(MockTxParams(1)), &config).unwrap();
+        assert!(!policy.update(50, false));
+        assert!(policy.postponement.is_none());
+
+        assert!(policy.update(200, true));
+        let postponement = policy.postponement.unwrap();
+        assert_eq!(postponement.base_fee_per_gas, 200);
+        // The postponement start is not updated while postponement is active.
+        assert!(policy.update(300, true));
+        assert_eq!(policy.postponement.unwrap().base_fee_per_gas, 200);
+
+        assert!(!policy.update(80, false));
+        assert!(policy.postponement.is_none());
+    }
+}
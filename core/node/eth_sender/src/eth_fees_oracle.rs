@@ -6,7 +6,7 @@ use std::{
 
 use zksync_eth_client::{ClientError, EnrichedClientError};
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
-use zksync_types::eth_sender::TxHistory;
+use zksync_types::{eth_sender::TxHistory, DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE};
 
 use crate::{abstract_l1_interface::OperatorType, EthSenderError};
 
@@ -15,7 +15,7 @@ pub(crate) struct EthFees {
     pub(crate) base_fee_per_gas: u64,
     pub(crate) priority_fee_per_gas: u64,
     pub(crate) blob_base_fee_per_gas: Option<u64>,
-    #[allow(dead_code)]
+    /// Price per pubdata byte on the settlement layer; only set for Gateway transactions.
     pub(crate) pubdata_price: Option<u64>,
 }
 
@@ -134,6 +134,39 @@ impl GasAdjusterFeesOracle {
         })
     }
 
+    /// Gateway is a ZK chain, so its fees are determined by the gateway base fee and pubdata price rather than
+    /// by L1 fee heuristics. Priority fees are not used by ZK chains.
+    fn calculate_fees_for_gateway_tx(
+        &self,
+        previous_sent_tx: &Option<TxHistory>,
+    ) -> Result<EthFees, EthSenderError> {
+        let pubdata_price = self.gas_adjuster.get_gateway_tx_pubdata_price();
+        let mut base_fee_per_gas = self.gas_adjuster.get_gateway_tx_base_fee();
+        self.assert_fee_is_not_zero(base_fee_per_gas, "base");
+        // EIP-1559 transactions on ZK chains implicitly have a gas-per-pubdata limit, so the gas price
+        // must be high enough to cover the pubdata price within this limit; otherwise, the transaction
+        // would be rejected by the gateway.
+        base_fee_per_gas = max(
+            base_fee_per_gas,
+            pubdata_price.div_ceil(DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE),
+        );
+
+        if let Some(previous_sent_tx) = previous_sent_tx {
+            // Increase the fee by at least 20% to prevent the "replacement transaction under-priced" error.
+            base_fee_per_gas = max(
+                base_fee_per_gas,
+                (previous_sent_tx.base_fee_per_gas * 6) / 5 + 1,
+            );
+        }
+
+        Ok(EthFees {
+            base_fee_per_gas,
+            priority_fee_per_gas: 0,
+            blob_base_fee_per_gas: None,
+            pubdata_price: Some(pubdata_price),
+        })
+    }
+
     fn verify_base_fee_not_too_low_on_resend(
         &self,
         tx_id: u32,
@@ -172,11 +205,104 @@ impl EthFeesOracle for GasAdjusterFeesOracle {
         time_in_mempool_in_l1_blocks: u32,
         operator_type: OperatorType,
     ) -> Result<EthFees, EthSenderError> {
-        let has_blob_sidecar = operator_type == OperatorType::Blob;
-        if has_blob_sidecar {
+        let has_blob_sidecar = operator_type.is_blob();
+        if operator_type == OperatorType::Gateway {
+            self.calculate_fees_for_gateway_tx(previous_sent_tx)
+        } else if has_blob_sidecar {
             self.calculate_fees_with_blob_sidecar(previous_sent_tx)
         } else {
             self.calculate_fees_no_blob_sidecar(previous_sent_tx, time_in_mempool_in_l1_blocks)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::H256;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockGatewayParams {
+        base_fee: u64,
+        pubdata_price: u64,
+    }
+
+    impl TxParamsProvider for MockGatewayParams {
+        fn get_base_fee(&self, _time_in_mempool_in_l1_blocks: u32) -> u64 {
+            unreachable!()
+        }
+
+        fn get_priority_fee(&self) -> u64 {
+            unreachable!()
+        }
+
+        fn get_next_block_minimal_base_fee(&self) -> u64 {
+            unreachable!()
+        }
+
+        fn get_blob_tx_base_fee(&self) -> u64 {
+            unreachable!()
+        }
+
+        fn get_blob_tx_blob_base_fee(&self) -> u64 {
+            unreachable!()
+        }
+
+        fn get_blob_tx_priority_fee(&self) -> u64 {
+            unreachable!()
+        }
+
+        fn get_gateway_tx_base_fee(&self) -> u64 {
+            self.base_fee
+        }
+
+        fn get_gateway_tx_pubdata_price(&self) -> u64 {
+            self.pubdata_price
+        }
+    }
+
+    fn oracle(base_fee: u64, pubdata_price: u64) -> GasAdjusterFeesOracle {
+        GasAdjusterFeesOracle {
+            gas_adjuster: Arc::new(MockGatewayParams {
+                base_fee,
+                pubdata_price,
+            }),
+            max_acceptable_priority_fee_in_gwei: 100_000_000_000,
+            time_in_mempool_in_l1_blocks_cap: 100,
+        }
+    }
+
+    #[test]
+    fn calculating_fees_for_gateway_tx() {
+        let fees = oracle(1_000, 1_000_000)
+            .calculate_fees(&None, 0, OperatorType::Gateway)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_000);
+        assert_eq!(fees.priority_fee_per_gas, 0);
+        assert_eq!(fees.blob_base_fee_per_gas, None);
+        assert_eq!(fees.pubdata_price, Some(1_000_000));
+
+        // The gas price must cover the pubdata price within the default gas-per-pubdata limit.
+        let pubdata_price = 1_000 * DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE + 1;
+        let fees = oracle(1_000, pubdata_price)
+            .calculate_fees(&None, 0, OperatorType::Gateway)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_001);
+
+        let previous_sent_tx = TxHistory {
+            id: 1,
+            eth_tx_id: 1,
+            base_fee_per_gas: 1_000,
+            priority_fee_per_gas: 0,
+            blob_base_fee_per_gas: None,
+            tx_hash: H256::zero(),
+            signed_raw_tx: vec![],
+            sent_at_block: Some(1),
+        };
+        let fees = oracle(1_000, 1_000_000)
+            .calculate_fees(&Some(previous_sent_tx), 10, OperatorType::Gateway)
+            .unwrap();
+        assert_eq!(fees.base_fee_per_gas, 1_201);
+    }
+}
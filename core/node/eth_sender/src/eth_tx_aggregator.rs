@@ -29,6 +29,7 @@ use zksync_types::{
 use super::aggregated_operations::AggregatedOperation;
 use crate::{
     aggregator::OperationSkippingRestrictions,
+    commit_postponement::CommitPostponementPolicy,
     health::{EthTxAggregatorHealthDetails, EthTxDetails},
     metrics::{PubdataKind, METRICS},
     publish_criterion::L1GasCriterion,
@@ -81,6 +82,7 @@ pub struct EthTxAggregator {
     settlement_mode: SettlementMode,
     sl_chain_id: SLChainId,
     health_updater: HealthUpdater,
+    commit_postponement_policy: Option<CommitPostponementPolicy>,
 }
 
 struct TxData {
@@ -140,9 +142,16 @@ impl EthTxAggregator {
             settlement_mode,
             sl_chain_id,
             health_updater: ReactiveHealthCheck::new("eth_tx_aggregator").1,
+            commit_postponement_policy: None,
         }
     }
 
+    /// Postpones commit transactions according to the provided policy, e.g. during L1 gas price spikes.
+    pub fn with_commit_postponement_policy(mut self, policy: CommitPostponementPolicy) -> Self {
+        self.commit_postponement_policy = Some(policy);
+        self
+    }
+
     pub async fn run(mut self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         self.health_updater
             .update(Health::from(HealthStatus::Ready));
@@ -566,6 +575,11 @@ impl EthTxAggregator {
             .await
             .then_some("there is a pending gateway upgrade"),
         };
+        if op_restrictions.commit_restriction.is_none() {
+            if let Some(policy) = &mut self.commit_postponement_policy {
+                op_restrictions.commit_restriction = policy.commit_restriction(storage).await;
+            }
+        }
         if self.config.tx_aggregation_paused {
             let reason = Some("tx aggregation is paused");
             op_restrictions.commit_restriction = reason;
@@ -815,10 +829,9 @@ impl EthTxAggregator {
         storage: &mut Connection<'_, Core>,
         from_addr: Option<Address>,
     ) -> Result<u64, EthSenderError> {
-        let is_gateway = self.settlement_mode.is_gateway();
         let db_nonce = storage
             .eth_sender_dal()
-            .get_next_nonce(from_addr, is_gateway)
+            .get_next_nonce(from_addr, self.settlement_mode)
             .await
             .unwrap()
             .unwrap_or(0);
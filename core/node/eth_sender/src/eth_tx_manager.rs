@@ -12,7 +12,9 @@ use zksync_eth_client::{
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_node_fee_model::l1_gas_price::TxParamsProvider;
 use zksync_shared_metrics::BlockL1Stage;
-use zksync_types::{eth_sender::EthTx, Address, L1BlockNumber, H256, U256};
+use zksync_types::{
+    eth_sender::EthTx, settlement::SettlementMode, Address, L1BlockNumber, H256, U256,
+};
 
 use super::{metrics::METRICS, EthSenderError};
 use crate::{
@@ -39,6 +41,10 @@ pub struct EthTxManager {
 }
 
 impl EthTxManager {
+    /// Creates a new manager. `previous_ethereum_gateway` and `previous_ethereum_gateway_blobs` are signing clients
+    /// for operator keys that are being rotated out; transactions signed by these keys are tracked (and resent
+    /// if necessary) until they are confirmed, but no new transactions are created for them.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: ConnectionPool<Core>,
         config: SenderConfig,
@@ -46,10 +52,16 @@ impl EthTxManager {
         ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
         ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
         l2_gateway: Option<Box<dyn BoundEthInterface>>,
+        previous_ethereum_gateway: Option<Box<dyn BoundEthInterface>>,
+        previous_ethereum_gateway_blobs: Option<Box<dyn BoundEthInterface>>,
     ) -> Self {
         let ethereum_gateway = ethereum_gateway.map(|eth| eth.for_component("eth_tx_manager"));
         let ethereum_gateway_blobs =
             ethereum_gateway_blobs.map(|eth| eth.for_component("eth_tx_manager"));
+        let previous_ethereum_gateway =
+            previous_ethereum_gateway.map(|eth| eth.for_component("eth_tx_manager"));
+        let previous_ethereum_gateway_blobs =
+            previous_ethereum_gateway_blobs.map(|eth| eth.for_component("eth_tx_manager"));
         let fees_oracle = GasAdjusterFeesOracle {
             gas_adjuster,
             max_acceptable_priority_fee_in_gwei: config.max_acceptable_priority_fee_in_gwei,
@@ -59,6 +71,8 @@ impl EthTxManager {
             ethereum_gateway,
             ethereum_gateway_blobs,
             l2_gateway,
+            previous_ethereum_gateway,
+            previous_ethereum_gateway_blobs,
             wait_confirmations: config.wait_confirmations,
         });
         tracing::info!(
@@ -131,7 +145,7 @@ impl EthTxManager {
             base_fee_per_gas,
             priority_fee_per_gas,
             blob_base_fee_per_gas,
-            pubdata_price: _,
+            pubdata_price,
         } = self.fees_oracle.calculate_fees(
             &previous_sent_tx,
             time_in_mempool_in_l1_blocks,
@@ -175,6 +189,9 @@ impl EthTxManager {
             METRICS.used_blob_fee_per_gas[&TransactionType::Blob].observe(blob_base_fee_per_gas);
             METRICS.used_base_fee_per_gas[&TransactionType::Blob].observe(base_fee_per_gas);
             METRICS.used_priority_fee_per_gas[&TransactionType::Blob].observe(priority_fee_per_gas);
+        } else if let Some(pubdata_price) = pubdata_price {
+            METRICS.used_base_fee_per_gas[&TransactionType::Gateway].observe(base_fee_per_gas);
+            METRICS.used_pubdata_price[&TransactionType::Gateway].observe(pubdata_price);
         } else {
             METRICS.used_base_fee_per_gas[&TransactionType::Regular].observe(base_fee_per_gas);
             METRICS.used_priority_fee_per_gas[&TransactionType::Regular]
@@ -269,10 +286,12 @@ impl EthTxManager {
     }
 
     pub(crate) fn operator_address(&self, operator_type: OperatorType) -> Option<Address> {
-        if operator_type == OperatorType::Blob {
-            self.l1_interface.get_blobs_operator_account()
-        } else {
-            None
+        match operator_type {
+            OperatorType::NonBlob | OperatorType::Gateway => None,
+            OperatorType::Blob => self.l1_interface.get_blobs_operator_account(),
+            OperatorType::PreviousNonBlob | OperatorType::PreviousBlob => self
+                .l1_interface
+                .get_previous_operator_account(operator_type),
         }
     }
     // Monitors the in-flight transactions, marks mined ones as confirmed,
@@ -293,7 +312,7 @@ impl EthTxManager {
                 .eth_sender_dal()
                 .get_inflight_txs(
                     self.operator_address(operator_type),
-                    operator_type == OperatorType::Gateway,
+                    operator_type.settlement_mode(),
                 )
                 .await
                 .unwrap();
@@ -448,6 +467,10 @@ impl EthTxManager {
             OperatorType::Gateway
         } else if tx.from_addr.is_none() {
             OperatorType::NonBlob
+        } else if tx.from_addr == self.operator_address(OperatorType::PreviousNonBlob) {
+            OperatorType::PreviousNonBlob
+        } else if tx.from_addr == self.operator_address(OperatorType::PreviousBlob) {
+            OperatorType::PreviousBlob
         } else {
             OperatorType::Blob
         }
@@ -516,6 +539,15 @@ impl EthTxManager {
         );
         let tx_type_label = tx.tx_type.into();
         METRICS.l1_gas_used[&tx_type_label].observe(gas_used.low_u128() as f64);
+        let l1_batch_count = storage
+            .blocks_dal()
+            .get_l1_batch_count_for_eth_tx_id(tx.id)
+            .await
+            .unwrap();
+        if l1_batch_count > 0 {
+            METRICS.l1_gas_used_per_l1_batch[&tx_type_label]
+                .observe(gas_used.low_u128() as f64 / l1_batch_count as f64);
+        }
 
         let duration_since_epoch = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
@@ -539,6 +571,8 @@ impl EthTxManager {
             .update(Health::from(HealthStatus::Ready));
 
         let pool = self.pool.clone();
+        self.assign_txs_to_previous_operator(&mut pool.connection_tagged("eth_sender").await?)
+            .await?;
 
         loop {
             let mut storage = pool.connection_tagged("eth_sender").await.unwrap();
@@ -560,6 +594,27 @@ impl EthTxManager {
         Ok(())
     }
 
+    /// If the main operator key is being rotated, marks transactions sent by the main operator before the rotation
+    /// as belonging to the previous operator, so that they are tracked using the previous key.
+    pub(crate) async fn assign_txs_to_previous_operator(
+        &self,
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<()> {
+        let Some(previous_operator) = self.operator_address(OperatorType::PreviousNonBlob) else {
+            return Ok(());
+        };
+        let reassigned_count = storage
+            .eth_sender_dal()
+            .assign_main_operator_txs_to_previous_operator(previous_operator)
+            .await?;
+        if reassigned_count > 0 {
+            tracing::info!(
+                "Assigned {reassigned_count} transactions of the main operator to the previous operator {previous_operator:?}"
+            );
+        }
+        Ok(())
+    }
+
     async fn send_new_eth_txs(
         &mut self,
         storage: &mut Connection<'_, Core>,
@@ -568,13 +623,12 @@ impl EthTxManager {
     ) {
         let number_inflight_txs = storage
             .eth_sender_dal()
-            .get_inflight_txs(
+            .get_inflight_txs_count(
                 self.operator_address(operator_type),
-                operator_type == OperatorType::Gateway,
+                operator_type.settlement_mode(),
             )
             .await
-            .unwrap()
-            .len();
+            .unwrap();
         let number_of_available_slots_for_eth_txs = self
             .config
             .max_txs_in_flight
@@ -587,7 +641,7 @@ impl EthTxManager {
                 .get_new_eth_txs(
                     number_of_available_slots_for_eth_txs,
                     &self.operator_address(operator_type),
-                    operator_type == OperatorType::Gateway,
+                    operator_type.settlement_mode(),
                 )
                 .await
                 .unwrap();
@@ -655,7 +709,7 @@ impl EthTxManager {
 
         let inflight_count = storage
             .eth_sender_dal()
-            .get_non_gateway_inflight_txs_count_for_gateway_migration()
+            .get_unconfirmed_txs_count_for_settlement_mode(SettlementMode::SettlesToL1)
             .await
             .unwrap();
         if inflight_count != 0 {
@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 use zksync_eth_client::ExecutedTxStatus;
 use zksync_health_check::{Health, HealthStatus};
 use zksync_types::{
-    aggregated_operations::AggregatedActionType, eth_sender::EthTx, web3::TransactionReceipt,
-    L1BlockNumber, Nonce, H256,
+    aggregated_operations::AggregatedActionType, eth_sender::EthTx,
+    settlement::SettlementLayerData, web3::TransactionReceipt, L1BlockNumber, Nonce, H256,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,3 +66,15 @@ impl From<EthTxManagerHealthDetails> for Health {
         Self::from(HealthStatus::Ready).with_details(details)
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SettlementLayerHealthDetails {
+    #[serde(flatten)]
+    pub data: SettlementLayerData,
+}
+
+impl From<SettlementLayerHealthDetails> for Health {
+    fn from(details: SettlementLayerHealthDetails) -> Self {
+        Self::from(HealthStatus::Ready).with_details(details)
+    }
+}
@@ -1,11 +1,13 @@
 mod aggregated_operations;
 mod aggregator;
+mod commit_postponement;
 mod error;
 mod eth_tx_aggregator;
 mod eth_tx_manager;
 mod health;
 mod metrics;
 mod publish_criterion;
+mod settlement_layer_health;
 mod zksync_functions;
 
 mod abstract_l1_interface;
@@ -18,6 +20,7 @@ mod tests;
 mod tester;
 
 pub use self::{
-    aggregator::Aggregator, error::EthSenderError, eth_tx_aggregator::EthTxAggregator,
-    eth_tx_manager::EthTxManager,
+    aggregator::Aggregator, commit_postponement::CommitPostponementPolicy, error::EthSenderError,
+    eth_tx_aggregator::EthTxAggregator, eth_tx_manager::EthTxManager,
+    settlement_layer_health::SettlementLayerHealthTask,
 };
@@ -40,6 +40,7 @@ pub(super) struct ActionTypeLabel(AggregatedActionType);
 pub(super) enum TransactionType {
     Blob,
     Regular,
+    Gateway,
 }
 
 impl From<AggregatedActionType> for ActionTypeLabel {
@@ -97,12 +98,18 @@ pub(super) struct EthSenderMetrics {
     pub used_priority_fee_per_gas: Family<TransactionType, Histogram<u64>>,
     #[metrics(buckets = FEE_BUCKETS)]
     pub used_blob_fee_per_gas: Family<TransactionType, Histogram<u64>>,
+    /// Price per pubdata byte used for settlement layer transactions; only reported for Gateway transactions.
+    #[metrics(buckets = FEE_BUCKETS)]
+    pub used_pubdata_price: Family<TransactionType, Histogram<u64>>,
     /// Last L1 block observed by the Ethereum sender.
     pub last_known_l1_block: Family<BlockNumberVariant, Gauge<usize>>,
     /// Number of in-flight txs produced by the Ethereum sender.
     pub number_of_inflight_txs: Family<OperatorType, Gauge<usize>>,
     #[metrics(buckets = GAS_BUCKETS)]
     pub l1_gas_used: Family<ActionTypeLabel, Histogram<f64>>,
+    /// Gas used by a settlement layer transaction divided by the number of L1 batches processed by it.
+    #[metrics(buckets = GAS_BUCKETS)]
+    pub l1_gas_used_per_l1_batch: Family<ActionTypeLabel, Histogram<f64>>,
     #[metrics(buckets = Buckets::LATENCIES)]
     pub l1_tx_mined_latency: Family<ActionTypeLabel, Histogram<Duration>>,
     #[metrics(buckets = & [1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0, 50.0])]
@@ -110,6 +117,13 @@ pub(super) struct EthSenderMetrics {
     /// Number of L1 batches aggregated for publishing with a specific reason.
     pub block_aggregation_reason: Family<AggregationReasonLabels, Counter>,
     pub l1_transient_errors: Counter,
+    /// Whether commit transactions are currently postponed because of a high L1 base fee.
+    pub commit_postponement_active: Gauge<u64>,
+    /// Time for which commit transactions were postponed because of a high L1 base fee.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub commit_postponement_time: Histogram<Duration>,
+    /// Lower-bound estimate of fees saved by postponing commit transactions, in gwei.
+    pub commit_postponement_savings_gwei: Counter,
 }
 
 impl EthSenderMetrics {
@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
+use zksync_health_check::{HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{
+    settlement::{SettlementLayerData, SettlementMode},
+    SLChainId,
+};
+
+use crate::health::SettlementLayerHealthDetails;
+
+/// Reports the settlement layer used by the node as a health check component, so that it's possible
+/// to tell whether the chain settles to L1 or to Gateway without inspecting logs. The same data is available
+/// to other components via [`Self::subscribe()`].
+#[derive(Debug)]
+pub struct SettlementLayerHealthTask {
+    pool: ConnectionPool<Core>,
+    settlement_mode: SettlementMode,
+    sl_chain_id: SLChainId,
+    poll_interval: Duration,
+    health_updater: HealthUpdater,
+    data_sender: watch::Sender<Option<SettlementLayerData>>,
+}
+
+impl SettlementLayerHealthTask {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        settlement_mode: SettlementMode,
+        sl_chain_id: SLChainId,
+    ) -> Self {
+        Self {
+            pool,
+            settlement_mode,
+            sl_chain_id,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            health_updater: ReactiveHealthCheck::new("settlement_layer").1,
+            data_sender: watch::channel(None).0,
+        }
+    }
+
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    /// Subscribes to settlement layer data. The data is `None` until the task is run.
+    pub fn subscribe(&self) -> watch::Receiver<Option<SettlementLayerData>> {
+        self.data_sender.subscribe()
+    }
+
+    /// Checks whether the node is migrating to the configured settlement layer, i.e. whether there are transactions
+    /// for the previous settlement layer that still need to be confirmed, or no transactions were created
+    /// for the configured settlement layer yet.
+    async fn is_migration_pending(
+        &self,
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<bool> {
+        let previous_settlement_mode = if self.settlement_mode.is_gateway() {
+            SettlementMode::SettlesToL1
+        } else {
+            SettlementMode::Gateway
+        };
+        let mut eth_sender_dal = storage.eth_sender_dal();
+        let latest_settlement_mode = eth_sender_dal
+            .get_latest_settlement_mode()
+            .await
+            .map_err(DalError::generalize)?;
+        if latest_settlement_mode.is_some_and(|mode| mode != self.settlement_mode) {
+            return Ok(true);
+        }
+        let inflight_txs_count = eth_sender_dal
+            .get_unconfirmed_txs_count_for_settlement_mode(previous_settlement_mode)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(inflight_txs_count > 0)
+    }
+
+    async fn update_health(&self) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("eth_sender").await?;
+        let migration_pending = self.is_migration_pending(&mut storage).await?;
+        drop(storage);
+
+        let data = SettlementLayerData {
+            settlement_mode: self.settlement_mode,
+            sl_chain_id: self.sl_chain_id,
+            migration_pending,
+        };
+        self.health_updater
+            .update(SettlementLayerHealthDetails { data }.into());
+        self.data_sender.send_replace(Some(data));
+        Ok(())
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow() {
+            if let Err(err) = self.update_health().await {
+                tracing::warn!("Failed updating settlement layer health: {err:#}");
+            }
+
+            if tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, settlement layer health task is shutting down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_health_check::{CheckHealth, HealthStatus};
+    use zksync_types::aggregated_operations::AggregatedActionType;
+
+    use super::*;
+
+    async fn save_eth_tx(storage: &mut Connection<'_, Core>, is_gateway: bool) {
+        storage
+            .eth_sender_dal()
+            .save_eth_tx(
+                0,
+                vec![],
+                AggregatedActionType::Commit,
+                Default::default(),
+                None,
+                None,
+                None,
+                is_gateway,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reporting_pending_migration() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let task =
+            SettlementLayerHealthTask::new(pool.clone(), SettlementMode::Gateway, SLChainId(505));
+        let health_check = task.health_check();
+        let data = task.subscribe();
+        assert_eq!(*data.borrow(), None);
+        let mut storage = pool.connection().await.unwrap();
+
+        task.update_health().await.unwrap();
+        let health = health_check.check_health().await;
+        assert_eq!(health.status(), HealthStatus::Ready);
+        let details = health.details().unwrap();
+        assert_eq!(details["settlement_mode"], "Gateway");
+        assert_eq!(details["sl_chain_id"], 505);
+        assert_eq!(details["migration_pending"], false);
+        assert_eq!(
+            *data.borrow(),
+            Some(SettlementLayerData {
+                settlement_mode: SettlementMode::Gateway,
+                sl_chain_id: SLChainId(505),
+                migration_pending: false,
+            })
+        );
+
+        // An unconfirmed transaction for L1 blocks the migration.
+        save_eth_tx(&mut storage, false).await;
+        task.update_health().await.unwrap();
+        let health = health_check.check_health().await;
+        assert_eq!(health.details().unwrap()["migration_pending"], true);
+        assert!(data.borrow().unwrap().migration_pending);
+    }
+}
@@ -25,6 +25,8 @@ use crate::{
 
 pub(super) const STATE_TRANSITION_CONTRACT_ADDRESS: Address = Address::repeat_byte(0xa0);
 pub(super) const STATE_TRANSITION_MANAGER_CONTRACT_ADDRESS: Address = Address::repeat_byte(0xb0);
+/// Blob operator must differ from the main operator, so that operator key rotation can be tested.
+const BLOB_OPERATOR_ADDRESS: Address = Address::repeat_byte(0xb1);
 
 // Alias to conveniently call static methods of `ETHSender`.
 type MockEthTxManager = EthTxManager;
@@ -115,10 +117,14 @@ pub(crate) struct EthSenderTester {
     pub gateway: Box<MockSettlementLayer>,
     pub gateway_blobs: Box<MockSettlementLayer>,
     pub l2_gateway: Box<MockSettlementLayer>,
+    /// Gateway for the main operator key that is being rotated out; set by [`Self::rotate_operator_key()`].
+    pub previous_gateway: Option<Box<MockSettlementLayer>>,
     pub manager: MockEthTxManager,
     pub aggregator: EthTxAggregator,
     pub gas_adjuster: Arc<GasAdjuster>,
     pub pubdata_sending_mode: PubdataSendingMode,
+    fee_history: Vec<BaseFees>,
+    non_ordering_confirmations: bool,
     next_l1_batch_number_to_seal: L1BatchNumber,
     next_l1_batch_number_to_commit: L1BatchNumber,
     next_l1_batch_number_to_prove: L1BatchNumber,
@@ -208,7 +214,7 @@ impl EthSenderTester {
                     l2_pubdata_price: 0.into(),
                 })
                 .take(Self::WAIT_CONFIRMATIONS as usize)
-                .chain(history)
+                .chain(history.clone())
                 .collect(),
             )
             .with_non_ordering_confirmation(non_ordering_confirmations)
@@ -216,6 +222,7 @@ impl EthSenderTester {
                 assert_eq!(call.to, Some(contracts_config.l1_multicall3_addr));
                 crate::tests::mock_multicall_response(call)
             })
+            .with_sender_account(BLOB_OPERATOR_ADDRESS)
             .build();
         gateway_blobs.advance_block_number(Self::WAIT_CONFIRMATIONS);
         let gateway_blobs = Box::new(gateway_blobs);
@@ -285,6 +292,8 @@ impl EthSenderTester {
             Some(gateway.clone()),
             Some(gateway_blobs.clone()),
             None,
+            None,
+            None,
         );
 
         let connection_pool_clone = connection_pool.clone();
@@ -299,11 +308,14 @@ impl EthSenderTester {
             gateway,
             gateway_blobs,
             l2_gateway,
+            previous_gateway: None,
             manager,
             aggregator,
             gas_adjuster,
             conn: connection_pool,
             pubdata_sending_mode,
+            fee_history: history,
+            non_ordering_confirmations,
             next_l1_batch_number_to_seal: L1BatchNumber(0),
             next_l1_batch_number_to_commit: L1BatchNumber(1),
             next_l1_batch_number_to_execute: L1BatchNumber(1),
@@ -321,11 +333,55 @@ impl EthSenderTester {
             None,
             None,
             Some(self.l2_gateway.clone()),
+            None,
+            None,
         );
         self.is_l2 = true;
         tracing::info!("Switched eth-sender tester to use Gateway!");
     }
 
+    /// Emulates rotation of the main operator key: restarts the manager with a new main operator, passing
+    /// the current main operator as the previous one.
+    pub async fn rotate_operator_key(&mut self, new_operator: Address) {
+        let contracts_config = ContractsConfig::for_tests();
+        let gateway = MockSettlementLayer::builder()
+            .with_fee_history(
+                std::iter::repeat_with(|| BaseFees {
+                    base_fee_per_gas: 1,
+                    base_fee_per_blob_gas: 1.into(),
+                    l2_pubdata_price: 0.into(),
+                })
+                .take(Self::WAIT_CONFIRMATIONS as usize)
+                .chain(self.fee_history.clone())
+                .collect(),
+            )
+            .with_non_ordering_confirmation(self.non_ordering_confirmations)
+            .with_call_handler(move |call, _| {
+                assert_eq!(call.to, Some(contracts_config.l1_multicall3_addr));
+                crate::tests::mock_multicall_response(call)
+            })
+            .with_sender_account(new_operator)
+            .build();
+        gateway.advance_block_number(self.gateway.advance_block_number(0));
+        let previous_gateway = std::mem::replace(&mut self.gateway, Box::new(gateway));
+
+        self.manager = EthTxManager::new(
+            self.conn.clone(),
+            EthConfig::for_tests().sender.unwrap(),
+            self.gas_adjuster.clone(),
+            Some(self.gateway.clone()),
+            Some(self.gateway_blobs.clone()),
+            None,
+            Some(previous_gateway.clone()),
+            None,
+        );
+        self.previous_gateway = Some(previous_gateway);
+        self.manager
+            .assign_txs_to_previous_operator(&mut self.storage().await)
+            .await
+            .unwrap();
+    }
+
     pub async fn storage(&self) -> Connection<'_, Core> {
         self.conn.connection().await.unwrap()
     }
@@ -464,16 +520,24 @@ impl EthSenderTester {
         self.gateway.advance_block_number(n);
         self.gateway_blobs.advance_block_number(n);
         self.l2_gateway.advance_block_number(n);
-        let tx_sent_before = self.gateway.sent_tx_count()
-            + self.gateway_blobs.sent_tx_count()
-            + self.l2_gateway.sent_tx_count();
+        if let Some(previous_gateway) = &self.previous_gateway {
+            previous_gateway.advance_block_number(n);
+        }
+        let tx_sent_before = self.sent_tx_count();
         self.manager
             .loop_iteration(&mut self.conn.connection().await.unwrap())
             .await;
-        self.tx_sent_in_last_iteration_count = (self.gateway.sent_tx_count()
+        self.tx_sent_in_last_iteration_count = self.sent_tx_count() - tx_sent_before;
+    }
+
+    fn sent_tx_count(&self) -> usize {
+        self.gateway.sent_tx_count()
             + self.gateway_blobs.sent_tx_count()
-            + self.l2_gateway.sent_tx_count())
-            - tx_sent_before;
+            + self.l2_gateway.sent_tx_count()
+            + self
+                .previous_gateway
+                .as_ref()
+                .map_or(0, |gateway| gateway.sent_tx_count())
     }
 
     pub async fn run_eth_sender_tx_manager_iteration(&mut self) {
@@ -613,26 +677,29 @@ impl EthSenderTester {
             self.storage()
                 .await
                 .eth_sender_dal()
-                .get_inflight_txs(self.manager.operator_address(OperatorType::NonBlob), false)
+                .get_inflight_txs_count(
+                    self.manager.operator_address(OperatorType::NonBlob),
+                    SettlementMode::SettlesToL1,
+                )
                 .await
                 .unwrap()
-                .len()
                 + self
                     .storage()
                     .await
                     .eth_sender_dal()
-                    .get_inflight_txs(self.manager.operator_address(OperatorType::Blob), false)
+                    .get_inflight_txs_count(
+                        self.manager.operator_address(OperatorType::Blob),
+                        SettlementMode::SettlesToL1,
+                    )
                     .await
                     .unwrap()
-                    .len()
         } else {
             self.storage()
                 .await
                 .eth_sender_dal()
-                .get_inflight_txs(None, true)
+                .get_inflight_txs_count(None, SettlementMode::Gateway)
                 .await
                 .unwrap()
-                .len()
         };
 
         assert_eq!(
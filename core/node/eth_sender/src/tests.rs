@@ -1,10 +1,16 @@
 use assert_matches::assert_matches;
 use test_casing::{test_casing, Product};
+use zksync_config::{
+    configs::eth_sender::{ProofSendingMode, SenderConfig},
+    EthConfig,
+};
+use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_l1_contract_interface::{
     i_executor::methods::ExecuteBatches, multicall3::Multicall3Call, Tokenizable,
 };
 use zksync_node_test_utils::create_l1_batch;
+use zksync_object_store::MockObjectStore;
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
     block::L1BatchHeader,
@@ -13,13 +19,16 @@ use zksync_types::{
     },
     ethabi::{self, Token},
     helpers::unix_timestamp_ms,
+    protocol_version::L1VerifierConfig,
+    settlement::SettlementMode,
     web3::{self, contract::Error},
-    Address, ProtocolVersionId, H256,
+    Address, L1BatchNumber, ProtocolVersionId, H256,
 };
 
 use crate::{
     abstract_l1_interface::OperatorType,
     aggregated_operations::AggregatedOperation,
+    aggregator::{Aggregator, OperationSkippingRestrictions},
     tester::{
         EthSenderTester, TestL1Batch, STATE_TRANSITION_CONTRACT_ADDRESS,
         STATE_TRANSITION_MANAGER_CONTRACT_ADDRESS,
@@ -255,7 +264,7 @@ async fn resend_each_block(commitment_mode: L1BatchCommitmentMode) -> anyhow::Re
             .eth_sender_dal()
             .get_inflight_txs(
                 tester.manager.operator_address(OperatorType::NonBlob),
-                false
+                SettlementMode::SettlesToL1
             )
             .await
             .unwrap()
@@ -263,6 +272,20 @@ async fn resend_each_block(commitment_mode: L1BatchCommitmentMode) -> anyhow::Re
         1
     );
 
+    assert_eq!(
+        tester
+            .storage()
+            .await
+            .eth_sender_dal()
+            .get_max_used_nonce(
+                tester.manager.operator_address(OperatorType::NonBlob),
+                SettlementMode::SettlesToL1
+            )
+            .await
+            .unwrap(),
+        Some(0)
+    );
+
     let sent_tx = tester
         .manager
         .l1_interface()
@@ -311,7 +334,7 @@ async fn resend_each_block(commitment_mode: L1BatchCommitmentMode) -> anyhow::Re
             .eth_sender_dal()
             .get_inflight_txs(
                 tester.manager.operator_address(OperatorType::NonBlob),
-                false
+                SettlementMode::SettlesToL1
             )
             .await
             .unwrap()
@@ -570,6 +593,121 @@ async fn switching_to_gateway_works_for_most_basic_scenario() {
     tester.assert_inflight_txs_count_equals(0).await;
 }
 
+#[test_log::test(tokio::test)]
+async fn rotating_operator_key() {
+    let mut tester = EthSenderTester::new(
+        ConnectionPool::<Core>::test_pool().await,
+        vec![100; 100],
+        true,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let first_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    let second_l1_batch = TestL1Batch::sealed(&mut tester).await;
+
+    first_l1_batch.save_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    tester.assert_inflight_txs_count_equals(1).await;
+    let first_commit_tx = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_last_sent_eth_tx_hash(first_l1_batch.number, AggregatedActionType::Commit)
+        .await
+        .unwrap();
+
+    let previous_operator = tester.gateway.sender_account();
+    let new_operator = Address::repeat_byte(0x33);
+    tester.rotate_operator_key(new_operator).await;
+    assert_eq!(
+        tester
+            .manager
+            .operator_address(OperatorType::PreviousNonBlob),
+        Some(previous_operator)
+    );
+
+    // The in-flight transaction should be reassigned to the previous operator.
+    let mut storage = tester.storage().await;
+    let previous_operator_txs = storage
+        .eth_sender_dal()
+        .get_inflight_txs(Some(previous_operator), SettlementMode::SettlesToL1)
+        .await
+        .unwrap();
+    assert_eq!(previous_operator_txs.len(), 1);
+    assert_eq!(previous_operator_txs[0].id, first_commit_tx.eth_tx_id);
+    let main_operator_txs_count = storage
+        .eth_sender_dal()
+        .get_inflight_txs_count(None, SettlementMode::SettlesToL1)
+        .await
+        .unwrap();
+    assert_eq!(main_operator_txs_count, 0);
+    drop(storage);
+
+    // The transaction isn't mined, so it should be resent using the previous operator key.
+    tester.gateway.advance_block_number(1);
+    tester
+        .previous_gateway
+        .as_ref()
+        .unwrap()
+        .advance_block_number(1);
+    let block_numbers = tester.get_block_numbers().await;
+    let (to_resend, _) = tester
+        .manager
+        .monitor_inflight_transactions_single_operator(
+            &mut tester.conn.connection().await.unwrap(),
+            block_numbers,
+            OperatorType::PreviousNonBlob,
+        )
+        .await
+        .unwrap()
+        .expect("transaction of the previous operator is not resent");
+    assert_eq!(to_resend.id, first_commit_tx.eth_tx_id);
+    assert_eq!(to_resend.from_addr, Some(previous_operator));
+
+    // New transactions should be sent by the new operator.
+    second_l1_batch.save_commit_tx(&mut tester).await;
+    tester.run_eth_sender_tx_manager_iteration().await;
+    assert_eq!(tester.gateway.sent_tx_count(), 1);
+    tester
+        .assert_tx_was_sent_in_last_iteration(second_l1_batch.number, AggregatedActionType::Commit)
+        .await;
+
+    // Confirm the transaction sent by the previous operator.
+    let first_commit_tx = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_last_sent_eth_tx_hash(first_l1_batch.number, AggregatedActionType::Commit)
+        .await
+        .unwrap();
+    let previous_gateway = tester.previous_gateway.as_ref().unwrap();
+    previous_gateway.execute_tx(
+        first_commit_tx.tx_hash,
+        true,
+        EthSenderTester::WAIT_CONFIRMATIONS,
+    );
+    tester.run_eth_sender_tx_manager_iteration().await;
+    let previous_operator_txs_count = tester
+        .storage()
+        .await
+        .eth_sender_dal()
+        .get_inflight_txs_count(Some(previous_operator), SettlementMode::SettlesToL1)
+        .await
+        .unwrap();
+    assert_eq!(previous_operator_txs_count, 0);
+
+    // Restarting the manager must not reassign transactions of the new operator.
+    tester
+        .manager
+        .assign_txs_to_previous_operator(&mut tester.storage().await)
+        .await
+        .unwrap();
+    tester.assert_inflight_txs_count_equals(1).await;
+}
+
 #[test_casing(2, COMMITMENT_MODES)]
 #[test_log::test(tokio::test)]
 async fn correct_order_for_confirmations(
@@ -896,3 +1034,65 @@ async fn get_multicall_data(commitment_mode: L1BatchCommitmentMode) {
     assert_eq!(data.verifier_address, Address::repeat_byte(5));
     assert_eq!(data.chain_protocol_version_id, ProtocolVersionId::latest());
 }
+
+#[tokio::test]
+async fn aggregating_dummy_proofs() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    let mut tester = EthSenderTester::new(
+        connection_pool.clone(),
+        vec![100; 100],
+        false,
+        false,
+        L1BatchCommitmentMode::Rollup,
+    )
+    .await;
+
+    let _genesis_l1_batch = TestL1Batch::sealed(&mut tester).await;
+    for _ in 0..4 {
+        let l1_batch = TestL1Batch::sealed(&mut tester).await;
+        l1_batch.commit(&mut tester, true).await;
+    }
+
+    let config = SenderConfig {
+        proof_sending_mode: ProofSendingMode::SkipEveryProof,
+        max_aggregated_skipped_proofs: 3,
+        ..EthConfig::for_tests().sender.unwrap()
+    };
+    let mut aggregator = Aggregator::new(
+        config,
+        MockObjectStore::arc(),
+        None,
+        L1BatchCommitmentMode::Rollup,
+        connection_pool.clone(),
+        tester.gateway.clone(),
+        SettlementMode::SettlesToL1,
+    )
+    .await
+    .unwrap();
+
+    let mut storage = connection_pool.connection().await.unwrap();
+    let operation = aggregator
+        .get_next_ready_operation(
+            &mut storage,
+            BaseSystemContractsHashes::default(),
+            ProtocolVersionId::latest(),
+            L1VerifierConfig::default(),
+            OperationSkippingRestrictions::default(),
+        )
+        .await
+        .unwrap()
+        .expect("no operation");
+    assert_matches!(&operation, AggregatedOperation::PublishProofOnchain(_));
+    assert_eq!(
+        operation.l1_batch_range(),
+        L1BatchNumber(1)..=L1BatchNumber(3)
+    );
+
+    let tx = tester.save_operation(operation).await;
+    let l1_batch_count = storage
+        .blocks_dal()
+        .get_l1_batch_count_for_eth_tx_id(tx.id)
+        .await
+        .unwrap();
+    assert_eq!(l1_batch_count, 3);
+}
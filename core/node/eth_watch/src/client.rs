@@ -106,6 +106,7 @@ pub struct EthHttpQueryClient<Net: Network> {
     // Only present for post-shared bridge chains.
     state_transition_manager_address: Option<Address>,
     chain_admin_address: Option<Address>,
+    address_policy_registry_address: Option<Address>,
     verifier_contract_abi: Contract,
     getters_facet_contract_abi: Contract,
     message_root_abi: Contract,
@@ -128,6 +129,7 @@ where
         l1_shared_bridge_addr: Option<Address>,
         state_transition_manager_address: Option<Address>,
         chain_admin_address: Option<Address>,
+        address_policy_registry_address: Option<Address>,
         governance_address: Address,
         confirmations_for_eth_event: Option<u64>,
         l2_chain_id: L2ChainId,
@@ -142,6 +144,7 @@ where
             diamond_proxy_addr,
             state_transition_manager_address,
             chain_admin_address,
+            address_policy_registry_address,
             governance_address,
             bytecode_supplier_addr,
             new_upgrade_cut_data_signature: state_transition_manager_contract()
@@ -172,6 +175,7 @@ where
             Some(self.governance_address),
             self.state_transition_manager_address,
             self.chain_admin_address,
+            self.address_policy_registry_address,
             Some(L2_MESSAGE_ROOT_ADDRESS),
         ]
         .into_iter()
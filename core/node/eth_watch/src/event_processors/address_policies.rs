@@ -0,0 +1,116 @@
+use zksync_dal::{
+    address_policies_dal::AddressPolicy, eth_watcher_dal::EventType, Connection, Core, CoreDal,
+    DalError,
+};
+use zksync_types::{api::Log, ethabi, h256_to_address, Address, L1BlockNumber, H256, U256};
+
+use crate::event_processors::{EventProcessor, EventProcessorError, EventsSource};
+
+/// Listens to `AddressPolicySet(address indexed account, uint8 policy)` events emitted by the address policy registry
+/// and mirrors the policies to the database, so that they can be enforced by transaction admission.
+///
+/// Policy values are interpreted as follows: 0 removes any policy, 1 greylists the address, 2 blacklists it.
+#[derive(Debug)]
+pub struct AddressPoliciesEventProcessor {
+    registry_address: Address,
+    address_policy_set_signature: H256,
+}
+
+impl AddressPoliciesEventProcessor {
+    pub fn new(registry_address: Address) -> Self {
+        Self {
+            registry_address,
+            address_policy_set_signature: ethabi::long_signature(
+                "AddressPolicySet",
+                &[ethabi::ParamType::Address, ethabi::ParamType::Uint(8)],
+            ),
+        }
+    }
+
+    fn parse_policy(event: &Log) -> anyhow::Result<(Address, Option<AddressPolicy>)> {
+        let account = event
+            .topics
+            .get(1)
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("missing topic 1"))?;
+        anyhow::ensure!(
+            event.data.0.len() == 32,
+            "unexpected data length: {}",
+            event.data.0.len()
+        );
+        let value = U256::from_big_endian(&event.data.0);
+        anyhow::ensure!(
+            value <= U256::from(u32::MAX),
+            "policy value {value} is out of range"
+        );
+        let policy = match value.as_u32() {
+            0 => None,
+            1 => Some(AddressPolicy::Greylisted),
+            2 => Some(AddressPolicy::Blacklisted),
+            value => anyhow::bail!("unknown policy value: {value}"),
+        };
+        Ok((h256_to_address(&account), policy))
+    }
+}
+
+#[async_trait::async_trait]
+impl EventProcessor for AddressPoliciesEventProcessor {
+    async fn process_events(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+        events: Vec<Log>,
+    ) -> Result<usize, EventProcessorError> {
+        let events_count = events.len();
+        let mut transaction = storage
+            .start_transaction()
+            .await
+            .map_err(DalError::generalize)?;
+
+        for event in &events {
+            assert_eq!(event.topics[0], self.address_policy_set_signature); // guaranteed by the watcher
+            if event.address != self.registry_address {
+                // The event signature may be shared with other watched contracts.
+                continue;
+            }
+
+            let (account, policy) = Self::parse_policy(event)
+                .map_err(|err| EventProcessorError::log_parse(err, "address policy"))?;
+            let block_number = event.block_number.ok_or_else(|| {
+                EventProcessorError::log_parse(
+                    anyhow::anyhow!("missing block number"),
+                    "address policy",
+                )
+            })?;
+            if block_number > u32::MAX.into() {
+                return Err(EventProcessorError::log_parse(
+                    anyhow::anyhow!("block number {block_number} is out of range"),
+                    "address policy",
+                ));
+            }
+            let block_number = block_number.as_u32();
+            tracing::info!(
+                "Setting policy for address {account:?} to {policy:?} (L1 block #{block_number})"
+            );
+            transaction
+                .address_policies_dal()
+                .set_address_policy(account, policy, L1BlockNumber(block_number))
+                .await
+                .map_err(DalError::generalize)?;
+        }
+
+        transaction.commit().await.map_err(DalError::generalize)?;
+        Ok(events_count)
+    }
+
+    fn topic1(&self) -> H256 {
+        self.address_policy_set_signature
+    }
+
+    fn event_source(&self) -> EventsSource {
+        EventsSource::L1
+    }
+
+    fn event_type(&self) -> EventType {
+        EventType::AddressPolicies
+    }
+}
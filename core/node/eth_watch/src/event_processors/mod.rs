@@ -5,11 +5,12 @@ use zksync_eth_client::{ContractCallError, EnrichedClientError};
 use zksync_types::{api::Log, H256};
 
 pub(crate) use self::{
-    appended_chain_batch_root::BatchRootProcessor,
+    address_policies::AddressPoliciesEventProcessor, appended_chain_batch_root::BatchRootProcessor,
     decentralized_upgrades::DecentralizedUpgradesEventProcessor,
     priority_ops::PriorityOpsEventProcessor,
 };
 
+mod address_policies;
 mod appended_chain_batch_root;
 mod decentralized_upgrades;
 mod priority_ops;
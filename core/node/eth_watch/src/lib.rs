@@ -5,23 +5,27 @@
 use std::{sync::Arc, time::Duration};
 
 use anyhow::Context as _;
+use serde::Serialize;
 use tokio::sync::watch;
-use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalError};
+use zksync_dal::{eth_watcher_dal::EventType, Connection, ConnectionPool, Core, CoreDal, DalError};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_mini_merkle_tree::MiniMerkleTree;
 use zksync_system_constants::PRIORITY_EXPIRATION;
 use zksync_types::{
     ethabi::Contract, protocol_version::ProtocolSemanticVersion,
-    web3::BlockNumber as Web3BlockNumber, L1BatchNumber, L2ChainId, PriorityOpId,
+    web3::BlockNumber as Web3BlockNumber, Address, L1BatchNumber, L2ChainId, PriorityOpId,
+    SLChainId,
 };
 
 pub use self::client::{EthClient, EthHttpQueryClient, L2EthClient};
 use self::{
     client::{L2EthClientW, RETRY_LIMIT},
     event_processors::{EventProcessor, EventProcessorError, PriorityOpsEventProcessor},
-    metrics::METRICS,
+    metrics::{ProcessorKind, METRICS},
 };
 use crate::event_processors::{
-    BatchRootProcessor, DecentralizedUpgradesEventProcessor, EventsSource,
+    AddressPoliciesEventProcessor, BatchRootProcessor, DecentralizedUpgradesEventProcessor,
+    EventsSource,
 };
 
 mod client;
@@ -38,13 +42,117 @@ struct EthWatchState {
     batch_merkle_tree: MiniMerkleTree<[u8; 96]>,
 }
 
+/// Health details reported separately for each [`EventProcessor`].
+#[derive(Debug, Default, Serialize)]
+struct EventProcessorHealthDetails {
+    /// Last settlement layer block fully processed by the processor.
+    last_processed_block: Option<u64>,
+    /// Number of blocks between the last processed block and the latest confirmed block.
+    lag_behind_head: Option<u64>,
+    /// Number of blocks between the last processed block and the latest finalized block.
+    lag_behind_finalized: Option<u64>,
+    /// Number of events processed during the latest poll.
+    events_processed_last_interval: usize,
+    /// Last error encountered by the processor. Not reset on subsequent successful polls;
+    /// use the health status to check whether the processor currently experiences errors.
+    last_error: Option<String>,
+}
+
+/// Progress made by an [`EventProcessor`] during a single poll.
+#[derive(Debug)]
+struct EventProcessorProgress {
+    last_processed_block: u64,
+    confirmed_block: u64,
+    finalized_block: u64,
+    processed_events: usize,
+}
+
+/// Chain ID and head block numbers of an events source, shared by all processors watching it
+/// during a single poll.
+#[derive(Debug, Clone, Copy)]
+struct ChainHead {
+    chain_id: SLChainId,
+    confirmed_block: u64,
+    finalized_block: u64,
+}
+
+impl ChainHead {
+    async fn fetch(client: &dyn EthClient) -> Result<Self, EventProcessorError> {
+        Ok(Self {
+            chain_id: client.chain_id().await?,
+            confirmed_block: client.confirmed_block_number().await?,
+            finalized_block: client.finalized_block_number().await?,
+        })
+    }
+}
+
+/// [`EventProcessor`] together with its health reporting.
+#[derive(Debug)]
+struct WatchedEventProcessor {
+    processor: Box<dyn EventProcessor>,
+    health_updater: HealthUpdater,
+    health_details: EventProcessorHealthDetails,
+}
+
+impl WatchedEventProcessor {
+    fn new(processor: Box<dyn EventProcessor>) -> Self {
+        let health_check_name = Self::health_check_name(processor.event_type());
+        Self {
+            processor,
+            health_updater: ReactiveHealthCheck::new(health_check_name).1,
+            health_details: EventProcessorHealthDetails::default(),
+        }
+    }
+
+    fn health_check_name(event_type: EventType) -> &'static str {
+        match event_type {
+            EventType::ProtocolUpgrades => "eth_watch_protocol_upgrades",
+            EventType::PriorityTransactions => "eth_watch_priority_ops",
+            EventType::ChainBatchRoot => "eth_watch_chain_batch_roots",
+            EventType::AddressPolicies => "eth_watch_address_policies",
+        }
+    }
+
+    fn report_progress(&mut self, progress: &EventProcessorProgress) {
+        let lag_behind_head = progress
+            .confirmed_block
+            .saturating_sub(progress.last_processed_block);
+        let lag_behind_finalized = progress
+            .finalized_block
+            .saturating_sub(progress.last_processed_block);
+
+        let kind = ProcessorKind::from(self.processor.event_type());
+        METRICS.last_processed_block[&kind].set(progress.last_processed_block);
+        METRICS.lag_behind_head[&kind].set(lag_behind_head);
+        METRICS.lag_behind_finalized[&kind].set(lag_behind_finalized);
+        METRICS.processed_events[&kind].set(progress.processed_events as u64);
+
+        let details = &mut self.health_details;
+        details.last_processed_block = Some(progress.last_processed_block);
+        details.lag_behind_head = Some(lag_behind_head);
+        details.lag_behind_finalized = Some(lag_behind_finalized);
+        details.events_processed_last_interval = progress.processed_events;
+        self.health_updater
+            .update(Health::from(HealthStatus::Ready).with_details(&self.health_details));
+    }
+
+    fn report_error(&mut self, err: &EventProcessorError) {
+        let kind = ProcessorKind::from(self.processor.event_type());
+        METRICS.processor_errors[&kind].inc();
+
+        self.health_details.last_error = Some(err.to_string());
+        self.health_updater
+            .update(Health::from(HealthStatus::Affected).with_details(&self.health_details));
+    }
+}
+
 /// Ethereum watcher component.
 #[derive(Debug)]
 pub struct EthWatch {
     l1_client: Arc<dyn EthClient>,
     sl_client: Arc<dyn EthClient>,
     poll_interval: Duration,
-    event_processors: Vec<Box<dyn EventProcessor>>,
+    event_processors: Vec<WatchedEventProcessor>,
     pool: ConnectionPool<Core>,
 }
 
@@ -57,6 +165,7 @@ impl EthWatch {
         pool: ConnectionPool<Core>,
         poll_interval: Duration,
         chain_id: L2ChainId,
+        address_policy_registry_addr: Option<Address>,
     ) -> anyhow::Result<Self> {
         let mut storage = pool.connection_tagged("eth_watch").await?;
         let l1_client: Arc<dyn EthClient> = l1_client.into();
@@ -92,6 +201,14 @@ impl EthWatch {
             );
             event_processors.push(Box::new(batch_root_processor));
         }
+        if let Some(registry_address) = address_policy_registry_addr {
+            let address_policies_processor = AddressPoliciesEventProcessor::new(registry_address);
+            event_processors.push(Box::new(address_policies_processor));
+        }
+        let event_processors = event_processors
+            .into_iter()
+            .map(WatchedEventProcessor::new)
+            .collect();
         Ok(Self {
             l1_client,
             sl_client,
@@ -101,6 +218,14 @@ impl EthWatch {
         })
     }
 
+    /// Returns health checks for all event processors, one per processor.
+    pub fn health_checks(&self) -> Vec<ReactiveHealthCheck> {
+        self.event_processors
+            .iter()
+            .map(|watched| watched.health_updater.subscribe())
+            .collect()
+    }
+
     #[tracing::instrument(name = "EthWatch::initialize_state", skip_all)]
     async fn initialize_state(
         storage: &mut Connection<'_, Core>,
@@ -178,69 +303,124 @@ impl EthWatch {
         &mut self,
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), EventProcessorError> {
-        for processor in &mut self.event_processors {
-            let client = match processor.event_source() {
-                EventsSource::L1 => self.l1_client.as_ref(),
-                EventsSource::SL => self.sl_client.as_ref(),
+        // Heads are fetched lazily, at most once per iteration for each events source.
+        let mut l1_head = None;
+        let mut sl_head = None;
+        // A failing processor doesn't prevent other processors from making progress and reporting their health.
+        let mut first_error = None;
+        for watched in &mut self.event_processors {
+            let (client, head) = match watched.processor.event_source() {
+                EventsSource::L1 => (self.l1_client.as_ref(), &mut l1_head),
+                EventsSource::SL => (self.sl_client.as_ref(), &mut sl_head),
             };
-            let chain_id = client.chain_id().await?;
-            let to_block = if processor.only_finalized_block() {
-                client.finalized_block_number().await?
-            } else {
-                client.confirmed_block_number().await?
+            if head.is_none() {
+                *head = Some(ChainHead::fetch(client).await);
+            }
+            let head = match head.as_ref().unwrap() {
+                Ok(head) => *head,
+                Err(err) => {
+                    watched.report_error(err);
+                    continue;
+                }
             };
 
-            let from_block = storage
-                .eth_watcher_dal()
-                .get_or_set_next_block_to_process(
-                    processor.event_type(),
-                    chain_id,
-                    to_block.saturating_sub(PRIORITY_EXPIRATION),
-                )
-                .await
-                .map_err(DalError::generalize)?;
-
-            // There are no new blocks so there is nothing to be done
-            if from_block > to_block {
-                continue;
+            match Self::process_new_events(watched.processor.as_mut(), client, head, storage).await
+            {
+                Ok(progress) => watched.report_progress(&progress),
+                Err(err) => {
+                    watched.report_error(&err);
+                    if matches!(err, EventProcessorError::Internal(_)) {
+                        return Err(err);
+                    }
+                    first_error.get_or_insert(err);
+                }
             }
+        }
 
-            let processor_events = client
-                .get_events(
-                    Web3BlockNumber::Number(from_block.into()),
-                    Web3BlockNumber::Number(to_block.into()),
-                    processor.topic1(),
-                    processor.topic2(),
-                    RETRY_LIMIT,
-                )
-                .await?;
-            let processed_events_count = processor
-                .process_events(storage, processor_events.clone())
-                .await?;
-
-            let next_block_to_process = if processed_events_count == processor_events.len() {
-                to_block + 1
-            } else if processed_events_count == 0 {
-                //nothing was processed
-                from_block
-            } else {
-                processor_events[processed_events_count - 1]
-                    .block_number
-                    .expect("Event block number is missing")
-                    .try_into()
-                    .unwrap()
-            };
+        let head_errors = [l1_head, sl_head]
+            .into_iter()
+            .flatten()
+            .filter_map(Result::err);
+        match first_error.into_iter().chain(head_errors).next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    async fn process_new_events(
+        processor: &mut dyn EventProcessor,
+        client: &dyn EthClient,
+        head: ChainHead,
+        storage: &mut Connection<'_, Core>,
+    ) -> Result<EventProcessorProgress, EventProcessorError> {
+        let ChainHead {
+            chain_id,
+            confirmed_block,
+            finalized_block,
+        } = head;
+        let to_block = if processor.only_finalized_block() {
+            finalized_block
+        } else {
+            confirmed_block
+        };
+
+        let from_block = storage
+            .eth_watcher_dal()
+            .get_or_set_next_block_to_process(
+                processor.event_type(),
+                chain_id,
+                to_block.saturating_sub(PRIORITY_EXPIRATION),
+            )
+            .await
+            .map_err(DalError::generalize)?;
 
-            storage
-                .eth_watcher_dal()
-                .update_next_block_to_process(
-                    processor.event_type(),
-                    chain_id,
-                    next_block_to_process,
-                )
-                .await
-                .map_err(DalError::generalize)?;
+        // There are no new blocks so there is nothing to be done
+        if from_block > to_block {
+            return Ok(EventProcessorProgress {
+                last_processed_block: from_block.saturating_sub(1),
+                confirmed_block,
+                finalized_block,
+                processed_events: 0,
+            });
         }
-        Ok(())
+
+        let processor_events = client
+            .get_events(
+                Web3BlockNumber::Number(from_block.into()),
+                Web3BlockNumber::Number(to_block.into()),
+                processor.topic1(),
+                processor.topic2(),
+                RETRY_LIMIT,
+            )
+            .await?;
+        let processed_events_count = processor
+            .process_events(storage, processor_events.clone())
+            .await?;
+
+        let next_block_to_process = if processed_events_count == processor_events.len() {
+            to_block + 1
+        } else if processed_events_count == 0 {
+            //nothing was processed
+            from_block
+        } else {
+            processor_events[processed_events_count - 1]
+                .block_number
+                .expect("Event block number is missing")
+                .try_into()
+                .unwrap()
+        };
+
+        storage
+            .eth_watcher_dal()
+            .update_next_block_to_process(processor.event_type(), chain_id, next_block_to_process)
+            .await
+            .map_err(DalError::generalize)?;
+
+        Ok(EventProcessorProgress {
+            last_processed_block: next_block_to_process.saturating_sub(1),
+            confirmed_block,
+            finalized_block,
+            processed_events: processed_events_count,
+        })
     }
 }
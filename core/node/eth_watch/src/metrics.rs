@@ -2,7 +2,8 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics};
+use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
+use zksync_dal::eth_watcher_dal::EventType;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -11,6 +12,26 @@ pub(super) enum PollStage {
     PersistUpgrades,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "processor", rename_all = "snake_case")]
+pub(super) enum ProcessorKind {
+    ProtocolUpgrades,
+    PriorityOps,
+    ChainBatchRoots,
+    AddressPolicies,
+}
+
+impl From<EventType> for ProcessorKind {
+    fn from(event_type: EventType) -> Self {
+        match event_type {
+            EventType::ProtocolUpgrades => Self::ProtocolUpgrades,
+            EventType::PriorityTransactions => Self::PriorityOps,
+            EventType::ChainBatchRoot => Self::ChainBatchRoots,
+            EventType::AddressPolicies => Self::AddressPolicies,
+        }
+    }
+}
+
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "server_eth_watch")]
 pub(super) struct EthWatcherMetrics {
@@ -19,6 +40,16 @@ pub(super) struct EthWatcherMetrics {
     /// Latency of polling and processing events split by stage.
     #[metrics(buckets = Buckets::LATENCIES)]
     pub poll_eth_node: Family<PollStage, Histogram<Duration>>,
+    /// Last settlement layer block processed by an event processor.
+    pub last_processed_block: Family<ProcessorKind, Gauge<u64>>,
+    /// Lag (in blocks) of an event processor behind the latest confirmed settlement layer block.
+    pub lag_behind_head: Family<ProcessorKind, Gauge<u64>>,
+    /// Lag (in blocks) of an event processor behind the latest finalized settlement layer block.
+    pub lag_behind_finalized: Family<ProcessorKind, Gauge<u64>>,
+    /// Number of events processed by an event processor during the latest poll.
+    pub processed_events: Family<ProcessorKind, Gauge<u64>>,
+    /// Number of errors encountered by an event processor.
+    pub processor_errors: Family<ProcessorKind, Counter>,
 }
 
 #[vise::register]
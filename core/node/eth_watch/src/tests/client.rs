@@ -7,6 +7,7 @@ use zksync_contracts::{
 use zksync_eth_client::{ContractCallError, EnrichedClientResult};
 use zksync_types::{
     abi::{self, ProposedUpgrade, ZkChainSpecificUpgradeData},
+    address_to_h256,
     api::{ChainAggProof, Log},
     bytecode::BytecodeHash,
     ethabi::{self, Token},
@@ -33,6 +34,8 @@ pub struct FakeEthClientData {
     batch_roots: HashMap<u64, Vec<Log>>,
     chain_roots: HashMap<u64, H256>,
     bytecode_preimages: HashMap<H256, Vec<u8>>,
+    address_policies: HashMap<u64, Vec<Log>>,
+    recorded_logs: HashMap<u64, Vec<Log>>,
 }
 
 impl FakeEthClientData {
@@ -48,6 +51,8 @@ impl FakeEthClientData {
             batch_roots: Default::default(),
             chain_roots: Default::default(),
             bytecode_preimages: Default::default(),
+            address_policies: Default::default(),
+            recorded_logs: Default::default(),
         }
     }
 
@@ -93,6 +98,25 @@ impl FakeEthClientData {
         }
     }
 
+    fn add_address_policies(&mut self, registry: Address, policies: &[(u64, Address, u8)]) {
+        for &(eth_block, account, policy) in policies {
+            self.address_policies
+                .entry(eth_block)
+                .or_default()
+                .push(address_policy_log(registry, eth_block, account, policy));
+        }
+    }
+
+    fn add_recorded_logs(&mut self, logs: &[Log]) {
+        for log in logs {
+            let block_number = log.block_number.expect("recorded log without block number");
+            self.recorded_logs
+                .entry(block_number.as_u64())
+                .or_default()
+                .push(log.clone());
+        }
+    }
+
     fn add_chain_roots(&mut self, chain_roots: &[(u64, H256)]) {
         for (batch, root) in chain_roots {
             self.chain_roots.insert(*batch, *root);
@@ -171,6 +195,22 @@ impl MockEthClient {
         self.inner.write().await.add_batch_roots(batch_roots);
     }
 
+    pub async fn add_address_policies(
+        &mut self,
+        registry: Address,
+        policies: &[(u64, Address, u8)],
+    ) {
+        self.inner
+            .write()
+            .await
+            .add_address_policies(registry, policies);
+    }
+
+    /// Adds logs recorded from a network, so that they are returned by `get_events()` along with the mocked ones.
+    pub async fn add_recorded_logs(&mut self, logs: &[Log]) {
+        self.inner.write().await.add_recorded_logs(logs);
+    }
+
     pub async fn add_chain_roots(&mut self, chain_roots: &[(u64, H256)]) {
         self.inner.write().await.add_chain_roots(chain_roots);
     }
@@ -212,6 +252,12 @@ impl EthClient for MockEthClient {
             if let Some(ops) = self.inner.read().await.batch_roots.get(&number) {
                 logs.extend_from_slice(ops);
             }
+            if let Some(ops) = self.inner.read().await.address_policies.get(&number) {
+                logs.extend_from_slice(ops);
+            }
+            if let Some(ops) = self.inner.read().await.recorded_logs.get(&number) {
+                logs.extend_from_slice(ops);
+            }
         }
         Ok(logs
             .into_iter()
@@ -241,22 +287,17 @@ impl EthClient for MockEthClient {
         &self,
         packed_version: H256,
     ) -> EnrichedClientResult<Option<Vec<u8>>> {
-        let from_block = *self
-            .inner
-            .read()
-            .await
-            .diamond_upgrades
-            .keys()
-            .min()
-            .unwrap_or(&0);
-        let to_block = *self
-            .inner
-            .read()
-            .await
-            .diamond_upgrades
-            .keys()
-            .max()
-            .unwrap_or(&0);
+        let (from_block, to_block) = {
+            let inner = self.inner.read().await;
+            let blocks = || {
+                inner
+                    .diamond_upgrades
+                    .keys()
+                    .chain(inner.recorded_logs.keys())
+                    .copied()
+            };
+            (blocks().min().unwrap_or(0), blocks().max().unwrap_or(0))
+        };
 
         let logs = self
             .get_events(
@@ -362,7 +403,7 @@ impl L2EthClient for MockEthClient {
     }
 }
 
-fn tx_into_log(tx: L1Tx) -> Log {
+pub(super) fn tx_into_log(tx: L1Tx) -> Log {
     let tx = abi::Transaction::try_from(Transaction::from(tx)).unwrap();
     let abi::Transaction::L1 {
         tx,
@@ -542,3 +583,32 @@ fn batch_root_to_log(sl_block_number: u64, l2_batch_number: u64, batch_root: H25
         block_timestamp: None,
     }
 }
+
+pub(super) fn address_policy_log(
+    registry: Address,
+    eth_block: u64,
+    account: Address,
+    policy: u8,
+) -> Log {
+    let topic1 = ethabi::long_signature(
+        "AddressPolicySet",
+        &[ethabi::ParamType::Address, ethabi::ParamType::Uint(8)],
+    );
+    let data = ethabi::encode(&[Token::Uint(policy.into())]);
+
+    Log {
+        address: registry,
+        topics: vec![topic1, address_to_h256(&account)],
+        data: data.into(),
+        block_hash: Some(H256::repeat_byte(0x11)),
+        block_number: Some(eth_block.into()),
+        l1_batch_number: None,
+        transaction_hash: Some(H256::random()),
+        transaction_index: Some(0u64.into()),
+        log_index: Some(0u64.into()),
+        transaction_log_index: Some(0u64.into()),
+        log_type: None,
+        removed: None,
+        block_timestamp: None,
+    }
+}
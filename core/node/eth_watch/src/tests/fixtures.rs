@@ -0,0 +1,406 @@
+//! Harness replaying recorded L1 / settlement layer logs through [`EventProcessor`]s.
+//!
+//! Fixtures are JSON files containing logs as returned by `eth_getLogs`, so they can be recorded from a live network
+//! (see [`record_fixture_from_network`]) and checked in to test processors against real event shapes. Checked-in
+//! fixtures are placed in the `fixtures` directory next to this module; there is at least one fixture per processor.
+
+use std::{future::Future, iter, path::Path, sync::Arc};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use zksync_contracts::{chain_admin_contract, hyperchain_contract};
+use zksync_dal::{address_policies_dal::AddressPolicy, ConnectionPool, Core, CoreDal};
+use zksync_eth_client::{
+    clients::{Client, DynClient, L1},
+    EthInterface,
+};
+use zksync_mini_merkle_tree::MiniMerkleTree;
+use zksync_types::{
+    api::Log,
+    l1::L1Tx,
+    protocol_version::ProtocolSemanticVersion,
+    web3::{BlockNumber, FilterBuilder},
+    Address, L1BatchNumber, L2ChainId, PriorityOpId, ProtocolVersion, ProtocolVersionId, SLChainId,
+    H256,
+};
+
+use super::{
+    build_l1_tx, chain_log_proofs, chain_roots_from_genesis, client::tx_into_log, get_all_db_txs,
+    setup_batch_roots, setup_db, SL_CHAIN_ID,
+};
+use crate::{
+    event_processors::{
+        AddressPoliciesEventProcessor, BatchRootProcessor, DecentralizedUpgradesEventProcessor,
+        EventProcessor, EventProcessorError, PriorityOpsEventProcessor,
+    },
+    tests::client::MockEthClient,
+};
+
+/// Set of logs recorded from an L1 or settlement layer network.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct LogFixture {
+    /// Human-readable description of the recorded logs (network, contract, block range etc.).
+    pub description: String,
+    /// ID of the chain the logs were recorded from.
+    pub chain_id: SLChainId,
+    /// Recorded logs in the order returned by the node.
+    pub logs: Vec<Log>,
+}
+
+impl LogFixture {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed reading fixture {path:?}"))?;
+        serde_json::from_str(&json).with_context(|| format!("failed parsing fixture {path:?}"))
+    }
+
+    /// Loads a fixture checked in to the `fixtures` directory.
+    pub fn checked_in(name: &str) -> Self {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/tests/fixtures")
+            .join(name);
+        Self::load(&path).unwrap()
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("failed writing fixture {path:?}"))
+    }
+
+    /// Retains only logs emitted by the specified contract, e.g. the diamond proxy of a specific chain.
+    pub fn emitted_by(mut self, address: Address) -> Self {
+        self.logs.retain(|log| log.address == address);
+        self
+    }
+
+    /// Returns logs that would be fed to the `processor` by the watcher, i.e. non-removed logs
+    /// matching its topics, ordered by their position on chain.
+    pub fn logs_for(&self, processor: &dyn EventProcessor) -> Vec<Log> {
+        let topic1 = processor.topic1();
+        let topic2 = processor.topic2();
+        let mut logs: Vec<_> = self
+            .logs
+            .iter()
+            .filter(|log| {
+                !log.is_removed()
+                    && log.topics.first() == Some(&topic1)
+                    && (topic2.is_none() || log.topics.get(1) == topic2.as_ref())
+            })
+            .cloned()
+            .collect();
+        logs.sort_by_key(|log| (log.block_number, log.log_index));
+        logs
+    }
+}
+
+/// Replays logs from the fixture through a processor, persisting the results to `pool`. The processor is created
+/// by `create_processor` from a mock client of the chain the fixture was recorded from. The client returns all logs
+/// from the fixture (e.g., upgrade cut data queried by the processor), not only ones fed to the processor.
+/// Returns the number of processed events as reported by the processor.
+pub(super) async fn replay_fixture<P, F>(
+    pool: &ConnectionPool<Core>,
+    fixture: &LogFixture,
+    create_processor: impl FnOnce(MockEthClient) -> F,
+) -> Result<usize, EventProcessorError>
+where
+    P: EventProcessor,
+    F: Future<Output = P>,
+{
+    let mut client = MockEthClient::new(fixture.chain_id);
+    client.add_recorded_logs(&fixture.logs).await;
+    let mut processor = create_processor(client).await;
+    let logs = fixture.logs_for(&processor);
+    let mut storage = pool.connection().await.unwrap();
+    processor.process_events(&mut storage, logs).await
+}
+
+/// Records logs emitted by `address` in the specified block range, optionally filtering them by `topic1`.
+pub(super) async fn record_fixture(
+    client: &DynClient<L1>,
+    address: Address,
+    (from_block, to_block): (u64, u64),
+    topic1: Option<H256>,
+) -> anyhow::Result<LogFixture> {
+    let filter = FilterBuilder::default()
+        .address(vec![address])
+        .from_block(BlockNumber::Number(from_block.into()))
+        .to_block(BlockNumber::Number(to_block.into()))
+        .topics(topic1.map(|topic| vec![topic]), None, None, None)
+        .build();
+    let logs = client.logs(&filter).await?;
+    let chain_id = client.fetch_chain_id().await?;
+    Ok(LogFixture {
+        description: format!(
+            "logs emitted by {address:?} in blocks {from_block}..={to_block} on chain {}",
+            chain_id.0
+        ),
+        chain_id,
+        logs: logs.into_iter().map(Log::from).collect(),
+    })
+}
+
+fn env_var(name: &str) -> anyhow::Result<String> {
+    std::env::var(name).with_context(|| format!("`{name}` env var is not set"))
+}
+
+/// Records a fixture from a live network. Not run by default; configured via env vars, e.g.
+///
+/// ```text
+/// ETH_WATCH_FIXTURE_RPC_URL=https://... ETH_WATCH_FIXTURE_ADDRESS=0x... \
+/// ETH_WATCH_FIXTURE_BLOCKS=100..200 ETH_WATCH_FIXTURE_OUTPUT=priority_ops.json \
+/// cargo test -p zksync_eth_watch record_fixture_from_network -- --ignored
+/// ```
+///
+/// `ETH_WATCH_FIXTURE_TOPIC` may be additionally set to only record events with the specified signature.
+#[tokio::test]
+#[ignore]
+async fn record_fixture_from_network() -> anyhow::Result<()> {
+    let rpc_url = env_var("ETH_WATCH_FIXTURE_RPC_URL")?;
+    let address: Address = env_var("ETH_WATCH_FIXTURE_ADDRESS")?.parse()?;
+    let blocks = env_var("ETH_WATCH_FIXTURE_BLOCKS")?;
+    let (from_block, to_block) = blocks
+        .split_once("..")
+        .context("`ETH_WATCH_FIXTURE_BLOCKS` must have `from..to` format")?;
+    let blocks = (from_block.parse()?, to_block.parse()?);
+    let topic1 = std::env::var("ETH_WATCH_FIXTURE_TOPIC")
+        .ok()
+        .map(|topic| topic.parse())
+        .transpose()?;
+    let output = env_var("ETH_WATCH_FIXTURE_OUTPUT")?;
+
+    let client: Client<L1> = Client::http(rpc_url.parse()?)
+        .context("Client::new()")?
+        .build();
+    let client: Box<DynClient<L1>> = Box::new(client);
+    let fixture = record_fixture(client.as_ref(), address, blocks, topic1).await?;
+    tracing::info!("Recorded {} logs to {output}", fixture.logs.len());
+    fixture.save(Path::new(&output))
+}
+
+fn priority_ops_fixture(diamond_proxy: Address) -> LogFixture {
+    let mut logs: Vec<_> = [build_l1_tx(0, 10), build_l1_tx(1, 12), build_l1_tx(2, 12)]
+        .into_iter()
+        .map(|tx| {
+            let mut log = tx_into_log(tx);
+            log.address = diamond_proxy;
+            log
+        })
+        .collect();
+    // Priority op emitted by another chain
+    logs.push(tx_into_log(build_l1_tx(0, 11)));
+    // Unrelated event
+    logs.push(Log {
+        address: diamond_proxy,
+        topics: vec![H256::repeat_byte(0xff)],
+        ..tx_into_log(build_l1_tx(3, 11))
+    });
+    // Log removed because of a reorg
+    logs.push(Log {
+        address: diamond_proxy,
+        removed: Some(true),
+        ..tx_into_log(build_l1_tx(3, 13))
+    });
+
+    LogFixture {
+        description: "test priority ops".to_owned(),
+        chain_id: SL_CHAIN_ID,
+        logs,
+    }
+}
+
+#[test]
+fn fixture_serialization_roundtrip() {
+    let fixture = priority_ops_fixture(Address::repeat_byte(1));
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("fixture.json");
+    fixture.save(&path).unwrap();
+    let restored = LogFixture::load(&path).unwrap();
+    assert_eq!(restored, fixture);
+}
+
+#[test_log::test(tokio::test)]
+async fn replaying_priority_ops_fixture() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&pool).await;
+    let diamond_proxy = Address::repeat_byte(1);
+    let fixture = &priority_ops_fixture(diamond_proxy).emitted_by(diamond_proxy);
+
+    let signature = hyperchain_contract()
+        .event("NewPriorityRequest")
+        .unwrap()
+        .signature();
+    let processed = replay_fixture(&pool, fixture, |mut sl_client| async move {
+        sl_client.set_processed_priority_transactions_count(3).await;
+        let processor =
+            PriorityOpsEventProcessor::new(PriorityOpId(0), Arc::new(sl_client)).unwrap();
+        assert!(fixture
+            .logs_for(&processor)
+            .iter()
+            .all(|log| log.topics[0] == signature));
+        processor
+    })
+    .await
+    .unwrap();
+    assert_eq!(processed, 3);
+
+    let db_txs = priority_ops_in_db(&pool).await;
+    let serial_ids: Vec<_> = db_txs.iter().map(|tx| tx.serial_id().0).collect();
+    assert_eq!(serial_ids, [0, 1, 2]);
+    let eth_blocks: Vec<_> = db_txs.iter().map(|tx| tx.eth_block().0).collect();
+    assert_eq!(eth_blocks, [10, 12, 12]);
+}
+
+async fn priority_ops_in_db(pool: &ConnectionPool<Core>) -> Vec<L1Tx> {
+    let mut storage = pool.connection().await.unwrap();
+    let mut db_txs: Vec<L1Tx> = get_all_db_txs(&mut storage)
+        .await
+        .into_iter()
+        .map(|tx| tx.try_into().unwrap())
+        .collect();
+    db_txs.sort_by_key(|tx| tx.common_data.serial_id);
+    db_txs
+}
+
+#[test_log::test(tokio::test)]
+async fn replaying_recorded_priority_ops() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&pool).await;
+    let diamond_proxy: Address = "0x6d6e010a2680e2e5a3b097ce411528b36d880ef6"
+        .parse()
+        .unwrap();
+    let fixture = LogFixture::checked_in("priority_ops.json").emitted_by(diamond_proxy);
+
+    let processed = replay_fixture(&pool, &fixture, |mut sl_client| async move {
+        sl_client.set_processed_priority_transactions_count(3).await;
+        PriorityOpsEventProcessor::new(PriorityOpId(0), Arc::new(sl_client)).unwrap()
+    })
+    .await
+    .unwrap();
+    assert_eq!(processed, 3);
+
+    let db_txs = priority_ops_in_db(&pool).await;
+    let serial_ids: Vec<_> = db_txs.iter().map(|tx| tx.serial_id().0).collect();
+    assert_eq!(serial_ids, [0, 1, 2]);
+    let eth_blocks: Vec<_> = db_txs.iter().map(|tx| tx.eth_block().0).collect();
+    assert_eq!(eth_blocks, [118, 121, 121]);
+    assert_eq!(db_txs[1].execute.calldata[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+}
+
+#[test_log::test(tokio::test)]
+async fn replaying_recorded_address_policies() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&pool).await;
+    let registry: Address = "0x4c2a4f4e9b1df7d3cd0e0c3c2a2f3b6fbe6b0d43"
+        .parse()
+        .unwrap();
+    let greylisted: Address = "0x9d3b5e6f1a7c2d4e8f0a1b2c3d4e5f60718293a4"
+        .parse()
+        .unwrap();
+    let blacklisted: Address = "0x1f2e3d4c5b6a79880716253443526170f8e9dacb"
+        .parse()
+        .unwrap();
+    let fixture = LogFixture::checked_in("address_policies.json");
+
+    // Events emitted by other registries must be ignored by the processor.
+    let processed = replay_fixture(&pool, &fixture, |_| async move {
+        AddressPoliciesEventProcessor::new(registry)
+    })
+    .await
+    .unwrap();
+    assert_eq!(processed, 4);
+
+    let mut storage = pool.connection().await.unwrap();
+    let mut dal = storage.address_policies_dal();
+    assert_eq!(
+        dal.get_address_policy(greylisted).await.unwrap(),
+        Some(AddressPolicy::Greylisted)
+    );
+    assert_eq!(dal.get_address_policy(blacklisted).await.unwrap(), None);
+}
+
+#[test_log::test(tokio::test)]
+async fn replaying_recorded_batch_roots() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&pool).await;
+    setup_batch_roots(&pool, 0).await;
+    let fixture = LogFixture::checked_in("batch_roots.json");
+    assert_eq!(fixture.chain_id, SL_CHAIN_ID);
+
+    let processed = replay_fixture(&pool, &fixture, |mut sl_client| async move {
+        sl_client.add_chain_roots(&chain_roots_from_genesis()).await;
+        sl_client.add_chain_log_proofs(chain_log_proofs()).await;
+        BatchRootProcessor::new(
+            L1BatchNumber(0),
+            MiniMerkleTree::new(iter::empty(), None),
+            L2ChainId::default(),
+            Arc::new(sl_client),
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(processed, 3);
+
+    let mut storage = pool.connection().await.unwrap();
+    for batch_number in 1..=3 {
+        let path = storage
+            .blocks_dal()
+            .get_l1_batch_chain_merkle_path(L1BatchNumber(batch_number))
+            .await
+            .unwrap();
+        assert!(
+            path.is_some(),
+            "no chain merkle path for batch #{batch_number}"
+        );
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn replaying_recorded_protocol_upgrades() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let base_version = ProtocolSemanticVersion {
+        minor: ProtocolVersionId::Version27,
+        patch: 0.into(),
+    };
+    pool.connection()
+        .await
+        .unwrap()
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion {
+            version: base_version,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let fixture = LogFixture::checked_in("protocol_upgrades.json");
+
+    let processed = replay_fixture(&pool, &fixture, |client| async move {
+        DecentralizedUpgradesEventProcessor::new(
+            base_version,
+            &chain_admin_contract(),
+            Arc::new(client.clone()),
+            Arc::new(client),
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(processed, 2);
+
+    let mut storage = pool.connection().await.unwrap();
+    let db_versions = storage.protocol_versions_dal().all_versions().await;
+    let expected_versions =
+        [(27, 0), (28, 0), (28, 1)].map(|(minor, patch): (u16, u32)| ProtocolSemanticVersion {
+            minor: minor.try_into().unwrap(),
+            patch: patch.into(),
+        });
+    assert_eq!(db_versions, expected_versions);
+    let upgrade = storage
+        .protocol_versions_dal()
+        .get_protocol_version_with_latest_patch(ProtocolVersionId::Version28)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(upgrade.version, expected_versions[2]);
+    // The upgrade timestamp is stored per minor version.
+    assert_eq!(upgrade.timestamp, 1_760_000_000);
+    assert!(upgrade.tx.is_none());
+}
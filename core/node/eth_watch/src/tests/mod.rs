@@ -1,13 +1,15 @@
 use std::convert::TryInto;
 
 use zksync_contracts::chain_admin_contract;
-use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_dal::{address_policies_dal::AddressPolicy, Connection, ConnectionPool, Core, CoreDal};
+use zksync_health_check::{CheckHealth, HealthStatus};
 use zksync_types::{
     abi,
     aggregated_operations::AggregatedActionType,
     api::ChainAggProof,
     block::L1BatchHeader,
     commitment::L1BatchCommitmentArtifacts,
+    ethabi,
     l1::{L1Tx, OpProcessingType, PriorityQueueType},
     l2_to_l1_log::BatchAndChainMerklePath,
     protocol_upgrade::{ProtocolUpgradeTx, ProtocolUpgradeTxCommonData},
@@ -16,9 +18,14 @@ use zksync_types::{
     ProtocolVersion, ProtocolVersionId, SLChainId, Transaction, H256, U256,
 };
 
-use crate::{tests::client::MockEthClient, EthWatch, L2EthClient};
+use crate::{
+    event_processors::{AddressPoliciesEventProcessor, EventProcessor, EventProcessorError},
+    tests::client::{address_policy_log, tx_into_log, MockEthClient},
+    EthWatch, L2EthClient,
+};
 
 mod client;
+mod fixtures;
 
 const SL_CHAIN_ID: SLChainId = SLChainId(505);
 
@@ -110,6 +117,7 @@ async fn create_test_watcher(
         connection_pool,
         std::time::Duration::from_nanos(1),
         L2ChainId::default(),
+        None,
     )
     .await
     .unwrap();
@@ -169,6 +177,80 @@ async fn test_normal_operation_l1_txs() {
     assert_eq!(db_tx.common_data.serial_id.0, 2);
 }
 
+#[test_log::test(tokio::test)]
+async fn processor_health_checks() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&connection_pool).await;
+    let (mut watcher, mut client) = create_l1_test_watcher(connection_pool.clone()).await;
+
+    let health_checks = watcher.health_checks();
+    let health_check_names: Vec<_> = health_checks.iter().map(|check| check.name()).collect();
+    assert_eq!(
+        health_check_names,
+        ["eth_watch_priority_ops", "eth_watch_protocol_upgrades"]
+    );
+    let priority_ops_health = &health_checks[0];
+    assert_eq!(
+        priority_ops_health.check_health().await.status(),
+        HealthStatus::NotReady
+    );
+
+    let mut storage = connection_pool.connection().await.unwrap();
+    client
+        .add_transactions(&[build_l1_tx(0, 10), build_l1_tx(1, 14)])
+        .await;
+    client.set_last_finalized_block_number(15).await;
+    watcher.loop_iteration(&mut storage).await.unwrap();
+
+    let health = priority_ops_health.check_health().await;
+    assert_eq!(health.status(), HealthStatus::Ready);
+    let details = health.details().unwrap();
+    assert_eq!(details["last_processed_block"], 15);
+    assert_eq!(details["lag_behind_head"], 0);
+    assert_eq!(details["lag_behind_finalized"], 0);
+    assert_eq!(details["events_processed_last_interval"], 2);
+    assert!(details["last_error"].is_null());
+
+    client.set_last_finalized_block_number(20).await;
+    watcher.loop_iteration(&mut storage).await.unwrap();
+
+    let health = priority_ops_health.check_health().await;
+    assert_eq!(health.status(), HealthStatus::Ready);
+    let details = health.details().unwrap();
+    assert_eq!(details["last_processed_block"], 20);
+    assert_eq!(details["events_processed_last_interval"], 0);
+}
+
+#[test_log::test(tokio::test)]
+async fn failing_processor_does_not_block_other_processors() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&connection_pool).await;
+    let (mut watcher, mut client) = create_l1_test_watcher(connection_pool.clone()).await;
+    let health_checks = watcher.health_checks();
+    let (priority_ops_health, upgrades_health) = (&health_checks[0], &health_checks[1]);
+
+    let mut storage = connection_pool.connection().await.unwrap();
+    let mut malformed_log = tx_into_log(build_l1_tx(0, 10));
+    malformed_log.data = vec![1, 2, 3].into();
+    client.add_recorded_logs(&[malformed_log]).await;
+    client.set_last_finalized_block_number(15).await;
+    let err = watcher.loop_iteration(&mut storage).await.unwrap_err();
+    assert!(
+        matches!(err, EventProcessorError::LogParse { .. }),
+        "{err:?}"
+    );
+
+    let health = priority_ops_health.check_health().await;
+    assert_eq!(health.status(), HealthStatus::Affected);
+    assert!(health.details().unwrap()["last_error"].is_string());
+
+    let health = upgrades_health.check_health().await;
+    assert_eq!(health.status(), HealthStatus::Ready);
+    let details = health.details().unwrap();
+    assert_eq!(details["last_processed_block"], 15);
+    assert_eq!(details["lag_behind_finalized"], 0);
+}
+
 #[test_log::test(tokio::test)]
 async fn test_gap_in_upgrade_timestamp() {
     let connection_pool = ConnectionPool::<Core>::test_pool().await;
@@ -216,6 +298,7 @@ async fn test_normal_operation_upgrade_timestamp() {
         connection_pool.clone(),
         std::time::Duration::from_nanos(1),
         L2ChainId::default(),
+        None,
     )
     .await
     .unwrap();
@@ -301,6 +384,95 @@ async fn test_normal_operation_upgrade_timestamp() {
     assert_eq!(expected_common_data, common_data);
 }
 
+#[test_log::test(tokio::test)]
+async fn test_address_policies() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    setup_db(&connection_pool).await;
+
+    let registry = Address::repeat_byte(0x42);
+    let greylisted = Address::repeat_byte(1);
+    let blacklisted = Address::repeat_byte(2);
+    let mut client = MockEthClient::new(SLChainId(42));
+    let mut watcher = EthWatch::new(
+        &chain_admin_contract(),
+        Box::new(client.clone()),
+        None,
+        connection_pool.clone(),
+        std::time::Duration::from_nanos(1),
+        L2ChainId::default(),
+        Some(registry),
+    )
+    .await
+    .unwrap();
+
+    client
+        .add_address_policies(
+            registry,
+            &[
+                (5, greylisted, 1),
+                (6, blacklisted, 2),
+                (20, blacklisted, 0),
+            ],
+        )
+        .await;
+    // Events from other contracts must be ignored.
+    client
+        .add_address_policies(Address::repeat_byte(0x43), &[(7, greylisted, 2)])
+        .await;
+    client.set_last_finalized_block_number(15).await;
+
+    let mut storage = connection_pool.connection().await.unwrap();
+    watcher.loop_iteration(&mut storage).await.unwrap();
+    let mut dal = storage.address_policies_dal();
+    assert_eq!(
+        dal.get_address_policy(greylisted).await.unwrap(),
+        Some(AddressPolicy::Greylisted)
+    );
+    assert_eq!(
+        dal.get_address_policy(blacklisted).await.unwrap(),
+        Some(AddressPolicy::Blacklisted)
+    );
+
+    client.set_last_finalized_block_number(25).await;
+    watcher.loop_iteration(&mut storage).await.unwrap();
+    let mut dal = storage.address_policies_dal();
+    assert_eq!(
+        dal.get_address_policy(greylisted).await.unwrap(),
+        Some(AddressPolicy::Greylisted)
+    );
+    assert_eq!(dal.get_address_policy(blacklisted).await.unwrap(), None);
+}
+
+#[test_log::test(tokio::test)]
+async fn address_policies_with_out_of_range_values_are_rejected() {
+    let connection_pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = connection_pool.connection().await.unwrap();
+    let registry = Address::repeat_byte(0x42);
+    let mut processor = AddressPoliciesEventProcessor::new(registry);
+
+    let mut log = address_policy_log(registry, 5, Address::repeat_byte(1), 1);
+    log.data = ethabi::encode(&[ethabi::Token::Uint(U256::from(u32::MAX) + 1)]).into();
+    let err = processor
+        .process_events(&mut storage, vec![log])
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, EventProcessorError::LogParse { .. }),
+        "{err:?}"
+    );
+
+    let mut log = address_policy_log(registry, 5, Address::repeat_byte(1), 1);
+    log.block_number = Some((u64::from(u32::MAX) + 1).into());
+    let err = processor
+        .process_events(&mut storage, vec![log])
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, EventProcessorError::LogParse { .. }),
+        "{err:?}"
+    );
+}
+
 #[test_log::test(tokio::test)]
 #[should_panic]
 async fn test_gap_in_single_batch() {
@@ -455,37 +627,7 @@ async fn test_batch_root_processor_from_genesis() {
             (11, 3, batch_roots[2]),
         ])
         .await;
-    sl_client
-        .add_chain_roots(&[
-            (
-                5,
-                H256::from_slice(
-                    &hex::decode(
-                        "10a2ef76e709d318b459be49f1e8d7f02d7120f2b501bc0afddd935f1a813c67",
-                    )
-                    .unwrap(),
-                ),
-            ),
-            (
-                9,
-                H256::from_slice(
-                    &hex::decode(
-                        "e0c3330f674b6b2d578f958a1dbd66f164d068b0bb5a9fb077eca013976fda6f",
-                    )
-                    .unwrap(),
-                ),
-            ),
-            (
-                11,
-                H256::from_slice(
-                    &hex::decode(
-                        "d22fc9a7b005fefecd33bb56cdbf70bcc23610e693cd21295f9920227c2cb1cc",
-                    )
-                    .unwrap(),
-                ),
-            ),
-        ])
-        .await;
+    sl_client.add_chain_roots(&chain_roots_from_genesis()).await;
     let chain_log_proofs = chain_log_proofs();
     sl_client.add_chain_log_proofs(chain_log_proofs).await;
 
@@ -661,6 +803,27 @@ fn batch_roots() -> Vec<H256> {
     .collect()
 }
 
+/// Chain roots after appending the first 3 batch roots from [`batch_roots()`] at SL batches 5, 9 and 11.
+fn chain_roots_from_genesis() -> Vec<(u64, H256)> {
+    [
+        (
+            5,
+            "10a2ef76e709d318b459be49f1e8d7f02d7120f2b501bc0afddd935f1a813c67",
+        ),
+        (
+            9,
+            "e0c3330f674b6b2d578f958a1dbd66f164d068b0bb5a9fb077eca013976fda6f",
+        ),
+        (
+            11,
+            "d22fc9a7b005fefecd33bb56cdbf70bcc23610e693cd21295f9920227c2cb1cc",
+        ),
+    ]
+    .into_iter()
+    .map(|(sl_batch, root)| (sl_batch, H256::from_slice(&hex::decode(root).unwrap())))
+    .collect()
+}
+
 fn chain_log_proofs() -> Vec<(L1BatchNumber, ChainAggProof)> {
     vec![
         (
@@ -0,0 +1,276 @@
+//! Oracle for `gas_per_pubdata` values served by the API.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use tokio::sync::watch;
+use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
+use zksync_types::{
+    api::{GasPerPubdataInfo, GasPerPubdataSample},
+    fee_model::{BatchFeeInput, PubdataIndependentBatchFeeModelInput},
+    helpers::unix_timestamp_ms,
+    ProtocolVersionId,
+};
+
+use crate::BatchFeeModelInputProvider;
+
+#[derive(Debug, Clone, Copy)]
+struct FeeInputSample {
+    timestamp: u64,
+    fee_input: BatchFeeInput,
+}
+
+impl FeeInputSample {
+    fn to_api(self) -> GasPerPubdataSample {
+        let (_, gas_per_pubdata) =
+            derive_base_fee_and_gas_per_pubdata(self.fee_input, ProtocolVersionId::latest().into());
+        GasPerPubdataSample {
+            timestamp: self.timestamp,
+            fair_pubdata_price: self.fee_input.fair_pubdata_price(),
+            gas_per_pubdata,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct OracleState {
+    samples: VecDeque<FeeInputSample>,
+    max_samples: usize,
+}
+
+/// Periodically samples the batch fee input and caches the derived `gas_per_pubdata` values, so that clients
+/// don't need to derive them from transaction simulation. In addition to the current value and recent history,
+/// provides a forecast based on the linear trend of the fair pubdata price (which, for rollups publishing pubdata
+/// in blobs, follows the L1 blob base fee).
+#[derive(Debug, Clone)]
+pub struct GasPerPubdataOracle {
+    state: Arc<RwLock<OracleState>>,
+    forecast_horizon: Duration,
+}
+
+impl GasPerPubdataOracle {
+    /// Creates an oracle retaining up to `max_samples` recent samples and forecasting `forecast_horizon` ahead.
+    pub fn new(max_samples: usize, forecast_horizon: Duration) -> Self {
+        assert!(max_samples > 0, "`max_samples` must be positive");
+        let state = OracleState {
+            samples: VecDeque::with_capacity(max_samples),
+            max_samples,
+        };
+        Self {
+            state: Arc::new(RwLock::new(state)),
+            forecast_horizon,
+        }
+    }
+
+    /// Returns a task that will update this oracle in background. `gas_price_scale_factor` must be equal to the factor
+    /// used by the transaction sender, so that the oracle values match ones required for transaction submission.
+    pub fn update_task(
+        &self,
+        provider: Arc<dyn BatchFeeModelInputProvider>,
+        gas_price_scale_factor: f64,
+        update_interval: Duration,
+    ) -> GasPerPubdataOracleUpdateTask {
+        GasPerPubdataOracleUpdateTask {
+            oracle: self.clone(),
+            provider,
+            gas_price_scale_factor,
+            update_interval,
+        }
+    }
+
+    fn push_sample(&self, sample: FeeInputSample) {
+        let mut state = self.state.write().unwrap();
+        if state.samples.len() == state.max_samples {
+            state.samples.pop_front();
+        }
+        state.samples.push_back(sample);
+    }
+
+    /// Returns the cached `gas_per_pubdata` info, or `None` if the oracle hasn't sampled the fee input yet.
+    pub fn info(&self) -> Option<GasPerPubdataInfo> {
+        let state = self.state.read().unwrap();
+        let current = state.samples.back()?.to_api();
+        let history = state.samples.iter().map(|sample| sample.to_api()).collect();
+        let forecast = Self::forecast(&state.samples, self.forecast_horizon);
+        Some(GasPerPubdataInfo {
+            current,
+            history,
+            forecast: forecast.map(FeeInputSample::to_api),
+        })
+    }
+
+    /// Extrapolates the fair pubdata price using least-squares linear regression over the samples.
+    fn forecast(samples: &VecDeque<FeeInputSample>, horizon: Duration) -> Option<FeeInputSample> {
+        let first = samples.front()?;
+        let last = samples.back()?;
+        let points = samples.iter().map(|sample| {
+            // Samples use wall-clock time, so timestamps are not necessarily monotonic.
+            let x = sample.timestamp.saturating_sub(first.timestamp) as f64;
+            (x, sample.fee_input.fair_pubdata_price() as f64)
+        });
+
+        let len = samples.len() as f64;
+        let (sum_x, sum_y) = points
+            .clone()
+            .fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| (sum_x + x, sum_y + y));
+        let (mean_x, mean_y) = (sum_x / len, sum_y / len);
+        let (covariance, variance) = points.fold((0.0, 0.0), |(cov, var), (x, y)| {
+            (
+                cov + (x - mean_x) * (y - mean_y),
+                var + (x - mean_x).powi(2),
+            )
+        });
+        if variance == 0.0 {
+            // Less than 2 samples, or all samples have the same timestamp.
+            return None;
+        }
+        let slope = covariance / variance;
+
+        let timestamp = last.timestamp.saturating_add(horizon.as_secs());
+        let x = timestamp.saturating_sub(first.timestamp) as f64;
+        let fair_pubdata_price = (mean_y + slope * (x - mean_x)).max(0.0).round() as u64;
+        let last_input = last.fee_input.into_pubdata_independent();
+        Some(FeeInputSample {
+            timestamp,
+            fee_input: BatchFeeInput::PubdataIndependent(PubdataIndependentBatchFeeModelInput {
+                fair_pubdata_price,
+                ..last_input
+            }),
+        })
+    }
+}
+
+/// Task updating [`GasPerPubdataOracle`]. Should be spawned as a Tokio task (exactly one task for the oracle).
+#[derive(Debug)]
+pub struct GasPerPubdataOracleUpdateTask {
+    oracle: GasPerPubdataOracle,
+    provider: Arc<dyn BatchFeeModelInputProvider>,
+    gas_price_scale_factor: f64,
+    update_interval: Duration,
+}
+
+impl GasPerPubdataOracleUpdateTask {
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            // Scaling is performed in the same way as in the transaction sender.
+            let scale_factor = self.gas_price_scale_factor;
+            match self
+                .provider
+                .get_batch_fee_input_scaled(scale_factor, scale_factor)
+                .await
+            {
+                Ok(fee_input) => self.oracle.push_sample(FeeInputSample {
+                    timestamp: unix_timestamp_ms() / 1_000,
+                    fee_input,
+                }),
+                Err(err) => tracing::warn!("Cannot sample batch fee input: {err:#}"),
+            }
+
+            if tokio::time::timeout(self.update_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, gas per pubdata oracle is shutting down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockBatchFeeParamsProvider;
+
+    fn sample(timestamp: u64, fair_pubdata_price: u64) -> FeeInputSample {
+        FeeInputSample {
+            timestamp,
+            fee_input: BatchFeeInput::PubdataIndependent(PubdataIndependentBatchFeeModelInput {
+                fair_l2_gas_price: 100_000_000,
+                fair_pubdata_price,
+                l1_gas_price: 1_000_000_000,
+            }),
+        }
+    }
+
+    #[test]
+    fn forecasting_pubdata_price() {
+        let oracle = GasPerPubdataOracle::new(3, Duration::from_secs(20));
+        assert!(oracle.info().is_none());
+
+        oracle.push_sample(sample(1_000, 1_000_000_000));
+        let info = oracle.info().unwrap();
+        assert_eq!(info.history, [info.current]);
+        assert_eq!(info.forecast, None);
+
+        for (i, price) in [2_000_000_000, 3_000_000_000, 4_000_000_000]
+            .into_iter()
+            .enumerate()
+        {
+            oracle.push_sample(sample(1_010 + i as u64 * 10, price));
+        }
+        let info = oracle.info().unwrap();
+        assert_eq!(info.history.len(), 3);
+        assert_eq!(info.history[0].timestamp, 1_010);
+        assert_eq!(info.current.timestamp, 1_030);
+        assert_eq!(info.current.gas_per_pubdata, 40);
+
+        let forecast = info.forecast.unwrap();
+        assert_eq!(forecast.timestamp, 1_050);
+        assert_eq!(forecast.fair_pubdata_price, 6_000_000_000);
+        assert_eq!(forecast.gas_per_pubdata, 60);
+    }
+
+    #[test]
+    fn forecast_is_not_negative() {
+        let oracle = GasPerPubdataOracle::new(10, Duration::from_secs(1_000));
+        oracle.push_sample(sample(0, 2_000_000_000));
+        oracle.push_sample(sample(10, 1_000_000_000));
+        let forecast = oracle.info().unwrap().forecast.unwrap();
+        assert_eq!(forecast.fair_pubdata_price, 0);
+        assert_eq!(forecast.gas_per_pubdata, 0);
+    }
+
+    #[test]
+    fn forecasting_with_non_monotonic_timestamps() {
+        let oracle = GasPerPubdataOracle::new(10, Duration::from_secs(20));
+        oracle.push_sample(sample(1_000, 1_000_000_000));
+        // Emulate wall-clock time going backwards.
+        oracle.push_sample(sample(990, 1_000_000_000));
+        oracle.push_sample(sample(1_010, 2_000_000_000));
+
+        let forecast = oracle.info().unwrap().forecast.unwrap();
+        assert_eq!(forecast.timestamp, 1_030);
+        assert_eq!(forecast.fair_pubdata_price, 4_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn updating_oracle() {
+        let oracle = GasPerPubdataOracle::new(10, Duration::from_secs(60));
+        let provider = Arc::new(MockBatchFeeParamsProvider::default());
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let task = oracle.update_task(provider.clone(), 1.5, Duration::from_millis(10));
+        let task = tokio::spawn(task.run(stop_receiver));
+
+        let info = loop {
+            if let Some(info) = oracle.info() {
+                break info;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        stop_sender.send_replace(true);
+        task.await.unwrap().unwrap();
+
+        let expected_fee_input = provider.get_batch_fee_input_scaled(1.5, 1.5).await.unwrap();
+        assert_eq!(
+            info.current.fair_pubdata_price,
+            expected_fee_input.fair_pubdata_price()
+        );
+        let unscaled_fee_input = provider.get_batch_fee_input_scaled(1.0, 1.0).await.unwrap();
+        assert!(info.current.fair_pubdata_price > unscaled_fee_input.fair_pubdata_price());
+    }
+}
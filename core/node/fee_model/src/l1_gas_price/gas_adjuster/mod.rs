@@ -368,12 +368,23 @@ impl TxParamsProvider for GasAdjuster {
         self.get_priority_fee() * 2
     }
 
+    // Similarly to blob txs, we overpay for the gateway base fee so that a transaction doesn't get stuck if the fee
+    // grows while it's in the mempool. This doesn't lead to systematic overpaying since ZK chains charge
+    // the actual base fee rather than `max_fee_per_gas`.
     fn get_gateway_tx_base_fee(&self) -> u64 {
-        todo!()
+        self.base_fee_statistics.last_added_value() * 2
     }
 
     fn get_gateway_tx_pubdata_price(&self) -> u64 {
-        todo!()
+        let pubdata_price = self
+            .l2_pubdata_price_statistics
+            .last_added_value()
+            .saturating_mul(2.into());
+        if pubdata_price > U256::from(u64::MAX) {
+            tracing::error!("Gateway pubdata price is too high: {pubdata_price}, using u64::MAX");
+            return u64::MAX;
+        }
+        pubdata_price.as_u64()
     }
 }
 
@@ -73,6 +73,7 @@ fn test_config(settlement_mode: SettlementMode) -> GasAdjusterConfig {
         internal_pubdata_pricing_multiplier: 1.0,
         max_blob_base_fee: None,
         settlement_mode,
+        gateway_settlement_supported: settlement_mode.is_gateway(),
     }
 }
 
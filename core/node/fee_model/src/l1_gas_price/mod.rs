@@ -36,6 +36,6 @@ pub trait TxParamsProvider: fmt::Debug + 'static + Send + Sync {
     /// Returns the recommended `max_fee_per_gas` value for gateway transactions.
     fn get_gateway_tx_base_fee(&self) -> u64;
 
-    /// Returns the recommended `max_fee_per_gas` value for gateway transactions.
+    /// Returns the recommended price per pubdata byte for gateway transactions.
     fn get_gateway_tx_pubdata_price(&self) -> u64;
 }
@@ -9,6 +9,7 @@ use zksync_types::fee_model::{
 
 use crate::l1_gas_price::GasAdjuster;
 
+pub mod gas_per_pubdata;
 pub mod l1_gas_price;
 
 /// Trait responsible for providing numerator and denominator for adjusting gas price that is denominated
@@ -2,13 +2,13 @@
 //! It initializes the Merkle tree with the basic setup (such as fields of special service accounts),
 //! setups the required databases, and outputs the data required to initialize a smart contract.
 
-use std::{collections::HashMap, fmt::Formatter};
+use std::{collections::HashMap, fmt::Formatter, path::PathBuf};
 
 use anyhow::Context as _;
 use zksync_config::GenesisConfig;
 use zksync_contracts::{
     hyperchain_contract, verifier_contract, BaseSystemContracts, BaseSystemContractsHashes,
-    GENESIS_UPGRADE_EVENT,
+    SystemContractsRepo, GENESIS_UPGRADE_EVENT,
 };
 use zksync_dal::{custom_genesis_export_dal::GenesisState, Connection, Core, CoreDal, DalError};
 use zksync_eth_client::{CallFunctionArgs, EthInterface};
@@ -22,7 +22,7 @@ use zksync_types::{
     fee_model::BatchFeeInput,
     protocol_upgrade::decode_genesis_upgrade_event,
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
-    system_contracts::get_system_smart_contracts,
+    system_contracts::{get_system_smart_contracts, get_system_smart_contracts_from_dir},
     u256_to_h256,
     web3::{BlockNumber, FilterBuilder},
     zk_evm_types::LogQuery,
@@ -136,6 +136,20 @@ impl GenesisParams {
         Self::from_genesis_config(config, base_system_contracts, system_contracts)
     }
 
+    /// Same as [`Self::load_genesis_params()`], but loads system contracts from the specified system contracts
+    /// repository (e.g., `contracts/system-contracts`) rather than from the default location.
+    pub fn load_genesis_params_from_dir(
+        config: GenesisConfig,
+        system_contracts_path: PathBuf,
+    ) -> Result<GenesisParams, GenesisError> {
+        let repo = SystemContractsRepo {
+            root: system_contracts_path.clone(),
+        };
+        let base_system_contracts = BaseSystemContracts::load_from_repo(&repo);
+        let system_contracts = get_system_smart_contracts_from_dir(system_contracts_path);
+        Self::from_genesis_config(config, base_system_contracts, system_contracts)
+    }
+
     pub fn mock() -> Self {
         Self {
             base_system_contracts: BaseSystemContracts::load_from_disk(),
@@ -480,6 +494,7 @@ pub(crate) async fn create_genesis_l1_batch_from_storage_logs_and_factory_deps(
         version: protocol_version,
         timestamp: 0,
         l1_verifier_config,
+        verifier_address: None,
         base_system_contracts_hashes: base_system_contracts.hashes(),
         tx: None,
     };
@@ -1,3 +1,4 @@
 pub mod blocks_state_reporter;
 mod metrics;
+pub mod orphaned_blobs_detector;
 pub mod periodic_job;
@@ -1,4 +1,4 @@
-use vise::{Gauge, Metrics};
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Metrics};
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "fri_prover")]
@@ -9,3 +9,25 @@ pub(crate) struct FriProverMetrics {
 
 #[vise::register]
 pub(crate) static FRI_PROVER_METRICS: vise::Global<FriProverMetrics> = vise::Global::new();
+
+/// Kinds of object store blobs checked by the orphaned blobs detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "blobs", rename_all = "snake_case")]
+pub(crate) enum TrackedBlobs {
+    MerklePaths,
+    VmRunData,
+    L1BatchProofs,
+    Snapshots,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "house_keeper")]
+pub(crate) struct OrphanedBlobsMetrics {
+    /// Number of orphaned blobs detected during the latest check.
+    pub orphaned_blobs: Family<TrackedBlobs, Gauge<usize>>,
+    /// Number of removed orphaned blobs.
+    pub removed_orphaned_blobs: Family<TrackedBlobs, Counter>,
+}
+
+#[vise::register]
+pub(crate) static ORPHANED_BLOBS_METRICS: vise::Global<OrphanedBlobsMetrics> = vise::Global::new();
@@ -0,0 +1,259 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_object_store::{Bucket, ObjectStore};
+
+use crate::{
+    metrics::{TrackedBlobs, ORPHANED_BLOBS_METRICS},
+    periodic_job::PeriodicJob,
+};
+
+/// Maximum number of keys checked against Postgres in a single query.
+const KEYS_QUERY_CHUNK_SIZE: usize = 10_000;
+
+impl TrackedBlobs {
+    const ALL: [Self; 4] = [
+        Self::MerklePaths,
+        Self::VmRunData,
+        Self::L1BatchProofs,
+        Self::Snapshots,
+    ];
+
+    fn bucket(self) -> Bucket {
+        match self {
+            Self::MerklePaths | Self::VmRunData => Bucket::WitnessInput,
+            Self::L1BatchProofs => Bucket::ProofsFri,
+            Self::Snapshots => Bucket::StorageSnapshot,
+        }
+    }
+
+    /// Returns the key prefix shared by all blobs of this kind. Must correspond to `StoredObject::encode_key()`
+    /// implementations for the relevant objects.
+    fn key_prefix(self) -> &'static str {
+        match self {
+            Self::MerklePaths => "merkel_tree_paths_",
+            Self::VmRunData => "vm_run_data_",
+            Self::L1BatchProofs => "l1_batch_proof_",
+            Self::Snapshots => "snapshot_l1_batch_",
+        }
+    }
+}
+
+/// Detects object store blobs that are not referenced from Postgres anymore (e.g., because the blob removal failed
+/// after the corresponding rows were deleted). Detected blobs are reported via metrics and, if the removal grace
+/// period is configured, removed once they stay orphaned for this period.
+///
+/// Only blobs managed by the core are checked; prover blobs are out of scope.
+#[derive(Debug)]
+pub struct OrphanedBlobsDetector {
+    check_interval_ms: u64,
+    removal_grace_period: Option<Duration>,
+    connection_pool: ConnectionPool<Core>,
+    blob_store: Arc<dyn ObjectStore>,
+    /// Orphaned blobs together with the time they were first detected.
+    orphaned_blobs: HashMap<(TrackedBlobs, String), Instant>,
+}
+
+impl OrphanedBlobsDetector {
+    pub fn new(
+        check_interval_ms: u64,
+        removal_grace_period: Option<Duration>,
+        connection_pool: ConnectionPool<Core>,
+        blob_store: Arc<dyn ObjectStore>,
+    ) -> Self {
+        Self {
+            check_interval_ms,
+            removal_grace_period,
+            connection_pool,
+            blob_store,
+            orphaned_blobs: HashMap::new(),
+        }
+    }
+
+    async fn referenced_keys(
+        &self,
+        blobs: TrackedBlobs,
+        keys: &[String],
+    ) -> anyhow::Result<HashSet<String>> {
+        let mut conn = self
+            .connection_pool
+            .connection_tagged("house_keeper")
+            .await?;
+        let mut referenced_keys = HashSet::new();
+        match blobs {
+            TrackedBlobs::MerklePaths | TrackedBlobs::VmRunData | TrackedBlobs::L1BatchProofs => {
+                for chunk in keys.chunks(KEYS_QUERY_CHUNK_SIZE) {
+                    let referenced_chunk = conn
+                        .proof_generation_dal()
+                        .filter_referenced_blob_urls(chunk)
+                        .await?;
+                    referenced_keys.extend(referenced_chunk);
+                }
+            }
+            TrackedBlobs::Snapshots => {
+                // Snapshot file paths are full URLs including the storage prefix
+                let filepaths = conn.snapshots_dal().get_all_snapshot_filepaths().await?;
+                referenced_keys.extend(
+                    filepaths
+                        .iter()
+                        .filter_map(|path| path.rsplit('/').next())
+                        .map(str::to_owned),
+                );
+            }
+        }
+        Ok(referenced_keys)
+    }
+
+    async fn detect_orphaned_blobs(&mut self) -> anyhow::Result<()> {
+        let now = Instant::now();
+        let mut orphaned_blobs = HashMap::new();
+        for blobs in TrackedBlobs::ALL {
+            let keys = self
+                .blob_store
+                .list_raw(blobs.bucket(), blobs.key_prefix())
+                .await
+                .with_context(|| format!("failed listing {blobs:?} blobs"))?;
+            let referenced_keys = self.referenced_keys(blobs, &keys).await?;
+
+            let mut orphaned_count = 0;
+            for key in keys {
+                if referenced_keys.contains(&key) {
+                    continue;
+                }
+                orphaned_count += 1;
+                let blob = (blobs, key);
+                let detected_at = self.orphaned_blobs.get(&blob).copied().unwrap_or(now);
+                orphaned_blobs.insert(blob, detected_at);
+            }
+            if orphaned_count > 0 {
+                tracing::info!(
+                    "Detected {orphaned_count} orphaned {blobs:?} blobs in bucket `{}`",
+                    blobs.bucket()
+                );
+            }
+            ORPHANED_BLOBS_METRICS.orphaned_blobs[&blobs].set(orphaned_count);
+        }
+        // Blobs that are no longer orphaned (e.g., removed or referenced in the meantime) are forgotten.
+        self.orphaned_blobs = orphaned_blobs;
+
+        if let Some(grace_period) = self.removal_grace_period {
+            self.remove_orphaned_blobs(now, grace_period).await;
+        }
+        Ok(())
+    }
+
+    async fn remove_orphaned_blobs(&mut self, now: Instant, grace_period: Duration) {
+        let expired_blobs: Vec<_> = self
+            .orphaned_blobs
+            .iter()
+            .filter(|(_, &detected_at)| now.duration_since(detected_at) >= grace_period)
+            .map(|(blob, _)| blob.clone())
+            .collect();
+
+        for blob in expired_blobs {
+            let (blobs, key) = &blob;
+            match self.blob_store.remove_raw(blobs.bucket(), key).await {
+                Ok(()) => {
+                    tracing::info!(
+                        "Removed orphaned blob `{key}` from bucket `{}`",
+                        blobs.bucket()
+                    );
+                    ORPHANED_BLOBS_METRICS.removed_orphaned_blobs[blobs].inc();
+                    self.orphaned_blobs.remove(&blob);
+                }
+                Err(err) => {
+                    // The blob will be retried during the next check.
+                    tracing::warn!(
+                        "Failed removing orphaned blob `{key}` from bucket `{}`: {:#}",
+                        blobs.bucket(),
+                        anyhow::Error::from(err)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PeriodicJob for OrphanedBlobsDetector {
+    const SERVICE_NAME: &'static str = "OrphanedBlobsDetector";
+
+    async fn run_routine_task(&mut self) -> anyhow::Result<()> {
+        self.detect_orphaned_blobs().await
+    }
+
+    fn polling_interval_ms(&self) -> u64 {
+        self.check_interval_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_object_store::MockObjectStore;
+    use zksync_types::{snapshots::SnapshotVersion, L1BatchNumber};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn detecting_and_removing_orphaned_snapshot_blobs() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let blob_store = MockObjectStore::arc();
+        let referenced_key = "snapshot_l1_batch_1_factory_deps.proto.gzip";
+        let orphaned_key = "snapshot_l1_batch_0_factory_deps.proto.gzip";
+        for key in [referenced_key, orphaned_key] {
+            blob_store
+                .put_raw(Bucket::StorageSnapshot, key, vec![1, 2, 3])
+                .await
+                .unwrap();
+        }
+        let referenced_path = format!(
+            "{}/{referenced_key}",
+            blob_store.storage_prefix_raw(Bucket::StorageSnapshot)
+        );
+        pool.connection()
+            .await
+            .unwrap()
+            .snapshots_dal()
+            .add_snapshot(
+                SnapshotVersion::Version0,
+                L1BatchNumber(1),
+                0,
+                &referenced_path,
+            )
+            .await
+            .unwrap();
+
+        // Orphaned blobs must only be reported if there's no grace period.
+        let mut detector =
+            OrphanedBlobsDetector::new(1_000, None, pool.clone(), blob_store.clone());
+        detector.run_routine_task().await.unwrap();
+        let orphaned_blobs: Vec<_> = detector.orphaned_blobs.keys().cloned().collect();
+        assert_eq!(
+            orphaned_blobs,
+            [(TrackedBlobs::Snapshots, orphaned_key.to_owned())]
+        );
+        blob_store
+            .get_raw(Bucket::StorageSnapshot, orphaned_key)
+            .await
+            .unwrap();
+
+        let mut detector =
+            OrphanedBlobsDetector::new(1_000, Some(Duration::ZERO), pool, blob_store.clone());
+        detector.run_routine_task().await.unwrap();
+        assert!(detector.orphaned_blobs.is_empty());
+        blob_store
+            .get_raw(Bucket::StorageSnapshot, orphaned_key)
+            .await
+            .unwrap_err();
+        blob_store
+            .get_raw(Bucket::StorageSnapshot, referenced_key)
+            .await
+            .unwrap();
+    }
+}
@@ -80,6 +80,85 @@ impl TreeEntryWithProof {
     }
 }
 
+/// Proofs for multiple keys at the same tree version. Hashes shared among Merkle paths (e.g., hashes of the upper tree levels
+/// or of the same empty subtrees) are stored only once.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TreeBatchProofs {
+    /// Deduplicated hashes referenced by the entries' Merkle paths.
+    pub hashes: Vec<H256>,
+    pub entries: Vec<TreeEntryWithBatchProof>,
+}
+
+/// Entry in a [`TreeBatchProofs`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TreeEntryWithBatchProof {
+    #[serde(default, skip_serializing_if = "H256::is_zero")]
+    pub value: H256,
+    #[serde(default, skip_serializing_if = "TreeEntryWithProof::is_zero")]
+    pub index: u64,
+    /// Indices of the Merkle path hashes in [`TreeBatchProofs::hashes`], in the root-to-leaf order.
+    pub merkle_path: Vec<u32>,
+}
+
+impl TreeBatchProofs {
+    fn new(entries: Vec<TreeEntryWithProof>) -> Self {
+        let mut hash_indices = HashMap::new();
+        let mut hashes = vec![];
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                let merkle_path = entry
+                    .merkle_path
+                    .into_iter()
+                    .map(|hash| {
+                        *hash_indices.entry(hash).or_insert_with(|| {
+                            hashes.push(hash);
+                            (hashes.len() - 1) as u32
+                        })
+                    })
+                    .collect();
+                TreeEntryWithBatchProof {
+                    value: entry.value,
+                    index: entry.index,
+                    merkle_path,
+                }
+            })
+            .collect();
+        Self { hashes, entries }
+    }
+
+    /// Expands these proofs into separate proofs for each entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a Merkle path references a non-existing hash.
+    pub fn into_entries(self) -> anyhow::Result<Vec<TreeEntryWithProof>> {
+        let hashes = self.hashes;
+        self.entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let merkle_path = entry
+                    .merkle_path
+                    .iter()
+                    .map(|&idx| {
+                        hashes.get(idx as usize).copied().with_context(|| {
+                            format!(
+                                "Merkle path for entry #{i} references non-existing hash #{idx}"
+                            )
+                        })
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+                Ok(TreeEntryWithProof {
+                    value: entry.value,
+                    index: entry.index,
+                    merkle_path,
+                })
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 struct HexNodeKey(NodeKey);
 
@@ -289,6 +368,14 @@ pub trait TreeApiClient: 'static + Send + Sync + fmt::Debug {
         l1_batch_number: L1BatchNumber,
         hashed_keys: Vec<U256>,
     ) -> Result<Vec<TreeEntryWithProof>, TreeApiError>;
+
+    /// Obtains proofs for the specified `hashed_keys` at the specified tree version (= L1 batch number)
+    /// in a compact form, with hashes shared among proofs deduplicated.
+    async fn get_batch_proofs(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeBatchProofs, TreeApiError>;
 }
 
 /// In-memory client implementation.
@@ -316,6 +403,15 @@ impl TreeApiClient for LazyAsyncTreeReader {
             Err(TreeApiError::NotReady(None))
         }
     }
+
+    async fn get_batch_proofs(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeBatchProofs, TreeApiError> {
+        let entries = self.get_proofs(l1_batch_number, hashed_keys).await?;
+        Ok(TreeBatchProofs::new(entries))
+    }
 }
 
 /// [`TreeApiClient`] implementation requesting data from a Merkle tree API server.
@@ -324,6 +420,7 @@ pub struct TreeApiHttpClient {
     inner: reqwest::Client,
     info_url: String,
     proofs_url: String,
+    batch_proofs_url: String,
 }
 
 impl TreeApiHttpClient {
@@ -338,6 +435,7 @@ impl TreeApiHttpClient {
             inner: client,
             info_url: url_base.to_owned(),
             proofs_url: format!("{url_base}/proofs"),
+            batch_proofs_url: format!("{url_base}/proofs/batch"),
         }
     }
 }
@@ -384,9 +482,32 @@ impl TreeApiClient for TreeApiHttpClient {
         l1_batch_number: L1BatchNumber,
         hashed_keys: Vec<U256>,
     ) -> Result<Vec<TreeEntryWithProof>, TreeApiError> {
+        let response: TreeProofsResponse = self
+            .request_proofs(&self.proofs_url, l1_batch_number, hashed_keys)
+            .await?;
+        Ok(response.entries)
+    }
+
+    async fn get_batch_proofs(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<TreeBatchProofs, TreeApiError> {
+        self.request_proofs(&self.batch_proofs_url, l1_batch_number, hashed_keys)
+            .await
+    }
+}
+
+impl TreeApiHttpClient {
+    async fn request_proofs<R: de::DeserializeOwned>(
+        &self,
+        url: &str,
+        l1_batch_number: L1BatchNumber,
+        hashed_keys: Vec<U256>,
+    ) -> Result<R, TreeApiError> {
         let response = self
             .inner
-            .post(&self.proofs_url)
+            .post(url)
             .json(&TreeProofsRequest {
                 l1_batch_number,
                 hashed_keys,
@@ -416,10 +537,9 @@ impl TreeApiClient for TreeApiHttpClient {
         let response = response.error_for_status().with_context(|| {
             format!("requesting proofs for L1 batch #{l1_batch_number} returned non-OK response")
         })?;
-        let response: TreeProofsResponse = response.json().await.with_context(|| {
+        Ok(response.json().await.with_context(|| {
             format!("failed deserializing proofs for L1 batch #{l1_batch_number}")
-        })?;
-        Ok(response.entries)
+        })?)
     }
 }
 
@@ -457,6 +577,20 @@ impl AsyncTreeReader {
         Ok(Json(response))
     }
 
+    async fn get_batch_proofs_handler(
+        State(this): State<Self>,
+        Json(request): Json<TreeProofsRequest>,
+    ) -> Result<Json<TreeBatchProofs>, TreeApiServerError> {
+        let latency = API_METRICS.latency[&MerkleTreeApiMethod::GetBatchProofs].start();
+        let entries = this
+            .get_proofs_inner(request.l1_batch_number, request.hashed_keys)
+            .await
+            .map_err(TreeApiServerError::NoTreeVersion)?;
+        let response = TreeBatchProofs::new(entries);
+        latency.observe();
+        Ok(Json(response))
+    }
+
     async fn get_nodes_handler(
         State(this): State<Self>,
         Json(request): Json<TreeNodesRequest>,
@@ -507,6 +641,10 @@ impl AsyncTreeReader {
         let app = Router::new()
             .route("/", routing::get(Self::info_handler))
             .route("/proofs", routing::post(Self::get_proofs_handler))
+            .route(
+                "/proofs/batch",
+                routing::post(Self::get_batch_proofs_handler),
+            )
             .route("/debug/nodes", routing::post(Self::get_nodes_handler))
             .route(
                 "/debug/stale-keys",
@@ -52,16 +52,37 @@ async fn merkle_tree_api() {
     hashed_keys.extend((0_u8..10).map(|byte| U256::from_big_endian(&[byte; 32])));
 
     let proofs = api_client
-        .get_proofs(L1BatchNumber(5), hashed_keys)
+        .get_proofs(L1BatchNumber(5), hashed_keys.clone())
         .await
         .unwrap();
     assert_eq!(proofs.len(), 20);
-    for (i, proof) in proofs.into_iter().enumerate() {
+    for (i, proof) in proofs.iter().enumerate() {
         let should_be_present = i < 10;
         assert_eq!(proof.index == 0, !should_be_present);
         assert!(!proof.merkle_path.is_empty());
     }
 
+    let batch_proofs = api_client
+        .get_batch_proofs(L1BatchNumber(5), hashed_keys.clone())
+        .await
+        .unwrap();
+    let total_path_len: usize = proofs.iter().map(|proof| proof.merkle_path.len()).sum();
+    assert!(batch_proofs.hashes.len() < total_path_len);
+    let expanded_proofs = batch_proofs.into_entries().unwrap();
+    assert_eq!(expanded_proofs.len(), proofs.len());
+    for ((proof, expanded), key) in proofs.iter().zip(&expanded_proofs).zip(&hashed_keys) {
+        assert_eq!(expanded.value, proof.value);
+        assert_eq!(expanded.index, proof.index);
+        assert_eq!(expanded.merkle_path, proof.merkle_path);
+        expanded.verify(*key, tree_info.root_hash).unwrap();
+    }
+
+    let err = api_client
+        .get_batch_proofs(L1BatchNumber(10), vec![])
+        .await
+        .unwrap_err();
+    assert_matches!(err, TreeApiError::NoVersion(_));
+
     let err = api_client
         .get_proofs(L1BatchNumber(10), vec![])
         .await
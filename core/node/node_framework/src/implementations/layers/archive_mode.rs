@@ -0,0 +1,90 @@
+use zksync_node_sync::archive_mode::{ArchiveIndexesTask, ArchiveModeValidationTask};
+
+use crate::{
+    implementations::resources::pools::{MasterPool, PoolResource},
+    service::StopReceiver,
+    task::{Task, TaskId, TaskKind},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the archive (full-history) mode of the external node.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+///
+/// ## Adds preconditions
+///
+/// - `ArchiveModeValidationTask`
+///
+/// ## Adds tasks
+///
+/// - `ArchiveIndexesTask`
+#[derive(Debug)]
+pub struct ArchiveModeLayer;
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub master_pool: PoolResource<MasterPool>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub validation_task: ArchiveModeValidationTask,
+    #[context(task)]
+    pub indexes_task: ArchiveIndexesTask,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ArchiveModeLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "archive_mode_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let validation_task = ArchiveModeValidationTask::new(input.master_pool.get().await?);
+        // Building indexes may take a long time, so we use a dedicated connection.
+        let indexes_task = ArchiveIndexesTask::new(input.master_pool.get_singleton().await?);
+        Ok(Output {
+            validation_task,
+            indexes_task,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ArchiveModeValidationTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Precondition
+    }
+
+    fn id(&self) -> TaskId {
+        "archive_mode_validation".into()
+    }
+
+    async fn run(self: Box<Self>, _stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run().await
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for ArchiveIndexesTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::OneshotTask
+    }
+
+    fn id(&self) -> TaskId {
+        "archive_indexes".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -4,7 +4,7 @@ use zksync_dal::{ConnectionPool, Core};
 use crate::{
     implementations::resources::pools::{MasterPool, PoolResource, ReplicaPool},
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{StopPhase, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -61,6 +61,10 @@ pub struct ContractVerificationApiTask {
 
 #[async_trait::async_trait]
 impl Task for ContractVerificationApiTask {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         "contract_verification_api".into()
     }
@@ -69,7 +73,7 @@ impl Task for ContractVerificationApiTask {
         zksync_contract_verification_server::start_server(
             self.master_pool,
             self.replica_pool,
-            self.config.bind_addr(),
+            &self.config,
             stop_receiver.0,
         )
         .await
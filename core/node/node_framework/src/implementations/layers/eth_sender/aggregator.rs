@@ -2,7 +2,7 @@ use anyhow::Context;
 use zksync_circuit_breaker::l1_txs::FailedL1TransactionChecker;
 use zksync_config::configs::{eth_sender::EthConfig, gateway::GatewayChainConfig, ContractsConfig};
 use zksync_eth_client::BoundEthInterface;
-use zksync_eth_sender::{Aggregator, EthTxAggregator};
+use zksync_eth_sender::{Aggregator, CommitPostponementPolicy, EthTxAggregator};
 use zksync_types::{commitment::L1BatchCommitmentMode, settlement::SettlementMode, L2ChainId};
 
 use crate::{
@@ -12,6 +12,7 @@ use crate::{
             BoundEthInterfaceForBlobsResource, BoundEthInterfaceForL2Resource,
             BoundEthInterfaceResource,
         },
+        gas_adjuster::GasAdjusterResource,
         healthcheck::AppHealthCheckResource,
         object_store::ObjectStoreResource,
         pools::{MasterPool, PoolResource, ReplicaPool},
@@ -35,6 +36,7 @@ use crate::{
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
 /// - `ObjectStoreResource`
+/// - `GasAdjusterResource` (optional; used to postpone commit transactions during L1 gas price spikes)
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
 /// ## Adds tasks
@@ -59,6 +61,7 @@ pub struct Input {
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
     pub eth_client_gateway: Option<BoundEthInterfaceForL2Resource>,
     pub object_store: ObjectStoreResource,
+    pub gas_adjuster: Option<GasAdjusterResource>,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
@@ -185,6 +188,25 @@ impl WiringLayer for EthTxAggregatorLayer {
         )
         .await;
 
+        let commit_postponement_policy = if self.settlement_mode.is_gateway() {
+            None
+        } else if let Some(gas_adjuster) = input.gas_adjuster {
+            CommitPostponementPolicy::new(gas_adjuster.0, &config)
+        } else {
+            if config.max_commit_base_fee_per_gas.is_some() {
+                tracing::warn!(
+                    "Commit transactions will not be postponed despite `max_commit_base_fee_per_gas` being set \
+                     since gas adjuster is not available"
+                );
+            }
+            None
+        };
+        let eth_tx_aggregator = if let Some(policy) = commit_postponement_policy {
+            eth_tx_aggregator.with_commit_postponement_policy(policy)
+        } else {
+            eth_tx_aggregator
+        };
+
         // Insert circuit breaker.
         input
             .circuit_breakers
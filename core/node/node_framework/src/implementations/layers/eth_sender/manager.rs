@@ -8,7 +8,8 @@ use crate::{
         circuit_breakers::CircuitBreakersResource,
         eth_interface::{
             BoundEthInterfaceForBlobsResource, BoundEthInterfaceForL2Resource,
-            BoundEthInterfaceResource,
+            BoundEthInterfaceForPreviousBlobsOperatorResource,
+            BoundEthInterfaceForPreviousOperatorResource, BoundEthInterfaceResource,
         },
         gas_adjuster::GasAdjusterResource,
         healthcheck::AppHealthCheckResource,
@@ -31,6 +32,8 @@ use crate::{
 /// - `PoolResource<ReplicaPool>`
 /// - `BoundEthInterfaceResource`
 /// - `BoundEthInterfaceForBlobsResource` (optional)
+/// - `BoundEthInterfaceForPreviousOperatorResource` (optional)
+/// - `BoundEthInterfaceForPreviousBlobsOperatorResource` (optional)
 /// - `TxParamsResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 ///
@@ -50,6 +53,9 @@ pub struct Input {
     pub eth_client: BoundEthInterfaceResource,
     pub eth_client_blobs: Option<BoundEthInterfaceForBlobsResource>,
     pub eth_client_gateway: Option<BoundEthInterfaceForL2Resource>,
+    pub eth_client_previous_operator: Option<BoundEthInterfaceForPreviousOperatorResource>,
+    pub eth_client_previous_blobs_operator:
+        Option<BoundEthInterfaceForPreviousBlobsOperatorResource>,
     pub gas_adjuster: GasAdjusterResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -87,6 +93,8 @@ impl WiringLayer for EthTxManagerLayer {
         let eth_client = input.eth_client.0.clone();
         let eth_client_blobs = input.eth_client_blobs.map(|c| c.0);
         let l2_client = input.eth_client_gateway.map(|c| c.0);
+        let previous_eth_client = input.eth_client_previous_operator.map(|c| c.0);
+        let previous_eth_client_blobs = input.eth_client_previous_blobs_operator.map(|c| c.0);
 
         let config = self.eth_sender_config.sender.context("sender")?;
 
@@ -99,6 +107,8 @@ impl WiringLayer for EthTxManagerLayer {
             Some(eth_client),
             eth_client_blobs,
             l2_client,
+            previous_eth_client,
+            previous_eth_client_blobs,
         );
 
         // Insert circuit breaker.
@@ -7,6 +7,7 @@ use zksync_types::{settlement::SettlementMode, L2ChainId};
 use crate::{
     implementations::resources::{
         eth_interface::{EthInterfaceResource, L2InterfaceResource},
+        healthcheck::{AppHealthCheckResource, DegradedMode},
         pools::{MasterPool, PoolResource},
     },
     service::StopReceiver,
@@ -34,6 +35,8 @@ pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     pub eth_client: EthInterfaceResource,
     pub gateway_client: Option<L2InterfaceResource>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -71,6 +74,7 @@ impl WiringLayer for EthWatchLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let layer_name = self.layer_name();
         let main_pool = input.master_pool.get().await?;
         let client = input.eth_client.0;
 
@@ -108,32 +112,52 @@ impl WiringLayer for EthWatchLayer {
                 .as_ref()
                 .map(|a| a.state_transition_proxy_addr),
             self.contracts_config.chain_admin_addr,
+            self.eth_watch_config.address_policy_registry_addr,
             self.contracts_config.governance_addr,
             self.eth_watch_config.confirmations_for_eth_event,
             self.chain_id,
         );
 
+        let mut degraded_mode = DegradedMode::default();
         let sl_l2_client: Option<Box<dyn L2EthClient>> =
-            if let Some(gateway_client) = input.gateway_client {
-                let contracts_config = self.gateway_chain_config.unwrap();
-                Some(Box::new(EthHttpQueryClient::new(
-                    gateway_client.0,
-                    contracts_config.diamond_proxy_addr,
-                    // Only present on L1.
-                    None,
-                    // Only present on L1.
-                    None,
-                    // Only present on L1.
-                    None,
-                    Some(contracts_config.state_transition_proxy_addr),
-                    contracts_config.chain_admin_addr,
-                    contracts_config.governance_addr,
-                    self.eth_watch_config.confirmations_for_eth_event,
-                    self.chain_id,
-                )))
-            } else {
-                None
+            match (input.gateway_client, self.gateway_chain_config) {
+                (Some(gateway_client), Some(contracts_config)) => {
+                    Some(Box::new(EthHttpQueryClient::new(
+                        gateway_client.0,
+                        contracts_config.diamond_proxy_addr,
+                        // Only present on L1.
+                        None,
+                        // Only present on L1.
+                        None,
+                        // Only present on L1.
+                        None,
+                        Some(contracts_config.state_transition_proxy_addr),
+                        contracts_config.chain_admin_addr,
+                        // The registry is only watched on L1.
+                        None,
+                        contracts_config.governance_addr,
+                        self.eth_watch_config.confirmations_for_eth_event,
+                        self.chain_id,
+                    )))
+                }
+                (Some(_), None) => {
+                    // Settling on Gateway requires the config, so the chain settles on L1.
+                    degraded_mode.missing_input("GatewayChainConfig", &["chain_batch_roots"]);
+                    None
+                }
+                (None, _) if self.settlement_mode.is_gateway() => {
+                    // Events are watched on L1 instead.
+                    degraded_mode.missing_input(
+                        "L2InterfaceResource",
+                        &["gateway_events", "chain_batch_roots"],
+                    );
+                    None
+                }
+                (None, _) => None,
             };
+        input
+            .app_health
+            .insert_degraded_component(layer_name, degraded_mode)?;
 
         let eth_watch = EthWatch::new(
             &chain_admin_contract(),
@@ -142,8 +166,16 @@ impl WiringLayer for EthWatchLayer {
             main_pool,
             self.eth_watch_config.poll_interval(),
             self.chain_id,
+            self.eth_watch_config.address_policy_registry_addr,
         )
         .await?;
+        for health_check in eth_watch.health_checks() {
+            input
+                .app_health
+                .0
+                .insert_component(health_check)
+                .map_err(WiringError::internal)?;
+        }
 
         Ok(Output { eth_watch })
     }
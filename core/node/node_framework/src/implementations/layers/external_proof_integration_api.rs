@@ -8,7 +8,7 @@ use crate::{
         pools::{PoolResource, ReplicaPool},
     },
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{StopPhase, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -71,6 +71,10 @@ impl WiringLayer for ExternalProofIntegrationApiLayer {
 
 #[async_trait::async_trait]
 impl Task for Api {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         "external_proof_integration_api".into()
     }
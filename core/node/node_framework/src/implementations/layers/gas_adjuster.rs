@@ -65,8 +65,35 @@ impl WiringLayer for GasAdjusterLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let gateway_client = if self.gas_adjuster_config.gateway_settlement_supported {
+            let gateway_client = input.l2_inteface_client.ok_or_else(|| {
+                WiringError::Configuration(
+                    "Gateway settlement is supported according to gas adjuster config, but Gateway RPC client \
+                     is not configured (is `gateway_rpc_url` set in L1 secrets?)"
+                        .into(),
+                )
+            })?;
+            Some(gateway_client)
+        } else {
+            if input.l2_inteface_client.is_some() {
+                tracing::info!(
+                    "Gateway RPC client is configured, but Gateway settlement is not supported according \
+                     to gas adjuster config; the client will not be used"
+                );
+            }
+            None
+        };
+
         let client = if self.gas_adjuster_config.settlement_mode.is_gateway() {
-            input.l2_inteface_client.unwrap().0.into()
+            let gateway_client = gateway_client.ok_or_else(|| {
+                WiringError::Configuration(
+                    "settlement mode is set to Gateway, but Gateway settlement is not supported according to \
+                     gas adjuster config (`gateway_settlement_supported` is not set); L1-only deployments \
+                     must use `SettlesToL1` mode"
+                        .into(),
+                )
+            })?;
+            gateway_client.0.into()
         } else {
             input.eth_interface_client.0.into()
         };
@@ -9,7 +9,7 @@ use zksync_web3_decl::jsonrpsee::core::Serialize;
 use crate::{
     implementations::resources::healthcheck::AppHealthCheckResource,
     service::StopReceiver,
-    task::{Task, TaskId, TaskKind},
+    task::{StopPhase, Task, TaskId, TaskKind},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -77,6 +77,10 @@ impl Task for HealthCheckTask {
         TaskKind::UnconstrainedTask
     }
 
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Infrastructure
+    }
+
     fn id(&self) -> TaskId {
         "healthcheck_server".into()
     }
@@ -1,10 +1,16 @@
+use std::time::Duration;
+
 use zksync_config::configs::house_keeper::HouseKeeperConfig;
 use zksync_house_keeper::{
-    blocks_state_reporter::L1BatchMetricsReporter, periodic_job::PeriodicJob,
+    blocks_state_reporter::L1BatchMetricsReporter, orphaned_blobs_detector::OrphanedBlobsDetector,
+    periodic_job::PeriodicJob,
 };
 
 use crate::{
-    implementations::resources::pools::{PoolResource, ReplicaPool},
+    implementations::resources::{
+        object_store::ObjectStoreResource,
+        pools::{PoolResource, ReplicaPool},
+    },
     service::StopReceiver,
     task::{Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
@@ -22,6 +28,8 @@ pub struct HouseKeeperLayer {
 #[context(crate = crate)]
 pub struct Input {
     pub replica_pool: PoolResource<ReplicaPool>,
+    /// Only required if orphaned blobs are checked.
+    pub object_store: Option<ObjectStoreResource>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -29,6 +37,8 @@ pub struct Input {
 pub struct Output {
     #[context(task)]
     pub l1_batch_metrics_reporter: L1BatchMetricsReporter,
+    #[context(task)]
+    pub orphaned_blobs_detector: Option<OrphanedBlobsDetector>,
 }
 
 impl HouseKeeperLayer {
@@ -56,11 +66,34 @@ impl WiringLayer for HouseKeeperLayer {
         let l1_batch_metrics_reporter = L1BatchMetricsReporter::new(
             self.house_keeper_config
                 .l1_batch_metrics_reporting_interval_ms,
-            replica_pool,
+            replica_pool.clone(),
         );
 
+        let orphaned_blobs_detector = if let Some(check_interval_ms) =
+            self.house_keeper_config.orphaned_blobs_check_interval_ms
+        {
+            let object_store = input.object_store.ok_or_else(|| {
+                WiringError::Configuration(
+                    "object store is required to check orphaned blobs".into(),
+                )
+            })?;
+            let removal_grace_period = self
+                .house_keeper_config
+                .orphaned_blobs_removal_grace_period_secs
+                .map(Duration::from_secs);
+            Some(OrphanedBlobsDetector::new(
+                check_interval_ms,
+                removal_grace_period,
+                replica_pool,
+                object_store.0,
+            ))
+        } else {
+            None
+        };
+
         Ok(Output {
             l1_batch_metrics_reporter,
+            orphaned_blobs_detector,
         })
     }
 }
@@ -75,3 +108,14 @@ impl Task for L1BatchMetricsReporter {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for OrphanedBlobsDetector {
+    fn id(&self) -> TaskId {
+        "orphaned_blobs_detector".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -20,7 +20,7 @@ use crate::{
         web3_api::TreeApiClientResource,
     },
     service::{ShutdownHook, StopReceiver},
-    task::{Task, TaskId, TaskKind},
+    task::{StopPhase, Task, TaskId, TaskKind},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -179,6 +179,10 @@ impl WiringLayer for MetadataCalculatorLayer {
 
 #[async_trait::async_trait]
 impl Task for MetadataCalculator {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Persistence
+    }
+
     fn id(&self) -> TaskId {
         "metadata_calculator".into()
     }
@@ -196,6 +200,10 @@ pub struct TreeApiTask {
 
 #[async_trait::async_trait]
 impl Task for TreeApiTask {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         "tree_api".into()
     }
@@ -1,3 +1,4 @@
+pub mod archive_mode;
 pub mod base_token;
 pub mod batch_status_updater;
 pub mod block_reverter;
@@ -22,6 +23,7 @@ pub mod main_node_client;
 pub mod main_node_fee_params_fetcher;
 pub mod metadata_calculator;
 pub mod node_storage_init;
+pub mod node_version_check;
 pub mod object_store;
 pub mod pk_signing_eth_client;
 pub mod pools_layer;
@@ -31,6 +33,7 @@ pub mod proof_data_handler;
 pub mod pruning;
 pub mod query_eth_client;
 pub mod reorg_detector;
+pub mod settlement_layer_data;
 pub mod sigint;
 pub mod state_keeper;
 pub mod sync_state_updater;
@@ -0,0 +1,66 @@
+use zksync_node_sync::node_version_check::NodeVersionCheckTask;
+
+use crate::{
+    implementations::resources::main_node_client::MainNodeClientResource,
+    service::StopReceiver,
+    task::{Task, TaskId, TaskKind},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer for the node version compatibility precondition for external node.
+/// Ensures that the external node can execute the protocol version used by the main node.
+///
+/// ## Requests resources
+///
+/// - `MainNodeClientResource`
+///
+/// ## Adds preconditions
+///
+/// - `NodeVersionCheckTask`
+#[derive(Debug)]
+pub struct NodeVersionCheckLayer;
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub main_node_client: MainNodeClientResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    #[context(task)]
+    pub task: NodeVersionCheckTask,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for NodeVersionCheckLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "node_version_check_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let MainNodeClientResource(main_node_client) = input.main_node_client;
+        let task = NodeVersionCheckTask::new(main_node_client);
+        Ok(Output { task })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for NodeVersionCheckTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Precondition
+    }
+
+    fn id(&self) -> TaskId {
+        "node_version_check".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run_once(stop_receiver.0).await
+    }
+}
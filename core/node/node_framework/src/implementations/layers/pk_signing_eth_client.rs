@@ -8,7 +8,9 @@ use zksync_eth_client::{clients::PKSigningClient, EthInterface};
 use crate::{
     implementations::resources::eth_interface::{
         BoundEthInterfaceForBlobsResource, BoundEthInterfaceForL2Resource,
-        BoundEthInterfaceResource, EthInterfaceResource, GatewayEthInterfaceResource,
+        BoundEthInterfaceForPreviousBlobsOperatorResource,
+        BoundEthInterfaceForPreviousOperatorResource, BoundEthInterfaceResource,
+        EthInterfaceResource, GatewayEthInterfaceResource,
     },
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
@@ -37,6 +39,11 @@ pub struct Output {
     /// Only provided if the blob operator key is provided to the layer.
     pub signing_client_for_blobs: Option<BoundEthInterfaceForBlobsResource>,
     pub signing_client_for_gateway: Option<BoundEthInterfaceForL2Resource>,
+    /// Only provided if the previous operator key is provided to the layer.
+    pub signing_client_for_previous_operator: Option<BoundEthInterfaceForPreviousOperatorResource>,
+    /// Only provided if the previous blob operator key is provided to the layer.
+    pub signing_client_for_previous_blobs_operator:
+        Option<BoundEthInterfaceForPreviousBlobsOperatorResource>,
 }
 
 impl PKSigningEthClientLayer {
@@ -86,17 +93,33 @@ impl WiringLayer for PKSigningEthClientLayer {
         );
         let signing_client = BoundEthInterfaceResource(Box::new(signing_client));
 
-        let signing_client_for_blobs = self.wallets.blob_operator.map(|blob_operator| {
-            let private_key = blob_operator.private_key();
-            let signing_client_for_blobs = PKSigningClient::new_raw(
-                private_key.clone(),
+        let new_l1_signing_client = |wallet: &wallets::Wallet| {
+            PKSigningClient::new_raw(
+                wallet.private_key().clone(),
                 self.contracts_config.diamond_proxy_addr,
                 gas_adjuster_config.default_priority_fee_per_gas,
                 l1_chain_id,
-                query_client,
-            );
-            BoundEthInterfaceForBlobsResource(Box::new(signing_client_for_blobs))
+                query_client.clone(),
+            )
+        };
+        let signing_client_for_blobs = self.wallets.blob_operator.as_ref().map(|blob_operator| {
+            BoundEthInterfaceForBlobsResource(Box::new(new_l1_signing_client(blob_operator)))
         });
+        let signing_client_for_previous_operator =
+            self.wallets.previous_operator.as_ref().map(|operator| {
+                BoundEthInterfaceForPreviousOperatorResource(Box::new(new_l1_signing_client(
+                    operator,
+                )))
+            });
+        let signing_client_for_previous_blobs_operator = self
+            .wallets
+            .previous_blob_operator
+            .as_ref()
+            .map(|operator| {
+                BoundEthInterfaceForPreviousBlobsOperatorResource(Box::new(new_l1_signing_client(
+                    operator,
+                )))
+            });
 
         let signing_client_for_gateway = if let (Some(client), Some(gateway_contracts)) =
             (&input.gateway_client, self.gateway_chain_config.as_ref())
@@ -125,6 +148,8 @@ impl WiringLayer for PKSigningEthClientLayer {
             signing_client,
             signing_client_for_blobs,
             signing_client_for_gateway,
+            signing_client_for_previous_operator,
+            signing_client_for_previous_blobs_operator,
         })
     }
 }
@@ -17,7 +17,7 @@ use crate::{
         pools::{PoolResource, ReplicaPool},
     },
     service::StopReceiver,
-    task::{Task, TaskId, TaskKind},
+    task::{StopPhase, Task, TaskId, TaskKind},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -83,6 +83,10 @@ impl Task for PostgresMetricsScrapingTask {
         TaskKind::UnconstrainedTask
     }
 
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Infrastructure
+    }
+
     fn id(&self) -> TaskId {
         "postgres_metrics_scraping".into()
     }
@@ -4,7 +4,7 @@ use zksync_vlog::prometheus::PrometheusExporterConfig;
 use crate::{
     implementations::resources::healthcheck::AppHealthCheckResource,
     service::StopReceiver,
-    task::{Task, TaskId, TaskKind},
+    task::{StopPhase, Task, TaskId, TaskKind},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -67,6 +67,10 @@ impl Task for PrometheusExporterTask {
         TaskKind::UnconstrainedTask
     }
 
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Infrastructure
+    }
+
     fn id(&self) -> TaskId {
         "prometheus_exporter".into()
     }
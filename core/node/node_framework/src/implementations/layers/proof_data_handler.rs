@@ -1,8 +1,8 @@
 use zksync_config::configs::ProofDataHandlerConfig;
 use zksync_proof_data_handler::{
-    ProofDataProcessor, RequestProcessor, RpcClient, TeeProofDataHandler,
+    AdminApi, ProofDataProcessor, RequestProcessor, RpcClient, TeeProofDataHandler,
 };
-use zksync_types::{commitment::L1BatchCommitmentMode, L2ChainId};
+use zksync_types::{commitment::L1BatchCommitmentMode, secrets::APIKey, L2ChainId};
 
 use crate::{
     implementations::resources::{
@@ -10,7 +10,7 @@ use crate::{
         pools::{MasterPool, PoolResource},
     },
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{StopPhase, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -21,6 +21,7 @@ pub struct ProofDataHandlerLayer {
     proof_data_handler_config: ProofDataHandlerConfig,
     commitment_mode: L1BatchCommitmentMode,
     l2_chain_id: L2ChainId,
+    admin_api_key: Option<APIKey>,
 }
 
 #[derive(Debug, FromContext)]
@@ -47,8 +48,15 @@ impl ProofDataHandlerLayer {
             proof_data_handler_config,
             commitment_mode,
             l2_chain_id,
+            admin_api_key: None,
         }
     }
+
+    /// Enables admin endpoints of the proof data handler server authenticated with the provided API key.
+    pub fn with_admin_api_key(mut self, admin_api_key: APIKey) -> Self {
+        self.admin_api_key = Some(admin_api_key);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -70,11 +78,16 @@ impl WiringLayer for ProofDataHandlerLayer {
             self.proof_data_handler_config.clone(),
             self.l2_chain_id,
         );
+        let admin_api = self
+            .admin_api_key
+            .map(|api_key| AdminApi::new(main_pool.clone(), api_key));
 
-        let api = if self.proof_data_handler_config.tee_config.tee_support {
+        // The server is only needed if TEE proving or admin endpoints are enabled.
+        let api = if self.proof_data_handler_config.tee_config.tee_support || admin_api.is_some() {
             Some(TeeProofDataHandler::new(
                 processor,
                 self.proof_data_handler_config.http_port,
+                admin_api,
             ))
         } else {
             None
@@ -88,7 +101,7 @@ impl WiringLayer for ProofDataHandlerLayer {
         );
         let rpc_client = RpcClient::new(
             processor,
-            self.proof_data_handler_config.clone().api_url,
+            self.proof_data_handler_config.gateway_urls(),
             self.proof_data_handler_config
                 .batch_readiness_check_interval(),
             self.proof_data_handler_config.retry_connection_interval(),
@@ -136,6 +149,10 @@ impl ProofDataHandlerTask {
 
 #[async_trait::async_trait]
 impl Task for ProofDataHandlerTask {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         "proof_data_handler".into()
     }
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use zksync_node_db_pruner::{DbPruner, DbPrunerConfig};
+use zksync_node_db_pruner::{CallTracesPruner, CallTracesPrunerConfig, DbPruner, DbPrunerConfig};
 
 use crate::{
     implementations::resources::{
@@ -90,3 +90,56 @@ impl Task for DbPruner {
         (*self).run(stop_receiver.0).await
     }
 }
+
+/// Wiring layer for the call traces pruner, which removes call traces older than the configured retention period.
+#[derive(Debug)]
+pub struct CallTracesPruningLayer {
+    retention: Duration,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct CallTracesPruningOutput {
+    #[context(task)]
+    pub call_traces_pruner: CallTracesPruner,
+}
+
+impl CallTracesPruningLayer {
+    pub fn new(retention: Duration) -> Self {
+        Self { retention }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for CallTracesPruningLayer {
+    type Input = Input;
+    type Output = CallTracesPruningOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "call_traces_pruning_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let main_pool = input.master_pool.get().await?;
+        let call_traces_pruner =
+            CallTracesPruner::new(CallTracesPrunerConfig::new(self.retention), main_pool);
+
+        input
+            .app_health
+            .0
+            .insert_component(call_traces_pruner.health_check())
+            .map_err(WiringError::internal)?;
+        Ok(CallTracesPruningOutput { call_traces_pruner })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for CallTracesPruner {
+    fn id(&self) -> TaskId {
+        "call_traces_pruner".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,97 @@
+use zksync_eth_sender::SettlementLayerHealthTask;
+use zksync_types::{settlement::SettlementMode, SLChainId};
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        pools::{PoolResource, ReplicaPool},
+        settlement_layer::SettlementLayerDataResource,
+    },
+    service::StopReceiver,
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+    FromContext, IntoContext,
+};
+
+/// Wiring layer reporting settlement layer data (settlement mode, settlement layer chain ID
+/// and whether a migration between settlement layers is pending) as a health check component.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<ReplicaPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds resources
+///
+/// - `SettlementLayerDataResource`
+///
+/// ## Adds tasks
+///
+/// - `SettlementLayerHealthTask`
+#[derive(Debug)]
+pub struct SettlementLayerDataLayer {
+    settlement_mode: SettlementMode,
+    sl_chain_id: SLChainId,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct Input {
+    pub replica_pool: PoolResource<ReplicaPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct Output {
+    pub settlement_layer_data: SettlementLayerDataResource,
+    #[context(task)]
+    pub task: SettlementLayerHealthTask,
+}
+
+impl SettlementLayerDataLayer {
+    pub fn new(settlement_mode: SettlementMode, sl_chain_id: SLChainId) -> Self {
+        Self {
+            settlement_mode,
+            sl_chain_id,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SettlementLayerDataLayer {
+    type Input = Input;
+    type Output = Output;
+
+    fn layer_name(&self) -> &'static str {
+        "settlement_layer_data_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let pool = input.replica_pool.get_singleton().await?;
+        let task = SettlementLayerHealthTask::new(pool, self.settlement_mode, self.sl_chain_id);
+
+        input
+            .app_health
+            .0
+            .insert_component(task.health_check())
+            .map_err(WiringError::internal)?;
+
+        Ok(Output {
+            settlement_layer_data: SettlementLayerDataResource(task.subscribe()),
+            task,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for SettlementLayerHealthTask {
+    fn id(&self) -> TaskId {
+        "settlement_layer_health".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -12,6 +12,7 @@ pub struct MainBatchExecutorLayer {
     save_call_traces: bool,
     optional_bytecode_compression: bool,
     fast_vm_mode: FastVmMode,
+    shadow_parallel_execution: bool,
 }
 
 impl MainBatchExecutorLayer {
@@ -20,6 +21,7 @@ impl MainBatchExecutorLayer {
             save_call_traces,
             optional_bytecode_compression,
             fast_vm_mode: FastVmMode::default(),
+            shadow_parallel_execution: false,
         }
     }
 
@@ -28,9 +30,17 @@ impl MainBatchExecutorLayer {
         self
     }
 
+    pub fn with_shadow_parallel_execution(mut self, enabled: bool) -> Self {
+        self.shadow_parallel_execution = enabled;
+        self
+    }
+
     fn create_executor<Tr: BatchTracer>(&self) -> BatchExecutorResource {
         let mut executor = MainBatchExecutorFactory::<Tr>::new(self.optional_bytecode_compression);
         executor.set_fast_vm_mode(self.fast_vm_mode);
+        if self.shadow_parallel_execution {
+            executor.enable_shadow_parallel_execution();
+        }
         executor.into()
     }
 }
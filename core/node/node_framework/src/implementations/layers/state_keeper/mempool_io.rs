@@ -3,7 +3,9 @@ use zksync_config::configs::{
     chain::{MempoolConfig, StateKeeperConfig},
     wallets,
 };
-use zksync_state_keeper::{MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer};
+use zksync_state_keeper::{
+    BaseSystemContractsPreloader, MempoolFetcher, MempoolGuard, MempoolIO, SequencerSealer,
+};
 use zksync_types::{commitment::PubdataType, Address, L2ChainId};
 
 use crate::{
@@ -33,6 +35,7 @@ use crate::{
 /// ## Adds tasks
 ///
 /// - `MempoolFetcherTask`
+/// - `BaseSystemContractsPreloader`
 #[derive(Debug)]
 pub struct MempoolIOLayer {
     zksync_network_id: L2ChainId,
@@ -57,6 +60,8 @@ pub struct Output {
     pub conditional_sealer: ConditionalSealerResource,
     #[context(task)]
     pub mempool_fetcher: MempoolFetcher,
+    #[context(task)]
+    pub base_system_contracts_preloader: BaseSystemContractsPreloader,
 }
 
 impl MempoolIOLayer {
@@ -139,6 +144,14 @@ impl WiringLayer for MempoolIOLayer {
             self.pubdata_type,
         )?;
 
+        // Create task preloading base system contracts for upcoming protocol upgrades.
+        let preloader_pool = master_pool
+            .get_singleton()
+            .await
+            .context("Get master pool")?;
+        let base_system_contracts_preloader =
+            BaseSystemContractsPreloader::new(preloader_pool, io.staged_base_system_contracts());
+
         // Create sealer.
         let sealer = SequencerSealer::new(self.state_keeper_config);
 
@@ -146,6 +159,7 @@ impl WiringLayer for MempoolIOLayer {
             state_keeper_io: io.into(),
             conditional_sealer: sealer.into(),
             mempool_fetcher,
+            base_system_contracts_preloader,
         })
     }
 }
@@ -160,3 +174,14 @@ impl Task for MempoolFetcher {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for BaseSystemContractsPreloader {
+    fn id(&self) -> TaskId {
+        "state_keeper/base_system_contracts_preloader".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -45,6 +45,14 @@ pub struct OutputHandlerLayer {
     /// May be set to `false` for nodes that do not participate in the sequencing process (e.g. external nodes)
     /// or run `vm_runner_protective_reads` component.
     protective_reads_persistence_enabled: bool,
+    /// Whether storage slots accessed by each transaction should be persisted.
+    storage_accesses_persistence_enabled: bool,
+    /// Number of latest L2 blocks for which transaction receipts should be cached. 0 disables the cache.
+    receipts_cache_l2_blocks: u32,
+    /// Whether call traces should be persisted only for failed transactions.
+    failed_call_traces_only: bool,
+    /// Whether call traces should be compressed when persisted.
+    compress_call_traces: bool,
 }
 
 #[derive(Debug, FromContext)]
@@ -72,6 +80,10 @@ impl OutputHandlerLayer {
             l2_block_seal_queue_capacity,
             pre_insert_txs: false,
             protective_reads_persistence_enabled: false,
+            storage_accesses_persistence_enabled: false,
+            receipts_cache_l2_blocks: 0,
+            failed_call_traces_only: false,
+            compress_call_traces: false,
         }
     }
 
@@ -87,6 +99,29 @@ impl OutputHandlerLayer {
         self.protective_reads_persistence_enabled = protective_reads_persistence_enabled;
         self
     }
+
+    pub fn with_storage_accesses_persistence_enabled(
+        mut self,
+        storage_accesses_persistence_enabled: bool,
+    ) -> Self {
+        self.storage_accesses_persistence_enabled = storage_accesses_persistence_enabled;
+        self
+    }
+
+    pub fn with_receipts_cache_l2_blocks(mut self, receipts_cache_l2_blocks: u32) -> Self {
+        self.receipts_cache_l2_blocks = receipts_cache_l2_blocks;
+        self
+    }
+
+    pub fn with_failed_call_traces_only(mut self, failed_call_traces_only: bool) -> Self {
+        self.failed_call_traces_only = failed_call_traces_only;
+        self
+    }
+
+    pub fn with_call_traces_compression(mut self, compress_call_traces: bool) -> Self {
+        self.compress_call_traces = compress_call_traces;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -119,6 +154,18 @@ impl WiringLayer for OutputHandlerLayer {
         if !self.protective_reads_persistence_enabled {
             persistence = persistence.without_protective_reads();
         }
+        if self.storage_accesses_persistence_enabled {
+            persistence = persistence.with_storage_accesses();
+        }
+        if self.receipts_cache_l2_blocks > 0 {
+            persistence = persistence.with_receipts_cache(self.receipts_cache_l2_blocks);
+        }
+        if self.failed_call_traces_only {
+            persistence = persistence.with_failed_call_traces_only();
+        }
+        if self.compress_call_traces {
+            persistence = persistence.with_compressed_call_traces();
+        }
 
         let tree_writes_persistence = TreeWritesPersistence::new(persistence_pool);
         let mut output_handler = OutputHandler::new(Box::new(persistence))
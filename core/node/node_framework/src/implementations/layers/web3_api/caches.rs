@@ -1,12 +1,14 @@
 use std::time::Duration;
 
 use zksync_node_api_server::web3::mempool_cache::{MempoolCache, MempoolCacheUpdateTask};
+use zksync_node_fee_model::gas_per_pubdata::{GasPerPubdataOracle, GasPerPubdataOracleUpdateTask};
 use zksync_node_framework_derive::FromContext;
 
 use crate::{
     implementations::resources::{
+        fee_input::ApiFeeInputResource,
         pools::{PoolResource, ReplicaPool},
-        web3_api::MempoolCacheResource,
+        web3_api::{GasPerPubdataOracleResource, MempoolCacheResource},
     },
     service::StopReceiver,
     task::{Task, TaskId},
@@ -74,3 +76,85 @@ impl Task for MempoolCacheUpdateTask {
         (*self).run(stop_receiver.0).await
     }
 }
+
+/// Wiring layer for the API `gas_per_pubdata` oracle.
+///
+/// ## Requests resources
+///
+/// - `ApiFeeInputResource`
+///
+/// ## Adds resources
+///
+/// - `GasPerPubdataOracleResource`
+///
+/// ## Adds tasks
+///
+/// - `GasPerPubdataOracleUpdateTask`
+#[derive(Debug)]
+pub struct GasPerPubdataOracleLayer {
+    gas_price_scale_factor: f64,
+    update_interval: Duration,
+    max_samples: usize,
+    forecast_horizon: Duration,
+}
+
+#[derive(Debug, FromContext)]
+#[context(crate = crate)]
+pub struct GasPerPubdataOracleInput {
+    pub fee_input: ApiFeeInputResource,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+pub struct GasPerPubdataOracleOutput {
+    pub oracle: GasPerPubdataOracleResource,
+    #[context(task)]
+    pub update_task: GasPerPubdataOracleUpdateTask,
+}
+
+impl GasPerPubdataOracleLayer {
+    /// Creates a layer sampling fee input every 10 seconds, retaining 10 minutes of history and forecasting
+    /// 5 minutes ahead. `gas_price_scale_factor` must be the same as for the transaction sender.
+    pub fn new(gas_price_scale_factor: f64) -> Self {
+        Self {
+            gas_price_scale_factor,
+            update_interval: Duration::from_secs(10),
+            max_samples: 60,
+            forecast_horizon: Duration::from_secs(300),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for GasPerPubdataOracleLayer {
+    type Input = GasPerPubdataOracleInput;
+    type Output = GasPerPubdataOracleOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "gas_per_pubdata_oracle_layer"
+    }
+
+    async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let oracle = GasPerPubdataOracle::new(self.max_samples, self.forecast_horizon);
+        let update_task = oracle.update_task(
+            input.fee_input.0,
+            self.gas_price_scale_factor,
+            self.update_interval,
+        );
+        Ok(GasPerPubdataOracleOutput {
+            oracle: oracle.into(),
+            update_task,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for GasPerPubdataOracleUpdateTask {
+    fn id(&self) -> TaskId {
+        "gas_per_pubdata_oracle_update_task".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
@@ -1,4 +1,4 @@
-use std::{num::NonZeroU32, time::Duration};
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use bridge_addresses::{L1UpdaterInner, MainNodeUpdaterInner};
@@ -8,7 +8,7 @@ use zksync_config::configs::api::MaxResponseSize;
 use zksync_contracts::{bridgehub_contract, l1_asset_router_contract};
 use zksync_node_api_server::web3::{
     state::{BridgeAddressesHandle, InternalApiConfig, SealedL2BlockNumber},
-    ApiBuilder, ApiServer, Namespace,
+    ApiBuilder, ApiServer, CustomNamespace, Namespace,
 };
 
 use crate::{
@@ -19,15 +19,20 @@ use crate::{
         resources::{
             circuit_breakers::CircuitBreakersResource,
             eth_interface::EthInterfaceResource,
-            healthcheck::AppHealthCheckResource,
+            healthcheck::{AppHealthCheckResource, DegradedMode},
             main_node_client::MainNodeClientResource,
+            object_store::ObjectStoreResource,
             pools::{PoolResource, ReplicaPool},
+            settlement_layer::SettlementLayerDataResource,
             sync_state::SyncStateResource,
-            web3_api::{MempoolCacheResource, TreeApiClientResource, TxSenderResource},
+            web3_api::{
+                GasPerPubdataOracleResource, MempoolCacheResource, TreeApiClientResource,
+                TxSenderResource,
+            },
         },
     },
     service::StopReceiver,
-    task::{Task, TaskId},
+    task::{StopPhase, Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
     FromContext, IntoContext,
 };
@@ -45,6 +50,7 @@ pub struct Web3ServerOptionalConfig {
     pub response_body_size_limit: Option<MaxResponseSize>,
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
     pub with_extended_tracing: bool,
+    pub with_strict_quantity_validation: bool,
     // Used by circuit breaker.
     pub replication_lag_limit: Option<Duration>,
     // Used by the external node.
@@ -52,6 +58,12 @@ pub struct Web3ServerOptionalConfig {
     // Used by the external node.
     pub bridge_addresses_refresh_interval: Option<Duration>,
     pub polling_interval: Option<Duration>,
+    /// Additional namespaces registered on top of `namespaces`.
+    pub custom_namespaces: Vec<Arc<dyn CustomNamespace>>,
+    /// TTL of signed snapshot download URLs. Requires `ObjectStoreResource`.
+    pub snapshots_download_url_ttl: Option<Duration>,
+    pub snapshots_requests_per_minute_limit: Option<NonZeroU32>,
+    pub snapshots_rate_limit_proxy_hops: Option<NonZeroU32>,
 }
 
 impl Web3ServerOptionalConfig {
@@ -83,7 +95,18 @@ impl Web3ServerOptionalConfig {
             api_builder =
                 api_builder.with_pruning_info_refresh_interval(pruning_info_refresh_interval);
         }
+        if let Some(limit) = self.snapshots_requests_per_minute_limit {
+            api_builder = api_builder.with_snapshots_requests_per_minute_limit(limit);
+        }
+        if let Some(proxy_hops) = self.snapshots_rate_limit_proxy_hops {
+            api_builder = api_builder.with_snapshots_rate_limit_proxy_hops(proxy_hops);
+        }
+        for namespace in self.custom_namespaces {
+            api_builder = api_builder.with_custom_namespace(namespace);
+        }
         api_builder = api_builder.with_extended_tracing(self.with_extended_tracing);
+        api_builder =
+            api_builder.with_strict_quantity_validation(self.with_strict_quantity_validation);
         api_builder
     }
 }
@@ -102,8 +125,11 @@ enum Transport {
 /// - `PoolResource<ReplicaPool>`
 /// - `TxSenderResource`
 /// - `SyncStateResource` (optional)
-/// - `TreeApiClientResource` (optional)
+/// - `TreeApiClientResource` (optional; `zks_getProof` is disabled without it)
 /// - `MempoolCacheResource`
+/// - `GasPerPubdataOracleResource` (optional)
+/// - `ObjectStoreResource` (optional; snapshot download URLs are not signed without it)
+/// - `SettlementLayerDataResource` (optional; used by `unstable_gatewayMigrationStatus`)
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 /// - `AppHealthCheckResource` (adds a health check)
 ///
@@ -127,6 +153,9 @@ pub struct Input {
     pub sync_state: Option<SyncStateResource>,
     pub tree_api_client: Option<TreeApiClientResource>,
     pub mempool_cache: MempoolCacheResource,
+    pub gas_per_pubdata_oracle: Option<GasPerPubdataOracleResource>,
+    pub object_store: Option<ObjectStoreResource>,
+    pub settlement_layer_data: Option<SettlementLayerDataResource>,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
     #[context(default)]
@@ -189,6 +218,7 @@ impl WiringLayer for Web3ServerLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let layer_name = self.layer_name();
         // Get required resources.
         let replica_resource_pool = input.replica_pool;
         let updaters_pool = replica_resource_pool.get_custom(1).await?;
@@ -197,6 +227,30 @@ impl WiringLayer for Web3ServerLayer {
         let MempoolCacheResource(mempool_cache) = input.mempool_cache;
         let sync_state = input.sync_state.map(|state| state.0);
         let tree_api_client = input.tree_api_client.map(|client| client.0);
+        let zks_namespace_enabled = self
+            .optional_config
+            .namespaces
+            .as_deref()
+            .unwrap_or(Namespace::DEFAULT)
+            .contains(&Namespace::Zks);
+        let snapshots_namespace_enabled = self
+            .optional_config
+            .namespaces
+            .as_deref()
+            .unwrap_or(Namespace::DEFAULT)
+            .contains(&Namespace::Snapshots);
+        let mut degraded_mode = DegradedMode::default();
+        if tree_api_client.is_none() && zks_namespace_enabled {
+            degraded_mode.missing_input("TreeApiClientResource", &["zks_getProof"]);
+        }
+        let snapshots_download_url_ttl = self.optional_config.snapshots_download_url_ttl;
+        let snapshots_blob_store = input.object_store.map(|store| store.0);
+        if snapshots_download_url_ttl.is_some()
+            && snapshots_blob_store.is_none()
+            && snapshots_namespace_enabled
+        {
+            degraded_mode.missing_input("ObjectStoreResource", &["snapshot_download_urls"]);
+        }
 
         let sealed_l2_block_handle = SealedL2BlockNumber::default();
         let bridge_addresses_handle =
@@ -241,6 +295,15 @@ impl WiringLayer for Web3ServerLayer {
         if let Some(client) = tree_api_client {
             api_builder = api_builder.with_tree_api(client);
         }
+        if let Some(oracle) = input.gas_per_pubdata_oracle {
+            api_builder = api_builder.with_gas_per_pubdata_oracle(oracle.0);
+        }
+        if let Some(data) = input.settlement_layer_data {
+            api_builder = api_builder.with_settlement_layer_data(data.0);
+        }
+        if let (Some(blob_store), Some(ttl)) = (snapshots_blob_store, snapshots_download_url_ttl) {
+            api_builder = api_builder.with_snapshot_download_urls(blob_store, ttl);
+        }
         match self.transport {
             Transport::Http => {
                 api_builder = api_builder.http(self.port);
@@ -267,6 +330,9 @@ impl WiringLayer for Web3ServerLayer {
             .0
             .insert_component(api_health_check)
             .map_err(WiringError::internal)?;
+        input
+            .app_health
+            .insert_degraded_component(layer_name, degraded_mode)?;
 
         // Insert circuit breaker.
         input
@@ -316,6 +382,10 @@ type ApiJoinHandle = JoinHandle<anyhow::Result<()>>;
 
 #[async_trait::async_trait]
 impl Task for Web3ApiTask {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         match self.transport {
             Transport::Http => "web3_http_server".into(),
@@ -342,6 +412,10 @@ pub struct ApiTaskGarbageCollector {
 
 #[async_trait::async_trait]
 impl Task for ApiTaskGarbageCollector {
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Ingress
+    }
+
     fn id(&self) -> TaskId {
         "api_task_garbage_collector".into()
     }
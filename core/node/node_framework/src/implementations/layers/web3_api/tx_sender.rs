@@ -16,7 +16,9 @@ use zksync_web3_decl::{
 use crate::{
     implementations::resources::{
         fee_input::ApiFeeInputResource,
+        healthcheck::{AppHealthCheckResource, DegradedMode},
         main_node_client::MainNodeClientResource,
+        object_store::ObjectStoreResource,
         pools::{PoolResource, ReplicaPool},
         state_keeper::ConditionalSealerResource,
         web3_api::{TxSenderResource, TxSinkResource},
@@ -44,6 +46,9 @@ pub struct PostgresStorageCachesConfig {
 /// - `PoolResource<ReplicaPool>`
 /// - `ConditionalSealerResource` (optional)
 /// - `FeeInputResource`
+/// - `ObjectStoreResource` (optional; calls against pruned blocks are not served from snapshots without it)
+/// - `MainNodeClientResource` (optional; the whitelisted tokens for AA cache is disabled without it)
+/// - `AppHealthCheckResource` (adds a health check if some optional resources are missing)
 ///
 /// ## Adds resources
 ///
@@ -61,6 +66,7 @@ pub struct TxSenderLayer {
     max_vm_concurrency: usize,
     whitelisted_tokens_for_aa_cache: bool,
     vm_mode: FastVmMode,
+    pruned_calls_snapshot_chunks_budget: Option<usize>,
 }
 
 #[derive(Debug, FromContext)]
@@ -71,6 +77,9 @@ pub struct Input {
     pub fee_input: ApiFeeInputResource,
     pub main_node_client: Option<MainNodeClientResource>,
     pub sealer: Option<ConditionalSealerResource>,
+    pub object_store: Option<ObjectStoreResource>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -97,13 +106,14 @@ impl TxSenderLayer {
             max_vm_concurrency,
             whitelisted_tokens_for_aa_cache: false,
             vm_mode: FastVmMode::Old,
+            pruned_calls_snapshot_chunks_budget: None,
         }
     }
 
     /// Enables the task for fetching the whitelisted tokens for the AA cache from the main node.
     /// Disabled by default.
     ///
-    /// Requires `MainNodeClientResource` to be present.
+    /// Requires `MainNodeClientResource`; without it, the layer is wired in degraded mode with the cache disabled.
     pub fn with_whitelisted_tokens_for_aa_cache(mut self, value: bool) -> Self {
         self.whitelisted_tokens_for_aa_cache = value;
         self
@@ -114,6 +124,18 @@ impl TxSenderLayer {
         self.vm_mode = mode;
         self
     }
+
+    /// Enables serving `eth_call`s against pruned blocks from storage snapshots, with each call allowed to load
+    /// at most `chunks_budget` snapshot chunks. Disabled by default.
+    ///
+    /// Requires `ObjectStoreResource`; without it, the layer is wired in degraded mode with such calls disabled.
+    pub fn with_pruned_calls_snapshot_chunks_budget(
+        mut self,
+        chunks_budget: Option<usize>,
+    ) -> Self {
+        self.pruned_calls_snapshot_chunks_budget = chunks_budget;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -126,6 +148,7 @@ impl WiringLayer for TxSenderLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        let layer_name = self.layer_name();
         // Get required resources.
         let tx_sink = input.tx_sink.0;
         let replica_pool = input.replica_pool.get().await?;
@@ -172,25 +195,41 @@ impl WiringLayer for TxSenderLayer {
         if let Some(sealer) = sealer {
             tx_sender = tx_sender.with_sealer(sealer);
         }
+        let mut degraded_mode = DegradedMode::default();
+        if let Some(chunks_budget) = self.pruned_calls_snapshot_chunks_budget {
+            if let Some(ObjectStoreResource(blob_store)) = input.object_store {
+                tx_sender = tx_sender.with_pruned_calls_from_snapshots(blob_store, chunks_budget);
+            } else {
+                degraded_mode
+                    .missing_input("ObjectStoreResource", &["pruned_calls_from_snapshots"]);
+            }
+        }
 
         // Add the task for updating the whitelisted tokens for the AA cache.
-        let whitelisted_tokens_for_aa_update_task = if self.whitelisted_tokens_for_aa_cache {
-            let MainNodeClientResource(main_node_client) =
-                input.main_node_client.ok_or_else(|| {
-                    WiringError::Configuration(
-                        "Main node client is required for the whitelisted tokens for AA cache"
-                            .into(),
-                    )
-                })?;
-            let whitelisted_tokens = Arc::new(RwLock::new(Default::default()));
-            tx_sender = tx_sender.with_whitelisted_tokens_for_aa(whitelisted_tokens.clone());
-            Some(WhitelistedTokensForAaUpdateTask {
-                whitelisted_tokens: whitelisted_tokens.clone(),
-                main_node_client,
-            })
-        } else {
-            None
-        };
+        let whitelisted_tokens_for_aa_update_task =
+            match (self.whitelisted_tokens_for_aa_cache, input.main_node_client) {
+                (true, Some(MainNodeClientResource(main_node_client))) => {
+                    let whitelisted_tokens = Arc::new(RwLock::new(Default::default()));
+                    tx_sender =
+                        tx_sender.with_whitelisted_tokens_for_aa(whitelisted_tokens.clone());
+                    Some(WhitelistedTokensForAaUpdateTask {
+                        whitelisted_tokens: whitelisted_tokens.clone(),
+                        main_node_client,
+                    })
+                }
+                (true, None) => {
+                    degraded_mode.missing_input(
+                        "MainNodeClientResource",
+                        &["whitelisted_tokens_for_aa_cache"],
+                    );
+                    None
+                }
+                (false, _) => None,
+            };
+
+        input
+            .app_health
+            .insert_degraded_component(layer_name, degraded_mode)?;
 
         let tx_sender = tx_sender.build(
             fee_input,
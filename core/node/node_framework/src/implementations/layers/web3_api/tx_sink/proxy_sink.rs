@@ -1,4 +1,8 @@
-use zksync_node_api_server::tx_sender::proxy::{AccountNonceSweeperTask, TxProxy};
+use std::{num::NonZeroUsize, time::Duration};
+
+use zksync_node_api_server::tx_sender::proxy::{
+    AccountNonceSweeperTask, TxProxy, TxProxyQueueTask,
+};
 
 use crate::{
     implementations::resources::{
@@ -13,8 +17,19 @@ use crate::{
 };
 
 /// Wiring layer for [`TxProxy`], [`TxSink`](zksync_node_api_server::tx_sender::tx_sink::TxSink) implementation.
-#[derive(Debug)]
-pub struct ProxySinkLayer;
+#[derive(Debug, Default)]
+pub struct ProxySinkLayer {
+    retry_queue: Option<(Duration, NonZeroUsize)>,
+}
+
+impl ProxySinkLayer {
+    /// Enables the durable queue for transactions that cannot be proxied because the main node is unavailable.
+    /// Queued transactions are dropped after `ttl`; the queue holds at most `capacity` transactions.
+    pub fn with_retry_queue(mut self, ttl: Duration, capacity: NonZeroUsize) -> Self {
+        self.retry_queue = Some((ttl, capacity));
+        self
+    }
+}
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
@@ -29,6 +44,8 @@ pub struct Output {
     pub tx_sink: TxSinkResource,
     #[context(task)]
     pub account_nonce_sweeper_task: AccountNonceSweeperTask,
+    #[context(task)]
+    pub retry_queue_task: Option<TxProxyQueueTask>,
 }
 
 #[async_trait::async_trait]
@@ -42,14 +59,21 @@ impl WiringLayer for ProxySinkLayer {
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
         let MainNodeClientResource(client) = input.main_node_client;
-        let proxy = TxProxy::new(client);
+        let mut proxy = TxProxy::new(client);
+        if let Some((_, capacity)) = self.retry_queue {
+            proxy = proxy.with_retry_queue(input.master_pool.get().await?, capacity.get());
+        }
 
         let pool = input.master_pool.get_singleton().await?;
         let task = proxy.account_nonce_sweeper_task(pool);
+        let retry_queue_task = self
+            .retry_queue
+            .and_then(|(ttl, _)| proxy.retry_queue_task(ttl));
 
         Ok(Output {
             tx_sink: proxy.into(),
             account_nonce_sweeper_task: task,
+            retry_queue_task,
         })
     }
 }
@@ -64,3 +88,14 @@ impl Task for AccountNonceSweeperTask {
         (*self).run(stop_receiver.0).await
     }
 }
+
+#[async_trait::async_trait]
+impl Task for TxProxyQueueTask {
+    fn id(&self) -> TaskId {
+        "tx_proxy_queue_task".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        (*self).run(stop_receiver.0).await
+    }
+}
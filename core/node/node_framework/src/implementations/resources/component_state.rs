@@ -0,0 +1,108 @@
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Serialize,
+};
+use zksync_dal::{
+    component_state_dal::ComponentStateEntry, ConnectionPool, Core, CoreDal, DalError,
+};
+
+use super::pools::{MasterPool, PoolResource};
+
+/// Typed access to the durable key-value state of a single component, e.g. a state machine or an admin switch.
+/// Intended to be created by wiring layers for components that need to persist state across restarts;
+/// all keys are scoped by the component name.
+#[derive(Debug, Clone)]
+pub struct ComponentStateStore {
+    pool: ConnectionPool<Core>,
+    component: &'static str,
+}
+
+impl ComponentStateStore {
+    pub fn new(pool: ConnectionPool<Core>, component: &'static str) -> Self {
+        Self { pool, component }
+    }
+
+    /// Creates a store backed by a dedicated single-connection pool.
+    pub async fn from_pool(
+        pool: &PoolResource<MasterPool>,
+        component: &'static str,
+    ) -> anyhow::Result<Self> {
+        Ok(Self::new(pool.get_singleton().await?, component))
+    }
+
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+
+    /// Returns the current value for `key`, or `None` if it was never set.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        key: &str,
+    ) -> anyhow::Result<Option<ComponentStateEntry<T>>> {
+        let mut storage = self.pool.connection_tagged(self.component).await?;
+        storage
+            .component_state_dal()
+            .get_state(self.component, key)
+            .await
+            .map_err(DalError::generalize)
+    }
+
+    /// Sets the value for `key` if its current version matches `expected_version`. Returns the new version,
+    /// or `None` if the value was concurrently modified.
+    pub async fn compare_and_set<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        expected_version: Option<u64>,
+    ) -> anyhow::Result<Option<u64>> {
+        let mut storage = self.pool.connection_tagged(self.component).await?;
+        storage
+            .component_state_dal()
+            .set_state(self.component, key, value, expected_version)
+            .await
+            .map_err(DalError::generalize)
+    }
+
+    /// Unconditionally sets the value for `key`. Returns the new version of the value.
+    pub async fn set<T: Serialize>(&self, key: &str, value: &T) -> anyhow::Result<u64> {
+        loop {
+            let expected_version = self
+                .get::<IgnoredAny>(key)
+                .await?
+                .map(|entry| entry.version);
+            if let Some(version) = self.compare_and_set(key, value, expected_version).await? {
+                return Ok(version);
+            }
+            self.report_conflict(key);
+        }
+    }
+
+    /// Updates the value for `key` based on its current value (`None` if the value was never set).
+    /// If the value is concurrently modified, `update_fn` is called again with the new value.
+    /// Returns the new version of the value.
+    pub async fn update<T, F>(&self, key: &str, mut update_fn: F) -> anyhow::Result<u64>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnMut(Option<T>) -> T,
+    {
+        loop {
+            let current = self.get::<T>(key).await?;
+            let expected_version = current.as_ref().map(|entry| entry.version);
+            let new_value = update_fn(current.map(|entry| entry.value));
+            if let Some(version) = self
+                .compare_and_set(key, &new_value, expected_version)
+                .await?
+            {
+                return Ok(version);
+            }
+            self.report_conflict(key);
+        }
+    }
+
+    fn report_conflict(&self, key: &str) {
+        tracing::debug!(
+            "State `{key}` of component `{}` was concurrently modified; retrying",
+            self.component
+        );
+    }
+}
@@ -64,3 +64,23 @@ impl Resource for BoundEthInterfaceForL2Resource {
         "common/bound_eth_interface_for_l2".into()
     }
 }
+
+/// Same as `BoundEthInterfaceResource`, but for the operator key that is being rotated out.
+#[derive(Debug, Clone)]
+pub struct BoundEthInterfaceForPreviousOperatorResource(pub Box<dyn BoundEthInterface>);
+
+impl Resource for BoundEthInterfaceForPreviousOperatorResource {
+    fn name() -> String {
+        "common/bound_eth_interface_for_previous_operator".into()
+    }
+}
+
+/// Same as `BoundEthInterfaceForBlobsResource`, but for the blob operator key that is being rotated out.
+#[derive(Debug, Clone)]
+pub struct BoundEthInterfaceForPreviousBlobsOperatorResource(pub Box<dyn BoundEthInterface>);
+
+impl Resource for BoundEthInterfaceForPreviousBlobsOperatorResource {
+    fn name() -> String {
+        "common/bound_eth_interface_for_previous_blobs_operator".into()
+    }
+}
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
-use zksync_health_check::AppHealthCheck;
+use serde::Serialize;
+use zksync_health_check::{AppHealthCheck, Health, HealthStatus};
 // Public re-exports from external crate to minimize the required dependencies.
 pub use zksync_health_check::{CheckHealth, ReactiveHealthCheck};
 
-use crate::resource::Resource;
+use crate::{resource::Resource, wiring_layer::WiringError};
 
 /// A resource that provides [`AppHealthCheck`] to the service.
 #[derive(Debug, Clone, Default)]
@@ -15,3 +16,90 @@ impl Resource for AppHealthCheckResource {
         "common/app_health_check".into()
     }
 }
+
+impl AppHealthCheckResource {
+    /// Registers a health check for a layer wired in the degraded mode. The health check is named after the layer
+    /// and permanently reports [`HealthStatus::Affected`] with `degraded_mode` as details, so that the node is still
+    /// considered healthy, but the disabled features are visible to operators.
+    ///
+    /// Does nothing if `degraded_mode` doesn't have missing inputs.
+    pub fn insert_degraded_component(
+        &self,
+        layer_name: &'static str,
+        degraded_mode: DegradedMode,
+    ) -> Result<(), WiringError> {
+        if !degraded_mode.is_degraded() {
+            return Ok(());
+        }
+        tracing::warn!(
+            missing_inputs = ?degraded_mode.missing_inputs,
+            disabled_features = ?degraded_mode.disabled_features,
+            "Layer `{layer_name}` is wired in degraded mode"
+        );
+
+        let (health_check, health_updater) = ReactiveHealthCheck::new(layer_name);
+        health_updater.update(Health::from(HealthStatus::Affected).with_details(degraded_mode));
+        // The health doesn't change during the node lifetime.
+        health_updater.freeze();
+        self.0
+            .insert_component(health_check)
+            .map_err(WiringError::internal)
+    }
+}
+
+/// Optional inputs missing for a wiring layer, together with the features disabled as a result.
+///
+/// Layers that can function without some of their optional inputs should record these inputs here
+/// instead of failing wiring or silently skipping functionality, and then register the result using
+/// [`AppHealthCheckResource::insert_degraded_component()`].
+#[derive(Debug, Default, Serialize)]
+pub struct DegradedMode {
+    missing_inputs: Vec<&'static str>,
+    disabled_features: Vec<&'static str>,
+}
+
+impl DegradedMode {
+    /// Records a missing optional input and the features disabled because of it.
+    pub fn missing_input(&mut self, input: &'static str, disabled_features: &[&'static str]) {
+        self.missing_inputs.push(input);
+        self.disabled_features.extend_from_slice(disabled_features);
+    }
+
+    /// Checks whether any inputs are missing.
+    pub fn is_degraded(&self) -> bool {
+        !self.missing_inputs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registering_degraded_component() {
+        let resource = AppHealthCheckResource::default();
+        resource
+            .insert_degraded_component("healthy_layer", DegradedMode::default())
+            .unwrap();
+
+        let mut degraded_mode = DegradedMode::default();
+        degraded_mode.missing_input("TreeApiClientResource", &["zks_getProof"]);
+        resource
+            .insert_degraded_component("degraded_layer", degraded_mode)
+            .unwrap();
+
+        let health = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(resource.0.check_health());
+        assert!(health.is_healthy());
+        let components = health.components();
+        assert!(!components.contains_key("healthy_layer"));
+        let degraded_health = &components["degraded_layer"];
+        assert_eq!(degraded_health.status(), HealthStatus::Affected);
+        let details = degraded_health.details().unwrap();
+        assert_eq!(details["missing_inputs"][0], "TreeApiClientResource");
+        assert_eq!(details["disabled_features"][0], "zks_getProof");
+    }
+}
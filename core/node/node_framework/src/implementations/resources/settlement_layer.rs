@@ -0,0 +1,15 @@
+use tokio::sync::watch;
+use zksync_types::settlement::SettlementLayerData;
+
+use crate::resource::Resource;
+
+/// A resource that provides [`SettlementLayerData`] reported by the settlement layer health task.
+/// The data is `None` until the task reports it for the first time.
+#[derive(Debug, Clone)]
+pub struct SettlementLayerDataResource(pub watch::Receiver<Option<SettlementLayerData>>);
+
+impl Resource for SettlementLayerDataResource {
+    fn name() -> String {
+        "common/settlement_layer_data".into()
+    }
+}
@@ -5,6 +5,7 @@ use zksync_node_api_server::{
     tx_sender::{tx_sink::TxSink, TxSender},
     web3::mempool_cache::MempoolCache,
 };
+use zksync_node_fee_model::gas_per_pubdata::GasPerPubdataOracle;
 
 use crate::resource::Resource;
 
@@ -71,3 +72,19 @@ impl From<MempoolCache> for MempoolCacheResource {
         Self(cache)
     }
 }
+
+/// A resource that provides [`GasPerPubdataOracle`] to the service.
+#[derive(Debug, Clone)]
+pub struct GasPerPubdataOracleResource(pub GasPerPubdataOracle);
+
+impl Resource for GasPerPubdataOracleResource {
+    fn name() -> String {
+        "api/gas_per_pubdata_oracle".into()
+    }
+}
+
+impl From<GasPerPubdataOracle> for GasPerPubdataOracleResource {
+    fn from(oracle: GasPerPubdataOracle) -> Self {
+        Self(oracle)
+    }
+}
@@ -1,16 +1,48 @@
 use std::any::TypeId;
 
+use zksync_types::L2ChainId;
+
 /// A unique identifier of a resource.
 ///
 /// Internal representation is [`TypeId`], which is a 64-bit hash.
 /// That is sufficient for our purposes, as even when using 2^16 different resources,
 /// the chance of a hash collision occurring is about 1 in 2^32.
 /// This [Stack overflow answer](https://stackoverflow.com/a/62667633) explains how to derive the likelihood.
+///
+/// Resources can optionally be scoped to a certain chain (see [`ResourceId::for_chain`]), so that several
+/// resources of the same type (e.g., connection pools for different chains) can coexist in a single service.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ResourceId(TypeId);
+pub struct ResourceId {
+    type_id: TypeId,
+    chain_id: Option<L2ChainId>,
+}
 
 impl ResourceId {
+    /// Returns the identifier of a global (i.e., not scoped to any chain) resource.
     pub fn of<T: 'static>() -> Self {
-        Self(TypeId::of::<T>())
+        Self {
+            type_id: TypeId::of::<T>(),
+            chain_id: None,
+        }
+    }
+
+    /// Returns the identifier of a resource scoped to the specified chain.
+    pub fn for_chain<T: 'static>(chain_id: L2ChainId) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            chain_id: Some(chain_id),
+        }
+    }
+
+    pub(crate) fn scoped<T: 'static>(chain_id: Option<L2ChainId>) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            chain_id,
+        }
+    }
+
+    /// Returns the chain this resource is scoped to, or `None` for global resources.
+    pub fn chain_id(&self) -> Option<L2ChainId> {
+        self.chain_id
     }
 }
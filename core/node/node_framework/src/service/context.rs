@@ -1,5 +1,7 @@
 use std::any::type_name;
 
+use zksync_types::L2ChainId;
+
 use super::shutdown_hook::ShutdownHook;
 use crate::{
     resource::{Resource, ResourceId, StoredResource},
@@ -12,17 +14,37 @@ use crate::{
 /// This the main point of interaction between with the service.
 ///
 /// The context provides access to the runtime, resources, and allows adding new tasks.
+///
+/// If the layer was added for a specific chain (see [`ZkStackServiceBuilder::add_layer_for_chain`]),
+/// resources are resolved within the namespace of this chain first, falling back to global resources.
+/// Resources inserted by such a layer are only visible to the layers of the same chain.
+///
+/// [`ZkStackServiceBuilder::add_layer_for_chain`]: crate::service::ZkStackServiceBuilder::add_layer_for_chain
 #[derive(Debug)]
 pub struct ServiceContext<'a> {
     layer: &'a str,
+    chain_id: Option<L2ChainId>,
     service: &'a mut ZkStackService,
 }
 
 impl<'a> ServiceContext<'a> {
     /// Instantiates a new context.
     /// The context keeps information about the layer that created it for reporting purposes.
-    pub(super) fn new(layer: &'a str, service: &'a mut ZkStackService) -> Self {
-        Self { layer, service }
+    pub(super) fn new(
+        layer: &'a str,
+        chain_id: Option<L2ChainId>,
+        service: &'a mut ZkStackService,
+    ) -> Self {
+        Self {
+            layer,
+            chain_id,
+            service,
+        }
+    }
+
+    /// Returns the chain the layer was added for, or `None` if the layer is global.
+    pub fn chain_id(&self) -> Option<L2ChainId> {
+        self.chain_id
     }
 
     /// Provides access to the runtime used by the service.
@@ -98,8 +120,13 @@ impl<'a> ServiceContext<'a> {
                 .clone()
         };
 
-        // Check whether the resource is already available.
-        if let Some(resource) = self.service.resources.get(&ResourceId::of::<T>()) {
+        // Check whether the resource is already available. Chain-scoped resources take precedence over global ones.
+        let scoped_id = ResourceId::scoped::<T>(self.chain_id);
+        let resource = self.service.resources.get(&scoped_id).or_else(|| {
+            self.chain_id?;
+            self.service.resources.get(&ResourceId::of::<T>())
+        });
+        if let Some(resource) = resource {
             tracing::info!(
                 "Layer {} has requested resource {} of type {}",
                 self.layer,
@@ -120,7 +147,7 @@ impl<'a> ServiceContext<'a> {
         // The requester is allowed to decide whether this is an error or not.
         Err(WiringError::ResourceLacking {
             name: T::name(),
-            id: ResourceId::of::<T>(),
+            id: scoped_id,
         })
     }
 
@@ -136,9 +163,10 @@ impl<'a> ServiceContext<'a> {
 
         // No such resource, insert a new one.
         let resource = f();
-        self.service
-            .resources
-            .insert(ResourceId::of::<T>(), Box::new(resource.clone()));
+        self.service.resources.insert(
+            ResourceId::scoped::<T>(self.chain_id),
+            Box::new(resource.clone()),
+        );
         tracing::info!(
             "Layer {} has created a new resource {}",
             self.layer,
@@ -156,8 +184,9 @@ impl<'a> ServiceContext<'a> {
     /// Adds a resource to the service.
     ///
     /// If the resource with the same type is already provided, the method will return an error.
+    /// For layers added for a specific chain, the resource is scoped to this chain.
     pub fn insert_resource<T: Resource>(&mut self, resource: T) -> Result<(), WiringError> {
-        let id = ResourceId::of::<T>();
+        let id = ResourceId::scoped::<T>(self.chain_id);
         if self.service.resources.contains_key(&id) {
             tracing::info!(
                 "Layer {} has attempted to provide resource {} of type {}, but it is already available",
@@ -166,7 +195,7 @@ impl<'a> ServiceContext<'a> {
                 type_name::<T>()
             );
             return Err(WiringError::ResourceAlreadyProvided {
-                id: ResourceId::of::<T>(),
+                id,
                 name: T::name(),
             });
         }
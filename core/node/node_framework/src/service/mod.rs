@@ -1,8 +1,9 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, mem, time::Duration};
 
 use error::TaskError;
 use futures::future::Fuse;
-use tokio::{runtime::Runtime, sync::watch, task::JoinHandle};
+use tokio::{runtime::Runtime, task::JoinHandle};
+use zksync_types::L2ChainId;
 use zksync_utils::panic_extractor::try_extract_panic_message;
 use zksync_vlog::ObservabilityGuard;
 
@@ -18,8 +19,9 @@ use crate::{
     service::{
         named_future::NamedFuture,
         runnables::{NamedBoxFuture, Runnables, TaskReprs},
+        stop_receiver::StopSenders,
     },
-    task::TaskId,
+    task::{StopPhase, TaskId},
     wiring_layer::{WireFn, WiringError, WiringLayer, WiringLayerExt},
 };
 
@@ -33,7 +35,7 @@ mod stop_receiver;
 #[cfg(test)]
 mod tests;
 
-// A reasonable amount of time for any task to finish the shutdown process
+// A reasonable amount of time for any task (or all tasks in a single stop phase) to finish the shutdown process
 const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// A builder for [`ZkStackService`].
@@ -42,11 +44,19 @@ pub struct ZkStackServiceBuilder {
     /// List of wiring layers.
     // Note: It has to be a `Vec` and not e.g. `HashMap` because the order in which we
     // iterate through it matters.
-    layers: Vec<(&'static str, WireFn)>,
+    layers: Vec<LayerEntry>,
     /// Tokio runtime used to spawn tasks.
     runtime: Runtime,
 }
 
+/// Wiring layer together with the chain it was added for (`None` for global layers).
+#[derive(Debug)]
+struct LayerEntry {
+    name: &'static str,
+    chain_id: Option<L2ChainId>,
+    wire_fn: WireFn,
+}
+
 impl ZkStackServiceBuilder {
     /// Creates a new builder.
     ///
@@ -89,26 +99,52 @@ impl ZkStackServiceBuilder {
     /// This may be useful if the same layer is a prerequisite for multiple other layers: it is safe
     /// to add it multiple times, and it will only be wired once.
     pub fn add_layer<T: WiringLayer>(&mut self, layer: T) -> &mut Self {
+        self.add_scoped_layer(None, layer)
+    }
+
+    /// Adds a wiring layer scoped to the specified chain.
+    ///
+    /// Resources requested by such a layer are looked up in the namespace of `chain_id` first, and then
+    /// among global resources (i.e., ones provided by the layers added via [`Self::add_layer`]).
+    /// Resources provided by the layer are only visible to the layers scoped to the same chain.
+    /// This allows to instantiate the same layer for several chains within a single service.
+    ///
+    /// Same as with [`Self::add_layer`], adding the same layer for the same chain several times is a no-op.
+    pub fn add_layer_for_chain<T: WiringLayer>(
+        &mut self,
+        chain_id: L2ChainId,
+        layer: T,
+    ) -> &mut Self {
+        self.add_scoped_layer(Some(chain_id), layer)
+    }
+
+    fn add_scoped_layer<T: WiringLayer>(
+        &mut self,
+        chain_id: Option<L2ChainId>,
+        layer: T,
+    ) -> &mut Self {
         let name = layer.layer_name();
         if !self
             .layers
             .iter()
-            .any(|(existing_name, _)| name == *existing_name)
+            .any(|entry| name == entry.name && chain_id == entry.chain_id)
         {
-            self.layers.push((name, layer.into_wire_fn()));
+            self.layers.push(LayerEntry {
+                name,
+                chain_id,
+                wire_fn: layer.into_wire_fn(),
+            });
         }
         self
     }
 
     /// Builds the service.
     pub fn build(self) -> ZkStackService {
-        let (stop_sender, _stop_receiver) = watch::channel(false);
-
         ZkStackService {
             layers: self.layers,
             resources: Default::default(),
             runnables: Default::default(),
-            stop_sender,
+            stop_senders: StopSenders::new(),
             runtime: self.runtime,
             errors: Vec::new(),
         }
@@ -122,12 +158,12 @@ pub struct ZkStackService {
     /// Cache of resources that have been requested at least by one task.
     resources: HashMap<ResourceId, Box<dyn StoredResource>>,
     /// List of wiring layers.
-    layers: Vec<(&'static str, WireFn)>,
+    layers: Vec<LayerEntry>,
     /// Different kinds of tasks for the service.
     runnables: Runnables,
 
-    /// Sender used to stop the tasks.
-    stop_sender: watch::Sender<bool>,
+    /// Senders used to stop the tasks, one per stop phase.
+    stop_senders: StopSenders,
     /// Tokio runtime used to spawn tasks.
     runtime: Runtime,
 
@@ -153,11 +189,12 @@ impl ZkStackService {
 
         let TaskReprs {
             tasks,
+            stop_phases,
             shutdown_hooks,
         } = self.prepare_tasks();
 
         let remaining = self.run_tasks(tasks);
-        self.shutdown_tasks(remaining);
+        self.shutdown_tasks(remaining, &stop_phases);
         self.run_shutdown_hooks(shutdown_hooks);
 
         tracing::info!("Exiting the service");
@@ -184,15 +221,24 @@ impl ZkStackService {
         let mut errors: Vec<(String, WiringError)> = Vec::new();
 
         let runtime_handle = self.runtime.handle().clone();
-        for (name, WireFn(wire_fn)) in wiring_layers {
+        for entry in wiring_layers {
+            let LayerEntry {
+                name,
+                chain_id,
+                wire_fn: WireFn(wire_fn),
+            } = entry;
             // We must process wiring layers sequentially and in the same order as they were added.
-            let mut context = ServiceContext::new(name, self);
+            let mut context = ServiceContext::new(name, chain_id, self);
             let task_result = wire_fn(&runtime_handle, &mut context);
             if let Err(err) = task_result {
                 // We don't want to bail on the first error, since it'll provide worse DevEx:
                 // People likely want to fix as much problems as they can in one go, rather than have
                 // to fix them one by one.
-                errors.push((name.to_string(), err));
+                let name = match chain_id {
+                    Some(chain_id) => format!("{name} (chain {})", chain_id.as_u64()),
+                    None => name.to_string(),
+                };
+                errors.push((name, err));
                 continue;
             };
         }
@@ -226,9 +272,8 @@ impl ZkStackService {
         let task_barrier = self.runnables.task_barrier();
 
         // Collect long-running tasks.
-        let stop_receiver = StopReceiver(self.stop_sender.subscribe());
         self.runnables
-            .prepare_tasks(task_barrier.clone(), stop_receiver.clone())
+            .prepare_tasks(task_barrier.clone(), &self.stop_senders)
     }
 
     /// Spawn the provided tasks and runs them until at least one task exits, and returns the list
@@ -258,11 +303,34 @@ impl ZkStackService {
         remaining
     }
 
-    /// Sends the stop signal and waits for the remaining tasks to finish.
-    fn shutdown_tasks(&mut self, remaining: Vec<TaskFuture>) {
-        // Send stop signal to remaining tasks and wait for them to finish.
-        self.stop_sender.send(true).ok();
+    /// Sends the stop signal phase by phase and waits for the remaining tasks in each phase to finish
+    /// before proceeding to the next phase.
+    fn shutdown_tasks(
+        &mut self,
+        mut remaining: Vec<TaskFuture>,
+        stop_phases: &HashMap<TaskId, StopPhase>,
+    ) {
+        for phase in StopPhase::ALL {
+            let (phase_tasks, other_tasks) = mem::take(&mut remaining)
+                .into_iter()
+                .partition::<Vec<_>, _>(|task| {
+                    stop_phases.get(&task.id()).copied().unwrap_or_default() == phase
+                });
+            remaining = other_tasks;
+
+            self.stop_senders.stop(phase);
+            if !phase_tasks.is_empty() {
+                tracing::info!(
+                    "Stopping {} task(s) in {phase:?} stop phase",
+                    phase_tasks.len()
+                );
+                self.shutdown_phase_tasks(phase_tasks);
+            }
+        }
+    }
 
+    /// Waits for the provided tasks (which have already received the stop signal) to finish.
+    fn shutdown_phase_tasks(&mut self, remaining: Vec<TaskFuture>) {
         // Collect names for remaining tasks for reporting purposes.
         // We have to re-collect, becuase `select_all` does not guarantes the order of returned remaining futures.
         let remaining_tasks_names: Vec<_> = remaining.iter().map(|task| task.id()).collect();
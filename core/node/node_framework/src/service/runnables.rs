@@ -1,12 +1,12 @@
-use std::{fmt, sync::Arc};
+use std::{collections::HashMap, fmt, sync::Arc};
 
 use anyhow::Context as _;
 use futures::{future::BoxFuture, FutureExt as _};
 use tokio::sync::Barrier;
 use zksync_utils::panic_extractor::try_extract_panic_message;
 
-use super::{named_future::NamedFuture, StopReceiver};
-use crate::task::{Task, TaskKind};
+use super::{named_future::NamedFuture, stop_receiver::StopSenders, StopReceiver};
+use crate::task::{StopPhase, Task, TaskId, TaskKind};
 
 /// Alias for futures with the name assigned.
 pub(crate) type NamedBoxFuture<T> = NamedFuture<BoxFuture<'static, T>>;
@@ -32,6 +32,8 @@ impl fmt::Debug for Runnables {
 /// A unified representation of tasks that can be run by the service.
 pub(super) struct TaskReprs {
     pub(super) tasks: Vec<NamedBoxFuture<anyhow::Result<()>>>,
+    /// Stop phases for long-running tasks.
+    pub(super) stop_phases: HashMap<TaskId, StopPhase>,
     pub(super) shutdown_hooks: Vec<NamedBoxFuture<anyhow::Result<()>>>,
 }
 
@@ -39,6 +41,7 @@ impl fmt::Debug for TaskReprs {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TaskReprs")
             .field("long_running_tasks", &self.tasks.len())
+            .field("stop_phases", &self.stop_phases)
             .field("shutdown_hooks", &self.shutdown_hooks.len())
             .finish()
     }
@@ -73,15 +76,17 @@ impl Runnables {
     pub(super) fn prepare_tasks(
         &mut self,
         task_barrier: Arc<Barrier>,
-        stop_receiver: StopReceiver,
+        stop_senders: &StopSenders,
     ) -> TaskReprs {
         let mut long_running_tasks = Vec::new();
+        let mut stop_phases = HashMap::new();
         let mut oneshot_tasks = Vec::new();
 
         for task in std::mem::take(&mut self.tasks) {
             let name = task.id();
             let kind = task.kind();
-            let stop_receiver = stop_receiver.clone();
+            let stop_phase = task.stop_phase();
+            let stop_receiver = stop_senders.subscribe(stop_phase);
             let task_barrier = task_barrier.clone();
             let task_future: BoxFuture<'static, _> =
                 Box::pin(task.run_internal(stop_receiver, task_barrier));
@@ -89,19 +94,25 @@ impl Runnables {
             if kind.is_oneshot() {
                 oneshot_tasks.push(named_future);
             } else {
+                stop_phases.insert(named_future.id(), stop_phase);
                 long_running_tasks.push(named_future);
             }
         }
 
         let only_oneshot_tasks = long_running_tasks.is_empty();
         // Create a system task that is cancellation-aware and will only exit on either oneshot task failure or
-        // stop signal.
-        let oneshot_runner_system_task =
-            oneshot_runner_task(oneshot_tasks, stop_receiver, only_oneshot_tasks);
+        // stop signal. Oneshot tasks receive stop signals in their own phases, so the runner itself is stopped last.
+        let oneshot_runner_system_task = oneshot_runner_task(
+            oneshot_tasks,
+            stop_senders.subscribe(StopPhase::Infrastructure),
+            only_oneshot_tasks,
+        );
+        stop_phases.insert(oneshot_runner_system_task.id(), StopPhase::Infrastructure);
         long_running_tasks.push(oneshot_runner_system_task);
 
         TaskReprs {
             tasks: long_running_tasks,
+            stop_phases,
             shutdown_hooks: std::mem::take(&mut self.shutdown_hooks),
         }
     }
@@ -1,5 +1,9 @@
+use std::collections::BTreeMap;
+
 use tokio::sync::watch;
 
+use crate::task::StopPhase;
+
 /// Represents a receiver for the stop signal.
 /// This signal is sent when the node is shutting down.
 /// Every task is expected to listen to this signal and stop its execution when it is received.
@@ -8,3 +12,27 @@ use tokio::sync::watch;
 /// and prevent tasks from hanging by accident.
 #[derive(Debug, Clone)]
 pub struct StopReceiver(pub watch::Receiver<bool>);
+
+/// Senders of the stop signal for each [`StopPhase`].
+#[derive(Debug)]
+pub(super) struct StopSenders(BTreeMap<StopPhase, watch::Sender<bool>>);
+
+impl StopSenders {
+    pub(super) fn new() -> Self {
+        let senders = StopPhase::ALL
+            .into_iter()
+            .map(|phase| (phase, watch::channel(false).0))
+            .collect();
+        Self(senders)
+    }
+
+    /// Returns a stop receiver for the tasks stopped in the specified phase.
+    pub(super) fn subscribe(&self, phase: StopPhase) -> StopReceiver {
+        StopReceiver(self.0[&phase].subscribe())
+    }
+
+    /// Sends the stop signal to the tasks stopped in the specified phase.
+    pub(super) fn stop(&self, phase: StopPhase) {
+        self.0[&phase].send_replace(true);
+    }
+}
@@ -1,12 +1,20 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use anyhow::anyhow;
 use assert_matches::assert_matches;
 use tokio::{runtime::Runtime, sync::Barrier};
+use zksync_types::L2ChainId;
 
 use crate::{
-    service::{StopReceiver, WiringError, WiringLayer, ZkStackServiceBuilder, ZkStackServiceError},
-    task::{Task, TaskId},
+    resource::Resource,
+    service::{
+        ServiceContext, StopReceiver, WiringError, WiringLayer, ZkStackServiceBuilder,
+        ZkStackServiceError,
+    },
+    task::{StopPhase, Task, TaskId},
     IntoContext,
 };
 
@@ -76,6 +84,97 @@ fn test_layers_are_unique() {
     );
 }
 
+// `add_layer_for_chain` should allow adding the same layer for different chains.
+#[test]
+fn test_layers_are_unique_per_chain() {
+    let mut zk_stack_service = ZkStackServiceBuilder::new().unwrap();
+    zk_stack_service
+        .add_layer(DefaultLayer {
+            name: "default_layer",
+        })
+        .add_layer_for_chain(
+            L2ChainId::from(1),
+            DefaultLayer {
+                name: "default_layer",
+            },
+        )
+        .add_layer_for_chain(
+            L2ChainId::from(2),
+            DefaultLayer {
+                name: "default_layer",
+            },
+        )
+        .add_layer_for_chain(
+            L2ChainId::from(2),
+            DefaultLayer {
+                name: "default_layer",
+            },
+        );
+    let actual_layers_len = zk_stack_service.layers.len();
+    assert_eq!(
+        3, actual_layers_len,
+        "Incorrect number of layers in the service"
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TestResource(&'static str);
+
+impl Resource for TestResource {
+    fn name() -> String {
+        "test_resource".into()
+    }
+}
+
+// Resources provided by chain-scoped layers should only be visible within the same chain.
+#[test]
+fn test_chain_scoped_resources() {
+    let mut service = ZkStackServiceBuilder::new().unwrap().build();
+    let first_chain = Some(L2ChainId::from(1));
+    let second_chain = Some(L2ChainId::from(2));
+
+    let mut context = ServiceContext::new("first_chain", first_chain, &mut service);
+    assert_matches!(
+        context.get_resource::<TestResource>(),
+        Err(WiringError::ResourceLacking { id, .. }) if id.chain_id() == first_chain
+    );
+    context.insert_resource(TestResource("first")).unwrap();
+    assert_matches!(
+        context.insert_resource(TestResource("first")),
+        Err(WiringError::ResourceAlreadyProvided { .. })
+    );
+
+    let mut context = ServiceContext::new("global", None, &mut service);
+    assert_matches!(
+        context.get_resource::<TestResource>(),
+        Err(WiringError::ResourceLacking { .. })
+    );
+    context.insert_resource(TestResource("global")).unwrap();
+
+    let mut context = ServiceContext::new("first_chain", first_chain, &mut service);
+    assert_eq!(
+        context.get_resource::<TestResource>().unwrap(),
+        TestResource("first")
+    );
+    // Global resources are accessible from the chain scope.
+    let mut context = ServiceContext::new("second_chain", second_chain, &mut service);
+    assert_eq!(
+        context.get_resource::<TestResource>().unwrap(),
+        TestResource("global")
+    );
+    context.insert_resource(TestResource("second")).unwrap();
+    assert_eq!(
+        context.get_resource::<TestResource>().unwrap(),
+        TestResource("second")
+    );
+
+    let mut context = ServiceContext::new("global", None, &mut service);
+    assert_eq!(
+        context.get_resource::<TestResource>().unwrap(),
+        TestResource("global")
+    );
+}
+
 // `ZkStack` Service's `run()` method has to return error if there is no tasks added.
 #[test]
 fn test_run_with_no_tasks() {
@@ -252,3 +351,89 @@ fn test_task_run() {
     let res2 = *remaining_task_was_run.lock().unwrap();
     assert!(res2, "Incorrect resource value");
 }
+
+#[derive(Debug)]
+struct PhasedTasksLayer {
+    stop_order: Arc<Mutex<Vec<StopPhase>>>,
+}
+
+#[derive(Debug, IntoContext)]
+#[context(crate = crate)]
+struct PhasedTasksLayerOutput {
+    #[context(task)]
+    exiting_task: ExitingTask,
+    #[context(task)]
+    infrastructure_task: PhasedTask,
+    #[context(task)]
+    ingress_task: PhasedTask,
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for PhasedTasksLayer {
+    type Input = ();
+    type Output = PhasedTasksLayerOutput;
+
+    fn layer_name(&self) -> &'static str {
+        "phased_tasks_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        Ok(PhasedTasksLayerOutput {
+            exiting_task: ExitingTask,
+            infrastructure_task: PhasedTask(StopPhase::Infrastructure, self.stop_order.clone()),
+            ingress_task: PhasedTask(StopPhase::Ingress, self.stop_order),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct ExitingTask;
+
+#[async_trait::async_trait]
+impl Task for ExitingTask {
+    fn id(&self) -> TaskId {
+        "exiting_task".into()
+    }
+
+    async fn run(self: Box<Self>, _stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct PhasedTask(StopPhase, Arc<Mutex<Vec<StopPhase>>>);
+
+#[async_trait::async_trait]
+impl Task for PhasedTask {
+    fn stop_phase(&self) -> StopPhase {
+        self.0
+    }
+
+    fn id(&self) -> TaskId {
+        format!("phased_task_{:?}", self.0).into()
+    }
+
+    async fn run(self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        stop_receiver.0.changed().await?;
+        if self.0 == StopPhase::Ingress {
+            // Emulate slow shutdown; tasks in later phases must not be stopped until this task exits.
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        self.1.lock().unwrap().push(self.0);
+        Ok(())
+    }
+}
+
+// Check that `ZkStack` Service stops tasks according to their stop phases.
+#[test]
+fn test_tasks_are_stopped_in_phases() {
+    let stop_order = Arc::new(Mutex::new(vec![]));
+    let mut zk_stack_service = ZkStackServiceBuilder::new().unwrap();
+    zk_stack_service.add_layer(PhasedTasksLayer {
+        stop_order: stop_order.clone(),
+    });
+    zk_stack_service.build().run(None).unwrap();
+
+    let stop_order = stop_order.lock().unwrap();
+    assert_eq!(*stop_order, [StopPhase::Ingress, StopPhase::Infrastructure]);
+}
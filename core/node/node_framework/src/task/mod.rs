@@ -7,7 +7,7 @@ use std::{
 
 use tokio::sync::Barrier;
 
-pub use self::types::{TaskId, TaskKind};
+pub use self::types::{StopPhase, TaskId, TaskKind};
 use crate::service::StopReceiver;
 
 mod types;
@@ -56,6 +56,12 @@ mod types;
 /// A task that can run without waiting for preconditions and can exit without stopping the service.
 /// Usually such tasks may be used for satisfying a precondition, for example, they can perform the database
 /// setup.
+///
+/// ## Stop phases
+///
+/// Each task declares the [`StopPhase`] in which it receives the stop signal via [`Task::stop_phase`].
+/// The service stops tasks in the dependency order, e.g. API servers are stopped before the components
+/// they rely on. By default, tasks are stopped in the [`StopPhase::Processing`] phase.
 #[async_trait::async_trait]
 pub trait Task: 'static + Send {
     /// Returns the kind of the task.
@@ -65,6 +71,12 @@ pub trait Task: 'static + Send {
         TaskKind::Task
     }
 
+    /// Returns the phase in which the task is stopped during the service shutdown.
+    /// Similarly to [`Task::kind`], the returned value is expected to be static.
+    fn stop_phase(&self) -> StopPhase {
+        StopPhase::Processing
+    }
+
     /// Unique name of the task.
     fn id(&self) -> TaskId;
 
@@ -132,6 +144,7 @@ impl fmt::Debug for dyn Task {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Task")
             .field("kind", &self.kind())
+            .field("stop_phase", &self.stop_phase())
             .field("name", &self.id())
             .finish()
     }
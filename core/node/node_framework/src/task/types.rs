@@ -24,6 +24,36 @@ impl TaskKind {
     }
 }
 
+/// Phase in which the task is stopped during the service shutdown.
+///
+/// On shutdown, the service sends the stop signal phase by phase in the declaration order (i.e., ingress tasks
+/// are stopped first, and infrastructure tasks are stopped last). The service waits for all tasks in a phase
+/// to finish before proceeding to the next phase, so that tasks can rely on the components from the later phases
+/// until they exit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StopPhase {
+    /// Tasks accepting external requests, e.g. API servers.
+    Ingress,
+    /// Tasks processing data, e.g. state keeper or L1 sender. This is the default phase.
+    #[default]
+    Processing,
+    /// Tasks persisting data, e.g. Merkle tree.
+    Persistence,
+    /// Tasks that should be available until the very end of the shutdown, e.g. health check server
+    /// or metrics exporter.
+    Infrastructure,
+}
+
+impl StopPhase {
+    /// All stop phases in the order they are stopped.
+    pub const ALL: [Self; 4] = [
+        Self::Ingress,
+        Self::Processing,
+        Self::Persistence,
+        Self::Infrastructure,
+    ];
+}
+
 /// A unique human-readable identifier of a task.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TaskId(String);
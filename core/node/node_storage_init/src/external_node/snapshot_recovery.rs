@@ -46,6 +46,10 @@ impl InitializeStorage for ExternalNodeSnapshotRecovery {
 
         let config = SnapshotsApplierConfig {
             max_concurrency: self.max_concurrency,
+            max_concurrent_applications: self.recovery_config.max_concurrent_applications,
+            insertion_batch_size: self.recovery_config.insertion_batch_size,
+            max_download_bandwidth: self.recovery_config.max_download_bandwidth,
+            max_replication_lag: self.recovery_config.max_replication_lag,
             ..SnapshotsApplierConfig::default()
         };
         let mut snapshots_applier_task = SnapshotsApplierTask::new(
@@ -131,6 +135,10 @@ mod tests {
                 snapshot_l1_batch_override: None,
                 drop_storage_key_preimages: false,
                 object_store_config: None,
+                max_concurrent_applications: None,
+                insertion_batch_size: None,
+                max_download_bandwidth: None,
+                max_replication_lag: None,
             },
             app_health,
         };
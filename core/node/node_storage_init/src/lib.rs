@@ -1,4 +1,9 @@
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{
+    future::Future,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
+};
 
 use tokio::sync::watch;
 use zksync_config::ObjectStoreConfig;
@@ -17,6 +22,14 @@ pub struct SnapshotRecoveryConfig {
     pub snapshot_l1_batch_override: Option<L1BatchNumber>,
     pub drop_storage_key_preimages: bool,
     pub object_store_config: Option<ObjectStoreConfig>,
+    /// Maximum number of storage log chunks concurrently inserted into Postgres.
+    pub max_concurrent_applications: Option<NonZeroUsize>,
+    /// Maximum number of storage logs inserted into Postgres in a single query.
+    pub insertion_batch_size: Option<NonZeroUsize>,
+    /// Maximum download bandwidth for storage log chunks in bytes per second.
+    pub max_download_bandwidth: Option<NonZeroU64>,
+    /// Maximum tolerated replay lag of Postgres replicas.
+    pub max_replication_lag: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Copy)]
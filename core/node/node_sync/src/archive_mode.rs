@@ -0,0 +1,118 @@
+//! Tasks supporting the archive (full-history) mode of the external node.
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+
+/// Precondition task checking that the node storage contains full history, i.e. the node was not recovered
+/// from a snapshot and was never pruned. This prevents running a "half archive" node if pruning or snapshot recovery
+/// was enabled at some point in the past.
+#[derive(Debug)]
+pub struct ArchiveModeValidationTask {
+    pool: ConnectionPool<Core>,
+}
+
+impl ArchiveModeValidationTask {
+    pub fn new(pool: ConnectionPool<Core>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("archive_mode").await?;
+        let snapshot_recovery = storage
+            .snapshot_recovery_dal()
+            .get_applied_snapshot_status()
+            .await?;
+        if let Some(recovery) = snapshot_recovery {
+            anyhow::bail!(
+                "Node storage was recovered from a snapshot at L1 batch #{}, so it doesn't contain full history; \
+                 an archive node must be synced from genesis",
+                recovery.l1_batch_number
+            );
+        }
+
+        let pruning_info = storage.pruning_dal().get_pruning_info().await?;
+        // Merkle tree pruning follows Postgres pruning, so this also ensures that all tree versions are retained.
+        if let Some(soft_pruned) = pruning_info.last_soft_pruned {
+            anyhow::bail!(
+                "Node storage was pruned up to L1 batch #{}, so it doesn't contain full history; \
+                 an archive node must never be pruned",
+                soft_pruned.l1_batch
+            );
+        }
+        tracing::info!("Checked that node storage contains full history");
+        Ok(())
+    }
+}
+
+/// Oneshot task creating additional Postgres indexes for historical queries in the archive mode.
+#[derive(Debug)]
+pub struct ArchiveIndexesTask {
+    pool: ConnectionPool<Core>,
+}
+
+impl ArchiveIndexesTask {
+    pub fn new(pool: ConnectionPool<Core>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("archive_mode").await?;
+        tokio::select! {
+            res = storage.system_dal().create_archive_indexes() => {
+                res.context("failed creating archive indexes")?;
+                tracing::info!("All archive indexes are created");
+            }
+            _ = stop_receiver.changed() => {
+                tracing::info!("Stop signal received, archive index creation is shutting down");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
+    use zksync_node_test_utils::prepare_recovery_snapshot;
+    use zksync_types::{L1BatchNumber, L2BlockNumber};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn validating_archive_storage() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        ArchiveModeValidationTask::new(pool.clone())
+            .run()
+            .await
+            .unwrap();
+
+        storage
+            .pruning_dal()
+            .insert_soft_pruning_log(L1BatchNumber(0), L2BlockNumber(0))
+            .await
+            .unwrap();
+        let err = ArchiveModeValidationTask::new(pool.clone())
+            .run()
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("pruned"), "{err:#}");
+    }
+
+    #[tokio::test]
+    async fn validating_archive_storage_after_snapshot_recovery() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        prepare_recovery_snapshot(&mut storage, L1BatchNumber(23), L2BlockNumber(42), &[]).await;
+
+        let err = ArchiveModeValidationTask::new(pool.clone())
+            .run()
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("snapshot"), "{err:#}");
+    }
+}
@@ -150,6 +150,7 @@ impl ExternalIO {
                 },
                 protocol_version.timestamp,
                 Default::default(), // verification keys are unused for EN
+                None,
                 BaseSystemContractsHashes {
                     bootloader: bootloader_code_hash,
                     default_aa: default_account_code_hash,
@@ -1,3 +1,4 @@
+pub mod archive_mode;
 pub mod batch_status_updater;
 mod client;
 pub mod data_availability_fetcher;
@@ -5,6 +6,7 @@ pub mod external_io;
 pub mod fetcher;
 pub mod genesis;
 mod metrics;
+pub mod node_version_check;
 pub mod sync_action;
 mod sync_state;
 pub mod testonly;
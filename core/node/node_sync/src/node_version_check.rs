@@ -0,0 +1,233 @@
+//! Compatibility check between the external node and the main node it syncs from.
+
+use std::time::Duration;
+
+use futures::FutureExt;
+use tokio::sync::watch;
+use zksync_shared_metrics::metadata::GIT_METRICS;
+use zksync_types::{api, ProtocolVersionId};
+use zksync_web3_decl::{
+    client::{DynClient, L2},
+    error::{ClientRpcContext, EnrichedClientError},
+    jsonrpsee::{core::ClientError, types::error::ErrorCode},
+    namespaces::ZksNamespaceClient,
+};
+
+/// Task checking that the external node is compatible with the main node, based on `zks_getNodeVersion`.
+///
+/// Fails if the main node uses a protocol version that the external node cannot execute. Other kinds of skew
+/// (e.g., different node versions, or the main node supporting newer protocol versions) are only logged.
+#[derive(Debug)]
+pub struct NodeVersionCheckTask {
+    main_node_client: Box<DynClient<L2>>,
+}
+
+impl NodeVersionCheckTask {
+    const BACKOFF_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(main_node_client: Box<DynClient<L2>>) -> Self {
+        Self {
+            main_node_client: main_node_client.for_component("node_version_check"),
+        }
+    }
+
+    /// Returns `None` if the main node doesn't support `zks_getNodeVersion`.
+    async fn fetch_main_node_version(
+        &self,
+    ) -> Result<Option<api::NodeVersion>, EnrichedClientError> {
+        match self
+            .main_node_client
+            .get_node_version()
+            .rpc_context("get_node_version")
+            .await
+        {
+            Ok(version) => Ok(Some(version)),
+            Err(err) => match err.as_ref() {
+                // Proxies may respond to non-whitelisted methods with an HTTP error, which is surfaced
+                // as a transport error. Transient network issues will be caught by the protocol version request.
+                ClientError::Transport(_) => Ok(None),
+                // Main nodes not supporting the method respond with HTTP 200 and `MethodNotFound` in the body.
+                ClientError::Call(call_err)
+                    if call_err.code() == ErrorCode::MethodNotFound.code() =>
+                {
+                    Ok(None)
+                }
+                _ => Err(err),
+            },
+        }
+    }
+
+    async fn fetch_main_node_protocol_version(&self) -> Result<Option<u16>, EnrichedClientError> {
+        let protocol_version = self
+            .main_node_client
+            .get_protocol_version(None)
+            .rpc_context("get_protocol_version")
+            .await?;
+        Ok(protocol_version.and_then(|version| version.minor_version))
+    }
+
+    async fn check(&self) -> anyhow::Result<()> {
+        loop {
+            let result = async {
+                let version = self.fetch_main_node_version().await?;
+                let protocol_version = self.fetch_main_node_protocol_version().await?;
+                Ok::<_, EnrichedClientError>((version, protocol_version))
+            }
+            .await;
+
+            match result {
+                Ok((version, protocol_version)) => {
+                    return check_compatibility(version.as_ref(), protocol_version);
+                }
+                Err(err) if err.is_retriable() => {
+                    tracing::warn!(
+                        "Transient error getting version info from main node client, will retry in {:?}: {err}",
+                        Self::BACKOFF_INTERVAL
+                    );
+                    tokio::time::sleep(Self::BACKOFF_INTERVAL).await;
+                }
+                Err(err) => {
+                    tracing::error!("Error getting version info from main node client: {err}");
+                    return Err(err.into());
+                }
+            }
+        }
+    }
+
+    /// Runs the task once, exiting either when the check is performed or when the stop signal is received.
+    pub async fn run_once(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        tokio::select! {
+            res = self.check().fuse() => res,
+            _ = stop_receiver.changed() => Ok(()),
+        }
+    }
+}
+
+fn check_compatibility(
+    main_node_version: Option<&api::NodeVersion>,
+    main_node_protocol_version: Option<u16>,
+) -> anyhow::Result<()> {
+    let local_version = env!("CARGO_PKG_VERSION");
+    let local_git_commit = GIT_METRICS.initialize().revision;
+    let max_supported_protocol_version = ProtocolVersionId::next() as u16;
+
+    if let Some(protocol_version) = main_node_protocol_version {
+        anyhow::ensure!(
+            ProtocolVersionId::try_from(protocol_version).is_ok(),
+            "Main node uses protocol version {protocol_version}, which is not supported by this external node \
+             (supported versions: 0..={max_supported_protocol_version}). Update the external node to a newer version"
+        );
+    }
+
+    let Some(main_node_version) = main_node_version else {
+        tracing::warn!(
+            "Main node doesn't support `zks_getNodeVersion`; skipping version compatibility check"
+        );
+        return Ok(());
+    };
+    tracing::info!(
+        "Main node version: {} (commit {:?}); external node version: {local_version} (commit {local_git_commit:?})",
+        main_node_version.version,
+        main_node_version.git_commit
+    );
+
+    if main_node_version.version != local_version {
+        tracing::warn!(
+            "External node version {local_version} differs from the main node version {}",
+            main_node_version.version
+        );
+    }
+    let unsupported_versions: Vec<_> = main_node_version
+        .supported_protocol_versions
+        .iter()
+        .filter(|&&version| version > max_supported_protocol_version)
+        .collect();
+    if !unsupported_versions.is_empty() {
+        tracing::warn!(
+            "Main node supports protocol versions {unsupported_versions:?}, which are not supported by this external node. \
+             The external node must be updated before these versions are activated"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_web3_decl::client::MockClient;
+
+    use super::*;
+
+    fn mock_protocol_version(minor_version: u16) -> api::ProtocolVersion {
+        api::ProtocolVersion {
+            minor_version: Some(minor_version),
+            ..api::ProtocolVersion::default()
+        }
+    }
+
+    fn mock_node_version(supported_protocol_versions: Vec<u16>) -> api::NodeVersion {
+        api::NodeVersion {
+            version: "1.0.0".to_owned(),
+            git_commit: None,
+            supported_protocol_versions,
+            api_features: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn checking_compatible_main_node() {
+        let latest = ProtocolVersionId::latest() as u16;
+        // The main node supports a protocol version unknown to the EN, but it's not used yet.
+        let node_version = mock_node_version((0..=latest + 5).collect());
+        let main_node_client = MockClient::builder(L2::default())
+            .method("zks_getNodeVersion", move || Ok(node_version.clone()))
+            .method("zks_getProtocolVersion", move |_: Option<u16>| {
+                Ok(Some(mock_protocol_version(latest)))
+            })
+            .build();
+
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        NodeVersionCheckTask::new(Box::new(main_node_client))
+            .run_once(stop_receiver)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn checking_main_node_without_version_method() {
+        let latest = ProtocolVersionId::latest() as u16;
+        let main_node_client = MockClient::builder(L2::default())
+            .method("zks_getProtocolVersion", move |_: Option<u16>| {
+                Ok(Some(mock_protocol_version(latest)))
+            })
+            .build();
+
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        NodeVersionCheckTask::new(Box::new(main_node_client))
+            .run_once(stop_receiver)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn checking_main_node_with_unsupported_protocol_version() {
+        let unsupported = ProtocolVersionId::next() as u16 + 1;
+        let node_version = mock_node_version((0..=unsupported).collect());
+        let main_node_client = MockClient::builder(L2::default())
+            .method("zks_getNodeVersion", move || Ok(node_version.clone()))
+            .method("zks_getProtocolVersion", move |_: Option<u16>| {
+                Ok(Some(mock_protocol_version(unsupported)))
+            })
+            .build();
+
+        let (_stop_sender, stop_receiver) = watch::channel(false);
+        let err = NodeVersionCheckTask::new(Box::new(main_node_client))
+            .run_once(stop_receiver)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("protocol version") && err.contains("not supported"),
+            "{err}"
+        );
+    }
+}
@@ -73,6 +73,7 @@ impl L2Parameters {
                 Ok(root_hash.map(|&hash| api::L1BatchDetails {
                     number,
                     base: mock_block_details_base(number.0, Some(hash)),
+                    proving_rounds: vec![],
                 }))
             })
             .method("zks_getBlockDetails", move |number: L2BlockNumber| {
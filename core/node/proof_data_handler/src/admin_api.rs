@@ -0,0 +1,147 @@
+//! Admin API of the proof data handler.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Request, State},
+    http::{header, HeaderMap},
+    middleware::Next,
+    routing::post,
+    Json, Router,
+};
+use secrecy::ExposeSecret;
+use serde::Deserialize;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::{secrets::APIKey, L1BatchNumber};
+
+use crate::{errors::RequestProcessorError, metrics::Method, middleware::MetricsMiddleware};
+
+/// Request to skip proof generation for a batch.
+#[derive(Debug, Deserialize)]
+pub(crate) struct SkipProvingRequest {
+    /// Identifier of the operator requesting the skip.
+    pub requested_by: String,
+    /// Why proof generation is skipped.
+    pub reason: String,
+}
+
+/// Compares byte strings so that the comparison time doesn't depend on the position of the first mismatch.
+fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let diff = lhs
+        .iter()
+        .zip(rhs)
+        .fold(0_u8, |acc, (&lhs, &rhs)| acc | (lhs ^ rhs));
+    std::hint::black_box(diff) == 0
+}
+
+/// Admin endpoints of the proof data handler authenticated with a bearer API key.
+///
+/// Skipping proof generation for a batch is recorded in the Postgres audit trail. The skip is propagated
+/// to prover gateways by [`RpcClient`](crate::RpcClient), so that unfinished prover jobs for the batch are skipped
+/// as well.
+#[derive(Debug)]
+pub struct AdminApi {
+    pool: ConnectionPool<Core>,
+    api_key: APIKey,
+}
+
+impl AdminApi {
+    pub fn new(pool: ConnectionPool<Core>, api_key: APIKey) -> Self {
+        Self { pool, api_key }
+    }
+
+    pub(crate) fn into_router(self) -> Router {
+        let metrics_middleware = axum::middleware::from_fn(|req: Request, next: Next| async move {
+            let middleware = MetricsMiddleware::new(Method::AdminSkipProving);
+            let response = next.run(req).await;
+            middleware.observe(response.status());
+            response
+        });
+
+        Router::new()
+            .route(
+                "/admin/skip_proving/:l1_batch_number",
+                post(Self::skip_proving_handler).layer(metrics_middleware),
+            )
+            .with_state(Arc::new(self))
+    }
+
+    /// Checks that the request carries the configured admin API key as a bearer token.
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), RequestProcessorError> {
+        let token = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        let expected_token = self.api_key.0.expose_secret();
+        match token {
+            Some(token) if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => Ok(()),
+            _ => Err(RequestProcessorError::Unauthorized(
+                "invalid admin API key".to_owned(),
+            )),
+        }
+    }
+
+    async fn skip_proving_handler(
+        State(api): State<Arc<Self>>,
+        headers: HeaderMap,
+        Path(l1_batch_number): Path<L1BatchNumber>,
+        Json(payload): Json<SkipProvingRequest>,
+    ) -> Result<(), RequestProcessorError> {
+        api.skip_proving(&headers, l1_batch_number, payload).await
+    }
+
+    #[tracing::instrument(skip(self, headers))]
+    async fn skip_proving(
+        &self,
+        headers: &HeaderMap,
+        l1_batch_number: L1BatchNumber,
+        request: SkipProvingRequest,
+    ) -> Result<(), RequestProcessorError> {
+        self.authorize(headers)?;
+        if request.requested_by.is_empty() || request.reason.is_empty() {
+            return Err(RequestProcessorError::InvalidRequest(
+                "`requested_by` and `reason` must be non-empty".to_owned(),
+            ));
+        }
+
+        let skipped = self
+            .pool
+            .connection_tagged("proof_data_handler")
+            .await?
+            .proof_generation_dal()
+            .mark_proof_generation_job_as_skipped_manually(
+                l1_batch_number,
+                &request.requested_by,
+                &request.reason,
+            )
+            .await?;
+        if !skipped {
+            return Err(RequestProcessorError::Conflict(format!(
+                "batch {l1_batch_number} is not awaiting proof generation"
+            )));
+        }
+
+        tracing::warn!(
+            "Proof generation for batch {l1_batch_number} was skipped by {}: {}",
+            request.requested_by,
+            request.reason
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparing_in_constant_time() {
+        assert!(constant_time_eq(b"admin-key", b"admin-key"));
+        assert!(!constant_time_eq(b"admin-key", b"admin-kez"));
+        assert!(!constant_time_eq(b"admin-key", b"admin"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
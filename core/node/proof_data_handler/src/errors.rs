@@ -10,6 +10,9 @@ pub(crate) enum RequestProcessorError {
     ObjectStore(ObjectStoreError),
     Dal(DalError),
     NoContent(String),
+    Unauthorized(String),
+    InvalidRequest(String),
+    Conflict(String),
 }
 
 impl From<DalError> for RequestProcessorError {
@@ -46,6 +49,12 @@ impl IntoResponse for RequestProcessorError {
                 tracing::error!("Expected content, received none: {:?}", err);
                 (StatusCode::NO_CONTENT, "No content".to_owned())
             }
+            Self::Unauthorized(err) => {
+                tracing::warn!("Unauthorized request: {err}");
+                (StatusCode::UNAUTHORIZED, "Unauthorized".to_owned())
+            }
+            Self::InvalidRequest(err) => (StatusCode::BAD_REQUEST, err),
+            Self::Conflict(err) => (StatusCode::CONFLICT, err),
         };
         (status_code, message).into_response()
     }
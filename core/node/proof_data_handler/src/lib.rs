@@ -1,11 +1,13 @@
 #[cfg(test)]
 mod tests;
 
+mod admin_api;
 mod errors;
 mod metrics;
 mod middleware;
 mod rpc_client;
 mod tee_proof_api;
 
+pub use admin_api::AdminApi;
 pub use rpc_client::{processor::ProofDataProcessor, RpcClient};
 pub use tee_proof_api::{RequestProcessor, TeeProofDataHandler};
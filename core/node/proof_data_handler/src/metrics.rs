@@ -1,6 +1,9 @@
 use std::{fmt, time::Duration};
 
-use vise::{EncodeLabelSet, EncodeLabelValue, Family, Histogram, LabeledFamily, Metrics, Unit};
+use vise::{
+    Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily, Metrics,
+    Unit,
+};
 use zksync_object_store::bincode;
 use zksync_prover_interface::inputs::WitnessInputData;
 use zksync_types::tee_types::TeeType;
@@ -29,6 +32,7 @@ pub(crate) enum Method {
     GetTeeProofInputs,
     TeeSubmitProofs,
     TeeRegisterAttestation,
+    AdminSkipProving,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet, EncodeLabelValue)]
@@ -70,3 +74,35 @@ impl ProofDataHandlerMetrics {
 
 #[vise::register]
 pub(super) static METRICS: vise::Global<ProofDataHandlerMetrics> = vise::Global::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(crate) enum SubmissionResult {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "proof_data_handler_gateway")]
+pub(crate) struct GatewayMetrics {
+    /// Number of proof generation data submissions to each prover gateway.
+    #[metrics(labels = ["endpoint", "result"])]
+    pub submissions: LabeledFamily<(String, SubmissionResult), Counter, 2>,
+    /// Number of failed connection attempts to each prover gateway.
+    #[metrics(labels = ["endpoint"])]
+    pub connection_failures: LabeledFamily<String, Counter>,
+    /// Number of proofs received from each prover gateway.
+    #[metrics(labels = ["endpoint"])]
+    pub received_proofs: LabeledFamily<String, Counter>,
+    /// Number of proving round notices received from each prover gateway.
+    #[metrics(labels = ["endpoint"])]
+    pub received_proving_round_notices: LabeledFamily<String, Counter>,
+    /// Number of times proof generation data submission has switched to another prover gateway.
+    pub failovers: Counter,
+    /// Health of each prover gateway according to the latest health probe (1 = healthy, 0 = unhealthy).
+    #[metrics(labels = ["endpoint"])]
+    pub endpoint_health: LabeledFamily<String, Gauge<u64>>,
+}
+
+#[vise::register]
+pub(super) static GATEWAY_METRICS: vise::Global<GatewayMetrics> = vise::Global::new();
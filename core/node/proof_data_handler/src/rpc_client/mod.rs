@@ -1,58 +1,192 @@
-use std::time::Duration;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
-use jsonrpsee::{async_client::Client, ws_client::WsClientBuilder};
+use futures::future;
+use jsonrpsee::{
+    async_client::Client,
+    ws_client::{PingConfig, WsClientBuilder},
+};
 use tokio::sync::watch;
 use zksync_prover_interface::{api::SubmitProofRequest, rpc::GatewayRpcClient};
 
-use crate::rpc_client::processor::ProofDataProcessor;
+use crate::{
+    metrics::{SubmissionResult, GATEWAY_METRICS},
+    rpc_client::processor::ProofDataProcessor,
+};
 
 pub mod processor;
 
+/// Timeout for establishing a connection to a prover gateway during a health probe.
+const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Client exchanging data with one or more prover gateways.
+///
+/// Proof generation data is submitted to a single gateway at a time. The client sticks to the selected gateway
+/// while it's reachable and fails over to the next configured gateway otherwise. All gateways are periodically
+/// probed in the background; failover skips gateways that failed the latest probe, and the submitter proactively
+/// leaves the selected gateway if it fails a probe while another gateway is healthy. Proofs are received from all
+/// configured gateways, since a gateway only returns proofs for the batches submitted to it. Similarly, intermediate
+/// proving progress is received from all configured gateways.
 #[derive(Debug)]
 pub struct RpcClient {
     processor: ProofDataProcessor,
-    ws_url: String,
+    ws_urls: Vec<String>,
+    /// Health of each gateway according to the latest health probe. Gateways are considered healthy until probed.
+    endpoint_health: Vec<AtomicBool>,
     readiness_check_interval: Duration,
     connection_retry_interval: Duration,
 }
 
 impl RpcClient {
+    /// Creates a new client. `ws_urls` are prover gateway URLs in the order of preference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ws_urls` is empty.
     pub fn new(
         processor: ProofDataProcessor,
-        ws_url: String,
+        ws_urls: Vec<String>,
         readiness_check_interval: Duration,
         connection_retry_interval: Duration,
     ) -> Self {
+        assert!(
+            !ws_urls.is_empty(),
+            "at least one prover gateway URL must be provided"
+        );
+        let endpoint_health = ws_urls.iter().map(|_| AtomicBool::new(true)).collect();
         Self {
             processor,
-            ws_url,
+            ws_urls,
+            endpoint_health,
             readiness_check_interval,
             connection_retry_interval,
         }
     }
 
     pub async fn run(self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let health_prober = self.run_health_prober(stop_receiver.clone());
         let proof_data_sender = self.run_and_maintain_proof_data_submitter(stop_receiver.clone());
-        let proof_receiver = self.run_and_maintain_proof_receiver(stop_receiver.clone());
+        let proof_receivers = future::join_all(
+            self.ws_urls
+                .iter()
+                .map(|ws_url| self.run_and_maintain_proof_receiver(ws_url, stop_receiver.clone())),
+        );
+        // Progress is received over a separate subscription, so that gateways not supporting it
+        // don't affect proof receiving.
+        let progress_receivers =
+            future::join_all(self.ws_urls.iter().map(|ws_url| {
+                self.run_and_maintain_progress_receiver(ws_url, stop_receiver.clone())
+            }));
 
-        tracing::info!("Starting proof data submitter and receiver");
+        tracing::info!(
+            "Starting proof data submitter and receivers for prover gateways {:?}",
+            self.ws_urls
+        );
 
         tokio::select! {
+            _ = health_prober => {
+                tracing::info!("Prover gateway health prober stopped");
+            }
             _ = proof_data_sender => {
                 tracing::info!("Proof data submitter stopped");
             }
-            _ = proof_receiver => {
-                tracing::info!("Proof receiver stopped");
+            _ = proof_receivers => {
+                tracing::info!("Proof receivers stopped");
+            }
+            _ = progress_receivers => {
+                tracing::info!("Proving progress receivers stopped");
             }
         }
 
         Ok(())
     }
 
+    fn is_healthy(&self, endpoint_idx: usize) -> bool {
+        self.endpoint_health[endpoint_idx].load(Ordering::Relaxed)
+    }
+
+    fn set_health(&self, endpoint_idx: usize, is_healthy: bool) {
+        let ws_url = &self.ws_urls[endpoint_idx];
+        let was_healthy = self.endpoint_health[endpoint_idx].swap(is_healthy, Ordering::Relaxed);
+        if was_healthy != is_healthy {
+            if is_healthy {
+                tracing::info!("Prover gateway {ws_url} is healthy again");
+            } else {
+                tracing::warn!("Prover gateway {ws_url} failed health probe");
+            }
+        }
+        GATEWAY_METRICS.endpoint_health[ws_url].set(is_healthy.into());
+    }
+
+    /// Returns healthy gateways other than the specified one in the order of preference, starting
+    /// from the gateway following the specified one.
+    fn healthy_alternatives(&self, endpoint_idx: usize) -> impl Iterator<Item = usize> + '_ {
+        let len = self.ws_urls.len();
+        (1..len)
+            .map(move |offset| (endpoint_idx + offset) % len)
+            .filter(|&idx| self.is_healthy(idx))
+    }
+
+    /// Periodically probes all gateways by connecting to them, and records their health.
+    /// Connections are kept between probes and are re-established once lost.
+    async fn run_health_prober(&self, stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let mut clients: Vec<Option<Client>> = self.ws_urls.iter().map(|_| None).collect();
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::warn!("Stop signal received, shutting down prover gateway health prober");
+                return Ok(());
+            }
+
+            for (endpoint_idx, (ws_url, client)) in
+                self.ws_urls.iter().zip(&mut clients).enumerate()
+            {
+                if !client.as_ref().is_some_and(Client::is_connected) {
+                    *client = match WsClientBuilder::default()
+                        .connection_timeout(HEALTH_PROBE_TIMEOUT)
+                        .enable_ws_ping(PingConfig::default())
+                        .build(ws_url)
+                        .await
+                    {
+                        Ok(client) => Some(client),
+                        Err(e) => {
+                            tracing::debug!("Health probe of gateway {ws_url} failed: {e}");
+                            None
+                        }
+                    };
+                }
+                self.set_health(endpoint_idx, client.is_some());
+            }
+
+            tokio::time::sleep(self.connection_retry_interval).await;
+        }
+    }
+
+    /// Returns the index of the gateway to switch to after the gateway with the specified index has failed.
+    /// Prefers healthy gateways; if no other gateway is healthy, switches to the next gateway.
+    fn fail_over(&self, endpoint_idx: usize) -> usize {
+        if self.ws_urls.len() == 1 {
+            return endpoint_idx;
+        }
+        let next_idx = self
+            .healthy_alternatives(endpoint_idx)
+            .next()
+            .unwrap_or((endpoint_idx + 1) % self.ws_urls.len());
+        tracing::warn!(
+            "Failing over proof data submission from prover gateway {} to {}",
+            self.ws_urls[endpoint_idx],
+            self.ws_urls[next_idx]
+        );
+        GATEWAY_METRICS.failovers.inc();
+        next_idx
+    }
+
     async fn run_and_maintain_proof_data_submitter(
         &self,
         stop_receiver: watch::Receiver<bool>,
     ) -> anyhow::Result<()> {
+        let mut endpoint_idx = 0;
         loop {
             tokio::time::sleep(self.connection_retry_interval).await;
             if *stop_receiver.borrow() {
@@ -60,35 +194,43 @@ impl RpcClient {
                 return Ok(());
             }
 
-            let client = WsClientBuilder::default().build(&self.ws_url).await;
-            if let Err(e) = client {
-                tracing::error!(
-                    "Failed to connect to the server for proof data submitter: {}, sleeping for {:?}",
-                    e,
-                    self.connection_retry_interval
-                );
-                continue;
-            }
+            let ws_url = &self.ws_urls[endpoint_idx];
+            let client = match WsClientBuilder::default().build(ws_url).await {
+                Ok(client) => client,
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to connect to the gateway {ws_url} for proof data submitter: {}, sleeping for {:?}",
+                        e,
+                        self.connection_retry_interval
+                    );
+                    GATEWAY_METRICS.connection_failures[ws_url].inc();
+                    endpoint_idx = self.fail_over(endpoint_idx);
+                    continue;
+                }
+            };
 
             tracing::info!(
                 "Established long living connection with gateway for proof data submitter by URL: {}",
-                self.ws_url
+                ws_url
             );
 
             if let Err(e) = self
-                .run_proof_data_submitter(client?, stop_receiver.clone())
+                .run_proof_data_submitter(endpoint_idx, client, stop_receiver.clone())
                 .await
             {
-                tracing::error!("Proof data submitter failed: {}", e);
+                tracing::error!("Proof data submitter for gateway {ws_url} failed: {}", e);
+                endpoint_idx = self.fail_over(endpoint_idx);
             }
         }
     }
 
     async fn run_proof_data_submitter(
         &self,
+        endpoint_idx: usize,
         client: Client,
         stop_receiver: watch::Receiver<bool>,
     ) -> anyhow::Result<()> {
+        let ws_url = self.ws_urls[endpoint_idx].as_str();
         loop {
             tokio::time::sleep(self.readiness_check_interval).await;
             if *stop_receiver.borrow() {
@@ -99,6 +241,16 @@ impl RpcClient {
                 tracing::error!("Connection to the server is lost, trying to reconnect");
                 return Err(anyhow::anyhow!("Connection to the server is lost"));
             }
+            if !self.is_healthy(endpoint_idx)
+                && self.healthy_alternatives(endpoint_idx).next().is_some()
+            {
+                return Err(anyhow::anyhow!(
+                    "Gateway failed health probe while other gateways are healthy"
+                ));
+            }
+
+            self.propagate_proof_generation_skips(ws_url, &client)
+                .await?;
 
             let Some(data) = self.processor.get_proof_generation_data().await? else {
                 tracing::info!("No proof generation data to send, waiting for new batches");
@@ -112,21 +264,53 @@ impl RpcClient {
             if let Err(e) = client.submit_proof_generation_data(data).await {
                 tracing::error!(
                     "Failed to submit proof generation data for batch {:?}, unlocking: {}",
-                    e,
-                    l1_batch_number
+                    l1_batch_number,
+                    e
                 );
+                GATEWAY_METRICS.submissions[&(ws_url.to_owned(), SubmissionResult::Failure)].inc();
                 self.processor.unlock_batch(l1_batch_number).await?;
             } else {
                 tracing::info!(
-                    "Proof generation data for batch {:?} was sent successfully",
+                    "Proof generation data for batch {:?} was sent successfully to gateway {ws_url}",
                     l1_batch_number
                 );
+                GATEWAY_METRICS.submissions[&(ws_url.to_owned(), SubmissionResult::Success)].inc();
             }
         }
     }
 
+    /// Propagates manual proof generation skips to the gateway, so that it skips the unfinished prover jobs
+    /// for the skipped batches. Failing to propagate a skip doesn't prevent submitting proof generation data;
+    /// propagation is retried on the next iteration.
+    async fn propagate_proof_generation_skips(
+        &self,
+        ws_url: &str,
+        client: &Client,
+    ) -> anyhow::Result<()> {
+        let l1_batch_numbers = self
+            .processor
+            .get_unpropagated_proof_generation_skips()
+            .await?;
+        for l1_batch_number in l1_batch_numbers {
+            if let Err(err) = client.skip_proving(l1_batch_number).await {
+                tracing::warn!(
+                    "Failed to propagate proof generation skip for batch {l1_batch_number} to gateway {ws_url}: {err}"
+                );
+                break;
+            }
+            tracing::info!(
+                "Propagated proof generation skip for batch {l1_batch_number} to gateway {ws_url}"
+            );
+            self.processor
+                .mark_proof_generation_skip_as_propagated(l1_batch_number)
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn run_and_maintain_proof_receiver(
         &self,
+        ws_url: &str,
         stop_receiver: watch::Receiver<bool>,
     ) -> anyhow::Result<()> {
         loop {
@@ -135,33 +319,37 @@ impl RpcClient {
                 return Ok(());
             }
 
-            let client = WsClientBuilder::default().build(&self.ws_url).await;
-            if let Err(e) = client {
-                tracing::error!(
-                    "Failed to connect to the server for proof receiver: {}, retrying in {:?}",
-                    e,
-                    self.connection_retry_interval
-                );
-                tokio::time::sleep(self.connection_retry_interval).await;
-                continue;
-            }
+            let client = match WsClientBuilder::default().build(ws_url).await {
+                Ok(client) => client,
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to connect to the gateway {ws_url} for proof receiver: {}, retrying in {:?}",
+                        e,
+                        self.connection_retry_interval
+                    );
+                    GATEWAY_METRICS.connection_failures[&ws_url.to_owned()].inc();
+                    tokio::time::sleep(self.connection_retry_interval).await;
+                    continue;
+                }
+            };
 
             tracing::info!(
                 "Established long living connection with gateway for proof receiver by URL: {}",
-                self.ws_url
+                ws_url
             );
 
             if let Err(e) = self
-                .run_proof_receiver(client?, stop_receiver.clone())
+                .run_proof_receiver(ws_url, client, stop_receiver.clone())
                 .await
             {
-                tracing::error!("Proof data receiver failed: {}", e);
+                tracing::error!("Proof data receiver for gateway {ws_url} failed: {}", e);
             }
         }
     }
 
     async fn run_proof_receiver(
         &self,
+        ws_url: &str,
         client: Client,
         stop_receiver: watch::Receiver<bool>,
     ) -> anyhow::Result<()> {
@@ -185,10 +373,77 @@ impl RpcClient {
                 SubmitProofRequest::SkippedProofGeneration(l1_batch_number) => l1_batch_number,
             };
 
-            tracing::info!("Received proof for batch {:?}", l1_batch_number);
+            tracing::info!(
+                "Received proof for batch {:?} from gateway {ws_url}",
+                l1_batch_number
+            );
+            GATEWAY_METRICS.received_proofs[&ws_url.to_owned()].inc();
 
             self.processor.handle_proof(proof).await?;
             client.received_final_proof(l1_batch_number).await?
         }
     }
+    async fn run_and_maintain_progress_receiver(
+        &self,
+        ws_url: &str,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::warn!("Stop signal received, shutting down proving progress receiver");
+                return Ok(());
+            }
+
+            let client = match WsClientBuilder::default().build(ws_url).await {
+                Ok(client) => client,
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to connect to the gateway {ws_url} for proving progress receiver: {}, retrying in {:?}",
+                        e,
+                        self.connection_retry_interval
+                    );
+                    GATEWAY_METRICS.connection_failures[&ws_url.to_owned()].inc();
+                    tokio::time::sleep(self.connection_retry_interval).await;
+                    continue;
+                }
+            };
+
+            if let Err(e) = self
+                .run_progress_receiver(ws_url, client, stop_receiver.clone())
+                .await
+            {
+                tracing::error!(
+                    "Proving progress receiver for gateway {ws_url} failed: {}",
+                    e
+                );
+                // The gateway may not support progress subscriptions, so don't resubscribe too eagerly.
+                tokio::time::sleep(self.connection_retry_interval).await;
+            }
+        }
+    }
+
+    async fn run_progress_receiver(
+        &self,
+        ws_url: &str,
+        client: Client,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let mut subscription = client.subscribe_for_proving_progress().await?;
+        loop {
+            if *stop_receiver.borrow() {
+                tracing::warn!("Stop signal received, shutting down proving progress receiver");
+                return Ok(());
+            }
+
+            let notice = match subscription.next().await {
+                Some(notice) => notice?,
+                None => {
+                    tracing::warn!("Proving progress subscription ended, needs resubscribing");
+                    return Err(anyhow::anyhow!("Proving progress subscription ended"));
+                }
+            };
+            GATEWAY_METRICS.received_proving_round_notices[&ws_url.to_owned()].inc();
+            self.processor.handle_proving_round_notice(notice).await?;
+        }
+    }
 }
@@ -3,7 +3,7 @@ use std::{sync::Arc, time::Duration};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_object_store::ObjectStore;
 use zksync_prover_interface::{
-    api::{ProofGenerationData, SubmitProofRequest},
+    api::{ProofGenerationData, ProvingRoundNotice, SubmitProofRequest},
     inputs::{
         L1BatchMetadataHashes, VMRunWitnessInputData, WitnessInputData, WitnessInputMerklePaths,
     },
@@ -76,6 +76,34 @@ impl ProofDataProcessor {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Returns batches whose proof generation was skipped manually, but the skip wasn't propagated to prover gateways yet.
+    pub(crate) async fn get_unpropagated_proof_generation_skips(
+        &self,
+    ) -> anyhow::Result<Vec<L1BatchNumber>> {
+        const LIMIT: usize = 100;
+
+        self.pool
+            .connection()
+            .await?
+            .proof_generation_dal()
+            .get_unpropagated_proof_generation_skips(LIMIT)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    pub(crate) async fn mark_proof_generation_skip_as_propagated(
+        &self,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        self.pool
+            .connection()
+            .await?
+            .proof_generation_dal()
+            .mark_proof_generation_skip_as_propagated(l1_batch_number)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     /// Will fetch all the required data for the batch and return it.
     ///
     /// ## Panics
@@ -256,4 +284,31 @@ impl ProofDataProcessor {
 
         Ok(())
     }
+    pub(crate) async fn handle_proving_round_notice(
+        &self,
+        notice: ProvingRoundNotice,
+    ) -> anyhow::Result<()> {
+        tracing::info!(
+            "Received {:?} notice for round {} of batch {:?} ({} jobs, failed circuits: {:?})",
+            notice.status,
+            notice.aggregation_round,
+            notice.l1_batch_number,
+            notice.jobs_count,
+            notice.failed_circuit_ids
+        );
+        self.pool
+            .connection()
+            .await?
+            .proof_generation_dal()
+            .save_proving_round(
+                notice.l1_batch_number,
+                notice.aggregation_round,
+                notice.status,
+                notice.jobs_count,
+                &notice.failed_circuit_ids,
+                notice.artifact_url.as_deref(),
+            )
+            .await?;
+        Ok(())
+    }
 }
@@ -17,7 +17,10 @@ use zksync_prover_interface::api::{
 };
 use zksync_types::{L1BatchNumber, L2ChainId};
 
-use crate::{errors::RequestProcessorError, metrics::Method, middleware::MetricsMiddleware};
+use crate::{
+    admin_api::AdminApi, errors::RequestProcessorError, metrics::Method,
+    middleware::MetricsMiddleware,
+};
 
 mod processor;
 
@@ -28,7 +31,13 @@ pub struct TeeProofDataHandler {
 }
 
 impl TeeProofDataHandler {
-    pub fn new(state: RequestProcessor, port: u16) -> TeeProofDataHandler {
+    /// Creates the server. TEE endpoints are only served if TEE support is enabled in the config;
+    /// admin endpoints are only served if `admin_api` is provided.
+    pub fn new(
+        state: RequestProcessor,
+        port: u16,
+        admin_api: Option<AdminApi>,
+    ) -> TeeProofDataHandler {
         let middleware_factory = |method: Method| {
             axum::middleware::from_fn(move |req: Request, next: Next| async move {
                 let middleware = MetricsMiddleware::new(method);
@@ -38,23 +47,30 @@ impl TeeProofDataHandler {
             })
         };
 
-        let router = Router::new()
-            .route(
-                "/tee/proof_inputs",
-                post(TeeProofDataHandler::get_tee_proof_generation_data)
-                    .layer(middleware_factory(Method::GetTeeProofInputs)),
-            )
-            .route(
-                "/tee/submit_proofs/:l1_batch_number",
-                post(TeeProofDataHandler::submit_tee_proof)
-                    .layer(middleware_factory(Method::TeeSubmitProofs)),
-            )
-            .route(
-                "/tee/register_attestation",
-                post(TeeProofDataHandler::register_tee_attestation)
-                    .layer(middleware_factory(Method::TeeRegisterAttestation)),
-            )
-            .with_state(state)
+        let mut router = Router::new();
+        if state.config.tee_config.tee_support {
+            router = router
+                .route(
+                    "/tee/proof_inputs",
+                    post(TeeProofDataHandler::get_tee_proof_generation_data)
+                        .layer(middleware_factory(Method::GetTeeProofInputs)),
+                )
+                .route(
+                    "/tee/submit_proofs/:l1_batch_number",
+                    post(TeeProofDataHandler::submit_tee_proof)
+                        .layer(middleware_factory(Method::TeeSubmitProofs)),
+                )
+                .route(
+                    "/tee/register_attestation",
+                    post(TeeProofDataHandler::register_tee_attestation)
+                        .layer(middleware_factory(Method::TeeRegisterAttestation)),
+                );
+        }
+        let mut router = router.with_state(state);
+        if let Some(admin_api) = admin_api {
+            router = router.merge(admin_api.into_router());
+        }
+        let router = router
             .layer(tower_http::compression::CompressionLayer::new())
             .layer(tower_http::decompression::RequestDecompressionLayer::new().zstd(true));
 
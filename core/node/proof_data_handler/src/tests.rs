@@ -10,9 +10,12 @@ use zksync_config::configs::{ProofDataHandlerConfig, TeeConfig};
 use zksync_dal::{ConnectionPool, CoreDal};
 use zksync_object_store::MockObjectStore;
 use zksync_prover_interface::api::SubmitTeeProofRequest;
-use zksync_types::{tee_types::TeeType, L1BatchNumber, L2ChainId};
+use zksync_types::{
+    block::L1BatchHeader, secrets::APIKey, tee_types::TeeType, L1BatchNumber, L2ChainId,
+    ProtocolVersion, ProtocolVersionId,
+};
 
-use crate::{RequestProcessor, TeeProofDataHandler};
+use crate::{AdminApi, RequestProcessor, TeeProofDataHandler};
 
 #[tokio::test]
 async fn request_tee_proof_inputs() {
@@ -21,6 +24,7 @@ async fn request_tee_proof_inputs() {
     let config = ProofDataHandlerConfig {
         http_port: 1337,
         api_url: "".to_string(),
+        fallback_api_urls: vec![],
         batch_readiness_check_interval_in_secs: 1,
         proof_generation_timeout_in_secs: 10,
         retry_connection_interval_in_secs: 10,
@@ -39,7 +43,7 @@ async fn request_tee_proof_inputs() {
         L2ChainId::default(),
     );
 
-    let app = TeeProofDataHandler::new(processor, config.http_port);
+    let app = TeeProofDataHandler::new(processor, config.http_port, None);
 
     let test_cases = vec![
         (json!({ "tee_type": "sgx" }), StatusCode::NO_CONTENT),
@@ -90,6 +94,7 @@ async fn submit_tee_proof() {
     let config = ProofDataHandlerConfig {
         http_port: 1337,
         api_url: "".to_string(),
+        fallback_api_urls: vec![],
         batch_readiness_check_interval_in_secs: 1,
         proof_generation_timeout_in_secs: 10,
         retry_connection_interval_in_secs: 10,
@@ -108,7 +113,7 @@ async fn submit_tee_proof() {
         L2ChainId::default(),
     );
 
-    let app = TeeProofDataHandler::new(processor, config.http_port);
+    let app = TeeProofDataHandler::new(processor, config.http_port, None);
 
     // this should fail because we haven't saved the attestation for the pubkey yet
 
@@ -162,6 +167,110 @@ async fn submit_tee_proof() {
     assert_eq!(proof.pubkey.as_ref().unwrap(), &tee_proof_request.0.pubkey);
 }
 
+// Test /admin/skip_proving endpoint: authentication, state transitions and the audit trail
+#[tokio::test]
+async fn skip_proving() {
+    let batch_number = L1BatchNumber(1);
+    let db_conn_pool = ConnectionPool::test_pool().await;
+    let mut conn = db_conn_pool.connection().await.unwrap();
+    conn.protocol_versions_dal()
+        .save_protocol_version_with_tx(&ProtocolVersion::default())
+        .await
+        .unwrap();
+    let header = L1BatchHeader::new(
+        batch_number,
+        1,
+        Default::default(),
+        ProtocolVersionId::latest(),
+    );
+    conn.blocks_dal()
+        .insert_mock_l1_batch(&header)
+        .await
+        .unwrap();
+
+    let config = ProofDataHandlerConfig {
+        http_port: 1337,
+        api_url: "".to_string(),
+        fallback_api_urls: vec![],
+        batch_readiness_check_interval_in_secs: 1,
+        proof_generation_timeout_in_secs: 10,
+        retry_connection_interval_in_secs: 10,
+        tee_config: TeeConfig {
+            tee_support: false,
+            first_tee_processed_batch: L1BatchNumber(0),
+            tee_proof_generation_timeout_in_secs: 600,
+            tee_batch_permanently_ignored_timeout_in_hours: 10 * 24,
+        },
+    };
+    let processor = RequestProcessor::new(
+        MockObjectStore::arc(),
+        db_conn_pool.clone(),
+        config.clone(),
+        L2ChainId::default(),
+    );
+    let admin_api = AdminApi::new(db_conn_pool.clone(), APIKey::from("admin-key"));
+    let app = TeeProofDataHandler::new(processor, config.http_port, Some(admin_api));
+
+    let uri = format!("/admin/skip_proving/{}", batch_number.0);
+    let body = json!({ "requested_by": "operator", "reason": "prover outage" });
+
+    let response = send_skip_proving_request(&app.router, &uri, None, &body).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = send_skip_proving_request(&app.router, &uri, Some("wrong-key"), &body).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The batch doesn't have proof generation details yet.
+    let response = send_skip_proving_request(&app.router, &uri, Some("admin-key"), &body).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    conn.proof_generation_dal()
+        .insert_proof_generation_details(batch_number)
+        .await
+        .unwrap();
+    let response = send_skip_proving_request(
+        &app.router,
+        &uri,
+        Some("admin-key"),
+        &json!({ "requested_by": "operator", "reason": "" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_skip_proving_request(&app.router, &uri, Some("admin-key"), &body).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let unpicked_batch = conn
+        .proof_generation_dal()
+        .get_oldest_unpicked_batch()
+        .await
+        .unwrap();
+    assert_eq!(unpicked_batch, None);
+    let records = conn
+        .proof_generation_dal()
+        .get_proof_generation_skip_records(batch_number)
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].requested_by, "operator");
+    assert_eq!(records[0].reason, "prover outage");
+
+    // TEE endpoints are not served if TEE support is disabled.
+    let response = app
+        .router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/tee/proof_inputs")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{ "tee_type": "sgx" }"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 // Mock SQL db with information about the status of the TEE proof generation
 async fn mock_tee_batch_status(
     db_conn_pool: ConnectionPool<zksync_dal::Core>,
@@ -210,3 +319,23 @@ async fn send_submit_tee_proof_request(
         .await
         .unwrap()
 }
+
+async fn send_skip_proving_request(
+    app: &Router,
+    uri: &str,
+    api_key: Option<&str>,
+    body: &serde_json::Value,
+) -> Response {
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(http::header::CONTENT_TYPE, "application/json");
+    if let Some(api_key) = api_key {
+        request = request.header(http::header::AUTHORIZATION, format!("Bearer {api_key}"));
+    }
+    let req_body = Body::from(serde_json::to_vec(body).unwrap());
+    app.clone()
+        .oneshot(request.body(req_body).unwrap())
+        .await
+        .unwrap()
+}
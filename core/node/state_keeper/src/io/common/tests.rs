@@ -378,6 +378,7 @@ async fn store_pending_l2_blocks(
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
@@ -0,0 +1,166 @@
+//! Preloading of base system contracts for scheduled protocol upgrades.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_contracts::{BaseSystemContracts, BaseSystemContractsHashes};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::{ProtocolVersionId, H256};
+use zksync_vm_executor::storage::get_base_system_contracts_by_version_id;
+
+use crate::metrics::KEEPER_METRICS;
+
+/// Base system contracts of upcoming protocol versions, preloaded and verified by [`BaseSystemContractsPreloader`].
+///
+/// The state keeper takes contracts from here when it opens the first batch of the new protocol version,
+/// so that the switch doesn't depend on loading (potentially missing or malformed) bytecodes at that moment.
+#[derive(Debug, Clone, Default)]
+pub struct StagedBaseSystemContracts(Arc<RwLock<HashMap<ProtocolVersionId, BaseSystemContracts>>>);
+
+impl StagedBaseSystemContracts {
+    pub(crate) fn get(&self, version: ProtocolVersionId) -> Option<BaseSystemContracts> {
+        self.0.read().unwrap().get(&version).cloned()
+    }
+
+    fn contains(&self, version: ProtocolVersionId) -> bool {
+        self.0.read().unwrap().contains_key(&version)
+    }
+
+    fn stage(&self, version: ProtocolVersionId, contracts: BaseSystemContracts) {
+        let mut staged = self.0.write().unwrap();
+        staged.insert(version, contracts);
+        KEEPER_METRICS
+            .staged_base_system_contracts
+            .set(staged.len());
+    }
+
+    /// Removes contracts for versions not newer than `current_version`; they are no longer needed.
+    fn prune(&self, current_version: ProtocolVersionId) {
+        let mut staged = self.0.write().unwrap();
+        staged.retain(|&version, _| version > current_version);
+        KEEPER_METRICS
+            .staged_base_system_contracts
+            .set(staged.len());
+    }
+}
+
+/// Periodically checks for scheduled protocol upgrades and stages base system contracts for them.
+///
+/// Contracts are loaded from Postgres (i.e., factory deps of the upgrade transaction received from L1),
+/// and checked against the base system contract hashes of the scheduled protocol version before staging.
+#[derive(Debug)]
+pub struct BaseSystemContractsPreloader {
+    pool: ConnectionPool<Core>,
+    staged: StagedBaseSystemContracts,
+    poll_interval: Duration,
+}
+
+impl BaseSystemContractsPreloader {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn new(pool: ConnectionPool<Core>, staged: StagedBaseSystemContracts) -> Self {
+        Self {
+            pool,
+            staged,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Stages contracts for all upcoming protocol versions that are not staged yet.
+    pub(crate) async fn preload(&self) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("state_keeper").await?;
+        let Some(current_version) = storage.protocol_versions_dal().last_used_version_id().await
+        else {
+            return Ok(()); // No sealed batches yet
+        };
+        self.staged.prune(current_version);
+
+        let upcoming_versions = storage
+            .protocol_versions_dal()
+            .versions_after(current_version)
+            .await?;
+        for (version, activation_timestamp) in upcoming_versions {
+            if self.staged.contains(version) {
+                continue;
+            }
+
+            let expected_hashes = storage
+                .protocol_versions_dal()
+                .get_protocol_version_with_latest_patch(version)
+                .await?
+                .with_context(|| format!("protocol version {version:?} disappeared"))?
+                .base_system_contracts_hashes;
+            let contracts = get_base_system_contracts_by_version_id(&mut storage, version)
+                .await
+                .and_then(|contracts| {
+                    let contracts = contracts.context("no base system contracts persisted")?;
+                    verify_base_system_contracts(&contracts, &expected_hashes)?;
+                    Ok(contracts)
+                });
+            match contracts {
+                Ok(contracts) => {
+                    tracing::info!(
+                        "Staged base system contracts {expected_hashes:?} for protocol version {version:?} \
+                         activated at timestamp {activation_timestamp}"
+                    );
+                    self.staged.stage(version, contracts);
+                }
+                Err(err) => {
+                    // Not fatal: upgrade data may be fixed before activation. Still, this needs attention
+                    // since the state keeper will fail to open the first batch of the new version.
+                    tracing::error!(
+                        "Failed preloading base system contracts for protocol version {version:?} \
+                         activated at timestamp {activation_timestamp}: {err:#}"
+                    );
+                    KEEPER_METRICS.base_system_contracts_preload_failures.inc();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            self.preload().await?;
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop signal received, base system contracts preloader is shutting down");
+        Ok(())
+    }
+}
+
+/// Checks that base system contracts correspond to the hashes of a protocol version. Contracts are looked up
+/// by bytecode hash, so the check is on the set of contracts: e.g., the EVM emulator must be present
+/// iff the protocol version enables it.
+pub(crate) fn verify_base_system_contracts(
+    contracts: &BaseSystemContracts,
+    expected: &BaseSystemContractsHashes,
+) -> anyhow::Result<()> {
+    let actual = contracts.hashes();
+    verify_hash("bootloader", actual.bootloader, expected.bootloader)?;
+    verify_hash("default AA", actual.default_aa, expected.default_aa)?;
+    match (actual.evm_emulator, expected.evm_emulator) {
+        (Some(actual_hash), Some(expected_hash)) => {
+            verify_hash("EVM emulator", actual_hash, expected_hash)
+        }
+        (None, None) => Ok(()),
+        (Some(_), None) => anyhow::bail!("EVM emulator is not expected for the protocol version"),
+        (None, Some(_)) => anyhow::bail!("EVM emulator is missing"),
+    }
+}
+
+fn verify_hash(name: &str, actual_hash: H256, expected_hash: H256) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        actual_hash == expected_hash,
+        "{name} hash {actual_hash:?} doesn't match hash {expected_hash:?} in the protocol version"
+    );
+    Ok(())
+}
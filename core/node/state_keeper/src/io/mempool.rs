@@ -26,7 +26,7 @@ use crate::{
     io::{
         common::{load_pending_batch, poll_iters, IoCursor},
         seal_logic::l2_block_seal_subtasks::L2BlockSealProcess,
-        L1BatchParams, L2BlockParams, PendingBatchData, StateKeeperIO,
+        L1BatchParams, L2BlockParams, PendingBatchData, StagedBaseSystemContracts, StateKeeperIO,
     },
     mempool_actor::l2_tx_filter,
     metrics::{L2BlockSealReason, AGGREGATION_METRICS, KEEPER_METRICS},
@@ -59,6 +59,7 @@ pub struct MempoolIO {
     chain_id: L2ChainId,
     l2_da_validator_address: Option<Address>,
     pubdata_type: PubdataType,
+    staged_base_system_contracts: StagedBaseSystemContracts,
 }
 
 impl IoSealCriteria for MempoolIO {
@@ -395,6 +396,9 @@ impl StateKeeperIO for MempoolIO {
         protocol_version: ProtocolVersionId,
         _cursor: &IoCursor,
     ) -> anyhow::Result<BaseSystemContracts> {
+        if let Some(contracts) = self.staged_base_system_contracts.get(protocol_version) {
+            return Ok(contracts);
+        }
         get_base_system_contracts_by_version_id(
             &mut self.pool.connection_tagged("state_keeper").await?,
             protocol_version,
@@ -525,9 +529,16 @@ impl MempoolIO {
             chain_id,
             l2_da_validator_address,
             pubdata_type,
+            staged_base_system_contracts: StagedBaseSystemContracts::default(),
         })
     }
 
+    /// Returns a handle to base system contracts staged for upcoming protocol versions. Contracts are staged
+    /// by [`BaseSystemContractsPreloader`](crate::BaseSystemContractsPreloader) created with this handle.
+    pub fn staged_base_system_contracts(&self) -> StagedBaseSystemContracts {
+        self.staged_base_system_contracts.clone()
+    }
+
     fn pubdata_params(&self, protocol_version: ProtocolVersionId) -> anyhow::Result<PubdataParams> {
         let pubdata_params = match (
             protocol_version.is_pre_gateway(),
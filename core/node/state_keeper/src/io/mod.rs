@@ -12,12 +12,14 @@ use zksync_vm_executor::storage::l1_batch_params;
 
 pub use self::{
     common::IoCursor,
+    contracts_preloader::{BaseSystemContractsPreloader, StagedBaseSystemContracts},
     output_handler::{OutputHandler, StateKeeperOutputHandler},
     persistence::{L2BlockSealerTask, StateKeeperPersistence, TreeWritesPersistence},
 };
 use super::seal_criteria::{IoSealCriteria, UnexecutableReason};
 
 pub mod common;
+pub(crate) mod contracts_preloader;
 pub(crate) mod mempool;
 mod output_handler;
 mod persistence;
@@ -31,6 +31,10 @@ pub struct StateKeeperPersistence {
     l2_legacy_shared_bridge_addr: Option<Address>,
     pre_insert_txs: bool,
     insert_protective_reads: bool,
+    insert_storage_accesses: bool,
+    receipts_cache_l2_blocks: u32,
+    failed_call_traces_only: bool,
+    compress_call_traces: bool,
     commands_sender: mpsc::Sender<Completable<L2BlockSealCommand>>,
     latest_completion_receiver: Option<oneshot::Receiver<()>>,
     // If true, `submit_l2_block()` will wait for the operation to complete.
@@ -94,6 +98,10 @@ impl StateKeeperPersistence {
             l2_legacy_shared_bridge_addr,
             pre_insert_txs: false,
             insert_protective_reads: true,
+            insert_storage_accesses: false,
+            receipts_cache_l2_blocks: 0,
+            failed_call_traces_only: false,
+            compress_call_traces: false,
             commands_sender,
             latest_completion_receiver: None,
             is_sync,
@@ -106,6 +114,32 @@ impl StateKeeperPersistence {
         self
     }
 
+    /// Enables persisting storage slots accessed by each executed transaction to Postgres, so that
+    /// they can be queried via `debug_getTransactionStorageAccesses`.
+    pub fn with_storage_accesses(mut self) -> Self {
+        self.insert_storage_accesses = true;
+        self
+    }
+
+    /// Enables caching receipts for the specified number of latest L2 blocks when sealing L1 batches.
+    pub fn with_receipts_cache(mut self, retained_l2_blocks: u32) -> Self {
+        self.receipts_cache_l2_blocks = retained_l2_blocks;
+        self
+    }
+
+    /// Makes the persistence discard call traces of successfully executed transactions.
+    pub fn with_failed_call_traces_only(mut self) -> Self {
+        self.failed_call_traces_only = true;
+        self
+    }
+
+    /// Makes the persistence compress call traces with zstd. Compressed traces cannot be read
+    /// by server versions predating compression support.
+    pub fn with_compressed_call_traces(mut self) -> Self {
+        self.compress_call_traces = true;
+        self
+    }
+
     /// Disables inserting protective reads to Postgres when persisting an L1 batch. This is only sound
     /// if the node won't *ever* run a full Merkle tree (such a tree requires protective reads to generate witness inputs).
     pub fn without_protective_reads(mut self) -> Self {
@@ -188,8 +222,18 @@ impl StateKeeperOutputHandler for StateKeeperPersistence {
     }
 
     async fn handle_l2_block(&mut self, updates_manager: &UpdatesManager) -> anyhow::Result<()> {
-        let command = updates_manager
-            .seal_l2_block_command(self.l2_legacy_shared_bridge_addr, self.pre_insert_txs);
+        let mut command = updates_manager.seal_l2_block_command(
+            self.l2_legacy_shared_bridge_addr,
+            self.pre_insert_txs,
+            self.insert_storage_accesses,
+        );
+        if self.insert_storage_accesses {
+            command.l2_block.collect_storage_accesses();
+        }
+        if self.failed_call_traces_only {
+            command.l2_block.discard_successful_call_traces();
+        }
+        command.compress_call_traces = self.compress_call_traces;
         self.submit_l2_block(command).await;
         Ok(())
     }
@@ -207,6 +251,7 @@ impl StateKeeperOutputHandler for StateKeeperPersistence {
                 self.pool.clone(),
                 self.l2_legacy_shared_bridge_addr,
                 self.insert_protective_reads,
+                self.receipts_cache_l2_blocks,
             )
             .await
             .with_context(|| format!("cannot persist L1 batch #{batch_number}"))?;
@@ -617,7 +662,8 @@ mod tests {
 
         // The first command should be successfully submitted immediately.
         let mut updates_manager = create_updates_manager();
-        let seal_command = updates_manager.seal_l2_block_command(Some(Address::default()), false);
+        let seal_command =
+            updates_manager.seal_l2_block_command(Some(Address::default()), false, false);
         persistence.submit_l2_block(seal_command).await;
 
         // The second command should lead to blocking
@@ -626,7 +672,8 @@ mod tests {
             virtual_blocks: 1,
         });
         updates_manager.push_l2_block();
-        let seal_command = updates_manager.seal_l2_block_command(Some(Address::default()), false);
+        let seal_command =
+            updates_manager.seal_l2_block_command(Some(Address::default()), false, false);
         {
             let submit_future = persistence.submit_l2_block(seal_command);
             futures::pin_mut!(submit_future);
@@ -655,7 +702,8 @@ mod tests {
             virtual_blocks: 1,
         });
         updates_manager.push_l2_block();
-        let seal_command = updates_manager.seal_l2_block_command(Some(Address::default()), false);
+        let seal_command =
+            updates_manager.seal_l2_block_command(Some(Address::default()), false, false);
         persistence.submit_l2_block(seal_command).await;
         let command = sealer.commands_receiver.recv().await.unwrap();
         command.completion_sender.send(()).unwrap();
@@ -674,7 +722,7 @@ mod tests {
         let mut updates_manager = create_updates_manager();
         for i in 1..=5 {
             let seal_command =
-                updates_manager.seal_l2_block_command(Some(Address::default()), false);
+                updates_manager.seal_l2_block_command(Some(Address::default()), false, false);
             updates_manager.set_next_l2_block_params(L2BlockParams {
                 timestamp: i,
                 virtual_blocks: 1,
@@ -213,8 +213,16 @@ impl L2BlockSealSubtask for MarkTransactionsInL2BlockSubtask {
                 command.base_fee_per_gas.into(),
                 command.l2_block.protocol_version,
                 command.pre_insert_txs,
+                command.compress_call_traces,
             )
             .await?;
+        if command.insert_storage_accesses {
+            // Must be performed after transactions are marked, since storage accesses reference transactions.
+            connection
+                .transactions_dal()
+                .insert_storage_accesses(&command.l2_block.executed_transactions)
+                .await?;
+        }
 
         progress.observe(command.l2_block.executed_transactions.len());
         Ok(())
@@ -498,6 +506,7 @@ mod tests {
             refunded_gas: 0,
             call_traces: Vec::new(),
             revert_reason: None,
+            storage_accesses: None,
         }];
         let events = vec![VmEvent {
             location: (L1BatchNumber(1), 0),
@@ -544,6 +553,7 @@ mod tests {
                 number: L2BlockNumber(1),
                 prev_block_hash: Default::default(),
                 virtual_blocks: Default::default(),
+                tx_storage_logs_ends: vec![],
                 protocol_version: ProtocolVersionId::latest(),
             },
             first_tx_index: 0,
@@ -554,6 +564,8 @@ mod tests {
             protocol_version: Some(ProtocolVersionId::latest()),
             l2_legacy_shared_bridge_addr: Default::default(),
             pre_insert_txs: false,
+            insert_storage_accesses: false,
+            compress_call_traces: false,
             pubdata_params: PubdataParams::default(),
         };
 
@@ -45,6 +45,7 @@ impl UpdatesManager {
         pool: ConnectionPool<Core>,
         l2_legacy_shared_bridge_addr: Option<Address>,
         insert_protective_reads: bool,
+        receipts_cache_l2_blocks: u32,
     ) -> anyhow::Result<()> {
         let started_at = Instant::now();
         let finished_batch = self
@@ -58,6 +59,7 @@ impl UpdatesManager {
         let l2_block_command = self.seal_l2_block_command(
             l2_legacy_shared_bridge_addr,
             false, // fictive L2 blocks don't have txs, so it's fine to pass `false` here.
+            false,
         );
 
         let mut connection = pool.connection_tagged("state_keeper").await?;
@@ -183,6 +185,16 @@ impl UpdatesManager {
             progress.observe(protective_reads.len());
         }
 
+        if receipts_cache_l2_blocks > 0 {
+            // Receipts contain L1 batch info, so they can only be cached once the batch is sealed.
+            let progress = L1_BATCH_METRICS.start(L1BatchSealStage::CacheReceipts);
+            transaction
+                .transactions_web3_dal()
+                .cache_l1_batch_receipts(self.l1_batch.number, receipts_cache_l2_blocks)
+                .await?;
+            progress.observe(None);
+        }
+
         let progress = L1_BATCH_METRICS.start(L1BatchSealStage::FilterWrittenSlots);
         let (initial_writes, all_writes_len): (Vec<_>, usize) =
             if let Some(state_diffs) = &finished_batch.state_diffs {
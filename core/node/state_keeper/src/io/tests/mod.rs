@@ -26,9 +26,13 @@ use zksync_types::{
 
 use self::tester::Tester;
 use crate::{
-    io::{seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, StateKeeperIO},
+    io::{
+        contracts_preloader::verify_base_system_contracts,
+        seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, BaseSystemContractsPreloader,
+        IoCursor, StateKeeperIO,
+    },
     mempool_actor::l2_tx_filter,
-    testonly::BASE_SYSTEM_CONTRACTS,
+    testonly::{test_batch_executor::random_upgrade_tx, BASE_SYSTEM_CONTRACTS},
     tests::{create_execution_result, create_transaction, seconds_since_epoch, Query},
     updates::{L2BlockSealCommand, L2BlockUpdates, UpdatesManager},
     StateKeeperOutputHandler, StateKeeperPersistence,
@@ -248,6 +252,8 @@ fn create_block_seal_command(
         protocol_version: Some(ProtocolVersionId::latest()),
         l2_legacy_shared_bridge_addr: Some(Address::default()),
         pre_insert_txs: false,
+        insert_storage_accesses: false,
+        compress_call_traces: false,
         pubdata_params: PubdataParams::default(),
     }
 }
@@ -398,6 +404,85 @@ async fn processing_events_when_sealing_l2_block() {
     }
 }
 
+#[tokio::test]
+async fn preloading_base_system_contracts_for_upgrade() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    tester.genesis(&pool).await;
+    let (io, _) = tester.create_test_mempool_io(pool.clone()).await;
+    let preloader =
+        BaseSystemContractsPreloader::new(pool.clone(), io.staged_base_system_contracts());
+
+    // Base system contracts are only delivered with the upgrade transaction.
+    let bootloader_code = vec![1_u8; 32];
+    let default_aa_code = vec![2_u8; 32];
+    let expected_hashes = BaseSystemContractsHashes {
+        bootloader: BytecodeHash::for_bytecode(&bootloader_code).value(),
+        default_aa: BytecodeHash::for_bytecode(&default_aa_code).value(),
+        evm_emulator: None,
+    };
+    let mut upgrade_tx = random_upgrade_tx(1);
+    upgrade_tx.common_data.protocol_version = ProtocolVersionId::next();
+    upgrade_tx.execute.factory_deps = vec![bootloader_code, default_aa_code];
+    let next_version = ProtocolVersion {
+        version: ProtocolSemanticVersion {
+            minor: ProtocolVersionId::next(),
+            patch: 0.into(),
+        },
+        timestamp: seconds_since_epoch() + 3_600,
+        base_system_contracts_hashes: expected_hashes,
+        tx: Some(upgrade_tx),
+        ..ProtocolVersion::default()
+    };
+    let mut storage = pool.connection().await.unwrap();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version_with_tx(&next_version)
+        .await
+        .unwrap();
+
+    preloader.preload().await.unwrap();
+    let staged = io
+        .staged_base_system_contracts()
+        .get(ProtocolVersionId::next())
+        .expect("contracts are not staged");
+    assert_eq!(staged.hashes(), expected_hashes);
+    // Contracts for the current version are not staged.
+    assert!(io
+        .staged_base_system_contracts()
+        .get(ProtocolVersionId::latest())
+        .is_none());
+
+    let cursor = IoCursor::new(&mut storage).await.unwrap();
+    let contracts = io
+        .load_base_system_contracts(ProtocolVersionId::next(), &cursor)
+        .await
+        .unwrap();
+    assert_eq!(contracts, staged);
+}
+
+#[test]
+fn verifying_base_system_contracts() {
+    let mut contracts = BASE_SYSTEM_CONTRACTS.clone();
+    contracts.evm_emulator = None;
+    let expected_hashes = contracts.hashes();
+    verify_base_system_contracts(&contracts, &expected_hashes).unwrap();
+
+    let tampered_hashes = BaseSystemContractsHashes {
+        bootloader: H256::repeat_byte(1),
+        ..expected_hashes
+    };
+    let err = verify_base_system_contracts(&contracts, &tampered_hashes).unwrap_err();
+    assert!(err.to_string().contains("bootloader"), "{err}");
+
+    let tampered_hashes = BaseSystemContractsHashes {
+        evm_emulator: Some(H256::repeat_byte(1)),
+        ..expected_hashes
+    };
+    let err = verify_base_system_contracts(&contracts, &tampered_hashes).unwrap_err();
+    assert!(err.to_string().contains("EVM emulator"), "{err}");
+}
+
 fn bytecode_publishing_events(
     l1_batch_number: L1BatchNumber,
     tx_index: u32,
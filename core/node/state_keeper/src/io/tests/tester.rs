@@ -83,6 +83,7 @@ impl Tester {
             internal_pubdata_pricing_multiplier: 1.0,
             max_blob_base_fee: None,
             settlement_mode: Default::default(),
+            gateway_settlement_supported: false,
         };
 
         GasAdjuster::new(
@@ -218,6 +219,7 @@ impl Tester {
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await
             .unwrap();
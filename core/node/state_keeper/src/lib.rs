@@ -1,7 +1,8 @@
 pub use self::{
     io::{
-        mempool::MempoolIO, L2BlockParams, L2BlockSealerTask, OutputHandler, StateKeeperIO,
-        StateKeeperOutputHandler, StateKeeperPersistence, TreeWritesPersistence,
+        mempool::MempoolIO, BaseSystemContractsPreloader, L2BlockParams, L2BlockSealerTask,
+        OutputHandler, StateKeeperIO, StateKeeperOutputHandler, StateKeeperPersistence,
+        TreeWritesPersistence,
     },
     keeper::ZkSyncStateKeeper,
     mempool_actor::MempoolFetcher,
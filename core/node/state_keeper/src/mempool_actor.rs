@@ -5,13 +5,13 @@ use anyhow::Context as _;
 use tokio::sync::mpsc;
 use tokio::sync::watch;
 use zksync_config::configs::chain::MempoolConfig;
-use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_dal::{address_policies_dal::AddressPolicy, Connection, ConnectionPool, Core, CoreDal};
 use zksync_mempool::L2TxFilter;
 use zksync_multivm::utils::derive_base_fee_and_gas_per_pubdata;
 use zksync_node_fee_model::BatchFeeModelInputProvider;
 #[cfg(test)]
 use zksync_types::H256;
-use zksync_types::{get_nonce_key, vm::VmVersion, Address, Nonce, Transaction};
+use zksync_types::{get_nonce_key, vm::VmVersion, Address, L1BlockNumber, Nonce, Transaction};
 
 use super::{metrics::KEEPER_METRICS, types::MempoolGuard};
 use crate::v26_utils::find_unsafe_deposit;
@@ -42,6 +42,8 @@ pub struct MempoolFetcher {
     stuck_tx_timeout: Option<Duration>,
     skip_unsafe_deposit_checks: bool,
     l1_to_l2_txs_paused: bool,
+    /// Last L1 block for which address policy changes were applied to the mempool.
+    address_policies_cursor: Option<L1BlockNumber>,
     #[cfg(test)]
     transaction_hashes_sender: mpsc::UnboundedSender<Vec<H256>>,
 }
@@ -61,6 +63,7 @@ impl MempoolFetcher {
             stuck_tx_timeout: config.remove_stuck_txs.then(|| config.stuck_tx_timeout()),
             skip_unsafe_deposit_checks: config.skip_unsafe_deposit_checks,
             l1_to_l2_txs_paused: config.l1_to_l2_txs_paused,
+            address_policies_cursor: None,
             #[cfg(test)]
             transaction_hashes_sender: mpsc::unbounded_channel().0,
         }
@@ -87,6 +90,28 @@ impl MempoolFetcher {
             let latency = KEEPER_METRICS.mempool_sync.start();
             let mut connection = self.pool.connection_tagged("state_keeper").await?;
             let mut storage_transaction = connection.start_transaction().await?;
+            let address_policy_changes = storage_transaction
+                .address_policies_dal()
+                .get_address_policy_changes(self.address_policies_cursor)
+                .await
+                .context("failed getting address policy changes")?;
+            let blacklisted_accounts: Vec<_> = address_policy_changes
+                .iter()
+                .filter(|change| change.policy == Some(AddressPolicy::Blacklisted))
+                .map(|change| change.address)
+                .collect();
+            if !blacklisted_accounts.is_empty() {
+                // Removed before syncing the mempool, so that transactions of blacklisted accounts are not loaded.
+                let removed_txs = storage_transaction
+                    .transactions_dal()
+                    .remove_pending_txs_for_initiators(&blacklisted_accounts)
+                    .await
+                    .context("failed removing transactions of blacklisted accounts")?;
+                tracing::info!(
+                    "Removed {removed_txs} pending transactions of {} blacklisted accounts",
+                    blacklisted_accounts.len()
+                );
+            }
             let mempool_info = self.mempool.get_mempool_info();
 
             KEEPER_METRICS
@@ -194,6 +219,11 @@ impl MempoolFetcher {
                 self.transaction_hashes_sender.send(transaction_hashes).ok();
             }
             let all_transactions_loaded = transactions.len() < self.sync_batch_size;
+            self.mempool
+                .apply_address_policy_changes(&address_policy_changes);
+            if let Some(last_change) = address_policy_changes.last() {
+                self.address_policies_cursor = Some(last_change.l1_block_number);
+            }
             self.mempool.insert(transactions_with_constraints, nonces);
             latency.observe();
 
@@ -464,4 +494,79 @@ mod tests {
         stop_sender.send_replace(true);
         fetcher_task.await.unwrap().expect("fetcher errored");
     }
+
+    #[tokio::test]
+    async fn removing_transactions_of_blacklisted_accounts() {
+        let pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+        let mut storage = pool.connection().await.unwrap();
+        insert_genesis_batch(&mut storage, &GenesisParams::mock())
+            .await
+            .unwrap();
+        drop(storage);
+
+        let mempool = MempoolGuard::new(PriorityOpId(0), 100);
+        let fee_params_provider: Arc<dyn BatchFeeModelInputProvider> =
+            Arc::new(MockBatchFeeParamsProvider::default());
+        let fee_input = fee_params_provider.get_batch_fee_input().await.unwrap();
+        let (base_fee, gas_per_pubdata) =
+            derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+
+        let mut fetcher = MempoolFetcher::new(
+            mempool.clone(),
+            fee_params_provider,
+            &TEST_MEMPOOL_CONFIG,
+            pool.clone(),
+        );
+        let (tx_hashes_sender, mut tx_hashes_receiver) = mpsc::unbounded_channel();
+        fetcher.transaction_hashes_sender = tx_hashes_sender;
+        let (stop_sender, stop_receiver) = watch::channel(false);
+        let fetcher_task = tokio::spawn(fetcher.run(stop_receiver));
+
+        let transaction = create_l2_transaction(base_fee, gas_per_pubdata);
+        let transaction_hash = transaction.hash();
+        let initiator = transaction.initiator_account();
+        let mut storage = pool.connection().await.unwrap();
+        storage
+            .transactions_dal()
+            .insert_transaction_l2(
+                &transaction,
+                TransactionExecutionMetrics::default(),
+                ValidationTraces::default(),
+            )
+            .await
+            .unwrap();
+        drop(storage);
+
+        let tx_hashes = wait_for_new_transactions(&mut tx_hashes_receiver).await;
+        assert_eq!(tx_hashes, [transaction_hash]);
+        assert_eq!(mempool.stats().l2_transaction_count, 1);
+
+        let mut storage = pool.connection().await.unwrap();
+        storage
+            .address_policies_dal()
+            .set_address_policy(
+                initiator,
+                Some(AddressPolicy::Blacklisted),
+                L1BlockNumber(1),
+            )
+            .await
+            .unwrap();
+        drop(storage);
+
+        while mempool.stats().l2_transaction_count > 0 {
+            tokio::time::sleep(TEST_MEMPOOL_CONFIG.sync_interval()).await;
+        }
+        // The transaction must be removed from the storage as well.
+        let mut storage = pool.connection().await.unwrap();
+        let stored_tx = storage
+            .transactions_dal()
+            .get_storage_tx_by_hash(transaction_hash)
+            .await
+            .unwrap();
+        assert!(stored_tx.is_none());
+        drop(storage);
+
+        stop_sender.send_replace(true);
+        fetcher_task.await.unwrap().expect("fetcher errored");
+    }
 }
@@ -100,6 +100,10 @@ pub struct StateKeeperMetrics {
     /// The time it takes to wait for new L2 block parameters
     #[metrics(buckets = Buckets::LATENCIES)]
     pub wait_for_l2_block_params: Histogram<Duration>,
+    /// Number of upcoming protocol versions with preloaded base system contracts.
+    pub staged_base_system_contracts: Gauge<usize>,
+    /// Number of failed attempts to preload base system contracts for an upcoming protocol version.
+    pub base_system_contracts_preload_failures: Counter,
 }
 
 fn vm_revert_reason_as_metric_label(reason: &VmRevertReason) -> &'static str {
@@ -258,6 +262,7 @@ pub(super) enum L1BatchSealStage {
     SetL1BatchNumberForL2Blocks,
     MarkTxsAsExecutedInL1Batch,
     InsertProtectiveReads,
+    CacheReceipts,
     FilterWrittenSlots,
     InsertInitialWrites,
     CommitL1Batch,
@@ -1,9 +1,12 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{Arc, Mutex},
 };
 
-use zksync_dal::{Connection, Core, CoreDal};
+use zksync_dal::{
+    address_policies_dal::{AddressPolicy, AddressPolicyChange},
+    Connection, Core, CoreDal,
+};
 use zksync_mempool::{L2TxFilter, MempoolInfo, MempoolStore};
 use zksync_types::{Address, Nonce, PriorityOpId, Transaction, TransactionTimeRangeConstraint};
 
@@ -52,6 +55,21 @@ impl MempoolGuard {
         );
     }
 
+    /// Applies address policy changes: deprioritizes transactions of greylisted accounts and removes transactions
+    /// of blacklisted accounts.
+    pub fn apply_address_policy_changes(&mut self, changes: &[AddressPolicyChange]) {
+        let mut mempool = self.0.lock().expect("failed to acquire mempool lock");
+        let mut blacklisted_accounts = HashSet::new();
+        for change in changes {
+            let deprioritized = change.policy == Some(AddressPolicy::Greylisted);
+            mempool.set_account_deprioritized(change.address, deprioritized);
+            if change.policy == Some(AddressPolicy::Blacklisted) {
+                blacklisted_accounts.insert(change.address);
+            }
+        }
+        mempool.remove_accounts(&blacklisted_accounts);
+    }
+
     pub fn has_next(&self, filter: &L2TxFilter) -> bool {
         self.0
             .lock()
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use zksync_multivm::{
     interface::{
-        Call, ExecutionResult, L2BlockEnv, TransactionExecutionResult, TxExecutionStatus, VmEvent,
-        VmExecutionMetrics, VmExecutionResultAndLogs,
+        Call, ExecutionResult, L2BlockEnv, StorageAccessSet, TransactionExecutionResult,
+        TxExecutionStatus, VmEvent, VmExecutionMetrics, VmExecutionResultAndLogs,
     },
     vm_latest::TransactionVmExt,
 };
@@ -33,6 +33,8 @@ pub struct L2BlockUpdates {
     pub prev_block_hash: H256,
     pub virtual_blocks: u32,
     pub protocol_version: ProtocolVersionId,
+    /// End indices of storage logs produced by each executed transaction in `storage_logs`.
+    pub(crate) tx_storage_logs_ends: Vec<usize>,
 }
 
 impl L2BlockUpdates {
@@ -59,6 +61,7 @@ impl L2BlockUpdates {
             prev_block_hash,
             virtual_blocks,
             protocol_version,
+            tx_storage_logs_ends: vec![],
         }
     }
 
@@ -147,6 +150,7 @@ impl L2BlockUpdates {
             .extend(tx_execution_result.logs.system_l2_to_l1_logs);
         self.storage_logs
             .extend(tx_execution_result.logs.storage_logs);
+        self.tx_storage_logs_ends.push(self.storage_logs.len());
         if tx.is_l1() {
             self.l1_tx_count += 1;
         }
@@ -159,6 +163,7 @@ impl L2BlockUpdates {
             refunded_gas: gas_refunded,
             call_traces,
             revert_reason,
+            storage_accesses: None,
         });
     }
 
@@ -179,14 +184,40 @@ impl L2BlockUpdates {
             max_virtual_blocks_to_create: self.virtual_blocks,
         }
     }
+
+    /// Computes storage slots accessed by each executed transaction. This is only necessary if storage accesses
+    /// are persisted, so it's not performed during transaction execution.
+    pub(crate) fn collect_storage_accesses(&mut self) {
+        let mut start = 0;
+        for (tx, &end) in self
+            .executed_transactions
+            .iter_mut()
+            .zip(&self.tx_storage_logs_ends)
+        {
+            let logs = &self.storage_logs[start..end];
+            tx.storage_accesses = Some(StorageAccessSet::from_storage_logs(logs));
+            start = end;
+        }
+    }
+
+    /// Discards call traces of successfully executed transactions, so that they are not persisted.
+    pub(crate) fn discard_successful_call_traces(&mut self) {
+        for tx in &mut self.executed_transactions {
+            if matches!(tx.execution_status, TxExecutionStatus::Success) {
+                tx.call_traces.clear();
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use zksync_multivm::vm_latest::TransactionVmExt;
 
     use super::*;
-    use crate::tests::{create_execution_result, create_transaction};
+    use crate::tests::{create_execution_result, create_transaction, Query};
 
     #[test]
     fn apply_empty_l2_tx() {
@@ -219,5 +250,56 @@ mod tests {
         assert_eq!(accumulator.txs_encoding_size, bootloader_encoding_size);
         assert_eq!(accumulator.payload_encoding_size, payload_encoding_size);
         assert_eq!(accumulator.l1_tx_count, 0);
+        assert_eq!(accumulator.executed_transactions[0].storage_accesses, None);
+    }
+
+    #[test]
+    fn collecting_storage_accesses() {
+        let mut accumulator = L2BlockUpdates::new(
+            0,
+            L2BlockNumber(0),
+            H256::random(),
+            0,
+            ProtocolVersionId::latest(),
+        );
+        let queries = [
+            [
+                (1.into(), Query::Read(0.into())),
+                (2.into(), Query::InitialWrite(1.into())),
+            ],
+            [
+                (2.into(), Query::Read(1.into())),
+                (3.into(), Query::RepeatedWrite(1.into(), 2.into())),
+            ],
+        ];
+        for (i, tx_queries) in queries.into_iter().enumerate() {
+            accumulator.extend_from_executed_transaction(
+                create_transaction(10, 100 + i as u64),
+                create_execution_result(tx_queries),
+                VmExecutionMetrics::default(),
+                vec![],
+            );
+        }
+        // Logs of the fictive transaction must not be attributed to executed transactions.
+        accumulator.extend_from_fictive_transaction(
+            create_execution_result([(4.into(), Query::InitialWrite(1.into()))]),
+            VmExecutionMetrics::default(),
+        );
+        assert_eq!(accumulator.storage_logs.len(), 5);
+
+        accumulator.collect_storage_accesses();
+        let accesses: Vec<_> = accumulator
+            .executed_transactions
+            .iter()
+            .map(|tx| tx.storage_accesses.clone().unwrap())
+            .collect();
+        let to_keys = |logs: &[StorageLogWithPreviousValue]| -> BTreeSet<_> {
+            logs.iter().map(|log| log.log.key).collect()
+        };
+        let logs = &accumulator.storage_logs;
+        assert_eq!(accesses[0].reads, to_keys(&logs[0..1]));
+        assert_eq!(accesses[0].writes, to_keys(&logs[1..2]));
+        assert_eq!(accesses[1].reads, to_keys(&logs[2..3]));
+        assert_eq!(accesses[1].writes, to_keys(&logs[3..4]));
     }
 }
@@ -112,6 +112,7 @@ impl UpdatesManager {
         &self,
         l2_legacy_shared_bridge_addr: Option<Address>,
         pre_insert_txs: bool,
+        insert_storage_accesses: bool,
     ) -> L2BlockSealCommand {
         L2BlockSealCommand {
             l1_batch_number: self.l1_batch.number,
@@ -124,6 +125,8 @@ impl UpdatesManager {
             protocol_version: Some(self.protocol_version),
             l2_legacy_shared_bridge_addr,
             pre_insert_txs,
+            insert_storage_accesses,
+            compress_call_traces: false,
             pubdata_params: self.pubdata_params,
         }
     }
@@ -249,6 +252,10 @@ pub struct L2BlockSealCommand {
     /// Should be set to `true` for EN's IO as EN doesn't store transactions in DB
     /// before they are included into L2 blocks.
     pub pre_insert_txs: bool,
+    /// Whether storage slots accessed by each transaction should be persisted to DB.
+    pub insert_storage_accesses: bool,
+    /// Whether call traces should be compressed when persisted to DB.
+    pub compress_call_traces: bool,
     pub pubdata_params: PubdataParams,
 }
 
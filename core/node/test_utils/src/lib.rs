@@ -212,6 +212,7 @@ pub fn execute_l2_transaction(transaction: L2Tx) -> TransactionExecutionResult {
         refunded_gas: 0,
         call_traces: vec![],
         revert_reason: None,
+        storage_accesses: None,
     }
 }
 
@@ -300,6 +300,7 @@ async fn store_l1_batches(
                 1.into(),
                 ProtocolVersionId::latest(),
                 false,
+                false,
             )
             .await?;
 
@@ -158,6 +158,7 @@ where
             .provider
             .estimate_fee(l2_tx.into(), None)
             .await
+            .map(|estimate| estimate.fee)
             .map_err(Into::into)
     }
 }
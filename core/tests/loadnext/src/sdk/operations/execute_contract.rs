@@ -157,6 +157,7 @@ where
             .provider
             .estimate_fee(execute.into(), None)
             .await
+            .map(|estimate| estimate.fee)
             .map_err(Into::into)
     }
 }
@@ -9,7 +9,10 @@ use clap::Parser;
 use shivini::{ProverContext, ProverContextConfig};
 use tokio_util::sync::CancellationToken;
 use zksync_circuit_prover::{FinalizationHintsCache, SetupDataCache, PROVER_BINARY_METRICS};
-use zksync_circuit_prover_service::job_runner::{circuit_prover_runner, WvgRunnerBuilder};
+use zksync_circuit_prover_service::{
+    gpu_metrics::{GpuJobTracker, GpuMetricsSampler},
+    job_runner::{circuit_prover_runner, WvgRunnerBuilder},
+};
 use zksync_config::{
     configs::{FriProverConfig, ObservabilityConfig},
     ObjectStoreConfig,
@@ -18,7 +21,7 @@ use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_gener
 use zksync_object_store::{ObjectStore, ObjectStoreFactory};
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_fri_types::PROVER_PROTOCOL_SEMANTIC_VERSION;
-use zksync_prover_keystore::keystore::Keystore;
+use zksync_prover_keystore::{keystore::Keystore, setup_key_fetcher::SetupKeyFetcher};
 use zksync_task_management::ManagedTasks;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
@@ -35,6 +38,9 @@ const GRACEFUL_SHUTDOWN_DURATION: Duration = Duration::from_secs(55);
 /// Free side effect, if the machine dies, only 1 job is in "pending" state.
 const CHANNEL_SIZE: usize = 1;
 
+/// How often GPU metrics are sampled via NVML.
+const GPU_METRICS_SAMPLING_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Parser)]
 #[command(author = "Matter Labs", version)]
 struct Cli {
@@ -56,6 +62,14 @@ struct Cli {
     /// None corresponds to allocating all available VRAM.
     #[arg(short = 'm', long)]
     pub(crate) max_allocation: Option<usize>,
+    /// Enables protocol version drain mode: jobs are not picked until all jobs of older protocol versions are proven.
+    /// Useful during protocol upgrades, when provers with new keys are started alongside provers with old keys.
+    #[arg(long)]
+    drain_older_protocol_versions: bool,
+    /// Chain label attached to GPU metrics. Prover jobs aren't keyed by chain, so the label should be set explicitly
+    /// if provers for multiple chains report to the same metrics backend.
+    #[arg(long, default_value = "default")]
+    chain_label: String,
 }
 
 #[tokio::main]
@@ -73,6 +87,7 @@ async fn main() -> anyhow::Result<()> {
         opt.max_allocation,
         object_store_config,
         prover_config.setup_data_path.into(),
+        prover_config.setup_keys_download_url,
     )
     .await
     .context("failed to load configs")?;
@@ -96,7 +111,7 @@ async fn main() -> anyhow::Result<()> {
         opt.heavy_wvg_count
     );
 
-    let builder = WvgRunnerBuilder::new(
+    let mut builder = WvgRunnerBuilder::new(
         connection_pool.clone(),
         object_store.clone(),
         PROVER_PROTOCOL_SEMANTIC_VERSION,
@@ -104,6 +119,12 @@ async fn main() -> anyhow::Result<()> {
         witness_vector_sender,
         cancellation_token.clone(),
     );
+    if opt.drain_older_protocol_versions {
+        tracing::info!(
+            "Protocol version drain mode enabled; jobs for {PROVER_PROTOCOL_SEMANTIC_VERSION} will be picked once older versions are proven"
+        );
+        builder = builder.with_protocol_version_drain();
+    }
 
     let light_wvg_runner = builder.light_wvg_runner(opt.light_wvg_count);
     let heavy_wvg_runner = builder.heavy_wvg_runner(opt.heavy_wvg_count);
@@ -114,6 +135,17 @@ async fn main() -> anyhow::Result<()> {
     // necessary as it has a connection_pool which will keep 1 connection active by default
     drop(builder);
 
+    let gpu_job_tracker = GpuJobTracker::default();
+    match GpuMetricsSampler::new(
+        gpu_job_tracker.clone(),
+        opt.chain_label,
+        GPU_METRICS_SAMPLING_INTERVAL,
+        cancellation_token.clone(),
+    ) {
+        Ok(sampler) => tasks.push(tokio::spawn(sampler.run())),
+        Err(err) => tracing::warn!("GPU metrics are not exported: {err:#}"),
+    }
+
     let circuit_prover_runner = circuit_prover_runner(
         connection_pool,
         object_store,
@@ -121,6 +153,7 @@ async fn main() -> anyhow::Result<()> {
         setup_data_cache,
         witness_vector_receiver,
         prover_context,
+        gpu_job_tracker,
     );
 
     tasks.extend(circuit_prover_runner.run());
@@ -178,13 +211,14 @@ fn load_configs(
 /// - connection pool - necessary to pick & store jobs from database
 /// - object store - necessary  for loading and storing artifacts to object store
 /// - prover context - necessary for circuit proving; VRAM allocation
-/// - setup data - necessary for circuit proving
+/// - setup data - necessary for circuit proving; missing keys are downloaded first if a download URL is configured
 /// - finalization hints - necessary for generating witness vectors
 async fn load_resources(
     secrets_path: Option<PathBuf>,
     max_gpu_vram_allocation: Option<usize>,
     object_store_config: ObjectStoreConfig,
     setup_data_path: PathBuf,
+    setup_keys_download_url: Option<String>,
 ) -> anyhow::Result<(
     ConnectionPool<Prover>,
     Arc<dyn ObjectStore>,
@@ -217,6 +251,18 @@ async fn load_resources(
         None => ProverContext::create().context("failed initializing gpu prover context")?,
     };
 
+    if let Some(download_url) = &setup_keys_download_url {
+        tracing::info!("Fetching missing setup keys for {PROVER_PROTOCOL_SEMANTIC_VERSION}...");
+        SetupKeyFetcher::new(
+            download_url,
+            PROVER_PROTOCOL_SEMANTIC_VERSION,
+            setup_data_path.clone(),
+        )
+        .fetch_missing_keys()
+        .await
+        .context("failed to fetch setup keys")?;
+    }
+
     tracing::info!("Loading setup data from disk...");
 
     let keystore = Keystore::locate().with_setup_path(Some(setup_data_path));
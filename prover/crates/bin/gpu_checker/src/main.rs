@@ -12,6 +12,7 @@ use shivini::ProverContext;
 use tokio::fs;
 use zksync_circuit_prover_service::{
     gpu_circuit_prover::GpuCircuitProverExecutor,
+    gpu_metrics::GpuJobTracker,
     types::{
         circuit::Circuit, circuit_prover_payload::GpuCircuitProverPayload,
         witness_vector_generator_payload::WitnessVectorGeneratorPayload,
@@ -129,7 +130,7 @@ async fn run_prover(
 
     let prover_context =
         ProverContext::create().context("failed initializing gpu prover context")?;
-    let prover = GpuCircuitProverExecutor::new(prover_context);
+    let prover = GpuCircuitProverExecutor::new(prover_context, GpuJobTracker::default());
     let _ = prover.execute(
         GpuCircuitProverPayload {
             circuit,
@@ -229,6 +230,7 @@ async fn main() -> anyhow::Result<()> {
         },
         max_retries: 1,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -4,7 +4,7 @@ use anyhow::Context as _;
 use async_trait::async_trait;
 use proof_compression_gpu::{run_proof_chain, SnarkWrapper, SnarkWrapperProof};
 use tokio::task::JoinHandle;
-use zksync_object_store::ObjectStore;
+use zksync_object_store::{ObjectStore, ObjectStoreError};
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_fri_types::{
     circuit_definitions::{
@@ -15,7 +15,8 @@ use zksync_prover_fri_types::{
     get_current_pod_name, AuxOutputWitnessWrapper, FriProofWrapper,
 };
 use zksync_prover_interface::outputs::{
-    FflonkL1BatchProofForL1, L1BatchProofForL1, PlonkL1BatchProofForL1,
+    FflonkL1BatchProofForL1, L1BatchCompressionCheckpoint, L1BatchProofForL1,
+    PlonkL1BatchProofForL1,
 };
 use zksync_prover_keystore::keystore::Keystore;
 use zksync_queued_job_processor::JobProcessor;
@@ -30,6 +31,7 @@ pub struct ProofCompressor {
     protocol_version: ProtocolSemanticVersion,
     keystore: Keystore,
     is_fflonk: bool,
+    checkpointing_enabled: bool,
 }
 
 impl ProofCompressor {
@@ -40,6 +42,7 @@ impl ProofCompressor {
         protocol_version: ProtocolSemanticVersion,
         keystore: Keystore,
         is_fflonk: bool,
+        checkpointing_enabled: bool,
     ) -> Self {
         Self {
             blob_store,
@@ -48,6 +51,69 @@ impl ProofCompressor {
             protocol_version,
             keystore,
             is_fflonk,
+            checkpointing_enabled,
+        }
+    }
+
+    fn into_checkpoint(proof: SnarkWrapperProof) -> L1BatchCompressionCheckpoint {
+        match proof {
+            SnarkWrapperProof::Plonk(proof) => L1BatchCompressionCheckpoint::Plonk(proof),
+            SnarkWrapperProof::FFfonk(proof) => L1BatchCompressionCheckpoint::Fflonk(proof),
+        }
+    }
+
+    fn from_checkpoint(checkpoint: L1BatchCompressionCheckpoint) -> SnarkWrapperProof {
+        match checkpoint {
+            L1BatchCompressionCheckpoint::Plonk(proof) => SnarkWrapperProof::Plonk(proof),
+            L1BatchCompressionCheckpoint::Fflonk(proof) => SnarkWrapperProof::FFfonk(proof),
+        }
+    }
+
+    /// Loads the compressed proof checkpointed by a previous attempt of the job, if any. Checkpoints are an optimization,
+    /// so any errors loading them are logged and result in compressing the proof from scratch.
+    async fn load_checkpoint(&self, l1_batch_number: L1BatchNumber) -> Option<SnarkWrapperProof> {
+        let key = (l1_batch_number, self.protocol_version);
+        let checkpoint: L1BatchCompressionCheckpoint = match self.blob_store.get(key).await {
+            Ok(checkpoint) => checkpoint,
+            Err(ObjectStoreError::KeyNotFound(_)) => return None,
+            Err(err) => {
+                tracing::warn!(
+                    "Failed loading compression checkpoint for L1 batch {l1_batch_number}, compressing from scratch: {err}"
+                );
+                return None;
+            }
+        };
+
+        let is_fflonk_checkpoint = matches!(checkpoint, L1BatchCompressionCheckpoint::Fflonk(_));
+        if is_fflonk_checkpoint != self.is_fflonk {
+            tracing::warn!(
+                "Compression checkpoint for L1 batch {l1_batch_number} was produced with another SNARK wrapper \
+                 (FFLONK: {is_fflonk_checkpoint}), compressing from scratch"
+            );
+            return None;
+        }
+        tracing::info!("Reusing compressed proof for L1 batch {l1_batch_number} from checkpoint");
+        METRICS.checkpoints_restored.inc();
+        Some(Self::from_checkpoint(checkpoint))
+    }
+
+    async fn save_checkpoint(
+        blob_store: &dyn ObjectStore,
+        key: (L1BatchNumber, ProtocolSemanticVersion),
+        checkpoint: &L1BatchCompressionCheckpoint,
+    ) {
+        let started_at = Instant::now();
+        match blob_store.put(key, checkpoint).await {
+            Ok(_) => {
+                METRICS.checkpoint_save_time.observe(started_at.elapsed());
+                tracing::info!("Saved compression checkpoint for L1 batch {}", key.0);
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "Failed saving compression checkpoint for L1 batch {}: {err}",
+                    key.0
+                );
+            }
         }
     }
 
@@ -122,7 +188,7 @@ impl JobProcessor for ProofCompressor {
 
     async fn process_job(
         &self,
-        _job_id: &L1BatchNumber,
+        job_id: &L1BatchNumber,
         job: ZkSyncRecursionLayerProof,
         _started_at: Instant,
     ) -> JoinHandle<anyhow::Result<Self::JobArtifacts>> {
@@ -133,12 +199,33 @@ impl JobProcessor for ProofCompressor {
             SnarkWrapper::Plonk
         };
 
-        tokio::task::spawn_blocking(move || {
-            Ok(run_proof_chain(
-                snark_wrapper_mode,
-                &keystore,
-                job.into_inner(),
-            ))
+        if !self.checkpointing_enabled {
+            return tokio::task::spawn_blocking(move || {
+                Ok(run_proof_chain(
+                    snark_wrapper_mode,
+                    &keystore,
+                    job.into_inner(),
+                ))
+            });
+        }
+
+        // Only the final SNARK wrapper proof is checkpointed, i.e. the checkpoint only helps if the job fails
+        // after compression; a job interrupted during compression starts over. `run_proof_chain()` runs all
+        // compression stages internally and doesn't expose intermediate proofs.
+        if let Some(proof) = self.load_checkpoint(*job_id).await {
+            return tokio::spawn(async move { Ok(proof) });
+        }
+        let blob_store = self.blob_store.clone();
+        let checkpoint_key = (*job_id, self.protocol_version);
+        tokio::spawn(async move {
+            let proof = tokio::task::spawn_blocking(move || {
+                run_proof_chain(snark_wrapper_mode, &keystore, job.into_inner())
+            })
+            .await
+            .context("proof compression panicked")?;
+            let checkpoint = Self::into_checkpoint(proof);
+            Self::save_checkpoint(&*blob_store, checkpoint_key, &checkpoint).await;
+            Ok(Self::from_checkpoint(checkpoint))
         })
     }
 
@@ -194,6 +281,19 @@ impl JobProcessor for ProofCompressor {
             .fri_proof_compressor_dal()
             .mark_proof_compression_job_successful(job_id, started_at.elapsed(), &blob_url)
             .await;
+
+        if self.checkpointing_enabled {
+            let checkpoint_key = (job_id, self.protocol_version);
+            if let Err(err) = self
+                .blob_store
+                .remove::<L1BatchCompressionCheckpoint>(checkpoint_key)
+                .await
+            {
+                tracing::warn!(
+                    "Failed removing compression checkpoint for L1 batch {job_id}: {err}"
+                );
+            }
+        }
         Ok(())
     }
 
@@ -100,6 +100,7 @@ async fn main() -> anyhow::Result<()> {
         protocol_version,
         keystore,
         is_fflonk,
+        config.checkpointing_enabled,
     );
 
     let (stop_sender, stop_receiver) = watch::channel(false);
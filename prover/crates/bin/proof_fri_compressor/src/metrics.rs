@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, Histogram, Metrics};
+use vise::{Buckets, Counter, Histogram, Metrics};
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "prover_fri_proof_fri_compressor")]
@@ -11,6 +11,10 @@ pub(crate) struct ProofFriCompressorMetrics {
     pub compression_time: Histogram<Duration>,
     #[metrics(buckets = Buckets::LATENCIES)]
     pub blob_save_time: Histogram<Duration>,
+    /// Number of compression jobs that reused a checkpointed compressed proof instead of compressing the proof.
+    pub checkpoints_restored: Counter,
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub checkpoint_save_time: Histogram<Duration>,
 }
 
 #[vise::register]
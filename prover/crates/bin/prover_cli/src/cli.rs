@@ -2,8 +2,8 @@ use clap::{command, Args, Parser, Subcommand};
 use zksync_types::url::SensitiveUrl;
 
 use crate::commands::{
-    config, debug_proof, delete, get_file_info, insert_batch, insert_version, requeue, restart,
-    stats, status::StatusCommand,
+    cancel, config, debug_proof, delete, get_file_info, insert_batch, insert_version, pause,
+    requeue, restart, resume, stats, status::StatusCommand,
 };
 
 pub const VERSION_STRING: &str = env!("CARGO_PKG_VERSION");
@@ -30,6 +30,9 @@ impl ProverCLI {
             ProverCommand::Stats(args) => stats::run(args, self.config).await?,
             ProverCommand::InsertVersion(args) => insert_version::run(args, self.config).await?,
             ProverCommand::InsertBatch(args) => insert_batch::run(args, self.config).await?,
+            ProverCommand::Pause(args) => pause::run(args, self.config).await?,
+            ProverCommand::Resume(args) => resume::run(args, self.config).await?,
+            ProverCommand::Cancel(args) => cancel::run(args, self.config).await?,
         };
         Ok(())
     }
@@ -62,4 +65,12 @@ pub enum ProverCommand {
     Stats(stats::Options),
     InsertVersion(insert_version::Args),
     InsertBatch(insert_batch::Args),
+    #[command(about = "Pauses dispensing of proving jobs across all rounds")]
+    Pause(pause::Args),
+    #[command(about = "Resumes dispensing of proving jobs")]
+    Resume(resume::Args),
+    #[command(
+        about = "Cancels unfinished witness generation jobs for a batch, e.g. after it was reverted"
+    )]
+    Cancel(cancel::Args),
 }
@@ -0,0 +1,34 @@
+use anyhow::Context as _;
+use clap::Args as ClapArgs;
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+use zksync_types::L1BatchNumber;
+
+use crate::cli::ProverCLIConfig;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Batch number to cancel witness generation jobs for.
+    #[clap(short, long)]
+    batch: L1BatchNumber,
+}
+
+pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = pool
+        .connection()
+        .await
+        .context("failed to acquire a connection")?;
+
+    let cancelled = conn
+        .fri_witness_generator_dal()
+        .cancel_witness_jobs_for_batch(args.batch)
+        .await?;
+    println!(
+        "Cancelled {cancelled} witness generation job(s) for batch {} 🛑",
+        args.batch
+    );
+    Ok(())
+}
@@ -1,8 +1,14 @@
+use std::collections::HashSet;
+
 use anyhow::Context;
 use clap::Args as ClapArgs;
 use dialoguer::{theme::ColorfulTheme, Input};
+use zksync_env_config::{object_store::ProverObjectStoreConfig, FromEnv};
+use zksync_object_store::{Bucket, ObjectStoreFactory, StoredObject};
 use zksync_prover_dal::{Connection, ConnectionPool, Prover, ProverDal};
-use zksync_types::L1BatchNumber;
+use zksync_prover_fri_types::AuxOutputWitnessWrapper;
+use zksync_prover_interface::outputs::{L1BatchCompressionCheckpoint, L1BatchProofForL1};
+use zksync_types::{basic_fri_types::AggregationRound, L1BatchNumber};
 
 use crate::cli::ProverCLIConfig;
 
@@ -20,9 +26,83 @@ pub struct Args {
     /// Batch number to delete
     #[clap(short, long, required_unless_present = "all", conflicts_with = "all", default_value_t = L1BatchNumber(0))]
     batch: L1BatchNumber,
+    /// List the jobs and object store blobs that would be deleted for the batch without deleting anything
+    #[clap(long, conflicts_with = "all", default_value_t = false)]
+    dry_run: bool,
+    /// Only delete prover DB rows for the batch, leaving object store blobs intact.
+    /// Otherwise, the object store is configured via `PROVER_OBJECT_STORE_*` env variables.
+    #[clap(long, conflicts_with = "all", default_value_t = false)]
+    keep_blobs: bool,
 }
 
 pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let prover_connection_pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = prover_connection_pool.connection().await.unwrap();
+
+    if args.all {
+        if !confirm_deletion()? {
+            return Ok(());
+        }
+        return delete_prover_db(conn).await;
+    }
+
+    let artifacts = BatchArtifacts::load(&mut conn, args.batch).await;
+    if artifacts.is_empty() {
+        println!("No prover data found for batch {}", args.batch);
+        return Ok(());
+    }
+    artifacts.print();
+    if args.dry_run {
+        println!("Dry run: nothing was deleted");
+        return Ok(());
+    }
+    if !confirm_deletion()? {
+        return Ok(());
+    }
+
+    // Create the store before touching the DB, so that a misconfigured store doesn't leave orphaned blobs.
+    let blob_store = if args.keep_blobs {
+        None
+    } else {
+        let object_store_config = ProverObjectStoreConfig::from_env()
+            .context("failed to load prover object store config")?;
+        let store = ObjectStoreFactory::new(object_store_config.0)
+            .create_store()
+            .await
+            .context("failed to create object store")?;
+        Some(store)
+    };
+
+    delete_batch_data(&mut conn, args.batch).await?;
+    println!("Deleted prover DB data for batch {}", args.batch);
+
+    if let Some(blob_store) = blob_store {
+        let mut failed_count = 0;
+        for (bucket, key) in &artifacts.blobs {
+            if let Err(err) = blob_store.remove_raw(*bucket, key).await {
+                eprintln!("Failed to remove blob `{key}` from bucket `{bucket}`: {err}");
+                failed_count += 1;
+            }
+        }
+        anyhow::ensure!(
+            failed_count == 0,
+            "failed to remove {failed_count} out of {} blobs for batch {}",
+            artifacts.blobs.len(),
+            args.batch
+        );
+        println!(
+            "Removed {} object store blobs for batch {}",
+            artifacts.blobs.len(),
+            args.batch
+        );
+    }
+    Ok(())
+}
+
+fn confirm_deletion() -> anyhow::Result<bool> {
     let confirmation = Input::<String>::with_theme(&ColorfulTheme::default())
         .with_prompt("Are you sure you want to delete the data?")
         .default("no".to_owned())
@@ -30,22 +110,157 @@ pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
 
     if confirmation != "yes" {
         println!("Aborted");
-        return Ok(());
+        return Ok(false);
     }
+    Ok(true)
+}
 
-    let prover_connection_pool = ConnectionPool::<Prover>::singleton(config.db_url)
-        .build()
-        .await
-        .context("failed to build a prover_connection_pool")?;
-    let conn = prover_connection_pool.connection().await.unwrap();
+/// Prover DB jobs and object store blobs associated with a single batch.
+#[derive(Debug, Default)]
+struct BatchArtifacts {
+    /// Number of jobs per table.
+    jobs: Vec<(&'static str, usize)>,
+    /// Deduplicated object store blobs.
+    blobs: Vec<(Bucket, String)>,
+}
 
-    if args.all {
-        delete_prover_db(conn).await?;
-    } else {
-        delete_batch_data(conn, args.batch).await?;
+impl BatchArtifacts {
+    async fn load(conn: &mut Connection<'_, Prover>, batch: L1BatchNumber) -> Self {
+        let mut this = Self::default();
+        let mut blobs = HashSet::new();
+        let mut add_blob = |bucket: Bucket, key: Option<String>| {
+            if let Some(key) = key.filter(|key| !key.is_empty()) {
+                if blobs.insert((bucket, key.clone())) {
+                    this.blobs.push((bucket, key));
+                }
+            }
+        };
+
+        let basic_job = conn
+            .fri_basic_witness_generator_dal()
+            .get_basic_witness_generator_job_for_batch(batch)
+            .await;
+        let has_protocol_version = basic_job
+            .as_ref()
+            .is_some_and(|job| job.protocol_version.is_some());
+        if let Some(job) = &basic_job {
+            add_blob(Bucket::WitnessInput, job.witness_inputs_blob_url.clone());
+        }
+        let basic_jobs_count = usize::from(basic_job.is_some());
+
+        let leaf_jobs = conn
+            .fri_leaf_witness_generator_dal()
+            .get_leaf_witness_generator_jobs_for_batch(batch)
+            .await;
+        for job in &leaf_jobs {
+            add_blob(
+                Bucket::LeafAggregationWitnessJobsFri,
+                job.closed_form_inputs_blob_url.clone(),
+            );
+        }
+
+        let node_jobs = conn
+            .fri_node_witness_generator_dal()
+            .get_node_witness_generator_jobs_for_batch(batch)
+            .await;
+        for job in &node_jobs {
+            add_blob(
+                Bucket::NodeAggregationWitnessJobsFri,
+                job.aggregations_url.clone(),
+            );
+        }
+
+        let recursion_tip_job = conn
+            .fri_recursion_tip_witness_generator_dal()
+            .get_recursion_tip_witness_generator_jobs_for_batch(batch)
+            .await;
+
+        let scheduler_job = conn
+            .fri_scheduler_witness_generator_dal()
+            .get_scheduler_witness_generator_jobs_for_batch(batch)
+            .await;
+        if let Some(job) = &scheduler_job {
+            add_blob(
+                Bucket::SchedulerWitnessJobsFri,
+                Some(job.scheduler_partial_input_blob_url.clone()),
+            );
+            add_blob(
+                AuxOutputWitnessWrapper::BUCKET,
+                Some(AuxOutputWitnessWrapper::encode_key(batch)),
+            );
+        }
+
+        let mut prover_jobs_count = 0;
+        for round in AggregationRound::ALL_ROUNDS {
+            let prover_jobs = conn
+                .fri_prover_jobs_dal()
+                .get_prover_jobs_stats_for_batch(batch, round)
+                .await;
+            prover_jobs_count += prover_jobs.len();
+            for job in prover_jobs {
+                add_blob(Bucket::ProverJobsFri, Some(job.circuit_blob_url));
+                add_blob(Bucket::ProofsFri, job.proof_blob_url);
+            }
+        }
+
+        let compressor_job = conn
+            .fri_proof_compressor_dal()
+            .get_proof_compression_job_for_batch(batch)
+            .await;
+        if let Some(job) = &compressor_job {
+            add_blob(Bucket::ProofsFri, job.fri_proof_blob_url.clone());
+            add_blob(L1BatchProofForL1::BUCKET, job.l1_proof_blob_url.clone());
+            // Checkpoints are keyed by the protocol version, which is only recorded for witness inputs.
+            if has_protocol_version {
+                let protocol_version = conn
+                    .fri_basic_witness_generator_dal()
+                    .protocol_version_for_l1_batch(batch)
+                    .await;
+                add_blob(
+                    L1BatchCompressionCheckpoint::BUCKET,
+                    Some(L1BatchCompressionCheckpoint::encode_key((
+                        batch,
+                        protocol_version,
+                    ))),
+                );
+            }
+        }
+
+        this.jobs = vec![
+            ("witness_inputs_fri", basic_jobs_count),
+            ("leaf_aggregation_witness_jobs_fri", leaf_jobs.len()),
+            ("node_aggregation_witness_jobs_fri", node_jobs.len()),
+            (
+                "recursion_tip_witness_jobs_fri",
+                usize::from(recursion_tip_job.is_some()),
+            ),
+            (
+                "scheduler_witness_jobs_fri",
+                usize::from(scheduler_job.is_some()),
+            ),
+            ("prover_jobs_fri", prover_jobs_count),
+            (
+                "proof_compression_jobs_fri",
+                usize::from(compressor_job.is_some()),
+            ),
+        ];
+        this
     }
 
-    Ok(())
+    fn is_empty(&self) -> bool {
+        self.jobs.iter().all(|&(_, count)| count == 0)
+    }
+
+    fn print(&self) {
+        println!("Prover DB rows:");
+        for (table, count) in &self.jobs {
+            println!("  {table}: {count}");
+        }
+        println!("Object store blobs ({}):", self.blobs.len());
+        for (bucket, key) in &self.blobs {
+            println!("  {bucket}/{key}");
+        }
+    }
 }
 
 async fn delete_prover_db(mut conn: Connection<'_, Prover>) -> anyhow::Result<()> {
@@ -73,20 +288,25 @@ async fn delete_prover_db(mut conn: Connection<'_, Prover>) -> anyhow::Result<()
 }
 
 async fn delete_batch_data(
-    mut conn: Connection<'_, Prover>,
+    conn: &mut Connection<'_, Prover>,
     block_number: L1BatchNumber,
 ) -> anyhow::Result<()> {
-    conn.fri_proof_compressor_dal()
+    let mut transaction = conn.start_transaction().await?;
+    transaction
+        .fri_proof_compressor_dal()
         .delete_batch_data(block_number)
         .await
         .context("failed to delete proof compressor data")?;
-    conn.fri_prover_jobs_dal()
+    transaction
+        .fri_prover_jobs_dal()
         .delete_batch_data(block_number)
         .await
         .context("failed to delete prover jobs data")?;
-    conn.fri_witness_generator_dal()
+    transaction
+        .fri_witness_generator_dal()
         .delete_batch_data(block_number)
         .await
         .context("failed to delete witness generator data")?;
+    transaction.commit().await?;
     Ok(())
 }
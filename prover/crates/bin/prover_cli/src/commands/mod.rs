@@ -1,10 +1,13 @@
+pub(crate) mod cancel;
 pub(crate) mod config;
 pub(crate) mod debug_proof;
 pub(crate) mod delete;
 pub(crate) mod get_file_info;
 pub(crate) mod insert_batch;
 pub(crate) mod insert_version;
+pub(crate) mod pause;
 pub(crate) mod requeue;
 pub(crate) mod restart;
+pub(crate) mod resume;
 pub(crate) mod stats;
 pub mod status;
@@ -0,0 +1,34 @@
+use anyhow::Context as _;
+use clap::Args as ClapArgs;
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+
+use crate::cli::ProverCLIConfig;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Reason for the pause displayed by `status pauses`, e.g. an upgrade or an incident.
+    #[clap(short, long)]
+    reason: Option<String>,
+}
+
+pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = pool
+        .connection()
+        .await
+        .context("failed to acquire a connection")?;
+
+    let inserted = conn
+        .proving_pauses_dal()
+        .pause_proving(args.reason.as_deref())
+        .await?;
+    if inserted {
+        println!("Paused dispensing of proving jobs ⏸️");
+    } else {
+        println!("Proving is already paused; updated the pause reason");
+    }
+    Ok(())
+}
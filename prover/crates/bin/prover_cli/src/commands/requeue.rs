@@ -1,14 +1,29 @@
 use anyhow::Context;
 use clap::Args as ClapArgs;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
-use zksync_types::{basic_fri_types::AggregationRound, prover_dal::StuckJobs, L1BatchNumber};
+use zksync_types::{
+    basic_fri_types::AggregationRound,
+    protocol_version::ProtocolSemanticVersion,
+    prover_dal::{StuckJobs, StuckJobsFilter},
+    L1BatchNumber,
+};
 
 use crate::cli::ProverCLIConfig;
 
 #[derive(ClapArgs)]
 pub struct Args {
-    #[clap(short, long)]
-    batch: L1BatchNumber,
+    /// Requeue stuck jobs for a single batch.
+    #[clap(short, long, conflicts_with_all = ["from_batch", "to_batch"])]
+    batch: Option<L1BatchNumber>,
+    /// Requeue stuck jobs for batches starting from the specified one (inclusive).
+    #[clap(long)]
+    from_batch: Option<L1BatchNumber>,
+    /// Requeue stuck jobs for batches up to the specified one (inclusive).
+    #[clap(long)]
+    to_batch: Option<L1BatchNumber>,
+    /// Requeue stuck jobs for the specified protocol version, e.g. `0.25.0`.
+    #[clap(long)]
+    protocol_version: Option<ProtocolSemanticVersion>,
     /// Maximum number of attempts to re-queue a job.
     /// Default value is 10.
     /// NOTE: this argument is temporary and will be deprecated once the `config` command is implemented.
@@ -16,7 +31,34 @@ pub struct Args {
     max_attempts: u32,
 }
 
+impl Args {
+    fn filter(&self) -> anyhow::Result<StuckJobsFilter> {
+        let l1_batch_range = match (self.batch, self.from_batch, self.to_batch) {
+            (Some(batch), ..) => Some(batch..=batch),
+            (None, None, None) => None,
+            (None, from_batch, to_batch) => {
+                let from_batch = from_batch.unwrap_or(L1BatchNumber(0));
+                let to_batch = to_batch.unwrap_or(L1BatchNumber(u32::MAX));
+                anyhow::ensure!(
+                    from_batch <= to_batch,
+                    "--from-batch must not exceed --to-batch"
+                );
+                Some(from_batch..=to_batch)
+            }
+        };
+        anyhow::ensure!(
+            l1_batch_range.is_some() || self.protocol_version.is_some(),
+            "at least one of --batch, --from-batch, --to-batch or --protocol-version must be specified"
+        );
+        Ok(StuckJobsFilter {
+            l1_batch_range,
+            protocol_version: self.protocol_version,
+        })
+    }
+}
+
 pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let filter = args.filter()?;
     let pool = ConnectionPool::<Prover>::singleton(config.db_url)
         .build()
         .await
@@ -29,13 +71,13 @@ pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
 
     let stuck_witness_input_jobs = conn
         .fri_basic_witness_generator_dal()
-        .requeue_stuck_witness_inputs_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_witness_inputs_jobs_matching(&filter, args.max_attempts)
         .await;
     display_requeued_stuck_jobs(stuck_witness_input_jobs, AggregationRound::BasicCircuits);
 
     let stuck_leaf_aggregations_stuck_jobs = conn
         .fri_witness_generator_dal()
-        .requeue_stuck_leaf_aggregation_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_leaf_aggregation_jobs_matching(&filter, args.max_attempts)
         .await;
     display_requeued_stuck_jobs(
         stuck_leaf_aggregations_stuck_jobs,
@@ -44,7 +86,7 @@ pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
 
     let stuck_node_aggregations_jobs = conn
         .fri_witness_generator_dal()
-        .requeue_stuck_node_aggregation_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_node_aggregation_jobs_matching(&filter, args.max_attempts)
         .await;
     display_requeued_stuck_jobs(
         stuck_node_aggregations_jobs,
@@ -53,19 +95,19 @@ pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
 
     let stuck_recursion_tip_job = conn
         .fri_recursion_tip_witness_generator_dal()
-        .requeue_stuck_recursion_tip_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_recursion_tip_jobs_matching(&filter, args.max_attempts)
         .await;
     display_requeued_stuck_jobs(stuck_recursion_tip_job, AggregationRound::RecursionTip);
 
     let stuck_scheduler_jobs = conn
         .fri_scheduler_witness_generator_dal()
-        .requeue_stuck_scheduler_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_scheduler_jobs_matching(&filter, args.max_attempts)
         .await;
     display_requeued_stuck_jobs(stuck_scheduler_jobs, AggregationRound::Scheduler);
 
     let stuck_proof_compressor_jobs = conn
         .fri_proof_compressor_dal()
-        .requeue_stuck_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_jobs_matching(&filter, args.max_attempts)
         .await;
     for stuck_job in stuck_proof_compressor_jobs {
         println!("Re-queuing proof compressor job {stuck_job:?} 🔁",);
@@ -73,7 +115,7 @@ pub async fn run(args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
 
     let stuck_prover_jobs = conn
         .fri_prover_jobs_dal()
-        .requeue_stuck_jobs_for_batch(args.batch, args.max_attempts)
+        .requeue_stuck_jobs_matching(&filter, args.max_attempts)
         .await;
 
     for stuck_job in stuck_prover_jobs {
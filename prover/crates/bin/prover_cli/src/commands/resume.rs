@@ -0,0 +1,27 @@
+use anyhow::Context as _;
+use clap::Args as ClapArgs;
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+
+use crate::cli::ProverCLIConfig;
+
+#[derive(ClapArgs)]
+pub struct Args {}
+
+pub async fn run(_args: Args, config: ProverCLIConfig) -> anyhow::Result<()> {
+    let pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = pool
+        .connection()
+        .await
+        .context("failed to acquire a connection")?;
+
+    let resumed = conn.proving_pauses_dal().resume_proving().await?;
+    if resumed {
+        println!("Resumed dispensing of proving jobs ▶️");
+    } else {
+        println!("Proving is not paused");
+    }
+    Ok(())
+}
@@ -4,12 +4,15 @@ use crate::cli::ProverCLIConfig;
 
 pub(crate) mod batch;
 pub(crate) mod l1;
+pub(crate) mod pauses;
 pub mod utils;
 
 #[derive(Subcommand)]
 pub enum StatusCommand {
     Batch(batch::Args),
     L1,
+    /// Displays whether proving is paused.
+    Pauses,
 }
 
 impl StatusCommand {
@@ -17,6 +20,7 @@ impl StatusCommand {
         match self {
             StatusCommand::Batch(args) => batch::run(args, config).await,
             StatusCommand::L1 => l1::run().await,
+            StatusCommand::Pauses => pauses::run(config).await,
         }
     }
 }
@@ -0,0 +1,25 @@
+use anyhow::Context as _;
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+
+use crate::cli::ProverCLIConfig;
+
+pub(crate) async fn run(config: ProverCLIConfig) -> anyhow::Result<()> {
+    let pool = ConnectionPool::<Prover>::singleton(config.db_url)
+        .build()
+        .await
+        .context("failed to build a prover_connection_pool")?;
+    let mut conn = pool
+        .connection()
+        .await
+        .context("failed to acquire a connection")?;
+
+    match conn.proving_pauses_dal().get_pause().await? {
+        Some(pause) => println!(
+            "Proving is paused for all rounds since {} ({})",
+            pause.paused_at,
+            pause.reason.as_deref().unwrap_or("no reason given")
+        ),
+        None => println!("Proving is not paused"),
+    }
+    Ok(())
+}
@@ -82,6 +82,7 @@ impl From<WitnessJobStatus> for Status {
             }
             WitnessJobStatus::Skipped => Status::Custom("Skipped ⏩".to_owned()),
             WitnessJobStatus::WaitingForProofs => Status::WaitingForProofs,
+            WitnessJobStatus::Cancelled => Status::Custom("Cancelled 🛑".to_owned()),
         }
     }
 }
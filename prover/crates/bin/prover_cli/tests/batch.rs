@@ -1498,3 +1498,62 @@ v Prover Jobs: Queued 📥
         .into(),
     );
 }
+
+#[tokio::test]
+#[doc = "prover_cli delete --batch 10000 --dry-run"]
+async fn pli_delete_batch_dry_run_keeps_data() {
+    let connection_pool = ConnectionPool::<Prover>::prover_test_pool().await;
+    let mut connection = connection_pool.connection().await.unwrap();
+
+    connection
+        .fri_protocol_versions_dal()
+        .save_prover_protocol_version(
+            ProtocolSemanticVersion::default(),
+            L1VerifierConfig::default(),
+        )
+        .await
+        .unwrap();
+
+    let batch_number = L1BatchNumber(10000);
+    insert_bwg_job(
+        FriWitnessJobStatus::Successful,
+        batch_number,
+        &mut connection,
+    )
+    .await;
+    insert_prover_job(
+        ProverJobStatus::Queued,
+        BaseLayerCircuitType::VM,
+        AggregationRound::BasicCircuits,
+        batch_number,
+        0,
+        &mut connection,
+    )
+    .await;
+
+    let output = Command::cargo_bin("prover_cli")
+        .unwrap()
+        .arg(connection_pool.database_url().expose_str())
+        .arg("delete")
+        .args(["--batch", "10000", "--dry-run"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("witness_inputs_fri: 1"), "{output}");
+    assert!(output.contains("prover_jobs_fri: 1"), "{output}");
+    assert!(output.contains("Dry run: nothing was deleted"), "{output}");
+
+    let basic_job = connection
+        .fri_basic_witness_generator_dal()
+        .get_basic_witness_generator_job_for_batch(batch_number)
+        .await;
+    assert!(basic_job.is_some());
+    let prover_jobs = connection
+        .fri_prover_jobs_dal()
+        .get_prover_jobs_stats_for_batch(batch_number, AggregationRound::BasicCircuits)
+        .await;
+    assert_eq!(prover_jobs.len(), 1);
+}
@@ -11,7 +11,7 @@ pub mod gpu_socket_listener {
     use zksync_object_store::bincode;
     use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
     use zksync_prover_fri_types::WitnessVectorArtifacts;
-    use zksync_prover_fri_utils::region_fetcher::Zone;
+    use zksync_prover_fri_utils::{region_fetcher::Zone, socket_utils::decode_witness_vector};
     use zksync_types::{
         protocol_version::ProtocolSemanticVersion,
         prover_dal::{GpuProverInstanceStatus, SocketAddress},
@@ -126,6 +126,8 @@ pub mod gpu_socket_listener {
 
             let deserialize_span = tracing::info_span!("deserialize_witness_vector");
             let witness_vector = deserialize_span.in_scope(|| {
+                let assembly =
+                    decode_witness_vector(assembly).context("Failed decoding witness vector")?;
                 bincode::deserialize::<WitnessVectorArtifacts>(&assembly)
                     .context("Failed deserializing witness vector")
             })?;
@@ -6,10 +6,11 @@ use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_interface::rpc::GatewayRpcServer;
 
-use crate::rpc_server::processor::RpcDataProcessor;
+use crate::rpc_server::processor::{ProgressPoller, RpcDataProcessor};
 
 pub struct RpcServer {
     pub(crate) processor: RpcDataProcessor,
+    pub(crate) progress_poller: ProgressPoller,
     pub(crate) ws_port: u16,
 }
 
@@ -19,8 +20,12 @@ impl RpcServer {
         blob_store: Arc<dyn ObjectStore>,
         pool: ConnectionPool<Prover>,
     ) -> Self {
-        let processor = RpcDataProcessor::new(pool, blob_store);
-        Self { processor, ws_port }
+        let (processor, progress_poller) = RpcDataProcessor::new(pool, blob_store);
+        Self {
+            processor,
+            progress_poller,
+            ws_port,
+        }
     }
 
     pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
@@ -31,6 +36,8 @@ impl RpcServer {
 
         tracing::info!("Started JSON-RPC server at {}", address);
 
+        let progress_poller_task = tokio::spawn(self.progress_poller.run(stop_receiver.clone()));
+
         tokio::spawn(async move {
             if stop_receiver.changed().await.is_err() {
                 tracing::warn!(
@@ -43,7 +50,7 @@ impl RpcServer {
         });
 
         handle.stopped().await;
-        Ok(())
+        progress_poller_task.await?
     }
 }
 mod processor;
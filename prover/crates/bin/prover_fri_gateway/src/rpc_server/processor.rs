@@ -1,4 +1,6 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use tokio::sync::{broadcast, watch};
 
 use jsonrpsee::{
     core::{async_trait, RpcResult, SubscriptionResult},
@@ -8,19 +10,149 @@ use jsonrpsee::{
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_interface::{
-    api::{ProofGenerationData, SubmitProofRequest},
+    api::{ProofGenerationData, ProvingRoundNotice, SubmitProofRequest},
     rpc::GatewayRpcServer,
 };
-use zksync_types::{prover_dal::ProofCompressionJobStatus, L1BatchNumber};
+use zksync_types::{
+    api::ProvingRoundStatus,
+    basic_fri_types::AggregationRound,
+    prover_dal::{ProofCompressionJobStatus, ProverJobsRoundStats},
+    L1BatchNumber,
+};
+
+/// Converts per-round prover job stats (ordered by batch and round) into notices for the server.
+/// Rounds that are still in progress don't produce notices.
+fn proving_round_notices(stats: &[ProverJobsRoundStats]) -> Vec<ProvingRoundNotice> {
+    stats
+        .iter()
+        .enumerate()
+        .filter_map(|(i, round_stats)| {
+            let status = if !round_stats.failed_circuit_ids.is_empty() {
+                ProvingRoundStatus::Failing
+            } else if round_stats.successful_jobs_count == round_stats.jobs_count {
+                // Leaf and node aggregation jobs are created gradually, so the round can only be considered
+                // completed once the next round has started.
+                let has_next_round = stats
+                    .get(i + 1)
+                    .is_some_and(|next| next.l1_batch_number == round_stats.l1_batch_number);
+                let is_created_gradually = matches!(
+                    round_stats.aggregation_round,
+                    AggregationRound::LeafAggregation | AggregationRound::NodeAggregation
+                );
+                if is_created_gradually && !has_next_round {
+                    return None;
+                }
+                ProvingRoundStatus::Completed
+            } else {
+                return None;
+            };
+
+            let artifact_url =
+                if status == ProvingRoundStatus::Completed && round_stats.jobs_count == 1 {
+                    round_stats.proof_blob_url.clone()
+                } else {
+                    None
+                };
+            Some(ProvingRoundNotice {
+                l1_batch_number: round_stats.l1_batch_number,
+                aggregation_round: round_stats.aggregation_round,
+                status,
+                jobs_count: round_stats.jobs_count,
+                failed_circuit_ids: round_stats.failed_circuit_ids.clone(),
+                artifact_url,
+            })
+        })
+        .collect()
+}
+
+/// Per-round prover job stats for batches which final proofs weren't sent to the server yet.
+type RoundStatsSnapshot = Arc<[ProverJobsRoundStats]>;
+
+/// Periodically polls per-round prover job stats and broadcasts them to all progress subscribers, so that
+/// the stats are queried once per poll regardless of the number of subscribers.
+#[derive(Debug)]
+pub(crate) struct ProgressPoller {
+    pool: ConnectionPool<Prover>,
+    sender: broadcast::Sender<RoundStatsSnapshot>,
+    /// First batch which final proof wasn't sent to the server; all preceding batches are not polled.
+    first_unsent_l1_batch: L1BatchNumber,
+}
+
+impl ProgressPoller {
+    const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+    fn new(pool: ConnectionPool<Prover>) -> Self {
+        // Subscribers only need the latest snapshot, so there's no point in buffering more.
+        let (sender, _) = broadcast::channel(1);
+        Self {
+            pool,
+            sender,
+            first_unsent_l1_batch: L1BatchNumber(0),
+        }
+    }
+
+    async fn poll(&mut self) -> anyhow::Result<RoundStatsSnapshot> {
+        let mut connection = self.pool.connection().await?;
+        let Some(first_unsent_l1_batch) = connection
+            .fri_proof_compressor_dal()
+            .get_first_batch_not_sent_to_server(self.first_unsent_l1_batch)
+            .await?
+        else {
+            return Ok(Arc::new([]));
+        };
+        // All batches before the cursor were sent to the server, and a sent batch never becomes unsent, so the cursor
+        // only moves forward. Batches after it that were already sent are filtered out by the stats query.
+        self.first_unsent_l1_batch = first_unsent_l1_batch;
+        let stats = connection
+            .fri_prover_jobs_dal()
+            .get_round_stats_for_unsent_batches(first_unsent_l1_batch)
+            .await;
+        Ok(stats.into())
+    }
+
+    pub(crate) async fn run(
+        mut self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            if self.sender.receiver_count() > 0 {
+                let stats = self.poll().await?;
+                // Can only fail if all subscribers have disconnected after the check above, which is fine.
+                self.sender.send(stats).ok();
+            }
+
+            if tokio::time::timeout(Self::POLL_INTERVAL, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, shutting down proving progress poller");
+        Ok(())
+    }
+}
 
 pub struct RpcDataProcessor {
     pool: ConnectionPool<Prover>,
     blob_store: Arc<dyn ObjectStore>,
+    progress_sender: broadcast::Sender<RoundStatsSnapshot>,
 }
 
 impl RpcDataProcessor {
-    pub fn new(pool: ConnectionPool<Prover>, blob_store: Arc<dyn ObjectStore>) -> Self {
-        Self { pool, blob_store }
+    /// Creates a processor together with the poller of proving progress, which must be run for progress
+    /// subscriptions to receive notices.
+    pub(crate) fn new(
+        pool: ConnectionPool<Prover>,
+        blob_store: Arc<dyn ObjectStore>,
+    ) -> (Self, ProgressPoller) {
+        let progress_poller = ProgressPoller::new(pool.clone());
+        let this = Self {
+            pool,
+            blob_store,
+            progress_sender: progress_poller.sender.clone(),
+        };
+        (this, progress_poller)
     }
 
     pub async fn subscribe(&self, pending: PendingSubscriptionSink) {
@@ -52,6 +184,56 @@ impl RpcDataProcessor {
         }
     }
 
+    pub async fn subscribe_for_progress(&self, pending: PendingSubscriptionSink) {
+        let Ok(mut sink) = pending.accept().await else {
+            return;
+        };
+        let mut stats_receiver = self.progress_sender.subscribe();
+        // Notices already sent to this subscriber, so that only changes are sent.
+        let mut sent_notices =
+            HashMap::<(L1BatchNumber, AggregationRound), ProvingRoundNotice>::new();
+
+        loop {
+            let stats = match stats_receiver.recv().await {
+                Ok(stats) => stats,
+                // Skipped snapshots are outdated anyway; the next one will be received on the next iteration.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return,
+            };
+            let notices = proving_round_notices(&stats);
+            // Forget about batches which proofs were sent to the server.
+            sent_notices.retain(|(l1_batch_number, _), _| {
+                stats
+                    .iter()
+                    .any(|round_stats| round_stats.l1_batch_number == *l1_batch_number)
+            });
+
+            for notice in notices {
+                let key = (notice.l1_batch_number, notice.aggregation_round);
+                if sent_notices.get(&key) == Some(&notice) {
+                    continue;
+                }
+
+                let msg = SubscriptionMessage::from_json(&notice).unwrap();
+                match sink.try_send(msg) {
+                    Ok(_) => {
+                        tracing::info!(
+                            "Proving round notice for round {} of batch {:?} was sent to client",
+                            notice.aggregation_round,
+                            notice.l1_batch_number
+                        );
+                        sent_notices.insert(key, notice);
+                    }
+                    Err(TrySendError::Closed(_)) => return,
+                    Err(TrySendError::Full(_)) => {
+                        tracing::warn!("Channel is full, waiting until it's ready");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     pub async fn next_submit_proof_request(&self) -> Option<(L1BatchNumber, SubmitProofRequest)> {
         let (l1_batch_number, protocol_version, status) = self
             .pool
@@ -96,6 +278,32 @@ impl RpcDataProcessor {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    /// Marks unfinished prover jobs for the batch as skipped. The skipped compression job (if any) is then reported
+    /// to the server like any other skipped proof.
+    pub async fn skip_batch_proving(&self, l1_batch_number: L1BatchNumber) -> anyhow::Result<()> {
+        let mut connection = self.pool.connection().await?;
+        let mut transaction = connection.start_transaction().await?;
+        let witness_jobs = transaction
+            .fri_witness_generator_dal()
+            .skip_witness_jobs_for_batch(l1_batch_number)
+            .await?;
+        let prover_jobs = transaction
+            .fri_prover_jobs_dal()
+            .skip_prover_jobs_for_batch(l1_batch_number)
+            .await?;
+        let compression_job = transaction
+            .fri_proof_compressor_dal()
+            .skip_proof_compression_job(l1_batch_number)
+            .await?;
+        transaction.commit().await?;
+
+        tracing::warn!(
+            "Proving for batch {l1_batch_number} was skipped by the server; skipped {witness_jobs} witness generator job(s), \
+             {prover_jobs} prover job(s), compression job skipped: {compression_job}"
+        );
+        Ok(())
+    }
+
     pub async fn save_proof_gen_data(&self, data: ProofGenerationData) -> anyhow::Result<()> {
         tracing::info!(
             "Received proof generation data for batch: {:?}",
@@ -140,6 +348,12 @@ impl GatewayRpcServer for RpcDataProcessor {
             .map_err(|err| ErrorObject::owned(INTERNAL_ERROR_CODE, format!("{err:?}"), None::<()>))
     }
 
+    async fn skip_proving(&self, batch: L1BatchNumber) -> RpcResult<()> {
+        self.skip_batch_proving(batch)
+            .await
+            .map_err(|err| ErrorObject::owned(INTERNAL_ERROR_CODE, format!("{err:?}"), None::<()>))
+    }
+
     async fn subscribe_for_proofs(
         &self,
         subscription_sink: PendingSubscriptionSink,
@@ -147,4 +361,12 @@ impl GatewayRpcServer for RpcDataProcessor {
         self.subscribe(subscription_sink).await;
         Ok(())
     }
+
+    async fn subscribe_for_proving_progress(
+        &self,
+        subscription_sink: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        self.subscribe_for_progress(subscription_sink).await;
+        Ok(())
+    }
 }
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use zksync_prover_dal::{Connection, Prover, ProverDal};
+
+use crate::{metrics::PROVER_JOB_MONITOR_METRICS, task_wiring::Task};
+
+/// `CompressionJobsArchiver` is a task that archives proof compression jobs together with witness generator jobs
+/// for the same batches. The task will archive jobs for batches which proofs were sent to the server
+/// a certain amount of time ago, once their prover jobs are archived by `ProverJobsArchiver`.
+/// Note: This component speeds up witness generators and compressors, in its absence, queries would slow down due to state growth.
+#[derive(Debug)]
+pub struct CompressionJobsArchiver {
+    /// duration after a proof was sent to the server, after which its jobs can be archived
+    archive_jobs_after: Duration,
+}
+
+impl CompressionJobsArchiver {
+    pub fn new(archive_jobs_after: Duration) -> Self {
+        Self { archive_jobs_after }
+    }
+}
+
+#[async_trait::async_trait]
+impl Task for CompressionJobsArchiver {
+    async fn invoke(&self, connection: &mut Connection<Prover>) -> anyhow::Result<()> {
+        let archived = connection
+            .fri_proof_compressor_dal()
+            .archive_old_jobs(self.archive_jobs_after)
+            .await;
+        if archived.compression_jobs > 0 {
+            tracing::info!(
+                "Archived {} compression jobs and {} witness generator jobs",
+                archived.compression_jobs,
+                archived.witness_jobs
+            );
+        }
+        PROVER_JOB_MONITOR_METRICS
+            .compression_job_archived
+            .inc_by(archived.compression_jobs as u64);
+        PROVER_JOB_MONITOR_METRICS
+            .witness_job_archived
+            .inc_by(archived.witness_jobs as u64);
+
+        let oldest_job_age = connection
+            .fri_proof_compressor_dal()
+            .get_oldest_not_archived_job_age()
+            .await
+            .unwrap_or_default();
+        PROVER_JOB_MONITOR_METRICS
+            .compression_jobs_archive_lag
+            .set(oldest_job_age.saturating_sub(self.archive_jobs_after));
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+pub use compression_jobs_archiver::CompressionJobsArchiver;
 pub use gpu_prover_archiver::GpuProverArchiver;
 pub use prover_jobs_archiver::ProverJobsArchiver;
 
+mod compression_jobs_archiver;
 mod gpu_prover_archiver;
 mod prover_jobs_archiver;
@@ -23,7 +23,10 @@ impl ProverJobAttemptsReporter {
         connection: &mut Connection<'_, Prover>,
         round: AggregationRound,
     ) -> anyhow::Result<()> {
-        let max_attempts = self.witness_generator_config.max_attempts;
+        let max_attempts = self
+            .witness_generator_config
+            .witness_generation_max_attempts()
+            .for_round(round);
         match round {
             AggregationRound::BasicCircuits => {
                 PROVER_JOB_MONITOR_METRICS.report_reached_max_attempts(
@@ -96,7 +99,7 @@ impl ProverJobAttemptsReporter {
         &self,
         connection: &mut Connection<'_, Prover>,
     ) -> anyhow::Result<()> {
-        let max_attempts = self.compressor_config.max_attempts;
+        let max_attempts = self.compressor_config.requeue_max_attempts();
 
         PROVER_JOB_MONITOR_METRICS.report_reached_max_attempts(
             JobType::ProofCompressor,
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
-use zksync_config::configs::fri_witness_generator::WitnessGenerationTimeouts;
+use zksync_config::configs::fri_witness_generator::{
+    WitnessGenerationMaxAttempts, WitnessGenerationTimeouts,
+};
 use zksync_prover_dal::{Connection, Prover, ProverDal};
 use zksync_types::prover_dal::StuckJobs;
 
@@ -11,14 +13,17 @@ use crate::{
 /// `WitnessGeneratorJobRequeuer` s a task that requeues witness generator jobs that have not made progress in a given unit of time.
 #[derive(Debug)]
 pub struct WitnessGeneratorJobRequeuer {
-    /// max attempts before giving up on the job
-    max_attempts: u32,
+    /// max attempts before giving up on the job, per round
+    max_attempts: WitnessGenerationMaxAttempts,
     /// the amount of time that must have passed before a job is considered to have not made progress
     processing_timeouts: WitnessGenerationTimeouts,
 }
 
 impl WitnessGeneratorJobRequeuer {
-    pub fn new(max_attempts: u32, processing_timeouts: WitnessGenerationTimeouts) -> Self {
+    pub fn new(
+        max_attempts: WitnessGenerationMaxAttempts,
+        processing_timeouts: WitnessGenerationTimeouts,
+    ) -> Self {
         Self {
             max_attempts,
             processing_timeouts,
@@ -35,7 +40,7 @@ impl WitnessGeneratorJobRequeuer {
     async fn requeue_stuck_basic_jobs(&self, connection: &mut Connection<'_, Prover>) {
         let stuck_jobs = connection
             .fri_basic_witness_generator_dal()
-            .requeue_stuck_basic_jobs(self.processing_timeouts.basic(), self.max_attempts)
+            .requeue_stuck_basic_jobs(self.processing_timeouts.basic(), self.max_attempts.basic())
             .await;
         self.emit_telemetry(WitnessType::WitnessInputsFri, &stuck_jobs);
     }
@@ -43,7 +48,7 @@ impl WitnessGeneratorJobRequeuer {
     async fn requeue_stuck_leaf_jobs(&self, connection: &mut Connection<'_, Prover>) {
         let stuck_jobs = connection
             .fri_leaf_witness_generator_dal()
-            .requeue_stuck_leaf_jobs(self.processing_timeouts.leaf(), self.max_attempts)
+            .requeue_stuck_leaf_jobs(self.processing_timeouts.leaf(), self.max_attempts.leaf())
             .await;
         self.emit_telemetry(WitnessType::LeafAggregationJobsFri, &stuck_jobs);
     }
@@ -51,7 +56,7 @@ impl WitnessGeneratorJobRequeuer {
     async fn requeue_stuck_node_jobs(&self, connection: &mut Connection<'_, Prover>) {
         let stuck_jobs = connection
             .fri_node_witness_generator_dal()
-            .requeue_stuck_node_jobs(self.processing_timeouts.node(), self.max_attempts)
+            .requeue_stuck_node_jobs(self.processing_timeouts.node(), self.max_attempts.node())
             .await;
         self.emit_telemetry(WitnessType::NodeAggregationJobsFri, &stuck_jobs);
     }
@@ -61,7 +66,7 @@ impl WitnessGeneratorJobRequeuer {
             .fri_recursion_tip_witness_generator_dal()
             .requeue_stuck_recursion_tip_jobs(
                 self.processing_timeouts.recursion_tip(),
-                self.max_attempts,
+                self.max_attempts.recursion_tip(),
             )
             .await;
         self.emit_telemetry(WitnessType::RecursionTipJobsFri, &stuck_jobs);
@@ -70,7 +75,10 @@ impl WitnessGeneratorJobRequeuer {
     async fn requeue_stuck_scheduler_jobs(&self, connection: &mut Connection<'_, Prover>) {
         let stuck_jobs = connection
             .fri_scheduler_witness_generator_dal()
-            .requeue_stuck_scheduler_jobs(self.processing_timeouts.scheduler(), self.max_attempts)
+            .requeue_stuck_scheduler_jobs(
+                self.processing_timeouts.scheduler(),
+                self.max_attempts.scheduler(),
+            )
             .await;
         self.emit_telemetry(WitnessType::SchedulerJobsFri, &stuck_jobs);
     }
@@ -13,12 +13,13 @@ use zksync_config::configs::{
 use zksync_core_leftovers::temp_config_store::{load_database_secrets, load_general_config};
 use zksync_prover_dal::{ConnectionPool, Prover};
 use zksync_prover_job_monitor::{
-    archiver::{GpuProverArchiver, ProverJobsArchiver},
+    archiver::{CompressionJobsArchiver, GpuProverArchiver, ProverJobsArchiver},
     attempts_reporter::ProverJobAttemptsReporter,
     autoscaler_queue_reporter::get_queue_reporter_router,
     job_requeuer::{ProofCompressorJobRequeuer, ProverJobRequeuer, WitnessGeneratorJobRequeuer},
     queue_reporter::{
-        ProofCompressorQueueReporter, ProverQueueReporter, WitnessGeneratorQueueReporter,
+        ProofCompressorQueueReporter, ProverQueueReporter, ProvingPauseReporter,
+        WitnessGeneratorQueueReporter,
     },
     task_wiring::TaskRunner,
     witness_job_queuer::WitnessJobQueuer,
@@ -160,10 +161,18 @@ fn get_tasks(
         prover_jobs_archiver,
     );
 
+    let compression_jobs_archiver =
+        CompressionJobsArchiver::new(prover_job_monitor_config.archive_compression_jobs_duration());
+    task_runner.add(
+        "CompressionJobsArchiver",
+        prover_job_monitor_config.compression_jobs_archiver_run_interval(),
+        compression_jobs_archiver,
+    );
+
     // job re-queuers
     let proof_compressor_job_requeuer = ProofCompressorJobRequeuer::new(
-        proof_compressor_config.max_attempts,
-        proof_compressor_config.generation_timeout(),
+        proof_compressor_config.requeue_max_attempts(),
+        proof_compressor_config.processing_timeout(),
     );
     task_runner.add(
         "ProofCompressorJobRequeuer",
@@ -182,7 +191,7 @@ fn get_tasks(
     );
 
     let witness_generator_job_requeuer = WitnessGeneratorJobRequeuer::new(
-        witness_generator_config.max_attempts,
+        witness_generator_config.witness_generation_max_attempts(),
         witness_generator_config.witness_generation_timeouts(),
     );
     task_runner.add(
@@ -213,6 +222,12 @@ fn get_tasks(
         witness_generator_queue_reporter,
     );
 
+    task_runner.add(
+        "ProvingPauseReporter",
+        prover_job_monitor_config.prover_queue_reporter_run_interval(),
+        ProvingPauseReporter,
+    );
+
     // witness job queuer
     let witness_job_queuer = WitnessJobQueuer {};
     task_runner.add(
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, LabeledFamily, Metrics};
 use zksync_types::protocol_version::ProtocolSemanticVersion;
 
@@ -6,8 +8,14 @@ use zksync_types::protocol_version::ProtocolSemanticVersion;
 pub(crate) struct ProverJobMonitorMetrics {
     pub prover_job_archived: Counter,
     pub gpu_prover_archived: Counter,
+    pub compression_job_archived: Counter,
+    pub witness_job_archived: Counter,
+    /// Time by which archiving of the oldest archivable compression job is overdue.
+    pub compression_jobs_archive_lag: Gauge<Duration>,
     #[metrics(labels = ["job_type"])]
     pub reached_max_attempts: LabeledFamily<JobType, Gauge>,
+    /// Whether dispensing of proving jobs is paused (1) or not (0).
+    pub proving_paused: Gauge<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
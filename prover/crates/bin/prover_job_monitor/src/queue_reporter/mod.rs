@@ -1,7 +1,9 @@
 pub use proof_compressor_queue_reporter::ProofCompressorQueueReporter;
 pub use prover_queue_reporter::ProverQueueReporter;
+pub use proving_pause_reporter::ProvingPauseReporter;
 pub use witness_generator_queue_reporter::WitnessGeneratorQueueReporter;
 
 mod proof_compressor_queue_reporter;
 mod prover_queue_reporter;
+mod proving_pause_reporter;
 mod witness_generator_queue_reporter;
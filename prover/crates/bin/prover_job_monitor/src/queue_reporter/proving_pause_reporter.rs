@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use zksync_prover_dal::{Connection, Prover, ProverDal};
+
+use crate::{metrics::PROVER_JOB_MONITOR_METRICS, task_wiring::Task};
+
+/// `ProvingPauseReporter` is a task that reports whether proving is paused.
+#[derive(Debug)]
+pub struct ProvingPauseReporter;
+
+#[async_trait]
+impl Task for ProvingPauseReporter {
+    async fn invoke(&self, connection: &mut Connection<Prover>) -> anyhow::Result<()> {
+        let pause = connection.proving_pauses_dal().get_pause().await?;
+        if let Some(pause) = &pause {
+            tracing::warn!(
+                "Proving is paused since {} (reason: {:?}).",
+                pause.paused_at,
+                pause.reason
+            );
+        }
+        PROVER_JOB_MONITOR_METRICS
+            .proving_paused
+            .set(pause.is_some().into());
+        Ok(())
+    }
+}
@@ -1,9 +1,20 @@
-use std::time::Instant;
+use std::{io, time::Instant};
 
 use async_trait::async_trait;
-use zksync_object_store::ObjectStore;
+use zksync_config::configs::{
+    fri_witness_generator::ArtifactsCompressionAlgorithm, FriWitnessGeneratorConfig,
+};
+use zksync_object_store::{_reexports::BoxedError, Bucket, ObjectStore, StoredObject};
 use zksync_prover_dal::{ConnectionPool, Prover};
 
+use crate::metrics::WITNESS_GENERATOR_METRICS;
+
+/// Prefix of compressed artifacts. It's followed by a byte identifying the compression algorithm
+/// and the compressed payload.
+const COMPRESSED_ARTIFACT_MAGIC: &[u8; 7] = b"zkwgcmp";
+const ZSTD_TAG: u8 = 1;
+const LZ4_TAG: u8 = 2;
+
 #[derive(Debug)]
 pub struct AggregationBlobUrls {
     pub aggregation_urls: String,
@@ -26,6 +37,7 @@ pub trait ArtifactsManager {
         job_id: u32,
         artifacts: Self::OutputArtifacts,
         object_store: &dyn ObjectStore,
+        compression: ArtifactsCompression,
     ) -> Self::BlobUrls;
 
     async fn save_to_database(
@@ -36,3 +48,147 @@ pub trait ArtifactsManager {
         artifacts: Self::OutputArtifacts,
     ) -> anyhow::Result<()>;
 }
+
+/// Compression of artifacts passed between witness generator rounds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArtifactsCompression {
+    algorithm: ArtifactsCompressionAlgorithm,
+    level: Option<i32>,
+}
+
+impl ArtifactsCompression {
+    pub fn new(config: &FriWitnessGeneratorConfig) -> Self {
+        Self {
+            algorithm: config.artifacts_compression,
+            level: config.artifacts_compression_level,
+        }
+    }
+
+    fn algorithm_label(&self) -> &'static str {
+        match self.algorithm {
+            ArtifactsCompressionAlgorithm::None => "none",
+            ArtifactsCompressionAlgorithm::Zstd => "zstd",
+            ArtifactsCompressionAlgorithm::Lz4 => "lz4",
+        }
+    }
+
+    fn compress(&self, raw: Vec<u8>) -> io::Result<Vec<u8>> {
+        let (tag, payload) = match self.algorithm {
+            ArtifactsCompressionAlgorithm::None => return Ok(raw),
+            ArtifactsCompressionAlgorithm::Zstd => {
+                let level = self.level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                (ZSTD_TAG, zstd::bulk::compress(&raw, level)?)
+            }
+            ArtifactsCompressionAlgorithm::Lz4 => (LZ4_TAG, lz4_flex::compress_prepend_size(&raw)),
+        };
+
+        let mut compressed =
+            Vec::with_capacity(COMPRESSED_ARTIFACT_MAGIC.len() + 1 + payload.len());
+        compressed.extend_from_slice(COMPRESSED_ARTIFACT_MAGIC);
+        compressed.push(tag);
+        compressed.extend_from_slice(&payload);
+
+        let label = self.algorithm_label();
+        WITNESS_GENERATOR_METRICS.artifact_raw_size_bytes[&label].observe(raw.len());
+        WITNESS_GENERATOR_METRICS.artifact_compressed_size_bytes[&label].observe(compressed.len());
+        Ok(compressed)
+    }
+
+    /// Decompresses an artifact. Artifacts without the compression prefix are returned as is.
+    fn decompress(bytes: Vec<u8>) -> io::Result<Vec<u8>> {
+        let Some(rest) = bytes.strip_prefix(COMPRESSED_ARTIFACT_MAGIC) else {
+            return Ok(bytes);
+        };
+        let Some((&tag, payload)) = rest.split_first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "compressed artifact is truncated",
+            ));
+        };
+        match tag {
+            ZSTD_TAG => zstd::stream::decode_all(payload),
+            LZ4_TAG => lz4_flex::decompress_size_prepended(payload)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unknown artifact compression algorithm: {tag}"),
+            )),
+        }
+    }
+}
+
+/// Artifact stored in the object store with the specified compression. When read, both compressed and uncompressed
+/// artifacts are accepted.
+#[derive(Debug)]
+pub struct CompressedArtifact<V> {
+    value: V,
+    compression: ArtifactsCompression,
+}
+
+impl<V> CompressedArtifact<V> {
+    pub fn new(value: V, compression: ArtifactsCompression) -> Self {
+        Self { value, compression }
+    }
+
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+}
+
+impl<V: StoredObject> StoredObject for CompressedArtifact<V> {
+    const BUCKET: Bucket = V::BUCKET;
+    type Key<'a> = V::Key<'a>;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        V::encode_key(key)
+    }
+
+    fn serialize(&self) -> Result<Vec<u8>, BoxedError> {
+        let raw = self.value.serialize()?;
+        Ok(self.compression.compress(raw)?)
+    }
+
+    fn deserialize(bytes: Vec<u8>) -> Result<Self, BoxedError> {
+        let raw = ArtifactsCompression::decompress(bytes)?;
+        Ok(Self::new(
+            V::deserialize(raw)?,
+            ArtifactsCompression::default(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressing_artifacts() {
+        let raw = b"artifact ".repeat(1_000);
+        for algorithm in [
+            ArtifactsCompressionAlgorithm::None,
+            ArtifactsCompressionAlgorithm::Zstd,
+            ArtifactsCompressionAlgorithm::Lz4,
+        ] {
+            let compression = ArtifactsCompression {
+                algorithm,
+                level: None,
+            };
+            let compressed = compression.compress(raw.clone()).unwrap();
+            if algorithm != ArtifactsCompressionAlgorithm::None {
+                assert!(compressed.starts_with(COMPRESSED_ARTIFACT_MAGIC));
+                assert!(compressed.len() < raw.len());
+            }
+            let decompressed = ArtifactsCompression::decompress(compressed).unwrap();
+            assert_eq!(decompressed, raw);
+        }
+    }
+
+    #[test]
+    fn decompressing_invalid_artifact() {
+        let mut bytes = COMPRESSED_ARTIFACT_MAGIC.to_vec();
+        ArtifactsCompression::decompress(bytes.clone()).unwrap_err();
+        bytes.push(u8::MAX);
+        let err = ArtifactsCompression::decompress(bytes).unwrap_err();
+        assert!(err.to_string().contains("unknown"), "{err}");
+    }
+}
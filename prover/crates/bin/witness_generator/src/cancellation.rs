@@ -0,0 +1,108 @@
+//! Cancellation of jobs processed by the witness generator, e.g. because the corresponding batch was reverted.
+
+use std::time::Duration;
+
+use tokio::{sync::watch, task::JoinHandle};
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+use zksync_types::basic_fri_types::AggregationRound;
+
+/// Interval between checks whether a processed job was cancelled.
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Signals that the processed job was cancelled. Cheaply cloneable; all clones observe the same signal.
+#[derive(Debug, Clone)]
+pub struct JobCancellation(watch::Receiver<bool>);
+
+impl JobCancellation {
+    fn new() -> (watch::Sender<bool>, Self) {
+        let (sender, receiver) = watch::channel(false);
+        (sender, Self(receiver))
+    }
+
+    /// Creates a cancellation that is never signalled.
+    pub fn never() -> Self {
+        Self::new().1
+    }
+
+    /// Starts a task polling the database for the cancellation of the specified job.
+    /// The task should be aborted once the job is processed.
+    pub(crate) fn watch(
+        connection_pool: ConnectionPool<Prover>,
+        round: AggregationRound,
+        job_id: u32,
+    ) -> (Self, JoinHandle<()>) {
+        let (sender, cancellation) = Self::new();
+        let watcher = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(CANCELLATION_POLL_INTERVAL).await;
+                let is_cancelled = async {
+                    let mut connection = connection_pool.connection().await?;
+                    let is_cancelled = connection
+                        .fri_witness_generator_dal()
+                        .is_witness_job_cancelled(job_id, round)
+                        .await?;
+                    anyhow::Ok(is_cancelled)
+                };
+                match is_cancelled.await {
+                    Ok(true) => {
+                        tracing::info!("{round:?} job {job_id} was cancelled; aborting");
+                        sender.send_replace(true);
+                        return;
+                    }
+                    Ok(false) => {}
+                    Err(err) => {
+                        tracing::warn!(
+                            "Failed checking cancellation of {round:?} job {job_id}: {err:#}"
+                        );
+                    }
+                }
+            }
+        });
+        (cancellation, watcher)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Returns an error if the job was cancelled.
+    pub fn check(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(!self.is_cancelled(), "job was cancelled");
+        Ok(())
+    }
+
+    /// Waits until the job is cancelled. Never resolves if the cancellation cannot be signalled.
+    pub async fn cancelled(&mut self) {
+        if self.0.wait_for(|&cancelled| cancelled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn signalling_cancellation() {
+        let (sender, mut cancellation) = JobCancellation::new();
+        let cloned = cancellation.clone();
+        assert!(!cloned.is_cancelled());
+        cloned.check().unwrap();
+
+        let wait_task = tokio::spawn(async move { cancellation.cancelled().await });
+        sender.send_replace(true);
+        wait_task.await.unwrap();
+        assert!(cloned.is_cancelled());
+        cloned.check().unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn never_signalled_cancellation() {
+        let mut cancellation = JobCancellation::never();
+        assert!(!cancellation.is_cancelled());
+        let wait_result =
+            tokio::time::timeout(Duration::from_millis(10), cancellation.cancelled()).await;
+        assert!(wait_result.is_err());
+    }
+}
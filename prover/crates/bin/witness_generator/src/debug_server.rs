@@ -0,0 +1,227 @@
+//! Local HTTP server exposing jobs currently processed by the witness generator, so that operators
+//! can see what a witness generator pod is doing without attaching to its logs.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Context as _;
+use axum::{extract::State, routing::get, Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::{watch, Semaphore};
+use zksync_types::{basic_fri_types::AggregationRound, L1BatchNumber};
+
+/// Maximum number of recent failures retained by [`JobsTracker`].
+const MAX_RECENT_FAILURES: usize = 50;
+
+/// Job currently processed by the witness generator.
+#[derive(Debug, Clone, Serialize)]
+pub struct InFlightJob {
+    pub round: AggregationRound,
+    pub job_id: u32,
+    pub l1_batch_number: L1BatchNumber,
+    /// Circuit ID for rounds processing jobs per circuit (leaf and node aggregation).
+    pub circuit_id: Option<u8>,
+    pub started_at: DateTime<Utc>,
+    /// Number of circuits currently held in memory by the job.
+    pub circuits_in_flight: usize,
+    pub max_circuits_in_flight: usize,
+}
+
+/// Job recently failed by the witness generator.
+#[derive(Debug, Clone, Serialize)]
+pub struct FailedJob {
+    pub round: AggregationRound,
+    pub job_id: u32,
+    pub failed_at: DateTime<Utc>,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobsReport {
+    pub in_flight: Vec<InFlightJob>,
+    pub recent_failures: Vec<FailedJob>,
+}
+
+#[derive(Debug)]
+struct TrackedJob {
+    l1_batch_number: L1BatchNumber,
+    circuit_id: Option<u8>,
+    started_at: DateTime<Utc>,
+    circuits_semaphore: Arc<Semaphore>,
+    max_circuits_in_flight: usize,
+}
+
+#[derive(Debug, Default)]
+struct TrackerInner {
+    in_flight: HashMap<(AggregationRound, u32), TrackedJob>,
+    recent_failures: VecDeque<FailedJob>,
+}
+
+/// Tracks jobs processed by witness generators in the current process. Cheaply cloneable; all clones
+/// share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct JobsTracker(Arc<Mutex<TrackerInner>>);
+
+impl JobsTracker {
+    /// Registers a job as in flight. The job is considered finished once the returned guard is dropped.
+    pub(crate) fn start_job(
+        &self,
+        round: AggregationRound,
+        job_id: u32,
+        (l1_batch_number, circuit_id): (L1BatchNumber, Option<u8>),
+        circuits_semaphore: Arc<Semaphore>,
+        max_circuits_in_flight: usize,
+    ) -> InFlightJobGuard {
+        let job = TrackedJob {
+            l1_batch_number,
+            circuit_id,
+            started_at: Utc::now(),
+            circuits_semaphore,
+            max_circuits_in_flight,
+        };
+        self.0
+            .lock()
+            .expect("jobs tracker is poisoned")
+            .in_flight
+            .insert((round, job_id), job);
+        InFlightJobGuard {
+            tracker: self.clone(),
+            key: (round, job_id),
+        }
+    }
+
+    pub(crate) fn record_failure(&self, round: AggregationRound, job_id: u32, error: &str) {
+        let mut inner = self.0.lock().expect("jobs tracker is poisoned");
+        if inner.recent_failures.len() == MAX_RECENT_FAILURES {
+            inner.recent_failures.pop_front();
+        }
+        inner.recent_failures.push_back(FailedJob {
+            round,
+            job_id,
+            failed_at: Utc::now(),
+            error: error.to_owned(),
+        });
+    }
+
+    pub fn report(&self) -> JobsReport {
+        let inner = self.0.lock().expect("jobs tracker is poisoned");
+        let mut in_flight: Vec<_> = inner
+            .in_flight
+            .iter()
+            .map(|(&(round, job_id), job)| InFlightJob {
+                round,
+                job_id,
+                l1_batch_number: job.l1_batch_number,
+                circuit_id: job.circuit_id,
+                started_at: job.started_at,
+                circuits_in_flight: job
+                    .max_circuits_in_flight
+                    .saturating_sub(job.circuits_semaphore.available_permits()),
+                max_circuits_in_flight: job.max_circuits_in_flight,
+            })
+            .collect();
+        in_flight.sort_unstable_by_key(|job| job.started_at);
+        JobsReport {
+            in_flight,
+            // Most recent failures go first.
+            recent_failures: inner.recent_failures.iter().rev().cloned().collect(),
+        }
+    }
+}
+
+/// Removes the job from the in-flight jobs on drop, including the case when the job panics.
+#[derive(Debug)]
+pub(crate) struct InFlightJobGuard {
+    tracker: JobsTracker,
+    key: (AggregationRound, u32),
+}
+
+impl Drop for InFlightJobGuard {
+    fn drop(&mut self) {
+        // Don't panic on a poisoned mutex in `drop()`, since it may be invoked during unwinding.
+        if let Ok(mut inner) = self.tracker.0.lock() {
+            inner.in_flight.remove(&self.key);
+        }
+    }
+}
+
+async fn get_jobs(State(tracker): State<JobsTracker>) -> Json<JobsReport> {
+    Json(tracker.report())
+}
+
+/// Runs the debug server on `0.0.0.0:{port}` until a stop signal is received.
+pub async fn run_debug_server(
+    port: u16,
+    tracker: JobsTracker,
+    mut stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let bind_address = SocketAddr::from(([0, 0, 0, 0], port));
+    tracing::info!("Starting witness generator debug server on {bind_address}");
+    let listener = tokio::net::TcpListener::bind(bind_address)
+        .await
+        .with_context(|| {
+            format!("Failed binding witness generator debug server to {bind_address}")
+        })?;
+
+    let router = Router::new()
+        .route("/jobs", get(get_jobs))
+        .with_state(tracker);
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async move {
+            if stop_receiver.changed().await.is_err() {
+                tracing::warn!(
+                    "Stop signal sender for witness generator debug server was dropped without sending a signal"
+                );
+            }
+            tracing::info!("Stop signal received, witness generator debug server is shutting down");
+        })
+        .await
+        .context("witness generator debug server failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracking_in_flight_jobs() {
+        let tracker = JobsTracker::default();
+        let semaphore = Arc::new(Semaphore::new(4));
+        let guard = tracker.start_job(
+            AggregationRound::LeafAggregation,
+            1,
+            (L1BatchNumber(5), Some(3)),
+            semaphore.clone(),
+            4,
+        );
+        let _permit = semaphore.try_acquire().unwrap();
+
+        let report = tracker.report();
+        assert_eq!(report.in_flight.len(), 1);
+        let job = &report.in_flight[0];
+        assert_eq!(job.round, AggregationRound::LeafAggregation);
+        assert_eq!(job.l1_batch_number, L1BatchNumber(5));
+        assert_eq!(job.circuit_id, Some(3));
+        assert_eq!(job.circuits_in_flight, 1);
+
+        drop(guard);
+        assert!(tracker.report().in_flight.is_empty());
+    }
+
+    #[test]
+    fn recent_failures_are_capped() {
+        let tracker = JobsTracker::default();
+        for job_id in 0..(MAX_RECENT_FAILURES as u32 + 5) {
+            tracker.record_failure(AggregationRound::BasicCircuits, job_id, "oops");
+        }
+
+        let failures = tracker.report().recent_failures;
+        assert_eq!(failures.len(), MAX_RECENT_FAILURES);
+        assert_eq!(failures[0].job_id, MAX_RECENT_FAILURES as u32 + 4);
+        assert_eq!(failures.last().unwrap().job_id, 5);
+    }
+}
@@ -2,11 +2,15 @@
 #![feature(generic_const_exprs)]
 
 pub mod artifacts;
+pub mod cancellation;
+pub mod debug_server;
 pub mod metrics;
 pub mod precalculated_merkle_paths_provider;
+mod resource_usage;
 pub mod rounds;
 mod storage_oracle;
 #[cfg(test)]
 mod tests;
 pub mod utils;
+pub mod vk_cache;
 mod witness;
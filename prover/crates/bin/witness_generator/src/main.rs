@@ -20,6 +20,7 @@ use zksync_task_management::ManagedTasks;
 use zksync_types::{basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion};
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 use zksync_witness_generator::{
+    debug_server::{run_debug_server, JobsTracker},
     metrics::SERVER_METRICS,
     rounds::{
         BasicCircuits, LeafAggregation, NodeAggregation, RecursionTip, Scheduler, WitnessGenerator,
@@ -53,6 +54,10 @@ struct Opt {
     /// Path to the secrets file.
     #[structopt(long)]
     secrets_path: Option<std::path::PathBuf>,
+    /// Port for the local debug HTTP server listing jobs processed by this process.
+    /// The server is not started if not specified.
+    #[structopt(long)]
+    debug_server_port: Option<u16>,
 }
 
 /// Checks if the configuration locally matches the one in the database.
@@ -181,6 +186,15 @@ async fn main() -> anyhow::Result<()> {
     let mut tasks = Vec::new();
     tasks.push(tokio::spawn(prometheus_task));
 
+    let jobs_tracker = JobsTracker::default();
+    if let Some(port) = opt.debug_server_port {
+        tasks.push(tokio::spawn(run_debug_server(
+            port,
+            jobs_tracker.clone(),
+            stop_receiver.clone(),
+        )));
+    }
+
     for round in rounds {
         tracing::info!(
             "initializing the {:?} witness generator, batch size: {:?} with protocol_version: {:?}",
@@ -197,7 +211,8 @@ async fn main() -> anyhow::Result<()> {
                     connection_pool.clone(),
                     protocol_version,
                     keystore.clone(),
-                );
+                )
+                .with_jobs_tracker(jobs_tracker.clone());
                 generator.run(stop_receiver.clone(), opt.batch_size)
             }
             AggregationRound::LeafAggregation => {
@@ -207,7 +222,8 @@ async fn main() -> anyhow::Result<()> {
                     connection_pool.clone(),
                     protocol_version,
                     keystore.clone(),
-                );
+                )
+                .with_jobs_tracker(jobs_tracker.clone());
                 generator.run(stop_receiver.clone(), opt.batch_size)
             }
             AggregationRound::NodeAggregation => {
@@ -217,7 +233,8 @@ async fn main() -> anyhow::Result<()> {
                     connection_pool.clone(),
                     protocol_version,
                     keystore.clone(),
-                );
+                )
+                .with_jobs_tracker(jobs_tracker.clone());
                 generator.run(stop_receiver.clone(), opt.batch_size)
             }
             AggregationRound::RecursionTip => {
@@ -227,7 +244,8 @@ async fn main() -> anyhow::Result<()> {
                     connection_pool.clone(),
                     protocol_version,
                     keystore.clone(),
-                );
+                )
+                .with_jobs_tracker(jobs_tracker.clone());
                 generator.run(stop_receiver.clone(), opt.batch_size)
             }
             AggregationRound::Scheduler => {
@@ -237,7 +255,8 @@ async fn main() -> anyhow::Result<()> {
                     connection_pool.clone(),
                     protocol_version,
                     keystore.clone(),
-                );
+                )
+                .with_jobs_tracker(jobs_tracker.clone());
                 generator.run(stop_receiver.clone(), opt.batch_size)
             }
         };
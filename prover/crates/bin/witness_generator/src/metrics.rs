@@ -1,8 +1,25 @@
 use std::time::Duration;
 
-use vise::{Buckets, Family, Gauge, Histogram, LabeledFamily, Metrics};
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
+    Metrics,
+};
 use zksync_prover_fri_utils::metrics::StageLabel;
 
+/// Buckets for process RSS: 256 MiB to 512 GiB.
+const RSS_BUCKETS: Buckets = Buckets::exponential(268_435_456.0..=549_755_813_888.0, 2.0);
+/// Buckets for artifact sizes: 1 KiB to 4 GiB.
+const ARTIFACT_SIZE_BUCKETS: Buckets = Buckets::exponential(1_024.0..=4_294_967_296.0, 4.0);
+
+/// Kind of items stored in the process-wide verification key cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "kind", rename_all = "snake_case")]
+pub(crate) enum VkCacheKind {
+    BaseLayerVk,
+    RecursiveLayerVk,
+    LeafParams,
+}
+
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "prover_fri_witness_generator")]
 pub(crate) struct WitnessGeneratorMetrics {
@@ -14,6 +31,27 @@ pub(crate) struct WitnessGeneratorMetrics {
     pub witness_generation_time: Family<StageLabel, Histogram<Duration>>,
     #[metrics(buckets = Buckets::LATENCIES)]
     pub blob_save_time: Family<StageLabel, Histogram<Duration>>,
+    /// Time spent expanding packed initial bootloader heap contents into the full bootloader memory.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub bootloader_memory_expansion_time: Histogram<Duration>,
+    /// Time spent processing a job.
+    #[metrics(buckets = Buckets::exponential(1.0..=61440.0, 2.0))]
+    pub job_processing_time: Family<StageLabel, Histogram<Duration>>,
+    /// Peak RSS of the witness generator process observed while processing a job.
+    #[metrics(buckets = RSS_BUCKETS)]
+    pub job_peak_rss_bytes: Family<StageLabel, Histogram<u64>>,
+    /// Number of jobs in the prefetch queue, including ones that are still being prepared.
+    pub prefetch_queue_occupancy: Family<StageLabel, Gauge<usize>>,
+    /// Size of compressed artifacts before compression.
+    #[metrics(buckets = ARTIFACT_SIZE_BUCKETS, labels = ["algorithm"])]
+    pub artifact_raw_size_bytes: LabeledFamily<&'static str, Histogram<usize>>,
+    /// Size of compressed artifacts as saved to the object store.
+    #[metrics(buckets = ARTIFACT_SIZE_BUCKETS, labels = ["algorithm"])]
+    pub artifact_compressed_size_bytes: LabeledFamily<&'static str, Histogram<usize>>,
+    /// Number of verification key cache lookups served from the cache.
+    pub vk_cache_hits: Family<VkCacheKind, Counter>,
+    /// Number of verification key cache lookups that required loading or computing the item.
+    pub vk_cache_misses: Family<VkCacheKind, Counter>,
 }
 
 #[vise::register]
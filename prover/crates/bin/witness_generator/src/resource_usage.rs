@@ -0,0 +1,154 @@
+//! Accounting of time and memory consumed by witness generator jobs.
+
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
+use zksync_prover_fri_utils::metrics::StageLabel;
+use zksync_types::{basic_fri_types::AggregationRound, L1BatchNumber};
+
+use crate::metrics::WITNESS_GENERATOR_METRICS;
+
+/// Returns the resident set size of the current process, or `None` if it cannot be determined
+/// (e.g., on non-Linux platforms).
+fn current_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kib: u64 = line
+        .strip_prefix("VmRSS:")?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1_024)
+}
+
+/// Tracks time and peak RSS of the process while a job is processed.
+///
+/// RSS is sampled on a dedicated thread, so that sampling isn't affected by CPU-heavy witness generation
+/// blocking the Tokio runtime. Since RSS is measured for the entire process, it includes memory used by jobs
+/// processed concurrently.
+#[derive(Debug)]
+pub(crate) struct JobResourceTracker {
+    started_at: Instant,
+    stop_sender: mpsc::Sender<()>,
+    sampler: thread::JoinHandle<Option<u64>>,
+}
+
+impl JobResourceTracker {
+    const RSS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn start() -> Self {
+        let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+        let sampler = thread::spawn(move || {
+            let mut peak_rss_bytes = current_rss_bytes();
+            // Either a stop signal or the sender being dropped stops sampling.
+            while let Err(mpsc::RecvTimeoutError::Timeout) =
+                stop_receiver.recv_timeout(Self::RSS_POLL_INTERVAL)
+            {
+                peak_rss_bytes = peak_rss_bytes.max(current_rss_bytes());
+            }
+            peak_rss_bytes.max(current_rss_bytes())
+        });
+        Self {
+            started_at: Instant::now(),
+            stop_sender,
+            sampler,
+        }
+    }
+
+    pub async fn finish(self) -> JobResourceUsage {
+        let time_taken = self.started_at.elapsed();
+        self.stop_sender.send(()).ok();
+        // Joining a thread is blocking, so it's performed on a blocking thread rather than on a Tokio worker.
+        let sampler = self.sampler;
+        let peak_rss_bytes = match tokio::task::spawn_blocking(move || sampler.join()).await {
+            Ok(Ok(peak_rss_bytes)) => peak_rss_bytes,
+            Ok(Err(_)) => {
+                tracing::warn!("RSS sampler thread panicked");
+                None
+            }
+            Err(err) => {
+                tracing::warn!("Failed joining RSS sampler thread: {err}");
+                None
+            }
+        };
+        JobResourceUsage {
+            time_taken,
+            peak_rss_bytes,
+        }
+    }
+}
+
+/// Time and memory consumed by a witness generator job.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct JobResourceUsage {
+    pub time_taken: Duration,
+    pub peak_rss_bytes: Option<u64>,
+}
+
+impl JobResourceUsage {
+    /// Reports usage to metrics and persists it to the prover DB. Errors are logged rather than returned
+    /// since accounting shouldn't affect job processing.
+    pub async fn report(
+        self,
+        connection_pool: &ConnectionPool<Prover>,
+        round: AggregationRound,
+        job_id: u32,
+        (l1_batch_number, circuit_id): (L1BatchNumber, Option<u8>),
+    ) {
+        tracing::info!(
+            "{round:?} job {job_id} for batch {l1_batch_number} (circuit {circuit_id:?}) took {:?}, peak RSS: {:?} bytes",
+            self.time_taken,
+            self.peak_rss_bytes
+        );
+        let stage = StageLabel::from(round);
+        WITNESS_GENERATOR_METRICS.job_processing_time[&stage].observe(self.time_taken);
+        if let Some(peak_rss_bytes) = self.peak_rss_bytes {
+            WITNESS_GENERATOR_METRICS.job_peak_rss_bytes[&stage].observe(peak_rss_bytes);
+        }
+
+        let mut connection = match connection_pool.connection().await {
+            Ok(connection) => connection,
+            Err(err) => {
+                tracing::warn!("Failed to acquire DB connection to save job stats: {err:#}");
+                return;
+            }
+        };
+        let result = connection
+            .fri_witness_generator_dal()
+            .save_job_stats(
+                job_id,
+                round,
+                l1_batch_number,
+                circuit_id,
+                self.time_taken,
+                self.peak_rss_bytes,
+            )
+            .await;
+        if let Err(err) = result {
+            tracing::warn!("Failed to save stats for {round:?} job {job_id}: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn tracking_resource_usage() {
+        let tracker = JobResourceTracker::start();
+        tokio::time::sleep(JobResourceTracker::RSS_POLL_INTERVAL * 2).await;
+        let usage = tracker.finish().await;
+
+        assert!(usage.time_taken >= JobResourceTracker::RSS_POLL_INTERVAL * 2);
+        let peak_rss_bytes = usage.peak_rss_bytes.expect("RSS is not available");
+        assert!(peak_rss_bytes > 0);
+    }
+}
@@ -8,7 +8,7 @@ use zksync_prover_fri_utils::get_recursive_layer_circuit_id_for_base_layer;
 use zksync_types::{basic_fri_types::AggregationRound, L1BatchNumber};
 
 use crate::{
-    artifacts::ArtifactsManager,
+    artifacts::{ArtifactsCompression, ArtifactsManager, CompressedArtifact},
     rounds::basic_circuits::{
         utils::create_aggregation_jobs, BasicCircuitArtifacts, BasicCircuits,
         BasicWitnessGeneratorJob,
@@ -39,6 +39,7 @@ impl ArtifactsManager for BasicCircuits {
         job_id: u32,
         artifacts: Self::OutputArtifacts,
         object_store: &dyn ObjectStore,
+        compression: ArtifactsCompression,
     ) -> String {
         let aux_output_witness_wrapper =
             AuxOutputWitnessWrapper(artifacts.aux_output_witness.clone());
@@ -49,7 +50,10 @@ impl ArtifactsManager for BasicCircuits {
             .unwrap();
         let wrapper = SchedulerPartialInputWrapper(artifacts.scheduler_witness);
         object_store
-            .put(L1BatchNumber(job_id), &wrapper)
+            .put(
+                L1BatchNumber(job_id),
+                &CompressedArtifact::new(wrapper, compression),
+            )
             .await
             .unwrap()
     }
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use circuit_definitions::zkevm_circuits::scheduler::{
     block_header::BlockAuxilaryOutputWitness, input::SchedulerCircuitInstanceWitness,
 };
+use tokio::sync::Semaphore;
 use zkevm_test_harness::boojum::{
     field::goldilocks::{GoldilocksExt2, GoldilocksField},
     gadgets::recursion::recursive_tree_hasher::CircuitGoldilocksPoseidon2Sponge,
@@ -12,15 +13,17 @@ use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_fri_types::get_current_pod_name;
 use zksync_prover_interface::inputs::WitnessInputData;
-use zksync_prover_keystore::keystore::Keystore;
 use zksync_types::{
     basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion, L1BatchNumber,
 };
 
 use crate::{
     artifacts::ArtifactsManager,
+    cancellation::JobCancellation,
     metrics::WITNESS_GENERATOR_METRICS,
     rounds::{basic_circuits::utils::generate_witness, JobManager},
+    utils::ProofsMemoryBudget,
+    vk_cache::CachedKeystore,
 };
 
 mod artifacts;
@@ -68,7 +71,9 @@ impl JobManager for BasicCircuits {
     async fn process_job(
         job: BasicWitnessGeneratorJob,
         object_store: Arc<dyn ObjectStore>,
-        max_circuits_in_flight: usize,
+        circuits_semaphore: Arc<Semaphore>,
+        _proofs_budget: ProofsMemoryBudget,
+        _cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<BasicCircuitArtifacts> {
         let BasicWitnessGeneratorJob {
@@ -83,7 +88,7 @@ impl JobManager for BasicCircuits {
         );
 
         let (circuit_urls, queue_urls, scheduler_witness, aux_output_witness) =
-            generate_witness(block_number, object_store, job, max_circuits_in_flight).await;
+            generate_witness(block_number, object_store, job, circuits_semaphore).await;
         WITNESS_GENERATOR_METRICS.witness_generation_time[&AggregationRound::BasicCircuits.into()]
             .observe(started_at.elapsed());
         tracing::info!(
@@ -103,7 +108,7 @@ impl JobManager for BasicCircuits {
     async fn prepare_job(
         metadata: L1BatchNumber,
         object_store: &dyn ObjectStore,
-        _keystore: Keystore,
+        _keystore: CachedKeystore,
     ) -> anyhow::Result<Self::Job> {
         tracing::info!("Processing FRI basic witness-gen for block {}", metadata.0);
         let started_at = Instant::now();
@@ -115,6 +120,10 @@ impl JobManager for BasicCircuits {
         Ok(job)
     }
 
+    fn job_location(job: &BasicWitnessGeneratorJob) -> (L1BatchNumber, Option<u8>) {
+        (job.block_number, None)
+    }
+
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
@@ -28,6 +28,7 @@ use zksync_system_constants::BOOTLOADER_ADDRESS;
 use zksync_types::{protocol_version::ProtocolSemanticVersion, L1BatchNumber};
 
 use crate::{
+    metrics::WITNESS_GENERATOR_METRICS,
     precalculated_merkle_paths_provider::PrecalculatedMerklePathsProvider,
     rounds::basic_circuits::Witness,
     storage_oracle::StorageOracle,
@@ -42,12 +43,16 @@ pub(super) async fn generate_witness(
     block_number: L1BatchNumber,
     object_store: Arc<dyn ObjectStore>,
     input: WitnessInputData,
-    max_circuits_in_flight: usize,
+    semaphore: Arc<Semaphore>,
 ) -> Witness {
+    let expansion_latency = WITNESS_GENERATOR_METRICS
+        .bootloader_memory_expansion_time
+        .start();
     let bootloader_contents = expand_bootloader_contents(
         &input.vm_run_data.initial_heap_content,
         input.vm_run_data.protocol_version,
     );
+    expansion_latency.observe();
 
     let tree = PrecalculatedMerklePathsProvider::new(
         input.merkle_paths,
@@ -139,8 +144,6 @@ pub(super) async fn generate_witness(
     })
     .instrument(make_circuits_span);
 
-    let semaphore = Arc::new(Semaphore::new(max_circuits_in_flight));
-
     let mut save_circuit_handles = vec![];
 
     let save_circuits_span = tracing::info_span!("save_circuits");
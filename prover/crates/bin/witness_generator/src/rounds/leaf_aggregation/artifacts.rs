@@ -8,7 +8,7 @@ use zksync_prover_fri_utils::get_recursive_layer_circuit_id_for_base_layer;
 use zksync_types::{basic_fri_types::AggregationRound, prover_dal::LeafAggregationJobMetadata};
 
 use crate::{
-    artifacts::{AggregationBlobUrls, ArtifactsManager},
+    artifacts::{AggregationBlobUrls, ArtifactsCompression, ArtifactsManager, CompressedArtifact},
     metrics::WITNESS_GENERATOR_METRICS,
     rounds::leaf_aggregation::{LeafAggregation, LeafAggregationArtifacts},
     utils::{AggregationWrapper, ClosedFormInputWrapper},
@@ -31,11 +31,11 @@ impl ArtifactsManager for LeafAggregation {
         };
 
         let artifacts = object_store
-            .get(key)
+            .get::<CompressedArtifact<ClosedFormInputWrapper>>(key)
             .await
             .unwrap_or_else(|_| panic!("leaf aggregation job artifacts missing: {:?}", key));
 
-        Ok(artifacts)
+        Ok(artifacts.into_inner())
     }
 
     #[tracing::instrument(
@@ -46,6 +46,7 @@ impl ArtifactsManager for LeafAggregation {
         _job_id: u32,
         artifacts: Self::OutputArtifacts,
         object_store: &dyn ObjectStore,
+        compression: ArtifactsCompression,
     ) -> AggregationBlobUrls {
         let started_at = Instant::now();
         let key = AggregationsKey {
@@ -54,7 +55,10 @@ impl ArtifactsManager for LeafAggregation {
             depth: 0,
         };
         let aggregation_urls = object_store
-            .put(key, &AggregationWrapper(artifacts.aggregations))
+            .put(
+                key,
+                &CompressedArtifact::new(AggregationWrapper(artifacts.aggregations), compression),
+            )
             .await
             .unwrap();
 
@@ -2,13 +2,9 @@ use std::{sync::Arc, time::Instant};
 
 use anyhow::Context as _;
 use async_trait::async_trait;
-use circuit_definitions::circuit_definitions::recursion_layer::base_circuit_type_into_recursive_leaf_circuit_type;
 use tokio::sync::Semaphore;
-use zkevm_test_harness::{
-    witness::recursive_aggregation::{
-        compute_leaf_params, create_leaf_witness, split_recursion_queue,
-    },
-    zkevm_circuits::scheduler::aux::BaseLayerCircuitType,
+use zkevm_test_harness::witness::recursive_aggregation::{
+    create_leaf_witness, split_recursion_queue,
 };
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
@@ -23,7 +19,6 @@ use zksync_prover_fri_types::{
     },
     get_current_pod_name, FriProofWrapper,
 };
-use zksync_prover_keystore::keystore::Keystore;
 use zksync_types::{
     basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion,
     prover_dal::LeafAggregationJobMetadata, L1BatchNumber,
@@ -31,12 +26,14 @@ use zksync_types::{
 
 use crate::{
     artifacts::ArtifactsManager,
+    cancellation::JobCancellation,
     metrics::WITNESS_GENERATOR_METRICS,
     rounds::JobManager,
     utils::{
         load_proofs_for_job_ids, save_recursive_layer_prover_input_artifacts,
-        ClosedFormInputWrapper,
+        ClosedFormInputWrapper, LoadedProofs, ProofsMemoryBudget,
     },
+    vk_cache::CachedKeystore,
 };
 
 mod artifacts;
@@ -77,7 +74,9 @@ impl JobManager for LeafAggregation {
     async fn process_job(
         job: LeafAggregationWitnessGeneratorJob,
         object_store: Arc<dyn ObjectStore>,
-        max_circuits_in_flight: usize,
+        circuits_semaphore: Arc<Semaphore>,
+        proofs_budget: ProofsMemoryBudget,
+        cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<LeafAggregationArtifacts> {
         tracing::info!(
@@ -107,13 +106,13 @@ impl JobManager for LeafAggregation {
             proofs_ids.push(proofs_ids_for_queue);
         }
 
-        let semaphore = Arc::new(Semaphore::new(max_circuits_in_flight));
-
         let mut handles = vec![];
         for (circuit_idx, (queue, proofs_ids_for_queue)) in
             queues.into_iter().zip(proofs_ids).enumerate()
         {
-            let semaphore = semaphore.clone();
+            let semaphore = circuits_semaphore.clone();
+            let proofs_budget = proofs_budget.clone();
+            let cancellation = cancellation.clone();
 
             let object_store = object_store.clone();
             let queue = queue.clone();
@@ -125,8 +124,16 @@ impl JobManager for LeafAggregation {
                     .acquire()
                     .await
                     .expect("failed to get permit to process queues chunk");
-
-                let proofs = load_proofs_for_job_ids(&proofs_ids_for_queue, &*object_store).await;
+                // Chunks waiting for a permit are skipped once the job is cancelled.
+                if cancellation.is_cancelled() {
+                    return vec![];
+                }
+
+                let LoadedProofs {
+                    proofs,
+                    reservation,
+                } = load_proofs_for_job_ids(&proofs_ids_for_queue, &*object_store, &proofs_budget)
+                    .await;
                 let base_proofs = proofs
                     .into_iter()
                     .map(|wrapper| match wrapper {
@@ -147,6 +154,8 @@ impl JobManager for LeafAggregation {
                     &base_vk,
                     &leaf_params,
                 );
+                // Proofs are consumed by the witness; release their space for other queues.
+                drop(reservation);
 
                 save_recursive_layer_prover_input_artifacts(
                     job.block_number,
@@ -164,6 +173,7 @@ impl JobManager for LeafAggregation {
         }
 
         let circuit_ids_and_urls_results = futures::future::join_all(handles).await;
+        cancellation.check()?;
         let circuit_ids_and_urls = circuit_ids_and_urls_results
             .into_iter()
             .flat_map(|x| x.unwrap())
@@ -196,7 +206,7 @@ impl JobManager for LeafAggregation {
     async fn prepare_job(
         metadata: LeafAggregationJobMetadata,
         object_store: &dyn ObjectStore,
-        keystore: Keystore,
+        keystore: CachedKeystore,
     ) -> anyhow::Result<LeafAggregationWitnessGeneratorJob> {
         let started_at = Instant::now();
         let closed_form_input = Self::get_artifacts(&metadata, object_store).await?;
@@ -209,14 +219,9 @@ impl JobManager for LeafAggregation {
             .load_base_layer_verification_key(metadata.circuit_id)
             .context("get_base_layer_vk_for_circuit_type()")?;
 
-        let leaf_circuit_id = base_circuit_type_into_recursive_leaf_circuit_type(
-            BaseLayerCircuitType::from_numeric_value(metadata.circuit_id),
-        ) as u8;
-
-        let leaf_vk = keystore
-            .load_recursive_layer_verification_key(leaf_circuit_id)
-            .context("get_recursive_layer_vk_for_circuit_type()")?;
-        let leaf_params = compute_leaf_params(metadata.circuit_id, base_vk.clone(), leaf_vk);
+        let leaf_params = keystore
+            .leaf_params(metadata.circuit_id)
+            .context("leaf_params()")?;
 
         WITNESS_GENERATOR_METRICS.prepare_job_time[&AggregationRound::LeafAggregation.into()]
             .observe(started_at.elapsed());
@@ -231,6 +236,10 @@ impl JobManager for LeafAggregation {
         })
     }
 
+    fn job_location(job: &LeafAggregationWitnessGeneratorJob) -> (L1BatchNumber, Option<u8>) {
+        (job.block_number, Some(job.circuit_id))
+    }
+
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
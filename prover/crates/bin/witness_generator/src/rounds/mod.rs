@@ -1,20 +1,29 @@
-use std::{marker::PhantomData, sync::Arc, time::Instant};
+use std::{future::Future, marker::PhantomData, sync::Arc, time::Instant};
 
 use anyhow::Context;
 use async_trait::async_trait;
-use tokio::task::JoinHandle;
+use tokio::{sync::Semaphore, task::JoinHandle};
 use zksync_config::configs::FriWitnessGeneratorConfig;
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_keystore::keystore::Keystore;
 use zksync_queued_job_processor::JobProcessor;
-use zksync_types::protocol_version::ProtocolSemanticVersion;
+use zksync_types::{protocol_version::ProtocolSemanticVersion, L1BatchNumber};
 
-use crate::artifacts::ArtifactsManager;
+use self::prefetch::JobPrefetchQueue;
+use crate::{
+    artifacts::{ArtifactsCompression, ArtifactsManager},
+    cancellation::JobCancellation,
+    debug_server::JobsTracker,
+    resource_usage::JobResourceTracker,
+    utils::ProofsMemoryBudget,
+    vk_cache::CachedKeystore,
+};
 
 mod basic_circuits;
 mod leaf_aggregation;
 mod node_aggregation;
+mod prefetch;
 mod recursion_tip;
 mod scheduler;
 
@@ -38,35 +47,64 @@ pub trait JobManager: ArtifactsManager {
     async fn process_job(
         job: Self::Job,
         object_store: Arc<dyn ObjectStore>,
-        max_circuits_in_flight: usize,
+        circuits_semaphore: Arc<Semaphore>,
+        proofs_budget: ProofsMemoryBudget,
+        cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<Self::OutputArtifacts>;
 
     async fn prepare_job(
         metadata: Self::Metadata,
         object_store: &dyn ObjectStore,
-        keystore: Keystore,
+        keystore: CachedKeystore,
     ) -> anyhow::Result<Self::Job>;
 
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
     ) -> anyhow::Result<Option<(u32, Self::Metadata)>>;
+
+    /// Returns the L1 batch number of the job and, for rounds processing jobs per circuit, its circuit ID.
+    fn job_location(job: &Self::Job) -> (L1BatchNumber, Option<u8>);
 }
 
 #[derive(Debug)]
-pub struct WitnessGenerator<R> {
+pub struct WitnessGenerator<R: JobManager> {
     pub config: FriWitnessGeneratorConfig,
     pub object_store: Arc<dyn ObjectStore>,
     pub connection_pool: ConnectionPool<Prover>,
     pub protocol_version: ProtocolSemanticVersion,
     pub keystore: Keystore,
+    jobs_tracker: JobsTracker,
+    prefetch_queue: JobPrefetchQueue<R>,
+    artifacts_compression: ArtifactsCompression,
     _round: PhantomData<R>,
 }
 
+/// Picks the next job for the round and loads its input artifacts.
+async fn fetch_job<R: JobManager>(
+    connection_pool: ConnectionPool<Prover>,
+    protocol_version: ProtocolSemanticVersion,
+    object_store: Arc<dyn ObjectStore>,
+    keystore: Keystore,
+) -> anyhow::Result<Option<(u32, R::Job)>> {
+    let Some((id, metadata)) = R::get_metadata(connection_pool, protocol_version)
+        .await
+        .context("get_metadata()")?
+    else {
+        return Ok(None);
+    };
+    tracing::info!("Processing {:?} job {:?}", R::ROUND, id);
+    let keystore = CachedKeystore::new(keystore, protocol_version);
+    let job = R::prepare_job(metadata, &*object_store, keystore)
+        .await
+        .context("prepare_job()")?;
+    Ok(Some((id, job)))
+}
+
 impl<R> WitnessGenerator<R>
 where
-    R: JobManager + ArtifactsManager,
+    R: JobManager + ArtifactsManager + 'static,
 {
     pub fn new(
         config: FriWitnessGeneratorConfig,
@@ -75,21 +113,43 @@ where
         protocol_version: ProtocolSemanticVersion,
         keystore: Keystore,
     ) -> Self {
+        let prefetch_queue = JobPrefetchQueue::new(config.prefetch_depth);
+        let artifacts_compression = ArtifactsCompression::new(&config);
         Self {
             config,
             object_store,
             connection_pool,
             protocol_version,
             keystore,
+            jobs_tracker: JobsTracker::default(),
+            prefetch_queue,
+            artifacts_compression,
             _round: Default::default(),
         }
     }
+
+    /// Reports jobs processed by this generator to the provided tracker (e.g., one exposed via the debug server).
+    pub fn with_jobs_tracker(mut self, jobs_tracker: JobsTracker) -> Self {
+        self.jobs_tracker = jobs_tracker;
+        self
+    }
+
+    fn fetch_job(
+        &self,
+    ) -> impl Future<Output = anyhow::Result<Option<(u32, R::Job)>>> + Send + 'static {
+        fetch_job::<R>(
+            self.connection_pool.clone(),
+            self.protocol_version,
+            self.object_store.clone(),
+            self.keystore.clone(),
+        )
+    }
 }
 
 #[async_trait]
 impl<R> JobProcessor for WitnessGenerator<R>
 where
-    R: JobManager + ArtifactsManager + Send + Sync,
+    R: JobManager + ArtifactsManager + Send + Sync + 'static,
 {
     type Job = R::Job;
     type JobId = u32;
@@ -98,24 +158,43 @@ where
     const SERVICE_NAME: &'static str = R::SERVICE_NAME;
 
     async fn get_next_job(&self) -> anyhow::Result<Option<(Self::JobId, Self::Job)>> {
-        if let Some((id, metadata)) =
-            R::get_metadata(self.connection_pool.clone(), self.protocol_version)
-                .await
-                .context("get_metadata()")?
-        {
-            tracing::info!("Processing {:?} job {:?}", R::ROUND, id);
-            Ok(Some((
-                id,
-                R::prepare_job(metadata, &*self.object_store, self.keystore.clone())
+        if !self.prefetch_queue.is_enabled() {
+            return self.fetch_job().await;
+        }
+
+        // A prefetched job may be empty if there were no jobs to pick at the time; re-check in this case.
+        let job = match self.prefetch_queue.pop().await.transpose()?.flatten() {
+            Some((job_id, job)) => {
+                // The job was marked as in progress when it was prefetched; restart its processing timeout,
+                // so that time spent in the queue isn't counted towards it.
+                self.connection_pool
+                    .connection()
+                    .await
+                    .context(format!(
+                        "failed to acquire DB connection for {:?}",
+                        R::ROUND
+                    ))?
+                    .fri_witness_generator_dal()
+                    .refresh_processing_started_at(job_id, R::ROUND)
                     .await
-                    .context("prepare_job()")?,
-            )))
-        } else {
-            Ok(None)
+                    .context(format!(
+                        "failed to refresh processing start time for {:?} job {job_id}",
+                        R::ROUND
+                    ))?;
+                Some((job_id, job))
+            }
+            None => self.fetch_job().await?,
+        };
+        // Only prefetch if there is a backlog of jobs, so that an idle witness generator doesn't poll the database
+        // more often than without prefetching.
+        if job.is_some() {
+            self.prefetch_queue.fill(|| self.fetch_job());
         }
+        Ok(job)
     }
 
     async fn save_failure(&self, job_id: Self::JobId, _started_at: Instant, error: String) {
+        self.jobs_tracker.record_failure(R::ROUND, job_id, &error);
         self.connection_pool
             .connection()
             .await
@@ -127,14 +206,50 @@ where
 
     async fn process_job(
         &self,
-        _job_id: &Self::JobId,
+        job_id: &Self::JobId,
         job: Self::Job,
         started_at: Instant,
     ) -> JoinHandle<anyhow::Result<Self::JobArtifacts>> {
         let object_store = self.object_store.clone();
+        let connection_pool = self.connection_pool.clone();
+        let job_id = *job_id;
+        let job_location = R::job_location(&job);
         let max_circuits_in_flight = self.config.max_circuits_in_flight;
+        let circuits_semaphore = Arc::new(Semaphore::new(max_circuits_in_flight));
+        let in_flight_guard = self.jobs_tracker.start_job(
+            R::ROUND,
+            job_id,
+            job_location,
+            circuits_semaphore.clone(),
+            max_circuits_in_flight,
+        );
+        let proofs_budget = ProofsMemoryBudget::new(self.config.max_proofs_in_memory);
+        let (cancellation, cancellation_watcher) =
+            JobCancellation::watch(connection_pool.clone(), R::ROUND, job_id);
         tokio::spawn(async move {
-            R::process_job(job, object_store, max_circuits_in_flight, started_at).await
+            let _in_flight_guard = in_flight_guard;
+            let resource_tracker = JobResourceTracker::start();
+            let mut cancelled = cancellation.clone();
+            let result = tokio::select! {
+                result = R::process_job(
+                    job,
+                    object_store,
+                    circuits_semaphore,
+                    proofs_budget,
+                    cancellation,
+                    started_at,
+                ) => result,
+                () = cancelled.cancelled() => {
+                    Err(anyhow::anyhow!("{:?} job {job_id} was cancelled", R::ROUND))
+                }
+            };
+            cancellation_watcher.abort();
+            resource_tracker
+                .finish()
+                .await
+                .report(&connection_pool, R::ROUND, job_id, job_location)
+                .await;
+            result
         })
     }
 
@@ -145,11 +260,35 @@ where
         started_at: Instant,
         artifacts: Self::JobArtifacts,
     ) -> anyhow::Result<()> {
+        let is_cancelled = self
+            .connection_pool
+            .connection()
+            .await
+            .context("failed to acquire DB connection")?
+            .fri_witness_generator_dal()
+            .is_witness_job_cancelled(job_id, R::ROUND)
+            .await
+            .context("is_witness_job_cancelled()")?;
+        if is_cancelled {
+            // Cancelled jobs must not produce jobs for the following rounds.
+            tracing::info!(
+                "{:?} job {job_id} was cancelled; discarding its artifacts",
+                R::ROUND
+            );
+            return Ok(());
+        }
+
         tracing::info!("Saving {:?} artifacts for job {:?}", R::ROUND, job_id);
 
         let blob_save_started_at = Instant::now();
 
-        let blob_urls = R::save_to_bucket(job_id, artifacts.clone(), &*self.object_store).await;
+        let blob_urls = R::save_to_bucket(
+            job_id,
+            artifacts.clone(),
+            &*self.object_store,
+            self.artifacts_compression,
+        )
+        .await;
 
         WITNESS_GENERATOR_METRICS.blob_save_time[&R::ROUND.into()]
             .observe(blob_save_started_at.elapsed());
@@ -170,7 +309,9 @@ where
     }
 
     fn max_attempts(&self) -> u32 {
-        self.config.max_attempts
+        self.config
+            .witness_generation_max_attempts()
+            .for_round(R::ROUND)
     }
 
     async fn get_job_attempts(&self, job_id: &Self::JobId) -> anyhow::Result<u32> {
@@ -7,7 +7,7 @@ use zksync_prover_fri_types::keys::AggregationsKey;
 use zksync_types::{basic_fri_types::AggregationRound, prover_dal::NodeAggregationJobMetadata};
 
 use crate::{
-    artifacts::{AggregationBlobUrls, ArtifactsManager},
+    artifacts::{AggregationBlobUrls, ArtifactsCompression, ArtifactsManager, CompressedArtifact},
     metrics::WITNESS_GENERATOR_METRICS,
     rounds::node_aggregation::{NodeAggregation, NodeAggregationArtifacts},
     utils::AggregationWrapper,
@@ -33,14 +33,17 @@ impl ArtifactsManager for NodeAggregation {
             circuit_id: metadata.circuit_id,
             depth: metadata.depth,
         };
-        let artifacts = object_store.get(key).await.unwrap_or_else(|error| {
-            panic!(
-                "node aggregation job artifacts getting error. Key: {:?}, error: {:?}",
-                key, error
-            )
-        });
+        let artifacts = object_store
+            .get::<CompressedArtifact<AggregationWrapper>>(key)
+            .await
+            .unwrap_or_else(|error| {
+                panic!(
+                    "node aggregation job artifacts getting error. Key: {:?}, error: {:?}",
+                    key, error
+                )
+            });
 
-        Ok(artifacts)
+        Ok(artifacts.into_inner())
     }
 
     #[tracing::instrument(
@@ -51,6 +54,7 @@ impl ArtifactsManager for NodeAggregation {
         _job_id: u32,
         artifacts: Self::OutputArtifacts,
         object_store: &dyn ObjectStore,
+        compression: ArtifactsCompression,
     ) -> AggregationBlobUrls {
         let started_at = Instant::now();
         let key = AggregationsKey {
@@ -59,7 +63,13 @@ impl ArtifactsManager for NodeAggregation {
             depth: artifacts.depth,
         };
         let aggregation_urls = object_store
-            .put(key, &AggregationWrapper(artifacts.next_aggregations))
+            .put(
+                key,
+                &CompressedArtifact::new(
+                    AggregationWrapper(artifacts.next_aggregations),
+                    compression,
+                ),
+            )
             .await
             .unwrap();
 
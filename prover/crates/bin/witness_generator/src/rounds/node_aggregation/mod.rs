@@ -20,7 +20,6 @@ use zksync_prover_fri_types::{
     },
     get_current_pod_name, FriProofWrapper,
 };
-use zksync_prover_keystore::{keystore::Keystore, utils::get_leaf_vk_params};
 use zksync_types::{
     basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion,
     prover_dal::NodeAggregationJobMetadata, L1BatchNumber,
@@ -28,9 +27,14 @@ use zksync_types::{
 
 use crate::{
     artifacts::ArtifactsManager,
+    cancellation::JobCancellation,
     metrics::WITNESS_GENERATOR_METRICS,
     rounds::JobManager,
-    utils::{load_proofs_for_job_ids, save_recursive_layer_prover_input_artifacts},
+    utils::{
+        load_proofs_for_job_ids, save_recursive_layer_prover_input_artifacts, LoadedProofs,
+        ProofsMemoryBudget,
+    },
+    vk_cache::CachedKeystore,
 };
 mod artifacts;
 
@@ -72,7 +76,9 @@ impl JobManager for NodeAggregation {
     async fn process_job(
         job: NodeAggregationWitnessGeneratorJob,
         object_store: Arc<dyn ObjectStore>,
-        max_circuits_in_flight: usize,
+        circuits_semaphore: Arc<Semaphore>,
+        proofs_budget: ProofsMemoryBudget,
+        cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<NodeAggregationArtifacts> {
         let node_vk_commitment = compute_node_vk_commitment(job.node_vk.clone());
@@ -100,8 +106,6 @@ impl JobManager for NodeAggregation {
             proofs_ids.len()
         );
 
-        let semaphore = Arc::new(Semaphore::new(max_circuits_in_flight));
-
         let mut handles = vec![];
         for (circuit_idx, (chunk, proofs_ids_for_chunk)) in job
             .aggregations
@@ -109,7 +113,9 @@ impl JobManager for NodeAggregation {
             .zip(proofs_ids)
             .enumerate()
         {
-            let semaphore = semaphore.clone();
+            let semaphore = circuits_semaphore.clone();
+            let proofs_budget = proofs_budget.clone();
+            let cancellation = cancellation.clone();
 
             let object_store = object_store.clone();
             let chunk = Vec::from(chunk);
@@ -121,8 +127,16 @@ impl JobManager for NodeAggregation {
                     .acquire()
                     .await
                     .expect("failed to get permit to process queues chunk");
+                // Chunks waiting for a permit are skipped once the job is cancelled.
+                if cancellation.is_cancelled() {
+                    return None;
+                }
 
-                let proofs = load_proofs_for_job_ids(&proofs_ids_for_chunk, &*object_store).await;
+                let LoadedProofs {
+                    proofs,
+                    reservation,
+                } = load_proofs_for_job_ids(&proofs_ids_for_chunk, &*object_store, &proofs_budget)
+                    .await;
                 let mut recursive_proofs = vec![];
                 for wrapper in proofs {
                     match wrapper {
@@ -145,6 +159,7 @@ impl JobManager for NodeAggregation {
                     node_vk_commitment,
                     &all_leafs_layer_params,
                 );
+                drop(reservation);
 
                 let recursive_circuit_id_and_url = save_recursive_layer_prover_input_artifacts(
                     job.block_number,
@@ -157,10 +172,10 @@ impl JobManager for NodeAggregation {
                 )
                 .await;
 
-                (
+                Some((
                     (result_circuit_id, input_queue),
                     recursive_circuit_id_and_url,
-                )
+                ))
             });
 
             handles.push(handle);
@@ -169,11 +184,15 @@ impl JobManager for NodeAggregation {
         let mut next_aggregations = vec![];
         let mut recursive_circuit_ids_and_urls = vec![];
         for handle in handles {
-            let (next_aggregation, recursive_circuit_id_and_url) = handle.await.unwrap();
+            let Some((next_aggregation, recursive_circuit_id_and_url)) = handle.await.unwrap()
+            else {
+                continue;
+            };
 
             next_aggregations.push(next_aggregation);
             recursive_circuit_ids_and_urls.extend(recursive_circuit_id_and_url);
         }
+        cancellation.check()?;
 
         WITNESS_GENERATOR_METRICS.witness_generation_time
             [&AggregationRound::NodeAggregation.into()]
@@ -204,7 +223,7 @@ impl JobManager for NodeAggregation {
     async fn prepare_job(
         metadata: Self::Metadata,
         object_store: &dyn ObjectStore,
-        keystore: Keystore,
+        keystore: CachedKeystore,
     ) -> anyhow::Result<NodeAggregationWitnessGeneratorJob> {
         let started_at = Instant::now();
         let artifacts = Self::get_artifacts(&metadata, object_store).await?;
@@ -233,11 +252,14 @@ impl JobManager for NodeAggregation {
             proofs_ids: metadata.prover_job_ids_for_proofs,
             leaf_vk,
             node_vk,
-            all_leafs_layer_params: get_leaf_vk_params(&keystore)
-                .context("get_leaf_vk_params()")?,
+            all_leafs_layer_params: keystore.all_leaf_params().context("all_leaf_params()")?,
         })
     }
 
+    fn job_location(job: &NodeAggregationWitnessGeneratorJob) -> (L1BatchNumber, Option<u8>) {
+        (job.block_number, Some(job.circuit_id))
+    }
+
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
@@ -0,0 +1,73 @@
+use std::{collections::VecDeque, fmt, future::Future, sync::Mutex};
+
+use tokio::task::JoinHandle;
+
+use super::JobManager;
+use crate::metrics::WITNESS_GENERATOR_METRICS;
+
+type FetchedJob<R> = anyhow::Result<Option<(u32, <R as JobManager>::Job)>>;
+
+/// Queue of jobs picked and prepared ahead of time, so that loading input artifacts for the next jobs
+/// overlaps with processing of the current job.
+///
+/// Prefetched jobs are marked as in progress in the database as soon as they are picked; their processing start time
+/// is refreshed once they are taken from the queue. If the witness generator stops before processing them,
+/// they are retried once the processing timeout elapses, same as any other interrupted job.
+pub(super) struct JobPrefetchQueue<R: JobManager> {
+    depth: usize,
+    jobs: Mutex<VecDeque<JoinHandle<FetchedJob<R>>>>,
+}
+
+impl<R: JobManager> fmt::Debug for JobPrefetchQueue<R> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("JobPrefetchQueue")
+            .field("depth", &self.depth)
+            .field("len", &self.jobs.lock().unwrap().len())
+            .finish()
+    }
+}
+
+impl<R: JobManager> JobPrefetchQueue<R> {
+    pub fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            jobs: Mutex::default(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.depth > 0
+    }
+
+    /// Takes the oldest prefetched job, waiting for it to be prepared if necessary.
+    /// Returns `None` if the queue is empty.
+    pub async fn pop(&self) -> Option<FetchedJob<R>> {
+        let handle = {
+            let mut jobs = self.jobs.lock().unwrap();
+            let handle = jobs.pop_front()?;
+            Self::report_occupancy(jobs.len());
+            handle
+        };
+        Some(match handle.await {
+            Ok(fetched_job) => fetched_job,
+            Err(err) => Err(anyhow::Error::new(err).context("job prefetching task failed")),
+        })
+    }
+
+    /// Spawns tasks prefetching jobs until the queue is full.
+    pub fn fill<Fut>(&self, mut fetch_job: impl FnMut() -> Fut)
+    where
+        Fut: Future<Output = FetchedJob<R>> + Send + 'static,
+    {
+        let mut jobs = self.jobs.lock().unwrap();
+        while jobs.len() < self.depth {
+            jobs.push_back(tokio::spawn(fetch_job()));
+        }
+        Self::report_occupancy(jobs.len());
+    }
+
+    fn report_occupancy(len: usize) {
+        WITNESS_GENERATOR_METRICS.prefetch_queue_occupancy[&R::ROUND.into()].set(len);
+    }
+}
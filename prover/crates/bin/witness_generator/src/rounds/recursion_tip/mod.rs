@@ -9,6 +9,7 @@ use circuit_definitions::{
     },
     recursion_layer_proof_config,
 };
+use tokio::sync::Semaphore;
 use zkevm_test_harness::{
     boojum::{
         field::{
@@ -38,14 +39,17 @@ use zkevm_test_harness::{
 use zksync_object_store::ObjectStore;
 use zksync_prover_dal::{ConnectionPool, Prover, ProverDal};
 use zksync_prover_fri_types::{get_current_pod_name, keys::ClosedFormInputKey};
-use zksync_prover_keystore::{keystore::Keystore, utils::get_leaf_vk_params};
 use zksync_types::{
     basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion, L1BatchNumber,
 };
 
 use crate::{
-    artifacts::ArtifactsManager, metrics::WITNESS_GENERATOR_METRICS, rounds::JobManager,
-    utils::ClosedFormInputWrapper,
+    artifacts::ArtifactsManager,
+    cancellation::JobCancellation,
+    metrics::WITNESS_GENERATOR_METRICS,
+    rounds::JobManager,
+    utils::{ClosedFormInputWrapper, ProofsMemoryBudget},
+    vk_cache::CachedKeystore,
 };
 
 mod artifacts;
@@ -88,7 +92,9 @@ impl JobManager for RecursionTip {
     async fn process_job(
         job: Self::Job,
         _object_store: Arc<dyn ObjectStore>,
-        _max_circuits_in_flight: usize,
+        _circuits_semaphore: Arc<Semaphore>,
+        _proofs_budget: ProofsMemoryBudget,
+        _cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<RecursionTipArtifacts> {
         tracing::info!(
@@ -132,7 +138,7 @@ impl JobManager for RecursionTip {
     async fn prepare_job(
         metadata: RecursionTipJobMetadata,
         object_store: &dyn ObjectStore,
-        keystore: Keystore,
+        keystore: CachedKeystore,
     ) -> anyhow::Result<RecursionTipWitnessGeneratorJob> {
         let started_at = Instant::now();
         let recursion_tip_proofs =
@@ -178,7 +184,7 @@ impl JobManager for RecursionTip {
 
         const EXPECTED_RECURSION_TIP_LEAVES: usize = 16;
 
-        let leaf_vk_commits = get_leaf_vk_params(&keystore).context("get_leaf_vk_params()")?;
+        let leaf_vk_commits = keystore.all_leaf_params().context("all_leaf_params()")?;
         assert_eq!(
             leaf_vk_commits.len(),
             EXPECTED_RECURSION_TIP_LEAVES,
@@ -216,6 +222,10 @@ impl JobManager for RecursionTip {
         })
     }
 
+    fn job_location(job: &RecursionTipWitnessGeneratorJob) -> (L1BatchNumber, Option<u8>) {
+        (job.block_number, None)
+    }
+
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
@@ -8,7 +8,7 @@ use zksync_prover_fri_types::{keys::FriCircuitKey, CircuitWrapper, FriProofWrapp
 use zksync_types::{basic_fri_types::AggregationRound, L1BatchNumber};
 
 use crate::{
-    artifacts::ArtifactsManager,
+    artifacts::{ArtifactsCompression, ArtifactsManager},
     rounds::scheduler::{Scheduler, SchedulerArtifacts},
 };
 
@@ -32,6 +32,7 @@ impl ArtifactsManager for Scheduler {
         job_id: u32,
         artifacts: Self::OutputArtifacts,
         object_store: &dyn ObjectStore,
+        _compression: ArtifactsCompression,
     ) -> String {
         let key = FriCircuitKey {
             block_number: L1BatchNumber(job_id),
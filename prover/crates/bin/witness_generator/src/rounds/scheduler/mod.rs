@@ -2,6 +2,7 @@ use std::{convert::TryInto, sync::Arc, time::Instant};
 
 use anyhow::Context as _;
 use async_trait::async_trait;
+use tokio::sync::Semaphore;
 use zkevm_test_harness::zkevm_circuits::recursion::{
     leaf_layer::input::RecursionLeafParametersWitness, NUM_BASE_LAYER_CIRCUITS,
 };
@@ -22,14 +23,17 @@ use zksync_prover_fri_types::{
     },
     get_current_pod_name, FriProofWrapper,
 };
-use zksync_prover_keystore::{keystore::Keystore, utils::get_leaf_vk_params};
 use zksync_types::{
     basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion, L1BatchNumber,
 };
 
 use crate::{
-    artifacts::ArtifactsManager, metrics::WITNESS_GENERATOR_METRICS, rounds::JobManager,
-    utils::SchedulerPartialInputWrapper,
+    artifacts::{ArtifactsManager, CompressedArtifact},
+    cancellation::JobCancellation,
+    metrics::WITNESS_GENERATOR_METRICS,
+    rounds::JobManager,
+    utils::{ProofsMemoryBudget, SchedulerPartialInputWrapper},
+    vk_cache::CachedKeystore,
 };
 
 mod artifacts;
@@ -75,7 +79,9 @@ impl JobManager for Scheduler {
     async fn process_job(
         job: SchedulerWitnessGeneratorJob,
         _object_store: Arc<dyn ObjectStore>,
-        _max_circuits_in_flight: usize,
+        _circuits_semaphore: Arc<Semaphore>,
+        _proofs_budget: ProofsMemoryBudget,
+        _cancellation: JobCancellation,
         started_at: Instant,
     ) -> anyhow::Result<SchedulerArtifacts> {
         tracing::info!(
@@ -120,7 +126,7 @@ impl JobManager for Scheduler {
     async fn prepare_job(
         metadata: SchedulerWitnessJobMetadata,
         object_store: &dyn ObjectStore,
-        keystore: Keystore,
+        keystore: CachedKeystore,
     ) -> anyhow::Result<Self::Job> {
         let started_at = Instant::now();
         let wrapper = Self::get_artifacts(&metadata.recursion_tip_job_id, object_store).await?;
@@ -140,8 +146,10 @@ impl JobManager for Scheduler {
                 ZkSyncRecursionLayerStorageType::NodeLayerCircuit as u8,
             )
             .context("get_recursive_layer_vk_for_circuit_type()")?;
-        let SchedulerPartialInputWrapper(mut scheduler_witness) =
-            object_store.get(metadata.l1_batch_number).await?;
+        let SchedulerPartialInputWrapper(mut scheduler_witness) = object_store
+            .get::<CompressedArtifact<SchedulerPartialInputWrapper>>(metadata.l1_batch_number)
+            .await?
+            .into_inner();
 
         let recursion_tip_vk = keystore
             .load_recursive_layer_verification_key(
@@ -150,7 +158,7 @@ impl JobManager for Scheduler {
             .context("get_recursion_tip_vk()")?;
         scheduler_witness.proof_witnesses = vec![recursion_tip_proof].into();
 
-        let leaf_vk_commits = get_leaf_vk_params(&keystore).context("get_leaf_vk_params()")?;
+        let leaf_vk_commits = keystore.all_leaf_params().context("all_leaf_params()")?;
         let leaf_layer_parameters = leaf_vk_commits
             .iter()
             .map(|el| el.1.clone())
@@ -170,6 +178,10 @@ impl JobManager for Scheduler {
         })
     }
 
+    fn job_location(job: &SchedulerWitnessGeneratorJob) -> (L1BatchNumber, Option<u8>) {
+        (job.block_number, None)
+    }
+
     async fn get_metadata(
         connection_pool: ConnectionPool<Prover>,
         protocol_version: ProtocolSemanticVersion,
@@ -4,7 +4,9 @@ use std::{
 };
 
 use circuit_definitions::circuit_definitions::base_layer::ZkSyncBaseLayerCircuit;
+use futures::StreamExt as _;
 use once_cell::sync::Lazy;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use zkevm_test_harness::boojum::field::goldilocks::GoldilocksField;
 use zksync_multivm::utils::get_used_bootloader_memory_bytes;
 use zksync_object_store::{serialize_using_bincode, Bucket, ObjectStore, StoredObject};
@@ -169,18 +171,110 @@ pub async fn save_recursive_layer_prover_input_artifacts(
     ids_and_urls
 }
 
-#[tracing::instrument(skip_all)]
+/// Maximum number of proofs fetched from the object store concurrently by [`load_proofs_for_job_ids()`].
+const MAX_CONCURRENT_PROOF_LOADS: usize = 8;
+
+/// Memory budget for proofs loaded by aggregation rounds, expressed as the number of proofs held in memory
+/// at the same time. Shared by all tasks processing a single job.
+#[derive(Debug, Clone, Default)]
+pub struct ProofsMemoryBudget {
+    semaphore: Option<Arc<Semaphore>>,
+    max_proofs: usize,
+}
+
+impl ProofsMemoryBudget {
+    /// Creates a budget. `None` means that the number of loaded proofs is not limited.
+    pub fn new(max_proofs_in_memory: Option<usize>) -> Self {
+        let Some(max_proofs) = max_proofs_in_memory else {
+            return Self::default();
+        };
+        // The budget must fit at least one proof, and permits for a single reservation are limited to `u32`.
+        let max_proofs = max_proofs.clamp(1, u32::MAX as usize);
+        Self {
+            semaphore: Some(Arc::new(Semaphore::new(max_proofs))),
+            max_proofs,
+        }
+    }
+
+    /// Reserves space for the specified number of proofs, waiting until enough proofs loaded by other tasks are released.
+    /// Reservations exceeding the budget are capped by it, so that they can eventually succeed.
+    async fn reserve(&self, proof_count: usize) -> ProofsReservation {
+        let Some(semaphore) = &self.semaphore else {
+            return ProofsReservation(None);
+        };
+        let permits = proof_count.min(self.max_proofs) as u32;
+        let permit = semaphore
+            .clone()
+            .acquire_many_owned(permits)
+            .await
+            .expect("proofs memory budget semaphore is never closed");
+        ProofsReservation(Some(permit))
+    }
+}
+
+/// Space reserved for proofs in a [`ProofsMemoryBudget`]. Released when dropped, so it should be held
+/// until the corresponding proofs are consumed.
+#[derive(Debug)]
+#[must_use = "reservation is released when dropped"]
+pub struct ProofsReservation(Option<OwnedSemaphorePermit>);
+
+/// Proofs loaded by [`load_proofs_for_job_ids()`] together with the space reserved for them in the memory budget.
+#[derive(Debug)]
+pub struct LoadedProofs {
+    pub proofs: Vec<FriProofWrapper>,
+    pub reservation: ProofsReservation,
+}
+
+/// Loads proofs with the specified job IDs after reserving space for them in the `budget`.
+/// Proofs are fetched in a bounded stream rather than all at once, so that a large set of proofs doesn't
+/// saturate the object store and memory with in-flight requests.
+#[tracing::instrument(skip_all, fields(proof_count = job_ids.len()))]
 pub async fn load_proofs_for_job_ids(
     job_ids: &[u32],
     object_store: &dyn ObjectStore,
-) -> Vec<FriProofWrapper> {
-    let mut handles = Vec::with_capacity(job_ids.len());
-    for job_id in job_ids {
-        handles.push(object_store.get(*job_id));
-    }
-    futures::future::join_all(handles)
-        .await
-        .into_iter()
+    budget: &ProofsMemoryBudget,
+) -> LoadedProofs {
+    let reservation = budget.reserve(job_ids.len()).await;
+    let proofs = futures::stream::iter(job_ids)
+        .map(|&job_id| object_store.get::<FriProofWrapper>(job_id))
+        .buffered(MAX_CONCURRENT_PROOF_LOADS)
         .map(|x| x.unwrap())
         .collect()
+        .await;
+    LoadedProofs {
+        proofs,
+        reservation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proofs_memory_budget_limits_reservations() {
+        let budget = ProofsMemoryBudget::new(Some(4));
+        let reservation = budget.reserve(3).await;
+        // Reservations exceeding the budget are capped.
+        let pending_reservation = budget.reserve(10);
+        tokio::pin!(pending_reservation);
+        tokio::time::timeout(Duration::from_millis(50), &mut pending_reservation)
+            .await
+            .unwrap_err();
+
+        drop(reservation);
+        let _reservation = pending_reservation.await;
+        tokio::time::timeout(Duration::from_millis(50), budget.reserve(1))
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn unlimited_proofs_memory_budget() {
+        let budget = ProofsMemoryBudget::new(None);
+        let _reservation = budget.reserve(1_000).await;
+        let _other_reservation = budget.reserve(1_000).await;
+    }
 }
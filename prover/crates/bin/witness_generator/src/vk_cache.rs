@@ -0,0 +1,184 @@
+//! Process-wide cache of verification keys and leaf parameters used to prepare witness generation jobs.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use anyhow::Context as _;
+use circuit_definitions::circuit_definitions::recursion_layer::base_circuit_type_into_recursive_leaf_circuit_type;
+use once_cell::sync::Lazy;
+use zkevm_test_harness::{
+    witness::recursive_aggregation::compute_leaf_params,
+    zkevm_circuits::scheduler::aux::BaseLayerCircuitType,
+};
+use zksync_prover_fri_types::circuit_definitions::{
+    boojum::field::goldilocks::GoldilocksField,
+    circuit_definitions::{
+        base_layer::ZkSyncBaseLayerVerificationKey,
+        recursion_layer::ZkSyncRecursionLayerVerificationKey,
+    },
+    zkevm_circuits::recursion::leaf_layer::input::RecursionLeafParametersWitness,
+};
+use zksync_prover_keystore::keystore::Keystore;
+use zksync_types::protocol_version::ProtocolSemanticVersion;
+
+use crate::metrics::{VkCacheKind, WITNESS_GENERATOR_METRICS};
+
+type CacheKey = (ProtocolSemanticVersion, u8);
+
+/// Map from `(protocol_version, circuit_id)` to a cached value. Entries are never evicted; a witness generator
+/// processes jobs for a single protocol version, so the number of entries is bounded by the number of circuits.
+#[derive(Debug)]
+struct CacheMap<T>(RwLock<HashMap<CacheKey, Arc<T>>>);
+
+impl<T> Default for CacheMap<T> {
+    fn default() -> Self {
+        Self(RwLock::default())
+    }
+}
+
+impl<T> CacheMap<T> {
+    fn get_or_try_insert(
+        &self,
+        kind: VkCacheKind,
+        key: CacheKey,
+        init: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<Arc<T>> {
+        if let Some(value) = self.0.read().expect("cache is poisoned").get(&key) {
+            WITNESS_GENERATOR_METRICS.vk_cache_hits[&kind].inc();
+            return Ok(value.clone());
+        }
+
+        WITNESS_GENERATOR_METRICS.vk_cache_misses[&kind].inc();
+        // Initialization is performed without holding the lock. Concurrent misses may initialize the same value
+        // several times, which is benign since all initializations produce equivalent values.
+        let value = Arc::new(init()?);
+        let mut map = self.0.write().expect("cache is poisoned");
+        Ok(map.entry(key).or_insert(value).clone())
+    }
+}
+
+#[derive(Debug, Default)]
+struct VkCache {
+    base_layer_vks: CacheMap<ZkSyncBaseLayerVerificationKey>,
+    recursive_layer_vks: CacheMap<ZkSyncRecursionLayerVerificationKey>,
+    leaf_params: CacheMap<RecursionLeafParametersWitness<GoldilocksField>>,
+}
+
+static VK_CACHE: Lazy<VkCache> = Lazy::new(VkCache::default);
+
+/// [`Keystore`] wrapper memoizing verification keys and leaf parameters for a specific protocol version
+/// in a process-wide cache, so that they are not loaded and recomputed for every job.
+#[derive(Debug, Clone)]
+pub struct CachedKeystore {
+    keystore: Keystore,
+    protocol_version: ProtocolSemanticVersion,
+}
+
+impl CachedKeystore {
+    /// Wraps a keystore containing keys for the specified protocol version.
+    pub fn new(keystore: Keystore, protocol_version: ProtocolSemanticVersion) -> Self {
+        Self {
+            keystore,
+            protocol_version,
+        }
+    }
+
+    pub fn load_base_layer_verification_key(
+        &self,
+        circuit_type: u8,
+    ) -> anyhow::Result<ZkSyncBaseLayerVerificationKey> {
+        let vk = VK_CACHE.base_layer_vks.get_or_try_insert(
+            VkCacheKind::BaseLayerVk,
+            (self.protocol_version, circuit_type),
+            || self.keystore.load_base_layer_verification_key(circuit_type),
+        )?;
+        Ok(ZkSyncBaseLayerVerificationKey::clone(&vk))
+    }
+
+    pub fn load_recursive_layer_verification_key(
+        &self,
+        circuit_type: u8,
+    ) -> anyhow::Result<ZkSyncRecursionLayerVerificationKey> {
+        let vk = VK_CACHE.recursive_layer_vks.get_or_try_insert(
+            VkCacheKind::RecursiveLayerVk,
+            (self.protocol_version, circuit_type),
+            || {
+                self.keystore
+                    .load_recursive_layer_verification_key(circuit_type)
+            },
+        )?;
+        Ok(ZkSyncRecursionLayerVerificationKey::clone(&vk))
+    }
+
+    /// Returns leaf parameters for the specified base layer circuit.
+    pub fn leaf_params(
+        &self,
+        circuit_type: u8,
+    ) -> anyhow::Result<RecursionLeafParametersWitness<GoldilocksField>> {
+        let params = VK_CACHE.leaf_params.get_or_try_insert(
+            VkCacheKind::LeafParams,
+            (self.protocol_version, circuit_type),
+            || {
+                let base_vk = self
+                    .load_base_layer_verification_key(circuit_type)
+                    .with_context(|| {
+                        format!("get_base_layer_vk_for_circuit_type({circuit_type})")
+                    })?;
+                let leaf_circuit_type = base_circuit_type_into_recursive_leaf_circuit_type(
+                    BaseLayerCircuitType::from_numeric_value(circuit_type),
+                );
+                let leaf_vk = self
+                    .load_recursive_layer_verification_key(leaf_circuit_type as u8)
+                    .with_context(|| {
+                        format!("get_recursive_layer_vk_for_circuit_type({leaf_circuit_type:?})")
+                    })?;
+                Ok(compute_leaf_params(circuit_type, base_vk, leaf_vk))
+            },
+        )?;
+        Ok(RecursionLeafParametersWitness::clone(&params))
+    }
+
+    /// Cached counterpart of [`get_leaf_vk_params()`](zksync_prover_keystore::utils::get_leaf_vk_params).
+    pub fn all_leaf_params(
+        &self,
+    ) -> anyhow::Result<Vec<(u8, RecursionLeafParametersWitness<GoldilocksField>)>> {
+        BaseLayerCircuitType::as_iter_u8()
+            .map(|circuit_type| Ok((circuit_type, self.leaf_params(circuit_type)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_map_memoizes_values() {
+        let map = CacheMap::<u32>::default();
+        let key = (ProtocolSemanticVersion::default(), 1);
+        let mut init_calls = 0;
+        for _ in 0..3 {
+            let value = map
+                .get_or_try_insert(VkCacheKind::LeafParams, key, || {
+                    init_calls += 1;
+                    Ok(42)
+                })
+                .unwrap();
+            assert_eq!(*value, 42);
+        }
+        assert_eq!(init_calls, 1);
+
+        let other_key = (ProtocolSemanticVersion::default(), 2);
+        map.get_or_try_insert(VkCacheKind::LeafParams, other_key, || {
+            anyhow::bail!("failed")
+        })
+        .unwrap_err();
+        // Errors are not cached.
+        let value = map
+            .get_or_try_insert(VkCacheKind::LeafParams, other_key, || Ok(23))
+            .unwrap();
+        assert_eq!(*value, 23);
+    }
+}
@@ -1,6 +1,7 @@
-use std::time::Instant;
+use std::{sync::Arc, time::Instant};
 
 use serde::Serialize;
+use tokio::sync::Semaphore;
 use zksync_config::{configs::object_store::ObjectStoreMode, ObjectStoreConfig};
 use zksync_object_store::ObjectStoreFactory;
 use zksync_prover_fri_types::{
@@ -11,12 +12,15 @@ use zksync_prover_fri_utils::get_recursive_layer_circuit_id_for_base_layer;
 use zksync_prover_keystore::keystore::Keystore;
 use zksync_types::{
     basic_fri_types::AggregationRound,
+    protocol_version::ProtocolSemanticVersion,
     prover_dal::{LeafAggregationJobMetadata, NodeAggregationJobMetadata},
     L1BatchNumber,
 };
 use zksync_witness_generator::{
+    cancellation::JobCancellation,
     rounds::{JobManager, LeafAggregation, NodeAggregation},
-    utils::AggregationWrapper,
+    utils::{AggregationWrapper, ProofsMemoryBudget},
+    vk_cache::CachedKeystore,
 };
 
 fn compare_serialized<T: Serialize>(expected: &T, actual: &T) {
@@ -44,20 +48,28 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
         .await
         .unwrap();
 
-    let keystore = Keystore::locate();
+    let keystore = CachedKeystore::new(Keystore::locate(), ProtocolSemanticVersion::default());
     let job = LeafAggregation::prepare_job(leaf_aggregation_job_metadata, &*object_store, keystore)
         .await
         .unwrap();
 
-    let artifacts = LeafAggregation::process_job(job, object_store.clone(), 500, Instant::now())
-        .await
-        .unwrap();
+    let artifacts = LeafAggregation::process_job(
+        job,
+        object_store.clone(),
+        Arc::new(Semaphore::new(500)),
+        ProofsMemoryBudget::default(),
+        JobCancellation::never(),
+        Instant::now(),
+    )
+    .await
+    .unwrap();
 
     let aggregations = AggregationWrapper(artifacts.aggregations);
 
@@ -67,6 +79,7 @@ async fn test_leaf_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -119,6 +132,7 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
     let object_store = ObjectStoreFactory::new(object_store_config)
         .create_store()
@@ -136,14 +150,21 @@ async fn test_node_witness_gen() {
         prover_job_ids_for_proofs: vec![5211320],
     };
 
-    let keystore = Keystore::locate();
+    let keystore = CachedKeystore::new(Keystore::locate(), ProtocolSemanticVersion::default());
     let job = NodeAggregation::prepare_job(node_aggregation_job_metadata, &*object_store, keystore)
         .await
         .unwrap();
 
-    let artifacts = NodeAggregation::process_job(job, object_store.clone(), 500, Instant::now())
-        .await
-        .unwrap();
+    let artifacts = NodeAggregation::process_job(
+        job,
+        object_store.clone(),
+        Arc::new(Semaphore::new(500)),
+        ProofsMemoryBudget::default(),
+        JobCancellation::never(),
+        Instant::now(),
+    )
+    .await
+    .unwrap();
 
     let aggregations = AggregationWrapper(artifacts.next_aggregations);
 
@@ -153,6 +174,7 @@ async fn test_node_witness_gen() {
         },
         max_retries: 5,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
     let expected_object_store = ObjectStoreFactory::new(expected_results_object_store_config)
         .create_store()
@@ -15,7 +15,9 @@ use zksync_prover_fri_types::{
     WitnessVectorArtifacts,
 };
 use zksync_prover_fri_utils::{
-    fetch_next_circuit, get_numeric_circuit_id, region_fetcher::Zone, socket_utils::send_assembly,
+    fetch_next_circuit, get_numeric_circuit_id,
+    region_fetcher::Zone,
+    socket_utils::{send_assembly, EncodedWitnessVector},
 };
 use zksync_prover_keystore::keystore::Keystore;
 use zksync_queued_job_processor::JobProcessor;
@@ -156,6 +158,8 @@ impl JobProcessor for WitnessVectorGenerator {
 
         let serialized: Vec<u8> =
             bincode::serialize(&artifacts).expect("Failed to serialize witness vector artifacts");
+        let encoded = EncodedWitnessVector::new(serialized, self.config.transfer_format)
+            .context("failed encoding witness vector")?;
 
         let now = Instant::now();
         let mut attempts = 0;
@@ -181,7 +185,7 @@ impl JobProcessor for WitnessVectorGenerator {
                     "Found prover at address {address:?} after {:?}. Sending witness vector job...",
                     now.elapsed()
                 );
-                let result = send_assembly(job_id, &serialized, &address);
+                let result = send_assembly(job_id, &encoded, &address);
                 handle_send_result(&result, job_id, &address, &self.pool, self.zone.to_string())
                     .await;
 
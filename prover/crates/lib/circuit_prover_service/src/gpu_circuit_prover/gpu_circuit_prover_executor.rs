@@ -7,7 +7,8 @@ use zksync_prover_job_processor::Executor;
 use zksync_types::prover_dal::FriProverJobMetadata;
 
 use crate::{
-    metrics::CIRCUIT_PROVER_METRICS, types::circuit_prover_payload::GpuCircuitProverPayload,
+    gpu_metrics::GpuJobTracker, metrics::CIRCUIT_PROVER_METRICS,
+    types::circuit_prover_payload::GpuCircuitProverPayload,
 };
 
 /// GpuCircuitProver executor implementation.
@@ -15,12 +16,14 @@ use crate::{
 /// NOTE: It requires prover context, which is the way Shivini allocates VRAM.
 pub struct GpuCircuitProverExecutor {
     _prover_context: ProverContext,
+    job_tracker: GpuJobTracker,
 }
 
 impl GpuCircuitProverExecutor {
-    pub fn new(prover_context: ProverContext) -> Self {
+    pub fn new(prover_context: ProverContext, job_tracker: GpuJobTracker) -> Self {
         Self {
             _prover_context: prover_context,
+            job_tracker,
         }
     }
 }
@@ -54,6 +57,7 @@ impl Executor for GpuCircuitProverExecutor {
             setup_data,
         } = input;
 
+        let _job_guard = self.job_tracker.start_job(&metadata);
         let proof_wrapper = circuit
             .prove(witness_vector, setup_data)
             .context("failed to gpu prove circuit")?;
@@ -0,0 +1,149 @@
+//! GPU metrics exporter. Samples GPU utilization, memory and temperature via NVML and attributes samples
+//! to the job being proven at the time, so that GPU underutilization can be tracked down to specific circuit types.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Context;
+use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, Nvml};
+use tokio_util::sync::CancellationToken;
+use zksync_types::prover_dal::FriProverJobMetadata;
+
+use crate::metrics::{GpuJobLabels, GPU_METRICS};
+
+/// Label value used for samples taken while GPU circuit prover waits for a job.
+const IDLE_LABEL: &str = "idle";
+
+/// Keeps track of the job currently proven on GPU.
+#[derive(Debug, Clone, Default)]
+pub struct GpuJobTracker(Arc<Mutex<Option<FriProverJobMetadata>>>);
+
+impl GpuJobTracker {
+    /// Marks the job as being proven until the returned guard is dropped.
+    pub fn start_job(&self, metadata: &FriProverJobMetadata) -> GpuJobGuard<'_> {
+        *self.0.lock().unwrap() = Some(*metadata);
+        GpuJobGuard(self)
+    }
+
+    fn labels(&self, chain: &str) -> GpuJobLabels {
+        let current_job = self.0.lock().unwrap();
+        match &*current_job {
+            Some(metadata) => GpuJobLabels {
+                chain: chain.to_owned(),
+                aggregation_round: metadata.aggregation_round.to_string(),
+                circuit_id: metadata.circuit_id.to_string(),
+            },
+            None => GpuJobLabels {
+                chain: chain.to_owned(),
+                aggregation_round: IDLE_LABEL.to_owned(),
+                circuit_id: IDLE_LABEL.to_owned(),
+            },
+        }
+    }
+}
+
+/// Guard returned by [`GpuJobTracker::start_job()`]; marks the GPU as idle on drop.
+#[derive(Debug)]
+pub struct GpuJobGuard<'a>(&'a GpuJobTracker);
+
+impl Drop for GpuJobGuard<'_> {
+    fn drop(&mut self) {
+        *self.0 .0.lock().unwrap() = None;
+    }
+}
+
+/// Periodically samples NVML metrics for all visible GPUs.
+pub struct GpuMetricsSampler {
+    nvml: Nvml,
+    job_tracker: GpuJobTracker,
+    chain: String,
+    sampling_interval: Duration,
+    cancellation_token: CancellationToken,
+}
+
+impl std::fmt::Debug for GpuMetricsSampler {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("GpuMetricsSampler")
+            .field("chain", &self.chain)
+            .field("sampling_interval", &self.sampling_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GpuMetricsSampler {
+    /// Initializes NVML. Fails if NVML is not available (e.g., NVIDIA drivers are not installed).
+    pub fn new(
+        job_tracker: GpuJobTracker,
+        chain: String,
+        sampling_interval: Duration,
+        cancellation_token: CancellationToken,
+    ) -> anyhow::Result<Self> {
+        let nvml = Nvml::init().context("failed initializing NVML")?;
+        Ok(Self {
+            nvml,
+            job_tracker,
+            chain,
+            sampling_interval,
+            cancellation_token,
+        })
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        let device_count = self
+            .nvml
+            .device_count()
+            .context("failed getting GPU device count")?;
+        tracing::info!(
+            "Sampling GPU metrics for {device_count} device(s) every {:?}",
+            self.sampling_interval
+        );
+
+        let mut interval = tokio::time::interval(self.sampling_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = self.cancellation_token.cancelled() => {
+                    tracing::info!("Stop signal received, shutting down GPU metrics sampler");
+                    return Ok(());
+                }
+            }
+
+            for device_index in 0..device_count {
+                if let Err(err) = self.sample_device(device_index) {
+                    // Sampling errors shouldn't bring the prover down.
+                    tracing::warn!("Failed sampling metrics for GPU #{device_index}: {err:#}");
+                }
+            }
+        }
+    }
+
+    fn sample_device(&self, device_index: u32) -> anyhow::Result<()> {
+        let device = self
+            .nvml
+            .device_by_index(device_index)
+            .context("device_by_index()")?;
+        let utilization = device.utilization_rates().context("utilization_rates()")?;
+        let memory = device.memory_info().context("memory_info()")?;
+        let temperature = device
+            .temperature(TemperatureSensor::Gpu)
+            .context("temperature()")?;
+
+        let device_label = device_index.to_string();
+        GPU_METRICS.utilization_percent[&device_label].set(utilization.gpu.into());
+        GPU_METRICS.memory_used_bytes[&device_label].set(memory.used);
+        GPU_METRICS.memory_total_bytes[&device_label].set(memory.total);
+        GPU_METRICS.temperature_celsius[&device_label].set(temperature.into());
+
+        let job_labels = self.job_tracker.labels(&self.chain);
+        GPU_METRICS.job_utilization_percent[&job_labels].observe(utilization.gpu.into());
+        if memory.total > 0 {
+            GPU_METRICS.job_memory_used_ratio[&job_labels]
+                .observe(memory.used as f64 / memory.total as f64);
+        }
+        Ok(())
+    }
+}
@@ -16,6 +16,8 @@ use crate::{
     gpu_circuit_prover::{
         GpuCircuitProverExecutor, GpuCircuitProverJobPicker, GpuCircuitProverJobSaver,
     },
+    gpu_metrics::GpuJobTracker,
+    protocol_version_drain::ProtocolVersionDrain,
     types::witness_vector_generator_execution_output::WitnessVectorGeneratorExecutionOutput,
     witness_vector_generator::{
         HeavyWitnessVectorMetadataLoader, LightWitnessVectorMetadataLoader,
@@ -35,6 +37,7 @@ pub struct WvgRunnerBuilder {
         tokio::sync::mpsc::Sender<(WitnessVectorGeneratorExecutionOutput, FriProverJobMetadata)>,
     cancellation_token: CancellationToken,
     pod_name: String,
+    protocol_version_drain: Option<Arc<ProtocolVersionDrain>>,
 }
 
 impl WvgRunnerBuilder {
@@ -57,9 +60,18 @@ impl WvgRunnerBuilder {
             sender,
             cancellation_token,
             pod_name: get_current_pod_name(),
+            protocol_version_drain: None,
         }
     }
 
+    /// Enables protocol version drain mode: jobs are not picked until all jobs for older protocol versions are proven.
+    /// See [`ProtocolVersionDrain`] for details.
+    pub fn with_protocol_version_drain(mut self) -> Self {
+        self.protocol_version_drain =
+            Some(Arc::new(ProtocolVersionDrain::new(self.protocol_version)));
+        self
+    }
+
     /// Witness Vector Generator runner implementation for light jobs.
     pub fn light_wvg_runner(
         &self,
@@ -107,6 +119,7 @@ impl WvgRunnerBuilder {
             self.object_store.clone(),
             self.finalization_hints_cache.clone(),
             metadata_loader,
+            self.protocol_version_drain.clone(),
         );
         let job_saver =
             WitnessVectorGeneratorJobSaver::new(self.connection_pool.clone(), self.sender.clone());
@@ -136,8 +149,9 @@ pub fn circuit_prover_runner(
         FriProverJobMetadata,
     )>,
     prover_context: ProverContext,
+    job_tracker: GpuJobTracker,
 ) -> JobRunner<GpuCircuitProverExecutor, GpuCircuitProverJobPicker, GpuCircuitProverJobSaver> {
-    let executor = GpuCircuitProverExecutor::new(prover_context);
+    let executor = GpuCircuitProverExecutor::new(prover_context, job_tracker);
     let job_picker = GpuCircuitProverJobPicker::new(receiver, setup_data_cache);
     let job_saver = GpuCircuitProverJobSaver::new(connection_pool, object_store, protocol_version);
     JobRunner::new(executor, job_picker, job_saver, 1, None)
@@ -3,7 +3,9 @@
 #![feature(generic_const_exprs, allocator_api)]
 
 pub mod gpu_circuit_prover;
+pub mod gpu_metrics;
 pub mod job_runner;
 mod metrics;
+pub mod protocol_version_drain;
 pub mod types;
 pub mod witness_vector_generator;
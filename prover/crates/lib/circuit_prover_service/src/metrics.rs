@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, Histogram, Metrics};
+use vise::{Buckets, EncodeLabelSet, Family, Gauge, Histogram, LabeledFamily, Metrics};
 
 /// Metrics for witness vector generator execution
 #[derive(Debug, Metrics)]
@@ -44,3 +44,53 @@ pub struct CircuitProverMetrics {
 
 #[vise::register]
 pub static CIRCUIT_PROVER_METRICS: vise::Global<CircuitProverMetrics> = vise::Global::new();
+
+/// Metrics for protocol version drain mode
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "protocol_version_drain")]
+pub struct ProtocolVersionDrainMetrics {
+    /// How many jobs (witness generation, proving and compression) are queued or in progress per protocol version?
+    #[metrics(labels = ["protocol_version"])]
+    pub remaining_jobs: LabeledFamily<String, Gauge<usize>>,
+    /// Is it safe to roll prover keys (1), i.e. are all jobs of older protocol versions proven?
+    pub safe_to_roll_keys: Gauge<u64>,
+}
+
+#[vise::register]
+pub static PROTOCOL_VERSION_DRAIN_METRICS: vise::Global<ProtocolVersionDrainMetrics> =
+    vise::Global::new();
+
+/// Labels of the job proven on GPU at the time a GPU sample is taken.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(crate) struct GpuJobLabels {
+    pub chain: String,
+    pub aggregation_round: String,
+    pub circuit_id: String,
+}
+
+/// Metrics for GPU utilization sampled via NVML
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "circuit_prover_gpu")]
+pub(crate) struct GpuMetrics {
+    /// What is the current GPU utilization (in percent)?
+    #[metrics(labels = ["device"])]
+    pub utilization_percent: LabeledFamily<String, Gauge<u64>>,
+    /// How much GPU memory is currently used?
+    #[metrics(labels = ["device"])]
+    pub memory_used_bytes: LabeledFamily<String, Gauge<u64>>,
+    /// How much GPU memory is there in total?
+    #[metrics(labels = ["device"])]
+    pub memory_total_bytes: LabeledFamily<String, Gauge<u64>>,
+    /// What is the current GPU temperature (in Celsius)?
+    #[metrics(labels = ["device"])]
+    pub temperature_celsius: LabeledFamily<String, Gauge<u64>>,
+    /// What is the GPU utilization (in percent) while proving a job of a certain type?
+    #[metrics(buckets = Buckets::linear(0.0..=100.0, 10.0))]
+    pub job_utilization_percent: Family<GpuJobLabels, Histogram<f64>>,
+    /// Which share of GPU memory is used while proving a job of a certain type?
+    #[metrics(buckets = Buckets::linear(0.0..=1.0, 0.1))]
+    pub job_memory_used_ratio: Family<GpuJobLabels, Histogram<f64>>,
+}
+
+#[vise::register]
+pub(crate) static GPU_METRICS: vise::Global<GpuMetrics> = vise::Global::new();
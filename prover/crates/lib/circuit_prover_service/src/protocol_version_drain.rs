@@ -0,0 +1,145 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use zksync_prover_dal::{Connection, Prover, ProverDal};
+use zksync_types::{
+    basic_fri_types::AggregationRound, protocol_version::ProtocolSemanticVersion,
+    prover_dal::JobCountStatistics,
+};
+
+use crate::metrics::PROTOCOL_VERSION_DRAIN_METRICS;
+
+/// Protocol version drain mode, used for smooth protocol upgrades.
+///
+/// During protocol upgrades, provers must finish jobs of the old protocol version before switching keys.
+/// In drain mode, provers running with the keys for `protocol_version` don't pick any jobs
+/// while there are unfinished jobs for older protocol versions in any stage of the pipeline (witness generation
+/// for all aggregation rounds, proving and compression), leaving the resources to provers running with older keys.
+/// Once older versions are drained, jobs are picked as usual and `safe_to_roll_keys` metric is set.
+/// The check is periodically re-evaluated, so that jobs for older versions appearing later (e.g., because
+/// an older-version batch progressed to the next aggregation round) are drained as well.
+#[derive(Debug)]
+pub struct ProtocolVersionDrain {
+    protocol_version: ProtocolSemanticVersion,
+    /// Last evaluated drain decision together with the time it was evaluated at.
+    cached_decision: tokio::sync::Mutex<Option<(Instant, bool)>>,
+    reported_versions: Mutex<HashSet<ProtocolSemanticVersion>>,
+    /// Only used to log drain state transitions.
+    drained: AtomicBool,
+}
+
+impl ProtocolVersionDrain {
+    /// Interval between re-evaluating the drain decision. The evaluation collects job stats from all job tables,
+    /// which is too expensive to do on every job pick.
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn new(protocol_version: ProtocolSemanticVersion) -> Self {
+        Self {
+            protocol_version,
+            cached_decision: tokio::sync::Mutex::default(),
+            reported_versions: Mutex::default(),
+            drained: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the number of queued or in-progress jobs per protocol version across all job tables.
+    async fn remaining_jobs(
+        connection: &mut Connection<'_, Prover>,
+    ) -> HashMap<ProtocolSemanticVersion, usize> {
+        let mut remaining_jobs = HashMap::<_, usize>::new();
+        let mut add_stats = |stats: HashMap<ProtocolSemanticVersion, JobCountStatistics>| {
+            for (version, stats) in stats {
+                *remaining_jobs.entry(version).or_default() += stats.all();
+            }
+        };
+
+        for round in AggregationRound::ALL_ROUNDS {
+            add_stats(
+                connection
+                    .fri_witness_generator_dal()
+                    .get_witness_jobs_stats(round)
+                    .await,
+            );
+        }
+        add_stats(
+            connection
+                .fri_prover_jobs_dal()
+                .get_generic_prover_jobs_stats()
+                .await,
+        );
+        add_stats(connection.fri_proof_compressor_dal().get_jobs_stats().await);
+        remaining_jobs
+    }
+
+    /// Checks whether jobs for the current protocol version can be picked, i.e. there are no remaining jobs
+    /// for older protocol versions. The decision is cached for [`Self::REFRESH_INTERVAL`]; concurrent callers
+    /// wait for a single evaluation instead of querying the database in parallel.
+    pub async fn can_pick_jobs(&self, connection: &mut Connection<'_, Prover>) -> bool {
+        let mut cached_decision = self.cached_decision.lock().await;
+        if let Some((evaluated_at, can_pick_jobs)) = *cached_decision {
+            if evaluated_at.elapsed() < Self::REFRESH_INTERVAL {
+                return can_pick_jobs;
+            }
+        }
+        let can_pick_jobs = self.evaluate(connection).await;
+        *cached_decision = Some((Instant::now(), can_pick_jobs));
+        can_pick_jobs
+    }
+
+    async fn evaluate(&self, connection: &mut Connection<'_, Prover>) -> bool {
+        let remaining_jobs = Self::remaining_jobs(connection).await;
+        self.report_remaining_jobs(&remaining_jobs);
+
+        let remaining_older_jobs: usize = remaining_jobs
+            .iter()
+            .filter(|(version, _)| **version < self.protocol_version)
+            .map(|(_, &count)| count)
+            .sum();
+        if remaining_older_jobs > 0 {
+            tracing::info!(
+                "Draining protocol versions older than {}: {remaining_older_jobs} jobs remaining",
+                self.protocol_version
+            );
+            if self.drained.swap(false, Ordering::Relaxed) {
+                tracing::warn!(
+                    "New jobs for protocol versions older than {} appeared, resuming drain",
+                    self.protocol_version
+                );
+            }
+            PROTOCOL_VERSION_DRAIN_METRICS.safe_to_roll_keys.set(0);
+            return false;
+        }
+
+        if !self.drained.swap(true, Ordering::Relaxed) {
+            tracing::info!(
+                "Protocol versions older than {} are drained, switching to picking jobs for it",
+                self.protocol_version
+            );
+        }
+        PROTOCOL_VERSION_DRAIN_METRICS.safe_to_roll_keys.set(1);
+        true
+    }
+
+    fn report_remaining_jobs(&self, remaining_jobs: &HashMap<ProtocolSemanticVersion, usize>) {
+        let mut reported_versions = self
+            .reported_versions
+            .lock()
+            .expect("reported versions are poisoned");
+        // Versions without remaining jobs may be absent from `remaining_jobs`, so they must be reset explicitly.
+        for version in reported_versions.iter() {
+            if !remaining_jobs.contains_key(version) {
+                PROTOCOL_VERSION_DRAIN_METRICS.remaining_jobs[&version.to_string()].set(0);
+            }
+        }
+        for (version, &count) in remaining_jobs {
+            PROTOCOL_VERSION_DRAIN_METRICS.remaining_jobs[&version.to_string()].set(count);
+        }
+        reported_versions.extend(remaining_jobs.keys().copied());
+    }
+}
@@ -13,6 +13,7 @@ use zksync_types::prover_dal::FriProverJobMetadata;
 
 use crate::{
     metrics::WITNESS_VECTOR_GENERATOR_METRICS,
+    protocol_version_drain::ProtocolVersionDrain,
     types::{circuit::Circuit, witness_vector_generator_payload::WitnessVectorGeneratorPayload},
     witness_vector_generator::{
         witness_vector_generator_metadata_loader::WitnessVectorMetadataLoader,
@@ -28,6 +29,7 @@ pub struct WitnessVectorGeneratorJobPicker<ML: WitnessVectorMetadataLoader> {
     object_store: Arc<dyn ObjectStore>,
     finalization_hints_cache: HashMap<ProverServiceDataKey, Arc<FinalizationHintsForProver>>,
     metadata_loader: ML,
+    protocol_version_drain: Option<Arc<ProtocolVersionDrain>>,
 }
 
 impl<ML: WitnessVectorMetadataLoader> WitnessVectorGeneratorJobPicker<ML> {
@@ -36,12 +38,14 @@ impl<ML: WitnessVectorMetadataLoader> WitnessVectorGeneratorJobPicker<ML> {
         object_store: Arc<dyn ObjectStore>,
         finalization_hints_cache: HashMap<ProverServiceDataKey, Arc<FinalizationHintsForProver>>,
         metadata_loader: ML,
+        protocol_version_drain: Option<Arc<ProtocolVersionDrain>>,
     ) -> Self {
         Self {
             connection_pool,
             object_store,
             finalization_hints_cache,
             metadata_loader,
+            protocol_version_drain,
         }
     }
 }
@@ -54,11 +58,16 @@ impl<ML: WitnessVectorMetadataLoader> JobPicker for WitnessVectorGeneratorJobPic
     ) -> anyhow::Result<Option<(WitnessVectorGeneratorPayload, FriProverJobMetadata)>> {
         let start_time = Instant::now();
         tracing::info!("Started picking witness vector generator job");
-        let connection = self
+        let mut connection = self
             .connection_pool
             .connection()
             .await
             .context("failed to get db connection")?;
+        if let Some(drain) = &self.protocol_version_drain {
+            if !drain.can_pick_jobs(&mut connection).await {
+                return Ok(None);
+            }
+        }
         let metadata = match self.metadata_loader.load_metadata(connection).await {
             None => return Ok(None),
             Some(metadata) => metadata,
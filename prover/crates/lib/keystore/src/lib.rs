@@ -26,6 +26,7 @@ use zksync_prover_fri_types::circuit_definitions::boojum::{
 pub mod commitment_utils;
 pub mod keystore;
 pub mod setup_data_generator;
+pub mod setup_key_fetcher;
 pub mod utils;
 
 #[cfg(feature = "gpu")]
@@ -0,0 +1,260 @@
+//! Downloading of setup keys for a specific protocol version.
+//!
+//! Keys for a protocol version are expected to be served at `{base_url}/{protocol_version}/`, together with a
+//! [`MANIFEST_FILE_NAME`] file listing key files and their SHA3-256 hashes. Keys are downloaded to temporary files
+//! that are resumed on subsequent attempts (including after a process restart) and are moved to their final location
+//! only after hash verification.
+
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use reqwest::{header, StatusCode};
+use serde::Deserialize;
+use sha3::{Digest, Sha3_256};
+use tokio::{fs, io::AsyncWriteExt};
+use zksync_basic_types::protocol_version::ProtocolSemanticVersion;
+
+/// Name of the manifest file listing setup keys for a protocol version.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+const PARTIAL_FILE_EXTENSION: &str = "part";
+const MAX_RETRIES: usize = 5;
+const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Manifest of setup keys for a specific protocol version.
+#[derive(Debug, Deserialize)]
+struct SetupKeysManifest {
+    /// Mapping from a file name to its hex-encoded SHA3-256 hash.
+    files: BTreeMap<String, String>,
+}
+
+impl SetupKeysManifest {
+    fn validate(&self) -> anyhow::Result<()> {
+        for (file_name, hash) in &self.files {
+            let is_plain_name = !file_name.is_empty()
+                && !file_name.starts_with('.')
+                && !file_name.contains(['/', '\\']);
+            anyhow::ensure!(
+                is_plain_name,
+                "invalid file name in manifest: {file_name:?}"
+            );
+            let hash =
+                hex::decode(hash).with_context(|| format!("invalid hash for {file_name}"))?;
+            anyhow::ensure!(hash.len() == 32, "invalid hash length for {file_name}");
+        }
+        Ok(())
+    }
+}
+
+/// Downloads setup keys missing from the setup data directory.
+#[derive(Debug)]
+pub struct SetupKeyFetcher {
+    client: reqwest::Client,
+    base_url: String,
+    setup_data_path: PathBuf,
+}
+
+impl SetupKeyFetcher {
+    pub fn new(
+        download_url: &str,
+        protocol_version: ProtocolSemanticVersion,
+        setup_data_path: PathBuf,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: format!("{}/{protocol_version}", download_url.trim_end_matches('/')),
+            setup_data_path,
+        }
+    }
+
+    /// Downloads all keys listed in the manifest that are not present in the setup data directory.
+    /// Keys already present are not re-verified.
+    pub async fn fetch_missing_keys(&self) -> anyhow::Result<()> {
+        let manifest = self.fetch_manifest().await?;
+        fs::create_dir_all(&self.setup_data_path)
+            .await
+            .with_context(|| format!("failed creating {:?}", self.setup_data_path))?;
+
+        let missing_files: Vec<_> = manifest
+            .files
+            .iter()
+            .filter(|(file_name, _)| !self.setup_data_path.join(file_name).exists())
+            .collect();
+        tracing::info!(
+            "{} out of {} setup key files are missing in {:?}",
+            missing_files.len(),
+            manifest.files.len(),
+            self.setup_data_path
+        );
+
+        for (file_name, expected_hash) in missing_files {
+            self.download_file(file_name, expected_hash).await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_manifest(&self) -> anyhow::Result<SetupKeysManifest> {
+        let url = format!("{}/{MANIFEST_FILE_NAME}", self.base_url);
+        let manifest_bytes = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .with_context(|| format!("failed fetching setup keys manifest from {url}"))?
+            .bytes()
+            .await
+            .context("failed reading setup keys manifest")?;
+        let manifest: SetupKeysManifest = serde_json::from_slice(&manifest_bytes)
+            .context("failed parsing setup keys manifest")?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    async fn download_file(&self, file_name: &str, expected_hash: &str) -> anyhow::Result<()> {
+        let url = format!("{}/{file_name}", self.base_url);
+        let path = self.setup_data_path.join(file_name);
+        let partial_path = self
+            .setup_data_path
+            .join(format!("{file_name}.{PARTIAL_FILE_EXTENSION}"));
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self.download_partial_file(&url, &partial_path).await {
+                Ok(()) => break,
+                Err(err) if attempt < MAX_RETRIES => {
+                    tracing::warn!(
+                        "Failed downloading {url} (attempt {attempt}/{MAX_RETRIES}): {err:#}; retrying in {RETRY_INTERVAL:?}"
+                    );
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                }
+                Err(err) => return Err(err.context(format!("failed downloading {url}"))),
+            }
+        }
+
+        let actual_hash = hash_file(partial_path.clone()).await?;
+        if !actual_hash.eq_ignore_ascii_case(expected_hash) {
+            // The partial file is corrupted, so it cannot be resumed.
+            fs::remove_file(&partial_path).await.ok();
+            anyhow::bail!(
+                "hash mismatch for {file_name}: expected {expected_hash}, got {actual_hash}"
+            );
+        }
+        fs::rename(&partial_path, &path)
+            .await
+            .with_context(|| format!("failed moving downloaded file to {path:?}"))?;
+        tracing::info!("Downloaded and verified setup key file {file_name}");
+        Ok(())
+    }
+
+    /// Downloads `url` to `partial_path`, resuming the download if the file already exists.
+    async fn download_partial_file(&self, url: &str, partial_path: &Path) -> anyhow::Result<()> {
+        let downloaded_len = match fs::metadata(partial_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+            Err(err) => return Err(err).context("failed reading partial file metadata"),
+        };
+
+        let mut request = self.client.get(url);
+        if downloaded_len > 0 {
+            tracing::info!("Resuming download of {url} from byte {downloaded_len}");
+            request = request.header(header::RANGE, format!("bytes={downloaded_len}-"));
+        }
+        let mut response = request.send().await?;
+        let mut file = match response.status() {
+            StatusCode::PARTIAL_CONTENT => fs::OpenOptions::new()
+                .append(true)
+                .open(partial_path)
+                .await
+                .context("failed opening partial file")?,
+            // The partial file is already complete; hash verification will catch the case when it's not.
+            StatusCode::RANGE_NOT_SATISFIABLE if downloaded_len > 0 => return Ok(()),
+            status if status.is_success() => {
+                if downloaded_len > 0 {
+                    tracing::info!(
+                        "Server doesn't support range requests; restarting download of {url}"
+                    );
+                }
+                fs::File::create(partial_path)
+                    .await
+                    .context("failed creating partial file")?
+            }
+            status => anyhow::bail!("unexpected response status: {status}"),
+        };
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk)
+                .await
+                .context("failed writing partial file")?;
+        }
+        file.sync_all()
+            .await
+            .context("failed syncing partial file")?;
+        Ok(())
+    }
+}
+
+async fn hash_file(path: PathBuf) -> anyhow::Result<String> {
+    tokio::task::spawn_blocking(move || hash_file_blocking(&path))
+        .await
+        .context("hashing task panicked")?
+}
+
+fn hash_file_blocking(path: &Path) -> anyhow::Result<String> {
+    let mut file = std::fs::File::open(path)
+        .with_context(|| format!("failed opening {path:?} for hashing"))?;
+    let mut hasher = Sha3_256::new();
+    io::copy(&mut file, &mut hasher).with_context(|| format!("failed hashing {path:?}"))?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(file_name: &str, hash: &str) -> SetupKeysManifest {
+        SetupKeysManifest {
+            files: BTreeMap::from([(file_name.to_owned(), hash.to_owned())]),
+        }
+    }
+
+    #[test]
+    fn validating_manifest() {
+        let hash = "ab".repeat(32);
+        manifest("setup_basic_1_data.bin", &hash)
+            .validate()
+            .unwrap();
+
+        for file_name in ["", "../setup_basic_1_data.bin", "keys/setup.bin", ".hidden"] {
+            let err = manifest(file_name, &hash).validate().unwrap_err();
+            assert!(err.to_string().contains("invalid file name"), "{err:#}");
+        }
+        manifest("setup_basic_1_data.bin", "ab")
+            .validate()
+            .unwrap_err();
+        manifest("setup_basic_1_data.bin", "not a hash")
+            .validate()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn hashing_file() {
+        let dir = std::env::temp_dir().join(format!("setup_key_fetcher_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("key.bin");
+        std::fs::write(&path, b"abc").unwrap();
+
+        let hash = hash_file_blocking(&path).unwrap();
+        // SHA3-256 test vector for "abc"
+        assert_eq!(
+            hash,
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS witness_generator_job_stats (
+    id                BIGSERIAL PRIMARY KEY,
+    job_id            BIGINT NOT NULL,
+    l1_batch_number   BIGINT NOT NULL,
+    aggregation_round SMALLINT NOT NULL,
+    circuit_id        SMALLINT,
+    time_taken        TIME NOT NULL,
+    peak_rss_bytes    BIGINT,
+    created_at        TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_witness_generator_job_stats_l1_batch_number
+    ON witness_generator_job_stats (l1_batch_number);
+
+COMMENT ON TABLE witness_generator_job_stats IS 'Processing time and peak memory usage of witness generator jobs. Used for capacity planning and detecting pathological batches.';
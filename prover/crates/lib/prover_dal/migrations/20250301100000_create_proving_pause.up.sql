@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS proving_pause (
+    reason     TEXT,
+    paused_at  TIMESTAMP NOT NULL,
+    -- artificial primary key ensuring that the table contains at most 1 row.
+    fake_key   BOOLEAN PRIMARY KEY,
+    CHECK (fake_key)
+);
+
+COMMENT ON TABLE proving_pause IS 'Pause of proving job dispensing. Proving jobs are not tagged with a chain, so the pause is global and affects all jobs in the database.';
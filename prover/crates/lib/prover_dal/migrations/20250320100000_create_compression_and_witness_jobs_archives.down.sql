@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS idx_proof_compression_jobs_fri_sent_to_server_updated_at;
+
+DROP TABLE IF EXISTS scheduler_witness_jobs_fri_archive;
+DROP TABLE IF EXISTS recursion_tip_witness_jobs_fri_archive;
+DROP TABLE IF EXISTS node_aggregation_witness_jobs_fri_archive;
+DROP TABLE IF EXISTS leaf_aggregation_witness_jobs_fri_archive;
+DROP TABLE IF EXISTS witness_inputs_fri_archive;
+DROP TABLE IF EXISTS proof_compression_jobs_fri_archive;
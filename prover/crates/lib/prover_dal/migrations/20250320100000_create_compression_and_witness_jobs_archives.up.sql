@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS proof_compression_jobs_fri_archive (LIKE proof_compression_jobs_fri);
+CREATE TABLE IF NOT EXISTS witness_inputs_fri_archive (LIKE witness_inputs_fri);
+CREATE TABLE IF NOT EXISTS leaf_aggregation_witness_jobs_fri_archive (LIKE leaf_aggregation_witness_jobs_fri);
+CREATE TABLE IF NOT EXISTS node_aggregation_witness_jobs_fri_archive (LIKE node_aggregation_witness_jobs_fri);
+CREATE TABLE IF NOT EXISTS recursion_tip_witness_jobs_fri_archive (LIKE recursion_tip_witness_jobs_fri);
+CREATE TABLE IF NOT EXISTS scheduler_witness_jobs_fri_archive (LIKE scheduler_witness_jobs_fri);
+
+CREATE INDEX IF NOT EXISTS idx_proof_compression_jobs_fri_archive_l1_batch_number
+    ON proof_compression_jobs_fri_archive (l1_batch_number);
+CREATE INDEX IF NOT EXISTS idx_proof_compression_jobs_fri_sent_to_server_updated_at
+    ON proof_compression_jobs_fri (updated_at)
+    WHERE (status = 'sent_to_server'::text);
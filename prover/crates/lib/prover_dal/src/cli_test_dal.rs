@@ -32,7 +32,7 @@ impl CliTestDal<'_, '_> {
                 AND circuit_id = $5
             "#,
             status.to_string(),
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             sequence_number as i64,
             aggregation_round as i16,
             circuit_id as i64,
@@ -66,7 +66,7 @@ impl CliTestDal<'_, '_> {
             SET
             status = $3
             "#,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             circuit_id as i16,
             status.to_string()
         )
@@ -94,7 +94,7 @@ impl CliTestDal<'_, '_> {
             SET
             status = $3
             "#,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             circuit_id as i16,
             status.to_string(),
         )
@@ -117,7 +117,7 @@ impl CliTestDal<'_, '_> {
             SET
             status = $2
             "#,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             status.to_string(),
         )
         .execute(self.storage.conn())
@@ -147,7 +147,7 @@ impl CliTestDal<'_, '_> {
             SET
             status = $2
             "#,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             status.to_string(),
         )
         .execute(self.storage.conn())
@@ -171,7 +171,7 @@ impl CliTestDal<'_, '_> {
             SET
             status = $2
             "#,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             status.to_string(),
         )
         .execute(self.storage.conn())
@@ -202,7 +202,7 @@ impl CliTestDal<'_, '_> {
             "#,
             status.to_string(),
             attempts as i64,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             sequence_number as i64,
             aggregation_round as i64,
             circuit_id as i16,
@@ -231,7 +231,7 @@ impl CliTestDal<'_, '_> {
             "#,
             status.to_string(),
             attempts as i64,
-            batch_number.0 as i64,
+            i64::from(batch_number.0),
             circuit_id as i16,
         )
         .execute(self.storage.conn())
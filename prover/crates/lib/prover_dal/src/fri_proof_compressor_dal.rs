@@ -4,13 +4,16 @@ use std::{collections::HashMap, str::FromStr, time::Duration};
 use zksync_basic_types::{
     protocol_version::{ProtocolSemanticVersion, ProtocolVersionId, VersionPatch},
     prover_dal::{
-        JobCountStatistics, ProofCompressionJobInfo, ProofCompressionJobStatus, StuckJobs,
+        ArchivedBatchJobs, JobCountStatistics, ProofCompressionJobInfo, ProofCompressionJobStatus,
+        StuckJobs, StuckJobsFilter,
     },
     L1BatchNumber,
 };
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
 
-use crate::{duration_to_naive_time, pg_interval_from_duration, Prover};
+use crate::{
+    duration_to_naive_time, pg_interval_from_duration, Prover, ProverDal, StuckJobsFilterParams,
+};
 
 #[derive(Debug)]
 pub struct FriProofCompressorDal<'a, 'c> {
@@ -56,6 +59,9 @@ impl FriProofCompressorDal<'_, '_> {
         picked_by: &str,
         protocol_version: ProtocolSemanticVersion,
     ) -> Option<L1BatchNumber> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE proof_compression_jobs_fri
@@ -95,7 +101,7 @@ impl FriProofCompressorDal<'_, '_> {
         .fetch_optional(self.storage.conn())
         .await
         .unwrap()
-        .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+        .map(|row| L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"))
     }
 
     pub async fn get_proof_compression_job_attempts(
@@ -210,7 +216,7 @@ impl FriProofCompressorDal<'_, '_> {
         .ok()?;
         match row {
             Some(row) => Some((
-                L1BatchNumber(row.l1_batch_number as u32),
+                L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
                 ProtocolSemanticVersion::new(
                     ProtocolVersionId::try_from(row.protocol_version.unwrap() as u16).unwrap(),
                     VersionPatch(row.protocol_version_patch as u32),
@@ -221,6 +227,46 @@ impl FriProofCompressorDal<'_, '_> {
         }
     }
 
+    /// Returns the first batch starting from `from_l1_batch` which final proof wasn't sent to the server yet.
+    /// Only batches with witness inputs are considered, i.e. the returned batch may still be in witness generation.
+    pub async fn get_first_batch_not_sent_to_server(
+        &mut self,
+        from_l1_batch: L1BatchNumber,
+    ) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number
+            FROM
+                witness_inputs_fri
+            WHERE
+                l1_batch_number >= $1
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        proof_compression_jobs_fri
+                    WHERE
+                        proof_compression_jobs_fri.l1_batch_number = witness_inputs_fri.l1_batch_number
+                        AND proof_compression_jobs_fri.status = $2
+                )
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                1
+            "#,
+            i64::from(from_l1_batch.0),
+            ProofCompressionJobStatus::SentToServer.to_string(),
+        )
+        .instrument("get_first_batch_not_sent_to_server")
+        .with_arg("from_l1_batch", &from_l1_batch)
+        .fetch_optional(self.storage)
+        .await?;
+        Ok(row.map(|row| {
+            L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number")
+        }))
+    }
+
     pub async fn mark_proof_sent_to_server(
         &mut self,
         block_number: L1BatchNumber,
@@ -303,7 +349,7 @@ impl FriProofCompressorDal<'_, '_> {
         .fetch_optional(self.storage.conn())
         .await
         .unwrap()
-        .map(|row| L1BatchNumber(row.l1_batch_number as u32));
+        .map(|row| L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"));
 
         result
     }
@@ -348,7 +394,7 @@ impl FriProofCompressorDal<'_, '_> {
             .unwrap()
             .into_iter()
             .map(|row| StuckJobs {
-                id: row.l1_batch_number as u64,
+                id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
                 status: row.status,
                 attempts: row.attempts as u64,
                 circuit_id: None,
@@ -418,54 +464,221 @@ impl FriProofCompressorDal<'_, '_> {
         .await
     }
 
+    /// Marks an unfinished proof compression job for the specified batch as skipped, so that the skip is reported
+    /// to the server. Returns `false` if there is no such job.
+    pub async fn skip_proof_compression_job(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<bool> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE proof_compression_jobs_fri
+            SET
+                status = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND status NOT IN ($1, $3, $4)
+            "#,
+            ProofCompressionJobStatus::Skipped.to_string(),
+            i64::from(l1_batch_number.0),
+            ProofCompressionJobStatus::Successful.to_string(),
+            ProofCompressionJobStatus::SentToServer.to_string(),
+        )
+        .execute(self.storage.conn())
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
     pub async fn requeue_stuck_jobs_for_batch(
         &mut self,
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
-        {
-            sqlx::query!(
-                r#"
-                UPDATE proof_compression_jobs_fri
-                SET
-                    status = 'queued',
-                    error = 'Manually requeued',
-                    attempts = 2,
-                    updated_at = NOW(),
-                    processing_started_at = NOW(),
-                    priority = priority + 1
+        self.requeue_stuck_jobs_matching(&StuckJobsFilter::for_batch(block_number), max_attempts)
+            .await
+    }
+
+    /// Requeues all in-progress or failed jobs matching the filter that have at least `max_attempts` attempts.
+    pub async fn requeue_stuck_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        let params = StuckJobsFilterParams::from(filter);
+        sqlx::query!(
+            r#"
+            UPDATE proof_compression_jobs_fri
+            SET
+                status = 'queued',
+                error = 'Manually requeued',
+                attempts = 2,
+                updated_at = NOW(),
+                processing_started_at = NOW(),
+                priority = priority + 1
+            WHERE
+                (
+                    $1::BIGINT IS NULL
+                    OR l1_batch_number >= $1
+                )
+                AND (
+                    $2::BIGINT IS NULL
+                    OR l1_batch_number <= $2
+                )
+                AND (
+                    $3::INT IS NULL
+                    OR (
+                        protocol_version = $3
+                        AND protocol_version_patch = $4
+                    )
+                )
+                AND attempts >= $5
+                AND (
+                    status = 'in_progress'
+                    OR status = 'failed'
+                )
+            RETURNING
+            l1_batch_number,
+            status,
+            attempts,
+            error,
+            picked_by
+            "#,
+            params.min_l1_batch_number,
+            params.max_l1_batch_number,
+            params.protocol_version,
+            params.protocol_version_patch,
+            max_attempts as i32,
+        )
+        .fetch_all(self.storage.conn())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| StuckJobs {
+            id: row.l1_batch_number as u64,
+            status: row.status,
+            attempts: row.attempts as u64,
+            circuit_id: None,
+            error: row.error,
+            picked_by: row.picked_by,
+        })
+        .collect()
+    }
+
+    /// Moves compression jobs for batches which proofs were sent to the server more than `archiving_interval` ago
+    /// to the archive table, together with witness generator jobs for the same batches. Batches that still have
+    /// prover jobs are skipped, so that prover jobs can be archived first.
+    pub async fn archive_old_jobs(&mut self, archiving_interval: Duration) -> ArchivedBatchJobs {
+        let archiving_interval_secs = pg_interval_from_duration(archiving_interval);
+
+        let row = sqlx::query!(
+            r#"
+            WITH archived_batches AS (
+                DELETE FROM proof_compression_jobs_fri AS c
                 WHERE
-                    l1_batch_number = $1
-                    AND attempts >= $2
-                    AND (
-                        status = 'in_progress'
-                        OR status = 'failed'
+                    c.status = 'sent_to_server'
+                    AND c.updated_at < NOW() - $1::INTERVAL
+                    AND NOT EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            prover_jobs_fri AS p
+                        WHERE
+                            p.l1_batch_number = c.l1_batch_number
                     )
-                RETURNING
-                status,
-                attempts,
-                error,
-                picked_by
-                "#,
-                i64::from(block_number.0),
-                max_attempts as i32,
+                RETURNING c.*
+            ),
+            inserted_compression_jobs AS (
+                INSERT INTO proof_compression_jobs_fri_archive
+                SELECT * FROM archived_batches
+            ),
+            deleted_basic AS (
+                DELETE FROM witness_inputs_fri
+                WHERE l1_batch_number IN (SELECT l1_batch_number FROM archived_batches)
+                RETURNING *
+            ),
+            inserted_basic AS (
+                INSERT INTO witness_inputs_fri_archive
+                SELECT * FROM deleted_basic
+            ),
+            deleted_leaf AS (
+                DELETE FROM leaf_aggregation_witness_jobs_fri
+                WHERE l1_batch_number IN (SELECT l1_batch_number FROM archived_batches)
+                RETURNING *
+            ),
+            inserted_leaf AS (
+                INSERT INTO leaf_aggregation_witness_jobs_fri_archive
+                SELECT * FROM deleted_leaf
+            ),
+            deleted_node AS (
+                DELETE FROM node_aggregation_witness_jobs_fri
+                WHERE l1_batch_number IN (SELECT l1_batch_number FROM archived_batches)
+                RETURNING *
+            ),
+            inserted_node AS (
+                INSERT INTO node_aggregation_witness_jobs_fri_archive
+                SELECT * FROM deleted_node
+            ),
+            deleted_recursion_tip AS (
+                DELETE FROM recursion_tip_witness_jobs_fri
+                WHERE l1_batch_number IN (SELECT l1_batch_number FROM archived_batches)
+                RETURNING *
+            ),
+            inserted_recursion_tip AS (
+                INSERT INTO recursion_tip_witness_jobs_fri_archive
+                SELECT * FROM deleted_recursion_tip
+            ),
+            deleted_scheduler AS (
+                DELETE FROM scheduler_witness_jobs_fri
+                WHERE l1_batch_number IN (SELECT l1_batch_number FROM archived_batches)
+                RETURNING *
+            ),
+            inserted_scheduler AS (
+                INSERT INTO scheduler_witness_jobs_fri_archive
+                SELECT * FROM deleted_scheduler
             )
-            .fetch_all(self.storage.conn())
-            .await
-            .unwrap()
-            .into_iter()
-            .map(|row| StuckJobs {
-                id: block_number.0 as u64,
-                status: row.status,
-                attempts: row.attempts as u64,
-                circuit_id: None,
-                error: row.error,
-                picked_by: row.picked_by,
-            })
-            .collect()
+            SELECT
+                (SELECT COUNT(*) FROM archived_batches) AS "compression_jobs!",
+                (SELECT COUNT(*) FROM deleted_basic)
+                + (SELECT COUNT(*) FROM deleted_leaf)
+                + (SELECT COUNT(*) FROM deleted_node)
+                + (SELECT COUNT(*) FROM deleted_recursion_tip)
+                + (SELECT COUNT(*) FROM deleted_scheduler) AS "witness_jobs!"
+            "#,
+            &archiving_interval_secs,
+        )
+        .fetch_one(self.storage.conn())
+        .await
+        .unwrap();
+
+        ArchivedBatchJobs {
+            compression_jobs: row.compression_jobs as usize,
+            witness_jobs: row.witness_jobs as usize,
         }
     }
 
+    /// Returns the time elapsed since the proof for the oldest non-archived batch was sent to the server.
+    pub async fn get_oldest_not_archived_job_age(&mut self) -> Option<Duration> {
+        let age_secs = sqlx::query_scalar!(
+            r#"
+            SELECT
+                EXTRACT(
+                    EPOCH
+                    FROM
+                    NOW() - MIN(updated_at)
+                )::BIGINT AS "age_secs"
+            FROM
+                proof_compression_jobs_fri
+            WHERE
+                status = 'sent_to_server'
+            "#
+        )
+        .fetch_one(self.storage.conn())
+        .await
+        .unwrap()?;
+        Some(Duration::from_secs(age_secs.max(0) as u64))
+    }
+
     pub async fn check_reached_max_attempts(&mut self, max_attempts: u32) -> usize {
         sqlx::query_scalar!(
             r#"
@@ -14,7 +14,8 @@ use zksync_basic_types::{
     },
     protocol_version::{ProtocolSemanticVersion, ProtocolVersionId, VersionPatch},
     prover_dal::{
-        FriProverJobMetadata, JobCountStatistics, ProverJobFriInfo, ProverJobStatus, StuckJobs,
+        FriProverJobMetadata, JobCountStatistics, ProofCompressionJobStatus, ProverJobFriInfo,
+        ProverJobStatus, ProverJobsRoundStats, StuckJobs, StuckJobsFilter,
     },
     L1BatchNumber,
 };
@@ -22,7 +23,9 @@ use zksync_db_connection::{
     connection::Connection, instrument::InstrumentExt, metrics::MethodLatency,
 };
 
-use crate::{duration_to_naive_time, pg_interval_from_duration, Prover};
+use crate::{
+    duration_to_naive_time, pg_interval_from_duration, Prover, ProverDal, StuckJobsFilterParams,
+};
 
 #[derive(Debug)]
 pub struct FriProverDal<'a, 'c> {
@@ -76,7 +79,7 @@ impl FriProverDal<'_, '_> {
             query_builder.push_values(
                 chunk.iter().enumerate(),
                 |mut row, (i, (circuit_id, circuit_blob_url))| {
-                    row.push_bind(l1_batch_number.0 as i64)
+                    row.push_bind(i64::from(l1_batch_number.0))
                         .push_bind(*circuit_id as i16)
                         .push_bind(circuit_blob_url)
                         .push_bind(aggregation_round as i64)
@@ -124,6 +127,9 @@ impl FriProverDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<FriProverJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE prover_jobs_fri
@@ -173,7 +179,8 @@ impl FriProverDal<'_, '_> {
         .expect("failed to get prover job")
         .map(|row| FriProverJobMetadata {
             id: row.id as u32,
-            block_number: L1BatchNumber(row.l1_batch_number as u32),
+            block_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             circuit_id: row.circuit_id as u8,
             aggregation_round: AggregationRound::try_from(i32::from(row.aggregation_round))
                 .unwrap(),
@@ -202,6 +209,9 @@ impl FriProverDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<FriProverJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE prover_jobs_fri
@@ -251,7 +261,8 @@ impl FriProverDal<'_, '_> {
         .expect("failed to get prover job")
         .map(|row| FriProverJobMetadata {
             id: row.id as u32,
-            block_number: L1BatchNumber(row.l1_batch_number as u32),
+            block_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             circuit_id: row.circuit_id as u8,
             aggregation_round: AggregationRound::try_from(i32::from(row.aggregation_round))
                 .unwrap(),
@@ -267,6 +278,9 @@ impl FriProverDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<FriProverJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE prover_jobs_fri
@@ -313,7 +327,8 @@ impl FriProverDal<'_, '_> {
         .unwrap()
         .map(|row| FriProverJobMetadata {
             id: row.id as u32,
-            block_number: L1BatchNumber(row.l1_batch_number as u32),
+            block_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             circuit_id: row.circuit_id as u8,
             aggregation_round: AggregationRound::try_from(i32::from(row.aggregation_round))
                 .unwrap(),
@@ -329,6 +344,9 @@ impl FriProverDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<FriProverJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         let circuit_ids: Vec<_> = circuits_to_pick
             .iter()
             .map(|tuple| i16::from(tuple.circuit_id))
@@ -403,7 +421,8 @@ impl FriProverDal<'_, '_> {
         .unwrap()
         .map(|row| FriProverJobMetadata {
             id: row.id as u32,
-            block_number: L1BatchNumber(row.l1_batch_number as u32),
+            block_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             circuit_id: row.circuit_id as u8,
             aggregation_round: AggregationRound::try_from(i32::from(row.aggregation_round))
                 .unwrap(),
@@ -492,7 +511,8 @@ impl FriProverDal<'_, '_> {
         .unwrap()
         .map(|row| FriProverJobMetadata {
             id: row.id as u32,
-            block_number: L1BatchNumber(row.l1_batch_number as u32),
+            block_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             circuit_id: row.circuit_id as u8,
             aggregation_round: AggregationRound::try_from(i32::from(row.aggregation_round))
                 .unwrap(),
@@ -734,7 +754,7 @@ impl FriProverDal<'_, '_> {
             .map(|row| {
                 (
                     (row.circuit_id as u8, row.aggregation_round as u8),
-                    L1BatchNumber(row.l1_batch_number as u32),
+                    L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
                 )
             })
             .collect()
@@ -799,7 +819,7 @@ impl FriProverDal<'_, '_> {
                 AND status = 'successful'
                 AND aggregation_round = $2
             "#,
-            l1_batch_number.0 as i64,
+            i64::from(l1_batch_number.0),
             AggregationRound::RecursionTip as i16,
         )
         .fetch_optional(self.storage.conn())
@@ -854,7 +874,7 @@ impl FriProverDal<'_, '_> {
             ORDER BY
                 circuit_id ASC
             "#,
-            l1_batch_number.0 as i64
+            i64::from(l1_batch_number.0)
         )
         .fetch_all(self.storage.conn())
         .await
@@ -911,6 +931,77 @@ impl FriProverDal<'_, '_> {
         .collect()
     }
 
+    /// Returns per-round prover job statistics for batches starting from `first_l1_batch` which final proofs
+    /// weren't sent to the server yet, ordered by batch number and aggregation round.
+    ///
+    /// `first_l1_batch` should be the first batch not sent to the server (see
+    /// [`FriProofCompressorDal::get_first_batch_not_sent_to_server()`](crate::fri_proof_compressor_dal::FriProofCompressorDal::get_first_batch_not_sent_to_server)),
+    /// so that only in-flight batches are scanned.
+    pub async fn get_round_stats_for_unsent_batches(
+        &mut self,
+        first_l1_batch: L1BatchNumber,
+    ) -> Vec<ProverJobsRoundStats> {
+        sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                aggregation_round,
+                COUNT(*) AS "jobs_count!",
+                COUNT(*) FILTER (
+                    WHERE
+                    status = 'successful'
+                ) AS "successful_jobs_count!",
+                ARRAY_AGG(DISTINCT circuit_id) FILTER (
+                    WHERE
+                    status = 'failed'
+                ) AS failed_circuit_ids,
+                MIN(proof_blob_url) AS proof_blob_url
+            FROM
+                prover_jobs_fri
+            WHERE
+                l1_batch_number >= $2
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        proof_compression_jobs_fri
+                    WHERE
+                        proof_compression_jobs_fri.l1_batch_number = prover_jobs_fri.l1_batch_number
+                        AND proof_compression_jobs_fri.status = $1
+                )
+            GROUP BY
+                l1_batch_number,
+                aggregation_round
+            ORDER BY
+                l1_batch_number,
+                aggregation_round
+            "#,
+            ProofCompressionJobStatus::SentToServer.to_string(),
+            i64::from(first_l1_batch.0),
+        )
+        .instrument("get_round_stats_for_unsent_batches")
+        .with_arg("first_l1_batch", &first_l1_batch)
+        .fetch_all(self.storage)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| ProverJobsRoundStats {
+            l1_batch_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
+            aggregation_round: AggregationRound::from(row.aggregation_round as u8),
+            jobs_count: row.jobs_count as u32,
+            successful_jobs_count: row.successful_jobs_count as u32,
+            failed_circuit_ids: row
+                .failed_circuit_ids
+                .unwrap_or_default()
+                .into_iter()
+                .map(|id| id as u8)
+                .collect(),
+            proof_blob_url: row.proof_blob_url,
+        })
+        .collect()
+    }
+
     pub async fn delete_prover_jobs_fri_batch_data(
         &mut self,
         l1_batch_number: L1BatchNumber,
@@ -935,6 +1026,28 @@ impl FriProverDal<'_, '_> {
             .await
     }
 
+    /// Marks all unfinished prover jobs for the specified batch as skipped. Returns the number of skipped jobs.
+    pub async fn skip_prover_jobs_for_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE prover_jobs_fri
+            SET
+                status = 'skipped',
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+                AND status NOT IN ('successful', 'skipped', 'ignored')
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .execute(self.storage.conn())
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     pub async fn delete_prover_jobs_fri(&mut self) -> sqlx::Result<sqlx::postgres::PgQueryResult> {
         sqlx::query!(
             r#"
@@ -954,49 +1067,75 @@ impl FriProverDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
-        {
-            sqlx::query!(
-                r#"
-                UPDATE prover_jobs_fri
-                SET
-                    status = 'queued',
-                    error = 'Manually requeued',
-                    attempts = 2,
-                    updated_at = NOW(),
-                    processing_started_at = NOW(),
-                    priority = priority + 1
-                WHERE
-                    l1_batch_number = $1
-                    AND attempts >= $2
-                    AND (
-                        status = 'in_progress'
-                        OR status = 'failed'
-                    )
-                RETURNING
-                id,
-                status,
-                attempts,
-                circuit_id,
-                error,
-                picked_by
-                "#,
-                i64::from(block_number.0),
-                max_attempts as i32,
-            )
-            .fetch_all(self.storage.conn())
+        self.requeue_stuck_jobs_matching(&StuckJobsFilter::for_batch(block_number), max_attempts)
             .await
-            .unwrap()
-            .into_iter()
-            .map(|row| StuckJobs {
-                id: row.id as u64,
-                status: row.status,
-                attempts: row.attempts as u64,
-                circuit_id: Some(row.circuit_id as u32),
-                error: row.error,
-                picked_by: row.picked_by,
-            })
-            .collect()
-        }
+    }
+
+    /// Requeues all in-progress or failed jobs matching the filter that have at least `max_attempts` attempts.
+    pub async fn requeue_stuck_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        let params = StuckJobsFilterParams::from(filter);
+        sqlx::query!(
+            r#"
+            UPDATE prover_jobs_fri
+            SET
+                status = 'queued',
+                error = 'Manually requeued',
+                attempts = 2,
+                updated_at = NOW(),
+                processing_started_at = NOW(),
+                priority = priority + 1
+            WHERE
+                (
+                    $1::BIGINT IS NULL
+                    OR l1_batch_number >= $1
+                )
+                AND (
+                    $2::BIGINT IS NULL
+                    OR l1_batch_number <= $2
+                )
+                AND (
+                    $3::INT IS NULL
+                    OR (
+                        protocol_version = $3
+                        AND protocol_version_patch = $4
+                    )
+                )
+                AND attempts >= $5
+                AND (
+                    status = 'in_progress'
+                    OR status = 'failed'
+                )
+            RETURNING
+            id,
+            status,
+            attempts,
+            circuit_id,
+            error,
+            picked_by
+            "#,
+            params.min_l1_batch_number,
+            params.max_l1_batch_number,
+            params.protocol_version,
+            params.protocol_version_patch,
+            max_attempts as i32,
+        )
+        .fetch_all(self.storage.conn())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| StuckJobs {
+            id: row.id as u64,
+            status: row.status,
+            attempts: row.attempts as u64,
+            circuit_id: Some(row.circuit_id as u32),
+            error: row.error,
+            picked_by: row.picked_by,
+        })
+        .collect()
     }
 
     pub async fn prover_job_ids_for(
@@ -1058,7 +1197,6 @@ mod tests {
     use zksync_db_connection::connection_pool::ConnectionPool;
 
     use super::*;
-    use crate::ProverDal;
 
     fn mock_circuit_ids_and_urls(num_circuits: usize) -> Vec<(u8, String)> {
         (0..num_circuits)
@@ -1093,4 +1231,213 @@ mod tests {
 
         transaction.commit().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn requeuing_stuck_jobs_matching_filter() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(
+                ProtocolSemanticVersion::default(),
+                L1VerifierConfig::default(),
+            )
+            .await
+            .unwrap();
+        for batch in 1..=3 {
+            conn.fri_prover_jobs_dal()
+                .insert_prover_jobs(
+                    L1BatchNumber(batch),
+                    mock_circuit_ids_and_urls(2),
+                    AggregationRound::BasicCircuits,
+                    0,
+                    ProtocolSemanticVersion::default(),
+                )
+                .await;
+        }
+        sqlx::query("UPDATE prover_jobs_fri SET status = 'failed', attempts = 10")
+            .execute(conn.conn())
+            .await
+            .unwrap();
+
+        let other_version = ProtocolSemanticVersion::new(
+            ProtocolVersionId::next(),
+            ProtocolSemanticVersion::default().patch,
+        );
+        let filter = StuckJobsFilter {
+            l1_batch_range: Some(L1BatchNumber(2)..=L1BatchNumber(3)),
+            protocol_version: Some(other_version),
+        };
+        let requeued = conn
+            .fri_prover_jobs_dal()
+            .requeue_stuck_jobs_matching(&filter, 10)
+            .await;
+        assert!(requeued.is_empty(), "{requeued:?}");
+
+        let filter = StuckJobsFilter {
+            protocol_version: Some(ProtocolSemanticVersion::default()),
+            ..filter
+        };
+        let requeued = conn
+            .fri_prover_jobs_dal()
+            .requeue_stuck_jobs_matching(&filter, 10)
+            .await;
+        assert_eq!(requeued.len(), 4, "{requeued:?}");
+        assert!(requeued.iter().all(|job| job.status == "queued"));
+
+        // Jobs for the first batch are not affected.
+        let requeued = conn
+            .fri_prover_jobs_dal()
+            .requeue_stuck_jobs_matching(&StuckJobsFilter::default(), 10)
+            .await;
+        assert_eq!(requeued.len(), 2, "{requeued:?}");
+    }
+
+    #[tokio::test]
+    async fn round_stats_are_limited_to_unsent_batches() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let protocol_version = ProtocolSemanticVersion::default();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+            .await
+            .unwrap();
+        for batch in 1..=3 {
+            let l1_batch_number = L1BatchNumber(batch);
+            conn.fri_basic_witness_generator_dal()
+                .save_witness_inputs(l1_batch_number, "witness_inputs", protocol_version)
+                .await
+                .unwrap();
+            conn.fri_prover_jobs_dal()
+                .insert_prover_jobs(
+                    l1_batch_number,
+                    mock_circuit_ids_and_urls(2),
+                    AggregationRound::BasicCircuits,
+                    0,
+                    protocol_version,
+                )
+                .await;
+        }
+        for batch in [1, 3] {
+            let l1_batch_number = L1BatchNumber(batch);
+            conn.fri_proof_compressor_dal()
+                .insert_proof_compression_job(l1_batch_number, "proof", protocol_version)
+                .await;
+            conn.fri_proof_compressor_dal()
+                .mark_proof_sent_to_server(l1_batch_number)
+                .await
+                .unwrap();
+        }
+
+        let first_unsent_batch = conn
+            .fri_proof_compressor_dal()
+            .get_first_batch_not_sent_to_server(L1BatchNumber(0))
+            .await
+            .unwrap();
+        assert_eq!(first_unsent_batch, Some(L1BatchNumber(2)));
+        let first_unsent_batch = conn
+            .fri_proof_compressor_dal()
+            .get_first_batch_not_sent_to_server(L1BatchNumber(3))
+            .await
+            .unwrap();
+        assert_eq!(first_unsent_batch, None);
+
+        for first_l1_batch in [L1BatchNumber(0), L1BatchNumber(2)] {
+            let stats = conn
+                .fri_prover_jobs_dal()
+                .get_round_stats_for_unsent_batches(first_l1_batch)
+                .await;
+            assert_eq!(stats.len(), 1, "{stats:?}");
+            assert_eq!(stats[0].l1_batch_number, L1BatchNumber(2));
+            assert_eq!(stats[0].jobs_count, 2);
+        }
+        let stats = conn
+            .fri_prover_jobs_dal()
+            .get_round_stats_for_unsent_batches(L1BatchNumber(3))
+            .await;
+        assert!(stats.is_empty(), "{stats:?}");
+    }
+
+    #[tokio::test]
+    async fn skipping_jobs_for_batch() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let protocol_version = ProtocolSemanticVersion::default();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+            .await
+            .unwrap();
+        for batch in [L1BatchNumber(1), L1BatchNumber(2)] {
+            conn.fri_basic_witness_generator_dal()
+                .save_witness_inputs(batch, "witness_inputs", protocol_version)
+                .await
+                .unwrap();
+            conn.fri_prover_jobs_dal()
+                .insert_prover_jobs(
+                    batch,
+                    mock_circuit_ids_and_urls(2),
+                    AggregationRound::BasicCircuits,
+                    0,
+                    protocol_version,
+                )
+                .await;
+        }
+        conn.fri_proof_compressor_dal()
+            .insert_proof_compression_job(L1BatchNumber(1), "proof", protocol_version)
+            .await;
+
+        let skipped = conn
+            .fri_witness_generator_dal()
+            .skip_witness_jobs_for_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(skipped, 1);
+        let skipped = conn
+            .fri_prover_jobs_dal()
+            .skip_prover_jobs_for_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(skipped, 2);
+        let skipped = conn
+            .fri_proof_compressor_dal()
+            .skip_proof_compression_job(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(skipped);
+        let job = conn
+            .fri_proof_compressor_dal()
+            .get_proof_compression_job_for_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(
+            matches!(job.status, ProofCompressionJobStatus::Skipped),
+            "{job:?}"
+        );
+
+        // Skipping is idempotent.
+        let skipped = conn
+            .fri_prover_jobs_dal()
+            .skip_prover_jobs_for_batch(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert_eq!(skipped, 0);
+        let skipped = conn
+            .fri_proof_compressor_dal()
+            .skip_proof_compression_job(L1BatchNumber(1))
+            .await
+            .unwrap();
+        assert!(!skipped);
+
+        // Jobs for other batches are not affected.
+        let job = conn
+            .fri_prover_jobs_dal()
+            .get_next_job(protocol_version, "test")
+            .await
+            .expect("no prover job for the second batch");
+        assert_eq!(job.block_number, L1BatchNumber(2));
+        let job = conn
+            .fri_basic_witness_generator_dal()
+            .get_next_basic_circuit_witness_job(protocol_version, "test")
+            .await;
+        assert_eq!(job, Some(L1BatchNumber(2)));
+    }
 }
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use zksync_basic_types::{
     protocol_version::{ProtocolSemanticVersion, ProtocolVersionId, VersionPatch},
-    prover_dal::{BasicWitnessGeneratorJobInfo, StuckJobs, WitnessJobStatus},
+    prover_dal::{BasicWitnessGeneratorJobInfo, StuckJobs, StuckJobsFilter, WitnessJobStatus},
     L1BatchNumber,
 };
 use zksync_db_connection::{
@@ -12,7 +12,9 @@ use zksync_db_connection::{
     utils::{duration_to_naive_time, pg_interval_from_duration},
 };
 
-use crate::{fri_witness_generator_dal::FriWitnessJobStatus, Prover};
+use crate::{
+    fri_witness_generator_dal::FriWitnessJobStatus, Prover, ProverDal, StuckJobsFilterParams,
+};
 
 #[derive(Debug)]
 pub struct FriBasicWitnessGeneratorDal<'a, 'c> {
@@ -60,6 +62,9 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<L1BatchNumber> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE witness_inputs_fri
@@ -97,7 +102,7 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         .fetch_optional(self.storage.conn())
         .await
         .unwrap()
-        .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+        .map(|row| L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"))
     }
 
     pub async fn set_status_for_basic_witness_job(
@@ -185,7 +190,7 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
@@ -259,6 +264,20 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
+        self.requeue_stuck_witness_inputs_jobs_matching(
+            &StuckJobsFilter::for_batch(block_number),
+            max_attempts,
+        )
+        .await
+    }
+
+    /// Requeues all in-progress or failed jobs matching the filter that have at least `max_attempts` attempts.
+    pub async fn requeue_stuck_witness_inputs_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        let params = StuckJobsFilterParams::from(filter);
         sqlx::query!(
             r#"
             UPDATE witness_inputs_fri
@@ -268,8 +287,22 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
                 processing_started_at = NOW(),
                 priority = priority + 1
             WHERE
-                l1_batch_number = $1
-                AND attempts >= $2
+                (
+                    $1::BIGINT IS NULL
+                    OR l1_batch_number >= $1
+                )
+                AND (
+                    $2::BIGINT IS NULL
+                    OR l1_batch_number <= $2
+                )
+                AND (
+                    $3::INT IS NULL
+                    OR (
+                        protocol_version = $3
+                        AND protocol_version_patch = $4
+                    )
+                )
+                AND attempts >= $5
                 AND (
                     status = 'in_progress'
                     OR status = 'failed'
@@ -281,7 +314,10 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
             error,
             picked_by
             "#,
-            i64::from(block_number.0),
+            params.min_l1_batch_number,
+            params.max_l1_batch_number,
+            params.protocol_version,
+            params.protocol_version_patch,
             max_attempts as i64
         )
         .fetch_all(self.storage.conn())
@@ -289,7 +325,7 @@ impl FriBasicWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
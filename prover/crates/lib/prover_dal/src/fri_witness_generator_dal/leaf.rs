@@ -45,6 +45,9 @@ impl FriLeafWitnessGeneratorDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<LeafAggregationJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         let row = sqlx::query!(
             r#"
             UPDATE leaf_aggregation_witness_jobs_fri
@@ -83,7 +86,8 @@ impl FriLeafWitnessGeneratorDal<'_, '_> {
         .await
         .unwrap()?;
 
-        let block_number = L1BatchNumber(row.l1_batch_number as u32);
+        let block_number =
+            L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number");
         let proof_job_ids = self
             .storage
             .fri_prover_jobs_dal()
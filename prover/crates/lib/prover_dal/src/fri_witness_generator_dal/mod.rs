@@ -6,18 +6,24 @@ pub mod node;
 pub mod recursion_tip;
 pub mod scheduler;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use sqlx::{types::chrono::NaiveDateTime, Row};
 use zksync_basic_types::{
     basic_fri_types::AggregationRound,
     protocol_version::{ProtocolSemanticVersion, ProtocolVersionId, VersionPatch},
-    prover_dal::{JobCountStatistics, ProofGenerationTime, StuckJobs},
+    prover_dal::{
+        JobCountStatistics, ProofGenerationTime, StuckJobs, StuckJobsFilter,
+        WitnessGeneratorJobStats,
+    },
     L1BatchNumber,
 };
-use zksync_db_connection::{connection::Connection, utils::naive_time_from_pg_interval};
+use zksync_db_connection::{
+    connection::Connection,
+    utils::{duration_to_naive_time, naive_time_from_pg_interval},
+};
 
-use crate::Prover;
+use crate::{Prover, StuckJobsFilterParams};
 
 #[derive(Debug)]
 pub struct FriWitnessGeneratorDal<'a, 'c> {
@@ -36,6 +42,8 @@ pub enum FriWitnessJobStatus {
     InProgress,
     #[strum(serialize = "queued")]
     Queued,
+    #[strum(serialize = "cancelled")]
+    Cancelled,
 }
 
 impl FriWitnessGeneratorDal<'_, '_> {
@@ -111,6 +119,7 @@ impl FriWitnessGeneratorDal<'_, '_> {
             WHERE
                 {job_id_column} = {job_id}
                 AND status != 'successful'
+                AND status != 'cancelled'
             "#,
         );
 
@@ -162,6 +171,114 @@ impl FriWitnessGeneratorDal<'_, '_> {
             .collect()
     }
 
+    /// Cancels all unfinished witness generator jobs for the specified batch, e.g. because the batch was reverted.
+    /// Cancelled jobs are never picked or re-queued; jobs already being processed are aborted by witness generators.
+    /// Returns the number of cancelled jobs.
+    pub async fn cancel_witness_jobs_for_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<u64> {
+        let mut cancelled_count = 0;
+        for round in AggregationRound::ALL_ROUNDS {
+            let table_name = Self::input_table_name_for(round);
+            let query = format!(
+                r#"
+                UPDATE {table_name}
+                SET
+                    status = 'cancelled',
+                    updated_at = NOW()
+                WHERE
+                    l1_batch_number = {}
+                    AND status NOT IN ('successful', 'skipped', 'cancelled')
+                "#,
+                i64::from(l1_batch_number.0)
+            );
+            cancelled_count += sqlx::query(&query)
+                .execute(self.storage.conn())
+                .await?
+                .rows_affected();
+        }
+        Ok(cancelled_count)
+    }
+
+    /// Marks all unfinished witness generator jobs for the specified batch as skipped, e.g. because proof generation
+    /// for the batch was skipped on the core side. Returns the number of skipped jobs.
+    pub async fn skip_witness_jobs_for_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<u64> {
+        let mut skipped_count = 0;
+        for round in AggregationRound::ALL_ROUNDS {
+            let table_name = Self::input_table_name_for(round);
+            let query = format!(
+                r#"
+                UPDATE {table_name}
+                SET
+                    status = 'skipped',
+                    updated_at = NOW()
+                WHERE
+                    l1_batch_number = {}
+                    AND status NOT IN ('successful', 'skipped', 'cancelled')
+                "#,
+                i64::from(l1_batch_number.0)
+            );
+            skipped_count += sqlx::query(&query)
+                .execute(self.storage.conn())
+                .await?
+                .rows_affected();
+        }
+        Ok(skipped_count)
+    }
+
+    /// Checks whether the specified job was cancelled via [`Self::cancel_witness_jobs_for_batch()`].
+    pub async fn is_witness_job_cancelled(
+        &mut self,
+        job_id: u32,
+        aggregation_round: AggregationRound,
+    ) -> sqlx::Result<bool> {
+        let table_name = Self::input_table_name_for(aggregation_round);
+        let job_id_column = Self::job_id_table_name_for(aggregation_round);
+        let query = format!(
+            r#"
+            SELECT
+                status
+            FROM
+                {table_name}
+            WHERE
+                {job_id_column} = {job_id}
+            "#,
+        );
+        let status = sqlx::query(&query)
+            .fetch_optional(self.storage.conn())
+            .await?
+            .map(|row| row.get::<String, &str>("status"));
+        Ok(status.as_deref() == Some(FriWitnessJobStatus::Cancelled.as_ref()))
+    }
+
+    /// Resets the processing start time of an in-progress job. Used for jobs picked ahead of processing
+    /// (e.g., prefetched ones), so that the processing timeout is counted from the moment processing starts.
+    pub async fn refresh_processing_started_at(
+        &mut self,
+        job_id: u32,
+        aggregation_round: AggregationRound,
+    ) -> sqlx::Result<()> {
+        let table_name = Self::input_table_name_for(aggregation_round);
+        let job_id_column = Self::job_id_table_name_for(aggregation_round);
+        let query = format!(
+            r#"
+            UPDATE {table_name}
+            SET
+                processing_started_at = NOW(),
+                updated_at = NOW()
+            WHERE
+                {job_id_column} = {job_id}
+                AND status = 'in_progress'
+            "#,
+        );
+        sqlx::query(&query).execute(self.storage.conn()).await?;
+        Ok(())
+    }
+
     fn input_table_name_for(aggregation_round: AggregationRound) -> &'static str {
         match aggregation_round {
             AggregationRound::BasicCircuits => "witness_inputs_fri",
@@ -210,10 +327,11 @@ impl FriWitnessGeneratorDal<'_, '_> {
             AggregationRound::NodeAggregation,
         )
         .await?;
-        self.delete_witness_generator_data(AggregationRound::RecursionTip)
+        self.delete_witness_generator_data_for_batch(block_number, AggregationRound::RecursionTip)
             .await?;
         self.delete_witness_generator_data_for_batch(block_number, AggregationRound::Scheduler)
-            .await
+            .await?;
+        self.delete_job_stats_for_batch(block_number).await
     }
 
     pub async fn delete_witness_generator_data(
@@ -244,7 +362,108 @@ impl FriWitnessGeneratorDal<'_, '_> {
         self.delete_witness_generator_data(AggregationRound::RecursionTip)
             .await?;
         self.delete_witness_generator_data(AggregationRound::Scheduler)
-            .await
+            .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM witness_generator_job_stats
+            "#
+        )
+        .execute(self.storage.conn())
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn save_job_stats(
+        &mut self,
+        job_id: u32,
+        aggregation_round: AggregationRound,
+        l1_batch_number: L1BatchNumber,
+        circuit_id: Option<u8>,
+        time_taken: Duration,
+        peak_rss_bytes: Option<u64>,
+    ) -> sqlx::Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+            witness_generator_job_stats (
+                job_id,
+                aggregation_round,
+                l1_batch_number,
+                circuit_id,
+                time_taken,
+                peak_rss_bytes,
+                created_at
+            )
+            VALUES
+            ($1, $2, $3, $4, $5, $6, NOW())
+            "#,
+            i64::from(job_id),
+            aggregation_round as i16,
+            i64::from(l1_batch_number.0),
+            circuit_id.map(i16::from),
+            duration_to_naive_time(time_taken),
+            peak_rss_bytes.map(|bytes| bytes as i64)
+        )
+        .execute(self.storage.conn())
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_job_stats_for_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<Vec<WitnessGeneratorJobStats>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                job_id,
+                aggregation_round,
+                circuit_id,
+                time_taken,
+                peak_rss_bytes,
+                created_at
+            FROM
+                witness_generator_job_stats
+            WHERE
+                l1_batch_number = $1
+            ORDER BY
+                aggregation_round,
+                circuit_id,
+                id
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .fetch_all(self.storage.conn())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| WitnessGeneratorJobStats {
+                job_id: row.job_id as u32,
+                l1_batch_number,
+                aggregation_round: AggregationRound::from(row.aggregation_round as u8),
+                circuit_id: row.circuit_id.map(|id| id as u8),
+                time_taken: row.time_taken,
+                peak_rss_bytes: row.peak_rss_bytes.map(|bytes| bytes as u64),
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+
+    async fn delete_job_stats_for_batch(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> sqlx::Result<sqlx::postgres::PgQueryResult> {
+        sqlx::query!(
+            r#"
+            DELETE FROM witness_generator_job_stats
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(l1_batch_number.0)
+        )
+        .execute(self.storage.conn())
+        .await
     }
 
     pub async fn requeue_stuck_leaf_aggregation_jobs_for_batch(
@@ -252,9 +471,23 @@ impl FriWitnessGeneratorDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
-        self.requeue_stuck_jobs_for_batch_in_aggregation_round(
+        self.requeue_stuck_leaf_aggregation_jobs_matching(
+            &StuckJobsFilter::for_batch(block_number),
+            max_attempts,
+        )
+        .await
+    }
+
+    /// Requeues all in-progress or failed leaf aggregation jobs matching the filter that have at least
+    /// `max_attempts` attempts.
+    pub async fn requeue_stuck_leaf_aggregation_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        self.requeue_stuck_jobs_in_aggregation_round(
             AggregationRound::LeafAggregation,
-            block_number,
+            filter,
             max_attempts,
         )
         .await
@@ -265,22 +498,37 @@ impl FriWitnessGeneratorDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
-        self.requeue_stuck_jobs_for_batch_in_aggregation_round(
+        self.requeue_stuck_node_aggregation_jobs_matching(
+            &StuckJobsFilter::for_batch(block_number),
+            max_attempts,
+        )
+        .await
+    }
+
+    /// Requeues all in-progress or failed node aggregation jobs matching the filter that have at least
+    /// `max_attempts` attempts.
+    pub async fn requeue_stuck_node_aggregation_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        self.requeue_stuck_jobs_in_aggregation_round(
             AggregationRound::NodeAggregation,
-            block_number,
+            filter,
             max_attempts,
         )
         .await
     }
 
-    async fn requeue_stuck_jobs_for_batch_in_aggregation_round(
+    async fn requeue_stuck_jobs_in_aggregation_round(
         &mut self,
         aggregation_round: AggregationRound,
-        block_number: L1BatchNumber,
+        filter: &StuckJobsFilter,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
         let table_name = Self::input_table_name_for(aggregation_round);
         let job_id_table_name = Self::job_id_table_name_for(aggregation_round);
+        let params = StuckJobsFilterParams::from(filter);
         let query = format!(
             r#"
             UPDATE {}
@@ -289,8 +537,10 @@ impl FriWitnessGeneratorDal<'_, '_> {
                 updated_at = NOW(),
                 processing_started_at = NOW()
             WHERE
-                l1_batch_number = {}
-                AND attempts >= {}
+                ($1::BIGINT IS NULL OR l1_batch_number >= $1)
+                AND ($2::BIGINT IS NULL OR l1_batch_number <= $2)
+                AND ($3::INT IS NULL OR (protocol_version = $3 AND protocol_version_patch = $4))
+                AND attempts >= $5
                 AND (status = 'in_progress' OR status = 'failed')
             RETURNING
                 {},
@@ -300,12 +550,14 @@ impl FriWitnessGeneratorDal<'_, '_> {
                 error,
                 picked_by
             "#,
-            table_name,
-            i64::from(block_number.0),
-            max_attempts,
-            job_id_table_name
+            table_name, job_id_table_name
         );
         sqlx::query(&query)
+            .bind(params.min_l1_batch_number)
+            .bind(params.max_l1_batch_number)
+            .bind(params.protocol_version)
+            .bind(params.protocol_version_patch)
+            .bind(i64::from(max_attempts))
             .fetch_all(self.storage.conn())
             .await
             .unwrap()
@@ -354,7 +606,8 @@ impl FriWitnessGeneratorDal<'_, '_> {
         .await?
         .into_iter()
         .map(|row| ProofGenerationTime {
-            l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+            l1_batch_number: L1BatchNumber::try_from(row.l1_batch_number)
+                .expect("invalid L1 batch number"),
             time_taken: naive_time_from_pg_interval(
                 row.time_taken.expect("time_taken must be present"),
             ),
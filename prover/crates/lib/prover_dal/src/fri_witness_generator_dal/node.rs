@@ -57,6 +57,9 @@ impl FriNodeWitnessGeneratorDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<NodeAggregationJobMetadata> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         let row = sqlx::query!(
             r#"
             UPDATE node_aggregation_witness_jobs_fri
@@ -103,7 +106,8 @@ impl FriNodeWitnessGeneratorDal<'_, '_> {
             _ => AggregationRound::NodeAggregation,
         };
 
-        let block_number = L1BatchNumber(row.l1_batch_number as u32);
+        let block_number =
+            L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number");
         let prover_job_ids = self
             .storage
             .fri_prover_jobs_dal()
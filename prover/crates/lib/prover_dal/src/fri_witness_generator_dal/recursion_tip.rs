@@ -3,7 +3,9 @@ use std::{str::FromStr, time::Duration};
 use zksync_basic_types::{
     basic_fri_types::AggregationRound,
     protocol_version::ProtocolSemanticVersion,
-    prover_dal::{RecursionTipWitnessGeneratorJobInfo, StuckJobs, WitnessJobStatus},
+    prover_dal::{
+        RecursionTipWitnessGeneratorJobInfo, StuckJobs, StuckJobsFilter, WitnessJobStatus,
+    },
     L1BatchNumber,
 };
 use zksync_db_connection::{
@@ -11,7 +13,7 @@ use zksync_db_connection::{
     utils::{duration_to_naive_time, pg_interval_from_duration},
 };
 
-use crate::Prover;
+use crate::{Prover, ProverDal, StuckJobsFilterParams};
 
 #[derive(Debug)]
 pub struct FriRecursionTipWitnessGeneratorDal<'a, 'c> {
@@ -54,7 +56,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         .await
         .unwrap()
         .into_iter()
-        .map(|row| (row.l1_batch_number as u64))
+        .map(|row| u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"))
         .collect()
     }
 
@@ -97,7 +99,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
@@ -112,6 +114,9 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<(L1BatchNumber, i32)> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE recursion_tip_witness_jobs_fri
@@ -152,7 +157,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .map(|row| {
             (
-                L1BatchNumber(row.l1_batch_number as u32),
+                L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
                 row.number_of_final_node_jobs,
             )
         })
@@ -174,7 +179,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
                 l1_batch_number = $2
             "#,
             duration_to_naive_time(time_taken),
-            l1_batch_number.0 as i64
+            i64::from(l1_batch_number.0)
         )
         .execute(self.storage.conn())
         .await
@@ -219,6 +224,20 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
+        self.requeue_stuck_recursion_tip_jobs_matching(
+            &StuckJobsFilter::for_batch(block_number),
+            max_attempts,
+        )
+        .await
+    }
+
+    /// Requeues all in-progress or failed jobs matching the filter that have at least `max_attempts` attempts.
+    pub async fn requeue_stuck_recursion_tip_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        let params = StuckJobsFilterParams::from(filter);
         sqlx::query!(
             r#"
             UPDATE recursion_tip_witness_jobs_fri
@@ -228,8 +247,22 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
                 processing_started_at = NOW(),
                 priority = priority + 1
             WHERE
-                l1_batch_number = $1
-                AND attempts >= $2
+                (
+                    $1::BIGINT IS NULL
+                    OR l1_batch_number >= $1
+                )
+                AND (
+                    $2::BIGINT IS NULL
+                    OR l1_batch_number <= $2
+                )
+                AND (
+                    $3::INT IS NULL
+                    OR (
+                        protocol_version = $3
+                        AND protocol_version_patch = $4
+                    )
+                )
+                AND attempts >= $5
                 AND (
                     status = 'in_progress'
                     OR status = 'failed'
@@ -241,7 +274,10 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
             error,
             picked_by
             "#,
-            i64::from(block_number.0),
+            params.min_l1_batch_number,
+            params.max_l1_batch_number,
+            params.protocol_version,
+            params.protocol_version_patch,
             max_attempts as i64
         )
         .fetch_all(self.storage.conn())
@@ -249,7 +285,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
@@ -284,7 +320,7 @@ impl FriRecursionTipWitnessGeneratorDal<'_, '_> {
             SET
             updated_at = NOW()
             "#,
-            block_number.0 as i64,
+            i64::from(block_number.0),
             closed_form_inputs_and_urls.len() as i32,
             protocol_version.minor as i32,
             protocol_version.patch.0 as i32,
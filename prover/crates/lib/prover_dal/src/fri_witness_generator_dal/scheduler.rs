@@ -3,7 +3,7 @@ use std::{str::FromStr, time::Duration};
 use zksync_basic_types::{
     basic_fri_types::AggregationRound,
     protocol_version::ProtocolSemanticVersion,
-    prover_dal::{SchedulerWitnessGeneratorJobInfo, StuckJobs, WitnessJobStatus},
+    prover_dal::{SchedulerWitnessGeneratorJobInfo, StuckJobs, StuckJobsFilter, WitnessJobStatus},
     L1BatchNumber,
 };
 use zksync_db_connection::{
@@ -11,7 +11,7 @@ use zksync_db_connection::{
     utils::{duration_to_naive_time, pg_interval_from_duration},
 };
 
-use crate::Prover;
+use crate::{Prover, ProverDal, StuckJobsFilterParams};
 
 #[derive(Debug)]
 pub struct FriSchedulerWitnessGeneratorDal<'a, 'c> {
@@ -48,7 +48,7 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         .await
         .unwrap()
         .into_iter()
-        .map(|row| (row.l1_batch_number as u64))
+        .map(|row| u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"))
         .collect()
     }
 
@@ -109,7 +109,7 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
@@ -124,6 +124,9 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         protocol_version: ProtocolSemanticVersion,
         picked_by: &str,
     ) -> Option<L1BatchNumber> {
+        if self.storage.proving_pauses_dal().is_proving_paused().await {
+            return None;
+        }
         sqlx::query!(
             r#"
             UPDATE scheduler_witness_jobs_fri
@@ -161,7 +164,7 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         .fetch_optional(self.storage.conn())
         .await
         .unwrap()
-        .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+        .map(|row| L1BatchNumber::try_from(row.l1_batch_number).expect("invalid L1 batch number"))
     }
 
     pub async fn mark_scheduler_job_as_successful(
@@ -225,6 +228,20 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         block_number: L1BatchNumber,
         max_attempts: u32,
     ) -> Vec<StuckJobs> {
+        self.requeue_stuck_scheduler_jobs_matching(
+            &StuckJobsFilter::for_batch(block_number),
+            max_attempts,
+        )
+        .await
+    }
+
+    /// Requeues all in-progress or failed jobs matching the filter that have at least `max_attempts` attempts.
+    pub async fn requeue_stuck_scheduler_jobs_matching(
+        &mut self,
+        filter: &StuckJobsFilter,
+        max_attempts: u32,
+    ) -> Vec<StuckJobs> {
+        let params = StuckJobsFilterParams::from(filter);
         sqlx::query!(
             r#"
             UPDATE scheduler_witness_jobs_fri
@@ -234,8 +251,22 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
                 processing_started_at = NOW(),
                 priority = priority + 1
             WHERE
-                l1_batch_number = $1
-                AND attempts >= $2
+                (
+                    $1::BIGINT IS NULL
+                    OR l1_batch_number >= $1
+                )
+                AND (
+                    $2::BIGINT IS NULL
+                    OR l1_batch_number <= $2
+                )
+                AND (
+                    $3::INT IS NULL
+                    OR (
+                        protocol_version = $3
+                        AND protocol_version_patch = $4
+                    )
+                )
+                AND attempts >= $5
                 AND (
                     status = 'in_progress'
                     OR status = 'failed'
@@ -247,7 +278,10 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
             error,
             picked_by
             "#,
-            i64::from(block_number.0),
+            params.min_l1_batch_number,
+            params.max_l1_batch_number,
+            params.protocol_version,
+            params.protocol_version_patch,
             max_attempts as i64
         )
         .fetch_all(self.storage.conn())
@@ -255,7 +289,7 @@ impl FriSchedulerWitnessGeneratorDal<'_, '_> {
         .unwrap()
         .into_iter()
         .map(|row| StuckJobs {
-            id: row.l1_batch_number as u64,
+            id: u64::try_from(row.l1_batch_number).expect("invalid L1 batch number"),
             status: row.status,
             attempts: row.attempts as u64,
             circuit_id: None,
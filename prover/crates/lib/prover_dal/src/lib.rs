@@ -1,7 +1,9 @@
+use zksync_basic_types::prover_dal::StuckJobsFilter;
 use zksync_db_connection::connection::DbMarker;
 pub use zksync_db_connection::{
     connection::Connection,
-    connection_pool::ConnectionPool,
+    connection_pool::{ConnectionPool, SeededTestTemplate},
+    seeded_test_template,
     utils::{duration_to_naive_time, pg_interval_from_duration},
 };
 
@@ -16,6 +18,7 @@ use crate::{
         node::FriNodeWitnessGeneratorDal, recursion_tip::FriRecursionTipWitnessGeneratorDal,
         scheduler::FriSchedulerWitnessGeneratorDal, FriWitnessGeneratorDal,
     },
+    proving_pauses_dal::ProvingPausesDal,
 };
 
 pub mod cli_test_dal;
@@ -24,6 +27,7 @@ pub mod fri_proof_compressor_dal;
 pub mod fri_protocol_versions_dal;
 pub mod fri_prover_dal;
 pub mod fri_witness_generator_dal;
+pub mod proving_pauses_dal;
 
 // This module is private and serves as a way to seal the trait.
 mod private {
@@ -59,6 +63,8 @@ where
     fn fri_protocol_versions_dal(&mut self) -> FriProtocolVersionsDal<'_, 'a>;
 
     fn fri_proof_compressor_dal(&mut self) -> FriProofCompressorDal<'_, 'a>;
+
+    fn proving_pauses_dal(&mut self) -> ProvingPausesDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -114,4 +120,31 @@ impl<'a> ProverDal<'a> for Connection<'a, Prover> {
     fn fri_proof_compressor_dal(&mut self) -> FriProofCompressorDal<'_, 'a> {
         FriProofCompressorDal { storage: self }
     }
+
+    fn proving_pauses_dal(&mut self) -> ProvingPausesDal<'_, 'a> {
+        ProvingPausesDal { storage: self }
+    }
+}
+
+/// Query parameters corresponding to a [`StuckJobsFilter`]; unspecified conditions are represented as `NULL`s.
+#[derive(Debug)]
+pub(crate) struct StuckJobsFilterParams {
+    pub min_l1_batch_number: Option<i64>,
+    pub max_l1_batch_number: Option<i64>,
+    pub protocol_version: Option<i32>,
+    pub protocol_version_patch: Option<i32>,
+}
+
+impl From<&StuckJobsFilter> for StuckJobsFilterParams {
+    fn from(filter: &StuckJobsFilter) -> Self {
+        let l1_batch_range = filter.l1_batch_range.as_ref();
+        Self {
+            min_l1_batch_number: l1_batch_range.map(|range| i64::from(range.start().0)),
+            max_l1_batch_number: l1_batch_range.map(|range| i64::from(range.end().0)),
+            protocol_version: filter.protocol_version.map(|version| version.minor as i32),
+            protocol_version_patch: filter
+                .protocol_version
+                .map(|version| version.patch.0 as i32),
+        }
+    }
 }
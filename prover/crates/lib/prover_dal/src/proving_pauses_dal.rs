@@ -0,0 +1,200 @@
+use zksync_basic_types::prover_dal::ProvingPause;
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+
+use crate::Prover;
+
+/// DAL managing the proving pause.
+///
+/// Proving jobs are not tagged with a chain, so the pause is global: while it is active, no jobs are dispensed
+/// for any aggregation round (witness generation, proving and compression) from the prover database.
+#[derive(Debug)]
+pub struct ProvingPausesDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Prover>,
+}
+
+impl ProvingPausesDal<'_, '_> {
+    /// Pauses dispensing of proving jobs. Returns `false` if proving is already paused; in this case,
+    /// the pause reason is updated.
+    pub async fn pause_proving(&mut self, reason: Option<&str>) -> DalResult<bool> {
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO
+            proving_pause (fake_key, reason, paused_at)
+            VALUES
+            (TRUE, $1, NOW())
+            ON CONFLICT (fake_key) DO
+            UPDATE
+            SET
+            reason = excluded.reason
+            RETURNING
+            (xmax = 0) AS "inserted!"
+            "#,
+            reason
+        )
+        .instrument("pause_proving")
+        .fetch_one(self.storage)
+        .await?;
+        Ok(row.inserted)
+    }
+
+    /// Resumes dispensing of proving jobs. Returns `false` if proving wasn't paused.
+    pub async fn resume_proving(&mut self) -> DalResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM proving_pause
+            "#
+        )
+        .instrument("resume_proving")
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns the active proving pause, or `None` if proving is not paused.
+    pub async fn get_pause(&mut self) -> DalResult<Option<ProvingPause>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                reason,
+                paused_at
+            FROM
+                proving_pause
+            "#
+        )
+        .instrument("get_pause")
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| ProvingPause {
+            reason: row.reason,
+            paused_at: row.paused_at,
+        }))
+    }
+
+    /// Checks whether dispensing of proving jobs is paused.
+    pub async fn is_proving_paused(&mut self) -> bool {
+        sqlx::query!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        proving_pause
+                ) AS "paused!"
+            "#
+        )
+        .fetch_one(self.storage.conn())
+        .await
+        .unwrap()
+        .paused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_basic_types::{
+        basic_fri_types::AggregationRound,
+        protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
+        L1BatchNumber,
+    };
+    use zksync_db_connection::connection_pool::ConnectionPool;
+
+    use super::*;
+    use crate::ProverDal;
+
+    #[tokio::test]
+    async fn pausing_and_resuming_proving() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        assert!(!conn.proving_pauses_dal().is_proving_paused().await);
+        assert!(conn
+            .proving_pauses_dal()
+            .get_pause()
+            .await
+            .unwrap()
+            .is_none());
+
+        let inserted = conn
+            .proving_pauses_dal()
+            .pause_proving(Some("upgrade"))
+            .await
+            .unwrap();
+        assert!(inserted);
+        let inserted = conn
+            .proving_pauses_dal()
+            .pause_proving(Some("incident"))
+            .await
+            .unwrap();
+        assert!(!inserted);
+        assert!(conn.proving_pauses_dal().is_proving_paused().await);
+
+        let pause = conn.proving_pauses_dal().get_pause().await.unwrap();
+        let pause = pause.expect("proving is not paused");
+        assert_eq!(pause.reason.as_deref(), Some("incident"));
+
+        let resumed = conn.proving_pauses_dal().resume_proving().await.unwrap();
+        assert!(resumed);
+        let resumed = conn.proving_pauses_dal().resume_proving().await.unwrap();
+        assert!(!resumed);
+        assert!(!conn.proving_pauses_dal().is_proving_paused().await);
+        assert!(conn
+            .proving_pauses_dal()
+            .get_pause()
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn pause_blocks_job_dispensing() {
+        let pool = ConnectionPool::<Prover>::prover_test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let protocol_version = ProtocolSemanticVersion::default();
+        conn.fri_protocol_versions_dal()
+            .save_prover_protocol_version(protocol_version, L1VerifierConfig::default())
+            .await
+            .unwrap();
+        conn.fri_prover_jobs_dal()
+            .insert_prover_jobs(
+                L1BatchNumber(1),
+                vec![(1, "circuit1".to_owned())],
+                AggregationRound::BasicCircuits,
+                0,
+                protocol_version,
+            )
+            .await;
+        conn.fri_proof_compressor_dal()
+            .insert_proof_compression_job(L1BatchNumber(1), "proof", protocol_version)
+            .await;
+
+        conn.proving_pauses_dal()
+            .pause_proving(Some("upgrade"))
+            .await
+            .unwrap();
+        let job = conn
+            .fri_prover_jobs_dal()
+            .get_next_job(protocol_version, "test")
+            .await;
+        assert!(job.is_none(), "{job:?}");
+        let job = conn
+            .fri_proof_compressor_dal()
+            .get_next_proof_compression_job("test", protocol_version)
+            .await;
+        assert!(job.is_none(), "{job:?}");
+
+        conn.proving_pauses_dal().resume_proving().await.unwrap();
+        let job = conn
+            .fri_prover_jobs_dal()
+            .get_next_job(protocol_version, "test")
+            .await
+            .expect("no prover job after resuming");
+        assert_eq!(job.block_number, L1BatchNumber(1));
+        let job = conn
+            .fri_proof_compressor_dal()
+            .get_next_proof_compression_job("test", protocol_version)
+            .await;
+        assert_eq!(job, Some(L1BatchNumber(1)));
+    }
+}
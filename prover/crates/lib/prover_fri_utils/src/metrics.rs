@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics};
+use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Histogram, LabeledFamily, Metrics};
 use zksync_types::basic_fri_types::AggregationRound;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet)]
@@ -35,3 +35,31 @@ pub(crate) struct ProverFriUtilsMetrics {
 #[vise::register]
 pub(crate) static PROVER_FRI_UTILS_METRICS: vise::Global<ProverFriUtilsMetrics> =
     vise::Global::new();
+
+/// Buckets for witness vector sizes: 1 MiB to 64 GiB.
+const WITNESS_VECTOR_SIZE_BUCKETS: Buckets =
+    Buckets::exponential(1_048_576.0..=68_719_476_736.0, 4.0);
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "prover_fri_witness_vector_transfer")]
+pub(crate) struct WitnessVectorTransferMetrics {
+    /// Time spent framing and compressing a witness vector before sending it.
+    #[metrics(buckets = Buckets::LATENCIES, labels = ["codec"])]
+    pub encode_time: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Time spent checking and decompressing a received witness vector.
+    #[metrics(buckets = Buckets::LATENCIES, labels = ["codec"])]
+    pub decode_time: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Time spent sending a witness vector to a prover.
+    #[metrics(buckets = Buckets::LATENCIES, labels = ["codec"])]
+    pub send_time: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Size of a serialized witness vector before compression.
+    #[metrics(buckets = WITNESS_VECTOR_SIZE_BUCKETS, labels = ["codec"])]
+    pub serialized_size_bytes: LabeledFamily<&'static str, Histogram<usize>>,
+    /// Size of a witness vector transferred over the network.
+    #[metrics(buckets = WITNESS_VECTOR_SIZE_BUCKETS, labels = ["codec"])]
+    pub transferred_size_bytes: LabeledFamily<&'static str, Histogram<usize>>,
+}
+
+#[vise::register]
+pub(crate) static WITNESS_VECTOR_TRANSFER_METRICS: vise::Global<WitnessVectorTransferMetrics> =
+    vise::Global::new();
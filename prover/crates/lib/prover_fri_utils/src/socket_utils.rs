@@ -4,9 +4,229 @@ use std::{
     time::{Duration, Instant},
 };
 
+use anyhow::Context as _;
+use zksync_config::configs::fri_witness_vector_generator::WitnessVectorTransferFormat;
+
+use crate::metrics::WITNESS_VECTOR_TRANSFER_METRICS;
+
+/// Magic prefix of framed witness vector transfers. Raw transfers start with the little-endian `u64` length
+/// of the serialized witness vector; interpreted this way, the magic is orders of magnitude larger than any possible
+/// length, so framed transfers cannot be confused with raw ones.
+const FRAME_MAGIC: [u8; 8] = *b"zkWVfrm1";
+const FRAME_VERSION: u8 = 1;
+/// Magic (8 bytes), version (1), codec (1), reserved (2), uncompressed length (8), payload length (8),
+/// CRC32 checksum of the payload (4).
+const FRAME_HEADER_LEN: usize = 32;
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+/// Upper bound on the uncompressed witness vector length declared in a frame header. Witness vectors are
+/// well below this size; the bound prevents a malformed header from triggering a huge allocation on decompression.
+const MAX_UNCOMPRESSED_LEN: u64 = 16 << 30; // 16 GiB
+
+/// Codec of a witness vector transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Codec {
+    /// Unframed `bincode`-serialized witness vector.
+    Raw,
+    /// Framed uncompressed witness vector.
+    Framed,
+    /// Framed witness vector compressed with zstd.
+    Zstd,
+}
+
+impl Codec {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Raw => "raw",
+            Self::Framed => "framed",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Raw => unreachable!("raw transfers are not framed"),
+            Self::Framed => 0,
+            Self::Zstd => 1,
+        }
+    }
+
+    fn from_byte(byte: u8) -> anyhow::Result<Self> {
+        match byte {
+            0 => Ok(Self::Framed),
+            1 => Ok(Self::Zstd),
+            _ => anyhow::bail!("unsupported witness vector codec: {byte}"),
+        }
+    }
+}
+
+impl From<WitnessVectorTransferFormat> for Codec {
+    fn from(format: WitnessVectorTransferFormat) -> Self {
+        match format {
+            WitnessVectorTransferFormat::Raw => Self::Raw,
+            WitnessVectorTransferFormat::Framed => Self::Framed,
+            WitnessVectorTransferFormat::FramedZstd => Self::Zstd,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct FrameHeader {
+    codec: Codec,
+    uncompressed_len: u64,
+    payload_len: u64,
+    checksum: u32,
+}
+
+impl FrameHeader {
+    fn encode(&self) -> [u8; FRAME_HEADER_LEN] {
+        let mut bytes = [0_u8; FRAME_HEADER_LEN];
+        bytes[..8].copy_from_slice(&FRAME_MAGIC);
+        bytes[8] = FRAME_VERSION;
+        bytes[9] = self.codec.to_byte();
+        // bytes[10..12] are reserved
+        bytes[12..20].copy_from_slice(&self.uncompressed_len.to_le_bytes());
+        bytes[20..28].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[28..32].copy_from_slice(&self.checksum.to_le_bytes());
+        bytes
+    }
+
+    /// Returns `Ok(None)` if the data is not framed.
+    fn decode(bytes: &[u8]) -> anyhow::Result<Option<Self>> {
+        if !bytes.starts_with(&FRAME_MAGIC) {
+            return Ok(None);
+        }
+        anyhow::ensure!(
+            bytes.len() >= FRAME_HEADER_LEN,
+            "witness vector frame is truncated: {} bytes",
+            bytes.len()
+        );
+        anyhow::ensure!(
+            bytes[8] == FRAME_VERSION,
+            "unsupported witness vector frame version: {}",
+            bytes[8]
+        );
+        let u64_at = |pos: usize| u64::from_le_bytes(bytes[pos..pos + 8].try_into().unwrap());
+        Ok(Some(Self {
+            codec: Codec::from_byte(bytes[9])?,
+            uncompressed_len: u64_at(12),
+            payload_len: u64_at(20),
+            checksum: u32::from_le_bytes(bytes[28..32].try_into().unwrap()),
+        }))
+    }
+}
+
+/// Witness vector encoded for sending to a prover.
+#[derive(Debug)]
+pub struct EncodedWitnessVector {
+    codec: Codec,
+    header: Option<[u8; FRAME_HEADER_LEN]>,
+    payload: Vec<u8>,
+}
+
+impl EncodedWitnessVector {
+    /// Encodes a `bincode`-serialized witness vector in the specified format.
+    pub fn new(serialized: Vec<u8>, format: WitnessVectorTransferFormat) -> anyhow::Result<Self> {
+        let codec = Codec::from(format);
+        let started_at = Instant::now();
+        let uncompressed_len = serialized.len();
+        let payload = match codec {
+            Codec::Raw | Codec::Framed => serialized,
+            Codec::Zstd => zstd::bulk::compress(&serialized, ZSTD_COMPRESSION_LEVEL)
+                .context("failed compressing witness vector")?,
+        };
+        let header = (codec != Codec::Raw).then(|| {
+            FrameHeader {
+                codec,
+                uncompressed_len: uncompressed_len as u64,
+                payload_len: payload.len() as u64,
+                checksum: crc32fast::hash(&payload),
+            }
+            .encode()
+        });
+
+        let label = codec.label();
+        WITNESS_VECTOR_TRANSFER_METRICS.encode_time[&label].observe(started_at.elapsed());
+        WITNESS_VECTOR_TRANSFER_METRICS.serialized_size_bytes[&label].observe(uncompressed_len);
+        Ok(Self {
+            codec,
+            header,
+            payload,
+        })
+    }
+
+    /// Returns the number of bytes sent over the network.
+    pub fn len(&self) -> usize {
+        self.header.map_or(0, |header| header.len()) + self.payload.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn reader(&self) -> impl Read + '_ {
+        let header: &[u8] = self.header.as_ref().map_or(&[], |header| header);
+        header.chain(self.payload.as_slice())
+    }
+}
+
+/// Decodes a witness vector received from a witness vector generator into its `bincode` serialization.
+/// The transfer format is detected automatically.
+pub fn decode_witness_vector(mut received: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    let started_at = Instant::now();
+    let Some(header) = FrameHeader::decode(&received)? else {
+        let label = Codec::Raw.label();
+        WITNESS_VECTOR_TRANSFER_METRICS.transferred_size_bytes[&label].observe(received.len());
+        return Ok(received);
+    };
+
+    let payload = &received[FRAME_HEADER_LEN..];
+    anyhow::ensure!(
+        payload.len() as u64 == header.payload_len,
+        "witness vector payload length mismatch: expected {} bytes, received {}",
+        header.payload_len,
+        payload.len()
+    );
+    let checksum = crc32fast::hash(payload);
+    anyhow::ensure!(
+        checksum == header.checksum,
+        "witness vector checksum mismatch: expected {:#010x}, got {checksum:#010x}",
+        header.checksum
+    );
+
+    let label = header.codec.label();
+    WITNESS_VECTOR_TRANSFER_METRICS.transferred_size_bytes[&label].observe(received.len());
+    let serialized = match header.codec {
+        Codec::Raw => unreachable!("raw transfers are not framed"),
+        Codec::Framed => {
+            received.drain(..FRAME_HEADER_LEN);
+            received
+        }
+        Codec::Zstd => {
+            anyhow::ensure!(
+                header.uncompressed_len <= MAX_UNCOMPRESSED_LEN,
+                "uncompressed witness vector length {} exceeds the limit of {MAX_UNCOMPRESSED_LEN} bytes",
+                header.uncompressed_len
+            );
+            let uncompressed_len = usize::try_from(header.uncompressed_len)
+                .context("uncompressed witness vector length overflow")?;
+            zstd::bulk::decompress(payload, uncompressed_len)
+                .context("failed decompressing witness vector")?
+        }
+    };
+    anyhow::ensure!(
+        serialized.len() as u64 == header.uncompressed_len,
+        "uncompressed witness vector length mismatch: expected {} bytes, got {}",
+        header.uncompressed_len,
+        serialized.len()
+    );
+    WITNESS_VECTOR_TRANSFER_METRICS.decode_time[&label].observe(started_at.elapsed());
+    WITNESS_VECTOR_TRANSFER_METRICS.serialized_size_bytes[&label].observe(serialized.len());
+    Ok(serialized)
+}
+
 pub fn send_assembly(
     job_id: u32,
-    mut serialized: &[u8],
+    encoded: &EncodedWitnessVector,
     socket_address: &SocketAddr,
 ) -> Result<(Duration, u64), String> {
     tracing::trace!(
@@ -22,9 +242,16 @@ pub fn send_assembly(
     for _ in 0..10 {
         match TcpStream::connect_timeout(socket_address, Duration::from_secs(6)) {
             Ok(mut stream) => {
-                return send(&mut serialized, &mut stream)
+                let result = send(&mut encoded.reader(), &mut stream)
                     .map(|result| (started_at.elapsed(), result))
                     .map_err(|err| format!("Could not send assembly to prover: {err:?}"));
+                if let Ok((elapsed, len)) = &result {
+                    let label = encoded.codec.label();
+                    WITNESS_VECTOR_TRANSFER_METRICS.send_time[&label].observe(*elapsed);
+                    WITNESS_VECTOR_TRANSFER_METRICS.transferred_size_bytes[&label]
+                        .observe(*len as usize);
+                }
+                return result;
             }
             Err(err) => {
                 error_messages.push(format!("{err:?}"));
@@ -60,3 +287,65 @@ fn send(read: &mut impl Read, tcp: &mut TcpStream) -> std::io::Result<u64> {
 fn can_be_retried(err: ErrorKind) -> bool {
     matches!(err, ErrorKind::TimedOut | ErrorKind::ConnectionRefused)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn received(encoded: &EncodedWitnessVector) -> Vec<u8> {
+        let mut received = vec![];
+        encoded.reader().read_to_end(&mut received).unwrap();
+        assert_eq!(received.len(), encoded.len());
+        received
+    }
+
+    #[test]
+    fn witness_vector_transfer_roundtrip() {
+        let serialized: Vec<u8> = (0..10_000_u32)
+            .flat_map(|i| (i % 7).to_le_bytes())
+            .collect();
+        for format in [
+            WitnessVectorTransferFormat::Raw,
+            WitnessVectorTransferFormat::Framed,
+            WitnessVectorTransferFormat::FramedZstd,
+        ] {
+            let encoded = EncodedWitnessVector::new(serialized.clone(), format).unwrap();
+            if format == WitnessVectorTransferFormat::FramedZstd {
+                assert!(encoded.len() < serialized.len());
+            }
+            let decoded = decode_witness_vector(received(&encoded)).unwrap();
+            assert_eq!(decoded, serialized, "{format:?}");
+        }
+    }
+
+    #[test]
+    fn detecting_corrupted_witness_vector() {
+        let serialized = vec![42_u8; 1_024];
+        let encoded =
+            EncodedWitnessVector::new(serialized, WitnessVectorTransferFormat::FramedZstd).unwrap();
+
+        let mut corrupted = received(&encoded);
+        *corrupted.last_mut().unwrap() ^= 1;
+        let err = decode_witness_vector(corrupted).unwrap_err().to_string();
+        assert!(err.contains("checksum mismatch"), "{err}");
+
+        let mut truncated = received(&encoded);
+        truncated.pop();
+        let err = decode_witness_vector(truncated).unwrap_err().to_string();
+        assert!(err.contains("length mismatch"), "{err}");
+    }
+
+    #[test]
+    fn rejecting_excessive_uncompressed_len() {
+        let serialized = vec![42_u8; 1_024];
+        let encoded =
+            EncodedWitnessVector::new(serialized, WitnessVectorTransferFormat::FramedZstd).unwrap();
+        let mut received = received(&encoded);
+        let mut header = FrameHeader::decode(&received).unwrap().unwrap();
+        header.uncompressed_len = u64::MAX;
+        received[..FRAME_HEADER_LEN].copy_from_slice(&header.encode());
+
+        let err = decode_witness_vector(received).unwrap_err().to_string();
+        assert!(err.contains("exceeds the limit"), "{err}");
+    }
+}
@@ -74,6 +74,30 @@ pub async fn drop_db_if_exists(db: &DatabaseConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Renames the database. The database must not have active connections.
+pub async fn rename_db(db: &DatabaseConfig, new_name: &str) -> anyhow::Result<()> {
+    let mut connection = PgConnection::connect(db.url.as_str()).await?;
+
+    let query = format!("ALTER DATABASE {} RENAME TO {new_name}", db.name);
+    sqlx::query(&query).execute(&mut connection).await?;
+
+    Ok(())
+}
+
+/// Returns the number of connections to the database established by other clients.
+pub async fn count_db_connections(db: &DatabaseConfig) -> anyhow::Result<i64> {
+    let mut connection = PgConnection::connect(db.url.as_str()).await?;
+
+    let (count,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM pg_stat_activity WHERE datname = $1 AND pid <> pg_backend_pid()",
+    )
+    .bind(&db.name)
+    .fetch_one(&mut connection)
+    .await?;
+
+    Ok(count)
+}
+
 pub async fn migrate_db(
     shell: &Shell,
     migrations_folder: PathBuf,
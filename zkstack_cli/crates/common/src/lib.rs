@@ -20,7 +20,7 @@ pub mod zks_provider;
 
 pub use prerequisites::{
     check_general_prerequisites, check_prerequisites, GCLOUD_PREREQUISITE, GPU_PREREQUISITES,
-    PROVER_CLI_PREREQUISITE,
+    POSTGRES_CLIENT_PREREQUISITES, PROVER_CLI_PREREQUISITE,
 };
 pub use prompt::{init_prompt_theme, Prompt, PromptConfirm, PromptSelect};
 pub use term::{error, logger, spinner};
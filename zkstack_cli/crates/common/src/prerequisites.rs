@@ -73,6 +73,24 @@ pub const GCLOUD_PREREQUISITE: [Prerequisite; 1] = [Prerequisite {
     custom_validator: None,
 }];
 
+pub const POSTGRES_CLIENT_PREREQUISITES: [Prerequisite; 3] = [
+    Prerequisite {
+        name: "pg_dump",
+        download_link: "https://www.postgresql.org/download/",
+        custom_validator: None,
+    },
+    Prerequisite {
+        name: "pg_restore",
+        download_link: "https://www.postgresql.org/download/",
+        custom_validator: None,
+    },
+    Prerequisite {
+        name: "pg_basebackup",
+        download_link: "https://www.postgresql.org/download/",
+        custom_validator: None,
+    },
+];
+
 pub const PROVER_CLI_PREREQUISITE: [Prerequisite; 1] = [Prerequisite {
     name: "prover_cli",
     download_link:
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+use crate::messages::{
+    MSG_BACKUP_ARCHIVE_HELP, MSG_BACKUP_DB_METHOD_HELP, MSG_BACKUP_OUTPUT_DIR_HELP,
+    MSG_BACKUP_UPLOAD_URL_HELP, MSG_RESTORE_FORCE_HELP,
+};
+
+/// Method used to back up the server Postgres database.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Display, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DbBackupMethod {
+    /// Logical dump of the server database made with `pg_dump`.
+    #[default]
+    Dump,
+    /// Physical backup of the entire Postgres cluster made with `pg_basebackup`.
+    Basebackup,
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct BackupArgs {
+    #[clap(long, help = MSG_BACKUP_OUTPUT_DIR_HELP)]
+    pub output_dir: Option<PathBuf>,
+    #[clap(long, value_enum, default_value_t, help = MSG_BACKUP_DB_METHOD_HELP)]
+    pub db_method: DbBackupMethod,
+    #[clap(long, help = MSG_BACKUP_UPLOAD_URL_HELP)]
+    pub upload_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct RestoreArgs {
+    #[clap(help = MSG_BACKUP_ARCHIVE_HELP)]
+    pub archive: String,
+    #[clap(long, help = MSG_RESTORE_FORCE_HELP)]
+    pub force: bool,
+}
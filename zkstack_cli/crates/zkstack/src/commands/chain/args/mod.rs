@@ -1,3 +1,4 @@
+pub mod backup;
 pub mod build_transactions;
 pub mod create;
 pub mod genesis;
@@ -0,0 +1,237 @@
+use std::{
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use xshell::{cmd, Shell};
+use zkstack_cli_common::{
+    check_prerequisites,
+    cmd::Cmd,
+    db::{count_db_connections, DatabaseConfig},
+    logger,
+    spinner::Spinner,
+    GCLOUD_PREREQUISITE, POSTGRES_CLIENT_PREREQUISITES,
+};
+use zkstack_cli_config::{
+    raw::RawConfig, ChainConfig, EcosystemConfig, GENERAL_FILE, SECRETS_FILE,
+};
+use zksync_basic_types::L2ChainId;
+
+use crate::{
+    commands::chain::args::backup::{BackupArgs, DbBackupMethod},
+    defaults::{MAIN_ROCKS_DB_PREFIX, ROCKS_DB_TREE},
+    messages::{
+        msg_backup_created, msg_backup_uploaded, msg_server_db_in_use,
+        MSG_BACKING_UP_CONFIGS_SPINNER, MSG_BACKING_UP_DATABASE_SPINNER,
+        MSG_BACKING_UP_TREE_SPINNER, MSG_BACKUP_UNSUPPORTED_UPLOAD_URL_ERR,
+        MSG_CHAIN_NOT_INITIALIZED, MSG_UPLOADING_BACKUP_SPINNER,
+    },
+};
+
+/// Version of the backup archive layout. Must be bumped on incompatible changes.
+pub(super) const BACKUP_FORMAT_VERSION: u32 = 1;
+pub(super) const MANIFEST_FILE: &str = "manifest.json";
+pub(super) const DB_DUMP_FILE: &str = "server_db.dump";
+pub(super) const DB_BASEBACKUP_DIR: &str = "server_db_basebackup";
+pub(super) const TREE_ARCHIVE: &str = "tree.tar.gz";
+pub(super) const CONFIGS_ARCHIVE: &str = "configs.tar.gz";
+
+/// Manifest stored in the root of the backup archive and used to verify its integrity on restore.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct BackupManifest {
+    pub format_version: u32,
+    pub chain_name: String,
+    pub chain_id: L2ChainId,
+    pub created_at: String,
+    pub db_method: DbBackupMethod,
+    pub files: Vec<BackupFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct BackupFile {
+    /// Path relative to the backup root.
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+impl BackupFile {
+    pub fn new(root: &Path, path: &Path) -> anyhow::Result<Self> {
+        let mut file = File::open(path).with_context(|| format!("failed opening {path:?}"))?;
+        let mut hasher = Sha256::new();
+        let size = io::copy(&mut file, &mut hasher)?;
+        let relative_path = path.strip_prefix(root)?.to_string_lossy().into_owned();
+        Ok(Self {
+            path: relative_path,
+            size,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+}
+
+/// Recursively lists files in `dir`, skipping the manifest.
+pub(super) fn list_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(list_files(&path)?);
+        } else if path.file_name().is_some_and(|name| name != MANIFEST_FILE) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Returns the Merkle tree RocksDB path used by the chain server with configs in `configs_dir`.
+pub(super) async fn merkle_tree_path(
+    shell: &Shell,
+    chain_config: &ChainConfig,
+    configs_dir: &Path,
+) -> anyhow::Result<PathBuf> {
+    let general = RawConfig::read(shell, configs_dir.join(GENERAL_FILE)).await?;
+    let path = general
+        .get_opt::<PathBuf>("db.merkle_tree.path")?
+        .unwrap_or_else(|| {
+            chain_config
+                .rocks_db_path
+                .join(MAIN_ROCKS_DB_PREFIX)
+                .join(ROCKS_DB_TREE)
+        });
+    // Relative paths are resolved against the directory the server is launched from.
+    Ok(chain_config.link_to_code.join(path))
+}
+
+pub(super) async fn server_database(
+    shell: &Shell,
+    configs_dir: &Path,
+) -> anyhow::Result<DatabaseConfig> {
+    let secrets = RawConfig::read(shell, configs_dir.join(SECRETS_FILE)).await?;
+    DatabaseConfig::from_url(&secrets.get("database.server_url")?)
+}
+
+/// Checks that no server components are connected to the server database. RocksDB cannot be safely copied
+/// or replaced while it's used, so backups and restores require the server to be stopped.
+pub(super) async fn ensure_server_stopped(server_db: &DatabaseConfig) -> anyhow::Result<()> {
+    let connection_count = count_db_connections(server_db).await?;
+    anyhow::ensure!(
+        connection_count == 0,
+        msg_server_db_in_use(&server_db.name, connection_count)
+    );
+    Ok(())
+}
+
+fn check_upload_url(url: &str) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        url.starts_with("gs://"),
+        MSG_BACKUP_UNSUPPORTED_UPLOAD_URL_ERR
+    );
+    Ok(())
+}
+
+pub async fn run(args: BackupArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    check_prerequisites(shell, &POSTGRES_CLIENT_PREREQUISITES, false);
+    if let Some(upload_url) = &args.upload_url {
+        check_upload_url(upload_url)?;
+        check_prerequisites(shell, &GCLOUD_PREREQUISITE, false);
+    }
+    let server_db = server_database(shell, &chain_config.configs).await?;
+    ensure_server_stopped(&server_db).await?;
+
+    let created_at = Utc::now();
+    let backup_name = format!(
+        "{}_backup_{}",
+        chain_config.name,
+        created_at.format("%Y%m%d%H%M%S")
+    );
+    let output_dir = match args.output_dir {
+        Some(dir) => dir,
+        None => shell.current_dir().join("backups"),
+    };
+    let backup_dir = shell.create_dir(output_dir.join(&backup_name))?;
+
+    let tree_path = merkle_tree_path(shell, &chain_config, &chain_config.configs).await?;
+    let spinner = Spinner::new(MSG_BACKING_UP_TREE_SPINNER);
+    let tree_archive = backup_dir.join(TREE_ARCHIVE);
+    Cmd::new(cmd!(shell, "tar -czf {tree_archive} -C {tree_path} .")).run()?;
+    spinner.finish();
+
+    let spinner = Spinner::new(MSG_BACKING_UP_DATABASE_SPINNER);
+    match args.db_method {
+        DbBackupMethod::Dump => {
+            let db_url = server_db.full_url().to_string();
+            let dump_path = backup_dir.join(DB_DUMP_FILE);
+            Cmd::new(cmd!(
+                shell,
+                "pg_dump --format=custom --no-owner --file={dump_path} --dbname={db_url}"
+            ))
+            .run()?;
+        }
+        DbBackupMethod::Basebackup => {
+            let cluster_url = server_db.url.to_string();
+            let basebackup_dir = backup_dir.join(DB_BASEBACKUP_DIR);
+            Cmd::new(cmd!(
+                shell,
+                "pg_basebackup --pgdata={basebackup_dir} --format=tar --gzip --checkpoint=fast --dbname={cluster_url}"
+            ))
+            .run()?;
+        }
+    }
+    spinner.finish();
+
+    let spinner = Spinner::new(MSG_BACKING_UP_CONFIGS_SPINNER);
+    let configs_archive = backup_dir.join(CONFIGS_ARCHIVE);
+    let configs_path = &chain_config.configs;
+    Cmd::new(cmd!(
+        shell,
+        "tar -czf {configs_archive} -C {configs_path} ."
+    ))
+    .run()?;
+    spinner.finish();
+
+    let files = list_files(&backup_dir)?
+        .iter()
+        .map(|path| BackupFile::new(&backup_dir, path))
+        .collect::<anyhow::Result<_>>()?;
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        chain_name: chain_config.name.clone(),
+        chain_id: chain_config.chain_id,
+        created_at: created_at.to_rfc3339(),
+        db_method: args.db_method,
+        files,
+    };
+    shell.write_file(
+        backup_dir.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+
+    // All components are already compressed, so the outer archive is not.
+    let archive_path = output_dir.join(format!("{backup_name}.tar"));
+    Cmd::new(cmd!(
+        shell,
+        "tar -cf {archive_path} -C {output_dir} {backup_name}"
+    ))
+    .run()?;
+    shell.remove_path(&backup_dir)?;
+
+    if let Some(upload_url) = args.upload_url {
+        let spinner = Spinner::new(MSG_UPLOADING_BACKUP_SPINNER);
+        Cmd::new(cmd!(shell, "gcloud storage cp {archive_path} {upload_url}")).run()?;
+        spinner.finish();
+        logger::info(msg_backup_uploaded(&upload_url));
+    }
+
+    logger::outro(msg_backup_created(&archive_path));
+    Ok(())
+}
@@ -20,14 +20,16 @@ use zkstack_cli_config::{
         gateway_preparation::{input::GatewayPreparationConfig, output::GatewayPreparationOutput},
         script_params::GATEWAY_PREPARATION,
     },
+    get_da_client_type,
     traits::{ReadConfig, SaveConfig, SaveConfigWithBasePath},
-    EcosystemConfig,
+    ChainConfig, EcosystemConfig,
 };
 use zkstack_cli_types::L1BatchCommitmentMode;
 use zksync_basic_types::{settlement::SettlementMode, Address, H256, U256, U64};
-use zksync_config::configs::gateway::GatewayChainConfig;
+use zksync_config::configs::gateway::{GatewayChainConfig, GatewayConfig};
 use zksync_system_constants::L2_BRIDGEHUB_ADDRESS;
 
+use super::init::get_l1_da_validator;
 use crate::{
     messages::MSG_CHAIN_NOT_INITIALIZED,
     utils::forge::{check_the_balance, fill_forge_private_key, WalletOwner},
@@ -88,6 +90,14 @@ pub async fn run(args: MigrateToGatewayArgs, shell: &Shell) -> anyhow::Result<()
 
     let genesis_config = chain_config.get_genesis_config().await?;
 
+    println!("Checking DA configuration compatibility with the Gateway...");
+    let gateway_da_validator_address = check_da_compatibility(
+        &chain_config,
+        &gateway_chain_config,
+        &gateway_gateway_config,
+    )
+    .await?;
+
     let preparation_config_path = GATEWAY_PREPARATION.input(&ecosystem_config.link_to_code);
     let preparation_config = GatewayPreparationConfig::new(
         &gateway_chain_config,
@@ -207,12 +217,6 @@ pub async fn run(args: MigrateToGatewayArgs, shell: &Shell) -> anyhow::Result<()
         L1BatchCommitmentMode::Rollup
     );
 
-    let gateway_da_validator_address = if is_rollup {
-        gateway_gateway_config.relayed_sl_da_validator
-    } else {
-        gateway_gateway_config.validium_da_validator
-    };
-
     println!("Setting DA validator pair...");
     let hash = call_script(
         shell,
@@ -360,6 +364,7 @@ pub async fn run(args: MigrateToGatewayArgs, shell: &Shell) -> anyhow::Result<()
 
     let mut general_config = chain_config.get_general_config().await?.patched();
     general_config.insert_yaml("eth.gas_adjuster.settlement_mode", SettlementMode::Gateway)?;
+    general_config.insert("eth.gas_adjuster.gateway_settlement_supported", true)?;
 
     if is_rollup {
         // For rollups, new type of commitment should be used, but not for validium.
@@ -377,6 +382,71 @@ pub async fn run(args: MigrateToGatewayArgs, shell: &Shell) -> anyhow::Result<()
     Ok(())
 }
 
+/// Checks that the chain's DA configuration is supported by the destination Gateway. Must be called
+/// before any migration transactions are sent, since an incompatible configuration only surfaces
+/// as commit failures after the switch.
+///
+/// Support is derived from the L1 / L2 DA validator pair configured for the chain: the L1 DA validator must have
+/// a counterpart deployed on the Gateway, which is returned.
+async fn check_da_compatibility(
+    chain_config: &ChainConfig,
+    gateway_chain_config: &ChainConfig,
+    gateway_config: &GatewayConfig,
+) -> anyhow::Result<Address> {
+    let chain_mode = chain_config.l1_batch_commit_data_generator_mode;
+    let gateway_mode = gateway_chain_config.l1_batch_commit_data_generator_mode;
+    let gateway_name = &gateway_chain_config.name;
+    // Validiums can settle on any Gateway, but rollup pubdata is only guaranteed to be available
+    // if the Gateway itself is a rollup.
+    let is_mode_supported = chain_mode == L1BatchCommitmentMode::Validium
+        || gateway_mode == L1BatchCommitmentMode::Rollup;
+    anyhow::ensure!(
+        is_mode_supported,
+        "{chain_mode} chains cannot settle on Gateway `{gateway_name}` running in {gateway_mode} mode: \
+         rollup pubdata must be published to a rollup settlement layer"
+    );
+
+    if chain_mode == L1BatchCommitmentMode::Rollup {
+        let general_config = chain_config.get_general_config().await?;
+        if let Some(da_client) = get_da_client_type(&general_config) {
+            anyhow::bail!(
+                "Rollup chains publish pubdata to the settlement layer, but the `{da_client}` DA client \
+                 is configured; remove `da_client` from the general config before migrating to Gateway"
+            );
+        }
+    }
+
+    let l1_contracts = chain_config.get_contracts_config()?.l1;
+    let l1_da_validator = get_l1_da_validator(chain_config).await?;
+    let l2_da_validator = chain_config.get_contracts_config()?.l2.da_validator_addr;
+    anyhow::ensure!(
+        l2_da_validator.is_some_and(|addr| !addr.is_zero()),
+        "L2 DA validator address is not set in the chain contracts config"
+    );
+
+    // Rollup pubdata is relayed to L1 by the Gateway; validium validators only check the DA commitment
+    // and have a single counterpart on the Gateway.
+    let validium_l1_da_validators = [
+        l1_contracts.no_da_validium_l1_validator_addr,
+        l1_contracts.avail_l1_da_validator_addr,
+    ];
+    let gateway_da_validator = if Some(l1_da_validator) == l1_contracts.rollup_l1_da_validator_addr
+    {
+        gateway_config.relayed_sl_da_validator
+    } else if validium_l1_da_validators.contains(&Some(l1_da_validator)) {
+        gateway_config.validium_da_validator
+    } else {
+        anyhow::bail!(
+            "L1 DA validator {l1_da_validator:?} configured for the chain has no counterpart on Gateway `{gateway_name}`"
+        );
+    };
+    anyhow::ensure!(
+        !gateway_da_validator.is_zero(),
+        "Gateway `{gateway_name}` has no counterpart deployed for L1 DA validator {l1_da_validator:?}"
+    );
+    Ok(gateway_da_validator)
+}
+
 async fn await_for_tx_to_complete(
     gateway_provider: &Provider<Http>,
     hash: H256,
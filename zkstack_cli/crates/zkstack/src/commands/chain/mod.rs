@@ -1,6 +1,9 @@
 use ::zkstack_cli_common::forge::ForgeScriptArgs;
-use args::build_transactions::BuildTransactionsArgs;
 pub(crate) use args::create::ChainCreateArgsFinal;
+use args::{
+    backup::{BackupArgs, RestoreArgs},
+    build_transactions::BuildTransactionsArgs,
+};
 use clap::{command, Subcommand};
 pub(crate) use create::create_chain_inner;
 use xshell::Shell;
@@ -12,6 +15,7 @@ use crate::commands::chain::{
 
 mod accept_chain_ownership;
 pub(crate) mod args;
+mod backup;
 mod build_transactions;
 pub(crate) mod common;
 #[cfg(feature = "gateway")]
@@ -29,6 +33,7 @@ mod migrate_from_gateway;
 #[cfg(feature = "gateway")]
 mod migrate_to_gateway;
 pub mod register_chain;
+mod restore;
 mod set_token_multiplier_setter;
 mod setup_legacy_bridge;
 mod utils;
@@ -88,6 +93,10 @@ pub enum ChainCommands {
     GatewayUpgrade(gateway_upgrade::GatewayUpgradeArgs),
     /// Enable EVM emulation on chain (Not supported yet)
     EnableEvmEmulator(ForgeScriptArgs),
+    /// Back up the server database, Merkle tree and chain configs into an archive
+    Backup(BackupArgs),
+    /// Restore the server database, Merkle tree and chain configs from a backup archive
+    Restore(RestoreArgs),
 }
 
 pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()> {
@@ -126,5 +135,7 @@ pub(crate) async fn run(shell: &Shell, args: ChainCommands) -> anyhow::Result<()
         #[cfg(feature = "gateway")]
         ChainCommands::GatewayUpgrade(args) => gateway_upgrade::run(args, shell).await,
         ChainCommands::EnableEvmEmulator(args) => enable_evm_emulator::run(args, shell).await,
+        ChainCommands::Backup(args) => backup::run(args, shell).await,
+        ChainCommands::Restore(args) => restore::run(args, shell).await,
     }
 }
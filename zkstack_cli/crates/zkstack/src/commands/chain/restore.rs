@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use xshell::{cmd, Shell};
+use zkstack_cli_common::{
+    check_prerequisites,
+    cmd::Cmd,
+    db::{drop_db_if_exists, init_db, rename_db, DatabaseConfig},
+    logger,
+    spinner::Spinner,
+    PromptConfirm, GCLOUD_PREREQUISITE, POSTGRES_CLIENT_PREREQUISITES,
+};
+use zkstack_cli_config::EcosystemConfig;
+
+use crate::{
+    commands::chain::{
+        args::backup::{DbBackupMethod, RestoreArgs},
+        backup::{
+            ensure_server_stopped, list_files, merkle_tree_path, server_database, BackupFile,
+            BackupManifest, BACKUP_FORMAT_VERSION, CONFIGS_ARCHIVE, DB_DUMP_FILE, MANIFEST_FILE,
+            TREE_ARCHIVE,
+        },
+    },
+    messages::{
+        msg_backup_chain_id_mismatch, msg_backup_corrupted_files, msg_backup_restored,
+        msg_backup_unsupported_format_version, msg_restore_confirm,
+        MSG_BASEBACKUP_RESTORE_UNSUPPORTED_ERR, MSG_CHAIN_NOT_INITIALIZED,
+        MSG_DOWNLOADING_BACKUP_SPINNER, MSG_RESTORE_ABORTED, MSG_RESTORING_CONFIGS_SPINNER,
+        MSG_RESTORING_DATABASE_SPINNER, MSG_RESTORING_TREE_SPINNER, MSG_VERIFYING_BACKUP_SPINNER,
+    },
+};
+
+/// Verifies that files in the backup directory exactly match the manifest.
+fn verify_backup(backup_dir: &Path, manifest: &BackupManifest) -> anyhow::Result<()> {
+    let actual_files = list_files(backup_dir)?
+        .iter()
+        .map(|path| BackupFile::new(backup_dir, path))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut corrupted_files = vec![];
+    for expected in &manifest.files {
+        let actual = actual_files.iter().find(|file| file.path == expected.path);
+        let is_valid =
+            actual.is_some_and(|file| file.size == expected.size && file.sha256 == expected.sha256);
+        if !is_valid {
+            corrupted_files.push(expected.path.clone());
+        }
+    }
+    for actual in &actual_files {
+        if !manifest.files.iter().any(|file| file.path == actual.path) {
+            corrupted_files.push(actual.path.clone());
+        }
+    }
+    anyhow::ensure!(
+        corrupted_files.is_empty(),
+        msg_backup_corrupted_files(&corrupted_files)
+    );
+    Ok(())
+}
+
+/// Restores the dump into a temporary database and replaces `server_db` with it only if `pg_restore` succeeds.
+async fn restore_database(
+    shell: &Shell,
+    server_db: &DatabaseConfig,
+    dump_path: &Path,
+) -> anyhow::Result<()> {
+    let temp_db = DatabaseConfig::new(server_db.url.clone(), format!("{}_restore", server_db.name));
+    drop_db_if_exists(&temp_db).await?;
+    init_db(&temp_db).await?;
+    let db_url = temp_db.full_url().to_string();
+    let restore_result = Cmd::new(cmd!(
+        shell,
+        "pg_restore --no-owner --dbname={db_url} {dump_path}"
+    ))
+    .run();
+    if let Err(err) = restore_result {
+        drop_db_if_exists(&temp_db).await?;
+        return Err(err.into());
+    }
+
+    drop_db_if_exists(server_db).await?;
+    rename_db(&temp_db, &server_db.name).await
+}
+
+/// Unpacks the tree next to `tree_path` and replaces the existing tree only after unpacking succeeds.
+fn restore_tree(shell: &Shell, tree_path: &Path, tree_archive: &Path) -> anyhow::Result<()> {
+    let mut temp_tree_path = tree_path.as_os_str().to_owned();
+    temp_tree_path.push(".restore");
+    let temp_tree_path = PathBuf::from(temp_tree_path);
+    shell.remove_path(&temp_tree_path)?;
+    shell.create_dir(&temp_tree_path)?;
+    let unpack_result = Cmd::new(cmd!(shell, "tar -xzf {tree_archive} -C {temp_tree_path}")).run();
+    if let Err(err) = unpack_result {
+        shell.remove_path(&temp_tree_path)?;
+        return Err(err.into());
+    }
+
+    shell.remove_path(tree_path)?;
+    std::fs::rename(&temp_tree_path, tree_path)
+        .with_context(|| format!("failed moving restored tree to {tree_path:?}"))
+}
+
+pub async fn run(args: RestoreArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_INITIALIZED)?;
+    check_prerequisites(shell, &POSTGRES_CLIENT_PREREQUISITES, false);
+
+    let temp_dir = shell.create_temp_dir()?;
+    let temp_path = temp_dir.path();
+    let archive_path = if args.archive.starts_with("gs://") {
+        check_prerequisites(shell, &GCLOUD_PREREQUISITE, false);
+        let spinner = Spinner::new(MSG_DOWNLOADING_BACKUP_SPINNER);
+        let archive_url = &args.archive;
+        Cmd::new(cmd!(shell, "gcloud storage cp {archive_url} {temp_path}")).run()?;
+        spinner.finish();
+        let file_name = archive_url.rsplit('/').next().unwrap_or_default();
+        temp_path.join(file_name)
+    } else {
+        PathBuf::from(&args.archive)
+    };
+
+    let spinner = Spinner::new(MSG_VERIFYING_BACKUP_SPINNER);
+    Cmd::new(cmd!(shell, "tar -xf {archive_path} -C {temp_path}")).run()?;
+    let backup_name = archive_path
+        .file_stem()
+        .context("invalid backup archive path")?;
+    let backup_dir = temp_path.join(backup_name);
+    let manifest: BackupManifest =
+        serde_json::from_str(&shell.read_file(backup_dir.join(MANIFEST_FILE))?)
+            .context("failed parsing backup manifest")?;
+    anyhow::ensure!(
+        manifest.format_version == BACKUP_FORMAT_VERSION,
+        msg_backup_unsupported_format_version(manifest.format_version)
+    );
+    anyhow::ensure!(
+        manifest.chain_id == chain_config.chain_id,
+        msg_backup_chain_id_mismatch(manifest.chain_id, chain_config.chain_id)
+    );
+    // Physical backups replace the entire Postgres cluster, which cannot be done safely while it is running.
+    anyhow::ensure!(
+        manifest.db_method == DbBackupMethod::Dump,
+        MSG_BASEBACKUP_RESTORE_UNSUPPORTED_ERR
+    );
+    verify_backup(&backup_dir, &manifest)?;
+    // Configs are unpacked to a temporary directory first, so that the database and the tree are restored
+    // to the locations they were backed up from, and the current configs are only overwritten on success.
+    let restored_configs = shell.create_dir(temp_path.join("configs"))?;
+    let configs_archive = backup_dir.join(CONFIGS_ARCHIVE);
+    Cmd::new(cmd!(
+        shell,
+        "tar -xzf {configs_archive} -C {restored_configs}"
+    ))
+    .run()?;
+    spinner.finish();
+
+    let server_db = server_database(shell, &restored_configs).await?;
+    let tree_path = merkle_tree_path(shell, &chain_config, &restored_configs).await?;
+    let confirmed = args.force
+        || PromptConfirm::new(msg_restore_confirm(
+            &chain_config.name,
+            &server_db.name,
+            &tree_path,
+        ))
+        .ask();
+    anyhow::ensure!(confirmed, MSG_RESTORE_ABORTED);
+    // The server may be configured with a different database than the one being restored.
+    ensure_server_stopped(&server_database(shell, &chain_config.configs).await?).await?;
+    ensure_server_stopped(&server_db).await?;
+
+    let spinner = Spinner::new(MSG_RESTORING_DATABASE_SPINNER);
+    restore_database(shell, &server_db, &backup_dir.join(DB_DUMP_FILE)).await?;
+    spinner.finish();
+
+    let spinner = Spinner::new(MSG_RESTORING_TREE_SPINNER);
+    restore_tree(shell, &tree_path, &backup_dir.join(TREE_ARCHIVE))?;
+    spinner.finish();
+
+    let spinner = Spinner::new(MSG_RESTORING_CONFIGS_SPINNER);
+    let configs_path = &chain_config.configs;
+    Cmd::new(cmd!(shell, "tar -xzf {configs_archive} -C {configs_path}")).run()?;
+    spinner.finish();
+
+    logger::outro(msg_backup_restored(
+        &manifest.chain_name,
+        &manifest.created_at,
+    ));
+    Ok(())
+}
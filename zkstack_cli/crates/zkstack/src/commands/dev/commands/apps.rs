@@ -0,0 +1,202 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use clap::Subcommand;
+use xshell::Shell;
+use zkstack_cli_common::{config::global_config, docker, logger, spinner::Spinner};
+use zkstack_cli_config::{
+    docker_compose::{DockerComposeConfig, DockerComposeService},
+    explorer::ExplorerConfig,
+    explorer_compose::ExplorerBackendComposeConfig,
+    portal::PortalConfig,
+    traits::SaveConfig,
+    AppsEcosystemConfig, EcosystemConfig,
+};
+
+use crate::{
+    commands::{
+        dev::messages::{
+            msg_app_not_set_up, msg_app_started, msg_app_stopped, MSG_APP_DOWN_ABOUT,
+            MSG_APP_STARTING_SPINNER, MSG_APP_UP_ABOUT,
+        },
+        explorer::run::prepare_explorer_js_config,
+        portal::prepare_portal_js_config,
+    },
+    consts::{
+        EXPLORER_APP_DOCKER_CONFIG_PATH, EXPLORER_APP_DOCKER_IMAGE, PORTAL_DOCKER_CONFIG_PATH,
+        PORTAL_DOCKER_IMAGE,
+    },
+    messages::msg_explorer_chain_not_initialized,
+};
+
+const PORTAL_COMPOSE_FILE: &str = "portal-docker-compose.yml";
+const EXPLORER_APP_COMPOSE_FILE: &str = "explorer-app-docker-compose.yml";
+
+#[derive(Subcommand, Debug)]
+pub enum AppCommands {
+    #[command(about = MSG_APP_UP_ABOUT)]
+    Up,
+    #[command(about = MSG_APP_DOWN_ABOUT)]
+    Down,
+}
+
+/// Web app that can be run against local chains.
+#[derive(Debug, Clone, Copy)]
+pub enum App {
+    Portal,
+    Explorer,
+}
+
+impl App {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Portal => "Portal",
+            Self::Explorer => "Explorer",
+        }
+    }
+
+    fn compose_file(self, ecosystem_path: &Path) -> PathBuf {
+        match self {
+            Self::Portal => {
+                PortalConfig::get_config_path(ecosystem_path).with_file_name(PORTAL_COMPOSE_FILE)
+            }
+            Self::Explorer => ExplorerConfig::get_config_path(ecosystem_path)
+                .with_file_name(EXPLORER_APP_COMPOSE_FILE),
+        }
+    }
+}
+
+pub async fn run(shell: &Shell, app: App, args: AppCommands) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    match args {
+        AppCommands::Up => up(shell, &ecosystem, app).await,
+        AppCommands::Down => down(shell, &ecosystem, app),
+    }
+}
+
+async fn up(shell: &Shell, ecosystem: &EcosystemConfig, app: App) -> anyhow::Result<()> {
+    let ecosystem_path = shell.current_dir();
+    let apps_config = AppsEcosystemConfig::read_or_create_default(shell)?;
+    let (config_js_path, port) = match app {
+        App::Portal => (
+            prepare_portal_js_config(shell, ecosystem).await?,
+            apps_config.portal.http_port,
+        ),
+        App::Explorer => (
+            prepare_explorer_js_config(shell, ecosystem)?,
+            apps_config.explorer.http_port,
+        ),
+    };
+
+    let compose_file = app.compose_file(&ecosystem_path);
+    compose_config(ecosystem, app, &config_js_path, port).save(shell, &compose_file)?;
+
+    let spinner = Spinner::new(MSG_APP_STARTING_SPINNER);
+    if let App::Explorer = app {
+        // The explorer app is useless without the backend, so start backend services for all enabled chains as well.
+        for backend_compose_file in explorer_backend_compose_files(ecosystem, &ecosystem_path)? {
+            docker::up(shell, &path_to_str(&backend_compose_file)?, true)?;
+        }
+    }
+    docker::up(shell, &path_to_str(&compose_file)?, true)?;
+    spinner.finish();
+
+    logger::outro(msg_app_started(app.name(), port));
+    Ok(())
+}
+
+fn down(shell: &Shell, ecosystem: &EcosystemConfig, app: App) -> anyhow::Result<()> {
+    let ecosystem_path = shell.current_dir();
+    let compose_file = app.compose_file(&ecosystem_path);
+    if !shell.path_exists(&compose_file) {
+        logger::info(msg_app_not_set_up(app.name()));
+        return Ok(());
+    }
+    docker::down(shell, &path_to_str(&compose_file)?)?;
+
+    if let App::Explorer = app {
+        for chain_name in enabled_chains(ecosystem) {
+            let backend_compose_file =
+                ExplorerBackendComposeConfig::get_config_path(&ecosystem_path, &chain_name);
+            if shell.path_exists(&backend_compose_file) {
+                docker::down(shell, &path_to_str(&backend_compose_file)?)?;
+            }
+        }
+    }
+    logger::outro(msg_app_stopped(app.name()));
+    Ok(())
+}
+
+fn enabled_chains(ecosystem: &EcosystemConfig) -> Vec<String> {
+    match global_config().chain_name {
+        Some(ref chain_name) => vec![chain_name.clone()],
+        None => ecosystem.list_of_chains(),
+    }
+}
+
+/// Returns paths to explorer backend docker-compose files for all enabled chains.
+/// These files are generated by `zkstack explorer init` from the chain configs.
+fn explorer_backend_compose_files(
+    ecosystem: &EcosystemConfig,
+    ecosystem_path: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    enabled_chains(ecosystem)
+        .into_iter()
+        .map(|chain_name| {
+            let path = ExplorerBackendComposeConfig::get_config_path(ecosystem_path, &chain_name);
+            if !path.exists() {
+                anyhow::bail!(msg_explorer_chain_not_initialized(&chain_name));
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+fn compose_config(
+    ecosystem: &EcosystemConfig,
+    app: App,
+    config_js_path: &Path,
+    port: u16,
+) -> DockerComposeConfig {
+    let (service_name, image, docker_config_path) = match app {
+        App::Portal => ("portal", PORTAL_DOCKER_IMAGE, PORTAL_DOCKER_CONFIG_PATH),
+        App::Explorer => (
+            "explorer-app",
+            EXPLORER_APP_DOCKER_IMAGE,
+            EXPLORER_APP_DOCKER_CONFIG_PATH,
+        ),
+    };
+    // RPC and API URLs in the mounted config are used by the browser, so they don't need to be adjusted
+    // to be reachable from the container.
+    let service = DockerComposeService {
+        image: image.to_string(),
+        platform: Some("linux/amd64".to_string()),
+        ports: Some(vec![format!("{port}:{port}")]),
+        environment: Some(HashMap::from([("PORT".to_string(), port.to_string())])),
+        volumes: Some(vec![format!(
+            "{}:{docker_config_path}:ro",
+            config_js_path.display()
+        )]),
+        depends_on: None,
+        restart: Some("unless-stopped".to_string()),
+        extra_hosts: None,
+        other: serde_json::Value::Null,
+    };
+
+    let mut config = DockerComposeConfig {
+        services: HashMap::new(),
+        name: Some(format!("{}-{service_name}", ecosystem.name)),
+        other: serde_json::Value::Null,
+    };
+    config.add_service(service_name, service);
+    config
+}
+
+fn path_to_str(path: &Path) -> anyhow::Result<String> {
+    path.to_str()
+        .map(str::to_owned)
+        .with_context(|| format!("non-UTF8 path: {path:?}"))
+}
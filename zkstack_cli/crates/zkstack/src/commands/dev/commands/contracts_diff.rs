@@ -0,0 +1,338 @@
+//! Pre-upgrade comparison of two system contract sets.
+//!
+//! Compares Foundry (`zkout`) artifacts of system contracts built from two `zksync-era` checkouts and reports
+//! bytecode size changes, storage layout changes and selector changes for each contract.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zkstack_cli_common::{contracts::build_system_contracts, logger, spinner::Spinner};
+use zkstack_cli_config::EcosystemConfig;
+
+use crate::commands::dev::messages::{
+    msg_contracts_diff_unsafe_storage, MSG_BUILDING_SYSTEM_CONTRACTS_SPINNER,
+    MSG_CONTRACTS_DIFF_JSON_HELP, MSG_CONTRACTS_DIFF_NEW_PATH_HELP, MSG_CONTRACTS_DIFF_NO_CHANGES,
+    MSG_CONTRACTS_DIFF_OLD_PATH_HELP, MSG_CONTRACTS_DIFF_SKIP_BUILD_HELP,
+};
+
+const SYSTEM_CONTRACTS_ARTIFACTS_DIR: &str = "contracts/system-contracts/zkout";
+
+#[derive(Debug, Parser)]
+pub struct ContractsDiffArgs {
+    #[clap(long, help = MSG_CONTRACTS_DIFF_OLD_PATH_HELP)]
+    pub old_path: PathBuf,
+    #[clap(long, help = MSG_CONTRACTS_DIFF_NEW_PATH_HELP)]
+    pub new_path: Option<PathBuf>,
+    #[clap(long, help = MSG_CONTRACTS_DIFF_SKIP_BUILD_HELP, default_missing_value = "true", num_args = 0..=1)]
+    pub skip_build: Option<bool>,
+    #[clap(long, help = MSG_CONTRACTS_DIFF_JSON_HELP)]
+    pub json: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct Artifact {
+    #[serde(default)]
+    bytecode: Option<ArtifactBytecode>,
+    #[serde(default, rename = "methodIdentifiers")]
+    method_identifiers: BTreeMap<String, String>,
+    #[serde(default, rename = "storageLayout")]
+    storage_layout: Option<StorageLayout>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArtifactBytecode {
+    object: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct StorageLayout {
+    storage: Vec<StorageEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct StorageEntry {
+    label: String,
+    slot: String,
+    offset: u64,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl StorageEntry {
+    fn describe(&self) -> String {
+        format!(
+            "{} ({}) at slot {}, offset {}",
+            self.label, self.ty, self.slot, self.offset
+        )
+    }
+}
+
+/// Contract data relevant for the comparison.
+#[derive(Debug)]
+struct ContractInfo {
+    bytecode: String,
+    selectors: BTreeSet<String>,
+    storage: Option<Vec<StorageEntry>>,
+}
+
+impl ContractInfo {
+    fn from_artifact(artifact: Artifact) -> Option<Self> {
+        let bytecode = artifact.bytecode?.object;
+        let bytecode = bytecode.trim_start_matches("0x").to_owned();
+        // Interfaces and abstract contracts have no bytecode and cannot be deployed.
+        if bytecode.is_empty() {
+            return None;
+        }
+        let selectors = artifact
+            .method_identifiers
+            .into_iter()
+            .map(|(signature, selector)| format!("0x{selector} {signature}"))
+            .collect();
+        Some(Self {
+            bytecode,
+            selectors,
+            storage: artifact.storage_layout.map(|layout| layout.storage),
+        })
+    }
+
+    fn bytecode_size(&self) -> usize {
+        self.bytecode.len() / 2
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ContractChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Storage layout change. Changes other than appending new variables may corrupt existing state on upgrade.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum StorageChange {
+    Appended { entry: String },
+    Removed { entry: String },
+    Modified { old: String, new: String },
+}
+
+impl StorageChange {
+    fn is_unsafe(&self) -> bool {
+        !matches!(self, Self::Appended { .. })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ContractDiff {
+    name: String,
+    change: ContractChange,
+    old_bytecode_size: Option<usize>,
+    new_bytecode_size: Option<usize>,
+    added_selectors: Vec<String>,
+    removed_selectors: Vec<String>,
+    /// `None` if storage layout is missing from either of the artifacts.
+    storage_changes: Option<Vec<StorageChange>>,
+}
+
+impl ContractDiff {
+    fn new(name: String, old: Option<&ContractInfo>, new: Option<&ContractInfo>) -> Option<Self> {
+        let change = match (old, new) {
+            (None, Some(_)) => ContractChange::Added,
+            (Some(_), None) => ContractChange::Removed,
+            (Some(old), Some(new)) if old.bytecode != new.bytecode => ContractChange::Modified,
+            _ => return None,
+        };
+        let empty = BTreeSet::new();
+        let old_selectors = old.map_or(&empty, |info| &info.selectors);
+        let new_selectors = new.map_or(&empty, |info| &info.selectors);
+        let storage_changes = match (old, new) {
+            (Some(old), Some(new)) => old
+                .storage
+                .as_deref()
+                .zip(new.storage.as_deref())
+                .map(|(old, new)| diff_storage(old, new)),
+            _ => Some(vec![]),
+        };
+
+        Some(Self {
+            name,
+            change,
+            old_bytecode_size: old.map(ContractInfo::bytecode_size),
+            new_bytecode_size: new.map(ContractInfo::bytecode_size),
+            added_selectors: new_selectors.difference(old_selectors).cloned().collect(),
+            removed_selectors: old_selectors.difference(new_selectors).cloned().collect(),
+            storage_changes,
+        })
+    }
+
+    fn unsafe_storage_changes(&self) -> usize {
+        self.storage_changes.as_ref().map_or(0, |changes| {
+            changes.iter().filter(|change| change.is_unsafe()).count()
+        })
+    }
+
+    fn print(&self) {
+        let size = |size: Option<usize>| size.map_or("-".to_owned(), |size| format!("{size} B"));
+        let mut lines = vec![format!(
+            "bytecode size: {} -> {}",
+            size(self.old_bytecode_size),
+            size(self.new_bytecode_size)
+        )];
+        lines.extend(
+            self.added_selectors
+                .iter()
+                .map(|selector| format!("+ {selector}")),
+        );
+        lines.extend(
+            self.removed_selectors
+                .iter()
+                .map(|selector| format!("- {selector}")),
+        );
+        match &self.storage_changes {
+            Some(changes) => lines.extend(changes.iter().map(|change| match change {
+                StorageChange::Appended { entry } => format!("storage appended: {entry}"),
+                StorageChange::Removed { entry } => format!("storage REMOVED: {entry}"),
+                StorageChange::Modified { old, new } => {
+                    format!("storage MODIFIED: {old} -> {new}")
+                }
+            })),
+            None => lines.push("storage layout is not available in artifacts".to_owned()),
+        }
+        logger::note(
+            format!("{} ({:?})", self.name, self.change),
+            lines.join("\n"),
+        );
+    }
+}
+
+fn diff_storage(old: &[StorageEntry], new: &[StorageEntry]) -> Vec<StorageChange> {
+    let key = |entry: &StorageEntry| (entry.slot.clone(), entry.offset);
+    let old_entries: BTreeMap<_, _> = old.iter().map(|entry| (key(entry), entry)).collect();
+    let new_entries: BTreeMap<_, _> = new.iter().map(|entry| (key(entry), entry)).collect();
+
+    let mut changes = vec![];
+    for (key, old_entry) in &old_entries {
+        match new_entries.get(key) {
+            None => changes.push(StorageChange::Removed {
+                entry: old_entry.describe(),
+            }),
+            Some(new_entry)
+                if new_entry.label != old_entry.label || new_entry.ty != old_entry.ty =>
+            {
+                changes.push(StorageChange::Modified {
+                    old: old_entry.describe(),
+                    new: new_entry.describe(),
+                });
+            }
+            Some(_) => { /* unchanged */ }
+        }
+    }
+    for (key, new_entry) in &new_entries {
+        if !old_entries.contains_key(key) {
+            changes.push(StorageChange::Appended {
+                entry: new_entry.describe(),
+            });
+        }
+    }
+    changes
+}
+
+/// Reads all deployable system contracts from the artifacts directory. Contracts are keyed by `{file}/{contract}`.
+fn read_contracts(link_to_code: &Path) -> anyhow::Result<BTreeMap<String, ContractInfo>> {
+    let artifacts_dir = link_to_code.join(SYSTEM_CONTRACTS_ARTIFACTS_DIR);
+    let mut contracts = BTreeMap::new();
+    let source_dirs = fs::read_dir(&artifacts_dir)
+        .with_context(|| format!("failed reading artifacts directory {artifacts_dir:?}"))?;
+    for source_dir in source_dirs {
+        let source_dir = source_dir?.path();
+        if !source_dir.is_dir() {
+            continue;
+        }
+        let source_name = source_dir
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        for artifact_path in fs::read_dir(&source_dir)? {
+            let artifact_path = artifact_path?.path();
+            if artifact_path.extension() != Some("json".as_ref()) {
+                continue;
+            }
+            let contents = fs::read_to_string(&artifact_path)?;
+            let artifact: Artifact = serde_json::from_str(&contents)
+                .with_context(|| format!("failed parsing artifact {artifact_path:?}"))?;
+            if let Some(info) = ContractInfo::from_artifact(artifact) {
+                let contract_name = artifact_path.file_stem().unwrap().to_string_lossy();
+                contracts.insert(format!("{source_name}/{contract_name}"), info);
+            }
+        }
+    }
+    Ok(contracts)
+}
+
+/// Compares system contracts built in two `zksync-era` checkouts. Only changed contracts are returned.
+pub(crate) fn diff_system_contracts(
+    old_link_to_code: &Path,
+    new_link_to_code: &Path,
+) -> anyhow::Result<Vec<ContractDiff>> {
+    let old_contracts = read_contracts(old_link_to_code)?;
+    let new_contracts = read_contracts(new_link_to_code)?;
+    let names: BTreeSet<_> = old_contracts.keys().chain(new_contracts.keys()).collect();
+    Ok(names
+        .into_iter()
+        .filter_map(|name| {
+            ContractDiff::new(
+                name.clone(),
+                old_contracts.get(name),
+                new_contracts.get(name),
+            )
+        })
+        .collect())
+}
+
+/// Prints the report and returns the total number of unsafe storage layout changes.
+pub(crate) fn print_report(diffs: &[ContractDiff]) -> usize {
+    if diffs.is_empty() {
+        logger::info(MSG_CONTRACTS_DIFF_NO_CHANGES);
+        return 0;
+    }
+    for diff in diffs {
+        diff.print();
+    }
+    let unsafe_changes: usize = diffs.iter().map(ContractDiff::unsafe_storage_changes).sum();
+    if unsafe_changes > 0 {
+        logger::warn(msg_contracts_diff_unsafe_storage(unsafe_changes));
+    }
+    unsafe_changes
+}
+
+pub fn run(shell: &Shell, args: ContractsDiffArgs) -> anyhow::Result<()> {
+    let new_path = match args.new_path {
+        Some(path) => path,
+        None => EcosystemConfig::from_file(shell)?.link_to_code,
+    };
+
+    if !args.skip_build.unwrap_or(false) {
+        for path in [&args.old_path, &new_path] {
+            let spinner = Spinner::new(MSG_BUILDING_SYSTEM_CONTRACTS_SPINNER);
+            build_system_contracts(shell.clone(), path.clone())?;
+            spinner.finish();
+        }
+    }
+
+    let diffs = diff_system_contracts(&args.old_path, &new_path)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&diffs)?);
+    } else {
+        print_report(&diffs);
+    }
+    Ok(())
+}
@@ -9,6 +9,7 @@ use crate::commands::dev::{
 };
 
 pub mod new_migration;
+pub mod sync_chains;
 
 #[derive(Debug, Parser)]
 pub struct DatabaseCommonArgs {
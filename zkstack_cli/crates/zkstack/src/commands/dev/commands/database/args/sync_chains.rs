@@ -0,0 +1,9 @@
+use clap::Parser;
+
+use crate::commands::dev::messages::MSG_DATABASE_SYNC_CHAINS_CHAINS_HELP;
+
+#[derive(Debug, Parser)]
+pub struct DatabaseSyncChainsArgs {
+    #[clap(long, value_delimiter = ',', help = MSG_DATABASE_SYNC_CHAINS_CHAINS_HELP)]
+    pub chains: Vec<String>,
+}
@@ -1,11 +1,14 @@
 use clap::Subcommand;
 use xshell::Shell;
 
-use self::args::{new_migration::DatabaseNewMigrationArgs, DatabaseCommonArgs};
+use self::args::{
+    new_migration::DatabaseNewMigrationArgs, sync_chains::DatabaseSyncChainsArgs,
+    DatabaseCommonArgs,
+};
 use crate::commands::dev::messages::{
     MSG_DATABASE_CHECK_SQLX_DATA_ABOUT, MSG_DATABASE_DROP_ABOUT, MSG_DATABASE_MIGRATE_ABOUT,
     MSG_DATABASE_NEW_MIGRATION_ABOUT, MSG_DATABASE_PREPARE_ABOUT, MSG_DATABASE_RESET_ABOUT,
-    MSG_DATABASE_SETUP_ABOUT,
+    MSG_DATABASE_SETUP_ABOUT, MSG_DATABASE_SYNC_CHAINS_ABOUT,
 };
 
 pub mod args;
@@ -16,6 +19,7 @@ mod new_migration;
 mod prepare;
 pub mod reset;
 mod setup;
+mod sync_chains;
 
 #[derive(Subcommand, Debug)]
 pub enum DatabaseCommands {
@@ -33,6 +37,8 @@ pub enum DatabaseCommands {
     Reset(DatabaseCommonArgs),
     #[clap(about = MSG_DATABASE_SETUP_ABOUT)]
     Setup(DatabaseCommonArgs),
+    #[clap(about = MSG_DATABASE_SYNC_CHAINS_ABOUT)]
+    SyncChains(DatabaseSyncChainsArgs),
 }
 
 pub async fn run(shell: &Shell, args: DatabaseCommands) -> anyhow::Result<()> {
@@ -44,5 +50,6 @@ pub async fn run(shell: &Shell, args: DatabaseCommands) -> anyhow::Result<()> {
         DatabaseCommands::Prepare(args) => prepare::run(shell, args).await,
         DatabaseCommands::Reset(args) => reset::run(shell, args).await,
         DatabaseCommands::Setup(args) => setup::run(shell, args).await,
+        DatabaseCommands::SyncChains(args) => sync_chains::run(shell, args).await,
     }
 }
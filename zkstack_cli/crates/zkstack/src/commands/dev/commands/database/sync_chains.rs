@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use xshell::{cmd, Shell};
+use zkstack_cli_common::{
+    check_prerequisites, cmd::Cmd, logger, spinner::Spinner, PROVER_CLI_PREREQUISITE,
+};
+use zkstack_cli_config::{ChainConfig, EcosystemConfig};
+use zksync_types::{protocol_version::ProtocolSemanticVersion, H256};
+
+use super::args::sync_chains::DatabaseSyncChainsArgs;
+use crate::commands::dev::messages::{
+    msg_database_sync_chains_skipped, msg_database_sync_chains_version,
+    MSG_DATABASE_SYNC_CHAINS_SUCCESS,
+};
+
+/// Protocol version of a chain, as recorded in the prover database.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ProverProtocolVersion {
+    version: ProtocolSemanticVersion,
+    snark_wrapper_vk_hash: H256,
+    fflonk_snark_wrapper_vk_hash: H256,
+}
+
+/// Reads the protocol version that the prover cluster serving the chain must support. Returns `None` if the chain
+/// doesn't have a prover database configured.
+async fn chain_prover_version(
+    chain_config: &ChainConfig,
+) -> anyhow::Result<Option<(String, ProverProtocolVersion)>> {
+    let secrets = chain_config.get_secrets_config().await?;
+    let Some(prover_url) = secrets.get_opt::<String>("database.prover_url")? else {
+        return Ok(None);
+    };
+
+    let genesis = chain_config.get_genesis_config().await?;
+    let version = ProverProtocolVersion {
+        version: genesis.get("genesis_protocol_semantic_version")?,
+        snark_wrapper_vk_hash: genesis.get("prover.snark_wrapper_vk_hash")?,
+        fflonk_snark_wrapper_vk_hash: genesis.get("prover.fflonk_snark_wrapper_vk_hash")?,
+    };
+    Ok(Some((prover_url, version)))
+}
+
+/// Ensures that prover databases contain protocol versions of all chains they serve. Several chains may share
+/// a prover database; the inserts are idempotent, so the command can be re-run after adding a chain.
+pub async fn run(shell: &Shell, args: DatabaseSyncChainsArgs) -> anyhow::Result<()> {
+    check_prerequisites(shell, &PROVER_CLI_PREREQUISITE, false);
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chains = if args.chains.is_empty() {
+        ecosystem_config.list_of_chains()
+    } else {
+        args.chains
+    };
+
+    // Prover database URL -> protocol versions -> chain names
+    let mut versions_by_db = BTreeMap::<_, BTreeMap<_, Vec<_>>>::new();
+    for chain_name in chains {
+        let chain_config = ecosystem_config.load_chain(Some(chain_name.clone()))?;
+        let Some((prover_url, version)) = chain_prover_version(&chain_config).await? else {
+            logger::warn(msg_database_sync_chains_skipped(&chain_name));
+            continue;
+        };
+        versions_by_db
+            .entry(prover_url)
+            .or_default()
+            .entry(version)
+            .or_default()
+            .push(chain_name);
+    }
+
+    for (prover_url, versions) in versions_by_db {
+        for (version, chain_names) in versions {
+            let minor = version.version.minor as u16;
+            let patch = version.version.patch.0;
+            let snark_wrapper = format!("{:?}", version.snark_wrapper_vk_hash);
+            let fflonk_snark_wrapper = format!("{:?}", version.fflonk_snark_wrapper_vk_hash);
+
+            let spinner = Spinner::new(&msg_database_sync_chains_version(
+                &version.version.to_string(),
+                &chain_names,
+            ));
+            Cmd::new(cmd!(
+                shell,
+                "prover_cli {prover_url} insert-version --version={minor} --patch={patch}
+                    --snark-wrapper={snark_wrapper} --fflonk-snark-wrapper={fflonk_snark_wrapper}"
+            ))
+            .run()?;
+            spinner.finish();
+        }
+    }
+
+    logger::outro(MSG_DATABASE_SYNC_CHAINS_SUCCESS);
+    Ok(())
+}
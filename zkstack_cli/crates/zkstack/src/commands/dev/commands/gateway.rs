@@ -1,11 +1,15 @@
-use std::{num::NonZeroUsize, str::FromStr, sync::Arc};
+use std::{num::NonZeroUsize, path::PathBuf, str::FromStr, sync::Arc};
 
 use anyhow::Context;
 use clap::{Parser, ValueEnum};
 use ethers::{
-    abi::{encode, parse_abi, Token},
+    abi::{decode, encode, parse_abi, ParamType, Token},
     contract::{abigen, BaseContract},
-    providers::{Http, Middleware, Provider},
+    providers::{
+        call_raw::{spoof, RawCall},
+        Http, JsonRpcError, Middleware, Provider, RpcError,
+    },
+    types::{transaction::eip2718::TypedTransaction, TransactionRequest},
     utils::hex,
 };
 use serde::{Deserialize, Serialize};
@@ -14,7 +18,7 @@ use xshell::Shell;
 use zkstack_cli_config::{
     forge_interface::gateway_ecosystem_upgrade::output::GatewayEcosystemUpgradeOutput,
     traits::{ReadConfig, ZkStackConfig},
-    ContractsConfig,
+    ContractsConfig, EcosystemConfig,
 };
 use zksync_contracts::{chain_admin_contract, hyperchain_contract, DIAMOND_CUT};
 use zksync_types::{
@@ -29,7 +33,10 @@ use zksync_web3_decl::{
     namespaces::{EthNamespaceClient, UnstableNamespaceClient, ZksNamespaceClient},
 };
 
-use super::events_gatherer::{get_logs_for_events, DEFAULT_BLOCK_RANGE};
+use super::{
+    contracts_diff,
+    events_gatherer::{get_logs_for_events, DEFAULT_BLOCK_RANGE},
+};
 
 /// To support both functionality of assignment inside local tests
 /// and to print out the changes to the user the following function is used.
@@ -676,6 +683,32 @@ impl AdminCall {
     }
 }
 
+/// Outcome of simulating a single [`AdminCall`].
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminCallSimulation {
+    description: String,
+    target: Address,
+    /// Revert reason if the call has failed; `None` if the call has succeeded.
+    revert_reason: Option<String>,
+}
+
+/// Selector of the `Error(string)` error used by `require` / `revert` with a message.
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+fn revert_reason(err: &JsonRpcError) -> String {
+    let Some(data) = err.as_revert_data() else {
+        return err.message.clone();
+    };
+    if let Some(encoded_reason) = data.strip_prefix(&ERROR_STRING_SELECTOR) {
+        if let Ok(tokens) = decode(&[ParamType::String], encoded_reason) {
+            if let Some(Token::String(reason)) = tokens.into_iter().next() {
+                return reason;
+            }
+        }
+    }
+    format!("{} (revert data: 0x{})", err.message, hex::encode(&data))
+}
+
 fn hex_address_display(addr: Address) -> String {
     format!("0x{}", hex::encode(addr.0))
 }
@@ -703,6 +736,7 @@ impl AdminCallBuilder {
             validator_timelock_abi: BaseContract::from(
                 parse_abi(&[
                     "function addValidator(uint256 _chainId, address _newValidator) external",
+                    "function removeValidator(uint256 _chainId, address _validator) external",
                 ])
                 .unwrap(),
             ),
@@ -722,8 +756,39 @@ impl AdminCallBuilder {
             .encode("addValidator", (U256::from(chain_id), validator_addr))
             .unwrap();
         let description = format!(
-            "Adding validator 0x{}",
-            hex::encode(validator_timelock_addr.0)
+            "Adding validator {} to timelock {}",
+            hex_address_display(validator_addr),
+            hex_address_display(validator_timelock_addr)
+        );
+
+        let call = AdminCall {
+            description,
+            data: data.to_vec(),
+            target: validator_timelock_addr,
+            value: U256::zero(),
+        };
+
+        self.calls.push(call);
+    }
+
+    /// Appends a call removing a validator from the timelock. During operator key rotation, new operators
+    /// are added via [`Self::append_validator()`] first, so that both old and new keys stay valid while
+    /// transactions signed by the old keys are in flight; old operators are removed once all their transactions
+    /// are confirmed.
+    pub fn append_remove_validator(
+        &mut self,
+        chain_id: u64,
+        validator_timelock_addr: Address,
+        validator_addr: Address,
+    ) {
+        let data = self
+            .validator_timelock_abi
+            .encode("removeValidator", (U256::from(chain_id), validator_addr))
+            .unwrap();
+        let description = format!(
+            "Removing validator {} from timelock {}",
+            hex_address_display(validator_addr),
+            hex_address_display(validator_timelock_addr)
         );
 
         let call = AdminCall {
@@ -816,6 +881,58 @@ impl AdminCallBuilder {
         println!("{}", serialized);
     }
 
+    /// Simulates each call with `eth_call` on behalf of the chain admin. The balance of the chain admin
+    /// is overridden so that calls transferring value don't fail because of insufficient funds.
+    ///
+    /// Calls are simulated independently against the latest state, so a call relying on the effects
+    /// of a preceding call in the sequence may be reported as reverting even though the full multicall succeeds.
+    pub async fn simulate(
+        &self,
+        l1_rpc_url: &str,
+        chain_admin_addr: Address,
+    ) -> anyhow::Result<Vec<AdminCallSimulation>> {
+        let provider = get_ethers_provider(l1_rpc_url)?;
+        let required_value = self
+            .calls
+            .iter()
+            .fold(U256::zero(), |acc, call| acc.saturating_add(call.value));
+        let current_balance = provider
+            .get_balance(chain_admin_addr, None)
+            .await
+            .context("failed getting chain admin balance")?;
+        let mut state = spoof::state();
+        state
+            .account(chain_admin_addr)
+            .balance(current_balance.max(required_value));
+
+        let mut simulations = Vec::with_capacity(self.calls.len());
+        for call in &self.calls {
+            let tx: TypedTransaction = TransactionRequest::new()
+                .from(chain_admin_addr)
+                .to(call.target)
+                .data(call.data.clone())
+                .value(call.value)
+                .into();
+            let revert_reason = match provider.call_raw(&tx).state(&state).await {
+                Ok(_) => None,
+                Err(err) => {
+                    let Some(err) = err.as_error_response() else {
+                        return Err(err).with_context(|| {
+                            format!("failed simulating call `{}`", call.description)
+                        });
+                    };
+                    Some(revert_reason(err))
+                }
+            };
+            simulations.push(AdminCallSimulation {
+                description: call.description.clone(),
+                target: call.target,
+                revert_reason,
+            });
+        }
+        Ok(simulations)
+    }
+
     pub fn compile_full_calldata(self) -> Vec<u8> {
         let tokens: Vec<_> = self.calls.into_iter().map(|x| x.into_token()).collect();
 
@@ -863,6 +980,13 @@ pub struct GatewayUpgradeCalldataArgs {
     #[clap(long, default_missing_value = "false")]
     force_display_finalization_params: Option<bool>,
     l2_tokens_indexing_block_range: Option<u64>,
+    /// Skip simulating the finalization calls on behalf of the chain admin before printing the calldata.
+    #[clap(long, default_missing_value = "true", num_args = 0..=1)]
+    skip_simulation: Option<bool>,
+    /// Path to the zksync-era checkout with the currently deployed contracts. If set, built system contracts
+    /// are compared with the ones in the ecosystem `link_to_code` before generating the calldata.
+    #[clap(long)]
+    contracts_diff_old_path: Option<PathBuf>,
 }
 
 pub struct GatewayUpgradeArgsInner {
@@ -962,6 +1086,17 @@ pub(crate) async fn run(shell: &Shell, args: GatewayUpgradeCalldataArgs) -> anyh
 
     let upgrade_info = GatewayUpgradeInfo::read(shell, &args.upgrade_description_path)?;
 
+    if let Some(old_path) = &args.contracts_diff_old_path {
+        let new_path = EcosystemConfig::from_file(shell)?.link_to_code;
+        let diffs = contracts_diff::diff_system_contracts(old_path, &new_path)?;
+        let unsafe_changes = contracts_diff::print_report(&diffs);
+        if unsafe_changes > 0 && !args.dangerous_no_cross_check.unwrap_or_default() {
+            println!("Storage layout of system contracts changed in an unsafe way; refusing to generate upgrade calldata.");
+            println!("If the changes are expected, pass `--dangerous-no-cross-check=true`.");
+            return Ok(());
+        }
+    }
+
     // 1. Update all the configs
 
     let chain_info = fetch_chain_info(&upgrade_info, &args.clone().into()).await?;
@@ -1008,8 +1143,27 @@ pub(crate) async fn run(shell: &Shell, args: GatewayUpgradeCalldataArgs) -> anyh
         };
     }
 
+    let l1_rpc_url = args.l1_rpc_url.clone();
+    let skip_simulation = args.skip_simulation.unwrap_or_default();
     let admin_calls_finalize = get_admin_call_builder(&upgrade_info, &chain_info, args.into());
 
+    if !skip_simulation {
+        println!("Simulating finalization calls on behalf of the chain admin...");
+        let simulations = admin_calls_finalize
+            .simulate(&l1_rpc_url, chain_info.chain_admin_addr)
+            .await?;
+        for simulation in &simulations {
+            match &simulation.revert_reason {
+                None => println!("[OK] {}", simulation.description),
+                Some(reason) => println!("[REVERT] {}: {reason}", simulation.description),
+            }
+        }
+        if simulations.iter().any(|sim| sim.revert_reason.is_some()) {
+            println!("WARNING: some calls revert when simulated. Calls are simulated independently, so calls relying on the effects of preceding ones may revert in simulation only; please review the reasons above.");
+        }
+        println!("---------------------------");
+    }
+
     admin_calls_finalize.display();
 
     let chain_admin_calldata = admin_calls_finalize.compile_full_calldata();
@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use clap::Parser;
+use ethers::{
+    abi::parse_abi,
+    contract::{abigen, BaseContract},
+    providers::Middleware,
+    utils::hex,
+};
+use serde::Serialize;
+use zksync_types::{Address, H256, L2_NATIVE_TOKEN_VAULT_ADDRESS, U256};
+
+use super::{
+    events_gatherer::DEFAULT_BLOCK_RANGE,
+    gateway::{
+        check_l2_ntv_existence, encode_ntv_asset_id, get_deployed_by_bridge, get_ethers_provider,
+        get_zk_client,
+    },
+};
+
+abigen!(
+    L2NativeTokenVaultAbi,
+    r"[
+    function assetId(address)(bytes32)
+    function L2_LEGACY_SHARED_BRIDGE()(address)
+]"
+);
+
+abigen!(
+    L1NativeTokenVaultAbi,
+    r"[
+    function assetId(address)(bytes32)
+]"
+);
+
+abigen!(
+    L2LegacySharedBridgeAbi,
+    r"[
+    function l1TokenAddress(address)(address)
+]"
+);
+
+/// Classification of an address deployed by the L2 legacy shared bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+enum TokenStatus {
+    /// The contract is not a token bridged from L1 (e.g., it's a beacon proxy or another helper contract).
+    NotBridgedToken,
+    /// The token is registered in native token vaults on both L1 and L2 with the expected asset ID.
+    Migrated,
+    /// The token is not registered in the L2 native token vault.
+    MissingL2Registration,
+    /// The token is not registered in the L1 native token vault.
+    MissingL1Registration,
+    /// The token is not registered in native token vaults on both L1 and L2.
+    MissingRegistration,
+    /// The token is registered on L2 with an asset ID different from the expected one.
+    AssetIdMismatch,
+}
+
+impl TokenStatus {
+    fn needs_l2_registration(self) -> bool {
+        matches!(
+            self,
+            Self::MissingL2Registration | Self::MissingRegistration
+        )
+    }
+
+    fn needs_l1_registration(self) -> bool {
+        matches!(
+            self,
+            Self::MissingL1Registration | Self::MissingRegistration
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TokenAuditEntry {
+    l2_address: Address,
+    l1_address: Address,
+    expected_asset_id: Option<H256>,
+    l2_asset_id: H256,
+    l1_asset_id: H256,
+    status: TokenStatus,
+}
+
+#[derive(Debug, Serialize)]
+struct RegistrationCall {
+    description: String,
+    target: Address,
+    #[serde(serialize_with = "serialize_hex")]
+    data: Vec<u8>,
+    value: U256,
+}
+
+fn serialize_hex<S>(bytes: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let hex_string = format!("0x{}", hex::encode(bytes));
+    serializer.serialize_str(&hex_string)
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct GatewayTokenAuditArgs {
+    chain_id: u64,
+    l1_rpc_url: String,
+    l2_rpc_url: String,
+    /// Address of the native token vault on L1.
+    l1_native_token_vault: Address,
+    l2_tokens_indexing_block_range: Option<u64>,
+    /// Print the calldata needed to register the tokens missing migration.
+    #[clap(long, default_missing_value = "true", num_args = 0..=1)]
+    emit_calldata: Option<bool>,
+    /// Maximum number of registration calls in a single printed batch.
+    #[clap(long, default_value_t = 50)]
+    calls_per_batch: usize,
+}
+
+async fn audit_tokens(args: &GatewayTokenAuditArgs) -> anyhow::Result<Vec<TokenAuditEntry>> {
+    let l2_client = get_zk_client(&args.l2_rpc_url, args.chain_id)?;
+    check_l2_ntv_existence(&l2_client).await?;
+
+    let l1_provider = get_ethers_provider(&args.l1_rpc_url)?;
+    let l2_provider = get_ethers_provider(&args.l2_rpc_url)?;
+    let l1_chain_id = l1_provider.get_chainid().await?;
+
+    let l2_native_token_vault =
+        L2NativeTokenVaultAbi::new(L2_NATIVE_TOKEN_VAULT_ADDRESS, l2_provider.clone());
+    let l2_legacy_shared_bridge_addr = l2_native_token_vault.l2_legacy_shared_bridge().await?;
+    if l2_legacy_shared_bridge_addr == Address::zero() {
+        println!("Chain does not have a legacy bridge. Nothing to audit");
+        return Ok(vec![]);
+    }
+    let l2_legacy_shared_bridge =
+        L2LegacySharedBridgeAbi::new(l2_legacy_shared_bridge_addr, l2_provider);
+    let l1_native_token_vault =
+        L1NativeTokenVaultAbi::new(args.l1_native_token_vault, Arc::clone(&l1_provider));
+
+    let all_tokens = get_deployed_by_bridge(
+        &args.l2_rpc_url,
+        l2_legacy_shared_bridge_addr,
+        args.l2_tokens_indexing_block_range
+            .unwrap_or(DEFAULT_BLOCK_RANGE),
+    )
+    .await?;
+
+    let mut entries = Vec::with_capacity(all_tokens.len());
+    for token in all_tokens {
+        let l1_address = l2_legacy_shared_bridge.l_1_token_address(token).await?;
+        let l2_asset_id = H256(l2_native_token_vault.asset_id(token).await?);
+        if l1_address == Address::zero() {
+            entries.push(TokenAuditEntry {
+                l2_address: token,
+                l1_address,
+                expected_asset_id: None,
+                l2_asset_id,
+                l1_asset_id: H256::zero(),
+                status: TokenStatus::NotBridgedToken,
+            });
+            continue;
+        }
+
+        let expected_asset_id = encode_ntv_asset_id(l1_chain_id, l1_address);
+        let l1_asset_id = H256(l1_native_token_vault.asset_id(l1_address).await?);
+        let status = match (l2_asset_id.is_zero(), l1_asset_id.is_zero()) {
+            (false, _) if l2_asset_id != expected_asset_id => TokenStatus::AssetIdMismatch,
+            (true, true) => TokenStatus::MissingRegistration,
+            (true, false) => TokenStatus::MissingL2Registration,
+            (false, true) => TokenStatus::MissingL1Registration,
+            (false, false) => TokenStatus::Migrated,
+        };
+        entries.push(TokenAuditEntry {
+            l2_address: token,
+            l1_address,
+            expected_asset_id: Some(expected_asset_id),
+            l2_asset_id,
+            l1_asset_id,
+            status,
+        });
+    }
+    Ok(entries)
+}
+
+fn registration_calls(
+    entries: &[TokenAuditEntry],
+    l1_native_token_vault: Address,
+) -> (Vec<RegistrationCall>, Vec<RegistrationCall>) {
+    let l2_ntv_abi = BaseContract::from(
+        parse_abi(&["function setLegacyTokenAssetId(address _l2TokenAddress) external"]).unwrap(),
+    );
+    let l1_ntv_abi = BaseContract::from(
+        parse_abi(&["function registerToken(address _nativeToken) external"]).unwrap(),
+    );
+
+    let l2_calls = entries
+        .iter()
+        .filter(|entry| entry.status.needs_l2_registration())
+        .map(|entry| RegistrationCall {
+            description: format!("Registering legacy L2 token {:#?}", entry.l2_address),
+            target: L2_NATIVE_TOKEN_VAULT_ADDRESS,
+            data: l2_ntv_abi
+                .encode("setLegacyTokenAssetId", entry.l2_address)
+                .unwrap()
+                .to_vec(),
+            value: U256::zero(),
+        })
+        .collect();
+    let l1_calls = entries
+        .iter()
+        .filter(|entry| entry.status.needs_l1_registration())
+        .map(|entry| RegistrationCall {
+            description: format!("Registering L1 token {:#?}", entry.l1_address),
+            target: l1_native_token_vault,
+            data: l1_ntv_abi
+                .encode("registerToken", entry.l1_address)
+                .unwrap()
+                .to_vec(),
+            value: U256::zero(),
+        })
+        .collect();
+    (l2_calls, l1_calls)
+}
+
+fn print_batches(
+    layer: &str,
+    calls: &[RegistrationCall],
+    calls_per_batch: usize,
+) -> anyhow::Result<()> {
+    if calls.is_empty() {
+        println!("No {layer} registration calls are needed");
+        return Ok(());
+    }
+    for (i, batch) in calls.chunks(calls_per_batch.max(1)).enumerate() {
+        println!("{layer} registration calls, batch #{i}:");
+        println!("{}", serde_json::to_string_pretty(batch)?);
+    }
+    Ok(())
+}
+
+pub(crate) async fn run(args: GatewayTokenAuditArgs) -> anyhow::Result<()> {
+    println!("Auditing tokens deployed by the legacy shared bridge...");
+    let entries = audit_tokens(&args).await?;
+
+    for entry in &entries {
+        println!(
+            "{:#?} (L1: {:#?}): {}",
+            entry.l2_address, entry.l1_address, entry.status
+        );
+    }
+    let missing_migration: Vec<_> = entries
+        .iter()
+        .filter(|entry| {
+            !matches!(
+                entry.status,
+                TokenStatus::Migrated | TokenStatus::NotBridgedToken
+            )
+        })
+        .collect();
+    println!(
+        "Audited {} addresses; {} token(s) are missing migration",
+        entries.len(),
+        missing_migration.len()
+    );
+    if !missing_migration.is_empty() {
+        println!("{}", serde_json::to_string_pretty(&missing_migration)?);
+    }
+
+    if args.emit_calldata.unwrap_or_default() {
+        let (l2_calls, l1_calls) = registration_calls(&entries, args.l1_native_token_vault);
+        print_batches("L2", &l2_calls, args.calls_per_batch)?;
+        print_batches("L1", &l1_calls, args.calls_per_batch)?;
+    }
+
+    let mismatched = entries
+        .iter()
+        .filter(|entry| entry.status == TokenStatus::AssetIdMismatch)
+        .count();
+    anyhow::ensure!(
+        mismatched == 0,
+        "{mismatched} token(s) are registered on L2 with unexpected asset IDs; they cannot be fixed by registration calls"
+    );
+    Ok(())
+}
@@ -1,6 +1,8 @@
+pub mod apps;
 pub mod clean;
 pub mod config_writer;
 pub mod contracts;
+pub mod contracts_diff;
 pub mod database;
 #[cfg(feature = "gateway")]
 pub(crate) mod events_gatherer;
@@ -11,10 +13,15 @@ pub mod gateway;
 pub mod gateway_finalize_preparation;
 #[cfg(feature = "gateway")]
 pub mod gateway_register_l2_tokens;
+#[cfg(feature = "gateway")]
+pub mod gateway_token_audit;
 pub mod genesis;
 pub mod lint;
 pub(crate) mod lint_utils;
+pub mod observability;
 pub mod prover;
+#[cfg(feature = "gateway")]
+pub mod rotate_validators;
 pub mod send_transactions;
 pub mod snapshot;
 pub(crate) mod sql_fmt;
@@ -0,0 +1,307 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use serde_json::json;
+use xshell::Shell;
+use zkstack_cli_common::{docker, logger, spinner::Spinner};
+use zkstack_cli_config::{
+    docker_compose::{DockerComposeConfig, DockerComposeService},
+    raw::RawConfig,
+    traits::SaveConfig,
+    EcosystemConfig, GENERAL_FILE,
+};
+
+use crate::commands::dev::messages::{
+    msg_observability_started, msg_observability_targets, MSG_OBSERVABILITY_DOWN_ABOUT,
+    MSG_OBSERVABILITY_GRAFANA_PORT_HELP, MSG_OBSERVABILITY_NOT_SET_UP,
+    MSG_OBSERVABILITY_NO_TARGETS_WARN, MSG_OBSERVABILITY_PROMETHEUS_PORT_HELP,
+    MSG_OBSERVABILITY_STARTING_SPINNER, MSG_OBSERVABILITY_STOPPED, MSG_OBSERVABILITY_UP_ABOUT,
+};
+
+/// Directory (relative to the ecosystem root) with the generated observability configs.
+const OBSERVABILITY_DIR: &str = "observability";
+const COMPOSE_FILE: &str = "docker-compose.yml";
+const PROMETHEUS_CONFIG_FILE: &str = "prometheus.yml";
+const PROMETHEUS_DOCKER_IMAGE: &str = "prom/prometheus:v2.53.0";
+const GRAFANA_DOCKER_IMAGE: &str = "grafana/grafana:11.1.0";
+/// Host name under which the host network is reachable from the containers.
+const DOCKER_HOST: &str = "host.docker.internal";
+const SCRAPE_INTERVAL: &str = "5s";
+
+/// Dashboards provisioned to Grafana.
+const DASHBOARDS: [(&str, &str); 3] = [
+    ("api.json", include_str!("dashboards/api.json")),
+    ("prover.json", include_str!("dashboards/prover.json")),
+    (
+        "eth_sender.json",
+        include_str!("dashboards/eth_sender.json"),
+    ),
+];
+
+/// Components exposing Prometheus metrics, together with paths of their metrics ports
+/// in the chain general config.
+const CHAIN_COMPONENTS: [(&str, &str); 8] = [
+    ("server", "api.prometheus.listener_port"),
+    ("contract_verifier", "contract_verifier.prometheus_port"),
+    ("prover_gateway", "prover_gateway.prometheus_listener_port"),
+    (
+        "witness_generator",
+        "witness_generator.prometheus_listener_port",
+    ),
+    (
+        "witness_vector_generator",
+        "witness_vector_generator.prometheus_listener_port",
+    ),
+    ("prover", "prover.prometheus_port"),
+    (
+        "proof_compressor",
+        "proof_compressor.prometheus_listener_port",
+    ),
+    ("prover_job_monitor", "prover_job_monitor.prometheus_port"),
+];
+/// Path of the metrics port in the external node general config.
+const EXTERNAL_NODE_PORT_PATH: &str = "api.prometheus.listener_port";
+
+#[derive(Subcommand, Debug)]
+pub enum ObservabilityCommands {
+    #[command(about = MSG_OBSERVABILITY_UP_ABOUT)]
+    Up(ObservabilityUpArgs),
+    #[command(about = MSG_OBSERVABILITY_DOWN_ABOUT)]
+    Down,
+}
+
+#[derive(Debug, Parser)]
+pub struct ObservabilityUpArgs {
+    #[clap(long, default_value_t = 9090, help = MSG_OBSERVABILITY_PROMETHEUS_PORT_HELP)]
+    pub prometheus_port: u16,
+    #[clap(long, default_value_t = 3000, help = MSG_OBSERVABILITY_GRAFANA_PORT_HELP)]
+    pub grafana_port: u16,
+}
+
+/// Metrics endpoint of a locally running component.
+#[derive(Debug)]
+struct ScrapeTarget {
+    chain: String,
+    component: &'static str,
+    port: u16,
+}
+
+pub async fn run(shell: &Shell, args: ObservabilityCommands) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    let dir = shell.current_dir().join(OBSERVABILITY_DIR);
+    match args {
+        ObservabilityCommands::Up(args) => up(shell, &ecosystem, &dir, args).await,
+        ObservabilityCommands::Down => down(shell, &dir),
+    }
+}
+
+async fn up(
+    shell: &Shell,
+    ecosystem: &EcosystemConfig,
+    dir: &Path,
+    args: ObservabilityUpArgs,
+) -> anyhow::Result<()> {
+    let targets = collect_targets(shell, ecosystem).await?;
+    if targets.is_empty() {
+        logger::warn(MSG_OBSERVABILITY_NO_TARGETS_WARN);
+    } else {
+        let chains: HashSet<_> = targets.iter().map(|t| &t.chain).collect();
+        logger::info(msg_observability_targets(targets.len(), chains.len()));
+    }
+
+    write_prometheus_config(shell, dir, &targets)?;
+    write_grafana_provisioning(shell, dir)?;
+    compose_config(&args).save(shell, dir.join(COMPOSE_FILE))?;
+
+    let spinner = Spinner::new(MSG_OBSERVABILITY_STARTING_SPINNER);
+    docker::up(shell, &path_to_str(&dir.join(COMPOSE_FILE))?, true)?;
+    spinner.finish();
+
+    logger::outro(msg_observability_started(
+        args.prometheus_port,
+        args.grafana_port,
+    ));
+    Ok(())
+}
+
+fn down(shell: &Shell, dir: &Path) -> anyhow::Result<()> {
+    let compose_file = dir.join(COMPOSE_FILE);
+    if !shell.path_exists(&compose_file) {
+        logger::info(MSG_OBSERVABILITY_NOT_SET_UP);
+        return Ok(());
+    }
+    docker::down(shell, &path_to_str(&compose_file)?)?;
+    logger::outro(MSG_OBSERVABILITY_STOPPED);
+    Ok(())
+}
+
+/// Collects metrics endpoints of all chains in the ecosystem from their general configs.
+/// Components without a configured metrics port are skipped.
+async fn collect_targets(
+    shell: &Shell,
+    ecosystem: &EcosystemConfig,
+) -> anyhow::Result<Vec<ScrapeTarget>> {
+    let mut targets = vec![];
+    for chain_name in ecosystem.list_of_chains() {
+        let chain = ecosystem
+            .load_chain(Some(chain_name.clone()))
+            .with_context(|| format!("failed loading chain `{chain_name}`"))?;
+        let general = chain.get_general_config().await?;
+        for (component, path) in CHAIN_COMPONENTS {
+            if let Some(port) = general.get_opt::<u16>(path)? {
+                targets.push(ScrapeTarget {
+                    chain: chain_name.clone(),
+                    component,
+                    port,
+                });
+            }
+        }
+
+        let Some(en_configs_path) = &chain.external_node_config_path else {
+            continue;
+        };
+        let en_general_path = en_configs_path.join(GENERAL_FILE);
+        if !shell.path_exists(&en_general_path) {
+            continue;
+        }
+        let en_general = RawConfig::read(shell, en_general_path).await?;
+        if let Some(port) = en_general.get_opt::<u16>(EXTERNAL_NODE_PORT_PATH)? {
+            targets.push(ScrapeTarget {
+                chain: chain_name.clone(),
+                component: "external_node",
+                port,
+            });
+        }
+    }
+    Ok(targets)
+}
+
+fn write_prometheus_config(
+    shell: &Shell,
+    dir: &Path,
+    targets: &[ScrapeTarget],
+) -> anyhow::Result<()> {
+    let mut jobs: Vec<(&str, Vec<serde_json::Value>)> = vec![];
+    for target in targets {
+        let static_config = json!({
+            "targets": [format!("{DOCKER_HOST}:{}", target.port)],
+            "labels": { "chain": target.chain },
+        });
+        match jobs.iter_mut().find(|(job, _)| *job == target.component) {
+            Some((_, configs)) => configs.push(static_config),
+            None => jobs.push((target.component, vec![static_config])),
+        }
+    }
+    let scrape_configs: Vec<_> = jobs
+        .into_iter()
+        .map(|(job, static_configs)| json!({ "job_name": job, "static_configs": static_configs }))
+        .collect();
+    let config = json!({
+        "global": { "scrape_interval": SCRAPE_INTERVAL },
+        "scrape_configs": scrape_configs,
+    });
+
+    shell.create_dir(dir)?;
+    shell.write_file(
+        dir.join(PROMETHEUS_CONFIG_FILE),
+        serde_yaml::to_string(&config)?,
+    )?;
+    Ok(())
+}
+
+fn write_grafana_provisioning(shell: &Shell, dir: &Path) -> anyhow::Result<()> {
+    let provisioning = dir.join("grafana/provisioning");
+    let datasources = json!({
+        "apiVersion": 1,
+        "datasources": [{
+            "name": "Prometheus",
+            "uid": "prometheus",
+            "type": "prometheus",
+            "access": "proxy",
+            "url": "http://prometheus:9090",
+            "isDefault": true,
+        }],
+    });
+    shell.create_dir(provisioning.join("datasources"))?;
+    shell.write_file(
+        provisioning.join("datasources/prometheus.yml"),
+        serde_yaml::to_string(&datasources)?,
+    )?;
+
+    let dashboards = json!({
+        "apiVersion": 1,
+        "providers": [{
+            "name": "zkstack",
+            "type": "file",
+            "options": { "path": "/var/lib/grafana/dashboards" },
+        }],
+    });
+    shell.create_dir(provisioning.join("dashboards"))?;
+    shell.write_file(
+        provisioning.join("dashboards/zkstack.yml"),
+        serde_yaml::to_string(&dashboards)?,
+    )?;
+
+    let dashboards_dir = dir.join("grafana/dashboards");
+    shell.create_dir(&dashboards_dir)?;
+    for (file_name, contents) in DASHBOARDS {
+        shell.write_file(dashboards_dir.join(file_name), contents)?;
+    }
+    Ok(())
+}
+
+fn compose_config(args: &ObservabilityUpArgs) -> DockerComposeConfig {
+    let prometheus = DockerComposeService {
+        image: PROMETHEUS_DOCKER_IMAGE.to_string(),
+        platform: None,
+        ports: Some(vec![format!("{}:9090", args.prometheus_port)]),
+        environment: None,
+        volumes: Some(vec![format!(
+            "./{PROMETHEUS_CONFIG_FILE}:/etc/prometheus/prometheus.yml:ro"
+        )]),
+        depends_on: None,
+        restart: Some("unless-stopped".to_string()),
+        extra_hosts: Some(vec![format!("{DOCKER_HOST}:host-gateway")]),
+        other: serde_json::Value::Null,
+    };
+    let grafana = DockerComposeService {
+        image: GRAFANA_DOCKER_IMAGE.to_string(),
+        platform: None,
+        ports: Some(vec![format!("{}:3000", args.grafana_port)]),
+        environment: Some(HashMap::from([
+            ("GF_AUTH_ANONYMOUS_ENABLED".to_string(), "true".to_string()),
+            (
+                "GF_AUTH_ANONYMOUS_ORG_ROLE".to_string(),
+                "Admin".to_string(),
+            ),
+            ("GF_AUTH_DISABLE_LOGIN_FORM".to_string(), "true".to_string()),
+        ])),
+        volumes: Some(vec![
+            "./grafana/provisioning:/etc/grafana/provisioning:ro".to_string(),
+            "./grafana/dashboards:/var/lib/grafana/dashboards:ro".to_string(),
+        ]),
+        depends_on: Some(vec!["prometheus".to_string()]),
+        restart: Some("unless-stopped".to_string()),
+        extra_hosts: None,
+        other: serde_json::Value::Null,
+    };
+
+    let mut config = DockerComposeConfig {
+        services: HashMap::new(),
+        name: Some("zkstack-observability".to_string()),
+        other: serde_json::Value::Null,
+    };
+    config.add_service("prometheus", prometheus);
+    config.add_service("grafana", grafana);
+    config
+}
+
+fn path_to_str(path: &Path) -> anyhow::Result<String> {
+    path.to_str()
+        .map(str::to_owned)
+        .with_context(|| format!("non-UTF8 path: {path:?}"))
+}
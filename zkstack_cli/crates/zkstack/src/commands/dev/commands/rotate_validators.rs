@@ -0,0 +1,64 @@
+use clap::Parser;
+use ethers::utils::hex;
+use zksync_types::Address;
+
+use super::gateway::AdminCallBuilder;
+
+/// Generates `ChainAdmin` calldata rotating validators (i.e., operator keys) of a chain in the validator timelock.
+///
+/// Rotation is performed in two steps, so that both old and new keys stay valid while transactions signed
+/// by the old keys are in flight:
+///
+/// 1. New operators are added with `--add`. After the calls are executed, the server is restarted with the new keys
+///    as `operator` / `blob_operator` and the old ones as `previous_operator` / `previous_blob_operator`.
+/// 2. Once all transactions signed by the old keys are confirmed, old operators are removed with `--remove`,
+///    and the previous operator keys can be removed from the server config.
+#[derive(Parser, Debug, Clone)]
+pub struct RotateValidatorsCalldataArgs {
+    chain_id: u64,
+    validator_timelock_addr: Address,
+    /// Validators to add to the timelock.
+    #[clap(long = "add")]
+    added_validators: Vec<Address>,
+    /// Validators to remove from the timelock.
+    #[clap(long = "remove")]
+    removed_validators: Vec<Address>,
+}
+
+impl RotateValidatorsCalldataArgs {
+    fn admin_call_builder(&self) -> anyhow::Result<AdminCallBuilder> {
+        anyhow::ensure!(
+            !self.added_validators.is_empty() || !self.removed_validators.is_empty(),
+            "no validators to add or remove; specify them with `--add` / `--remove`"
+        );
+        if let Some(validator) = self
+            .added_validators
+            .iter()
+            .find(|addr| self.removed_validators.contains(addr))
+        {
+            anyhow::bail!("validator {validator:?} is both added and removed");
+        }
+
+        let mut builder = AdminCallBuilder::new();
+        // Validators are added before removing the old ones, so that the chain always has valid operators.
+        for &validator in &self.added_validators {
+            builder.append_validator(self.chain_id, self.validator_timelock_addr, validator);
+        }
+        for &validator in &self.removed_validators {
+            builder.append_remove_validator(self.chain_id, self.validator_timelock_addr, validator);
+        }
+        Ok(builder)
+    }
+}
+
+pub(crate) fn run(args: RotateValidatorsCalldataArgs) -> anyhow::Result<()> {
+    let admin_calls = args.admin_call_builder()?;
+    admin_calls.display();
+
+    let chain_admin_calldata = admin_calls.compile_full_calldata();
+    println!(
+        "Full calldata to call `ChainAdmin` with : {}",
+        hex::encode(&chain_admin_calldata)
+    );
+    Ok(())
+}
@@ -12,6 +12,8 @@ pub(super) const MSG_SUBCOMMAND_TESTS_ABOUT: &str = "Run tests";
 pub(super) const MSG_SUBCOMMAND_CLEAN: &str = "Clean artifacts";
 pub(super) const MSG_SUBCOMMAND_LINT_ABOUT: &str = "Lint code";
 pub(super) const MSG_CONTRACTS_ABOUT: &str = "Build contracts";
+pub(super) const MSG_CONTRACTS_DIFF_ABOUT: &str =
+    "Compare system contracts between two zksync-era checkouts before an upgrade";
 pub(super) const MSG_CONFIG_WRITER_ABOUT: &str = "Overwrite general config";
 #[cfg(feature = "gateway")]
 pub(super) const MSG_GATEWAY_UPGRADE_CALLDATA: &str =
@@ -22,6 +24,14 @@ pub(super) const MSG_GATEWAY_FINALIZE: &str = "Gateway upgrade post-stage2 final
 #[cfg(feature = "gateway")]
 pub(super) const MSG_GATEWAY_REGISTER_L2_TOKENS: &str = "Gateway register legacy tokens on L2";
 
+#[cfg(feature = "gateway")]
+pub(super) const MSG_GATEWAY_TOKEN_AUDIT: &str =
+    "Gateway audit of legacy shared bridge tokens missing native token vault migration";
+
+#[cfg(feature = "gateway")]
+pub(super) const MSG_ROTATE_VALIDATORS_CALLDATA: &str =
+    "Generate calldata adding and removing validators during operator key rotation";
+
 pub(super) const MSG_SUBCOMMAND_FMT_ABOUT: &str = "Format code";
 
 pub(super) const MSG_SUBCOMMAND_SNAPSHOTS_CREATOR_ABOUT: &str = "Snapshots creator";
@@ -75,6 +85,23 @@ pub(super) const MSG_DATABASE_RESET_ABOUT: &str =
     "Reset databases. If no databases are selected, all databases will be reset.";
 pub(super) const MSG_DATABASE_SETUP_ABOUT: &str =
     "Setup databases. If no databases are selected, all databases will be setup.";
+pub(super) const MSG_DATABASE_SYNC_CHAINS_ABOUT: &str =
+    "Register protocol versions of ecosystem chains in the prover databases serving them.";
+pub(super) const MSG_DATABASE_SYNC_CHAINS_CHAINS_HELP: &str =
+    "Comma-separated names of the chains to sync. If not specified, all ecosystem chains are synced.";
+pub(super) const MSG_DATABASE_SYNC_CHAINS_SUCCESS: &str =
+    "Prover databases are in sync with chains";
+
+pub(super) fn msg_database_sync_chains_skipped(chain_name: &str) -> String {
+    format!("Chain {chain_name} doesn't have a prover database configured, skipping")
+}
+
+pub(super) fn msg_database_sync_chains_version(version: &str, chain_names: &[String]) -> String {
+    format!(
+        "Registering protocol version {version} for chain(s) {}...",
+        chain_names.join(", ")
+    )
+}
 
 // Database new_migration messages
 pub(super) const MSG_DATABASE_NEW_MIGRATION_DB_PROMPT: &str =
@@ -124,6 +151,20 @@ pub(super) const MSG_BUILD_L1_DA_CONTRACTS_HELP: &str = "Build L1 DA contracts";
 pub(super) const MSG_BUILD_L2_CONTRACTS_HELP: &str = "Build L2 contracts";
 pub(super) const MSG_BUILD_SYSTEM_CONTRACTS_HELP: &str = "Build system contracts";
 
+// Contracts diff related messages
+pub(super) const MSG_CONTRACTS_DIFF_OLD_PATH_HELP: &str =
+    "Path to the zksync-era checkout with the currently deployed contracts";
+pub(super) const MSG_CONTRACTS_DIFF_NEW_PATH_HELP: &str =
+    "Path to the zksync-era checkout with the proposed contracts [default: ecosystem link_to_code]";
+pub(super) const MSG_CONTRACTS_DIFF_SKIP_BUILD_HELP: &str =
+    "Use existing artifacts instead of building system contracts";
+pub(super) const MSG_CONTRACTS_DIFF_JSON_HELP: &str = "Print the report as JSON";
+pub(super) const MSG_CONTRACTS_DIFF_NO_CHANGES: &str = "System contracts are unchanged";
+
+pub(super) fn msg_contracts_diff_unsafe_storage(count: usize) -> String {
+    format!("Found {count} storage layout change(s) that may corrupt existing contract state")
+}
+
 // Integration tests related messages
 pub(super) fn msg_integration_tests_run(external_node: bool) -> String {
     let base = "Running integration tests";
@@ -260,3 +301,52 @@ pub(super) fn msg_not_ready_components(components: &str) -> String {
 
 // Genesis
 pub(super) const MSG_GENESIS_FILE_GENERATION_STARTED: &str = "Regenerate genesis file";
+
+// Observability related messages
+pub(super) const MSG_OBSERVABILITY_ABOUT: &str =
+    "Run Prometheus and Grafana scraping metrics of locally running components";
+pub(super) const MSG_OBSERVABILITY_UP_ABOUT: &str =
+    "Generate scrape configs and start Prometheus and Grafana containers";
+pub(super) const MSG_OBSERVABILITY_DOWN_ABOUT: &str = "Stop Prometheus and Grafana containers";
+pub(super) const MSG_OBSERVABILITY_PROMETHEUS_PORT_HELP: &str = "Host port to expose Prometheus on";
+pub(super) const MSG_OBSERVABILITY_GRAFANA_PORT_HELP: &str = "Host port to expose Grafana on";
+pub(super) const MSG_OBSERVABILITY_NO_TARGETS_WARN: &str =
+    "No metrics endpoints found in chain configs; Prometheus will have no targets";
+pub(super) const MSG_OBSERVABILITY_STARTING_SPINNER: &str =
+    "Starting Prometheus and Grafana containers...";
+pub(super) const MSG_OBSERVABILITY_STOPPED: &str = "Prometheus and Grafana containers stopped";
+pub(super) const MSG_OBSERVABILITY_NOT_SET_UP: &str =
+    "Observability stack is not set up, nothing to stop";
+
+pub(super) fn msg_observability_targets(count: usize, chains: usize) -> String {
+    format!("Generated scrape config with {count} targets for {chains} chain(s)")
+}
+
+pub(super) fn msg_observability_started(prometheus_port: u16, grafana_port: u16) -> String {
+    format!(
+        "Observability stack is running: Prometheus at http://localhost:{prometheus_port}, \
+         Grafana at http://localhost:{grafana_port}"
+    )
+}
+
+// Portal and explorer related messages
+pub(super) const MSG_PORTAL_ABOUT: &str =
+    "Run the bridge portal against local chains using a generated docker-compose file";
+pub(super) const MSG_EXPLORER_ABOUT: &str =
+    "Run the block explorer against local chains using generated docker-compose files";
+pub(super) const MSG_APP_UP_ABOUT: &str =
+    "Render docker-compose file from chain configs and start containers";
+pub(super) const MSG_APP_DOWN_ABOUT: &str = "Stop containers started by `up`";
+pub(super) const MSG_APP_STARTING_SPINNER: &str = "Starting containers...";
+
+pub(super) fn msg_app_started(app: &str, port: u16) -> String {
+    format!("{app} is running at http://localhost:{port}")
+}
+
+pub(super) fn msg_app_stopped(app: &str) -> String {
+    format!("{app} containers stopped")
+}
+
+pub(super) fn msg_app_not_set_up(app: &str) -> String {
+    format!("{app} is not set up, nothing to stop")
+}
@@ -3,17 +3,29 @@ use commands::status::args::StatusArgs;
 use messages::MSG_STATUS_ABOUT;
 #[cfg(feature = "gateway")]
 use messages::{
-    MSG_GATEWAY_FINALIZE, MSG_GATEWAY_REGISTER_L2_TOKENS, MSG_GATEWAY_UPGRADE_CALLDATA,
+    MSG_GATEWAY_FINALIZE, MSG_GATEWAY_REGISTER_L2_TOKENS, MSG_GATEWAY_TOKEN_AUDIT,
+    MSG_GATEWAY_UPGRADE_CALLDATA, MSG_ROTATE_VALIDATORS_CALLDATA,
 };
 use xshell::Shell;
 
 use self::commands::{
-    clean::CleanCommands, config_writer::ConfigWriterArgs, contracts::ContractsArgs,
-    database::DatabaseCommands, fmt::FmtArgs, lint::LintArgs, prover::ProverCommands,
-    send_transactions::args::SendTransactionsArgs, snapshot::SnapshotCommands, test::TestCommands,
+    apps::{App, AppCommands},
+    clean::CleanCommands,
+    config_writer::ConfigWriterArgs,
+    contracts::ContractsArgs,
+    contracts_diff::ContractsDiffArgs,
+    database::DatabaseCommands,
+    fmt::FmtArgs,
+    lint::LintArgs,
+    observability::ObservabilityCommands,
+    prover::ProverCommands,
+    send_transactions::args::SendTransactionsArgs,
+    snapshot::SnapshotCommands,
+    test::TestCommands,
 };
 use crate::commands::dev::messages::{
-    MSG_CONFIG_WRITER_ABOUT, MSG_CONTRACTS_ABOUT, MSG_GENERATE_GENESIS_ABOUT,
+    MSG_CONFIG_WRITER_ABOUT, MSG_CONTRACTS_ABOUT, MSG_CONTRACTS_DIFF_ABOUT, MSG_EXPLORER_ABOUT,
+    MSG_GENERATE_GENESIS_ABOUT, MSG_OBSERVABILITY_ABOUT, MSG_PORTAL_ABOUT,
     MSG_PROVER_VERSION_ABOUT, MSG_SEND_TXNS_ABOUT, MSG_SUBCOMMAND_CLEAN,
     MSG_SUBCOMMAND_DATABASE_ABOUT, MSG_SUBCOMMAND_FMT_ABOUT, MSG_SUBCOMMAND_LINT_ABOUT,
     MSG_SUBCOMMAND_SNAPSHOTS_CREATOR_ABOUT, MSG_SUBCOMMAND_TESTS_ABOUT,
@@ -43,6 +55,8 @@ pub enum DevCommands {
     Prover(ProverCommands),
     #[command(about = MSG_CONTRACTS_ABOUT)]
     Contracts(ContractsArgs),
+    #[command(about = MSG_CONTRACTS_DIFF_ABOUT)]
+    ContractsDiff(ContractsDiffArgs),
     #[command(about = MSG_CONFIG_WRITER_ABOUT, alias = "o")]
     ConfigWriter(ConfigWriterArgs),
     #[command(about = MSG_SEND_TXNS_ABOUT)]
@@ -51,6 +65,12 @@ pub enum DevCommands {
     Status(StatusArgs),
     #[command(about = MSG_GENERATE_GENESIS_ABOUT, alias = "genesis")]
     GenerateGenesis,
+    #[command(subcommand, about = MSG_OBSERVABILITY_ABOUT)]
+    Observability(ObservabilityCommands),
+    #[command(subcommand, about = MSG_PORTAL_ABOUT)]
+    Portal(AppCommands),
+    #[command(subcommand, about = MSG_EXPLORER_ABOUT)]
+    Explorer(AppCommands),
     #[cfg(feature = "gateway")]
     #[command(about = MSG_GATEWAY_UPGRADE_CALLDATA)]
     GatewayUpgradeCalldata(commands::gateway::GatewayUpgradeCalldataArgs),
@@ -62,6 +82,12 @@ pub enum DevCommands {
     #[cfg(feature = "gateway")]
     #[command(about = MSG_GATEWAY_REGISTER_L2_TOKENS)]
     GatewayL2TokenRegistration(commands::gateway_register_l2_tokens::GatewayRegisterL2TokensArgs),
+    #[cfg(feature = "gateway")]
+    #[command(about = MSG_GATEWAY_TOKEN_AUDIT)]
+    GatewayTokenAudit(commands::gateway_token_audit::GatewayTokenAuditArgs),
+    #[cfg(feature = "gateway")]
+    #[command(about = MSG_ROTATE_VALIDATORS_CALLDATA)]
+    RotateValidatorsCalldata(commands::rotate_validators::RotateValidatorsCalldataArgs),
 }
 
 pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
@@ -74,12 +100,18 @@ pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
         DevCommands::Fmt(args) => commands::fmt::run(shell.clone(), args).await?,
         DevCommands::Prover(command) => commands::prover::run(shell, command).await?,
         DevCommands::Contracts(args) => commands::contracts::run(shell, args)?,
+        DevCommands::ContractsDiff(args) => commands::contracts_diff::run(shell, args)?,
         DevCommands::ConfigWriter(args) => commands::config_writer::run(shell, args)?,
         DevCommands::SendTransactions(args) => {
             commands::send_transactions::run(shell, args).await?
         }
         DevCommands::Status(args) => commands::status::run(shell, args).await?,
         DevCommands::GenerateGenesis => commands::genesis::run(shell).await?,
+        DevCommands::Observability(command) => commands::observability::run(shell, command).await?,
+        DevCommands::Portal(command) => commands::apps::run(shell, App::Portal, command).await?,
+        DevCommands::Explorer(command) => {
+            commands::apps::run(shell, App::Explorer, command).await?
+        }
         #[cfg(feature = "gateway")]
         DevCommands::GatewayUpgradeCalldata(args) => commands::gateway::run(shell, args).await?,
         #[cfg(feature = "gateway")]
@@ -90,6 +122,10 @@ pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
         DevCommands::GatewayL2TokenRegistration(args) => {
             commands::gateway_register_l2_tokens::run(args).await?
         }
+        #[cfg(feature = "gateway")]
+        DevCommands::GatewayTokenAudit(args) => commands::gateway_token_audit::run(args).await?,
+        #[cfg(feature = "gateway")]
+        DevCommands::RotateValidatorsCalldata(args) => commands::rotate_validators::run(args)?,
     }
     Ok(())
 }
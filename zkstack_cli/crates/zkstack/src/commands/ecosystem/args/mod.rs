@@ -3,3 +3,4 @@ pub mod change_default;
 pub mod create;
 pub mod gateway_upgrade;
 pub mod init;
+pub mod upgrade_plan;
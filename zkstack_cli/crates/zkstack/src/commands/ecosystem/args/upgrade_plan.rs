@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use zkstack_cli_common::Prompt;
+use zkstack_cli_types::L1Network;
+
+use crate::{
+    defaults::LOCAL_RPC_URL,
+    messages::{
+        MSG_L1_RPC_URL_HELP, MSG_L1_RPC_URL_INVALID_ERR, MSG_L1_RPC_URL_PROMPT,
+        MSG_UPGRADE_PLAN_OUT_HELP, MSG_UPGRADE_PLAN_TARGET_HELP,
+    },
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Parser)]
+pub struct UpgradePlanArgs {
+    #[clap(long, help = MSG_UPGRADE_PLAN_TARGET_HELP)]
+    pub target: PathBuf,
+    #[clap(long, help = MSG_L1_RPC_URL_HELP)]
+    pub l1_rpc_url: Option<String>,
+    #[clap(long, short, help = MSG_UPGRADE_PLAN_OUT_HELP)]
+    pub out: Option<PathBuf>,
+}
+
+impl UpgradePlanArgs {
+    pub fn fill_values_with_prompt(self, l1_network: L1Network) -> UpgradePlanArgsFinal {
+        let l1_rpc_url = self.l1_rpc_url.unwrap_or_else(|| {
+            let mut prompt = Prompt::new(MSG_L1_RPC_URL_PROMPT);
+            if l1_network == L1Network::Localhost {
+                prompt = prompt.default(LOCAL_RPC_URL);
+            }
+            prompt
+                .validate_with(|val: &String| -> Result<(), String> {
+                    Url::parse(val)
+                        .map(|_| ())
+                        .map_err(|_| MSG_L1_RPC_URL_INVALID_ERR.to_string())
+                })
+                .ask()
+        });
+        UpgradePlanArgsFinal {
+            target: self.target,
+            l1_rpc_url,
+            out: self.out,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpgradePlanArgsFinal {
+    pub target: PathBuf,
+    pub l1_rpc_url: String,
+    pub out: Option<PathBuf>,
+}
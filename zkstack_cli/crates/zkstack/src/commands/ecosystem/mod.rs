@@ -1,5 +1,6 @@
 use args::build_transactions::BuildTransactionsArgs;
 use clap::Subcommand;
+use upgrade::UpgradeCommands;
 use xshell::Shell;
 
 use crate::commands::ecosystem::args::{
@@ -16,6 +17,7 @@ pub mod create_configs;
 mod gateway_upgrade;
 pub(crate) mod init;
 pub(crate) mod setup_observability;
+mod upgrade;
 mod utils;
 
 #[derive(Subcommand, Debug)]
@@ -36,6 +38,9 @@ pub enum EcosystemCommands {
     /// downloading Grafana dashboards from the era-observability repo
     #[command(alias = "obs")]
     SetupObservability,
+    /// Ecosystem upgrade related commands
+    #[command(subcommand)]
+    Upgrade(UpgradeCommands),
     /// Gateway version upgrade
     #[cfg(feature = "gateway")]
     GatewayUpgrade(crate::commands::ecosystem::args::gateway_upgrade::GatewayUpgradeArgs),
@@ -48,6 +53,7 @@ pub(crate) async fn run(shell: &Shell, args: EcosystemCommands) -> anyhow::Resul
         EcosystemCommands::Init(args) => init::run(args, shell).await,
         EcosystemCommands::ChangeDefaultChain(args) => change_default::run(args, shell),
         EcosystemCommands::SetupObservability => setup_observability::run(shell),
+        EcosystemCommands::Upgrade(args) => upgrade::run(shell, args).await,
         #[cfg(feature = "gateway")]
         EcosystemCommands::GatewayUpgrade(args) => gateway_upgrade::run(args, shell).await,
     }
@@ -0,0 +1,19 @@
+use clap::Subcommand;
+use xshell::Shell;
+
+use crate::commands::ecosystem::args::upgrade_plan::UpgradePlanArgs;
+
+pub mod plan;
+
+#[derive(Subcommand, Debug)]
+pub enum UpgradeCommands {
+    /// Compare deployed contracts with the target protocol version
+    /// and generate an ordered upgrade plan
+    Plan(UpgradePlanArgs),
+}
+
+pub(crate) async fn run(shell: &Shell, args: UpgradeCommands) -> anyhow::Result<()> {
+    match args {
+        UpgradeCommands::Plan(args) => plan::run(args, shell).await,
+    }
+}
@@ -0,0 +1,582 @@
+use std::{cmp::Ordering, collections::BTreeSet, path::PathBuf, str::FromStr, sync::Arc};
+
+use anyhow::Context;
+use ethers::{
+    contract::abigen,
+    providers::{Http, Middleware, Provider},
+};
+use serde::{Deserialize, Serialize};
+use xshell::Shell;
+use zkstack_cli_common::{logger, spinner::Spinner};
+use zkstack_cli_config::{
+    forge_interface::script_params::{
+        FINALIZE_UPGRADE_SCRIPT_PARAMS, GATEWAY_UPGRADE_CHAIN_PARAMS,
+        GATEWAY_UPGRADE_ECOSYSTEM_PARAMS,
+    },
+    traits::{ReadConfig, SaveConfig, ZkStackConfig},
+    EcosystemConfig,
+};
+use zksync_types::{
+    h256_to_address, protocol_version::ProtocolSemanticVersion, Address, H256, U256,
+};
+
+use crate::{
+    commands::ecosystem::args::upgrade_plan::{UpgradePlanArgs, UpgradePlanArgsFinal},
+    messages::{
+        msg_chain_load_err, msg_upgrade_plan_saved, MSG_FETCHING_DEPLOYED_CONTRACTS_SPINNER,
+        MSG_UPGRADE_PLAN, MSG_UPGRADE_PLAN_NOTHING_TO_DO, MSG_UPGRADE_PLAN_WARNINGS,
+    },
+};
+
+/// Storage slot holding the implementation address of an EIP-1967 proxy.
+const EIP1967_IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+abigen!(
+    BridgehubAbi,
+    r"[
+    function getHyperchain(uint256)(address)
+]"
+);
+
+abigen!(
+    ChainTypeManagerAbi,
+    r"[
+    function protocolVersion()(uint256)
+]"
+);
+
+abigen!(
+    DiamondProxyAbi,
+    r"[
+    function getProtocolVersion()(uint256)
+    function facetAddresses()(address[])
+    function getVerifier()(address)
+]"
+);
+
+/// Contracts expected to be deployed for the target protocol version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradeTarget {
+    pub protocol_version: ProtocolSemanticVersion,
+    pub bridgehub_implementation: Address,
+    pub chain_type_manager_implementation: Address,
+    /// Facets of the diamond proxy, in any order.
+    pub facets: Vec<Address>,
+    pub verifier: Address,
+}
+
+impl ZkStackConfig for UpgradeTarget {}
+
+#[derive(Debug, Clone)]
+struct DeployedChain {
+    name: String,
+    diamond_proxy: Address,
+    chain_admin: Address,
+    protocol_version: ProtocolSemanticVersion,
+    facets: Vec<Address>,
+    verifier: Address,
+}
+
+#[derive(Debug, Clone)]
+struct DeployedState {
+    bridgehub_proxy: Address,
+    bridgehub_implementation: Address,
+    chain_type_manager_proxy: Address,
+    chain_type_manager_implementation: Address,
+    chain_type_manager_protocol_version: ProtocolSemanticVersion,
+    proxy_admin: Address,
+    chains: Vec<DeployedChain>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum UpgradeStepKind {
+    /// Forge script run by the ecosystem operator.
+    Script,
+    /// Call executed by the ecosystem governance.
+    GovernanceCall,
+    /// Call executed by the chain admin.
+    ChainAdminCall,
+    /// Restart of the chain server with the new protocol version.
+    ServerRestart,
+}
+
+/// Downtime of the affected chains caused by a step. Variants are ordered by severity.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExpectedDowntime {
+    None,
+    /// The chain server is unavailable while it restarts.
+    ServerRestart,
+    /// The chain cannot commit new batches until the upgrade transaction is executed.
+    UntilUpgradeExecuted,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpgradeStep {
+    pub kind: UpgradeStepKind,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<PathBuf>,
+    /// Contract called by the step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call: Option<String>,
+    pub downtime: ExpectedDowntime,
+}
+
+impl UpgradeStep {
+    fn script(script: PathBuf, description: impl Into<String>) -> Self {
+        Self {
+            kind: UpgradeStepKind::Script,
+            description: description.into(),
+            chain: None,
+            script: Some(script),
+            target: None,
+            call: None,
+            downtime: ExpectedDowntime::None,
+        }
+    }
+
+    fn call(
+        kind: UpgradeStepKind,
+        target: Address,
+        call: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            description: description.into(),
+            chain: None,
+            script: None,
+            target: Some(target),
+            call: Some(call.into()),
+            downtime: ExpectedDowntime::None,
+        }
+    }
+
+    fn for_chain(mut self, chain: &str) -> Self {
+        self.chain = Some(chain.to_owned());
+        self
+    }
+
+    fn with_downtime(mut self, downtime: ExpectedDowntime) -> Self {
+        self.downtime = downtime;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradePlan {
+    pub current_protocol_version: ProtocolSemanticVersion,
+    pub target_protocol_version: ProtocolSemanticVersion,
+    /// The most severe downtime among all steps.
+    pub expected_downtime: ExpectedDowntime,
+    pub steps: Vec<UpgradeStep>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl ZkStackConfig for UpgradePlan {}
+
+pub async fn run(args: UpgradePlanArgs, shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let args = args.fill_values_with_prompt(ecosystem_config.l1_network);
+    let target = UpgradeTarget::read(shell, &args.target)?;
+
+    let spinner = Spinner::new(MSG_FETCHING_DEPLOYED_CONTRACTS_SPINNER);
+    let deployed = fetch_deployed_state(&args, &ecosystem_config).await?;
+    spinner.finish();
+
+    let plan = build_upgrade_plan(&deployed, &target);
+    if plan.steps.is_empty() {
+        logger::info(MSG_UPGRADE_PLAN_NOTHING_TO_DO);
+    } else {
+        logger::note(MSG_UPGRADE_PLAN, format_steps(&plan.steps));
+    }
+    if !plan.warnings.is_empty() {
+        logger::warn(format!(
+            "{MSG_UPGRADE_PLAN_WARNINGS}\n{}",
+            plan.warnings.join("\n")
+        ));
+    }
+
+    if let Some(out) = &args.out {
+        plan.save(shell, out)?;
+        logger::outro(msg_upgrade_plan_saved(out));
+    }
+    Ok(())
+}
+
+fn decode_protocol_version(packed: U256) -> anyhow::Result<ProtocolSemanticVersion> {
+    ProtocolSemanticVersion::try_from_packed(packed)
+        .map_err(|err| anyhow::anyhow!("invalid packed protocol version {packed}: {err}"))
+}
+
+async fn get_implementation(provider: &Provider<Http>, proxy: Address) -> anyhow::Result<Address> {
+    let slot = H256::from_str(EIP1967_IMPLEMENTATION_SLOT)?;
+    let value = provider
+        .get_storage_at(proxy, slot, None)
+        .await
+        .with_context(|| format!("failed reading implementation of proxy {proxy:?}"))?;
+    Ok(h256_to_address(&value))
+}
+
+async fn fetch_deployed_state(
+    args: &UpgradePlanArgsFinal,
+    ecosystem_config: &EcosystemConfig,
+) -> anyhow::Result<DeployedState> {
+    let provider = Arc::new(
+        Provider::<Http>::try_from(args.l1_rpc_url.as_str())
+            .context("failed creating L1 provider")?,
+    );
+    let contracts = ecosystem_config.get_contracts_config()?.ecosystem_contracts;
+
+    let bridgehub_proxy = contracts.bridgehub_proxy_addr;
+    let chain_type_manager_proxy = contracts.state_transition_proxy_addr;
+    let bridgehub_implementation = get_implementation(&provider, bridgehub_proxy).await?;
+    let chain_type_manager_implementation =
+        get_implementation(&provider, chain_type_manager_proxy).await?;
+    let chain_type_manager = ChainTypeManagerAbi::new(chain_type_manager_proxy, provider.clone());
+    let chain_type_manager_protocol_version =
+        decode_protocol_version(chain_type_manager.protocol_version().await?)?;
+
+    let bridgehub = BridgehubAbi::new(bridgehub_proxy, provider.clone());
+    let mut chains = vec![];
+    for name in ecosystem_config.list_of_chains() {
+        let chain_config = ecosystem_config
+            .load_chain(Some(name.clone()))
+            .context(msg_chain_load_err(&name))?;
+        let diamond_proxy = bridgehub
+            .get_hyperchain(U256::from(chain_config.chain_id.as_u64()))
+            .await?;
+        if diamond_proxy == Address::zero() {
+            // The chain is created locally, but is not registered on L1 yet.
+            continue;
+        }
+        let chain_admin = chain_config.get_contracts_config()?.l1.chain_admin_addr;
+
+        let diamond = DiamondProxyAbi::new(diamond_proxy, provider.clone());
+        chains.push(DeployedChain {
+            name,
+            diamond_proxy,
+            chain_admin,
+            protocol_version: decode_protocol_version(diamond.get_protocol_version().await?)?,
+            facets: diamond.facet_addresses().await?,
+            verifier: diamond.get_verifier().await?,
+        });
+    }
+
+    Ok(DeployedState {
+        bridgehub_proxy,
+        bridgehub_implementation,
+        chain_type_manager_proxy,
+        chain_type_manager_implementation,
+        chain_type_manager_protocol_version,
+        proxy_admin: contracts.transparent_proxy_admin_addr,
+        chains,
+    })
+}
+
+/// Builds an ordered upgrade plan: ecosystem-wide steps go first, followed by upgrades
+/// of individual chains and finalization of the upgrade.
+fn build_upgrade_plan(deployed: &DeployedState, target: &UpgradeTarget) -> UpgradePlan {
+    let target_version = target.protocol_version;
+    let mut steps = vec![];
+    let mut warnings = vec![];
+
+    let new_version_required = deployed.chain_type_manager_protocol_version < target_version;
+    let bridgehub_outdated = deployed.bridgehub_implementation != target.bridgehub_implementation;
+    let chain_type_manager_outdated =
+        deployed.chain_type_manager_implementation != target.chain_type_manager_implementation;
+    let ecosystem_outdated =
+        new_version_required || bridgehub_outdated || chain_type_manager_outdated;
+    if deployed.chain_type_manager_protocol_version > target_version {
+        warnings.push(format!(
+            "Chain type manager is at protocol version {}, which is newer than the target {target_version}",
+            deployed.chain_type_manager_protocol_version
+        ));
+    }
+
+    if ecosystem_outdated {
+        steps.push(UpgradeStep::script(
+            GATEWAY_UPGRADE_ECOSYSTEM_PARAMS.script(),
+            format!("Deploy implementations for protocol version {target_version} and prepare governance calls"),
+        ));
+    }
+    if bridgehub_outdated {
+        steps.push(UpgradeStep::call(
+            UpgradeStepKind::GovernanceCall,
+            deployed.proxy_admin,
+            format!(
+                "upgrade({:?}, {:?})",
+                deployed.bridgehub_proxy, target.bridgehub_implementation
+            ),
+            "Upgrade bridgehub proxy implementation",
+        ));
+    }
+    if chain_type_manager_outdated {
+        steps.push(UpgradeStep::call(
+            UpgradeStepKind::GovernanceCall,
+            deployed.proxy_admin,
+            format!(
+                "upgrade({:?}, {:?})",
+                deployed.chain_type_manager_proxy, target.chain_type_manager_implementation
+            ),
+            "Upgrade chain type manager proxy implementation",
+        ));
+    }
+    if new_version_required {
+        steps.push(UpgradeStep::call(
+            UpgradeStepKind::GovernanceCall,
+            deployed.chain_type_manager_proxy,
+            "setNewVersionUpgrade(..)",
+            format!(
+                "Register upgrade from protocol version {} to {target_version} in the chain type manager",
+                deployed.chain_type_manager_protocol_version
+            ),
+        ));
+    }
+
+    let expected_facets: BTreeSet<_> = target.facets.iter().copied().collect();
+    for chain in &deployed.chains {
+        match chain.protocol_version.cmp(&target_version) {
+            Ordering::Less => {
+                if chain.protocol_version < deployed.chain_type_manager_protocol_version {
+                    warnings.push(format!(
+                        "Chain `{}` is at protocol version {}, behind the chain type manager ({}); \
+                         it must be upgraded to the current version before upgrading to {target_version}",
+                        chain.name,
+                        chain.protocol_version,
+                        deployed.chain_type_manager_protocol_version
+                    ));
+                }
+                steps.push(
+                    UpgradeStep::script(
+                        GATEWAY_UPGRADE_CHAIN_PARAMS.script(),
+                        "Prepare chain upgrade data",
+                    )
+                    .for_chain(&chain.name),
+                );
+                steps.push(
+                    UpgradeStep::call(
+                        UpgradeStepKind::ChainAdminCall,
+                        chain.diamond_proxy,
+                        format!(
+                            "upgradeChainFromVersion({}, ..)",
+                            chain.protocol_version.pack()
+                        ),
+                        format!(
+                            "Upgrade chain from protocol version {} to {target_version} via chain admin {:?}",
+                            chain.protocol_version, chain.chain_admin
+                        ),
+                    )
+                    .for_chain(&chain.name)
+                    .with_downtime(ExpectedDowntime::UntilUpgradeExecuted),
+                );
+                steps.push(UpgradeStep {
+                    kind: UpgradeStepKind::ServerRestart,
+                    description: format!(
+                        "Restart the server with protocol version {target_version}"
+                    ),
+                    chain: Some(chain.name.clone()),
+                    script: None,
+                    target: None,
+                    call: None,
+                    downtime: ExpectedDowntime::ServerRestart,
+                });
+            }
+            Ordering::Equal => {
+                let facets: BTreeSet<_> = chain.facets.iter().copied().collect();
+                if facets != expected_facets {
+                    warnings.push(format!(
+                        "Chain `{}` is at the target protocol version, but its facets differ from the expected ones",
+                        chain.name
+                    ));
+                }
+                if chain.verifier != target.verifier {
+                    warnings.push(format!(
+                        "Chain `{}` uses verifier {:?}, expected {:?}",
+                        chain.name, chain.verifier, target.verifier
+                    ));
+                }
+            }
+            Ordering::Greater => {
+                warnings.push(format!(
+                    "Chain `{}` is at protocol version {}, which is newer than the target {target_version}",
+                    chain.name, chain.protocol_version
+                ));
+            }
+        }
+    }
+
+    if ecosystem_outdated {
+        steps.push(UpgradeStep::script(
+            FINALIZE_UPGRADE_SCRIPT_PARAMS.script(),
+            "Finalize the upgrade",
+        ));
+    }
+
+    let expected_downtime = steps
+        .iter()
+        .map(|step| step.downtime)
+        .max()
+        .unwrap_or(ExpectedDowntime::None);
+    UpgradePlan {
+        current_protocol_version: deployed.chain_type_manager_protocol_version,
+        target_protocol_version: target_version,
+        expected_downtime,
+        steps,
+        warnings,
+    }
+}
+
+fn format_steps(steps: &[UpgradeStep]) -> String {
+    steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let mut line = format!("{}. [{}]", i + 1, step.kind);
+            if let Some(chain) = &step.chain {
+                line += &format!(" ({chain})");
+            }
+            line += &format!(" {}", step.description);
+            if let Some(script) = &step.script {
+                line += &format!(": {}", script.display());
+            }
+            if let (Some(target), Some(call)) = (&step.target, &step.call) {
+                line += &format!(": {target:?}.{call}");
+            }
+            if step.downtime != ExpectedDowntime::None {
+                line += &format!(" (downtime: {})", step.downtime);
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{protocol_version::VersionPatch, ProtocolVersionId};
+
+    use super::*;
+
+    fn version(minor: ProtocolVersionId) -> ProtocolSemanticVersion {
+        ProtocolSemanticVersion::new(minor, VersionPatch(0))
+    }
+
+    fn target() -> UpgradeTarget {
+        UpgradeTarget {
+            protocol_version: version(ProtocolVersionId::Version26),
+            bridgehub_implementation: Address::repeat_byte(0x10),
+            chain_type_manager_implementation: Address::repeat_byte(0x11),
+            facets: vec![Address::repeat_byte(0x12), Address::repeat_byte(0x13)],
+            verifier: Address::repeat_byte(0x14),
+        }
+    }
+
+    fn chain(name: &str, protocol_version: ProtocolSemanticVersion) -> DeployedChain {
+        DeployedChain {
+            name: name.to_owned(),
+            diamond_proxy: Address::repeat_byte(0x20),
+            chain_admin: Address::repeat_byte(0x21),
+            protocol_version,
+            facets: vec![Address::repeat_byte(0x13), Address::repeat_byte(0x12)],
+            verifier: Address::repeat_byte(0x14),
+        }
+    }
+
+    fn deployed(protocol_version: ProtocolSemanticVersion) -> DeployedState {
+        DeployedState {
+            bridgehub_proxy: Address::repeat_byte(1),
+            bridgehub_implementation: Address::repeat_byte(2),
+            chain_type_manager_proxy: Address::repeat_byte(3),
+            chain_type_manager_implementation: Address::repeat_byte(4),
+            chain_type_manager_protocol_version: protocol_version,
+            proxy_admin: Address::repeat_byte(5),
+            chains: vec![],
+        }
+    }
+
+    #[test]
+    fn up_to_date_ecosystem_has_empty_plan() {
+        let target = target();
+        let mut deployed = deployed(target.protocol_version);
+        deployed.bridgehub_implementation = target.bridgehub_implementation;
+        deployed.chain_type_manager_implementation = target.chain_type_manager_implementation;
+        deployed.chains = vec![chain("era", target.protocol_version)];
+
+        let plan = build_upgrade_plan(&deployed, &target);
+        assert!(plan.steps.is_empty(), "{plan:?}");
+        assert!(plan.warnings.is_empty(), "{plan:?}");
+        assert_eq!(plan.expected_downtime, ExpectedDowntime::None);
+    }
+
+    #[test]
+    fn plan_for_outdated_ecosystem() {
+        let target = target();
+        let old_version = version(ProtocolVersionId::Version25);
+        let mut deployed = deployed(old_version);
+        deployed.chains = vec![
+            chain("era", old_version),
+            chain("validium", target.protocol_version),
+        ];
+        deployed.chains[1].verifier = Address::repeat_byte(0x15);
+
+        let plan = build_upgrade_plan(&deployed, &target);
+        let kinds: Vec<_> = plan
+            .steps
+            .iter()
+            .map(|step| (step.kind, step.chain.as_deref()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (UpgradeStepKind::Script, None),
+                (UpgradeStepKind::GovernanceCall, None),
+                (UpgradeStepKind::GovernanceCall, None),
+                (UpgradeStepKind::GovernanceCall, None),
+                (UpgradeStepKind::Script, Some("era")),
+                (UpgradeStepKind::ChainAdminCall, Some("era")),
+                (UpgradeStepKind::ServerRestart, Some("era")),
+                (UpgradeStepKind::Script, None),
+            ]
+        );
+        assert_eq!(
+            plan.steps.last().unwrap().script,
+            Some(FINALIZE_UPGRADE_SCRIPT_PARAMS.script())
+        );
+        assert_eq!(
+            plan.expected_downtime,
+            ExpectedDowntime::UntilUpgradeExecuted
+        );
+        assert_eq!(plan.warnings.len(), 1, "{plan:?}");
+        assert!(plan.warnings[0].contains("validium"), "{plan:?}");
+    }
+
+    #[test]
+    fn plan_for_lagging_chain() {
+        let target = target();
+        let mut deployed = deployed(target.protocol_version);
+        deployed.bridgehub_implementation = target.bridgehub_implementation;
+        deployed.chain_type_manager_implementation = target.chain_type_manager_implementation;
+        deployed.chains = vec![chain("era", version(ProtocolVersionId::Version24))];
+
+        let plan = build_upgrade_plan(&deployed, &target);
+        assert_eq!(plan.steps.len(), 3, "{plan:?}");
+        assert!(plan
+            .steps
+            .iter()
+            .all(|step| step.chain.as_deref() == Some("era")));
+        assert_eq!(plan.warnings.len(), 1, "{plan:?}");
+    }
+}
@@ -3,7 +3,7 @@ use xshell::Shell;
 
 mod backend;
 mod init;
-mod run;
+pub(crate) mod run;
 
 #[derive(Subcommand, Debug)]
 pub enum ExplorerCommands {
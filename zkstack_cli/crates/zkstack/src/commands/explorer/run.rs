@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use xshell::Shell;
@@ -16,9 +16,33 @@ use crate::{
 
 pub(crate) fn run(shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
-    let ecosystem_path = shell.current_dir();
     // Get ecosystem level apps.yaml config
     let apps_config = AppsEcosystemConfig::read_or_create_default(shell)?;
+    let config_js_path = prepare_explorer_js_config(shell, &ecosystem_config)?;
+
+    let config_path = ExplorerConfig::get_config_path(&shell.current_dir());
+    logger::info(msg_explorer_running_with_config(&config_path));
+    logger::info(msg_explorer_starting_on(
+        "127.0.0.1",
+        apps_config.explorer.http_port,
+    ));
+    let name = explorer_app_name(&ecosystem_config.name);
+    run_explorer(
+        shell,
+        &config_js_path,
+        &name,
+        apps_config.explorer.http_port,
+    )?;
+    Ok(())
+}
+
+/// Filters the explorer config to the enabled chains and saves it as a JS file that can be mounted
+/// to the explorer app Docker image. Returns the path to the JS config.
+pub(crate) fn prepare_explorer_js_config(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+) -> anyhow::Result<PathBuf> {
+    let ecosystem_path = shell.current_dir();
     // If specific_chain is provided, run only with that chain; otherwise, run with all chains
     let chains_enabled = match global_config().chain_name {
         Some(ref chain_name) => vec![chain_name.clone()],
@@ -40,23 +64,9 @@ pub(crate) fn run(shell: &Shell) -> anyhow::Result<()> {
     // Save explorer config
     explorer_config.save(shell, &config_path)?;
 
-    let config_js_path = explorer_config
+    explorer_config
         .save_as_js(shell)
-        .context(MSG_EXPLORER_FAILED_TO_CREATE_CONFIG_ERR)?;
-
-    logger::info(msg_explorer_running_with_config(&config_path));
-    logger::info(msg_explorer_starting_on(
-        "127.0.0.1",
-        apps_config.explorer.http_port,
-    ));
-    let name = explorer_app_name(&ecosystem_config.name);
-    run_explorer(
-        shell,
-        &config_js_path,
-        &name,
-        apps_config.explorer.http_port,
-    )?;
-    Ok(())
+        .context(MSG_EXPLORER_FAILED_TO_CREATE_CONFIG_ERR)
 }
 
 fn run_explorer(
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use ethers::types::Address;
@@ -117,6 +117,25 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
     let ecosystem_config: EcosystemConfig = EcosystemConfig::from_file(shell)?;
     // Get ecosystem level apps.yaml config
     let apps_config = AppsEcosystemConfig::read_or_create_default(shell)?;
+    let config_js_path = prepare_portal_js_config(shell, &ecosystem_config).await?;
+
+    let config_path = PortalConfig::get_config_path(&shell.current_dir());
+    logger::info(msg_portal_running_with_config(&config_path));
+    logger::info(msg_portal_starting_on(
+        "127.0.0.1",
+        apps_config.portal.http_port,
+    ));
+    let name = portal_app_name(&ecosystem_config.name);
+    run_portal(shell, &config_js_path, &name, apps_config.portal.http_port)?;
+    Ok(())
+}
+
+/// Updates the portal config with the enabled chains and saves it as a JS file that can be mounted
+/// to the portal Docker image. Returns the path to the JS config.
+pub(crate) async fn prepare_portal_js_config(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+) -> anyhow::Result<PathBuf> {
     // Display all chains, unless --chain is passed
     let chains_enabled = match global_config().chain_name {
         Some(ref chain_name) => vec![chain_name.clone()],
@@ -129,7 +148,7 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
         .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?;
 
     // Validate and update portal config
-    validate_portal_config(&mut portal_config, &ecosystem_config).await?;
+    validate_portal_config(&mut portal_config, ecosystem_config).await?;
     portal_config.hide_except(&chains_enabled);
     if portal_config.is_empty() {
         anyhow::bail!(MSG_PORTAL_FAILED_TO_FIND_ANY_CHAIN_ERR);
@@ -138,18 +157,9 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
     // Save portal config
     portal_config.save(shell, &config_path)?;
 
-    let config_js_path = portal_config
+    portal_config
         .save_as_js(shell)
-        .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)?;
-
-    logger::info(msg_portal_running_with_config(&config_path));
-    logger::info(msg_portal_starting_on(
-        "127.0.0.1",
-        apps_config.portal.http_port,
-    ));
-    let name = portal_app_name(&ecosystem_config.name);
-    run_portal(shell, &config_js_path, &name, apps_config.portal.http_port)?;
-    Ok(())
+        .context(MSG_PORTAL_FAILED_TO_CREATE_CONFIG_ERR)
 }
 
 fn run_portal(shell: &Shell, config_file_path: &Path, name: &str, port: u16) -> anyhow::Result<()> {
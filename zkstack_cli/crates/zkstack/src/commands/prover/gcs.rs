@@ -1,3 +1,4 @@
+use anyhow::Context as _;
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{
     check_prerequisites, cmd::Cmd, logger, spinner::Spinner, GCLOUD_PREREQUISITE,
@@ -8,7 +9,8 @@ use super::args::init::ProofStorageGCSCreateBucket;
 use crate::{
     consts::PROVER_STORE_MAX_RETRIES,
     messages::{
-        msg_bucket_created, MSG_CREATING_GCS_BUCKET_SPINNER, MSG_GETTING_GCP_PROJECTS_SPINNER,
+        msg_bucket_created, msg_gcs_bucket_inaccessible, MSG_CREATING_GCS_BUCKET_SPINNER,
+        MSG_GETTING_GCP_PROJECTS_SPINNER, MSG_VALIDATING_GCS_BUCKET_SPINNER,
     },
 };
 
@@ -38,9 +40,33 @@ pub(crate) fn create_gcs_bucket(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     })
 }
 
+/// Checks that an existing GCS bucket is accessible, so that misconfigured buckets are caught during
+/// initialization rather than when provers start.
+pub(crate) fn validate_gcs_bucket(shell: &Shell, bucket_base_url: &str) -> anyhow::Result<()> {
+    check_prerequisites(shell, &GCLOUD_PREREQUISITE, false);
+
+    // The base URL may contain a path within the bucket (e.g., `gs://bucket/prefix`).
+    let bucket_name = bucket_base_url
+        .trim_start_matches("gs://")
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let bucket = format!("gs://{bucket_name}");
+    let cmd = Cmd::new(cmd!(
+        shell,
+        "gcloud storage buckets describe {bucket} --format=value(name)"
+    ));
+    let spinner = Spinner::new(MSG_VALIDATING_GCS_BUCKET_SPINNER);
+    cmd.run()
+        .with_context(|| msg_gcs_bucket_inaccessible(bucket_base_url))?;
+    spinner.finish();
+    Ok(())
+}
+
 pub(crate) fn get_project_ids(shell: &Shell) -> anyhow::Result<Vec<String>> {
     let spinner = Spinner::new(MSG_GETTING_GCP_PROJECTS_SPINNER);
 
@@ -17,7 +17,7 @@ use zksync_config::{configs::object_store::ObjectStoreMode, ObjectStoreConfig};
 use super::{
     args::init::{ProofStorageConfig, ProofStorageFileBacked, ProverInitArgs},
     compressor_keys::{download_compressor_key, get_default_compressor_keys_path},
-    gcs::create_gcs_bucket,
+    gcs::{create_gcs_bucket, validate_gcs_bucket},
     init_bellman_cuda::run as init_bellman_cuda,
     setup_keys,
 };
@@ -46,6 +46,13 @@ pub(crate) async fn run(args: ProverInitArgs, shell: &Shell) -> anyhow::Result<(
         copy_configs(shell, &ecosystem_config.link_to_code, &chain_config.configs)?;
     }
 
+    setup_keys::check_keys_match_chain(
+        shell,
+        &get_link_to_prover(&ecosystem_config),
+        &chain_config,
+    )
+    .await?;
+
     let mut general_config = chain_config.get_general_config().await?.patched();
 
     let proof_object_store_config =
@@ -104,14 +111,18 @@ fn get_object_store_config(
             &EcosystemConfig::from_file(shell)?,
             config,
         )?),
-        Some(ProofStorageConfig::GCS(config)) => Some(ObjectStoreConfig {
-            mode: ObjectStoreMode::GCSWithCredentialFile {
-                bucket_base_url: config.bucket_base_url,
-                gcs_credential_file_path: config.credentials_file,
-            },
-            max_retries: PROVER_STORE_MAX_RETRIES,
-            local_mirror_path: None,
-        }),
+        Some(ProofStorageConfig::GCS(config)) => {
+            validate_gcs_bucket(shell, &config.bucket_base_url)?;
+            Some(ObjectStoreConfig {
+                mode: ObjectStoreMode::GCSWithCredentialFile {
+                    bucket_base_url: config.bucket_base_url,
+                    gcs_credential_file_path: config.credentials_file,
+                },
+                max_retries: PROVER_STORE_MAX_RETRIES,
+                local_mirror_path: None,
+                write_versioned_envelopes: false,
+            })
+        }
         Some(ProofStorageConfig::GCSCreateBucket(config)) => {
             Some(create_gcs_bucket(shell, config)?)
         }
@@ -259,6 +270,7 @@ fn init_file_backed_proof_storage(
         },
         max_retries: PROVER_STORE_MAX_RETRIES,
         local_mirror_path: None,
+        write_versioned_envelopes: false,
     };
 
     Ok(object_store_config)
@@ -1,15 +1,68 @@
-use anyhow::Ok;
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{Context as _, Ok};
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{
     check_prerequisites, cmd::Cmd, logger, spinner::Spinner, GCLOUD_PREREQUISITE, GPU_PREREQUISITES,
 };
-use zkstack_cli_config::{get_link_to_prover, EcosystemConfig};
+use zkstack_cli_config::{get_link_to_prover, ChainConfig, EcosystemConfig};
+use zksync_types::{protocol_version::ProtocolSemanticVersion, H256};
 
 use crate::{
     commands::prover::args::setup_keys::{Mode, Region, SetupKeysArgs},
-    messages::{MSG_GENERATING_SK_SPINNER, MSG_SK_GENERATED},
+    messages::{
+        msg_setup_keys_mismatch, MSG_CHECKING_SETUP_KEYS_SPINNER, MSG_GENERATING_SK_SPINNER,
+        MSG_SK_GENERATED,
+    },
 };
 
+/// Checks that setup keys in the prover directory correspond to the verification keys expected by the chain,
+/// i.e., that the prover checkout matches the chain protocol version. Otherwise, downloaded or generated keys
+/// would produce proofs rejected on L1.
+pub(crate) async fn check_keys_match_chain(
+    shell: &Shell,
+    link_to_prover: &Path,
+    chain_config: &ChainConfig,
+) -> anyhow::Result<()> {
+    let spinner = Spinner::new(MSG_CHECKING_SETUP_KEYS_SPINNER);
+    let commitments_path = link_to_prover.join("data/keys/commitments.json");
+    let commitments = shell
+        .read_file(&commitments_path)
+        .with_context(|| format!("Could not read commitments file {commitments_path:?}"))?;
+    let commitments: HashMap<String, H256> =
+        serde_json::from_str(&commitments).context("Could not parse commitments.json")?;
+
+    let genesis = chain_config.get_genesis_config().await?;
+    let protocol_version = genesis
+        .get_opt::<ProtocolSemanticVersion>("genesis_protocol_semantic_version")?
+        .map_or_else(|| "unknown".to_owned(), |version| version.to_string());
+    for (commitment_key, genesis_key) in [
+        ("snark_wrapper", "prover.snark_wrapper_vk_hash"),
+        (
+            "fflonk_snark_wrapper",
+            "prover.fflonk_snark_wrapper_vk_hash",
+        ),
+    ] {
+        let Some(chain_commitment) = genesis.get_opt::<H256>(genesis_key)? else {
+            continue;
+        };
+        let keys_commitment = commitments
+            .get(commitment_key)
+            .with_context(|| format!("Could not find {commitment_key} in commitments.json"))?;
+        anyhow::ensure!(
+            *keys_commitment == chain_commitment,
+            msg_setup_keys_mismatch(
+                commitment_key,
+                &format!("{keys_commitment:?}"),
+                &format!("{chain_commitment:?}"),
+                &protocol_version
+            )
+        );
+    }
+    spinner.finish();
+    Ok(())
+}
+
 pub(crate) async fn run(args: SetupKeysArgs, shell: &Shell) -> anyhow::Result<()> {
     let args = args.fill_values_with_prompt();
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
@@ -5,6 +5,7 @@ use ethers::{
     utils::format_ether,
 };
 use url::Url;
+use zksync_basic_types::L2ChainId;
 use zksync_consensus_roles::attester;
 
 use crate::utils::forge::WalletOwner;
@@ -152,6 +153,21 @@ pub(super) const MSG_WRITING_OUTPUT_FILES_SPINNER: &str = "Writing output files.
 pub(super) const MSG_ECOSYSTEM_TXN_OUTRO: &str = "Transactions successfully built";
 pub(super) const MSG_ECOSYSTEM_TXN_OUT_PATH_INVALID_ERR: &str = "Invalid path";
 
+/// Ecosystem upgrade plan related messages
+pub(super) const MSG_UPGRADE_PLAN_TARGET_HELP: &str =
+    "Path to the description of the target protocol version (YAML, TOML or JSON)";
+pub(super) const MSG_UPGRADE_PLAN_OUT_HELP: &str = "Path to save the upgrade plan to";
+pub(super) const MSG_FETCHING_DEPLOYED_CONTRACTS_SPINNER: &str =
+    "Fetching deployed contracts from L1...";
+pub(super) const MSG_UPGRADE_PLAN: &str = "Upgrade plan";
+pub(super) const MSG_UPGRADE_PLAN_NOTHING_TO_DO: &str =
+    "Ecosystem matches the target protocol version, no upgrade is required";
+pub(super) const MSG_UPGRADE_PLAN_WARNINGS: &str = "Upgrade plan warnings:";
+
+pub(super) fn msg_upgrade_plan_saved(path: &Path) -> String {
+    format!("Upgrade plan saved to {}", path.display())
+}
+
 /// Chain create related messages
 pub(super) const MSG_PROVER_MODE_HELP: &str = "Prover options";
 pub(super) const MSG_CHAIN_ID_HELP: &str = "Chain ID";
@@ -214,6 +230,75 @@ pub(super) const MSG_INITIALIZING_PROVER_DATABASE: &str = "Initializing prover d
 pub(super) const MSG_FAILED_TO_DROP_PROVER_DATABASE_ERR: &str = "Failed to drop prover database";
 pub(super) const MSG_GENESIS_DATABASES_INITIALIZED: &str = "Databases initialized successfully";
 
+/// Chain backup related messages
+pub(super) const MSG_BACKUP_OUTPUT_DIR_HELP: &str =
+    "Directory to store the backup archive in. Defaults to `backups` in the current directory";
+pub(super) const MSG_BACKUP_DB_METHOD_HELP: &str = "Method used to back up the server database";
+pub(super) const MSG_BACKUP_UPLOAD_URL_HELP: &str =
+    "Object store URL to upload the backup archive to (e.g. gs://bucket/backups/)";
+pub(super) const MSG_BACKUP_ARCHIVE_HELP: &str =
+    "Path or object store URL (gs://...) of the backup archive";
+pub(super) const MSG_RESTORE_FORCE_HELP: &str =
+    "Overwrite the server database, Merkle tree and chain configs without confirmation";
+pub(super) const MSG_RESTORE_ABORTED: &str = "Restore aborted";
+pub(super) const MSG_BACKUP_UNSUPPORTED_UPLOAD_URL_ERR: &str =
+    "Only Google Cloud Storage (gs://) URLs are supported for backups";
+pub(super) const MSG_BASEBACKUP_RESTORE_UNSUPPORTED_ERR: &str =
+    "Backups made with `pg_basebackup` must be restored manually into the Postgres data directory";
+pub(super) const MSG_BACKING_UP_TREE_SPINNER: &str = "Backing up Merkle tree...";
+pub(super) const MSG_BACKING_UP_DATABASE_SPINNER: &str = "Backing up server database...";
+pub(super) const MSG_BACKING_UP_CONFIGS_SPINNER: &str = "Backing up chain configs...";
+pub(super) const MSG_UPLOADING_BACKUP_SPINNER: &str = "Uploading backup...";
+pub(super) const MSG_DOWNLOADING_BACKUP_SPINNER: &str = "Downloading backup...";
+pub(super) const MSG_VERIFYING_BACKUP_SPINNER: &str = "Verifying backup...";
+pub(super) const MSG_RESTORING_CONFIGS_SPINNER: &str = "Restoring chain configs...";
+pub(super) const MSG_RESTORING_DATABASE_SPINNER: &str = "Restoring server database...";
+pub(super) const MSG_RESTORING_TREE_SPINNER: &str = "Restoring Merkle tree...";
+
+pub(super) fn msg_backup_created(path: &Path) -> String {
+    format!("Backup created at {}", path.display())
+}
+
+pub(super) fn msg_backup_uploaded(url: &str) -> String {
+    format!("Backup uploaded to {url}")
+}
+
+pub(super) fn msg_backup_restored(chain_name: &str, created_at: &str) -> String {
+    format!("Chain {chain_name} restored from the backup created at {created_at}")
+}
+
+pub(super) fn msg_restore_confirm(chain_name: &str, db_name: &str, tree_path: &Path) -> String {
+    format!(
+        "Restoring will overwrite database `{db_name}`, the Merkle tree at {} and configs of chain {chain_name}. Continue?",
+        tree_path.display()
+    )
+}
+
+pub(super) fn msg_server_db_in_use(db_name: &str, connection_count: i64) -> String {
+    format!(
+        "Database `{db_name}` has {connection_count} active connection(s); stop the chain server and retry"
+    )
+}
+
+pub(super) fn msg_backup_unsupported_format_version(version: u32) -> String {
+    format!("Unsupported backup format version: {version}")
+}
+
+pub(super) fn msg_backup_chain_id_mismatch(backup: L2ChainId, chain: L2ChainId) -> String {
+    format!(
+        "Backup was made for chain ID {}, but the current chain ID is {}",
+        backup.as_u64(),
+        chain.as_u64()
+    )
+}
+
+pub(super) fn msg_backup_corrupted_files(files: &[String]) -> String {
+    format!(
+        "Backup integrity check failed for files: {}",
+        files.join(", ")
+    )
+}
+
 /// Chain update related messages
 pub(super) const MSG_WALLETS_CONFIG_MUST_BE_PRESENT: &str = "Wallets configuration must be present";
 
@@ -443,11 +528,33 @@ pub(super) const MSG_BELLMAN_CUDA_SELECTION_PATH: &str = "I have the code alread
 pub(super) const MSG_CLOUD_TYPE_PROMPT: &str = "Select the cloud connection mode:";
 pub(super) const MSG_THREADS_PROMPT: &str = "Provide the number of threads:";
 pub(super) const MSG_SETUP_KEYS_PROMPT: &str = "Do you want to setup keys?";
+pub(super) const MSG_VALIDATING_GCS_BUCKET_SPINNER: &str = "Validating GCS bucket...";
+pub(super) const MSG_CHECKING_SETUP_KEYS_SPINNER: &str =
+    "Checking that setup keys match the chain verification keys...";
 
 pub(super) fn msg_bucket_created(bucket_name: &str) -> String {
     format!("Bucket created successfully with url: gs://{bucket_name}")
 }
 
+pub(super) fn msg_gcs_bucket_inaccessible(bucket_base_url: &str) -> String {
+    format!(
+        "GCS bucket {bucket_base_url} does not exist or is not accessible with the current gcloud credentials"
+    )
+}
+
+pub(super) fn msg_setup_keys_mismatch(
+    key: &str,
+    keys_commitment: &str,
+    chain_commitment: &str,
+    protocol_version: &str,
+) -> String {
+    format!(
+        "Setup keys in the prover directory don't match the chain (protocol version {protocol_version}): \
+         {key} commitment is {keys_commitment}, while the chain expects {chain_commitment}. \
+         Check out the prover version matching the chain protocol version"
+    )
+}
+
 /// Contract verifier related messages
 pub(super) const MSG_BUILDING_CONTRACT_VERIFIER: &str = "Building contract verifier";
 pub(super) const MSG_RUNNING_CONTRACT_VERIFIER: &str = "Running contract verifier";